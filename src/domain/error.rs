@@ -65,6 +65,9 @@ pub enum GitTypeError {
 
     #[error("Validation error: {0}")]
     ValidationError(String),
+
+    #[error("Operation cancelled")]
+    Cancelled,
 }
 
 impl GitTypeError {
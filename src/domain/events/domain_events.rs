@@ -19,3 +19,17 @@ impl Event for DomainEvent {
         self
     }
 }
+
+/// Published once a finished stage is recorded, so the presentation layer can
+/// signal the end of the stage to the player
+#[derive(Debug, Clone)]
+pub struct StageCompleted {
+    /// The stage completed a session that beats the all-time best
+    pub personal_best: bool,
+}
+
+impl Event for StageCompleted {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
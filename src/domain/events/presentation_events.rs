@@ -1,6 +1,9 @@
 use std::any::Any;
+use std::path::PathBuf;
 
 use crate::domain::events::Event;
+use crate::domain::repositories::trending_repository::TrendingRepositoryInfo;
+use crate::infrastructure::http::GitHubStarredRepository;
 
 /// Event emitted when user requests to exit the application (Ctrl+C)
 #[derive(Clone, Debug)]
@@ -12,5 +15,46 @@ impl Event for ExitRequested {
     }
 }
 
+/// Event emitted to suspend the TUI and open a source file in the user's editor
+#[derive(Clone, Debug, PartialEq)]
+pub struct OpenInEditor {
+    pub path: PathBuf,
+    /// 1-indexed
+    pub line: usize,
+}
+
+impl Event for OpenInEditor {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 // Re-export ScreenTransition as NavigateTo event
 pub use crate::presentation::tui::ScreenTransition as NavigateTo;
+
+/// Event emitted from the background fetch once the starred repositories have arrived or failed
+#[derive(Clone, Debug)]
+pub struct StarredRepositoriesLoaded {
+    /// Error message on failure, so the screen can show it in place of the list
+    pub repositories: Result<Vec<GitHubStarredRepository>, String>,
+}
+
+impl Event for StarredRepositoriesLoaded {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Event emitted from the background fetch once a trending list has arrived or failed
+#[derive(Clone, Debug)]
+pub struct TrendingRepositoriesLoaded {
+    pub cache_key: String,
+    /// Error message on failure, so the screen can show it in place of the list
+    pub repositories: Result<Vec<TrendingRepositoryInfo>, String>,
+}
+
+impl Event for TrendingRepositoriesLoaded {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
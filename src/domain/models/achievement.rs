@@ -0,0 +1,197 @@
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use std::collections::HashSet;
+
+use crate::domain::models::storage::StoredStageResult;
+
+/// Milestones earned from completed stages; ids are stored, so never rename them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Achievement {
+    FirstStage,
+    TenStages,
+    HundredStages,
+    Wpm40,
+    Wpm60,
+    Wpm80,
+    Wpm100,
+    Flawless,
+    Polyglot,
+    Explorer,
+    ThreeDayStreak,
+    SevenDayStreak,
+    IntoTheWild,
+}
+
+/// An achievement with the time it was first earned, if it has been
+#[derive(Debug, Clone, PartialEq)]
+pub struct AchievementStatus {
+    pub achievement: Achievement,
+    pub unlocked_at: Option<DateTime<Utc>>,
+}
+
+/// Running totals over completed stages, in the order they were played
+#[derive(Debug, Clone, Default)]
+pub struct AchievementProgress {
+    pub stages: usize,
+    pub best_wpm: f64,
+    pub flawless: bool,
+    pub wild: bool,
+    pub languages: HashSet<String>,
+    pub repositories: HashSet<i64>,
+    pub longest_streak_days: usize,
+    streak_days: usize,
+    last_day: Option<NaiveDate>,
+}
+
+impl Achievement {
+    pub fn all() -> &'static [Achievement] {
+        &[
+            Achievement::FirstStage,
+            Achievement::TenStages,
+            Achievement::HundredStages,
+            Achievement::Wpm40,
+            Achievement::Wpm60,
+            Achievement::Wpm80,
+            Achievement::Wpm100,
+            Achievement::Flawless,
+            Achievement::Polyglot,
+            Achievement::Explorer,
+            Achievement::ThreeDayStreak,
+            Achievement::SevenDayStreak,
+            Achievement::IntoTheWild,
+        ]
+    }
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            Achievement::FirstStage => "first_stage",
+            Achievement::TenStages => "stages_10",
+            Achievement::HundredStages => "stages_100",
+            Achievement::Wpm40 => "wpm_40",
+            Achievement::Wpm60 => "wpm_60",
+            Achievement::Wpm80 => "wpm_80",
+            Achievement::Wpm100 => "wpm_100",
+            Achievement::Flawless => "flawless",
+            Achievement::Polyglot => "polyglot",
+            Achievement::Explorer => "explorer",
+            Achievement::ThreeDayStreak => "streak_3",
+            Achievement::SevenDayStreak => "streak_7",
+            Achievement::IntoTheWild => "into_the_wild",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .find(|achievement| achievement.id() == id)
+            .copied()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Achievement::FirstStage => "First Keystrokes",
+            Achievement::TenStages => "Warming Up",
+            Achievement::HundredStages => "Centurion",
+            Achievement::Wpm40 => "Steady Hands",
+            Achievement::Wpm60 => "Fluent",
+            Achievement::Wpm80 => "Fast Fingers",
+            Achievement::Wpm100 => "Hundred Club",
+            Achievement::Flawless => "Flawless",
+            Achievement::Polyglot => "Polyglot",
+            Achievement::Explorer => "Explorer",
+            Achievement::ThreeDayStreak => "On a Roll",
+            Achievement::SevenDayStreak => "Habit Formed",
+            Achievement::IntoTheWild => "Into the Wild",
+        }
+    }
+
+    /// How to earn it, shown while still locked
+    pub fn hint(&self) -> &'static str {
+        match self {
+            Achievement::FirstStage => "Complete your first stage",
+            Achievement::TenStages => "Complete 10 stages",
+            Achievement::HundredStages => "Complete 100 stages",
+            Achievement::Wpm40 => "Reach 40 WPM in a stage",
+            Achievement::Wpm60 => "Reach 60 WPM in a stage",
+            Achievement::Wpm80 => "Reach 80 WPM in a stage",
+            Achievement::Wpm100 => "Reach 100 WPM in a stage",
+            Achievement::Flawless => "Complete a stage with 100% accuracy",
+            Achievement::Polyglot => "Complete stages in 3 different languages",
+            Achievement::Explorer => "Complete stages in 5 different repositories",
+            Achievement::ThreeDayStreak => "Practice 3 days in a row",
+            Achievement::SevenDayStreak => "Practice 7 days in a row",
+            Achievement::IntoTheWild => "Complete a Wild difficulty stage",
+        }
+    }
+
+    pub fn is_met(&self, progress: &AchievementProgress) -> bool {
+        match self {
+            Achievement::FirstStage => progress.stages >= 1,
+            Achievement::TenStages => progress.stages >= 10,
+            Achievement::HundredStages => progress.stages >= 100,
+            Achievement::Wpm40 => progress.best_wpm >= 40.0,
+            Achievement::Wpm60 => progress.best_wpm >= 60.0,
+            Achievement::Wpm80 => progress.best_wpm >= 80.0,
+            Achievement::Wpm100 => progress.best_wpm >= 100.0,
+            Achievement::Flawless => progress.flawless,
+            Achievement::Polyglot => progress.languages.len() >= 3,
+            Achievement::Explorer => progress.repositories.len() >= 5,
+            Achievement::ThreeDayStreak => progress.longest_streak_days >= 3,
+            Achievement::SevenDayStreak => progress.longest_streak_days >= 7,
+            Achievement::IntoTheWild => progress.wild,
+        }
+    }
+
+    /// Replay completed stages oldest first, returning each achievement with the
+    /// completion time of the stage that earned it
+    pub fn evaluate(stages: &[StoredStageResult]) -> Vec<(Achievement, DateTime<Utc>)> {
+        let mut ordered: Vec<&StoredStageResult> = stages.iter().collect();
+        ordered.sort_by_key(|stage| stage.completed_at);
+
+        let mut progress = AchievementProgress::default();
+        let mut unlocked: Vec<(Achievement, DateTime<Utc>)> = Vec::new();
+        for stage in ordered {
+            progress.record(stage);
+            unlocked.extend(
+                Self::all()
+                    .iter()
+                    .filter(|achievement| {
+                        achievement.is_met(&progress)
+                            && !unlocked.iter().any(|(earned, _)| earned == *achievement)
+                    })
+                    .map(|achievement| (*achievement, stage.completed_at))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        unlocked
+    }
+}
+
+impl AchievementProgress {
+    pub fn record(&mut self, stage: &StoredStageResult) {
+        self.stages += 1;
+        self.best_wpm = self.best_wpm.max(stage.wpm);
+        self.flawless |= stage.accuracy >= 100.0 && stage.mistakes == 0;
+        self.wild |= stage.difficulty_level.as_deref() == Some("Wild");
+        if let Some(language) = stage.language.as_ref().filter(|l| !l.is_empty()) {
+            self.languages.insert(language.to_lowercase());
+        }
+        if let Some(repository_id) = stage.repository_id {
+            self.repositories.insert(repository_id);
+        }
+
+        let day = stage.completed_at.with_timezone(&Local).date_naive();
+        self.streak_days = match self.last_day {
+            Some(last) if last == day => self.streak_days,
+            Some(last) if last.succ_opt() == Some(day) => self.streak_days + 1,
+            _ => 1,
+        };
+        self.last_day = Some(day);
+        self.longest_streak_days = self.longest_streak_days.max(self.streak_days);
+    }
+}
+
+impl AchievementStatus {
+    pub fn is_unlocked(&self) -> bool {
+        self.unlocked_at.is_some()
+    }
+}
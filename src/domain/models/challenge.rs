@@ -109,6 +109,17 @@ impl Challenge {
         }
     }
 
+    /// Stable identity across extractions, unlike `id`: the same code always
+    /// has the same fingerprint, wherever and whenever it was extracted
+    pub fn fingerprint(&self) -> String {
+        use sha2::{Digest, Sha256};
+
+        Sha256::digest(self.code_content.as_bytes())
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
     pub fn get_display_title(&self) -> String {
         if let Some(ref path) = self.source_file_path {
             // Convert absolute path to relative path for cleaner display
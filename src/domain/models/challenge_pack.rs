@@ -0,0 +1,87 @@
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+use std::io::{Read, Write};
+
+use crate::domain::models::{Challenge, DifficultyLevel};
+use crate::{GitTypeError, Result};
+
+/// Bumped whenever a pack written by this version can't be read by older ones
+pub const PACK_FORMAT_VERSION: u32 = 1;
+
+/// A fixed, ordered set of challenges that plays as the identical session on
+/// every machine, e.g. for a class; stored as gzip-compressed JSON (`.gtpack`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChallengePack {
+    pub format_version: u32,
+    /// Repository the challenges came from, e.g. `owner/name`
+    pub source: String,
+    pub seed: u64,
+    pub difficulty: DifficultyLevel,
+    /// Played in this order, one per stage
+    pub challenges: Vec<Challenge>,
+}
+
+impl ChallengePack {
+    /// Pick `stages` challenges of `difficulty`; the same challenges and seed always give the same pack
+    pub fn new(
+        source: &str,
+        mut challenges: Vec<Challenge>,
+        difficulty: DifficultyLevel,
+        stages: usize,
+        seed: u64,
+    ) -> Self {
+        challenges.retain(|challenge| challenge.difficulty_level == Some(difficulty));
+        challenges.sort_by(|a, b| content_key(a).cmp(&content_key(b)));
+        challenges.shuffle(&mut StdRng::seed_from_u64(seed));
+        challenges.truncate(stages);
+
+        Self {
+            format_version: PACK_FORMAT_VERSION,
+            source: source.to_string(),
+            seed,
+            difficulty,
+            challenges,
+        }
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&serde_json::to_vec(self)?)?;
+        Ok(encoder.finish()?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut json = Vec::new();
+        GzDecoder::new(bytes)
+            .read_to_end(&mut json)
+            .map_err(|e| GitTypeError::ValidationError(format!("Not a challenge pack: {}", e)))?;
+        let pack: Self = serde_json::from_slice(&json)?;
+
+        if pack.format_version > PACK_FORMAT_VERSION {
+            return Err(GitTypeError::ValidationError(format!(
+                "This challenge pack needs a newer gittype (pack format {}, supported up to {})",
+                pack.format_version, PACK_FORMAT_VERSION
+            )));
+        }
+        if pack.challenges.is_empty() {
+            return Err(GitTypeError::ValidationError(
+                "The challenge pack contains no challenges".to_string(),
+            ));
+        }
+        Ok(pack)
+    }
+}
+
+/// Ids are random per extraction, so challenges are ordered by what they contain
+fn content_key(challenge: &Challenge) -> (Option<&str>, Option<usize>, Option<usize>, &str) {
+    (
+        challenge.source_file_path.as_deref(),
+        challenge.start_line,
+        challenge.end_line,
+        &challenge.code_content,
+    )
+}
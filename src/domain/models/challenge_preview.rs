@@ -0,0 +1,75 @@
+use super::{Challenge, DifficultyLevel, LanguageCalibrations, Languages, StreamerMode};
+use std::path::Path;
+
+/// What the stage summary shows of the upcoming challenge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChallengePreview {
+    /// File name without its directories; hidden in streamer mode
+    pub file_name: Option<String>,
+    pub language: String,
+    pub line_count: usize,
+    pub char_count: usize,
+    pub difficulty: Option<DifficultyLevel>,
+    /// Time the challenge should take at the player's pace in its language
+    pub expected_seconds: Option<u64>,
+}
+
+impl ChallengePreview {
+    pub fn new(challenge: &Challenge) -> Self {
+        Self {
+            file_name: challenge
+                .source_file_path
+                .as_deref()
+                .filter(|_| !StreamerMode::is_enabled())
+                .and_then(|path| Path::new(path).file_name())
+                .map(|name| name.to_string_lossy().to_string()),
+            language: Languages::get_display_name(challenge.language.as_deref()),
+            line_count: challenge.code_content.lines().count(),
+            char_count: challenge.code_content.chars().count(),
+            difficulty: challenge.difficulty_level,
+            expected_seconds: None,
+        }
+    }
+
+    /// Estimate the typing time from the player's calibrated pace in the
+    /// challenge's language
+    pub fn with_calibrations(
+        mut self,
+        challenge: &Challenge,
+        calibrations: &LanguageCalibrations,
+    ) -> Self {
+        self.expected_seconds = challenge
+            .language
+            .as_deref()
+            .and_then(|language| calibrations.expected_wpm(language))
+            .filter(|wpm| *wpm > 0.0)
+            .map(|wpm| (self.char_count as f64 / 5.0 / wpm * 60.0).round() as u64);
+        self
+    }
+
+    /// Single line summary, e.g. `main.rs · Rust · 12 lines · 340 chars · Normal · ~1m 05s`
+    pub fn summary(&self) -> String {
+        let lines = match self.line_count {
+            1 => "1 line".to_string(),
+            count => format!("{} lines", count),
+        };
+        self.file_name
+            .iter()
+            .cloned()
+            .chain([
+                self.language.clone(),
+                lines,
+                format!("{} chars", self.char_count),
+            ])
+            .chain(
+                self.difficulty
+                    .map(|difficulty| format!("{:?}", difficulty)),
+            )
+            .chain(self.expected_seconds.map(|seconds| match seconds {
+                0..=59 => format!("~{}s", seconds),
+                _ => format!("~{}m {:02}s", seconds / 60, seconds % 60),
+            }))
+            .collect::<Vec<_>>()
+            .join(" · ")
+    }
+}
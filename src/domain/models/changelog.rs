@@ -0,0 +1,80 @@
+/// CHANGELOG.md as shipped with this build
+const BUNDLED_CHANGELOG: &str = include_str!("../../../CHANGELOG.md");
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangelogSection {
+    pub title: String,
+    pub entries: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangelogRelease {
+    pub version: String,
+    pub date: Option<String>,
+    pub sections: Vec<ChangelogSection>,
+}
+
+/// Releases parsed from a Keep a Changelog document, newest first
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Changelog {
+    pub releases: Vec<ChangelogRelease>,
+}
+
+impl Changelog {
+    pub fn bundled() -> Self {
+        Self::parse(BUNDLED_CHANGELOG)
+    }
+
+    pub fn parse(markdown: &str) -> Self {
+        let mut releases: Vec<ChangelogRelease> = Vec::new();
+        for line in markdown.lines().map(str::trim_end) {
+            if let Some(heading) = line.strip_prefix("## [") {
+                let (version, rest) = heading.split_once(']').unwrap_or((heading, ""));
+                releases.push(ChangelogRelease {
+                    version: version.to_string(),
+                    date: rest
+                        .trim_start_matches([' ', '-'])
+                        .split_whitespace()
+                        .next()
+                        .map(str::to_string),
+                    sections: Vec::new(),
+                });
+            } else if let Some(release) = releases.last_mut() {
+                if let Some(title) = line.strip_prefix("### ") {
+                    release.sections.push(ChangelogSection {
+                        title: title.trim().to_string(),
+                        entries: Vec::new(),
+                    });
+                } else if let Some(entry) = line.strip_prefix("- ") {
+                    if let Some(section) = release.sections.last_mut() {
+                        section.entries.push(Self::strip_commit_link(entry));
+                    }
+                }
+            }
+        }
+        Self { releases }
+    }
+
+    /// Notes for a version, accepting an optional leading `v`
+    pub fn release(&self, version: &str) -> Option<&ChangelogRelease> {
+        let version = version.trim_start_matches('v');
+        self.releases
+            .iter()
+            .find(|release| release.version.trim_start_matches('v') == version)
+    }
+
+    /// The running version has notes and differs from the last version whose notes were shown
+    pub fn has_unseen_release(&self, last_seen: Option<&str>, current: &str) -> bool {
+        last_seen.map(|seen| seen.trim_start_matches('v')) != Some(current.trim_start_matches('v'))
+            && self.release(current).is_some()
+    }
+
+    fn strip_commit_link(entry: &str) -> String {
+        entry
+            .rfind(" ([")
+            .filter(|_| entry.ends_with(')'))
+            .map_or(entry, |index| &entry[..index])
+            .trim()
+            .to_string()
+    }
+}
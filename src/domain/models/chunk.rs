@@ -13,8 +13,12 @@ pub enum ChunkType {
     Module,
     Const,
     Variable,
-    Component, // JSX/TSX React components
-    Namespace, // For C# namespaces
+    Component,         // JSX/TSX React components
+    Hook,              // React hooks (`use*` functions)
+    Macro,             // Rust `macro_rules!` definitions
+    AsyncFunction,     // `async def` / async functions
+    DecoratedFunction, // functions together with their decorators
+    Namespace,         // For C# namespaces
     // New chunk types for middle implementations
     Loop,          // for/while/loop constructs
     Conditional,   // if/switch/match statements
@@ -25,6 +29,7 @@ pub enum ChunkType {
     Comprehension, // list/dict comprehensions
     CodeBlock,     // generic code blocks
     File,          // entire file for Zen mode
+    Prose,         // doc comments and README paragraphs
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use std::sync::RwLock;
+
+static CURRENT: RwLock<CommentaryStyle> = RwLock::new(CommentaryStyle::Playful);
+
+/// Tone of the rank commentary typed out before the session summary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentaryStyle {
+    /// A joke written for every rank
+    #[default]
+    Playful,
+    /// Plain statements of the rank and its tier, for classrooms and work
+    Serious,
+}
+
+impl CommentaryStyle {
+    pub fn current() -> CommentaryStyle {
+        *CURRENT.read().unwrap()
+    }
+
+    pub fn set_current(style: CommentaryStyle) {
+        *CURRENT.write().unwrap() = style;
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use std::sync::RwLock;
+
+static CURRENT: RwLock<CompletionFeedback> = RwLock::new(CompletionFeedback::None);
+
+/// How the terminal signals that a stage ended, so a player in a background
+/// pane notices; a session that sets a new all-time best signals twice
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CompletionFeedback {
+    #[default]
+    None,
+    /// The terminal bell, which multiplexers such as tmux mark on the pane
+    Bell,
+    /// The whole screen briefly in reverse video
+    Flash,
+}
+
+impl CompletionFeedback {
+    pub fn current() -> CompletionFeedback {
+        *CURRENT.read().unwrap()
+    }
+
+    pub fn set_current(feedback: CompletionFeedback) {
+        *CURRENT.write().unwrap() = feedback;
+    }
+
+    /// Signals for a finished stage: two when it completed a personal best session
+    pub fn repeats(personal_best: bool) -> usize {
+        if personal_best {
+            2
+        } else {
+            1
+        }
+    }
+}
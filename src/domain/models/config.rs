@@ -1,10 +1,57 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
 use crate::domain::models::color_mode::ColorMode;
+use crate::domain::models::commentary_style::CommentaryStyle;
+use crate::domain::models::completion_feedback::CompletionFeedback;
+use crate::domain::models::language::Language;
+use crate::domain::models::locale::LocalePreset;
+use crate::domain::models::race_bot::RaceBot;
+use crate::domain::models::reminder::Reminder;
+use crate::domain::models::wpm_mode::WpmMode;
+
+/// Discord application used for Rich Presence; override with `discord.client_id` in config.json
+pub const DEFAULT_DISCORD_CLIENT_ID: &str = "1290966396187418654";
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Config {
     pub theme: ThemeConfig,
+    #[serde(default)]
+    pub discord: DiscordConfig,
+    #[serde(default)]
+    pub tutorial: TutorialConfig,
+    #[serde(default)]
+    pub input: InputConfig,
+    #[serde(default)]
+    pub whats_new: WhatsNewConfig,
+    #[serde(default)]
+    pub profile: ProfileConfig,
+    #[serde(default)]
+    pub version_check: VersionCheckConfig,
+    #[serde(default)]
+    pub display: DisplayConfig,
+    #[serde(default)]
+    pub commentary: CommentaryConfig,
+    #[serde(default)]
+    pub streamer: StreamerConfig,
+    #[serde(default)]
+    pub extraction: ExtractionConfig,
+    #[serde(default)]
+    pub telemetry: TelemetryConfig,
+    #[serde(default)]
+    pub screenshots: ScreenshotConfig,
+    #[serde(default)]
+    pub difficulty_gates: DifficultyGateConfig,
+    #[serde(default)]
+    pub network: NetworkConfig,
+    #[serde(default)]
+    pub race: RaceConfig,
+    #[serde(default)]
+    pub reminders: ReminderConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,6 +59,11 @@ pub struct ThemeConfig {
     #[serde(default = "default_theme_id")]
     pub current_theme_id: String,
     pub current_color_mode: ColorMode,
+    /// Follow the terminal's background at startup; on for new configs,
+    /// off for configs saved before it existed so their color mode is kept,
+    /// and cleared once a color mode is picked in settings
+    #[serde(default)]
+    pub auto_color_mode: bool,
 }
 
 impl Default for ThemeConfig {
@@ -19,10 +71,442 @@ impl Default for ThemeConfig {
         Self {
             current_theme_id: "default".to_string(),
             current_color_mode: ColorMode::default(),
+            auto_color_mode: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscordConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_discord_client_id")]
+    pub client_id: String,
+}
+
+impl Default for DiscordConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            client_id: default_discord_client_id(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TutorialConfig {
+    #[serde(default)]
+    pub completed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputConfig {
+    /// Keyboard test calibration, removed from each completed stage's time
+    #[serde(default)]
+    pub latency_ms: u64,
+    /// Pause the stage while the terminal reports it lost focus
+    #[serde(default = "default_pause_on_focus_loss")]
+    pub pause_on_focus_loss: bool,
+}
+
+impl Default for InputConfig {
+    fn default() -> Self {
+        Self {
+            latency_ms: 0,
+            pause_on_focus_loss: default_pause_on_focus_loss(),
+        }
+    }
+}
+
+impl InputConfig {
+    pub fn latency_compensation(&self) -> Duration {
+        Duration::from_millis(self.latency_ms)
+    }
+}
+
+/// Release whose "What's new" notes were last shown, and the newer release
+/// whose update banner was dismissed
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct WhatsNewConfig {
+    #[serde(default)]
+    pub last_seen_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_update: Option<String>,
+}
+
+/// Identity shown on the profile screen and in shared results
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ProfileConfig {
+    #[serde(default)]
+    pub display_name: Option<String>,
+}
+
+/// How often to look for new releases on startup, and which releases count
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct VersionCheckConfig {
+    #[serde(default)]
+    pub frequency: VersionCheckFrequency,
+    #[serde(default)]
+    pub channel: ReleaseChannel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionCheckFrequency {
+    Off,
+    #[default]
+    Daily,
+    Weekly,
+}
+
+impl VersionCheckFrequency {
+    /// Hours between checks, or `None` when checking is disabled
+    pub fn interval_hours(&self) -> Option<u64> {
+        match self {
+            VersionCheckFrequency::Off => None,
+            VersionCheckFrequency::Daily => Some(24),
+            VersionCheckFrequency::Weekly => Some(24 * 7),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ReleaseChannel {
+    #[default]
+    Stable,
+    /// Also offer pre-releases such as `1.2.0-beta.1`
+    PreRelease,
+}
+
+/// How screens show numbers, dates, times and live stats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisplayConfig {
+    #[serde(default)]
+    pub locale: LocalePreset,
+    /// Show the live WPM in large digits beside the code while typing
+    #[serde(default)]
+    pub big_wpm: bool,
+    /// Move live stats and a minimap beside the code on ultra-wide terminals
+    #[serde(default)]
+    pub wide_layout: bool,
+    /// Whether speeds count five-character words or code tokens
+    #[serde(default)]
+    pub wpm_mode: WpmMode,
+    /// Show the repository and stage in the terminal window title while typing
+    #[serde(default = "default_window_title")]
+    pub window_title: bool,
+    /// Most frames drawn per second; keystrokes arriving faster share a frame.
+    /// `0` draws every change immediately.
+    #[serde(default = "default_max_fps")]
+    pub max_fps: u32,
+    /// Ring the bell or flash the screen when a stage ends
+    #[serde(default)]
+    pub completion_feedback: CompletionFeedback,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            locale: LocalePreset::default(),
+            big_wpm: false,
+            wide_layout: false,
+            wpm_mode: WpmMode::default(),
+            window_title: default_window_title(),
+            max_fps: default_max_fps(),
+            completion_feedback: CompletionFeedback::default(),
+        }
+    }
+}
+
+/// Rank commentary typed out before the session summary
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct CommentaryConfig {
+    #[serde(default)]
+    pub style: CommentaryStyle,
+    /// JSON file of rank messages, e.g. a translation; ranks and tiers it
+    /// leaves out keep the bundled lines of `style`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub messages_file: Option<PathBuf>,
+}
+
+/// Streamer mode hides repository names and paths, shows the big live WPM
+/// panel, and mirrors live stats to a text file for OBS
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct StreamerConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Defaults to `streamer_stats.txt` in the app data directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats_file: Option<PathBuf>,
+}
+
+/// Save the final typing screen of every stage, viewable from the session details
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ScreenshotConfig {
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// Lock Hard and Wild until the tier below was played at `required_accuracy` percent
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DifficultyGateConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_required_accuracy")]
+    pub required_accuracy: f64,
+}
+
+impl Default for DifficultyGateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            required_accuracy: default_required_accuracy(),
+        }
+    }
+}
+
+/// Bots that race every stage unless `--bots` picks others
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct RaceConfig {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bots: Vec<RaceBot>,
+}
+
+/// Daily practice reminders installed with `gittype remind --at`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ReminderConfig {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub at: Vec<Reminder>,
+}
+
+impl ReminderConfig {
+    /// Keeps the reminders sorted by time; false if `reminder` was already set
+    pub fn add(&mut self, reminder: Reminder) -> bool {
+        if self.at.contains(&reminder) {
+            return false;
         }
+        self.at.push(reminder);
+        self.at.sort();
+        true
+    }
+
+    /// False if `reminder` was not set
+    pub fn remove(&mut self, reminder: Reminder) -> bool {
+        let before = self.at.len();
+        self.at.retain(|at| *at != reminder);
+        self.at.len() != before
+    }
+}
+
+/// Environment variables checked for the proxy, in order; every online feature
+/// talks HTTPS, so `HTTPS_PROXY` wins over `HTTP_PROXY`
+const PROXY_ENV_VARS: [&str; 6] = [
+    "HTTPS_PROXY",
+    "https_proxy",
+    "ALL_PROXY",
+    "all_proxy",
+    "HTTP_PROXY",
+    "http_proxy",
+];
+const NO_PROXY_ENV_VARS: [&str; 2] = ["NO_PROXY", "no_proxy"];
+
+/// Proxy and certificate settings for trending, the version check and cloning
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct NetworkConfig {
+    /// Proxy URL such as `http://proxy.corp:8080`; defaults to `HTTPS_PROXY` or `HTTP_PROXY`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    /// Comma-separated hosts that bypass the proxy; defaults to `NO_PROXY`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_proxy: Option<String>,
+    /// PEM file of CA certificates trusted in addition to the system roots
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_bundle: Option<PathBuf>,
+}
+
+impl NetworkConfig {
+    pub const DEFAULT: NetworkConfig = NetworkConfig {
+        proxy: None,
+        no_proxy: None,
+        ca_bundle: None,
+    };
+
+    /// The configured proxy, else the first non-empty proxy environment variable
+    pub fn effective_proxy(&self) -> Option<String> {
+        self.effective_proxy_with(|name| std::env::var(name).ok())
+    }
+
+    /// The configured bypass list, else `NO_PROXY`
+    pub fn effective_no_proxy(&self) -> Option<String> {
+        self.effective_no_proxy_with(|name| std::env::var(name).ok())
+    }
+
+    /// `effective_proxy` reading variables through `env` instead of the process environment
+    pub fn effective_proxy_with(&self, env: impl Fn(&str) -> Option<String>) -> Option<String> {
+        Self::configured_or_env(&self.proxy, &PROXY_ENV_VARS, env)
+    }
+
+    /// `effective_no_proxy` reading variables through `env` instead of the process environment
+    pub fn effective_no_proxy_with(&self, env: impl Fn(&str) -> Option<String>) -> Option<String> {
+        Self::configured_or_env(&self.no_proxy, &NO_PROXY_ENV_VARS, env)
+    }
+
+    fn configured_or_env(
+        configured: &Option<String>,
+        names: &[&str],
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Option<String> {
+        configured
+            .iter()
+            .cloned()
+            .chain(names.iter().filter_map(|name| env(name)))
+            .map(|value| value.trim().to_string())
+            .find(|value| !value.is_empty())
+    }
+}
+
+/// Anonymous usage reports are off unless enabled with `gittype telemetry enable`,
+/// and are only sent once an endpoint is configured
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct TelemetryConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+    /// Reports are sent at most once a day
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_sent_on: Option<NaiveDate>,
+}
+
+impl TelemetryConfig {
+    /// Endpoint to send today's report to, if telemetry is enabled and not yet sent today
+    pub fn due_endpoint(&self, today: NaiveDate) -> Option<&str> {
+        self.endpoint
+            .as_deref()
+            .filter(|_| self.enabled && self.last_sent_on != Some(today))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ExtractionConfig {
+    #[serde(default)]
+    pub languages: LanguageSelection,
+    /// Extension to language name or alias, e.g. `"sc": "scala"`; takes
+    /// precedence over the built-in extension table
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extensions: BTreeMap<String, String>,
+    /// What happens to chunks that look like they contain credentials
+    #[serde(default)]
+    pub secrets: SecretHandling,
+}
+
+/// Chunks with likely API keys, tokens or private keys are never shown as they are
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretHandling {
+    /// Leave the chunk out of the challenges
+    #[default]
+    Skip,
+    /// Keep the chunk with every character of the secret replaced by `*`
+    Mask,
+}
+
+impl ExtractionConfig {
+    pub const DEFAULT: ExtractionConfig = ExtractionConfig {
+        languages: LanguageSelection::ALL,
+        extensions: BTreeMap::new(),
+        secrets: SecretHandling::Skip,
+    };
+
+    /// Language mapped to `extension` (with or without the leading dot)
+    pub fn mapped_language(&self, extension: &str) -> Option<Box<dyn Language>> {
+        self.extensions
+            .iter()
+            .find(|(mapped, _)| mapped.trim_start_matches('.') == extension)
+            .and_then(|(_, name)| crate::domain::models::Languages::get_by_name(name))
+    }
+
+    /// Extensions mapped to `language`, without the leading dot
+    pub fn extensions_for(&self, language: &dyn Language) -> Vec<&str> {
+        self.extensions
+            .keys()
+            .map(|extension| extension.trim_start_matches('.'))
+            .filter(|extension| {
+                self.mapped_language(extension)
+                    .is_some_and(|mapped| mapped.name() == language.name())
+            })
+            .collect()
+    }
+
+    /// Names in `languages` or `extensions` that are not a supported language or alias
+    pub fn unknown_names(&self) -> Vec<String> {
+        let mapped: Vec<String> = self.extensions.values().cloned().collect();
+        let mut unknown = self.languages.unknown_names();
+        unknown.extend(
+            crate::domain::models::Languages::validate_languages(&mapped)
+                .err()
+                .unwrap_or_default(),
+        );
+        unknown
+    }
+}
+
+/// Languages challenges may come from, by name or alias. An empty `enabled`
+/// list allows every language; `disabled` always wins.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct LanguageSelection {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub enabled: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled: Vec<String>,
+}
+
+impl LanguageSelection {
+    pub const ALL: LanguageSelection = LanguageSelection {
+        enabled: Vec::new(),
+        disabled: Vec::new(),
+    };
+
+    pub fn allows(&self, language: &dyn Language) -> bool {
+        let matches = |names: &[String]| {
+            names.iter().any(|name| {
+                let name = name.to_lowercase();
+                name == language.name() || language.aliases().contains(&name.as_str())
+            })
+        };
+        (self.enabled.is_empty() || matches(&self.enabled)) && !matches(&self.disabled)
+    }
+
+    /// Listed names that are not a supported language or alias
+    pub fn unknown_names(&self) -> Vec<String> {
+        let names: Vec<String> = self.enabled.iter().chain(&self.disabled).cloned().collect();
+        crate::domain::models::Languages::validate_languages(&names)
+            .err()
+            .unwrap_or_default()
     }
 }
 
 fn default_theme_id() -> String {
     "default".to_string()
 }
+
+fn default_discord_client_id() -> String {
+    DEFAULT_DISCORD_CLIENT_ID.to_string()
+}
+
+fn default_window_title() -> bool {
+    true
+}
+
+fn default_max_fps() -> u32 {
+    60
+}
+
+fn default_pause_on_focus_loss() -> bool {
+    true
+}
+
+fn default_required_accuracy() -> f64 {
+    95.0
+}
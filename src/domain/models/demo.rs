@@ -0,0 +1,94 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static PLAYING: AtomicBool = AtomicBool::new(false);
+static IDLE_TIMEOUT_SECS: AtomicU64 = AtomicU64::new(DemoMode::DEFAULT_IDLE_TIMEOUT.as_secs());
+
+/// Attract mode started with `gittype demo`: after the title screen sits idle,
+/// a scripted typist plays a session of real challenges. Nothing is saved
+pub struct DemoMode;
+
+impl DemoMode {
+    pub const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// How long each summary screen stays up before the demo moves on
+    pub const SUMMARY_DURATION: Duration = Duration::from_secs(5);
+
+    /// Speed of the scripted typist
+    pub const WPM: f64 = 75.0;
+
+    pub fn enable(idle_timeout: Duration) {
+        IDLE_TIMEOUT_SECS.store(idle_timeout.as_secs(), Ordering::Relaxed);
+        ENABLED.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_enabled() -> bool {
+        ENABLED.load(Ordering::Relaxed)
+    }
+
+    /// Time without input on the title screen before the demo starts
+    pub fn idle_timeout() -> Duration {
+        Duration::from_secs(IDLE_TIMEOUT_SECS.load(Ordering::Relaxed))
+    }
+
+    /// Whether the current session is played by the typist rather than a person
+    pub fn is_playing() -> bool {
+        Self::is_enabled() && PLAYING.load(Ordering::Relaxed)
+    }
+
+    pub fn set_playing(playing: bool) {
+        PLAYING.store(playing, Ordering::Relaxed);
+    }
+}
+
+/// Types a challenge at a steady pace with some unevenness and an occasional
+/// corrected typo, so the live stats read like a person's
+#[derive(Debug, Clone, PartialEq)]
+pub struct DemoTypist {
+    wpm: f64,
+    keystrokes: usize,
+    next_key_at: Duration,
+}
+
+impl DemoTypist {
+    /// Every this many keystrokes a letter or digit is mistyped first
+    const TYPO_EVERY: usize = 41;
+
+    /// Pause after a typo, as if noticing it
+    const TYPO_PAUSE: Duration = Duration::from_millis(350);
+
+    /// Multipliers of the average gap between keystrokes, cycled
+    const RHYTHM: [f64; 8] = [1.0, 0.75, 1.25, 0.9, 1.1, 0.8, 1.35, 0.85];
+
+    pub fn new(wpm: f64) -> Self {
+        Self {
+            wpm,
+            keystrokes: 0,
+            next_key_at: Duration::ZERO,
+        }
+    }
+
+    /// The key to press `elapsed` after typing started, when one is due;
+    /// `expected` is the next character of the challenge
+    pub fn next_key(&mut self, elapsed: Duration, expected: char) -> Option<char> {
+        if elapsed < self.next_key_at {
+            return None;
+        }
+        self.keystrokes += 1;
+
+        let gap = 60.0 / (self.wpm * 5.0) * Self::RHYTHM[self.keystrokes % Self::RHYTHM.len()];
+        let typo =
+            self.keystrokes.is_multiple_of(Self::TYPO_EVERY) && expected.is_ascii_alphanumeric();
+        self.next_key_at += Duration::from_secs_f64(gap);
+        if typo {
+            self.next_key_at += Self::TYPO_PAUSE;
+            return Some(if expected == 'x' { 'z' } else { 'x' });
+        }
+        Some(expected)
+    }
+
+    pub fn keystrokes(&self) -> usize {
+        self.keystrokes
+    }
+}
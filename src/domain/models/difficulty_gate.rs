@@ -0,0 +1,26 @@
+use crate::domain::models::DifficultyLevel;
+
+/// A difficulty the active profile can't play yet
+#[derive(Debug, Clone, PartialEq)]
+pub struct DifficultyLock {
+    pub difficulty: DifficultyLevel,
+    /// Tier to reach `required_accuracy` on
+    pub requires: DifficultyLevel,
+    pub required_accuracy: f64,
+    /// Best session accuracy on `requires`, if it was played
+    pub best_accuracy: Option<f64>,
+}
+
+impl DifficultyLock {
+    /// e.g. "Reach 95% accuracy on Normal to unlock Hard (best so far 91.2%)"
+    pub fn message(&self) -> String {
+        let progress = self
+            .best_accuracy
+            .map(|best| format!(" (best so far {:.1}%)", best))
+            .unwrap_or_default();
+        format!(
+            "Reach {}% accuracy on {:?} to unlock {:?}{}",
+            self.required_accuracy, self.requires, self.difficulty, progress
+        )
+    }
+}
@@ -10,6 +10,30 @@ pub enum DifficultyLevel {
 }
 
 impl DifficultyLevel {
+    pub const ALL: [DifficultyLevel; 5] = [
+        DifficultyLevel::Easy,
+        DifficultyLevel::Normal,
+        DifficultyLevel::Hard,
+        DifficultyLevel::Wild,
+        DifficultyLevel::Zen,
+    ];
+
+    /// Case-insensitive lookup by variant name, e.g. `normal`
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|difficulty| format!("{:?}", difficulty).eq_ignore_ascii_case(name))
+    }
+
+    /// Tier whose accuracy unlocks this one when difficulty gates are on
+    pub fn gate(&self) -> Option<DifficultyLevel> {
+        match self {
+            DifficultyLevel::Hard => Some(DifficultyLevel::Normal),
+            DifficultyLevel::Wild => Some(DifficultyLevel::Hard),
+            _ => None,
+        }
+    }
+
     pub fn char_limits(&self) -> (usize, usize) {
         match self {
             DifficultyLevel::Easy => (20, 100),
@@ -45,23 +69,17 @@ impl DifficultyLevel {
         chunk: &CodeChunk,
         code_char_count: usize,
     ) -> Vec<DifficultyLevel> {
-        [
-            DifficultyLevel::Easy,
-            DifficultyLevel::Normal,
-            DifficultyLevel::Hard,
-            DifficultyLevel::Wild,
-            DifficultyLevel::Zen,
-        ]
-        .iter()
-        .filter(|&difficulty| match difficulty {
-            DifficultyLevel::Zen => matches!(chunk.chunk_type, ChunkType::File),
-            DifficultyLevel::Wild => true,
-            _ => {
-                let (min_chars, _) = difficulty.char_limits();
-                code_char_count >= min_chars
-            }
-        })
-        .copied()
-        .collect()
+        Self::ALL
+            .iter()
+            .filter(|&difficulty| match difficulty {
+                DifficultyLevel::Zen => matches!(chunk.chunk_type, ChunkType::File),
+                DifficultyLevel::Wild => true,
+                _ => {
+                    let (min_chars, _) = difficulty.char_limits();
+                    code_char_count >= min_chars
+                }
+            })
+            .copied()
+            .collect()
     }
 }
@@ -0,0 +1,145 @@
+use rand::distr::weighted::WeightedIndex;
+use rand::distr::Distribution;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::domain::models::{Challenge, KeyLoad};
+
+/// Builds short drills out of the tokens of a repository's own code, e.g.
+/// `Foo::new()` or `->`, drawing those made of the player's weakest keys
+/// most often; drills are plain text, not real code challenges
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrillGenerator {
+    weak_keys: Vec<char>,
+    tokens: Vec<String>,
+    weights: Vec<f64>,
+}
+
+impl DrillGenerator {
+    pub const LINES_PER_DRILL: usize = 4;
+    pub const LINE_WIDTH: usize = 48;
+
+    /// Keys typed fewer times than this aren't judged yet
+    pub const MIN_KEYSTROKES: usize = 20;
+
+    /// How many of the weakest keys a drill concentrates on
+    const WEAK_KEYS: usize = 6;
+
+    /// Extra likelihood of a token per weak key it contains
+    const WEAK_KEY_WEIGHT: f64 = 4.0;
+
+    const MAX_TOKEN_CHARS: usize = 24;
+
+    /// Until enough keystrokes are recorded, the symbols of the code count as
+    /// the weak keys, since they are what code typing adds to prose
+    pub fn new(challenges: &[Challenge], key_load: &KeyLoad) -> Self {
+        let mut weak_keys = key_load
+            .weakest(Self::MIN_KEYSTROKES, Self::WEAK_KEYS * 2)
+            .into_iter()
+            .filter(|key| key.is_ascii_graphic())
+            .take(Self::WEAK_KEYS)
+            .collect::<Vec<_>>();
+
+        let counts = challenges.iter().flat_map(Self::tokens_of).fold(
+            BTreeMap::<String, usize>::new(),
+            |mut counts, token| {
+                *counts.entry(token).or_default() += 1;
+                counts
+            },
+        );
+        if weak_keys.is_empty() {
+            weak_keys = counts
+                .keys()
+                .flat_map(|token| token.chars())
+                .filter(|c| c.is_ascii_punctuation())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+        }
+
+        let (tokens, weights) = counts
+            .into_iter()
+            .map(|(token, count)| {
+                let weak_hits = token.chars().filter(|c| weak_keys.contains(c)).count();
+                let weight =
+                    (1.0 + (count as f64).ln()) * (1.0 + Self::WEAK_KEY_WEIGHT * weak_hits as f64);
+                (token, weight)
+            })
+            .unzip();
+
+        Self {
+            weak_keys,
+            tokens,
+            weights,
+        }
+    }
+
+    /// The keys the drills are weighted towards
+    pub fn weak_keys(&self) -> &[char] {
+        &self.weak_keys
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// `count` drills; the same tokens and seed always give the same drills
+    pub fn generate(&self, count: usize, seed: u64) -> Vec<Challenge> {
+        let Ok(distribution) = WeightedIndex::new(&self.weights) else {
+            return Vec::new();
+        };
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        (0..count)
+            .map(|index| {
+                let lines = (0..Self::LINES_PER_DRILL)
+                    .map(|_| {
+                        let mut line = String::new();
+                        loop {
+                            let token = &self.tokens[distribution.sample(&mut rng)];
+                            if !line.is_empty() && line.len() + 1 + token.len() > Self::LINE_WIDTH {
+                                break line;
+                            }
+                            if !line.is_empty() {
+                                line.push(' ');
+                            }
+                            line.push_str(token);
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                Challenge::new(format!("drill-{}-{}", seed, index + 1), lines.join("\n"))
+            })
+            .collect()
+    }
+
+    /// Whitespace-separated tokens of the code outside comments, kept to
+    /// short runs of printable ASCII
+    fn tokens_of(challenge: &Challenge) -> Vec<String> {
+        let code: String = challenge
+            .code_content
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let in_comment = challenge
+                    .comment_ranges
+                    .iter()
+                    .any(|(start, end)| i >= *start && i < *end);
+                if in_comment {
+                    ' '
+                } else {
+                    c
+                }
+            })
+            .collect();
+
+        code.split_whitespace()
+            .filter(|token| {
+                (2..=Self::MAX_TOKEN_CHARS).contains(&token.len())
+                    && token.chars().all(|c| c.is_ascii_graphic())
+            })
+            .map(str::to_string)
+            .collect()
+    }
+}
@@ -0,0 +1,187 @@
+use glob::{MatchOptions, Pattern};
+
+use std::borrow::Cow;
+use std::path::Path;
+
+pub const EDITOR_CONFIG_FILE: &str = ".editorconfig";
+
+/// Columns of a tab when `.editorconfig` sets neither `tab_width` nor `indent_size`
+pub const DEFAULT_TAB_WIDTH: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndentStyle {
+    Tab,
+    Space,
+}
+
+/// The indentation properties of `.editorconfig`; unset ones leave the source as written
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Indentation {
+    pub style: Option<IndentStyle>,
+    pub indent_size: Option<usize>,
+    pub tab_width: Option<usize>,
+}
+
+impl Indentation {
+    /// `tab_width`, which defaults to `indent_size` as in the EditorConfig spec
+    pub fn tab_width(&self) -> usize {
+        self.tab_width
+            .or(self.indent_size)
+            .filter(|width| *width > 0)
+            .unwrap_or(DEFAULT_TAB_WIDTH)
+    }
+
+    /// Properties of `self`, with unset ones taken from `fallback`
+    pub fn or(self, fallback: Self) -> Self {
+        Self {
+            style: self.style.or(fallback.style),
+            indent_size: self.indent_size.or(fallback.indent_size),
+            tab_width: self.tab_width.or(fallback.tab_width),
+        }
+    }
+
+    /// Rewrite the leading whitespace of every line in the configured style,
+    /// keeping its width; alignment narrower than a tab stays as spaces
+    pub fn apply<'a>(&self, source: &'a str) -> Cow<'a, str> {
+        let Some(style) = self.style else {
+            return Cow::Borrowed(source);
+        };
+        let tab_width = self.tab_width();
+        let mut changed = false;
+
+        let rewritten: String = source
+            .split_inclusive('\n')
+            .map(|line| {
+                let rest = line.trim_start_matches([' ', '\t']);
+                let leading = &line[..line.len() - rest.len()];
+                let columns = leading.chars().fold(0, |column, ch| match ch {
+                    '\t' => (column / tab_width + 1) * tab_width,
+                    _ => column + 1,
+                });
+                let indent = match style {
+                    IndentStyle::Space => " ".repeat(columns),
+                    IndentStyle::Tab => format!(
+                        "{}{}",
+                        "\t".repeat(columns / tab_width),
+                        " ".repeat(columns % tab_width)
+                    ),
+                };
+                changed |= indent != leading;
+                indent + rest
+            })
+            .collect();
+
+        if changed {
+            Cow::Owned(rewritten)
+        } else {
+            Cow::Borrowed(source)
+        }
+    }
+}
+
+/// One parsed `.editorconfig` file; only the indentation properties are read
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EditorConfig {
+    /// Set when `.editorconfig` files further up must be ignored
+    pub root: bool,
+    sections: Vec<(Vec<Pattern>, Indentation)>,
+}
+
+impl EditorConfig {
+    pub fn parse(content: &str) -> Self {
+        let mut config = Self::default();
+
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with(['#', ';']) {
+                continue;
+            }
+            if let Some(glob) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                config
+                    .sections
+                    .push((section_patterns(glob), Indentation::default()));
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = value.trim().to_ascii_lowercase();
+
+            match (
+                config.sections.last_mut(),
+                key.trim().to_ascii_lowercase().as_str(),
+            ) {
+                (None, "root") => config.root = value == "true",
+                (Some((_, indentation)), "indent_style") => {
+                    indentation.style = match value.as_str() {
+                        "tab" => Some(IndentStyle::Tab),
+                        "space" => Some(IndentStyle::Space),
+                        _ => None,
+                    }
+                }
+                (Some((_, indentation)), "indent_size") => {
+                    indentation.indent_size = value.parse().ok()
+                }
+                (Some((_, indentation)), "tab_width") => indentation.tab_width = value.parse().ok(),
+                _ => {}
+            }
+        }
+        config
+    }
+
+    /// Indentation for `relative_path`, relative to the directory of this
+    /// file; later sections override earlier ones
+    pub fn indentation_for(&self, relative_path: &Path) -> Indentation {
+        let path = relative_path.to_string_lossy().replace('\\', "/");
+        let options = MatchOptions {
+            require_literal_separator: true,
+            ..MatchOptions::new()
+        };
+
+        self.sections
+            .iter()
+            .filter(|(patterns, _)| {
+                patterns
+                    .iter()
+                    .any(|pattern| pattern.matches_with(&path, options))
+            })
+            .fold(Indentation::default(), |indentation, (_, section)| {
+                section.or(indentation)
+            })
+    }
+}
+
+/// Globs without a `/` match in any directory; `{a,b}` alternatives become
+/// separate patterns and `**.ext` becomes `**/*.ext`
+fn section_patterns(glob: &str) -> Vec<Pattern> {
+    let glob = match glob.strip_prefix('/') {
+        Some(anchored) => anchored.to_string(),
+        None if glob.contains('/') => glob.to_string(),
+        None => format!("**/{}", glob),
+    };
+    let glob = glob
+        .split("**")
+        .enumerate()
+        .map(|(i, part)| match part.chars().next() {
+            Some(ch) if i > 0 && ch != '/' => format!("/*{}", part),
+            _ => part.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("**");
+    expand_braces(&glob)
+        .iter()
+        .filter_map(|glob| Pattern::new(glob).ok())
+        .collect()
+}
+
+fn expand_braces(glob: &str) -> Vec<String> {
+    let Some((prefix, rest)) = glob.split_once('{') else {
+        return vec![glob.to_string()];
+    };
+    let Some((alternatives, suffix)) = rest.split_once('}') else {
+        return vec![glob.to_string()];
+    };
+    alternatives
+        .split(',')
+        .flat_map(|alternative| expand_braces(&format!("{}{}{}", prefix, alternative, suffix)))
+        .collect()
+}
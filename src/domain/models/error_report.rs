@@ -0,0 +1,214 @@
+use crate::GitTypeError;
+
+const ISSUES_URL: &str = "https://github.com/unhappychoice/gittype/issues";
+
+const CLONE_AUTH_SUGGESTIONS: [&str; 3] = [
+    "Check the repository exists and you have access to it",
+    "For private repositories, add your SSH key to ssh-agent and use git@github.com:owner/repo.git",
+    "Public repositories clone over https://github.com/owner/repo without credentials",
+];
+
+const CERTIFICATE_SUGGESTIONS: [&str; 2] = [
+    "Behind a TLS-inspecting proxy, set network.ca_bundle in ~/.gittype/config.json to its CA",
+    "Check the system clock is correct",
+];
+
+/// A failure explained for the player, with what to try next
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorReport {
+    pub title: String,
+    pub detail: Option<String>,
+    pub suggestions: Vec<String>,
+    /// Whether running the same step again can succeed without changing the command
+    pub retryable: bool,
+}
+
+impl ErrorReport {
+    pub fn from_error(error: &GitTypeError) -> Self {
+        match error {
+            GitTypeError::RepositoryCloneError(e) if Self::is_auth_failure(e) => Self::new(
+                "Authentication failed while cloning",
+                e.message(),
+                &CLONE_AUTH_SUGGESTIONS,
+                true,
+            ),
+            GitTypeError::RepositoryCloneError(e) if e.class() == git2::ErrorClass::Ssl => {
+                Self::new(
+                    "Could not verify the server's certificate",
+                    e.message(),
+                    &CERTIFICATE_SUGGESTIONS,
+                    true,
+                )
+            }
+            GitTypeError::RepositoryCloneError(e) => Self::new(
+                "Failed to clone repository",
+                e.message(),
+                &[
+                    "Check the repository URL is correct",
+                    "Check you have access to the repository",
+                    "Check your internet connection",
+                ],
+                true,
+            ),
+            GitTypeError::NoSupportedFiles => Self::no_challenges(None),
+            GitTypeError::ExtractionFailed(msg) if msg.starts_with("No challenges") => {
+                Self::no_challenges(Some(msg))
+            }
+            GitTypeError::TreeSitterLanguageError(e) => Self::unsupported_language(&e.to_string()),
+            GitTypeError::ExtractionFailed(msg)
+                if msg.starts_with("Unknown language")
+                    || msg.starts_with("Unsupported language") =>
+            {
+                Self::unsupported_language(msg)
+            }
+            GitTypeError::DatabaseError(e) if Self::is_locked(e) => Self::new(
+                "The database is locked",
+                &e.to_string(),
+                &[
+                    "Another gittype may be running; finish or quit it, then retry",
+                    "Sync tools holding ~/.gittype/gittype.db open can also lock it",
+                ],
+                true,
+            ),
+            GitTypeError::DatabaseError(e) => {
+                Self::new("Database error", &e.to_string(), &[], false)
+            }
+            GitTypeError::RepositoryNotFound(path) => Self::new(
+                "Repository not found",
+                &path.display().to_string(),
+                &["Ensure the path exists and is a valid repository"],
+                false,
+            ),
+            GitTypeError::ExtractionFailed(msg) => Self::new(
+                "Code extraction failed",
+                msg,
+                &["Try a different --langs filter"],
+                false,
+            ),
+            GitTypeError::InvalidRepositoryFormat(msg) => Self::new(
+                "Invalid repository format",
+                msg,
+                &[
+                    "Use owner/repo",
+                    "Use https://github.com/owner/repo",
+                    "Use git@github.com:owner/repo.git",
+                ],
+                false,
+            ),
+            GitTypeError::IoError(e) => Self::new("IO error", &e.to_string(), &[], true),
+            GitTypeError::GlobPatternError(e) => Self::new(
+                "Invalid glob pattern",
+                &e.to_string(),
+                &["Check the include and exclude patterns in your config"],
+                false,
+            ),
+            GitTypeError::SerializationError(e) => {
+                Self::new("Serialization error", &e.to_string(), &[], false)
+            }
+            GitTypeError::TerminalError(msg) if msg.contains("No such device or address") => {
+                Self::new(
+                    "Terminal error",
+                    msg,
+                    &[
+                        "This often happens in WSL or SSH sessions with limited terminal features",
+                        "Run gittype in a native or GUI terminal emulator",
+                    ],
+                    false,
+                )
+            }
+            GitTypeError::TerminalError(msg) => Self::new("Terminal error", msg, &[], false),
+            GitTypeError::WalkDirError(e) => Self::new(
+                "Directory walk error",
+                &e.to_string(),
+                &["Check directory permissions"],
+                true,
+            ),
+            GitTypeError::PanicError(msg) => {
+                Self::new("Application panic", msg, &[], false).reported_at(ISSUES_URL)
+            }
+            GitTypeError::HttpError(e) => Self::new(
+                "HTTP request failed",
+                &e.to_string(),
+                &["Check your internet connection"],
+                true,
+            ),
+            GitTypeError::ApiError(msg) => Self::new(
+                "API error",
+                msg,
+                &["The service may be temporarily unavailable"],
+                true,
+            ),
+            GitTypeError::ValidationError(msg) => Self {
+                title: msg.clone(),
+                detail: None,
+                suggestions: Vec::new(),
+                retryable: false,
+            },
+            GitTypeError::ScreenInitializationError(msg) => {
+                Self::new("Screen initialization error", msg, &[], false).reported_at(ISSUES_URL)
+            }
+            GitTypeError::Cancelled => Self {
+                title: "Cancelled".to_string(),
+                detail: None,
+                suggestions: Vec::new(),
+                retryable: true,
+            },
+        }
+    }
+
+    fn new(title: &str, detail: &str, suggestions: &[&str], retryable: bool) -> Self {
+        Self {
+            title: title.to_string(),
+            detail: Some(detail.to_string()),
+            suggestions: suggestions.iter().map(|s| s.to_string()).collect(),
+            retryable,
+        }
+    }
+
+    /// Internal errors have no fix on the player's side beyond reporting them
+    fn reported_at(mut self, url: &str) -> Self {
+        self.suggestions
+            .push(format!("This is unexpected; please report it at {}", url));
+        self
+    }
+
+    fn no_challenges(detail: Option<&str>) -> Self {
+        Self {
+            title: "No challenges found".to_string(),
+            detail: detail.map(str::to_string),
+            suggestions: vec![
+                "Use a different repository path".to_string(),
+                "Adjust the --langs filter (e.g., --langs rust,python)".to_string(),
+                "Run `gittype scan` to see which files are picked up".to_string(),
+            ],
+            retryable: false,
+        }
+    }
+
+    fn unsupported_language(detail: &str) -> Self {
+        Self::new(
+            "Unsupported language",
+            detail,
+            &[
+                "Run `gittype languages` to list the supported languages",
+                "Remove the language from --langs or from your config",
+            ],
+            false,
+        )
+    }
+
+    fn is_auth_failure(error: &git2::Error) -> bool {
+        error.code() == git2::ErrorCode::Auth
+            || (matches!(
+                error.class(),
+                git2::ErrorClass::Http | git2::ErrorClass::Ssh
+            ) && error.message().to_lowercase().contains("auth"))
+    }
+
+    fn is_locked(error: &rusqlite::Error) -> bool {
+        matches!(
+            error.sqlite_error_code(),
+            Some(rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked)
+        )
+    }
+}
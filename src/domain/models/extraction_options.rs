@@ -1,12 +1,28 @@
-use crate::domain::models::Languages;
+use crate::domain::models::{ChallengePack, Languages};
+
+use std::path::PathBuf;
 
 #[derive(Debug, Clone)]
 pub struct ExtractionOptions {
     pub include_patterns: Vec<String>,
     pub exclude_patterns: Vec<String>,
     pub languages: Option<Vec<String>>,
-    /// Maximum file size in bytes to process (default: 2MB)
-    pub max_file_size_bytes: u64,
+    /// Files larger than this many kilobytes are skipped (default: 1MB)
+    pub max_file_size_kb: u64,
+    /// Files averaging more characters per line than this are treated as minified
+    pub max_average_line_length: usize,
+    /// Repository-relative files to restrict extraction to (e.g. files touched by a commit)
+    pub commit_files: Option<Vec<PathBuf>>,
+    /// Name or repository-relative directory of the workspace package to restrict extraction to
+    pub package: Option<String>,
+    /// Walk into symlinked files and directories instead of skipping them
+    pub follow_symlinks: bool,
+    /// Also extract doc comments and README paragraphs as prose challenges
+    pub include_prose: bool,
+    /// Play every file as one whole challenge instead of extracting chunks
+    pub snippets: bool,
+    /// Play this pack's challenges in order instead of extracting any
+    pub pack: Option<ChallengePack>,
 }
 
 impl Default for ExtractionOptions {
@@ -95,12 +111,40 @@ impl Default for ExtractionOptions {
                 "**/perf-tests/**".to_string(),
             ],
             languages: None,
-            max_file_size_bytes: 1024 * 1024, // 1MB limit
+            max_file_size_kb: 1024, // 1MB limit
+            max_average_line_length: 500,
+            commit_files: None,
+            package: None,
+            follow_symlinks: false,
+            include_prose: false,
+            snippets: false,
+            pack: None,
         }
     }
 }
 
 impl ExtractionOptions {
+    /// Commit-scoped extraction only covers part of the repository, so it bypasses the cache
+    pub fn is_commit_scoped(&self) -> bool {
+        self.commit_files.is_some()
+    }
+
+    /// Challenges that differ from a plain extraction are neither read from
+    /// nor written to the cache
+    pub fn bypasses_cache(&self) -> bool {
+        self.is_commit_scoped() || self.package.is_some() || self.include_prose || self.snippets
+    }
+
+    /// Collect files of any extension and play each one whole
+    pub fn enable_snippets(&mut self) {
+        self.snippets = true;
+        self.include_patterns = vec!["**/*".to_string()];
+    }
+
+    pub fn exceeds_file_size_limit(&self, size_bytes: u64) -> bool {
+        size_bytes > self.max_file_size_kb * 1024
+    }
+
     pub fn apply_language_filter(&mut self) {
         if let Some(ref languages) = self.languages {
             let registry = Languages::all_languages();
@@ -112,7 +156,7 @@ impl ExtractionOptions {
                         name_lower == lang.name() || lang.aliases().contains(&name_lower.as_str())
                     })
                 })
-                .flat_map(|lang| lang.file_patterns())
+                .flat_map(|lang| Languages::file_patterns(lang.as_ref()))
                 .collect();
         }
     }
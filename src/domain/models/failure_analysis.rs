@@ -0,0 +1,152 @@
+use crate::domain::models::Challenge;
+
+/// Mistakes this close together (in characters) are reported as one cluster
+const CLUSTER_GAP: usize = 8;
+const MAX_CLUSTERS: usize = 3;
+const MAX_SNIPPET_CHARS: usize = 40;
+
+/// Where in the challenge's source a character sits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A run of mistakes close to each other in the challenge text
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorCluster {
+    pub location: SourceLocation,
+    pub mistakes: usize,
+    pub snippet: String,
+}
+
+/// What went wrong in the stage that ended the session
+#[derive(Debug, Clone, PartialEq)]
+pub struct FailureAnalysis {
+    pub challenge: Challenge,
+    pub typed_chars: usize,
+    pub total_chars: usize,
+    pub failure_location: SourceLocation,
+    pub expected_char: Option<char>,
+    pub clusters: Vec<ErrorCluster>,
+}
+
+impl FailureAnalysis {
+    /// `target_text` is the text the player was typing; `typed_chars` is how far
+    /// they got and `mistake_positions` are the character offsets they missed
+    pub fn new(
+        challenge: Challenge,
+        target_text: &str,
+        typed_chars: usize,
+        mistake_positions: &[usize],
+    ) -> Self {
+        let chars: Vec<char> = target_text.chars().collect();
+        let typed_chars = typed_chars.min(chars.len());
+        let first_line = challenge.start_line.unwrap_or(1);
+
+        Self {
+            typed_chars,
+            total_chars: chars.len(),
+            failure_location: Self::locate(&chars, typed_chars, first_line),
+            expected_char: chars.get(typed_chars).copied(),
+            clusters: Self::cluster(&chars, mistake_positions, first_line),
+            challenge,
+        }
+    }
+
+    /// e.g. "src/main.rs:12-40", falling back to the challenge id
+    pub fn challenge_label(&self) -> String {
+        match (&self.challenge.source_file_path, self.challenge.start_line) {
+            (Some(path), Some(start)) => match self.challenge.end_line {
+                Some(end) if end != start => format!("{}:{}-{}", path, start, end),
+                _ => format!("{}:{}", path, start),
+            },
+            (Some(path), None) => path.clone(),
+            (None, _) => self.challenge.id.clone(),
+        }
+    }
+
+    pub fn progress_percentage(&self) -> f64 {
+        if self.total_chars == 0 {
+            return 0.0;
+        }
+        self.typed_chars as f64 / self.total_chars as f64 * 100.0
+    }
+
+    /// The expected character, with whitespace made visible
+    pub fn expected_char_display(&self) -> Option<String> {
+        self.expected_char.map(|c| match c {
+            '\n' => "↵".to_string(),
+            '\t' => "⇥".to_string(),
+            ' ' => "␣".to_string(),
+            c => c.to_string(),
+        })
+    }
+
+    fn locate(chars: &[char], position: usize, first_line: usize) -> SourceLocation {
+        let before = &chars[..position.min(chars.len())];
+        let line_start = before
+            .iter()
+            .rposition(|&c| c == '\n')
+            .map_or(0, |index| index + 1);
+        SourceLocation {
+            line: first_line + before.iter().filter(|&&c| c == '\n').count(),
+            column: before.len() - line_start + 1,
+        }
+    }
+
+    fn cluster(chars: &[char], positions: &[usize], first_line: usize) -> Vec<ErrorCluster> {
+        let mut positions: Vec<usize> = positions
+            .iter()
+            .copied()
+            .filter(|&position| position < chars.len())
+            .collect();
+        positions.sort_unstable();
+
+        let mut groups: Vec<(usize, usize)> = Vec::new();
+        let mut last_position: Option<usize> = None;
+        for position in positions {
+            match (groups.last_mut(), last_position) {
+                (Some((_, mistakes)), Some(last)) if position - last <= CLUSTER_GAP => {
+                    *mistakes += 1
+                }
+                _ => groups.push((position, 1)),
+            }
+            last_position = Some(position);
+        }
+
+        groups.sort_by(|(a_start, a_count), (b_start, b_count)| {
+            b_count.cmp(a_count).then(a_start.cmp(b_start))
+        });
+        groups
+            .into_iter()
+            .take(MAX_CLUSTERS)
+            .map(|(start, mistakes)| ErrorCluster {
+                location: Self::locate(chars, start, first_line),
+                mistakes,
+                snippet: Self::line_snippet(chars, start),
+            })
+            .collect()
+    }
+
+    fn line_snippet(chars: &[char], position: usize) -> String {
+        let line_start = chars[..position]
+            .iter()
+            .rposition(|&c| c == '\n')
+            .map_or(0, |index| index + 1);
+        let line: String = chars[line_start..]
+            .iter()
+            .take_while(|&&c| c != '\n')
+            .collect();
+        let line = line.trim();
+
+        if line.chars().count() > MAX_SNIPPET_CHARS {
+            format!(
+                "{}…",
+                line.chars().take(MAX_SNIPPET_CHARS - 1).collect::<String>()
+            )
+        } else {
+            line.to_string()
+        }
+    }
+}
@@ -2,6 +2,12 @@ use serde::{Deserialize, Serialize};
 
 use std::path::PathBuf;
 
+/// Characters of a commit hash shown next to the branch
+const SHORT_COMMIT_LEN: usize = 7;
+
+/// What libgit2 reports as the branch of a detached HEAD; older sessions stored it
+pub const DETACHED_HEAD: &str = "HEAD";
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GitRepository {
     pub user_name: String,
@@ -11,9 +17,49 @@ pub struct GitRepository {
     pub commit_hash: Option<String>,
     pub is_dirty: bool,
     pub root_path: Option<PathBuf>,
+    /// SPDX expression of the repository's license, `None` when none was detected
+    #[serde(default)]
+    pub license: Option<String>,
 }
 
 impl GitRepository {
+    /// Credit for code taken from this repository, for shared results and exports
+    pub fn attribution(&self) -> String {
+        Self::attribution_for(
+            &format!("{}/{}", self.user_name, self.repository_name),
+            self.license.as_deref(),
+        )
+    }
+
+    pub fn attribution_for(repo_spec: &str, license: Option<&str>) -> String {
+        match license {
+            Some(license) => format!("Code by {} contributors, {} license", repo_spec, license),
+            None => format!("Code by {} contributors", repo_spec),
+        }
+    }
+
+    /// Branch and short commit of the checkout, like `main@1a2b3c4`
+    pub fn version_label(&self) -> Option<String> {
+        Self::version_label_for(self.branch.as_deref(), self.commit_hash.as_deref())
+    }
+
+    /// Version label of a recorded branch and commit; without a branch the
+    /// commit was played from a detached HEAD
+    pub fn version_label_for(branch: Option<&str>, commit_hash: Option<&str>) -> Option<String> {
+        let branch = branch.filter(|branch| *branch != DETACHED_HEAD);
+        let commit = commit_hash.map(Self::short_commit);
+        match (branch, commit) {
+            (Some(branch), Some(commit)) => Some(format!("{}@{}", branch, commit)),
+            (Some(branch), None) => Some(branch.to_string()),
+            (None, Some(commit)) => Some(format!("{} (detached)", commit)),
+            (None, None) => None,
+        }
+    }
+
+    pub fn short_commit(commit_hash: &str) -> &str {
+        &commit_hash[..commit_hash.len().min(SHORT_COMMIT_LEN)]
+    }
+
     /// Generate a cache key from the repository URL.
     /// Supports multiple URL formats:
     /// - https://github.com/owner/repo -> github_com_owner_repo
@@ -0,0 +1,196 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::storage::{SessionResultData, StoredRepository, StoredSession};
+use crate::{GitTypeError, Result};
+
+/// `.gittype-history.json`: finished sessions in a versioned format that other
+/// tools can read and write instead of the SQLite database. Readers ignore
+/// fields they don't know; only incompatible changes bump the version
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryFile {
+    pub format: String,
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub sessions: Vec<HistorySession>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistorySession {
+    pub started_at: DateTime<Utc>,
+    pub completed_at: DateTime<Utc>,
+    /// Profile that played the session; `null` for the default player
+    #[serde(default)]
+    pub player: Option<String>,
+    pub repository: HistoryRepository,
+    #[serde(default)]
+    pub branch: Option<String>,
+    #[serde(default)]
+    pub commit: Option<String>,
+    #[serde(default)]
+    pub dirty: bool,
+    pub game_mode: String,
+    #[serde(default)]
+    pub difficulty: Option<String>,
+    /// Implausible results are kept but never count for best records
+    #[serde(default)]
+    pub unverified: bool,
+    pub result: HistoryResult,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryRepository {
+    pub owner: String,
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryResult {
+    pub duration_ms: u64,
+    pub keystrokes: usize,
+    pub mistakes: usize,
+    pub wpm: f64,
+    pub cpm: f64,
+    /// Percent, 0 to 100
+    pub accuracy: f64,
+    pub score: f64,
+    pub stages_completed: usize,
+    pub stages_attempted: usize,
+    pub stages_skipped: usize,
+}
+
+impl HistoryFile {
+    pub const FORMAT: &'static str = "gittype-history";
+    pub const VERSION: u32 = 1;
+
+    pub fn new(exported_at: DateTime<Utc>, sessions: Vec<HistorySession>) -> Self {
+        Self {
+            format: Self::FORMAT.to_string(),
+            version: Self::VERSION,
+            exported_at,
+            sessions,
+        }
+    }
+
+    /// Whether `contents` claims to be a history file, before it is validated
+    pub fn is_history_file(contents: &str) -> bool {
+        serde_json::from_str::<serde_json::Value>(contents)
+            .is_ok_and(|value| value["format"] == Self::FORMAT)
+    }
+
+    /// Read and validate a history file
+    pub fn parse(contents: &str) -> Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(contents).map_err(|e| {
+            GitTypeError::ValidationError(format!("History file is not valid JSON: {}", e))
+        })?;
+        if value["format"] != Self::FORMAT {
+            return Err(GitTypeError::ValidationError(format!(
+                "Not a history file: \"format\" must be \"{}\"",
+                Self::FORMAT
+            )));
+        }
+        match value["version"].as_u64() {
+            Some(version) if version == u64::from(Self::VERSION) => {}
+            Some(version) if version > u64::from(Self::VERSION) => {
+                return Err(GitTypeError::ValidationError(format!(
+                    "History file version {} is newer than this gittype supports ({}); please upgrade",
+                    version,
+                    Self::VERSION
+                )))
+            }
+            _ => {
+                return Err(GitTypeError::ValidationError(format!(
+                    "Unsupported history file version: {}",
+                    value["version"]
+                )))
+            }
+        }
+
+        let file: Self = serde_json::from_value(value)
+            .map_err(|e| GitTypeError::ValidationError(format!("Invalid history file: {}", e)))?;
+        file.validate()?;
+        Ok(file)
+    }
+
+    /// Checks the rules the JSON types alone can't express, naming the first
+    /// offending field, e.g. `sessions[2].result.accuracy`
+    pub fn validate(&self) -> Result<()> {
+        for (index, session) in self.sessions.iter().enumerate() {
+            session.validate().map_err(|message| {
+                GitTypeError::ValidationError(format!(
+                    "Invalid history file: sessions[{}].{}",
+                    index, message
+                ))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl HistorySession {
+    pub fn new(
+        session: &StoredSession,
+        repository: &StoredRepository,
+        result: &SessionResultData,
+    ) -> Self {
+        Self {
+            started_at: session.started_at,
+            completed_at: session.completed_at.unwrap_or(session.started_at),
+            player: session.player_name.clone(),
+            repository: HistoryRepository {
+                owner: repository.user_name.clone(),
+                name: repository.repository_name.clone(),
+                url: repository.remote_url.clone(),
+            },
+            branch: session.branch.clone(),
+            commit: session.commit_hash.clone(),
+            dirty: session.is_dirty,
+            game_mode: session.game_mode.clone(),
+            difficulty: session.difficulty_level.clone(),
+            unverified: session.unverified,
+            result: HistoryResult {
+                duration_ms: result.duration_ms,
+                keystrokes: result.keystrokes,
+                mistakes: result.mistakes,
+                wpm: result.wpm,
+                cpm: result.cpm,
+                accuracy: result.accuracy,
+                score: result.score,
+                stages_completed: result.stages_completed,
+                stages_attempted: result.stages_attempted,
+                stages_skipped: result.stages_skipped,
+            },
+        }
+    }
+
+    fn validate(&self) -> std::result::Result<(), String> {
+        if self.completed_at < self.started_at {
+            return Err("completed_at is before started_at".to_string());
+        }
+        if self.repository.owner.trim().is_empty() || self.repository.name.trim().is_empty() {
+            return Err("repository needs an owner and a name".to_string());
+        }
+        if self.game_mode.trim().is_empty() {
+            return Err("game_mode must not be empty".to_string());
+        }
+
+        let result = &self.result;
+        for (field, value) in [
+            ("wpm", result.wpm),
+            ("cpm", result.cpm),
+            ("score", result.score),
+        ] {
+            if !value.is_finite() || value < 0.0 {
+                return Err(format!("result.{} must be a non-negative number", field));
+            }
+        }
+        if !(0.0..=100.0).contains(&result.accuracy) {
+            return Err("result.accuracy must be between 0 and 100".to_string());
+        }
+        if result.stages_completed > result.stages_attempted {
+            return Err("result.stages_completed is more than result.stages_attempted".to_string());
+        }
+        Ok(())
+    }
+}
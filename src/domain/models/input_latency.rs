@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+/// Upper bound on the time removed from a stage, so a slow probe cannot inflate scores
+pub const MAX_LATENCY_COMPENSATION: Duration = Duration::from_millis(100);
+
+/// Terminal round-trip time measured by the keyboard test
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatencyEstimate {
+    pub samples: usize,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl LatencyEstimate {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let (&min, &max) = (sorted.first()?, sorted.last()?);
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        Some(Self {
+            samples: sorted.len(),
+            median,
+            min,
+            max,
+        })
+    }
+
+    /// Time subtracted from each completed stage, in whole milliseconds
+    pub fn compensation_ms(&self) -> u64 {
+        self.median.min(MAX_LATENCY_COMPENSATION).as_millis() as u64
+    }
+}
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+/// Rows of a US QWERTY keyboard as (unshifted, shifted) keys, with the
+/// horizontal offset of each row in key widths
+const QWERTY_ROWS: [(&str, &str, f64); 4] = [
+    ("`1234567890-=", "~!@#$%^&*()_+", 0.0),
+    ("qwertyuiop[]\\", "QWERTYUIOP{}|", 1.5),
+    ("asdfghjkl;'", "ASDFGHJKL:\"", 1.75),
+    ("zxcvbnm,./", "ZXCVBNM<>?", 2.25),
+];
+
+/// Touch-typing fingers from left to right; both thumbs share the space bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Finger {
+    LeftPinky,
+    LeftRing,
+    LeftMiddle,
+    LeftIndex,
+    Thumb,
+    RightIndex,
+    RightMiddle,
+    RightRing,
+    RightPinky,
+}
+
+impl Finger {
+    pub fn all() -> [Finger; 9] {
+        [
+            Finger::LeftPinky,
+            Finger::LeftRing,
+            Finger::LeftMiddle,
+            Finger::LeftIndex,
+            Finger::Thumb,
+            Finger::RightIndex,
+            Finger::RightMiddle,
+            Finger::RightRing,
+            Finger::RightPinky,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Finger::LeftPinky => "Left pinky",
+            Finger::LeftRing => "Left ring",
+            Finger::LeftMiddle => "Left middle",
+            Finger::LeftIndex => "Left index",
+            Finger::Thumb => "Thumbs",
+            Finger::RightIndex => "Right index",
+            Finger::RightMiddle => "Right middle",
+            Finger::RightRing => "Right ring",
+            Finger::RightPinky => "Right pinky",
+        }
+    }
+}
+
+/// Physical key arrangement used to tell which key and finger types a character
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+}
+
+impl KeyboardLayout {
+    /// Row and column of the key that types `ch`, with or without Shift
+    pub fn key_position(&self, ch: char) -> Option<(usize, usize)> {
+        self.rows()
+            .iter()
+            .enumerate()
+            .find_map(|(row, (unshifted, shifted, _))| {
+                unshifted
+                    .chars()
+                    .position(|key| key == ch)
+                    .or_else(|| shifted.chars().position(|key| key == ch))
+                    .map(|column| (row, column))
+            })
+    }
+
+    /// Whether two keys touch, on the same row or staggered on neighbouring rows
+    pub fn are_adjacent(&self, a: (usize, usize), b: (usize, usize)) -> bool {
+        let x = |(row, column): (usize, usize)| column as f64 + self.rows()[row].2;
+        match a.0.abs_diff(b.0) {
+            0 => a.1.abs_diff(b.1) == 1,
+            1 => (x(a) - x(b)).abs() < 1.0,
+            _ => false,
+        }
+    }
+
+    /// The finger that types `ch` in standard touch typing; Tab and Enter go
+    /// to the pinkies and space to the thumbs
+    pub fn finger(&self, ch: char) -> Option<Finger> {
+        match ch {
+            ' ' => Some(Finger::Thumb),
+            '\t' => Some(Finger::LeftPinky),
+            '\n' => Some(Finger::RightPinky),
+            _ => self.key_position(ch).map(|(row, column)| {
+                // The number row has one more key left of the home columns
+                match column + usize::from(row > 0) {
+                    0..=1 => Finger::LeftPinky,
+                    2 => Finger::LeftRing,
+                    3 => Finger::LeftMiddle,
+                    4..=5 => Finger::LeftIndex,
+                    6..=7 => Finger::RightIndex,
+                    8 => Finger::RightMiddle,
+                    9 => Finger::RightRing,
+                    _ => Finger::RightPinky,
+                }
+            }),
+        }
+    }
+
+    fn rows(&self) -> &'static [(&'static str, &'static str, f64)] {
+        match self {
+            KeyboardLayout::Qwerty => &QWERTY_ROWS,
+        }
+    }
+}
@@ -0,0 +1,221 @@
+/// Where a set of keybindings applies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeymapContext {
+    Global,
+    Title,
+    Typing,
+    StageSummary,
+    SessionFailure,
+    SessionSummary,
+    Records,
+    Analytics,
+    Achievements,
+    Profile,
+    Settings,
+    Help,
+}
+
+impl KeymapContext {
+    pub fn all() -> [KeymapContext; 12] {
+        [
+            KeymapContext::Global,
+            KeymapContext::Title,
+            KeymapContext::Typing,
+            KeymapContext::StageSummary,
+            KeymapContext::SessionFailure,
+            KeymapContext::SessionSummary,
+            KeymapContext::Records,
+            KeymapContext::Analytics,
+            KeymapContext::Achievements,
+            KeymapContext::Profile,
+            KeymapContext::Settings,
+            KeymapContext::Help,
+        ]
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            KeymapContext::Global => "Everywhere",
+            KeymapContext::Title => "Title",
+            KeymapContext::Typing => "Typing",
+            KeymapContext::StageSummary => "Stage Summary",
+            KeymapContext::SessionFailure => "Session Failure",
+            KeymapContext::SessionSummary => "Session Summary",
+            KeymapContext::Records => "Records",
+            KeymapContext::Analytics => "Analytics",
+            KeymapContext::Achievements => "Achievements",
+            KeymapContext::Profile => "Profile",
+            KeymapContext::Settings => "Settings",
+            KeymapContext::Help => "Help",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBinding {
+    /// Display form of the keys, e.g. "←→/HL"
+    pub keys: String,
+    pub action: String,
+}
+
+impl KeyBinding {
+    pub fn new(keys: &str, action: &str) -> Self {
+        Self {
+            keys: keys.to_string(),
+            action: action.to_string(),
+        }
+    }
+}
+
+/// The keybindings active in each context
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keymap {
+    contexts: Vec<(KeymapContext, Vec<KeyBinding>)>,
+}
+
+impl Keymap {
+    pub fn new(contexts: Vec<(KeymapContext, Vec<KeyBinding>)>) -> Self {
+        Self { contexts }
+    }
+
+    pub fn bindings_for(&self, context: KeymapContext) -> &[KeyBinding] {
+        self.contexts
+            .iter()
+            .find(|(c, _)| *c == context)
+            .map_or(&[], |(_, bindings)| bindings.as_slice())
+    }
+
+    /// Contexts with at least one binding, in display order
+    pub fn contexts(&self) -> impl Iterator<Item = (KeymapContext, &[KeyBinding])> {
+        KeymapContext::all()
+            .into_iter()
+            .map(|context| (context, self.bindings_for(context)))
+            .filter(|(_, bindings)| !bindings.is_empty())
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(keys, action)| KeyBinding::new(keys, action))
+                .collect::<Vec<_>>()
+        };
+
+        Self::new(vec![
+            (
+                KeymapContext::Global,
+                bindings(&[("Ctrl+C", "Quit immediately")]),
+            ),
+            (
+                KeymapContext::Title,
+                bindings(&[
+                    ("SPACE", "Start game"),
+                    ("←→/HL", "Change difficulty"),
+                    ("R", "Records"),
+                    ("A", "Analytics"),
+                    ("E", "Achievements"),
+                    ("P", "Profile"),
+                    ("S", "Settings"),
+                    ("I/?", "Help"),
+                    ("ESC", "Quit"),
+                ]),
+            ),
+            (
+                KeymapContext::Typing,
+                bindings(&[
+                    ("SPACE", "Start countdown"),
+                    ("ESC", "Open or close the stage menu"),
+                    ("S", "Skip stage (in menu)"),
+                    ("F", "Flag challenge as bad & skip (in menu)"),
+                    ("W", "Show/hide big WPM panel (in menu)"),
+                    ("Q", "Give up (in menu)"),
+                ]),
+            ),
+            (
+                KeymapContext::StageSummary,
+                bindings(&[
+                    ("SPACE", "Continue"),
+                    ("S", "Study the challenge's source file"),
+                    ("O", "Open the source file in $EDITOR"),
+                    ("F", "Flag challenge as bad"),
+                    ("ESC", "Quit session"),
+                ]),
+            ),
+            (
+                KeymapContext::SessionFailure,
+                bindings(&[
+                    ("R", "Retry with new challenges"),
+                    ("C", "Retry the failed challenge"),
+                    ("T", "Back to title"),
+                    ("ESC", "Session summary & exit"),
+                ]),
+            ),
+            (
+                KeymapContext::SessionSummary,
+                bindings(&[
+                    ("D", "Stage details"),
+                    ("R", "Retry"),
+                    ("S", "Share result"),
+                    ("T", "Back to title"),
+                    ("ESC", "Exit"),
+                ]),
+            ),
+            (
+                KeymapContext::Records,
+                bindings(&[
+                    ("↑↓/JK", "Select session"),
+                    ("ENTER/SPACE", "Session details"),
+                    ("S", "Change sort"),
+                    ("F", "Change date filter"),
+                    ("R", "Refresh"),
+                    ("ESC", "Back"),
+                ]),
+            ),
+            (
+                KeymapContext::Analytics,
+                bindings(&[
+                    ("←→/HL", "Switch view"),
+                    ("↑↓/JK", "Select item"),
+                    ("R", "Refresh"),
+                    ("ESC", "Back"),
+                ]),
+            ),
+            (
+                KeymapContext::Achievements,
+                bindings(&[("↑↓/JK", "Select achievement"), ("ESC", "Back")]),
+            ),
+            (
+                KeymapContext::Profile,
+                bindings(&[
+                    ("E", "Edit display name"),
+                    ("ENTER", "Save name"),
+                    ("ESC", "Cancel editing or go back"),
+                ]),
+            ),
+            (
+                KeymapContext::Settings,
+                bindings(&[
+                    ("←→/HL", "Switch section"),
+                    ("TAB", "Next category"),
+                    ("↑↓/JK", "Select option"),
+                    ("/", "Search settings"),
+                    ("SPACE", "Save and close"),
+                    ("D", "Keyboard test"),
+                    ("ESC", "Clear search or discard changes"),
+                ]),
+            ),
+            (
+                KeymapContext::Help,
+                bindings(&[
+                    ("←→/HL", "Switch tabs"),
+                    ("↑↓/JK", "Scroll"),
+                    ("G", "GitHub"),
+                    ("T", "Tutorial"),
+                    ("ESC", "Close"),
+                ]),
+            ),
+        ])
+    }
+}
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+
+use crate::domain::models::{Finger, KeyboardLayout};
+
+/// Keystrokes expected on a key or finger and how many of them were mistyped
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoadStats {
+    pub keystrokes: usize,
+    pub mistakes: usize,
+}
+
+impl LoadStats {
+    /// Share of the keystrokes that were mistyped, in percent
+    pub fn error_rate(&self) -> f64 {
+        if self.keystrokes == 0 {
+            return 0.0;
+        }
+        self.mistakes as f64 / self.keystrokes as f64 * 100.0
+    }
+}
+
+/// Keystrokes of a stage or session counted per `K`, such as the expected
+/// character or the finger that types it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Load<K: Ord> {
+    entries: BTreeMap<K, LoadStats>,
+}
+
+/// Keystrokes counted per expected character; the one load that is stored
+pub type KeyLoad = Load<char>;
+
+/// Keystrokes attributed to the finger that types the expected character
+pub type FingerLoad = Load<Finger>;
+
+impl<K: Ord> Default for Load<K> {
+    fn default() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+}
+
+impl<K: Ord + Copy> Load<K> {
+    pub fn record(&mut self, key: K, is_correct: bool) {
+        self.add(key, 1, usize::from(!is_correct));
+    }
+
+    pub fn stats(&self, key: K) -> LoadStats {
+        self.entries.get(&key).copied().unwrap_or_default()
+    }
+
+    pub fn total_keystrokes(&self) -> usize {
+        self.entries.values().map(|stats| stats.keystrokes).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total_keystrokes() == 0
+    }
+
+    /// Share of all keystrokes that fell on `key`, in percent
+    pub fn load_percentage(&self, key: K) -> f64 {
+        match self.total_keystrokes() {
+            0 => 0.0,
+            total => self.stats(key).keystrokes as f64 / total as f64 * 100.0,
+        }
+    }
+
+    /// Counts of both loads added together
+    pub fn merge(&self, other: &Self) -> Self {
+        let mut merged = self.clone();
+        other
+            .entries
+            .iter()
+            .for_each(|(key, stats)| merged.add(*key, stats.keystrokes, stats.mistakes));
+        merged
+    }
+
+    /// Up to `limit` entries with the highest error rate among those with at
+    /// least `min_keystrokes`, weakest first, so a couple of slips on a rare
+    /// key don't dominate
+    pub fn weakest(&self, min_keystrokes: usize, limit: usize) -> Vec<K> {
+        let mut weak: Vec<(K, LoadStats)> = self
+            .entries
+            .iter()
+            .filter(|(_, stats)| stats.keystrokes >= min_keystrokes && stats.mistakes > 0)
+            .map(|(key, stats)| (*key, *stats))
+            .collect();
+        weak.sort_by(|(_, a), (_, b)| b.error_rate().total_cmp(&a.error_rate()));
+        weak.into_iter().take(limit).map(|(key, _)| key).collect()
+    }
+
+    fn add(&mut self, key: K, keystrokes: usize, mistakes: usize) {
+        let stats = self.entries.entry(key).or_default();
+        stats.keystrokes += keystrokes;
+        stats.mistakes += mistakes;
+    }
+}
+
+impl KeyLoad {
+    /// The same keystrokes attributed to the finger that types each character
+    /// on `layout`; characters off the layout are left out
+    pub fn by_finger(&self, layout: &KeyboardLayout) -> FingerLoad {
+        self.entries
+            .iter()
+            .filter_map(|(ch, stats)| layout.finger(*ch).map(|finger| (finger, stats)))
+            .fold(FingerLoad::default(), |mut load, (finger, stats)| {
+                load.add(finger, stats.keystrokes, stats.mistakes);
+                load
+            })
+    }
+}
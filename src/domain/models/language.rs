@@ -1,10 +1,31 @@
 use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use crate::domain::models::config::{ExtractionConfig, LanguageSelection};
 
 use crate::domain::models::languages::{
     CSharp, Clojure, Cpp, Dart, Elixir, Erlang, Go, Haskell, Java, JavaScript, Kotlin, Php, Python,
     Ruby, Rust, Scala, Swift, TypeScript, Zig, C,
 };
 
+static EXTRACTION: RwLock<ExtractionConfig> = RwLock::new(ExtractionConfig::DEFAULT);
+
+/// Extension shared by C and C++ headers
+const HEADER_EXTENSION: &str = "h";
+
+/// Line prefixes only C++ headers contain
+const CPP_HEADER_MARKERS: [&str; 8] = [
+    "class ",
+    "namespace ",
+    "using ",
+    "template<",
+    "template <",
+    "public:",
+    "private:",
+    "protected:",
+];
+
 /// Domain trait representing a programming language
 pub trait Language: std::fmt::Debug + Send + Sync {
     /// Returns the internal name of the language
@@ -104,10 +125,24 @@ impl Languages {
     pub fn all_file_patterns() -> Vec<String> {
         Self::all_languages()
             .into_iter()
-            .flat_map(|lang| lang.file_patterns())
+            .flat_map(|lang| Self::file_patterns(lang.as_ref()))
             .collect()
     }
 
+    /// Glob patterns for `language`, including extensions mapped to it in
+    /// `extraction.extensions`
+    pub fn file_patterns(language: &dyn Language) -> Vec<String> {
+        let extraction = Self::extraction();
+        let mut patterns = language.file_patterns();
+        patterns.extend(
+            extraction
+                .extensions_for(language)
+                .into_iter()
+                .map(|ext| format!("**/*.{}", ext)),
+        );
+        patterns
+    }
+
     pub fn get_supported_languages() -> Vec<&'static str> {
         Self::all_languages()
             .into_iter()
@@ -134,17 +169,114 @@ impl Languages {
         }
     }
 
+    /// Extensions mapped in `extraction.extensions` win over the built-in
+    /// table; languages left out by `extraction.languages` never match
     pub fn from_extension(extension: &str) -> Option<Box<dyn Language>> {
-        Self::all_languages()
-            .into_iter()
-            .find(|lang| lang.extensions().contains(&extension))
+        Self::from_extension_in(extension, &Self::extraction())
+    }
+
+    pub fn from_extension_in(
+        extension: &str,
+        extraction: &ExtractionConfig,
+    ) -> Option<Box<dyn Language>> {
+        extraction
+            .mapped_language(extension)
+            .or_else(|| {
+                Self::all_languages()
+                    .into_iter()
+                    .find(|lang| lang.extensions().contains(&extension))
+            })
+            .filter(|lang| extraction.languages.allows(lang.as_ref()))
+    }
+
+    /// Extraction settings from config; the built-in defaults until set
+    pub fn extraction() -> ExtractionConfig {
+        EXTRACTION.read().unwrap().clone()
+    }
+
+    pub fn set_extraction(extraction: ExtractionConfig) {
+        *EXTRACTION.write().unwrap() = extraction;
+    }
+
+    /// Languages challenges may come from; every language until set from config
+    pub fn selection() -> LanguageSelection {
+        Self::extraction().languages
+    }
+
+    /// Languages for the files of one project. Unless `.h` is mapped in
+    /// config, headers are parsed as C++ when the project has C++ sources
+    /// but no C sources.
+    pub fn for_project_files(files: &[PathBuf]) -> Vec<(PathBuf, Box<dyn Language>)> {
+        let extraction = Self::extraction();
+        let has_extension = |extensions: &[&str]| {
+            files.iter().any(|path| {
+                path.extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| extensions.contains(&ext) && ext != HEADER_EXTENSION)
+            })
+        };
+        let headers_are_cpp = extraction.mapped_language(HEADER_EXTENSION).is_none()
+            && has_extension(&Cpp.extensions())
+            && !has_extension(&C.extensions())
+            && extraction.languages.allows(&Cpp);
+
+        files
+            .iter()
+            .filter_map(|path| {
+                let extension = path.extension().and_then(|ext| ext.to_str())?;
+                let language: Box<dyn Language> = match extension {
+                    HEADER_EXTENSION if headers_are_cpp => Box::new(Cpp),
+                    _ => Self::from_extension_in(extension, &extraction)?,
+                };
+                Some((path.clone(), language))
+            })
+            .collect()
+    }
+
+    /// Reclassifies a `.h` header from its content: C++-only syntax makes it
+    /// C++, while an `extern "C"` block marks a C API shared with C++ code
+    pub fn refine_header(
+        path: &Path,
+        content: &str,
+        language: Box<dyn Language>,
+    ) -> Box<dyn Language> {
+        let extraction = Self::extraction();
+        if path.extension().and_then(|ext| ext.to_str()) != Some(HEADER_EXTENSION)
+            || extraction.mapped_language(HEADER_EXTENSION).is_some()
+        {
+            return language;
+        }
+
+        let has_cpp_syntax = content.lines().map(str::trim_start).any(|line| {
+            CPP_HEADER_MARKERS
+                .iter()
+                .any(|marker| line.starts_with(marker))
+        });
+        let refined: Box<dyn Language> = if has_cpp_syntax {
+            Box::new(Cpp)
+        } else if content.contains("extern \"C\"") {
+            Box::new(C)
+        } else {
+            return language;
+        };
+        if extraction.languages.allows(refined.as_ref()) {
+            refined
+        } else {
+            language
+        }
     }
 
-    pub fn detect_from_path(path: &std::path::Path) -> String {
+    pub fn detect_from_path(path: &Path) -> String {
         match path.extension().and_then(|ext| ext.to_str()) {
-            Some(ext) => Self::from_extension(ext)
-                .map(|lang| lang.name().to_string())
-                .unwrap_or_else(|| "text".to_string()),
+            Some(ext) => Self::from_extension_in(
+                ext,
+                &ExtractionConfig {
+                    languages: LanguageSelection::ALL,
+                    ..Self::extraction()
+                },
+            )
+            .map(|lang| lang.name().to_string())
+            .unwrap_or_else(|| "text".to_string()),
             None => "text".to_string(),
         }
     }
@@ -0,0 +1,114 @@
+use serde::Serialize;
+
+use crate::domain::models::storage::LanguageActivity;
+
+/// How fast one language types for the player compared to their code typing
+/// overall; symbol heavy languages such as Haskell sit below 1.0
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LanguageCalibration {
+    pub language: String,
+    pub factor: f64,
+    /// Average WPM of the player's verified stages in this language
+    pub baseline_wpm: Option<f64>,
+    pub stages: usize,
+}
+
+/// Per-language calibration for the player, from their verified stage results
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct LanguageCalibrations {
+    /// Average WPM across every language, weighted by stages
+    pub overall_wpm: Option<f64>,
+    pub languages: Vec<LanguageCalibration>,
+}
+
+impl LanguageCalibration {
+    /// Stages a language needs before the player's own pace replaces the default factor
+    pub const MIN_STAGES: usize = 5;
+
+    /// Built-in factor for languages the player has not typed enough of yet
+    pub fn default_factor(language: &str) -> f64 {
+        match language.to_lowercase().as_str() {
+            "haskell" | "clojure" | "erlang" | "scala" => 0.85,
+            "rust" | "cpp" | "elixir" | "zig" => 0.9,
+            "c" | "csharp" | "php" | "swift" | "typescript" => 0.95,
+            "go" | "python" | "ruby" => 1.05,
+            _ => 1.0,
+        }
+    }
+
+    /// Whether the factor comes from the player's own stages rather than the default
+    pub fn is_measured(&self) -> bool {
+        self.stages >= Self::MIN_STAGES
+    }
+
+    /// `wpm` as it would read in an average language for this player
+    pub fn normalize(&self, wpm: f64) -> f64 {
+        if self.factor > 0.0 {
+            wpm / self.factor
+        } else {
+            wpm
+        }
+    }
+}
+
+impl LanguageCalibrations {
+    pub fn from_activity(activity: &[LanguageActivity]) -> Self {
+        let stages: usize = activity.iter().map(|language| language.stages).sum();
+        let overall_wpm = (stages > 0).then(|| {
+            activity
+                .iter()
+                .map(|language| language.avg_wpm * language.stages as f64)
+                .sum::<f64>()
+                / stages as f64
+        });
+
+        let mut languages: Vec<LanguageCalibration> = activity
+            .iter()
+            .filter(|language| language.stages > 0)
+            .map(|language| {
+                let measured = language.stages >= LanguageCalibration::MIN_STAGES;
+                let factor = match overall_wpm {
+                    Some(overall) if measured && overall > 0.0 => language.avg_wpm / overall,
+                    _ => LanguageCalibration::default_factor(&language.language),
+                };
+                LanguageCalibration {
+                    language: language.language.clone(),
+                    factor,
+                    baseline_wpm: Some(language.avg_wpm),
+                    stages: language.stages,
+                }
+            })
+            .collect();
+        languages.sort_by(|a, b| a.language.cmp(&b.language));
+
+        Self {
+            overall_wpm,
+            languages,
+        }
+    }
+
+    /// The player's calibration for `language`, or the default factor when
+    /// they have never typed it
+    pub fn for_language(&self, language: &str) -> LanguageCalibration {
+        self.languages
+            .iter()
+            .find(|calibration| calibration.language.eq_ignore_ascii_case(language))
+            .cloned()
+            .unwrap_or_else(|| LanguageCalibration {
+                language: language.to_string(),
+                factor: LanguageCalibration::default_factor(language),
+                baseline_wpm: None,
+                stages: 0,
+            })
+    }
+
+    /// WPM the player can expect in `language`: their own average once it
+    /// is measured, otherwise their overall pace scaled by the default factor
+    pub fn expected_wpm(&self, language: &str) -> Option<f64> {
+        let calibration = self.for_language(language);
+        if calibration.is_measured() {
+            return calibration.baseline_wpm;
+        }
+        self.overall_wpm.map(|overall| overall * calibration.factor)
+    }
+}
@@ -0,0 +1,53 @@
+use crate::domain::models::ChunkType;
+
+/// What gittype can extract from one registered language, as listed by
+/// `gittype languages`
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageCapability {
+    pub name: String,
+    pub display_name: String,
+    pub aliases: Vec<String>,
+    pub extensions: Vec<String>,
+    /// Definition chunk types, in query order
+    pub chunk_types: Vec<ChunkType>,
+    /// Whether loops, conditionals and other blocks inside definitions are extracted too
+    pub has_middle_implementation: bool,
+}
+
+impl LanguageCapability {
+    /// Plain-text listing with one block per language
+    pub fn render(capabilities: &[LanguageCapability]) -> String {
+        capabilities
+            .iter()
+            .map(LanguageCapability::render_one)
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    fn render_one(&self) -> String {
+        let extensions: Vec<String> = self.extensions.iter().map(|e| format!(".{}", e)).collect();
+        let chunk_types: Vec<String> = self
+            .chunk_types
+            .iter()
+            .map(|chunk_type| format!("{:?}", chunk_type))
+            .collect();
+        let inner_blocks = if self.has_middle_implementation {
+            "yes"
+        } else {
+            "no"
+        };
+
+        let mut lines = vec![format!("{} ({})", self.display_name, self.name)];
+        lines.push(Self::row("Extensions", &extensions.join(", ")));
+        if !self.aliases.is_empty() {
+            lines.push(Self::row("Aliases", &self.aliases.join(", ")));
+        }
+        lines.push(Self::row("Chunk types", &chunk_types.join(", ")));
+        lines.push(Self::row("Inner blocks", inner_blocks));
+        lines.join("\n")
+    }
+
+    fn row(label: &str, value: &str) -> String {
+        format!("  {:<14}{}", format!("{}:", label), value)
+    }
+}
@@ -1,5 +1,4 @@
 use super::{ExecutionContext, Step, StepResult, StepType};
-use crate::presentation::tui::screens::loading_screen::ProgressReporter;
 use crate::presentation::ui::Colors;
 use crate::Result;
 use ratatui::style::Color;
@@ -56,6 +55,23 @@ impl Step for CacheCheckStep {
     }
 
     fn execute(&self, context: &mut ExecutionContext) -> Result<StepResult> {
+        // A challenge pack replaces extraction entirely, like a cache hit
+        if let Some(pack) = context.extraction_options.and_then(|o| o.pack.as_ref()) {
+            log::info!(
+                "Playing challenge pack from {} ({} challenges)",
+                pack.source,
+                pack.challenges.len()
+            );
+            if let Some(challenge_store) = &context.challenge_store {
+                challenge_store.set_challenges(pack.challenges.clone());
+            }
+            context.cache_used = true;
+            if let Some(session_store) = &context.session_store {
+                session_store.set_loading_completed(true);
+            }
+            return Ok(StepResult::Skipped);
+        }
+
         // Early return if no git repository info
         let Some(ref git_repo) = context.git_repository else {
             log::info!("No git repository info - skipping cache check");
@@ -69,6 +85,14 @@ impl Step for CacheCheckStep {
             git_repo.is_dirty
         );
 
+        if context
+            .extraction_options
+            .is_some_and(|options| options.bypasses_cache())
+        {
+            log::info!("Commit-scoped, prose or snippet extraction - skipping cache check");
+            return Ok(StepResult::Skipped);
+        }
+
         // Early return if repository is dirty
         if git_repo.is_dirty {
             log::info!(
@@ -85,10 +109,9 @@ impl Step for CacheCheckStep {
         };
 
         // Try to load from cache
-        let Ok(Some(cached_challenges)) = challenge_repository.load_challenges_with_progress(
-            git_repo,
-            context.loading_screen.map(|s| s as &dyn ProgressReporter),
-        ) else {
+        let Ok(Some(cached_challenges)) =
+            challenge_repository.load_challenges_with_progress(git_repo, context.reporter())
+        else {
             log::info!(
                 "Cache miss for {} - proceeding with full extraction",
                 git_repo.remote_url
@@ -105,8 +128,14 @@ impl Step for CacheCheckStep {
 
         let challenge_count = cached_challenges.len();
 
-        // Store challenges in ChallengeStore
+        // Store challenges in ChallengeStore, leaving out the ones flagged as bad
         if let Some(challenge_store) = &context.challenge_store {
+            let cached_challenges = match &context.challenge_flag_service {
+                Some(challenge_flag_service) => {
+                    challenge_flag_service.exclude_flagged(cached_challenges)
+                }
+                None => cached_challenges,
+            };
             challenge_store.set_challenges(cached_challenges);
         }
 
@@ -1,8 +1,7 @@
-use super::{ExecutionContext, Step, StepResult, StepType};
-use crate::infrastructure::git::{LocalGitRepositoryClient, RemoteGitRepositoryClient};
-use crate::presentation::tui::screens::loading_screen::ProgressReporter;
+use super::{ExecutionContext, ProgressReporter, Step, StepResult, StepType, SubStepReporter};
+use crate::infrastructure::git::{ClonePhase, LocalGitRepositoryClient, RemoteGitRepositoryClient};
 use crate::presentation::ui::Colors;
-use crate::Result;
+use crate::{GitTypeError, Result};
 use ratatui::style::Color;
 
 #[derive(Debug, Clone)]
@@ -54,14 +53,31 @@ impl Step for CloningStep {
             return Ok(StepResult::Skipped);
         };
 
-        let progress_callback = |current: usize, total: usize| {
-            if let Some(screen) = context.loading_screen {
-                screen.set_file_counts(StepType::Cloning, current, total, None);
+        let reporter = context.reporter();
+        let mut current_phase = None;
+        let progress_callback = |phase: ClonePhase, current: usize, total: usize| {
+            let Some(reporter) = reporter else {
+                return true;
+            };
+            let (index, label) = Self::sub_step(phase);
+            if current_phase.replace(phase) != Some(phase) {
+                reporter.set_sub_step(Some(label.to_string()));
             }
+            SubStepReporter::new(reporter, index, 2).set_file_counts(
+                StepType::Cloning,
+                current,
+                total,
+                None,
+            );
+            !reporter.is_cancelled()
         };
 
-        let repo_path =
-            RemoteGitRepositoryClient::new().clone_repository(repo_spec, progress_callback)?;
+        let repo_path = RemoteGitRepositoryClient::new()
+            .clone_repository(repo_spec, progress_callback)
+            .map_err(|e| match reporter.is_some_and(|r| r.is_cancelled()) {
+                true => GitTypeError::Cancelled,
+                false => e,
+            })?;
         context.current_repo_path = Some(repo_path.clone());
 
         // Extract git repository information after cloning
@@ -76,3 +92,13 @@ impl Step for CloningStep {
         Ok(StepResult::RepoPath(repo_path))
     }
 }
+
+impl CloningStep {
+    /// Position and label of a clone phase among the step's two sub-steps
+    fn sub_step(phase: ClonePhase) -> (usize, &'static str) {
+        match phase {
+            ClonePhase::Fetching => (0, "Fetching"),
+            ClonePhase::CheckingOut => (1, "Checking out"),
+        }
+    }
+}
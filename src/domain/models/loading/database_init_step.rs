@@ -33,28 +33,38 @@ impl Step for DatabaseInitStep {
     }
 
     fn supports_progress(&self) -> bool {
-        false
+        true
     }
     fn progress_unit(&self) -> &str {
-        ""
+        "migrations"
     }
 
     fn format_progress(
         &self,
-        _processed: usize,
-        _total: usize,
-        _progress: f64,
-        _spinner: char,
+        processed: usize,
+        total: usize,
+        progress: f64,
+        spinner: char,
     ) -> String {
-        "Initializing database...".to_string()
+        format!(
+            "{} {:.1}% {}/{} migrations",
+            spinner,
+            progress * 100.0,
+            processed,
+            total
+        )
     }
 
-    fn execute(&self, _context: &mut ExecutionContext) -> Result<StepResult> {
+    fn execute(&self, context: &mut ExecutionContext) -> Result<StepResult> {
         log::info!("DatabaseInitStep: Starting database initialization");
 
-        // Initialize database with migrations
+        // Initialize database with migrations, reporting each one on the loading screen
         let database = Database::new()?;
-        database.init()?;
+        database.init_with_progress(|applied, pending| {
+            if let Some(reporter) = context.reporter() {
+                reporter.set_file_counts(StepType::DatabaseInit, applied, pending, None);
+            }
+        })?;
         log::info!("DatabaseInitStep: Database initialized successfully");
 
         // Initialize global session repository
@@ -1,10 +1,9 @@
 use super::{ExecutionContext, Step, StepResult, StepType};
-use crate::domain::models::{Language, Languages};
-use crate::domain::services::source_code_parser::SourceCodeParser;
+use crate::domain::models::Languages;
+use crate::domain::services::source_code_parser::{SnippetExtractor, SourceCodeParser};
 use crate::presentation::ui::Colors;
 use crate::{GitTypeError, Result};
 use ratatui::style::Color;
-use std::path::PathBuf;
 
 #[derive(Debug, Clone)]
 pub struct ExtractingStep;
@@ -61,7 +60,7 @@ impl Step for ExtractingStep {
             GitTypeError::ExtractionFailed("No extraction options available".to_string())
         })?;
 
-        let screen = context.loading_screen.ok_or_else(|| {
+        let screen = context.reporter().ok_or_else(|| {
             GitTypeError::ExtractionFailed("No loading screen available".to_string())
         })?;
 
@@ -71,17 +70,23 @@ impl Step for ExtractingStep {
             )
         })?;
 
+        if options.snippets {
+            let snippet_dir = context
+                .current_repo_path
+                .as_ref()
+                .or(context.repo_path)
+                .ok_or_else(|| {
+                    GitTypeError::ExtractionFailed("No snippet directory available".to_string())
+                })?;
+            let chunks = SnippetExtractor::extract(snippet_dir, scanned_files);
+            if chunks.is_empty() {
+                return Err(GitTypeError::NoSupportedFiles);
+            }
+            return Ok(StepResult::Chunks(chunks));
+        }
+
         let mut extractor = SourceCodeParser::new()?;
-        let files_to_process: Vec<(PathBuf, Box<dyn Language>)> = scanned_files
-            .iter()
-            .filter_map(|path| {
-                if let Some(extension) = path.extension().and_then(|e| e.to_str()) {
-                    Languages::from_extension(extension).map(|language| (path.to_owned(), language))
-                } else {
-                    None
-                }
-            })
-            .collect();
+        let files_to_process = Languages::for_project_files(scanned_files);
 
         let chunks = extractor.extract_chunks_with_progress(files_to_process, options, screen)?;
 
@@ -102,14 +102,30 @@ impl Step for FinalizingStep {
                 // Reset session to clean state
                 concrete_session_manager.reset();
 
-                // Set session configuration
-                let session_config = SessionConfig {
-                    max_stages: 3,
-                    session_timeout: None,
-                    difficulty: DifficultyLevel::Normal,
-                    max_skips: 3,
+                // Commit practice (e.g. from the post-commit hook) is a single-stage session
+                let commit_scoped = context
+                    .extraction_options
+                    .is_some_and(|options| options.is_commit_scoped());
+                let pack = context.extraction_options.and_then(|o| o.pack.as_ref());
+
+                // Set session configuration; a pack plays every challenge once, without skips
+                let session_config = match pack {
+                    Some(pack) => SessionConfig {
+                        max_stages: pack.challenges.len(),
+                        session_timeout: None,
+                        difficulty: pack.difficulty,
+                        max_skips: 0,
+                    },
+                    None => SessionConfig {
+                        max_stages: if commit_scoped { 1 } else { 3 },
+                        session_timeout: None,
+                        difficulty: DifficultyLevel::Normal,
+                        max_skips: 3,
+                    },
                 };
                 concrete_session_manager.set_config(session_config);
+                concrete_session_manager
+                    .set_fixed_challenges(pack.map(|pack| pack.challenges.clone()));
 
                 // Set git repository context
                 concrete_session_manager.set_git_repository(git_repository);
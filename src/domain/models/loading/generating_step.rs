@@ -59,15 +59,29 @@ impl Step for GeneratingStep {
             GitTypeError::ExtractionFailed("No chunks available from ExtractingStep".to_string())
         })?;
 
-        let screen = context.loading_screen.ok_or_else(|| {
+        let screen = context.reporter().ok_or_else(|| {
             GitTypeError::ExtractionFailed("No loading screen available".to_string())
         })?;
 
         let converter = ChallengeGenerator::new();
-        let generated_challenges = converter.convert_with_progress(chunks, screen);
+        let generated_challenges = if context
+            .extraction_options
+            .is_some_and(|options| options.snippets)
+        {
+            converter.convert_snippets(chunks)
+        } else {
+            converter.convert_with_progress(chunks, screen)
+        };
+        // A partial set of challenges must not be cached
+        screen.check_cancelled()?;
+
+        let bypasses_cache = context
+            .extraction_options
+            .is_some_and(|options| options.bypasses_cache());
 
         // Cache the generated challenges if we have git repository info
-        if let Some(ref git_repo) = context.git_repository {
+        // (commit-scoped, prose and snippet challenges differ from a plain extraction, so they are never cached)
+        if let Some(git_repo) = context.git_repository.as_ref().filter(|_| !bypasses_cache) {
             if let Some(ref challenge_repository) = context.challenge_repository {
                 match challenge_repository.save_challenges(git_repo, &generated_challenges, None) {
                     Ok(_) => {
@@ -86,8 +100,14 @@ impl Step for GeneratingStep {
             }
         }
 
-        // Store challenges in ChallengeStore
+        // Store challenges in ChallengeStore, leaving out the ones flagged as bad
         if let Some(challenge_store) = &context.challenge_store {
+            let generated_challenges = match &context.challenge_flag_service {
+                Some(challenge_flag_service) => {
+                    challenge_flag_service.exclude_flagged(generated_challenges)
+                }
+                None => generated_challenges,
+            };
             challenge_store.set_challenges(generated_challenges);
         }
 
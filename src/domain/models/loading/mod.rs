@@ -1,6 +1,7 @@
 use crate::domain::models::ExtractionOptions;
 use crate::domain::models::{Challenge, CodeChunk, GitRepository};
 use crate::domain::repositories::challenge_repository::ChallengeRepositoryInterface;
+use crate::domain::services::challenge_flag_service::ChallengeFlagServiceInterface;
 use crate::domain::stores::{
     ChallengeStoreInterface, RepositoryStoreInterface, SessionStoreInterface,
 };
@@ -16,6 +17,7 @@ pub mod database_init_step;
 pub mod extracting_step;
 pub mod finalizing_step;
 pub mod generating_step;
+pub mod progress_reporter;
 pub mod scanning_step;
 pub mod step_manager;
 
@@ -26,6 +28,9 @@ pub use database_init_step::DatabaseInitStep;
 pub use extracting_step::ExtractingStep;
 pub use finalizing_step::FinalizingStep;
 pub use generating_step::GeneratingStep;
+pub use progress_reporter::{
+    CancellationToken, NoOpProgressReporter, ProgressReporter, SubStepReporter,
+};
 pub use scanning_step::ScanningStep;
 pub use step_manager::StepManager;
 
@@ -47,7 +52,10 @@ pub struct ExecutionContext<'a> {
     pub repo_path: Option<&'a PathBuf>,
     pub extraction_options: Option<&'a ExtractionOptions>,
     pub loading_screen: Option<&'a LoadingScreen>,
+    /// Receives progress when no loading screen is shown, e.g. while warming headlessly
+    pub progress_reporter: Option<&'a dyn ProgressReporter>,
     pub challenge_repository: Option<Arc<dyn ChallengeRepositoryInterface>>,
+    pub challenge_flag_service: Option<Arc<dyn ChallengeFlagServiceInterface>>,
     pub current_repo_path: Option<PathBuf>,
     pub git_repository: Option<GitRepository>,
     pub scanned_files: Option<Vec<PathBuf>>, // Temporary storage for step results
@@ -62,6 +70,15 @@ pub struct ExecutionContext<'a> {
         Option<Arc<dyn crate::domain::services::session_manager_service::SessionManagerInterface>>,
 }
 
+impl<'a> ExecutionContext<'a> {
+    /// Where steps report progress: the loading screen, else the headless reporter
+    pub fn reporter(&self) -> Option<&'a dyn ProgressReporter> {
+        self.loading_screen
+            .map(|screen| screen as &dyn ProgressReporter)
+            .or(self.progress_reporter)
+    }
+}
+
 #[derive(Debug)]
 pub enum StepResult {
     RepoPath(PathBuf),
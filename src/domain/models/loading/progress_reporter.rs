@@ -0,0 +1,126 @@
+use crate::domain::models::loading::StepType;
+use crate::{GitTypeError, Result};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// Receives the progress of the loading steps, whether a screen, a table row or nothing shows it
+pub trait ProgressReporter: Sync {
+    fn set_step(&self, step_type: StepType);
+    fn set_current_file(&self, file: Option<String>);
+    fn set_file_counts(
+        &self,
+        step_type: StepType,
+        processed: usize,
+        total: usize,
+        current_file: Option<String>,
+    );
+    /// Names the phase of the current step that is running; `None` clears it
+    fn set_sub_step(&self, _label: Option<String>) {}
+    fn finish(&self) -> Result<()> {
+        Ok(())
+    }
+    /// Whether whoever shows the progress asked the work to stop
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+    fn check_cancelled(&self) -> Result<()> {
+        match self.is_cancelled() {
+            true => Err(GitTypeError::Cancelled),
+            false => Ok(()),
+        }
+    }
+}
+
+pub struct NoOpProgressReporter;
+
+impl ProgressReporter for NoOpProgressReporter {
+    fn set_step(&self, _step_type: StepType) {}
+    fn set_current_file(&self, _file: Option<String>) {}
+    fn set_file_counts(
+        &self,
+        _step_type: StepType,
+        _processed: usize,
+        _total: usize,
+        _current_file: Option<String>,
+    ) {
+    }
+}
+
+/// Shared flag that asks running loading work to stop; clones observe the same flag
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Phase `index` of `count` equal phases of a step, e.g. fetching then checking
+/// out while cloning; each phase counts from zero while the parent's progress
+/// keeps moving forward. Sub-steps can be nested; name them with `set_sub_step`.
+pub struct SubStepReporter<'a> {
+    parent: &'a dyn ProgressReporter,
+    index: usize,
+    count: usize,
+}
+
+impl<'a> SubStepReporter<'a> {
+    pub fn new(parent: &'a dyn ProgressReporter, index: usize, count: usize) -> Self {
+        Self {
+            parent,
+            index: index.min(count.saturating_sub(1)),
+            count: count.max(1),
+        }
+    }
+}
+
+impl ProgressReporter for SubStepReporter<'_> {
+    fn set_step(&self, step_type: StepType) {
+        self.parent.set_step(step_type);
+    }
+
+    fn set_current_file(&self, file: Option<String>) {
+        self.parent.set_current_file(file);
+    }
+
+    fn set_file_counts(
+        &self,
+        step_type: StepType,
+        processed: usize,
+        total: usize,
+        current_file: Option<String>,
+    ) {
+        match total {
+            0 => self
+                .parent
+                .set_file_counts(step_type, processed, total, current_file),
+            _ => self.parent.set_file_counts(
+                step_type,
+                self.index * total + processed.min(total),
+                self.count * total,
+                current_file,
+            ),
+        }
+    }
+
+    fn set_sub_step(&self, label: Option<String>) {
+        self.parent.set_sub_step(label);
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.parent.is_cancelled()
+    }
+}
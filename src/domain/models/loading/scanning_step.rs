@@ -74,7 +74,7 @@ impl Step for ScanningStep {
             }
         }
 
-        let screen = context.loading_screen.ok_or_else(|| {
+        let screen = context.reporter().ok_or_else(|| {
             GitTypeError::ExtractionFailed("No loading screen available".to_string())
         })?;
 
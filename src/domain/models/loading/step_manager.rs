@@ -3,7 +3,6 @@ use super::{
     FinalizingStep, GeneratingStep, ScanningStep, Step, StepResult,
 };
 use crate::domain::models::loading::StepType;
-use crate::presentation::tui::screens::loading_screen::ProgressReporter;
 use crate::Result;
 
 pub struct StepManager {
@@ -31,6 +30,19 @@ impl StepManager {
         }
     }
 
+    /// Clone, cache check and extraction only, for caching challenges without starting a game
+    pub fn headless() -> Self {
+        Self {
+            steps: vec![
+                Box::new(CloningStep),
+                Box::new(CacheCheckStep),
+                Box::new(ScanningStep),
+                Box::new(ExtractingStep),
+                Box::new(GeneratingStep),
+            ],
+        }
+    }
+
     pub fn get_step_by_name(&self, step_name: &str) -> Option<&dyn Step> {
         self.steps
             .iter()
@@ -72,13 +84,14 @@ impl StepManager {
             }
 
             // Set current step for progress reporting
-            if let Some(screen) = context.loading_screen {
-                screen.set_step(step.step_type());
+            if let Some(reporter) = context.reporter() {
+                reporter.check_cancelled()?;
+                reporter.set_step(step.step_type());
 
                 // Initialize progress for steps that support it
                 if step.supports_progress() {
                     // Initialize with 0% progress
-                    screen.set_file_counts(step.step_type(), 0, 1, None);
+                    reporter.set_file_counts(step.step_type(), 0, 1, None);
                 }
             }
 
@@ -86,10 +99,14 @@ impl StepManager {
             let step_result = step.execute(context)?;
 
             // Mark step as completed after successful execution
-            if let Some(screen) = context.loading_screen {
+            if let Some(reporter) = context.reporter() {
+                if step.supports_progress() {
+                    reporter.set_file_counts(step.step_type(), 1, 1, None);
+                }
+            }
+            if context.loading_screen.is_some() {
                 // For steps that support progress, ensure they show 100% completion
                 if step.supports_progress() {
-                    screen.set_file_counts(step.step_type(), 1, 1, None);
                     // Small delay to ensure the completion is visible before transitioning
                     std::thread::sleep(std::time::Duration::from_millis(200));
                 } else {
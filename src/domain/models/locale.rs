@@ -0,0 +1,248 @@
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+
+use std::sync::RwLock;
+
+static CURRENT: RwLock<Locale> = RwLock::new(Locale::ISO);
+
+/// Where the number, date and time conventions shown to the player come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LocalePreset {
+    /// Detected from `LC_ALL`, `LC_TIME` or `LANG`
+    #[default]
+    System,
+    EnUs,
+    EnGb,
+    De,
+    Fr,
+    Ja,
+    Iso,
+}
+
+impl LocalePreset {
+    pub fn all() -> [LocalePreset; 7] {
+        [
+            LocalePreset::System,
+            LocalePreset::EnUs,
+            LocalePreset::EnGb,
+            LocalePreset::De,
+            LocalePreset::Fr,
+            LocalePreset::Ja,
+            LocalePreset::Iso,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LocalePreset::System => "System",
+            LocalePreset::EnUs => "English (US)",
+            LocalePreset::EnGb => "English (UK)",
+            LocalePreset::De => "German",
+            LocalePreset::Fr => "French",
+            LocalePreset::Ja => "Japanese",
+            LocalePreset::Iso => "ISO 8601",
+        }
+    }
+
+    pub fn resolve(&self) -> Locale {
+        match self {
+            LocalePreset::System => Locale::from_env(),
+            LocalePreset::EnUs => Locale::EN_US,
+            LocalePreset::EnGb => Locale::EN_GB,
+            LocalePreset::De => Locale::DE,
+            LocalePreset::Fr => Locale::FR,
+            LocalePreset::Ja => Locale::JA,
+            LocalePreset::Iso => Locale::ISO,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    DayMonthYear,
+    MonthDayYear,
+    YearMonthDay,
+}
+
+/// Conventions for formatting numbers, dates and times
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    /// ISO 639-1 code of the language for translated text, e.g. `de`
+    pub language: &'static str,
+    pub thousands_separator: Option<char>,
+    pub decimal_separator: char,
+    pub date_order: DateOrder,
+    pub date_separator: char,
+    pub twenty_four_hour: bool,
+}
+
+impl Locale {
+    pub const EN_US: Locale = Locale {
+        language: "en",
+        thousands_separator: Some(','),
+        decimal_separator: '.',
+        date_order: DateOrder::MonthDayYear,
+        date_separator: '/',
+        twenty_four_hour: false,
+    };
+    pub const EN_GB: Locale = Locale {
+        language: "en",
+        thousands_separator: Some(','),
+        decimal_separator: '.',
+        date_order: DateOrder::DayMonthYear,
+        date_separator: '/',
+        twenty_four_hour: true,
+    };
+    pub const DE: Locale = Locale {
+        language: "de",
+        thousands_separator: Some('.'),
+        decimal_separator: ',',
+        date_order: DateOrder::DayMonthYear,
+        date_separator: '.',
+        twenty_four_hour: true,
+    };
+    pub const FR: Locale = Locale {
+        language: "fr",
+        thousands_separator: Some(' '),
+        decimal_separator: ',',
+        date_order: DateOrder::DayMonthYear,
+        date_separator: '/',
+        twenty_four_hour: true,
+    };
+    pub const JA: Locale = Locale {
+        language: "ja",
+        thousands_separator: Some(','),
+        decimal_separator: '.',
+        date_order: DateOrder::YearMonthDay,
+        date_separator: '/',
+        twenty_four_hour: true,
+    };
+    pub const ISO: Locale = Locale {
+        language: "en",
+        thousands_separator: None,
+        decimal_separator: '.',
+        date_order: DateOrder::YearMonthDay,
+        date_separator: '-',
+        twenty_four_hour: true,
+    };
+
+    /// Conventions used for everything shown to the player; ISO until set from config
+    pub fn current() -> Locale {
+        *CURRENT.read().unwrap()
+    }
+
+    pub fn set_current(locale: Locale) {
+        *CURRENT.write().unwrap() = locale;
+    }
+
+    pub fn from_env() -> Locale {
+        ["LC_ALL", "LC_TIME", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .map_or(Locale::ISO, |tag| Locale::from_tag(&tag))
+    }
+
+    /// Conventions for a POSIX locale name such as `de_DE.UTF-8`; unknown names get ISO
+    pub fn from_tag(tag: &str) -> Locale {
+        let tag = tag
+            .split(['.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase()
+            .replace('-', "_");
+        let (language, region) = tag.split_once('_').unwrap_or((tag.as_str(), ""));
+
+        match language {
+            "en" if matches!(region, "gb" | "ie" | "au" | "nz" | "in" | "za") => Locale::EN_GB,
+            "en" => Locale::EN_US,
+            "de" => Locale::DE,
+            "fr" => Locale::FR,
+            "ja" => Locale::JA,
+            // Dates as in Japanese, text in English until translated
+            "zh" | "ko" => Locale {
+                language: "en",
+                ..Locale::JA
+            },
+            _ => Locale::ISO,
+        }
+    }
+
+    pub fn format_count(&self, value: usize) -> String {
+        self.group_digits(&value.to_string())
+    }
+
+    /// `value` rounded to `decimals` places, with the integer part grouped
+    pub fn format_decimal(&self, value: f64, decimals: usize) -> String {
+        let formatted = format!("{:.*}", decimals, value);
+        let (sign, unsigned) = formatted
+            .strip_prefix('-')
+            .map_or(("", formatted.as_str()), |rest| ("-", rest));
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        let mut result = format!("{}{}", sign, self.group_digits(integer));
+        if !fraction.is_empty() {
+            result.push(self.decimal_separator);
+            result.push_str(fraction);
+        }
+        result
+    }
+
+    pub fn format_date(&self, date: NaiveDate) -> String {
+        let (year, month, day) = (
+            format!("{:04}", date.year()),
+            format!("{:02}", date.month()),
+            format!("{:02}", date.day()),
+        );
+        let parts = match self.date_order {
+            DateOrder::DayMonthYear => [day, month, year],
+            DateOrder::MonthDayYear => [month, day, year],
+            DateOrder::YearMonthDay => [year, month, day],
+        };
+        parts.join(&self.date_separator.to_string())
+    }
+
+    pub fn format_time(&self, time: NaiveTime, with_seconds: bool) -> String {
+        let seconds = if with_seconds {
+            format!(":{:02}", time.second())
+        } else {
+            String::new()
+        };
+        if self.twenty_four_hour {
+            format!("{:02}:{:02}{}", time.hour(), time.minute(), seconds)
+        } else {
+            let (is_pm, hour) = time.hour12();
+            let suffix = if is_pm { "PM" } else { "AM" };
+            format!("{}:{:02}{} {}", hour, time.minute(), seconds, suffix)
+        }
+    }
+
+    pub fn format_datetime(&self, datetime: NaiveDateTime, with_seconds: bool) -> String {
+        format!(
+            "{} {}",
+            self.format_date(datetime.date()),
+            self.format_time(datetime.time(), with_seconds)
+        )
+    }
+
+    fn group_digits(&self, digits: &str) -> String {
+        let Some(separator) = self.thousands_separator else {
+            return digits.to_string();
+        };
+        digits
+            .chars()
+            .rev()
+            .enumerate()
+            .fold(String::new(), |mut grouped, (index, digit)| {
+                if index > 0 && index % 3 == 0 {
+                    grouped.push(separator);
+                }
+                grouped.push(digit);
+                grouped
+            })
+            .chars()
+            .rev()
+            .collect()
+    }
+}
@@ -0,0 +1,82 @@
+/// Severity of a log line, most severe first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub fn parse(level: &str) -> Option<Self> {
+        match level {
+            "ERROR" => Some(LogLevel::Error),
+            "WARN" => Some(LogLevel::Warn),
+            "INFO" => Some(LogLevel::Info),
+            "DEBUG" => Some(LogLevel::Debug),
+            "TRACE" => Some(LogLevel::Trace),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Info => "INFO",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Trace => "TRACE",
+        }
+    }
+}
+
+/// One line of a gittype log file, written as `{timestamp} [{level}] {target} - {message}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    pub timestamp: Option<String>,
+    pub level: Option<LogLevel>,
+    pub target: Option<String>,
+    pub message: String,
+}
+
+impl LogEntry {
+    /// Lines that do not follow the log format, such as the continuation of a
+    /// multi-line message, are kept whole as the message
+    pub fn parse(line: &str) -> Self {
+        Self::parse_formatted(line).unwrap_or_else(|| LogEntry {
+            timestamp: None,
+            level: None,
+            target: None,
+            message: line.to_string(),
+        })
+    }
+
+    /// Entries at least as severe as `min_level`. Unformatted
+    /// lines inherit the level of the entry they continue.
+    pub fn filter_by_level(entries: &[LogEntry], min_level: LogLevel) -> Vec<LogEntry> {
+        entries
+            .iter()
+            .scan(None, |level, entry| {
+                *level = entry.level.or(*level);
+                Some((*level, entry))
+            })
+            .filter(|(level, _)| level.is_some_and(|level| level <= min_level))
+            .map(|(_, entry)| entry.clone())
+            .collect()
+    }
+
+    fn parse_formatted(line: &str) -> Option<Self> {
+        let (timestamp, rest) = line.split_once(" [")?;
+        let (level, rest) = rest.split_once("] ")?;
+        let level = LogLevel::parse(level)?;
+        let (target, message) = rest.split_once(" - ").unwrap_or(("", rest));
+
+        Some(LogEntry {
+            timestamp: Some(timestamp.to_string()),
+            level: Some(level),
+            target: (!target.is_empty()).then(|| target.to_string()),
+            message: message.to_string(),
+        })
+    }
+}
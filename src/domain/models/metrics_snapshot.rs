@@ -0,0 +1,140 @@
+use chrono::NaiveDate;
+
+use std::fmt::Write;
+
+use crate::domain::models::storage::{DailyActivity, LanguageActivity};
+use crate::domain::models::StatusSummary;
+
+/// All-time practice counters and per-language gauges for personal dashboards
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsSnapshot {
+    pub sessions: usize,
+    pub keystrokes: usize,
+    pub practice_days: usize,
+    pub streak: usize,
+    pub languages: Vec<LanguageActivity>,
+}
+
+impl MetricsSnapshot {
+    /// `activity` must be ordered most recent day first
+    pub fn new(
+        activity: &[DailyActivity],
+        languages: Vec<LanguageActivity>,
+        today: NaiveDate,
+    ) -> Self {
+        Self {
+            sessions: activity.iter().map(|day| day.sessions).sum(),
+            keystrokes: activity.iter().map(|day| day.keystrokes).sum(),
+            practice_days: activity.len(),
+            streak: StatusSummary::from_daily_activity(activity, today).streak,
+            languages,
+        }
+    }
+
+    /// Prometheus text exposition format, readable by node_exporter's textfile collector
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        Self::family(
+            &mut out,
+            "gittype_sessions_total",
+            "Typing sessions played.",
+            "counter",
+            [(None, self.sessions.to_string())],
+        );
+        Self::family(
+            &mut out,
+            "gittype_keystrokes_total",
+            "Keystrokes typed across all sessions.",
+            "counter",
+            [(None, self.keystrokes.to_string())],
+        );
+        Self::family(
+            &mut out,
+            "gittype_practice_days_total",
+            "Days with at least one session.",
+            "counter",
+            [(None, self.practice_days.to_string())],
+        );
+        Self::family(
+            &mut out,
+            "gittype_streak_days",
+            "Consecutive practice days up to today.",
+            "gauge",
+            [(None, self.streak.to_string())],
+        );
+
+        let per_language = |value: fn(&LanguageActivity) -> String| {
+            self.languages
+                .iter()
+                .map(move |language| (Some(language.language.as_str()), value(language)))
+        };
+        Self::family(
+            &mut out,
+            "gittype_language_wpm",
+            "Average WPM of completed stages per language.",
+            "gauge",
+            per_language(|l| format!("{:.2}", l.avg_wpm)),
+        );
+        Self::family(
+            &mut out,
+            "gittype_language_best_wpm",
+            "Best stage WPM per language.",
+            "gauge",
+            per_language(|l| format!("{:.2}", l.best_wpm)),
+        );
+        Self::family(
+            &mut out,
+            "gittype_language_accuracy",
+            "Average accuracy percentage of completed stages per language.",
+            "gauge",
+            per_language(|l| format!("{:.2}", l.avg_accuracy)),
+        );
+        Self::family(
+            &mut out,
+            "gittype_language_stages_total",
+            "Completed stages per language.",
+            "counter",
+            per_language(|l| l.stages.to_string()),
+        );
+        Self::family(
+            &mut out,
+            "gittype_language_keystrokes_total",
+            "Keystrokes typed in completed stages per language.",
+            "counter",
+            per_language(|l| l.keystrokes.to_string()),
+        );
+
+        out
+    }
+
+    fn family<'a>(
+        out: &mut String,
+        name: &str,
+        help: &str,
+        kind: &str,
+        samples: impl IntoIterator<Item = (Option<&'a str>, String)>,
+    ) {
+        let _ = writeln!(out, "# HELP {} {}", name, help);
+        let _ = writeln!(out, "# TYPE {} {}", name, kind);
+        for (language, value) in samples {
+            let _ = match language {
+                Some(language) => writeln!(
+                    out,
+                    "{}{{language=\"{}\"}} {}",
+                    name,
+                    Self::escape_label(language),
+                    value
+                ),
+                None => writeln!(out, "{} {}", name, value),
+            };
+        }
+    }
+
+    fn escape_label(value: &str) -> String {
+        value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+    }
+}
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::KeyboardLayout;
+
+/// What kind of slip a mistyped character was
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MistakeKind {
+    /// A letter typed in the wrong case
+    Case,
+    /// The right key with Shift pressed or released by mistake, e.g. `;` for `:`
+    ShiftedSymbol,
+    /// The next character typed before the expected one
+    Transposition,
+    /// A key next to the expected one
+    AdjacentKey,
+    Other,
+}
+
+impl MistakeKind {
+    pub fn all() -> [MistakeKind; 5] {
+        [
+            MistakeKind::AdjacentKey,
+            MistakeKind::Case,
+            MistakeKind::ShiftedSymbol,
+            MistakeKind::Transposition,
+            MistakeKind::Other,
+        ]
+    }
+
+    /// `next` is the character after `expected` in the target text, if any
+    pub fn classify(expected: char, typed: char, next: Option<char>) -> Self {
+        let layout = KeyboardLayout::default();
+        let expected_key = layout.key_position(expected);
+        let typed_key = layout.key_position(typed);
+
+        if expected.is_alphabetic()
+            && typed.is_alphabetic()
+            && expected.to_lowercase().eq(typed.to_lowercase())
+        {
+            MistakeKind::Case
+        } else if expected_key.is_some() && expected_key == typed_key {
+            MistakeKind::ShiftedSymbol
+        } else if next == Some(typed) {
+            MistakeKind::Transposition
+        } else if let (Some(expected_key), Some(typed_key)) = (expected_key, typed_key) {
+            if layout.are_adjacent(expected_key, typed_key) {
+                MistakeKind::AdjacentKey
+            } else {
+                MistakeKind::Other
+            }
+        } else {
+            MistakeKind::Other
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MistakeKind::AdjacentKey => "Adjacent key",
+            MistakeKind::Case => "Wrong case",
+            MistakeKind::ShiftedSymbol => "Shifted symbol",
+            MistakeKind::Transposition => "Transposition",
+            MistakeKind::Other => "Other",
+        }
+    }
+
+    /// What to practice when this kind of mistake is the most common
+    pub fn advice(&self) -> &'static str {
+        match self {
+            MistakeKind::AdjacentKey => "Slow down a little and aim for the centre of each key",
+            MistakeKind::Case => "Press Shift before the letter and release it right after",
+            MistakeKind::ShiftedSymbol => "Check whether a symbol needs Shift before you reach",
+            MistakeKind::Transposition => "Let each key finish before starting the next one",
+            MistakeKind::Other => "Read a few characters ahead instead of typing from memory",
+        }
+    }
+}
+
+/// Mistakes of a stage or session counted by kind
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MistakeBreakdown {
+    pub adjacent_key: usize,
+    pub case: usize,
+    pub shifted_symbol: usize,
+    pub transposition: usize,
+    pub other: usize,
+}
+
+impl MistakeBreakdown {
+    pub fn record(&mut self, kind: MistakeKind) {
+        *self.count_mut(kind) += 1;
+    }
+
+    pub fn count(&self, kind: MistakeKind) -> usize {
+        match kind {
+            MistakeKind::AdjacentKey => self.adjacent_key,
+            MistakeKind::Case => self.case,
+            MistakeKind::ShiftedSymbol => self.shifted_symbol,
+            MistakeKind::Transposition => self.transposition,
+            MistakeKind::Other => self.other,
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        MistakeKind::all()
+            .iter()
+            .map(|kind| self.count(*kind))
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total() == 0
+    }
+
+    /// Counts of both breakdowns added together
+    pub fn merge(&self, other: &Self) -> Self {
+        MistakeKind::all().iter().fold(*self, |mut merged, kind| {
+            *merged.count_mut(*kind) += other.count(*kind);
+            merged
+        })
+    }
+
+    /// The most frequent kind other than `Other`, the first listed on ties
+    pub fn most_common(&self) -> Option<MistakeKind> {
+        MistakeKind::all()
+            .into_iter()
+            .filter(|kind| *kind != MistakeKind::Other && self.count(*kind) > 0)
+            .rev()
+            .max_by_key(|kind| self.count(*kind))
+    }
+
+    fn count_mut(&mut self, kind: MistakeKind) -> &mut usize {
+        match kind {
+            MistakeKind::AdjacentKey => &mut self.adjacent_key,
+            MistakeKind::Case => &mut self.case,
+            MistakeKind::ShiftedSymbol => &mut self.shifted_symbol,
+            MistakeKind::Transposition => &mut self.transposition,
+            MistakeKind::Other => &mut self.other,
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+const MAX_COSTLY_TOKENS: usize = 5;
+
+/// One character of the challenge text and what was typed there by mistake
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffChar {
+    pub expected: char,
+    /// First wrong character typed at this position, if any
+    pub mistyped: Option<char>,
+}
+
+/// A line of the challenge text that had at least one mistake
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffLine {
+    pub line_number: usize,
+    pub chars: Vec<DiffChar>,
+}
+
+/// Challenge text annotated with the positions the player mistyped
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MistakeDiff {
+    pub lines: Vec<DiffLine>,
+    /// Tokens containing mistakes with their mistake counts, most costly first
+    pub costly_tokens: Vec<(String, usize)>,
+    pub total_mistakes: usize,
+}
+
+impl MistakeDiff {
+    /// `mistakes` are `(position, typed character)` pairs in typing order
+    pub fn new(target_text: &str, mistakes: &[(usize, char)]) -> Self {
+        let chars: Vec<char> = target_text.chars().collect();
+        let mistakes: Vec<(usize, char)> = mistakes
+            .iter()
+            .copied()
+            .filter(|&(position, _)| position < chars.len())
+            .collect();
+
+        let mut first_mistyped: HashMap<usize, char> = HashMap::new();
+        mistakes.iter().for_each(|&(position, typed)| {
+            first_mistyped.entry(position).or_insert(typed);
+        });
+
+        Self {
+            lines: Self::diff_lines(&chars, &first_mistyped),
+            costly_tokens: Self::costly_tokens(&chars, &mistakes),
+            total_mistakes: mistakes.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total_mistakes == 0
+    }
+
+    fn diff_lines(chars: &[char], first_mistyped: &HashMap<usize, char>) -> Vec<DiffLine> {
+        let mut lines = Vec::new();
+        let mut line_start = 0;
+
+        for (index, line) in chars.split_inclusive(|&c| c == '\n').enumerate() {
+            let diff_chars: Vec<DiffChar> = line
+                .iter()
+                .enumerate()
+                .map(|(offset, &expected)| DiffChar {
+                    expected,
+                    mistyped: first_mistyped.get(&(line_start + offset)).copied(),
+                })
+                .collect();
+            if diff_chars.iter().any(|c| c.mistyped.is_some()) {
+                lines.push(DiffLine {
+                    line_number: index + 1,
+                    chars: diff_chars,
+                });
+            }
+            line_start += line.len();
+        }
+
+        lines
+    }
+
+    fn costly_tokens(chars: &[char], mistakes: &[(usize, char)]) -> Vec<(String, usize)> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        mistakes
+            .iter()
+            .filter_map(|&(position, _)| Self::token_at(chars, position))
+            .for_each(|token| *counts.entry(token).or_default() += 1);
+
+        let mut tokens: Vec<(String, usize)> = counts.into_iter().collect();
+        tokens.sort_by(|(a_token, a_count), (b_token, b_count)| {
+            b_count.cmp(a_count).then_with(|| a_token.cmp(b_token))
+        });
+        tokens.truncate(MAX_COSTLY_TOKENS);
+        tokens
+    }
+
+    /// The identifier around `position`, or the single symbol at it
+    fn token_at(chars: &[char], position: usize) -> Option<String> {
+        let is_word = |c: char| c.is_alphanumeric() || c == '_';
+        let c = chars[position];
+        if c.is_whitespace() {
+            return None;
+        }
+        if !is_word(c) {
+            return Some(c.to_string());
+        }
+
+        let start = chars[..position]
+            .iter()
+            .rposition(|&c| !is_word(c))
+            .map_or(0, |index| index + 1);
+        let end = chars[position..]
+            .iter()
+            .position(|&c| !is_word(c))
+            .map_or(chars.len(), |offset| position + offset);
+        Some(chars[start..end].iter().collect())
+    }
+}
@@ -1,37 +1,125 @@
+pub mod achievement;
 pub mod challenge;
+pub mod challenge_pack;
+pub mod challenge_preview;
+pub mod changelog;
 pub mod chunk;
 pub mod color_mode;
 pub mod color_scheme;
+pub mod commentary_style;
+pub mod completion_feedback;
 pub mod config;
 pub mod countdown;
+pub mod demo;
+pub mod difficulty_gate;
 pub mod difficulty_level;
+pub mod drill;
+pub mod editor_config;
+pub mod error_report;
 pub mod extraction_options;
+pub mod failure_analysis;
 pub mod git_repository;
 pub mod git_repository_ref;
+pub mod history_file;
+pub mod input_latency;
+pub mod keyboard_layout;
+pub mod keymap;
+pub mod keystroke_load;
 pub mod language;
+pub mod language_calibration;
+pub mod language_capability;
 pub mod languages;
 pub mod loading;
+pub mod locale;
+pub mod log_entry;
+pub mod metrics_snapshot;
+pub mod mistake_breakdown;
+pub mod mistake_diff;
+pub mod player_profile;
+pub mod practice_calendar;
+pub mod presence;
+pub mod race_bot;
 pub mod rank;
+pub mod read_only;
+pub mod reminder;
+pub mod repository_detail;
+pub mod repository_report;
+pub mod result_plausibility;
+pub mod result_verification;
+pub mod scan_report;
 pub mod session;
+pub mod source_study;
 pub mod stage;
+pub mod stage_screenshot;
+pub mod status_summary;
 pub mod storage;
+pub mod streamer;
+pub mod telemetry;
 pub mod theme;
 pub mod total;
 pub mod typing;
+pub mod typing_baseline;
 pub mod ui;
 pub mod version;
+pub mod warm_progress;
+pub mod workspace_package;
+pub mod wpm_mode;
 
 // Re-export main types for easy access
+pub use achievement::{Achievement, AchievementProgress, AchievementStatus};
 pub use challenge::Challenge;
+pub use challenge_pack::ChallengePack;
+pub use challenge_preview::ChallengePreview;
+pub use changelog::{Changelog, ChangelogRelease, ChangelogSection};
 pub use chunk::{ChunkType, CodeChunk};
+pub use commentary_style::CommentaryStyle;
+pub use completion_feedback::CompletionFeedback;
 pub use countdown::Countdown;
+pub use demo::{DemoMode, DemoTypist};
+pub use difficulty_gate::DifficultyLock;
 pub use difficulty_level::DifficultyLevel;
+pub use drill::DrillGenerator;
+pub use editor_config::{EditorConfig, IndentStyle, Indentation};
+pub use error_report::ErrorReport;
 pub use extraction_options::ExtractionOptions;
+pub use failure_analysis::{ErrorCluster, FailureAnalysis, SourceLocation};
 pub use git_repository::GitRepository;
 pub use git_repository_ref::GitRepositoryRef;
+pub use history_file::{HistoryFile, HistoryRepository, HistoryResult, HistorySession};
+pub use input_latency::LatencyEstimate;
+pub use keyboard_layout::{Finger, KeyboardLayout};
+pub use keymap::{KeyBinding, Keymap, KeymapContext};
+pub use keystroke_load::{FingerLoad, KeyLoad, Load, LoadStats};
 pub use language::{Language, Languages};
+pub use language_calibration::{LanguageCalibration, LanguageCalibrations};
+pub use language_capability::LanguageCapability;
+pub use locale::{DateOrder, Locale, LocalePreset};
+pub use log_entry::{LogEntry, LogLevel};
+pub use metrics_snapshot::MetricsSnapshot;
+pub use mistake_breakdown::{MistakeBreakdown, MistakeKind};
+pub use mistake_diff::{DiffChar, DiffLine, MistakeDiff};
+pub use player_profile::PlayerProfile;
+pub use practice_calendar::{PracticeCalendar, PracticeEvent};
+pub use presence::Presence;
+pub use race_bot::RaceBot;
 pub use rank::{Rank, RankTier};
+pub use read_only::ReadOnlyMode;
+pub use reminder::Reminder;
+pub use repository_detail::RepositoryDetail;
+pub use repository_report::RepositoryReport;
+pub use result_plausibility::{ImplausibilityReason, ResultPlausibility};
+pub use result_verification::ResultVerification;
+pub use scan_report::ScanReport;
 pub use session::{Session, SessionAction, SessionConfig, SessionResult, SessionState};
+pub use source_study::SourceStudy;
 pub use stage::{GameMode, Stage, StageConfig, StageResult};
+pub use stage_screenshot::StageScreenshot;
+pub use status_summary::StatusSummary;
+pub use streamer::{StreamerMode, StreamerStats};
+pub use telemetry::TelemetryReport;
 pub use total::{Total, TotalResult};
 pub use typing::{CodeContext, InputResult, ProcessingOptions};
+pub use typing_baseline::{BaselineSource, BaselineSummary, TypingBaseline};
+pub use warm_progress::{WarmProgress, WarmStatus};
+pub use workspace_package::{WorkspaceKind, WorkspacePackage};
+pub use wpm_mode::WpmMode;
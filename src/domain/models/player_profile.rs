@@ -0,0 +1,122 @@
+use chrono::{Local, NaiveDate};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::RwLock;
+
+use crate::domain::models::storage::StoredStageResult;
+use crate::domain::models::{Languages, Rank};
+
+/// Name shown until the player picks one in the profile screen
+pub const DEFAULT_DISPLAY_NAME: &str = "Player";
+
+static ACTIVE_PLAYER: RwLock<Option<String>> = RwLock::new(None);
+
+/// Who the player is, summarized from their config and completed stages
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerProfile {
+    pub display_name: String,
+    pub rank_title: Option<String>,
+    pub favorite_language: Option<String>,
+    pub current_streak_days: usize,
+    pub stages_completed: usize,
+    pub best_score: f64,
+}
+
+impl PlayerProfile {
+    /// `today` is the local date anchoring the streak, which stays alive until a full local day is missed
+    pub fn from_history(
+        display_name: Option<&str>,
+        stages: &[StoredStageResult],
+        today: NaiveDate,
+    ) -> Self {
+        let best_score = stages.iter().map(|s| s.score).fold(0.0, f64::max);
+
+        Self {
+            display_name: Self::normalize_name(display_name)
+                .unwrap_or_else(|| DEFAULT_DISPLAY_NAME.to_string()),
+            rank_title: (!stages.is_empty())
+                .then(|| Rank::for_score(best_score).name().to_string()),
+            favorite_language: Self::favorite_language(stages),
+            current_streak_days: Self::current_streak(stages, today),
+            stages_completed: stages.len(),
+            best_score,
+        }
+    }
+
+    /// Name new sessions are recorded under; unset until read from config
+    pub fn active_player() -> Option<String> {
+        ACTIVE_PLAYER.read().unwrap().clone()
+    }
+
+    pub fn set_active_player(name: Option<&str>) {
+        *ACTIVE_PLAYER.write().unwrap() = Self::normalize_name(name);
+    }
+
+    /// Trimmed display name, or `None` when only whitespace was given
+    pub fn normalize_name(name: Option<&str>) -> Option<String> {
+        name.map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+    }
+
+    /// First letter of the first two words, e.g. "Ada Lovelace" becomes "AL"
+    pub fn initials(&self) -> String {
+        self.display_name
+            .split_whitespace()
+            .filter_map(|word| word.chars().next())
+            .take(2)
+            .flat_map(char::to_uppercase)
+            .collect()
+    }
+
+    pub fn favorite_language_display(&self) -> Option<String> {
+        self.favorite_language
+            .as_deref()
+            .map(|language| Languages::get_display_name(Some(language)))
+    }
+
+    /// One line appended to shared results so they carry the player's identity
+    pub fn share_signature(&self) -> String {
+        let mut parts = vec![self.display_name.clone()];
+        parts.extend(self.rank_title.clone());
+        parts.extend(self.favorite_language_display());
+        if self.current_streak_days > 0 {
+            parts.push(format!("🔥 {}-day streak", self.current_streak_days));
+        }
+        format!("— {}", parts.join(" · "))
+    }
+
+    fn favorite_language(stages: &[StoredStageResult]) -> Option<String> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        stages
+            .iter()
+            .filter_map(|stage| stage.language.as_ref().filter(|l| !l.is_empty()))
+            .for_each(|language| *counts.entry(language.to_lowercase()).or_default() += 1);
+
+        counts
+            .into_iter()
+            .max_by(|(a_name, a_count), (b_name, b_count)| {
+                a_count.cmp(b_count).then_with(|| b_name.cmp(a_name))
+            })
+            .map(|(language, _)| language)
+    }
+
+    fn current_streak(stages: &[StoredStageResult], today: NaiveDate) -> usize {
+        let days: BTreeSet<NaiveDate> = stages
+            .iter()
+            .map(|stage| stage.completed_at.with_timezone(&Local).date_naive())
+            .collect();
+
+        let start = if days.contains(&today) {
+            Some(today)
+        } else {
+            today
+                .pred_opt()
+                .filter(|yesterday| days.contains(yesterday))
+        };
+
+        std::iter::successors(start, |day| {
+            day.pred_opt().filter(|prev| days.contains(prev))
+        })
+        .count()
+    }
+}
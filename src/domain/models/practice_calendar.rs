@@ -0,0 +1,152 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::models::storage::{SessionResultData, StoredRepository, StoredSession};
+
+/// RFC 5545 caps content lines at 75 octets, excluding the line break
+const MAX_LINE_OCTETS: usize = 75;
+
+/// A finished session as a calendar event
+#[derive(Debug, Clone, PartialEq)]
+pub struct PracticeEvent {
+    pub session_id: i64,
+    pub started_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    pub repository: Option<String>,
+    pub score: f64,
+    pub wpm: f64,
+    pub accuracy: f64,
+    pub stages_completed: usize,
+    pub rank_name: Option<String>,
+}
+
+/// Practice history in iCalendar format, for overlaying on a personal calendar;
+/// written as a header, one event per session and a footer
+pub struct PracticeCalendar;
+
+impl PracticeEvent {
+    pub fn new(
+        session: &StoredSession,
+        repository: Option<&StoredRepository>,
+        result: &SessionResultData,
+    ) -> Self {
+        Self {
+            session_id: session.id,
+            started_at: session.started_at,
+            duration_ms: result.duration_ms,
+            repository: repository
+                .map(|repo| format!("{}/{}", repo.user_name, repo.repository_name)),
+            score: result.score,
+            wpm: result.wpm,
+            accuracy: result.accuracy,
+            stages_completed: result.stages_completed,
+            rank_name: result.rank_name.clone(),
+        }
+    }
+
+    pub fn ended_at(&self) -> DateTime<Utc> {
+        self.started_at + Duration::milliseconds(self.duration_ms as i64)
+    }
+
+    /// e.g. "gittype: rust-lang/rust"
+    pub fn summary(&self) -> String {
+        match &self.repository {
+            Some(repository) => format!("gittype: {}", repository),
+            None => "gittype practice".to_string(),
+        }
+    }
+
+    pub fn description(&self) -> String {
+        let mut lines = vec![
+            format!(
+                "Duration: {}m {}s",
+                self.duration_ms / 60000,
+                (self.duration_ms % 60000) / 1000
+            ),
+            format!("Score: {:.0}", self.score),
+            format!("WPM: {:.1}", self.wpm),
+            format!("Accuracy: {:.1}%", self.accuracy),
+            format!("Stages: {}", self.stages_completed),
+        ];
+        if let Some(rank) = &self.rank_name {
+            lines.push(format!("Rank: {}", rank));
+        }
+        lines.join("\n")
+    }
+}
+
+impl PracticeCalendar {
+    /// Opening lines of an iCalendar (RFC 5545) document with CRLF line endings
+    pub fn ics_header() -> String {
+        let mut out = String::new();
+        Self::line(&mut out, "BEGIN:VCALENDAR");
+        Self::line(&mut out, "VERSION:2.0");
+        Self::line(&mut out, "PRODID:-//gittype//Practice History//EN");
+        Self::line(&mut out, "CALSCALE:GREGORIAN");
+        Self::line(&mut out, "X-WR-CALNAME:gittype practice");
+        out
+    }
+
+    pub fn ics_event(event: &PracticeEvent, generated_at: DateTime<Utc>) -> String {
+        let mut out = String::new();
+        Self::line(&mut out, "BEGIN:VEVENT");
+        Self::line(
+            &mut out,
+            &format!("UID:session-{}@gittype", event.session_id),
+        );
+        Self::line(
+            &mut out,
+            &format!("DTSTAMP:{}", Self::timestamp(generated_at)),
+        );
+        Self::line(
+            &mut out,
+            &format!("DTSTART:{}", Self::timestamp(event.started_at)),
+        );
+        Self::line(
+            &mut out,
+            &format!("DTEND:{}", Self::timestamp(event.ended_at())),
+        );
+        Self::line(
+            &mut out,
+            &format!("SUMMARY:{}", Self::escape_text(&event.summary())),
+        );
+        Self::line(
+            &mut out,
+            &format!("DESCRIPTION:{}", Self::escape_text(&event.description())),
+        );
+        Self::line(&mut out, "TRANSP:TRANSPARENT");
+        Self::line(&mut out, "END:VEVENT");
+        out
+    }
+
+    pub fn ics_footer() -> String {
+        let mut out = String::new();
+        Self::line(&mut out, "END:VCALENDAR");
+        out
+    }
+
+    fn timestamp(time: DateTime<Utc>) -> String {
+        time.format("%Y%m%dT%H%M%SZ").to_string()
+    }
+
+    fn escape_text(value: &str) -> String {
+        value
+            .replace('\\', "\\\\")
+            .replace(';', "\\;")
+            .replace(',', "\\,")
+            .replace('\n', "\\n")
+    }
+
+    /// Write a content line, folding it onto continuation lines past 75 octets
+    fn line(out: &mut String, content: &str) {
+        let mut line_octets = 0;
+        for c in content.chars() {
+            if line_octets + c.len_utf8() > MAX_LINE_OCTETS {
+                out.push_str("\r\n ");
+                line_octets = 1;
+            }
+            line_octets += c.len_utf8();
+            out.push(c);
+        }
+        out.push_str("\r\n");
+    }
+}
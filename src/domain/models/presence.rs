@@ -0,0 +1,45 @@
+use crate::domain::models::WpmMode;
+
+/// What the player is doing right now, as shown in Discord Rich Presence
+#[derive(Debug, Clone, PartialEq)]
+pub struct Presence {
+    pub language: Option<String>,
+    pub repository: Option<String>,
+    pub current_stage: usize,
+    pub total_stages: usize,
+    pub wpm: f64,
+}
+
+impl Presence {
+    /// e.g. "Typing rust in tokio"
+    pub fn details(&self) -> String {
+        let language = self.language.as_deref().unwrap_or("code");
+        match self.repository.as_deref() {
+            Some(repository) => format!("Typing {} in {}", language, repository),
+            None => format!("Typing {}", language),
+        }
+    }
+
+    /// e.g. "Stage 3/5, 72 WPM"
+    pub fn state(&self) -> String {
+        format!(
+            "Stage {}/{}, {:.0} {}",
+            self.current_stage,
+            self.total_stages,
+            self.wpm,
+            WpmMode::current().label()
+        )
+    }
+
+    pub fn summary(&self) -> String {
+        format!("{} — {}", self.details(), self.state())
+    }
+
+    /// Whether this differs from `other` in anything but the live WPM
+    pub fn is_new_activity(&self, other: &Presence) -> bool {
+        self.language != other.language
+            || self.repository != other.repository
+            || self.current_stage != other.current_stage
+            || self.total_stages != other.total_stages
+    }
+}
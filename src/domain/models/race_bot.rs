@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+
+use std::sync::RwLock;
+use std::time::Duration;
+
+use crate::domain::models::WpmMode;
+use crate::{GitTypeError, Result};
+
+static LINEUP: RwLock<Vec<RaceBot>> = RwLock::new(Vec::new());
+
+/// Built-in skill profiles as (name, WPM, accuracy percent)
+const PRESETS: [(&str, f64, f64); 5] = [
+    ("novice", 30.0, 92.0),
+    ("casual", 45.0, 95.0),
+    ("fast", 70.0, 97.0),
+    ("pro", 100.0, 98.0),
+    ("legend", 140.0, 99.0),
+];
+
+/// Time a bot spends noticing a mistake before typing on
+const MISTAKE_RECOVERY: Duration = Duration::from_millis(300);
+
+/// Computer opponent that types the same challenge as the player
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RaceBot {
+    pub name: String,
+    /// Keystrokes per minute in the current WPM unit, mistakes included
+    pub wpm: f64,
+    /// Percent of keystrokes that are correct; every mistake costs a
+    /// keystroke and a short pause
+    pub accuracy: f64,
+    /// Set for ghosts of past attempts, which keep that attempt's pace
+    #[serde(skip)]
+    finish_time: Option<Duration>,
+}
+
+impl RaceBot {
+    pub fn new(name: &str, wpm: f64, accuracy: f64) -> Result<Self> {
+        if !(wpm.is_finite() && wpm > 0.0) {
+            return Err(GitTypeError::ValidationError(format!(
+                "Bot speed must be a positive WPM, got {}",
+                wpm
+            )));
+        }
+        if !(accuracy > 0.0 && accuracy <= 100.0) {
+            return Err(GitTypeError::ValidationError(format!(
+                "Bot accuracy must be between 0 and 100, got {}",
+                accuracy
+            )));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            wpm,
+            accuracy,
+            finish_time: None,
+        })
+    }
+
+    /// Replays a past attempt that reached `wpm` at `accuracy` in `finish_time`,
+    /// typing at that attempt's average pace
+    pub fn ghost(wpm: f64, accuracy: f64, finish_time: Duration) -> Self {
+        Self {
+            name: "Ghost".to_string(),
+            wpm,
+            accuracy,
+            finish_time: Some(finish_time),
+        }
+    }
+
+    pub fn is_ghost(&self) -> bool {
+        self.finish_time.is_some()
+    }
+
+    /// A preset name such as `pro`, or `WPM[@ACCURACY]` such as `70@96`
+    pub fn parse(spec: &str) -> Result<Self> {
+        let spec = spec.trim();
+        if let Some((name, wpm, accuracy)) = PRESETS
+            .iter()
+            .find(|(name, _, _)| name.eq_ignore_ascii_case(spec))
+        {
+            return Self::new(&Self::capitalize(name), *wpm, *accuracy);
+        }
+
+        let (wpm, accuracy) = spec.split_once('@').unwrap_or((spec, "100"));
+        let invalid = || {
+            GitTypeError::ValidationError(format!(
+                "Unknown bot '{}'; use one of {} or WPM[@ACCURACY] like 70@96",
+                spec,
+                Self::preset_names().join(", ")
+            ))
+        };
+        let wpm: f64 = wpm.trim().parse().map_err(|_| invalid())?;
+        let accuracy: f64 = accuracy
+            .trim()
+            .trim_end_matches('%')
+            .parse()
+            .map_err(|_| invalid())?;
+        Self::new("Bot", wpm, accuracy)
+    }
+
+    pub fn preset_names() -> Vec<&'static str> {
+        PRESETS.iter().map(|(name, _, _)| *name).collect()
+    }
+
+    /// Bots racing in this game; empty unless set from `--bots` or the config
+    pub fn lineup() -> Vec<RaceBot> {
+        LINEUP.read().unwrap().clone()
+    }
+
+    pub fn set_lineup(bots: Vec<RaceBot>) {
+        *LINEUP.write().unwrap() = bots;
+    }
+
+    /// Characters of `text` the bot has typed after `elapsed`
+    pub fn position(&self, text: &str, elapsed: Duration, mode: WpmMode) -> usize {
+        let chars = text.chars().count();
+        if let Some(finish_time) = self.finish_time {
+            let done = elapsed.as_secs_f64() / finish_time.as_secs_f64().max(0.001);
+            return ((chars as f64 * done) as usize).min(chars);
+        }
+
+        // Invert `WpmMode::wpm` to get the bot's keystrokes per minute on this text
+        let cpm = match mode {
+            WpmMode::Characters => self.wpm * 5.0,
+            WpmMode::Tokens => match WpmMode::count_tokens(text) {
+                0 => self.wpm * 5.0,
+                tokens => self.wpm * chars as f64 / tokens as f64,
+            },
+        };
+        let mistakes_per_char = 1.0 - self.accuracy / 100.0;
+        let secs_per_char = (1.0 + mistakes_per_char) * 60.0 / cpm
+            + mistakes_per_char * MISTAKE_RECOVERY.as_secs_f64();
+
+        ((elapsed.as_secs_f64() / secs_per_char) as usize).min(chars)
+    }
+
+    /// Label for the race view, like `Pro 100 WPM · 98%`
+    pub fn label(&self, mode: WpmMode) -> String {
+        format!(
+            "{} {:.0} {} · {:.0}%",
+            self.name,
+            self.wpm,
+            mode.label(),
+            self.accuracy
+        )
+    }
+
+    fn capitalize(name: &str) -> String {
+        let mut chars = name.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
+    }
+}
@@ -1,3 +1,5 @@
+use serde::Deserialize;
+
 use crate::presentation::ui::Colors;
 
 /// Represents a rank with associated metadata
@@ -9,7 +11,8 @@ pub struct Rank {
     pub max_score: u32,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum RankTier {
     Beginner,
     Intermediate,
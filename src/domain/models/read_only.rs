@@ -0,0 +1,21 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Whether another gittype owns the database, so this one plays on a copy
+/// that is dropped at exit; off until the instance lock is found taken
+pub struct ReadOnlyMode;
+
+impl ReadOnlyMode {
+    /// Shown at the top of the title screen while enabled
+    pub const BANNER: &'static str =
+        "⚠ Another gittype is running: history is read-only and this session will not be saved";
+
+    pub fn is_enabled() -> bool {
+        ENABLED.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(enabled: bool) {
+        ENABLED.store(enabled, Ordering::Relaxed);
+    }
+}
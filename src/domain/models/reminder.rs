@@ -0,0 +1,81 @@
+use chrono::{NaiveTime, Timelike};
+use serde::{Deserialize, Serialize};
+
+use crate::{GitTypeError, Result};
+
+/// Daily nudge to practice, delivered as a desktop notification by a
+/// user-level scheduled task; stored as `HH:MM`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Reminder {
+    time: NaiveTime,
+}
+
+impl Reminder {
+    pub const NOTIFICATION_TITLE: &'static str = "gittype";
+
+    pub fn new(hour: u32, minute: u32) -> Result<Self> {
+        NaiveTime::from_hms_opt(hour, minute, 0)
+            .map(|time| Self { time })
+            .ok_or_else(|| Self::invalid(&format!("{}:{}", hour, minute)))
+    }
+
+    /// 24-hour `HH:MM`, e.g. `09:00` or `9:00`
+    pub fn parse(at: &str) -> Result<Self> {
+        let (hour, minute) = at.trim().split_once(':').ok_or_else(|| Self::invalid(at))?;
+        let hour: u32 = hour.parse().map_err(|_| Self::invalid(at))?;
+        let minute: u32 = minute.parse().map_err(|_| Self::invalid(at))?;
+        Self::new(hour, minute).map_err(|_| Self::invalid(at))
+    }
+
+    pub fn hour(&self) -> u32 {
+        self.time.hour()
+    }
+
+    pub fn minute(&self) -> u32 {
+        self.time.minute()
+    }
+
+    /// `HH:MM`, as given to `--at`
+    pub fn label(&self) -> String {
+        self.time.format("%H:%M").to_string()
+    }
+
+    /// Name of the scheduled task, unique per reminder time
+    pub fn task_name(&self) -> String {
+        format!("gittype-reminder-{}", self.time.format("%H%M"))
+    }
+
+    /// Notification text, mentioning the streak at stake when there is one
+    pub fn notification_body(streak: usize) -> String {
+        match streak {
+            0 => "Time for some typing practice. Run gittype to start a session.".to_string(),
+            1 => "Keep your 1-day streak going. Run gittype to start a session.".to_string(),
+            days => format!(
+                "Keep your {}-day streak going. Run gittype to start a session.",
+                days
+            ),
+        }
+    }
+
+    fn invalid(at: &str) -> GitTypeError {
+        GitTypeError::ValidationError(format!(
+            "Invalid reminder time '{}'; use 24-hour HH:MM like 09:00",
+            at
+        ))
+    }
+}
+
+impl TryFrom<String> for Reminder {
+    type Error = GitTypeError;
+
+    fn try_from(at: String) -> Result<Self> {
+        Self::parse(&at)
+    }
+}
+
+impl From<Reminder> for String {
+    fn from(reminder: Reminder) -> Self {
+        reminder.label()
+    }
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::storage::{
+    LanguageStats, StoredRepositoryWithLanguages, StoredStageResult,
+};
+
+/// Play history and local cache state for one stored repository
+#[derive(Debug, Clone)]
+pub struct RepositoryDetail {
+    pub repository: StoredRepositoryWithLanguages,
+    pub is_cached: bool,
+    pub stages_completed: usize,
+    pub best_wpm: f64,
+    pub best_accuracy: f64,
+    pub best_score: f64,
+    pub languages: Vec<LanguageStats>,
+    pub cached_challenges: usize,
+    pub last_played_at: Option<DateTime<Utc>>,
+}
+
+impl RepositoryDetail {
+    pub fn from_history(
+        repository: StoredRepositoryWithLanguages,
+        is_cached: bool,
+        stages: &[StoredStageResult],
+        languages: Vec<LanguageStats>,
+        cached_challenges: usize,
+    ) -> Self {
+        Self {
+            repository,
+            is_cached,
+            stages_completed: stages.len(),
+            best_wpm: stages.iter().map(|s| s.wpm).fold(0.0, f64::max),
+            best_accuracy: stages.iter().map(|s| s.accuracy).fold(0.0, f64::max),
+            best_score: stages.iter().map(|s| s.score).fold(0.0, f64::max),
+            languages,
+            cached_challenges,
+            last_played_at: stages.iter().map(|s| s.completed_at).max(),
+        }
+    }
+
+    pub fn repo_spec(&self) -> String {
+        format!(
+            "{}/{}",
+            self.repository.user_name, self.repository.repository_name
+        )
+    }
+}
@@ -0,0 +1,140 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::{GitRepository, PracticeEvent};
+
+/// Bars of the WPM trend, lowest first
+const SPARK_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Sessions shown in the WPM trend, most recent kept
+const TREND_SESSIONS: usize = 60;
+
+/// Label, metric, decimal places and unit of a row in the bests table
+type BestRow = (&'static str, fn(&PracticeEvent) -> f64, usize, &'static str);
+
+/// One repository's practice history as a Markdown document, for keeping in a notes repository
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RepositoryReport {
+    pub repo_spec: String,
+    /// SPDX expression of the repository's license, if one was detected
+    pub license: Option<String>,
+    /// Oldest first
+    pub sessions: Vec<PracticeEvent>,
+}
+
+impl RepositoryReport {
+    pub fn new(repo_spec: &str, mut sessions: Vec<PracticeEvent>) -> Self {
+        sessions.sort_by_key(|session| session.started_at);
+        Self {
+            repo_spec: repo_spec.to_string(),
+            license: None,
+            sessions,
+        }
+    }
+
+    pub fn with_license(self, license: Option<String>) -> Self {
+        Self { license, ..self }
+    }
+
+    pub fn to_markdown(&self, generated_at: DateTime<Utc>) -> String {
+        let mut out = format!(
+            "# gittype report: {}\n\n_Generated {} · {} sessions_\n\n_{}_\n",
+            self.repo_spec,
+            generated_at.format("%Y-%m-%d %H:%M UTC"),
+            self.sessions.len(),
+            GitRepository::attribution_for(&self.repo_spec, self.license.as_deref())
+        );
+
+        if self.sessions.is_empty() {
+            out.push_str("\nNo sessions recorded for this repository yet.\n");
+            return out;
+        }
+
+        out.push_str(&self.bests_section());
+        out.push_str(&self.trend_section());
+        out.push_str(&self.history_section());
+        out
+    }
+
+    /// WPM of each session as unicode bars scaled between the lowest and highest value
+    pub fn sparkline(values: &[f64]) -> String {
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let top = SPARK_BARS.len() - 1;
+        values
+            .iter()
+            .map(|value| match max - min {
+                range if range > 0.0 => {
+                    SPARK_BARS[((value - min) / range * top as f64).round() as usize]
+                }
+                _ => SPARK_BARS[top / 2],
+            })
+            .collect()
+    }
+
+    fn bests_section(&self) -> String {
+        let rows: [BestRow; 3] = [
+            ("Score", |s| s.score, 0, ""),
+            ("WPM", |s| s.wpm, 1, ""),
+            ("Accuracy", |s| s.accuracy, 1, "%"),
+        ];
+
+        rows.iter()
+            .filter_map(|(label, metric, precision, suffix)| {
+                self.sessions
+                    .iter()
+                    .max_by(|a, b| metric(a).total_cmp(&metric(b)))
+                    .map(|session| {
+                        format!(
+                            "| {} | {:.*}{} | {} |\n",
+                            label,
+                            precision,
+                            metric(session),
+                            suffix,
+                            session.started_at.format("%Y-%m-%d")
+                        )
+                    })
+            })
+            .fold(
+                "\n## Bests\n\n| | Best | Date |\n|---|---:|---|\n".to_string(),
+                |out, row| out + &row,
+            )
+    }
+
+    fn trend_section(&self) -> String {
+        let recent = &self.sessions[self.sessions.len().saturating_sub(TREND_SESSIONS)..];
+        let wpm: Vec<f64> = recent.iter().map(|session| session.wpm).collect();
+        let min = wpm.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = wpm.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        format!(
+            "\n## Trend\n\nWPM of the last {} sessions, oldest first ({:.1}–{:.1}):\n\n```\n{}\n```\n",
+            recent.len(),
+            min,
+            max,
+            Self::sparkline(&wpm)
+        )
+    }
+
+    fn history_section(&self) -> String {
+        self.sessions
+            .iter()
+            .rev()
+            .map(|session| {
+                format!(
+                    "| {} | {}m {:02}s | {:.0} | {:.1} | {:.1}% | {} | {} |\n",
+                    session.started_at.format("%Y-%m-%d %H:%M"),
+                    session.duration_ms / 60000,
+                    (session.duration_ms % 60000) / 1000,
+                    session.score,
+                    session.wpm,
+                    session.accuracy,
+                    session.stages_completed,
+                    session.rank_name.as_deref().unwrap_or("-")
+                )
+            })
+            .fold(
+                "\n## History\n\n| Date (UTC) | Duration | Score | WPM | Accuracy | Stages | Rank |\n|---|---:|---:|---:|---:|---:|---|\n"
+                    .to_string(),
+                |out, row| out + &row,
+            )
+    }
+}
@@ -0,0 +1,118 @@
+use std::time::Duration;
+
+/// Something about a result no person at a keyboard should produce
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImplausibilityReason {
+    /// A whole stage typed faster than [`ResultPlausibility::MAX_SUSTAINED_WPM`]
+    SustainedSpeed,
+    /// Keystrokes spaced as evenly as a script sends them
+    UniformRhythm,
+    /// Text was pasted into the typing screen
+    Pasted,
+}
+
+impl ImplausibilityReason {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ImplausibilityReason::SustainedSpeed => "sustained >300 WPM",
+            ImplausibilityReason::UniformRhythm => "machine-even keystrokes",
+            ImplausibilityReason::Pasted => "pasted text",
+        }
+    }
+}
+
+/// Whether a session looks typed by hand; an implausible result is kept
+/// but marked unverified, and stays out of best records and leaderboards
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResultPlausibility {
+    reasons: Vec<ImplausibilityReason>,
+}
+
+impl ResultPlausibility {
+    pub const MAX_SUSTAINED_WPM: f64 = 300.0;
+
+    /// Keystrokes a stage needs before its speed counts as sustained
+    const MIN_SUSTAINED_KEYSTROKES: usize = 50;
+
+    /// Keystrokes a stage needs before its rhythm is judged
+    const MIN_RHYTHM_KEYSTROKES: usize = 20;
+
+    /// Standard deviation of the gaps between keystrokes below which a
+    /// stage reads as generated
+    const MIN_INTERVAL_DEVIATION: Duration = Duration::from_millis(1);
+
+    /// Judge a session from the keystroke times of each stage, measured
+    /// from the stage start, and how often text was pasted
+    pub fn assess(stage_keystroke_times: &[Vec<Duration>], paste_attempts: usize) -> Self {
+        let mut reasons = Vec::new();
+        if stage_keystroke_times
+            .iter()
+            .any(|times| Self::is_sustained_speed(times))
+        {
+            reasons.push(ImplausibilityReason::SustainedSpeed);
+        }
+        if stage_keystroke_times
+            .iter()
+            .any(|times| Self::is_uniform_rhythm(times))
+        {
+            reasons.push(ImplausibilityReason::UniformRhythm);
+        }
+        if paste_attempts > 0 {
+            reasons.push(ImplausibilityReason::Pasted);
+        }
+        Self { reasons }
+    }
+
+    pub fn is_verified(&self) -> bool {
+        self.reasons.is_empty()
+    }
+
+    pub fn reasons(&self) -> &[ImplausibilityReason] {
+        &self.reasons
+    }
+
+    /// `Unverified (pasted text, ...)`, or `None` for a plausible result
+    pub fn label(&self) -> Option<String> {
+        (!self.is_verified()).then(|| {
+            format!(
+                "Unverified ({})",
+                self.reasons
+                    .iter()
+                    .map(ImplausibilityReason::label)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+    }
+
+    fn is_sustained_speed(times: &[Duration]) -> bool {
+        if times.len() < Self::MIN_SUSTAINED_KEYSTROKES {
+            return false;
+        }
+        let (Some(first), Some(last)) = (times.first(), times.last()) else {
+            return false;
+        };
+        let minutes = last.saturating_sub(*first).as_secs_f64() / 60.0;
+        if minutes <= 0.0 {
+            return true;
+        }
+        (times.len() - 1) as f64 / 5.0 / minutes > Self::MAX_SUSTAINED_WPM
+    }
+
+    fn is_uniform_rhythm(times: &[Duration]) -> bool {
+        if times.len() < Self::MIN_RHYTHM_KEYSTROKES {
+            return false;
+        }
+        let intervals: Vec<f64> = times
+            .windows(2)
+            .map(|pair| pair[1].saturating_sub(pair[0]).as_secs_f64())
+            .collect();
+        let mean = intervals.iter().sum::<f64>() / intervals.len() as f64;
+        let variance = intervals
+            .iter()
+            .map(|interval| (interval - mean).powi(2))
+            .sum::<f64>()
+            / intervals.len() as f64;
+        variance.sqrt() < Self::MIN_INTERVAL_DEVIATION.as_secs_f64()
+    }
+}
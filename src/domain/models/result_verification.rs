@@ -0,0 +1,104 @@
+use sha2::{Digest, Sha256};
+
+use crate::domain::models::SessionResult;
+use crate::{GitTypeError, Result};
+
+/// Ships in every binary, so a code shows a shared result was not edited by
+/// hand; it is no proof against someone who reads this source
+const VERIFICATION_KEY: &[u8] = b"gittype result verification v1";
+
+const CODE_PREFIX: &str = "GT1";
+
+/// Hex digits of the MAC kept in a code
+const MAC_LENGTH: usize = 12;
+
+/// The figures of a shared result, signed together with a digest of the
+/// session's keystroke log and a per-session seed, e.g.
+/// `GT1-4321-385-12-9f3a1c2b0d4e7a11-3b9c0e1f2a4d`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultVerification {
+    pub score: u64,
+    pub cpm: u64,
+    pub mistakes: usize,
+    pub seed: u32,
+    /// First bytes of the SHA-256 of the keystroke log
+    pub log_digest: u32,
+}
+
+impl ResultVerification {
+    pub fn new(result: &SessionResult, keystroke_log: &str, seed: u32) -> Self {
+        let digest = Sha256::digest(keystroke_log.as_bytes());
+        Self {
+            score: result.session_score.max(0.0).round() as u64,
+            cpm: result.overall_cpm.max(0.0).round() as u64,
+            mistakes: result.valid_mistakes + result.invalid_mistakes,
+            seed,
+            log_digest: u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]),
+        }
+    }
+
+    pub fn code(&self) -> String {
+        format!("{}-{}", self.payload(), self.mac())
+    }
+
+    /// Read the figures back from a code, failing when it is malformed or
+    /// any part of it was changed
+    pub fn parse(code: &str) -> Result<Self> {
+        let malformed =
+            || GitTypeError::ValidationError(format!("Not a gittype verification code: {}", code));
+        let parts: Vec<&str> = code.trim().split('-').collect();
+        let [prefix, score, cpm, mistakes, seed_and_digest, mac] = parts[..] else {
+            return Err(malformed());
+        };
+        if prefix != CODE_PREFIX || seed_and_digest.len() != 16 {
+            return Err(malformed());
+        }
+
+        let verification = Self {
+            score: score.parse().map_err(|_| malformed())?,
+            cpm: cpm.parse().map_err(|_| malformed())?,
+            mistakes: mistakes.parse().map_err(|_| malformed())?,
+            seed: u32::from_str_radix(&seed_and_digest[..8], 16).map_err(|_| malformed())?,
+            log_digest: u32::from_str_radix(&seed_and_digest[8..], 16).map_err(|_| malformed())?,
+        };
+        if !verification.mac().eq_ignore_ascii_case(mac) {
+            return Err(GitTypeError::ValidationError(
+                "The verification code does not match its result; it was edited or mistyped"
+                    .to_string(),
+            ));
+        }
+        Ok(verification)
+    }
+
+    fn payload(&self) -> String {
+        format!(
+            "{}-{}-{}-{}-{:08x}{:08x}",
+            CODE_PREFIX, self.score, self.cpm, self.mistakes, self.seed, self.log_digest
+        )
+    }
+
+    fn mac(&self) -> String {
+        hmac_sha256(VERIFICATION_KEY, self.payload().as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>()[..MAC_LENGTH]
+            .to_string()
+    }
+}
+
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    const BLOCK_SIZE: usize = 64;
+    let mut block = [0u8; BLOCK_SIZE];
+    block[..key.len()].copy_from_slice(key);
+
+    let pad = |byte: u8| block.map(|k| k ^ byte);
+    let inner = Sha256::new()
+        .chain_update(pad(0x36))
+        .chain_update(message)
+        .finalize();
+    Sha256::new()
+        .chain_update(pad(0x5c))
+        .chain_update(inner)
+        .finalize()
+        .into()
+}
@@ -0,0 +1,162 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+
+use crate::domain::models::{Challenge, CodeChunk, DifficultyLevel, Languages};
+
+const DIFFICULTIES: [DifficultyLevel; 5] = [
+    DifficultyLevel::Easy,
+    DifficultyLevel::Normal,
+    DifficultyLevel::Hard,
+    DifficultyLevel::Wild,
+    DifficultyLevel::Zen,
+];
+
+/// What scanning, extracting and generating produce for a repository,
+/// reported by `gittype scan` without starting a game
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanReport {
+    pub repo_path: PathBuf,
+    /// Most common first
+    pub files_per_language: Vec<(String, usize)>,
+    /// Most common first
+    pub chunks_per_type: Vec<(String, usize)>,
+    pub challenges_per_difficulty: Vec<(DifficultyLevel, usize)>,
+    /// Files skipped by the parser (too large, binary, minified or unparsable)
+    pub files_without_chunks: usize,
+    /// Challenges players flagged as bad, left out of games
+    pub flagged_challenges: usize,
+}
+
+impl ScanReport {
+    pub fn new(
+        repo_path: &Path,
+        files: &[PathBuf],
+        chunks: &[CodeChunk],
+        challenges: &[Challenge],
+    ) -> Self {
+        let files_per_language = Self::count_sorted(
+            Languages::for_project_files(files)
+                .into_iter()
+                .map(|(_, language)| language.name().to_string()),
+        );
+        let chunks_per_type =
+            Self::count_sorted(chunks.iter().map(|chunk| format!("{:?}", chunk.chunk_type)));
+        let challenges_per_difficulty = DIFFICULTIES
+            .iter()
+            .map(|difficulty| {
+                let count = challenges
+                    .iter()
+                    .filter(|challenge| challenge.difficulty_level == Some(*difficulty))
+                    .count();
+                (*difficulty, count)
+            })
+            .collect();
+        let files_with_chunks = chunks
+            .iter()
+            .map(|chunk| &chunk.file_path)
+            .collect::<HashSet<_>>()
+            .len();
+
+        Self {
+            repo_path: repo_path.to_path_buf(),
+            files_per_language,
+            chunks_per_type,
+            challenges_per_difficulty,
+            files_without_chunks: files.len().saturating_sub(files_with_chunks),
+            flagged_challenges: 0,
+        }
+    }
+
+    pub fn with_flagged_challenges(mut self, flagged_challenges: usize) -> Self {
+        self.flagged_challenges = flagged_challenges;
+        self
+    }
+
+    pub fn total_files(&self) -> usize {
+        self.files_per_language.iter().map(|(_, count)| count).sum()
+    }
+
+    pub fn total_chunks(&self) -> usize {
+        self.chunks_per_type.iter().map(|(_, count)| count).sum()
+    }
+
+    pub fn total_challenges(&self) -> usize {
+        self.challenges_per_difficulty
+            .iter()
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// Plain-text report, ending with a hint when nothing would be playable
+    pub fn render(&self) -> String {
+        let mut lines = vec![format!("Scan report for {}", self.repo_path.display())];
+
+        lines.push(String::new());
+        lines.push(format!("Files found: {}", self.total_files()));
+        lines.extend(Self::rows(&self.files_per_language));
+
+        lines.push(String::new());
+        lines.push(format!(
+            "Code chunks: {} ({} files produced none)",
+            self.total_chunks(),
+            self.files_without_chunks
+        ));
+        lines.extend(Self::rows(&self.chunks_per_type));
+
+        lines.push(String::new());
+        lines.push(format!("Estimated challenges: {}", self.total_challenges()));
+        lines.extend(Self::rows(
+            &self
+                .challenges_per_difficulty
+                .iter()
+                .map(|(difficulty, count)| (format!("{:?}", difficulty), *count))
+                .collect::<Vec<_>>(),
+        ));
+        if self.flagged_challenges > 0 {
+            lines.push(format!(
+                "Flagged as bad: {} (excluded from games)",
+                self.flagged_challenges
+            ));
+        }
+
+        if let Some(hint) = self.hint() {
+            lines.push(String::new());
+            lines.push(hint.to_string());
+        }
+        lines.join("\n")
+    }
+
+    fn hint(&self) -> Option<&'static str> {
+        if self.total_files() == 0 {
+            Some("No supported source files found. Check the path, --langs, and any .gittypeignore or exclude patterns.")
+        } else if self.total_chunks() == 0 {
+            Some("Files were found but none could be parsed. Large, binary and minified files are skipped; see `gittype logs` for details.")
+        } else if self.total_challenges() == 0 {
+            Some("Code was extracted but every chunk was too short or too long for any difficulty.")
+        } else {
+            None
+        }
+    }
+
+    fn rows(counts: &[(String, usize)]) -> Vec<String> {
+        counts
+            .iter()
+            .map(|(name, count)| format!("  {:<16}{:>6}", name, count))
+            .collect()
+    }
+
+    fn count_sorted<K: Eq + Hash + Ord>(keys: impl Iterator<Item = K>) -> Vec<(K, usize)> {
+        let mut counts: Vec<(K, usize)> = keys
+            .fold(HashMap::new(), |mut counts, key| {
+                *counts.entry(key).or_insert(0) += 1;
+                counts
+            })
+            .into_iter()
+            .collect();
+        counts.sort_by(|(a_key, a_count), (b_key, b_count)| {
+            b_count.cmp(a_count).then_with(|| a_key.cmp(b_key))
+        });
+        counts
+    }
+}
@@ -1,6 +1,8 @@
 use std::time::{Duration, Instant};
 
+use crate::domain::models::result_plausibility::ResultPlausibility;
 use crate::domain::models::stage::{Stage, StageResult};
+use crate::domain::models::wpm_mode::WpmMode;
 
 #[derive(Debug, Clone)]
 pub struct Session {
@@ -31,6 +33,10 @@ pub struct SessionResult {
     pub worst_stage_accuracy: f64,
     pub session_score: f64,
     pub session_successful: bool, // True if session was completed successfully
+    /// Code for `gittype verify`, set once the keystrokes behind the result are known
+    pub verification_code: Option<String>,
+    /// Whether the keystrokes look typed by hand; set alongside the verification code
+    pub plausibility: ResultPlausibility,
 }
 
 impl Session {
@@ -66,9 +72,48 @@ impl SessionResult {
             worst_stage_accuracy: f64::MAX,
             session_score: 0.0,
             session_successful: false,
+            verification_code: None,
+            plausibility: ResultPlausibility::default(),
         }
     }
 
+    /// Code tokens per character over the completed stages, weighted by the
+    /// characters typed in each
+    pub fn token_density(&self) -> f64 {
+        WpmMode::mean_token_density(
+            self.stage_results
+                .iter()
+                .filter(|stage| !stage.was_skipped && !stage.was_failed)
+                .map(|stage| {
+                    (
+                        stage.cpm * stage.completion_time.as_secs_f64(),
+                        stage.token_density,
+                    )
+                }),
+        )
+    }
+
+    /// The sessions with a completed stage that scored highest and lowest;
+    /// ties go to the last best and the first worst
+    pub fn best_and_worst(sessions: &[SessionResult]) -> Option<(&SessionResult, &SessionResult)> {
+        let mut completed = sessions.iter().filter(|sr| sr.stages_completed > 0);
+        let first = completed.next()?;
+        Some(completed.fold((first, first), |(best, worst), session| {
+            (
+                if session.session_score >= best.session_score {
+                    session
+                } else {
+                    best
+                },
+                if session.session_score < worst.session_score {
+                    session
+                } else {
+                    worst
+                },
+            )
+        }))
+    }
+
     pub fn get_session_completion_status(&self) -> String {
         match (self.stages_completed, self.stages_skipped) {
             (0, 0) => "No challenges attempted".to_string(),
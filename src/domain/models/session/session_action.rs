@@ -3,7 +3,7 @@ use crate::domain::services::scoring::StageResult;
 #[derive(Debug, Clone)]
 pub enum SessionAction {
     Start,
-    CompleteStage(StageResult),
+    CompleteStage(Box<StageResult>),
     Complete,
     Abort,
     Reset,
@@ -0,0 +1,27 @@
+/// The whole source file a challenge was cut from, read after typing it
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceStudy {
+    pub path: String,
+    pub lines: Vec<String>,
+    /// First line of the challenge, 1-indexed
+    pub start_line: usize,
+    /// Last line of the challenge, 1-indexed and inclusive
+    pub end_line: usize,
+}
+
+impl SourceStudy {
+    pub fn is_challenge_line(&self, line_number: usize) -> bool {
+        (self.start_line..=self.end_line).contains(&line_number)
+    }
+
+    /// Index of the first line shown on opening: the challenge with `context_lines` above it
+    pub fn initial_offset(&self, context_lines: usize) -> usize {
+        self.start_line
+            .saturating_sub(context_lines + 1)
+            .min(self.max_offset())
+    }
+
+    pub fn max_offset(&self) -> usize {
+        self.lines.len().saturating_sub(1)
+    }
+}
@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use crate::domain::models::Challenge;
+use crate::domain::models::{Challenge, KeyLoad, MistakeBreakdown};
 
 #[derive(Debug, Clone)]
 pub struct Stage {
@@ -11,7 +11,10 @@ pub struct Stage {
 #[derive(Debug, Clone, PartialEq)]
 pub struct StageResult {
     pub cpm: f64,
+    /// Five-character words per minute; token mode converts it only for display
     pub wpm: f64,
+    /// Code tokens per character of the stage's text, for speeds shown in token mode
+    pub token_density: f64,
     pub accuracy: f64,
     pub keystrokes: usize,
     pub mistakes: usize,
@@ -27,6 +30,8 @@ pub struct StageResult {
     pub was_skipped: bool,
     pub was_failed: bool,
     pub challenge_path: String,
+    pub mistake_breakdown: MistakeBreakdown,
+    pub key_load: KeyLoad,
 }
 
 impl Default for StageResult {
@@ -34,6 +39,7 @@ impl Default for StageResult {
         Self {
             cpm: 0.0,
             wpm: 0.0,
+            token_density: 0.0,
             accuracy: 0.0,
             keystrokes: 0,
             mistakes: 0,
@@ -49,6 +55,8 @@ impl Default for StageResult {
             was_skipped: false,
             was_failed: false,
             challenge_path: String::new(),
+            mistake_breakdown: MistakeBreakdown::default(),
+            key_load: KeyLoad::default(),
         }
     }
 }
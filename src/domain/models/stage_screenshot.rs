@@ -0,0 +1,30 @@
+use std::path::{Path, PathBuf};
+
+/// Directory under the app data directory holding one folder per recorded session
+pub const SCREENSHOTS_DIR: &str = "screenshots";
+
+/// The typing screen as it looked when a stage was finished, as ANSI text and
+/// as an HTML page, for sharing the exact run
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageScreenshot {
+    /// Matches `stage_number` of the recorded stage result
+    pub stage_number: usize,
+    pub ansi: String,
+    pub html: String,
+}
+
+impl StageScreenshot {
+    pub fn session_dir(app_data_dir: &Path, session_id: i64) -> PathBuf {
+        app_data_dir
+            .join(SCREENSHOTS_DIR)
+            .join(format!("session-{}", session_id))
+    }
+
+    pub fn ansi_path(app_data_dir: &Path, session_id: i64, stage_number: usize) -> PathBuf {
+        Self::session_dir(app_data_dir, session_id).join(format!("stage-{}.ans", stage_number))
+    }
+
+    pub fn html_path(app_data_dir: &Path, session_id: i64, stage_number: usize) -> PathBuf {
+        Self::session_dir(app_data_dir, session_id).join(format!("stage-{}.html", stage_number))
+    }
+}
@@ -0,0 +1,76 @@
+use chrono::{Duration, NaiveDate};
+
+use crate::domain::models::storage::DailyActivity;
+
+/// One-line practice summary for shell prompts and status bars
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StatusSummary {
+    pub streak: usize,
+    pub today_sessions: usize,
+    pub today_wpm: f64,
+    pub today_best_wpm: f64,
+    pub today_accuracy: f64,
+    pub today_keystrokes: usize,
+}
+
+impl StatusSummary {
+    pub const DEFAULT_FORMAT: &'static str = "🔥{streak} ⌨ {today_wpm}wpm";
+
+    pub const PLACEHOLDERS: [&'static str; 6] = [
+        "streak",
+        "today_sessions",
+        "today_wpm",
+        "today_best_wpm",
+        "today_accuracy",
+        "today_keystrokes",
+    ];
+
+    /// `activity` must be ordered most recent day first
+    pub fn from_daily_activity(activity: &[DailyActivity], today: NaiveDate) -> Self {
+        let today_activity = activity.iter().find(|day| day.date == today);
+
+        Self {
+            streak: Self::streak(activity, today),
+            today_sessions: today_activity.map_or(0, |day| day.sessions),
+            today_wpm: today_activity.map_or(0.0, |day| day.avg_wpm),
+            today_best_wpm: today_activity.map_or(0.0, |day| day.best_wpm),
+            today_accuracy: today_activity.map_or(0.0, |day| day.avg_accuracy),
+            today_keystrokes: today_activity.map_or(0, |day| day.keystrokes),
+        }
+    }
+
+    /// Replace `{placeholder}`s in `format`; unknown placeholders are left untouched
+    pub fn render(&self, format: &str) -> String {
+        Self::PLACEHOLDERS
+            .iter()
+            .fold(format.to_string(), |line, name| {
+                line.replace(&format!("{{{}}}", name), &self.value(name))
+            })
+    }
+
+    fn value(&self, name: &str) -> String {
+        match name {
+            "streak" => self.streak.to_string(),
+            "today_sessions" => self.today_sessions.to_string(),
+            "today_wpm" => format!("{:.0}", self.today_wpm),
+            "today_best_wpm" => format!("{:.0}", self.today_best_wpm),
+            "today_accuracy" => format!("{:.0}", self.today_accuracy),
+            "today_keystrokes" => self.today_keystrokes.to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// Consecutive practice days ending today, or yesterday if today has no session yet
+    fn streak(activity: &[DailyActivity], today: NaiveDate) -> usize {
+        let start = match activity.first().map(|day| day.date) {
+            Some(date) if date == today || date == today - Duration::days(1) => date,
+            _ => return 0,
+        };
+
+        activity
+            .iter()
+            .zip(0..)
+            .take_while(|(day, offset)| day.date == start - Duration::days(*offset))
+            .count()
+    }
+}
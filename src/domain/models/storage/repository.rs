@@ -1,11 +1,16 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
 use crate::infrastructure::git::git_repository_ref_parser::GitRepositoryRefParser;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StoredRepository {
     pub id: i64,
     pub user_name: String,
     pub repository_name: String,
     pub remote_url: String,
+    /// SPDX expression detected when the repository was last played
+    pub license: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -15,9 +20,39 @@ pub struct StoredRepositoryWithLanguages {
     pub repository_name: String,
     pub remote_url: String,
     pub languages: Vec<String>,
+    pub metadata: Option<RepositoryMetadata>,
+    /// Start of the most recent session, `None` if never played
+    pub last_played_at: Option<DateTime<Utc>>,
+    /// `None` until a session of the repository is rated
+    pub rating: Option<RepositoryRating>,
     // TODO: Add is_cached property and merge it in domain/repository logic
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepositoryMetadata {
+    pub description: Option<String>,
+    pub stars: u64,
+    pub default_branch: Option<String>,
+    pub primary_language: Option<String>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+/// The player's own ratings of a repository as practice material, one per rated session
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RepositoryRating {
+    pub average: f64,
+    pub count: usize,
+}
+
+/// A rated repository with the language GitHub reports for it, for ranking suggestions
+#[derive(Debug, Clone, PartialEq)]
+pub struct RatedRepository {
+    pub user_name: String,
+    pub repository_name: String,
+    pub primary_language: Option<String>,
+    pub rating: RepositoryRating,
+}
+
 impl StoredRepositoryWithLanguages {
     // TODO: Remove this and add http_url property
     pub fn http_url(&self) -> String {
@@ -26,3 +61,43 @@ impl StoredRepositoryWithLanguages {
             .unwrap_or_else(|_| self.remote_url.clone())
     }
 }
+
+impl RepositoryMetadata {
+    pub const TTL_HOURS: i64 = 24;
+
+    pub fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        now - self.fetched_at < Duration::hours(Self::TTL_HOURS)
+    }
+
+    /// Compact star count such as `★ 987` or `★ 12.3k`
+    pub fn stars_label(&self) -> String {
+        match self.stars {
+            stars if stars >= 1_000_000 => format!("★ {:.1}M", stars as f64 / 1_000_000.0),
+            stars if stars >= 1_000 => format!("★ {:.1}k", stars as f64 / 1_000.0),
+            stars => format!("★ {}", stars),
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        self.primary_language
+            .as_ref()
+            .map(|language| format!("{} · {}", self.stars_label(), language))
+            .unwrap_or_else(|| self.stars_label())
+    }
+}
+
+impl RepositoryRating {
+    pub const MIN: u8 = 1;
+    pub const MAX: u8 = 5;
+
+    /// Such as `4.5/5 (2 ratings)`
+    pub fn label(&self) -> String {
+        format!(
+            "{:.1}/{} ({} rating{})",
+            self.average,
+            Self::MAX,
+            self.count,
+            if self.count == 1 { "" } else { "s" }
+        )
+    }
+}
@@ -1,8 +1,13 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
 
-use crate::domain::models::{Challenge, StageResult};
+use std::time::Duration;
 
-#[derive(Debug, Clone)]
+use crate::domain::models::{
+    Challenge, DifficultyLevel, KeyLoad, MistakeBreakdown, RaceBot, StageResult, WpmMode,
+};
+
+#[derive(Debug, Clone, Serialize)]
 pub struct StoredSession {
     pub id: i64,
     pub repository_id: Option<i64>,
@@ -15,10 +20,55 @@ pub struct StoredSession {
     pub difficulty_level: Option<String>,
     pub max_stages: Option<i32>,
     pub time_limit_seconds: Option<i32>,
+    /// Profile that played the session; `None` for sessions recorded before profiles
+    pub player_name: Option<String>,
+    /// Marked implausible when it was played, see `ResultPlausibility`
+    pub unverified: bool,
+}
+
+/// Which finished sessions to read; the dates are inclusive local days
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionHistoryFilter {
+    pub repository_id: Option<i64>,
+    pub since: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+}
+
+/// Aggregated session results for a single local day
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyActivity {
+    pub date: NaiveDate,
+    pub sessions: usize,
+    pub avg_wpm: f64,
+    pub best_wpm: f64,
+    pub avg_accuracy: f64,
+    pub keystrokes: usize,
+}
+
+/// All-time aggregates of completed stages in a single language
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageActivity {
+    pub language: String,
+    pub stages: usize,
+    pub avg_wpm: f64,
+    pub best_wpm: f64,
+    pub avg_accuracy: f64,
+    pub keystrokes: usize,
+}
+
+/// A player's best session results in one repository
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaderboardEntry {
+    pub player_name: String,
+    pub best_score: f64,
+    pub best_wpm: f64,
+    pub best_accuracy: f64,
+    pub sessions: usize,
+    pub last_played_at: Option<DateTime<Utc>>,
 }
 
 /// Session stage result data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SessionStageResult {
     pub stage_number: i64,
     pub wpm: f64,
@@ -43,6 +93,61 @@ pub struct SessionStageResult {
     pub start_line: Option<i64>,
     pub end_line: Option<i64>,
     pub code_content: Option<String>,
+    pub challenge_id: Option<String>,
+    pub comment_ranges: Vec<(usize, usize)>,
+    /// Missing for stages recorded before mistakes were classified
+    pub mistake_breakdown: Option<MistakeBreakdown>,
+    /// Missing for stages recorded before keystrokes were counted per key
+    pub key_load: Option<KeyLoad>,
+}
+
+impl SessionStageResult {
+    /// The stage's challenge, to be played again; `None` when its code wasn't kept
+    pub fn challenge(&self) -> Option<Challenge> {
+        let code_content = self.code_content.clone()?;
+        let id = self
+            .challenge_id
+            .clone()
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        let mut challenge =
+            Challenge::new(id, code_content).with_comment_ranges(self.comment_ranges.clone());
+        if let (Some(file_path), Some(start_line), Some(end_line)) =
+            (&self.file_path, self.start_line, self.end_line)
+        {
+            challenge = challenge.with_source_info(
+                file_path.clone(),
+                start_line as usize,
+                end_line as usize,
+            );
+        }
+        if let Some(language) = &self.language {
+            challenge = challenge.with_language(language.clone());
+        }
+        if let Some(difficulty) = self
+            .difficulty_level
+            .as_deref()
+            .and_then(DifficultyLevel::from_name)
+        {
+            challenge = challenge.with_difficulty_level(difficulty);
+        }
+        Some(challenge)
+    }
+
+    /// Ghost that types the stage again at the pace of this attempt
+    pub fn ghost(&self, mode: WpmMode) -> RaceBot {
+        RaceBot::ghost(
+            self.wpm_in(mode),
+            self.accuracy,
+            Duration::from_millis(self.duration_ms),
+        )
+    }
+
+    /// WPM in `mode`, recomputed from the stage's code when it was kept
+    pub fn wpm_in(&self, mode: WpmMode) -> f64 {
+        self.code_content
+            .as_deref()
+            .map_or(self.wpm, |code| mode.wpm(self.cpm, code))
+    }
 }
 
 /// Detailed session stage result data with all fields
@@ -74,7 +179,7 @@ pub struct DetailedSessionStageResult {
 }
 
 /// Session result data aggregate
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SessionResultData {
     pub keystrokes: usize,
     pub mistakes: usize,
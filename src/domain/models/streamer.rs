@@ -0,0 +1,40 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::domain::models::WpmMode;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Whether screens should hide repository names, paths and commits; off until set from config
+pub struct StreamerMode;
+
+impl StreamerMode {
+    /// Shown in place of repository identifiers
+    pub const HIDDEN_REPOSITORY: &'static str = "Repository hidden";
+
+    pub fn is_enabled() -> bool {
+        ENABLED.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(enabled: bool) {
+        ENABLED.store(enabled, Ordering::Relaxed);
+    }
+}
+
+/// Live numbers written to the streamer stats file
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct StreamerStats {
+    pub wpm: f64,
+    pub accuracy: f64,
+}
+
+impl StreamerStats {
+    /// One metric per line, so OBS text sources can show the file as is
+    pub fn render(&self) -> String {
+        format!(
+            "{}: {:.0}\nAccuracy: {:.0}%\n",
+            WpmMode::current().label(),
+            self.wpm.max(0.0),
+            self.accuracy.clamp(0.0, 100.0)
+        )
+    }
+}
@@ -0,0 +1,56 @@
+use serde::Serialize;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::domain::models::storage::{DailyActivity, LanguageActivity};
+use crate::Result;
+
+/// Anonymous usage counts; never contains code, file contents, repository
+/// names, paths or the player's name
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TelemetryReport {
+    pub version: String,
+    pub os: String,
+    pub sessions: usize,
+    /// Completed stages per language
+    pub languages: BTreeMap<String, usize>,
+    /// Crash counts keyed by `crash_signature`
+    pub crash_signatures: BTreeMap<String, usize>,
+}
+
+impl TelemetryReport {
+    pub fn new(
+        daily_activity: &[DailyActivity],
+        language_activity: &[LanguageActivity],
+        crash_signatures: BTreeMap<String, usize>,
+    ) -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            sessions: daily_activity.iter().map(|day| day.sessions).sum(),
+            languages: language_activity
+                .iter()
+                .map(|activity| (activity.language.clone(), activity.stages))
+                .collect(),
+            crash_signatures,
+        }
+    }
+
+    /// Identifies a crash by version and source location only, e.g.
+    /// `1.2.0 screens/typing_screen.rs:42`; panic messages may quote code and
+    /// full paths may contain the user's name, so neither is kept
+    pub fn crash_signature(file: &str, line: u32) -> String {
+        let components: Vec<&str> = Path::new(file)
+            .components()
+            .filter_map(|component| component.as_os_str().to_str())
+            .collect();
+        let short_path = components[components.len().saturating_sub(2)..].join("/");
+        format!("{} {}:{}", env!("CARGO_PKG_VERSION"), short_path, line)
+    }
+
+    /// Exactly the body that is sent
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
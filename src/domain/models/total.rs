@@ -1,6 +1,8 @@
 use std::time::{Duration, Instant};
 
+use super::locale::Locale;
 use super::session::{Session, SessionResult};
+use super::wpm_mode::WpmMode;
 
 #[derive(Debug, Clone)]
 pub struct Total {
@@ -79,6 +81,17 @@ impl TotalResult {
         }
     }
 
+    /// Code tokens per character over all sessions, weighted by the characters
+    /// typed in each
+    pub fn token_density(&self) -> f64 {
+        WpmMode::mean_token_density(self.session_results.iter().map(|session| {
+            (
+                session.overall_cpm * session.valid_session_duration.as_secs_f64(),
+                session.token_density(),
+            )
+        }))
+    }
+
     pub fn get_completion_status(&self) -> String {
         match (self.total_sessions_completed, self.total_sessions_attempted) {
             (0, 0) => "No sessions attempted".to_string(),
@@ -100,6 +113,49 @@ impl TotalResult {
             self.total_duration.as_secs_f64() / 60.0
         )
     }
+
+    /// Plain-text result block printed after the TUI closes with `--summary-only`
+    pub fn create_summary_text(&self) -> String {
+        if self.total_sessions_attempted == 0 {
+            return "gittype: no sessions completed".to_string();
+        }
+
+        let locale = Locale::current();
+        let duration_secs = self.total_duration.as_secs();
+        [
+            "gittype session summary".to_string(),
+            format!(
+                "Sessions:   {}/{} completed",
+                self.total_sessions_completed, self.total_sessions_attempted
+            ),
+            format!(
+                "Stages:     {}/{} completed, {} skipped",
+                self.total_stages_completed, self.total_stages_attempted, self.total_stages_skipped
+            ),
+            format!("Score:      {}", locale.format_decimal(self.total_score, 0)),
+            format!(
+                "Speed:      {} WPM / {} CPM (best session {} WPM)",
+                locale.format_decimal(self.overall_wpm, 1),
+                locale.format_decimal(self.overall_cpm, 0),
+                locale.format_decimal(self.best_session_wpm, 1)
+            ),
+            format!(
+                "Accuracy:   {}%",
+                locale.format_decimal(self.overall_accuracy, 1)
+            ),
+            format!(
+                "Keystrokes: {} ({} mistakes)",
+                locale.format_count(self.total_keystrokes),
+                locale.format_count(self.total_mistakes)
+            ),
+            format!(
+                "Time:       {}m {:02}s",
+                duration_secs / 60,
+                duration_secs % 60
+            ),
+        ]
+        .join("\n")
+    }
 }
 
 impl Default for Total {
@@ -0,0 +1,204 @@
+use chrono::{DateTime, TimeZone, Utc};
+use serde::Serialize;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{GitTypeError, Result};
+
+/// Typing test site a baseline was exported from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BaselineSource {
+    Monkeytype,
+    Keybr,
+}
+
+/// One prose typing test imported from another site
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypingBaseline {
+    pub source: BaselineSource,
+    pub taken_at: DateTime<Utc>,
+    pub wpm: f64,
+    pub accuracy: f64,
+    pub duration_seconds: Option<f64>,
+}
+
+/// Aggregate of imported prose tests, compared against code typing in analytics
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BaselineSummary {
+    pub tests: usize,
+    pub avg_wpm: f64,
+    pub best_wpm: f64,
+    pub avg_accuracy: f64,
+    pub sources: Vec<BaselineSource>,
+}
+
+impl BaselineSource {
+    pub const ALL: [BaselineSource; 2] = [BaselineSource::Monkeytype, BaselineSource::Keybr];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BaselineSource::Monkeytype => "monkeytype",
+            BaselineSource::Keybr => "keybr",
+        }
+    }
+
+    /// Guess the source from a CSV header row
+    pub fn detect(header: &[String]) -> Option<Self> {
+        let has = |name: &str| header.iter().any(|column| column == name);
+        if has("wpm") && has("acc") {
+            Some(BaselineSource::Monkeytype)
+        } else if has("speed") && has("errors") {
+            Some(BaselineSource::Keybr)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for BaselineSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for BaselineSource {
+    type Err = GitTypeError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|source| source.as_str().eq_ignore_ascii_case(value))
+            .ok_or_else(|| {
+                GitTypeError::ValidationError(format!(
+                    "Unknown baseline source: {} (expected monkeytype or keybr)",
+                    value
+                ))
+            })
+    }
+}
+
+impl TypingBaseline {
+    /// Parse a monkeytype or keybr CSV export; the source is detected from the header when not given
+    pub fn parse_csv(contents: &str, source: Option<BaselineSource>) -> Result<Vec<Self>> {
+        let mut rows = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(Self::split_csv_line);
+        let header = rows
+            .next()
+            .ok_or_else(|| GitTypeError::ValidationError("CSV file is empty".to_string()))?;
+        let source = source
+            .or_else(|| BaselineSource::detect(&header))
+            .ok_or_else(|| {
+                GitTypeError::ValidationError(
+                    "Unrecognized CSV header; pass --source monkeytype or --source keybr"
+                        .to_string(),
+                )
+            })?;
+        let columns: HashMap<&str, usize> = header
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (name.as_str(), index))
+            .collect();
+
+        rows.enumerate()
+            .map(|(index, row)| {
+                let field = |name: &str| {
+                    columns
+                        .get(name)
+                        .and_then(|&column| row.get(column))
+                        .map(|value| value.trim())
+                        .filter(|value| !value.is_empty())
+                };
+                match source {
+                    BaselineSource::Monkeytype => Self::monkeytype_row(&field),
+                    BaselineSource::Keybr => Self::keybr_row(&field),
+                }
+                .ok_or_else(|| {
+                    GitTypeError::ValidationError(format!(
+                        "Invalid {} row on line {}",
+                        source,
+                        index + 2
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// monkeytype: `wpm`, `acc` (percent), `testDuration` (seconds), `timestamp` (epoch ms)
+    fn monkeytype_row<'a>(field: &impl Fn(&str) -> Option<&'a str>) -> Option<Self> {
+        Some(Self {
+            source: BaselineSource::Monkeytype,
+            taken_at: Self::parse_time(field("timestamp")?)?,
+            wpm: field("wpm")?.parse().ok()?,
+            accuracy: field("acc")?.parse().ok()?,
+            duration_seconds: field("testDuration").and_then(|value| value.parse().ok()),
+        })
+    }
+
+    /// keybr: `speed` (characters per minute), `length`, `errors`, `time` (ms), `timeStamp`
+    fn keybr_row<'a>(field: &impl Fn(&str) -> Option<&'a str>) -> Option<Self> {
+        let speed: f64 = field("speed")?.parse().ok()?;
+        let length: f64 = field("length")?.parse().ok()?;
+        let errors: f64 = field("errors")?.parse().ok()?;
+        let accuracy = if length > 0.0 {
+            ((length - errors) / length * 100.0).clamp(0.0, 100.0)
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            source: BaselineSource::Keybr,
+            taken_at: Self::parse_time(field("timeStamp")?)?,
+            wpm: speed / 5.0,
+            accuracy,
+            duration_seconds: field("time")
+                .and_then(|value| value.parse::<f64>().ok())
+                .map(|ms| ms / 1000.0),
+        })
+    }
+
+    /// Accepts epoch milliseconds or RFC 3339
+    fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+        value
+            .parse::<i64>()
+            .ok()
+            .and_then(|ms| Utc.timestamp_millis_opt(ms).single())
+            .or_else(|| {
+                DateTime::parse_from_rfc3339(value)
+                    .ok()
+                    .map(|time| time.with_timezone(&Utc))
+            })
+    }
+
+    fn split_csv_line(line: &str) -> Vec<String> {
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut chars = line.trim_end_matches('\r').chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match (c, in_quotes) {
+                ('"', true) if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                ('"', _) => in_quotes = !in_quotes,
+                (',', false) => fields.push(std::mem::take(&mut field)),
+                _ => field.push(c),
+            }
+        }
+        fields.push(field);
+        fields
+    }
+}
+
+impl BaselineSummary {
+    /// Code typing speed as a percentage of prose speed, e.g. 65.0 when code is slower
+    pub fn code_to_prose_ratio(&self, code_wpm: f64) -> Option<f64> {
+        (self.avg_wpm > 0.0).then(|| code_wpm / self.avg_wpm * 100.0)
+    }
+}
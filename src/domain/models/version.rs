@@ -1,10 +1,72 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::domain::models::config::ReleaseChannel;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VersionCacheEntry {
     pub latest_version: String,
     pub current_version: String,
     pub update_available: bool,
     pub last_checked: DateTime<Utc>,
+    #[serde(default)]
+    pub channel: ReleaseChannel,
+    /// Notes of `latest_version`, kept so they are fetched only once
+    #[serde(default)]
+    pub release_notes: Option<ReleaseNotes>,
+}
+
+/// A published release and the highlights of its notes, as previewed in
+/// the title screen's update banner
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReleaseNotes {
+    pub version: String,
+    pub url: String,
+    pub highlights: Vec<String>,
+}
+
+impl ReleaseNotes {
+    /// Bullets previewed before pointing at the full notes
+    pub const MAX_HIGHLIGHTS: usize = 3;
+
+    /// Notes without highlights, e.g. for a version cached before notes were kept
+    pub fn without_highlights(version: &str) -> Self {
+        Self {
+            version: version.to_string(),
+            url: Self::release_url(version),
+            highlights: Vec::new(),
+        }
+    }
+
+    /// Take the first bullets of a Markdown release body as highlights,
+    /// dropping GitHub's "by @author in <PR URL>" attributions
+    pub fn from_markdown(version: &str, url: Option<&str>, body: &str) -> Self {
+        let highlights = body
+            .lines()
+            .filter_map(|line| {
+                let line = line.trim();
+                line.strip_prefix("- ").or_else(|| line.strip_prefix("* "))
+            })
+            .map(|item| {
+                let item = item.split(" by @").next().unwrap_or(item);
+                item.replace("**", "").trim().to_string()
+            })
+            .filter(|item| !item.is_empty())
+            .take(Self::MAX_HIGHLIGHTS)
+            .collect();
+        Self {
+            version: version.to_string(),
+            url: url
+                .map(str::to_string)
+                .unwrap_or_else(|| Self::release_url(version)),
+            highlights,
+        }
+    }
+
+    fn release_url(version: &str) -> String {
+        format!(
+            "https://github.com/unhappychoice/gittype/releases/tag/v{}",
+            version
+        )
+    }
 }
@@ -0,0 +1,143 @@
+use crate::domain::models::loading::StepType;
+use crate::domain::models::Locale;
+
+/// Where one repository of a warm run is
+#[derive(Debug, Clone, PartialEq)]
+pub enum WarmStatus {
+    Queued,
+    Running { step: StepType, progress: f64 },
+    Done { challenges: usize, from_cache: bool },
+    Failed(String),
+}
+
+/// Per-repository progress of `gittype warm`, rendered as one line per repository
+#[derive(Debug, Clone, PartialEq)]
+pub struct WarmProgress {
+    rows: Vec<(String, WarmStatus)>,
+}
+
+impl WarmProgress {
+    pub fn new(repositories: &[String]) -> Self {
+        Self {
+            rows: repositories
+                .iter()
+                .map(|repository| (repository.clone(), WarmStatus::Queued))
+                .collect(),
+        }
+    }
+
+    pub fn status(&self, index: usize) -> Option<&WarmStatus> {
+        self.rows.get(index).map(|(_, status)| status)
+    }
+
+    pub fn set_step(&mut self, index: usize, step: StepType) {
+        self.set_status(
+            index,
+            WarmStatus::Running {
+                step,
+                progress: 0.0,
+            },
+        );
+    }
+
+    pub fn set_progress(&mut self, index: usize, step: StepType, processed: usize, total: usize) {
+        let progress = if total > 0 {
+            (processed as f64 / total as f64).min(1.0)
+        } else {
+            0.0
+        };
+        self.set_status(index, WarmStatus::Running { step, progress });
+    }
+
+    pub fn finish(&mut self, index: usize, challenges: usize, from_cache: bool) {
+        self.set_status(
+            index,
+            WarmStatus::Done {
+                challenges,
+                from_cache,
+            },
+        );
+    }
+
+    pub fn fail(&mut self, index: usize, message: &str) {
+        let message = message.lines().next().unwrap_or_default().to_string();
+        self.set_status(index, WarmStatus::Failed(message));
+    }
+
+    pub fn failed_count(&self) -> usize {
+        self.rows
+            .iter()
+            .filter(|(_, status)| matches!(status, WarmStatus::Failed(_)))
+            .count()
+    }
+
+    /// One line per repository, cut to `width` columns so redrawing in place stays aligned
+    pub fn render(&self, width: usize) -> Vec<String> {
+        let name_width = self
+            .rows
+            .iter()
+            .map(|(name, _)| name.chars().count())
+            .max()
+            .unwrap_or(0);
+        self.rows
+            .iter()
+            .map(|(name, status)| {
+                let line = format!(
+                    "{} {:<name_width$}  {}",
+                    Self::icon(status),
+                    name,
+                    Self::detail(status)
+                );
+                line.chars()
+                    .take(width)
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    fn set_status(&mut self, index: usize, status: WarmStatus) {
+        if let Some((_, current)) = self.rows.get_mut(index) {
+            *current = status;
+        }
+    }
+
+    fn icon(status: &WarmStatus) -> &'static str {
+        match status {
+            WarmStatus::Queued => "◦",
+            WarmStatus::Running { .. } => "»",
+            WarmStatus::Done { .. } => "✓",
+            WarmStatus::Failed(_) => "✗",
+        }
+    }
+
+    fn detail(status: &WarmStatus) -> String {
+        match status {
+            WarmStatus::Queued => "queued".to_string(),
+            WarmStatus::Running { step, progress } => {
+                format!("{:<14} {:>3.0}%", Self::step_label(step), progress * 100.0)
+            }
+            WarmStatus::Done {
+                challenges,
+                from_cache,
+            } => format!(
+                "{} challenges{}",
+                Locale::current().format_count(*challenges),
+                if *from_cache { " (already cached)" } else { "" }
+            ),
+            WarmStatus::Failed(message) => message.clone(),
+        }
+    }
+
+    fn step_label(step: &StepType) -> &'static str {
+        match step {
+            StepType::Cloning => "cloning",
+            StepType::CacheCheck => "checking cache",
+            StepType::Scanning => "scanning",
+            StepType::Extracting => "extracting",
+            StepType::Generating => "generating",
+            _ => "preparing",
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::path::{Path, PathBuf};
+
+/// Build tool whose workspace manifest lists a package
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WorkspaceKind {
+    Cargo,
+    Npm,
+    Go,
+}
+
+impl WorkspaceKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            WorkspaceKind::Cargo => "cargo",
+            WorkspaceKind::Npm => "npm",
+            WorkspaceKind::Go => "go",
+        }
+    }
+}
+
+/// A member of a Cargo workspace, an npm workspace or a go.work file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspacePackage {
+    pub name: String,
+    /// Directory of the package, relative to the repository root
+    pub path: PathBuf,
+    pub kind: WorkspaceKind,
+}
+
+impl WorkspacePackage {
+    /// Whether `query` is the package name or its directory, with or without
+    /// a leading `./` or trailing slash
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim();
+        let directory = query
+            .strip_prefix("./")
+            .unwrap_or(query)
+            .trim_end_matches('/');
+        self.name == query || self.path == Path::new(directory)
+    }
+
+    /// Whether a repository-relative file belongs to the package
+    pub fn contains(&self, relative_path: &Path) -> bool {
+        relative_path.starts_with(&self.path)
+    }
+}
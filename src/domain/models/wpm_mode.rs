@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+use std::sync::RwLock;
+
+static CURRENT: RwLock<WpmMode> = RwLock::new(WpmMode::Characters);
+
+/// What one "word" of typing speed stands for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WpmMode {
+    /// Five characters, the classic prose definition
+    #[default]
+    Characters,
+    /// One code token: an identifier, keyword or number, or a single symbol
+    Tokens,
+}
+
+impl WpmMode {
+    pub fn current() -> WpmMode {
+        *CURRENT.read().unwrap()
+    }
+
+    pub fn set_current(mode: WpmMode) {
+        *CURRENT.write().unwrap() = mode;
+    }
+
+    /// Unit shown next to speeds computed in this mode
+    pub fn label(&self) -> &'static str {
+        match self {
+            WpmMode::Characters => "WPM",
+            WpmMode::Tokens => "TPM",
+        }
+    }
+
+    /// Words per minute for typing `text` at `cpm`; token mode scales the
+    /// speed by how many tokens the text packs into each character
+    pub fn wpm(&self, cpm: f64, text: &str) -> f64 {
+        self.wpm_at_density(cpm, Self::token_density(text))
+    }
+
+    /// Words per minute for typing at `cpm` a text with `token_density` tokens per character
+    pub fn wpm_at_density(&self, cpm: f64, token_density: f64) -> f64 {
+        match self {
+            WpmMode::Characters => cpm / 5.0,
+            WpmMode::Tokens => cpm * token_density,
+        }
+    }
+
+    /// A recorded WPM, which always counts five-character words, in this mode
+    pub fn from_recorded(&self, wpm: f64, token_density: f64) -> f64 {
+        self.wpm_at_density(wpm * 5.0, token_density)
+    }
+
+    /// Code tokens per character of `text`, 0 for an empty text
+    pub fn token_density(text: &str) -> f64 {
+        match text.chars().count() {
+            0 => 0.0,
+            chars => Self::count_tokens(text) as f64 / chars as f64,
+        }
+    }
+
+    /// Words per minute at `cpm` over several texts, each given as the
+    /// (wpm, cpm, seconds) it was typed at, weighting their token density by time
+    pub fn wpm_across(&self, cpm: f64, parts: impl IntoIterator<Item = (f64, f64, f64)>) -> f64 {
+        match self {
+            WpmMode::Characters => cpm / 5.0,
+            WpmMode::Tokens => {
+                let (words, chars) =
+                    parts
+                        .into_iter()
+                        .fold((0.0, 0.0), |(words, chars), (wpm, part_cpm, secs)| {
+                            (words + wpm * secs, chars + part_cpm * secs)
+                        });
+                if chars > 0.0 {
+                    cpm * words / chars
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+
+    /// Mean token density of `(characters, density)` parts, weighted by characters
+    pub fn mean_token_density(parts: impl IntoIterator<Item = (f64, f64)>) -> f64 {
+        let (tokens, chars) =
+            parts
+                .into_iter()
+                .fold((0.0, 0.0), |(tokens, chars), (part_chars, density)| {
+                    (tokens + part_chars * density, chars + part_chars)
+                });
+        if chars > 0.0 {
+            tokens / chars
+        } else {
+            0.0
+        }
+    }
+
+    /// Runs of letters, digits and `_` count once; every other visible character counts alone
+    pub fn count_tokens(text: &str) -> usize {
+        text.chars()
+            .fold((0, false), |(count, in_word), ch| {
+                if ch.is_alphanumeric() || ch == '_' {
+                    (count + usize::from(!in_word), true)
+                } else {
+                    (count + usize::from(!ch.is_whitespace()), false)
+                }
+            })
+            .0
+    }
+}
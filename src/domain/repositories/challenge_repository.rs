@@ -1,10 +1,12 @@
+use crate::domain::models::loading::ProgressReporter;
 use crate::domain::models::loading::StepType;
-use crate::domain::models::{Challenge, DifficultyLevel, GitRepository};
+use crate::domain::models::{Challenge, DifficultyLevel, GitRepository, Languages};
+use crate::domain::services::source_code_parser::SecretScanner;
+use crate::domain::services::text_processor::TextProcessor;
 use crate::infrastructure::storage::compressed_file_storage::{
     CompressedFileStorage, CompressedFileStorageInterface,
 };
 use crate::infrastructure::storage::file_storage::FileStorageInterface;
-use crate::presentation::tui::screens::loading_screen::ProgressReporter;
 use crate::Result;
 use rayon::prelude::*;
 use shaku::Interface;
@@ -26,6 +28,8 @@ struct ChallengePointer {
 struct CacheData {
     repo_key: String,
     commit_hash: String,
+    /// Checkout the challenges were extracted from, so a deleted clone's cache can be found
+    repo_root: Option<PathBuf>,
     challenge_pointers: Vec<ChallengePointer>,
 }
 
@@ -47,6 +51,9 @@ pub trait ChallengeRepositoryInterface: Interface {
     fn clear_cache(&self) -> Result<()>;
     fn invalidate_repository(&self, repo: &GitRepository) -> Result<bool>;
     fn list_cache_keys(&self) -> Result<Vec<String>>;
+    fn count_cached_challenges(&self, repo_key: &str) -> Result<usize>;
+    fn invalidate_repository_key(&self, repo_key: &str) -> Result<usize>;
+    fn remove_stale_caches(&self) -> Result<(usize, u64)>;
 }
 
 #[derive(Debug, Clone, shaku::Component)]
@@ -98,6 +105,7 @@ impl ChallengeRepository {
         let cache_data = CacheData {
             repo_key: repo.cache_key(),
             commit_hash: commit_str.to_string(),
+            repo_root: repo.root_path.clone(),
             challenge_pointers,
         };
 
@@ -198,6 +206,72 @@ impl ChallengeRepository {
     }
 
     pub fn list_cache_keys(&self) -> Result<Vec<String>> {
+        let mut keys: Vec<String> = self
+            .load_cache_entries()?
+            .into_iter()
+            .map(|(_, d)| format!("{}:{}", d.repo_key, d.commit_hash))
+            .collect();
+
+        keys.sort();
+        keys.dedup();
+        Ok(keys)
+    }
+
+    /// Challenges cached for a repository across every commit it was played at
+    pub fn count_cached_challenges(&self, repo_key: &str) -> Result<usize> {
+        Ok(self
+            .load_cache_entries()?
+            .iter()
+            .filter(|(_, d)| d.repo_key == repo_key)
+            .map(|(_, d)| d.challenge_pointers.len())
+            .sum())
+    }
+
+    /// Remove every cache file for a repository, returning how many were deleted
+    pub fn invalidate_repository_key(&self, repo_key: &str) -> Result<usize> {
+        let paths: Vec<PathBuf> = self
+            .load_cache_entries()?
+            .into_iter()
+            .filter(|(_, d)| d.repo_key == repo_key)
+            .map(|(path, _)| path)
+            .collect();
+        for path in &paths {
+            self.storage.delete_file(path)?;
+        }
+        Ok(paths.len())
+    }
+
+    /// Remove cache files whose checkout no longer exists, along with any this
+    /// version can't read, returning how many were deleted and their bytes
+    pub fn remove_stale_caches(&self) -> Result<(usize, u64)> {
+        let cache_dir = self.effective_cache_dir();
+        let storage = (self.storage.as_ref() as &dyn std::any::Any)
+            .downcast_ref::<CompressedFileStorage>()
+            .ok_or_else(|| {
+                crate::GitTypeError::ExtractionFailed("Failed to downcast storage".to_string())
+            })?;
+
+        let stale: Vec<PathBuf> = self
+            .storage
+            .list_files_in_dir(&cache_dir)
+            .into_iter()
+            .filter(|path| path.extension().is_some_and(|ext| ext == "bin"))
+            .filter(|path| match storage.load::<CacheData>(path) {
+                Ok(Some(data)) => data.repo_root.is_some_and(|root| !root.exists()),
+                Ok(None) => false,
+                Err(_) => true,
+            })
+            .collect();
+
+        let mut freed = 0;
+        for path in &stale {
+            freed += self.storage.get_file_size(path).unwrap_or(0);
+            self.storage.delete_file(path)?;
+        }
+        Ok((stale.len(), freed))
+    }
+
+    fn load_cache_entries(&self) -> Result<Vec<(PathBuf, CacheData)>> {
         let cache_dir = self.effective_cache_dir();
         let files = self.storage.list_files_in_dir(&cache_dir);
 
@@ -207,24 +281,21 @@ impl ChallengeRepository {
                 crate::GitTypeError::ExtractionFailed("Failed to downcast storage".to_string())
             })?;
 
-        let mut keys: Vec<String> = files
-            .iter()
+        Ok(files
+            .into_iter()
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.ends_with(".bin"))
+            })
             .filter_map(|path| {
-                if path.file_name()?.to_str()?.ends_with(".bin") {
-                    storage
-                        .load::<CacheData>(path)
-                        .ok()
-                        .flatten()
-                        .map(|d| format!("{}:{}", d.repo_key, d.commit_hash))
-                } else {
-                    None
-                }
+                storage
+                    .load::<CacheData>(&path)
+                    .ok()
+                    .flatten()
+                    .map(|d| (path, d))
             })
-            .collect();
-
-        keys.sort();
-        keys.dedup();
-        Ok(keys)
+            .collect())
     }
 
     fn reconstruct_challenge(
@@ -251,12 +322,13 @@ impl ChallengeRepository {
 
         let file_content = self
             .file_storage
-            .read_to_string(&absolute_path)
+            .read(&absolute_path)
             .map_err(|e| {
                 log::debug!("Failed to read file {}: {}", file_path, e);
                 e
             })
-            .ok()?;
+            .ok()
+            .map(|bytes| TextProcessor::decode_source(&bytes).0)?;
 
         let lines: Vec<&str> = file_content.lines().collect();
 
@@ -277,6 +349,9 @@ impl ChallengeRepository {
             }
             _ => file_content,
         };
+        // Caches written before secrets were redacted may still point at them
+        let code_content =
+            SecretScanner::redact_text(code_content, Languages::extraction().secrets, file_path)?;
 
         Some(Challenge {
             id: pointer.id.clone(),
@@ -355,4 +430,16 @@ impl ChallengeRepositoryInterface for ChallengeRepository {
     fn list_cache_keys(&self) -> Result<Vec<String>> {
         ChallengeRepository::list_cache_keys(self)
     }
+
+    fn count_cached_challenges(&self, repo_key: &str) -> Result<usize> {
+        ChallengeRepository::count_cached_challenges(self, repo_key)
+    }
+
+    fn invalidate_repository_key(&self, repo_key: &str) -> Result<usize> {
+        ChallengeRepository::invalidate_repository_key(self, repo_key)
+    }
+
+    fn remove_stale_caches(&self) -> Result<(usize, u64)> {
+        ChallengeRepository::remove_stale_caches(self)
+    }
 }
@@ -1,13 +1,17 @@
 pub mod challenge_repository;
 pub mod git_repository_repository;
+pub mod repository_metadata_repository;
 pub mod session_repository;
 pub mod stage_repository;
+pub mod starred_repository;
 pub mod trending_repository;
 pub mod version_repository;
 
 pub use challenge_repository::ChallengeRepository;
 pub use git_repository_repository::GitRepositoryRepository;
+pub use repository_metadata_repository::RepositoryMetadataRepository;
 pub use session_repository::SessionRepository;
 pub use stage_repository::StageRepository;
+pub use starred_repository::StarredRepository;
 pub use trending_repository::TrendingRepository;
 pub use version_repository::VersionRepository;
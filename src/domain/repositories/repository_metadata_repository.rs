@@ -0,0 +1,131 @@
+use chrono::Utc;
+use shaku::Interface;
+
+use std::sync::Arc;
+
+use crate::domain::models::storage::RepositoryMetadata;
+use crate::domain::models::{GitRepository, GitRepositoryRef};
+use crate::infrastructure::database::daos::RepositoryDaoInterface;
+use crate::infrastructure::git::GitRepositoryRefParser;
+use crate::infrastructure::http::github_api_client::GitHubApiClientFactory;
+use crate::infrastructure::http::GitHubRepositoryInfo;
+use crate::Result;
+
+const GITHUB_ORIGIN: &str = "github.com";
+
+pub trait RepositoryMetadataRepositoryInterface: Interface {
+    fn get_cached_metadata(&self, git_repo: &GitRepository) -> Result<Option<RepositoryMetadata>>;
+    fn refresh_metadata(&self, git_repo: &GitRepository) -> Result<Option<RepositoryMetadata>>;
+    fn refresh_all_metadata(&self) -> Result<()>;
+}
+
+/// GitHub metadata (stars, description, ...) for played repositories, cached in the database
+#[derive(shaku::Component)]
+#[shaku(interface = RepositoryMetadataRepositoryInterface)]
+pub struct RepositoryMetadataRepository {
+    #[shaku(inject)]
+    github_client_factory: Arc<dyn GitHubApiClientFactory>,
+    #[shaku(inject)]
+    repository_dao: Arc<dyn RepositoryDaoInterface>,
+}
+
+impl RepositoryMetadataRepository {
+    pub fn new(
+        github_client_factory: Arc<dyn GitHubApiClientFactory>,
+        repository_dao: Arc<dyn RepositoryDaoInterface>,
+    ) -> Self {
+        Self {
+            github_client_factory,
+            repository_dao,
+        }
+    }
+
+    fn github_ref(remote_url: &str) -> Option<GitRepositoryRef> {
+        GitRepositoryRefParser::parse(remote_url)
+            .ok()
+            .filter(|repo_ref| repo_ref.origin == GITHUB_ORIGIN)
+    }
+
+    /// Falls back to stale cached metadata when the API is unreachable
+    fn refresh_stored(
+        &self,
+        repository_id: i64,
+        repo_ref: &GitRepositoryRef,
+    ) -> Result<Option<RepositoryMetadata>> {
+        let cached = self.repository_dao.get_repository_metadata(repository_id)?;
+        if cached
+            .as_ref()
+            .is_some_and(|metadata| metadata.is_fresh(Utc::now()))
+        {
+            return Ok(cached);
+        }
+
+        match self.fetch_from_api(repo_ref) {
+            Ok(metadata) => {
+                self.repository_dao
+                    .update_repository_metadata(repository_id, &metadata)?;
+                Ok(Some(metadata))
+            }
+            Err(e) => {
+                log::warn!(
+                    "Failed to fetch GitHub metadata for {}/{}: {}",
+                    repo_ref.owner,
+                    repo_ref.name,
+                    e
+                );
+                Ok(cached)
+            }
+        }
+    }
+
+    fn fetch_from_api(&self, repo_ref: &GitRepositoryRef) -> Result<RepositoryMetadata> {
+        let client = self.github_client_factory.create()?;
+        let rt = tokio::runtime::Runtime::new()?;
+        let info = rt.block_on(client.fetch_repository(&repo_ref.owner, &repo_ref.name))?;
+        Ok(Self::to_metadata(info))
+    }
+
+    fn to_metadata(info: GitHubRepositoryInfo) -> RepositoryMetadata {
+        RepositoryMetadata {
+            description: info.description,
+            stars: info.stargazers_count,
+            default_branch: Some(info.default_branch),
+            primary_language: info.language,
+            fetched_at: Utc::now(),
+        }
+    }
+}
+
+impl RepositoryMetadataRepositoryInterface for RepositoryMetadataRepository {
+    /// Metadata stored by a previous fetch, without touching the network
+    fn get_cached_metadata(&self, git_repo: &GitRepository) -> Result<Option<RepositoryMetadata>> {
+        match self
+            .repository_dao
+            .find_repository(&git_repo.user_name, &git_repo.repository_name)?
+        {
+            Some(stored) => self.repository_dao.get_repository_metadata(stored.id),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetch metadata from GitHub unless the cached copy is still fresh
+    fn refresh_metadata(&self, git_repo: &GitRepository) -> Result<Option<RepositoryMetadata>> {
+        let Some(repo_ref) = Self::github_ref(&git_repo.remote_url) else {
+            return Ok(None);
+        };
+
+        let repository_id = self.repository_dao.ensure_repository(git_repo)?;
+        self.refresh_stored(repository_id, &repo_ref)
+    }
+
+    /// Refresh stale metadata for every stored GitHub repository
+    fn refresh_all_metadata(&self) -> Result<()> {
+        self.repository_dao
+            .get_all_repositories()?
+            .iter()
+            .filter_map(|stored| Self::github_ref(&stored.remote_url).map(|r| (stored.id, r)))
+            .try_for_each(|(repository_id, repo_ref)| {
+                self.refresh_stored(repository_id, &repo_ref).map(|_| ())
+            })
+    }
+}
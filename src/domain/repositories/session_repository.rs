@@ -2,10 +2,12 @@ use std::sync::Arc;
 
 use crate::domain::error::GitTypeError;
 use crate::domain::models::storage::{
-    SaveSessionResultParams, SaveStageParams, SessionResultData, SessionStageResult,
-    StoredRepository, StoredSession,
+    LanguageActivity, SaveSessionResultParams, SaveStageParams, SessionResultData,
+    SessionStageResult, StoredRepository, StoredSession,
+};
+use crate::domain::models::{
+    Challenge, GitRepository, KeyLoad, LanguageCalibrations, PlayerProfile, SessionResult,
 };
-use crate::domain::models::{Challenge, GitRepository, SessionResult};
 use crate::domain::services::scoring::{StageCalculator, StageResult, StageTracker};
 use crate::infrastructure::database::daos::{
     ChallengeDao, ChallengeDaoInterface, RepositoryDao, RepositoryDaoInterface, SessionDao,
@@ -28,6 +30,8 @@ pub trait SessionRepositoryTrait: shaku::Interface {
     ) -> Result<i64>;
     fn get_session_stage_results(&self, session_id: i64) -> Result<Vec<SessionStageResult>>;
     fn get_all_repositories(&self) -> Result<Vec<StoredRepository>>;
+    fn get_repository(&self, repository_id: i64) -> Result<Option<StoredRepository>>;
+    fn get_session(&self, session_id: i64) -> Result<Option<StoredSession>>;
     fn get_sessions_filtered(
         &self,
         repository_filter: Option<i64>,
@@ -41,6 +45,8 @@ pub trait SessionRepositoryTrait: shaku::Interface {
         &self,
         session_id: i64,
     ) -> Result<Option<SessionResultData>>;
+    /// The player's per-language pace, from verified completed stages
+    fn get_language_baselines(&self) -> Result<Vec<LanguageActivity>>;
 }
 
 /// Repository for session business logic
@@ -100,6 +106,7 @@ impl SessionRepositoryTrait for SessionRepository {
             git_repository,
             game_mode,
             difficulty_level,
+            PlayerProfile::active_player().as_deref(),
         )?;
 
         // 3. Save session result
@@ -171,6 +178,14 @@ impl SessionRepositoryTrait for SessionRepository {
         self.repository_dao.get_all_repositories()
     }
 
+    fn get_repository(&self, repository_id: i64) -> Result<Option<StoredRepository>> {
+        self.repository_dao.get_repository_by_id(repository_id)
+    }
+
+    fn get_session(&self, session_id: i64) -> Result<Option<StoredSession>> {
+        self.session_dao.get_session(session_id)
+    }
+
     fn get_sessions_filtered(
         &self,
         repository_filter: Option<i64>,
@@ -225,6 +240,10 @@ impl SessionRepositoryTrait for SessionRepository {
     ) -> Result<Option<SessionResultData>> {
         self.session_dao.get_session_result(session_id)
     }
+
+    fn get_language_baselines(&self) -> Result<Vec<LanguageActivity>> {
+        self.session_dao.get_language_baselines()
+    }
 }
 
 impl SessionRepository {
@@ -329,7 +348,8 @@ impl SessionRepository {
         Ok(())
     }
 
-    /// Record session using the global instance
+    /// Record session using the global instance; the new session id, or
+    /// `None` when the global instance is not initialized
     pub fn record_session_global(
         session_result: &SessionResult,
         git_repository: Option<&GitRepository>,
@@ -337,7 +357,7 @@ impl SessionRepository {
         difficulty_level: Option<&str>,
         stage_trackers: &[(String, StageTracker)],
         challenges: &[Challenge],
-    ) -> Result<()> {
+    ) -> Result<Option<i64>> {
         let global = Self::global();
         let guard = global
             .lock()
@@ -354,17 +374,17 @@ impl SessionRepository {
             ) {
                 Ok(session_id) => {
                     log::info!("Successfully recorded session with ID: {}", session_id);
+                    Ok(Some(session_id))
                 }
                 Err(e) => {
                     log::error!("Failed to record session to database: {}", e);
-                    return Err(e);
+                    Err(e)
                 }
             }
         } else {
             log::warn!("Session service not initialized, skipping database recording");
+            Ok(None)
         }
-
-        Ok(())
     }
 
     /// Get best records for comparison display
@@ -414,6 +434,36 @@ impl SessionRepository {
         }
     }
 
+    /// Per-language calibration using the global instance
+    pub fn get_language_calibrations_global() -> Result<Option<LanguageCalibrations>> {
+        let global = Self::global();
+        let guard = global
+            .lock()
+            .map_err(|e| GitTypeError::database_error(format!("Failed to acquire lock: {}", e)))?;
+
+        if let Some(service) = guard.as_ref() {
+            service
+                .get_language_baselines()
+                .map(|baselines| Some(LanguageCalibrations::from_activity(&baselines)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Keystrokes of every recorded stage per expected character, using the global instance
+    pub fn get_key_load_global() -> Result<Option<KeyLoad>> {
+        let global = Self::global();
+        let guard = global
+            .lock()
+            .map_err(|e| GitTypeError::database_error(format!("Failed to acquire lock: {}", e)))?;
+
+        if let Some(service) = guard.as_ref() {
+            service.session_dao.get_key_load().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Determine best status for a session using session start records
     pub fn determine_best_status_with_start_records(
         session_score: f64,
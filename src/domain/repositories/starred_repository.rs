@@ -0,0 +1,52 @@
+use shaku::Interface;
+
+use std::sync::Arc;
+
+use crate::infrastructure::http::github_api_client::GitHubApiClientFactory;
+use crate::infrastructure::http::GitHubStarredRepository;
+use crate::Result;
+
+pub trait StarredRepositoryInterface: Interface {
+    /// Repositories starred by the signed-in GitHub user, most recently starred first
+    fn get_starred_repositories_sync(&self) -> Result<Vec<GitHubStarredRepository>>;
+}
+
+/// Stars of the GitHub account `gh` is signed in to, offered as practice material
+#[derive(shaku::Component)]
+#[shaku(interface = StarredRepositoryInterface)]
+pub struct StarredRepository {
+    #[shaku(inject)]
+    github_client_factory: Arc<dyn GitHubApiClientFactory>,
+}
+
+impl StarredRepository {
+    const PER_PAGE: u32 = 100;
+
+    /// Stars beyond this many pages are left out
+    const MAX_PAGES: u32 = 5;
+
+    pub fn new(github_client_factory: Arc<dyn GitHubApiClientFactory>) -> Self {
+        Self {
+            github_client_factory,
+        }
+    }
+}
+
+impl StarredRepositoryInterface for StarredRepository {
+    fn get_starred_repositories_sync(&self) -> Result<Vec<GitHubStarredRepository>> {
+        let client = self.github_client_factory.create()?;
+        let rt = tokio::runtime::Runtime::new()?;
+        rt.block_on(async {
+            let mut repositories = Vec::new();
+            for page in 1..=Self::MAX_PAGES {
+                let starred = client.fetch_starred(page, Self::PER_PAGE).await?;
+                let last_page = (starred.len() as u32) < Self::PER_PAGE;
+                repositories.extend(starred);
+                if last_page {
+                    break;
+                }
+            }
+            Ok(repositories)
+        })
+    }
+}
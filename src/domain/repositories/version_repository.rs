@@ -6,14 +6,21 @@ use std::path::PathBuf;
 use std::pin::Pin;
 use std::sync::Arc;
 
-use crate::domain::models::version::VersionCacheEntry;
+use crate::domain::models::config::ReleaseChannel;
+use crate::domain::models::version::{ReleaseNotes, VersionCacheEntry};
 use crate::infrastructure::http::github_api_client::GitHubApiClientFactory;
 use crate::infrastructure::storage::app_data_provider::AppDataProvider;
 use crate::infrastructure::storage::file_storage::{FileStorage, FileStorageInterface};
 use crate::Result;
 
+type LatestReleaseFuture<'a> = Pin<Box<dyn Future<Output = Result<ReleaseNotes>> + Send + 'a>>;
+
 pub trait VersionRepositoryInterface: Interface {
-    fn fetch_latest_version(&self) -> Pin<Box<dyn Future<Output = Result<String>> + Send + '_>>;
+    fn fetch_latest_release(
+        &self,
+        channel: ReleaseChannel,
+        frequency_hours: u64,
+    ) -> LatestReleaseFuture<'_>;
 }
 
 #[derive(shaku::Component)]
@@ -50,8 +57,13 @@ impl VersionRepository {
     }
 
     #[cfg(feature = "test-mocks")]
-    pub fn is_cache_valid_for_test(&self, entry: &VersionCacheEntry, frequency_hours: u64) -> bool {
-        self.is_cache_valid(entry, frequency_hours)
+    pub fn is_cache_valid_for_test(
+        &self,
+        entry: &VersionCacheEntry,
+        channel: ReleaseChannel,
+        frequency_hours: u64,
+    ) -> bool {
+        self.is_cache_valid(entry, channel, frequency_hours)
     }
 
     #[cfg(feature = "test-mocks")]
@@ -59,31 +71,53 @@ impl VersionRepository {
         Self::normalize_version_tag(tag)
     }
 
-    /// Fetch the latest version from cache or API
-    pub async fn fetch_latest_version(&self) -> Result<String> {
-        const CHECK_FREQUENCY_HOURS: u64 = 24;
+    /// Fetch the latest version on `channel` from cache or API, hitting the API
+    /// at most once every `frequency_hours` even when it keeps failing
+    pub async fn fetch_latest_version(
+        &self,
+        channel: ReleaseChannel,
+        frequency_hours: u64,
+    ) -> Result<String> {
+        Ok(self
+            .fetch_latest_release(channel, frequency_hours)
+            .await?
+            .version)
+    }
 
-        // Check cache first
-        if let Some(cached_entry) = self.get_cached_version()? {
-            if self.is_cache_valid(&cached_entry, CHECK_FREQUENCY_HOURS) {
-                return Ok(cached_entry.latest_version);
-            }
+    /// Like `fetch_latest_version`, with the release's notes; they are cached
+    /// along with the version, so each release's notes are fetched once
+    pub async fn fetch_latest_release(
+        &self,
+        channel: ReleaseChannel,
+        frequency_hours: u64,
+    ) -> Result<ReleaseNotes> {
+        let cached_entry = self
+            .get_cached_version()?
+            .filter(|entry| entry.channel == channel);
+
+        if let Some(entry) = cached_entry
+            .as_ref()
+            .filter(|entry| self.is_cache_valid(entry, channel, frequency_hours))
+        {
+            return Ok(entry
+                .release_notes
+                .clone()
+                .filter(|notes| notes.version == entry.latest_version)
+                .unwrap_or_else(|| ReleaseNotes::without_highlights(&entry.latest_version)));
         }
 
-        // Fetch from API
-        match self.fetch_from_api().await {
-            Ok(latest_version) => {
-                self.save_to_cache(&latest_version)?;
-                Ok(latest_version)
+        match self.fetch_from_api(channel).await {
+            Ok(release) => {
+                self.save_to_cache(&release.version, Some(&release), channel)?;
+                Ok(release)
             }
             Err(e) => {
                 log::warn!("Failed to fetch latest version from API: {}", e);
-                // Fall back to cached version if available
-                if let Some(cached_entry) = self.get_cached_version()? {
-                    Ok(cached_entry.latest_version)
-                } else {
-                    Err(e)
-                }
+                let (fallback, notes) = cached_entry
+                    .map(|entry| (entry.latest_version, entry.release_notes))
+                    .unwrap_or_else(|| (env!("CARGO_PKG_VERSION").to_string(), None));
+                self.save_to_cache(&fallback, notes.as_ref(), channel)?;
+                Err(e)
             }
         }
     }
@@ -106,7 +140,12 @@ impl VersionRepository {
     }
 
     /// Save version information to cache
-    fn save_to_cache(&self, latest_version: &str) -> Result<()> {
+    fn save_to_cache(
+        &self,
+        latest_version: &str,
+        release_notes: Option<&ReleaseNotes>,
+        channel: ReleaseChannel,
+    ) -> Result<()> {
         if cfg!(debug_assertions) {
             return Ok(());
         }
@@ -116,6 +155,8 @@ impl VersionRepository {
             current_version: env!("CARGO_PKG_VERSION").to_string(),
             update_available: false, // not used
             last_checked: Utc::now(),
+            channel,
+            release_notes: release_notes.cloned(),
         };
 
         let cache_path = self.get_version_cache_path()?;
@@ -129,12 +170,25 @@ impl VersionRepository {
         file_storage.write_json(&cache_path, &entry)
     }
 
-    /// Fetch the latest version from GitHub API
-    async fn fetch_from_api(&self) -> Result<String> {
+    /// Fetch the latest release from GitHub API
+    async fn fetch_from_api(&self, channel: ReleaseChannel) -> Result<ReleaseNotes> {
         let github_client = self.github_client_factory.create()?;
-        let release = github_client.fetch_latest_release().await?;
-        let version = Self::normalize_version_tag(&release.tag_name);
-        Ok(version)
+        let release = match channel {
+            ReleaseChannel::Stable => github_client.fetch_latest_release().await?,
+            ReleaseChannel::PreRelease => github_client
+                .fetch_releases()
+                .await?
+                .into_iter()
+                .find(|release| !release.draft)
+                .ok_or_else(|| {
+                    crate::GitTypeError::ExtractionFailed("No releases published".to_string())
+                })?,
+        };
+        Ok(ReleaseNotes::from_markdown(
+            &Self::normalize_version_tag(&release.tag_name),
+            release.html_url.as_deref(),
+            release.body.as_deref().unwrap_or_default(),
+        ))
     }
 
     fn get_version_cache_path(&self) -> Result<PathBuf> {
@@ -143,7 +197,12 @@ impl VersionRepository {
     }
 
     /// Check if a cache entry is still valid
-    fn is_cache_valid(&self, entry: &VersionCacheEntry, frequency_hours: u64) -> bool {
+    fn is_cache_valid(
+        &self,
+        entry: &VersionCacheEntry,
+        channel: ReleaseChannel,
+        frequency_hours: u64,
+    ) -> bool {
         let now = chrono::Utc::now();
         let hours_since_check = (now - entry.last_checked).num_hours();
         let time_valid = hours_since_check < frequency_hours as i64;
@@ -152,7 +211,7 @@ impl VersionRepository {
         let current_version = env!("CARGO_PKG_VERSION");
         let version_valid = entry.current_version == current_version;
 
-        time_valid && version_valid
+        time_valid && version_valid && entry.channel == channel
     }
 
     /// Strip 'v' prefix from version tag if present
@@ -162,7 +221,15 @@ impl VersionRepository {
 }
 
 impl VersionRepositoryInterface for VersionRepository {
-    fn fetch_latest_version(&self) -> Pin<Box<dyn Future<Output = Result<String>> + Send + '_>> {
-        Box::pin(VersionRepository::fetch_latest_version(self))
+    fn fetch_latest_release(
+        &self,
+        channel: ReleaseChannel,
+        frequency_hours: u64,
+    ) -> LatestReleaseFuture<'_> {
+        Box::pin(VersionRepository::fetch_latest_release(
+            self,
+            channel,
+            frequency_hours,
+        ))
     }
 }
@@ -0,0 +1,52 @@
+use crate::domain::error::Result;
+use crate::domain::models::{Achievement, AchievementStatus};
+use crate::infrastructure::database::daos::{AchievementDaoInterface, StageDaoInterface};
+use shaku::Interface;
+use std::sync::Arc;
+
+pub trait AchievementServiceInterface: Interface {
+    /// Every achievement in display order, storing any newly earned from stage history
+    fn load_achievements(&self) -> Result<Vec<AchievementStatus>>;
+}
+
+#[derive(shaku::Component)]
+#[shaku(interface = AchievementServiceInterface)]
+pub struct AchievementService {
+    #[shaku(inject)]
+    stage_dao: Arc<dyn StageDaoInterface>,
+    #[shaku(inject)]
+    achievement_dao: Arc<dyn AchievementDaoInterface>,
+}
+
+impl AchievementService {
+    pub fn new(
+        stage_dao: Arc<dyn StageDaoInterface>,
+        achievement_dao: Arc<dyn AchievementDaoInterface>,
+    ) -> Self {
+        Self {
+            stage_dao,
+            achievement_dao,
+        }
+    }
+}
+
+impl AchievementServiceInterface for AchievementService {
+    fn load_achievements(&self) -> Result<Vec<AchievementStatus>> {
+        let stages = self.stage_dao.get_completed_stages(None)?;
+        self.achievement_dao
+            .insert_unlocked_achievements(&Achievement::evaluate(&stages))?;
+
+        // Stored unlocks survive history being cleared, and keep their first date
+        let unlocked = self.achievement_dao.get_unlocked_achievements()?;
+        Ok(Achievement::all()
+            .iter()
+            .map(|achievement| AchievementStatus {
+                achievement: *achievement,
+                unlocked_at: unlocked
+                    .iter()
+                    .find(|(earned, _)| earned == achievement)
+                    .map(|(_, unlocked_at)| *unlocked_at),
+            })
+            .collect())
+    }
+}
@@ -1,12 +1,16 @@
 use crate::domain::error::Result;
+use crate::domain::models::{
+    BaselineSummary, FingerLoad, KeyLoad, KeyboardLayout, LanguageCalibrations, WpmMode,
+};
 use crate::domain::repositories::session_repository::SessionRepositoryTrait;
-use crate::infrastructure::database::daos::RepositoryDaoInterface;
+use crate::infrastructure::database::daos::{BaselineDaoInterface, RepositoryDaoInterface};
 use chrono::NaiveDate;
+use serde::Serialize;
 use shaku::Interface;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AnalyticsData {
     pub total_sessions: usize,
     pub avg_cpm: f64,
@@ -24,9 +28,14 @@ pub struct AnalyticsData {
     pub repository_stats: HashMap<String, RepoStats>,
     pub language_stats: HashMap<String, LangStats>,
     pub reference_date: Option<NaiveDate>,
+    pub prose_baseline: Option<BaselineSummary>,
+    /// Keystrokes per finger over the stages that recorded it
+    pub finger_load: FingerLoad,
+    /// How fast each language types for the player against their overall pace
+    pub language_calibrations: LanguageCalibrations,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RepoStats {
     pub avg_cpm: f64,
     pub avg_wpm: f64,
@@ -43,7 +52,7 @@ pub struct RepoStats {
     pub stages_skipped: usize,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct LangStats {
     pub avg_cpm: f64,
     pub avg_wpm: f64,
@@ -71,16 +80,20 @@ pub struct AnalyticsService {
     session_repository: Arc<dyn SessionRepositoryTrait>,
     #[shaku(inject)]
     repository_dao: Arc<dyn RepositoryDaoInterface>,
+    #[shaku(inject)]
+    baseline_dao: Arc<dyn BaselineDaoInterface>,
 }
 
 impl AnalyticsService {
     pub fn new(
         session_repository: Arc<dyn SessionRepositoryTrait>,
         repository_dao: Arc<dyn RepositoryDaoInterface>,
+        baseline_dao: Arc<dyn BaselineDaoInterface>,
     ) -> Self {
         Self {
             session_repository,
             repository_dao,
+            baseline_dao,
         }
     }
 }
@@ -90,6 +103,10 @@ impl AnalyticsServiceInterface for AnalyticsService {
         let session_repo = &self.session_repository;
         let git_repo_repo = &self.repository_dao;
         let sessions = session_repo.get_sessions_filtered(None, Some(90), "date", true)?;
+        let prose_baseline = self.baseline_dao.get_baseline_summary()?;
+        let language_calibrations = LanguageCalibrations::from_activity(
+            &session_repo.get_language_baselines().unwrap_or_default(),
+        );
 
         if sessions.is_empty() {
             return Ok(AnalyticsData {
@@ -109,6 +126,9 @@ impl AnalyticsServiceInterface for AnalyticsService {
                 repository_stats: HashMap::new(),
                 language_stats: HashMap::new(),
                 reference_date: None,
+                prose_baseline,
+                finger_load: FingerLoad::default(),
+                language_calibrations,
             });
         }
 
@@ -227,6 +247,11 @@ impl AnalyticsServiceInterface for AnalyticsService {
 
         let mut repository_stats = HashMap::new();
         let mut language_stats = HashMap::new();
+        let mut key_load = KeyLoad::default();
+        // (wpm, cpm, seconds) of every stage, for WPM in the configured mode
+        let wpm_mode = WpmMode::current();
+        let mut repository_speeds: HashMap<String, Vec<(f64, f64, f64)>> = HashMap::new();
+        let mut language_speeds: HashMap<String, Vec<(f64, f64, f64)>> = HashMap::new();
 
         let all_repositories = git_repo_repo.get_all_repositories()?;
         let repo_map: HashMap<i64, String> = all_repositories
@@ -282,6 +307,27 @@ impl AnalyticsServiceInterface for AnalyticsService {
                     .get_session_stage_results(session.id)
                     .unwrap_or_default();
                 for stage in stage_results {
+                    if let Some(load) = &stage.key_load {
+                        key_load = key_load.merge(load);
+                    }
+                    let speed = (
+                        wpm_mode.wpm(stage.cpm, stage.code_content.as_deref().unwrap_or_default()),
+                        stage.cpm,
+                        stage.duration_ms as f64 / 1000.0,
+                    );
+                    if let Some(repo_name) = session.repository_id.and_then(|id| repo_map.get(&id))
+                    {
+                        repository_speeds
+                            .entry(repo_name.clone())
+                            .or_default()
+                            .push(speed);
+                    }
+                    if let Some(language) = &stage.language {
+                        language_speeds
+                            .entry(language.clone())
+                            .or_default()
+                            .push(speed);
+                    }
                     if let Some(language) = stage.language {
                         let lang_stats =
                             language_stats
@@ -314,11 +360,14 @@ impl AnalyticsServiceInterface for AnalyticsService {
             }
         }
 
-        for stats in repository_stats.values_mut() {
+        for (repo_name, stats) in repository_stats.iter_mut() {
             if stats.total_sessions > 0 {
                 stats.avg_cpm =
                     stats.total_keystrokes as f64 / (stats.total_duration_ms as f64 / 60000.0);
-                stats.avg_wpm = stats.avg_cpm / 5.0;
+                stats.avg_wpm = wpm_mode.wpm_across(
+                    stats.avg_cpm,
+                    repository_speeds.remove(repo_name).unwrap_or_default(),
+                );
                 stats.avg_accuracy = ((stats.total_keystrokes - stats.total_mistakes) as f64
                     / stats.total_keystrokes as f64)
                     * 100.0;
@@ -326,11 +375,14 @@ impl AnalyticsServiceInterface for AnalyticsService {
             }
         }
 
-        for stats in language_stats.values_mut() {
+        for (language, stats) in language_stats.iter_mut() {
             if stats.total_sessions > 0 {
                 stats.avg_cpm =
                     stats.total_keystrokes as f64 / (stats.total_duration_ms as f64 / 60000.0);
-                stats.avg_wpm = stats.avg_cpm / 5.0;
+                stats.avg_wpm = wpm_mode.wpm_across(
+                    stats.avg_cpm,
+                    language_speeds.remove(language).unwrap_or_default(),
+                );
                 stats.avg_accuracy = ((stats.total_keystrokes - stats.total_mistakes) as f64
                     / stats.total_keystrokes as f64)
                     * 100.0;
@@ -355,6 +407,9 @@ impl AnalyticsServiceInterface for AnalyticsService {
             repository_stats,
             language_stats,
             reference_date: None,
+            prose_baseline,
+            finger_load: key_load.by_finger(&KeyboardLayout::default()),
+            language_calibrations,
         })
     }
 }
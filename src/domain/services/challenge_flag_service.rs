@@ -0,0 +1,61 @@
+use crate::domain::error::Result;
+use crate::domain::models::Challenge;
+use crate::infrastructure::database::daos::ChallengeFlagDaoInterface;
+use shaku::Interface;
+use std::sync::Arc;
+
+pub trait ChallengeFlagServiceInterface: Interface {
+    /// Remember `challenge` as bad (garbled, generated, unreadable) so later
+    /// sessions leave it out; returns false when it was already flagged
+    fn flag(&self, challenge: &Challenge) -> Result<bool>;
+    /// `challenges` without the flagged ones. When flags can't be read,
+    /// nothing is excluded.
+    fn exclude_flagged(&self, challenges: Vec<Challenge>) -> Vec<Challenge>;
+    fn count_flagged(&self, challenges: &[Challenge]) -> Result<usize>;
+}
+
+#[derive(shaku::Component)]
+#[shaku(interface = ChallengeFlagServiceInterface)]
+pub struct ChallengeFlagService {
+    #[shaku(inject)]
+    challenge_flag_dao: Arc<dyn ChallengeFlagDaoInterface>,
+}
+
+impl ChallengeFlagService {
+    pub fn new(challenge_flag_dao: Arc<dyn ChallengeFlagDaoInterface>) -> Self {
+        Self { challenge_flag_dao }
+    }
+}
+
+impl ChallengeFlagServiceInterface for ChallengeFlagService {
+    fn flag(&self, challenge: &Challenge) -> Result<bool> {
+        self.challenge_flag_dao.insert_flag(challenge)
+    }
+
+    fn exclude_flagged(&self, challenges: Vec<Challenge>) -> Vec<Challenge> {
+        let flagged = match self.challenge_flag_dao.get_flagged_fingerprints() {
+            Ok(flagged) if !flagged.is_empty() => flagged,
+            Ok(_) => return challenges,
+            Err(e) => {
+                log::warn!("Failed to load challenge flags: {}", e);
+                return challenges;
+            }
+        };
+
+        let total = challenges.len();
+        let kept: Vec<Challenge> = challenges
+            .into_iter()
+            .filter(|challenge| !flagged.contains(&challenge.fingerprint()))
+            .collect();
+        log::info!("Excluded {} flagged challenges", total - kept.len());
+        kept
+    }
+
+    fn count_flagged(&self, challenges: &[Challenge]) -> Result<usize> {
+        let flagged = self.challenge_flag_dao.get_flagged_fingerprints()?;
+        Ok(challenges
+            .iter()
+            .filter(|challenge| flagged.contains(&challenge.fingerprint()))
+            .count())
+    }
+}
@@ -1,15 +1,16 @@
 use super::{
     chunk_splitter::ChunkSplitter, code_character_counter::CodeCharacterCounter,
-    progress_tracker::ProgressTracker,
+    difficulty_estimator::DifficultyEstimator, progress_tracker::ProgressTracker,
 };
-use crate::domain::models::{Challenge, CodeChunk, DifficultyLevel};
-use crate::presentation::tui::screens::loading_screen::ProgressReporter;
+use crate::domain::models::loading::ProgressReporter;
+use crate::domain::models::{Challenge, ChunkType, CodeChunk, DifficultyLevel};
 use rayon::prelude::*;
 
 /// Main orchestrator for converting CodeChunks into Challenges
 pub struct ChallengeGenerator {
     chunk_splitter: ChunkSplitter,
     character_counter: CodeCharacterCounter,
+    difficulty_estimator: DifficultyEstimator,
 }
 
 impl Default for ChallengeGenerator {
@@ -23,6 +24,7 @@ impl ChallengeGenerator {
         Self {
             chunk_splitter: ChunkSplitter::new(),
             character_counter: CodeCharacterCounter::new(),
+            difficulty_estimator: DifficultyEstimator::new(),
         }
     }
 
@@ -55,16 +57,24 @@ impl ChallengeGenerator {
 
         let chunk_challenges: Vec<Challenge> = valid_chunks
             .par_iter()
+            .filter(|_| !progress.is_cancelled())
             .inspect(|_| {
                 progress_tracker.increment_and_report(progress);
             })
             .flat_map(|chunk| {
                 let code_char_count = self.character_counter.count_code_characters(chunk);
+                let complexity = self.difficulty_estimator.complexity(chunk);
+                let effective_char_count = (code_char_count as f64 * complexity).round() as usize;
 
-                DifficultyLevel::applicable_difficulties(chunk, code_char_count)
+                DifficultyLevel::applicable_difficulties(chunk, effective_char_count)
                     .into_par_iter()
                     .flat_map(move |difficulty| {
-                        self.process_chunk_for_difficulty(chunk, &difficulty, code_char_count)
+                        self.process_chunk_for_difficulty(
+                            chunk,
+                            &difficulty,
+                            effective_char_count,
+                            complexity,
+                        )
                     })
             })
             .collect();
@@ -74,34 +84,50 @@ impl ChallengeGenerator {
         chunk_challenges
     }
 
+    /// Snippets are played whole at every difficulty, never split or filtered by length
+    pub fn convert_snippets(&self, chunks: Vec<CodeChunk>) -> Vec<Challenge> {
+        chunks
+            .iter()
+            .flat_map(|chunk| {
+                DifficultyLevel::ALL
+                    .iter()
+                    .filter_map(|difficulty| Challenge::from_chunk(chunk, Some(*difficulty)))
+            })
+            .collect()
+    }
+
     fn process_chunk_for_difficulty(
         &self,
         chunk: &CodeChunk,
         difficulty: &DifficultyLevel,
-        code_char_count: usize,
+        effective_char_count: usize,
+        complexity: f64,
     ) -> Vec<Challenge> {
         let (_, max_chars) = difficulty.char_limits();
 
-        match (difficulty, code_char_count > max_chars) {
+        match (difficulty, effective_char_count > max_chars) {
             (DifficultyLevel::Zen | DifficultyLevel::Wild, _) | (_, false) => {
                 Challenge::from_chunk(chunk, Some(*difficulty))
                     .map(|challenge| vec![challenge])
                     .unwrap_or_default()
             }
-            (_, true) => self
-                .chunk_splitter
-                .split(chunk, difficulty)
-                .map(|(truncated_content, adjusted_comment_ranges, end_line)| {
-                    vec![Challenge::from_content_and_chunk(
-                        truncated_content,
-                        chunk,
-                        chunk.start_line,
-                        end_line,
-                        &adjusted_comment_ranges,
-                        Some(*difficulty),
-                    )]
-                })
-                .unwrap_or_default(),
+            (_, true) => match chunk.chunk_type {
+                ChunkType::Prose => self.chunk_splitter.split_prose(chunk, difficulty),
+                _ => self
+                    .chunk_splitter
+                    .split_weighted(chunk, difficulty, complexity),
+            }
+            .map(|(truncated_content, adjusted_comment_ranges, end_line)| {
+                vec![Challenge::from_content_and_chunk(
+                    truncated_content,
+                    chunk,
+                    chunk.start_line,
+                    end_line,
+                    &adjusted_comment_ranges,
+                    Some(*difficulty),
+                )]
+            })
+            .unwrap_or_default(),
         }
     }
 }
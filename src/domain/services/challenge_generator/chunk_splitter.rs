@@ -23,8 +23,21 @@ impl ChunkSplitter {
         &self,
         chunk: &'a CodeChunk,
         difficulty: &DifficultyLevel,
+    ) -> Option<SplitResult<'a>> {
+        self.split_weighted(chunk, difficulty, 1.0)
+    }
+
+    /// Like `split`, with the character limits divided by `complexity` so
+    /// harder code is cut shorter
+    pub fn split_weighted<'a>(
+        &self,
+        chunk: &'a CodeChunk,
+        difficulty: &DifficultyLevel,
+        complexity: f64,
     ) -> Option<SplitResult<'a>> {
         let (min_chars, max_chars) = difficulty.char_limits();
+        let min_chars = (min_chars as f64 / complexity).round() as usize;
+        let max_chars = (max_chars as f64 / complexity).round() as usize;
 
         // Check if content already fits within limits (common case)
         let code_char_count = self.character_counter.count_code_characters(chunk);
@@ -73,6 +86,36 @@ impl ChunkSplitter {
         Some((truncated_content, adjusted_comment_ranges, end_line))
     }
 
+    /// Cuts prose after the last whole sentence within the difficulty's limit
+    pub fn split_prose<'a>(
+        &self,
+        chunk: &'a CodeChunk,
+        difficulty: &DifficultyLevel,
+    ) -> Option<SplitResult<'a>> {
+        let (min_chars, max_chars) = difficulty.char_limits();
+        let content = chunk.content.as_str();
+
+        let cut = content
+            .char_indices()
+            .filter(|(_, ch)| !ch.is_whitespace())
+            .enumerate()
+            .take_while(|&(index, _)| index < max_chars)
+            .filter(|&(index, (byte, ch))| {
+                let at_boundary = content[byte + ch.len_utf8()..]
+                    .chars()
+                    .next()
+                    .is_none_or(char::is_whitespace);
+                index + 1 >= min_chars && matches!(ch, '.' | '!' | '?') && at_boundary
+            })
+            .last()
+            .map(|(_, (byte, ch))| byte + ch.len_utf8())?;
+
+        let truncated = &content[..cut];
+        let end_line =
+            (chunk.start_line + truncated.lines().count().saturating_sub(1)).min(chunk.end_line);
+        Some((Cow::Borrowed(truncated), Vec::new(), end_line))
+    }
+
     /// Finds the optimal break point for splitting content to stay within target character count
     fn find_optimal_break_point(
         &self,
@@ -0,0 +1,108 @@
+use crate::domain::models::CodeChunk;
+
+/// Symbol share of typical code; denser code counts as longer
+const BASELINE_SYMBOL_RATIO: f64 = 0.2;
+const SYMBOL_WEIGHT: f64 = 2.0;
+
+/// Nesting levels of typical code; deeper code counts as longer
+const BASELINE_INDENT_DEPTH: f64 = 1.0;
+const INDENT_WEIGHT: f64 = 0.15;
+const INDENT_WIDTH: usize = 4;
+
+/// Line length coefficient of variation of typical code; ragged code counts as longer
+const BASELINE_LINE_VARIATION: f64 = 0.5;
+const VARIATION_WEIGHT: f64 = 0.25;
+
+const MIN_COMPLEXITY: f64 = 0.75;
+const MAX_COMPLEXITY: f64 = 2.5;
+
+/// Weighs a chunk's length by how hard it is to type: symbol density,
+/// indentation depth and line length variance
+pub struct DifficultyEstimator;
+
+impl DifficultyEstimator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Multiplier applied to the code character count before it is compared
+    /// against the difficulty limits; 1.0 for typical code
+    pub fn complexity(&self, chunk: &CodeChunk) -> f64 {
+        let lines: Vec<&str> = chunk
+            .content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+        if lines.is_empty() {
+            return 1.0;
+        }
+
+        let complexity = 1.0
+            + SYMBOL_WEIGHT * (Self::symbol_ratio(chunk) - BASELINE_SYMBOL_RATIO)
+            + INDENT_WEIGHT * (Self::mean_indent_depth(&lines) - BASELINE_INDENT_DEPTH)
+            + VARIATION_WEIGHT * (Self::line_length_variation(&lines) - BASELINE_LINE_VARIATION);
+        complexity.clamp(MIN_COMPLEXITY, MAX_COMPLEXITY)
+    }
+
+    /// Share of non-whitespace code characters that are punctuation or operators
+    fn symbol_ratio(chunk: &CodeChunk) -> f64 {
+        // Comment ranges are byte offsets, walked in order alongside the characters
+        let mut sorted_ranges = chunk.comment_ranges.clone();
+        sorted_ranges.sort_by_key(|&(start, _)| start);
+        let mut range_idx = 0;
+
+        let (symbols, total) = chunk
+            .content
+            .char_indices()
+            .filter(|(_, ch)| !ch.is_whitespace())
+            .filter(|&(index, _)| {
+                while range_idx < sorted_ranges.len() && sorted_ranges[range_idx].1 <= index {
+                    range_idx += 1;
+                }
+                !(range_idx < sorted_ranges.len() && index >= sorted_ranges[range_idx].0)
+            })
+            .fold((0usize, 0usize), |(symbols, total), (_, ch)| {
+                let is_symbol = ch.is_ascii_punctuation() && ch != '_';
+                (symbols + usize::from(is_symbol), total + 1)
+            });
+        if total == 0 {
+            0.0
+        } else {
+            symbols as f64 / total as f64
+        }
+    }
+
+    fn mean_indent_depth(lines: &[&str]) -> f64 {
+        let total_width: usize = lines
+            .iter()
+            .map(|line| {
+                line.chars()
+                    .take_while(|ch| ch.is_whitespace())
+                    .map(|ch| if ch == '\t' { INDENT_WIDTH } else { 1 })
+                    .sum::<usize>()
+            })
+            .sum();
+        total_width as f64 / INDENT_WIDTH as f64 / lines.len() as f64
+    }
+
+    /// Standard deviation of trimmed line lengths relative to their mean
+    fn line_length_variation(lines: &[&str]) -> f64 {
+        let lengths: Vec<f64> = lines
+            .iter()
+            .map(|line| line.trim().chars().count() as f64)
+            .collect();
+        let mean = lengths.iter().sum::<f64>() / lengths.len() as f64;
+        if mean == 0.0 {
+            return 0.0;
+        }
+        let variance =
+            lengths.iter().map(|len| (len - mean).powi(2)).sum::<f64>() / lengths.len() as f64;
+        variance.sqrt() / mean
+    }
+}
+
+impl Default for DifficultyEstimator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
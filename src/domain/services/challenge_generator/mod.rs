@@ -2,9 +2,11 @@
 mod challenge_generator;
 pub mod chunk_splitter;
 pub mod code_character_counter;
+pub mod difficulty_estimator;
 pub mod progress_tracker;
 
 pub use challenge_generator::ChallengeGenerator;
 pub use chunk_splitter::ChunkSplitter;
 pub use code_character_counter::CodeCharacterCounter;
+pub use difficulty_estimator::DifficultyEstimator;
 pub use progress_tracker::ProgressTracker;
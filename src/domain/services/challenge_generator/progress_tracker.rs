@@ -1,5 +1,5 @@
+use crate::domain::models::loading::ProgressReporter;
 use crate::domain::models::loading::StepType;
-use crate::presentation::tui::screens::loading_screen::ProgressReporter;
 use std::sync::{
     atomic::{AtomicUsize, Ordering},
     Arc,
@@ -1,7 +1,11 @@
 use crate::domain::models::config::Config;
+use crate::domain::models::ui::rank_messages::RankMessages;
+use crate::domain::models::{
+    CommentaryStyle, CompletionFeedback, Locale, PlayerProfile, StreamerMode, WpmMode,
+};
 use crate::infrastructure::storage::file_storage::{FileStorage, FileStorageInterface};
 use crate::infrastructure::storage::AppDataProvider;
-use crate::Result;
+use crate::{GitTypeError, Result};
 use shaku::Interface;
 use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
@@ -48,6 +52,36 @@ impl ConfigService {
         Self::new(Arc::new(FileStorage::new()))
     }
 
+    /// Point the process-wide settings at `config`; the game start and the
+    /// settings screen both apply them here, so a save takes effect at once.
+    /// A custom rank messages file that can't be read leaves the bundled
+    /// messages in place and is returned as the error
+    pub fn apply_settings(config: &Config) -> Result<()> {
+        Locale::set_current(config.display.locale.resolve());
+        WpmMode::set_current(config.display.wpm_mode);
+        CompletionFeedback::set_current(config.display.completion_feedback);
+        StreamerMode::set_enabled(config.streamer.enabled);
+        PlayerProfile::set_active_player(config.profile.display_name.as_deref());
+        CommentaryStyle::set_current(config.commentary.style);
+
+        let Some(path) = &config.commentary.messages_file else {
+            RankMessages::set_custom(None);
+            return Ok(());
+        };
+        let messages = FileStorage::new()
+            .read_to_string(path)
+            .and_then(|json| RankMessages::from_json(&json))
+            .map_err(|e| {
+                GitTypeError::ValidationError(format!(
+                    "Failed to load rank messages from {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+        RankMessages::set_custom(Some(messages));
+        Ok(())
+    }
+
     pub fn update_config<F>(&self, updater: F) -> Result<()>
     where
         F: FnOnce(&mut Config),
@@ -1,5 +1,6 @@
 use crate::domain::models::typing::CodeContext;
-use crate::domain::models::Challenge;
+use crate::domain::models::{Challenge, SourceStudy};
+use crate::domain::services::text_processor::TextProcessor;
 use crate::infrastructure::storage::file_storage::{FileStorage, FileStorageInterface};
 use crate::Result;
 use std::path::{Path, PathBuf};
@@ -22,28 +23,65 @@ pub fn load_context_for_challenge(
         return Ok(CodeContext::empty());
     };
 
-    // Convert relative path to absolute path by resolving from git repository root
-    let file_path = if Path::new(source_path).is_absolute() {
-        PathBuf::from(source_path)
-    } else {
-        // Resolve relative path from git root if available
-        if let Some(root) = git_root {
-            root.join(source_path)
-        } else {
-            // Fallback to using the path as-is if git root is not provided
-            PathBuf::from(source_path)
-        }
-    };
-
     load_context_lines(
         &file_storage,
-        &file_path,
+        &resolve_source_path(source_path, git_root),
         start_line,
         end_line,
         context_lines,
     )
 }
 
+/// The full source file of `challenge`, or `None` when it no longer exists on this machine
+pub fn load_source_study(
+    challenge: &Challenge,
+    git_root: Option<&Path>,
+) -> Result<Option<SourceStudy>> {
+    let file_storage = FileStorage::new();
+    let (Some(source_path), Some(start_line), Some(end_line)) = (
+        &challenge.source_file_path,
+        challenge.start_line,
+        challenge.end_line,
+    ) else {
+        return Ok(None);
+    };
+
+    let file_path = resolve_source_path(source_path, git_root);
+    Ok(
+        load_source_lines(&file_storage, &file_path)?.map(|lines| SourceStudy {
+            path: source_path.clone(),
+            lines,
+            start_line,
+            end_line,
+        }),
+    )
+}
+
+/// Where `challenge` starts on disk, or `None` when its file no longer exists on this machine
+pub fn locate_source_file(
+    file_storage: &FileStorage,
+    challenge: &Challenge,
+    git_root: Option<&Path>,
+) -> Option<(PathBuf, usize)> {
+    let source_path = challenge.source_file_path.as_deref()?;
+    let file_path = resolve_source_path(source_path, git_root);
+    file_storage
+        .file_exists(&file_path)
+        .then(|| (file_path, challenge.start_line.unwrap_or(1)))
+}
+
+pub fn load_source_lines(
+    file_storage: &FileStorage,
+    file_path: &Path,
+) -> Result<Option<Vec<String>>> {
+    if !file_storage.file_exists(file_path) {
+        return Ok(None);
+    }
+
+    let (content, _) = TextProcessor::decode_source(&file_storage.read(file_path)?);
+    Ok(Some(content.lines().map(str::to_string).collect()))
+}
+
 pub fn load_context_lines(
     file_storage: &FileStorage,
     file_path: &Path,
@@ -55,7 +93,7 @@ pub fn load_context_lines(
         return Ok(CodeContext::empty());
     }
 
-    let content = file_storage.read_to_string(file_path)?;
+    let (content, _) = TextProcessor::decode_source(&file_storage.read(file_path)?);
     let lines: Vec<&str> = content.lines().collect();
 
     // Calculate context ranges (1-indexed to 0-indexed)
@@ -90,3 +128,11 @@ pub fn load_context_lines(
         post_context,
     })
 }
+
+/// Relative paths are resolved from the git root; without one they are used as-is
+fn resolve_source_path(source_path: &str, git_root: Option<&Path>) -> PathBuf {
+    match git_root {
+        Some(root) if !Path::new(source_path).is_absolute() => root.join(source_path),
+        _ => PathBuf::from(source_path),
+    }
+}
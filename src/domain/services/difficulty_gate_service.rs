@@ -0,0 +1,76 @@
+use crate::domain::error::Result;
+use crate::domain::models::player_profile::DEFAULT_DISPLAY_NAME;
+use crate::domain::models::{DifficultyLevel, DifficultyLock, PlayerProfile};
+use crate::domain::services::config_service::ConfigServiceInterface;
+use crate::infrastructure::database::daos::DifficultyUnlockDaoInterface;
+use shaku::Interface;
+use std::sync::Arc;
+
+pub trait DifficultyGateServiceInterface: Interface {
+    /// Gated difficulties the active profile hasn't unlocked, recording any
+    /// it now qualifies for; empty while `difficulty_gates` is disabled
+    fn locked_difficulties(&self) -> Result<Vec<DifficultyLock>>;
+}
+
+/// Unlocks are kept per profile, so raising the required accuracy later
+/// doesn't lock a difficulty again
+#[derive(shaku::Component)]
+#[shaku(interface = DifficultyGateServiceInterface)]
+pub struct DifficultyGateService {
+    #[shaku(inject)]
+    difficulty_unlock_dao: Arc<dyn DifficultyUnlockDaoInterface>,
+    #[shaku(inject)]
+    config_service: Arc<dyn ConfigServiceInterface>,
+}
+
+impl DifficultyGateService {
+    pub fn new(
+        difficulty_unlock_dao: Arc<dyn DifficultyUnlockDaoInterface>,
+        config_service: Arc<dyn ConfigServiceInterface>,
+    ) -> Self {
+        Self {
+            difficulty_unlock_dao,
+            config_service,
+        }
+    }
+}
+
+impl DifficultyGateServiceInterface for DifficultyGateService {
+    fn locked_difficulties(&self) -> Result<Vec<DifficultyLock>> {
+        let gates = self.config_service.get_config().difficulty_gates;
+        if !gates.enabled {
+            return Ok(Vec::new());
+        }
+
+        let player =
+            PlayerProfile::active_player().unwrap_or_else(|| DEFAULT_DISPLAY_NAME.to_string());
+        let unlocked = self.difficulty_unlock_dao.get_unlocked(&player)?;
+
+        let mut locks: Vec<DifficultyLock> = Vec::new();
+        for (difficulty, requires) in DifficultyLevel::ALL
+            .into_iter()
+            .filter(|difficulty| !unlocked.contains(difficulty))
+            .filter_map(|difficulty| difficulty.gate().map(|requires| (difficulty, requires)))
+        {
+            // A locked tier can't be played, so whatever it gates stays locked too
+            let requires_locked = locks.iter().any(|lock| lock.difficulty == requires);
+            let best_accuracy = self
+                .difficulty_unlock_dao
+                .get_best_accuracy(&player, requires)?;
+
+            if !requires_locked && best_accuracy.is_some_and(|best| best >= gates.required_accuracy)
+            {
+                self.difficulty_unlock_dao
+                    .insert_unlock(&player, difficulty)?;
+            } else {
+                locks.push(DifficultyLock {
+                    difficulty,
+                    requires,
+                    required_accuracy: gates.required_accuracy,
+                    best_accuracy,
+                });
+            }
+        }
+        Ok(locks)
+    }
+}
@@ -0,0 +1,182 @@
+use crate::domain::models::Presence;
+use crate::domain::services::config_service::ConfigServiceInterface;
+use crate::infrastructure::discord_ipc::DiscordIpcClient;
+use serde_json::{json, Value};
+use shaku::Interface;
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Discord allows 5 activity updates per 20 seconds; live WPM refreshes stay well below that
+pub const UPDATE_INTERVAL: Duration = Duration::from_secs(15);
+pub const RECONNECT_INTERVAL: Duration = Duration::from_secs(30);
+
+pub trait DiscordPresenceServiceInterface: Interface {
+    /// Publish the activity if Rich Presence is enabled in settings
+    fn update(&self, presence: &Presence);
+    fn clear(&self);
+}
+
+/// What the UI thread asks the presence worker to do
+pub enum PresenceCommand {
+    Update(Presence, String),
+    Clear(String),
+    #[cfg(feature = "test-mocks")]
+    Flush(mpsc::Sender<()>),
+}
+
+/// Connection and throttling state, owned by the worker thread
+#[derive(Default)]
+pub struct PresenceWorker {
+    client: Option<DiscordIpcClient>,
+    last_sent: Option<(Presence, Instant)>,
+    started_at: Option<i64>,
+    last_connect_attempt: Option<Instant>,
+}
+
+impl PresenceWorker {
+    fn run(state: Arc<Mutex<Self>>, receiver: mpsc::Receiver<PresenceCommand>) {
+        for command in receiver {
+            let mut worker = state.lock().unwrap();
+            match command {
+                PresenceCommand::Update(presence, client_id) => {
+                    worker.update(&presence, &client_id)
+                }
+                PresenceCommand::Clear(client_id) => worker.clear(&client_id),
+                #[cfg(feature = "test-mocks")]
+                PresenceCommand::Flush(done) => {
+                    let _ = done.send(());
+                }
+            }
+        }
+    }
+
+    fn update(&mut self, presence: &Presence, client_id: &str) {
+        if !self.should_send(presence) {
+            return;
+        }
+
+        let started_at = *self
+            .started_at
+            .get_or_insert_with(|| chrono::Utc::now().timestamp());
+        let activity = DiscordPresenceService::activity_json(presence, started_at);
+        if self.send(Some(&activity), client_id) {
+            self.last_sent = Some((presence.clone(), Instant::now()));
+        }
+    }
+
+    fn clear(&mut self, client_id: &str) {
+        self.started_at = None;
+        if self.last_sent.take().is_some() {
+            self.send(None, client_id);
+        }
+    }
+
+    fn should_send(&self, presence: &Presence) -> bool {
+        self.last_sent.as_ref().is_none_or(|(last, sent_at)| {
+            presence.is_new_activity(last)
+                || (presence.wpm.round() != last.wpm.round()
+                    && sent_at.elapsed() >= UPDATE_INTERVAL)
+        })
+    }
+
+    fn send(&mut self, activity: Option<&Value>, client_id: &str) -> bool {
+        if self.client.is_none() {
+            if self
+                .last_connect_attempt
+                .is_some_and(|at| at.elapsed() < RECONNECT_INTERVAL)
+            {
+                return false;
+            }
+            self.last_connect_attempt = Some(Instant::now());
+            self.client = DiscordIpcClient::connect(client_id)
+                .inspect_err(|e| log::debug!("Discord Rich Presence unavailable: {}", e))
+                .ok();
+        }
+
+        let Some(connected) = self.client.as_mut() else {
+            return false;
+        };
+        match connected.set_activity(activity) {
+            Ok(()) => true,
+            Err(e) => {
+                log::debug!("Failed to update Discord Rich Presence: {}", e);
+                self.client = None;
+                false
+            }
+        }
+    }
+}
+
+/// Hands presence changes to a worker thread, so connecting to Discord and
+/// writing to its socket never hold up a frame
+#[derive(shaku::Component)]
+#[shaku(interface = DiscordPresenceServiceInterface)]
+pub struct DiscordPresenceService {
+    #[shaku(default)]
+    worker: Arc<Mutex<PresenceWorker>>,
+    #[shaku(default)]
+    sender: Mutex<Option<mpsc::Sender<PresenceCommand>>>,
+    #[shaku(inject)]
+    config_service: Arc<dyn ConfigServiceInterface>,
+}
+
+impl DiscordPresenceService {
+    pub fn new(config_service: Arc<dyn ConfigServiceInterface>) -> Self {
+        Self {
+            worker: Arc::new(Mutex::new(PresenceWorker::default())),
+            sender: Mutex::new(None),
+            config_service,
+        }
+    }
+
+    /// Activities sent so far, once the commands already queued are handled
+    #[cfg(feature = "test-mocks")]
+    pub fn sent_activities_for_test(&self) -> Vec<Option<Value>> {
+        let (done, flushed) = mpsc::channel();
+        self.dispatch(PresenceCommand::Flush(done));
+        let _ = flushed.recv();
+        self.worker
+            .lock()
+            .unwrap()
+            .client
+            .as_ref()
+            .map(|client| client.activities().to_vec())
+            .unwrap_or_default()
+    }
+
+    pub fn activity_json(presence: &Presence, started_at: i64) -> Value {
+        json!({
+            "details": presence.details(),
+            "state": presence.state(),
+            "timestamps": { "start": started_at },
+        })
+    }
+
+    /// Queues a command, starting the worker on first use
+    fn dispatch(&self, command: PresenceCommand) {
+        let mut sender = self.sender.lock().unwrap();
+        let sender = sender.get_or_insert_with(|| {
+            let (sender, receiver) = mpsc::channel();
+            let worker = Arc::clone(&self.worker);
+            std::thread::spawn(move || PresenceWorker::run(worker, receiver));
+            sender
+        });
+        let _ = sender.send(command);
+    }
+}
+
+impl DiscordPresenceServiceInterface for DiscordPresenceService {
+    fn update(&self, presence: &Presence) {
+        let config = self.config_service.get_config().discord;
+        if !config.enabled {
+            self.clear();
+            return;
+        }
+        self.dispatch(PresenceCommand::Update(presence.clone(), config.client_id));
+    }
+
+    fn clear(&self) {
+        let client_id = self.config_service.get_config().discord.client_id;
+        self.dispatch(PresenceCommand::Clear(client_id));
+    }
+}
@@ -0,0 +1,95 @@
+use crate::domain::error::{GitTypeError, Result};
+use crate::domain::repositories::trending_repository::TrendingRepositoryInfo;
+use crate::infrastructure::database::daos::DismissedRepositoryDaoInterface;
+use shaku::Interface;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+pub trait DismissedRepositoryServiceInterface: Interface {
+    /// Never suggest `repo_name` (`owner/name`) again; returns false when it
+    /// was already dismissed
+    fn dismiss(&self, repo_name: &str) -> Result<bool>;
+    /// Allow suggesting `repo_name` again; returns false when it wasn't dismissed
+    fn restore(&self, repo_name: &str) -> Result<bool>;
+    fn is_dismissed(&self, repo_name: &str) -> Result<bool>;
+    fn list(&self) -> Result<Vec<String>>;
+    /// `repositories` without the dismissed ones. When dismissals can't be
+    /// read, nothing is excluded.
+    fn exclude_dismissed(
+        &self,
+        repositories: Vec<TrendingRepositoryInfo>,
+    ) -> Vec<TrendingRepositoryInfo>;
+}
+
+#[derive(shaku::Component)]
+#[shaku(interface = DismissedRepositoryServiceInterface)]
+pub struct DismissedRepositoryService {
+    #[shaku(inject)]
+    dismissed_repository_dao: Arc<dyn DismissedRepositoryDaoInterface>,
+}
+
+impl DismissedRepositoryService {
+    pub fn new(dismissed_repository_dao: Arc<dyn DismissedRepositoryDaoInterface>) -> Self {
+        Self {
+            dismissed_repository_dao,
+        }
+    }
+}
+
+impl DismissedRepositoryServiceInterface for DismissedRepositoryService {
+    fn dismiss(&self, repo_name: &str) -> Result<bool> {
+        self.dismissed_repository_dao
+            .insert_dismissed(&validate_repo_name(repo_name)?)
+    }
+
+    fn restore(&self, repo_name: &str) -> Result<bool> {
+        self.dismissed_repository_dao
+            .delete_dismissed(&validate_repo_name(repo_name)?)
+    }
+
+    fn is_dismissed(&self, repo_name: &str) -> Result<bool> {
+        Ok(self
+            .list()?
+            .iter()
+            .any(|dismissed| dismissed.eq_ignore_ascii_case(repo_name.trim())))
+    }
+
+    fn list(&self) -> Result<Vec<String>> {
+        self.dismissed_repository_dao.get_dismissed()
+    }
+
+    fn exclude_dismissed(
+        &self,
+        repositories: Vec<TrendingRepositoryInfo>,
+    ) -> Vec<TrendingRepositoryInfo> {
+        let dismissed: HashSet<String> = match self.list() {
+            Ok(dismissed) if !dismissed.is_empty() => dismissed
+                .iter()
+                .map(|repo_name| repo_name.to_lowercase())
+                .collect(),
+            Ok(_) => return repositories,
+            Err(e) => {
+                log::warn!("Failed to load dismissed repositories: {}", e);
+                return repositories;
+            }
+        };
+
+        repositories
+            .into_iter()
+            .filter(|repository| !dismissed.contains(&repository.repo_name.to_lowercase()))
+            .collect()
+    }
+}
+
+fn validate_repo_name(repo_name: &str) -> Result<String> {
+    let repo_name = repo_name.trim();
+    match repo_name.split_once('/') {
+        Some((owner, name)) if !owner.is_empty() && !name.is_empty() && !name.contains('/') => {
+            Ok(repo_name.to_string())
+        }
+        _ => Err(GitTypeError::ValidationError(format!(
+            "Repository name must be in format 'owner/repo': {}",
+            repo_name
+        ))),
+    }
+}
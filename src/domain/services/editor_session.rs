@@ -0,0 +1,159 @@
+use std::time::Instant;
+
+use crate::domain::models::typing::InputResult;
+use crate::domain::models::{Language, Languages, StageResult};
+use crate::domain::services::scoring::{StageCalculator, StageInput, StageTracker};
+use crate::domain::services::source_code_parser::parsers::parse_with_thread_local;
+use crate::domain::services::source_code_parser::{CacheBuilder, CommentProcessor};
+use crate::domain::services::typing_core::TypingCore;
+use crate::{GitTypeError, Result};
+
+/// Challenge prepared from an editor buffer or selection
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditorChallenge {
+    pub text_to_type: String,
+    pub language: Option<String>,
+    pub source_path: Option<String>,
+}
+
+/// Typing progress after a batch of input
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditorProgress {
+    pub position: usize,
+    pub total: usize,
+    pub mistakes: usize,
+    pub completed: bool,
+}
+
+/// A single typing challenge driven by an external editor instead of the TUI
+#[derive(Default)]
+pub struct EditorSession {
+    typing_core: Option<TypingCore>,
+    tracker: Option<StageTracker>,
+}
+
+impl EditorSession {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load code to type. Comments are skipped when the language is known.
+    pub fn load(
+        &mut self,
+        content: &str,
+        language: Option<&str>,
+        source_path: Option<String>,
+    ) -> Result<EditorChallenge> {
+        if content.trim().is_empty() {
+            return Err(GitTypeError::ValidationError(
+                "Cannot start a challenge on empty text".to_string(),
+            ));
+        }
+
+        let language = language.and_then(Languages::get_by_name).or_else(|| {
+            source_path
+                .as_deref()
+                .and_then(|path| std::path::Path::new(path).extension())
+                .and_then(|ext| ext.to_str())
+                .and_then(Languages::from_extension)
+        });
+
+        let comment_ranges = language
+            .as_deref()
+            .map(|language| Self::comment_ranges(content, language))
+            .unwrap_or_default();
+
+        let typing_core = TypingCore::new(content, &comment_ranges, Default::default());
+        let text_to_type = typing_core.text_to_type().to_string();
+
+        self.tracker = Some(match source_path.clone() {
+            Some(path) => StageTracker::new_with_path(text_to_type.clone(), path),
+            None => StageTracker::new(text_to_type.clone()),
+        });
+        self.typing_core = Some(typing_core);
+
+        Ok(EditorChallenge {
+            text_to_type,
+            language: language.map(|language| language.name().to_string()),
+            source_path,
+        })
+    }
+
+    pub fn start(&mut self) -> Result<()> {
+        let tracker = self.tracker.as_mut().ok_or_else(Self::not_loaded)?;
+        tracker.set_start_time(Instant::now());
+        tracker.record(StageInput::Start);
+        Ok(())
+    }
+
+    /// Feed typed characters; `\n` is Enter and `\t` is Tab, as in the TUI
+    pub fn input(&mut self, text: &str) -> Result<EditorProgress> {
+        let (Some(typing_core), Some(tracker)) = (self.typing_core.as_mut(), self.tracker.as_mut())
+        else {
+            return Err(Self::not_loaded());
+        };
+
+        if tracker.start_time.is_none() {
+            return Err(GitTypeError::ValidationError(
+                "Challenge has not been started".to_string(),
+            ));
+        }
+
+        for ch in text.chars() {
+            if !typing_core.can_accept_input() {
+                break;
+            }
+
+            tracker.record(StageInput::Keystroke {
+                ch,
+                position: typing_core.current_position_to_type(),
+            });
+
+            let result = match ch {
+                '\n' => typing_core.process_enter_input(),
+                '\t' => typing_core.process_tab_input(),
+                _ => typing_core.process_character_input(ch),
+            };
+
+            if result == InputResult::Completed {
+                tracker.record(StageInput::Finish);
+            }
+        }
+
+        Ok(EditorProgress {
+            position: typing_core.current_position_to_type(),
+            total: typing_core.text_to_type().chars().count(),
+            mistakes: typing_core.mistakes(),
+            completed: typing_core.is_completed(),
+        })
+    }
+
+    /// Final result; an unfinished challenge is recorded as skipped
+    pub fn finish(&mut self) -> Result<StageResult> {
+        let typing_core = self.typing_core.take().ok_or_else(Self::not_loaded)?;
+        let mut tracker = self.tracker.take().ok_or_else(Self::not_loaded)?;
+
+        if !typing_core.is_completed() {
+            tracker.record(StageInput::Skip);
+        }
+
+        Ok(StageCalculator::calculate(&tracker))
+    }
+
+    pub fn is_loaded(&self) -> bool {
+        self.typing_core.is_some()
+    }
+
+    fn comment_ranges(content: &str, language: &dyn Language) -> Vec<(usize, usize)> {
+        parse_with_thread_local(language.name(), content)
+            .and_then(|tree| {
+                let cache = CacheBuilder::build_byte_to_char_cache(content);
+                CommentProcessor::extract_comment_ranges(&tree, content, language, &cache).ok()
+            })
+            .unwrap_or_default()
+    }
+
+    fn not_loaded() -> GitTypeError {
+        GitTypeError::ValidationError("No challenge loaded".to_string())
+    }
+}
@@ -0,0 +1,23 @@
+use crate::domain::models::Keymap;
+use shaku::Interface;
+
+pub trait KeymapServiceInterface: Interface {
+    /// Bindings currently in effect for every screen
+    fn keymap(&self) -> Keymap;
+}
+
+#[derive(shaku::Component, Default)]
+#[shaku(interface = KeymapServiceInterface)]
+pub struct KeymapService {}
+
+impl KeymapService {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl KeymapServiceInterface for KeymapService {
+    fn keymap(&self) -> Keymap {
+        Keymap::default()
+    }
+}
@@ -0,0 +1,139 @@
+use std::path::{Path, PathBuf};
+
+/// Names of license files at a repository root, lowercase and without extension
+const LICENSE_FILE_STEMS: [&str; 3] = ["license", "licence", "copying"];
+
+/// Phrases that identify well-known license texts with their SPDX identifier;
+/// the first entry whose phrases all appear wins, so narrower texts come first
+const LICENSE_FINGERPRINTS: [(&str, &[&str]); 15] = [
+    (
+        "AGPL-3.0",
+        &["gnu affero general public license", "version 3"],
+    ),
+    (
+        "LGPL-3.0",
+        &["gnu lesser general public license", "version 3"],
+    ),
+    (
+        "LGPL-2.1",
+        &["gnu lesser general public license", "version 2.1"],
+    ),
+    ("GPL-3.0", &["gnu general public license", "version 3"]),
+    ("GPL-2.0", &["gnu general public license", "version 2"]),
+    ("Apache-2.0", &["apache license", "version 2.0"]),
+    ("MPL-2.0", &["mozilla public license", "2.0"]),
+    ("EPL-2.0", &["eclipse public license", "2.0"]),
+    ("BSL-1.0", &["boost software license"]),
+    ("Unlicense", &["this is free and unencumbered software"]),
+    ("CC0-1.0", &["cc0 1.0 universal"]),
+    (
+        "BSD-3-Clause",
+        &["redistribution and use", "neither the name"],
+    ),
+    (
+        "BSD-2-Clause",
+        &["redistribution and use", "binary form must reproduce"],
+    ),
+    (
+        "ISC",
+        &["permission to use, copy, modify, and/or distribute"],
+    ),
+    ("MIT", &["permission is hereby granted, free of charge"]),
+];
+
+pub struct LicenseDetector;
+
+impl LicenseDetector {
+    /// SPDX expression of the license of the repository at `root`: what its license
+    /// files identify (several, like LICENSE-MIT and LICENSE-APACHE, are offered as
+    /// alternatives), else what its Cargo.toml or package.json declares
+    pub fn detect(root: &Path) -> Option<String> {
+        let mut licenses: Vec<String> = Self::license_files(root)
+            .iter()
+            .filter_map(|path| std::fs::read(path).ok())
+            .filter_map(|bytes| Self::identify(&String::from_utf8_lossy(&bytes)))
+            .collect();
+        licenses.sort();
+        licenses.dedup();
+
+        match licenses.len() {
+            0 => Self::from_manifests(root),
+            1 => licenses.pop(),
+            _ => Some(licenses.join(" OR ")),
+        }
+    }
+
+    /// SPDX identifier of a license text: its `SPDX-License-Identifier` tag, else
+    /// the well-known license whose wording it contains
+    pub fn identify(text: &str) -> Option<String> {
+        if let Some(tagged) = text.lines().find_map(|line| {
+            line.split_once("SPDX-License-Identifier:")
+                .map(|(_, expression)| expression.trim().trim_end_matches("*/").trim())
+                .filter(|expression| !expression.is_empty())
+        }) {
+            return Some(tagged.to_string());
+        }
+
+        let normalized = text
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        LICENSE_FINGERPRINTS
+            .iter()
+            .find(|(_, phrases)| phrases.iter().all(|phrase| normalized.contains(phrase)))
+            .map(|(spdx, _)| spdx.to_string())
+    }
+
+    /// License expression declared by a Cargo.toml `[package]` or a package.json
+    pub fn from_manifests(root: &Path) -> Option<String> {
+        let cargo = || {
+            let manifest = std::fs::read_to_string(root.join("Cargo.toml")).ok()?;
+            let mut in_package = false;
+            manifest.lines().map(str::trim).find_map(|line| {
+                if line.starts_with('[') {
+                    in_package = line == "[package]";
+                    return None;
+                }
+                let (key, value) = line.split_once('=')?;
+                (in_package && key.trim() == "license")
+                    .then(|| value.trim().trim_matches('"').to_string())
+            })
+        };
+        let npm = || {
+            let manifest = std::fs::read_to_string(root.join("package.json")).ok()?;
+            serde_json::from_str::<serde_json::Value>(&manifest)
+                .ok()?
+                .get("license")?
+                .as_str()
+                .map(str::to_string)
+        };
+
+        cargo()
+            .or_else(npm)
+            .filter(|license| !license.is_empty() && license != "UNLICENSED")
+    }
+
+    fn license_files(root: &Path) -> Vec<PathBuf> {
+        let mut files: Vec<PathBuf> = std::fs::read_dir(root)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .filter(|path| {
+                let name = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_lowercase())
+                    .unwrap_or_default();
+                LICENSE_FILE_STEMS.iter().any(|stem| {
+                    name.strip_prefix(stem).is_some_and(|rest| {
+                        rest.is_empty() || rest.starts_with('.') || rest.starts_with('-')
+                    })
+                })
+            })
+            .collect();
+        files.sort();
+        files
+    }
+}
@@ -1,7 +1,18 @@
+pub mod achievement_service;
 pub mod analytics_service;
+pub mod challenge_flag_service;
 pub mod challenge_generator;
 pub mod config_service;
 pub mod context_loader;
+pub mod difficulty_gate_service;
+pub mod discord_presence_service;
+pub mod dismissed_repository_service;
+pub mod editor_session;
+pub mod keymap_service;
+pub mod license_detector;
+pub mod profile_service;
+pub mod repository_detail_service;
+pub mod repository_rating_service;
 pub mod repository_service;
 pub mod scoring;
 pub mod session_manager_service;
@@ -9,10 +20,12 @@ pub mod session_service;
 pub mod source_code_parser;
 pub mod source_file_extractor;
 pub mod stage_builder_service;
+pub mod streamer_stats_service;
 pub mod text_processor;
 pub mod theme_service;
 pub mod typing_core;
 pub mod version_service;
+pub mod workspace_detector;
 
 pub use analytics_service::{AnalyticsData, AnalyticsService, LangStats, RepoStats};
 pub use repository_service::RepositoryService;
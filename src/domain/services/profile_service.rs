@@ -0,0 +1,60 @@
+use crate::domain::error::Result;
+use crate::domain::models::PlayerProfile;
+use crate::domain::services::config_service::{ConfigService, ConfigServiceInterface};
+use crate::infrastructure::database::daos::StageDaoInterface;
+use crate::GitTypeError;
+use shaku::Interface;
+use std::sync::Arc;
+
+pub trait ProfileServiceInterface: Interface {
+    fn load_profile(&self) -> Result<PlayerProfile>;
+    /// Store the chosen name; blank names fall back to the default
+    fn set_display_name(&self, display_name: &str) -> Result<()>;
+}
+
+#[derive(shaku::Component)]
+#[shaku(interface = ProfileServiceInterface)]
+pub struct ProfileService {
+    #[shaku(inject)]
+    stage_dao: Arc<dyn StageDaoInterface>,
+    #[shaku(inject)]
+    config_service: Arc<dyn ConfigServiceInterface>,
+}
+
+impl ProfileService {
+    pub fn new(
+        stage_dao: Arc<dyn StageDaoInterface>,
+        config_service: Arc<dyn ConfigServiceInterface>,
+    ) -> Self {
+        Self {
+            stage_dao,
+            config_service,
+        }
+    }
+}
+
+impl ProfileServiceInterface for ProfileService {
+    fn load_profile(&self) -> Result<PlayerProfile> {
+        let stages = self.stage_dao.get_completed_stages(None)?;
+        let config = self.config_service.get_config();
+        Ok(PlayerProfile::from_history(
+            config.profile.display_name.as_deref(),
+            &stages,
+            chrono::Local::now().date_naive(),
+        ))
+    }
+
+    fn set_display_name(&self, display_name: &str) -> Result<()> {
+        let config_service = (self.config_service.as_ref() as &dyn std::any::Any)
+            .downcast_ref::<ConfigService>()
+            .ok_or_else(|| {
+                GitTypeError::TerminalError("Failed to get ConfigService".to_string())
+            })?;
+
+        config_service.update_config(|config| {
+            config.profile.display_name = PlayerProfile::normalize_name(Some(display_name))
+        })?;
+        PlayerProfile::set_active_player(Some(display_name));
+        self.config_service.save()
+    }
+}
@@ -0,0 +1,108 @@
+use crate::domain::error::Result;
+use crate::domain::models::storage::{LeaderboardEntry, StoredRepositoryWithLanguages};
+use crate::domain::models::{GitRepository, RepositoryDetail};
+use crate::domain::repositories::challenge_repository::ChallengeRepositoryInterface;
+use crate::infrastructure::database::daos::{SessionDaoInterface, StageDaoInterface};
+use crate::infrastructure::git::remote::remote_git_repository_client::RemoteGitRepositoryClient;
+use crate::infrastructure::git::GitRepositoryRefParser;
+use shaku::Interface;
+use std::sync::Arc;
+
+pub trait RepositoryDetailServiceInterface: Interface {
+    fn get_repository_detail(
+        &self,
+        repository: &StoredRepositoryWithLanguages,
+    ) -> Result<RepositoryDetail>;
+    /// Drop cached challenges so the next play re-extracts them, returning cache files removed
+    fn refresh_cache(&self, repository: &StoredRepositoryWithLanguages) -> Result<usize>;
+    /// Remove the local clone and its cached challenges; play history is kept
+    fn delete_repository(&self, repository: &StoredRepositoryWithLanguages) -> Result<()>;
+    /// Best results of each local profile that played the repository
+    fn get_leaderboard(
+        &self,
+        repository: &StoredRepositoryWithLanguages,
+    ) -> Result<Vec<LeaderboardEntry>>;
+}
+
+#[derive(shaku::Component)]
+#[shaku(interface = RepositoryDetailServiceInterface)]
+pub struct RepositoryDetailService {
+    #[shaku(inject)]
+    stage_dao: Arc<dyn StageDaoInterface>,
+    #[shaku(inject)]
+    session_dao: Arc<dyn SessionDaoInterface>,
+    #[shaku(inject)]
+    challenge_repository: Arc<dyn ChallengeRepositoryInterface>,
+    #[shaku(default)]
+    remote_git_client: RemoteGitRepositoryClient,
+}
+
+impl RepositoryDetailService {
+    pub fn new(
+        stage_dao: Arc<dyn StageDaoInterface>,
+        session_dao: Arc<dyn SessionDaoInterface>,
+        challenge_repository: Arc<dyn ChallengeRepositoryInterface>,
+        remote_git_client: RemoteGitRepositoryClient,
+    ) -> Self {
+        Self {
+            stage_dao,
+            session_dao,
+            challenge_repository,
+            remote_git_client,
+        }
+    }
+
+    fn cache_key(repository: &StoredRepositoryWithLanguages) -> String {
+        GitRepository {
+            user_name: repository.user_name.clone(),
+            repository_name: repository.repository_name.clone(),
+            remote_url: repository.remote_url.clone(),
+            branch: None,
+            commit_hash: None,
+            is_dirty: false,
+            root_path: None,
+            license: None,
+        }
+        .cache_key()
+    }
+}
+
+impl RepositoryDetailServiceInterface for RepositoryDetailService {
+    fn get_repository_detail(
+        &self,
+        repository: &StoredRepositoryWithLanguages,
+    ) -> Result<RepositoryDetail> {
+        let stages = self.stage_dao.get_completed_stages(Some(repository.id))?;
+        let languages = self.stage_dao.get_language_breakdown(Some(repository.id))?;
+        let cached_challenges = self
+            .challenge_repository
+            .count_cached_challenges(&Self::cache_key(repository))?;
+
+        Ok(RepositoryDetail::from_history(
+            repository.clone(),
+            self.remote_git_client
+                .is_repository_cached(&repository.remote_url),
+            &stages,
+            languages,
+            cached_challenges,
+        ))
+    }
+
+    fn refresh_cache(&self, repository: &StoredRepositoryWithLanguages) -> Result<usize> {
+        self.challenge_repository
+            .invalidate_repository_key(&Self::cache_key(repository))
+    }
+
+    fn delete_repository(&self, repository: &StoredRepositoryWithLanguages) -> Result<()> {
+        let repo_ref = GitRepositoryRefParser::parse(&repository.remote_url)?;
+        self.remote_git_client.delete_repository(&repo_ref)?;
+        self.refresh_cache(repository).map(|_| ())
+    }
+
+    fn get_leaderboard(
+        &self,
+        repository: &StoredRepositoryWithLanguages,
+    ) -> Result<Vec<LeaderboardEntry>> {
+        self.session_dao.get_repository_leaderboard(repository.id)
+    }
+}
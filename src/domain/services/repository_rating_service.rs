@@ -0,0 +1,118 @@
+use crate::domain::error::{GitTypeError, Result};
+use crate::domain::models::storage::{RatedRepository, RepositoryRating};
+use crate::domain::repositories::trending_repository::TrendingRepositoryInfo;
+use crate::infrastructure::database::daos::RepositoryRatingDaoInterface;
+use shaku::Interface;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Preference of repositories with no rating of their own or of their language
+const NEUTRAL_RATING: f64 = 3.0;
+
+pub trait RepositoryRatingServiceInterface: Interface {
+    /// Rate the repository of a recorded session as practice material;
+    /// returns false when the session was played without a repository
+    fn rate_session(&self, session_id: i64, rating: u8) -> Result<bool>;
+    /// `repositories` ordered by the player's own ratings: of the repository
+    /// itself, else of rated repositories in its language. Unrated ones rank
+    /// as a middling rating and ties keep their order. When ratings can't be
+    /// read, the order is kept.
+    fn sort_by_preference(
+        &self,
+        repositories: Vec<TrendingRepositoryInfo>,
+    ) -> Vec<TrendingRepositoryInfo>;
+}
+
+#[derive(shaku::Component)]
+#[shaku(interface = RepositoryRatingServiceInterface)]
+pub struct RepositoryRatingService {
+    #[shaku(inject)]
+    repository_rating_dao: Arc<dyn RepositoryRatingDaoInterface>,
+}
+
+impl RepositoryRatingService {
+    pub fn new(repository_rating_dao: Arc<dyn RepositoryRatingDaoInterface>) -> Self {
+        Self {
+            repository_rating_dao,
+        }
+    }
+}
+
+impl RepositoryRatingServiceInterface for RepositoryRatingService {
+    fn rate_session(&self, session_id: i64, rating: u8) -> Result<bool> {
+        if !(RepositoryRating::MIN..=RepositoryRating::MAX).contains(&rating) {
+            return Err(GitTypeError::ValidationError(format!(
+                "A rating must be between {} and {}, got {}",
+                RepositoryRating::MIN,
+                RepositoryRating::MAX,
+                rating
+            )));
+        }
+        self.repository_rating_dao.rate_session(session_id, rating)
+    }
+
+    fn sort_by_preference(
+        &self,
+        mut repositories: Vec<TrendingRepositoryInfo>,
+    ) -> Vec<TrendingRepositoryInfo> {
+        let rated = match self.repository_rating_dao.get_rated_repositories() {
+            Ok(rated) if !rated.is_empty() => rated,
+            Ok(_) => return repositories,
+            Err(e) => {
+                log::warn!("Failed to load repository ratings: {}", e);
+                return repositories;
+            }
+        };
+
+        let by_name: HashMap<String, f64> = rated
+            .iter()
+            .map(|repository| {
+                (
+                    format!("{}/{}", repository.user_name, repository.repository_name)
+                        .to_lowercase(),
+                    repository.rating.average,
+                )
+            })
+            .collect();
+        let by_language = language_averages(&rated);
+
+        let preference = |repository: &TrendingRepositoryInfo| {
+            by_name
+                .get(&repository.repo_name.to_lowercase())
+                .or_else(|| {
+                    repository
+                        .primary_language
+                        .as_ref()
+                        .and_then(|language| by_language.get(&language.to_lowercase()))
+                })
+                .copied()
+                .unwrap_or(NEUTRAL_RATING)
+        };
+        repositories.sort_by(|a, b| preference(b).total_cmp(&preference(a)));
+        repositories
+    }
+}
+
+/// Average of all ratings given to repositories of each language, keyed in lowercase
+fn language_averages(rated: &[RatedRepository]) -> HashMap<String, f64> {
+    rated
+        .iter()
+        .filter_map(|repository| {
+            repository
+                .primary_language
+                .as_ref()
+                .map(|language| (language.to_lowercase(), repository.rating))
+        })
+        .fold(
+            HashMap::<String, (f64, usize)>::new(),
+            |mut totals, (language, rating)| {
+                let (sum, count) = totals.entry(language).or_default();
+                *sum += rating.average * rating.count as f64;
+                *count += rating.count;
+                totals
+            },
+        )
+        .into_iter()
+        .map(|(language, (sum, count))| (language, sum / count as f64))
+        .collect()
+}
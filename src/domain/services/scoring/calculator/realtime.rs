@@ -1,17 +1,20 @@
+use crate::domain::models::WpmMode;
 use std::time::Duration;
 
 /// Real-time metric calculation
 pub struct RealTimeCalculator;
 
 impl RealTimeCalculator {
+    /// `text` is the whole text of the stage, used for token-based WPM
     pub fn calculate(
+        text: &str,
         current_position: usize,
         mistakes: usize,
         elapsed_time: Duration,
     ) -> RealTimeResult {
         let elapsed_secs = elapsed_time.as_secs_f64().max(0.1);
         let cpm = (current_position as f64 / elapsed_secs) * 60.0;
-        let wpm = cpm / 5.0;
+        let wpm = WpmMode::current().wpm(cpm, text);
         let accuracy = if current_position > 0 {
             ((current_position.saturating_sub(mistakes)) as f64 / current_position as f64) * 100.0
         } else {
@@ -25,6 +28,31 @@ impl RealTimeCalculator {
             mistakes,
         }
     }
+
+    /// WPM at the end of every whole second of `elapsed_time`, over the
+    /// `window` before it; `keystroke_times` are the correct keystrokes,
+    /// measured from the stage start
+    pub fn wpm_history(
+        text: &str,
+        keystroke_times: &[Duration],
+        elapsed_time: Duration,
+        window: Duration,
+    ) -> Vec<f64> {
+        // WPM grows with CPM in every mode, so the text is measured once
+        let wpm_per_cpm = WpmMode::current().wpm(1.0, text);
+        (1..=elapsed_time.as_secs())
+            .map(|second| {
+                let end = Duration::from_secs(second);
+                let span = window.min(end);
+                let start = end - span;
+                let typed = keystroke_times
+                    .iter()
+                    .filter(|time| **time > start && **time <= end)
+                    .count();
+                typed as f64 / span.as_secs_f64().max(0.1) * 60.0 * wpm_per_cpm
+            })
+            .collect()
+    }
 }
 
 pub struct RealTimeResult {
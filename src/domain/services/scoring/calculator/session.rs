@@ -1,4 +1,4 @@
-use crate::domain::models::SessionResult;
+use crate::domain::models::{ResultPlausibility, SessionResult};
 use crate::domain::services::scoring::{
     ScoreCalculator, SessionTracker, SessionTrackerData, SessionTrackerInterface,
 };
@@ -154,6 +154,8 @@ impl SessionCalculator {
             worst_stage_accuracy,
             session_score,
             session_successful,
+            verification_code: None,
+            plausibility: ResultPlausibility::default(),
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::domain::models::{Rank, StageResult};
+use crate::domain::models::{Rank, StageResult, WpmMode};
 use crate::domain::services::scoring::{RankCalculator, ScoreCalculator, StageTracker};
 
 /// Stage level result calculation
@@ -52,6 +52,7 @@ impl StageCalculator {
         StageResult {
             cpm,
             wpm,
+            token_density: WpmMode::token_density(&data.target_text),
             accuracy,
             keystrokes: data.keystrokes.len(),
             mistakes,
@@ -67,6 +68,8 @@ impl StageCalculator {
             was_skipped: data.was_skipped,
             was_failed: data.was_failed,
             challenge_path: data.challenge_path,
+            mistake_breakdown: tracker.mistake_breakdown(),
+            key_load: tracker.key_load(),
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::domain::models::TotalResult;
+use crate::domain::models::{SessionResult, TotalResult};
 use crate::domain::services::scoring::{TotalTracker, TotalTrackerData, TotalTrackerInterface};
 use std::time::{Duration, Instant};
 
@@ -63,32 +63,18 @@ impl TotalCalculator {
         };
 
         // Calculate best/worst session metrics from sessions with best/worst scores
-        let valid_sessions: Vec<_> = session_results
-            .iter()
-            .filter(|sr| sr.stages_completed > 0)
-            .collect();
-
-        let (best_session_wpm, best_session_accuracy) = if valid_sessions.is_empty() {
-            (0.0, 0.0)
-        } else {
-            // Find session with highest score
-            let best_session = valid_sessions
-                .iter()
-                .max_by(|a, b| a.session_score.partial_cmp(&b.session_score).unwrap())
-                .unwrap();
-            (best_session.overall_wpm, best_session.overall_accuracy)
-        };
-
-        let (worst_session_wpm, worst_session_accuracy) = if valid_sessions.is_empty() {
-            (0.0, 0.0)
-        } else {
-            // Find session with lowest score
-            let worst_session = valid_sessions
-                .iter()
-                .min_by(|a, b| a.session_score.partial_cmp(&b.session_score).unwrap())
-                .unwrap();
-            (worst_session.overall_wpm, worst_session.overall_accuracy)
-        };
+        let (
+            (best_session_wpm, best_session_accuracy),
+            (worst_session_wpm, worst_session_accuracy),
+        ) = SessionResult::best_and_worst(session_results).map_or(
+            ((0.0, 0.0), (0.0, 0.0)),
+            |(best, worst)| {
+                (
+                    (best.overall_wpm, best.overall_accuracy),
+                    (worst.overall_wpm, worst.overall_accuracy),
+                )
+            },
+        );
 
         TotalResult {
             start_time: Instant::now(), // This should ideally be tracked properly
@@ -1,10 +1,14 @@
 use std::time::Instant;
 
+use crate::domain::models::{KeyLoad, MistakeBreakdown, MistakeKind};
+
 #[derive(Debug, Clone)]
 pub struct Keystroke {
     pub character: char,
     pub position: usize,
     pub is_correct: bool,
+    /// Set for mistyped characters
+    pub mistake: Option<MistakeKind>,
     pub timestamp: Instant,
 }
 
@@ -19,6 +23,7 @@ pub struct StageTracker {
     recorded_duration: Option<std::time::Duration>,
     paused_time: Option<Instant>,
     total_paused_duration: std::time::Duration,
+    latency_compensation: std::time::Duration,
     challenge_path: String,
     was_skipped: bool,
     was_failed: bool,
@@ -35,6 +40,7 @@ impl StageTracker {
             recorded_duration: None,
             paused_time: None,
             total_paused_duration: std::time::Duration::ZERO,
+            latency_compensation: std::time::Duration::ZERO,
             challenge_path: String::new(),
             was_skipped: false,
             was_failed: false,
@@ -51,6 +57,7 @@ impl StageTracker {
             recorded_duration: None,
             paused_time: None,
             total_paused_duration: std::time::Duration::ZERO,
+            latency_compensation: std::time::Duration::ZERO,
             challenge_path,
             was_skipped: false,
             was_failed: false,
@@ -62,6 +69,11 @@ impl StageTracker {
         self.start_time = Some(start_time);
     }
 
+    /// Terminal latency to remove from the time of a completed stage
+    pub fn set_latency_compensation(&mut self, compensation: std::time::Duration) {
+        self.latency_compensation = compensation;
+    }
+
     pub fn record(&mut self, input: StageInput) {
         match input {
             StageInput::Start => {
@@ -80,11 +92,13 @@ impl StageTracker {
                 } else {
                     false
                 };
+                let mistake = (!is_correct).then(|| self.classify_mistake(ch, position));
 
                 let keystroke = Keystroke {
                     character: ch,
                     position,
                     is_correct,
+                    mistake,
                     timestamp: Instant::now(),
                 };
 
@@ -103,8 +117,12 @@ impl StageTracker {
                     self.paused_time = None;
                 }
                 if let Some(start) = self.start_time {
-                    self.recorded_duration =
-                        Some(start.elapsed().saturating_sub(self.total_paused_duration));
+                    self.recorded_duration = Some(
+                        start
+                            .elapsed()
+                            .saturating_sub(self.total_paused_duration)
+                            .saturating_sub(self.latency_compensation),
+                    );
                 }
             }
             StageInput::Pause => {
@@ -143,6 +161,52 @@ impl StageTracker {
         }
     }
 
+    /// Last `limit` mistyped keystrokes as `(expected, typed)`, oldest first
+    pub fn recent_mistakes(&self, limit: usize) -> Vec<(char, char)> {
+        let mut mistakes: Vec<(char, char)> = self
+            .keystrokes
+            .iter()
+            .rev()
+            .filter(|keystroke| !keystroke.is_correct)
+            .filter_map(|keystroke| {
+                self.target_text
+                    .chars()
+                    .nth(keystroke.position)
+                    .map(|expected| (expected, keystroke.character))
+            })
+            .take(limit)
+            .collect();
+        mistakes.reverse();
+        mistakes
+    }
+
+    /// Mistyped keystrokes of the stage counted by kind
+    pub fn mistake_breakdown(&self) -> MistakeBreakdown {
+        self.keystrokes
+            .iter()
+            .filter_map(|keystroke| keystroke.mistake)
+            .fold(MistakeBreakdown::default(), |mut breakdown, kind| {
+                breakdown.record(kind);
+                breakdown
+            })
+    }
+
+    /// Keystrokes of the stage counted per expected character
+    pub fn key_load(&self) -> KeyLoad {
+        let target: Vec<char> = self.target_text.chars().collect();
+        self.keystrokes
+            .iter()
+            .filter_map(|keystroke| {
+                target
+                    .get(keystroke.position)
+                    .map(|expected| (*expected, keystroke.is_correct))
+            })
+            .fold(KeyLoad::default(), |mut load, (expected, is_correct)| {
+                load.record(expected, is_correct);
+                load
+            })
+    }
+
     pub fn get_data(&self) -> StageTrackerData {
         let elapsed_time = if let Some(recorded) = self.recorded_duration {
             recorded
@@ -171,6 +235,14 @@ impl StageTracker {
             was_failed: self.was_failed,
         }
     }
+
+    fn classify_mistake(&self, typed: char, position: usize) -> MistakeKind {
+        let mut rest = self.target_text.chars().skip(position);
+        match rest.next() {
+            Some(expected) => MistakeKind::classify(expected, typed, rest.next()),
+            None => MistakeKind::Other,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
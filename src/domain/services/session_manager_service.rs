@@ -1,8 +1,9 @@
-use crate::domain::events::domain_events::DomainEvent;
+use crate::domain::events::domain_events::{DomainEvent, StageCompleted};
 use crate::domain::events::EventBusInterface;
 use crate::domain::models::{
-    Challenge, DifficultyLevel, GitRepository, SessionAction, SessionConfig, SessionResult,
-    SessionState,
+    Challenge, DemoMode, DifficultyLevel, FailureAnalysis, GitRepository, MistakeDiff, RaceBot,
+    ResultPlausibility, ResultVerification, SessionAction, SessionConfig, SessionResult,
+    SessionState, StageScreenshot,
 };
 use crate::domain::repositories::session_repository::{BestRecords, BestStatus};
 use crate::domain::repositories::SessionRepository;
@@ -11,10 +12,14 @@ use crate::domain::services::scoring::{
     StageTracker, TotalTrackerInterface,
 };
 use crate::domain::services::stage_builder_service::{StageRepository, StageRepositoryInterface};
+use crate::infrastructure::storage::file_storage::{FileStorage, FileStorageInterface};
 use crate::{GitTypeError, Result};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+/// Draws tried when re-rolling, to avoid serving the same challenge again
+const REROLL_ATTEMPTS: usize = 5;
+
 /// Manages the overall session state and stage progression
 #[derive(shaku::Component)]
 #[shaku(interface = SessionManagerInterface)]
@@ -35,6 +40,38 @@ pub struct SessionManager {
     session_challenges: Mutex<Vec<Challenge>>,
     #[shaku(default)]
     best_records_at_start: Mutex<Option<BestRecords>>,
+    #[shaku(default)]
+    latency_compensation: Mutex<Duration>,
+    #[shaku(default)]
+    failed_challenge: Mutex<Option<Challenge>>,
+    #[shaku(default)]
+    retry_challenge: Mutex<Option<Challenge>>,
+    #[shaku(default)]
+    ghost: Mutex<Option<RaceBot>>,
+    #[shaku(default)]
+    upcoming_challenge: Mutex<Option<Challenge>>,
+    /// Drawn while a stage is typed, with the stage index it was drawn for
+    #[shaku(default)]
+    prefetched_challenge: Mutex<Option<(usize, Challenge)>>,
+    #[shaku(default)]
+    next_challenge_rerolled: Mutex<bool>,
+    #[shaku(default)]
+    fixed_challenges: Mutex<Option<Vec<Challenge>>>,
+    /// Played instead of the repository's code while set
+    #[shaku(default)]
+    drills: Mutex<Option<Vec<Challenge>>>,
+    #[shaku(default)]
+    verification_seed: Mutex<u32>,
+    #[shaku(default)]
+    paste_attempts: Mutex<usize>,
+    #[shaku(default)]
+    pending_screenshot: Mutex<Option<(String, String)>>,
+    #[shaku(default)]
+    stage_screenshots: Mutex<Vec<StageScreenshot>>,
+    #[shaku(default)]
+    recorded_session_id: Mutex<Option<i64>>,
+    #[shaku(default)]
+    difficulty_fallback: Mutex<Option<(DifficultyLevel, DifficultyLevel)>>,
     #[shaku(inject)]
     event_bus: Arc<dyn EventBusInterface>,
     #[shaku(inject)]
@@ -72,6 +109,21 @@ impl SessionManager {
             git_repository: Mutex::new(None),
             session_challenges: Mutex::new(Vec::new()),
             best_records_at_start: Mutex::new(None),
+            latency_compensation: Mutex::new(Duration::ZERO),
+            failed_challenge: Mutex::new(None),
+            retry_challenge: Mutex::new(None),
+            ghost: Mutex::new(None),
+            upcoming_challenge: Mutex::new(None),
+            prefetched_challenge: Mutex::new(None),
+            next_challenge_rerolled: Mutex::new(false),
+            fixed_challenges: Mutex::new(None),
+            drills: Mutex::new(None),
+            verification_seed: Mutex::new(0),
+            paste_attempts: Mutex::new(0),
+            pending_screenshot: Mutex::new(None),
+            stage_screenshots: Mutex::new(Vec::new()),
+            recorded_session_id: Mutex::new(None),
+            difficulty_fallback: Mutex::new(None),
             event_bus,
             stage_repository,
             session_tracker,
@@ -108,6 +160,55 @@ impl SessionManager {
         *self.config.lock().unwrap() = config;
     }
 
+    /// Serve these challenges in order, one per stage, instead of drawing
+    /// from the StageRepository (e.g. a challenge pack); survives `reset`
+    pub fn set_fixed_challenges(&self, challenges: Option<Vec<Challenge>>) {
+        *self.fixed_challenges.lock().unwrap() = challenges;
+    }
+
+    /// Serve these drills, one per stage, ahead of fixed challenges and draws
+    /// until they are cleared with `None`; also survives `reset`
+    pub fn set_drills(&self, drills: Option<Vec<Challenge>>) {
+        *self.drills.lock().unwrap() = drills;
+    }
+
+    pub fn is_drilling(&self) -> bool {
+        self.drills.lock().unwrap().is_some()
+    }
+
+    /// Challenges a session can go through: one per stage plus one per skip
+    pub fn challenge_slots(&self) -> usize {
+        let config = self.config.lock().unwrap();
+        config.max_stages + config.max_skips
+    }
+
+    /// The typing screen of the stage about to be finalized, as ANSI text and
+    /// HTML; kept with the stage's number once it is finalized
+    pub fn capture_stage_screenshot(&self, ansi: String, html: String) {
+        *self.pending_screenshot.lock().unwrap() = Some((ansi, html));
+    }
+
+    #[cfg(feature = "test-mocks")]
+    pub fn get_stage_screenshots_for_test(&self) -> Vec<StageScreenshot> {
+        self.stage_screenshots.lock().unwrap().clone()
+    }
+
+    /// Id of the finished session in the database; `None` until it is recorded
+    /// and for sessions that aren't (e.g. read-only mode)
+    pub fn get_recorded_session_id(&self) -> Option<i64> {
+        *self.recorded_session_id.lock().unwrap()
+    }
+
+    #[cfg(feature = "test-mocks")]
+    pub fn set_recorded_session_id_for_test(&self, session_id: Option<i64>) {
+        *self.recorded_session_id.lock().unwrap() = session_id;
+    }
+
+    /// Calibrated terminal latency applied to every new stage tracker; survives `reset`
+    pub fn set_latency_compensation(&self, compensation: Duration) {
+        *self.latency_compensation.lock().unwrap() = compensation;
+    }
+
     fn setup_event_subscriptions_internal(
         bus: &dyn EventBusInterface,
         instance: &std::sync::Weak<SessionManager>,
@@ -176,6 +277,9 @@ impl SessionManager {
 
                 // Reset session tracker for new session
                 self.session_tracker.reset();
+                *self.failed_challenge.lock().unwrap() = None;
+                *self.verification_seed.lock().unwrap() = rand::random();
+                *self.paste_attempts.lock().unwrap() = 0;
 
                 SessionState::InProgress {
                     current_stage: 1,
@@ -194,7 +298,7 @@ impl SessionManager {
                 self.stage_results
                     .lock()
                     .unwrap()
-                    .push(stage_result.clone());
+                    .push((**stage_result).clone());
 
                 // Count actually completed stages (not skipped and not failed)
                 let completed_stages = self
@@ -246,6 +350,13 @@ impl SessionManager {
                 *self.current_stage_tracker.lock().unwrap() = None;
                 self.stage_trackers.lock().unwrap().clear();
                 self.session_challenges.lock().unwrap().clear();
+                *self.failed_challenge.lock().unwrap() = None;
+                *self.upcoming_challenge.lock().unwrap() = None;
+                *self.prefetched_challenge.lock().unwrap() = None;
+                *self.next_challenge_rerolled.lock().unwrap() = false;
+                *self.pending_screenshot.lock().unwrap() = None;
+                self.stage_screenshots.lock().unwrap().clear();
+                *self.recorded_session_id.lock().unwrap() = None;
 
                 // Reset session tracker
                 self.session_tracker.reset();
@@ -286,6 +397,12 @@ impl SessionManager {
         self.stage_trackers.lock().unwrap().clear();
         *self.git_repository.lock().unwrap() = None;
         self.session_challenges.lock().unwrap().clear();
+        *self.failed_challenge.lock().unwrap() = None;
+        *self.retry_challenge.lock().unwrap() = None;
+        *self.upcoming_challenge.lock().unwrap() = None;
+        *self.prefetched_challenge.lock().unwrap() = None;
+        *self.next_challenge_rerolled.lock().unwrap() = false;
+        *self.paste_attempts.lock().unwrap() = 0;
 
         // Capture best records at session start for accurate comparison later
         *self.best_records_at_start.lock().unwrap() =
@@ -304,6 +421,10 @@ impl SessionManager {
         *self.git_repository.lock().unwrap() = git_repository;
     }
 
+    pub fn get_git_repository(&self) -> Option<GitRepository> {
+        self.git_repository.lock().unwrap().clone()
+    }
+
     #[cfg(feature = "test-mocks")]
     pub fn get_session_challenges_for_test(&self) -> Vec<Challenge> {
         self.session_challenges.lock().unwrap().clone()
@@ -375,19 +496,32 @@ impl SessionManager {
     /// Get current challenge for the session
     pub fn get_current_challenge(&self) -> Result<Option<Challenge>> {
         if matches!(*self.state.lock().unwrap(), SessionState::InProgress { .. }) {
-            let stage_repo = self
-                .stage_repository
-                .as_any()
-                .downcast_ref::<StageRepository>()
-                .ok_or_else(|| {
-                    GitTypeError::TerminalError("Failed to downcast StageRepository".to_string())
-                })?;
-            Ok(stage_repo.get_challenge_for_difficulty(self.config.lock().unwrap().difficulty))
+            if let Some(challenge) = self.retry_challenge.lock().unwrap().take() {
+                return Ok(Some(challenge));
+            }
+            if let Some(challenge) = self.upcoming_challenge.lock().unwrap().take() {
+                return Ok(Some(challenge));
+            }
+            self.draw_challenge()
         } else {
             Ok(None)
         }
     }
 
+    /// Whether the session just completed beat the all-time best held when it started
+    pub fn is_personal_best_session(&self) -> bool {
+        if !matches!(self.get_state(), SessionState::Completed { .. }) {
+            return false;
+        }
+        self.generate_session_result()
+            .and_then(|result| {
+                self.get_best_status_for_score(result.session_score)
+                    .ok()
+                    .flatten()
+            })
+            .is_some_and(|status| status.is_all_time_best)
+    }
+
     /// Get best status for a given score
     pub fn get_best_status_for_score(&self, score: f64) -> Result<Option<BestStatus>> {
         let best_records_at_start = self.best_records_at_start.lock().unwrap();
@@ -441,6 +575,42 @@ impl SessionManager {
         }
     }
 
+    /// Save an in-progress session that is being cut short, e.g. by SIGHUP.
+    /// The stage being typed counts as failed once it has started.
+    pub fn persist_interrupted_session(&self) -> Result<()> {
+        if !self.is_in_progress() {
+            return Ok(());
+        }
+
+        let in_flight = self
+            .current_stage_tracker
+            .lock()
+            .unwrap()
+            .take()
+            .filter(|tracker| tracker.start_time.is_some());
+        if let Some(mut tracker) = in_flight {
+            tracker.record(StageInput::Fail);
+            let stage_result = StageCalculator::calculate(&tracker);
+            self.session_tracker.record(stage_result.clone());
+
+            let stage_name = format!("Stage {}", self.current_stage());
+            if let Some(challenge) = self.get_current_challenge().ok().flatten() {
+                self.session_challenges.lock().unwrap().push(challenge);
+            }
+            self.stage_trackers
+                .lock()
+                .unwrap()
+                .push((stage_name, tracker));
+            self.reduce(SessionAction::CompleteStage(Box::new(stage_result)))?;
+        }
+        self.reduce(SessionAction::Abort)?;
+
+        if self.stage_results.lock().unwrap().is_empty() {
+            return Ok(());
+        }
+        self.record_and_update_trackers()
+    }
+
     /// Check if session is completed
     pub fn is_completed(&self) -> bool {
         matches!(*self.state.lock().unwrap(), SessionState::Completed { .. })
@@ -478,10 +648,76 @@ impl SessionManager {
     pub fn generate_session_result(&self) -> Option<SessionResult> {
         // Use SessionTracker and SessionCalculator for proper flow implementation
         let session_data = self.session_tracker.get_data();
-        let result = SessionCalculator::calculate_from_data(&session_data);
+        let mut result = SessionCalculator::calculate_from_data(&session_data);
+        result.plausibility = ResultPlausibility::assess(
+            &self.stage_keystroke_times(),
+            *self.paste_attempts.lock().unwrap(),
+        );
+        // An implausible result gets no code to vouch for it
+        result.verification_code = result.plausibility.is_verified().then(|| {
+            ResultVerification::new(
+                &result,
+                &self.keystroke_log(),
+                *self.verification_seed.lock().unwrap(),
+            )
+            .code()
+        });
         Some(result)
     }
 
+    /// Note that text was pasted into the typing screen during the session
+    pub fn record_paste_attempt(&self) {
+        *self.paste_attempts.lock().unwrap() += 1;
+    }
+
+    /// Time of every keystroke of each finished stage, from the stage start
+    fn stage_keystroke_times(&self) -> Vec<Vec<Duration>> {
+        self.stage_trackers
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, tracker)| {
+                let data = tracker.get_data();
+                data.keystrokes
+                    .iter()
+                    .map(|keystroke| {
+                        data.start_time
+                            .map(|start| keystroke.timestamp.saturating_duration_since(start))
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Every keystroke of the finished stages with its time into the stage
+    fn keystroke_log(&self) -> String {
+        self.stage_trackers
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(stage_name, tracker)| {
+                let data = tracker.get_data();
+                let started_at = data.start_time;
+                data.keystrokes
+                    .iter()
+                    .map(|keystroke| {
+                        format!(
+                            "{}:{}:{}:{};",
+                            keystroke.character,
+                            keystroke.position,
+                            keystroke.is_correct as u8,
+                            started_at
+                                .map(|start| keystroke.timestamp.saturating_duration_since(start))
+                                .unwrap_or_default()
+                                .as_millis()
+                        )
+                    })
+                    .fold(format!("{}\n", stage_name), |log, line| log + &line)
+            })
+            .collect()
+    }
+
     // Removed generate_total_result - not used
 
     /// Record session to database and update total tracker
@@ -499,7 +735,11 @@ impl SessionManager {
     /// Record session to database
     fn record_session_to_database(&self, session_result: &SessionResult) -> Result<()> {
         // Get game mode and difficulty from global repositories or session config
-        let game_mode = format!("{:?}", self.config.lock().unwrap().difficulty);
+        let game_mode = if self.is_drilling() {
+            "Drill".to_string()
+        } else {
+            format!("{:?}", self.config.lock().unwrap().difficulty)
+        };
 
         let difficulty_level = Some(format!("{:?}", self.config.lock().unwrap().difficulty));
 
@@ -511,18 +751,48 @@ impl SessionManager {
         let session_challenges = self.session_challenges.lock().unwrap().clone();
 
         // Call SessionRepository to save to database
-        SessionRepository::record_session_global(
+        if let Some(session_id) = SessionRepository::record_session_global(
             session_result,
             git_repository.as_ref(),
             &game_mode,
             difficulty_level.as_deref(),
             &stage_trackers,
             &session_challenges,
-        )?;
+        )? {
+            *self.recorded_session_id.lock().unwrap() = Some(session_id);
+            self.save_stage_screenshots(session_id);
+        }
 
         Ok(())
     }
 
+    /// Written once the session has an id; a failure only loses the screenshots
+    fn save_stage_screenshots(&self, session_id: i64) {
+        let screenshots = std::mem::take(&mut *self.stage_screenshots.lock().unwrap());
+        if screenshots.is_empty() {
+            return;
+        }
+
+        let file_storage = FileStorage::new();
+        let result = file_storage.get_app_data_dir().and_then(|app_data_dir| {
+            file_storage
+                .create_dir_all(&StageScreenshot::session_dir(&app_data_dir, session_id))?;
+            screenshots.iter().try_for_each(|screenshot| {
+                file_storage.write(
+                    &StageScreenshot::ansi_path(&app_data_dir, session_id, screenshot.stage_number),
+                    screenshot.ansi.as_bytes(),
+                )?;
+                file_storage.write(
+                    &StageScreenshot::html_path(&app_data_dir, session_id, screenshot.stage_number),
+                    screenshot.html.as_bytes(),
+                )
+            })
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to save stage screenshots: {}", e);
+        }
+    }
+
     /// Add completed session to TotalTracker
     fn add_session_to_total_tracker(&self) -> Result<()> {
         if let Some(session_result) = self.generate_session_result() {
@@ -539,6 +809,14 @@ impl SessionManager {
         self.stage_trackers.lock().unwrap().clear();
         self.session_challenges.lock().unwrap().clear();
         *self.best_records_at_start.lock().unwrap() = None;
+        *self.failed_challenge.lock().unwrap() = None;
+        *self.upcoming_challenge.lock().unwrap() = None;
+        *self.prefetched_challenge.lock().unwrap() = None;
+        *self.next_challenge_rerolled.lock().unwrap() = false;
+        *self.pending_screenshot.lock().unwrap() = None;
+        self.stage_screenshots.lock().unwrap().clear();
+        *self.recorded_session_id.lock().unwrap() = None;
+        *self.paste_attempts.lock().unwrap() = 0;
 
         // Reset session tracker
         self.session_tracker.reset();
@@ -548,27 +826,204 @@ impl SessionManager {
     // Challenge Management (delegated to StageRepository)
     // ============================================
 
+    /// Remember the challenge the player gave up on, for the failure screen
+    pub fn record_failed_challenge(&self, challenge: Challenge) {
+        *self.failed_challenge.lock().unwrap() = Some(challenge);
+    }
+
+    /// Serve this challenge as the first stage of the next session
+    pub fn queue_challenge_retry(&self, challenge: Challenge) {
+        *self.retry_challenge.lock().unwrap() = Some(challenge);
+        *self.ghost.lock().unwrap() = None;
+    }
+
+    /// Serve this challenge as the first stage of the next session, raced
+    /// against the ghost of an earlier attempt
+    pub fn queue_ghost_race(&self, challenge: Challenge, ghost: RaceBot) {
+        self.queue_challenge_retry(challenge);
+        *self.ghost.lock().unwrap() = Some(ghost);
+    }
+
+    /// The queued ghost, for the stage about to be played
+    pub fn take_ghost(&self) -> Option<RaceBot> {
+        self.ghost.lock().unwrap().take()
+    }
+
+    /// Breakdown of the failed stage, built from its tracker and challenge
+    pub fn get_failure_analysis(&self) -> Option<FailureAnalysis> {
+        let challenge = self.failed_challenge.lock().unwrap().clone()?;
+        let tracker = self.current_stage_tracker.lock().unwrap().clone()?;
+        let data = tracker.get_data();
+
+        let typed_chars = data
+            .keystrokes
+            .iter()
+            .filter(|keystroke| keystroke.is_correct)
+            .map(|keystroke| keystroke.position + 1)
+            .max()
+            .unwrap_or(0);
+        let mistake_positions: Vec<usize> = data
+            .keystrokes
+            .iter()
+            .filter(|keystroke| !keystroke.is_correct)
+            .map(|keystroke| keystroke.position)
+            .collect();
+
+        Some(FailureAnalysis::new(
+            challenge,
+            &data.target_text,
+            typed_chars,
+            &mistake_positions,
+        ))
+    }
+
     /// Get the next challenge for the current stage using StageRepository
     pub fn get_next_challenge(&self) -> Result<Option<Challenge>> {
         if matches!(*self.state.lock().unwrap(), SessionState::InProgress { .. }) {
-            // Get challenge from StageRepository based on current difficulty setting
-            let stage_repo = self
-                .stage_repository
-                .as_any()
-                .downcast_ref::<StageRepository>()
-                .ok_or_else(|| {
-                    GitTypeError::TerminalError("Failed to downcast StageRepository".to_string())
-                })?;
-            Ok(stage_repo.get_challenge_for_difficulty(self.config.lock().unwrap().difficulty))
+            self.draw_challenge()
         } else {
             Ok(None)
         }
     }
 
+    /// Draw the challenge of the next stage ahead of time so it can be
+    /// previewed; the same challenge is served when the stage starts
+    pub fn preview_next_challenge(&self) -> Result<Option<Challenge>> {
+        if let Some(challenge) = self.upcoming_challenge.lock().unwrap().clone() {
+            return Ok(Some(challenge));
+        }
+        let stage_index = self.session_challenges.lock().unwrap().len();
+        let prefetched = self
+            .prefetched_challenge
+            .lock()
+            .unwrap()
+            .take()
+            .filter(|(index, _)| *index == stage_index);
+        let challenge = match prefetched {
+            Some((_, challenge)) => Some(challenge),
+            None => self.get_next_challenge()?,
+        };
+        self.upcoming_challenge
+            .lock()
+            .unwrap()
+            .clone_from(&challenge);
+        Ok(challenge)
+    }
+
+    /// Draw the challenge of the stage after the one being typed, so it can
+    /// be laid out before the stage changes. It becomes the next preview
+    /// unless a stage is skipped or failed in between.
+    pub fn prefetch_next_challenge(&self) -> Result<Option<Challenge>> {
+        let (current_stage, max_stages) = self.get_stage_info()?;
+        if !self.is_in_progress() || current_stage >= max_stages {
+            return Ok(None);
+        }
+        let next_index = self.session_challenges.lock().unwrap().len() + 1;
+        if let Some((index, challenge)) = self.prefetched_challenge.lock().unwrap().as_ref() {
+            if *index == next_index {
+                return Ok(Some(challenge.clone()));
+            }
+        }
+
+        let challenge = match self.fixed_challenge(next_index) {
+            Some(challenge) => challenge,
+            None => self.get_next_challenge()?,
+        };
+        *self.prefetched_challenge.lock().unwrap() =
+            challenge.clone().map(|challenge| (next_index, challenge));
+        Ok(challenge)
+    }
+
+    /// One re-roll of the previewed challenge is allowed per session, none
+    /// when the challenges are fixed
+    pub fn can_reroll_next_challenge(&self) -> bool {
+        !*self.next_challenge_rerolled.lock().unwrap()
+            && self.fixed_challenges.lock().unwrap().is_none()
+            && !self.is_drilling()
+    }
+
+    /// Replace the previewed challenge with another draw, preferring a
+    /// different challenge when the pool has one
+    pub fn reroll_next_challenge(&self) -> Result<Option<Challenge>> {
+        if !self.can_reroll_next_challenge() {
+            return Ok(self.upcoming_challenge.lock().unwrap().clone());
+        }
+        let previous_id = self
+            .upcoming_challenge
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|challenge| challenge.id.clone());
+
+        let mut challenge = None;
+        for _ in 0..REROLL_ATTEMPTS {
+            challenge = self.get_next_challenge()?;
+            if challenge.as_ref().map(|c| &c.id) != previous_id.as_ref() {
+                break;
+            }
+        }
+
+        if challenge.is_some() {
+            *self.next_challenge_rerolled.lock().unwrap() = true;
+            self.upcoming_challenge
+                .lock()
+                .unwrap()
+                .clone_from(&challenge);
+        }
+        Ok(challenge)
+    }
+
+    /// The drill or fixed challenge at `index` of the session, `None` when
+    /// the challenges are drawn instead
+    fn fixed_challenge(&self, index: usize) -> Option<Option<Challenge>> {
+        if let Some(drills) = self.drills.lock().unwrap().as_ref() {
+            return Some(drills.get(index).cloned());
+        }
+        self.fixed_challenges
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|challenges| challenges.get(index).cloned())
+    }
+
+    /// The drill or fixed challenge of the stage being played, or one drawn
+    /// from the StageRepository for the current difficulty
+    fn draw_challenge(&self) -> Result<Option<Challenge>> {
+        let stage_index = self.session_challenges.lock().unwrap().len();
+        if let Some(challenge) = self.fixed_challenge(stage_index) {
+            return Ok(challenge);
+        }
+        let stage_repo = self
+            .stage_repository
+            .as_any()
+            .downcast_ref::<StageRepository>()
+            .ok_or_else(|| {
+                GitTypeError::TerminalError("Failed to downcast StageRepository".to_string())
+            })?;
+        Ok(stage_repo.get_challenge_for_difficulty(self.config.lock().unwrap().difficulty))
+    }
+
     // ============================================
     // StageTracker Management Methods
     // ============================================
 
+    /// Challenge of the most recently finished stage
+    pub fn get_last_stage_challenge(&self) -> Option<Challenge> {
+        self.session_challenges.lock().unwrap().last().cloned()
+    }
+
+    /// Mistyped positions of the most recently finished stage
+    pub fn get_last_stage_mistake_diff(&self) -> Option<MistakeDiff> {
+        let data = self.stage_trackers.lock().unwrap().last()?.1.get_data();
+        let mistakes: Vec<(usize, char)> = data
+            .keystrokes
+            .iter()
+            .filter(|keystroke| !keystroke.is_correct)
+            .map(|keystroke| (keystroke.position, keystroke.character))
+            .collect();
+        Some(MistakeDiff::new(&data.target_text, &mistakes))
+    }
+
     /// Get current stage number (used by global API)
     fn current_stage(&self) -> usize {
         match *self.state.lock().unwrap() {
@@ -600,10 +1055,12 @@ impl SessionManager {
         target_text: String,
         challenge_path: Option<String>,
     ) -> Result<()> {
-        *self.current_stage_tracker.lock().unwrap() = Some(match challenge_path {
+        let mut tracker = match challenge_path {
             Some(path) => StageTracker::new_with_path(target_text, path),
             None => StageTracker::new(target_text),
-        });
+        };
+        tracker.set_latency_compensation(*self.latency_compensation.lock().unwrap());
+        *self.current_stage_tracker.lock().unwrap() = Some(tracker);
         Ok(())
     }
 
@@ -738,9 +1195,26 @@ impl SessionManager {
                         .push((stage_name, tracker));
                 }
             }
+            if let Some((ansi, html)) = self.pending_screenshot.lock().unwrap().take() {
+                let stage_number = self.stage_trackers.lock().unwrap().len();
+                self.stage_screenshots
+                    .lock()
+                    .unwrap()
+                    .push(StageScreenshot {
+                        stage_number,
+                        ansi,
+                        html,
+                    });
+            }
 
             // Update SessionManager state using reducer pattern
-            self.reduce(SessionAction::CompleteStage(stage_result.clone()))?;
+            self.reduce(SessionAction::CompleteStage(Box::new(stage_result.clone())))?;
+
+            if !DemoMode::is_playing() {
+                self.event_bus.as_event_bus().publish(StageCompleted {
+                    personal_best: self.is_personal_best_session(),
+                });
+            }
 
             Ok(stage_result)
         } else {
@@ -766,7 +1240,27 @@ impl SessionManager {
 
     /// Set difficulty level for the session
     pub fn set_difficulty(&self, difficulty: DifficultyLevel) {
-        self.config.lock().unwrap().difficulty = difficulty;
+        let resolved = self
+            .stage_repository
+            .as_any()
+            .downcast_ref::<StageRepository>()
+            .and_then(|repo| repo.resolve_difficulty(difficulty))
+            .unwrap_or(difficulty);
+        if resolved != difficulty {
+            log::info!(
+                "No {:?} challenges available, falling back to {:?}",
+                difficulty,
+                resolved
+            );
+        }
+        *self.difficulty_fallback.lock().unwrap() =
+            (resolved != difficulty).then_some((difficulty, resolved));
+        self.config.lock().unwrap().difficulty = resolved;
+    }
+
+    /// The (requested, played) tiers when the requested one had no challenges
+    pub fn get_difficulty_fallback(&self) -> Option<(DifficultyLevel, DifficultyLevel)> {
+        *self.difficulty_fallback.lock().unwrap()
     }
 
     /// Get current difficulty level
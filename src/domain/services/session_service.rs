@@ -1,10 +1,11 @@
 use crate::domain::error::Result;
 use crate::domain::models::storage::{SessionResultData, StoredRepository, StoredSession};
 use crate::domain::repositories::session_repository::SessionRepositoryTrait;
+use serde::Serialize;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-#[derive(Clone)]
+#[derive(Clone, Serialize)]
 pub struct SessionDisplayData {
     pub session: StoredSession,
     pub repository: Option<StoredRepository>,
@@ -26,6 +27,8 @@ impl Default for SessionDisplayData {
                 difficulty_level: None,
                 max_stages: None,
                 time_limit_seconds: None,
+                player_name: None,
+                unverified: false,
             },
             repository: None,
             session_result: None,
@@ -41,6 +44,7 @@ pub trait SessionServiceInterface: shaku::Interface {
         sort_by: &str,
         sort_descending: bool,
     ) -> Result<Vec<SessionDisplayData>>;
+    fn get_session_with_display_data(&self, session_id: i64) -> Result<Option<SessionDisplayData>>;
     fn get_all_repositories(&self) -> Result<Vec<StoredRepository>>;
 }
 
@@ -68,6 +72,10 @@ impl SessionServiceInterface for SessionService {
         )
     }
 
+    fn get_session_with_display_data(&self, session_id: i64) -> Result<Option<SessionDisplayData>> {
+        SessionService::get_session_with_display_data(self, session_id)
+    }
+
     fn get_all_repositories(&self) -> Result<Vec<StoredRepository>> {
         SessionService::get_all_repositories(self)
     }
@@ -113,6 +121,29 @@ impl SessionService {
         Ok(session_display_data)
     }
 
+    /// One session with its repository and result; `None` when no session has that id
+    pub fn get_session_with_display_data(
+        &self,
+        session_id: i64,
+    ) -> Result<Option<SessionDisplayData>> {
+        let Some(session) = self.repository.get_session(session_id)? else {
+            return Ok(None);
+        };
+
+        let repository = session
+            .repository_id
+            .map(|id| self.repository.get_repository(id))
+            .transpose()?
+            .flatten();
+        let session_result = self.repository.get_session_result(session.id)?;
+
+        Ok(Some(SessionDisplayData {
+            session,
+            repository,
+            session_result,
+        }))
+    }
+
     pub fn get_all_repositories(&self) -> Result<Vec<StoredRepository>> {
         self.repository.get_all_repositories()
     }
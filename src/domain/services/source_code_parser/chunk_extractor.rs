@@ -257,6 +257,15 @@ impl ChunkExtractor {
             Some(_) => start_line - 1,
         };
 
+        let extract_source = match parent {
+            None => source_code,
+            Some(p) => p.content,
+        };
+
+        let chunk_name = extractor
+            .extract_name(node, extract_source, capture_name)
+            .unwrap_or_else(|| capture_name.to_owned());
+
         let chunk_type = match parent {
             None => {
                 // Standard chunk: use extractor method
@@ -267,6 +276,7 @@ impl ChunkExtractor {
                 extractor.middle_capture_name_to_chunk_type(capture_name)?
             }
         };
+        let chunk_type = extractor.refine_chunk_type(node, &chunk_name, chunk_type);
 
         let final_file_path = match parent {
             None => file_path.to_path_buf(),
@@ -283,15 +293,6 @@ impl ChunkExtractor {
             indent_offset_chars,
         );
 
-        let extract_source = match parent {
-            None => source_code,
-            Some(p) => p.content,
-        };
-
-        let chunk_name = extractor
-            .extract_name(node, extract_source, capture_name)
-            .unwrap_or_else(|| capture_name.to_owned());
-
         Some(CodeChunk {
             name: chunk_name,
             content: normalized_content,
@@ -29,7 +29,7 @@ impl CommentProcessor {
         Ok(comment_ranges)
     }
 
-    fn query_comment_nodes<'a>(
+    pub(super) fn query_comment_nodes<'a>(
         tree: &'a Tree,
         source_code: &str,
         language: &dyn Language,
@@ -3,6 +3,9 @@ pub mod chunk_extractor;
 mod comment_processor;
 mod indent_processor;
 pub mod parsers;
+mod prose_extractor;
+mod secret_scanner;
+mod snippet_extractor;
 #[allow(clippy::module_inception)]
 mod source_code_parser;
 
@@ -10,4 +13,7 @@ pub use cache_builder::CacheBuilder;
 pub use chunk_extractor::{ChunkExtractor, ParentChunk};
 pub use comment_processor::CommentProcessor;
 pub use indent_processor::IndentProcessor;
+pub use prose_extractor::{ProseExtractor, PROSE_LANGUAGE};
+pub use secret_scanner::SecretScanner;
+pub use snippet_extractor::SnippetExtractor;
 pub use source_code_parser::SourceCodeParser;
@@ -25,6 +25,11 @@ impl LanguageExtractor for GoExtractor {
             (type_spec name: (type_identifier) @name type: (array_type)) @type_alias
             (type_spec name: (type_identifier) @name type: (map_type)) @type_alias
             (type_spec name: (type_identifier) @name type: (channel_type)) @type_alias
+            (type_alias name: (type_identifier) @name) @type_alias
+            (type_declaration . (type_spec type: (struct_type)) .) @struct
+            (type_declaration . (type_spec type: (interface_type)) .) @interface
+            (type_declaration . (type_spec type: [(type_identifier) (function_type) (pointer_type) (slice_type) (array_type) (map_type) (channel_type)]) .) @type_alias
+            (type_declaration . (type_alias) .) @type_alias
         "
     }
 
@@ -50,6 +55,11 @@ impl LanguageExtractor for GoExtractor {
         match capture_name {
             "const_block" => Some(self.extract_const_var_names(node, source_code, "const")),
             "var_block" => Some(self.extract_const_var_names(node, source_code, "var")),
+            "method" => self.extract_method_name(node, source_code),
+            // Single type declarations are captured whole so the `type` keyword is typed too
+            _ if node.kind() == "type_declaration" => node
+                .named_child(0)
+                .and_then(|spec| self.extract_name_from_node(spec, source_code)),
             _ => self.extract_name_from_node(node, source_code),
         }
     }
@@ -99,6 +109,33 @@ impl GoExtractor {
         None
     }
 
+    /// `Receiver.Method`, without the pointer or type parameters of the receiver
+    fn extract_method_name(&self, node: Node, source_code: &str) -> Option<String> {
+        let name = node
+            .child_by_field_name("name")?
+            .utf8_text(source_code.as_bytes())
+            .ok()?;
+        let receiver = node
+            .child_by_field_name("receiver")
+            .and_then(|receiver| receiver.named_child(0))
+            .and_then(|parameter| parameter.child_by_field_name("type"))
+            .and_then(|receiver_type| Self::find_type_identifier(receiver_type))
+            .and_then(|receiver_type| receiver_type.utf8_text(source_code.as_bytes()).ok());
+
+        Some(match receiver {
+            Some(receiver) => format!("{}.{}", receiver, name),
+            None => name.to_string(),
+        })
+    }
+
+    fn find_type_identifier(node: Node) -> Option<Node> {
+        if node.kind() == "type_identifier" {
+            return Some(node);
+        }
+        node.named_children(&mut node.walk())
+            .find_map(Self::find_type_identifier)
+    }
+
     fn extract_const_var_names(
         &self,
         node: Node,
@@ -3,11 +3,12 @@ use crate::domain::models::languages::{
     Ruby, Rust, Scala, Swift, TypeScript, Zig, C,
 };
 use crate::domain::models::ChunkType;
-use crate::domain::models::Language;
+use crate::domain::models::{Language, LanguageCapability, Languages};
 use crate::{GitTypeError, Result};
 use once_cell::sync::Lazy;
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::sync::RwLock;
 use tree_sitter::{Node, Parser, Query, Tree};
 
 pub mod c;
@@ -24,6 +25,7 @@ pub mod javascript;
 pub mod kotlin;
 pub mod php;
 pub mod python;
+pub mod react;
 pub mod ruby;
 pub mod rust;
 pub mod scala;
@@ -40,16 +42,55 @@ pub trait LanguageExtractor {
     fn capture_name_to_chunk_type(&self, capture_name: &str) -> Option<ChunkType>;
     fn extract_name(&self, node: Node, source_code: &str, capture_name: &str) -> Option<String>;
 
+    /// Narrows the chunk type of a definition once its node and name are known
+    fn refine_chunk_type(&self, _node: Node, _name: &str, chunk_type: ChunkType) -> ChunkType {
+        chunk_type
+    }
+
+    /// Chunk types only produced by `refine_chunk_type`
+    fn refined_chunk_types(&self) -> Vec<ChunkType> {
+        vec![]
+    }
+
     fn middle_implementation_query(&self) -> &str;
     fn middle_capture_name_to_chunk_type(&self, _capture_name: &str) -> Option<ChunkType>;
 }
 
+/// Patterns loaded from `queries/<lang>.scm` in the app data directory. A
+/// file starting with `; replace` drops the built-in patterns instead of
+/// extending them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryOverride {
+    pub patterns: String,
+    pub replace: bool,
+}
+
+impl QueryOverride {
+    pub const REPLACE_DIRECTIVE: &'static str = "; replace";
+
+    pub fn parse(source: &str) -> Self {
+        Self {
+            patterns: source.to_string(),
+            replace: source.trim_start().starts_with(Self::REPLACE_DIRECTIVE),
+        }
+    }
+
+    pub fn merge(&self, builtin: &str) -> String {
+        if self.replace {
+            self.patterns.clone()
+        } else {
+            format!("{}\n{}", builtin, self.patterns)
+        }
+    }
+}
+
 type ParserFactory = fn() -> Result<Parser>;
 type ExtractorFactory = fn() -> Box<dyn LanguageExtractor>;
 
 pub struct ParserRegistry {
     parsers: HashMap<String, ParserFactory>,
     extractors: HashMap<String, ExtractorFactory>,
+    query_overrides: RwLock<HashMap<String, QueryOverride>>,
 }
 
 impl ParserRegistry {
@@ -57,6 +98,7 @@ impl ParserRegistry {
         let mut registry = Self {
             parsers: HashMap::new(),
             extractors: HashMap::new(),
+            query_overrides: RwLock::new(HashMap::new()),
         };
 
         // Register all supported languages using a macro to reduce repetition
@@ -125,9 +167,12 @@ impl ParserRegistry {
     pub fn create_query(&self, language: &str) -> Result<Query> {
         let extractor = self.get_extractor(language)?;
         let tree_sitter_lang = extractor.tree_sitter_language();
-        let query_str = extractor.query_patterns();
+        let query_str = match self.query_overrides.read().unwrap().get(language) {
+            Some(query_override) => query_override.merge(extractor.query_patterns()),
+            None => extractor.query_patterns().to_string(),
+        };
 
-        Query::new(&tree_sitter_lang, query_str).map_err(|e| {
+        Query::new(&tree_sitter_lang, &query_str).map_err(|e| {
             GitTypeError::ExtractionFailed(format!(
                 "Failed to create query for {}: {}",
                 language, e
@@ -135,6 +180,34 @@ impl ParserRegistry {
         })
     }
 
+    /// Compiles the built-in patterns of `language` merged with `query_override`
+    pub fn create_query_with(
+        &self,
+        language: &str,
+        query_override: &QueryOverride,
+    ) -> Result<Query> {
+        let extractor = self.get_extractor(language)?;
+        let query_str = query_override.merge(extractor.query_patterns());
+
+        Query::new(&extractor.tree_sitter_language(), &query_str).map_err(|e| {
+            GitTypeError::ExtractionFailed(format!(
+                "Invalid query override for {}: {}",
+                language, e
+            ))
+        })
+    }
+
+    /// Uses `query_override` for every later `create_query` of `language`;
+    /// patterns that fail to compile are rejected and the built-ins kept
+    pub fn set_query_override(&self, language: &str, query_override: QueryOverride) -> Result<()> {
+        self.create_query_with(language, &query_override)?;
+        self.query_overrides
+            .write()
+            .unwrap()
+            .insert(language.to_string(), query_override);
+        Ok(())
+    }
+
     pub fn create_comment_query(&self, language: &str) -> Result<Query> {
         let extractor = self.get_extractor(language)?;
         let tree_sitter_lang = extractor.tree_sitter_language();
@@ -171,6 +244,47 @@ impl ParserRegistry {
     pub fn supported_languages(&self) -> Vec<String> {
         self.parsers.keys().cloned().collect()
     }
+
+    /// Extensions and chunk types of `language`, read from its extractor's queries
+    pub fn capability(&self, language: &dyn Language) -> Result<LanguageCapability> {
+        let extractor = self.get_extractor(language.name())?;
+        let query = self.create_query(language.name())?;
+
+        let mut chunk_types: Vec<ChunkType> = Vec::new();
+        query
+            .capture_names()
+            .iter()
+            .filter_map(|capture_name| extractor.capture_name_to_chunk_type(capture_name))
+            .chain(extractor.refined_chunk_types())
+            // Name captures are reported as code blocks, not definitions
+            .filter(|chunk_type| *chunk_type != ChunkType::CodeBlock)
+            .for_each(|chunk_type| {
+                if !chunk_types.contains(&chunk_type) {
+                    chunk_types.push(chunk_type);
+                }
+            });
+
+        Ok(LanguageCapability {
+            name: language.name().to_string(),
+            display_name: language.display_name().to_string(),
+            aliases: language.aliases().iter().map(|a| a.to_string()).collect(),
+            extensions: language
+                .extensions()
+                .iter()
+                .map(|e| e.to_string())
+                .collect(),
+            chunk_types,
+            has_middle_implementation: !extractor.middle_implementation_query().trim().is_empty(),
+        })
+    }
+
+    /// Capabilities of every registered language, in `Languages::all_languages` order
+    pub fn capabilities(&self) -> Vec<LanguageCapability> {
+        Languages::all_languages()
+            .iter()
+            .filter_map(|language| self.capability(language.as_ref()).ok())
+            .collect()
+    }
 }
 
 static REGISTRY: Lazy<ParserRegistry> = Lazy::new(ParserRegistry::new);
@@ -14,6 +14,8 @@ impl LanguageExtractor for PythonExtractor {
         "
             (function_definition name: (identifier) @name) @function
             (class_definition name: (identifier) @name) @class
+            (decorated_definition definition: (function_definition)) @decorated_function
+            (decorated_definition definition: (class_definition)) @decorated_class
         "
     }
 
@@ -25,13 +27,55 @@ impl LanguageExtractor for PythonExtractor {
         match capture_name {
             "function" => Some(ChunkType::Function),
             "class" => Some(ChunkType::Class),
+            "decorated_function" => Some(ChunkType::DecoratedFunction),
+            "decorated_class" => Some(ChunkType::Class),
             "name" => Some(ChunkType::CodeBlock),
             _ => None,
         }
     }
 
-    fn extract_name(&self, node: Node, source_code: &str, _capture_name: &str) -> Option<String> {
-        self.extract_name_from_node(node, source_code)
+    fn extract_name(&self, node: Node, source_code: &str, capture_name: &str) -> Option<String> {
+        let definition = Self::definition_of(node);
+        let name = self.extract_name_from_node(definition, source_code)?;
+        if !matches!(
+            capture_name,
+            "function" | "decorated_function" | "nested_function"
+        ) {
+            return Some(name);
+        }
+
+        // Nested functions are named after their enclosing functions: `outer.inner`
+        let enclosing: Vec<String> = std::iter::successors(definition.parent(), Node::parent)
+            .filter(|ancestor| ancestor.kind() == "function_definition")
+            .filter_map(|ancestor| self.extract_name_from_node(ancestor, source_code))
+            .collect();
+
+        Some(
+            enclosing
+                .into_iter()
+                .rev()
+                .chain(std::iter::once(name))
+                .collect::<Vec<_>>()
+                .join("."),
+        )
+    }
+
+    fn refine_chunk_type(&self, node: Node, _name: &str, chunk_type: ChunkType) -> ChunkType {
+        let definition = Self::definition_of(node);
+        let mut cursor = definition.walk();
+        let is_async = chunk_type == ChunkType::Function
+            && definition
+                .children(&mut cursor)
+                .any(|child| child.kind() == "async");
+        if is_async {
+            ChunkType::AsyncFunction
+        } else {
+            chunk_type
+        }
+    }
+
+    fn refined_chunk_types(&self) -> Vec<ChunkType> {
+        vec![ChunkType::AsyncFunction]
     }
 
     fn middle_implementation_query(&self) -> &str {
@@ -65,6 +109,14 @@ impl LanguageExtractor for PythonExtractor {
 }
 
 impl PythonExtractor {
+    /// The function or class a decorated definition wraps
+    fn definition_of(node: Node) -> Node {
+        match node.kind() {
+            "decorated_definition" => node.child_by_field_name("definition").unwrap_or(node),
+            _ => node,
+        }
+    }
+
     fn extract_name_from_node(&self, node: Node, source_code: &str) -> Option<String> {
         let mut cursor = node.walk();
         if cursor.goto_first_child() {
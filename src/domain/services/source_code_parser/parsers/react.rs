@@ -0,0 +1,44 @@
+use crate::domain::models::ChunkType;
+use tree_sitter::Node;
+
+const JSX_KINDS: [&str; 3] = ["jsx_element", "jsx_self_closing_element", "jsx_fragment"];
+
+/// Function chunks named `useSomething` become hooks, and capitalized
+/// functions returning JSX become components
+pub fn refine_chunk_type(node: Node, name: &str, chunk_type: ChunkType) -> ChunkType {
+    if chunk_type != ChunkType::Function {
+        return chunk_type;
+    }
+    if is_hook_name(name) {
+        ChunkType::Hook
+    } else if name.starts_with(|c: char| c.is_ascii_uppercase()) && returns_jsx(node) {
+        ChunkType::Component
+    } else {
+        chunk_type
+    }
+}
+
+pub fn is_hook_name(name: &str) -> bool {
+    name.strip_prefix("use")
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+fn returns_jsx(node: Node) -> bool {
+    let returned = match node.kind() {
+        "return_statement" => node.named_child(0),
+        "arrow_function" => node.child_by_field_name("body"),
+        _ => None,
+    };
+    if returned.is_some_and(is_jsx) {
+        return true;
+    }
+
+    node.named_children(&mut node.walk()).any(returns_jsx)
+}
+
+fn is_jsx(node: Node) -> bool {
+    match node.kind() {
+        "parenthesized_expression" => node.named_child(0).is_some_and(is_jsx),
+        kind => JSX_KINDS.contains(&kind),
+    }
+}
@@ -13,7 +13,8 @@ impl LanguageExtractor for RustExtractor {
     fn query_patterns(&self) -> &str {
         "
             (function_item name: (identifier) @name) @function
-            (impl_item type: (type_identifier) @name) @impl
+            (impl_item) @impl
+            (macro_definition name: (identifier)) @macro
             (struct_item name: (type_identifier) @name) @struct
             (enum_item name: (type_identifier) @name) @enum
             (trait_item name: (type_identifier) @name) @trait
@@ -30,6 +31,7 @@ impl LanguageExtractor for RustExtractor {
         match capture_name {
             "function" => Some(ChunkType::Function),
             "impl" => Some(ChunkType::Class),
+            "macro" => Some(ChunkType::Macro),
             "struct" => Some(ChunkType::Struct),
             "enum" => Some(ChunkType::Enum),
             "trait" => Some(ChunkType::Trait),
@@ -40,8 +42,11 @@ impl LanguageExtractor for RustExtractor {
         }
     }
 
-    fn extract_name(&self, node: Node, source_code: &str, _capture_name: &str) -> Option<String> {
-        self.extract_name_from_node(node, source_code)
+    fn extract_name(&self, node: Node, source_code: &str, capture_name: &str) -> Option<String> {
+        match capture_name {
+            "impl" => self.extract_impl_name(node, source_code),
+            _ => self.extract_name_from_node(node, source_code),
+        }
     }
 
     fn middle_implementation_query(&self) -> &str {
@@ -90,6 +95,19 @@ impl RustExtractor {
         None
     }
 
+    /// `Type` for inherent impls and `Trait for Type` for trait impls
+    fn extract_impl_name(&self, node: Node, source_code: &str) -> Option<String> {
+        let text = |field| {
+            node.child_by_field_name(field)
+                .and_then(|child| child.utf8_text(source_code.as_bytes()).ok())
+        };
+        let type_name = text("type")?;
+        Some(match text("trait") {
+            Some(trait_name) => format!("{} for {}", trait_name, type_name),
+            None => type_name.to_string(),
+        })
+    }
+
     pub fn create_parser() -> Result<Parser> {
         let mut parser = Parser::new();
         parser
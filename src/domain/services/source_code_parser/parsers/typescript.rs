@@ -1,4 +1,4 @@
-use super::LanguageExtractor;
+use super::{react, LanguageExtractor};
 use crate::domain::models::ChunkType;
 use crate::{GitTypeError, Result};
 use tree_sitter::{Node, Parser};
@@ -86,6 +86,14 @@ impl LanguageExtractor for TypeScriptExtractor {
         }
     }
 
+    fn refine_chunk_type(&self, node: Node, name: &str, chunk_type: ChunkType) -> ChunkType {
+        react::refine_chunk_type(node, name, chunk_type)
+    }
+
+    fn refined_chunk_types(&self) -> Vec<ChunkType> {
+        vec![ChunkType::Component, ChunkType::Hook]
+    }
+
     fn middle_implementation_query(&self) -> &str {
         "
         (for_statement) @for_loop
@@ -0,0 +1,188 @@
+use super::CommentProcessor;
+use crate::domain::models::{ChunkType, CodeChunk, Language};
+use crate::Result;
+use std::path::Path;
+use tree_sitter::Tree;
+
+/// Language tag of challenges built from comments and README prose
+pub const PROSE_LANGUAGE: &str = "prose";
+
+/// Shortest comment block or paragraph, in words, worth typing
+const MIN_WORDS: usize = 8;
+
+/// Column prose is rewrapped at
+const WRAP_WIDTH: usize = 72;
+
+/// Words of a prose chunk used as its name
+const NAME_WORDS: usize = 4;
+
+/// Comment markers stripped from the start of each line, longest first
+const COMMENT_MARKERS: [&str; 14] = [
+    "///", "//!", "/**", "-- |", "//", "/*", "--", ";;", "%%", "##", "#", "*", ";", "%",
+];
+
+/// Line endings that mark commented-out code rather than prose
+const CODE_LINE_ENDINGS: [char; 4] = [';', '{', '}', '='];
+
+/// Line prefixes of markdown that is not prose: headings, tables, HTML,
+/// images, badges, rules and link definitions
+const MARKDOWN_MARKUP_PREFIXES: [&str; 8] = ["#", "|", "<", "![", "[![", "---", "===", "[//]"];
+
+/// Builds prose challenges from comment blocks and README paragraphs
+pub struct ProseExtractor;
+
+impl ProseExtractor {
+    /// Comment blocks of a parsed file, where comments on consecutive lines
+    /// form one block
+    pub fn extract_from_comments(
+        tree: &Tree,
+        source_code: &str,
+        file_path: &Path,
+        language: &dyn Language,
+    ) -> Result<Vec<CodeChunk>> {
+        let mut nodes = CommentProcessor::query_comment_nodes(tree, source_code, language)?;
+        nodes.sort_by_key(|node| node.start_byte());
+
+        let blocks = nodes.iter().fold(
+            Vec::<(usize, usize, Vec<&str>)>::new(),
+            |mut blocks, node| {
+                let text = &source_code[node.byte_range()];
+                let start_line = node.start_position().row + 1;
+                // Line comments may end at column 0 of the next line, after their newline
+                let end = node.end_position();
+                let end_line = match end.column {
+                    0 if end.row + 1 > start_line => end.row,
+                    _ => end.row + 1,
+                };
+                match blocks.last_mut() {
+                    Some(block) if block.1 + 1 >= start_line => {
+                        block.1 = end_line;
+                        block.2.push(text);
+                    }
+                    _ => blocks.push((start_line, end_line, vec![text])),
+                }
+                blocks
+            },
+        );
+
+        Ok(blocks
+            .into_iter()
+            .filter_map(|(start_line, end_line, texts)| {
+                let lines: Vec<String> = texts
+                    .iter()
+                    .flat_map(|text| text.lines())
+                    .map(|line| Self::strip_comment_markers(line).to_string())
+                    .collect();
+                if lines
+                    .iter()
+                    .any(|line| line.ends_with(CODE_LINE_ENDINGS.as_slice()))
+                {
+                    return None;
+                }
+                Self::build_chunk(&lines, file_path, start_line, end_line)
+            })
+            .collect())
+    }
+
+    /// Paragraphs of a markdown file, skipping code fences and markup lines
+    pub fn extract_from_markdown(content: &str, file_path: &Path) -> Vec<CodeChunk> {
+        let mut in_fence = false;
+        let lines: Vec<Option<(usize, &str)>> = content
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                let trimmed = line.trim();
+                if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                    in_fence = !in_fence;
+                    return None;
+                }
+                let is_markup = MARKDOWN_MARKUP_PREFIXES
+                    .iter()
+                    .any(|prefix| trimmed.starts_with(prefix));
+                (!in_fence && !trimmed.is_empty() && !is_markup && !line.starts_with("    "))
+                    .then_some((index + 1, trimmed))
+            })
+            .collect();
+
+        lines
+            .split(Option::is_none)
+            .filter_map(|paragraph| {
+                let paragraph: Vec<(usize, &str)> = paragraph.iter().flatten().copied().collect();
+                let start_line = paragraph.first()?.0;
+                let end_line = paragraph.last()?.0;
+                let lines: Vec<String> = paragraph
+                    .iter()
+                    .map(|(_, line)| Self::strip_inline_markup(line))
+                    .collect();
+                Self::build_chunk(&lines, file_path, start_line, end_line)
+            })
+            .collect()
+    }
+
+    fn build_chunk(
+        lines: &[String],
+        file_path: &Path,
+        start_line: usize,
+        end_line: usize,
+    ) -> Option<CodeChunk> {
+        let words: Vec<&str> = lines.iter().flat_map(|l| l.split_whitespace()).collect();
+        if words.len() < MIN_WORDS {
+            return None;
+        }
+
+        Some(CodeChunk {
+            content: Self::wrap(&words),
+            file_path: file_path.to_path_buf(),
+            start_line,
+            end_line,
+            language: PROSE_LANGUAGE.to_string(),
+            chunk_type: ChunkType::Prose,
+            name: words[..NAME_WORDS].join(" "),
+            comment_ranges: Vec::new(),
+            original_indentation: 0,
+        })
+    }
+
+    fn strip_comment_markers(line: &str) -> &str {
+        let line = line.trim();
+        let line = line.strip_suffix("*/").unwrap_or(line).trim_end();
+        COMMENT_MARKERS
+            .iter()
+            .find_map(|marker| line.strip_prefix(marker))
+            .unwrap_or(line)
+            .trim()
+    }
+
+    /// Drops emphasis and code markers and keeps only the text of links
+    fn strip_inline_markup(line: &str) -> String {
+        let mut text = String::with_capacity(line.len());
+        let mut chars = line.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '`' | '*' | '[' => {}
+                ']' if chars.peek() == Some(&'(') => {
+                    chars.by_ref().take_while(|&c| c != ')').for_each(drop);
+                }
+                ']' => {}
+                _ => text.push(ch),
+            }
+        }
+        text
+    }
+
+    fn wrap(words: &[&str]) -> String {
+        words
+            .iter()
+            .fold(Vec::<String>::new(), |mut lines, word| {
+                match lines.last_mut() {
+                    Some(line) if line.len() + 1 + word.len() <= WRAP_WIDTH => {
+                        line.push(' ');
+                        line.push_str(word);
+                    }
+                    _ => lines.push(word.to_string()),
+                }
+                lines
+            })
+            .join("\n")
+    }
+}
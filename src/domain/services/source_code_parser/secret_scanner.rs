@@ -0,0 +1,212 @@
+use crate::domain::models::config::SecretHandling;
+use crate::domain::models::CodeChunk;
+use std::ops::Range;
+
+/// Prefixes of well-known credential formats with the shortest full token length
+const TOKEN_PREFIXES: [(&str, usize); 18] = [
+    ("ghp_", 40), // GitHub personal access token
+    ("gho_", 40), // GitHub OAuth token
+    ("ghu_", 40), // GitHub user-to-server token
+    ("ghs_", 40), // GitHub server-to-server token
+    ("ghr_", 40), // GitHub refresh token
+    ("github_pat_", 40),
+    ("glpat-", 26), // GitLab personal access token
+    ("xoxb-", 24),  // Slack bot token
+    ("xoxp-", 24),  // Slack user token
+    ("xoxa-", 24),
+    ("xoxs-", 24),
+    ("sk_live_", 24), // Stripe secret key
+    ("rk_live_", 24), // Stripe restricted key
+    ("sk-", 40),      // OpenAI and Anthropic style API keys
+    ("AIza", 39),     // Google API key
+    ("AKIA", 20),     // AWS access key id
+    ("ASIA", 20),     // AWS temporary access key id
+    ("npm_", 40),
+];
+
+/// Names whose string literal values are treated as credentials
+const SECRET_NAMES: [&str; 9] = [
+    "password",
+    "passwd",
+    "secret",
+    "token",
+    "api_key",
+    "apikey",
+    "api-key",
+    "access_key",
+    "private_key",
+];
+
+/// Shortest string literal assigned to a secret name that counts as a credential;
+/// placeholders such as `"changeme"` are shorter or lack digits
+const MIN_SECRET_LITERAL_LEN: usize = 12;
+
+const PRIVATE_KEY_BEGIN: &str = "-----BEGIN ";
+const PRIVATE_KEY_MARKER: &str = "PRIVATE KEY-----";
+const PRIVATE_KEY_END: &str = "-----END ";
+
+/// Finds likely credentials in extracted chunks so that typing screens and
+/// share cards never show them
+pub struct SecretScanner;
+
+impl SecretScanner {
+    /// The chunk as it may be played: unchanged without secrets, otherwise
+    /// dropped or masked per `handling`
+    pub fn redact(chunk: CodeChunk, handling: SecretHandling) -> Option<CodeChunk> {
+        let location = format!(
+            "{}:{}-{}",
+            chunk.file_path.display(),
+            chunk.start_line,
+            chunk.end_line
+        );
+        let content = Self::redact_text(chunk.content, handling, &location)?;
+        Some(CodeChunk { content, ..chunk })
+    }
+
+    /// `content` unchanged without secrets, otherwise `None` or masked per `handling`;
+    /// `location` only names the source in the log
+    pub fn redact_text(
+        content: String,
+        handling: SecretHandling,
+        location: &str,
+    ) -> Option<String> {
+        let secrets = Self::find(&content);
+        if secrets.is_empty() {
+            return Some(content);
+        }
+
+        log::info!("{} likely secret(s) in {}", secrets.len(), location);
+        match handling {
+            SecretHandling::Skip => None,
+            SecretHandling::Mask => Some(Self::mask(&content, &secrets)),
+        }
+    }
+
+    /// Byte ranges of the likely secrets in `content`, sorted and non-overlapping
+    pub fn find(content: &str) -> Vec<Range<usize>> {
+        let mut ranges: Vec<Range<usize>> = Self::private_key_blocks(content)
+            .into_iter()
+            .chain(Self::prefixed_tokens(content))
+            .chain(Self::assigned_literals(content))
+            .collect();
+        ranges.sort_by_key(|range| range.start);
+
+        ranges.into_iter().fold(Vec::new(), |mut merged, range| {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+            merged
+        })
+    }
+
+    /// Replaces every non-whitespace character in `ranges` with `*`, keeping the
+    /// character count and line breaks so comment ranges and line numbers still hold
+    pub fn mask(content: &str, ranges: &[Range<usize>]) -> String {
+        content
+            .char_indices()
+            .map(|(index, ch)| {
+                let is_secret = ranges.iter().any(|range| range.contains(&index));
+                if is_secret && !ch.is_whitespace() {
+                    '*'
+                } else {
+                    ch
+                }
+            })
+            .collect()
+    }
+
+    /// Bodies of PEM private key blocks, up to the end marker or the end of the chunk
+    fn private_key_blocks(content: &str) -> Vec<Range<usize>> {
+        let mut blocks = Vec::new();
+        let mut from = 0;
+        while let Some(begin) = content[from..].find(PRIVATE_KEY_BEGIN).map(|i| from + i) {
+            let header_end = content[begin..]
+                .find('\n')
+                .map_or(content.len(), |i| begin + i);
+            if !content[begin..header_end].contains(PRIVATE_KEY_MARKER) {
+                from = header_end;
+                continue;
+            }
+
+            let end = content[header_end..]
+                .find(PRIVATE_KEY_END)
+                .map_or(content.len(), |i| header_end + i);
+            blocks.push(header_end..end);
+            from = end;
+        }
+        blocks
+    }
+
+    /// Words that start like a known token format and are long enough to be one
+    fn prefixed_tokens(content: &str) -> Vec<Range<usize>> {
+        Self::words(content)
+            .filter(|(_, word)| {
+                word.chars().any(|ch| ch.is_ascii_digit())
+                    && TOKEN_PREFIXES
+                        .iter()
+                        .any(|(prefix, min_len)| word.starts_with(prefix) && word.len() >= *min_len)
+            })
+            .map(|(start, word)| start..start + word.len())
+            .collect()
+    }
+
+    /// String literals assigned to or compared with a secret-sounding name,
+    /// like `api_key = "..."` or `"password": "..."`
+    fn assigned_literals(content: &str) -> Vec<Range<usize>> {
+        let lowercase = content.to_ascii_lowercase();
+        let mut literals = Vec::new();
+        for name in SECRET_NAMES {
+            let mut from = 0;
+            while let Some(found) = lowercase[from..].find(name).map(|i| from + i) {
+                from = found + name.len();
+                if let Some(literal) = Self::literal_after_name(content, from) {
+                    literals.push(literal);
+                }
+            }
+        }
+        literals
+    }
+
+    /// The string literal following the rest of a name at `from`, its closing
+    /// quote or bracket and an assignment or comparison operator
+    fn literal_after_name(content: &str, from: usize) -> Option<Range<usize>> {
+        let rest = &content[from..];
+        let rest = rest.trim_start_matches(|ch: char| Self::is_token_char(ch) || ch == '.');
+        let rest = rest.trim_start_matches(['"', '\'', ']', ' ', '\t']);
+        let operator_len = rest.len() - rest.trim_start_matches(['=', ':', '>', '!']).len();
+        if operator_len == 0 || operator_len > 3 {
+            return None;
+        }
+        let rest = rest[operator_len..].trim_start_matches([' ', '\t']);
+
+        let quote = rest.chars().next().filter(|ch| matches!(ch, '"' | '\''))?;
+        let value = &rest[1..];
+        let value = &value[..value.find(quote)?];
+        let is_path = value.starts_with(['/', '.']) || value.contains("://");
+        let is_credential = value.len() >= MIN_SECRET_LITERAL_LEN
+            && !is_path
+            && !value
+                .chars()
+                .any(|ch| ch.is_whitespace() || matches!(ch, '$' | '{' | '%'))
+            && value.chars().any(|ch| ch.is_ascii_digit())
+            && value.chars().any(|ch| ch.is_ascii_alphabetic());
+        if !is_credential {
+            return None;
+        }
+
+        let start = content.len() - rest.len() + 1;
+        Some(start..start + value.len())
+    }
+
+    fn words(content: &str) -> impl Iterator<Item = (usize, &str)> {
+        content
+            .split(|ch: char| !Self::is_token_char(ch))
+            .filter(|word| !word.is_empty())
+            .map(move |word| (word.as_ptr() as usize - content.as_ptr() as usize, word))
+    }
+
+    fn is_token_char(ch: char) -> bool {
+        ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-')
+    }
+}
@@ -0,0 +1,57 @@
+use super::{SecretScanner, PROSE_LANGUAGE};
+use crate::domain::models::{ChunkType, CodeChunk, Languages};
+use std::path::{Path, PathBuf};
+
+/// Turns each file of a hand-curated snippet directory into one whole chunk,
+/// typed exactly as written without parsing or splitting
+pub struct SnippetExtractor;
+
+impl SnippetExtractor {
+    /// Files are read as text; unreadable and blank files are skipped. The
+    /// language comes from the extension, and anything unrecognised is prose.
+    /// Files with likely secrets are skipped or masked like extracted chunks.
+    pub fn extract(snippet_dir: &Path, files: &[PathBuf]) -> Vec<CodeChunk> {
+        let secrets = Languages::extraction().secrets;
+        files
+            .iter()
+            .filter_map(|path| {
+                let content = std::fs::read_to_string(path)
+                    .map_err(|e| log::warn!("Skipping snippet {}: {}", path.display(), e))
+                    .ok()?;
+                Self::build_chunk(snippet_dir, path, &content)
+            })
+            .filter_map(|chunk| SecretScanner::redact(chunk, secrets))
+            .collect()
+    }
+
+    pub fn detect_language(path: &Path) -> String {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(Languages::from_extension)
+            .map(|language| language.name().to_string())
+            .unwrap_or_else(|| PROSE_LANGUAGE.to_string())
+    }
+
+    fn build_chunk(snippet_dir: &Path, path: &Path, content: &str) -> Option<CodeChunk> {
+        let content = content.replace("\r\n", "\n").trim_end().to_string();
+        if content.trim().is_empty() {
+            return None;
+        }
+
+        let relative_path = path.strip_prefix(snippet_dir).unwrap_or(path);
+        Some(CodeChunk {
+            end_line: content.lines().count(),
+            content,
+            file_path: relative_path.to_path_buf(),
+            start_line: 1,
+            language: Self::detect_language(path),
+            chunk_type: ChunkType::File,
+            name: relative_path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            comment_ranges: Vec::new(),
+            original_indentation: 0,
+        })
+    }
+}
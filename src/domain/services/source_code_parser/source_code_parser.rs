@@ -1,12 +1,14 @@
+use crate::domain::models::editor_config::EDITOR_CONFIG_FILE;
+use crate::domain::models::loading::ProgressReporter;
 use crate::domain::models::loading::StepType;
-use crate::domain::models::Language;
-use crate::domain::models::{CodeChunk, ExtractionOptions};
+use crate::domain::models::{CodeChunk, EditorConfig, ExtractionOptions, Indentation};
+use crate::domain::models::{Language, Languages};
 use crate::domain::services::source_code_parser::parsers::parse_with_thread_local;
-use crate::domain::services::source_code_parser::ChunkExtractor;
+use crate::domain::services::source_code_parser::{ChunkExtractor, ProseExtractor, SecretScanner};
+use crate::domain::services::text_processor::TextProcessor;
 use crate::infrastructure::git::LocalGitRepositoryClient;
 use crate::infrastructure::storage::file_storage::FileStorage;
 use crate::infrastructure::storage::file_storage::FileStorageInterface;
-use crate::presentation::tui::screens::loading_screen::ProgressReporter;
 use crate::{GitTypeError, Result};
 use rayon::prelude::*;
 use std::path::{Path, PathBuf};
@@ -37,7 +39,7 @@ impl SourceCodeParser {
         progress: &P,
     ) -> Result<Vec<CodeChunk>> {
         let git_root = Self::find_git_root(&files_to_process)?;
-        let valid_files = self.filter_and_sort_files(files_to_process, options);
+        let valid_files = self.sort_by_size(files_to_process);
         let valid_files_count = valid_files.len();
 
         // Initialize extracting progress from 0
@@ -45,8 +47,10 @@ impl SourceCodeParser {
         progress.set_file_counts(StepType::Extracting, 0, valid_files_count, None);
 
         let file_storage = self.file_storage.clone();
+        let secrets = Languages::extraction().secrets;
         let all_chunks: Vec<CodeChunk> = valid_files
             .into_par_iter()
+            .filter(|_| !progress.is_cancelled())
             .inspect(|_| {
                 let current = processed.fetch_add(1, Ordering::Relaxed) + 1;
                 Self::update_progress_if_needed(progress, current, valid_files_count);
@@ -55,16 +59,34 @@ impl SourceCodeParser {
                 Self::read_and_parse_file(&file_storage, &git_root, &path, language).into_par_iter()
             })
             .flat_map(|(tree, content, file_path, git_root, language)| {
-                ChunkExtractor::extract_chunks_from_tree(
+                let mut chunks = ChunkExtractor::extract_chunks_from_tree(
                     &tree,
                     &content,
                     &file_path,
                     &git_root,
                     language.as_ref(),
                 )
-                .unwrap_or_default()
+                .unwrap_or_default();
+                if options.include_prose {
+                    let relative_path = file_path.strip_prefix(&git_root).unwrap_or(&file_path);
+                    chunks.extend(
+                        ProseExtractor::extract_from_comments(
+                            &tree,
+                            &content,
+                            relative_path,
+                            language.as_ref(),
+                        )
+                        .unwrap_or_default(),
+                    );
+                }
+                chunks
             })
+            .chain(self.extract_readme_prose(&git_root, options))
+            // Chunks with undecodable bytes can't be typed
+            .filter(|chunk| !chunk.content.contains(char::REPLACEMENT_CHARACTER))
+            .filter_map(|chunk| SecretScanner::redact(chunk, secrets))
             .collect();
+        progress.check_cancelled()?;
 
         // Get final count and ensure final progress is exactly 100%
         let final_count = processed.load(Ordering::Relaxed);
@@ -74,6 +96,41 @@ impl SourceCodeParser {
         Ok(all_chunks)
     }
 
+    /// Paragraphs of the README files at the repository root in prose mode;
+    /// commit-scoped extraction only covers the committed files, so it has none
+    fn extract_readme_prose(&self, git_root: &Path, options: &ExtractionOptions) -> Vec<CodeChunk> {
+        if !options.include_prose || options.is_commit_scoped() {
+            return Vec::new();
+        }
+        let Ok(entries) = self.file_storage.read_dir(git_root) else {
+            return Vec::new();
+        };
+
+        entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                let is_readme = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.to_uppercase().starts_with("README"));
+                let is_markdown = path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_none_or(|ext| matches!(ext, "md" | "markdown" | "txt"));
+                is_readme && is_markdown
+            })
+            .filter_map(|path| {
+                let content = self.file_storage.read_to_string(&path).ok()?;
+                let relative_path = path.strip_prefix(git_root).unwrap_or(&path);
+                Some(ProseExtractor::extract_from_markdown(
+                    &content,
+                    relative_path,
+                ))
+            })
+            .flatten()
+            .collect()
+    }
+
     fn find_git_root(files_to_process: &[(PathBuf, Box<dyn Language>)]) -> Result<PathBuf> {
         files_to_process
             .first()
@@ -82,27 +139,17 @@ impl SourceCodeParser {
             .ok_or_else(|| GitTypeError::ExtractionFailed("Git repository not found".to_string()))
     }
 
-    fn filter_and_sort_files(
+    /// Files over the size limit were already left out by `SourceFileExtractor`
+    fn sort_by_size(
         &self,
         files_to_process: Vec<(PathBuf, Box<dyn Language>)>,
-        options: &ExtractionOptions,
     ) -> Vec<(PathBuf, Box<dyn Language>, u64)> {
         let file_storage = self.file_storage.clone();
         let mut valid_files: Vec<_> = files_to_process
             .into_par_iter()
-            .filter_map(|(path, lang)| {
+            .map(|(path, lang)| {
                 let size = file_storage.metadata(&path).map(|m| m.len()).unwrap_or(0);
-                if size > options.max_file_size_bytes {
-                    log::warn!(
-                        "Skipping large file: {:?} ({}MB > {}MB limit)",
-                        path,
-                        size / (1024 * 1024),
-                        options.max_file_size_bytes / (1024 * 1024)
-                    );
-                    None
-                } else {
-                    Some((path, lang, size))
-                }
+                (path, lang, size)
             })
             .collect();
 
@@ -140,7 +187,21 @@ impl SourceCodeParser {
         PathBuf,
         Box<dyn Language>,
     )> {
-        let content = file_storage.read_to_string(file_path).ok()?;
+        let bytes = file_storage
+            .read(file_path)
+            .map_err(|e| log::warn!("Skipping unreadable file {:?}: {}", file_path, e))
+            .ok()?;
+        let (content, lossy) = TextProcessor::decode_source(&bytes);
+        if lossy {
+            log::warn!(
+                "Invalid UTF-8 in {:?}; skipping challenges containing undecodable bytes",
+                file_path
+            );
+        }
+        let content = Self::indentation_for(file_storage, git_root, file_path)
+            .apply(&content)
+            .into_owned();
+        let language = Languages::refine_header(file_path, &content, language);
         let tree = parse_with_thread_local(language.name(), &content)?;
 
         Some((
@@ -151,4 +212,30 @@ impl SourceCodeParser {
             language,
         ))
     }
+
+    /// Merged `.editorconfig` indentation for `file_path`, from its directory
+    /// up to the git root or the first file marked `root = true`
+    fn indentation_for(
+        file_storage: &FileStorage,
+        git_root: &Path,
+        file_path: &Path,
+    ) -> Indentation {
+        let mut indentation = Indentation::default();
+        for dir in file_path
+            .ancestors()
+            .skip(1)
+            .take_while(|dir| dir.starts_with(git_root))
+        {
+            let Ok(content) = file_storage.read_to_string(&dir.join(EDITOR_CONFIG_FILE)) else {
+                continue;
+            };
+            let config = EditorConfig::parse(&content);
+            let relative_path = file_path.strip_prefix(dir).unwrap_or(file_path);
+            indentation = indentation.or(config.indentation_for(relative_path));
+            if config.root {
+                break;
+            }
+        }
+        indentation
+    }
 }
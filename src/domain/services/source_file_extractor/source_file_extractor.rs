@@ -1,12 +1,17 @@
+use crate::domain::models::loading::ProgressReporter;
 use crate::domain::models::loading::StepType;
 use crate::domain::models::{ExtractionOptions, Languages};
+use crate::domain::services::text_processor::TextProcessor;
+use crate::domain::services::workspace_detector::WorkspaceDetector;
 use crate::infrastructure::storage::file_storage::FileStorage;
 use crate::infrastructure::storage::file_storage::FileStorageInterface;
-use crate::presentation::tui::screens::loading_screen::ProgressReporter;
 use crate::Result;
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use std::path::{Path, PathBuf};
 
+/// Bytes inspected for NUL when sniffing binary files, matching git's heuristic
+const BINARY_SNIFF_BYTES: usize = 8000;
+
 pub struct SourceFileExtractor {
     file_storage: FileStorage,
 }
@@ -53,16 +58,45 @@ impl SourceFileExtractor {
         let exclude_patterns = compile_patterns(&options.exclude_patterns);
         let gittypeignore_matcher = self.load_gittypeignore_matcher(repo_path);
 
-        let total_files_estimated = self.count_files(repo_path)?;
+        let total_files_estimated = self.count_files(repo_path, options.follow_symlinks)?;
+        progress.check_cancelled()?;
 
         let files = self.collect_files(
             repo_path,
+            options.follow_symlinks,
             &compile_patterns(&options.include_patterns),
             &exclude_patterns,
             gittypeignore_matcher.as_ref(),
             total_files_estimated,
             progress,
         )?;
+        progress.check_cancelled()?;
+        let files = match options.commit_files.as_deref() {
+            Some(commit_files) => files
+                .into_iter()
+                .filter(|path| {
+                    let relative = path.strip_prefix(repo_path).unwrap_or(path);
+                    commit_files.iter().any(|file| file == relative)
+                })
+                .collect(),
+            None => files,
+        };
+        let files = match options.package.as_deref() {
+            Some(package) => {
+                let package = WorkspaceDetector::find(repo_path, package)?;
+                files
+                    .into_iter()
+                    .filter(|path| package.contains(path.strip_prefix(repo_path).unwrap_or(path)))
+                    .collect()
+            }
+            None => files,
+        };
+        let files = files
+            .into_iter()
+            .filter(|path| options.snippets || self.is_supported_language(path))
+            .filter(|path| self.is_within_size_limit(path, options))
+            .filter(|path| self.is_playable_text(path, options.max_average_line_length))
+            .collect();
 
         // Ensure final progress is exactly 100%
         progress.set_file_counts(
@@ -75,21 +109,27 @@ impl SourceFileExtractor {
         Ok(files)
     }
 
-    fn count_files(&self, repo_path: &Path) -> Result<usize> {
-        let entries = self.file_storage.walk_directory(repo_path)?;
+    fn count_files(&self, repo_path: &Path, follow_symlinks: bool) -> Result<usize> {
+        let entries = self
+            .file_storage
+            .walk_directory(repo_path, follow_symlinks)?;
         Ok(entries.iter().filter(|entry| entry.is_file).count())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn collect_files(
         &self,
         repo_path: &Path,
+        follow_symlinks: bool,
         include_patterns: &[glob::Pattern],
         exclude_patterns: &[glob::Pattern],
         gittypeignore_matcher: Option<&Gitignore>,
         total_files_estimated: usize,
         progress: &dyn ProgressReporter,
     ) -> Result<Vec<PathBuf>> {
-        let entries = self.file_storage.walk_directory(repo_path)?;
+        let entries = self
+            .file_storage
+            .walk_directory(repo_path, follow_symlinks)?;
 
         let files: Vec<PathBuf> = entries
             .into_iter()
@@ -107,7 +147,6 @@ impl SourceFileExtractor {
                 }
             })
             .map(|(_, entry)| entry.path)
-            .filter(|path| self.is_supported_language(path))
             .filter(|path| {
                 Self::should_collect(
                     path,
@@ -129,6 +168,48 @@ impl SourceFileExtractor {
             .unwrap_or(false)
     }
 
+    fn is_within_size_limit(&self, path: &Path, options: &ExtractionOptions) -> bool {
+        match self
+            .file_storage
+            .metadata(path)
+            .map(|metadata| metadata.len())
+        {
+            Ok(size) if options.exceeds_file_size_limit(size) => {
+                log::info!(
+                    "Skipping large file: {} ({}KB > {}KB limit)",
+                    path.display(),
+                    size / 1024,
+                    options.max_file_size_kb
+                );
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// Rejects binary and minified files
+    fn is_playable_text(&self, path: &Path, max_average_line_length: usize) -> bool {
+        let Ok(bytes) = self.file_storage.read(path) else {
+            return true;
+        };
+
+        // Sniff the decoded text so UTF-16 files marked by a BOM, whose ASCII
+        // characters carry a NUL byte, are not taken for binaries
+        let (text, _) = TextProcessor::decode_source(&bytes);
+        if text.chars().take(BINARY_SNIFF_BYTES).any(|c| c == '\0') {
+            log::debug!("Skipping binary file: {}", path.display());
+            return false;
+        }
+
+        let lines = text.lines().filter(|l| !l.is_empty()).count();
+        if lines > 0 && text.len() / lines > max_average_line_length {
+            log::debug!("Skipping minified file: {}", path.display());
+            return false;
+        }
+
+        true
+    }
+
     fn should_collect(
         path: &Path,
         repo_path: &Path,
@@ -1,4 +1,6 @@
-use crate::domain::models::{Challenge, DifficultyLevel, GameMode, GitRepository, StageConfig};
+use crate::domain::models::{
+    Challenge, DifficultyLevel, GameMode, GitRepository, Languages, StageConfig,
+};
 use crate::domain::stores::{
     ChallengeStoreInterface, RepositoryStoreInterface, SessionStoreInterface,
 };
@@ -261,12 +263,29 @@ impl StageRepository {
         if let Some(screen) = manager.get_screen_mut(&ScreenType::Title) {
             if let Some(title_screen) = screen.as_any().downcast_ref::<TitleScreen>() {
                 title_screen.set_challenge_counts(challenge_counts);
+                title_screen.set_language_counts(self.count_challenges_by_language());
                 title_screen.set_git_repository(self.git_repository.lock().unwrap().clone());
             }
         }
         Ok(())
     }
 
+    /// Challenges per language display name, most common first
+    pub fn count_challenges_by_language(&self) -> Vec<(String, usize)> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        self.with_challenges(|challenges| {
+            for challenge in challenges {
+                *counts
+                    .entry(Languages::get_display_name(challenge.language.as_deref()))
+                    .or_default() += 1;
+            }
+        });
+
+        let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        counts
+    }
+
     pub fn count_challenges_by_difficulty(&self) -> [usize; 5] {
         // Use cached indices for O(1) counting
         if *self.indices_cached.lock().unwrap() {
@@ -312,6 +331,22 @@ impl StageRepository {
             .unwrap_or([0; 5])
         }
     }
+
+    /// The tier to play when `requested` is asked for: itself when it has
+    /// challenges, else the nearest tier that does, preferring the easier one
+    /// on a tie. `None` when the repository has no challenges at all.
+    pub fn resolve_difficulty(&self, requested: DifficultyLevel) -> Option<DifficultyLevel> {
+        let counts = self.count_challenges_by_difficulty();
+        let requested_index = DifficultyLevel::ALL
+            .iter()
+            .position(|difficulty| *difficulty == requested)?;
+        DifficultyLevel::ALL
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| counts[*i] > 0)
+            .min_by_key(|(i, _)| (i.abs_diff(requested_index), *i))
+            .map(|(_, difficulty)| difficulty)
+    }
 }
 
 // Default implementation removed - use new() with stores instead
@@ -0,0 +1,93 @@
+use crate::domain::models::StreamerStats;
+use crate::domain::services::config_service::ConfigServiceInterface;
+use crate::infrastructure::storage::file_storage::FileStorageInterface;
+use crate::Result;
+use shaku::Interface;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Often enough for a live readout without rewriting the file every frame
+pub const WRITE_INTERVAL: Duration = Duration::from_millis(500);
+pub const DEFAULT_STATS_FILE_NAME: &str = "streamer_stats.txt";
+
+pub trait StreamerStatsServiceInterface: Interface {
+    /// Write the stats file if streamer mode is enabled in settings and the text changed
+    fn publish(&self, stats: &StreamerStats);
+}
+
+#[derive(shaku::Component)]
+#[shaku(interface = StreamerStatsServiceInterface)]
+pub struct StreamerStatsService {
+    #[shaku(default)]
+    last_written: Mutex<Option<(String, Instant)>>,
+    #[shaku(inject)]
+    file_storage: Arc<dyn FileStorageInterface>,
+    #[shaku(inject)]
+    config_service: Arc<dyn ConfigServiceInterface>,
+}
+
+impl StreamerStatsService {
+    pub fn new(
+        file_storage: Arc<dyn FileStorageInterface>,
+        config_service: Arc<dyn ConfigServiceInterface>,
+    ) -> Self {
+        Self {
+            last_written: Mutex::new(None),
+            file_storage,
+            config_service,
+        }
+    }
+
+    pub fn stats_path(&self) -> Result<PathBuf> {
+        match self.config_service.get_config().streamer.stats_file {
+            Some(path) => Ok(path),
+            None => Ok(self
+                .file_storage
+                .get_app_data_dir()?
+                .join(DEFAULT_STATS_FILE_NAME)),
+        }
+    }
+
+    #[cfg(feature = "test-mocks")]
+    pub fn last_written_for_test(&self) -> Option<String> {
+        self.last_written
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|(text, _)| text.clone())
+    }
+
+    fn should_write(&self, text: &str) -> bool {
+        self.last_written
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_none_or(|(last, written_at)| last != text && written_at.elapsed() >= WRITE_INTERVAL)
+    }
+
+    fn write(&self, text: &str) -> Result<()> {
+        let path = self.stats_path()?;
+        if let Some(parent) = path.parent() {
+            self.file_storage.create_dir_all(parent)?;
+        }
+        self.file_storage.write(&path, text.as_bytes())
+    }
+}
+
+impl StreamerStatsServiceInterface for StreamerStatsService {
+    fn publish(&self, stats: &StreamerStats) {
+        if !self.config_service.get_config().streamer.enabled {
+            return;
+        }
+        let text = stats.render();
+        if !self.should_write(&text) {
+            return;
+        }
+
+        if let Err(e) = self.write(&text) {
+            log::warn!("Failed to write streamer stats: {}", e);
+        }
+        *self.last_written.lock().unwrap() = Some((text, Instant::now()));
+    }
+}
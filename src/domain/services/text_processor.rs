@@ -1,6 +1,40 @@
 pub struct TextProcessor;
 
 impl TextProcessor {
+    /// Decodes source file bytes, stripping a UTF-8 BOM, decoding UTF-16 files
+    /// marked by a BOM and normalizing line endings. Invalid sequences become
+    /// U+FFFD; the flag reports whether any were replaced.
+    pub fn decode_source(bytes: &[u8]) -> (String, bool) {
+        let utf16 = |bytes: &[u8], to_unit: fn([u8; 2]) -> u16| {
+            let units = bytes
+                .chunks_exact(2)
+                .map(|pair| to_unit([pair[0], pair[1]]));
+            let mut lossy = !bytes.len().is_multiple_of(2);
+            let text: String = char::decode_utf16(units)
+                .map(|c| {
+                    c.unwrap_or_else(|_| {
+                        lossy = true;
+                        char::REPLACEMENT_CHARACTER
+                    })
+                })
+                .collect();
+            (text, lossy)
+        };
+
+        let (text, lossy) = match bytes {
+            [0xEF, 0xBB, 0xBF, rest @ ..] => Self::decode_utf8(rest),
+            [0xFF, 0xFE, rest @ ..] => utf16(rest, u16::from_le_bytes),
+            [0xFE, 0xFF, rest @ ..] => utf16(rest, u16::from_be_bytes),
+            _ => Self::decode_utf8(bytes),
+        };
+        (Self::normalize_line_endings(&text), lossy)
+    }
+
+    /// Converts CRLF and lone CR line endings to LF
+    pub fn normalize_line_endings(text: &str) -> String {
+        text.replace("\r\n", "\n").replace('\r', "\n")
+    }
+
     pub fn process_challenge_text(text: &str) -> String {
         text.lines()
             .map(|line| line.trim_end()) // Remove trailing whitespace
@@ -273,4 +307,11 @@ impl TextProcessor {
         // Everything from current position to end of line is comment or whitespace
         true
     }
+
+    fn decode_utf8(bytes: &[u8]) -> (String, bool) {
+        match String::from_utf8_lossy(bytes) {
+            std::borrow::Cow::Borrowed(text) => (text.to_string(), false),
+            std::borrow::Cow::Owned(text) => (text, true),
+        }
+    }
 }
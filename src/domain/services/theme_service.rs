@@ -6,6 +6,7 @@ use crate::domain::models::theme::Theme;
 use crate::domain::services::config_service::ConfigServiceInterface;
 use crate::infrastructure::storage::app_data_provider::AppDataProvider;
 use crate::infrastructure::storage::file_storage::{FileStorage, FileStorageInterface};
+use crate::infrastructure::terminal::TerminalBackground;
 use crate::presentation::ui::Colors;
 use shaku::Interface;
 use std::collections::HashMap;
@@ -161,7 +162,13 @@ impl ThemeServiceInterface for ThemeService {
 
         let config = self.config_service.get_config();
         let current_theme_id = config.theme.current_theme_id.clone();
-        let current_color_mode = config.theme.current_color_mode.clone();
+        // Light terminals get light colors unless a color mode was picked in settings
+        let current_color_mode = config
+            .theme
+            .auto_color_mode
+            .then(TerminalBackground::detect)
+            .flatten()
+            .unwrap_or_else(|| config.theme.current_color_mode.clone());
 
         // Find theme by ID
         let available_themes = self.get_available_themes();
@@ -1,5 +1,6 @@
 use crate::domain::models::typing::{InputResult, ProcessingOptions};
 use crate::domain::models::Challenge;
+use crate::domain::services::text_processor::TextProcessor;
 
 #[derive(Debug, Clone)]
 pub struct TypingCore {
@@ -82,6 +83,10 @@ impl TypingCore {
             })
             .collect();
 
+        let (original_text, normalized_ranges) =
+            Self::normalize_line_endings(original_text, normalized_ranges);
+        let original_text = original_text.as_str();
+
         let (text_to_type, text_mapping_to_type) =
             Self::create_typing_text(original_text, &normalized_ranges, &options);
 
@@ -278,6 +283,30 @@ impl TypingCore {
     }
 
     // Text processing methods
+    /// Normalizes line endings, shifting char-based comment ranges past each
+    /// `\r` dropped from a CRLF pair
+    fn normalize_line_endings(
+        original: &str,
+        comment_ranges: Vec<(usize, usize)>,
+    ) -> (String, Vec<(usize, usize)>) {
+        let chars: Vec<char> = original.chars().collect();
+        let dropped: Vec<usize> = chars
+            .windows(2)
+            .enumerate()
+            .filter(|(_, pair)| pair == &['\r', '\n'])
+            .map(|(index, _)| index)
+            .collect();
+        let shift = |pos: usize| pos - dropped.partition_point(|&index| index < pos);
+
+        (
+            TextProcessor::normalize_line_endings(original),
+            comment_ranges
+                .into_iter()
+                .map(|(start, end)| (shift(start), shift(end)))
+                .collect(),
+        )
+    }
+
     fn create_typing_text(
         original: &str,
         comment_ranges: &[(usize, usize)],
@@ -538,6 +567,9 @@ impl TypingCore {
 
     // High-level input processing methods
     pub fn process_character_input(&mut self, input_char: char) -> InputResult {
+        if matches!(input_char, '\r' | '\n') {
+            return self.process_enter_input();
+        }
         if !self.can_accept_input() {
             return InputResult::NoAction;
         }
@@ -1,9 +1,12 @@
+use crate::domain::models::version::ReleaseNotes;
 use crate::domain::repositories::version_repository::VersionRepositoryInterface;
+use crate::domain::services::config_service::{ConfigService, ConfigServiceInterface};
 use crate::{GitTypeError, Result};
 use shaku::Interface;
+use std::cmp::Ordering;
 use std::future::Future;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 type VersionCheckFuture<'a> =
     Pin<Box<dyn Future<Output = Result<(bool, String, String)>> + Send + 'a>>;
@@ -11,13 +14,21 @@ type VersionCheckFuture<'a> =
 pub trait VersionServiceInterface: Interface {
     fn check(&self) -> VersionCheckFuture<'_>;
     fn check_with_version(&self, current_version: &str) -> VersionCheckFuture<'_>;
+    /// Newer release found by the last check, unless the player dismissed it
+    fn pending_update(&self) -> Option<ReleaseNotes>;
+    /// Stop announcing the pending release; a later one is announced again
+    fn dismiss_update(&self) -> Result<()>;
 }
 
 #[derive(shaku::Component)]
 #[shaku(interface = VersionServiceInterface)]
 pub struct VersionService {
+    #[shaku(default)]
+    newer_release: RwLock<Option<ReleaseNotes>>,
     #[shaku(inject)]
     repository: Arc<dyn VersionRepositoryInterface>,
+    #[shaku(inject)]
+    config_service: Arc<dyn ConfigServiceInterface>,
 }
 
 impl VersionServiceInterface for VersionService {
@@ -34,19 +45,65 @@ impl VersionServiceInterface for VersionService {
     ) -> Pin<Box<dyn Future<Output = Result<(bool, String, String)>> + Send + '_>> {
         let current_version = current_version.to_string();
         Box::pin(async move {
-            let latest_version = self.repository.fetch_latest_version().await?;
-            let has_update = VersionService::is_version_newer(&latest_version, &current_version);
+            let version_check = self.config_service.get_config().version_check;
+            let Some(frequency_hours) = version_check.frequency.interval_hours() else {
+                return Ok((false, current_version.clone(), current_version));
+            };
+
+            let release = self
+                .repository
+                .fetch_latest_release(version_check.channel, frequency_hours)
+                .await?;
+            let has_update = VersionService::is_version_newer(&release.version, &current_version);
+            let latest_version = release.version.clone();
+            *self.newer_release.write().unwrap() = has_update.then_some(release);
             Ok((has_update, current_version, latest_version))
         })
     }
+
+    fn pending_update(&self) -> Option<ReleaseNotes> {
+        let dismissed = self.config_service.get_config().whats_new.dismissed_update;
+        self.newer_release
+            .read()
+            .unwrap()
+            .clone()
+            .filter(|release| dismissed.as_deref() != Some(release.version.as_str()))
+    }
+
+    fn dismiss_update(&self) -> Result<()> {
+        let Some(release) = self.newer_release.read().unwrap().clone() else {
+            return Ok(());
+        };
+        if let Some(config_service) =
+            (self.config_service.as_ref() as &dyn std::any::Any).downcast_ref::<ConfigService>()
+        {
+            config_service.update_config(|config| {
+                config.whats_new.dismissed_update = Some(release.version)
+            })?;
+            self.config_service.save()?;
+        }
+        Ok(())
+    }
 }
 
 impl VersionService {
     #[cfg(feature = "test-mocks")]
     pub fn new_for_test() -> Result<Self> {
+        Self::new_for_test_with_config(Default::default())
+    }
+
+    #[cfg(feature = "test-mocks")]
+    pub fn new_for_test_with_config(
+        version_check: crate::domain::models::config::VersionCheckConfig,
+    ) -> Result<Self> {
         use crate::domain::repositories::version_repository::VersionRepository;
+        use crate::domain::services::config_service::ConfigService;
+        let config_service = ConfigService::new_for_test()?;
+        config_service.update_config(|config| config.version_check = version_check)?;
         Ok(Self {
+            newer_release: RwLock::new(None),
             repository: Arc::new(VersionRepository::new_for_test()?),
+            config_service: Arc::new(config_service),
         })
     }
 
@@ -56,32 +113,57 @@ impl VersionService {
     }
 
     fn is_version_newer(latest: &str, current: &str) -> bool {
-        let latest_parts = Self::parse_version(latest);
-        let current_parts = Self::parse_version(current);
-
-        match (latest_parts, current_parts) {
-            (Ok(latest), Ok(current)) => {
-                for (l, c) in latest.iter().zip(current.iter()) {
-                    if l > c {
-                        return true;
-                    } else if l < c {
-                        return false;
-                    }
-                }
-                latest.len() > current.len()
-            }
+        match (Self::parse_version(latest), Self::parse_version(current)) {
+            (Ok(latest), Ok(current)) => Self::compare_versions(&latest, &current).is_gt(),
             _ => false,
         }
     }
 
-    fn parse_version(version: &str) -> Result<Vec<u32>> {
-        version
+    /// Splits `1.2.0-beta.1+build` into its numeric parts and pre-release identifiers
+    fn parse_version(version: &str) -> Result<(Vec<u32>, Vec<String>)> {
+        let version = version.split('+').next().unwrap_or(version);
+        let (core, pre_release) = version.split_once('-').unwrap_or((version, ""));
+
+        let core = core
             .split('.')
             .map(|part| {
                 part.parse::<u32>().map_err(|e| {
                     GitTypeError::ExtractionFailed(format!("Invalid version format: {}", e))
                 })
             })
-            .collect()
+            .collect::<Result<Vec<u32>>>()?;
+        let pre_release = pre_release
+            .split('.')
+            .filter(|identifier| !identifier.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        Ok((core, pre_release))
+    }
+
+    /// Semver precedence: a release outranks its own pre-releases, and
+    /// numeric pre-release identifiers compare numerically
+    fn compare_versions(a: &(Vec<u32>, Vec<String>), b: &(Vec<u32>, Vec<String>)) -> Ordering {
+        let (a_core, a_pre) = a;
+        let (b_core, b_pre) = b;
+
+        a_core
+            .cmp(b_core)
+            .then_with(|| match (a_pre.is_empty(), b_pre.is_empty()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => a_pre
+                    .iter()
+                    .zip(b_pre)
+                    .map(|(x, y)| match (x.parse::<u64>(), y.parse::<u64>()) {
+                        (Ok(x), Ok(y)) => x.cmp(&y),
+                        (Ok(_), Err(_)) => Ordering::Less,
+                        (Err(_), Ok(_)) => Ordering::Greater,
+                        (Err(_), Err(_)) => x.cmp(y),
+                    })
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or_else(|| a_pre.len().cmp(&b_pre.len())),
+            })
     }
 }
@@ -0,0 +1,271 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::domain::models::{WorkspaceKind, WorkspacePackage};
+use crate::{GitTypeError, Result};
+
+pub struct WorkspaceDetector;
+
+impl WorkspaceDetector {
+    /// Packages listed by the Cargo.toml `[workspace]`, package.json `workspaces`
+    /// and go.work at `root`, ordered by directory; the root itself is left out
+    pub fn detect(root: &Path) -> Vec<WorkspacePackage> {
+        let mut packages = BTreeMap::new();
+        let mut add = |kind: WorkspaceKind, manifest: &str, (members, excluded): Members| {
+            for path in Self::expand(root, &members, manifest, &excluded) {
+                let name = Self::package_name(kind, &root.join(&path))
+                    .unwrap_or_else(|| path.to_string_lossy().into_owned());
+                packages
+                    .entry(path.clone())
+                    .or_insert(WorkspacePackage { name, path, kind });
+            }
+        };
+
+        if let Ok(manifest) = std::fs::read_to_string(root.join("Cargo.toml")) {
+            add(
+                WorkspaceKind::Cargo,
+                "Cargo.toml",
+                Self::cargo_members(&manifest),
+            );
+        }
+        if let Ok(manifest) = std::fs::read_to_string(root.join("package.json")) {
+            add(
+                WorkspaceKind::Npm,
+                "package.json",
+                Self::npm_workspaces(&manifest),
+            );
+        }
+        if let Ok(go_work) = std::fs::read_to_string(root.join("go.work")) {
+            add(
+                WorkspaceKind::Go,
+                "go.mod",
+                (Self::go_work_uses(&go_work), Vec::new()),
+            );
+        }
+
+        packages.into_values().collect()
+    }
+
+    /// The package at `root` named `query` or living in the directory `query`
+    pub fn find(root: &Path, query: &str) -> Result<WorkspacePackage> {
+        let packages = Self::detect(root);
+        if packages.is_empty() {
+            return Err(GitTypeError::ValidationError(format!(
+                "{} has no Cargo, npm or Go workspace to pick package `{}` from",
+                root.display(),
+                query
+            )));
+        }
+
+        packages
+            .iter()
+            .find(|package| package.matches(query))
+            .cloned()
+            .ok_or_else(|| {
+                GitTypeError::ValidationError(format!(
+                    "No workspace package `{}`; available: {}",
+                    query,
+                    packages
+                        .iter()
+                        .map(|package| package.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+            })
+    }
+
+    /// `members` and `exclude` globs of a Cargo.toml `[workspace]` section
+    pub fn cargo_members(manifest: &str) -> Members {
+        let mut in_workspace = false;
+        let mut key: Option<String> = None;
+        let mut arrays: BTreeMap<String, String> = BTreeMap::new();
+
+        for line in manifest
+            .lines()
+            .map(|line| Self::strip_comment(line, "#").trim())
+        {
+            if let Some(current) = &key {
+                let value = arrays.entry(current.clone()).or_default();
+                value.push_str(line);
+                if Self::is_closed(value) {
+                    key = None;
+                }
+                continue;
+            }
+            if line.starts_with('[') {
+                in_workspace = line == "[workspace]";
+                continue;
+            }
+            let Some((name, value)) = line.split_once('=') else {
+                continue;
+            };
+            let name = name.trim();
+            if in_workspace && (name == "members" || name == "exclude") {
+                let value = value.trim().to_string();
+                if !Self::is_closed(&value) {
+                    key = Some(name.to_string());
+                }
+                arrays.insert(name.to_string(), value);
+            }
+        }
+
+        let strings = |name: &str| {
+            arrays
+                .get(name)
+                .map(|value| Self::quoted_strings(value))
+                .unwrap_or_default()
+        };
+        (strings("members"), strings("exclude"))
+    }
+
+    /// Globs of the package.json `workspaces` field, either a list or the
+    /// `packages` of an object; `!` patterns are exclusions
+    pub fn npm_workspaces(manifest: &str) -> Members {
+        let Ok(json) = serde_json::from_str::<serde_json::Value>(manifest) else {
+            return (Vec::new(), Vec::new());
+        };
+        let workspaces = json.get("workspaces");
+        let patterns = workspaces
+            .and_then(|workspaces| workspaces.as_array())
+            .or_else(|| workspaces?.get("packages")?.as_array())
+            .map(|patterns| {
+                patterns
+                    .iter()
+                    .filter_map(|pattern| pattern.as_str())
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        patterns.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut members, mut excluded), pattern| {
+                match pattern.strip_prefix('!') {
+                    Some(pattern) => excluded.push(pattern.to_string()),
+                    None => members.push(pattern),
+                }
+                (members, excluded)
+            },
+        )
+    }
+
+    /// Module directories of the `use` directives of a go.work file
+    pub fn go_work_uses(go_work: &str) -> Vec<String> {
+        let mut in_block = false;
+        let mut uses = Vec::new();
+
+        for line in go_work
+            .lines()
+            .map(|line| Self::strip_comment(line, "//").trim())
+        {
+            if in_block {
+                match line {
+                    ")" => in_block = false,
+                    "" => {}
+                    directory => uses.push(directory.trim_matches('"').to_string()),
+                }
+                continue;
+            }
+            let Some(rest) = line.strip_prefix("use") else {
+                continue;
+            };
+            match rest.trim() {
+                "(" => in_block = true,
+                directory if rest.starts_with(char::is_whitespace) => {
+                    uses.push(directory.trim_matches('"').to_string())
+                }
+                _ => {}
+            }
+        }
+        uses
+    }
+
+    /// Repository-relative directories matching `members` that hold `manifest`
+    fn expand(
+        root: &Path,
+        members: &[String],
+        manifest: &str,
+        excluded: &[String],
+    ) -> Vec<PathBuf> {
+        let matching = |pattern: &str| -> Vec<PathBuf> {
+            glob::glob(&root.join(pattern).to_string_lossy())
+                .into_iter()
+                .flatten()
+                .flatten()
+                .filter(|path| path.is_dir())
+                .filter_map(|path| path.strip_prefix(root).ok().map(Path::to_path_buf))
+                .filter(|path| !path.as_os_str().is_empty())
+                .collect()
+        };
+        let excluded: Vec<PathBuf> = excluded
+            .iter()
+            .flat_map(|pattern| matching(pattern))
+            .collect();
+
+        members
+            .iter()
+            .flat_map(|pattern| matching(pattern))
+            .filter(|path| root.join(path).join(manifest).is_file())
+            .filter(|path| !excluded.contains(path))
+            .collect()
+    }
+
+    /// Name from the package's own manifest
+    fn package_name(kind: WorkspaceKind, directory: &Path) -> Option<String> {
+        match kind {
+            WorkspaceKind::Cargo => {
+                let manifest = std::fs::read_to_string(directory.join("Cargo.toml")).ok()?;
+                let mut in_package = false;
+                manifest.lines().map(str::trim).find_map(|line| {
+                    if line.starts_with('[') {
+                        in_package = line == "[package]";
+                        return None;
+                    }
+                    let (key, value) = line.split_once('=')?;
+                    (in_package && key.trim() == "name")
+                        .then(|| value.trim().trim_matches('"').to_string())
+                })
+            }
+            WorkspaceKind::Npm => {
+                let manifest = std::fs::read_to_string(directory.join("package.json")).ok()?;
+                serde_json::from_str::<serde_json::Value>(&manifest)
+                    .ok()?
+                    .get("name")?
+                    .as_str()
+                    .map(str::to_string)
+            }
+            WorkspaceKind::Go => {
+                let go_mod = std::fs::read_to_string(directory.join("go.mod")).ok()?;
+                go_mod.lines().find_map(|line| {
+                    line.trim()
+                        .strip_prefix("module ")
+                        .map(|module| module.trim().trim_matches('"').to_string())
+                })
+            }
+        }
+        .filter(|name| !name.is_empty())
+    }
+
+    fn strip_comment<'a>(line: &'a str, marker: &str) -> &'a str {
+        line.split_once(marker).map_or(line, |(code, _)| code)
+    }
+
+    fn is_closed(array: &str) -> bool {
+        array.matches('[').count() <= array.matches(']').count()
+    }
+
+    /// Contents of the double or single quoted strings in `text`
+    fn quoted_strings(text: &str) -> Vec<String> {
+        let mut strings = Vec::new();
+        let mut chars = text.chars();
+        while let Some(c) = chars.next() {
+            if c == '"' || c == '\'' {
+                strings.push(chars.by_ref().take_while(|&next| next != c).collect());
+            }
+        }
+        strings
+    }
+}
+
+/// Member globs and the globs excluded from them
+pub type Members = (Vec<String>, Vec<String>);
@@ -19,6 +19,11 @@ pub trait RepositoryStoreInterface: Interface {
     fn get_extraction_options(&self) -> Option<ExtractionOptions>;
     fn set_extraction_options(&self, options: ExtractionOptions);
 
+    /// Offer to play the current directory's repository right away instead of the menu
+    fn set_quick_play(&self, quick_play: bool);
+    /// Whether quick play was offered; reading it withdraws the offer
+    fn take_quick_play(&self) -> bool;
+
     fn clear(&self);
 }
 
@@ -33,6 +38,8 @@ pub struct RepositoryStore {
     repo_path: RwLock<Option<PathBuf>>,
     #[shaku(default)]
     extraction_options: RwLock<Option<ExtractionOptions>>,
+    #[shaku(default)]
+    quick_play: RwLock<bool>,
 }
 
 impl RepositoryStore {
@@ -43,6 +50,7 @@ impl RepositoryStore {
             repo_spec: RwLock::new(None),
             repo_path: RwLock::new(None),
             extraction_options: RwLock::new(None),
+            quick_play: RwLock::new(false),
         }
     }
 }
@@ -54,6 +62,7 @@ impl Default for RepositoryStore {
             repo_spec: RwLock::new(None),
             repo_path: RwLock::new(None),
             extraction_options: RwLock::new(None),
+            quick_play: RwLock::new(false),
         }
     }
 }
@@ -95,10 +104,19 @@ impl RepositoryStoreInterface for RepositoryStore {
         *self.extraction_options.write().unwrap() = Some(options);
     }
 
+    fn set_quick_play(&self, quick_play: bool) {
+        *self.quick_play.write().unwrap() = quick_play;
+    }
+
+    fn take_quick_play(&self) -> bool {
+        std::mem::take(&mut *self.quick_play.write().unwrap())
+    }
+
     fn clear(&self) {
         *self.git_repository.write().unwrap() = None;
         *self.repo_spec.write().unwrap() = None;
         *self.repo_path.write().unwrap() = None;
         *self.extraction_options.write().unwrap() = None;
+        *self.quick_play.write().unwrap() = false;
     }
 }
@@ -2,6 +2,8 @@ use shaku::Interface;
 
 use std::sync::RwLock;
 
+use crate::domain::models::ErrorReport;
+
 pub trait SessionStoreInterface: Interface {
     fn is_loading_completed(&self) -> bool;
     fn set_loading_completed(&self, completed: bool);
@@ -13,6 +15,10 @@ pub trait SessionStoreInterface: Interface {
     fn set_error_message(&self, message: String);
     fn clear_error_message(&self);
 
+    /// Why loading failed, for the error screen
+    fn get_error_report(&self) -> Option<ErrorReport>;
+    fn set_error_report(&self, report: ErrorReport);
+
     fn clear(&self);
 }
 
@@ -25,6 +31,8 @@ pub struct SessionStore {
     loading_failed: RwLock<bool>,
     #[shaku(default)]
     error_message: RwLock<Option<String>>,
+    #[shaku(default)]
+    error_report: RwLock<Option<ErrorReport>>,
 }
 
 impl SessionStore {
@@ -34,6 +42,7 @@ impl SessionStore {
             loading_completed: RwLock::new(false),
             loading_failed: RwLock::new(false),
             error_message: RwLock::new(None),
+            error_report: RwLock::new(None),
         }
     }
 }
@@ -44,6 +53,7 @@ impl Default for SessionStore {
             loading_completed: RwLock::new(false),
             loading_failed: RwLock::new(false),
             error_message: RwLock::new(None),
+            error_report: RwLock::new(None),
         }
     }
 }
@@ -77,9 +87,18 @@ impl SessionStoreInterface for SessionStore {
         *self.error_message.write().unwrap() = None;
     }
 
+    fn get_error_report(&self) -> Option<ErrorReport> {
+        self.error_report.read().unwrap().clone()
+    }
+
+    fn set_error_report(&self, report: ErrorReport) {
+        *self.error_report.write().unwrap() = Some(report);
+    }
+
     fn clear(&self) {
         *self.loading_completed.write().unwrap() = false;
         *self.loading_failed.write().unwrap() = false;
         *self.error_message.write().unwrap() = None;
+        *self.error_report.write().unwrap() = None;
     }
 }
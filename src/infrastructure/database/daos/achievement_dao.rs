@@ -0,0 +1,81 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use rusqlite::params;
+use shaku::Interface;
+
+use std::sync::Arc;
+
+use crate::domain::models::Achievement;
+use crate::{GitTypeError, Result};
+
+use super::super::database::DatabaseInterface;
+
+pub trait AchievementDaoInterface: Interface {
+    /// Earned achievements with their unlock time; unknown ids are ignored
+    fn get_unlocked_achievements(&self) -> Result<Vec<(Achievement, DateTime<Utc>)>>;
+    /// Record unlocks not stored before; returns how many were new
+    fn insert_unlocked_achievements(
+        &self,
+        achievements: &[(Achievement, DateTime<Utc>)],
+    ) -> Result<usize>;
+}
+
+pub struct AchievementDao {
+    db: Arc<dyn DatabaseInterface>,
+}
+
+impl AchievementDao {
+    pub fn new(db: Arc<dyn DatabaseInterface>) -> Self {
+        Self { db }
+    }
+}
+
+impl AchievementDaoInterface for AchievementDao {
+    fn get_unlocked_achievements(&self) -> Result<Vec<(Achievement, DateTime<Utc>)>> {
+        let conn = self.db.get_connection()?;
+
+        let rows = conn
+            .prepare("SELECT id, unlocked_at FROM achievements ORDER BY unlocked_at")?
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        rows.into_iter()
+            .filter_map(|(id, unlocked_at)| {
+                Achievement::from_id(&id).map(|achievement| (achievement, unlocked_at))
+            })
+            .map(|(achievement, unlocked_at)| {
+                NaiveDateTime::parse_from_str(&unlocked_at, "%Y-%m-%d %H:%M:%S")
+                    .map(|dt| (achievement, dt.and_utc()))
+                    .map_err(|e| {
+                        GitTypeError::database_error(format!("Failed to parse timestamp: {}", e))
+                    })
+            })
+            .collect()
+    }
+
+    fn insert_unlocked_achievements(
+        &self,
+        achievements: &[(Achievement, DateTime<Utc>)],
+    ) -> Result<usize> {
+        let mut conn = self.db.get_connection()?;
+        let tx = conn.transaction()?;
+
+        let inserted = {
+            let mut stmt =
+                tx.prepare("INSERT OR IGNORE INTO achievements (id, unlocked_at) VALUES (?, ?)")?;
+            achievements
+                .iter()
+                .try_fold(0, |inserted, (achievement, unlocked_at)| {
+                    stmt.execute(params![
+                        achievement.id(),
+                        unlocked_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    ])
+                    .map(|changed| inserted + changed)
+                })?
+        };
+
+        tx.commit()?;
+        Ok(inserted)
+    }
+}
@@ -0,0 +1,92 @@
+use rusqlite::params;
+use shaku::Interface;
+
+use std::sync::Arc;
+
+use crate::domain::models::{BaselineSource, BaselineSummary, TypingBaseline};
+use crate::Result;
+
+use super::super::database::DatabaseInterface;
+
+pub trait BaselineDaoInterface: Interface {
+    /// Insert tests not imported before; returns how many were new
+    fn insert_baselines(&self, baselines: &[TypingBaseline]) -> Result<usize>;
+    fn get_baseline_summary(&self) -> Result<Option<BaselineSummary>>;
+}
+
+pub struct BaselineDao {
+    db: Arc<dyn DatabaseInterface>,
+}
+
+impl BaselineDao {
+    pub fn new(db: Arc<dyn DatabaseInterface>) -> Self {
+        Self { db }
+    }
+}
+
+impl BaselineDaoInterface for BaselineDao {
+    fn insert_baselines(&self, baselines: &[TypingBaseline]) -> Result<usize> {
+        let mut conn = self.db.get_connection()?;
+        let tx = conn.transaction()?;
+
+        let inserted = {
+            let mut stmt = tx.prepare(
+                "INSERT OR IGNORE INTO typing_baselines
+                    (source, taken_at, wpm, accuracy, duration_seconds)
+                 VALUES (?, ?, ?, ?, ?)",
+            )?;
+            baselines.iter().try_fold(0, |inserted, baseline| {
+                stmt.execute(params![
+                    baseline.source.as_str(),
+                    baseline
+                        .taken_at
+                        .format("%Y-%m-%d %H:%M:%S%.3f")
+                        .to_string(),
+                    baseline.wpm,
+                    baseline.accuracy,
+                    baseline.duration_seconds,
+                ])
+                .map(|changed| inserted + changed)
+            })?
+        };
+
+        tx.commit()?;
+        Ok(inserted)
+    }
+
+    fn get_baseline_summary(&self) -> Result<Option<BaselineSummary>> {
+        let conn = self.db.get_connection()?;
+
+        let (tests, avg_wpm, best_wpm, avg_accuracy) = conn.query_row(
+            "SELECT COUNT(*), AVG(wpm), MAX(wpm), AVG(accuracy) FROM typing_baselines",
+            [],
+            |row| {
+                Ok((
+                    row.get::<_, i64>(0)? as usize,
+                    row.get::<_, Option<f64>>(1)?.unwrap_or(0.0),
+                    row.get::<_, Option<f64>>(2)?.unwrap_or(0.0),
+                    row.get::<_, Option<f64>>(3)?.unwrap_or(0.0),
+                ))
+            },
+        )?;
+        if tests == 0 {
+            return Ok(None);
+        }
+
+        let sources = conn
+            .prepare("SELECT DISTINCT source FROM typing_baselines ORDER BY source")?
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .iter()
+            .filter_map(|source| source.parse::<BaselineSource>().ok())
+            .collect();
+
+        Ok(Some(BaselineSummary {
+            tests,
+            avg_wpm,
+            best_wpm,
+            avg_accuracy,
+            sources,
+        }))
+    }
+}
@@ -1,6 +1,6 @@
 use rusqlite::{params, Transaction};
 use serde_json;
-use shaku::{Component, Interface};
+use shaku::Interface;
 
 use std::sync::Arc;
 
@@ -16,13 +16,11 @@ pub trait ChallengeDaoInterface: Interface {
         tx: &Transaction,
         challenge: &Challenge,
     ) -> Result<i64>;
+
+    fn delete_unreferenced_challenges(&self) -> Result<usize>;
 }
 
-#[derive(Component)]
-#[shaku(interface = ChallengeDaoInterface)]
 pub struct ChallengeDao {
-    #[allow(dead_code)]
-    #[shaku(inject)]
     db: Arc<dyn DatabaseInterface>,
 }
 
@@ -70,4 +68,13 @@ impl ChallengeDaoInterface for ChallengeDao {
             ))),
         }
     }
+
+    /// Remove challenges no stage points at any more, returning how many went
+    fn delete_unreferenced_challenges(&self) -> Result<usize> {
+        let conn = self.db.get_connection()?;
+        Ok(conn.execute(
+            "DELETE FROM challenges WHERE id NOT IN (SELECT challenge_id FROM stages)",
+            [],
+        )?)
+    }
 }
@@ -0,0 +1,53 @@
+use chrono::Utc;
+use rusqlite::params;
+use shaku::Interface;
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::domain::models::Challenge;
+use crate::Result;
+
+use super::super::database::DatabaseInterface;
+
+pub trait ChallengeFlagDaoInterface: Interface {
+    /// Record a flag; returns false when the challenge was already flagged
+    fn insert_flag(&self, challenge: &Challenge) -> Result<bool>;
+    fn get_flagged_fingerprints(&self) -> Result<HashSet<String>>;
+}
+
+pub struct ChallengeFlagDao {
+    db: Arc<dyn DatabaseInterface>,
+}
+
+impl ChallengeFlagDao {
+    pub fn new(db: Arc<dyn DatabaseInterface>) -> Self {
+        Self { db }
+    }
+}
+
+impl ChallengeFlagDaoInterface for ChallengeFlagDao {
+    fn insert_flag(&self, challenge: &Challenge) -> Result<bool> {
+        let conn = self.db.get_connection()?;
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO challenge_flags (fingerprint, file_path, language, flagged_at)
+             VALUES (?, ?, ?, ?)",
+            params![
+                challenge.fingerprint(),
+                challenge.source_file_path,
+                challenge.language,
+                Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            ],
+        )?;
+        Ok(inserted > 0)
+    }
+
+    fn get_flagged_fingerprints(&self) -> Result<HashSet<String>> {
+        let conn = self.db.get_connection()?;
+        let fingerprints = conn
+            .prepare("SELECT fingerprint FROM challenge_flags")?
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<std::result::Result<HashSet<_>, _>>()?;
+        Ok(fingerprints)
+    }
+}
@@ -0,0 +1,83 @@
+use chrono::Utc;
+use rusqlite::params;
+use shaku::Interface;
+
+use std::sync::Arc;
+
+use crate::domain::models::player_profile::DEFAULT_DISPLAY_NAME;
+use crate::domain::models::DifficultyLevel;
+use crate::Result;
+
+use super::super::database::DatabaseInterface;
+
+/// Sessions recorded before profiles were tracked count for the default player name
+pub trait DifficultyUnlockDaoInterface: Interface {
+    fn get_unlocked(&self, player_name: &str) -> Result<Vec<DifficultyLevel>>;
+    /// Returns false when `difficulty` was already unlocked
+    fn insert_unlock(&self, player_name: &str, difficulty: DifficultyLevel) -> Result<bool>;
+    /// Highest session accuracy of `player_name` on `difficulty`, in percent
+    fn get_best_accuracy(
+        &self,
+        player_name: &str,
+        difficulty: DifficultyLevel,
+    ) -> Result<Option<f64>>;
+}
+
+pub struct DifficultyUnlockDao {
+    db: Arc<dyn DatabaseInterface>,
+}
+
+impl DifficultyUnlockDao {
+    pub fn new(db: Arc<dyn DatabaseInterface>) -> Self {
+        Self { db }
+    }
+}
+
+impl DifficultyUnlockDaoInterface for DifficultyUnlockDao {
+    fn get_unlocked(&self, player_name: &str) -> Result<Vec<DifficultyLevel>> {
+        let conn = self.db.get_connection()?;
+        let unlocked = conn
+            .prepare("SELECT difficulty_level FROM difficulty_unlocks WHERE player_name = ?")?
+            .query_map(params![player_name], |row| row.get::<_, String>(0))?
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .iter()
+            .filter_map(|name| DifficultyLevel::from_name(name))
+            .collect();
+        Ok(unlocked)
+    }
+
+    fn insert_unlock(&self, player_name: &str, difficulty: DifficultyLevel) -> Result<bool> {
+        let conn = self.db.get_connection()?;
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO difficulty_unlocks (player_name, difficulty_level, unlocked_at)
+             VALUES (?, ?, ?)",
+            params![
+                player_name,
+                format!("{:?}", difficulty),
+                Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+            ],
+        )?;
+        Ok(inserted > 0)
+    }
+
+    fn get_best_accuracy(
+        &self,
+        player_name: &str,
+        difficulty: DifficultyLevel,
+    ) -> Result<Option<f64>> {
+        let conn = self.db.get_connection()?;
+        let best = conn.query_row(
+            "SELECT MAX(sr.accuracy)
+             FROM session_results sr
+             JOIN sessions s ON s.id = sr.session_id
+             WHERE COALESCE(s.player_name, ?) = ? AND sr.difficulty_level = ?",
+            params![
+                DEFAULT_DISPLAY_NAME,
+                player_name,
+                format!("{:?}", difficulty)
+            ],
+            |row| row.get::<_, Option<f64>>(0),
+        )?;
+        Ok(best)
+    }
+}
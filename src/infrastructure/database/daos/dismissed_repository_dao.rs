@@ -0,0 +1,60 @@
+use chrono::Utc;
+use rusqlite::params;
+use shaku::Interface;
+
+use std::sync::Arc;
+
+use crate::Result;
+
+use super::super::database::DatabaseInterface;
+
+/// Repositories are named `owner/name`, compared case-insensitively
+pub trait DismissedRepositoryDaoInterface: Interface {
+    /// Returns false when the repository was already dismissed
+    fn insert_dismissed(&self, repo_name: &str) -> Result<bool>;
+    /// Returns false when the repository wasn't dismissed
+    fn delete_dismissed(&self, repo_name: &str) -> Result<bool>;
+    fn get_dismissed(&self) -> Result<Vec<String>>;
+}
+
+pub struct DismissedRepositoryDao {
+    db: Arc<dyn DatabaseInterface>,
+}
+
+impl DismissedRepositoryDao {
+    pub fn new(db: Arc<dyn DatabaseInterface>) -> Self {
+        Self { db }
+    }
+}
+
+impl DismissedRepositoryDaoInterface for DismissedRepositoryDao {
+    fn insert_dismissed(&self, repo_name: &str) -> Result<bool> {
+        let conn = self.db.get_connection()?;
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO dismissed_repositories (repo_name, dismissed_at) VALUES (?, ?)",
+            params![
+                repo_name,
+                Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+            ],
+        )?;
+        Ok(inserted > 0)
+    }
+
+    fn delete_dismissed(&self, repo_name: &str) -> Result<bool> {
+        let conn = self.db.get_connection()?;
+        let deleted = conn.execute(
+            "DELETE FROM dismissed_repositories WHERE repo_name = ?",
+            params![repo_name],
+        )?;
+        Ok(deleted > 0)
+    }
+
+    fn get_dismissed(&self) -> Result<Vec<String>> {
+        let conn = self.db.get_connection()?;
+        let repo_names = conn
+            .prepare("SELECT repo_name FROM dismissed_repositories ORDER BY repo_name")?
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(repo_names)
+    }
+}
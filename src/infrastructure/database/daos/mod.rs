@@ -1,9 +1,21 @@
+pub mod achievement_dao;
+pub mod baseline_dao;
 pub mod challenge_dao;
+pub mod challenge_flag_dao;
+pub mod difficulty_unlock_dao;
+pub mod dismissed_repository_dao;
 pub mod repository_dao;
+pub mod repository_rating_dao;
 pub mod session_dao;
 pub mod stage_dao;
 
+pub use achievement_dao::{AchievementDao, AchievementDaoInterface};
+pub use baseline_dao::{BaselineDao, BaselineDaoInterface};
 pub use challenge_dao::{ChallengeDao, ChallengeDaoInterface};
+pub use challenge_flag_dao::{ChallengeFlagDao, ChallengeFlagDaoInterface};
+pub use difficulty_unlock_dao::{DifficultyUnlockDao, DifficultyUnlockDaoInterface};
+pub use dismissed_repository_dao::{DismissedRepositoryDao, DismissedRepositoryDaoInterface};
 pub use repository_dao::{RepositoryDao, RepositoryDaoInterface};
+pub use repository_rating_dao::{RepositoryRatingDao, RepositoryRatingDaoInterface};
 pub use session_dao::{SessionDao, SessionDaoInterface};
 pub use stage_dao::{StageDao, StageDaoInterface};
@@ -1,10 +1,13 @@
+use chrono::NaiveDateTime;
 use rusqlite::{params, Transaction};
-use shaku::{Component, Interface};
+use shaku::Interface;
 
 use std::sync::Arc;
 
 use crate::domain::error::GitTypeError;
-use crate::domain::models::storage::{StoredRepository, StoredRepositoryWithLanguages};
+use crate::domain::models::storage::{
+    RepositoryMetadata, RepositoryRating, StoredRepository, StoredRepositoryWithLanguages,
+};
 use crate::domain::models::GitRepository;
 use crate::Result;
 
@@ -25,12 +28,15 @@ pub trait RepositoryDaoInterface: Interface {
         repository_name: &str,
     ) -> Result<Option<StoredRepository>>;
     fn get_all_repositories_with_languages(&self) -> Result<Vec<StoredRepositoryWithLanguages>>;
+    fn get_repository_metadata(&self, repository_id: i64) -> Result<Option<RepositoryMetadata>>;
+    fn update_repository_metadata(
+        &self,
+        repository_id: i64,
+        metadata: &RepositoryMetadata,
+    ) -> Result<()>;
 }
 
-#[derive(Component)]
-#[shaku(interface = RepositoryDaoInterface)]
 pub struct RepositoryDao {
-    #[shaku(inject)]
     db: Arc<dyn DatabaseInterface>,
 }
 
@@ -66,12 +72,24 @@ impl RepositoryDaoInterface for RepositoryDao {
             );
 
         match existing {
-            Ok(id) => Ok(id),
+            Ok(id) => {
+                // Keep the last detected license when a checkout no longer has one
+                tx.execute(
+                    "UPDATE repositories SET license = COALESCE(?, license) WHERE id = ?",
+                    params![git_repo.license, id],
+                )?;
+                Ok(id)
+            }
             Err(rusqlite::Error::QueryReturnedNoRows) => {
                 // Create new repository
                 tx.execute(
-                    "INSERT INTO repositories (user_name, repository_name, remote_url) VALUES (?, ?, ?)",
-                    params![git_repo.user_name, git_repo.repository_name, git_repo.remote_url],
+                    "INSERT INTO repositories (user_name, repository_name, remote_url, license) VALUES (?, ?, ?, ?)",
+                    params![
+                        git_repo.user_name,
+                        git_repo.repository_name,
+                        git_repo.remote_url,
+                        git_repo.license
+                    ],
                 )?;
                 Ok(tx.last_insert_rowid())
             }
@@ -86,7 +104,7 @@ impl RepositoryDaoInterface for RepositoryDao {
     fn get_all_repositories(&self) -> Result<Vec<StoredRepository>> {
         let conn = self.db.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, user_name, repository_name, remote_url FROM repositories ORDER BY user_name, repository_name",
+            "SELECT id, user_name, repository_name, remote_url, license FROM repositories ORDER BY user_name, repository_name",
         )?;
 
         let repositories = stmt
@@ -96,6 +114,7 @@ impl RepositoryDaoInterface for RepositoryDao {
                     user_name: row.get(1)?,
                     repository_name: row.get(2)?,
                     remote_url: row.get(3)?,
+                    license: row.get(4)?,
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -107,7 +126,7 @@ impl RepositoryDaoInterface for RepositoryDao {
     fn get_repository_by_id(&self, repository_id: i64) -> Result<Option<StoredRepository>> {
         let conn = self.db.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, user_name, repository_name, remote_url FROM repositories WHERE id = ?",
+            "SELECT id, user_name, repository_name, remote_url, license FROM repositories WHERE id = ?",
         )?;
 
         match stmt.query_row(params![repository_id], |row| {
@@ -116,6 +135,7 @@ impl RepositoryDaoInterface for RepositoryDao {
                 user_name: row.get(1)?,
                 repository_name: row.get(2)?,
                 remote_url: row.get(3)?,
+                license: row.get(4)?,
             })
         }) {
             Ok(repo) => Ok(Some(repo)),
@@ -135,7 +155,7 @@ impl RepositoryDaoInterface for RepositoryDao {
     ) -> Result<Option<StoredRepository>> {
         let conn = self.db.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, user_name, repository_name, remote_url FROM repositories WHERE user_name = ? AND repository_name = ?",
+            "SELECT id, user_name, repository_name, remote_url, license FROM repositories WHERE user_name = ? AND repository_name = ?",
         )?;
 
         match stmt.query_row(params![user_name, repository_name], |row| {
@@ -144,6 +164,7 @@ impl RepositoryDaoInterface for RepositoryDao {
                 user_name: row.get(1)?,
                 repository_name: row.get(2)?,
                 remote_url: row.get(3)?,
+                license: row.get(4)?,
             })
         }) {
             Ok(repo) => Ok(Some(repo)),
@@ -160,7 +181,11 @@ impl RepositoryDaoInterface for RepositoryDao {
         let conn = self.db.get_connection()?;
         let mut stmt = conn.prepare(
             "SELECT DISTINCT r.id, r.user_name, r.repository_name, r.remote_url, 
-                    GROUP_CONCAT(DISTINCT sr.language) as languages
+                    GROUP_CONCAT(DISTINCT sr.language) as languages,
+                    r.description, r.stars, r.default_branch, r.primary_language,
+                    r.metadata_fetched_at, MAX(s.started_at) as last_played_at,
+                    (SELECT AVG(rating) FROM repository_ratings WHERE repository_id = r.id),
+                    (SELECT COUNT(*) FROM repository_ratings WHERE repository_id = r.id)
              FROM repositories r 
              LEFT JOIN sessions s ON r.id = s.repository_id
              LEFT JOIN stage_results sr ON s.id = sr.session_id
@@ -184,10 +209,94 @@ impl RepositoryDaoInterface for RepositoryDao {
                     repository_name: row.get(2)?,
                     remote_url: row.get(3)?,
                     languages,
+                    metadata: metadata_from_row(row, 5)?,
+                    last_played_at: row
+                        .get::<_, Option<String>>(10)?
+                        .and_then(|s| NaiveDateTime::parse_from_str(&s, TIMESTAMP_FORMAT).ok())
+                        .map(|played_at| played_at.and_utc()),
+                    rating: rating_from_row(row, 11)?,
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         Ok(repositories)
     }
+
+    /// Get cached GitHub metadata for a repository, if it has ever been fetched
+    fn get_repository_metadata(&self, repository_id: i64) -> Result<Option<RepositoryMetadata>> {
+        let conn = self.db.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT description, stars, default_branch, primary_language, metadata_fetched_at
+             FROM repositories WHERE id = ?",
+        )?;
+
+        match stmt.query_row(params![repository_id], |row| metadata_from_row(row, 0)) {
+            Ok(metadata) => Ok(metadata),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(GitTypeError::database_error(format!(
+                "Database error: {}",
+                e
+            ))),
+        }
+    }
+
+    /// Store GitHub metadata for a repository
+    fn update_repository_metadata(
+        &self,
+        repository_id: i64,
+        metadata: &RepositoryMetadata,
+    ) -> Result<()> {
+        let conn = self.db.get_connection()?;
+        conn.execute(
+            "UPDATE repositories
+             SET description = ?, stars = ?, default_branch = ?, primary_language = ?,
+                 metadata_fetched_at = ?
+             WHERE id = ?",
+            params![
+                metadata.description,
+                metadata.stars as i64,
+                metadata.default_branch,
+                metadata.primary_language,
+                metadata.fetched_at.format(TIMESTAMP_FORMAT).to_string(),
+                repository_id
+            ],
+        )?;
+        Ok(())
+    }
+}
+
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Read the average and count columns starting at `offset`; `None` when never rated
+fn rating_from_row(
+    row: &rusqlite::Row,
+    offset: usize,
+) -> rusqlite::Result<Option<RepositoryRating>> {
+    let count: i64 = row.get(offset + 1)?;
+    Ok(row
+        .get::<_, Option<f64>>(offset)?
+        .map(|average| RepositoryRating {
+            average,
+            count: count as usize,
+        }))
+}
+
+/// Read the metadata columns starting at `offset`; `None` until the first fetch
+fn metadata_from_row(
+    row: &rusqlite::Row,
+    offset: usize,
+) -> rusqlite::Result<Option<RepositoryMetadata>> {
+    let fetched_at: Option<String> = row.get(offset + 4)?;
+    fetched_at
+        .and_then(|s| NaiveDateTime::parse_from_str(&s, TIMESTAMP_FORMAT).ok())
+        .map(|fetched_at| {
+            Ok(RepositoryMetadata {
+                description: row.get(offset)?,
+                stars: row.get::<_, Option<i64>>(offset + 1)?.unwrap_or(0).max(0) as u64,
+                default_branch: row.get(offset + 2)?,
+                primary_language: row.get(offset + 3)?,
+                fetched_at: fetched_at.and_utc(),
+            })
+        })
+        .transpose()
 }
@@ -0,0 +1,72 @@
+use chrono::Utc;
+use rusqlite::params;
+use shaku::Interface;
+
+use std::sync::Arc;
+
+use crate::domain::models::storage::{RatedRepository, RepositoryRating};
+use crate::Result;
+
+use super::super::database::DatabaseInterface;
+
+pub trait RepositoryRatingDaoInterface: Interface {
+    /// Store `rating` for the repository `session_id` was played on, replacing
+    /// an earlier rating of the same session; returns false when the session
+    /// doesn't exist or has no repository
+    fn rate_session(&self, session_id: i64, rating: u8) -> Result<bool>;
+    fn get_rated_repositories(&self) -> Result<Vec<RatedRepository>>;
+}
+
+pub struct RepositoryRatingDao {
+    db: Arc<dyn DatabaseInterface>,
+}
+
+impl RepositoryRatingDao {
+    pub fn new(db: Arc<dyn DatabaseInterface>) -> Self {
+        Self { db }
+    }
+}
+
+impl RepositoryRatingDaoInterface for RepositoryRatingDao {
+    fn rate_session(&self, session_id: i64, rating: u8) -> Result<bool> {
+        let conn = self.db.get_connection()?;
+        let stored = conn.execute(
+            "INSERT INTO repository_ratings (session_id, repository_id, rating, rated_at)
+             SELECT id, repository_id, ?, ? FROM sessions
+             WHERE id = ? AND repository_id IS NOT NULL
+             ON CONFLICT (session_id) DO UPDATE
+             SET rating = excluded.rating, rated_at = excluded.rated_at",
+            params![
+                rating,
+                Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                session_id,
+            ],
+        )?;
+        Ok(stored > 0)
+    }
+
+    fn get_rated_repositories(&self) -> Result<Vec<RatedRepository>> {
+        let conn = self.db.get_connection()?;
+        let repositories = conn
+            .prepare(
+                "SELECT r.user_name, r.repository_name, r.primary_language,
+                        AVG(rr.rating), COUNT(*)
+                 FROM repository_ratings rr
+                 JOIN repositories r ON r.id = rr.repository_id
+                 GROUP BY r.id",
+            )?
+            .query_map([], |row| {
+                Ok(RatedRepository {
+                    user_name: row.get(0)?,
+                    repository_name: row.get(1)?,
+                    primary_language: row.get(2)?,
+                    rating: RepositoryRating {
+                        average: row.get(3)?,
+                        count: row.get::<_, i64>(4)? as usize,
+                    },
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(repositories)
+    }
+}
@@ -1,21 +1,26 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rusqlite::{params, OptionalExtension, Transaction};
-use shaku::{Component, Interface};
+use shaku::Interface;
 
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::domain::error::GitTypeError;
+use crate::domain::models::player_profile::DEFAULT_DISPLAY_NAME;
 use crate::domain::models::storage::{
-    SaveSessionResultParams, SaveStageParams, SessionResultData, SessionStageResult, StoredSession,
+    DailyActivity, LanguageActivity, LeaderboardEntry, SaveSessionResultParams, SaveStageParams,
+    SessionHistoryFilter, SessionResultData, SessionStageResult, StoredSession,
+};
+use crate::domain::models::{
+    GitRepository, HistorySession, KeyLoad, Rank, RankTier, SessionResult,
 };
-use crate::domain::models::{GitRepository, Rank, RankTier, SessionResult};
 use crate::domain::services::scoring::RankCalculator;
 use crate::Result;
 
 use super::super::database::DatabaseInterface;
 
 pub trait SessionDaoInterface: Interface {
+    #[allow(clippy::too_many_arguments)]
     fn create_session_in_transaction(
         &self,
         tx: &Transaction,
@@ -24,6 +29,7 @@ pub trait SessionDaoInterface: Interface {
         git_repo: Option<&GitRepository>,
         game_mode: &str,
         difficulty_level: Option<&str>,
+        player_name: Option<&str>,
     ) -> Result<i64>;
     fn save_session_result_in_transaction(
         &self,
@@ -35,7 +41,9 @@ pub trait SessionDaoInterface: Interface {
         tx: &Transaction,
         params: SaveStageParams,
     ) -> Result<()>;
+    fn get_session(&self, session_id: i64) -> Result<Option<StoredSession>>;
     fn get_repository_sessions(&self, repository_id: i64) -> Result<Vec<StoredSession>>;
+    /// Best records leave out sessions marked unverified
     fn get_todays_best_session(&self) -> Result<Option<StoredSession>>;
     fn get_weekly_best_session(&self) -> Result<Option<StoredSession>>;
     fn get_all_time_best_session(&self) -> Result<Option<StoredSession>>;
@@ -47,13 +55,35 @@ pub trait SessionDaoInterface: Interface {
         order_by: &str,
         ascending: bool,
     ) -> Result<Vec<StoredSession>>;
+    /// Up to `limit` finished sessions with their results, oldest first,
+    /// starting after the `after` session of the previous page
+    fn get_session_history_page(
+        &self,
+        filter: &SessionHistoryFilter,
+        after: Option<&StoredSession>,
+        limit: usize,
+    ) -> Result<Vec<(StoredSession, SessionResultData)>>;
     fn get_session_stage_results(&self, session_id: i64) -> Result<Vec<SessionStageResult>>;
+    fn get_daily_activity(&self) -> Result<Vec<DailyActivity>>;
+    fn get_language_activity(&self) -> Result<Vec<LanguageActivity>>;
+    /// The player's per-language pace, from verified completed stages
+    fn get_language_baselines(&self) -> Result<Vec<LanguageActivity>>;
+    /// Keystrokes of every recorded stage counted per expected character
+    fn get_key_load(&self) -> Result<KeyLoad>;
+    /// Best verified results per player in a repository, highest score first
+    fn get_repository_leaderboard(&self, repository_id: i64) -> Result<Vec<LeaderboardEntry>>;
+    /// Store a session read from a history file, always as unverified since a
+    /// file can't show how it was typed; `false` when the same player already
+    /// has a session starting at that time in the repository
+    fn import_history_session_in_transaction(
+        &self,
+        tx: &Transaction,
+        repository_id: i64,
+        session: &HistorySession,
+    ) -> Result<bool>;
 }
 
-#[derive(Component)]
-#[shaku(interface = SessionDaoInterface)]
 pub struct SessionDao {
-    #[shaku(inject)]
     db: Arc<dyn DatabaseInterface>,
 }
 
@@ -61,6 +91,36 @@ impl SessionDao {
     pub fn new(db: Arc<dyn DatabaseInterface>) -> Self {
         Self { db }
     }
+
+    fn tier_name(tier: &RankTier) -> &'static str {
+        match tier {
+            RankTier::Beginner => "Beginner",
+            RankTier::Intermediate => "Intermediate",
+            RankTier::Advanced => "Advanced",
+            RankTier::Expert => "Expert",
+            RankTier::Legendary => "Legendary",
+        }
+    }
+
+    fn query_language_activity(&self, sql: &str) -> Result<Vec<LanguageActivity>> {
+        let conn = self.db.get_connection()?;
+
+        let mut stmt = conn.prepare(sql)?;
+        let activity = stmt
+            .query_map([], |row| {
+                Ok(LanguageActivity {
+                    language: row.get(0)?,
+                    stages: row.get::<_, i64>(1)? as usize,
+                    avg_wpm: row.get::<_, Option<f64>>(2)?.unwrap_or(0.0),
+                    best_wpm: row.get::<_, Option<f64>>(3)?.unwrap_or(0.0),
+                    avg_accuracy: row.get::<_, Option<f64>>(4)?.unwrap_or(0.0),
+                    keystrokes: row.get::<_, Option<i64>>(5)?.unwrap_or(0) as usize,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(activity)
+    }
 }
 
 impl SessionDaoInterface for SessionDao {
@@ -69,10 +129,11 @@ impl SessionDaoInterface for SessionDao {
         &self,
         tx: &Transaction,
         repository_id: Option<i64>,
-        _session_result: &SessionResult,
+        session_result: &SessionResult,
         git_repo: Option<&GitRepository>,
         game_mode: &str,
         difficulty_level: Option<&str>,
+        player_name: Option<&str>,
     ) -> Result<i64> {
         let started_at = Self::system_time_to_sqlite_timestamp(SystemTime::now()); // Use current time
         let completed_at = Some(Self::system_time_to_sqlite_timestamp(SystemTime::now())); // Mark as completed now
@@ -80,8 +141,9 @@ impl SessionDaoInterface for SessionDao {
         tx.execute(
             "INSERT INTO sessions (
                 repository_id, started_at, completed_at, branch, commit_hash, is_dirty,
-                game_mode, difficulty_level, max_stages, time_limit_seconds
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                game_mode, difficulty_level, max_stages, time_limit_seconds, player_name,
+                unverified
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             params![
                 repository_id,
                 started_at,
@@ -92,7 +154,9 @@ impl SessionDaoInterface for SessionDao {
                 game_mode,
                 difficulty_level,
                 None::<i32>, // max_stages - not available in SessionResult
-                None::<i32>  // time_limit_seconds - not available in SessionResult
+                None::<i32>, // time_limit_seconds - not available in SessionResult
+                player_name,
+                !session_result.plausibility.is_verified()
             ],
         )?;
 
@@ -112,13 +176,7 @@ impl SessionDaoInterface for SessionDao {
         let difficulty_level = params.difficulty_level;
         // Calculate tier and rank from session score
         let session_rank = Rank::for_score(session_result.session_score);
-        let tier_name = match session_rank.tier() {
-            RankTier::Beginner => "Beginner",
-            RankTier::Intermediate => "Intermediate",
-            RankTier::Advanced => "Advanced",
-            RankTier::Expert => "Expert",
-            RankTier::Legendary => "Legendary",
-        };
+        let tier_name = Self::tier_name(session_rank.tier());
 
         // Calculate position information using RankCalculator
         let (_, tier_position, tier_total, overall_position, overall_total) =
@@ -191,8 +249,9 @@ impl SessionDaoInterface for SessionDao {
                 stage_id, session_id, repository_id, keystrokes, mistakes, duration_ms, 
                 wpm, cpm, accuracy, consistency_streaks, score, rank_name, tier_name, 
                 rank_position, rank_total, position, total,
-                was_skipped, was_failed, completed_at, language, difficulty_level
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                was_skipped, was_failed, completed_at, language, difficulty_level,
+                mistake_breakdown, key_load
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             rusqlite::params![
                 stage_id,
                 params.session_id,
@@ -221,7 +280,9 @@ impl SessionDaoInterface for SessionDao {
                 params.challenge.and_then(|c| c.language.clone()),
                 params
                     .challenge
-                    .and_then(|c| c.difficulty_level.as_ref().map(|d| format!("{:?}", d)))
+                    .and_then(|c| c.difficulty_level.as_ref().map(|d| format!("{:?}", d))),
+                serde_json::to_string(&params.stage_result.mistake_breakdown).unwrap_or_default(),
+                serde_json::to_string(&params.stage_result.key_load).unwrap_or_default()
             ],
         )?;
 
@@ -229,11 +290,57 @@ impl SessionDaoInterface for SessionDao {
     }
 
     /// Get session history for a repository
+    fn get_session(&self, session_id: i64) -> Result<Option<StoredSession>> {
+        let conn = self.db.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, repository_id, started_at, completed_at, branch, commit_hash,
+                    is_dirty, game_mode, difficulty_level, max_stages, time_limit_seconds,
+                    player_name, unverified
+             FROM sessions
+             WHERE id = ?",
+        )?;
+
+        let session = stmt
+            .query_row(params![session_id], |row| {
+                let started_at_str: String = row.get(2)?;
+                let started_at = Self::parse_sqlite_timestamp(&started_at_str)
+                    .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+
+                let completed_at = row
+                    .get::<_, Option<String>>(3)?
+                    .map(|s| {
+                        Self::parse_sqlite_timestamp(&s)
+                            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))
+                    })
+                    .transpose()?;
+
+                Ok(StoredSession {
+                    id: row.get(0)?,
+                    repository_id: row.get(1)?,
+                    started_at,
+                    completed_at,
+                    branch: row.get(4)?,
+                    commit_hash: row.get(5)?,
+                    is_dirty: row.get(6)?,
+                    game_mode: row.get(7)?,
+                    difficulty_level: row.get(8)?,
+                    max_stages: row.get(9)?,
+                    time_limit_seconds: row.get(10)?,
+                    player_name: row.get(11)?,
+                    unverified: row.get(12)?,
+                })
+            })
+            .optional()?;
+
+        Ok(session)
+    }
+
     fn get_repository_sessions(&self, repository_id: i64) -> Result<Vec<StoredSession>> {
         let conn = self.db.get_connection()?;
         let mut stmt = conn.prepare(
             "SELECT id, repository_id, started_at, completed_at, branch, commit_hash,
-                    is_dirty, game_mode, difficulty_level, max_stages, time_limit_seconds
+                    is_dirty, game_mode, difficulty_level, max_stages, time_limit_seconds,
+                    player_name, unverified
              FROM sessions 
              WHERE repository_id = ? 
              ORDER BY started_at DESC",
@@ -265,6 +372,8 @@ impl SessionDaoInterface for SessionDao {
                     difficulty_level: row.get(8)?,
                     max_stages: row.get(9)?,
                     time_limit_seconds: row.get(10)?,
+                    player_name: row.get(11)?,
+                    unverified: row.get(12)?,
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -279,10 +388,11 @@ impl SessionDaoInterface for SessionDao {
 
         let mut stmt = conn.prepare(
             "SELECT s.id, s.repository_id, s.started_at, s.completed_at, s.branch, s.commit_hash,
-                    s.is_dirty, s.game_mode, s.difficulty_level, s.max_stages, s.time_limit_seconds
+                    s.is_dirty, s.game_mode, s.difficulty_level, s.max_stages, s.time_limit_seconds,
+                    s.player_name, s.unverified
              FROM sessions s 
              JOIN session_results sr ON s.id = sr.session_id
-             WHERE DATE(s.started_at) = ?
+             WHERE DATE(s.started_at) = ? AND s.unverified = 0
              ORDER BY sr.score DESC
              LIMIT 1",
         )?;
@@ -313,6 +423,8 @@ impl SessionDaoInterface for SessionDao {
                     difficulty_level: row.get(8)?,
                     max_stages: row.get(9)?,
                     time_limit_seconds: row.get(10)?,
+                    player_name: row.get(11)?,
+                    unverified: row.get(12)?,
                 })
             })
             .optional()?;
@@ -327,10 +439,11 @@ impl SessionDaoInterface for SessionDao {
 
         let mut stmt = conn.prepare(
             "SELECT s.id, s.repository_id, s.started_at, s.completed_at, s.branch, s.commit_hash,
-                    s.is_dirty, s.game_mode, s.difficulty_level, s.max_stages, s.time_limit_seconds
+                    s.is_dirty, s.game_mode, s.difficulty_level, s.max_stages, s.time_limit_seconds,
+                    s.player_name, s.unverified
              FROM sessions s 
              JOIN session_results sr ON s.id = sr.session_id
-             WHERE DATE(s.started_at) >= ?
+             WHERE DATE(s.started_at) >= ? AND s.unverified = 0
              ORDER BY sr.score DESC
              LIMIT 1",
         )?;
@@ -361,6 +474,8 @@ impl SessionDaoInterface for SessionDao {
                     difficulty_level: row.get(8)?,
                     max_stages: row.get(9)?,
                     time_limit_seconds: row.get(10)?,
+                    player_name: row.get(11)?,
+                    unverified: row.get(12)?,
                 })
             })
             .optional()?;
@@ -374,9 +489,11 @@ impl SessionDaoInterface for SessionDao {
 
         let mut stmt = conn.prepare(
             "SELECT s.id, s.repository_id, s.started_at, s.completed_at, s.branch, s.commit_hash,
-                    s.is_dirty, s.game_mode, s.difficulty_level, s.max_stages, s.time_limit_seconds
+                    s.is_dirty, s.game_mode, s.difficulty_level, s.max_stages, s.time_limit_seconds,
+                    s.player_name, s.unverified
              FROM sessions s 
              JOIN session_results sr ON s.id = sr.session_id
+             WHERE s.unverified = 0
              ORDER BY sr.score DESC
              LIMIT 1",
         )?;
@@ -407,6 +524,8 @@ impl SessionDaoInterface for SessionDao {
                     difficulty_level: row.get(8)?,
                     max_stages: row.get(9)?,
                     time_limit_seconds: row.get(10)?,
+                    player_name: row.get(11)?,
+                    unverified: row.get(12)?,
                 })
             })
             .optional()?;
@@ -464,7 +583,8 @@ impl SessionDaoInterface for SessionDao {
 
         let mut query = String::from(
             "SELECT s.id, s.repository_id, s.started_at, s.completed_at, s.branch, s.commit_hash,
-                    s.is_dirty, s.game_mode, s.difficulty_level, s.max_stages, s.time_limit_seconds
+                    s.is_dirty, s.game_mode, s.difficulty_level, s.max_stages, s.time_limit_seconds,
+                    s.player_name, s.unverified
              FROM sessions s 
              INNER JOIN session_results sr ON s.id = sr.session_id
              WHERE s.completed_at IS NOT NULL",
@@ -532,6 +652,8 @@ impl SessionDaoInterface for SessionDao {
                         difficulty_level: row.get(8)?,
                         max_stages: row.get(9)?,
                         time_limit_seconds: row.get(10)?,
+                        player_name: row.get(11)?,
+                        unverified: row.get(12)?,
                     })
                 },
             )?
@@ -540,6 +662,109 @@ impl SessionDaoInterface for SessionDao {
         Ok(sessions)
     }
 
+    /// Keyset pagination on (started_at, id), so every page costs the same
+    /// no matter how deep into the history it is
+    fn get_session_history_page(
+        &self,
+        filter: &SessionHistoryFilter,
+        after: Option<&StoredSession>,
+        limit: usize,
+    ) -> Result<Vec<(StoredSession, SessionResultData)>> {
+        let conn = self.db.get_connection()?;
+
+        let mut query = String::from(
+            "SELECT s.id, s.repository_id, s.started_at, s.completed_at, s.branch, s.commit_hash,
+                    s.is_dirty, s.game_mode, s.difficulty_level, s.max_stages, s.time_limit_seconds,
+                    sr.keystrokes, sr.mistakes, sr.duration_ms, sr.wpm, sr.cpm, sr.accuracy,
+                    sr.stages_completed, sr.stages_attempted, sr.stages_skipped, sr.score,
+                    sr.rank_name, sr.tier_name, sr.rank_position, sr.rank_total, sr.position, sr.total,
+                    s.player_name, s.unverified
+             FROM sessions s
+             INNER JOIN session_results sr ON s.id = sr.session_id
+             WHERE s.completed_at IS NOT NULL",
+        );
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+
+        if let Some(repo_id) = filter.repository_id {
+            query.push_str(" AND s.repository_id = ?");
+            params.push(Box::new(repo_id));
+        }
+        if let Some(since) = filter.since {
+            query.push_str(" AND DATE(s.started_at, 'localtime') >= ?");
+            params.push(Box::new(since.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(until) = filter.until {
+            query.push_str(" AND DATE(s.started_at, 'localtime') <= ?");
+            params.push(Box::new(until.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(after) = after {
+            query.push_str(" AND (s.started_at, s.id) > (?, ?)");
+            params.push(Box::new(
+                after.started_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            ));
+            params.push(Box::new(after.id));
+        }
+        query.push_str(" ORDER BY s.started_at ASC, s.id ASC LIMIT ?");
+        params.push(Box::new(limit as i64));
+
+        let mut stmt = conn.prepare(&query)?;
+        let page = stmt
+            .query_map(
+                rusqlite::params_from_iter(params.iter().map(|param| param.as_ref())),
+                |row| {
+                    let started_at_str: String = row.get(2)?;
+                    let started_at = Self::parse_sqlite_timestamp(&started_at_str)
+                        .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+
+                    let completed_at = row
+                        .get::<_, Option<String>>(3)?
+                        .map(|s| {
+                            Self::parse_sqlite_timestamp(&s)
+                                .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))
+                        })
+                        .transpose()?;
+
+                    let session = StoredSession {
+                        id: row.get(0)?,
+                        repository_id: row.get(1)?,
+                        started_at,
+                        completed_at,
+                        branch: row.get(4)?,
+                        commit_hash: row.get(5)?,
+                        is_dirty: row.get(6)?,
+                        game_mode: row.get(7)?,
+                        difficulty_level: row.get(8)?,
+                        max_stages: row.get(9)?,
+                        time_limit_seconds: row.get(10)?,
+                        player_name: row.get(27)?,
+                        unverified: row.get(28)?,
+                    };
+                    let result = SessionResultData {
+                        keystrokes: row.get::<_, i64>(11)? as usize,
+                        mistakes: row.get::<_, i64>(12)? as usize,
+                        duration_ms: row.get::<_, i64>(13)? as u64,
+                        wpm: row.get(14)?,
+                        cpm: row.get(15)?,
+                        accuracy: row.get(16)?,
+                        stages_completed: row.get::<_, i64>(17)? as usize,
+                        stages_attempted: row.get::<_, i64>(18)? as usize,
+                        stages_skipped: row.get::<_, i64>(19)? as usize,
+                        score: row.get(20)?,
+                        rank_name: row.get(21)?,
+                        tier_name: row.get(22)?,
+                        rank_position: row.get(23)?,
+                        rank_total: row.get(24)?,
+                        position: row.get(25)?,
+                        total: row.get(26)?,
+                    };
+                    Ok((session, result))
+                },
+            )?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(page)
+    }
+
     /// Get stage results for a specific session
     fn get_session_stage_results(&self, session_id: i64) -> Result<Vec<SessionStageResult>> {
         let conn = self.db.get_connection()?;
@@ -549,7 +774,8 @@ impl SessionDaoInterface for SessionDao {
                     sr.score, sr.language, sr.difficulty_level, sr.rank_name, sr.tier_name,
                     sr.rank_position, sr.rank_total, sr.position, sr.total, sr.was_skipped, sr.was_failed,
                     s.stage_number,
-                    c.file_path, c.start_line, c.end_line, c.code_content
+                    c.file_path, c.start_line, c.end_line, c.code_content,
+                    sr.mistake_breakdown, sr.key_load, c.id, c.comment_ranges
              FROM stage_results sr
              JOIN stages s ON sr.stage_id = s.id
              LEFT JOIN challenges c ON s.challenge_id = c.id
@@ -583,12 +809,214 @@ impl SessionDaoInterface for SessionDao {
                     start_line: row.get(19)?,
                     end_line: row.get(20)?,
                     code_content: row.get(21)?,
+                    challenge_id: row.get(24)?,
+                    comment_ranges: row
+                        .get::<_, Option<String>>(25)?
+                        .and_then(|json| serde_json::from_str(&json).ok())
+                        .unwrap_or_default(),
+                    mistake_breakdown: row
+                        .get::<_, Option<String>>(22)?
+                        .and_then(|json| serde_json::from_str(&json).ok()),
+                    key_load: row
+                        .get::<_, Option<String>>(23)?
+                        .and_then(|json| serde_json::from_str(&json).ok()),
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         Ok(stage_results)
     }
+
+    /// Per-day session aggregates, most recent day first
+    fn get_daily_activity(&self) -> Result<Vec<DailyActivity>> {
+        let conn = self.db.get_connection()?;
+
+        let mut stmt = conn.prepare(
+            "SELECT DATE(s.started_at, 'localtime') AS day, COUNT(*), AVG(sr.wpm), MAX(sr.wpm),
+                    AVG(sr.accuracy), SUM(sr.keystrokes)
+             FROM sessions s
+             JOIN session_results sr ON s.id = sr.session_id
+             GROUP BY day
+             ORDER BY day DESC",
+        )?;
+
+        let activity = stmt
+            .query_map([], |row| {
+                let day: String = row.get(0)?;
+                let date = NaiveDate::parse_from_str(&day, "%Y-%m-%d")
+                    .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+
+                Ok(DailyActivity {
+                    date,
+                    sessions: row.get::<_, i64>(1)? as usize,
+                    avg_wpm: row.get::<_, Option<f64>>(2)?.unwrap_or(0.0),
+                    best_wpm: row.get::<_, Option<f64>>(3)?.unwrap_or(0.0),
+                    avg_accuracy: row.get::<_, Option<f64>>(4)?.unwrap_or(0.0),
+                    keystrokes: row.get::<_, Option<i64>>(5)?.unwrap_or(0) as usize,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(activity)
+    }
+
+    /// Per-language aggregates of completed (not skipped or failed) stages
+    fn get_language_activity(&self) -> Result<Vec<LanguageActivity>> {
+        self.query_language_activity(
+            "SELECT language, COUNT(*), AVG(wpm), MAX(wpm), AVG(accuracy), SUM(keystrokes)
+             FROM stage_results
+             WHERE language IS NOT NULL AND language != ''
+               AND was_skipped = 0 AND was_failed = 0
+             GROUP BY language
+             ORDER BY language",
+        )
+    }
+
+    /// Like `get_language_activity`, leaving out sessions marked unverified
+    fn get_language_baselines(&self) -> Result<Vec<LanguageActivity>> {
+        self.query_language_activity(
+            "SELECT sr.language, COUNT(*), AVG(sr.wpm), MAX(sr.wpm), AVG(sr.accuracy),
+                    SUM(sr.keystrokes)
+             FROM stage_results sr
+             JOIN sessions s ON s.id = sr.session_id
+             WHERE sr.language IS NOT NULL AND sr.language != ''
+               AND sr.was_skipped = 0 AND sr.was_failed = 0
+               AND s.unverified = 0
+             GROUP BY sr.language
+             ORDER BY sr.language",
+        )
+    }
+
+    fn get_key_load(&self) -> Result<KeyLoad> {
+        let conn = self.db.get_connection()?;
+
+        let mut stmt =
+            conn.prepare("SELECT key_load FROM stage_results WHERE key_load IS NOT NULL")?;
+        let loads = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(loads
+            .iter()
+            .filter_map(|json| serde_json::from_str::<KeyLoad>(json).ok())
+            .fold(KeyLoad::default(), |total, load| total.merge(&load)))
+    }
+
+    /// Sessions recorded before profiles were tracked count for the default player name
+    fn get_repository_leaderboard(&self, repository_id: i64) -> Result<Vec<LeaderboardEntry>> {
+        let conn = self.db.get_connection()?;
+
+        let mut stmt = conn.prepare(
+            "SELECT COALESCE(s.player_name, ?) AS player, MAX(sr.score), MAX(sr.wpm),
+                    MAX(sr.accuracy), COUNT(*), MAX(s.completed_at)
+             FROM sessions s
+             JOIN session_results sr ON s.id = sr.session_id
+             WHERE s.repository_id = ? AND s.unverified = 0
+             GROUP BY player
+             ORDER BY MAX(sr.score) DESC, player",
+        )?;
+
+        let entries = stmt
+            .query_map(params![DEFAULT_DISPLAY_NAME, repository_id], |row| {
+                let last_played_at = row
+                    .get::<_, Option<String>>(5)?
+                    .map(|s| {
+                        Self::parse_sqlite_timestamp(&s)
+                            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))
+                    })
+                    .transpose()?;
+
+                Ok(LeaderboardEntry {
+                    player_name: row.get(0)?,
+                    best_score: row.get::<_, Option<f64>>(1)?.unwrap_or(0.0),
+                    best_wpm: row.get::<_, Option<f64>>(2)?.unwrap_or(0.0),
+                    best_accuracy: row.get::<_, Option<f64>>(3)?.unwrap_or(0.0),
+                    sessions: row.get::<_, i64>(4)? as usize,
+                    last_played_at,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(entries)
+    }
+
+    fn import_history_session_in_transaction(
+        &self,
+        tx: &Transaction,
+        repository_id: i64,
+        session: &HistorySession,
+    ) -> Result<bool> {
+        let started_at = session.started_at.format("%Y-%m-%d %H:%M:%S").to_string();
+
+        let exists: bool = tx.query_row(
+            "SELECT EXISTS(
+                SELECT 1 FROM sessions
+                WHERE repository_id = ? AND started_at = ? AND player_name IS ?
+             )",
+            params![repository_id, started_at, session.player],
+            |row| row.get(0),
+        )?;
+        if exists {
+            return Ok(false);
+        }
+
+        tx.execute(
+            "INSERT INTO sessions (
+                repository_id, started_at, completed_at, branch, commit_hash, is_dirty,
+                game_mode, difficulty_level, player_name, unverified
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            params![
+                repository_id,
+                started_at,
+                session.completed_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                session.branch,
+                session.commit,
+                session.dirty,
+                session.game_mode,
+                session.difficulty,
+                session.player,
+                true
+            ],
+        )?;
+        let session_id = tx.last_insert_rowid();
+
+        let result = &session.result;
+        let rank = Rank::for_score(result.score);
+        let (_, tier_position, tier_total, overall_position, overall_total) =
+            RankCalculator::calculate_tier_info(result.score);
+        tx.execute(
+            "INSERT INTO session_results (
+                session_id, repository_id, keystrokes, mistakes, duration_ms,
+                wpm, cpm, accuracy, stages_completed, stages_attempted, stages_skipped,
+                score, rank_name, tier_name, rank_position, rank_total, position, total,
+                game_mode, difficulty_level
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            params![
+                session_id,
+                repository_id,
+                result.keystrokes as i64,
+                result.mistakes as i64,
+                result.duration_ms as i64,
+                result.wpm,
+                result.cpm,
+                result.accuracy,
+                result.stages_completed as i64,
+                result.stages_attempted as i64,
+                result.stages_skipped as i64,
+                result.score,
+                rank.name(),
+                Self::tier_name(rank.tier()),
+                tier_position as i64,
+                tier_total as i64,
+                overall_position as i64,
+                overall_total as i64,
+                session.game_mode,
+                session.difficulty
+            ],
+        )?;
+
+        Ok(true)
+    }
 }
 
 impl SessionDao {
@@ -1,6 +1,6 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDateTime, Utc};
 use rusqlite::params;
-use shaku::{Component, Interface};
+use shaku::Interface;
 
 use std::sync::Arc;
 
@@ -29,10 +29,7 @@ pub trait StageDaoInterface: Interface {
     fn get_difficulty_breakdown(&self, repository_id: Option<i64>) -> Result<Vec<DifficultyStats>>;
 }
 
-#[derive(Component)]
-#[shaku(interface = StageDaoInterface)]
 pub struct StageDao {
-    #[shaku(inject)]
     db: Arc<dyn DatabaseInterface>,
 }
 
@@ -397,10 +394,13 @@ impl StageDao {
         })
     }
 
-    /// Parse SQLite timestamp string to DateTime<Utc>
+    /// Parse SQLite timestamp string to DateTime<Utc>, as written by `SessionDao` or RFC 3339
     fn parse_sqlite_timestamp(timestamp: &str) -> Result<DateTime<Utc>> {
         DateTime::parse_from_rfc3339(timestamp)
             .map(|dt| dt.with_timezone(&Utc))
+            .or_else(|_| {
+                NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%d %H:%M:%S").map(|dt| dt.and_utc())
+            })
             .map_err(|e| GitTypeError::database_error(format!("Failed to parse timestamp: {}", e)))
     }
 }
@@ -1,11 +1,20 @@
 use rusqlite::Connection;
 use shaku::Interface;
 
+#[cfg(not(feature = "test-mocks"))]
+use std::fs::{File, OpenOptions};
+use std::path::Path;
 #[cfg(not(feature = "test-mocks"))]
 use std::path::PathBuf;
+#[cfg(not(feature = "test-mocks"))]
+use std::sync::OnceLock;
 use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
 
 use crate::domain::error::GitTypeError;
+#[cfg(not(feature = "test-mocks"))]
+use crate::domain::models::DemoMode;
+use crate::domain::models::ReadOnlyMode;
 use crate::Result;
 
 use super::migrations::{get_all_migrations, get_latest_version};
@@ -17,9 +26,18 @@ pub trait DatabaseInterface: Interface {
 }
 
 pub struct Database {
-    connection: Mutex<Connection>,
+    connection: Arc<Mutex<Connection>>,
 }
 
+/// Held until exit by the gittype that owns the database file
+#[cfg(not(feature = "test-mocks"))]
+static INSTANCE_LOCK: OnceLock<File> = OnceLock::new();
+
+/// The in-memory copy every read-only or demo connection shares, so all of
+/// them see one history and the file is copied once per process
+#[cfg(not(feature = "test-mocks"))]
+static READ_ONLY_COPY: OnceLock<Arc<Mutex<Connection>>> = OnceLock::new();
+
 impl shaku::Component<crate::presentation::di::AppModule> for Database {
     type Interface = dyn DatabaseInterface;
     type Parameters = ();
@@ -42,6 +60,9 @@ impl Database {
     #[cfg(not(feature = "test-mocks"))]
     pub fn new() -> Result<Self> {
         let db_path = Self::get_database_path()?;
+        if ReadOnlyMode::is_enabled() || DemoMode::is_enabled() {
+            return Self::shared_copy(&db_path);
+        }
 
         if let Some(parent) = db_path.parent() {
             std::fs::create_dir_all(parent)?;
@@ -51,29 +72,215 @@ impl Database {
         // Enable foreign key constraints
         connection.execute("PRAGMA foreign_keys = ON", [])?;
         let db = Self {
-            connection: Mutex::new(connection),
+            connection: Arc::new(Mutex::new(connection)),
         };
         Ok(db)
     }
 
     #[cfg(feature = "test-mocks")]
     pub fn new() -> Result<Self> {
-        // Use in-memory database for tests
-        let connection = Connection::open(":memory:")?;
+        Self::in_memory()
+    }
+
+    /// A private database that lives only as long as this value, with the full schema
+    pub fn in_memory() -> Result<Self> {
+        let connection = Connection::open_in_memory()?;
         // Enable foreign key constraints
         connection.execute("PRAGMA foreign_keys = ON", [])?;
         let db = Self {
-            connection: Mutex::new(connection),
+            connection: Arc::new(Mutex::new(connection)),
         };
-        // Automatically initialize schema for tests
         db.init()?;
         Ok(db)
     }
 
+    /// An in-memory copy of the database file at `path`, so writes never reach it;
+    /// a missing file or one at another schema version gives an empty copy
+    pub fn copy_of(path: &Path) -> Result<Self> {
+        let db = Self::in_memory()?;
+        if !path.exists() {
+            return Ok(db);
+        }
+        db.with_connection(|conn| {
+            conn.busy_timeout(Duration::from_secs(5))?;
+            conn.execute(
+                "ATTACH DATABASE ?1 AS disk",
+                [path.to_string_lossy().as_ref()],
+            )?;
+            let disk_version: Option<i32> =
+                conn.query_row("SELECT MAX(version) FROM disk.schema_version", [], |row| {
+                    row.get(0)
+                })?;
+            if disk_version == Some(get_latest_version()) {
+                let tables = conn
+                    .prepare(
+                        "SELECT name FROM main.sqlite_master WHERE type = 'table'
+                         AND name NOT LIKE 'sqlite_%' AND name != 'schema_version'",
+                    )?
+                    .query_map([], |row| row.get::<_, String>(0))?
+                    .collect::<rusqlite::Result<Vec<_>>>()?;
+                let copy = tables
+                    .iter()
+                    .map(|table| {
+                        format!(
+                            "INSERT INTO main.\"{0}\" SELECT * FROM disk.\"{0}\";",
+                            table
+                        )
+                    })
+                    .collect::<String>();
+                conn.execute_batch(&format!(
+                    "PRAGMA foreign_keys = OFF; BEGIN; {} COMMIT; PRAGMA foreign_keys = ON;",
+                    copy
+                ))?;
+            } else {
+                log::warn!(
+                    "Database schema version {:?} differs from {}; starting with empty history",
+                    disk_version,
+                    get_latest_version()
+                );
+            }
+            conn.execute("DETACH DATABASE disk", [])?;
+            Ok(())
+        })?;
+        Ok(db)
+    }
+
+    #[cfg(not(feature = "test-mocks"))]
+    fn shared_copy(db_path: &Path) -> Result<Self> {
+        if let Some(connection) = READ_ONLY_COPY.get() {
+            return Ok(Self {
+                connection: Arc::clone(connection),
+            });
+        }
+        let copy = Self::copy_of(db_path).or_else(|e| {
+            log::warn!(
+                "Failed to copy the database, starting with empty history: {}",
+                e
+            );
+            Self::in_memory()
+        })?;
+        Ok(Self {
+            connection: Arc::clone(READ_ONLY_COPY.get_or_init(|| copy.connection)),
+        })
+    }
+
+    /// Take the lock that marks this process as the owner of the database file;
+    /// false when another gittype holds it
+    #[cfg(not(feature = "test-mocks"))]
+    pub fn acquire_instance_lock() -> Result<bool> {
+        let db_path = Self::get_database_path()?;
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(format!("{}.lock", db_path.display()))?;
+
+        match file.try_lock() {
+            Ok(()) => {
+                let _ = INSTANCE_LOCK.set(file);
+                Ok(true)
+            }
+            Err(std::fs::TryLockError::WouldBlock) => Ok(false),
+            Err(std::fs::TryLockError::Error(e)) => Err(e.into()),
+        }
+    }
+
+    #[cfg(feature = "test-mocks")]
+    pub fn acquire_instance_lock() -> Result<bool> {
+        Ok(true)
+    }
+
+    /// Take the instance lock, or switch this process to read-only mode when
+    /// another gittype holds it; returns whether the lock was taken
+    pub fn acquire_or_read_only() -> Result<bool> {
+        let acquired = Self::acquire_instance_lock()?;
+        if !acquired {
+            log::warn!("Database is held by another gittype, using a read-only copy");
+            ReadOnlyMode::set_enabled(true);
+        }
+        Ok(acquired)
+    }
+
     pub fn init(&self) -> Result<()> {
         self.init_tables()
     }
 
+    /// Like `init`, calling `on_progress(applied, pending)` around each migration
+    pub fn init_with_progress<F: FnMut(usize, usize)>(&self, on_progress: F) -> Result<()> {
+        self.create_schema_version_table()?;
+        self.run_migrations(on_progress)
+    }
+
+    /// Problems reported by `PRAGMA quick_check`, empty when the database is intact
+    pub fn quick_check(&self) -> Result<Vec<String>> {
+        self.with_connection(|conn| {
+            let problems = conn
+                .prepare("PRAGMA quick_check")?
+                .query_map([], |row| row.get::<_, String>(0))?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            Ok(problems
+                .into_iter()
+                .filter(|problem| problem != "ok")
+                .collect())
+        })
+    }
+
+    /// Rebuild the database file without its free pages, returning the bytes reclaimed
+    pub fn vacuum(&self) -> Result<u64> {
+        self.with_connection(|conn| {
+            let size = |conn: &Connection| -> rusqlite::Result<u64> {
+                let pages: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+                let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+                Ok((pages * page_size) as u64)
+            };
+            let before = size(conn)?;
+            conn.execute_batch("VACUUM")?;
+            Ok(before.saturating_sub(size(conn)?))
+        })
+    }
+
+    /// Open the database and run `quick_check`; a file too damaged to open is
+    /// reported as a problem too, while other errors (e.g. a lock) are returned
+    pub fn integrity_problems() -> Result<Vec<String>> {
+        match Self::new().and_then(|database| database.quick_check()) {
+            Err(GitTypeError::DatabaseError(rusqlite::Error::SqliteFailure(error, message)))
+                if matches!(
+                    error.code,
+                    rusqlite::ErrorCode::DatabaseCorrupt | rusqlite::ErrorCode::NotADatabase
+                ) =>
+            {
+                Ok(vec![message.unwrap_or_else(|| error.to_string())])
+            }
+            result => result,
+        }
+    }
+
+    /// Move the database file and its journals aside so the next open starts
+    /// from an empty schema; returns where the damaged file went
+    #[cfg(not(feature = "test-mocks"))]
+    pub fn backup_damaged() -> Result<PathBuf> {
+        let db_path = Self::get_database_path()?;
+        let suffix = format!("corrupt-{}", chrono::Local::now().format("%Y%m%d-%H%M%S"));
+        let backup_of = |path: &PathBuf| PathBuf::from(format!("{}.{}", path.display(), suffix));
+
+        ["", "-wal", "-shm", "-journal"]
+            .iter()
+            .map(|journal| PathBuf::from(format!("{}{}", db_path.display(), journal)))
+            .filter(|path| path.exists())
+            .try_for_each(|path| std::fs::rename(&path, backup_of(&path)))?;
+        Ok(backup_of(&db_path))
+    }
+
+    #[cfg(feature = "test-mocks")]
+    pub fn backup_damaged() -> Result<std::path::PathBuf> {
+        Err(GitTypeError::database_error(
+            "The in-memory test database has no file to back up".to_string(),
+        ))
+    }
+
     #[cfg(not(feature = "test-mocks"))]
     fn get_database_path() -> Result<PathBuf> {
         if cfg!(test) {
@@ -95,9 +302,7 @@ impl Database {
     }
 
     pub fn init_tables(&self) -> Result<()> {
-        self.create_schema_version_table()?;
-        self.run_migrations()?;
-        Ok(())
+        self.init_with_progress(|_, _| {})
     }
 
     pub fn with_connection<F, R>(&self, f: F) -> Result<R>
@@ -142,27 +347,36 @@ impl Database {
         })
     }
 
-    fn set_schema_version(&self, version: i32) -> Result<()> {
-        self.with_connection(|conn| {
-            conn.execute("INSERT INTO schema_version (version) VALUES (?)", [version])?;
-            Ok(())
-        })
-    }
-
-    fn run_migrations(&self) -> Result<()> {
+    /// Each migration commits together with its version, so a first launch
+    /// that is interrupted resumes at the migration it stopped in
+    fn run_migrations<F: FnMut(usize, usize)>(&self, mut on_progress: F) -> Result<()> {
         let current_version = self.get_current_schema_version()?;
-        let latest_version = get_latest_version();
+        if current_version >= get_latest_version() {
+            return Ok(());
+        }
 
-        if current_version < latest_version {
-            let migrations = get_all_migrations();
+        let pending: Vec<_> = get_all_migrations()
+            .into_iter()
+            .filter(|migration| migration.version() > current_version)
+            .collect();
+        on_progress(0, pending.len());
 
-            for migration in migrations {
-                let version = migration.version();
-                if version > current_version {
-                    self.with_connection(|conn| migration.up(conn))?;
-                    self.set_schema_version(version)?;
-                }
-            }
+        for (applied, migration) in pending.iter().enumerate() {
+            log::info!(
+                "Applying migration {}: {}",
+                migration.version(),
+                migration.description()
+            );
+            let mut conn = self.get_connection()?;
+            let tx = conn.transaction()?;
+            migration.up(&tx)?;
+            tx.execute(
+                "INSERT INTO schema_version (version) VALUES (?)",
+                [migration.version()],
+            )?;
+            tx.commit()?;
+            drop(conn);
+            on_progress(applied + 1, pending.len());
         }
 
         Ok(())
@@ -1,4 +1,16 @@
 pub mod v001_initial_schema;
+pub mod v002_repository_metadata;
+pub mod v003_typing_baselines;
+pub mod v004_achievements;
+pub mod v005_challenge_flags;
+pub mod v006_session_players;
+pub mod v007_repository_ratings;
+pub mod v008_dismissed_repositories;
+pub mod v009_difficulty_unlocks;
+pub mod v010_stage_mistake_breakdown;
+pub mod v011_stage_key_load;
+pub mod v012_repository_license;
+pub mod v013_session_unverified;
 
 use rusqlite::Connection;
 
@@ -11,7 +23,21 @@ pub trait Migration {
 }
 
 pub fn get_all_migrations() -> Vec<Box<dyn Migration>> {
-    vec![Box::new(v001_initial_schema::InitialSchema)]
+    vec![
+        Box::new(v001_initial_schema::InitialSchema),
+        Box::new(v002_repository_metadata::RepositoryMetadata),
+        Box::new(v003_typing_baselines::TypingBaselines),
+        Box::new(v004_achievements::Achievements),
+        Box::new(v005_challenge_flags::ChallengeFlags),
+        Box::new(v006_session_players::SessionPlayers),
+        Box::new(v007_repository_ratings::RepositoryRatings),
+        Box::new(v008_dismissed_repositories::DismissedRepositories),
+        Box::new(v009_difficulty_unlocks::DifficultyUnlocks),
+        Box::new(v010_stage_mistake_breakdown::StageMistakeBreakdown),
+        Box::new(v011_stage_key_load::StageKeyLoad),
+        Box::new(v012_repository_license::RepositoryLicense),
+        Box::new(v013_session_unverified::SessionUnverified),
+    ]
 }
 
 pub fn get_latest_version() -> i32 {
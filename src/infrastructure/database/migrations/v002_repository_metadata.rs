@@ -0,0 +1,52 @@
+use rusqlite::Connection;
+
+use crate::Result;
+
+use super::Migration;
+
+pub struct RepositoryMetadata;
+
+const METADATA_COLUMNS: &[(&str, &str)] = &[
+    ("description", "TEXT"),
+    ("stars", "INTEGER"),
+    ("default_branch", "TEXT"),
+    ("primary_language", "TEXT"),
+    ("metadata_fetched_at", "DATETIME"),
+];
+
+impl Migration for RepositoryMetadata {
+    fn version(&self) -> i32 {
+        2
+    }
+
+    fn description(&self) -> &str {
+        "Add GitHub metadata columns (description, stars, default branch, primary language) to repositories"
+    }
+
+    fn up(&self, conn: &Connection) -> Result<()> {
+        let existing = self.existing_columns(conn)?;
+        METADATA_COLUMNS
+            .iter()
+            .filter(|(name, _)| !existing.iter().any(|column| column == name))
+            .try_for_each(|(name, column_type)| {
+                conn.execute(
+                    &format!(
+                        "ALTER TABLE repositories ADD COLUMN {} {}",
+                        name, column_type
+                    ),
+                    [],
+                )?;
+                Ok(())
+            })
+    }
+}
+
+impl RepositoryMetadata {
+    fn existing_columns(&self, conn: &Connection) -> Result<Vec<String>> {
+        let mut stmt = conn.prepare("PRAGMA table_info(repositories)")?;
+        let columns = stmt
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(columns)
+    }
+}
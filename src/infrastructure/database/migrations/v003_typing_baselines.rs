@@ -0,0 +1,34 @@
+use rusqlite::Connection;
+
+use crate::Result;
+
+use super::Migration;
+
+pub struct TypingBaselines;
+
+impl Migration for TypingBaselines {
+    fn version(&self) -> i32 {
+        3
+    }
+
+    fn description(&self) -> &str {
+        "Create typing_baselines table for prose typing tests imported from monkeytype and keybr"
+    }
+
+    fn up(&self, conn: &Connection) -> Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS typing_baselines (
+                id INTEGER PRIMARY KEY,
+                source TEXT NOT NULL,
+                taken_at DATETIME NOT NULL,
+                wpm REAL NOT NULL,
+                accuracy REAL NOT NULL,
+                duration_seconds REAL,
+                imported_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                UNIQUE(source, taken_at)
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use rusqlite::Connection;
+
+use crate::Result;
+
+use super::Migration;
+
+pub struct Achievements;
+
+impl Migration for Achievements {
+    fn version(&self) -> i32 {
+        4
+    }
+
+    fn description(&self) -> &str {
+        "Create achievements table recording when each achievement was first earned"
+    }
+
+    fn up(&self, conn: &Connection) -> Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS achievements (
+                id TEXT PRIMARY KEY,
+                unlocked_at DATETIME NOT NULL
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use rusqlite::Connection;
+
+use crate::Result;
+
+use super::Migration;
+
+pub struct ChallengeFlags;
+
+impl Migration for ChallengeFlags {
+    fn version(&self) -> i32 {
+        5
+    }
+
+    fn description(&self) -> &str {
+        "Create challenge_flags table recording challenges flagged as bad by the player"
+    }
+
+    fn up(&self, conn: &Connection) -> Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS challenge_flags (
+                fingerprint TEXT PRIMARY KEY,
+                file_path TEXT,
+                language TEXT,
+                flagged_at DATETIME NOT NULL
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use rusqlite::Connection;
+
+use crate::Result;
+
+use super::Migration;
+
+pub struct SessionPlayers;
+
+impl Migration for SessionPlayers {
+    fn version(&self) -> i32 {
+        6
+    }
+
+    fn description(&self) -> &str {
+        "Add player_name to sessions so local profiles can be ranked against each other"
+    }
+
+    fn up(&self, conn: &Connection) -> Result<()> {
+        let has_column = conn
+            .prepare("PRAGMA table_info(sessions)")?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .iter()
+            .any(|column| column == "player_name");
+        if !has_column {
+            conn.execute("ALTER TABLE sessions ADD COLUMN player_name TEXT", [])?;
+        }
+        Ok(())
+    }
+}
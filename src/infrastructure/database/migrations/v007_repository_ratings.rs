@@ -0,0 +1,37 @@
+use rusqlite::Connection;
+
+use crate::Result;
+
+use super::Migration;
+
+pub struct RepositoryRatings;
+
+impl Migration for RepositoryRatings {
+    fn version(&self) -> i32 {
+        7
+    }
+
+    fn description(&self) -> &str {
+        "Create repository_ratings table storing the player's 1-5 rating of a repository after each session"
+    }
+
+    fn up(&self, conn: &Connection) -> Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS repository_ratings (
+                session_id INTEGER PRIMARY KEY,
+                repository_id INTEGER NOT NULL,
+                rating INTEGER NOT NULL CHECK (rating BETWEEN 1 AND 5),
+                rated_at DATETIME NOT NULL,
+                FOREIGN KEY (session_id) REFERENCES sessions (id),
+                FOREIGN KEY (repository_id) REFERENCES repositories (id)
+            )",
+            [],
+        )?;
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_repository_ratings_repository_id
+             ON repository_ratings (repository_id)",
+            [],
+        )?;
+        Ok(())
+    }
+}
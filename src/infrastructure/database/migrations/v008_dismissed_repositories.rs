@@ -0,0 +1,28 @@
+use rusqlite::Connection;
+
+use crate::Result;
+
+use super::Migration;
+
+pub struct DismissedRepositories;
+
+impl Migration for DismissedRepositories {
+    fn version(&self) -> i32 {
+        8
+    }
+
+    fn description(&self) -> &str {
+        "Create dismissed_repositories table listing repositories never to suggest in trending"
+    }
+
+    fn up(&self, conn: &Connection) -> Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS dismissed_repositories (
+                repo_name TEXT PRIMARY KEY COLLATE NOCASE,
+                dismissed_at DATETIME NOT NULL
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use rusqlite::Connection;
+
+use crate::Result;
+
+use super::Migration;
+
+pub struct DifficultyUnlocks;
+
+impl Migration for DifficultyUnlocks {
+    fn version(&self) -> i32 {
+        9
+    }
+
+    fn description(&self) -> &str {
+        "Create difficulty_unlocks table recording the gated difficulties each player unlocked"
+    }
+
+    fn up(&self, conn: &Connection) -> Result<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS difficulty_unlocks (
+                player_name TEXT NOT NULL,
+                difficulty_level TEXT NOT NULL,
+                unlocked_at DATETIME NOT NULL,
+                PRIMARY KEY (player_name, difficulty_level)
+            )",
+            [],
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use rusqlite::Connection;
+
+use crate::Result;
+
+use super::Migration;
+
+pub struct StageMistakeBreakdown;
+
+impl Migration for StageMistakeBreakdown {
+    fn version(&self) -> i32 {
+        10
+    }
+
+    fn description(&self) -> &str {
+        "Add mistake_breakdown to stage_results so mistakes can be reviewed by kind"
+    }
+
+    fn up(&self, conn: &Connection) -> Result<()> {
+        let has_column = conn
+            .prepare("PRAGMA table_info(stage_results)")?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .iter()
+            .any(|column| column == "mistake_breakdown");
+        if !has_column {
+            conn.execute(
+                "ALTER TABLE stage_results ADD COLUMN mistake_breakdown TEXT",
+                [],
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use rusqlite::Connection;
+
+use crate::Result;
+
+use super::Migration;
+
+pub struct StageKeyLoad;
+
+impl Migration for StageKeyLoad {
+    fn version(&self) -> i32 {
+        11
+    }
+
+    fn description(&self) -> &str {
+        "Add key_load to stage_results so keystrokes can be reviewed per key and per finger"
+    }
+
+    fn up(&self, conn: &Connection) -> Result<()> {
+        let has_column = conn
+            .prepare("PRAGMA table_info(stage_results)")?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .iter()
+            .any(|column| column == "key_load");
+        if !has_column {
+            conn.execute("ALTER TABLE stage_results ADD COLUMN key_load TEXT", [])?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use rusqlite::Connection;
+
+use crate::Result;
+
+use super::Migration;
+
+pub struct RepositoryLicense;
+
+impl Migration for RepositoryLicense {
+    fn version(&self) -> i32 {
+        12
+    }
+
+    fn description(&self) -> &str {
+        "Add license to repositories so exports can credit the code they quote"
+    }
+
+    fn up(&self, conn: &Connection) -> Result<()> {
+        let has_column = conn
+            .prepare("PRAGMA table_info(repositories)")?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .iter()
+            .any(|column| column == "license");
+        if !has_column {
+            conn.execute("ALTER TABLE repositories ADD COLUMN license TEXT", [])?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use rusqlite::Connection;
+
+use crate::Result;
+
+use super::Migration;
+
+pub struct SessionUnverified;
+
+impl Migration for SessionUnverified {
+    fn version(&self) -> i32 {
+        13
+    }
+
+    fn description(&self) -> &str {
+        "Add unverified to sessions so implausible results stay out of best records and leaderboards"
+    }
+
+    fn up(&self, conn: &Connection) -> Result<()> {
+        let has_column = conn
+            .prepare("PRAGMA table_info(sessions)")?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .iter()
+            .any(|column| column == "unverified");
+        if !has_column {
+            conn.execute(
+                "ALTER TABLE sessions ADD COLUMN unverified INTEGER NOT NULL DEFAULT 0",
+                [],
+            )?;
+        }
+        Ok(())
+    }
+}
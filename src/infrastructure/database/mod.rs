@@ -2,3 +2,4 @@ pub mod daos;
 #[allow(clippy::module_inception)]
 pub mod database;
 pub mod migrations;
+pub mod storage_backend;
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use shaku::{HasComponent, Interface, Module, ModuleBuildContext};
+
+use crate::Result;
+
+use super::daos::{
+    AchievementDao, AchievementDaoInterface, BaselineDao, BaselineDaoInterface, ChallengeDao,
+    ChallengeDaoInterface, ChallengeFlagDao, ChallengeFlagDaoInterface, DifficultyUnlockDao,
+    DifficultyUnlockDaoInterface, DismissedRepositoryDao, DismissedRepositoryDaoInterface,
+    RepositoryDao, RepositoryDaoInterface, RepositoryRatingDao, RepositoryRatingDaoInterface,
+    SessionDao, SessionDaoInterface, StageDao, StageDaoInterface,
+};
+use super::database::{Database, DatabaseInterface};
+
+/// Where session history is kept. Commands, screens and the DI container reach
+/// it only through these DAOs, so another backend (e.g. a shared server) only
+/// has to provide them
+pub trait StorageBackend: Interface {
+    fn name(&self) -> &'static str;
+    /// The database the DAOs share, for work that spans several of them in
+    /// one transaction
+    fn database(&self) -> Arc<dyn DatabaseInterface>;
+    fn session_dao(&self) -> Box<dyn SessionDaoInterface>;
+    fn stage_dao(&self) -> Box<dyn StageDaoInterface>;
+    fn repository_dao(&self) -> Box<dyn RepositoryDaoInterface>;
+    fn repository_rating_dao(&self) -> Box<dyn RepositoryRatingDaoInterface>;
+    fn challenge_dao(&self) -> Box<dyn ChallengeDaoInterface>;
+    fn challenge_flag_dao(&self) -> Box<dyn ChallengeFlagDaoInterface>;
+    fn dismissed_repository_dao(&self) -> Box<dyn DismissedRepositoryDaoInterface>;
+    fn difficulty_unlock_dao(&self) -> Box<dyn DifficultyUnlockDaoInterface>;
+    fn achievement_dao(&self) -> Box<dyn AchievementDaoInterface>;
+    fn baseline_dao(&self) -> Box<dyn BaselineDaoInterface>;
+}
+
+/// The default backend: the local SQLite database
+#[derive(shaku::Component)]
+#[shaku(interface = StorageBackend)]
+pub struct SqliteBackend {
+    #[shaku(inject)]
+    database: Arc<dyn DatabaseInterface>,
+}
+
+impl SqliteBackend {
+    /// Open the database file and apply pending migrations
+    pub fn open() -> Result<Self> {
+        let database = Database::new()?;
+        database.init()?;
+        Ok(Self::with_database(Arc::new(database)))
+    }
+
+    /// Open the database file as it is, for quick reads that should not pay for
+    /// migrations; a database that was never migrated reports schema version 0
+    pub fn open_unmigrated() -> Result<Self> {
+        Ok(Self::with_database(Arc::new(Database::new()?)))
+    }
+
+    /// A fresh database that is never written to disk, e.g. for tests
+    pub fn in_memory() -> Result<Self> {
+        Ok(Self::with_database(Arc::new(Database::in_memory()?)))
+    }
+
+    pub fn with_database(database: Arc<dyn DatabaseInterface>) -> Self {
+        Self { database }
+    }
+}
+
+impl StorageBackend for SqliteBackend {
+    fn name(&self) -> &'static str {
+        "sqlite"
+    }
+
+    fn database(&self) -> Arc<dyn DatabaseInterface> {
+        Arc::clone(&self.database)
+    }
+
+    fn session_dao(&self) -> Box<dyn SessionDaoInterface> {
+        Box::new(SessionDao::new(self.database()))
+    }
+
+    fn stage_dao(&self) -> Box<dyn StageDaoInterface> {
+        Box::new(StageDao::new(self.database()))
+    }
+
+    fn repository_dao(&self) -> Box<dyn RepositoryDaoInterface> {
+        Box::new(RepositoryDao::new(self.database()))
+    }
+
+    fn repository_rating_dao(&self) -> Box<dyn RepositoryRatingDaoInterface> {
+        Box::new(RepositoryRatingDao::new(self.database()))
+    }
+
+    fn challenge_dao(&self) -> Box<dyn ChallengeDaoInterface> {
+        Box::new(ChallengeDao::new(self.database()))
+    }
+
+    fn challenge_flag_dao(&self) -> Box<dyn ChallengeFlagDaoInterface> {
+        Box::new(ChallengeFlagDao::new(self.database()))
+    }
+
+    fn dismissed_repository_dao(&self) -> Box<dyn DismissedRepositoryDaoInterface> {
+        Box::new(DismissedRepositoryDao::new(self.database()))
+    }
+
+    fn difficulty_unlock_dao(&self) -> Box<dyn DifficultyUnlockDaoInterface> {
+        Box::new(DifficultyUnlockDao::new(self.database()))
+    }
+
+    fn achievement_dao(&self) -> Box<dyn AchievementDaoInterface> {
+        Box::new(AchievementDao::new(self.database()))
+    }
+
+    fn baseline_dao(&self) -> Box<dyn BaselineDaoInterface> {
+        Box::new(BaselineDao::new(self.database()))
+    }
+}
+
+/// Each DAO of the DI container is the one of the container's `StorageBackend`
+macro_rules! backend_dao_components {
+    ($($dao:ident: $interface:ident => $method:ident,)*) => {$(
+        impl<M: Module + HasComponent<dyn StorageBackend>> shaku::Component<M> for $dao {
+            type Interface = dyn $interface;
+            type Parameters = ();
+
+            fn build(
+                context: &mut ModuleBuildContext<M>,
+                _params: Self::Parameters,
+            ) -> Box<dyn $interface> {
+                let backend: Arc<dyn StorageBackend> = M::build_component(context);
+                backend.$method()
+            }
+        }
+    )*};
+}
+
+backend_dao_components! {
+    SessionDao: SessionDaoInterface => session_dao,
+    StageDao: StageDaoInterface => stage_dao,
+    RepositoryDao: RepositoryDaoInterface => repository_dao,
+    RepositoryRatingDao: RepositoryRatingDaoInterface => repository_rating_dao,
+    ChallengeDao: ChallengeDaoInterface => challenge_dao,
+    ChallengeFlagDao: ChallengeFlagDaoInterface => challenge_flag_dao,
+    DismissedRepositoryDao: DismissedRepositoryDaoInterface => dismissed_repository_dao,
+    DifficultyUnlockDao: DifficultyUnlockDaoInterface => difficulty_unlock_dao,
+    AchievementDao: AchievementDaoInterface => achievement_dao,
+    BaselineDao: BaselineDaoInterface => baseline_dao,
+}
+
+/// The backend used outside the DI container; SQLite until others exist
+pub fn open_default_backend() -> Result<Box<dyn StorageBackend>> {
+    Ok(Box::new(SqliteBackend::open()?))
+}
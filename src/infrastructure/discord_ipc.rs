@@ -0,0 +1,173 @@
+use serde_json::{json, Value};
+
+pub const OP_HANDSHAKE: u32 = 0;
+pub const OP_FRAME: u32 = 1;
+pub const OP_CLOSE: u32 = 2;
+
+/// Encode an IPC frame: little-endian opcode and length followed by the JSON payload
+pub fn encode_frame(opcode: u32, payload: &Value) -> Vec<u8> {
+    let body = payload.to_string().into_bytes();
+    let mut frame = Vec::with_capacity(8 + body.len());
+    frame.extend_from_slice(&opcode.to_le_bytes());
+    frame.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    frame.extend_from_slice(&body);
+    frame
+}
+
+/// Decode the `(opcode, length)` header of an IPC frame
+pub fn decode_header(header: [u8; 8]) -> (u32, u32) {
+    let opcode = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+    let length = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+    (opcode, length)
+}
+
+/// `SET_ACTIVITY` command payload; `None` clears the current activity
+pub fn set_activity_payload(pid: u32, activity: Option<&Value>, nonce: u64) -> Value {
+    json!({
+        "cmd": "SET_ACTIVITY",
+        "args": { "pid": pid, "activity": activity },
+        "nonce": nonce.to_string(),
+    })
+}
+
+#[cfg(not(feature = "test-mocks"))]
+mod real_impl {
+    use super::*;
+    use crate::{GitTypeError, Result};
+    use std::io::{Read, Write};
+    use std::path::PathBuf;
+
+    trait IpcStream: Read + Write + Send {}
+    impl<T: Read + Write + Send> IpcStream for T {}
+
+    /// Client for the local Discord desktop IPC socket
+    pub struct DiscordIpcClient {
+        stream: Box<dyn IpcStream>,
+        nonce: u64,
+    }
+
+    impl DiscordIpcClient {
+        pub fn connect(client_id: &str) -> Result<Self> {
+            let stream = Self::candidate_paths()
+                .into_iter()
+                .find_map(|path| Self::open(&path).ok())
+                .ok_or_else(|| {
+                    GitTypeError::ApiError("Discord IPC socket not found".to_string())
+                })?;
+
+            let mut client = Self { stream, nonce: 0 };
+            client.send(OP_HANDSHAKE, &json!({ "v": 1, "client_id": client_id }))?;
+            let (opcode, response) = client.receive()?;
+            if opcode != OP_FRAME || response["evt"] != "READY" {
+                return Err(GitTypeError::ApiError(format!(
+                    "Discord rejected handshake: {}",
+                    response
+                )));
+            }
+            Ok(client)
+        }
+
+        pub fn set_activity(&mut self, activity: Option<&Value>) -> Result<()> {
+            self.nonce += 1;
+            let payload = set_activity_payload(std::process::id(), activity, self.nonce);
+            self.send(OP_FRAME, &payload)?;
+            let (_, response) = self.receive()?;
+            if response["evt"] == "ERROR" {
+                return Err(GitTypeError::ApiError(format!(
+                    "Discord rejected activity: {}",
+                    response["data"]
+                )));
+            }
+            Ok(())
+        }
+
+        fn send(&mut self, opcode: u32, payload: &Value) -> Result<()> {
+            self.stream.write_all(&encode_frame(opcode, payload))?;
+            self.stream.flush()?;
+            Ok(())
+        }
+
+        fn receive(&mut self) -> Result<(u32, Value)> {
+            let mut header = [0u8; 8];
+            self.stream.read_exact(&mut header)?;
+            let (opcode, length) = decode_header(header);
+            let mut body = vec![0u8; length as usize];
+            self.stream.read_exact(&mut body)?;
+            if opcode == OP_CLOSE {
+                return Err(GitTypeError::ApiError(
+                    "Discord closed the IPC connection".to_string(),
+                ));
+            }
+            Ok((opcode, serde_json::from_slice(&body)?))
+        }
+
+        #[cfg(unix)]
+        fn candidate_paths() -> Vec<PathBuf> {
+            let base = ["XDG_RUNTIME_DIR", "TMPDIR", "TMP", "TEMP"]
+                .iter()
+                .find_map(|key| std::env::var_os(key))
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from("/tmp"));
+            (0..10)
+                .map(|i| base.join(format!("discord-ipc-{}", i)))
+                .collect()
+        }
+
+        #[cfg(windows)]
+        fn candidate_paths() -> Vec<PathBuf> {
+            (0..10)
+                .map(|i| PathBuf::from(format!(r"\\.\pipe\discord-ipc-{}", i)))
+                .collect()
+        }
+
+        #[cfg(unix)]
+        fn open(path: &std::path::Path) -> Result<Box<dyn IpcStream>> {
+            let stream = std::os::unix::net::UnixStream::connect(path)?;
+            stream.set_read_timeout(Some(std::time::Duration::from_secs(2)))?;
+            stream.set_write_timeout(Some(std::time::Duration::from_secs(2)))?;
+            Ok(Box::new(stream))
+        }
+
+        #[cfg(windows)]
+        fn open(path: &std::path::Path) -> Result<Box<dyn IpcStream>> {
+            let pipe = std::fs::OpenOptions::new()
+                .read(true)
+                .write(true)
+                .open(path)?;
+            Ok(Box::new(pipe))
+        }
+    }
+}
+
+#[cfg(feature = "test-mocks")]
+mod mock_impl {
+    use super::*;
+    use crate::Result;
+
+    /// Mock implementation that records activities instead of talking to Discord
+    #[derive(Default)]
+    pub struct DiscordIpcClient {
+        activities: Vec<Option<Value>>,
+    }
+
+    impl DiscordIpcClient {
+        pub fn connect(_client_id: &str) -> Result<Self> {
+            Ok(Self::default())
+        }
+
+        pub fn set_activity(&mut self, activity: Option<&Value>) -> Result<()> {
+            self.activities.push(activity.cloned());
+            Ok(())
+        }
+
+        pub fn activities(&self) -> &[Option<Value>] {
+            &self.activities
+        }
+    }
+}
+
+#[cfg(not(feature = "test-mocks"))]
+pub use real_impl::DiscordIpcClient;
+
+#[cfg(feature = "test-mocks")]
+pub use mock_impl::DiscordIpcClient;
@@ -0,0 +1,66 @@
+use std::path::Path;
+
+/// Used when neither `$VISUAL` nor `$EDITOR` is set
+pub const DEFAULT_EDITOR: &str = "vi";
+
+/// Program and arguments that open `path` at `line` in `editor`, a command
+/// line such as `nvim` or `code --wait`; `None` when `editor` is blank
+pub fn editor_command(editor: &str, path: &Path, line: usize) -> Option<(String, Vec<String>)> {
+    let mut words = editor.split_whitespace().map(str::to_string);
+    let program = words.next()?;
+    let mut args: Vec<String> = words.collect();
+
+    let name = Path::new(&program)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or(&program);
+    let location = format!("{}:{}", path.display(), line);
+    match name {
+        "code" | "code-insiders" | "codium" | "cursor" => {
+            args.extend(["--goto".to_string(), location]);
+        }
+        "subl" | "hx" | "helix" | "zed" => args.push(location),
+        _ => args.extend([format!("+{}", line), path.display().to_string()]),
+    }
+    Some((program, args))
+}
+
+#[cfg(not(feature = "test-mocks"))]
+mod real_impl {
+    use std::path::Path;
+    use std::process::Command;
+
+    /// Open `path` at `line` in the user's editor and wait for it to exit;
+    /// the terminal must already be handed back to the editor
+    pub fn open_in_editor(path: &Path, line: usize) -> Result<(), Box<dyn std::error::Error>> {
+        let editor = ["VISUAL", "EDITOR"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.trim().is_empty())
+            .unwrap_or_else(|| super::DEFAULT_EDITOR.to_string());
+        let (program, args) = super::editor_command(&editor, path, line)
+            .ok_or_else(|| format!("Invalid editor command: {}", editor))?;
+
+        let status = Command::new(&program).args(&args).status()?;
+        if !status.success() {
+            return Err(format!("{} exited with {}", program, status).into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test-mocks")]
+mod mock_impl {
+    use std::path::Path;
+
+    /// Mock implementation that doesn't actually start an editor
+    pub fn open_in_editor(_path: &Path, _line: usize) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "test-mocks"))]
+pub use real_impl::open_in_editor;
+
+#[cfg(feature = "test-mocks")]
+pub use mock_impl::open_in_editor;
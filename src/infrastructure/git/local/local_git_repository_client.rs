@@ -5,12 +5,15 @@ use std::path::{Path, PathBuf};
 
 use crate::domain::error::{GitTypeError, Result};
 use crate::domain::models::GitRepository;
+use crate::domain::services::license_detector::LicenseDetector;
 use crate::infrastructure::git::git_repository_ref_parser::GitRepositoryRefParser;
 
 pub trait LocalGitRepositoryClientInterface: Interface {
     fn is_git_repository(&self, path: &Path) -> bool;
     fn get_repository_root(&self, path: &Path) -> Option<PathBuf>;
     fn extract_git_repository(&self, repo_path: &Path) -> Result<GitRepository>;
+    fn get_commit_changed_files(&self, repo_path: &Path, revision: &str) -> Result<Vec<PathBuf>>;
+    fn hooks_dir(&self, repo_path: &Path) -> Result<PathBuf>;
 }
 
 #[derive(Component, Default, Clone)]
@@ -27,6 +30,9 @@ impl LocalGitRepositoryClient {
         git_dir.exists()
     }
 
+    /// Work tree containing `path`; `.git` may be a directory or, in linked
+    /// worktrees and submodules, a file. Inside a bare repository this is the
+    /// worktree it has checked out, see [`Self::resolve_work_tree`].
     pub fn get_repository_root(&self, path: &Path) -> Option<PathBuf> {
         let mut current_path = path.to_path_buf();
 
@@ -34,6 +40,9 @@ impl LocalGitRepositoryClient {
             if self.is_git_repository(&current_path) {
                 return Some(current_path);
             }
+            if Self::is_bare_repository(&current_path) {
+                return self.resolve_work_tree(&current_path).ok();
+            }
 
             if !current_path.pop() {
                 break;
@@ -43,10 +52,65 @@ impl LocalGitRepositoryClient {
         None
     }
 
+    /// `path` itself, unless it is a bare repository: then the linked worktree
+    /// that has the bare repository's HEAD branch checked out, else its first one
+    pub fn resolve_work_tree(&self, path: &Path) -> Result<PathBuf> {
+        if !Self::is_bare_repository(path) {
+            return Ok(path.to_path_buf());
+        }
+
+        let repo = Repository::open_bare(path).map_err(|e| {
+            GitTypeError::ExtractionFailed(format!("Failed to open git repository: {}", e))
+        })?;
+        let head = repo
+            .head()
+            .ok()
+            .and_then(|head| head.name().ok().map(String::from));
+        let work_trees: Vec<PathBuf> = repo
+            .worktrees()
+            .map(|names| {
+                names
+                    .iter()
+                    .flatten()
+                    .flatten()
+                    .filter_map(|name| repo.find_worktree(name).ok())
+                    .filter(|worktree| worktree.validate().is_ok())
+                    .map(|worktree| worktree.path().to_path_buf())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        work_trees
+            .iter()
+            .find(|work_tree| {
+                let checked_out = Repository::open(work_tree)
+                    .ok()
+                    .and_then(|repo| repo.head().ok()?.name().ok().map(String::from));
+                head.is_some() && checked_out == head
+            })
+            .or(work_trees.first())
+            .cloned()
+            .ok_or_else(|| {
+                GitTypeError::ExtractionFailed(format!(
+                    "{} is a bare repository without worktrees; add one with `git worktree add`",
+                    path.display()
+                ))
+            })
+    }
+
+    /// A bare repository keeps `HEAD`, `objects` and `refs` at its top level instead of in `.git`
+    fn is_bare_repository(path: &Path) -> bool {
+        !path.join(".git").exists()
+            && path.join("HEAD").is_file()
+            && path.join("objects").is_dir()
+            && path.join("refs").is_dir()
+    }
+
     pub fn extract_git_repository(&self, repo_path: &Path) -> Result<GitRepository> {
         let canonical_path = repo_path.canonicalize().map_err(|_| {
             GitTypeError::ExtractionFailed("Path canonicalization failed".to_string())
         })?;
+        let canonical_path = self.resolve_work_tree(&canonical_path)?;
 
         let git_root = self.get_repository_root(&canonical_path).ok_or_else(|| {
             GitTypeError::ExtractionFailed("Git repository not found".to_string())
@@ -72,6 +136,7 @@ impl LocalGitRepositoryClient {
             branch,
             commit_hash,
             is_dirty,
+            license: LicenseDetector::detect(&git_root),
             root_path: Some(git_root),
         })
     }
@@ -85,7 +150,13 @@ impl LocalGitRepositoryClient {
         })
     }
 
+    /// Name of the checked out branch; a detached HEAD has none
     fn get_current_branch(&self, repo: &Repository) -> Result<String> {
+        if repo.head_detached().unwrap_or(false) {
+            return Err(GitTypeError::ExtractionFailed(
+                "HEAD is detached".to_string(),
+            ));
+        }
         let head = repo
             .head()
             .map_err(|e| GitTypeError::ExtractionFailed(format!("Failed to get HEAD: {}", e)))?;
@@ -113,8 +184,65 @@ impl LocalGitRepositoryClient {
             .map(|statuses| !statuses.is_empty())
     }
 
+    /// Repository-relative paths added or modified by the given commit
+    pub fn get_commit_changed_files(
+        &self,
+        repo_path: &Path,
+        revision: &str,
+    ) -> Result<Vec<PathBuf>> {
+        let repo = Repository::discover(repo_path).map_err(|e| {
+            GitTypeError::ExtractionFailed(format!("Failed to open git repository: {}", e))
+        })?;
+
+        let commit = repo
+            .revparse_single(revision)
+            .and_then(|object| object.peel_to_commit())
+            .map_err(|e| {
+                GitTypeError::ExtractionFailed(format!(
+                    "Failed to resolve commit '{}': {}",
+                    revision, e
+                ))
+            })?;
+
+        let tree = commit.tree().map_err(|e| {
+            GitTypeError::ExtractionFailed(format!("Failed to read commit tree: {}", e))
+        })?;
+        let parent_tree = commit.parent(0).ok().and_then(|parent| parent.tree().ok());
+
+        let diff = repo
+            .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
+            .map_err(|e| GitTypeError::ExtractionFailed(format!("Failed to diff commit: {}", e)))?;
+
+        Ok(diff
+            .deltas()
+            .filter(|delta| delta.status() != git2::Delta::Deleted)
+            .filter_map(|delta| delta.new_file().path().map(Path::to_path_buf))
+            .collect())
+    }
+
+    /// Directory git runs hooks from for the repository containing `repo_path`:
+    /// `core.hooksPath` when set, else `hooks` in the shared git directory. A
+    /// relative `core.hooksPath` is taken from the work tree, or in a bare
+    /// repository from the git directory, as git itself does
+    pub fn hooks_dir(&self, repo_path: &Path) -> Result<PathBuf> {
+        let repo = Repository::discover(repo_path)
+            .map_err(|_| GitTypeError::RepositoryNotFound(repo_path.to_path_buf()))?;
+
+        let configured = repo
+            .config()
+            .ok()
+            .and_then(|config| config.get_path("core.hooksPath").ok());
+
+        Ok(match configured {
+            Some(path) if path.is_absolute() => path,
+            Some(path) => repo.workdir().unwrap_or(repo.commondir()).join(path),
+            None => repo.commondir().join("hooks"),
+        })
+    }
+
     /// Create a GitRepository from a local path
     pub fn create_from_local_path(&self, path: &Path) -> Result<GitRepository> {
+        let path = &self.resolve_work_tree(path)?;
         let repo = Repository::open(path).map_err(|e| {
             GitTypeError::ExtractionFailed(format!("Failed to open git repository: {}", e))
         })?;
@@ -140,11 +268,12 @@ impl LocalGitRepositoryClient {
                 .unwrap_or_else(|_| ("unknown".to_string(), "unknown".to_string()))
         };
 
-        // Get current branch
+        // Get current branch, none on a detached HEAD
         let branch = repo
             .head()
             .and_then(|head| head.shorthand().map(String::from))
-            .ok();
+            .ok()
+            .filter(|_| !repo.head_detached().unwrap_or(false));
 
         // Get current commit hash
         let commit_hash = repo
@@ -167,6 +296,7 @@ impl LocalGitRepositoryClient {
             commit_hash,
             is_dirty,
             root_path: Some(path.to_path_buf()),
+            license: LicenseDetector::detect(path),
         })
     }
 }
@@ -183,4 +313,12 @@ impl LocalGitRepositoryClientInterface for LocalGitRepositoryClient {
     fn extract_git_repository(&self, repo_path: &Path) -> Result<GitRepository> {
         self.extract_git_repository(repo_path)
     }
+
+    fn get_commit_changed_files(&self, repo_path: &Path, revision: &str) -> Result<Vec<PathBuf>> {
+        self.get_commit_changed_files(repo_path, revision)
+    }
+
+    fn hooks_dir(&self, repo_path: &Path) -> Result<PathBuf> {
+        self.hooks_dir(repo_path)
+    }
 }
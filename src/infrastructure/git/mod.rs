@@ -4,4 +4,4 @@ pub mod remote;
 
 pub use git_repository_ref_parser::GitRepositoryRefParser;
 pub use local::LocalGitRepositoryClient;
-pub use remote::RemoteGitRepositoryClient;
+pub use remote::{ClonePhase, RemoteGitRepositoryClient};
@@ -1,3 +1,3 @@
 pub mod remote_git_repository_client;
 
-pub use remote_git_repository_client::RemoteGitRepositoryClient;
+pub use remote_git_repository_client::{ClonePhase, RemoteGitRepositoryClient};
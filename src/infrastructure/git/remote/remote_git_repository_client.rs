@@ -1,5 +1,5 @@
 use git2::build::{CheckoutBuilder, RepoBuilder};
-use git2::{Cred, FetchOptions, RemoteCallbacks};
+use git2::{Cred, CredentialType, FetchOptions, RemoteCallbacks};
 use shaku::{Component, Interface};
 
 use std::cell::RefCell;
@@ -10,6 +10,8 @@ use std::rc::Rc;
 use crate::domain::error::Result;
 use crate::domain::models::GitRepositoryRef;
 use crate::infrastructure::git::git_repository_ref_parser::GitRepositoryRefParser;
+use crate::infrastructure::github_cli;
+use crate::infrastructure::http::Network;
 use crate::GitTypeError;
 
 pub trait RemoteGitRepositoryClientInterface: Interface {
@@ -19,6 +21,13 @@ pub trait RemoteGitRepositoryClientInterface: Interface {
     fn is_repository_cached(&self, remote_url: &str) -> bool;
 }
 
+/// Phase of a clone whose progress is being reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClonePhase {
+    Fetching,
+    CheckingOut,
+}
+
 #[derive(Component, Default, Clone)]
 #[shaku(interface = RemoteGitRepositoryClientInterface)]
 pub struct RemoteGitRepositoryClient;
@@ -45,9 +54,11 @@ impl RemoteGitRepositoryClient {
             })
     }
 
+    /// Clone `repo_spec` into the repository cache; `progress_callback` returning
+    /// false while fetching aborts the clone and removes what was fetched
     pub fn clone_repository<F>(&self, repo_spec: &str, progress_callback: F) -> Result<PathBuf>
     where
-        F: FnMut(usize, usize),
+        F: FnMut(ClonePhase, usize, usize) -> bool,
     {
         let repo_info = GitRepositoryRefParser::parse(repo_spec)?;
 
@@ -73,13 +84,17 @@ impl RemoteGitRepositoryClient {
         let callback_cell = Rc::new(RefCell::new(progress_callback));
         let callback_clone = callback_cell.clone();
 
-        remote_callbacks.pack_progress(move |_stage, current, total| {
-            if total == 0 {
-                return;
-            }
-            if let Ok(mut cb) = callback_clone.try_borrow_mut() {
-                cb(current, total);
+        remote_callbacks.transfer_progress(move |stats| {
+            if stats.total_objects() == 0 {
+                return true;
             }
+            callback_clone.try_borrow_mut().map_or(true, |mut cb| {
+                cb(
+                    ClonePhase::Fetching,
+                    stats.received_objects(),
+                    stats.total_objects(),
+                )
+            })
         });
 
         let cell_clone = callback_cell.clone();
@@ -89,18 +104,36 @@ impl RemoteGitRepositoryClient {
                 return;
             }
             if let Ok(mut cb) = cell_clone.try_borrow_mut() {
-                cb(cur, total);
+                cb(ClonePhase::CheckingOut, cur, total);
             }
         });
         builder.with_checkout(checkout_builder);
 
-        remote_callbacks.credentials(|_url, username_from_url, _allowed_types| {
-            Cred::ssh_key_from_agent(username_from_url.unwrap_or("git"))
+        let gh_token = (repo_info.origin == "github.com")
+            .then(github_cli::auth_token)
+            .flatten();
+        let mut token_attempted = false;
+        remote_callbacks.credentials(move |_url, username_from_url, allowed_types| {
+            match &gh_token {
+                // A rejected token would otherwise be retried forever
+                Some(token)
+                    if allowed_types.contains(CredentialType::USER_PASS_PLAINTEXT)
+                        && !token_attempted =>
+                {
+                    token_attempted = true;
+                    Cred::userpass_plaintext("x-access-token", token)
+                }
+                _ => Cred::ssh_key_from_agent(username_from_url.unwrap_or("git")),
+            }
         });
 
         fetch_options.remote_callbacks(remote_callbacks);
+        fetch_options.proxy_options(Network::git_proxy_options(&clone_url));
         builder.fetch_options(fetch_options);
-        builder.clone(&clone_url, &local_path)?;
+        Network::apply_git_ca_bundle()?;
+        builder.clone(&clone_url, &local_path).inspect_err(|_| {
+            let _ = remove_dir_all(&local_path);
+        })?;
 
         Ok(local_path)
     }
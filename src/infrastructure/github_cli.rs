@@ -0,0 +1,42 @@
+#[cfg(not(feature = "test-mocks"))]
+mod real_impl {
+    use std::process::Command;
+    use std::sync::OnceLock;
+
+    /// Token of an authenticated `gh` CLI session for github.com, if any.
+    /// The lookup shells out once per process and is memoized.
+    pub fn auth_token() -> Option<String> {
+        static TOKEN: OnceLock<Option<String>> = OnceLock::new();
+        TOKEN.get_or_init(read_token).clone()
+    }
+
+    fn read_token() -> Option<String> {
+        let output = Command::new("gh")
+            .args(["auth", "token", "--hostname", "github.com"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())?;
+
+        let token = String::from_utf8(output.stdout).ok()?.trim().to_string();
+        if token.is_empty() {
+            None
+        } else {
+            log::debug!("Using GitHub credentials from gh CLI");
+            Some(token)
+        }
+    }
+}
+
+#[cfg(feature = "test-mocks")]
+mod mock_impl {
+    /// Mock implementation that never shells out to `gh`
+    pub fn auth_token() -> Option<String> {
+        None
+    }
+}
+
+#[cfg(not(feature = "test-mocks"))]
+pub use real_impl::auth_token;
+
+#[cfg(feature = "test-mocks")]
+pub use mock_impl::auth_token;
@@ -6,11 +6,39 @@ use crate::Result;
 #[derive(Debug, Deserialize, Clone)]
 pub struct GitHubRelease {
     pub tag_name: String,
+    #[serde(default)]
+    pub prerelease: bool,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub html_url: Option<String>,
+    /// Release notes in Markdown
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct GitHubRepositoryInfo {
+    pub description: Option<String>,
+    pub stargazers_count: u64,
+    pub default_branch: String,
+    pub language: Option<String>,
+}
+
+/// A repository starred by the signed-in user
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct GitHubStarredRepository {
+    /// `owner/name`
+    pub full_name: String,
+    pub description: Option<String>,
+    pub language: Option<String>,
+    pub stargazers_count: u64,
 }
 
 #[cfg(not(feature = "test-mocks"))]
 mod real_impl {
     use super::*;
+    use crate::infrastructure::http::Network;
     use crate::GitTypeError;
 
     pub struct GitHubApiClient {
@@ -19,9 +47,9 @@ mod real_impl {
 
     impl GitHubApiClient {
         pub fn new() -> Result<Self> {
-            let client = reqwest::Client::builder()
-                .user_agent("gittype")
+            let client = Network::client_builder()?
                 .timeout(std::time::Duration::from_secs(10))
+                .default_headers(Self::auth_headers())
                 .build()
                 .map_err(|e| {
                     GitTypeError::ExtractionFailed(format!("Failed to create HTTP client: {}", e))
@@ -30,6 +58,16 @@ mod real_impl {
             Ok(Self { client })
         }
 
+        fn auth_headers() -> reqwest::header::HeaderMap {
+            let mut headers = reqwest::header::HeaderMap::new();
+            if let Some(value) = crate::infrastructure::github_cli::auth_token()
+                .and_then(|token| format!("Bearer {}", token).parse().ok())
+            {
+                headers.insert(reqwest::header::AUTHORIZATION, value);
+            }
+            headers
+        }
+
         pub async fn fetch_latest_release(&self) -> Result<GitHubRelease> {
             let url = "https://api.github.com/repos/unhappychoice/gittype/releases/latest";
             let response = self.client.get(url).send().await.map_err(|e| {
@@ -49,6 +87,73 @@ mod real_impl {
 
             Ok(release)
         }
+
+        /// Recent releases, newest first, including pre-releases
+        pub async fn fetch_releases(&self) -> Result<Vec<GitHubRelease>> {
+            let url = "https://api.github.com/repos/unhappychoice/gittype/releases?per_page=10";
+            let response = self.client.get(url).send().await.map_err(|e| {
+                GitTypeError::ExtractionFailed(format!("Failed to fetch releases: {}", e))
+            })?;
+
+            if !response.status().is_success() {
+                return Err(GitTypeError::ExtractionFailed(format!(
+                    "GitHub API request failed with status: {}",
+                    response.status()
+                )));
+            }
+
+            response
+                .json()
+                .await
+                .map_err(|e| GitTypeError::ExtractionFailed(format!("Failed to parse JSON: {}", e)))
+        }
+
+        pub async fn fetch_repository(
+            &self,
+            owner: &str,
+            name: &str,
+        ) -> Result<GitHubRepositoryInfo> {
+            let url = format!("https://api.github.com/repos/{}/{}", owner, name);
+            let response = self.client.get(&url).send().await?;
+
+            if !response.status().is_success() {
+                return Err(GitTypeError::ApiError(format!(
+                    "GitHub API request failed with status: {}",
+                    response.status()
+                )));
+            }
+
+            Ok(response.json().await?)
+        }
+
+        /// One page of the signed-in user's stars, most recently starred first;
+        /// needs the token of a `gh` CLI session
+        pub async fn fetch_starred(
+            &self,
+            page: u32,
+            per_page: u32,
+        ) -> Result<Vec<GitHubStarredRepository>> {
+            if crate::infrastructure::github_cli::auth_token().is_none() {
+                return Err(GitTypeError::ApiError(
+                    "Sign in with `gh auth login` to list your starred repositories".to_string(),
+                ));
+            }
+
+            let url = format!(
+                "https://api.github.com/user/starred?per_page={}&page={}",
+                per_page, page
+            );
+            let response = self.client.get(&url).send().await?;
+
+            if !response.status().is_success() {
+                return Err(GitTypeError::ApiError(format!(
+                    "GitHub API request failed with status: {}",
+                    response.status()
+                )));
+            }
+
+            Ok(response.json().await?)
+        }
     }
 }
 
@@ -66,8 +171,73 @@ mod mock_impl {
         pub async fn fetch_latest_release(&self) -> Result<GitHubRelease> {
             Ok(GitHubRelease {
                 tag_name: "v1.0.0".to_string(),
+                prerelease: false,
+                draft: false,
+                html_url: Some(
+                    "https://github.com/unhappychoice/gittype/releases/tag/v1.0.0".to_string(),
+                ),
+                body: Some("## What's Changed\n* Add release notes preview by @someone in https://github.com/unhappychoice/gittype/pull/1\n".to_string()),
+            })
+        }
+
+        pub async fn fetch_releases(&self) -> Result<Vec<GitHubRelease>> {
+            Ok(vec![
+                GitHubRelease {
+                    tag_name: "v1.1.0-beta.1".to_string(),
+                    prerelease: true,
+                    draft: false,
+                    html_url: None,
+                    body: None,
+                },
+                GitHubRelease {
+                    tag_name: "v1.0.0".to_string(),
+                    prerelease: false,
+                    draft: false,
+                    html_url: None,
+                    body: None,
+                },
+            ])
+        }
+
+        pub async fn fetch_repository(
+            &self,
+            _owner: &str,
+            _name: &str,
+        ) -> Result<GitHubRepositoryInfo> {
+            Ok(GitHubRepositoryInfo {
+                description: Some("A mock repository".to_string()),
+                stargazers_count: 1234,
+                default_branch: "main".to_string(),
+                language: Some("Rust".to_string()),
             })
         }
+
+        pub async fn fetch_starred(
+            &self,
+            page: u32,
+            _per_page: u32,
+        ) -> Result<Vec<GitHubStarredRepository>> {
+            if page > 1 {
+                return Ok(Vec::new());
+            }
+            Ok(vec![
+                GitHubStarredRepository {
+                    full_name: "tokio-rs/tokio".to_string(),
+                    description: Some(
+                        "A runtime for writing reliable asynchronous applications with Rust."
+                            .to_string(),
+                    ),
+                    language: Some("Rust".to_string()),
+                    stargazers_count: 28000,
+                },
+                GitHubStarredRepository {
+                    full_name: "sharkdp/bat".to_string(),
+                    description: Some("A cat(1) clone with wings.".to_string()),
+                    language: Some("Rust".to_string()),
+                    stargazers_count: 51000,
+                },
+            ])
+        }
     }
 }
 
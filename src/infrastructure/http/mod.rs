@@ -1,5 +1,11 @@
 pub mod github_api_client;
+pub mod network;
 pub mod oss_insight_client;
+pub mod telemetry_client;
 
-pub use github_api_client::{GitHubApiClient, GitHubRelease};
+pub use github_api_client::{
+    GitHubApiClient, GitHubRelease, GitHubRepositoryInfo, GitHubStarredRepository,
+};
+pub use network::Network;
 pub use oss_insight_client::OssInsightClient;
+pub use telemetry_client::TelemetryClient;
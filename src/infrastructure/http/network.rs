@@ -0,0 +1,123 @@
+use reqwest::{Certificate, ClientBuilder, NoProxy, Proxy};
+
+use std::sync::RwLock;
+
+use crate::domain::models::config::NetworkConfig;
+use crate::{GitTypeError, Result};
+
+static CONFIG: RwLock<NetworkConfig> = RwLock::new(NetworkConfig::DEFAULT);
+
+/// Proxy and CA bundle settings shared by every HTTP client and git clone
+pub struct Network;
+
+impl Network {
+    pub fn config() -> NetworkConfig {
+        CONFIG.read().unwrap().clone()
+    }
+
+    pub fn set_config(config: NetworkConfig) {
+        *CONFIG.write().unwrap() = config;
+    }
+
+    /// A client builder with the gittype user agent, the proxy and the extra CA certificates
+    pub fn client_builder() -> Result<ClientBuilder> {
+        let config = Self::config();
+        let mut builder = reqwest::Client::builder().user_agent("gittype");
+
+        if let Some(proxy) = config.effective_proxy() {
+            let no_proxy = config
+                .effective_no_proxy()
+                .and_then(|hosts| NoProxy::from_string(&hosts));
+            let proxy = Proxy::all(&proxy).map_err(|e| {
+                GitTypeError::ApiError(format!("Invalid proxy URL '{}': {}", proxy, e))
+            })?;
+            builder = builder.proxy(proxy.no_proxy(no_proxy));
+        }
+
+        let certificates = Self::ca_certificates(&config)?;
+        if !certificates.is_empty() {
+            builder = builder.tls_certs_merge(certificates);
+        }
+
+        Ok(builder)
+    }
+
+    /// Proxy for fetching `url`: the effective proxy, else libgit2's own detection
+    /// from `http.proxy` in the git config; no proxy when the host is in `no_proxy`
+    pub fn git_proxy_options(url: &str) -> git2::ProxyOptions<'static> {
+        let config = Self::config();
+        let mut options = git2::ProxyOptions::new();
+        let bypassed = config
+            .effective_no_proxy()
+            .is_some_and(|no_proxy| Self::bypasses_proxy(&no_proxy, url));
+        if bypassed {
+            return options;
+        }
+        match config.effective_proxy() {
+            Some(proxy) => options.url(&proxy),
+            None => options.auto(),
+        };
+        options
+    }
+
+    /// Whether the host of `url` is listed in the comma-separated `no_proxy`:
+    /// `*` matches every host, and a domain matches itself and its subdomains
+    pub fn bypasses_proxy(no_proxy: &str, url: &str) -> bool {
+        let Some(host) = reqwest::Url::parse(url)
+            .ok()
+            .and_then(|url| url.host_str().map(|host| host.to_ascii_lowercase()))
+        else {
+            return false;
+        };
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+
+        no_proxy
+            .split(',')
+            .map(|entry| entry.trim().trim_start_matches('.').to_ascii_lowercase())
+            .filter(|entry| !entry.is_empty())
+            .any(|entry| {
+                entry == "*"
+                    || host == entry
+                    || host
+                        .strip_suffix(entry.as_str())
+                        .is_some_and(|prefix| prefix.ends_with('.'))
+            })
+    }
+
+    /// Trust `ca_bundle` in libgit2 as well; it adds to the system roots
+    pub fn apply_git_ca_bundle() -> Result<()> {
+        let Some(path) = Self::config().ca_bundle else {
+            return Ok(());
+        };
+
+        // SAFETY: called right before a clone, while no other libgit2 TLS stream is open
+        unsafe { git2::opts::set_ssl_cert_file(&path) }.map_err(|e| {
+            GitTypeError::ApiError(format!("Failed to use CA bundle {}: {}", path.display(), e))
+        })
+    }
+
+    /// Certificates from `ca_bundle`, empty when none is configured
+    pub fn ca_certificates(config: &NetworkConfig) -> Result<Vec<Certificate>> {
+        let Some(path) = &config.ca_bundle else {
+            return Ok(Vec::new());
+        };
+
+        let pem = std::fs::read(path).map_err(|e| {
+            GitTypeError::ApiError(format!(
+                "Failed to read CA bundle {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let certificates = Certificate::from_pem_bundle(&pem).map_err(|e| {
+            GitTypeError::ApiError(format!("Invalid CA bundle {}: {}", path.display(), e))
+        })?;
+        if certificates.is_empty() {
+            return Err(GitTypeError::ApiError(format!(
+                "CA bundle {} contains no certificates",
+                path.display()
+            )));
+        }
+        Ok(certificates)
+    }
+}
@@ -16,25 +16,22 @@ pub trait OssInsightClientInterface: Interface + std::fmt::Debug {
 mod real_impl {
     use super::*;
 
-    use reqwest::Client;
     use serde::Deserialize;
 
     use std::time::Duration;
 
+    use crate::infrastructure::http::Network;
     use crate::GitTypeError;
 
+    /// Builds its HTTP client per request, so network settings loaded after the
+    /// DI container was built still apply
     #[derive(Debug, Clone, shaku::Component)]
     #[shaku(interface = OssInsightClientInterface)]
-    pub struct OssInsightClient {
-        #[shaku(default)]
-        client: Client,
-    }
+    pub struct OssInsightClient;
 
     impl OssInsightClient {
         pub fn new() -> Self {
-            Self {
-                client: Client::new(),
-            }
+            Self
         }
 
         fn map_language_name(&self, lang: &str) -> String {
@@ -104,12 +101,11 @@ mod real_impl {
                 url = format!("{}&language={}", url, urlencoding::encode(&api_lang));
             }
 
-            let response = self
-                .client
+            let response = Network::client_builder()?
+                .timeout(Duration::from_secs(10))
+                .build()?
                 .get(&url)
-                .header("User-Agent", "gittype")
                 .header("Accept", "application/json")
-                .timeout(Duration::from_secs(10))
                 .send()
                 .await?;
 
@@ -0,0 +1,68 @@
+use crate::domain::models::TelemetryReport;
+use crate::Result;
+
+#[cfg(not(feature = "test-mocks"))]
+mod real_impl {
+    use super::*;
+    use crate::infrastructure::http::Network;
+    use crate::GitTypeError;
+
+    pub struct TelemetryClient {
+        client: reqwest::Client,
+    }
+
+    impl TelemetryClient {
+        pub fn new() -> Result<Self> {
+            let client = Network::client_builder()?
+                .timeout(std::time::Duration::from_secs(5))
+                .build()
+                .map_err(|e| {
+                    GitTypeError::ApiError(format!("Failed to create HTTP client: {}", e))
+                })?;
+
+            Ok(Self { client })
+        }
+
+        /// POST the report as JSON; the body is exactly `TelemetryReport::to_json`
+        pub async fn send(&self, endpoint: &str, report: &TelemetryReport) -> Result<()> {
+            let response = self
+                .client
+                .post(endpoint)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(report.to_json()?)
+                .send()
+                .await?;
+
+            if !response.status().is_success() {
+                return Err(GitTypeError::ApiError(format!(
+                    "Telemetry endpoint responded with status: {}",
+                    response.status()
+                )));
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "test-mocks")]
+mod mock_impl {
+    use super::*;
+
+    pub struct TelemetryClient;
+
+    impl TelemetryClient {
+        pub fn new() -> Result<Self> {
+            Ok(Self)
+        }
+
+        pub async fn send(&self, _endpoint: &str, _report: &TelemetryReport) -> Result<()> {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(not(feature = "test-mocks"))]
+pub use real_impl::TelemetryClient;
+
+#[cfg(feature = "test-mocks")]
+pub use mock_impl::TelemetryClient;
@@ -1,3 +1,6 @@
+use crate::domain::models::TelemetryReport;
+use crate::infrastructure::storage::app_data_provider::AppDataProvider;
+use crate::infrastructure::storage::file_storage::FileStorage;
 use crate::{domain::error::GitTypeError, Result};
 use chrono;
 use log4rs::{
@@ -5,11 +8,14 @@ use log4rs::{
     config::{Appender, Config, Logger, Root},
     encode::pattern::PatternEncoder,
 };
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
 static CURRENT_LOG_FILE: OnceLock<String> = OnceLock::new();
 
+const CRASH_SIGNATURES_FILE: &str = "crash_signatures.json";
+
 pub fn setup_logging() -> Result<()> {
     let log_dir = get_log_directory()?;
     std::fs::create_dir_all(&log_dir)?;
@@ -89,6 +95,57 @@ pub fn get_current_log_file_path() -> Option<String> {
     CURRENT_LOG_FILE.get().cloned()
 }
 
+/// The newest `gittype_*.log` in `log_dir`; file names carry a sortable timestamp
+pub fn find_latest_log_file(log_dir: &Path) -> Option<PathBuf> {
+    std::fs::read_dir(log_dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("gittype_") && name.ends_with(".log"))
+        })
+        .max()
+}
+
+/// Remove logs in `log_dir` whose file name timestamp is before `cutoff`,
+/// always keeping the newest `gittype_*.log`; returns the count and bytes removed
+pub fn trim_old_logs(log_dir: &Path, cutoff: chrono::NaiveDateTime) -> Result<(usize, u64)> {
+    let Ok(entries) = std::fs::read_dir(log_dir) else {
+        return Ok((0, 0));
+    };
+    let latest = find_latest_log_file(log_dir);
+
+    let (mut removed, mut freed) = (0, 0);
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let written_at = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(".log"))
+            .and_then(|stem| stem.get(stem.len().saturating_sub(15)..))
+            .and_then(|stamp| chrono::NaiveDateTime::parse_from_str(stamp, "%Y%m%d_%H%M%S").ok());
+        if written_at.is_some_and(|written_at| written_at < cutoff)
+            && latest.as_ref() != Some(&path)
+        {
+            freed += path.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+            std::fs::remove_file(&path)?;
+            removed += 1;
+        }
+    }
+    Ok((removed, freed))
+}
+
+/// The last `count` lines of the log file at `path`
+pub fn read_log_tail(path: &Path, count: usize) -> Result<Vec<String>> {
+    let content = std::fs::read(path)?;
+    let lines: Vec<String> = String::from_utf8_lossy(&content)
+        .lines()
+        .map(str::to_string)
+        .collect();
+    Ok(lines[lines.len().saturating_sub(count)..].to_vec())
+}
+
 /// Get appropriate log level based on build configuration
 fn get_log_level() -> log::LevelFilter {
     if cfg!(debug_assertions) {
@@ -129,6 +186,33 @@ pub fn log_panic_to_file(panic_info: &std::panic::PanicHookInfo) {
     }
 }
 
+/// Count a panic by version and location for telemetry; the message is never kept
+pub fn record_crash_signature(panic_info: &std::panic::PanicHookInfo) {
+    let Some(location) = panic_info.location() else {
+        return;
+    };
+    let signature = TelemetryReport::crash_signature(location.file(), location.line());
+    let mut signatures = read_crash_signatures();
+    *signatures.entry(signature).or_insert(0) += 1;
+
+    let written = crash_signatures_path().and_then(|path| {
+        std::fs::write(path, serde_json::to_string_pretty(&signatures)?)?;
+        Ok(())
+    });
+    if let Err(e) = written {
+        log::warn!("Failed to record crash signature: {}", e);
+    }
+}
+
+/// Crash counts recorded by `record_crash_signature`, empty when none were recorded
+pub fn read_crash_signatures() -> BTreeMap<String, usize> {
+    crash_signatures_path()
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
 /// Log application error to file with detailed context
 pub fn log_error_to_file(error: &GitTypeError) {
     use std::error::Error;
@@ -162,6 +246,10 @@ pub fn log_error_to_file(error: &GitTypeError) {
     write_error_to_file(&error_info);
 }
 
+fn crash_signatures_path() -> Result<PathBuf> {
+    Ok(<FileStorage as AppDataProvider>::get_app_data_dir()?.join(CRASH_SIGNATURES_FILE))
+}
+
 fn format_panic_info(panic_info: &std::panic::PanicHookInfo) -> String {
     let timestamp = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
 
@@ -1,8 +1,12 @@
 pub mod browser;
 pub mod console;
 pub mod database;
+pub mod discord_ipc;
+pub mod editor;
 pub mod git;
+pub mod github_cli;
 pub mod http;
 pub mod logging;
+pub mod reminder_scheduler;
 pub mod storage;
 pub mod terminal;
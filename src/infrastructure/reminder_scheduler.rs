@@ -0,0 +1,351 @@
+use std::path::{Path, PathBuf};
+
+use crate::domain::models::Reminder;
+
+/// Ends every crontab line gittype manages, followed by the reminder's time
+const CRON_MARKER: &str = "# gittype reminder";
+
+/// Lets `notify-send` reach the desktop session from cron's bare environment
+const CRON_SESSION_BUS: &str = "DBUS_SESSION_BUS_ADDRESS=unix:path=/run/user/$(id -u)/bus";
+
+/// The user-level task scheduler of the current platform, and the desktop
+/// notifier its reminders run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheduler {
+    Cron,
+    Launchd,
+    TaskScheduler,
+}
+
+impl Scheduler {
+    pub fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Scheduler::Launchd
+        } else if cfg!(windows) {
+            Scheduler::TaskScheduler
+        } else {
+            Scheduler::Cron
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Scheduler::Cron => "cron",
+            Scheduler::Launchd => "launchd",
+            Scheduler::TaskScheduler => "Task Scheduler",
+        }
+    }
+
+    /// Program and arguments that show a desktop notification
+    pub fn notification_command(&self, title: &str, body: &str) -> (String, Vec<String>) {
+        match self {
+            Scheduler::Cron => (
+                "notify-send".to_string(),
+                vec![title.to_string(), body.to_string()],
+            ),
+            Scheduler::Launchd => (
+                "osascript".to_string(),
+                vec![
+                    "-e".to_string(),
+                    format!(
+                        "display notification {} with title {}",
+                        applescript_string(body),
+                        applescript_string(title)
+                    ),
+                ],
+            ),
+            Scheduler::TaskScheduler => (
+                "powershell".to_string(),
+                vec![
+                    "-NoProfile".to_string(),
+                    "-Command".to_string(),
+                    format!(
+                        "Add-Type -AssemblyName System.Windows.Forms; \
+                         $n = New-Object System.Windows.Forms.NotifyIcon; \
+                         $n.Icon = [System.Drawing.SystemIcons]::Information; \
+                         $n.Visible = $true; \
+                         $n.ShowBalloonTip(10000, {}, {}, 'Info'); \
+                         Start-Sleep -Seconds 10; $n.Dispose()",
+                        powershell_string(title),
+                        powershell_string(body)
+                    ),
+                ],
+            ),
+        }
+    }
+}
+
+/// Crontab line running `exe remind --notify` every day at the reminder's time
+pub fn crontab_line(reminder: &Reminder, exe: &Path) -> String {
+    format!(
+        "{} {} * * * {} {} remind --notify {}",
+        reminder.minute(),
+        reminder.hour(),
+        CRON_SESSION_BUS,
+        shell_quote(&exe.display().to_string()),
+        cron_marker(reminder)
+    )
+}
+
+/// `crontab` with the reminder's line, replacing one already there for the same time
+pub fn crontab_with(crontab: &str, reminder: &Reminder, exe: &Path) -> String {
+    let mut crontab = crontab_without(crontab, reminder);
+    crontab.push_str(&crontab_line(reminder, exe));
+    crontab.push('\n');
+    crontab
+}
+
+/// `crontab` without the reminder's line; lines gittype didn't write are kept
+pub fn crontab_without(crontab: &str, reminder: &Reminder) -> String {
+    let marker = cron_marker(reminder);
+    crontab
+        .lines()
+        .filter(|line| !line.trim_end().ends_with(&marker))
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+pub fn launchd_label(reminder: &Reminder) -> String {
+    format!(
+        "com.gittype.reminder.{:02}{:02}",
+        reminder.hour(),
+        reminder.minute()
+    )
+}
+
+/// Launch agent running `exe remind --notify` every day at the reminder's time
+pub fn launchd_plist(reminder: &Reminder, exe: &Path) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>Label</key>
+    <string>{}</string>
+    <key>ProgramArguments</key>
+    <array>
+        <string>{}</string>
+        <string>remind</string>
+        <string>--notify</string>
+    </array>
+    <key>StartCalendarInterval</key>
+    <dict>
+        <key>Hour</key>
+        <integer>{}</integer>
+        <key>Minute</key>
+        <integer>{}</integer>
+    </dict>
+</dict>
+</plist>
+"#,
+        launchd_label(reminder),
+        xml_escape(&exe.display().to_string()),
+        reminder.hour(),
+        reminder.minute()
+    )
+}
+
+/// Where the launch agent of a reminder is written
+pub fn launch_agent_path(home: &Path, reminder: &Reminder) -> PathBuf {
+    home.join("Library")
+        .join("LaunchAgents")
+        .join(format!("{}.plist", launchd_label(reminder)))
+}
+
+/// `schtasks` arguments creating a daily task that runs `exe remind --notify`
+pub fn schtasks_create_args(reminder: &Reminder, exe: &Path) -> Vec<String> {
+    vec![
+        "/Create".to_string(),
+        "/F".to_string(),
+        "/SC".to_string(),
+        "DAILY".to_string(),
+        "/TN".to_string(),
+        reminder.task_name(),
+        "/TR".to_string(),
+        format!("\"{}\" remind --notify", exe.display()),
+        "/ST".to_string(),
+        reminder.label(),
+    ]
+}
+
+pub fn schtasks_delete_args(reminder: &Reminder) -> Vec<String> {
+    vec![
+        "/Delete".to_string(),
+        "/F".to_string(),
+        "/TN".to_string(),
+        reminder.task_name(),
+    ]
+}
+
+fn cron_marker(reminder: &Reminder) -> String {
+    format!("{} {}", CRON_MARKER, reminder.label())
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+fn applescript_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn powershell_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(not(feature = "test-mocks"))]
+mod real_impl {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    use super::Scheduler;
+    use crate::domain::models::Reminder;
+    use crate::{GitTypeError, Result};
+
+    /// Schedule `gittype remind --notify` daily at the reminder's time with
+    /// the platform's user-level scheduler
+    pub fn install_reminder(reminder: &Reminder) -> Result<()> {
+        let exe = std::env::current_exe()?;
+        match Scheduler::current() {
+            Scheduler::Cron => {
+                write_crontab(&super::crontab_with(&read_crontab()?, reminder, &exe))
+            }
+            Scheduler::Launchd => {
+                let path = super::launch_agent_path(&home_dir()?, reminder);
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(&path, super::launchd_plist(reminder, &exe))?;
+                // Reload in case an agent for this time was already loaded
+                let _ = Command::new("launchctl").arg("unload").arg(&path).output();
+                run(
+                    "launchctl",
+                    &["load".into(), "-w".into(), path.display().to_string()],
+                )
+            }
+            Scheduler::TaskScheduler => {
+                run("schtasks", &super::schtasks_create_args(reminder, &exe))
+            }
+        }
+    }
+
+    /// Remove the scheduled task of the reminder; succeeds if there is none
+    pub fn uninstall_reminder(reminder: &Reminder) -> Result<()> {
+        match Scheduler::current() {
+            Scheduler::Cron => {
+                let crontab = read_crontab()?;
+                let without = super::crontab_without(&crontab, reminder);
+                if without == crontab {
+                    return Ok(());
+                }
+                write_crontab(&without)
+            }
+            Scheduler::Launchd => {
+                let path = super::launch_agent_path(&home_dir()?, reminder);
+                if !path.exists() {
+                    return Ok(());
+                }
+                let _ = Command::new("launchctl")
+                    .args(["unload", "-w"])
+                    .arg(&path)
+                    .output();
+                std::fs::remove_file(&path)?;
+                Ok(())
+            }
+            Scheduler::TaskScheduler => {
+                let _ = Command::new("schtasks")
+                    .args(super::schtasks_delete_args(reminder))
+                    .output()?;
+                Ok(())
+            }
+        }
+    }
+
+    pub fn send_notification(title: &str, body: &str) -> Result<()> {
+        let (program, args) = Scheduler::current().notification_command(title, body);
+        run(&program, &args)
+    }
+
+    /// The user's crontab; empty when they have none yet
+    fn read_crontab() -> Result<String> {
+        let output = Command::new("crontab").arg("-l").output()?;
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        } else {
+            Ok(String::new())
+        }
+    }
+
+    fn write_crontab(crontab: &str) -> Result<()> {
+        let mut child = Command::new("crontab")
+            .arg("-")
+            .stdin(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(crontab.as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(failed("crontab", &output));
+        }
+        Ok(())
+    }
+
+    fn run(program: &str, args: &[String]) -> Result<()> {
+        let output = Command::new(program).args(args).output()?;
+        if !output.status.success() {
+            return Err(failed(program, &output));
+        }
+        Ok(())
+    }
+
+    fn failed(program: &str, output: &std::process::Output) -> GitTypeError {
+        GitTypeError::IoError(std::io::Error::other(format!(
+            "{} exited with {}: {}",
+            program,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+
+    fn home_dir() -> Result<std::path::PathBuf> {
+        dirs::home_dir().ok_or_else(|| {
+            GitTypeError::IoError(std::io::Error::other("Could not determine home directory"))
+        })
+    }
+}
+
+#[cfg(feature = "test-mocks")]
+mod mock_impl {
+    use crate::domain::models::Reminder;
+    use crate::Result;
+
+    /// Mock implementation that doesn't touch the system scheduler
+    pub fn install_reminder(_reminder: &Reminder) -> Result<()> {
+        Ok(())
+    }
+
+    /// Mock implementation that doesn't touch the system scheduler
+    pub fn uninstall_reminder(_reminder: &Reminder) -> Result<()> {
+        Ok(())
+    }
+
+    /// Mock implementation that doesn't show notifications
+    pub fn send_notification(_title: &str, _body: &str) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "test-mocks"))]
+pub use real_impl::{install_reminder, send_notification, uninstall_reminder};
+
+#[cfg(feature = "test-mocks")]
+pub use mock_impl::{install_reminder, send_notification, uninstall_reminder};
@@ -16,8 +16,11 @@ pub struct FileEntry {
 pub trait FileStorageInterface: Interface + std::fmt::Debug {
     fn delete_file(&self, file_path: &Path) -> Result<()>;
     fn file_exists(&self, file_path: &Path) -> bool;
-    fn walk_directory(&self, path: &Path) -> Result<Vec<FileEntry>>;
+    /// Symlinked entries are skipped unless `follow_symlinks` is set; symlink
+    /// cycles are always skipped
+    fn walk_directory(&self, path: &Path, follow_symlinks: bool) -> Result<Vec<FileEntry>>;
     fn read_to_string(&self, file_path: &Path) -> Result<String>;
+    fn read(&self, file_path: &Path) -> Result<Vec<u8>>;
     fn create_dir_all(&self, path: &Path) -> Result<()>;
     fn write(&self, file_path: &Path, contents: &[u8]) -> Result<()>;
     fn metadata(&self, file_path: &Path) -> Result<std::fs::Metadata>;
@@ -101,7 +104,7 @@ mod real_impl {
             file_path.exists()
         }
 
-        fn walk_directory(&self, path: &Path) -> Result<Vec<FileEntry>> {
+        fn walk_directory(&self, path: &Path, follow_symlinks: bool) -> Result<Vec<FileEntry>> {
             use ignore::WalkBuilder;
 
             if !path.exists() {
@@ -116,24 +119,32 @@ mod real_impl {
                 .git_ignore(true)
                 .git_global(true)
                 .git_exclude(true)
+                .follow_links(follow_symlinks)
                 .build();
 
-            walker
-                .map(|entry| {
+            let entries = walker
+                .filter_map(|entry| {
                     entry
-                        .map_err(|e| GitTypeError::ExtractionFailed(format!("Walk error: {}", e)))
-                        .map(|entry| FileEntry {
-                            path: entry.path().to_path_buf(),
-                            is_file: entry.path().is_file(),
-                        })
+                        .map_err(|e| log::warn!("Skipping entry while walking: {}", e))
+                        .ok()
                 })
-                .collect()
+                .filter(|entry| follow_symlinks || entry.depth() == 0 || !entry.path_is_symlink())
+                .map(|entry| FileEntry {
+                    path: entry.path().to_path_buf(),
+                    is_file: entry.path().is_file(),
+                })
+                .collect();
+            Ok(entries)
         }
 
         fn read_to_string(&self, file_path: &Path) -> Result<String> {
             std::fs::read_to_string(file_path).map_err(|e| e.into())
         }
 
+        fn read(&self, file_path: &Path) -> Result<Vec<u8>> {
+            std::fs::read(file_path).map_err(|e| e.into())
+        }
+
         fn create_dir_all(&self, path: &Path) -> Result<()> {
             std::fs::create_dir_all(path).map_err(|e| e.into())
         }
@@ -179,6 +190,8 @@ mod mock_impl {
         pub files: Vec<FileEntry>,
         #[shaku(default)]
         file_contents: HashMap<PathBuf, String>,
+        #[shaku(default)]
+        file_bytes: HashMap<PathBuf, Vec<u8>>,
     }
 
     impl FileStorageInterface for FileStorage {
@@ -190,7 +203,7 @@ mod mock_impl {
             self.file_contents.contains_key(file_path)
         }
 
-        fn walk_directory(&self, path: &Path) -> Result<Vec<FileEntry>> {
+        fn walk_directory(&self, path: &Path, _follow_symlinks: bool) -> Result<Vec<FileEntry>> {
             // For mock implementation, simulate real behavior for non-existent paths
             if path.to_str() == Some("/nonexistent/path") {
                 return Err(GitTypeError::ExtractionFailed(format!(
@@ -210,6 +223,23 @@ mod mock_impl {
             })
         }
 
+        fn read(&self, file_path: &Path) -> Result<Vec<u8>> {
+            self.file_bytes
+                .get(file_path)
+                .cloned()
+                .or_else(|| {
+                    self.file_contents
+                        .get(file_path)
+                        .map(|content| content.as_bytes().to_vec())
+                })
+                .ok_or_else(|| {
+                    GitTypeError::ExtractionFailed(format!(
+                        "Mock file not found: {}",
+                        file_path.display()
+                    ))
+                })
+        }
+
         fn create_dir_all(&self, _path: &Path) -> Result<()> {
             Ok(())
         }
@@ -263,6 +293,7 @@ mod mock_impl {
             Self {
                 files: Vec::new(),
                 file_contents: HashMap::new(),
+                file_bytes: HashMap::new(),
             }
         }
 
@@ -284,6 +315,10 @@ mod mock_impl {
             self.file_contents.insert(path.into(), content);
         }
 
+        pub fn set_file_bytes<P: Into<PathBuf>>(&mut self, path: P, bytes: Vec<u8>) {
+            self.file_bytes.insert(path.into(), bytes);
+        }
+
         pub fn read_json<T>(&self, _file_path: &Path) -> Result<Option<T>>
         where
             T: for<'de> Deserialize<'de>,
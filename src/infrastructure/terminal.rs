@@ -1,7 +1,14 @@
+use crate::domain::models::color_mode::ColorMode;
+use crate::domain::models::CompletionFeedback;
+use crossterm::terminal::SetTitle;
+use crossterm::QueueableCommand;
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 use shaku::{Component, Interface};
-use std::io::{stdout, Stdout};
+use std::collections::VecDeque;
+use std::io::{stdout, Stdout, Write};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 pub trait TerminalInterface: Interface {
     fn get(&self) -> Terminal<CrosstermBackend<Stdout>>;
@@ -21,3 +28,312 @@ impl TerminalInterface for TerminalComponent {
         Terminal::new(backend).expect("Failed to create terminal")
     }
 }
+
+/// XTWINOPS: save the window title on the terminal's title stack, and restore it
+const PUSH_TITLE: &str = "\x1b[22;0t";
+const POP_TITLE: &str = "\x1b[23;0t";
+
+/// Title currently set by gittype; `None` while the terminal shows its own title
+static CURRENT_TITLE: Mutex<Option<String>> = Mutex::new(None);
+
+/// The terminal window title, saved before gittype first changes it and restored
+/// afterwards; terminals without a title stack simply keep the last title
+pub struct WindowTitle;
+
+impl WindowTitle {
+    /// e.g. "gittype — tokio — stage 2/5"
+    pub fn for_stage(
+        repository: Option<&str>,
+        current_stage: usize,
+        total_stages: usize,
+    ) -> String {
+        let stage = format!("stage {}/{}", current_stage, total_stages);
+        match repository {
+            Some(repository) => format!("gittype — {} — {}", repository, stage),
+            None => format!("gittype — {}", stage),
+        }
+    }
+
+    /// Skipped when stdout is not a terminal, so redirected output stays clean
+    pub fn set(title: &str) {
+        if !atty::is(atty::Stream::Stdout) {
+            return;
+        }
+        if let Err(e) = Self::set_on(&mut stdout(), title) {
+            log::debug!("Failed to set the window title: {}", e);
+        }
+    }
+
+    pub fn restore() {
+        if let Err(e) = Self::restore_on(&mut stdout()) {
+            log::debug!("Failed to restore the window title: {}", e);
+        }
+    }
+
+    /// Writes only when the title changes, saving the original title the first time
+    pub fn set_on(out: &mut impl Write, title: &str) -> std::io::Result<()> {
+        let mut current = CURRENT_TITLE.lock().unwrap();
+        if current.as_deref() == Some(title) {
+            return Ok(());
+        }
+
+        if current.is_none() {
+            out.write_all(PUSH_TITLE.as_bytes())?;
+        }
+        out.queue(SetTitle(title))?;
+        out.flush()?;
+        *current = Some(title.to_string());
+        Ok(())
+    }
+
+    /// Brings back the title saved by the first `set_on`; does nothing if none was set
+    pub fn restore_on(out: &mut impl Write) -> std::io::Result<()> {
+        let mut current = CURRENT_TITLE.lock().unwrap();
+        if current.take().is_none() {
+            return Ok(());
+        }
+
+        out.write_all(POP_TITLE.as_bytes())?;
+        out.flush()
+    }
+}
+
+const BELL: &str = "\x07";
+
+/// DECSCNM: show the whole screen in reverse video, and normally again
+const REVERSE_VIDEO_ON: &str = "\x1b[?5h";
+const REVERSE_VIDEO_OFF: &str = "\x1b[?5l";
+
+/// Audible or visual bell at the end of a stage. Signals are queued and
+/// written a step at a time as they fall due, so a flash never holds up
+/// input or drawing
+#[derive(Debug, Default)]
+pub struct TerminalBell {
+    /// Escape codes still to write, each with the time it is due
+    pending: VecDeque<(Instant, &'static str)>,
+}
+
+impl TerminalBell {
+    /// How long a flash lasts, and the pause between repeated signals
+    pub const FLASH_DURATION: Duration = Duration::from_millis(100);
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue `repeats` signals, the first due at `now` or right after the
+    /// ones still pending
+    pub fn ring(&mut self, feedback: CompletionFeedback, repeats: usize, now: Instant) {
+        let steps: &[&'static str] = match feedback {
+            CompletionFeedback::None => &[],
+            CompletionFeedback::Bell => &[BELL],
+            CompletionFeedback::Flash => &[REVERSE_VIDEO_ON, REVERSE_VIDEO_OFF],
+        };
+        let mut due = self
+            .pending
+            .back()
+            .map_or(now, |&(last, _)| (last + Self::FLASH_DURATION).max(now));
+        for _ in 0..repeats {
+            for &step in steps {
+                self.pending.push_back((due, step));
+                due += Self::FLASH_DURATION;
+            }
+        }
+    }
+
+    /// Write the steps due by `now`
+    pub fn write_due(&mut self, out: &mut impl Write, now: Instant) -> std::io::Result<()> {
+        let mut written = false;
+        while let Some(&(due, step)) = self.pending.front() {
+            if due > now {
+                break;
+            }
+            out.write_all(step.as_bytes())?;
+            self.pending.pop_front();
+            written = true;
+        }
+        if written {
+            out.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Write everything still pending at once, so the screen is never left
+    /// in reverse video when the terminal is handed back
+    pub fn finish(&mut self, out: &mut impl Write) -> std::io::Result<()> {
+        match self.pending.back() {
+            Some(&(last, _)) => self.write_due(out, last),
+            None => Ok(()),
+        }
+    }
+
+    /// When the next queued step is due
+    pub fn next_due(&self) -> Option<Instant> {
+        self.pending.front().map(|&(due, _)| due)
+    }
+}
+
+/// Whether the terminal draws on a light or a dark background, so the matching
+/// color mode can be picked at startup
+pub struct TerminalBackground;
+
+impl TerminalBackground {
+    /// Asks the terminal, then falls back to `COLORFGBG`; `None` when neither tells
+    pub fn detect() -> Option<ColorMode> {
+        detect_background()
+    }
+
+    /// Color mode for an OSC 11 reply such as `ESC ] 11 ; rgb:ffff/ffff/dddd BEL`
+    pub fn from_osc_reply(reply: &[u8]) -> Option<ColorMode> {
+        let reply = String::from_utf8_lossy(reply);
+        let rgb = reply.split("]11;rgb:").nth(1)?;
+        let rgb = rgb.split(['\x07', '\x1b']).next()?;
+        let channels: Vec<f64> = rgb
+            .split('/')
+            .map(|channel| {
+                let value = u32::from_str_radix(channel, 16).ok()?;
+                let max = 16u32.checked_pow(channel.len() as u32)?.checked_sub(1)?;
+                (max > 0).then(|| value as f64 / max as f64)
+            })
+            .collect::<Option<_>>()?;
+        let [r, g, b] = channels[..] else {
+            return None;
+        };
+        Some(Self::for_luminance(0.2126 * r + 0.7152 * g + 0.0722 * b))
+    }
+
+    /// Color mode for `COLORFGBG`, e.g. `15;0`, whose last field is the
+    /// background's ANSI color: 7 and 9–15 are light
+    pub fn from_colorfgbg(value: &str) -> Option<ColorMode> {
+        let background: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+        match background {
+            7 | 9..=15 => Some(ColorMode::Light),
+            0..=6 | 8 => Some(ColorMode::Dark),
+            _ => None,
+        }
+    }
+
+    fn for_luminance(luminance: f64) -> ColorMode {
+        if luminance > 0.5 {
+            ColorMode::Light
+        } else {
+            ColorMode::Dark
+        }
+    }
+}
+
+#[cfg(not(feature = "test-mocks"))]
+mod real_impl {
+    use super::TerminalBackground;
+    use crate::domain::models::color_mode::ColorMode;
+
+    /// OSC 11: ask the terminal for its background color
+    #[cfg(unix)]
+    const BACKGROUND_QUERY: &str = "\x1b]11;?\x07";
+
+    /// DSR: every terminal answers with the cursor position, so reading up to that
+    /// reply never waits on a terminal that ignores the background query
+    #[cfg(unix)]
+    const CURSOR_POSITION_QUERY: &str = "\x1b[6n";
+
+    /// Longest reply read back for the two queries
+    #[cfg(unix)]
+    const MAX_QUERY_REPLY: usize = 128;
+
+    pub fn detect_background() -> Option<ColorMode> {
+        query_background().or_else(|| {
+            std::env::var("COLORFGBG")
+                .ok()
+                .and_then(|value| TerminalBackground::from_colorfgbg(&value))
+        })
+    }
+
+    #[cfg(unix)]
+    fn query_background() -> Option<ColorMode> {
+        use crossterm::terminal::{disable_raw_mode, enable_raw_mode, is_raw_mode_enabled};
+        use std::io::{stdout, Write};
+        use std::time::{Duration, Instant};
+
+        if !atty::is(atty::Stream::Stdin) || !atty::is(atty::Stream::Stdout) {
+            return None;
+        }
+        let was_raw = is_raw_mode_enabled().unwrap_or(false);
+        if !was_raw {
+            enable_raw_mode().ok()?;
+        }
+
+        let mut out = stdout();
+        let written = out
+            .write_all(format!("{}{}", BACKGROUND_QUERY, CURSOR_POSITION_QUERY).as_bytes())
+            .and_then(|_| out.flush());
+        let reply = written
+            .ok()
+            .map(|_| read_reply(Instant::now() + Duration::from_millis(200)));
+
+        // Raw mode stays on until the read is over so the reply is never echoed
+        if !was_raw {
+            let _ = disable_raw_mode();
+        }
+        reply.and_then(|reply| TerminalBackground::from_osc_reply(&reply))
+    }
+
+    /// Reads stdin until the cursor position reply (ESC [ row ; col R) or the
+    /// deadline, waiting with poll so no read is left blocked afterwards
+    #[cfg(unix)]
+    fn read_reply(deadline: std::time::Instant) -> Vec<u8> {
+        let mut reply = Vec::new();
+        let mut buffer = [0u8; MAX_QUERY_REPLY];
+        while reply.len() < MAX_QUERY_REPLY {
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            let mut fd = libc::pollfd {
+                fd: libc::STDIN_FILENO,
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            // SAFETY: `fd` is a single valid pollfd for the duration of the call
+            let ready = unsafe { libc::poll(&mut fd, 1, remaining.as_millis() as libc::c_int) };
+            if ready <= 0 {
+                break;
+            }
+            let wanted = MAX_QUERY_REPLY - reply.len();
+            // SAFETY: `buffer` holds at least `wanted` writable bytes
+            let read = unsafe {
+                libc::read(
+                    libc::STDIN_FILENO,
+                    buffer.as_mut_ptr().cast::<libc::c_void>(),
+                    wanted,
+                )
+            };
+            if read <= 0 {
+                break;
+            }
+            reply.extend_from_slice(&buffer[..read as usize]);
+            if reply.ends_with(b"R") && reply.contains(&b'[') {
+                break;
+            }
+        }
+        reply
+    }
+
+    /// Windows consoles don't answer OSC 11
+    #[cfg(not(unix))]
+    fn query_background() -> Option<ColorMode> {
+        None
+    }
+}
+
+#[cfg(feature = "test-mocks")]
+mod mock_impl {
+    use crate::domain::models::color_mode::ColorMode;
+
+    /// Mock implementation that leaves the configured color mode in place
+    pub fn detect_background() -> Option<ColorMode> {
+        None
+    }
+}
+
+#[cfg(not(feature = "test-mocks"))]
+use real_impl::detect_background;
+
+#[cfg(feature = "test-mocks")]
+use mock_impl::detect_background;
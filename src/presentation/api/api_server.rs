@@ -0,0 +1,187 @@
+use serde_json::{json, Value};
+
+use std::io::{BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::domain::models::storage::SessionResultData;
+use crate::domain::repositories::session_repository::SessionRepositoryTrait;
+use crate::domain::services::analytics_service::AnalyticsServiceInterface;
+use crate::domain::services::session_service::{SessionDisplayData, SessionServiceInterface};
+use crate::presentation::api::http::{HttpRequest, HttpResponse};
+use crate::{GitTypeError, Result};
+
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:7878";
+
+pub const ENDPOINTS: &[&str] = &["/sessions", "/sessions/{id}", "/stats", "/analytics"];
+
+const SORT_FIELDS: &[&str] = &["date", "score", "repository", "duration"];
+
+/// Read-only JSON API over the session history for local dashboards and scripts
+pub struct ApiServer {
+    session_service: Arc<dyn SessionServiceInterface>,
+    session_repository: Arc<dyn SessionRepositoryTrait>,
+    analytics_service: Arc<dyn AnalyticsServiceInterface>,
+}
+
+impl ApiServer {
+    pub fn new(
+        session_service: Arc<dyn SessionServiceInterface>,
+        session_repository: Arc<dyn SessionRepositoryTrait>,
+        analytics_service: Arc<dyn AnalyticsServiceInterface>,
+    ) -> Self {
+        Self {
+            session_service,
+            session_repository,
+            analytics_service,
+        }
+    }
+
+    /// Serve connections one at a time until the listener fails
+    pub fn serve(&self, listener: TcpListener) -> Result<()> {
+        for stream in listener.incoming() {
+            let outcome = stream.map_err(GitTypeError::from).and_then(|stream| {
+                stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+                self.handle_connection(stream)
+            });
+            if let Err(e) = outcome {
+                log::warn!("API connection failed: {}", e);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn handle_connection<S: Read + Write>(&self, mut stream: S) -> Result<()> {
+        let request = HttpRequest::read(&mut BufReader::new(&mut stream));
+        let response = match request {
+            Ok(request) => {
+                let response = self.handle(&request);
+                log::debug!("{} {} -> {}", request.method, request.path, response.status);
+                response
+            }
+            Err(e) => HttpResponse::error(400, e.to_string()),
+        };
+        response.write_to(&mut stream)
+    }
+
+    pub fn handle(&self, request: &HttpRequest) -> HttpResponse {
+        if request.method != "GET" {
+            return HttpResponse::error(405, format!("Method not allowed: {}", request.method));
+        }
+
+        let result = match request.path.as_str() {
+            "" => Ok(json!({ "name": "gittype", "endpoints": ENDPOINTS })),
+            "/sessions" => self.sessions(request),
+            "/stats" => self.stats(request),
+            "/analytics" => self
+                .analytics_service
+                .load_analytics_data()
+                .and_then(|data| Ok(serde_json::to_value(data)?)),
+            path => match path.strip_prefix("/sessions/").map(str::parse::<i64>) {
+                Some(Ok(id)) => self.session(id),
+                _ => return HttpResponse::error(404, format!("Not found: {}", path)),
+            },
+        };
+
+        match result {
+            Ok(Value::Null) => HttpResponse::error(404, format!("Not found: {}", request.path)),
+            Ok(body) => HttpResponse::ok(body),
+            Err(GitTypeError::ValidationError(message)) => HttpResponse::error(400, message),
+            Err(e) => HttpResponse::error(500, e.to_string()),
+        }
+    }
+
+    fn sessions(&self, request: &HttpRequest) -> Result<Value> {
+        let mut sessions = self.filtered_sessions(request)?;
+        if let Some(limit) = request.query_param::<usize>("limit")? {
+            sessions.truncate(limit);
+        }
+        Ok(serde_json::to_value(sessions)?)
+    }
+
+    fn session(&self, id: i64) -> Result<Value> {
+        let Some(session) = self.session_service.get_session_with_display_data(id)? else {
+            return Ok(Value::Null);
+        };
+
+        let mut body = serde_json::to_value(session)?;
+        body["stages"] =
+            serde_json::to_value(self.session_repository.get_session_stage_results(id)?)?;
+        Ok(body)
+    }
+
+    fn stats(&self, request: &HttpRequest) -> Result<Value> {
+        let sessions = self.filtered_sessions(request)?;
+        Ok(Self::stats_json(&sessions))
+    }
+
+    /// Sessions matching `?days=`, `?repository_id=`, `?sort=` and `?order=asc|desc`
+    fn filtered_sessions(&self, request: &HttpRequest) -> Result<Vec<SessionDisplayData>> {
+        let sort = request
+            .query
+            .get("sort")
+            .map(String::as_str)
+            .unwrap_or("date");
+        if !SORT_FIELDS.contains(&sort) {
+            return Err(GitTypeError::ValidationError(format!(
+                "Invalid value for `sort`: {} (expected one of {})",
+                sort,
+                SORT_FIELDS.join(", ")
+            )));
+        }
+        let descending = match request.query.get("order").map(String::as_str) {
+            None | Some("desc") => true,
+            Some("asc") => false,
+            Some(other) => {
+                return Err(GitTypeError::ValidationError(format!(
+                    "Invalid value for `order`: {} (expected asc or desc)",
+                    other
+                )))
+            }
+        };
+
+        self.session_service.get_sessions_with_display_data(
+            request.query_param("repository_id")?,
+            request.query_param("days")?,
+            sort,
+            descending,
+        )
+    }
+
+    fn stats_json(sessions: &[SessionDisplayData]) -> Value {
+        let results: Vec<&SessionResultData> = sessions
+            .iter()
+            .filter_map(|data| data.session_result.as_ref())
+            .collect();
+        let average = |value: fn(&SessionResultData) -> f64| {
+            if results.is_empty() {
+                0.0
+            } else {
+                results.iter().map(|r| value(r)).sum::<f64>() / results.len() as f64
+            }
+        };
+        let best = |value: fn(&SessionResultData) -> f64| {
+            results.iter().map(|r| value(r)).fold(0.0, f64::max)
+        };
+        let total = |value: fn(&SessionResultData) -> usize| {
+            results.iter().map(|r| value(r)).sum::<usize>()
+        };
+
+        json!({
+            "total_sessions": sessions.len(),
+            "total_keystrokes": total(|r| r.keystrokes),
+            "total_mistakes": total(|r| r.mistakes),
+            "total_duration_ms": results.iter().map(|r| r.duration_ms).sum::<u64>(),
+            "stages_completed": total(|r| r.stages_completed),
+            "stages_skipped": total(|r| r.stages_skipped),
+            "avg_wpm": average(|r| r.wpm),
+            "best_wpm": best(|r| r.wpm),
+            "avg_cpm": average(|r| r.cpm),
+            "avg_accuracy": average(|r| r.accuracy),
+            "best_score": best(|r| r.score),
+            "first_session_at": sessions.iter().map(|data| data.session.started_at).min(),
+            "last_session_at": sessions.iter().map(|data| data.session.started_at).max(),
+        })
+    }
+}
@@ -0,0 +1,144 @@
+use serde_json::{json, Value};
+
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+
+use crate::{GitTypeError, Result};
+
+pub const MAX_HEADER_BYTES: usize = 8 * 1024;
+
+/// Request line and query string of an HTTP/1.x request; headers and bodies are ignored
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+    pub method: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl HttpRequest {
+    /// Read the request head, stopping at the blank line that ends the headers
+    pub fn read<R: BufRead>(reader: &mut R) -> Result<Self> {
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+
+        let mut header_bytes = request_line.len();
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+            header_bytes += read;
+            if read == 0 || line.trim().is_empty() {
+                break;
+            }
+            if header_bytes > MAX_HEADER_BYTES {
+                return Err(GitTypeError::ValidationError(
+                    "Request headers too large".to_string(),
+                ));
+            }
+        }
+
+        Self::parse(&request_line)
+    }
+
+    /// Parse a request line such as `GET /sessions?days=7 HTTP/1.1`
+    pub fn parse(request_line: &str) -> Result<Self> {
+        let mut parts = request_line.split_whitespace();
+        let (Some(method), Some(target), Some(version)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(GitTypeError::ValidationError(format!(
+                "Malformed request line: {}",
+                request_line.trim()
+            )));
+        };
+        if !version.starts_with("HTTP/1.") {
+            return Err(GitTypeError::ValidationError(format!(
+                "Unsupported protocol: {}",
+                version
+            )));
+        }
+
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let query = query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (Self::decode(key), Self::decode(value))
+            })
+            .collect();
+
+        Ok(Self {
+            method: method.to_string(),
+            path: path.trim_end_matches('/').to_string(),
+            query,
+        })
+    }
+
+    pub fn query_param<T: std::str::FromStr>(&self, name: &str) -> Result<Option<T>> {
+        self.query
+            .get(name)
+            .map(|value| {
+                value.parse().map_err(|_| {
+                    GitTypeError::ValidationError(format!(
+                        "Invalid value for `{}`: {}",
+                        name, value
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    fn decode(value: &str) -> String {
+        let value = value.replace('+', " ");
+        urlencoding::decode(&value)
+            .map(|decoded| decoded.into_owned())
+            .unwrap_or(value)
+    }
+}
+
+impl HttpResponse {
+    pub fn ok(body: Value) -> Self {
+        Self { status: 200, body }
+    }
+
+    pub fn error(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+
+    pub fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            _ => "Internal Server Error",
+        }
+    }
+
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let body = self.body.to_string();
+        write!(
+            writer,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n",
+            self.status,
+            self.reason(),
+            body.len()
+        )?;
+        if self.status == 405 {
+            write!(writer, "Allow: GET\r\n")?;
+        }
+        write!(writer, "Connection: close\r\n\r\n{}", body)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod api_server;
+pub mod http;
+
+pub use api_server::ApiServer;
+pub use http::{HttpRequest, HttpResponse};
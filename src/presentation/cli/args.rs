@@ -1,7 +1,13 @@
+use chrono::NaiveDate;
 use clap::{Parser, Subcommand};
 
 use std::path::PathBuf;
 
+use crate::domain::models::{DemoMode, StatusSummary};
+use crate::presentation::api::api_server::DEFAULT_ADDRESS;
+use crate::presentation::cli::commands::purge::PurgeTarget;
+use crate::presentation::spectator::DEFAULT_SPECTATE_ADDRESS;
+
 #[derive(Parser)]
 #[command(name = "gittype")]
 #[command(
@@ -12,7 +18,13 @@ use std::path::PathBuf;
                   gittype                           # Use current directory\n  \
                   gittype /path/to/repo             # Use specific repository\n  \
                   gittype --repo owner/repo         # Clone and use GitHub repository\n  \
-                  gittype --langs rust,python       # Filter by languages"
+                  gittype --langs rust,python       # Filter by languages\n  \
+                  gittype --commit HEAD             # Practice the files changed in a commit\n  \
+                  gittype --package core            # Practice one package of a workspace\n  \
+                  gittype --prose                   # Mix in doc comments and README prose\n  \
+                  gittype --bots casual,pro         # Race two bots of different skill\n  \
+                  gittype snippets ./exercises      # Play each file of a directory as a challenge\n  \
+                  gittype pack play class.gtpack    # Play a shared, fixed set of challenges"
 )]
 #[command(version = env!("CARGO_PKG_VERSION"))]
 pub struct Cli {
@@ -48,6 +60,86 @@ pub struct Cli {
     )]
     pub langs: Option<Vec<String>>,
 
+    /// Practice only the files changed in a commit, as a single-stage session
+    #[arg(
+        long,
+        value_name = "REV",
+        help = "Practice only the files changed in a commit (e.g. HEAD)",
+        long_help = "Practice only the files changed in a commit, as a single-stage session. \
+                     Accepts any git revision (e.g. HEAD, HEAD~1, a commit hash). \
+                     Used by the post-commit hook installed with `gittype hooks install`."
+    )]
+    pub commit: Option<String>,
+
+    /// Practice only one package of a Cargo, npm or Go workspace
+    #[arg(
+        long,
+        value_name = "NAME",
+        help = "Practice only one package of a Cargo, npm or Go workspace",
+        long_help = "Practice only one package of a Cargo, npm or Go workspace, given by its \
+                     name or directory (e.g. --package core or --package crates/core). \
+                     Packages are read from Cargo.toml [workspace] members, package.json \
+                     workspaces and go.work. Without this flag, a local workspace asks \
+                     which package to practice before extracting."
+    )]
+    pub package: Option<String>,
+
+    /// Follow symlinked files and directories while scanning the repository
+    #[arg(
+        long,
+        help = "Follow symlinks while scanning the repository",
+        long_help = "Follow symlinked files and directories while scanning the repository. \
+                     Symlinks are skipped by default; symlink cycles are always skipped."
+    )]
+    pub follow_symlinks: bool,
+
+    /// Mix doc comments and README paragraphs in as prose challenges
+    #[arg(
+        long,
+        help = "Mix in doc comments and README prose as challenges",
+        long_help = "Mix doc comments and README paragraphs in with the code as natural-language \
+                     challenges, tagged with the language \"prose\". \
+                     Prose sessions are not read from or written to the challenge cache."
+    )]
+    pub prose: bool,
+
+    /// Print a plain-text summary of the run to stdout on exit
+    #[arg(
+        long,
+        help = "Print a plain-text summary of the run to stdout on exit",
+        long_help = "Print a plain-text summary of the run to stdout on exit, \
+                     for capturing results in scripts or screenshots. \
+                     The session itself plays as usual in the TUI."
+    )]
+    pub summary_only: bool,
+
+    /// Mirror the typing screen to spectators connecting with `gittype spectate`
+    #[arg(
+        long,
+        value_name = "ADDR",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = DEFAULT_SPECTATE_ADDRESS,
+        help = "Let `gittype spectate` watch the typing screen (default 127.0.0.1:7879)",
+        long_help = "Mirror the typing screen, read-only, to every terminal that runs \
+                     `gittype spectate`. Listens on 127.0.0.1:7879 unless an address is given \
+                     as --broadcast=ADDR; use 0.0.0.0:PORT to allow other machines."
+    )]
+    pub broadcast: Option<String>,
+
+    /// Race computer opponents of the given skill (comma-separated)
+    #[arg(
+        long,
+        value_name = "BOTS",
+        value_delimiter = ',',
+        help = "Race bots typing the same code (e.g. novice,pro or 70@96)",
+        long_help = "Race bots that type the same challenge, shown as extra progress bars. \
+                     Each bot is a preset (novice, casual, fast, pro, legend) \
+                     or a speed with an optional accuracy percent, like 70@96. \
+                     Overrides the bots in the `race` section of config.json."
+    )]
+    pub bots: Option<Vec<String>>,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -58,14 +150,53 @@ pub enum Commands {
     History,
     /// Show analytics
     Stats,
+    /// Print a one-line practice summary for status bars and shell prompts
+    Status {
+        /// Output template
+        #[arg(
+            long,
+            default_value = StatusSummary::DEFAULT_FORMAT,
+            long_help = "Output template. Placeholders:\n  \
+                         {streak}            consecutive days with at least one session\n  \
+                         {today_sessions}    sessions played today\n  \
+                         {today_wpm}         average WPM today\n  \
+                         {today_best_wpm}    best session WPM today\n  \
+                         {today_accuracy}    average accuracy today (%)\n  \
+                         {today_keystrokes}  keystrokes typed today"
+        )]
+        format: String,
+    },
     /// Export session data
     Export {
-        /// Export format
-        #[arg(long, default_value = "json")]
+        /// Export format (`ics` writes one calendar event per session,
+        /// `markdown` a report for the repository given with --repo,
+        /// `history` a versioned `.gittype-history.json` file for other tools and `gittype import`)
+        #[arg(long, default_value = "json", value_parser = ["json", "ics", "markdown", "history"])]
         format: String,
         /// Output file path
         #[arg(long)]
         output: Option<PathBuf>,
+        /// Only export sessions of this repository (`owner/name`); required with `--format markdown`
+        #[arg(long, value_name = "OWNER/NAME")]
+        repo: Option<String>,
+        /// Only export sessions started on or after this date (UTC)
+        #[arg(long, value_name = "YYYY-MM-DD")]
+        since: Option<NaiveDate>,
+        /// Only export sessions started on or before this date (UTC)
+        #[arg(long, value_name = "YYYY-MM-DD")]
+        until: Option<NaiveDate>,
+        /// Write Prometheus metrics to FILE (`-` for stdout), e.g. for node_exporter's textfile collector
+        #[arg(long, value_name = "FILE")]
+        prometheus: Option<PathBuf>,
+    },
+    /// Import monkeytype or keybr typing tests as a prose-speed baseline, or
+    /// sessions from a `.gittype-history.json` file
+    Import {
+        /// CSV file exported from monkeytype or keybr, or a history file
+        file: PathBuf,
+        /// Site the CSV came from (detected from the header when omitted; ignored for history files)
+        #[arg(long, value_parser = ["monkeytype", "keybr"])]
+        source: Option<String>,
     },
     /// Manage challenge cache
     Cache {
@@ -77,6 +208,107 @@ pub enum Commands {
         #[command(subcommand)]
         repo_command: RepoCommands,
     },
+    /// Manage the post-commit practice hook
+    Hooks {
+        #[command(subcommand)]
+        hooks_command: HooksCommands,
+    },
+    /// Run gittype as a server for editor integrations or local dashboards
+    Serve {
+        /// Speak newline-delimited JSON-RPC 2.0 over stdin/stdout
+        #[arg(
+            long,
+            long_help = "Speak newline-delimited JSON-RPC 2.0 over stdin/stdout. \
+                         Methods: initialize, challenge/load, challenge/start, \
+                         challenge/input, challenge/finish, shutdown, exit"
+        )]
+        stdio: bool,
+        /// Serve a read-only JSON API over HTTP on ADDR (default 127.0.0.1:7878)
+        #[arg(
+            long,
+            value_name = "ADDR",
+            num_args = 0..=1,
+            default_missing_value = DEFAULT_ADDRESS,
+            conflicts_with = "stdio",
+            long_help = "Serve a read-only JSON API over HTTP on ADDR (default 127.0.0.1:7878). \
+                         Endpoints: /sessions, /sessions/{id}, /stats, /analytics"
+        )]
+        api: Option<String>,
+    },
+    /// Watch a game started with `--broadcast` from another terminal
+    Spectate {
+        /// Address the game broadcasts on
+        #[arg(value_name = "ADDR", default_value = DEFAULT_SPECTATE_ADDRESS)]
+        address: String,
+    },
+    /// Report the files, code chunks and challenges found in a repository without playing
+    Scan {
+        /// Repository path to scan (defaults to the current directory)
+        #[arg(value_name = "REPO_PATH")]
+        path: Option<PathBuf>,
+        /// Filter by programming languages (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        langs: Option<Vec<String>>,
+        /// Follow symlinks while scanning the repository
+        #[arg(long)]
+        follow_symlinks: bool,
+        /// Only print the report; don't cache the generated challenges
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Attract mode for booths and recordings: a typing demo plays whenever the title screen sits idle
+    Demo {
+        /// Repository to take the challenges from (defaults to the current directory)
+        #[arg(value_name = "REPO_PATH")]
+        path: Option<PathBuf>,
+        /// Seconds without input on the title screen before the demo starts
+        #[arg(long, value_name = "SECONDS", default_value_t = DemoMode::DEFAULT_IDLE_TIMEOUT.as_secs())]
+        idle: u64,
+    },
+    /// Play each file of a snippet directory as one whole challenge, skipping chunk extraction
+    Snippets {
+        /// Directory of hand-curated snippets; each file's language is detected from its extension
+        #[arg(value_name = "DIR")]
+        dir: PathBuf,
+    },
+    /// Bundle a fixed, seeded set of challenges into a shareable file, or play one
+    Pack {
+        #[command(subcommand)]
+        pack_command: PackCommands,
+    },
+    /// Remove challenge caches of deleted clones, vacuum the database and trim old logs
+    Gc,
+    /// List supported languages with their extensions and extracted chunk types
+    Languages,
+    /// Show the newest log file in a viewer, or print its last lines
+    Logs {
+        /// Print the last N lines instead of opening the viewer
+        #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "100")]
+        tail: Option<usize>,
+    },
+    /// Remove local data to reset or uninstall gittype
+    Purge {
+        /// Data to remove (asks about each kind of data when omitted)
+        #[arg(value_enum)]
+        targets: Vec<PurgeTarget>,
+        /// Remove without asking for confirmation
+        #[arg(long, short)]
+        yes: bool,
+    },
+    /// Get a daily desktop notification to practice, or list and remove reminders
+    Remind {
+        /// Time of day to be reminded, in 24-hour HH:MM (opens the reminder list when omitted)
+        #[arg(long, value_name = "HH:MM")]
+        at: Option<String>,
+        /// Show the reminder notification now; run by the scheduled task
+        #[arg(long, hide = true, conflicts_with = "at")]
+        notify: bool,
+    },
+    /// Preview, enable or disable anonymous usage telemetry (off by default)
+    Telemetry {
+        #[command(subcommand)]
+        telemetry_command: TelemetryCommands,
+    },
     /// Select and practice with trending repositories from GitHub
     Trending {
         /// Programming language to filter trending repositories
@@ -91,6 +323,20 @@ pub enum Commands {
         #[arg(long, default_value = "daily")]
         period: String,
     },
+    /// Check the verification code of a shared result
+    Verify {
+        /// Code from the shared text, e.g. GT1-4321-385-12-9f3a1c2b0d4e7a11-3b9c0e1f2a4d
+        code: String,
+    },
+    /// Clone a list of repositories and cache their challenges ahead of time, e.g. before going offline
+    Warm {
+        /// Text file with one repository per line (owner/repo or a clone URL); `#` starts a comment line
+        #[arg(long, value_name = "FILE")]
+        file: PathBuf,
+        /// How many repositories to clone and extract at the same time
+        #[arg(long, short, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..=32))]
+        jobs: u16,
+    },
 }
 
 #[derive(Subcommand)]
@@ -114,4 +360,65 @@ pub enum RepoCommands {
     },
     /// Play a cached repository interactively
     Play,
+    /// List repositories never suggested in trending
+    Dismissed,
+    /// Allow a dismissed repository to be suggested in trending again
+    Restore {
+        /// Repository as owner/repo
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum HooksCommands {
+    /// Install a post-commit hook that practices the files changed by each commit
+    Install {
+        /// Overwrite an existing post-commit hook
+        #[arg(long)]
+        force: bool,
+    },
+    /// Remove the gittype post-commit hook
+    Uninstall,
+}
+
+#[derive(Subcommand)]
+pub enum PackCommands {
+    /// Write a seeded pick of a repository's challenges to a pack file
+    Create {
+        /// Repository path to extract challenges from
+        #[arg(value_name = "REPO_PATH")]
+        path: PathBuf,
+        /// Pack file to write
+        #[arg(long, value_name = "FILE")]
+        out: PathBuf,
+        /// Number of stages (challenges) in the pack
+        #[arg(long, default_value_t = 3)]
+        stages: usize,
+        /// Difficulty of the packed challenges
+        #[arg(long, default_value = "normal", value_parser = ["easy", "normal", "hard", "wild", "zen"])]
+        difficulty: String,
+        /// Seed for picking the challenges (random when omitted; printed either way)
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Play the challenges of a pack file in order
+    Play {
+        /// Pack file written by `gittype pack create`
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TelemetryCommands {
+    /// Print exactly the report that would be sent, without sending it
+    Show,
+    /// Opt in to sending the report at most once a day
+    Enable {
+        /// URL the report is POSTed to as JSON
+        #[arg(long)]
+        endpoint: Option<String>,
+    },
+    /// Opt out; nothing is sent
+    Disable,
 }
@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::domain::models::DemoMode;
+use crate::presentation::cli::args::Cli;
+use crate::presentation::cli::commands::game::run_game_session;
+use crate::Result;
+
+/// Open the title screen in attract mode: after `idle_secs` without input a
+/// scripted typist plays real challenges from the repository, and any key
+/// hands control back. History is left untouched
+pub fn run_demo(mut cli: Cli, repo_path: Option<PathBuf>, idle_secs: u64) -> Result<()> {
+    if repo_path.is_some() {
+        cli.repo_path = repo_path;
+    }
+    DemoMode::enable(Duration::from_secs(idle_secs));
+    run_game_session(cli)
+}
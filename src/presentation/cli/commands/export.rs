@@ -1,15 +1,390 @@
-use std::path::PathBuf;
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use shaku::HasComponent;
 
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::domain::models::storage::{
+    SessionHistoryFilter, SessionResultData, StoredRepository, StoredSession,
+};
+use crate::domain::models::{
+    HistoryFile, HistorySession, MetricsSnapshot, PracticeCalendar, PracticeEvent, RepositoryReport,
+};
+use crate::domain::services::session_service::SessionDisplayData;
 use crate::infrastructure::console::{Console, ConsoleImpl};
-use crate::Result;
+use crate::infrastructure::database::daos::SessionDaoInterface;
+use crate::infrastructure::database::database::{Database, DatabaseInterface};
+use crate::infrastructure::database::storage_backend::{open_default_backend, StorageBackend};
+use crate::presentation::di::AppModule;
+use crate::{GitTypeError, Result};
+
+/// Sessions read from the database at a time, so exporting years of
+/// history never holds all of it in memory
+pub const EXPORT_PAGE_SIZE: usize = 500;
+
+/// The `--repo`, `--since` and `--until` options of an export
+#[derive(Debug, Clone, Default)]
+pub struct ExportScope {
+    pub repo: Option<String>,
+    pub since: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+}
+
+pub fn run_export(
+    format: String,
+    output: Option<PathBuf>,
+    prometheus: Option<PathBuf>,
+    scope: ExportScope,
+) -> Result<()> {
+    // Must run before anything opens the file, so a running gittype keeps it to itself
+    Database::acquire_or_read_only()?;
+    if let Some(path) = prometheus {
+        return run_prometheus_export(&path);
+    }
+    if let (Some(since), Some(until)) = (scope.since, scope.until) {
+        if since > until {
+            return Err(GitTypeError::ValidationError(format!(
+                "--since {} is after --until {}",
+                since, until
+            )));
+        }
+    }
 
-pub fn run_export(format: String, output: Option<PathBuf>) -> Result<()> {
+    match format.as_str() {
+        "json" => run_json_export(output.as_deref(), &scope),
+        "ics" => run_ics_export(output.as_deref(), &scope),
+        "markdown" => run_markdown_export(output.as_deref(), &scope),
+        "history" => run_history_export(output.as_deref(), &scope),
+        _ => Err(GitTypeError::ValidationError(format!(
+            "Unknown export format '{}', expected json, ics, markdown or history",
+            format
+        ))),
+    }
+}
+
+/// Write Prometheus metrics to `path`, or stdout for `-`
+pub fn run_prometheus_export(path: &Path) -> Result<()> {
     let console = ConsoleImpl::new();
-    console.eprintln("❌ Export command is not yet implemented")?;
-    console.eprintln(&format!("💡 Requested format: {}", format))?;
+    let container = AppModule::builder().build();
+    let database: Arc<dyn DatabaseInterface> = container.resolve();
+    database.init_tables()?;
+    let session_dao: Arc<dyn SessionDaoInterface> = container.resolve();
+    let metrics = load_metrics_snapshot(session_dao.as_ref())?.to_prometheus();
+
+    if path == Path::new("-") {
+        console.print(&metrics)?;
+        return Ok(());
+    }
+
+    write_atomically(path, &metrics)?;
+    console.println(&format!("✅ Wrote metrics to {}", path.display()))?;
+    Ok(())
+}
+
+/// Write every finished session as a JSON array to `output`, or stdout when omitted
+pub fn run_json_export(output: Option<&Path>, scope: &ExportScope) -> Result<()> {
+    let backend = open_default_backend()?;
+    let filter = resolve_history_filter(backend.as_ref(), scope)?;
+    let exported = write_to_output(output, |out| {
+        write_json_export(backend.as_ref(), &filter, EXPORT_PAGE_SIZE, out)
+    })?;
+    report_written(output, exported)
+}
+
+/// Write one calendar event per finished session to `output`, or stdout when omitted
+pub fn run_ics_export(output: Option<&Path>, scope: &ExportScope) -> Result<()> {
+    let backend = open_default_backend()?;
+    let filter = resolve_history_filter(backend.as_ref(), scope)?;
+    let exported = write_to_output(output, |out| {
+        write_ics_export(backend.as_ref(), &filter, EXPORT_PAGE_SIZE, Utc::now(), out)
+    })?;
+    report_written(output, exported)
+}
+
+/// Write a `.gittype-history.json` document to `output`, or stdout when omitted
+pub fn run_history_export(output: Option<&Path>, scope: &ExportScope) -> Result<()> {
+    let backend = open_default_backend()?;
+    let filter = resolve_history_filter(backend.as_ref(), scope)?;
+    let exported = write_to_output(output, |out| {
+        write_history_export(backend.as_ref(), &filter, EXPORT_PAGE_SIZE, Utc::now(), out)
+    })?;
+    report_written(output, exported)
+}
+
+/// Write the Markdown report of the `--repo` repository to `output`, or stdout when omitted
+pub fn run_markdown_export(output: Option<&Path>, scope: &ExportScope) -> Result<()> {
+    let console = ConsoleImpl::new();
+    let backend = open_default_backend()?;
+    let report = load_repository_report(backend.as_ref(), scope)?;
+    let markdown = report.to_markdown(Utc::now());
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, markdown)?;
+            console.println(&format!(
+                "✅ Exported {} sessions of {} to {}",
+                report.sessions.len(),
+                report.repo_spec,
+                path.display()
+            ))?;
+        }
+        None => console.print(&markdown)?,
+    }
+    Ok(())
+}
+
+/// Turn the `--repo` spec into the repository's id; the dates carry over as given
+pub fn resolve_history_filter(
+    backend: &dyn StorageBackend,
+    scope: &ExportScope,
+) -> Result<SessionHistoryFilter> {
+    let repository_id = match scope.repo.as_deref() {
+        Some(repo_spec) => Some(find_repository(backend, repo_spec)?.id),
+        None => None,
+    };
+    Ok(SessionHistoryFilter {
+        repository_id,
+        since: scope.since,
+        until: scope.until,
+    })
+}
+
+/// Visit the sessions matching `filter`, oldest first, one page of
+/// `page_size` at a time; returns how many were visited
+pub fn for_each_session(
+    session_dao: &dyn SessionDaoInterface,
+    filter: &SessionHistoryFilter,
+    page_size: usize,
+    mut visit: impl FnMut(StoredSession, SessionResultData) -> Result<()>,
+) -> Result<usize> {
+    let page_size = page_size.max(1);
+    let mut after: Option<StoredSession> = None;
+    let mut visited = 0;
+    loop {
+        let page = session_dao.get_session_history_page(filter, after.as_ref(), page_size)?;
+        let is_last_page = page.len() < page_size;
+        for (session, result) in page {
+            after = Some(session.clone());
+            visit(session, result)?;
+            visited += 1;
+        }
+        if is_last_page {
+            return Ok(visited);
+        }
+    }
+}
+
+/// A JSON array with one session per line, written as the pages are read
+pub fn write_json_export(
+    backend: &dyn StorageBackend,
+    filter: &SessionHistoryFilter,
+    page_size: usize,
+    out: &mut dyn Write,
+) -> Result<usize> {
+    let repositories = repository_map(backend)?;
+    out.write_all(b"[")?;
+    let mut first = true;
+    let exported = for_each_session(
+        backend.session_dao().as_ref(),
+        filter,
+        page_size,
+        |session, result| {
+            out.write_all(if first { b"\n  " } else { b",\n  " })?;
+            first = false;
+            let record = SessionDisplayData {
+                repository: session
+                    .repository_id
+                    .and_then(|id| repositories.get(&id).cloned()),
+                session,
+                session_result: Some(result),
+            };
+            serde_json::to_writer(&mut *out, &record)?;
+            Ok(())
+        },
+    )?;
+    out.write_all(if exported == 0 { b"]\n" } else { b"\n]\n" })?;
+    Ok(exported)
+}
+
+/// A history file with one session per line, written as the pages are read;
+/// sessions whose repository is unknown are left out
+pub fn write_history_export(
+    backend: &dyn StorageBackend,
+    filter: &SessionHistoryFilter,
+    page_size: usize,
+    exported_at: DateTime<Utc>,
+    out: &mut dyn Write,
+) -> Result<usize> {
+    let repositories = repository_map(backend)?;
+    write!(
+        out,
+        "{{\"format\":\"{}\",\"version\":{},\"exported_at\":{},\"sessions\":[",
+        HistoryFile::FORMAT,
+        HistoryFile::VERSION,
+        serde_json::to_string(&exported_at)?
+    )?;
+    let mut exported = 0;
+    for_each_session(
+        backend.session_dao().as_ref(),
+        filter,
+        page_size,
+        |session, result| {
+            let Some(repository) = session.repository_id.and_then(|id| repositories.get(&id))
+            else {
+                return Ok(());
+            };
+            out.write_all(if exported == 0 { b"\n  " } else { b",\n  " })?;
+            exported += 1;
+            serde_json::to_writer(
+                &mut *out,
+                &HistorySession::new(&session, repository, &result),
+            )?;
+            Ok(())
+        },
+    )?;
+    out.write_all(if exported == 0 { b"]}\n" } else { b"\n]}\n" })?;
+    Ok(exported)
+}
+
+/// An iCalendar document with one event per session, written as the pages are read
+pub fn write_ics_export(
+    backend: &dyn StorageBackend,
+    filter: &SessionHistoryFilter,
+    page_size: usize,
+    generated_at: DateTime<Utc>,
+    out: &mut dyn Write,
+) -> Result<usize> {
+    let repositories = repository_map(backend)?;
+    out.write_all(PracticeCalendar::ics_header().as_bytes())?;
+    let exported = for_each_session(
+        backend.session_dao().as_ref(),
+        filter,
+        page_size,
+        |session, result| {
+            let repository = session.repository_id.and_then(|id| repositories.get(&id));
+            let event = PracticeEvent::new(&session, repository, &result);
+            out.write_all(PracticeCalendar::ics_event(&event, generated_at).as_bytes())?;
+            Ok(())
+        },
+    )?;
+    out.write_all(PracticeCalendar::ics_footer().as_bytes())?;
+    Ok(exported)
+}
+
+/// The `--repo` repository's sessions within the scope's days, read a page at a time
+pub fn load_repository_report(
+    backend: &dyn StorageBackend,
+    scope: &ExportScope,
+) -> Result<RepositoryReport> {
+    let repo_spec = scope.repo.as_deref().ok_or_else(|| {
+        GitTypeError::ValidationError(
+            "--format markdown needs a repository, e.g. --repo owner/name".to_string(),
+        )
+    })?;
+    let repository = find_repository(backend, repo_spec)?;
+    let filter = SessionHistoryFilter {
+        repository_id: Some(repository.id),
+        since: scope.since,
+        until: scope.until,
+    };
+
+    let mut sessions = Vec::new();
+    for_each_session(
+        backend.session_dao().as_ref(),
+        &filter,
+        EXPORT_PAGE_SIZE,
+        |session, result| {
+            sessions.push(PracticeEvent::new(&session, Some(&repository), &result));
+            Ok(())
+        },
+    )?;
+    Ok(RepositoryReport::new(repo_spec, sessions).with_license(repository.license))
+}
+
+pub fn load_metrics_snapshot(session_dao: &dyn SessionDaoInterface) -> Result<MetricsSnapshot> {
+    Ok(MetricsSnapshot::new(
+        &session_dao.get_daily_activity()?,
+        session_dao.get_language_activity()?,
+        Local::now().date_naive(),
+    ))
+}
+
+fn parse_repo_spec(repo_spec: &str) -> Result<(&str, &str)> {
+    repo_spec
+        .split_once('/')
+        .filter(|(owner, name)| !owner.is_empty() && !name.is_empty())
+        .ok_or_else(|| {
+            GitTypeError::ValidationError(format!(
+                "Invalid repository '{}', expected owner/name",
+                repo_spec
+            ))
+        })
+}
+
+fn find_repository(backend: &dyn StorageBackend, repo_spec: &str) -> Result<StoredRepository> {
+    let (owner, name) = parse_repo_spec(repo_spec)?;
+    backend
+        .repository_dao()
+        .find_repository(owner, name)?
+        .ok_or_else(|| {
+            GitTypeError::ValidationError(format!("No sessions recorded for {}", repo_spec))
+        })
+}
+
+fn repository_map(backend: &dyn StorageBackend) -> Result<HashMap<i64, StoredRepository>> {
+    Ok(backend
+        .repository_dao()
+        .get_all_repositories()?
+        .into_iter()
+        .map(|repository| (repository.id, repository))
+        .collect())
+}
+
+/// Run `write` against a buffered `output` file, or stdout when omitted
+fn write_to_output(
+    output: Option<&Path>,
+    write: impl FnOnce(&mut dyn Write) -> Result<usize>,
+) -> Result<usize> {
+    let written = match output {
+        Some(path) => {
+            let mut out = BufWriter::new(File::create(path)?);
+            let written = write(&mut out)?;
+            out.flush()?;
+            written
+        }
+        None => {
+            let mut out = BufWriter::new(std::io::stdout().lock());
+            let written = write(&mut out)?;
+            out.flush()?;
+            written
+        }
+    };
+    Ok(written)
+}
+
+fn report_written(output: Option<&Path>, exported: usize) -> Result<()> {
     if let Some(path) = output {
-        console.eprintln(&format!("💡 Requested output: {}", path.display()))?;
+        ConsoleImpl::new().println(&format!(
+            "✅ Exported {} sessions to {}",
+            exported,
+            path.display()
+        ))?;
     }
-    console.eprintln("💡 This feature is planned for a future release")?;
-    std::process::exit(1);
+    Ok(())
+}
+
+/// Scrapers polling the file must never observe a partially written export
+fn write_atomically(path: &Path, contents: &str) -> Result<()> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "metrics".to_string());
+    let temp_path = path.with_file_name(format!(".{}.tmp", file_name));
+
+    std::fs::write(&temp_path, contents)?;
+    std::fs::rename(&temp_path, path)?;
+    Ok(())
 }
@@ -1,23 +1,70 @@
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
-use crate::domain::models::{ExtractionOptions, Languages};
+use crate::domain::models::config::Config;
+use crate::domain::models::{
+    ChallengePack, DemoMode, ErrorReport, ExtractionOptions, Languages, RaceBot,
+};
+use crate::domain::services::scoring::{TotalCalculator, TotalTrackerInterface};
+use crate::domain::services::source_code_parser::parsers::{get_parser_registry, QueryOverride};
 use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::domain::services::workspace_detector::WorkspaceDetector;
 use crate::domain::stores::RepositoryStoreInterface;
 use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::infrastructure::database::database::Database;
+use crate::infrastructure::git::LocalGitRepositoryClient;
 use crate::infrastructure::logging;
+use crate::infrastructure::storage::file_storage::{FileStorage, FileStorageInterface};
 use crate::presentation::cli::args::Cli;
+use crate::presentation::cli::commands::purge::confirm;
+use crate::presentation::cli::screen_runner::run_screen;
 use crate::presentation::di::AppModule;
 use crate::presentation::signal_handler::setup_signal_handlers;
-use crate::presentation::tui::screens::{VersionCheckResult, VersionCheckScreen};
+use crate::presentation::spectator::SpectatorBroadcast;
+use crate::presentation::tui::screens::{
+    PackageChoice, WorkspacePackageScreen, WorkspacePackageScreenData,
+};
 use crate::presentation::tui::{ScreenManagerFactory, ScreenManagerImpl, ScreenType};
 use crate::{GitTypeError, Result};
 
+/// Where the challenges of a game session come from
+pub(crate) enum GameSource {
+    /// The repository given on the command line
+    Repository,
+    /// Every file of a directory as a whole challenge
+    Snippets(PathBuf),
+    /// A challenge pack, played in order
+    Pack(ChallengePack),
+}
+
 pub fn run_game_session(cli: Cli) -> Result<()> {
+    start_game_session(cli, GameSource::Repository)
+}
+
+pub(crate) fn start_game_session(cli: Cli, source: GameSource) -> Result<()> {
     log::info!("Starting GitType game session");
 
     let console = ConsoleImpl::new();
 
+    let commit_scope = match cli.commit.as_deref() {
+        Some(revision) => match resolve_commit_scope(&cli, revision)? {
+            Some(scope) => Some(scope),
+            None => {
+                console.println(&format!(
+                    "ℹ️ No supported source files changed in {} - nothing to practice",
+                    revision
+                ))?;
+                return Ok(());
+            }
+        },
+        None => None,
+    };
+
+    // Must run before the DI container opens its own connection to the file
+    if Database::acquire_or_read_only()? && !repair_damaged_database(&console)? {
+        return Ok(());
+    }
+
     // Create DI container
     let container = AppModule::builder().build();
 
@@ -44,42 +91,49 @@ pub fn run_game_session(cli: Cli) -> Result<()> {
     // Get ScreenManagerFactory from DI container
     let factory: &dyn ScreenManagerFactory = container.resolve_ref();
 
-    // Check for updates before starting the game session
-    let should_exit = {
-        let rt = tokio::runtime::Runtime::new().map_err(|e| {
-            GitTypeError::TerminalError(format!("Failed to create tokio runtime: {}", e))
-        })?;
-        rt.block_on(async {
-            use crate::domain::services::version_service::VersionServiceInterface;
-            use shaku::HasComponent;
-            let version_service: std::sync::Arc<dyn VersionServiceInterface> = container.resolve();
-            if let Ok((has_update, current_version, latest_version)) = version_service.check().await
-            {
-                if has_update {
-                    match VersionCheckScreen::show_legacy(&current_version, &latest_version)? {
-                        VersionCheckResult::Continue => return Ok::<bool, GitTypeError>(false),
-                        VersionCheckResult::Exit => return Ok::<bool, GitTypeError>(true),
-                    }
-                }
-            }
-            Ok(false)
-        })
-    }?;
-
-    if should_exit {
-        log::info!("User exited after update notification");
-        return Ok(());
-    }
-
-    // Initialize config service (must be done before theme service)
+    // Initialize config service (must be done before the version check and theme service)
     {
-        use crate::domain::services::config_service::ConfigServiceInterface;
+        use crate::domain::services::config_service::{ConfigService, ConfigServiceInterface};
         let config_service: &dyn ConfigServiceInterface = container.resolve_ref();
         if let Err(e) = config_service.init() {
             log::warn!("Failed to initialize config service: {}", e);
             console.eprintln(&format!("⚠️ Warning: Failed to load configuration: {}", e))?;
             console.eprintln("   Using default configuration.")?;
         }
+
+        // Apply the keyboard test calibration to stage timing
+        if let Some(session_manager) = session_manager_trait
+            .as_any()
+            .downcast_ref::<SessionManager>()
+        {
+            session_manager
+                .set_latency_compensation(config_service.get_config().input.latency_compensation());
+        }
+
+        if let Err(e) = ConfigService::apply_settings(&config_service.get_config()) {
+            console.eprintln(&format!("⚠️ Warning: {}", e))?;
+        }
+        apply_extraction_config(&console, &config_service.get_config())?;
+        apply_query_overrides(&console)?;
+        RaceBot::set_lineup(race_bots(
+            &console,
+            cli.bots.as_deref(),
+            &config_service.get_config(),
+        )?);
+    }
+
+    // Check for updates; a newer release is announced on the title screen
+    {
+        let rt = tokio::runtime::Runtime::new().map_err(|e| {
+            GitTypeError::TerminalError(format!("Failed to create tokio runtime: {}", e))
+        })?;
+        rt.block_on(async {
+            use crate::domain::services::version_service::VersionServiceInterface;
+            let version_service: &dyn VersionServiceInterface = container.resolve_ref();
+            if let Err(e) = version_service.check().await {
+                log::debug!("Version check failed: {}", e);
+            }
+        });
     }
 
     // Initialize theme service
@@ -97,7 +151,12 @@ pub fn run_game_session(cli: Cli) -> Result<()> {
 
     // Session repository will be initialized in DatabaseInitStep during loading screen
 
-    let mut options = ExtractionOptions::default();
+    let quick_play = matches!(source, GameSource::Repository) && is_bare_launch_in_work_tree(&cli);
+    let mut options = ExtractionOptions {
+        follow_symlinks: cli.follow_symlinks,
+        include_prose: cli.prose,
+        ..ExtractionOptions::default()
+    };
 
     if let Some(langs) = cli.langs {
         if let Err(unsupported_langs) = Languages::validate_languages(&langs) {
@@ -120,13 +179,40 @@ pub fn run_game_session(cli: Cli) -> Result<()> {
     }
 
     let repo_spec = cli.repo.as_deref();
-    let default_repo_path = cli.repo_path.unwrap_or_else(|| PathBuf::from("."));
+    let is_repository = matches!(source, GameSource::Repository);
+    let default_repo_path = match (source, commit_scope) {
+        (GameSource::Snippets(snippet_dir), _) => {
+            options.enable_snippets();
+            Some(snippet_dir)
+        }
+        (GameSource::Pack(pack), _) => {
+            options.pack = Some(pack);
+            None
+        }
+        (GameSource::Repository, Some((repo_root, commit_files))) => {
+            options.commit_files = Some(commit_files);
+            Some(repo_root)
+        }
+        (GameSource::Repository, None) => {
+            let repo_path = cli.repo_path.unwrap_or_else(|| PathBuf::from("."));
+            Some(LocalGitRepositoryClient::new().resolve_work_tree(&repo_path)?)
+        }
+    };
     let initial_repo_path = if repo_spec.is_some() {
         None
     } else {
-        Some(&default_repo_path)
+        default_repo_path.as_ref()
     };
 
+    if is_repository {
+        let workspace_root = initial_repo_path.filter(|_| options.commit_files.is_none());
+        match workspace_package(cli.package.as_deref(), workspace_root) {
+            Ok(package) => options.package = package,
+            Err(GitTypeError::Cancelled) => return Ok(()),
+            Err(e) => return Err(e),
+        }
+    }
+
     // Store processing parameters in RepositoryStore
     let repository_store: &dyn RepositoryStoreInterface = container.resolve_ref();
     if let Some(spec) = repo_spec {
@@ -136,6 +222,7 @@ pub fn run_game_session(cli: Cli) -> Result<()> {
         repository_store.set_repo_path(path.clone());
     }
     repository_store.set_extraction_options(options.clone());
+    repository_store.set_quick_play(quick_play);
 
     log::info!(
         "Initializing all screens with processing parameters: repo_spec={:?}, repo_path={:?}",
@@ -147,15 +234,29 @@ pub fn run_game_session(cli: Cli) -> Result<()> {
     let screen_manager_impl = factory.create(&container);
     let screen_manager = Arc::new(Mutex::new(screen_manager_impl));
 
-    // Set up signal handlers with ScreenManager reference
-    setup_signal_handlers(screen_manager.clone());
-
-    {
-        let mut manager = screen_manager.lock().unwrap();
-        manager.initialize_terminal()?;
-        manager.set_current_screen(ScreenType::Loading)?;
+    if let Some(address) = cli.broadcast.as_deref() {
+        let local_address = SpectatorBroadcast::start(address)?;
+        console.println(&format!(
+            "📡 Broadcasting on {}; watch with: gittype spectate {}",
+            local_address, local_address
+        ))?;
+        if !local_address.ip().is_loopback() {
+            console.eprintln(
+                "⚠️ The broadcast is reachable from other machines and has no authentication",
+            )?;
+        }
     }
 
+    screen_manager.lock().unwrap().initialize_terminal()?;
+
+    // Set up signal handlers with ScreenManager reference, once there is a terminal to restore
+    setup_signal_handlers(screen_manager.clone(), session_manager_trait.clone());
+
+    screen_manager
+        .lock()
+        .unwrap()
+        .set_current_screen(ScreenType::Loading)?;
+
     // Set up event subscriptions after initialization
     ScreenManagerImpl::setup_event_subscriptions(&screen_manager);
 
@@ -166,6 +267,12 @@ pub fn run_game_session(cli: Cli) -> Result<()> {
     match session_result {
         Ok(_) => {
             log::info!("Game session completed successfully");
+            super::telemetry::send_telemetry_if_due();
+            if cli.summary_only {
+                let total_tracker: Arc<dyn TotalTrackerInterface> = container.resolve();
+                let total_result = TotalCalculator::calculate_from_data(&total_tracker.get_data());
+                console.println(&total_result.create_summary_text())?;
+            }
         }
         Err(e) => {
             log::error!("Game session failed with error: {}", e);
@@ -176,107 +283,214 @@ pub fn run_game_session(cli: Cli) -> Result<()> {
     Ok(())
 }
 
-fn handle_game_error(console: &impl Console, e: GitTypeError) -> Result<()> {
-    // Log the error details for debugging before handling user-friendly output
-    logging::log_error_to_file(&e);
+/// Repository root and the supported source files changed by `revision`,
+/// or `None` when the commit touched nothing worth practicing
+fn resolve_commit_scope(cli: &Cli, revision: &str) -> Result<Option<(PathBuf, Vec<PathBuf>)>> {
+    if cli.repo.is_some() {
+        return Err(GitTypeError::ValidationError(
+            "--commit cannot be combined with --repo".to_string(),
+        ));
+    }
 
-    match e {
-        GitTypeError::NoSupportedFiles => {
-            console.eprintln("❌ No code chunks found in the repository")?;
-            console.eprintln("💡 Try:")?;
-            console.eprintln("   • Using a different repository path")?;
-            console.eprintln("   • Adjusting --langs filter (e.g., --langs rust,python)")?;
-            std::process::exit(1);
-        }
-        GitTypeError::RepositoryNotFound(path) => {
-            console.eprintln(&format!(
-                "❌ Repository not found at path: {}",
-                path.display()
-            ))?;
-            console.eprintln("💡 Ensure the path exists and is a valid repository")?;
-            std::process::exit(1);
-        }
-        GitTypeError::RepositoryCloneError(git_error) => {
-            console.eprintln(&format!("❌ Failed to clone repository: {}", git_error))?;
-            console.eprintln("💡 Check:")?;
-            console.eprintln("   • Repository URL is correct")?;
-            console.eprintln("   • You have access to the repository")?;
-            console.eprintln("   • Internet connection is available")?;
-            std::process::exit(1);
-        }
-        GitTypeError::ExtractionFailed(msg) => {
-            console.eprintln(&format!("❌ Code extraction failed: {}", msg))?;
-            console.eprintln("💡 Try using different --langs filter")?;
-            std::process::exit(1);
-        }
-        GitTypeError::InvalidRepositoryFormat(msg) => {
-            console.eprintln(&format!("❌ Invalid repository format: {}", msg))?;
-            console.eprintln("💡 Supported formats:")?;
-            console.eprintln("   • owner/repo")?;
-            console.eprintln("   • https://github.com/owner/repo")?;
-            console.eprintln("   • git@github.com:owner/repo.git")?;
-            std::process::exit(1);
-        }
-        GitTypeError::IoError(io_error) => {
-            console.eprintln(&format!("❌ IO error: {}", io_error))?;
-            std::process::exit(1);
-        }
-        GitTypeError::DatabaseError(db_error) => {
-            console.eprintln(&format!("❌ Database error: {}", db_error))?;
-            std::process::exit(1);
-        }
-        GitTypeError::GlobPatternError(glob_error) => {
-            console.eprintln(&format!("❌ Invalid glob pattern: {}", glob_error))?;
-            console.eprintln("💡 Check your glob patterns in ExtractionOptions")?;
-            std::process::exit(1);
-        }
-        GitTypeError::SerializationError(json_error) => {
-            console.eprintln(&format!("❌ Serialization error: {}", json_error))?;
-            std::process::exit(1);
+    let client = LocalGitRepositoryClient::new();
+    let repo_path = cli.repo_path.clone().unwrap_or_else(|| PathBuf::from("."));
+    let repo_root = repo_path
+        .canonicalize()
+        .ok()
+        .and_then(|path| client.get_repository_root(&path))
+        .ok_or_else(|| GitTypeError::RepositoryNotFound(repo_path.clone()))?;
+
+    let commit_files: Vec<PathBuf> = client
+        .get_commit_changed_files(&repo_root, revision)?
+        .into_iter()
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| Languages::from_extension(ext).is_some())
+        })
+        .collect();
+
+    Ok((!commit_files.is_empty()).then_some((repo_root, commit_files)))
+}
+
+/// Offer to back up a database that fails `PRAGMA quick_check`, typically
+/// after a power loss, so the loading screen rebuilds the schema in a fresh
+/// file; `false` when the player declined and the session must not start
+fn repair_damaged_database(console: &ConsoleImpl) -> Result<bool> {
+    let problems = Database::integrity_problems()?;
+    if problems.is_empty() {
+        return Ok(true);
+    }
+
+    log::error!("Database integrity check failed: {}", problems.join("; "));
+    console.eprintln("❌ The gittype database is damaged:")?;
+    for problem in problems.iter().take(5) {
+        console.eprintln(&format!("   {}", problem))?;
+    }
+    if !confirm(
+        console,
+        "Back up the damaged file and continue with a fresh database? Session history will start over",
+    )? {
+        console.eprintln("Left the database untouched. Run gittype again to repair it.")?;
+        return Ok(false);
+    }
+
+    let backup_path = Database::backup_damaged()?;
+    console.eprintln(&format!(
+        "✅ Moved the damaged database to {}",
+        backup_path.display()
+    ))?;
+    Ok(true)
+}
+
+/// The package to extract from: `--package` (checked up front for a local
+/// repository, after cloning for `--repo`), else the one picked from a local
+/// workspace of two or more packages; `Cancelled` when the picker was closed
+fn workspace_package(
+    package: Option<&str>,
+    local_root: Option<&PathBuf>,
+) -> Result<Option<String>> {
+    if let Some(package) = package {
+        return match local_root {
+            Some(root) => WorkspaceDetector::find(root, package)
+                .map(|package| Some(package.path.to_string_lossy().into_owned())),
+            None => Ok(Some(package.to_string())),
+        };
+    }
+
+    let Some(root) =
+        local_root.filter(|_| !DemoMode::is_enabled() && atty::is(atty::Stream::Stdout))
+    else {
+        return Ok(None);
+    };
+    let packages = WorkspaceDetector::detect(root);
+    if packages.len() < 2 {
+        return Ok(None);
+    }
+
+    let data = WorkspacePackageScreenData {
+        repository: root
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        packages,
+    };
+    let choice = run_screen::<WorkspacePackageScreen, _, _, _>(
+        ScreenType::WorkspacePackages,
+        Some(data),
+        Some(|screen: &WorkspacePackageScreen| screen.get_choice()),
+    )?;
+    match choice {
+        Some(PackageChoice::Package(package)) => {
+            Ok(Some(package.path.to_string_lossy().into_owned()))
         }
-        GitTypeError::TerminalError(msg) => {
-            console.eprintln(&format!("❌ Terminal error: {}", msg))?;
-            if msg.contains("No such device or address") {
-                console.eprintln("💡 This error often occurs in WSL or SSH environments where terminal features are limited.")?;
-                console.eprintln(
-                    "   Try running GitType in a native terminal or GUI terminal emulator.",
-                )?;
+        Some(PackageChoice::WholeRepository) => Ok(None),
+        None => Err(GitTypeError::Cancelled),
+    }
+}
+
+/// Launched without a repository path, `--repo` or `--commit` from inside a git work tree
+fn is_bare_launch_in_work_tree(cli: &Cli) -> bool {
+    cli.repo_path.is_none()
+        && cli.repo.is_none()
+        && cli.commit.is_none()
+        && std::env::current_dir()
+            .ok()
+            .and_then(|dir| LocalGitRepositoryClient::new().get_repository_root(&dir))
+            .is_some()
+}
+
+/// Apply the `extraction.languages` filter and `extraction.extensions` mapping
+pub(crate) fn apply_extraction_config(console: &impl Console, config: &Config) -> Result<()> {
+    let extraction = config.extraction.clone();
+    let unknown = extraction.unknown_names();
+    if !unknown.is_empty() {
+        console.eprintln(&format!(
+            "⚠️ Warning: Unknown language(s) in extraction config: {}",
+            unknown.join(", ")
+        ))?;
+    }
+    Languages::set_extraction(extraction);
+    Ok(())
+}
+
+/// Bots from `--bots`, else the valid ones from the config
+pub(crate) fn race_bots(
+    console: &impl Console,
+    specs: Option<&[String]>,
+    config: &Config,
+) -> Result<Vec<RaceBot>> {
+    if let Some(specs) = specs {
+        return specs.iter().map(|spec| RaceBot::parse(spec)).collect();
+    }
+
+    let mut bots = Vec::new();
+    for bot in &config.race.bots {
+        match RaceBot::new(&bot.name, bot.wpm, bot.accuracy) {
+            Ok(bot) => bots.push(bot),
+            Err(e) => {
+                console.eprintln(&format!("⚠️ Warning: Skipping bot '{}': {}", bot.name, e))?
             }
-            std::process::exit(1);
         }
-        GitTypeError::WalkDirError(walk_error) => {
-            console.eprintln(&format!("❌ Directory walk error: {}", walk_error))?;
-            console.eprintln("💡 Check directory permissions and try again")?;
-            std::process::exit(1);
-        }
-        GitTypeError::TreeSitterLanguageError(lang_error) => {
-            console.eprintln(&format!("❌ Language parsing error: {}", lang_error))?;
-            console.eprintln("💡 This might be caused by unsupported language features")?;
-            std::process::exit(1);
-        }
-        GitTypeError::PanicError(msg) => {
-            console.eprintln(&format!("💥 Application panic occurred: {}", msg))?;
-            console.eprintln("💡 This indicates an unexpected error. Please report this issue.")?;
-            std::process::exit(1);
-        }
-        GitTypeError::HttpError(http_error) => {
-            console.eprintln(&format!("❌ HTTP request failed: {}", http_error))?;
-            console.eprintln("💡 Check your internet connection and try again")?;
-            std::process::exit(1);
-        }
-        GitTypeError::ApiError(msg) => {
-            console.eprintln(&format!("❌ API error: {}", msg))?;
-            console.eprintln("💡 The service may be temporarily unavailable")?;
-            std::process::exit(1);
-        }
-        GitTypeError::ValidationError(msg) => {
-            console.eprintln(&format!("❌ {}", msg))?;
-            std::process::exit(1);
-        }
-        GitTypeError::ScreenInitializationError(msg) => {
-            console.eprintln(&format!("❌ Screen initialization error: {}", msg))?;
-            console.eprintln("💡 This is an internal error. Please report this issue.")?;
-            std::process::exit(1);
+    }
+    Ok(bots)
+}
+
+/// Merge `queries/<lang>.scm` files from the app data directory into the parser registry
+pub(crate) fn apply_query_overrides(console: &impl Console) -> Result<()> {
+    let file_storage = FileStorage::new();
+    let Ok(entries) = file_storage
+        .get_app_data_dir()
+        .and_then(|dir| file_storage.read_dir(&dir.join("queries")))
+    else {
+        return Ok(());
+    };
+
+    entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "scm"))
+        .try_for_each(|path| {
+            let stem = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or("");
+            let applied = Languages::get_by_name(stem)
+                .ok_or_else(|| {
+                    GitTypeError::ExtractionFailed(format!("Unknown language: {}", stem))
+                })
+                .and_then(|language| {
+                    let source = file_storage.read_to_string(&path)?;
+                    get_parser_registry()
+                        .set_query_override(language.name(), QueryOverride::parse(&source))
+                });
+            match applied {
+                Ok(()) => {
+                    log::info!("Loaded query override {}", path.display());
+                    Ok(())
+                }
+                Err(e) => console.eprintln(&format!(
+                    "⚠️ Warning: Ignoring query override {}: {}",
+                    path.display(),
+                    e
+                )),
+            }
+        })
+}
+
+fn handle_game_error(console: &impl Console, e: GitTypeError) -> Result<()> {
+    // Log the error details for debugging before handling user-friendly output
+    logging::log_error_to_file(&e);
+
+    let report = ErrorReport::from_error(&e);
+    match &report.detail {
+        Some(detail) => console.eprintln(&format!("❌ {}: {}", report.title, detail))?,
+        None => console.eprintln(&format!("❌ {}", report.title))?,
+    }
+    if !report.suggestions.is_empty() {
+        console.eprintln("💡 Try:")?;
+        for suggestion in &report.suggestions {
+            console.eprintln(&format!("   • {}", suggestion))?;
         }
     }
+    std::process::exit(1);
 }
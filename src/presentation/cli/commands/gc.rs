@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use crate::domain::repositories::challenge_repository::ChallengeRepositoryInterface;
+use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::infrastructure::database::daos::{ChallengeDao, ChallengeDaoInterface};
+use crate::infrastructure::database::database::{Database, DatabaseInterface};
+use crate::infrastructure::logging::{get_log_directory, trim_old_logs};
+use crate::presentation::cli::commands::purge::format_size;
+use crate::{GitTypeError, Result};
+
+/// Logs older than this are removed by `gittype gc`
+pub const LOG_RETENTION_DAYS: i64 = 30;
+
+/// What one `gittype gc` run cleaned up
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GcReport {
+    pub stale_caches: usize,
+    pub cache_bytes: u64,
+    pub orphaned_challenges: usize,
+    pub database_bytes: u64,
+    pub old_logs: usize,
+    pub log_bytes: u64,
+}
+
+impl GcReport {
+    pub fn reclaimed(&self) -> u64 {
+        self.cache_bytes + self.database_bytes + self.log_bytes
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        vec![
+            format!(
+                "🧹 Stale challenge caches: {} removed ({})",
+                self.stale_caches,
+                format_size(self.cache_bytes)
+            ),
+            format!(
+                "🧹 Orphaned challenge rows: {} removed",
+                self.orphaned_challenges
+            ),
+            format!(
+                "🧹 Database vacuumed ({})",
+                format_size(self.database_bytes)
+            ),
+            format!(
+                "🧹 Logs older than {} days: {} removed ({})",
+                LOG_RETENTION_DAYS,
+                self.old_logs,
+                format_size(self.log_bytes)
+            ),
+            format!("✅ Reclaimed {}", format_size(self.reclaimed())),
+        ]
+    }
+}
+
+/// Drop challenge caches of deleted clones, orphaned challenge rows and old
+/// logs, then vacuum `database`
+pub fn collect_garbage(
+    challenge_repository: &dyn ChallengeRepositoryInterface,
+    database: Arc<Database>,
+    log_dir: &std::path::Path,
+) -> Result<GcReport> {
+    let (stale_caches, cache_bytes) = challenge_repository.remove_stale_caches()?;
+    let orphaned_challenges =
+        ChallengeDao::new(Arc::clone(&database) as Arc<dyn DatabaseInterface>)
+            .delete_unreferenced_challenges()?;
+    let database_bytes = database.vacuum()?;
+
+    let cutoff = (chrono::Local::now() - chrono::Duration::days(LOG_RETENTION_DAYS)).naive_local();
+    let (old_logs, log_bytes) = trim_old_logs(log_dir, cutoff)?;
+
+    Ok(GcReport {
+        stale_caches,
+        cache_bytes,
+        orphaned_challenges,
+        database_bytes,
+        old_logs,
+        log_bytes,
+    })
+}
+
+pub fn run_gc(challenge_repository: &dyn ChallengeRepositoryInterface) -> Result<()> {
+    let console = ConsoleImpl::new();
+    if !Database::acquire_instance_lock()? {
+        return Err(GitTypeError::ValidationError(
+            "Another gittype is using the database; close it before running gc".to_string(),
+        ));
+    }
+    let database = Database::new()?;
+    database.init()?;
+
+    let report = collect_garbage(
+        challenge_repository,
+        Arc::new(database),
+        &get_log_directory()?,
+    )?;
+    for line in report.lines() {
+        console.println(&line)?;
+    }
+    Ok(())
+}
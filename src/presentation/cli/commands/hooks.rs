@@ -0,0 +1,119 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::infrastructure::git::LocalGitRepositoryClient;
+use crate::{GitTypeError, Result};
+
+const HOOK_NAME: &str = "post-commit";
+const HOOK_MARKER: &str = "# gittype post-commit hook";
+
+pub fn run_hooks_install(force: bool) -> Result<()> {
+    let console = ConsoleImpl::new();
+    let hook_path = install_post_commit_hook(Path::new("."), force)?;
+
+    console.println(&format!(
+        "✅ Installed post-commit hook at {}",
+        hook_path.display()
+    ))?;
+    console.println("   Every commit now starts a one-stage session on the files it changed.")?;
+    console.println("   Set GITTYPE_SKIP_HOOK=1 to skip it for a single commit.")?;
+    console.println("   Remove it with: gittype hooks uninstall")?;
+    Ok(())
+}
+
+pub fn run_hooks_uninstall() -> Result<()> {
+    let console = ConsoleImpl::new();
+
+    match uninstall_post_commit_hook(Path::new("."))? {
+        Some(hook_path) => console.println(&format!(
+            "✅ Removed post-commit hook at {}",
+            hook_path.display()
+        )),
+        None => console.println("No gittype post-commit hook is installed."),
+    }
+}
+
+/// Write the gittype post-commit hook for the repository containing `repo_path`.
+/// An existing hook that was not written by gittype is only replaced with `force`.
+pub fn install_post_commit_hook(repo_path: &Path, force: bool) -> Result<PathBuf> {
+    let hook_path = LocalGitRepositoryClient::new()
+        .hooks_dir(repo_path)?
+        .join(HOOK_NAME);
+
+    if !force && hook_path.exists() && !is_gittype_hook(&hook_path) {
+        return Err(GitTypeError::ValidationError(format!(
+            "A post-commit hook already exists at {} (use --force to overwrite it)",
+            hook_path.display()
+        )));
+    }
+
+    if let Some(parent) = hook_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&hook_path, post_commit_hook_script())?;
+    make_executable(&hook_path)?;
+
+    Ok(hook_path)
+}
+
+/// Remove the gittype post-commit hook, returning its path if one was removed.
+/// Hooks not written by gittype are left untouched.
+pub fn uninstall_post_commit_hook(repo_path: &Path) -> Result<Option<PathBuf>> {
+    let hook_path = LocalGitRepositoryClient::new()
+        .hooks_dir(repo_path)?
+        .join(HOOK_NAME);
+
+    if !hook_path.exists() {
+        return Ok(None);
+    }
+
+    if !is_gittype_hook(&hook_path) {
+        return Err(GitTypeError::ValidationError(format!(
+            "The post-commit hook at {} was not installed by gittype; remove it manually",
+            hook_path.display()
+        )));
+    }
+
+    fs::remove_file(&hook_path)?;
+    Ok(Some(hook_path))
+}
+
+pub fn post_commit_hook_script() -> String {
+    format!(
+        r#"#!/bin/sh
+{HOOK_MARKER}
+# Practice typing the files changed by each commit.
+# Remove with: gittype hooks uninstall
+
+[ -n "$GITTYPE_SKIP_HOOK" ] && exit 0
+command -v gittype >/dev/null 2>&1 || exit 0
+
+# Only run when attached to an interactive terminal (not during rebases from GUIs, CI, ...)
+if (: </dev/tty) 2>/dev/null; then
+    gittype --commit HEAD </dev/tty >/dev/tty 2>&1 || true
+fi
+"#
+    )
+}
+
+fn is_gittype_hook(hook_path: &Path) -> bool {
+    fs::read_to_string(hook_path)
+        .map(|content| content.contains(HOOK_MARKER))
+        .unwrap_or(false)
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = fs::metadata(path)?.permissions();
+    permissions.set_mode(0o755);
+    fs::set_permissions(path, permissions)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use std::path::Path;
+
+use crate::domain::models::{BaselineSource, GitRepository, HistoryFile, TypingBaseline};
+use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::infrastructure::database::daos::BaselineDaoInterface;
+use crate::infrastructure::database::storage_backend::{open_default_backend, StorageBackend};
+use crate::Result;
+
+pub fn run_import(file: &Path, source: Option<&str>) -> Result<()> {
+    let console = ConsoleImpl::new();
+    let contents = std::fs::read_to_string(file)?;
+    if HistoryFile::is_history_file(&contents) {
+        let (total, imported) = import_history(&contents, open_default_backend()?.as_ref())?;
+        console.println(&format!(
+            "✅ Imported {} of {} sessions from {}",
+            imported,
+            total,
+            file.display()
+        ))?;
+        if imported < total {
+            console.println(&format!(
+                "   {} were already recorded and skipped.",
+                total - imported
+            ))?;
+        }
+        return Ok(());
+    }
+
+    let baseline_dao = open_default_backend()?.baseline_dao();
+
+    let (total, imported) = import_baselines(file, source, baseline_dao.as_ref())?;
+
+    console.println(&format!(
+        "✅ Imported {} of {} typing tests from {}",
+        imported,
+        total,
+        file.display()
+    ))?;
+    if imported < total {
+        console.println(&format!(
+            "   {} were already imported and skipped.",
+            total - imported
+        ))?;
+    }
+    console.println("   Compare prose and code speed in: gittype stats")?;
+    Ok(())
+}
+
+/// Parse `file` and store its tests; returns (tests in file, newly imported)
+pub fn import_baselines(
+    file: &Path,
+    source: Option<&str>,
+    baseline_dao: &dyn BaselineDaoInterface,
+) -> Result<(usize, usize)> {
+    let source = source.map(str::parse::<BaselineSource>).transpose()?;
+    let contents = std::fs::read_to_string(file)?;
+    let baselines = TypingBaseline::parse_csv(&contents, source)?;
+
+    let imported = baseline_dao.insert_baselines(&baselines)?;
+    Ok((baselines.len(), imported))
+}
+
+/// Validate a history file and store its sessions; returns (sessions in
+/// file, newly imported). Nothing is stored when the file is invalid or
+/// storing any of it fails
+pub fn import_history(contents: &str, backend: &dyn StorageBackend) -> Result<(usize, usize)> {
+    let history = HistoryFile::parse(contents)?;
+    let repository_dao = backend.repository_dao();
+    let session_dao = backend.session_dao();
+    let database = backend.database();
+    let conn = database.get_connection()?;
+    let tx = conn.unchecked_transaction()?;
+
+    let mut imported = 0;
+    for session in &history.sessions {
+        let repository_id = repository_dao.ensure_repository_in_transaction(
+            &tx,
+            &GitRepository {
+                user_name: session.repository.owner.clone(),
+                repository_name: session.repository.name.clone(),
+                remote_url: session.repository.url.clone(),
+                branch: None,
+                commit_hash: None,
+                is_dirty: false,
+                root_path: None,
+                license: None,
+            },
+        )?;
+        if session_dao.import_history_session_in_transaction(&tx, repository_id, session)? {
+            imported += 1;
+        }
+    }
+    tx.commit()?;
+    Ok((history.sessions.len(), imported))
+}
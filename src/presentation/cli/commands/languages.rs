@@ -0,0 +1,14 @@
+use crate::domain::models::LanguageCapability;
+use crate::domain::services::source_code_parser::parsers::get_parser_registry;
+use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::presentation::cli::commands::game::apply_query_overrides;
+use crate::Result;
+
+/// Print every supported language with its extensions and extracted chunk
+/// types, including those added by query overrides
+pub fn run_languages() -> Result<()> {
+    let console = ConsoleImpl::new();
+    apply_query_overrides(&console)?;
+    let capabilities = get_parser_registry().capabilities();
+    console.println(&LanguageCapability::render(&capabilities))
+}
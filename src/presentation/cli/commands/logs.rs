@@ -0,0 +1,34 @@
+use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::infrastructure::logging::{find_latest_log_file, get_log_directory, read_log_tail};
+use crate::presentation::cli::screen_runner::run_screen;
+use crate::presentation::tui::screens::LogViewerScreen;
+use crate::presentation::tui::ScreenType;
+use crate::Result;
+
+const DEFAULT_TAIL: usize = 100;
+
+/// Print the last `tail` lines of the newest log file, or open the log viewer
+/// when no count is given and a terminal is attached
+pub fn run_logs(tail: Option<usize>) -> Result<()> {
+    if tail.is_none() && atty::is(atty::Stream::Stdout) {
+        run_screen::<LogViewerScreen, (), (), fn(&LogViewerScreen) -> Option<()>>(
+            ScreenType::LogViewer,
+            None,
+            None,
+        )?;
+        return Ok(());
+    }
+
+    let console = ConsoleImpl::new();
+    let log_dir = get_log_directory()?;
+    let Some(log_path) = find_latest_log_file(&log_dir) else {
+        console.eprintln(&format!("No log files found in {}", log_dir.display()))?;
+        return Ok(());
+    };
+
+    console.eprintln(&format!("==> {} <==", log_path.display()))?;
+    for line in read_log_tail(&log_path, tail.unwrap_or(DEFAULT_TAIL))? {
+        console.println(&line)?;
+    }
+    Ok(())
+}
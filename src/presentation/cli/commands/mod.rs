@@ -1,13 +1,49 @@
+pub mod demo;
 pub mod export;
 pub mod game;
+pub mod gc;
 pub mod history;
+pub mod hooks;
+pub mod import;
+pub mod languages;
+pub mod logs;
+pub mod pack;
+pub mod purge;
+pub mod remind;
 pub mod repo;
+pub mod scan;
+pub mod serve;
+pub mod snippets;
+pub mod spectate;
 pub mod stats;
+pub mod status;
+pub mod telemetry;
 pub mod trending;
+pub mod verify;
+pub mod warm;
 
+pub use demo::run_demo;
 pub use export::run_export;
 pub use game::run_game_session;
+pub use gc::run_gc;
 pub use history::run_history;
-pub use repo::{run_repo_clear, run_repo_list, run_repo_play};
+pub use hooks::{run_hooks_install, run_hooks_uninstall};
+pub use import::run_import;
+pub use languages::run_languages;
+pub use logs::run_logs;
+pub use pack::{run_pack_create, run_pack_play};
+pub use purge::run_purge;
+pub use remind::run_remind;
+pub use repo::{
+    run_repo_clear, run_repo_dismissed, run_repo_list, run_repo_play, run_repo_restore,
+};
+pub use scan::run_scan;
+pub use serve::run_serve;
+pub use snippets::run_snippets;
+pub use spectate::run_spectate;
 pub use stats::run_stats;
+pub use status::run_status;
+pub use telemetry::{run_telemetry_disable, run_telemetry_enable, run_telemetry_show};
 pub use trending::run_trending;
+pub use verify::run_verify;
+pub use warm::run_warm;
@@ -0,0 +1,122 @@
+use std::path::{Path, PathBuf};
+
+use crate::domain::models::{Challenge, ChallengePack, DifficultyLevel, ExtractionOptions};
+use crate::domain::services::challenge_flag_service::{
+    ChallengeFlagService, ChallengeFlagServiceInterface,
+};
+use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::infrastructure::database::storage_backend::open_default_backend;
+use crate::infrastructure::git::LocalGitRepositoryClient;
+use crate::presentation::cli::args::Cli;
+use crate::presentation::cli::commands::game::{start_game_session, GameSource};
+use crate::presentation::cli::commands::scan::{apply_user_extraction_config, extract_challenges};
+use crate::{GitTypeError, Result};
+
+/// Extract `repo_path` and write a seeded pick of its challenges to `out`
+pub fn run_pack_create(
+    repo_path: &Path,
+    out: &Path,
+    stages: usize,
+    difficulty: &str,
+    seed: Option<u64>,
+) -> Result<()> {
+    let console = ConsoleImpl::new();
+    let difficulty = DifficultyLevel::from_name(difficulty).ok_or_else(|| {
+        GitTypeError::ValidationError(format!("Unknown difficulty: {}", difficulty))
+    })?;
+    if stages == 0 {
+        return Err(GitTypeError::ValidationError(
+            "A challenge pack needs at least one stage".to_string(),
+        ));
+    }
+    apply_user_extraction_config(&console)?;
+
+    let repo_path = repo_path
+        .canonicalize()
+        .map_err(|_| GitTypeError::RepositoryNotFound(repo_path.to_path_buf()))?;
+    console.eprintln(&format!(
+        "Extracting challenges from {}...",
+        repo_path.display()
+    ))?;
+    let (_, _, challenges) = extract_challenges(&repo_path, &ExtractionOptions::default())?;
+
+    let seed = seed.unwrap_or_else(rand::random);
+    let pack = ChallengePack::new(
+        &pack_source(&repo_path),
+        exclude_flagged(challenges)?,
+        difficulty,
+        stages,
+        seed,
+    );
+    if pack.challenges.is_empty() {
+        return Err(GitTypeError::ValidationError(format!(
+            "No {:?} challenges found in {}",
+            difficulty,
+            repo_path.display()
+        )));
+    }
+
+    std::fs::write(out, pack.to_bytes()?)?;
+    console.println(&format!(
+        "✅ Wrote {} {:?} challenges from {} to {} (seed {})",
+        pack.challenges.len(),
+        pack.difficulty,
+        pack.source,
+        out.display(),
+        pack.seed
+    ))?;
+    if pack.challenges.len() < stages {
+        console.println(&format!(
+            "   Only {} of the {} requested stages were available",
+            pack.challenges.len(),
+            stages
+        ))?;
+    }
+    console.println(&format!(
+        "   Play it with: gittype pack play {}",
+        out.display()
+    ))
+}
+
+/// Play the challenges of a pack in order; every player of the same file gets the same session
+pub fn run_pack_play(cli: Cli, file: PathBuf) -> Result<()> {
+    validate_pack_play_args(&cli)?;
+    let pack = load_challenge_pack(&file)?;
+    start_game_session(cli, GameSource::Pack(pack))
+}
+
+pub fn load_challenge_pack(file: &Path) -> Result<ChallengePack> {
+    let bytes = std::fs::read(file).map_err(|e| {
+        GitTypeError::ValidationError(format!("Failed to read {}: {}", file.display(), e))
+    })?;
+    ChallengePack::from_bytes(&bytes)
+}
+
+pub fn validate_pack_play_args(cli: &Cli) -> Result<()> {
+    if cli.repo.is_some() || cli.commit.is_some() || cli.repo_path.is_some() {
+        return Err(GitTypeError::ValidationError(
+            "pack play cannot be combined with a repository path, --repo or --commit".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// `owner/name` from the git remote, or the directory name outside a repository
+fn pack_source(repo_path: &Path) -> String {
+    LocalGitRepositoryClient::new()
+        .create_from_local_path(repo_path)
+        .map(|repository| format!("{}/{}", repository.user_name, repository.repository_name))
+        .unwrap_or_else(|_| {
+            repo_path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        })
+}
+
+fn exclude_flagged(challenges: Vec<Challenge>) -> Result<Vec<Challenge>> {
+    Ok(
+        ChallengeFlagService::new(open_default_backend()?.challenge_flag_dao().into())
+            .exclude_flagged(challenges),
+    )
+}
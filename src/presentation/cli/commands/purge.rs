@@ -0,0 +1,190 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+
+use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::infrastructure::database::database::Database;
+use crate::infrastructure::storage::app_data_provider::AppDataProvider;
+use crate::Result;
+
+/// Local data that `gittype purge` can remove
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PurgeTarget {
+    /// Session history, stats and achievements
+    Database,
+    /// Repositories cloned with --repo or trending
+    Repos,
+    /// Extracted challenges, trending lists and the update check
+    Cache,
+    Logs,
+    /// Settings and the custom theme
+    Config,
+}
+
+impl PurgeTarget {
+    pub fn all() -> [PurgeTarget; 5] {
+        [
+            PurgeTarget::Database,
+            PurgeTarget::Repos,
+            PurgeTarget::Cache,
+            PurgeTarget::Logs,
+            PurgeTarget::Config,
+        ]
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            PurgeTarget::Database => "Database (session history, stats, achievements)",
+            PurgeTarget::Repos => "Cloned repositories",
+            PurgeTarget::Cache => "Challenge and trending cache",
+            PurgeTarget::Logs => "Logs and crash signatures",
+            PurgeTarget::Config => "Config (settings, custom theme)",
+        }
+    }
+
+    /// Files and directories holding this data inside `data_dir`
+    pub fn paths(&self, data_dir: &Path) -> Vec<PathBuf> {
+        let names: &[&str] = match self {
+            PurgeTarget::Database => &["gittype.db", "gittype.db-wal", "gittype.db-shm"],
+            PurgeTarget::Repos => &["repos"],
+            PurgeTarget::Cache => &["cache", "version_cache.json"],
+            PurgeTarget::Logs => &["logs", "crash_signatures.json"],
+            PurgeTarget::Config => &["config.json", "custom-theme.json"],
+        };
+        names.iter().map(|name| data_dir.join(name)).collect()
+    }
+
+    /// Bytes on disk, or `None` when nothing of this target exists
+    pub fn size(&self, data_dir: &Path) -> Option<u64> {
+        let existing: Vec<PathBuf> = self
+            .paths(data_dir)
+            .into_iter()
+            .filter(|path| path.exists())
+            .collect();
+        (!existing.is_empty()).then(|| existing.iter().map(|path| disk_usage(path)).sum())
+    }
+
+    /// Remove everything belonging to this target, returning the bytes freed
+    pub fn purge(&self, data_dir: &Path) -> Result<u64> {
+        let freed = self.size(data_dir).unwrap_or(0);
+        for path in self.paths(data_dir) {
+            if path.is_dir() {
+                fs::remove_dir_all(&path)?;
+            } else if path.exists() {
+                fs::remove_file(&path)?;
+            }
+        }
+        Ok(freed)
+    }
+}
+
+struct PurgeCommand;
+impl AppDataProvider for PurgeCommand {}
+
+/// Remove local gittype data. Without `targets`, each kind of data found is
+/// offered one by one; `yes` skips all confirmation.
+pub fn run_purge(targets: &[PurgeTarget], yes: bool) -> Result<()> {
+    let console = ConsoleImpl::new();
+    let data_dir = PurgeCommand::get_app_data_dir()?;
+
+    let mut found: Vec<(PurgeTarget, u64)> = PurgeTarget::all()
+        .into_iter()
+        .filter(|target| targets.is_empty() || targets.contains(target))
+        .filter_map(|target| target.size(&data_dir).map(|size| (target, size)))
+        .collect();
+
+    if found
+        .iter()
+        .any(|(target, _)| *target == PurgeTarget::Database)
+        && !Database::acquire_instance_lock()?
+    {
+        console
+            .println("⚠️ Another gittype is using the database; close it to purge the database.")?;
+        found.retain(|(target, _)| *target != PurgeTarget::Database);
+    }
+
+    if found.is_empty() {
+        console.println(&format!("Nothing to purge in {}.", data_dir.display()))?;
+        return Ok(());
+    }
+
+    console.println(&format!("gittype data in {}:", data_dir.display()))?;
+    let selected: Vec<PurgeTarget> = if yes {
+        found.iter().map(|(target, _)| *target).collect()
+    } else if targets.is_empty() {
+        let mut selected = Vec::new();
+        for (target, size) in &found {
+            if confirm(
+                &console,
+                &format!("Remove {} ({})?", target.title(), format_size(*size)),
+            )? {
+                selected.push(*target);
+            }
+        }
+        selected
+    } else {
+        for (target, size) in &found {
+            console.println(&format!("  {} ({})", target.title(), format_size(*size)))?;
+        }
+        if confirm(&console, "Remove the data above?")? {
+            found.iter().map(|(target, _)| *target).collect()
+        } else {
+            Vec::new()
+        }
+    };
+
+    if selected.is_empty() {
+        console.println("Nothing was removed.")?;
+        return Ok(());
+    }
+
+    for target in selected {
+        let freed = target.purge(&data_dir)?;
+        console.println(&format!(
+            "✅ Removed {} ({})",
+            target.title(),
+            format_size(freed)
+        ))?;
+    }
+    Ok(())
+}
+
+pub(crate) fn confirm(console: &ConsoleImpl, question: &str) -> Result<bool> {
+    console.print(&format!("{} [y/N]: ", question))?;
+    console.flush()?;
+
+    let mut input = String::new();
+    console.read_line(&mut input)?;
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+fn disk_usage(path: &Path) -> u64 {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.is_dir() => fs::read_dir(path)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| disk_usage(&entry.path()))
+                    .sum()
+            })
+            .unwrap_or(0),
+        Ok(metadata) => metadata.len(),
+        Err(_) => 0,
+    }
+}
+
+pub(crate) fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
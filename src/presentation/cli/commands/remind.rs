@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use crate::domain::models::Reminder;
+use crate::domain::services::config_service::{ConfigService, ConfigServiceInterface};
+use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::infrastructure::reminder_scheduler::{install_reminder, send_notification, Scheduler};
+use crate::infrastructure::storage::file_storage::FileStorage;
+use crate::presentation::cli::commands::status::load_status_summary;
+use crate::presentation::cli::screen_runner::run_screen;
+use crate::presentation::tui::screens::ReminderScreen;
+use crate::presentation::tui::ScreenType;
+use crate::Result;
+
+/// Schedule a daily reminder at `at`, or manage the existing ones
+pub fn run_remind(at: Option<&str>, notify: bool) -> Result<()> {
+    if notify {
+        return run_remind_notify();
+    }
+    match at {
+        Some(at) => run_remind_add(at),
+        None if atty::is(atty::Stream::Stdout) => {
+            run_screen::<ReminderScreen, (), (), fn(&ReminderScreen) -> Option<()>>(
+                ScreenType::Reminders,
+                None,
+                None,
+            )?;
+            Ok(())
+        }
+        None => run_remind_list(),
+    }
+}
+
+fn run_remind_add(at: &str) -> Result<()> {
+    let console = ConsoleImpl::new();
+    let reminder = Reminder::parse(at)?;
+    install_reminder(&reminder)?;
+
+    let config_service = load_config_service()?;
+    config_service.update_config(|config| {
+        config.reminders.add(reminder);
+    })?;
+    config_service.save()?;
+
+    console.println(&format!(
+        "✅ Reminder set for {} every day ({})",
+        reminder.label(),
+        Scheduler::current().name()
+    ))?;
+    console.println("   Manage or remove reminders with: gittype remind")?;
+    Ok(())
+}
+
+fn run_remind_list() -> Result<()> {
+    let console = ConsoleImpl::new();
+    let reminders = load_config_service()?.get_config().reminders.at;
+    if reminders.is_empty() {
+        return console.println("No reminders set. Add one with: gittype remind --at 09:00");
+    }
+    for reminder in reminders {
+        console.println(&format!("{} every day", reminder.label()))?;
+    }
+    Ok(())
+}
+
+/// Run by the scheduled task: show the practice notification
+fn run_remind_notify() -> Result<()> {
+    let streak = load_status_summary()
+        .map(|summary| summary.streak)
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to load the streak for the reminder: {}", e);
+            0
+        });
+    send_notification(
+        Reminder::NOTIFICATION_TITLE,
+        &Reminder::notification_body(streak),
+    )
+}
+
+fn load_config_service() -> Result<ConfigService> {
+    ConfigService::new(Arc::new(FileStorage::new()))
+}
@@ -1,23 +1,52 @@
 use std::sync::Arc;
 
+use crate::domain::models::WarmStatus;
+use crate::domain::services::dismissed_repository_service::{
+    DismissedRepositoryService, DismissedRepositoryServiceInterface,
+};
 use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::infrastructure::database::storage_backend::open_default_backend;
 use crate::infrastructure::storage::app_data_provider::AppDataProvider;
 use crate::infrastructure::storage::file_storage::{FileStorage, FileStorageInterface};
+use crate::presentation::cli::commands::purge::confirm;
 use crate::presentation::cli::commands::run_game_session;
+use crate::presentation::cli::commands::warm::warm_list;
 use crate::presentation::cli::screen_runner::run_screen;
 use crate::presentation::cli::Cli;
 use crate::presentation::tui::screens::{RepoListScreen, RepoPlayScreen};
 use crate::presentation::tui::ScreenType;
 use crate::{GitTypeError, Result};
 
+/// Queued stars are cloned and extracted this many at a time
+const QUEUE_WARM_JOBS: usize = 4;
+
 pub fn run_repo_list() -> Result<()> {
-    run_screen::<RepoListScreen, _, _, _>(
+    // The list opens with the cached metadata; what the refresh fetches from
+    // GitHub is stored for the next time it is shown
+    std::thread::spawn(refresh_repository_metadata);
+
+    let play_request = run_screen::<RepoListScreen, _, _, _>(
         ScreenType::RepoList,
         None::<()>,
-        None::<fn(&RepoListScreen) -> Option<()>>,
+        Some(|screen: &RepoListScreen| screen.get_play_request()),
     )?;
 
-    Ok(())
+    match play_request {
+        Some(repo_spec) => start_repository(repo_spec),
+        None => Ok(()),
+    }
+}
+
+fn refresh_repository_metadata() {
+    use crate::domain::repositories::repository_metadata_repository::RepositoryMetadataRepositoryInterface;
+    use crate::presentation::di::AppModule;
+    use shaku::HasComponent;
+
+    let container = AppModule::builder().build();
+    let metadata_repository: Arc<dyn RepositoryMetadataRepositoryInterface> = container.resolve();
+    if let Err(e) = metadata_repository.refresh_all_metadata() {
+        log::warn!("Failed to refresh repository metadata: {}", e);
+    }
 }
 
 struct RepoClearCommand;
@@ -117,35 +146,105 @@ pub fn run_repo_play() -> Result<()> {
     let container = AppModule::builder().build();
     let _theme_service: Arc<dyn ThemeServiceInterface> = container.resolve();
 
-    // Run screen and get selected repository
-    let selected_repo = run_screen::<RepoPlayScreen, _, _, _>(
+    // Run screen and get the selected repository or the queued stars
+    let (selected_repo, practice_queue) = run_screen::<RepoPlayScreen, _, _, _>(
         ScreenType::RepoPlay,
         None::<()>,
         Some(|screen: &RepoPlayScreen| {
-            screen
-                .get_selected_repository()
-                .map(|(repo, _)| (repo.user_name.clone(), repo.repository_name.clone()))
+            Some((
+                screen
+                    .get_selected_repository()
+                    .map(|(repo, _)| (repo.user_name.clone(), repo.repository_name.clone())),
+                screen.get_practice_queue(),
+            ))
         }),
-    )?;
+    )?
+    .unwrap_or_default();
+
+    if !practice_queue.is_empty() {
+        return play_queue(&console, &practice_queue);
+    }
 
     // If a repository was selected, start the game
     if let Some((user_name, repo_name)) = selected_repo {
-        let repo_spec = format!("{}/{}", user_name, repo_name);
+        start_repository(format!("{}/{}", user_name, repo_name))
+    } else {
+        console.println("Repository selection cancelled.")?;
+        Ok(())
+    }
+}
 
-        console.println(&format!("Starting gittype with repository: {}", repo_spec))?;
+/// Warm every queued repository up front, then play them one after another,
+/// asking before each after the first; repositories that failed to warm are skipped
+fn play_queue(console: &ConsoleImpl, queue: &[String]) -> Result<()> {
+    let progress = warm_list(console, queue, QUEUE_WARM_JOBS)?;
+    let ready: Vec<&String> = queue
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| matches!(progress.status(*index), Some(WarmStatus::Done { .. })))
+        .map(|(_, repo_spec)| repo_spec)
+        .collect();
+
+    for (position, repo_spec) in ready.iter().enumerate() {
+        if position > 0 && !confirm(console, &format!("Play {} next?", repo_spec))? {
+            break;
+        }
+        start_repository(repo_spec.to_string())?;
+    }
+    Ok(())
+}
 
-        // Create a Cli struct to pass to run_game_session
-        let cli = Cli {
-            repo_path: None,
-            repo: Some(repo_spec),
-            langs: None,
-            command: None,
-        };
+pub fn run_repo_dismissed() -> Result<()> {
+    let console = ConsoleImpl::new();
+    let dismissed = dismissed_repository_service()?.list()?;
+    if dismissed.is_empty() {
+        return console.println("No repositories are dismissed from trending.");
+    }
+
+    console.println("Never suggested in trending:")?;
+    dismissed
+        .iter()
+        .try_for_each(|repo_name| console.println(&format!("  {}", repo_name)))?;
+    console.println("Restore one with: gittype repo restore <owner/repo>")
+}
 
-        // Start the game session
-        run_game_session(cli)
+pub fn run_repo_restore(name: &str) -> Result<()> {
+    let console = ConsoleImpl::new();
+    if dismissed_repository_service()?.restore(name)? {
+        console.println(&format!(
+            "{} can be suggested in trending again.",
+            name.trim()
+        ))
     } else {
-        console.println("Repository selection cancelled.")?;
-        Ok(())
+        console.println(&format!("{} was not dismissed.", name.trim()))
     }
 }
+
+fn dismissed_repository_service() -> Result<DismissedRepositoryService> {
+    Ok(DismissedRepositoryService::new(
+        open_default_backend()?.dismissed_repository_dao().into(),
+    ))
+}
+
+fn start_repository(repo_spec: String) -> Result<()> {
+    let console = ConsoleImpl::new();
+    console.println(&format!("Starting gittype with repository: {}", repo_spec))?;
+
+    // Create a Cli struct to pass to run_game_session
+    let cli = Cli {
+        repo_path: None,
+        repo: Some(repo_spec),
+        langs: None,
+        commit: None,
+        package: None,
+        follow_symlinks: false,
+        prose: false,
+        summary_only: false,
+        broadcast: None,
+        bots: None,
+        command: None,
+    };
+
+    // Start the game session
+    run_game_session(cli)
+}
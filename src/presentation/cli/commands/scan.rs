@@ -0,0 +1,135 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use shaku::HasComponent;
+
+use crate::domain::models::loading::NoOpProgressReporter;
+use crate::domain::models::{Challenge, CodeChunk, ExtractionOptions, Languages, ScanReport};
+use crate::domain::repositories::challenge_repository::ChallengeRepositoryInterface;
+use crate::domain::services::challenge_flag_service::{
+    ChallengeFlagService, ChallengeFlagServiceInterface,
+};
+use crate::domain::services::challenge_generator::ChallengeGenerator;
+use crate::domain::services::config_service::ConfigServiceInterface;
+use crate::domain::services::source_code_parser::SourceCodeParser;
+use crate::domain::services::source_file_extractor::SourceFileExtractor;
+use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::infrastructure::database::storage_backend::open_default_backend;
+use crate::infrastructure::git::LocalGitRepositoryClient;
+use crate::presentation::cli::commands::game::{apply_extraction_config, apply_query_overrides};
+use crate::presentation::di::AppModule;
+use crate::{GitTypeError, Result};
+
+/// Run scanning, extracting and generating for `path` and print what they
+/// produced. Unless `dry_run`, the challenges are also cached so the next
+/// game in this repository starts without extracting again.
+pub fn run_scan(
+    path: Option<PathBuf>,
+    langs: Option<Vec<String>>,
+    follow_symlinks: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let console = ConsoleImpl::new();
+    apply_user_extraction_config(&console)?;
+
+    let repo_path = path.unwrap_or_else(|| PathBuf::from("."));
+    let repo_path = repo_path
+        .canonicalize()
+        .map_err(|_| GitTypeError::RepositoryNotFound(repo_path.clone()))?;
+    let repo_path = LocalGitRepositoryClient::new().resolve_work_tree(&repo_path)?;
+
+    let mut options = ExtractionOptions {
+        follow_symlinks,
+        ..ExtractionOptions::default()
+    };
+    if let Some(langs) = langs {
+        Languages::validate_languages(&langs).map_err(|unsupported| {
+            GitTypeError::ValidationError(format!(
+                "Unsupported language(s): {}",
+                unsupported.join(", ")
+            ))
+        })?;
+        options.languages = Some(langs);
+        options.apply_language_filter();
+    }
+
+    console.eprintln(&format!("Scanning {}...", repo_path.display()))?;
+    let (files, chunks, challenges) = extract_challenges(&repo_path, &options)?;
+
+    let report = ScanReport::new(&repo_path, &files, &chunks, &challenges)
+        .with_flagged_challenges(count_flagged(&challenges)?);
+    console.println(&report.render())?;
+
+    if !dry_run && !challenges.is_empty() {
+        cache_challenges(&console, &repo_path, &challenges)?;
+    }
+    Ok(())
+}
+
+/// Load the config's extraction settings and query overrides, as a game session would
+pub(crate) fn apply_user_extraction_config(console: &impl Console) -> Result<()> {
+    let container = AppModule::builder().build();
+    let config_service: &dyn ConfigServiceInterface = container.resolve_ref();
+    if let Err(e) = config_service.init() {
+        log::warn!("Failed to initialize config service: {}", e);
+    }
+    apply_extraction_config(console, &config_service.get_config())?;
+    apply_query_overrides(console)
+}
+
+/// Scanned files, extracted chunks and generated challenges of `repo_path`, without a loading screen
+pub(crate) fn extract_challenges(
+    repo_path: &Path,
+    options: &ExtractionOptions,
+) -> Result<(Vec<PathBuf>, Vec<CodeChunk>, Vec<Challenge>)> {
+    let files = SourceFileExtractor::new().collect_with_progress_with_options(
+        repo_path,
+        options,
+        &NoOpProgressReporter,
+    )?;
+
+    let files_to_process = Languages::for_project_files(&files);
+    let chunks = if files_to_process.is_empty() {
+        Vec::new()
+    } else {
+        SourceCodeParser::new()?.extract_chunks_with_progress(
+            files_to_process,
+            options,
+            &NoOpProgressReporter,
+        )?
+    };
+    let challenges =
+        ChallengeGenerator::new().convert_with_progress(chunks.clone(), &NoOpProgressReporter);
+    Ok((files, chunks, challenges))
+}
+
+fn count_flagged(challenges: &[Challenge]) -> Result<usize> {
+    if challenges.is_empty() {
+        return Ok(0);
+    }
+    ChallengeFlagService::new(open_default_backend()?.challenge_flag_dao().into())
+        .count_flagged(challenges)
+}
+
+/// Store `challenges` under the repository's current commit, unless it has uncommitted changes
+pub(crate) fn cache_challenges(
+    console: &ConsoleImpl,
+    repo_path: &Path,
+    challenges: &[Challenge],
+) -> Result<()> {
+    let Ok(git_repository) = LocalGitRepositoryClient::new().create_from_local_path(repo_path)
+    else {
+        return console.eprintln("Not a git repository root, so nothing was cached.");
+    };
+    if git_repository.is_dirty {
+        return console.eprintln("The repository has uncommitted changes, so nothing was cached.");
+    }
+
+    let container = AppModule::builder().build();
+    let challenge_repository: Arc<dyn ChallengeRepositoryInterface> = container.resolve();
+    challenge_repository.save_challenges(&git_repository, challenges, None)?;
+    console.eprintln(&format!(
+        "Cached {} challenges for the next game.",
+        challenges.len()
+    ))
+}
@@ -0,0 +1,70 @@
+use shaku::HasComponent;
+
+use std::io;
+use std::net::TcpListener;
+use std::sync::Arc;
+
+use crate::domain::repositories::session_repository::SessionRepositoryTrait;
+use crate::domain::services::analytics_service::AnalyticsServiceInterface;
+use crate::domain::services::session_service::SessionServiceInterface;
+use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::infrastructure::database::database::DatabaseInterface;
+use crate::presentation::api::ApiServer;
+use crate::presentation::di::AppModule;
+use crate::presentation::rpc::EditorServer;
+use crate::{GitTypeError, Result};
+
+pub fn run_serve(stdio: bool, api: Option<&str>) -> Result<()> {
+    match (stdio, api) {
+        (true, _) => {
+            log::info!("Starting editor server on stdio");
+            let stdin = io::stdin();
+            EditorServer::new().run(stdin.lock(), io::stdout().lock())
+        }
+        (false, Some(address)) => run_api_server(address),
+        (false, None) => Err(GitTypeError::ValidationError(
+            "Specify a transport for the server, e.g. `gittype serve --stdio` or `gittype serve --api`"
+                .to_string(),
+        )),
+    }
+}
+
+fn run_api_server(address: &str) -> Result<()> {
+    let console = ConsoleImpl::new();
+    let listener = bind_api_listener(address)?;
+    let local_address = listener.local_addr()?;
+
+    console.println(&format!(
+        "🌐 Serving gittype API on http://{}",
+        local_address
+    ))?;
+    if !local_address.ip().is_loopback() {
+        console
+            .eprintln("⚠️ The API is reachable from other machines and has no authentication")?;
+    }
+    console.println("   Press Ctrl+C to stop")?;
+
+    create_api_server()?.serve(listener)
+}
+
+pub fn bind_api_listener(address: &str) -> Result<TcpListener> {
+    TcpListener::bind(address).map_err(|e| {
+        GitTypeError::ValidationError(format!("Failed to bind API server to {}: {}", address, e))
+    })
+}
+
+pub fn create_api_server() -> Result<ApiServer> {
+    let container = AppModule::builder().build();
+    let database: Arc<dyn DatabaseInterface> = container.resolve();
+    database.init_tables()?;
+
+    let session_service: Arc<dyn SessionServiceInterface> = container.resolve();
+    let session_repository: Arc<dyn SessionRepositoryTrait> = container.resolve();
+    let analytics_service: Arc<dyn AnalyticsServiceInterface> = container.resolve();
+
+    Ok(ApiServer::new(
+        session_service,
+        session_repository,
+        analytics_service,
+    ))
+}
@@ -0,0 +1,26 @@
+use std::path::{Path, PathBuf};
+
+use crate::presentation::cli::args::Cli;
+use crate::presentation::cli::commands::game::{start_game_session, GameSource};
+use crate::{GitTypeError, Result};
+
+/// Play every file in `dir` as one whole challenge, e.g. an exercise set put together by a teacher
+pub fn run_snippets(cli: Cli, dir: PathBuf) -> Result<()> {
+    validate_snippet_args(&cli, &dir)?;
+    start_game_session(cli, GameSource::Snippets(dir))
+}
+
+pub fn validate_snippet_args(cli: &Cli, dir: &Path) -> Result<()> {
+    if cli.repo.is_some() || cli.commit.is_some() || cli.repo_path.is_some() {
+        return Err(GitTypeError::ValidationError(
+            "snippets cannot be combined with a repository path, --repo or --commit".to_string(),
+        ));
+    }
+    if !dir.is_dir() {
+        return Err(GitTypeError::ValidationError(format!(
+            "Snippet directory not found: {}",
+            dir.display()
+        )));
+    }
+    Ok(())
+}
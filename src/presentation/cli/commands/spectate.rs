@@ -0,0 +1,8 @@
+use crate::presentation::spectator::SpectatorViewer;
+use crate::Result;
+
+/// Watch another terminal's game, started with `--broadcast`, until a key is pressed
+pub fn run_spectate(address: &str) -> Result<()> {
+    log::info!("Spectating the broadcast on {}", address);
+    SpectatorViewer::run(address)
+}
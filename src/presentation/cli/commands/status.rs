@@ -0,0 +1,28 @@
+use chrono::Local;
+
+use crate::domain::models::StatusSummary;
+use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::infrastructure::database::storage_backend::{SqliteBackend, StorageBackend};
+use crate::Result;
+
+/// Print a one-line summary without starting the TUI or building the full DI container
+pub fn run_status(format: &str) -> Result<()> {
+    let console = ConsoleImpl::new();
+    let summary = load_status_summary()?;
+    console.println(&summary.render(format))?;
+    Ok(())
+}
+
+pub fn load_status_summary() -> Result<StatusSummary> {
+    let backend = SqliteBackend::open_unmigrated()?;
+    // Skipping migrations keeps this fast; a database that has never been
+    // migrated has no sessions to summarize yet
+    let activity = match backend.database().get_current_schema_version() {
+        Ok(version) if version > 0 => backend.session_dao().get_daily_activity()?,
+        _ => Vec::new(),
+    };
+    Ok(StatusSummary::from_daily_activity(
+        &activity,
+        Local::now().date_naive(),
+    ))
+}
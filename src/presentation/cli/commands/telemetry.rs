@@ -0,0 +1,102 @@
+use chrono::Local;
+
+use std::sync::Arc;
+
+use crate::domain::models::config::TelemetryConfig;
+use crate::domain::models::TelemetryReport;
+use crate::domain::services::config_service::{ConfigService, ConfigServiceInterface};
+use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::infrastructure::database::storage_backend::open_default_backend;
+use crate::infrastructure::http::TelemetryClient;
+use crate::infrastructure::logging::read_crash_signatures;
+use crate::infrastructure::storage::file_storage::FileStorage;
+use crate::Result;
+
+/// Print the telemetry status and the exact report that would be sent
+pub fn run_telemetry_show() -> Result<()> {
+    let console = ConsoleImpl::new();
+    let config = load_config_service()?.get_config().telemetry;
+
+    console.println(&status_line(&config))?;
+    console.println("")?;
+    console.println("This is exactly what would be sent:")?;
+    console.println(&load_telemetry_report()?.to_json()?)?;
+    Ok(())
+}
+
+pub fn run_telemetry_enable(endpoint: Option<&str>) -> Result<()> {
+    let console = ConsoleImpl::new();
+    let config_service = load_config_service()?;
+    config_service.update_config(|config| {
+        config.telemetry.enabled = true;
+        if let Some(endpoint) = endpoint {
+            config.telemetry.endpoint = Some(endpoint.to_string());
+        }
+    })?;
+    config_service.save()?;
+
+    console.println(&status_line(&config_service.get_config().telemetry))?;
+    console.println("   Preview the report with: gittype telemetry show")?;
+    Ok(())
+}
+
+pub fn run_telemetry_disable() -> Result<()> {
+    let console = ConsoleImpl::new();
+    let config_service = load_config_service()?;
+    config_service.update_config(|config| config.telemetry.enabled = false)?;
+    config_service.save()?;
+
+    console.println(&status_line(&config_service.get_config().telemetry))
+}
+
+/// Session and language counts from the database plus recorded crash signatures
+pub fn load_telemetry_report() -> Result<TelemetryReport> {
+    let session_dao = open_default_backend()?.session_dao();
+
+    Ok(TelemetryReport::new(
+        &session_dao.get_daily_activity()?,
+        &session_dao.get_language_activity()?,
+        read_crash_signatures(),
+    ))
+}
+
+/// Send today's report if the player opted in; failures are only logged
+pub fn send_telemetry_if_due() {
+    let result = load_config_service().and_then(|config_service| {
+        let today = Local::now().date_naive();
+        let telemetry = config_service.get_config().telemetry;
+        let Some(endpoint) = telemetry.due_endpoint(today) else {
+            return Ok(());
+        };
+
+        let report = load_telemetry_report()?;
+        tokio::runtime::Runtime::new()?
+            .block_on(TelemetryClient::new()?.send(endpoint, &report))?;
+        config_service.update_config(|config| config.telemetry.last_sent_on = Some(today))?;
+        config_service.save()
+    });
+    if let Err(e) = result {
+        log::warn!("Failed to send telemetry: {}", e);
+    }
+}
+
+fn status_line(config: &TelemetryConfig) -> String {
+    match (config.enabled, config.endpoint.as_deref()) {
+        (false, _) => {
+            "Telemetry is disabled. Enable it with: gittype telemetry enable --endpoint <URL>"
+                .to_string()
+        }
+        (true, None) => {
+            "Telemetry is enabled, but no endpoint is configured, so nothing is sent. Set one with: gittype telemetry enable --endpoint <URL>"
+                .to_string()
+        }
+        (true, Some(endpoint)) => format!(
+            "Telemetry is enabled; the report is sent to {} at most once a day.",
+            endpoint
+        ),
+    }
+}
+
+fn load_config_service() -> Result<ConfigService> {
+    ConfigService::new(Arc::new(FileStorage::new()))
+}
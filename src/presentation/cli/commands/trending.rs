@@ -86,6 +86,13 @@ pub fn run_trending(
             repo_path: None,
             repo: Some(repo_url),
             langs: None,
+            commit: None,
+            package: None,
+            follow_symlinks: false,
+            prose: false,
+            summary_only: false,
+            broadcast: None,
+            bots: None,
             command: None,
         };
         return run_game_session(cli);
@@ -113,6 +120,13 @@ pub fn run_trending(
                 repo_path: None,
                 repo: Some(repo_url),
                 langs: None,
+                commit: None,
+                package: None,
+                follow_symlinks: false,
+                prose: false,
+                summary_only: false,
+                broadcast: None,
+                bots: None,
                 command: None,
             };
             return run_game_session(cli);
@@ -157,6 +171,13 @@ pub fn run_trending(
                     repo_path: None,
                     repo: Some(repo_url),
                     langs: None,
+                    commit: None,
+                    package: None,
+                    follow_symlinks: false,
+                    prose: false,
+                    summary_only: false,
+                    broadcast: None,
+                    bots: None,
                     command: None,
                 };
                 return run_game_session(cli);
@@ -0,0 +1,15 @@
+use crate::domain::models::ResultVerification;
+use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::Result;
+
+/// Check a verification code from a shared result and print the figures it vouches for
+pub fn run_verify(code: &str) -> Result<()> {
+    let console = ConsoleImpl::new();
+    let verification = ResultVerification::parse(code)?;
+
+    console.println("✅ Valid gittype result")?;
+    console.println(&format!("   Score:    {}", verification.score))?;
+    console.println(&format!("   CPM:      {}", verification.cpm))?;
+    console.println(&format!("   Mistakes: {}", verification.mistakes))?;
+    console.println("   Compare these with the shared text; any difference means it was edited.")
+}
@@ -0,0 +1,242 @@
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crossterm::{
+    cursor::MoveToPreviousLine,
+    queue,
+    terminal::{self, Clear, ClearType},
+};
+use shaku::HasComponent;
+
+use crate::domain::models::loading::{ExecutionContext, ProgressReporter, StepManager, StepType};
+use crate::domain::models::{ExtractionOptions, WarmProgress};
+use crate::domain::repositories::challenge_repository::ChallengeRepositoryInterface;
+use crate::domain::stores::{ChallengeStore, ChallengeStoreInterface};
+use crate::infrastructure::console::{Console, ConsoleImpl};
+use crate::infrastructure::git::GitRepositoryRefParser;
+use crate::presentation::cli::commands::scan::apply_user_extraction_config;
+use crate::presentation::di::AppModule;
+use crate::{GitTypeError, Result};
+
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Clone every repository listed in `file` and cache its challenges, up to
+/// `jobs` at a time, so later games in them start without network access or extraction
+pub fn run_warm(file: &Path, jobs: usize) -> Result<()> {
+    let console = ConsoleImpl::new();
+    let contents = std::fs::read_to_string(file)?;
+    let repositories = parse_repository_list(&contents)?;
+    if repositories.is_empty() {
+        return console.println(&format!("No repositories listed in {}", file.display()));
+    }
+
+    let progress = warm_list(&console, &repositories, jobs)?;
+    let total = repositories.len();
+    let failed = progress.failed_count();
+    match failed {
+        0 => Ok(()),
+        _ => Err(GitTypeError::ValidationError(format!(
+            "{} of {} repositories could not be warmed",
+            failed, total
+        ))),
+    }
+}
+
+/// Clone and cache `repositories`, `jobs` at a time, and print how many made it
+pub fn warm_list(
+    console: &impl Console,
+    repositories: &[String],
+    jobs: usize,
+) -> Result<WarmProgress> {
+    apply_user_extraction_config(console)?;
+    let container = AppModule::builder().build();
+    let challenge_repository: Arc<dyn ChallengeRepositoryInterface> = container.resolve();
+
+    let progress = Mutex::new(WarmProgress::new(repositories));
+    let interactive = atty::is(atty::Stream::Stdout);
+    warm_repositories(
+        repositories,
+        jobs,
+        &challenge_repository,
+        &progress,
+        interactive,
+    )?;
+
+    let progress = progress.into_inner().unwrap();
+    console.println(&format!(
+        "Warmed {} of {} repositories.",
+        repositories.len() - progress.failed_count(),
+        repositories.len()
+    ))?;
+    Ok(progress)
+}
+
+/// Repository specs of a list file, one per line; blank lines and lines
+/// starting with `#` are skipped. Every spec is checked before anything is cloned.
+pub fn parse_repository_list(contents: &str) -> Result<Vec<String>> {
+    let repositories: Vec<(usize, &str)> = contents
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+
+    let invalid: Vec<String> = repositories
+        .iter()
+        .filter(|(_, spec)| GitRepositoryRefParser::parse(spec).is_err())
+        .map(|(line, spec)| format!("line {}: {}", line, spec))
+        .collect();
+    if !invalid.is_empty() {
+        return Err(GitTypeError::ValidationError(format!(
+            "Unsupported repository format ({})",
+            invalid.join(", ")
+        )));
+    }
+
+    Ok(repositories
+        .into_iter()
+        .map(|(_, spec)| spec.to_string())
+        .collect())
+}
+
+/// Run the headless loading steps for every repository on `jobs` worker threads,
+/// redrawing the progress table in place when stdout is a terminal
+fn warm_repositories(
+    repositories: &[String],
+    jobs: usize,
+    challenge_repository: &Arc<dyn ChallengeRepositoryInterface>,
+    progress: &Mutex<WarmProgress>,
+    interactive: bool,
+) -> Result<()> {
+    let options = ExtractionOptions::default();
+    let next = AtomicUsize::new(0);
+    let mut stdout = std::io::stdout();
+
+    std::thread::scope(|scope| -> Result<()> {
+        let workers: Vec<_> = (0..jobs.clamp(1, repositories.len()))
+            .map(|_| {
+                scope.spawn(|| loop {
+                    let index = next.fetch_add(1, Ordering::SeqCst);
+                    let Some(repo_spec) = repositories.get(index) else {
+                        break;
+                    };
+                    warm_repository(index, repo_spec, &options, challenge_repository, progress);
+                })
+            })
+            .collect();
+
+        let width = interactive
+            .then(|| terminal::size().ok())
+            .flatten()
+            .map_or(usize::MAX, |(columns, _)| columns as usize);
+        let mut drawn = 0;
+        while interactive && workers.iter().any(|worker| !worker.is_finished()) {
+            drawn = draw_table(&mut stdout, &progress.lock().unwrap(), width, drawn)?;
+            std::thread::sleep(REDRAW_INTERVAL);
+        }
+        workers.into_iter().for_each(|worker| {
+            let _ = worker.join();
+        });
+        draw_table(&mut stdout, &progress.lock().unwrap(), width, drawn)?;
+        Ok(())
+    })
+}
+
+fn warm_repository(
+    index: usize,
+    repo_spec: &str,
+    options: &ExtractionOptions,
+    challenge_repository: &Arc<dyn ChallengeRepositoryInterface>,
+    progress: &Mutex<WarmProgress>,
+) {
+    let reporter = RowReporter { index, progress };
+    let challenge_store: Arc<dyn ChallengeStoreInterface> = Arc::new(ChallengeStore::default());
+    let mut context = ExecutionContext {
+        repo_spec: Some(repo_spec),
+        repo_path: None,
+        extraction_options: Some(options),
+        loading_screen: None,
+        progress_reporter: Some(&reporter),
+        challenge_repository: Some(Arc::clone(challenge_repository)),
+        challenge_flag_service: None,
+        current_repo_path: None,
+        git_repository: None,
+        scanned_files: None,
+        chunks: None,
+        cache_used: false,
+        challenge_store: Some(Arc::clone(&challenge_store)),
+        repository_store: None,
+        session_store: None,
+        stage_repository: None,
+        session_manager: None,
+    };
+
+    let result = StepManager::headless().execute_pipeline(&mut context);
+    let mut progress = progress.lock().unwrap();
+    match result {
+        Ok(()) => progress.finish(
+            index,
+            challenge_store.get_challenges().map_or(0, |c| c.len()),
+            context.cache_used,
+        ),
+        Err(e) => {
+            log::warn!("Failed to warm {}: {}", repo_spec, e);
+            progress.fail(index, &e.to_string());
+        }
+    }
+}
+
+/// Replace the `drawn` lines printed last with the current table; returns the lines printed
+fn draw_table(
+    stdout: &mut impl Write,
+    progress: &WarmProgress,
+    width: usize,
+    drawn: usize,
+) -> Result<usize> {
+    let lines = progress.render(width);
+    if drawn > 0 {
+        queue!(
+            stdout,
+            MoveToPreviousLine(drawn as u16),
+            Clear(ClearType::FromCursorDown)
+        )?;
+    }
+    for line in &lines {
+        writeln!(stdout, "{}", line)?;
+    }
+    stdout.flush()?;
+    Ok(lines.len())
+}
+
+/// Forwards the loading steps' progress of one repository to its table row
+struct RowReporter<'a> {
+    index: usize,
+    progress: &'a Mutex<WarmProgress>,
+}
+
+impl ProgressReporter for RowReporter<'_> {
+    fn set_step(&self, step_type: StepType) {
+        self.progress
+            .lock()
+            .unwrap()
+            .set_step(self.index, step_type);
+    }
+
+    fn set_current_file(&self, _file: Option<String>) {}
+
+    fn set_file_counts(
+        &self,
+        step_type: StepType,
+        processed: usize,
+        total: usize,
+        _current_file: Option<String>,
+    ) {
+        self.progress
+            .lock()
+            .unwrap()
+            .set_progress(self.index, step_type, processed, total);
+    }
+}
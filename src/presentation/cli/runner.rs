@@ -1,37 +1,130 @@
 use crate::domain::repositories::challenge_repository::ChallengeRepositoryInterface;
+use crate::domain::services::config_service::{ConfigService, ConfigServiceInterface};
+use crate::infrastructure::http::Network;
 use crate::infrastructure::logging::{setup_console_logging, setup_logging};
-use crate::presentation::cli::args::{CacheCommands, RepoCommands};
+use crate::infrastructure::storage::file_storage::FileStorage;
+use crate::presentation::cli::args::{
+    CacheCommands, HooksCommands, PackCommands, RepoCommands, TelemetryCommands,
+};
+use crate::presentation::cli::commands::export::ExportScope;
 use crate::presentation::cli::commands::{
-    run_export, run_game_session, run_history, run_repo_clear, run_repo_list, run_repo_play,
-    run_stats, run_trending,
+    run_demo, run_export, run_game_session, run_gc, run_history, run_hooks_install,
+    run_hooks_uninstall, run_import, run_languages, run_logs, run_pack_create, run_pack_play,
+    run_purge, run_remind, run_repo_clear, run_repo_dismissed, run_repo_list, run_repo_play,
+    run_repo_restore, run_scan, run_serve, run_snippets, run_spectate, run_stats, run_status,
+    run_telemetry_disable, run_telemetry_enable, run_telemetry_show, run_trending, run_verify,
+    run_warm,
 };
 use crate::presentation::cli::{Cli, Commands};
 use crate::presentation::di::AppModule;
 use crate::{GitTypeError, Result};
 use shaku::HasComponent;
 
+use std::sync::Arc;
+
 pub fn run_cli(cli: Cli) -> Result<()> {
-    if let Err(e) = setup_logging() {
+    // Listing languages, reading or deleting logs, or a scheduled reminder must not
+    // start a new log file
+    let skip_file_logging = matches!(
+        cli.command,
+        Some(
+            Commands::Languages
+                | Commands::Gc
+                | Commands::Logs { .. }
+                | Commands::Purge { .. }
+                | Commands::Remind { notify: true, .. }
+        )
+    );
+    if skip_file_logging {
+        setup_console_logging();
+    } else if let Err(e) = setup_logging() {
         setup_console_logging();
         eprintln!("⚠️ Warning: Failed to setup file logging: {}", e);
         eprintln!("   Logs will only be shown in console.");
     }
 
+    // Proxy and CA bundle settings apply to every command that goes online
+    match ConfigService::new(Arc::new(FileStorage::new())) {
+        Ok(config_service) => Network::set_config(config_service.get_config().network),
+        Err(e) => log::warn!("Failed to load network settings: {}", e),
+    }
+
     match &cli.command {
         Some(Commands::History) => run_history(),
         Some(Commands::Stats) => run_stats(),
-        Some(Commands::Export { format, output }) => run_export(format.clone(), output.clone()),
+        Some(Commands::Status { format }) => run_status(format),
+        Some(Commands::Export {
+            format,
+            output,
+            repo,
+            prometheus,
+            since,
+            until,
+        }) => run_export(
+            format.clone(),
+            output.clone(),
+            prometheus.clone(),
+            ExportScope {
+                repo: repo.clone(),
+                since: *since,
+                until: *until,
+            },
+        ),
+        Some(Commands::Import { file, source }) => run_import(file, source.as_deref()),
         Some(Commands::Cache { cache_command }) => {
             let module = AppModule::builder().build();
             let challenge_repository: &dyn ChallengeRepositoryInterface = module.resolve_ref();
             run_cache_command(cache_command, challenge_repository)
         }
         Some(Commands::Repo { repo_command }) => run_repo_command(repo_command),
+        Some(Commands::Hooks { hooks_command }) => run_hooks_command(hooks_command),
+        Some(Commands::Serve { stdio, api }) => run_serve(*stdio, api.as_deref()),
+        Some(Commands::Spectate { address }) => run_spectate(address),
+        Some(Commands::Scan {
+            path,
+            langs,
+            follow_symlinks,
+            dry_run,
+        }) => run_scan(path.clone(), langs.clone(), *follow_symlinks, *dry_run),
+        Some(Commands::Demo { path, idle }) => {
+            let path = path.clone();
+            let idle = *idle;
+            run_demo(cli, path, idle)
+        }
+        Some(Commands::Snippets { dir }) => {
+            let dir = dir.clone();
+            run_snippets(cli, dir)
+        }
+        Some(Commands::Pack { pack_command }) => match pack_command {
+            PackCommands::Create {
+                path,
+                out,
+                stages,
+                difficulty,
+                seed,
+            } => run_pack_create(path, out, *stages, difficulty, *seed),
+            PackCommands::Play { file } => {
+                let file = file.clone();
+                run_pack_play(cli, file)
+            }
+        },
+        Some(Commands::Languages) => run_languages(),
+        Some(Commands::Gc) => {
+            let module = AppModule::builder().build();
+            let challenge_repository: &dyn ChallengeRepositoryInterface = module.resolve_ref();
+            run_gc(challenge_repository)
+        }
+        Some(Commands::Logs { tail }) => run_logs(*tail),
+        Some(Commands::Purge { targets, yes }) => run_purge(targets, *yes),
+        Some(Commands::Remind { at, notify }) => run_remind(at.as_deref(), *notify),
+        Some(Commands::Telemetry { telemetry_command }) => run_telemetry_command(telemetry_command),
         Some(Commands::Trending {
             language,
             repo_name,
             period,
         }) => run_trending(language.clone(), repo_name.clone(), period.clone()),
+        Some(Commands::Verify { code }) => run_verify(code),
+        Some(Commands::Warm { file, jobs }) => run_warm(file, *jobs as usize),
         None => run_game_session(cli),
     }
 }
@@ -113,5 +206,22 @@ fn run_repo_command(repo_command: &RepoCommands) -> Result<()> {
         RepoCommands::List => run_repo_list(),
         RepoCommands::Clear { force } => run_repo_clear(*force),
         RepoCommands::Play => run_repo_play(),
+        RepoCommands::Dismissed => run_repo_dismissed(),
+        RepoCommands::Restore { name } => run_repo_restore(name),
+    }
+}
+
+fn run_hooks_command(hooks_command: &HooksCommands) -> Result<()> {
+    match hooks_command {
+        HooksCommands::Install { force } => run_hooks_install(*force),
+        HooksCommands::Uninstall => run_hooks_uninstall(),
+    }
+}
+
+fn run_telemetry_command(telemetry_command: &TelemetryCommands) -> Result<()> {
+    match telemetry_command {
+        TelemetryCommands::Show => run_telemetry_show(),
+        TelemetryCommands::Enable { endpoint } => run_telemetry_enable(endpoint.as_deref()),
+        TelemetryCommands::Disable => run_telemetry_disable(),
     }
 }
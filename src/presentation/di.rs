@@ -1,34 +1,52 @@
 use crate::domain::events::EventBus;
 use crate::domain::repositories::challenge_repository::ChallengeRepository;
 use crate::domain::repositories::git_repository_repository::GitRepositoryRepository;
+use crate::domain::repositories::repository_metadata_repository::RepositoryMetadataRepository;
 use crate::domain::repositories::session_repository::SessionRepository;
 use crate::domain::repositories::stage_repository::StageRepository as DomainStageRepository;
+use crate::domain::repositories::starred_repository::StarredRepository;
 use crate::domain::repositories::trending_repository::TrendingRepository;
 use crate::domain::repositories::version_repository::VersionRepository;
+use crate::domain::services::achievement_service::AchievementService;
 use crate::domain::services::analytics_service::AnalyticsService;
+use crate::domain::services::challenge_flag_service::ChallengeFlagService;
 use crate::domain::services::config_service::ConfigService;
+use crate::domain::services::difficulty_gate_service::DifficultyGateService;
+use crate::domain::services::discord_presence_service::DiscordPresenceService;
+use crate::domain::services::dismissed_repository_service::DismissedRepositoryService;
+use crate::domain::services::keymap_service::KeymapService;
+use crate::domain::services::profile_service::ProfileService;
+use crate::domain::services::repository_detail_service::RepositoryDetailService;
+use crate::domain::services::repository_rating_service::RepositoryRatingService;
 use crate::domain::services::repository_service::RepositoryService;
 use crate::domain::services::scoring::{SessionTracker, TotalTracker};
 use crate::domain::services::session_manager_service::SessionManager;
 use crate::domain::services::session_service::SessionService;
 use crate::domain::services::stage_builder_service::StageRepository as StageBuilderRepository;
+use crate::domain::services::streamer_stats_service::StreamerStatsService;
 use crate::domain::services::theme_service::ThemeService;
 use crate::domain::services::version_service::VersionService;
 use crate::domain::stores::{ChallengeStore, RepositoryStore, SessionStore};
-use crate::infrastructure::database::daos::{ChallengeDao, RepositoryDao, SessionDao, StageDao};
+use crate::infrastructure::database::daos::{
+    AchievementDao, BaselineDao, ChallengeDao, ChallengeFlagDao, DifficultyUnlockDao,
+    DismissedRepositoryDao, RepositoryDao, RepositoryRatingDao, SessionDao, StageDao,
+};
 use crate::infrastructure::database::database::Database;
+use crate::infrastructure::database::storage_backend::SqliteBackend;
 use crate::infrastructure::http::github_api_client::GitHubApiClientFactoryImpl;
 use crate::infrastructure::http::oss_insight_client::OssInsightClient;
 use crate::infrastructure::storage::compressed_file_storage::CompressedFileStorage;
 use crate::infrastructure::storage::file_storage::FileStorage;
 use crate::infrastructure::terminal::TerminalComponent;
 use crate::presentation::tui::screens::{
-    AnalyticsScreen, AnimationScreen, HelpScreen, InfoDialogScreen, LoadingScreen, PanicScreen,
-    RecordsScreen, RepoListScreen, RepoPlayScreen, SessionDetailScreen, SessionDetailsDialog,
-    SessionFailureScreen, SessionSummaryScreen, SessionSummaryShareScreen, SettingsScreen,
-    StageSummaryScreen, TitleScreen, TotalSummaryScreen, TotalSummaryShareScreen,
-    TrendingLanguageSelectionScreen, TrendingRepositorySelectionScreen, TypingScreen,
-    VersionCheckScreen,
+    AchievementsScreen, AnalyticsScreen, AnimationScreen, ErrorScreen, HelpScreen,
+    InfoDialogScreen, KeyboardTestScreen, LeaderboardScreen, LoadingScreen, LogViewerScreen,
+    PanicScreen, ProfileScreen, RecordsScreen, ReminderScreen, RepoDetailScreen, RepoListScreen,
+    RepoPlayScreen, SessionDetailScreen, SessionDetailsDialog, SessionFailureScreen,
+    SessionSummaryScreen, SessionSummaryShareScreen, SettingsScreen, StageSummaryScreen,
+    TitleScreen, TotalSummaryScreen, TotalSummaryShareScreen, TrendingLanguageSelectionScreen,
+    TrendingRepositorySelectionScreen, TutorialScreen, TypingScreen, VersionCheckScreen,
+    WhatsNewScreen, WorkspacePackageScreen,
 };
 use crate::presentation::tui::ScreenManagerFactoryImpl;
 
@@ -40,8 +58,15 @@ shaku::module! {
             OssInsightClient,
             GitHubApiClientFactoryImpl,
             Database,
+            SqliteBackend,
+            AchievementDao,
+            BaselineDao,
             ChallengeDao,
+            ChallengeFlagDao,
+            DifficultyUnlockDao,
+            DismissedRepositoryDao,
             RepositoryDao,
+            RepositoryRatingDao,
             SessionDao,
             StageDao,
             EventBus,
@@ -50,9 +75,11 @@ shaku::module! {
             RepositoryStore,
             SessionStore,
             GitRepositoryRepository,
+            RepositoryMetadataRepository,
             SessionRepository,
             DomainStageRepository,
             ChallengeRepository,
+            StarredRepository,
             TrendingRepository,
             VersionRepository,
             SessionService,
@@ -60,10 +87,20 @@ shaku::module! {
             SessionTracker,
             TotalTracker,
             StageBuilderRepository,
+            AchievementService,
             AnalyticsService,
+            ChallengeFlagService,
+            ProfileService,
+            KeymapService,
             RepositoryService,
+            RepositoryDetailService,
+            RepositoryRatingService,
             VersionService,
             ConfigService,
+            DifficultyGateService,
+            DiscordPresenceService,
+            DismissedRepositoryService,
+            StreamerStatsService,
             ThemeService,
             ScreenManagerFactoryImpl,
             TitleScreen,
@@ -71,13 +108,21 @@ shaku::module! {
             AnimationScreen,
             HelpScreen,
             LoadingScreen,
+            ErrorScreen,
             PanicScreen,
             SessionFailureScreen,
             InfoDialogScreen,
+            KeyboardTestScreen,
+            LeaderboardScreen,
             SessionDetailsDialog,
             StageSummaryScreen,
+            AchievementsScreen,
+            ProfileScreen,
             AnalyticsScreen,
             RecordsScreen,
+            LogViewerScreen,
+            ReminderScreen,
+            RepoDetailScreen,
             RepoListScreen,
             RepoPlayScreen,
             SessionDetailScreen,
@@ -88,7 +133,10 @@ shaku::module! {
             TotalSummaryShareScreen,
             TrendingLanguageSelectionScreen,
             TrendingRepositorySelectionScreen,
-            VersionCheckScreen
+            TutorialScreen,
+            VersionCheckScreen,
+            WhatsNewScreen,
+            WorkspacePackageScreen
         ],
         providers = []
     }
@@ -1,6 +1,9 @@
+pub mod api;
 pub mod cli;
 pub mod di;
+pub mod rpc;
 pub mod sharing;
 pub mod signal_handler;
+pub mod spectator;
 pub mod tui;
 pub mod ui;
@@ -0,0 +1,234 @@
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+use crate::domain::models::StageResult;
+use crate::domain::services::editor_session::EditorSession;
+use crate::presentation::rpc::protocol::{
+    RpcError, RpcRequest, RpcResponse, INVALID_PARAMS, INVALID_REQUEST, JSONRPC_VERSION,
+    METHOD_NOT_FOUND, PARSE_ERROR, SERVER_ERROR,
+};
+use crate::{GitTypeError, Result};
+
+pub const METHODS: &[&str] = &[
+    "initialize",
+    "challenge/load",
+    "challenge/start",
+    "challenge/input",
+    "challenge/finish",
+    "shutdown",
+    "exit",
+];
+
+/// Newline-delimited JSON-RPC server that lets editor plugins run a challenge on an open buffer
+#[derive(Default)]
+pub struct EditorServer {
+    session: EditorSession,
+    exited: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LoadParams {
+    text: Option<String>,
+    path: Option<String>,
+    language: Option<String>,
+    start_line: Option<usize>,
+    end_line: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InputParams {
+    text: String,
+}
+
+impl EditorServer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serve requests until `exit` or end of input
+    pub fn run<R: BufRead, W: Write>(&mut self, reader: R, mut writer: W) -> Result<()> {
+        for line in reader.lines() {
+            let line = line?;
+            if let Some(response) = self.handle_line(&line) {
+                writeln!(writer, "{}", response)?;
+                writer.flush()?;
+            }
+            if self.exited {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Handle one request line, returning the serialized response (none for notifications)
+    pub fn handle_line(&mut self, line: &str) -> Option<String> {
+        if line.trim().is_empty() {
+            return None;
+        }
+
+        let response = match serde_json::from_str::<RpcRequest>(line) {
+            Ok(request) => self.handle_request(request)?,
+            Err(e) => RpcResponse::failure(
+                Value::Null,
+                RpcError::new(PARSE_ERROR, format!("Parse error: {}", e)),
+            ),
+        };
+
+        serde_json::to_string(&response).ok()
+    }
+
+    pub fn has_exited(&self) -> bool {
+        self.exited
+    }
+
+    fn handle_request(&mut self, request: RpcRequest) -> Option<RpcResponse> {
+        if request
+            .jsonrpc
+            .as_deref()
+            .is_some_and(|version| version != JSONRPC_VERSION)
+        {
+            return Some(RpcResponse::failure(
+                request.id.unwrap_or(Value::Null),
+                RpcError::new(INVALID_REQUEST, "Unsupported JSON-RPC version"),
+            ));
+        }
+
+        let outcome = self.dispatch(&request.method, request.params);
+        let id = request.id?;
+
+        Some(match outcome {
+            Ok(result) => RpcResponse::success(id, result),
+            Err(error) => RpcResponse::failure(id, error),
+        })
+    }
+
+    fn dispatch(&mut self, method: &str, params: Value) -> std::result::Result<Value, RpcError> {
+        match method {
+            "initialize" => Ok(json!({
+                "name": "gittype",
+                "version": env!("CARGO_PKG_VERSION"),
+                "methods": METHODS,
+            })),
+            "challenge/load" => {
+                let params: LoadParams = Self::parse_params(params)?;
+                self.load(params).map_err(Self::server_error)
+            }
+            "challenge/start" => self
+                .session
+                .start()
+                .map(|_| json!({ "started": true }))
+                .map_err(Self::server_error),
+            "challenge/input" => {
+                let params: InputParams = Self::parse_params(params)?;
+                self.session
+                    .input(&params.text)
+                    .map(|progress| {
+                        json!({
+                            "position": progress.position,
+                            "total": progress.total,
+                            "mistakes": progress.mistakes,
+                            "completed": progress.completed,
+                        })
+                    })
+                    .map_err(Self::server_error)
+            }
+            "challenge/finish" => self
+                .session
+                .finish()
+                .map(|result| Self::result_json(&result))
+                .map_err(Self::server_error),
+            "shutdown" => Ok(Value::Null),
+            "exit" => {
+                self.exited = true;
+                Ok(Value::Null)
+            }
+            _ => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Method not found: {}", method),
+            )),
+        }
+    }
+
+    fn load(&mut self, params: LoadParams) -> Result<Value> {
+        let content = match (params.text, params.path.as_deref()) {
+            (Some(text), _) => text,
+            (None, Some(path)) => {
+                Self::read_lines(Path::new(path), params.start_line, params.end_line)?
+            }
+            (None, None) => {
+                return Err(GitTypeError::ValidationError(
+                    "Either `text` or `path` is required".to_string(),
+                ))
+            }
+        };
+
+        let challenge =
+            self.session
+                .load(&content, params.language.as_deref(), params.path.clone())?;
+
+        Ok(json!({
+            "textToType": challenge.text_to_type,
+            "language": challenge.language,
+            "sourcePath": challenge.source_path,
+            "characters": challenge.text_to_type.chars().count(),
+        }))
+    }
+
+    /// Read a 1-based, inclusive line range (the whole file when no range is given)
+    fn read_lines(
+        path: &Path,
+        start_line: Option<usize>,
+        end_line: Option<usize>,
+    ) -> Result<String> {
+        let content = std::fs::read_to_string(path)?;
+        if start_line.is_none() && end_line.is_none() {
+            return Ok(content);
+        }
+
+        let start = start_line.unwrap_or(1).max(1);
+        let end = end_line.unwrap_or(usize::MAX);
+        if end < start {
+            return Err(GitTypeError::ValidationError(format!(
+                "Invalid line range {}-{}",
+                start, end
+            )));
+        }
+
+        Ok(content
+            .lines()
+            .skip(start - 1)
+            .take(end - start + 1)
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    fn result_json(result: &StageResult) -> Value {
+        json!({
+            "cpm": result.cpm,
+            "wpm": result.wpm,
+            "accuracy": result.accuracy,
+            "keystrokes": result.keystrokes,
+            "mistakes": result.mistakes,
+            "durationMs": result.completion_time.as_millis() as u64,
+            "score": result.challenge_score,
+            "rank": result.rank_name,
+            "tier": result.tier_name,
+            "skipped": result.was_skipped,
+        })
+    }
+
+    fn parse_params<T: for<'de> Deserialize<'de>>(
+        params: Value,
+    ) -> std::result::Result<T, RpcError> {
+        serde_json::from_value(params)
+            .map_err(|e| RpcError::new(INVALID_PARAMS, format!("Invalid params: {}", e)))
+    }
+
+    fn server_error(error: GitTypeError) -> RpcError {
+        RpcError::new(SERVER_ERROR, error.to_string())
+    }
+}
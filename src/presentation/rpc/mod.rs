@@ -0,0 +1,5 @@
+pub mod editor_server;
+pub mod protocol;
+
+pub use editor_server::EditorServer;
+pub use protocol::{RpcError, RpcRequest, RpcResponse};
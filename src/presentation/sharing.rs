@@ -1,7 +1,8 @@
 use anyhow::Result;
 use crossterm::event::KeyCode;
 
-use crate::domain::models::{GitRepository, SessionResult};
+use crate::domain::models::storage::RepositoryMetadata;
+use crate::domain::models::{GitRepository, PlayerProfile, SessionResult, StreamerMode};
 use crate::domain::services::scoring::Rank;
 use crate::infrastructure::browser;
 use crate::presentation::ui::Colors;
@@ -36,8 +37,10 @@ impl SharingService {
         metrics: &SessionResult,
         platform: SharingPlatform,
         repo_info: &Option<GitRepository>,
+        repo_metadata: &Option<RepositoryMetadata>,
+        profile: &Option<PlayerProfile>,
     ) -> Result<()> {
-        let url = Self::generate_share_url(metrics, &platform, repo_info);
+        let url = Self::generate_share_url(metrics, &platform, repo_info, repo_metadata, profile);
 
         match Self::open_browser(&url) {
             Ok(()) => {
@@ -55,8 +58,10 @@ impl SharingService {
         metrics: &SessionResult,
         platform: &SharingPlatform,
         repo_info: &Option<GitRepository>,
+        repo_metadata: &Option<RepositoryMetadata>,
+        profile: &Option<PlayerProfile>,
     ) -> String {
-        let text = Self::create_share_text(metrics, repo_info);
+        let text = Self::create_share_text(metrics, repo_info, repo_metadata, profile);
 
         match platform {
             SharingPlatform::X => {
@@ -95,29 +100,65 @@ impl SharingService {
         }
     }
 
-    pub fn create_share_text(metrics: &SessionResult, repo_info: &Option<GitRepository>) -> String {
+    pub fn create_share_text(
+        metrics: &SessionResult,
+        repo_info: &Option<GitRepository>,
+        repo_metadata: &Option<RepositoryMetadata>,
+        profile: &Option<PlayerProfile>,
+    ) -> String {
         let best_rank = Rank::for_score(metrics.session_score);
-        if let Some(repo) = repo_info {
+        let signature = profile
+            .as_ref()
+            .map(|profile| format!("\n{}", profile.share_signature()))
+            .unwrap_or_default()
+            + &metrics
+                .verification_code
+                .as_ref()
+                .map(|code| format!("\nVerify: {}", code))
+                .or_else(|| {
+                    metrics
+                        .plausibility
+                        .label()
+                        .map(|label| format!("\n{}", label))
+                })
+                .unwrap_or_default();
+        if let Some(repo) = repo_info.as_ref().filter(|_| !StreamerMode::is_enabled()) {
             format!(
-                "Achieved \"{}\" with {:.0}pts on [{}/{}] in gittype! CPM: {:.0}, Mistakes: {} 🚀\n\nType your own code! https://github.com/unhappychoice/gittype\n\n#gittype #typing #coding",
+                "Achieved \"{}\" with {:.0}pts on [{}] in gittype! CPM: {:.0}, Mistakes: {} 🚀\n{}{}\n\nType your own code! https://github.com/unhappychoice/gittype\n\n#gittype #typing #coding",
                 best_rank.name(),
                 metrics.session_score,
-                repo.user_name,
-                repo.repository_name,
+                Self::repository_label(repo, repo_metadata),
                 metrics.overall_cpm,
-                metrics.valid_mistakes + metrics.invalid_mistakes
+                metrics.valid_mistakes + metrics.invalid_mistakes,
+                repo.attribution(),
+                signature
             )
         } else {
             format!(
-                "Achieved \"{}\" with {:.0}pts in gittype! CPM: {:.0}, Mistakes: {} 🚀\n\nType your own code! https://github.com/unhappychoice/gittype\n\n#gittype #typing #coding",
+                "Achieved \"{}\" with {:.0}pts in gittype! CPM: {:.0}, Mistakes: {} 🚀{}\n\nType your own code! https://github.com/unhappychoice/gittype\n\n#gittype #typing #coding",
                 best_rank.name(),
                 metrics.session_score,
                 metrics.overall_cpm,
-                metrics.valid_mistakes + metrics.invalid_mistakes
+                metrics.valid_mistakes + metrics.invalid_mistakes,
+                signature
             )
         }
     }
 
+    fn repository_label(
+        repo: &GitRepository,
+        repo_metadata: &Option<RepositoryMetadata>,
+    ) -> String {
+        let name = match repo.version_label() {
+            Some(version) => format!("{}/{} ({})", repo.user_name, repo.repository_name, version),
+            None => format!("{}/{}", repo.user_name, repo.repository_name),
+        };
+        match repo_metadata {
+            Some(metadata) => format!("{} {}", name, metadata.summary()),
+            None => name,
+        }
+    }
+
     fn open_browser(url: &str) -> Result<()> {
         browser::open_url(url).map_err(|e| anyhow::anyhow!("Failed to open browser: {}", e))
     }
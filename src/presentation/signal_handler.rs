@@ -1,4 +1,5 @@
 use crossterm::cursor::{Hide, Show};
+use crossterm::event::{DisableBracketedPaste, DisableFocusChange};
 use crossterm::execute;
 use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen,
@@ -11,20 +12,31 @@ use std::sync::{Arc, Mutex};
 
 use crate::domain::events::presentation_events::ExitRequested;
 use crate::domain::events::EventBus;
-use crate::infrastructure::logging::{log_error_to_file, log_panic_to_file};
+use crate::domain::services::session_manager_service::{SessionManager, SessionManagerInterface};
+use crate::infrastructure::logging::{
+    log_error_to_file, log_panic_to_file, record_crash_signature,
+};
 use crate::presentation::tui::screens::PanicScreen;
 use crate::presentation::tui::{Screen, ScreenManagerImpl};
 use crate::GitTypeError;
 
+/// Install the panic hook and a handler for SIGINT, SIGTERM and SIGHUP
 pub fn setup_signal_handlers(
     screen_manager: Arc<Mutex<ScreenManagerImpl<CrosstermBackend<std::io::Stdout>>>>,
+    session_manager: Arc<dyn SessionManagerInterface>,
 ) {
     let manager_for_panic = screen_manager.clone();
 
     std::panic::set_hook(Box::new(move |panic_info| {
         // Restore terminal to normal state
         let _ = disable_raw_mode();
-        let _ = execute!(std::io::stderr(), LeaveAlternateScreen, Show);
+        let _ = execute!(
+            std::io::stderr(),
+            DisableBracketedPaste,
+            DisableFocusChange,
+            LeaveAlternateScreen,
+            Show
+        );
 
         // Get panic message
         let message = if let Some(s) = panic_info.payload().downcast_ref::<&str>() {
@@ -50,6 +62,7 @@ pub fn setup_signal_handlers(
 
         // Log panic information to file
         log_panic_to_file(panic_info);
+        record_crash_signature(panic_info);
 
         // Also log with our error type for consistency
         let error = GitTypeError::PanicError(full_message.clone());
@@ -70,9 +83,14 @@ pub fn setup_signal_handlers(
     }));
 
     ctrlc::set_handler(move || {
-        // Get EventBus from ScreenManager and publish ExitRequested event
+        // Save first: after SIGHUP the terminal is gone and the process may be
+        // killed before the exit path below finishes
+        persist_interrupted_session(&session_manager);
+
+        // The game loop holds the ScreenManager for the whole session, so
+        // don't wait on it
         screen_manager
-            .lock()
+            .try_lock()
             .ok()
             .map(|manager| {
                 manager
@@ -86,7 +104,16 @@ pub fn setup_signal_handlers(
                 std::process::exit(0);
             });
     })
-    .expect("Error setting Ctrl-C handler");
+    .expect("Error setting signal handler");
+}
+
+/// Record whatever was played of the current session before exiting
+pub fn persist_interrupted_session(session_manager: &Arc<dyn SessionManagerInterface>) {
+    if let Some(manager) = session_manager.as_any().downcast_ref::<SessionManager>() {
+        if let Err(e) = manager.persist_interrupted_session() {
+            log::error!("Failed to save interrupted session: {}", e);
+        }
+    }
 }
 
 /// Show panic screen using the PanicScreen component with ratatui
@@ -0,0 +1,133 @@
+use ratatui::buffer::Buffer;
+
+use std::io::Write;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::presentation::spectator::SpectatorFrame;
+use crate::{GitTypeError, Result};
+
+/// Spectators don't need the typing screen's 30 FPS
+const MIN_FRAME_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A spectator that stops reading is dropped rather than stalling the game
+const WRITE_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Sends frames to every connected spectator; the latest frame goes to newcomers
+/// right away so they don't wait for the next keystroke
+pub struct SpectatorBroadcaster {
+    local_addr: SocketAddr,
+    clients: Arc<Mutex<Vec<TcpStream>>>,
+    last_line: Arc<Mutex<Option<String>>>,
+    last_sent_at: Mutex<Option<Instant>>,
+}
+
+impl SpectatorBroadcaster {
+    /// Listens on `address` and accepts spectators on a background thread
+    pub fn bind(address: &str) -> Result<Self> {
+        let listener = TcpListener::bind(address).map_err(|e| {
+            GitTypeError::ValidationError(format!(
+                "Failed to start the broadcast on {}: {}",
+                address, e
+            ))
+        })?;
+        let local_addr = listener.local_addr()?;
+        let clients = Arc::new(Mutex::new(Vec::new()));
+        let last_line = Arc::new(Mutex::new(None::<String>));
+
+        let accepted = Arc::clone(&clients);
+        let latest = Arc::clone(&last_line);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+                if stream.set_write_timeout(Some(WRITE_TIMEOUT)).is_err() {
+                    continue;
+                }
+                if let Some(line) = latest.lock().unwrap().as_deref() {
+                    if stream.write_all(line.as_bytes()).is_err() {
+                        continue;
+                    }
+                }
+                log::info!("Spectator connected from {:?}", stream.peer_addr());
+                accepted.lock().unwrap().push(stream);
+            }
+        });
+
+        Ok(Self {
+            local_addr,
+            clients,
+            last_line,
+            last_sent_at: Mutex::new(None),
+        })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    pub fn spectator_count(&self) -> usize {
+        self.clients.lock().unwrap().len()
+    }
+
+    /// Whether enough time has passed since the last frame to send another
+    pub fn is_due(&self) -> bool {
+        self.last_sent_at
+            .lock()
+            .unwrap()
+            .is_none_or(|sent| sent.elapsed() >= MIN_FRAME_INTERVAL)
+    }
+
+    /// Sends the frame unless it is unchanged or comes too soon after the last one
+    pub fn publish(&self, frame: &SpectatorFrame) -> Result<()> {
+        if !self.is_due() {
+            return Ok(());
+        }
+
+        let line = frame.to_json_line()?;
+        let mut last_line = self.last_line.lock().unwrap();
+        if last_line.as_deref() == Some(line.as_str()) {
+            return Ok(());
+        }
+
+        self.clients
+            .lock()
+            .unwrap()
+            .retain_mut(|client| client.write_all(line.as_bytes()).is_ok());
+        *last_line = Some(line);
+        *self.last_sent_at.lock().unwrap() = Some(Instant::now());
+        Ok(())
+    }
+}
+
+static BROADCAST: Mutex<Option<SpectatorBroadcaster>> = Mutex::new(None);
+
+/// The broadcast of this game, if it was started with `--broadcast`
+pub struct SpectatorBroadcast;
+
+impl SpectatorBroadcast {
+    pub fn start(address: &str) -> Result<SocketAddr> {
+        let broadcaster = SpectatorBroadcaster::bind(address)?;
+        let local_addr = broadcaster.local_addr();
+        *BROADCAST.lock().unwrap() = Some(broadcaster);
+        Ok(local_addr)
+    }
+
+    pub fn is_active() -> bool {
+        BROADCAST.lock().unwrap().is_some()
+    }
+
+    /// Mirrors a rendered frame to spectators; failures are only logged
+    pub fn publish_buffer(buffer: &Buffer) {
+        let broadcast = BROADCAST.lock().unwrap();
+        let Some(broadcaster) = broadcast.as_ref().filter(|b| b.is_due()) else {
+            return;
+        };
+        if let Err(e) = broadcaster.publish(&SpectatorFrame::capture(buffer)) {
+            log::warn!("Failed to broadcast frame: {}", e);
+        }
+    }
+}
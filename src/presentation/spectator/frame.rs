@@ -0,0 +1,84 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+};
+use serde::{Deserialize, Serialize};
+
+use std::str::FromStr;
+
+use crate::presentation::ui::ScreenCapture;
+use crate::Result;
+
+/// Text drawn in one style; colors use ratatui's names (`Reset`, `Red`, `#1e1e1e`, `42`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StyledRun {
+    pub fg: String,
+    pub bg: String,
+    /// `Modifier` bits
+    pub modifier: u16,
+    pub text: String,
+}
+
+/// One rendered typing screen as sent to spectators, one line of JSON per frame
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpectatorFrame {
+    pub width: u16,
+    pub height: u16,
+    /// Runs per row, without trailing blank cells
+    pub rows: Vec<Vec<StyledRun>>,
+}
+
+impl SpectatorFrame {
+    pub fn capture(buffer: &Buffer) -> Self {
+        let rows = ScreenCapture::styled_rows(buffer)
+            .into_iter()
+            .map(|runs| {
+                runs.into_iter()
+                    .map(|((fg, bg, modifier), text)| StyledRun {
+                        fg: fg.to_string(),
+                        bg: bg.to_string(),
+                        modifier: modifier.bits(),
+                        text,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            width: buffer.area.width,
+            height: buffer.area.height,
+            rows,
+        }
+    }
+
+    pub fn to_json_line(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)? + "\n")
+    }
+
+    pub fn from_json_line(line: &str) -> Result<Self> {
+        Ok(serde_json::from_str(line.trim_end())?)
+    }
+
+    /// Draws the frame from the top-left of `area`, clipping what doesn't fit
+    pub fn render(&self, buffer: &mut Buffer, area: Rect) {
+        for (row, runs) in self.rows.iter().enumerate().take(area.height as usize) {
+            let y = area.y + row as u16;
+            let mut x = area.x;
+            for run in runs {
+                let remaining = area.right().saturating_sub(x) as usize;
+                if remaining == 0 {
+                    break;
+                }
+                (x, _) = buffer.set_stringn(x, y, &run.text, remaining, Self::style(run));
+            }
+        }
+    }
+
+    fn style(run: &StyledRun) -> Style {
+        Style::default()
+            .fg(Color::from_str(&run.fg).unwrap_or(Color::Reset))
+            .bg(Color::from_str(&run.bg).unwrap_or(Color::Reset))
+            .add_modifier(Modifier::from_bits_truncate(run.modifier))
+    }
+}
@@ -0,0 +1,10 @@
+pub mod broadcaster;
+pub mod frame;
+pub mod viewer;
+
+pub use broadcaster::{SpectatorBroadcast, SpectatorBroadcaster};
+pub use frame::{SpectatorFrame, StyledRun};
+pub use viewer::SpectatorViewer;
+
+/// Where `--broadcast` listens and `gittype spectate` connects by default
+pub const DEFAULT_SPECTATE_ADDRESS: &str = "127.0.0.1:7879";
@@ -0,0 +1,147 @@
+use crossterm::cursor::{Hide, Show};
+use crossterm::event::{poll, read, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    widgets::Paragraph,
+    Frame, Terminal,
+};
+
+use std::io::{stdout, BufRead, BufReader};
+use std::net::TcpStream;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::Duration;
+
+use crate::presentation::spectator::SpectatorFrame;
+use crate::{GitTypeError, Result};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Read-only mirror of another player's typing screen
+#[derive(Debug, Default)]
+pub struct SpectatorViewer {
+    frame: Option<SpectatorFrame>,
+    ended: bool,
+}
+
+impl SpectatorViewer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Connects to a game started with `--broadcast` and shows it until a key
+    /// is pressed or the game ends
+    pub fn run(address: &str) -> Result<()> {
+        let stream = TcpStream::connect(address).map_err(|e| {
+            GitTypeError::ValidationError(format!(
+                "Failed to connect to a broadcast on {}: {}",
+                address, e
+            ))
+        })?;
+        let lines = Self::spawn_reader(stream);
+
+        enable_raw_mode().map_err(|e| {
+            GitTypeError::TerminalError(format!("Failed to enable raw mode: {}", e))
+        })?;
+        execute!(stdout(), EnterAlternateScreen, Hide).map_err(|e| {
+            GitTypeError::TerminalError(format!("Failed to enter alternate screen: {}", e))
+        })?;
+
+        let result = Self::new().event_loop(&lines);
+
+        let _ = execute!(stdout(), LeaveAlternateScreen, Show);
+        let _ = disable_raw_mode();
+        result
+    }
+
+    /// Frame lines from the broadcast; the channel closes when the game ends
+    fn spawn_reader(stream: TcpStream) -> Receiver<String> {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stream).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        receiver
+    }
+
+    fn event_loop(&mut self, lines: &Receiver<String>) -> Result<()> {
+        let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+        loop {
+            loop {
+                match lines.try_recv() {
+                    Ok(line) => self.handle_line(&line),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        self.ended = true;
+                        break;
+                    }
+                }
+            }
+
+            terminal.draw(|frame| self.render(frame))?;
+
+            if poll(POLL_INTERVAL)? {
+                if let Event::Key(key) = read()? {
+                    let is_exit_key = matches!(key.code, KeyCode::Esc | KeyCode::Char('q'))
+                        || (key.code == KeyCode::Char('c')
+                            && key.modifiers.contains(KeyModifiers::CONTROL));
+                    if key.kind == KeyEventKind::Press && (is_exit_key || self.ended) {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Keeps the latest frame; lines that don't parse are skipped
+    pub fn handle_line(&mut self, line: &str) {
+        match SpectatorFrame::from_json_line(line) {
+            Ok(frame) => self.frame = Some(frame),
+            Err(e) => log::warn!("Ignoring malformed spectator frame: {}", e),
+        }
+    }
+
+    pub fn end(&mut self) {
+        self.ended = true;
+    }
+
+    pub fn current_frame(&self) -> Option<&SpectatorFrame> {
+        self.frame.as_ref()
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let area = frame.area();
+        if let Some(mirrored) = &self.frame {
+            mirrored.render(frame.buffer_mut(), area);
+        }
+
+        let status = match (&self.frame, self.ended) {
+            (_, true) => "The broadcast has ended. Press any key to exit.",
+            (None, false) => "Waiting for the player to start typing... (q to quit)",
+            (Some(_), false) => return,
+        };
+        let line = Rect {
+            y: area.bottom().saturating_sub(1),
+            height: area.height.min(1),
+            ..area
+        };
+        frame.render_widget(
+            Paragraph::new(status)
+                .alignment(Alignment::Center)
+                .style(Style::default().add_modifier(Modifier::REVERSED)),
+            line,
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use std::time::{Duration, Instant};
+
+/// Caps how often frames are drawn. A frame requested within the frame
+/// interval of the last one waits for the interval to pass, and further
+/// requests meanwhile join it, so a burst of keystrokes costs one redraw
+/// while a lone keystroke is still drawn at once.
+#[derive(Debug)]
+pub struct FrameLimiter {
+    frame_interval: Duration,
+    last_frame: Option<Instant>,
+    pending: bool,
+}
+
+impl FrameLimiter {
+    /// `max_fps` of `0` leaves frames unlimited
+    pub fn new(max_fps: u32) -> Self {
+        let frame_interval = match max_fps {
+            0 => Duration::ZERO,
+            fps => Duration::from_secs(1) / fps,
+        };
+        Self {
+            frame_interval,
+            last_frame: None,
+            pending: false,
+        }
+    }
+
+    pub fn frame_interval(&self) -> Duration {
+        self.frame_interval
+    }
+
+    pub fn request(&mut self) {
+        self.pending = true;
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+
+    /// How long the requested frame still has to wait; `None` when no frame
+    /// was requested
+    pub fn time_until_due(&self, now: Instant) -> Option<Duration> {
+        if !self.pending {
+            return None;
+        }
+        Some(self.last_frame.map_or(Duration::ZERO, |last| {
+            (last + self.frame_interval).saturating_duration_since(now)
+        }))
+    }
+
+    /// Whether a requested frame may be drawn at `now`
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.time_until_due(now) == Some(Duration::ZERO)
+    }
+
+    /// Record a drawn frame, which satisfies any pending request
+    pub fn frame_drawn(&mut self, now: Instant) {
+        self.last_frame = Some(now);
+        self.pending = false;
+    }
+}
@@ -0,0 +1,88 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+/// Keys that were all waiting to be read at once came in faster than anyone
+/// types. Keys typed while the loop was busy can bunch up too, but a stall
+/// would have to last seconds to queue a run of text this long, so it is a
+/// paste from a terminal without bracketed paste.
+pub const PASTE_BURST_MIN_CHARS: usize = 32;
+
+/// Normalizes raw terminal key events before they reach screens, smoothing
+/// over platform quirks (mostly Windows consoles):
+/// - key-up and repeat events are dropped, so a Windows keystroke reported
+///   as press and release counts once
+/// - on Windows, AltGr, which arrives as Ctrl+Alt, is treated as plain text input
+/// - control characters from legacy consoles become their named keys
+pub struct KeyInputFilter;
+
+impl KeyInputFilter {
+    pub fn accept(key_event: KeyEvent) -> Option<KeyEvent> {
+        (key_event.kind == KeyEventKind::Press).then(|| Self::normalize(key_event))
+    }
+
+    /// The text of keys read in one burst when it looks like a paste: at
+    /// least `PASTE_BURST_MIN_CHARS` presses, all of them text
+    pub fn paste_text(burst: &[KeyEvent]) -> Option<String> {
+        let text: Option<String> = burst
+            .iter()
+            .filter(|key_event| key_event.kind == KeyEventKind::Press)
+            .map(|&key_event| match Self::normalize(key_event) {
+                KeyEvent {
+                    code: KeyCode::Enter,
+                    ..
+                } => Some('\n'),
+                KeyEvent {
+                    code: KeyCode::Tab, ..
+                } => Some('\t'),
+                KeyEvent {
+                    code: KeyCode::Char(c),
+                    modifiers,
+                    ..
+                } if (modifiers - KeyModifiers::SHIFT).is_empty() => Some(c),
+                _ => None,
+            })
+            .collect();
+        text.filter(|text| text.chars().count() >= PASTE_BURST_MIN_CHARS)
+    }
+
+    fn normalize(key_event: KeyEvent) -> KeyEvent {
+        let KeyCode::Char(c) = key_event.code else {
+            return key_event;
+        };
+
+        let modifiers = Self::strip_altgr(key_event.modifiers);
+        let (code, modifiers) = match c {
+            '\r' | '\n' => (KeyCode::Enter, modifiers),
+            '\t' => (KeyCode::Tab, modifiers),
+            '\u{8}' | '\u{7f}' => (KeyCode::Backspace, modifiers),
+            '\u{1b}' => (KeyCode::Esc, modifiers),
+            '\u{1}'..='\u{1a}' => (
+                KeyCode::Char((b'a' + c as u8 - 1) as char),
+                modifiers | KeyModifiers::CONTROL,
+            ),
+            c => (KeyCode::Char(c), modifiers),
+        };
+
+        KeyEvent {
+            code,
+            modifiers,
+            ..key_event
+        }
+    }
+
+    #[cfg(windows)]
+    fn strip_altgr(modifiers: KeyModifiers) -> KeyModifiers {
+        let altgr = KeyModifiers::CONTROL | KeyModifiers::ALT;
+        if modifiers.contains(altgr) {
+            modifiers - altgr
+        } else {
+            modifiers
+        }
+    }
+
+    /// Other platforms report AltGr characters without modifiers, so Ctrl+Alt
+    /// is a real binding there
+    #[cfg(not(windows))]
+    fn strip_altgr(modifiers: KeyModifiers) -> KeyModifiers {
+        modifiers
+    }
+}
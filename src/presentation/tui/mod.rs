@@ -1,9 +1,13 @@
+pub mod frame_limiter;
+pub mod key_input;
 pub mod screen;
 pub mod screen_manager;
 pub mod screen_transition_manager;
 pub mod screens;
 pub mod views;
 
+pub use frame_limiter::FrameLimiter;
+pub use key_input::KeyInputFilter;
 pub use screen::*;
 pub use screen_manager::{ScreenManagerFactory, ScreenManagerFactoryImpl, ScreenManagerImpl};
 pub use screen_transition_manager::ScreenTransitionManager;
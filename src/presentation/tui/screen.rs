@@ -1,4 +1,4 @@
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use std::time::Duration;
 
@@ -24,6 +24,8 @@ pub enum ScreenType {
     SessionFailure,
     Records,
     Analytics,
+    Achievements,
+    Profile,
     SessionDetail,
     SessionSharing,
     Animation,
@@ -32,12 +34,21 @@ pub enum ScreenType {
     Help,
     DetailsDialog,
     Settings,
+    Tutorial,
+    WhatsNew,
+    KeyboardTest,
+    Error,
     Panic,
     // CLI screens
+    LogViewer,
     RepoList,
+    RepoDetail,
+    Leaderboard,
     RepoPlay,
+    Reminders,
     TrendingLanguageSelection,
     TrendingRepositorySelection,
+    WorkspacePackages,
 }
 
 /// Update strategy defines how and when a screen should be updated and re-rendered
@@ -103,6 +114,25 @@ pub trait Screen: Send + Sync + shaku::Interface {
     /// Handle keyboard input events
     fn handle_key_event(&self, key_event: KeyEvent) -> Result<()>;
 
+    /// Handle text pasted into the terminal, or read in a burst too long to
+    /// have been typed. By default each character arrives as a key press.
+    fn handle_paste(&self, text: &str) -> Result<()> {
+        for ch in text.chars().filter(|&ch| ch != '\r') {
+            let code = match ch {
+                '\n' => KeyCode::Enter,
+                '\t' => KeyCode::Tab,
+                ch => KeyCode::Char(ch),
+            };
+            self.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))?;
+        }
+        Ok(())
+    }
+
+    /// Handle the terminal reporting that it lost or regained focus
+    fn handle_focus_change(&self, _focused: bool) -> Result<()> {
+        Ok(())
+    }
+
     /// Render the screen using ratatui
     fn render_ratatui(&self, frame: &mut ratatui::Frame) -> Result<()>;
 
@@ -27,13 +27,17 @@
 //! ```
 //!
 use crossterm::cursor::{Hide, Show};
-use crossterm::event::{poll, read, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::event::{
+    poll, read, DisableBracketedPaste, DisableFocusChange, EnableBracketedPaste, EnableFocusChange,
+    Event, KeyCode, KeyEvent, KeyModifiers,
+};
 use crossterm::execute;
 use crossterm::style::ResetColor;
 use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen,
 };
 use ratatui::backend::CrosstermBackend;
+use ratatui::buffer::Buffer;
 use ratatui::Terminal;
 use shaku::{Component, Interface};
 
@@ -43,8 +47,12 @@ use std::sync::{Arc, Mutex};
 use std::thread::sleep;
 use std::time::{Duration, Instant};
 
-use crate::domain::events::presentation_events::{ExitRequested, NavigateTo};
+use crate::domain::events::domain_events::StageCompleted;
+use crate::domain::events::presentation_events::{ExitRequested, NavigateTo, OpenInEditor};
 use crate::domain::events::{EventBus, EventBusInterface};
+use crate::domain::models::config::DisplayConfig;
+use crate::domain::models::CompletionFeedback;
+use crate::domain::services::config_service::ConfigServiceInterface;
 use crate::domain::services::scoring::{
     SessionTracker, SessionTrackerInterface, TotalTracker, TotalTrackerInterface,
 };
@@ -55,13 +63,20 @@ use crate::domain::stores::{
     ChallengeStore, ChallengeStoreInterface, RepositoryStore, RepositoryStoreInterface,
     SessionStore, SessionStoreInterface,
 };
-use crate::infrastructure::terminal::TerminalInterface;
+use crate::infrastructure::editor::open_in_editor;
+use crate::infrastructure::terminal::{TerminalBell, TerminalInterface, WindowTitle};
+use crate::presentation::tui::frame_limiter::FrameLimiter;
+use crate::presentation::tui::key_input::KeyInputFilter;
 use crate::presentation::tui::screen_transition_manager::ScreenTransitionManager;
 use crate::presentation::tui::screens::{
-    AnalyticsScreen, AnalyticsScreenInterface, AnimationScreen, AnimationScreenInterface,
-    HelpScreen, HelpScreenInterface, InfoDialogScreen, InfoDialogScreenInterface, LoadingScreen,
-    LoadingScreenInterface, PanicScreen, PanicScreenInterface, RecordsScreen,
-    RecordsScreenInterface, RepoListScreen, RepoListScreenInterface, RepoPlayScreen,
+    AchievementsScreen, AchievementsScreenInterface, AnalyticsScreen, AnalyticsScreenInterface,
+    AnimationScreen, AnimationScreenInterface, ErrorScreen, ErrorScreenInterface, HelpScreen,
+    HelpScreenInterface, InfoDialogScreen, InfoDialogScreenInterface, KeyboardTestScreen,
+    KeyboardTestScreenInterface, LeaderboardScreen, LeaderboardScreenInterface, LoadingScreen,
+    LoadingScreenInterface, LogViewerScreen, LogViewerScreenInterface, PanicScreen,
+    PanicScreenInterface, ProfileScreen, ProfileScreenInterface, RecordsScreen,
+    RecordsScreenInterface, ReminderScreen, ReminderScreenInterface, RepoDetailScreen,
+    RepoDetailScreenInterface, RepoListScreen, RepoListScreenInterface, RepoPlayScreen,
     RepoPlayScreenInterface, SessionDetailScreen, SessionDetailScreenInterface,
     SessionDetailsDialog, SessionDetailsDialogInterface, SessionFailureScreen,
     SessionFailureScreenInterface, SessionSummaryScreen, SessionSummaryScreenInterface,
@@ -70,12 +85,15 @@ use crate::presentation::tui::screens::{
     TitleScreenInterface, TotalSummaryScreen, TotalSummaryScreenInterface, TotalSummaryShareScreen,
     TotalSummaryShareScreenInterface, TrendingLanguageSelectionScreen,
     TrendingLanguageSelectionScreenInterface, TrendingRepositorySelectionScreen,
-    TrendingRepositorySelectionScreenInterface, TypingScreen, TypingScreenInterface,
-    VersionCheckScreen, VersionCheckScreenInterface,
+    TrendingRepositorySelectionScreenInterface, TutorialScreen, TutorialScreenInterface,
+    TypingScreen, TypingScreenInterface, VersionCheckScreen, VersionCheckScreenInterface,
+    WhatsNewScreen, WhatsNewScreenInterface, WorkspacePackageScreen,
+    WorkspacePackageScreenInterface,
 };
 use crate::presentation::tui::{
     Screen, ScreenDataProvider, ScreenTransition, ScreenType, UpdateStrategy,
 };
+use crate::presentation::ui::ColorSupport;
 use crate::{GitTypeError, Result};
 
 /// Wrapper to make Arc<T: Screen> implement Screen
@@ -102,6 +120,10 @@ impl<T: Screen + Send + Sync + ?Sized> Screen for ArcScreenWrapper<T> {
         self.0.handle_key_event(key_event)
     }
 
+    fn handle_focus_change(&self, focused: bool) -> Result<()> {
+        self.0.handle_focus_change(focused)
+    }
+
     fn render_ratatui(&self, frame: &mut ratatui::Frame) -> Result<()> {
         self.0.render_ratatui(frame)
     }
@@ -150,10 +172,20 @@ pub struct ScreenManagerImpl<
     last_update: Instant,
     ratatui_terminal: Terminal<B>,
     exit_requested: bool,
+    color_support: ColorSupport,
+    frame_limiter: FrameLimiter,
+    // What the terminal shows, to skip drawing frames that change nothing
+    last_frame: Option<Buffer>,
 
     // Pending screen transition - shared across threads
     pending_transition: Arc<Mutex<Option<ScreenTransition>>>,
 
+    // File to open in the user's editor once the current frame is done
+    pending_editor: Arc<Mutex<Option<OpenInEditor>>>,
+
+    // Bell or flash for finished stages, written as each step falls due
+    bell: Arc<Mutex<TerminalBell>>,
+
     // Event bus for UI events
     event_bus: Arc<dyn EventBusInterface>,
 
@@ -182,7 +214,12 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
             last_update: Instant::now(),
             ratatui_terminal: terminal,
             exit_requested: false,
+            color_support: ColorSupport::detect(),
+            frame_limiter: FrameLimiter::new(DisplayConfig::default().max_fps),
+            last_frame: None,
             pending_transition: Arc::new(Mutex::new(None)),
+            pending_editor: Arc::new(Mutex::new(None)),
+            bell: Arc::new(Mutex::new(TerminalBell::new())),
             event_bus: event_bus.clone(),
             session_store,
             session_manager,
@@ -190,6 +227,11 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
         }
     }
 
+    /// Limit drawing to `max_fps` frames per second; `0` removes the limit
+    pub fn set_max_fps(&mut self, max_fps: u32) {
+        self.frame_limiter = FrameLimiter::new(max_fps);
+    }
+
     pub fn get_event_bus(&self) -> Arc<dyn EventBusInterface> {
         Arc::clone(&self.event_bus)
     }
@@ -199,6 +241,11 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
         self.pending_transition.lock().unwrap().clone()
     }
 
+    #[cfg(feature = "test-mocks")]
+    pub fn pending_editor_for_test(&self) -> Option<OpenInEditor> {
+        self.pending_editor.lock().unwrap().clone()
+    }
+
     /// Set up event subscriptions for navigation events
     /// Takes a weak reference to avoid circular references
     pub fn setup_event_subscriptions(manager_ref: &Arc<Mutex<Self>>) {
@@ -208,9 +255,13 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
             manager.event_bus.clone()
         }; // Release lock before subscribing
 
-        let pending_transition = {
+        let (pending_transition, pending_editor, bell) = {
             let manager = manager_ref.lock().unwrap();
-            manager.pending_transition.clone()
+            (
+                manager.pending_transition.clone(),
+                manager.pending_editor.clone(),
+                manager.bell.clone(),
+            )
         };
 
         log::info!(
@@ -234,6 +285,28 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
                 });
         }
 
+        // Subscribe to OpenInEditor events
+        event_bus
+            .as_event_bus()
+            .subscribe(move |event: &OpenInEditor| {
+                if let Ok(mut pending) = pending_editor.lock() {
+                    *pending = Some(event.clone());
+                }
+            });
+
+        // Subscribe to StageCompleted events
+        event_bus
+            .as_event_bus()
+            .subscribe(move |event: &StageCompleted| {
+                if let Ok(mut bell) = bell.lock() {
+                    bell.ring(
+                        CompletionFeedback::current(),
+                        CompletionFeedback::repeats(event.personal_best),
+                        Instant::now(),
+                    );
+                }
+            });
+
         // Subscribe to ExitRequested events
         {
             let manager_weak_clone = manager_weak.clone();
@@ -309,14 +382,28 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
                 }
             }
 
+            // Terminals without focus reporting or bracketed paste ignore these
+            let _ = execute!(stdout(), EnableFocusChange, EnableBracketedPaste);
+
             self.terminal_initialized = true;
         }
         Ok(())
     }
 
     pub fn cleanup_terminal(&mut self) -> Result<()> {
+        WindowTitle::restore();
         if self.terminal_initialized {
-            execute!(stdout(), LeaveAlternateScreen, Show).map_err(|e| {
+            if let Ok(mut bell) = self.bell.lock() {
+                let _ = bell.finish(&mut stdout());
+            }
+            execute!(
+                stdout(),
+                DisableBracketedPaste,
+                DisableFocusChange,
+                LeaveAlternateScreen,
+                Show
+            )
+            .map_err(|e| {
                 GitTypeError::TerminalError(format!("Failed to restore terminal: {}", e))
             })?;
 
@@ -397,24 +484,35 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
             ScreenType::SessionFailure => SessionFailureScreen::default_provider(),
             ScreenType::Records => RecordsScreen::default_provider(),
             ScreenType::Analytics => AnalyticsScreen::default_provider(),
+            ScreenType::Achievements => AchievementsScreen::default_provider(),
+            ScreenType::Profile => ProfileScreen::default_provider(),
             ScreenType::SessionDetail => SessionDetailScreen::default_provider(),
             ScreenType::SessionSharing => SessionSummaryShareScreen::default_provider(),
             ScreenType::Animation => AnimationScreen::default_provider(),
             ScreenType::VersionCheck => VersionCheckScreen::default_provider(),
+            ScreenType::WhatsNew => WhatsNewScreen::default_provider(),
             ScreenType::InfoDialog => InfoDialogScreen::default_provider(),
             ScreenType::Help => HelpScreen::default_provider(),
             ScreenType::DetailsDialog => SessionDetailsDialog::default_provider(),
             ScreenType::Settings => SettingsScreen::default_provider(),
+            ScreenType::Tutorial => TutorialScreen::default_provider(),
+            ScreenType::KeyboardTest => KeyboardTestScreen::default_provider(),
+            ScreenType::Error => ErrorScreen::default_provider(),
             ScreenType::Panic => PanicScreen::default_provider(),
             // CLI screens
+            ScreenType::LogViewer => LogViewerScreen::default_provider(),
             ScreenType::RepoPlay => RepoPlayScreen::default_provider(),
+            ScreenType::Reminders => ReminderScreen::default_provider(),
             ScreenType::RepoList => RepoListScreen::default_provider(),
+            ScreenType::RepoDetail => RepoDetailScreen::default_provider(),
+            ScreenType::Leaderboard => LeaderboardScreen::default_provider(),
             ScreenType::TrendingLanguageSelection => {
                 TrendingLanguageSelectionScreen::default_provider()
             }
             ScreenType::TrendingRepositorySelection => {
                 TrendingRepositorySelectionScreen::default_provider()
             }
+            ScreenType::WorkspacePackages => WorkspacePackageScreen::default_provider(),
         };
 
         provider.provide()
@@ -425,6 +523,7 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
         self.ratatui_terminal.clear().map_err(|e| {
             GitTypeError::TerminalError(format!("Failed to clear ratatui terminal: {}", e))
         })?;
+        self.last_frame = None;
         Ok(())
     }
 
@@ -548,6 +647,11 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
             // Handle input
             self.handle_input()?;
 
+            // Draw what input and updates changed, at most once per frame interval
+            self.render_if_due()?;
+
+            self.ring_bell_if_due();
+
             // Check for pending screen transitions
             let pending_transition = {
                 self.pending_transition
@@ -566,6 +670,15 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
                 }
             }
 
+            let pending_editor = self
+                .pending_editor
+                .lock()
+                .ok()
+                .and_then(|mut pending| pending.take());
+            if let Some(request) = pending_editor {
+                self.suspend_for_editor(&request)?;
+            }
+
             // Check if exit was requested
             if self.exit_requested {
                 break;
@@ -575,6 +688,40 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
         Ok(())
     }
 
+    /// Write the bell or flash steps that are due. Only a real terminal gets
+    /// them, so redirected output and test backends stay clean
+    fn ring_bell_if_due(&mut self) {
+        if !self.terminal_initialized {
+            return;
+        }
+        let Ok(mut bell) = self.bell.lock() else {
+            return;
+        };
+        if let Err(e) = bell.write_due(&mut stdout(), Instant::now()) {
+            log::debug!("Failed to ring the terminal bell: {}", e);
+        }
+    }
+
+    /// Hand the terminal to the editor until it exits, then redraw the current screen
+    fn suspend_for_editor(&mut self, request: &OpenInEditor) -> Result<()> {
+        let was_initialized = self.terminal_initialized;
+        self.cleanup_terminal()?;
+
+        if let Err(e) = open_in_editor(&request.path, request.line) {
+            log::warn!(
+                "ScreenManager: Failed to open {} in editor: {}",
+                request.path.display(),
+                e
+            );
+        }
+
+        if was_initialized {
+            self.initialize_terminal()?;
+        }
+        self.clear_screen()?;
+        self.render_current_screen()
+    }
+
     fn update_and_render(&mut self) -> Result<()> {
         if let Some(screen) = self.screens.get_mut(&self.current_screen_type) {
             let strategy = screen.get_update_strategy();
@@ -609,10 +756,32 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
                             let _ = repo.update_title_screen_data(self);
                         }
 
+                        // First launch: walk new players through the tutorial
+                        let show_tutorial = self
+                            .screens
+                            .get(&ScreenType::Tutorial)
+                            .and_then(|screen| screen.as_any().downcast_ref::<TutorialScreen>())
+                            .is_some_and(|tutorial| tutorial.should_show_on_launch());
+                        // After an update: show this release's notes once instead
+                        let whats_new = self
+                            .screens
+                            .get(&ScreenType::WhatsNew)
+                            .and_then(|screen| screen.as_any().downcast_ref::<WhatsNewScreen>());
+                        let show_whats_new = !show_tutorial
+                            && whats_new.is_some_and(|screen| screen.should_show_on_launch());
+                        if show_tutorial {
+                            // New players have nothing to compare against
+                            if let Some(whats_new) = whats_new {
+                                whats_new.mark_seen();
+                            }
+                            self.handle_transition(ScreenTransition::Push(ScreenType::Tutorial))?;
+                        } else if show_whats_new {
+                            self.handle_transition(ScreenTransition::Push(ScreenType::WhatsNew))?;
+                        }
+
                         return Ok(());
                     } else if loading_failed {
-                        // Could transition to an error screen or back to title
-                        self.handle_transition(ScreenTransition::Replace(ScreenType::Title))?;
+                        self.handle_transition(ScreenTransition::Replace(ScreenType::Error))?;
                         return Ok(());
                     }
                 }
@@ -633,7 +802,7 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
                 }
 
                 if needs_render {
-                    self.render_current_screen()?;
+                    self.frame_limiter.request();
                 }
                 self.last_update = now;
             }
@@ -660,52 +829,161 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
         } else {
             Duration::from_millis(100)
         };
+        // Wake up in time to draw a frame held back by the frame limiter
+        let timeout = self
+            .frame_limiter
+            .time_until_due(Instant::now())
+            .map_or(timeout, |wait| timeout.min(wait));
+        // ...and to write the next step of a bell or flash
+        let timeout = self
+            .bell
+            .lock()
+            .ok()
+            .and_then(|bell| bell.next_due())
+            .map_or(timeout, |due| {
+                timeout.min(due.saturating_duration_since(Instant::now()))
+            });
 
         if poll(timeout)? {
-            if let Event::Key(key_event) = read()? {
-                if key_event.kind == KeyEventKind::Press {
-                    if key_event.modifiers.contains(KeyModifiers::CONTROL)
-                        && key_event.code == KeyCode::Char('c')
-                    {
-                        // Ctrl+C should either transition to ExitSummary or exit if already there
-                        if self.current_screen_type == ScreenType::TotalSummary {
-                            self.exit_requested = true;
-                        } else {
-                            let _ = self.handle_transition(ScreenTransition::Replace(
-                                ScreenType::TotalSummary,
-                            ));
-                        }
-                        return Ok(());
-                    }
+            let event = read()?;
+            self.handle_event(event)?;
+        }
 
-                    if let Some(screen) = self.screens.get_mut(&self.current_screen_type) {
-                        screen.handle_key_event(key_event)?;
-                    }
+        Ok(())
+    }
+
+    fn handle_event(&mut self, event: Event) -> Result<()> {
+        match event {
+            Event::Key(key_event) => self.handle_key_burst(key_event),
+            Event::Paste(text) => self.handle_paste(&text),
+            Event::Resize(_, _) => self.handle_resize(),
+            Event::FocusLost => self.handle_focus_change(false),
+            Event::FocusGained => self.handle_focus_change(true),
+            _ => Ok(()),
+        }
+    }
 
-                    // Always re-render on key input for ratatui screens
-                    // as they may have internal state changes (list selection, etc.)
-                    self.render_current_screen()?;
+    /// Read the keys already waiting behind `key_event` with it, so a paste
+    /// from a terminal without bracketed paste reaches the screen as one
+    fn handle_key_burst(&mut self, key_event: KeyEvent) -> Result<()> {
+        let mut burst = vec![key_event];
+        let mut next_event = None;
+        while poll(Duration::ZERO)? {
+            match read()? {
+                Event::Key(key_event) => burst.push(key_event),
+                event => {
+                    next_event = Some(event);
+                    break;
                 }
             }
         }
 
+        match KeyInputFilter::paste_text(&burst) {
+            Some(text) => self.handle_paste(&text)?,
+            None => {
+                for key_event in burst {
+                    self.handle_key_input(key_event)?;
+                }
+            }
+        }
+        match next_event {
+            Some(event) => self.handle_event(event),
+            None => Ok(()),
+        }
+    }
+
+    pub fn handle_paste(&mut self, text: &str) -> Result<()> {
+        if let Some(screen) = self.screens.get(&self.current_screen_type) {
+            screen.handle_paste(text)?;
+        }
+        self.frame_limiter.request();
+        Ok(())
+    }
+
+    fn handle_key_input(&mut self, key_event: KeyEvent) -> Result<()> {
+        let Some(key_event) = KeyInputFilter::accept(key_event) else {
+            return Ok(());
+        };
+
+        if key_event.modifiers.contains(KeyModifiers::CONTROL)
+            && key_event.code == KeyCode::Char('c')
+        {
+            // Ctrl+C should either transition to ExitSummary or exit if already there
+            if self.current_screen_type == ScreenType::TotalSummary {
+                self.exit_requested = true;
+            } else {
+                let _ = self.handle_transition(ScreenTransition::Replace(ScreenType::TotalSummary));
+            }
+            return Ok(());
+        }
+
+        if let Some(screen) = self.screens.get_mut(&self.current_screen_type) {
+            screen.handle_key_event(key_event)?;
+        }
+
+        // Always re-render on key input for ratatui screens
+        // as they may have internal state changes (list selection, etc.)
+        self.frame_limiter.request();
+        Ok(())
+    }
+
+    fn handle_focus_change(&mut self, focused: bool) -> Result<()> {
+        if let Some(screen) = self.screens.get(&self.current_screen_type) {
+            screen.handle_focus_change(focused)?;
+        }
+        self.frame_limiter.request();
         Ok(())
     }
 
+    /// Repaints the whole frame at the new size. Screens keep their own state
+    /// (typing position, timers) and recompute layout on render, so only the
+    /// stale cells from the previous size need discarding.
+    pub fn handle_resize(&mut self) -> Result<()> {
+        self.clear_screen()?;
+        self.render_current_screen()
+    }
+
+    /// Draw the frame requested by input or updates once the frame limiter allows
+    pub fn render_if_due(&mut self) -> Result<bool> {
+        if !self.frame_limiter.is_due(Instant::now()) {
+            return Ok(false);
+        }
+        self.render_current_screen()?;
+        Ok(true)
+    }
+
+    /// Draws the current screen now. Only cells that differ from what the
+    /// terminal shows are written, and a frame identical to the last one
+    /// writes nothing at all.
     pub fn render_current_screen(&mut self) -> Result<()> {
+        let color_support = self.color_support;
+        let draw_error =
+            |e| GitTypeError::TerminalError(format!("Failed to draw ratatui frame: {}", e));
         if let Some(screen) = self.screens.get_mut(&self.current_screen_type) {
-            self.ratatui_terminal
-                .draw(|frame| {
-                    let _ = screen.render_ratatui(frame);
-                })
-                .map_err(|e| {
-                    GitTypeError::TerminalError(format!("Failed to draw ratatui frame: {}", e))
-                })?;
+            let terminal = &mut self.ratatui_terminal;
+            terminal.autoresize().map_err(draw_error)?;
+            let mut frame = terminal.get_frame();
+            let _ = screen.render_ratatui(&mut frame);
+            color_support.apply(frame.buffer_mut());
+
+            let buffer = terminal.current_buffer_mut();
+            if self.last_frame.as_ref() == Some(buffer) {
+                buffer.reset();
+            } else {
+                self.last_frame = Some(buffer.clone());
+                terminal.apply_buffer().map_err(draw_error)?;
+            }
         }
+        self.frame_limiter.frame_drawn(Instant::now());
 
         Ok(())
     }
 
+    /// Whether a requested frame is still waiting to be drawn
+    pub fn has_pending_frame(&self) -> bool {
+        self.frame_limiter.is_pending()
+    }
+
     pub fn get_current_screen_type(&self) -> &ScreenType {
         &self.current_screen_type
     }
@@ -738,6 +1016,8 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
 
     /// Static cleanup function for use when ScreenManager instance is not available
     pub fn cleanup_terminal_static() {
+        WindowTitle::restore();
+
         // Disable raw mode first
         if let Err(e) = disable_raw_mode() {
             eprintln!("Warning: Failed to disable raw mode: {}", e);
@@ -746,6 +1026,8 @@ impl<B: ratatui::backend::Backend + Send + 'static> ScreenManagerImpl<B> {
         // Exit alternate screen and restore cursor with explicit error handling
         if let Err(e) = execute!(
             stdout(),
+            DisableBracketedPaste,
+            DisableFocusChange,
             LeaveAlternateScreen,
             Show,
             ResetColor,
@@ -830,6 +1112,8 @@ pub struct ScreenManagerFactoryImpl {
     #[shaku(inject)]
     terminal: Arc<dyn TerminalInterface>,
     #[shaku(inject)]
+    config_service: Arc<dyn ConfigServiceInterface>,
+    #[shaku(inject)]
     title_screen: Arc<dyn TitleScreenInterface>,
     #[shaku(inject)]
     typing_screen: Arc<dyn TypingScreenInterface>,
@@ -840,6 +1124,8 @@ pub struct ScreenManagerFactoryImpl {
     #[shaku(inject)]
     loading_screen: Arc<dyn LoadingScreenInterface>,
     #[shaku(inject)]
+    error_screen: Arc<dyn ErrorScreenInterface>,
+    #[shaku(inject)]
     panic_screen: Arc<dyn PanicScreenInterface>,
     #[shaku(inject)]
     session_failure_screen: Arc<dyn SessionFailureScreenInterface>,
@@ -862,19 +1148,39 @@ pub struct ScreenManagerFactoryImpl {
     #[shaku(inject)]
     settings_screen: Arc<dyn SettingsScreenInterface>,
     #[shaku(inject)]
+    tutorial_screen: Arc<dyn TutorialScreenInterface>,
+    #[shaku(inject)]
+    achievements_screen: Arc<dyn AchievementsScreenInterface>,
+    #[shaku(inject)]
+    profile_screen: Arc<dyn ProfileScreenInterface>,
+    #[shaku(inject)]
+    keyboard_test_screen: Arc<dyn KeyboardTestScreenInterface>,
+    #[shaku(inject)]
     total_summary_screen: Arc<dyn TotalSummaryScreenInterface>,
     #[shaku(inject)]
     total_summary_share_screen: Arc<dyn TotalSummaryShareScreenInterface>,
     #[shaku(inject)]
     version_check_screen: Arc<dyn VersionCheckScreenInterface>,
     #[shaku(inject)]
+    whats_new_screen: Arc<dyn WhatsNewScreenInterface>,
+    #[shaku(inject)]
+    log_viewer_screen: Arc<dyn LogViewerScreenInterface>,
+    #[shaku(inject)]
     repo_list_screen: Arc<dyn RepoListScreenInterface>,
     #[shaku(inject)]
+    repo_detail_screen: Arc<dyn RepoDetailScreenInterface>,
+    #[shaku(inject)]
+    leaderboard_screen: Arc<dyn LeaderboardScreenInterface>,
+    #[shaku(inject)]
     repo_play_screen: Arc<dyn RepoPlayScreenInterface>,
     #[shaku(inject)]
+    reminder_screen: Arc<dyn ReminderScreenInterface>,
+    #[shaku(inject)]
     trending_language_selection_screen: Arc<dyn TrendingLanguageSelectionScreenInterface>,
     #[shaku(inject)]
     trending_repository_selection_screen: Arc<dyn TrendingRepositorySelectionScreenInterface>,
+    #[shaku(inject)]
+    workspace_package_screen: Arc<dyn WorkspacePackageScreenInterface>,
 }
 
 impl ScreenManagerFactory for ScreenManagerFactoryImpl {
@@ -895,6 +1201,7 @@ impl ScreenManagerFactory for ScreenManagerFactoryImpl {
             stage_repository,
             terminal,
         );
+        manager.set_max_fps(self.config_service.get_config().display.max_fps);
 
         // Register screens from DI (Components)
         // Explicit type coercion from Arc<dyn Interface> to Arc<dyn Screen>
@@ -908,6 +1215,8 @@ impl ScreenManagerFactory for ScreenManagerFactoryImpl {
         manager.register_screen_interface(help_screen);
         let loading_screen: Arc<dyn Screen> = self.loading_screen.clone();
         manager.register_screen_interface(loading_screen);
+        let error_screen: Arc<dyn Screen> = self.error_screen.clone();
+        manager.register_screen_interface(error_screen);
         let panic_screen: Arc<dyn Screen> = self.panic_screen.clone();
         manager.register_screen_interface(panic_screen);
         let session_failure_screen: Arc<dyn Screen> = self.session_failure_screen.clone();
@@ -931,22 +1240,42 @@ impl ScreenManagerFactory for ScreenManagerFactoryImpl {
         manager.register_screen_interface(session_summary_share_screen);
         let settings_screen: Arc<dyn Screen> = self.settings_screen.clone();
         manager.register_screen_interface(settings_screen);
+        let tutorial_screen: Arc<dyn Screen> = self.tutorial_screen.clone();
+        manager.register_screen_interface(tutorial_screen);
+        let achievements_screen: Arc<dyn Screen> = self.achievements_screen.clone();
+        manager.register_screen_interface(achievements_screen);
+        let profile_screen: Arc<dyn Screen> = self.profile_screen.clone();
+        manager.register_screen_interface(profile_screen);
+        let keyboard_test_screen: Arc<dyn Screen> = self.keyboard_test_screen.clone();
+        manager.register_screen_interface(keyboard_test_screen);
         let total_summary_screen: Arc<dyn Screen> = self.total_summary_screen.clone();
         manager.register_screen_interface(total_summary_screen);
         let total_summary_share_screen: Arc<dyn Screen> = self.total_summary_share_screen.clone();
         manager.register_screen_interface(total_summary_share_screen);
         let version_check_screen: Arc<dyn Screen> = self.version_check_screen.clone();
         manager.register_screen_interface(version_check_screen);
+        let whats_new_screen: Arc<dyn Screen> = self.whats_new_screen.clone();
+        manager.register_screen_interface(whats_new_screen);
+        let log_viewer_screen: Arc<dyn Screen> = self.log_viewer_screen.clone();
+        manager.register_screen_interface(log_viewer_screen);
         let repo_list_screen: Arc<dyn Screen> = self.repo_list_screen.clone();
         manager.register_screen_interface(repo_list_screen);
+        let repo_detail_screen: Arc<dyn Screen> = self.repo_detail_screen.clone();
+        manager.register_screen_interface(repo_detail_screen);
+        let leaderboard_screen: Arc<dyn Screen> = self.leaderboard_screen.clone();
+        manager.register_screen_interface(leaderboard_screen);
         let repo_play_screen: Arc<dyn Screen> = self.repo_play_screen.clone();
         manager.register_screen_interface(repo_play_screen);
+        let reminder_screen: Arc<dyn Screen> = self.reminder_screen.clone();
+        manager.register_screen_interface(reminder_screen);
         let trending_language_selection_screen: Arc<dyn Screen> =
             self.trending_language_selection_screen.clone();
         manager.register_screen_interface(trending_language_selection_screen);
         let trending_repository_selection_screen: Arc<dyn Screen> =
             self.trending_repository_selection_screen.clone();
         manager.register_screen_interface(trending_repository_selection_screen);
+        let workspace_package_screen: Arc<dyn Screen> = self.workspace_package_screen.clone();
+        manager.register_screen_interface(workspace_package_screen);
 
         manager
     }
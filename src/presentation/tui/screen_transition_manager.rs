@@ -95,6 +95,10 @@ impl ScreenTransitionManager {
             // From SessionDetail
             (ScreenType::SessionDetail, ScreenType::Records) => {}
             (ScreenType::SessionDetail, ScreenType::TotalSummary) => {}
+            (ScreenType::SessionDetail, ScreenType::Typing) => {
+                // Ghost race - start a fresh session with the queued challenge
+                Self::handle_retry_transition(session_manager)?;
+            }
 
             // From Sharing
             (ScreenType::SessionSharing, ScreenType::SessionSummary) => {}
@@ -161,7 +165,7 @@ impl ScreenTransitionManager {
                 tracker.record(StageInput::Fail);
                 let stage_result = StageCalculator::calculate(tracker);
                 drop(tracker_guard);
-                sm.reduce(SessionAction::CompleteStage(stage_result))?;
+                sm.reduce(SessionAction::CompleteStage(Box::new(stage_result)))?;
             } else {
                 drop(tracker_guard);
             }
@@ -0,0 +1,236 @@
+use crate::domain::events::presentation_events::NavigateTo;
+use crate::domain::events::EventBusInterface;
+use crate::domain::models::{AchievementStatus, Locale};
+use crate::domain::services::achievement_service::{
+    AchievementService, AchievementServiceInterface,
+};
+use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::infrastructure::database::storage_backend::open_default_backend;
+use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
+use crate::presentation::ui::Colors;
+use crate::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use std::sync::{Arc, RwLock};
+
+pub trait AchievementsScreenInterface: Screen {}
+
+#[derive(shaku::Component)]
+#[shaku(interface = AchievementsScreenInterface)]
+pub struct AchievementsScreen {
+    #[shaku(default)]
+    achievements: RwLock<Vec<AchievementStatus>>,
+    #[shaku(default)]
+    list_state: RwLock<ListState>,
+    #[shaku(inject)]
+    event_bus: Arc<dyn EventBusInterface>,
+    #[shaku(inject)]
+    theme_service: Arc<dyn ThemeServiceInterface>,
+}
+
+impl AchievementsScreen {
+    pub fn new(
+        event_bus: Arc<dyn EventBusInterface>,
+        theme_service: Arc<dyn ThemeServiceInterface>,
+    ) -> Self {
+        Self {
+            achievements: RwLock::new(Vec::new()),
+            list_state: RwLock::new(ListState::default()),
+            event_bus,
+            theme_service,
+        }
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.list_state.read().unwrap().selected()
+    }
+
+    fn move_selection(&self, forward: bool) {
+        let len = self.achievements.read().unwrap().len();
+        if len == 0 {
+            return;
+        }
+        let mut list_state = self.list_state.write().unwrap();
+        let selected = list_state.selected().unwrap_or(0);
+        list_state.select(Some(if forward {
+            (selected + 1).min(len - 1)
+        } else {
+            selected.saturating_sub(1)
+        }));
+    }
+
+    fn render_header(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let achievements = self.achievements.read().unwrap();
+        let unlocked = achievements.iter().filter(|a| a.is_unlocked()).count();
+
+        let header = Paragraph::new(Line::from(vec![
+            Span::styled("Achievements", Style::default().fg(colors.title()).bold()),
+            Span::styled(
+                format!("  {}/{} unlocked", unlocked, achievements.len()),
+                Style::default().fg(colors.text_secondary()),
+            ),
+        ]))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border())),
+        );
+        frame.render_widget(header, area);
+    }
+
+    fn render_list(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let achievements = self.achievements.read().unwrap();
+        let items: Vec<ListItem> = achievements
+            .iter()
+            .map(|status| {
+                let achievement = status.achievement;
+                let line = match status.unlocked_at {
+                    Some(unlocked_at) => Line::from(vec![
+                        Span::styled("★ ", Style::default().fg(colors.score())),
+                        Span::styled(
+                            format!("{:<18}", achievement.name()),
+                            Style::default().fg(colors.text()).bold(),
+                        ),
+                        Span::styled(
+                            format!("{:<46}", achievement.hint()),
+                            Style::default().fg(colors.text_secondary()),
+                        ),
+                        Span::styled(
+                            Locale::current().format_date(
+                                unlocked_at.with_timezone(&chrono::Local).date_naive(),
+                            ),
+                            Style::default().fg(colors.success()),
+                        ),
+                    ]),
+                    None => Line::from(vec![
+                        Span::styled("☆ ", Style::default().fg(colors.text_secondary())),
+                        Span::styled(
+                            format!("{:<18}", achievement.name()),
+                            Style::default().fg(colors.text_secondary()),
+                        ),
+                        Span::styled(
+                            format!("{:<46}", achievement.hint()),
+                            Style::default().fg(colors.text_secondary()),
+                        ),
+                        Span::styled("Locked", Style::default().fg(colors.text_secondary())),
+                    ]),
+                };
+                ListItem::new(line)
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(colors.border())),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(colors.background_secondary())
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("► ");
+
+        frame.render_stateful_widget(list, area, &mut self.list_state.write().unwrap());
+    }
+
+    fn render_footer(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let controls = Paragraph::new(Line::from(vec![
+            Span::styled("[↑↓/JK]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Navigate  ", Style::default().fg(colors.text())),
+            Span::styled("[ESC]", Style::default().fg(colors.error())),
+            Span::styled(" Back", Style::default().fg(colors.text())),
+        ]))
+        .alignment(Alignment::Center);
+        frame.render_widget(controls, area);
+    }
+}
+
+pub struct AchievementsScreenDataProvider;
+
+impl ScreenDataProvider for AchievementsScreenDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        let backend = open_default_backend()?;
+        let service =
+            AchievementService::new(backend.stage_dao().into(), backend.achievement_dao().into());
+
+        service
+            .load_achievements()
+            .map(|achievements| Box::new(achievements) as Box<dyn std::any::Any>)
+    }
+}
+
+impl Screen for AchievementsScreen {
+    fn get_type(&self) -> ScreenType {
+        ScreenType::Achievements
+    }
+
+    fn default_provider() -> Box<dyn ScreenDataProvider>
+    where
+        Self: Sized,
+    {
+        Box::new(AchievementsScreenDataProvider)
+    }
+
+    fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
+        let achievements = data.downcast::<Vec<AchievementStatus>>()?;
+        *self.list_state.write().unwrap() =
+            ListState::default().with_selected((!achievements.is_empty()).then_some(0));
+        *self.achievements.write().unwrap() = *achievements;
+        Ok(())
+    }
+
+    fn handle_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(false),
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(true),
+            KeyCode::Esc => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Pop);
+            }
+            KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn render_ratatui(&self, frame: &mut Frame) -> Result<()> {
+        let colors = self.theme_service.get_colors();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(frame.area());
+
+        self.render_header(frame, chunks[0], &colors);
+        self.render_list(frame, chunks[1], &colors);
+        self.render_footer(frame, chunks[2], &colors);
+        Ok(())
+    }
+
+    fn get_update_strategy(&self) -> UpdateStrategy {
+        UpdateStrategy::InputOnly
+    }
+
+    fn update(&self) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl AchievementsScreenInterface for AchievementsScreen {}
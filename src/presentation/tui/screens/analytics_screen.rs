@@ -5,10 +5,9 @@ use crate::domain::services::analytics_service::{
     AnalyticsData, AnalyticsService, AnalyticsServiceInterface,
 };
 use crate::domain::services::theme_service::ThemeServiceInterface;
-use crate::infrastructure::database::daos::{RepositoryDao, RepositoryDaoInterface};
-use crate::infrastructure::database::database::{Database, DatabaseInterface};
+use crate::infrastructure::database::storage_backend::open_default_backend;
 use crate::presentation::tui::views::analytics::{
-    LanguagesView, OverviewView, RepositoriesView, TrendsView,
+    FingersView, LanguagesView, OverviewView, RepositoriesView, TrendsView,
 };
 use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
 use crate::presentation::ui::Colors;
@@ -30,6 +29,7 @@ pub enum ViewMode {
     Trends,
     Repositories,
     Languages,
+    Fingers,
 }
 
 impl ViewMode {
@@ -39,6 +39,7 @@ impl ViewMode {
             ViewMode::Trends => "Trends",
             ViewMode::Repositories => "Repositories",
             ViewMode::Languages => "Languages",
+            ViewMode::Fingers => "Fingers",
         }
     }
 
@@ -47,16 +48,18 @@ impl ViewMode {
             ViewMode::Overview => ViewMode::Trends,
             ViewMode::Trends => ViewMode::Repositories,
             ViewMode::Repositories => ViewMode::Languages,
-            ViewMode::Languages => ViewMode::Overview,
+            ViewMode::Languages => ViewMode::Fingers,
+            ViewMode::Fingers => ViewMode::Overview,
         }
     }
 
     pub fn previous(&self) -> Self {
         match self {
-            ViewMode::Overview => ViewMode::Languages,
+            ViewMode::Overview => ViewMode::Fingers,
             ViewMode::Trends => ViewMode::Overview,
             ViewMode::Repositories => ViewMode::Trends,
             ViewMode::Languages => ViewMode::Repositories,
+            ViewMode::Fingers => ViewMode::Languages,
         }
     }
 }
@@ -96,10 +99,12 @@ pub struct AnalyticsScreenDataProvider {}
 impl ScreenDataProvider for AnalyticsScreenDataProvider {
     fn provide(&self) -> Result<Box<dyn std::any::Any>> {
         let session_repository = Arc::new(SessionRepository::new()?);
-        let db = Arc::new(Database::new()?) as Arc<dyn DatabaseInterface>;
-        let repository_dao =
-            Arc::new(RepositoryDao::new(Arc::clone(&db))) as Arc<dyn RepositoryDaoInterface>;
-        let service = AnalyticsService::new(session_repository, repository_dao);
+        let backend = open_default_backend()?;
+        let service = AnalyticsService::new(
+            session_repository,
+            backend.repository_dao().into(),
+            backend.baseline_dao().into(),
+        );
 
         service
             .load_analytics_data()
@@ -240,6 +245,7 @@ impl AnalyticsScreen {
             ViewMode::Trends,
             ViewMode::Repositories,
             ViewMode::Languages,
+            ViewMode::Fingers,
         ];
 
         let mut tab_spans = Vec::new();
@@ -305,6 +311,7 @@ impl AnalyticsScreen {
                         colors,
                     )
                 }
+                ViewMode::Fingers => FingersView::render(f, area, data, colors),
             }
         } else {
             let loading = Paragraph::new("Loading analytics data...")
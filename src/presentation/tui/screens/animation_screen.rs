@@ -1,6 +1,6 @@
 use crate::domain::events::presentation_events::NavigateTo;
 use crate::domain::events::EventBusInterface;
-use crate::domain::models::{RankTier, SessionResult};
+use crate::domain::models::{DemoMode, RankTier, SessionResult};
 use crate::domain::services::scoring::Rank;
 use crate::domain::services::session_manager_service::SessionManagerInterface;
 use crate::domain::services::theme_service::ThemeServiceInterface;
@@ -247,6 +247,14 @@ impl Screen for AnimationScreen {
     }
 
     fn handle_key_event(&self, key_event: crossterm::event::KeyEvent) -> Result<()> {
+        if DemoMode::is_playing() {
+            DemoMode::set_playing(false);
+            self.event_bus
+                .as_event_bus()
+                .publish(NavigateTo::PopTo(ScreenType::Title));
+            return Ok(());
+        }
+
         match key_event.code {
             KeyCode::Char('s') | KeyCode::Char('S') => {
                 self.event_bus
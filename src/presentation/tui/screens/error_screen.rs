@@ -0,0 +1,227 @@
+use crate::domain::events::presentation_events::NavigateTo;
+use crate::domain::events::EventBusInterface;
+use crate::domain::models::ErrorReport;
+use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::domain::stores::SessionStoreInterface;
+use crate::infrastructure::logging::get_current_log_file_path;
+use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
+use crate::presentation::ui::Colors;
+use crate::Result;
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Paragraph, Wrap},
+    Frame,
+};
+use std::sync::{Arc, RwLock};
+
+pub struct ErrorScreenDataProvider;
+
+impl ScreenDataProvider for ErrorScreenDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(()))
+    }
+}
+
+pub trait ErrorScreenInterface: Screen {}
+
+/// Explains why loading the repository failed and what to try, instead of
+/// dropping the player back to the title with nothing to play
+#[derive(shaku::Component)]
+#[shaku(interface = ErrorScreenInterface)]
+pub struct ErrorScreen {
+    #[shaku(default)]
+    report: RwLock<Option<ErrorReport>>,
+    #[shaku(inject)]
+    event_bus: Arc<dyn EventBusInterface>,
+    #[shaku(inject)]
+    theme_service: Arc<dyn ThemeServiceInterface>,
+    #[shaku(inject)]
+    session_store: Arc<dyn SessionStoreInterface>,
+}
+
+impl ErrorScreen {
+    pub fn new(
+        event_bus: Arc<dyn EventBusInterface>,
+        theme_service: Arc<dyn ThemeServiceInterface>,
+        session_store: Arc<dyn SessionStoreInterface>,
+    ) -> Self {
+        Self {
+            report: RwLock::new(None),
+            event_bus,
+            theme_service,
+            session_store,
+        }
+    }
+
+    pub fn get_report(&self) -> Option<ErrorReport> {
+        self.report.read().unwrap().clone()
+    }
+
+    fn can_retry(&self) -> bool {
+        self.get_report().is_some_and(|report| report.retryable)
+    }
+
+    fn report_lines(report: &ErrorReport, colors: &Colors) -> Vec<Line<'static>> {
+        let mut lines = vec![Line::from(Span::styled(
+            format!("❌ {}", report.title),
+            Style::default().fg(colors.error()),
+        ))];
+        if let Some(detail) = &report.detail {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                detail.clone(),
+                Style::default().fg(colors.text_secondary()),
+            )));
+        }
+        if !report.suggestions.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "💡 Try:",
+                Style::default().fg(colors.info()),
+            )));
+            lines.extend(report.suggestions.iter().map(|suggestion| {
+                Line::from(Span::styled(
+                    format!("• {}", suggestion),
+                    Style::default().fg(colors.text()),
+                ))
+            }));
+        }
+        lines
+    }
+
+    fn footer_line(&self, colors: &Colors) -> Line<'static> {
+        let mut spans = Vec::new();
+        if self.can_retry() {
+            spans.push(Span::styled(
+                "[R]",
+                Style::default().fg(colors.key_action()),
+            ));
+            spans.push(Span::styled(
+                " Retry | ",
+                Style::default().fg(colors.text()),
+            ));
+        }
+        spans.push(Span::styled(
+            "[ESC]",
+            Style::default().fg(colors.key_back()),
+        ));
+        spans.push(Span::styled(" Exit", Style::default().fg(colors.text())));
+        Line::from(spans)
+    }
+}
+
+impl Screen for ErrorScreen {
+    fn get_type(&self) -> ScreenType {
+        ScreenType::Error
+    }
+
+    fn default_provider() -> Box<dyn ScreenDataProvider>
+    where
+        Self: Sized,
+    {
+        Box::new(ErrorScreenDataProvider)
+    }
+
+    fn init_with_data(&self, _data: Box<dyn std::any::Any>) -> Result<()> {
+        *self.report.write().unwrap() = self.session_store.get_error_report();
+        Ok(())
+    }
+
+    fn handle_key_event(&self, key_event: crossterm::event::KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Char('r') | KeyCode::Char('R') if self.can_retry() => {
+                self.event_bus
+                    .as_event_bus()
+                    .publish(NavigateTo::Replace(ScreenType::Loading));
+            }
+            KeyCode::Esc => self.event_bus.as_event_bus().publish(NavigateTo::Exit),
+            KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Exit)
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn render_ratatui(&self, frame: &mut Frame) -> Result<()> {
+        let colors = self.theme_service.get_colors();
+        let report = self.get_report().unwrap_or_else(|| ErrorReport {
+            title: "Loading failed".to_string(),
+            detail: None,
+            suggestions: Vec::new(),
+            retryable: true,
+        });
+
+        let area = frame.area();
+        let lines = Self::report_lines(&report, &colors);
+        let content_width = area.width.saturating_sub(8).min(100);
+        let content_height = lines.len() as u16 + 2;
+        // Content + spacing + log path + spacing + navigation
+        let top_spacing = area.height.saturating_sub(content_height + 5) / 2;
+        let horizontal_margin = area.width.saturating_sub(content_width) / 2;
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(top_spacing),
+                Constraint::Length(content_height), // Report
+                Constraint::Length(1),              // Spacing
+                Constraint::Length(1),              // Log path
+                Constraint::Length(1),              // Spacing
+                Constraint::Length(1),              // Navigation
+                Constraint::Min(0),
+            ])
+            .split(area);
+        let content_area = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(horizontal_margin),
+                Constraint::Length(content_width),
+                Constraint::Min(0),
+            ])
+            .split(chunks[1])[1];
+
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: false }),
+            content_area,
+        );
+
+        let log_path = get_current_log_file_path()
+            .unwrap_or_else(|| "~/.gittype/logs/gittype_YYYYMMDD_HHMMSS.log".to_string());
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                format!("📋 Details logged to {}", log_path),
+                Style::default().fg(colors.text_secondary()),
+            )))
+            .alignment(Alignment::Center),
+            chunks[3],
+        );
+        frame.render_widget(
+            Paragraph::new(self.footer_line(&colors)).alignment(Alignment::Center),
+            chunks[5],
+        );
+
+        Ok(())
+    }
+
+    fn get_update_strategy(&self) -> UpdateStrategy {
+        UpdateStrategy::InputOnly
+    }
+
+    fn update(&self) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn is_exitable(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ErrorScreenInterface for ErrorScreen {}
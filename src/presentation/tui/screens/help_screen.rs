@@ -1,6 +1,8 @@
 use crate::domain::events::presentation_events::NavigateTo;
 use crate::domain::events::EventBusInterface;
 use crate::domain::models::rank::{Rank, RankTier};
+use crate::domain::models::{KeyBinding, Keymap, KeymapContext};
+use crate::domain::services::keymap_service::KeymapServiceInterface;
 use crate::domain::services::theme_service::ThemeServiceInterface;
 use crate::infrastructure::browser;
 use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
@@ -9,7 +11,7 @@ use crate::Result;
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
-    style::Style,
+    style::{Color, Style},
     text::{Line, Span, Text},
     widgets::{
         Block, Borders, Clear, Padding, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
@@ -26,6 +28,7 @@ pub enum HelpSection {
     Scoring,
     Ranks,
     GameHelp,
+    Keybindings,
     #[default]
     CLI,
     About,
@@ -39,6 +42,7 @@ impl HelpSection {
             HelpSection::Scoring => "Scoring System",
             HelpSection::Ranks => "Rank System",
             HelpSection::GameHelp => "Game Help",
+            HelpSection::Keybindings => "Keys",
             HelpSection::CLI => "CLI Usage",
             HelpSection::About => "About & Credits",
             HelpSection::ThirdPartyLicenses => "Third-Party Licenses",
@@ -52,6 +56,7 @@ impl HelpSection {
             HelpSection::Scoring,
             HelpSection::Ranks,
             HelpSection::GameHelp,
+            HelpSection::Keybindings,
             HelpSection::Community,
             HelpSection::About,
             HelpSection::ThirdPartyLicenses,
@@ -83,12 +88,15 @@ pub struct HelpScreen {
     event_bus: Arc<dyn EventBusInterface>,
     #[shaku(inject)]
     theme_service: Arc<dyn ThemeServiceInterface>,
+    #[shaku(inject)]
+    keymap_service: Arc<dyn KeymapServiceInterface>,
 }
 
 impl HelpScreen {
     pub fn new(
         event_bus: Arc<dyn EventBusInterface>,
         theme_service: Arc<dyn ThemeServiceInterface>,
+        keymap_service: Arc<dyn KeymapServiceInterface>,
     ) -> Self {
         Self {
             current_section: RwLock::new(HelpSection::CLI),
@@ -98,6 +106,7 @@ impl HelpScreen {
             viewport_height: RwLock::new(0),
             event_bus,
             theme_service,
+            keymap_service,
         }
     }
 
@@ -454,6 +463,37 @@ impl HelpScreen {
         ])
     }
 
+    fn get_keybindings_content(keymap: &Keymap, colors: &Colors) -> Text<'static> {
+        let lines = keymap
+            .contexts()
+            .enumerate()
+            .flat_map(|(index, (context, bindings))| {
+                let header = Line::from(vec![Span::styled(
+                    format!("{}:", context.title()),
+                    Style::default().fg(colors.title()).bold(),
+                )]);
+                let separator = (index > 0).then(|| Line::from(""));
+                separator
+                    .into_iter()
+                    .chain([header, Line::from("")])
+                    .chain(bindings.iter().map(|binding| {
+                        Line::from(vec![
+                            Span::styled(
+                                format!("{:<16}", binding.keys),
+                                Style::default().fg(colors.key_action()),
+                            ),
+                            Span::styled(
+                                binding.action.clone(),
+                                Style::default().fg(colors.text()),
+                            ),
+                        ])
+                    }))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        Text::from(lines)
+    }
+
     fn get_cli_content(colors: &Colors) -> Text<'static> {
         Text::from(vec![
             Line::from(vec![Span::styled(
@@ -883,12 +923,13 @@ impl HelpScreen {
         frame.render_widget(tabs, area);
     }
 
-    fn render_content(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+    fn render_content(&self, frame: &mut Frame, area: Rect, keymap: &Keymap, colors: &Colors) {
         let current_section = *self.current_section.read().unwrap();
         let content = match current_section {
             HelpSection::Scoring => Self::get_scoring_content(colors),
             HelpSection::Ranks => Self::get_ranks_content(colors),
             HelpSection::GameHelp => Self::get_game_help_content(colors),
+            HelpSection::Keybindings => Self::get_keybindings_content(keymap, colors),
             HelpSection::CLI => Self::get_cli_content(colors),
             HelpSection::About => Self::get_about_content(colors),
             HelpSection::ThirdPartyLicenses => Self::get_third_party_licenses_content(),
@@ -994,7 +1035,7 @@ impl HelpScreen {
         frame.render_widget(back_para, back_area);
     }
 
-    fn render_footer(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+    fn render_footer(&self, frame: &mut Frame, area: Rect, keymap: &Keymap, colors: &Colors) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -1023,21 +1064,37 @@ impl HelpScreen {
         frame.render_widget(star_para, chunks[1]);
 
         // Instructions
-        let instructions = vec![
-            Span::styled("[←→/HL]", Style::default().fg(colors.info())),
-            Span::styled(" Switch tabs ", Style::default().fg(colors.text())),
-            Span::styled("[↑↓/JK]", Style::default().fg(colors.info())),
-            Span::styled(" Scroll ", Style::default().fg(colors.text())),
-            Span::styled("[G]", Style::default().fg(colors.key_action())),
-            Span::styled(" GitHub ", Style::default().fg(colors.text())),
-            Span::styled("[ESC]", Style::default().fg(colors.error())),
-            Span::styled(" Close", Style::default().fg(colors.text())),
-        ];
+        let bindings = keymap.bindings_for(KeymapContext::Help);
+        let instructions: Vec<Span> = bindings
+            .iter()
+            .enumerate()
+            .flat_map(|(index, binding)| {
+                let separator = if index + 1 < bindings.len() { " " } else { "" };
+                [
+                    Span::styled(
+                        format!("[{}]", binding.keys),
+                        Style::default().fg(Self::key_color(binding, colors)),
+                    ),
+                    Span::styled(
+                        format!(" {}{}", binding.action, separator),
+                        Style::default().fg(colors.text()),
+                    ),
+                ]
+            })
+            .collect();
         let instructions_para =
             Paragraph::new(Line::from(instructions)).alignment(Alignment::Center);
         frame.render_widget(instructions_para, chunks[3]);
     }
 
+    fn key_color(binding: &KeyBinding, colors: &Colors) -> Color {
+        match binding.keys.as_str() {
+            "ESC" => colors.error(),
+            keys if keys.contains(['←', '↑']) => colors.info(),
+            _ => colors.key_action(),
+        }
+    }
+
     fn centered_rect(width: u16, height: u16, r: Rect) -> Rect {
         let popup_layout = Layout::default()
             .direction(Direction::Vertical)
@@ -1157,6 +1214,12 @@ impl Screen for HelpScreen {
                         Ok(())
                     }
                 }
+                KeyCode::Char('t') => {
+                    self.event_bus
+                        .as_event_bus()
+                        .publish(NavigateTo::Push(ScreenType::Tutorial));
+                    Ok(())
+                }
                 KeyCode::Esc => {
                     self.event_bus.as_event_bus().publish(NavigateTo::Pop);
                     Ok(())
@@ -1189,9 +1252,10 @@ impl Screen for HelpScreen {
             ])
             .split(area);
 
+        let keymap = self.keymap_service.keymap();
         self.render_tabs(frame, chunks[0], &colors);
-        self.render_content(frame, chunks[1], &colors);
-        self.render_footer(frame, chunks[2], &colors);
+        self.render_content(frame, chunks[1], &keymap, &colors);
+        self.render_footer(frame, chunks[2], &keymap, &colors);
 
         Ok(())
     }
@@ -0,0 +1,448 @@
+use crate::domain::events::presentation_events::NavigateTo;
+use crate::domain::events::EventBusInterface;
+use crate::domain::models::LatencyEstimate;
+use crate::domain::services::config_service::{ConfigService, ConfigServiceInterface};
+use crate::domain::services::session_manager_service::{SessionManager, SessionManagerInterface};
+use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
+use crate::presentation::ui::Colors;
+use crate::{GitTypeError, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph},
+    Frame,
+};
+use std::collections::VecDeque;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+const LATENCY_SAMPLES: usize = 10;
+const MAX_LOGGED_KEYS: usize = 12;
+
+/// Combinations some terminals swallow or rewrite, e.g. Ctrl+Backspace arriving as Ctrl+H
+pub const KEY_CHECKLIST: &[&str] = &[
+    "Tab",
+    "Shift+Tab",
+    "Backspace",
+    "Ctrl+Backspace",
+    "Alt+Backspace",
+    "Ctrl+←",
+    "Ctrl+→",
+    "Ctrl+W",
+    "Alt+B",
+];
+
+pub trait KeyboardTestScreenInterface: Screen {}
+
+/// Shows which key events the terminal delivers and calibrates stage timing for its latency
+#[derive(shaku::Component)]
+#[shaku(interface = KeyboardTestScreenInterface)]
+pub struct KeyboardTestScreen {
+    #[shaku(default)]
+    key_log: RwLock<VecDeque<(String, KeyEvent)>>,
+
+    #[shaku(default)]
+    latency: RwLock<Option<LatencyEstimate>>,
+
+    #[shaku(default)]
+    probe_error: RwLock<Option<String>>,
+
+    #[shaku(inject)]
+    event_bus: Arc<dyn EventBusInterface>,
+    #[shaku(inject)]
+    theme_service: Arc<dyn ThemeServiceInterface>,
+    #[shaku(inject)]
+    config_service: Arc<dyn ConfigServiceInterface>,
+    #[shaku(inject)]
+    session_manager: Arc<dyn SessionManagerInterface>,
+}
+
+impl KeyboardTestScreen {
+    pub fn new(
+        event_bus: Arc<dyn EventBusInterface>,
+        theme_service: Arc<dyn ThemeServiceInterface>,
+        config_service: Arc<dyn ConfigServiceInterface>,
+        session_manager: Arc<dyn SessionManagerInterface>,
+    ) -> Self {
+        Self {
+            key_log: RwLock::new(VecDeque::new()),
+            latency: RwLock::new(None),
+            probe_error: RwLock::new(None),
+            event_bus,
+            theme_service,
+            config_service,
+            session_manager,
+        }
+    }
+
+    #[cfg(feature = "test-mocks")]
+    pub fn set_latency_samples_for_test(&self, samples: &[Duration]) {
+        *self.latency.write().unwrap() = LatencyEstimate::from_samples(samples);
+        *self.probe_error.write().unwrap() = None;
+    }
+
+    /// Human-readable name of a key event, e.g. "Ctrl+Alt+C" or "Shift+Tab"
+    pub fn describe_key(key_event: &KeyEvent) -> String {
+        let mut modifiers = key_event.modifiers;
+        let key = match key_event.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => {
+                if c.is_uppercase() {
+                    modifiers.remove(KeyModifiers::SHIFT);
+                }
+                if modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) {
+                    c.to_ascii_uppercase().to_string()
+                } else {
+                    c.to_string()
+                }
+            }
+            KeyCode::BackTab => {
+                modifiers.insert(KeyModifiers::SHIFT);
+                "Tab".to_string()
+            }
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            code => format!("{:?}", code),
+        };
+
+        [
+            (KeyModifiers::CONTROL, "Ctrl+"),
+            (KeyModifiers::ALT, "Alt+"),
+            (KeyModifiers::SUPER, "Super+"),
+            (KeyModifiers::SHIFT, "Shift+"),
+        ]
+        .iter()
+        .filter(|(modifier, _)| modifiers.contains(*modifier))
+        .map(|(_, prefix)| *prefix)
+        .chain(std::iter::once(key.as_str()))
+        .collect()
+    }
+
+    pub fn logged_keys(&self) -> Vec<String> {
+        self.key_log
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    pub fn latency(&self) -> Option<LatencyEstimate> {
+        *self.latency.read().unwrap()
+    }
+
+    fn log_key(&self, key_event: KeyEvent) {
+        let mut key_log = self.key_log.write().unwrap();
+        key_log.push_front((Self::describe_key(&key_event), key_event));
+        key_log.truncate(MAX_LOGGED_KEYS);
+    }
+
+    fn has_seen(&self, name: &str) -> bool {
+        self.key_log
+            .read()
+            .unwrap()
+            .iter()
+            .any(|(logged, _)| logged == name)
+    }
+
+    /// Time cursor position reports; the round trip covers the same terminal path as a keystroke
+    fn measure_latency(&self) {
+        let result = if crossterm::terminal::is_raw_mode_enabled().unwrap_or(false) {
+            (0..LATENCY_SAMPLES)
+                .map(|_| {
+                    let started = Instant::now();
+                    crossterm::cursor::position()
+                        .map(|_| started.elapsed())
+                        .map_err(GitTypeError::from)
+                })
+                .collect::<Result<Vec<_>>>()
+                .map(|samples| LatencyEstimate::from_samples(&samples))
+        } else {
+            Err(GitTypeError::TerminalError(
+                "Terminal is not interactive".to_string(),
+            ))
+        };
+
+        match result {
+            Ok(estimate) => {
+                *self.latency.write().unwrap() = estimate;
+                *self.probe_error.write().unwrap() = None;
+            }
+            Err(e) => {
+                *self.latency.write().unwrap() = None;
+                *self.probe_error.write().unwrap() = Some(e.to_string());
+            }
+        }
+    }
+
+    /// Store the estimate in config.json and apply it to the stages that follow
+    fn save_calibration(&self) {
+        let Some(estimate) = self.latency() else {
+            return;
+        };
+        let latency_ms = estimate.compensation_ms();
+
+        if let Some(config_service) =
+            (self.config_service.as_ref() as &dyn std::any::Any).downcast_ref::<ConfigService>()
+        {
+            let _ = config_service.update_config(|config| config.input.latency_ms = latency_ms);
+            let _ = self.config_service.save();
+        }
+        if let Some(session_manager) = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+        {
+            session_manager.set_latency_compensation(Duration::from_millis(latency_ms));
+        }
+    }
+
+    fn render_key_log(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let key_log = self.key_log.read().unwrap();
+        let lines: Vec<Line> = if key_log.is_empty() {
+            vec![Line::from(Span::styled(
+                "Press any key or combination...",
+                Style::default().fg(colors.text_secondary()),
+            ))]
+        } else {
+            key_log
+                .iter()
+                .map(|(name, key_event)| {
+                    Line::from(vec![
+                        Span::styled(
+                            format!("{:<20}", name),
+                            Style::default().fg(colors.key_action()).bold(),
+                        ),
+                        Span::styled(
+                            format!("{:?}  {:?}", key_event.code, key_event.modifiers),
+                            Style::default().fg(colors.text_secondary()),
+                        ),
+                    ])
+                })
+                .collect()
+        };
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border()))
+                .title("Received Keys")
+                .padding(Padding::horizontal(1)),
+        );
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_checklist(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let lines: Vec<Line> = KEY_CHECKLIST
+            .iter()
+            .map(|name| {
+                let (mark, style) = if self.has_seen(name) {
+                    ("✓ ", Style::default().fg(colors.success()))
+                } else {
+                    ("· ", Style::default().fg(colors.text_secondary()))
+                };
+                Line::from(vec![
+                    Span::styled(mark, style),
+                    Span::styled(name.to_string(), Style::default().fg(colors.text())),
+                ])
+            })
+            .collect();
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border()))
+                .title("Try These")
+                .padding(Padding::horizontal(1)),
+        );
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_latency(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let saved_ms = self.config_service.get_config().input.latency_ms;
+        let ms = |duration: Duration| format!("{:.1} ms", duration.as_secs_f64() * 1000.0);
+
+        let measurement = match (self.latency(), self.probe_error.read().unwrap().clone()) {
+            (Some(estimate), _) => Line::from(vec![
+                Span::styled("Round trip: ", Style::default().fg(colors.cpm_wpm())),
+                Span::styled(ms(estimate.median), Style::default().fg(colors.text())),
+                Span::styled(
+                    format!(
+                        " median (min {}, max {}, {} samples)",
+                        ms(estimate.min),
+                        ms(estimate.max),
+                        estimate.samples
+                    ),
+                    Style::default().fg(colors.text_secondary()),
+                ),
+                Span::raw("  │  "),
+                Span::styled("Compensation: ", Style::default().fg(colors.accuracy())),
+                Span::styled(
+                    format!("{} ms", estimate.compensation_ms()),
+                    Style::default().fg(colors.text()),
+                ),
+            ]),
+            (None, Some(error)) => Line::from(Span::styled(
+                format!("Latency could not be measured: {}", error),
+                Style::default().fg(colors.warning()),
+            )),
+            (None, None) => Line::from(Span::styled(
+                "Measuring...",
+                Style::default().fg(colors.text_secondary()),
+            )),
+        };
+
+        let lines = vec![
+            measurement,
+            Line::from(vec![
+                Span::styled(
+                    "Saved calibration: ",
+                    Style::default().fg(colors.duration()),
+                ),
+                Span::styled(
+                    format!("{} ms", saved_ms),
+                    Style::default().fg(colors.text()),
+                ),
+                Span::styled(
+                    " is removed from the time of each completed stage",
+                    Style::default().fg(colors.text_secondary()),
+                ),
+            ]),
+        ];
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border()))
+                .title("Input Latency")
+                .padding(Padding::horizontal(1)),
+        );
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_footer(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let mut instructions = Vec::new();
+        if self.latency().is_some() {
+            instructions.extend([
+                Span::styled("[ENTER]", Style::default().fg(colors.success())),
+                Span::styled(" Save calibration ", Style::default().fg(colors.text())),
+            ]);
+        }
+        instructions.extend([
+            Span::styled("[F5]", Style::default().fg(colors.info())),
+            Span::styled(" Re-measure ", Style::default().fg(colors.text())),
+            Span::styled("[ESC]", Style::default().fg(colors.error())),
+            Span::styled(" Close", Style::default().fg(colors.text())),
+        ]);
+
+        let footer = Paragraph::new(Line::from(instructions)).alignment(Alignment::Center);
+        frame.render_widget(footer, area);
+    }
+}
+
+pub struct KeyboardTestScreenDataProvider;
+
+impl ScreenDataProvider for KeyboardTestScreenDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(()))
+    }
+}
+
+impl Screen for KeyboardTestScreen {
+    fn get_type(&self) -> ScreenType {
+        ScreenType::KeyboardTest
+    }
+
+    fn default_provider() -> Box<dyn ScreenDataProvider>
+    where
+        Self: Sized,
+    {
+        Box::new(KeyboardTestScreenDataProvider)
+    }
+
+    fn init_with_data(&self, _data: Box<dyn std::any::Any>) -> Result<()> {
+        self.key_log.write().unwrap().clear();
+        self.measure_latency();
+        Ok(())
+    }
+
+    fn handle_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Esc => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Pop);
+            }
+            KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+            }
+            KeyCode::Enter if self.latency().is_some() => {
+                self.save_calibration();
+                self.event_bus.as_event_bus().publish(NavigateTo::Pop);
+            }
+            KeyCode::F(5) => {
+                self.log_key(key_event);
+                self.measure_latency();
+            }
+            _ => self.log_key(key_event),
+        }
+        Ok(())
+    }
+
+    fn render_ratatui(&self, frame: &mut Frame) -> Result<()> {
+        let colors = self.theme_service.get_colors();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(4),
+                Constraint::Length(1),
+            ])
+            .split(frame.area());
+
+        let header = Paragraph::new(Line::from(vec![
+            Span::styled("Keyboard Test", Style::default().fg(colors.title()).bold()),
+            Span::styled(
+                " - press keys to see exactly what your terminal sends",
+                Style::default().fg(colors.text_secondary()),
+            ),
+        ]))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border())),
+        );
+        frame.render_widget(header, chunks[0]);
+
+        let body = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+            .split(chunks[1]);
+        self.render_key_log(frame, body[0], &colors);
+        self.render_checklist(frame, body[1], &colors);
+        self.render_latency(frame, chunks[2], &colors);
+        self.render_footer(frame, chunks[3], &colors);
+
+        Ok(())
+    }
+
+    fn get_update_strategy(&self) -> UpdateStrategy {
+        UpdateStrategy::InputOnly
+    }
+
+    fn update(&self) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl KeyboardTestScreenInterface for KeyboardTestScreen {}
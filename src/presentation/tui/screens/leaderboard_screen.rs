@@ -0,0 +1,292 @@
+use crate::domain::events::presentation_events::NavigateTo;
+use crate::domain::events::EventBusInterface;
+use crate::domain::models::player_profile::DEFAULT_DISPLAY_NAME;
+use crate::domain::models::storage::LeaderboardEntry;
+use crate::domain::models::{Locale, PlayerProfile};
+use crate::domain::services::repository_detail_service::RepositoryDetailServiceInterface;
+use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::presentation::tui::screens::RepoDetailScreen;
+use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
+use crate::presentation::ui::Colors;
+use crate::{GitTypeError, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph},
+    Frame,
+};
+use std::sync::{Arc, RwLock};
+
+/// Repository whose leaderboard is shown, with its entries best first
+#[derive(Debug, Clone, Default)]
+pub struct LeaderboardScreenData {
+    pub repo_spec: String,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+pub trait LeaderboardScreenInterface: Screen {}
+
+/// Ranks the local profiles that played a repository by their best session score
+#[derive(shaku::Component)]
+#[shaku(interface = LeaderboardScreenInterface)]
+pub struct LeaderboardScreen {
+    #[shaku(default)]
+    data: RwLock<LeaderboardScreenData>,
+    #[shaku(inject)]
+    event_bus: Arc<dyn EventBusInterface>,
+    #[shaku(inject)]
+    theme_service: Arc<dyn ThemeServiceInterface>,
+    #[shaku(inject)]
+    repository_detail_service: Arc<dyn RepositoryDetailServiceInterface>,
+}
+
+impl LeaderboardScreen {
+    pub fn new(
+        event_bus: Arc<dyn EventBusInterface>,
+        theme_service: Arc<dyn ThemeServiceInterface>,
+        repository_detail_service: Arc<dyn RepositoryDetailServiceInterface>,
+    ) -> Self {
+        Self {
+            data: RwLock::new(LeaderboardScreenData::default()),
+            event_bus,
+            theme_service,
+            repository_detail_service,
+        }
+    }
+
+    pub fn get_data(&self) -> LeaderboardScreenData {
+        self.data.read().unwrap().clone()
+    }
+
+    fn render_header(&self, frame: &mut Frame, area: Rect, repo_spec: &str, colors: &Colors) {
+        let header = Paragraph::new(Line::from(vec![
+            Span::styled(
+                "Leaderboard",
+                Style::default()
+                    .fg(colors.title())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("  {}", repo_spec),
+                Style::default().fg(colors.text_secondary()),
+            ),
+        ]))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border())),
+        );
+        frame.render_widget(header, area);
+    }
+
+    fn render_entries(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        entries: &[LeaderboardEntry],
+        colors: &Colors,
+    ) {
+        let active_player =
+            PlayerProfile::active_player().unwrap_or_else(|| DEFAULT_DISPLAY_NAME.to_string());
+        let locale = Locale::current();
+
+        let lines: Vec<Line> = if entries.is_empty() {
+            vec![Line::from(Span::styled(
+                "No sessions recorded for this repository yet",
+                Style::default().fg(colors.text_secondary()),
+            ))]
+        } else {
+            std::iter::once(Line::from(Span::styled(
+                format!(
+                    "  {:>3}  {:<20} {:>10} {:>9} {:>9} {:>9}  {}",
+                    "#", "Player", "Score", "WPM", "Accuracy", "Sessions", "Last played"
+                ),
+                Style::default()
+                    .fg(colors.text_secondary())
+                    .add_modifier(Modifier::BOLD),
+            )))
+            .chain(entries.iter().enumerate().map(|(index, entry)| {
+                let is_active = entry.player_name == active_player;
+                let name_style = if is_active {
+                    Style::default()
+                        .fg(colors.success())
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(colors.text())
+                };
+                let last_played = entry
+                    .last_played_at
+                    .map(|played_at| {
+                        locale.format_datetime(
+                            played_at.with_timezone(&chrono::Local).naive_local(),
+                            false,
+                        )
+                    })
+                    .unwrap_or_default();
+                Line::from(vec![
+                    Span::styled(if is_active { "▶ " } else { "  " }, name_style),
+                    Span::styled(
+                        format!("{:>3}  ", index + 1),
+                        Style::default().fg(colors.text_secondary()),
+                    ),
+                    Span::styled(format!("{:<20} ", entry.player_name), name_style),
+                    Span::styled(
+                        format!("{:>10} ", locale.format_decimal(entry.best_score, 0)),
+                        Style::default().fg(colors.score()),
+                    ),
+                    Span::styled(
+                        format!("{:>9} ", locale.format_decimal(entry.best_wpm, 1)),
+                        Style::default().fg(colors.cpm_wpm()),
+                    ),
+                    Span::styled(
+                        format!(
+                            "{:>9} ",
+                            format!("{}%", locale.format_decimal(entry.best_accuracy, 1))
+                        ),
+                        Style::default().fg(colors.accuracy()),
+                    ),
+                    Span::styled(
+                        format!("{:>9}  ", entry.sessions),
+                        Style::default().fg(colors.text()),
+                    ),
+                    Span::styled(last_played, Style::default().fg(colors.text_secondary())),
+                ])
+            }))
+            .collect()
+        };
+
+        let table = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border()))
+                .title("Best session per profile")
+                .title_style(
+                    Style::default()
+                        .fg(colors.text())
+                        .add_modifier(Modifier::BOLD),
+                )
+                .padding(Padding::horizontal(1)),
+        );
+        frame.render_widget(table, area);
+    }
+
+    fn render_controls(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let controls = Paragraph::new(vec![
+            Line::from(Span::styled(
+                "Profiles are display names; change yours in the profile screen to play as someone else",
+                Style::default().fg(colors.text_secondary()),
+            )),
+            Line::from(vec![
+                Span::styled("[ESC]", Style::default().fg(colors.key_back())),
+                Span::styled(" Back", Style::default().fg(colors.text())),
+            ]),
+        ])
+        .alignment(Alignment::Center);
+        frame.render_widget(controls, area);
+    }
+}
+
+pub struct LeaderboardScreenDataProvider;
+
+impl ScreenDataProvider for LeaderboardScreenDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(()))
+    }
+}
+
+impl Screen for LeaderboardScreen {
+    fn get_type(&self) -> ScreenType {
+        ScreenType::Leaderboard
+    }
+
+    fn default_provider() -> Box<dyn ScreenDataProvider>
+    where
+        Self: Sized,
+    {
+        Box::new(LeaderboardScreenDataProvider)
+    }
+
+    fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
+        if let Ok(data) = data.downcast::<LeaderboardScreenData>() {
+            *self.data.write().unwrap() = *data;
+        }
+        Ok(())
+    }
+
+    fn on_pushed_from(&self, source_screen: &dyn Screen) -> Result<()> {
+        let detail = source_screen
+            .as_any()
+            .downcast_ref::<RepoDetailScreen>()
+            .and_then(|repo_detail| repo_detail.get_detail())
+            .ok_or_else(|| {
+                GitTypeError::ScreenInitializationError(
+                    "Leaderboard requires a repository shown in RepoDetail".to_string(),
+                )
+            })?;
+
+        let entries = self
+            .repository_detail_service
+            .get_leaderboard(&detail.repository)?;
+        *self.data.write().unwrap() = LeaderboardScreenData {
+            repo_spec: detail.repo_spec(),
+            entries,
+        };
+        Ok(())
+    }
+
+    fn handle_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        if key_event.kind != KeyEventKind::Press {
+            return Ok(());
+        }
+
+        match key_event.code {
+            KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+            }
+            KeyCode::Esc => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Pop);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn render_ratatui(&self, frame: &mut Frame) -> Result<()> {
+        let colors = self.theme_service.get_colors();
+        let data = self.get_data();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Header
+                Constraint::Min(1),    // Entries
+                Constraint::Length(2), // Controls
+            ])
+            .split(frame.area());
+
+        self.render_header(frame, chunks[0], &data.repo_spec, &colors);
+        self.render_entries(frame, chunks[1], &data.entries, &colors);
+        self.render_controls(frame, chunks[2], &colors);
+        Ok(())
+    }
+
+    fn get_update_strategy(&self) -> UpdateStrategy {
+        UpdateStrategy::InputOnly
+    }
+
+    fn update(&self) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn is_exitable(&self) -> bool {
+        true
+    }
+}
+
+impl LeaderboardScreenInterface for LeaderboardScreen {}
@@ -1,8 +1,13 @@
 use crate::domain::events::presentation_events::ExitRequested;
 use crate::domain::events::EventBusInterface;
-use crate::domain::models::loading::{ExecutionContext, StepManager, StepType};
-use crate::domain::models::{Challenge, ExtractionOptions, GitRepository};
+use crate::domain::models::loading::{
+    CancellationToken, ExecutionContext, ProgressReporter, StepManager, StepType,
+};
+use crate::domain::models::{
+    Challenge, ErrorReport, ExtractionOptions, GitRepository, StreamerMode,
+};
 use crate::domain::repositories::challenge_repository::ChallengeRepositoryInterface;
+use crate::domain::services::challenge_flag_service::ChallengeFlagServiceInterface;
 use crate::domain::services::session_manager_service::SessionManagerInterface;
 use crate::domain::services::stage_builder_service::StageRepositoryInterface;
 use crate::domain::services::theme_service::ThemeServiceInterface;
@@ -23,42 +28,14 @@ use std::sync::{
 use std::thread;
 use std::time::Duration;
 
-pub trait ProgressReporter: Sync {
-    fn set_step(&self, step_type: StepType);
-    fn set_current_file(&self, file: Option<String>);
-    fn set_file_counts(
-        &self,
-        step_type: StepType,
-        processed: usize,
-        total: usize,
-        current_file: Option<String>,
-    );
-    fn finish(&self) -> Result<()> {
-        Ok(())
-    }
-}
-
-pub struct NoOpProgressReporter;
-
-impl ProgressReporter for NoOpProgressReporter {
-    fn set_step(&self, _step_type: StepType) {}
-    fn set_current_file(&self, _file: Option<String>) {}
-    fn set_file_counts(
-        &self,
-        _step_type: StepType,
-        _processed: usize,
-        _total: usize,
-        _current_file: Option<String>,
-    ) {
-    }
-}
-
 #[derive(Clone)]
 pub struct LoadingScreenState {
     pub current_step: Arc<RwLock<StepType>>,
     pub step_progress: Arc<RwLock<std::collections::HashMap<StepType, StepProgress>>>,
     pub spinner_index: Arc<AtomicUsize>,
     pub should_stop: Arc<AtomicBool>,
+    pub cancellation: CancellationToken,
+    pub sub_step: Arc<RwLock<Option<String>>>,
     pub repo_info: Arc<RwLock<Option<String>>>,
     pub all_steps: Arc<RwLock<Vec<StepInfo>>>,
 }
@@ -82,6 +59,8 @@ impl Default for LoadingScreenState {
             step_progress: Arc::new(RwLock::new(std::collections::HashMap::new())),
             spinner_index: Arc::new(AtomicUsize::new(0)),
             should_stop: Arc::new(AtomicBool::new(false)),
+            cancellation: CancellationToken::new(),
+            sub_step: Arc::new(RwLock::new(None)),
             repo_info: Arc::new(RwLock::new(None)),
             all_steps: Arc::new(RwLock::new(steps_info)),
         }
@@ -119,6 +98,8 @@ pub struct LoadingScreen {
     #[shaku(inject)]
     challenge_repository: Arc<dyn ChallengeRepositoryInterface>,
     #[shaku(inject)]
+    challenge_flag_service: Arc<dyn ChallengeFlagServiceInterface>,
+    #[shaku(inject)]
     challenge_store: Arc<dyn ChallengeStoreInterface>,
     #[shaku(inject)]
     repository_store: Arc<dyn RepositoryStoreInterface>,
@@ -137,8 +118,11 @@ impl LoadingScreen {
         challenge_repository: Arc<dyn ChallengeRepositoryInterface>,
         theme_service: Arc<dyn ThemeServiceInterface>,
     ) -> Self {
+        use crate::domain::services::challenge_flag_service::ChallengeFlagService;
         use crate::domain::services::{stage_builder_service::StageRepository, SessionManager};
         use crate::domain::stores::{ChallengeStore, RepositoryStore, SessionStore};
+        use crate::infrastructure::database::daos::ChallengeFlagDao;
+        use crate::infrastructure::database::database::Database;
 
         let challenge_store = Arc::new(ChallengeStore::new_for_test());
         let repository_store = Arc::new(RepositoryStore::new_for_test());
@@ -165,12 +149,18 @@ impl LoadingScreen {
             total_tracker,
         )) as Arc<dyn SessionManagerInterface>;
 
+        let challenge_flag_service =
+            Arc::new(ChallengeFlagService::new(Arc::new(ChallengeFlagDao::new(
+                Arc::new(Database::new().expect("Failed to create test database")),
+            )))) as Arc<dyn ChallengeFlagServiceInterface>;
+
         Self {
             state: RwLock::new(LoadingScreenState::default()),
             render_handle: RwLock::new(None),
             event_bus,
             theme_service,
             challenge_repository,
+            challenge_flag_service,
             challenge_store,
             repository_store,
             session_store,
@@ -245,6 +235,7 @@ impl LoadingScreen {
         let repo_path_owned = repo_path.cloned();
         let event_bus = self.event_bus.clone();
         let challenge_repository = self.challenge_repository.clone();
+        let challenge_flag_service = self.challenge_flag_service.clone();
         let challenge_store = self.challenge_store.clone();
         let repository_store = self.repository_store.clone();
         let session_store = self.session_store.clone();
@@ -258,6 +249,7 @@ impl LoadingScreen {
                 render_handle: RwLock::new(None),
                 event_bus: event_bus.clone(),
                 challenge_repository,
+                challenge_flag_service,
                 challenge_store: challenge_store.clone(),
                 repository_store: repository_store.clone(),
                 session_store: session_store.clone(),
@@ -281,6 +273,7 @@ impl LoadingScreen {
                     log::error!("Repository processing failed: {}", e);
                     session_store.set_loading_failed(true);
                     session_store.set_error_message(format!("Repository processing failed: {}", e));
+                    session_store.set_error_report(ErrorReport::from_error(&e));
                 }
             }
 
@@ -298,18 +291,21 @@ impl LoadingScreen {
     }
 
     pub fn set_git_repository(&self, git_repository: &GitRepository) -> Result<()> {
-        let mut parts = vec![format!(
-            "📁 {}/{}",
-            git_repository.user_name, git_repository.repository_name
-        )];
-
-        if let Some(ref branch) = git_repository.branch {
-            parts.push(format!("🌿 {}", branch));
-        }
-
-        if let Some(ref commit) = git_repository.commit_hash {
-            parts.push(format!("📝 {}", &commit[..8]));
-        }
+        let mut parts = if StreamerMode::is_enabled() {
+            vec![format!("📁 {}", StreamerMode::HIDDEN_REPOSITORY)]
+        } else {
+            let mut parts = vec![format!(
+                "📁 {}/{}",
+                git_repository.user_name, git_repository.repository_name
+            )];
+            if let Some(ref branch) = git_repository.branch {
+                parts.push(format!("🌿 {}", branch));
+            }
+            if let Some(ref commit) = git_repository.commit_hash {
+                parts.push(format!("📝 {}", &commit[..8]));
+            }
+            parts
+        };
 
         let status_symbol = if git_repository.is_dirty {
             "⚠️"
@@ -363,7 +359,9 @@ impl LoadingScreen {
             repo_path,
             extraction_options: Some(options),
             loading_screen: Some(self),
+            progress_reporter: None,
             challenge_repository: Some(self.challenge_repository.clone()),
+            challenge_flag_service: Some(self.challenge_flag_service.clone()),
             current_repo_path: None,
             git_repository: None,
             scanned_files: None,
@@ -421,9 +419,13 @@ impl Drop for LoadingScreen {
 
 impl ProgressReporter for LoadingScreen {
     fn set_step(&self, step_type: StepType) {
-        if let Ok(mut current_step) = self.state.read().unwrap().current_step.write() {
+        let state = self.state.read().unwrap();
+        if let Ok(mut current_step) = state.current_step.write() {
             *current_step = step_type;
         }
+        if let Ok(mut sub_step) = state.sub_step.write() {
+            *sub_step = None;
+        };
     }
 
     fn set_current_file(&self, _file: Option<String>) {
@@ -462,6 +464,16 @@ impl ProgressReporter for LoadingScreen {
             }
         }
     }
+
+    fn set_sub_step(&self, label: Option<String>) {
+        if let Ok(mut sub_step) = self.state.read().unwrap().sub_step.write() {
+            *sub_step = label;
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.state.read().unwrap().cancellation.is_cancelled()
+    }
 }
 
 impl Screen for LoadingScreen {
@@ -479,6 +491,10 @@ impl Screen for LoadingScreen {
     fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
         let loading_data = data.downcast::<LoadingScreenData>()?;
 
+        // Start over when retrying from the error screen
+        *self.state.write().unwrap() = LoadingScreenState::default();
+        self.session_store.clear();
+
         // Get processing parameters from LoadingScreenData if provided,
         // otherwise fallback to RepositoryStore
         let (repo_spec, repo_path, extraction_options) =
@@ -513,6 +529,7 @@ impl Screen for LoadingScreen {
         if key_event.code == KeyCode::Char('c')
             && key_event.modifiers.contains(KeyModifiers::CONTROL)
         {
+            self.state.read().unwrap().cancellation.cancel();
             self.event_bus.as_event_bus().publish(ExitRequested);
         }
 
@@ -0,0 +1,351 @@
+use crate::domain::events::presentation_events::NavigateTo;
+use crate::domain::events::EventBusInterface;
+use crate::domain::models::{LogEntry, LogLevel};
+use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::infrastructure::logging::{find_latest_log_file, get_log_directory, read_log_tail};
+use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
+use crate::presentation::ui::Colors;
+use crate::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Padding, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+    },
+    Frame,
+};
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+/// Lines loaded from the end of the log file
+const MAX_LINES: usize = 2000;
+
+pub struct LogViewerScreenData {
+    pub log_path: Option<PathBuf>,
+    pub entries: Vec<LogEntry>,
+}
+
+pub trait LogViewerScreenInterface: Screen {}
+
+#[derive(shaku::Component)]
+#[shaku(interface = LogViewerScreenInterface)]
+pub struct LogViewerScreen {
+    #[shaku(default)]
+    log_path: RwLock<Option<PathBuf>>,
+    #[shaku(default)]
+    entries: RwLock<Vec<LogEntry>>,
+    #[shaku(default)]
+    min_level: RwLock<Option<LogLevel>>,
+    #[shaku(default)]
+    scroll_position: RwLock<usize>,
+    #[shaku(default)]
+    viewport_height: RwLock<usize>,
+    #[shaku(inject)]
+    event_bus: Arc<dyn EventBusInterface>,
+    #[shaku(inject)]
+    theme_service: Arc<dyn ThemeServiceInterface>,
+}
+
+impl LogViewerScreen {
+    pub fn new(
+        event_bus: Arc<dyn EventBusInterface>,
+        theme_service: Arc<dyn ThemeServiceInterface>,
+    ) -> Self {
+        Self {
+            log_path: RwLock::new(None),
+            entries: RwLock::new(Vec::new()),
+            min_level: RwLock::new(None),
+            scroll_position: RwLock::new(0),
+            viewport_height: RwLock::new(0),
+            event_bus,
+            theme_service,
+        }
+    }
+
+    /// Entries shown with the current level filter
+    pub fn visible_entries(&self) -> Vec<LogEntry> {
+        let entries = self.entries.read().unwrap();
+        match *self.min_level.read().unwrap() {
+            Some(min_level) => LogEntry::filter_by_level(&entries, min_level),
+            None => entries.clone(),
+        }
+    }
+
+    pub fn min_level(&self) -> Option<LogLevel> {
+        *self.min_level.read().unwrap()
+    }
+
+    pub fn scroll_position(&self) -> usize {
+        *self.scroll_position.read().unwrap()
+    }
+
+    /// All lines → warnings and errors → errors only
+    fn cycle_filter(&self) {
+        let mut min_level = self.min_level.write().unwrap();
+        *min_level = match *min_level {
+            None => Some(LogLevel::Warn),
+            Some(LogLevel::Warn) => Some(LogLevel::Error),
+            Some(_) => None,
+        };
+        *self.scroll_position.write().unwrap() = usize::MAX;
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.visible_entries()
+            .len()
+            .saturating_sub(*self.viewport_height.read().unwrap())
+    }
+
+    fn scroll_by(&self, delta: isize) {
+        let max_scroll = self.max_scroll();
+        let mut scroll_position = self.scroll_position.write().unwrap();
+        *scroll_position = scroll_position
+            .min(max_scroll)
+            .saturating_add_signed(delta)
+            .min(max_scroll);
+    }
+
+    fn page_size(&self) -> isize {
+        (*self.viewport_height.read().unwrap()).max(1) as isize
+    }
+
+    fn level_color(level: LogLevel, colors: &Colors) -> ratatui::style::Color {
+        match level {
+            LogLevel::Error => colors.error(),
+            LogLevel::Warn => colors.warning(),
+            LogLevel::Info => colors.info(),
+            LogLevel::Debug | LogLevel::Trace => colors.text_secondary(),
+        }
+    }
+
+    fn entry_line(entry: &LogEntry, colors: &Colors) -> Line<'static> {
+        let Some(level) = entry.level else {
+            return Line::from(Span::styled(
+                entry.message.clone(),
+                Style::default().fg(colors.text()),
+            ));
+        };
+
+        let mut spans = vec![];
+        if let Some(timestamp) = &entry.timestamp {
+            spans.push(Span::styled(
+                format!("{} ", timestamp),
+                Style::default().fg(colors.text_secondary()),
+            ));
+        }
+        spans.push(Span::styled(
+            format!("{:<5} ", level.label()),
+            Style::default()
+                .fg(Self::level_color(level, colors))
+                .add_modifier(Modifier::BOLD),
+        ));
+        if let Some(target) = &entry.target {
+            spans.push(Span::styled(
+                format!("{} ", target),
+                Style::default().fg(colors.text_secondary()),
+            ));
+        }
+        spans.push(Span::styled(
+            entry.message.clone(),
+            Style::default().fg(colors.text()),
+        ));
+        Line::from(spans)
+    }
+
+    fn filter_label(&self) -> &'static str {
+        match self.min_level() {
+            None => "All",
+            Some(LogLevel::Error) => "Errors",
+            Some(_) => "Warnings",
+        }
+    }
+
+    fn render_header(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let path = self
+            .log_path
+            .read()
+            .unwrap()
+            .as_ref()
+            .map_or("No log file found".to_string(), |path| {
+                path.display().to_string()
+            });
+        let header = Paragraph::new(Line::from(vec![
+            Span::styled(
+                "Logs  ",
+                Style::default()
+                    .fg(colors.title())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(path, Style::default().fg(colors.text_secondary())),
+        ]))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border())),
+        );
+        frame.render_widget(header, area);
+    }
+
+    fn render_content(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let entries = self.visible_entries();
+        let viewport_height = area.height.saturating_sub(2) as usize;
+        *self.viewport_height.write().unwrap() = viewport_height;
+
+        let max_scroll = entries.len().saturating_sub(viewport_height);
+        let scroll_position = self.scroll_position().min(max_scroll);
+        *self.scroll_position.write().unwrap() = scroll_position;
+
+        let block = Block::default()
+            .title(format!(
+                " {} lines · Filter: {} ",
+                entries.len(),
+                self.filter_label()
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.border()))
+            .padding(Padding::horizontal(1));
+
+        if entries.is_empty() {
+            let empty = Paragraph::new(Span::styled(
+                "Nothing logged at this level.",
+                Style::default().fg(colors.text_secondary()),
+            ))
+            .alignment(Alignment::Center)
+            .block(block);
+            frame.render_widget(empty, area);
+            return;
+        }
+
+        let lines: Vec<Line> = entries
+            .iter()
+            .skip(scroll_position)
+            .take(viewport_height)
+            .map(|entry| Self::entry_line(entry, colors))
+            .collect();
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+
+        if max_scroll > 0 {
+            let mut scrollbar_state = ScrollbarState::new(max_scroll).position(scroll_position);
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight),
+                area.inner(Margin {
+                    vertical: 1,
+                    horizontal: 0,
+                }),
+                &mut scrollbar_state,
+            );
+        }
+    }
+
+    fn render_footer(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let controls = Paragraph::new(Line::from(vec![
+            Span::styled("[↑↓/JK]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Scroll  ", Style::default().fg(colors.text())),
+            Span::styled("[PGUP/PGDN]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Page  ", Style::default().fg(colors.text())),
+            Span::styled("[g/G]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Top/Bottom  ", Style::default().fg(colors.text())),
+            Span::styled("[F]", Style::default().fg(colors.key_action())),
+            Span::styled(" Filter  ", Style::default().fg(colors.text())),
+            Span::styled("[ESC]", Style::default().fg(colors.key_back())),
+            Span::styled(" Close", Style::default().fg(colors.text())),
+        ]))
+        .alignment(Alignment::Center);
+        frame.render_widget(controls, area);
+    }
+}
+
+pub struct LogViewerScreenDataProvider;
+
+impl ScreenDataProvider for LogViewerScreenDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        let log_path = find_latest_log_file(&get_log_directory()?);
+        let entries = match &log_path {
+            Some(path) => read_log_tail(path, MAX_LINES)?
+                .iter()
+                .map(|line| LogEntry::parse(line))
+                .collect(),
+            None => Vec::new(),
+        };
+        Ok(Box::new(LogViewerScreenData { log_path, entries }))
+    }
+}
+
+impl Screen for LogViewerScreen {
+    fn get_type(&self) -> ScreenType {
+        ScreenType::LogViewer
+    }
+
+    fn default_provider() -> Box<dyn ScreenDataProvider>
+    where
+        Self: Sized,
+    {
+        Box::new(LogViewerScreenDataProvider)
+    }
+
+    fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
+        let data = data.downcast::<LogViewerScreenData>()?;
+        *self.log_path.write().unwrap() = data.log_path;
+        *self.entries.write().unwrap() = data.entries;
+        *self.min_level.write().unwrap() = None;
+        // Open at the newest lines; clamped on the first render
+        *self.scroll_position.write().unwrap() = usize::MAX;
+        Ok(())
+    }
+
+    fn handle_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_by(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_by(1),
+            KeyCode::PageUp => self.scroll_by(-self.page_size()),
+            KeyCode::PageDown => self.scroll_by(self.page_size()),
+            KeyCode::Home | KeyCode::Char('g') => *self.scroll_position.write().unwrap() = 0,
+            KeyCode::End | KeyCode::Char('G') => {
+                *self.scroll_position.write().unwrap() = self.max_scroll()
+            }
+            KeyCode::Char('f') | KeyCode::Char('F') => self.cycle_filter(),
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn render_ratatui(&self, frame: &mut Frame) -> Result<()> {
+        let colors = self.theme_service.get_colors();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(frame.area());
+
+        self.render_header(frame, chunks[0], &colors);
+        self.render_content(frame, chunks[1], &colors);
+        self.render_footer(frame, chunks[2], &colors);
+        Ok(())
+    }
+
+    fn get_update_strategy(&self) -> UpdateStrategy {
+        UpdateStrategy::InputOnly
+    }
+
+    fn update(&self) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl LogViewerScreenInterface for LogViewerScreen {}
@@ -1,10 +1,15 @@
 // Game screens
+pub mod achievements_screen;
 pub mod analytics_screen;
 pub mod animation_screen;
+pub mod error_screen;
 pub mod help_screen;
 pub mod info_dialog;
+pub mod keyboard_test_screen;
+pub mod leaderboard_screen;
 pub mod loading_screen;
 pub mod panic_screen;
+pub mod profile_screen;
 pub mod records_screen;
 pub mod session_detail_screen;
 pub mod session_details_dialog;
@@ -16,26 +21,53 @@ pub mod stage_summary_screen;
 pub mod title_screen;
 pub mod total_summary_screen;
 pub mod total_summary_share_screen;
+pub mod tutorial_screen;
 pub mod typing_screen;
 pub mod version_check_screen;
+pub mod whats_new_screen;
 
 // CLI screens
+pub mod log_viewer_screen;
+pub mod reminder_screen;
+pub mod repo_detail_screen;
 pub mod repo_list_screen;
 pub mod repo_play_screen;
 pub mod trending_language_selection_screen;
 pub mod trending_repository_selection_screen;
+pub mod workspace_package_screen;
 
 // Re-exports
+pub use achievements_screen::{
+    AchievementsScreen, AchievementsScreenDataProvider, AchievementsScreenInterface,
+};
 pub use analytics_screen::{AnalyticsScreen, AnalyticsScreenInterface, AnalyticsScreenProvider};
 pub use animation_screen::{AnimationScreen, AnimationScreenInterface};
+pub use error_screen::{ErrorScreen, ErrorScreenDataProvider, ErrorScreenInterface};
 pub use help_screen::{HelpScreen, HelpScreenInterface};
 pub use info_dialog::{
     InfoDialogScreen, InfoDialogScreenDataProvider, InfoDialogScreenInterface,
     InfoDialogScreenProvider,
 };
+pub use keyboard_test_screen::{
+    KeyboardTestScreen, KeyboardTestScreenDataProvider, KeyboardTestScreenInterface,
+};
+pub use leaderboard_screen::{
+    LeaderboardScreen, LeaderboardScreenData, LeaderboardScreenDataProvider,
+    LeaderboardScreenInterface,
+};
 pub use loading_screen::{LoadingScreen, LoadingScreenInterface};
+pub use log_viewer_screen::{
+    LogViewerScreen, LogViewerScreenData, LogViewerScreenDataProvider, LogViewerScreenInterface,
+};
 pub use panic_screen::{PanicScreen, PanicScreenInterface};
+pub use profile_screen::{ProfileScreen, ProfileScreenDataProvider, ProfileScreenInterface};
 pub use records_screen::{RecordsScreen, RecordsScreenInterface, RecordsScreenProvider};
+pub use reminder_screen::{
+    ReminderScreen, ReminderScreenData, ReminderScreenDataProvider, ReminderScreenInterface,
+};
+pub use repo_detail_screen::{
+    RepoDetailScreen, RepoDetailScreenDataProvider, RepoDetailScreenInterface,
+};
 pub use repo_list_screen::{RepoListScreen, RepoListScreenDataProvider, RepoListScreenInterface};
 pub use repo_play_screen::{RepoPlayScreen, RepoPlayScreenDataProvider, RepoPlayScreenInterface};
 pub use session_detail_screen::{SessionDetailScreen, SessionDetailScreenInterface};
@@ -69,7 +101,11 @@ pub use trending_repository_selection_screen::{
     TrendingRepositorySelectionScreen, TrendingRepositorySelectionScreenDataProvider,
     TrendingRepositorySelectionScreenInterface,
 };
+pub use tutorial_screen::{TutorialScreen, TutorialScreenInterface};
 pub use typing_screen::{TypingScreen, TypingScreenInterface, TypingScreenProvider};
-pub use version_check_screen::{
-    VersionCheckResult, VersionCheckScreen, VersionCheckScreenInterface,
+pub use version_check_screen::{VersionCheckScreen, VersionCheckScreenInterface};
+pub use whats_new_screen::{WhatsNewScreen, WhatsNewScreenDataProvider, WhatsNewScreenInterface};
+pub use workspace_package_screen::{
+    PackageChoice, WorkspacePackageScreen, WorkspacePackageScreenData,
+    WorkspacePackageScreenDataProvider, WorkspacePackageScreenInterface,
 };
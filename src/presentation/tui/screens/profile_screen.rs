@@ -0,0 +1,378 @@
+use crate::domain::events::presentation_events::NavigateTo;
+use crate::domain::events::EventBusInterface;
+use crate::domain::models::{Locale, PlayerProfile};
+use crate::domain::services::profile_service::ProfileServiceInterface;
+use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
+use crate::presentation::ui::Colors;
+use crate::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+    Frame,
+};
+use std::sync::{Arc, RwLock};
+
+const MAX_DISPLAY_NAME_LENGTH: usize = 24;
+
+pub trait ProfileScreenInterface: Screen {}
+
+#[derive(shaku::Component)]
+#[shaku(interface = ProfileScreenInterface)]
+pub struct ProfileScreen {
+    #[shaku(default)]
+    profile: RwLock<Option<PlayerProfile>>,
+    #[shaku(default)]
+    name_input: RwLock<Option<String>>,
+    #[shaku(default)]
+    status_message: RwLock<Option<String>>,
+    #[shaku(inject)]
+    event_bus: Arc<dyn EventBusInterface>,
+    #[shaku(inject)]
+    theme_service: Arc<dyn ThemeServiceInterface>,
+    #[shaku(inject)]
+    profile_service: Arc<dyn ProfileServiceInterface>,
+}
+
+impl ProfileScreen {
+    pub fn new(
+        event_bus: Arc<dyn EventBusInterface>,
+        theme_service: Arc<dyn ThemeServiceInterface>,
+        profile_service: Arc<dyn ProfileServiceInterface>,
+    ) -> Self {
+        Self {
+            profile: RwLock::new(None),
+            name_input: RwLock::new(None),
+            status_message: RwLock::new(None),
+            event_bus,
+            theme_service,
+            profile_service,
+        }
+    }
+
+    pub fn get_profile(&self) -> Option<PlayerProfile> {
+        self.profile.read().unwrap().clone()
+    }
+
+    pub fn is_editing_name(&self) -> bool {
+        self.name_input.read().unwrap().is_some()
+    }
+
+    pub fn get_status_message(&self) -> Option<String> {
+        self.status_message.read().unwrap().clone()
+    }
+
+    fn start_editing(&self) {
+        let current = self
+            .profile
+            .read()
+            .unwrap()
+            .as_ref()
+            .map(|profile| profile.display_name.clone())
+            .unwrap_or_default();
+        *self.name_input.write().unwrap() = Some(current);
+        *self.status_message.write().unwrap() = None;
+    }
+
+    fn save_name(&self) -> Result<()> {
+        let Some(name) = self.name_input.write().unwrap().take() else {
+            return Ok(());
+        };
+
+        *self.status_message.write().unwrap() =
+            Some(match self.profile_service.set_display_name(&name) {
+                Ok(()) => {
+                    *self.profile.write().unwrap() = Some(self.profile_service.load_profile()?);
+                    "Display name saved".to_string()
+                }
+                Err(e) => format!("Failed to save display name: {}", e),
+            });
+        Ok(())
+    }
+
+    fn handle_name_input(&self, key_event: KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Enter => self.save_name()?,
+            KeyCode::Esc => *self.name_input.write().unwrap() = None,
+            KeyCode::Backspace => {
+                if let Some(name) = self.name_input.write().unwrap().as_mut() {
+                    name.pop();
+                }
+            }
+            KeyCode::Char(c) if !key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Some(name) = self
+                    .name_input
+                    .write()
+                    .unwrap()
+                    .as_mut()
+                    .filter(|name| name.chars().count() < MAX_DISPLAY_NAME_LENGTH)
+                {
+                    name.push(c);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn render_avatar(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        profile: &PlayerProfile,
+        colors: &Colors,
+    ) {
+        let avatar = Paragraph::new(vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                profile.initials(),
+                Style::default()
+                    .fg(colors.title())
+                    .add_modifier(Modifier::BOLD),
+            )),
+        ])
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(colors.border())),
+        );
+        frame.render_widget(avatar, area);
+    }
+
+    fn render_details(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        profile: &PlayerProfile,
+        colors: &Colors,
+    ) {
+        let label = |text: &'static str| {
+            Span::styled(
+                format!("{:<20}", text),
+                Style::default().fg(colors.text_secondary()),
+            )
+        };
+        let value = |text: String| Span::styled(text, Style::default().fg(colors.text()));
+
+        let name_line = match self.name_input.read().unwrap().as_ref() {
+            Some(input) => Line::from(vec![
+                label("Display name"),
+                Span::styled(
+                    format!("{}_", input),
+                    Style::default()
+                        .fg(colors.key_action())
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            None => Line::from(vec![
+                label("Display name"),
+                Span::styled(
+                    profile.display_name.clone(),
+                    Style::default()
+                        .fg(colors.text())
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+        };
+
+        let lines = vec![
+            name_line,
+            Line::from(vec![
+                label("Title"),
+                value(
+                    profile
+                        .rank_title
+                        .clone()
+                        .unwrap_or_else(|| "Unranked".to_string()),
+                ),
+            ]),
+            Line::from(vec![
+                label("Favorite language"),
+                value(
+                    profile
+                        .favorite_language_display()
+                        .unwrap_or_else(|| "-".to_string()),
+                ),
+            ]),
+            Line::from(vec![
+                label("Current streak"),
+                value(match profile.current_streak_days {
+                    1 => "1 day".to_string(),
+                    days => format!("{} days", days),
+                }),
+            ]),
+            Line::from(vec![
+                label("Stages completed"),
+                value(profile.stages_completed.to_string()),
+            ]),
+            Line::from(vec![
+                label("Best score"),
+                value(Locale::current().format_decimal(profile.best_score, 0)),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                label("Share signature"),
+                Span::styled(
+                    profile.share_signature(),
+                    Style::default().fg(colors.text_secondary()),
+                ),
+            ]),
+        ];
+
+        let details = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border()))
+                .padding(Padding::horizontal(2)),
+        );
+        frame.render_widget(details, area);
+    }
+
+    fn render_footer(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let spans = if self.is_editing_name() {
+            vec![
+                Span::styled("[ENTER]", Style::default().fg(colors.key_action())),
+                Span::styled(" Save  ", Style::default().fg(colors.text())),
+                Span::styled("[ESC]", Style::default().fg(colors.error())),
+                Span::styled(" Cancel", Style::default().fg(colors.text())),
+            ]
+        } else {
+            vec![
+                Span::styled("[E]", Style::default().fg(colors.key_action())),
+                Span::styled(" Edit name  ", Style::default().fg(colors.text())),
+                Span::styled("[ESC]", Style::default().fg(colors.error())),
+                Span::styled(" Back", Style::default().fg(colors.text())),
+            ]
+        };
+        frame.render_widget(
+            Paragraph::new(Line::from(spans)).alignment(Alignment::Center),
+            area,
+        );
+    }
+}
+
+pub struct ProfileScreenDataProvider;
+
+impl ScreenDataProvider for ProfileScreenDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(()))
+    }
+}
+
+impl Screen for ProfileScreen {
+    fn get_type(&self) -> ScreenType {
+        ScreenType::Profile
+    }
+
+    fn default_provider() -> Box<dyn ScreenDataProvider>
+    where
+        Self: Sized,
+    {
+        Box::new(ProfileScreenDataProvider)
+    }
+
+    fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
+        *self.name_input.write().unwrap() = None;
+        *self.status_message.write().unwrap() = None;
+        let profile = match data.downcast::<PlayerProfile>() {
+            Ok(profile) => *profile,
+            Err(_) => self.profile_service.load_profile()?,
+        };
+        *self.profile.write().unwrap() = Some(profile);
+        Ok(())
+    }
+
+    fn handle_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        if key_event.code == KeyCode::Char('c')
+            && key_event.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+            return Ok(());
+        }
+        if self.is_editing_name() {
+            return self.handle_name_input(key_event);
+        }
+
+        match key_event.code {
+            KeyCode::Char('e') | KeyCode::Char('E') => self.start_editing(),
+            KeyCode::Esc => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Pop);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn render_ratatui(&self, frame: &mut Frame) -> Result<()> {
+        let colors = self.theme_service.get_colors();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .split(frame.area());
+
+        let header = Paragraph::new(Span::styled(
+            "Profile",
+            Style::default()
+                .fg(colors.title())
+                .add_modifier(Modifier::BOLD),
+        ))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border())),
+        );
+        frame.render_widget(header, chunks[0]);
+
+        if let Some(profile) = self.profile.read().unwrap().as_ref() {
+            let body = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(14), Constraint::Min(0)])
+                .split(chunks[1]);
+            let avatar_area = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(5), Constraint::Min(0)])
+                .split(body[0])[0];
+
+            self.render_avatar(frame, avatar_area, profile, &colors);
+            self.render_details(frame, body[1], profile, &colors);
+        }
+
+        if let Some(message) = self.status_message.read().unwrap().as_ref() {
+            frame.render_widget(
+                Paragraph::new(Span::styled(
+                    message.clone(),
+                    Style::default().fg(colors.info()),
+                ))
+                .alignment(Alignment::Center),
+                chunks[2],
+            );
+        }
+        self.render_footer(frame, chunks[3], &colors);
+        Ok(())
+    }
+
+    fn get_update_strategy(&self) -> UpdateStrategy {
+        UpdateStrategy::InputOnly
+    }
+
+    fn update(&self) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ProfileScreenInterface for ProfileScreen {}
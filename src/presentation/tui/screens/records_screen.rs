@@ -1,6 +1,7 @@
 use crate::domain::events::presentation_events::NavigateTo;
 use crate::domain::events::EventBusInterface;
 use crate::domain::models::storage::StoredRepository;
+use crate::domain::models::{GitRepository, Locale};
 use crate::domain::services::session_service::{SessionDisplayData, SessionServiceInterface};
 use crate::domain::services::theme_service::ThemeServiceInterface;
 use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
@@ -394,7 +395,7 @@ fn format_session_line_ratatui_static<'a>(
     colors: &Colors,
 ) -> Line<'a> {
     let local_time: DateTime<Local> = session_data.session.started_at.into();
-    let date_str = local_time.format("%Y-%m-%d %H:%M").to_string();
+    let date_str = Locale::current().format_datetime(local_time.naive_local(), false);
 
     let repo_str = if let Some(ref repo) = session_data.repository {
         format!("{}/{}", repo.user_name, repo.repository_name)
@@ -405,9 +406,9 @@ fn format_session_line_ratatui_static<'a>(
     let (cpm_str, acc_str, score_str, stages_str, duration_str) =
         if let Some(ref result) = session_data.session_result {
             (
-                format!("{:.1}", result.cpm),
-                format!("{:.1}%", result.accuracy),
-                format!("{:.0}", result.score),
+                Locale::current().format_decimal(result.cpm, 1),
+                format!("{}%", Locale::current().format_decimal(result.accuracy, 1)),
+                Locale::current().format_decimal(result.score, 0),
                 format!("{}/{}", result.stages_completed, result.stages_attempted),
                 format!(
                     "{}m{}s",
@@ -425,6 +426,13 @@ fn format_session_line_ratatui_static<'a>(
             )
         };
 
+    let commit_str = session_data
+        .session
+        .commit_hash
+        .as_deref()
+        .map(GitRepository::short_commit)
+        .unwrap_or("--");
+
     // Truncate repository name if too long
     let repo_display = if repo_str.len() > 24 {
         format!("{}...", &repo_str[..21])
@@ -434,13 +442,17 @@ fn format_session_line_ratatui_static<'a>(
 
     Line::from(vec![
         Span::styled(
-            format!("{:<17}", date_str),
+            format!("{:<19}", date_str),
             Style::default().fg(colors.text()),
         ),
         Span::styled(
             format!("{:<26}", repo_display),
             Style::default().fg(colors.info()),
         ),
+        Span::styled(
+            format!("{:<9}", commit_str),
+            Style::default().fg(colors.text_secondary()),
+        ),
         Span::styled(
             format!("{:>6}", score_str),
             Style::default().fg(colors.score()),
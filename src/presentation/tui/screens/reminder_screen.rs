@@ -0,0 +1,302 @@
+use crate::domain::events::presentation_events::NavigateTo;
+use crate::domain::events::EventBusInterface;
+use crate::domain::models::Reminder;
+use crate::domain::services::config_service::{ConfigService, ConfigServiceInterface};
+use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::infrastructure::reminder_scheduler::uninstall_reminder;
+use crate::infrastructure::storage::file_storage::FileStorage;
+use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
+use crate::presentation::ui::Colors;
+use crate::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph},
+    Frame,
+};
+use std::sync::{Arc, RwLock};
+
+pub struct ReminderScreenData {
+    pub reminders: Vec<Reminder>,
+}
+
+pub trait ReminderScreenInterface: Screen {}
+
+/// Lists the daily practice reminders and removes them from the scheduler
+#[derive(shaku::Component)]
+#[shaku(interface = ReminderScreenInterface)]
+pub struct ReminderScreen {
+    #[shaku(default)]
+    reminders: RwLock<Vec<Reminder>>,
+    #[shaku(default)]
+    list_state: RwLock<ListState>,
+    #[shaku(default)]
+    message: RwLock<Option<String>>,
+    #[shaku(inject)]
+    event_bus: Arc<dyn EventBusInterface>,
+    #[shaku(inject)]
+    theme_service: Arc<dyn ThemeServiceInterface>,
+    #[shaku(inject)]
+    config_service: Arc<dyn ConfigServiceInterface>,
+}
+
+impl ReminderScreen {
+    pub fn new(
+        event_bus: Arc<dyn EventBusInterface>,
+        theme_service: Arc<dyn ThemeServiceInterface>,
+        config_service: Arc<dyn ConfigServiceInterface>,
+    ) -> Self {
+        Self {
+            reminders: RwLock::new(Vec::new()),
+            list_state: RwLock::new(ListState::default()),
+            message: RwLock::new(None),
+            event_bus,
+            theme_service,
+            config_service,
+        }
+    }
+
+    pub fn reminders(&self) -> Vec<Reminder> {
+        self.reminders.read().unwrap().clone()
+    }
+
+    pub fn selected_reminder(&self) -> Option<Reminder> {
+        let selected = self.list_state.read().unwrap().selected()?;
+        self.reminders.read().unwrap().get(selected).copied()
+    }
+
+    pub fn message(&self) -> Option<String> {
+        self.message.read().unwrap().clone()
+    }
+
+    fn move_selection(&self, forward: bool) {
+        let len = self.reminders.read().unwrap().len();
+        if len == 0 {
+            return;
+        }
+        let mut list_state = self.list_state.write().unwrap();
+        let selected = list_state.selected().unwrap_or(0);
+        list_state.select(Some(if forward {
+            (selected + 1).min(len - 1)
+        } else {
+            selected.saturating_sub(1)
+        }));
+    }
+
+    /// Uninstall the selected reminder's scheduled task, then forget it
+    fn remove_selected(&self) {
+        let Some(reminder) = self.selected_reminder() else {
+            return;
+        };
+        if let Err(e) = uninstall_reminder(&reminder) {
+            log::warn!("Failed to remove reminder {}: {}", reminder.label(), e);
+            *self.message.write().unwrap() = Some(format!(
+                "Could not remove the {} reminder: {}",
+                reminder.label(),
+                e
+            ));
+            return;
+        }
+
+        if let Some(config_service) =
+            (self.config_service.as_ref() as &dyn std::any::Any).downcast_ref::<ConfigService>()
+        {
+            let _ = config_service.update_config(|config| {
+                config.reminders.remove(reminder);
+            });
+            if let Err(e) = self.config_service.save() {
+                log::warn!("Failed to save config after removing a reminder: {}", e);
+            }
+        }
+
+        let mut reminders = self.reminders.write().unwrap();
+        reminders.retain(|at| *at != reminder);
+        let mut list_state = self.list_state.write().unwrap();
+        let selected = list_state.selected();
+        list_state.select(match selected {
+            _ if reminders.is_empty() => None,
+            Some(selected) => Some(selected.min(reminders.len() - 1)),
+            None => Some(0),
+        });
+        *self.message.write().unwrap() = Some(format!("Removed the {} reminder", reminder.label()));
+    }
+
+    fn render_header(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let header = Paragraph::new(Line::from(vec![
+            Span::styled(
+                "Reminders  ",
+                Style::default()
+                    .fg(colors.title())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "Daily practice notifications",
+                Style::default().fg(colors.text_secondary()),
+            ),
+        ]))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border())),
+        );
+        frame.render_widget(header, area);
+    }
+
+    fn render_content(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let reminders = self.reminders();
+        let block = Block::default()
+            .title(format!(" {} daily ", reminders.len()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.border()))
+            .padding(Padding::horizontal(1));
+
+        if reminders.is_empty() {
+            let empty = Paragraph::new(vec![
+                Line::from(Span::styled(
+                    "No reminders set.",
+                    Style::default().fg(colors.text_secondary()),
+                )),
+                Line::from(Span::styled(
+                    "Add one with: gittype remind --at 09:00",
+                    Style::default().fg(colors.text_secondary()),
+                )),
+            ])
+            .alignment(Alignment::Center)
+            .block(block);
+            frame.render_widget(empty, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = reminders
+            .iter()
+            .map(|reminder| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        reminder.label(),
+                        Style::default()
+                            .fg(colors.text())
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled("  every day", Style::default().fg(colors.text_secondary())),
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::default().bg(colors.background_secondary()))
+            .highlight_symbol("▶ ");
+        frame.render_stateful_widget(list, area, &mut self.list_state.write().unwrap());
+    }
+
+    fn render_footer(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let message = Paragraph::new(Span::styled(
+            self.message().unwrap_or_default(),
+            Style::default().fg(colors.info()),
+        ))
+        .alignment(Alignment::Center);
+        frame.render_widget(message, Rect { height: 1, ..area });
+
+        let controls = Paragraph::new(Line::from(vec![
+            Span::styled("[↑↓/JK]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Select  ", Style::default().fg(colors.text())),
+            Span::styled("[D]", Style::default().fg(colors.key_action())),
+            Span::styled(" Remove  ", Style::default().fg(colors.text())),
+            Span::styled("[ESC]", Style::default().fg(colors.key_back())),
+            Span::styled(" Close", Style::default().fg(colors.text())),
+        ]))
+        .alignment(Alignment::Center);
+        frame.render_widget(
+            controls,
+            Rect {
+                y: area.y + 1,
+                height: 1,
+                ..area
+            },
+        );
+    }
+}
+
+pub struct ReminderScreenDataProvider;
+
+impl ScreenDataProvider for ReminderScreenDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        let config = ConfigService::new(Arc::new(FileStorage::new()))?.get_config();
+        Ok(Box::new(ReminderScreenData {
+            reminders: config.reminders.at,
+        }))
+    }
+}
+
+impl Screen for ReminderScreen {
+    fn get_type(&self) -> ScreenType {
+        ScreenType::Reminders
+    }
+
+    fn default_provider() -> Box<dyn ScreenDataProvider>
+    where
+        Self: Sized,
+    {
+        Box::new(ReminderScreenDataProvider)
+    }
+
+    fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
+        let data = data.downcast::<ReminderScreenData>()?;
+        // Removing a reminder saves the config, so start from what is on disk
+        self.config_service.init()?;
+        let selected = (!data.reminders.is_empty()).then_some(0);
+        *self.reminders.write().unwrap() = data.reminders;
+        *self.list_state.write().unwrap() = ListState::default().with_selected(selected);
+        *self.message.write().unwrap() = None;
+        Ok(())
+    }
+
+    fn handle_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(false),
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(true),
+            KeyCode::Char('d') | KeyCode::Char('D') | KeyCode::Delete => self.remove_selected(),
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn render_ratatui(&self, frame: &mut Frame) -> Result<()> {
+        let colors = self.theme_service.get_colors();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(2),
+            ])
+            .split(frame.area());
+
+        self.render_header(frame, chunks[0], &colors);
+        self.render_content(frame, chunks[1], &colors);
+        self.render_footer(frame, chunks[2], &colors);
+        Ok(())
+    }
+
+    fn get_update_strategy(&self) -> UpdateStrategy {
+        UpdateStrategy::InputOnly
+    }
+
+    fn update(&self) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ReminderScreenInterface for ReminderScreen {}
@@ -0,0 +1,466 @@
+use crate::domain::events::presentation_events::NavigateTo;
+use crate::domain::events::EventBusInterface;
+use crate::domain::models::{Languages, Locale, RepositoryDetail};
+use crate::domain::services::dismissed_repository_service::DismissedRepositoryServiceInterface;
+use crate::domain::services::repository_detail_service::RepositoryDetailServiceInterface;
+use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::domain::stores::RepositoryStoreInterface;
+use crate::presentation::tui::screens::RepoListScreen;
+use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
+use crate::presentation::ui::Colors;
+use crate::{GitTypeError, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Padding, Paragraph},
+    Frame,
+};
+use std::sync::{Arc, RwLock};
+
+pub trait RepoDetailScreenInterface: Screen {}
+
+#[derive(shaku::Component)]
+#[shaku(interface = RepoDetailScreenInterface)]
+pub struct RepoDetailScreen {
+    #[shaku(default)]
+    detail: RwLock<Option<RepositoryDetail>>,
+    #[shaku(default)]
+    confirming_delete: RwLock<bool>,
+    #[shaku(default)]
+    status_message: RwLock<Option<String>>,
+    #[shaku(inject)]
+    event_bus: Arc<dyn EventBusInterface>,
+    #[shaku(inject)]
+    theme_service: Arc<dyn ThemeServiceInterface>,
+    #[shaku(inject)]
+    repository_detail_service: Arc<dyn RepositoryDetailServiceInterface>,
+    #[shaku(inject)]
+    repository_store: Arc<dyn RepositoryStoreInterface>,
+    #[shaku(inject)]
+    dismissed_repository_service: Arc<dyn DismissedRepositoryServiceInterface>,
+}
+
+impl RepoDetailScreen {
+    pub fn new(
+        event_bus: Arc<dyn EventBusInterface>,
+        theme_service: Arc<dyn ThemeServiceInterface>,
+        repository_detail_service: Arc<dyn RepositoryDetailServiceInterface>,
+        repository_store: Arc<dyn RepositoryStoreInterface>,
+        dismissed_repository_service: Arc<dyn DismissedRepositoryServiceInterface>,
+    ) -> Self {
+        Self {
+            detail: RwLock::new(None),
+            confirming_delete: RwLock::new(false),
+            status_message: RwLock::new(None),
+            event_bus,
+            theme_service,
+            repository_detail_service,
+            repository_store,
+            dismissed_repository_service,
+        }
+    }
+
+    pub fn get_detail(&self) -> Option<RepositoryDetail> {
+        self.detail.read().unwrap().clone()
+    }
+
+    pub fn is_confirming_delete(&self) -> bool {
+        *self.confirming_delete.read().unwrap()
+    }
+
+    pub fn get_status_message(&self) -> Option<String> {
+        self.status_message.read().unwrap().clone()
+    }
+
+    fn reload(&self, detail: &RepositoryDetail) -> Result<()> {
+        let reloaded = self
+            .repository_detail_service
+            .get_repository_detail(&detail.repository)?;
+        *self.detail.write().unwrap() = Some(reloaded);
+        Ok(())
+    }
+
+    fn refresh_cache(&self, detail: &RepositoryDetail) -> Result<()> {
+        let removed = self
+            .repository_detail_service
+            .refresh_cache(&detail.repository)?;
+        *self.status_message.write().unwrap() = Some(format!(
+            "Cleared {} cached challenge set{}; they will be re-extracted on next play",
+            removed,
+            if removed == 1 { "" } else { "s" }
+        ));
+        self.reload(detail)
+    }
+
+    fn delete(&self, detail: &RepositoryDetail) -> Result<()> {
+        self.repository_detail_service
+            .delete_repository(&detail.repository)?;
+        *self.status_message.write().unwrap() = Some(format!(
+            "Deleted local clone of {}; play history is kept",
+            detail.repo_spec()
+        ));
+        self.reload(detail)
+    }
+
+    /// Toggle whether `gittype trending` may suggest this repository
+    fn toggle_never_suggest(&self, detail: &RepositoryDetail) -> Result<()> {
+        let repo_spec = detail.repo_spec();
+        let message = if self.dismissed_repository_service.is_dismissed(&repo_spec)? {
+            self.dismissed_repository_service.restore(&repo_spec)?;
+            format!("{} can be suggested in trending again", repo_spec)
+        } else {
+            self.dismissed_repository_service.dismiss(&repo_spec)?;
+            format!("{} will no longer be suggested in trending", repo_spec)
+        };
+        *self.status_message.write().unwrap() = Some(message);
+        Ok(())
+    }
+
+    fn render_header(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        detail: &RepositoryDetail,
+        colors: &Colors,
+    ) {
+        let header = Paragraph::new(Line::from(vec![
+            Span::styled(
+                detail.repo_spec(),
+                Style::default()
+                    .fg(colors.title())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("  {}", detail.repository.http_url()),
+                Style::default().fg(colors.text_secondary()),
+            ),
+        ]))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border())),
+        );
+        frame.render_widget(header, area);
+    }
+
+    fn render_overview(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        detail: &RepositoryDetail,
+        colors: &Colors,
+    ) {
+        let row = |label: &str, value: String, color| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<19}", label),
+                    Style::default().fg(colors.text_secondary()),
+                ),
+                Span::styled(value, Style::default().fg(color)),
+            ])
+        };
+
+        let (clone_label, clone_color) = if detail.is_cached {
+            ("● Cloned", colors.success())
+        } else {
+            ("○ Not cloned", colors.text_secondary())
+        };
+        let lines = vec![
+            row("Local clone", clone_label.to_string(), clone_color),
+            row(
+                "Cached challenges",
+                detail.cached_challenges.to_string(),
+                colors.text(),
+            ),
+            Line::from(""),
+            row(
+                "Stages completed",
+                detail.stages_completed.to_string(),
+                colors.text(),
+            ),
+            row(
+                "Best WPM",
+                Locale::current().format_decimal(detail.best_wpm, 1),
+                colors.cpm_wpm(),
+            ),
+            row(
+                "Best accuracy",
+                format!(
+                    "{}%",
+                    Locale::current().format_decimal(detail.best_accuracy, 1)
+                ),
+                colors.accuracy(),
+            ),
+            row(
+                "Best score",
+                Locale::current().format_decimal(detail.best_score, 0),
+                colors.score(),
+            ),
+            row(
+                "Last played",
+                detail
+                    .last_played_at
+                    .map(|played_at| {
+                        Locale::current().format_datetime(
+                            played_at.with_timezone(&chrono::Local).naive_local(),
+                            false,
+                        )
+                    })
+                    .unwrap_or_else(|| "Never".to_string()),
+                colors.text(),
+            ),
+        ];
+
+        let overview = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border()))
+                .title("Overview")
+                .title_style(
+                    Style::default()
+                        .fg(colors.text())
+                        .add_modifier(Modifier::BOLD),
+                )
+                .padding(Padding::horizontal(1)),
+        );
+        frame.render_widget(overview, area);
+    }
+
+    fn render_languages(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        detail: &RepositoryDetail,
+        colors: &Colors,
+    ) {
+        let items: Vec<ListItem> = if detail.languages.is_empty() {
+            vec![ListItem::new(Span::styled(
+                "No stages played yet",
+                Style::default().fg(colors.text_secondary()),
+            ))]
+        } else {
+            detail
+                .languages
+                .iter()
+                .map(|stats| {
+                    ListItem::new(Line::from(vec![
+                        Span::styled(
+                            format!("{:<14}", Languages::get_display_name(Some(&stats.language))),
+                            Style::default().fg(colors.info()),
+                        ),
+                        Span::styled(
+                            format!("{:>4} stages  ", stats.stage_count),
+                            Style::default().fg(colors.text()),
+                        ),
+                        Span::styled(
+                            format!("{:>5.1} WPM  ", stats.avg_wpm),
+                            Style::default().fg(colors.cpm_wpm()),
+                        ),
+                        Span::styled(
+                            format!("{:>5.1}%", stats.avg_accuracy),
+                            Style::default().fg(colors.accuracy()),
+                        ),
+                    ]))
+                })
+                .collect()
+        };
+
+        let languages = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border()))
+                .title("Languages")
+                .title_style(
+                    Style::default()
+                        .fg(colors.text())
+                        .add_modifier(Modifier::BOLD),
+                )
+                .padding(Padding::horizontal(1)),
+        );
+        frame.render_widget(languages, area);
+    }
+
+    fn render_status(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let line = if self.is_confirming_delete() {
+            Line::from(Span::styled(
+                "Delete the local clone and its cached challenges? [Y] Yes  [any key] No",
+                Style::default().fg(colors.warning()),
+            ))
+        } else {
+            Line::from(Span::styled(
+                self.get_status_message().unwrap_or_default(),
+                Style::default().fg(colors.info()),
+            ))
+        };
+        frame.render_widget(Paragraph::new(line).alignment(Alignment::Center), area);
+    }
+
+    fn render_controls(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let controls = Paragraph::new(Line::from(vec![
+            Span::styled("[P]", Style::default().fg(colors.key_action())),
+            Span::styled(" Play  ", Style::default().fg(colors.text())),
+            Span::styled("[R]", Style::default().fg(colors.key_action())),
+            Span::styled(" Refresh cache  ", Style::default().fg(colors.text())),
+            Span::styled("[L]", Style::default().fg(colors.key_action())),
+            Span::styled(" Leaderboard  ", Style::default().fg(colors.text())),
+            Span::styled("[N]", Style::default().fg(colors.key_action())),
+            Span::styled(" Never suggest  ", Style::default().fg(colors.text())),
+            Span::styled("[D]", Style::default().fg(colors.error())),
+            Span::styled(" Delete clone  ", Style::default().fg(colors.text())),
+            Span::styled("[ESC]", Style::default().fg(colors.key_back())),
+            Span::styled(" Back", Style::default().fg(colors.text())),
+        ]))
+        .alignment(Alignment::Center);
+        frame.render_widget(controls, area);
+    }
+}
+
+pub struct RepoDetailScreenDataProvider;
+
+impl ScreenDataProvider for RepoDetailScreenDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(()))
+    }
+}
+
+impl Screen for RepoDetailScreen {
+    fn get_type(&self) -> ScreenType {
+        ScreenType::RepoDetail
+    }
+
+    fn default_provider() -> Box<dyn ScreenDataProvider>
+    where
+        Self: Sized,
+    {
+        Box::new(RepoDetailScreenDataProvider)
+    }
+
+    fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
+        *self.confirming_delete.write().unwrap() = false;
+        *self.status_message.write().unwrap() = None;
+        if let Ok(detail) = data.downcast::<RepositoryDetail>() {
+            *self.detail.write().unwrap() = Some(*detail);
+        }
+        Ok(())
+    }
+
+    fn on_pushed_from(&self, source_screen: &dyn Screen) -> Result<()> {
+        let (repository, _) = source_screen
+            .as_any()
+            .downcast_ref::<RepoListScreen>()
+            .and_then(|repo_list| repo_list.get_selected_repository())
+            .ok_or_else(|| {
+                GitTypeError::ScreenInitializationError(
+                    "RepoDetail requires a repository selected in RepoList".to_string(),
+                )
+            })?;
+
+        let detail = self
+            .repository_detail_service
+            .get_repository_detail(&repository)?;
+        *self.detail.write().unwrap() = Some(detail);
+        Ok(())
+    }
+
+    fn handle_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        if key_event.kind != KeyEventKind::Press {
+            return Ok(());
+        }
+
+        if key_event.code == KeyCode::Char('c')
+            && key_event.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+            return Ok(());
+        }
+
+        let Some(detail) = self.get_detail() else {
+            if key_event.code == KeyCode::Esc {
+                self.event_bus.as_event_bus().publish(NavigateTo::Pop);
+            }
+            return Ok(());
+        };
+
+        if self.is_confirming_delete() {
+            *self.confirming_delete.write().unwrap() = false;
+            return match key_event.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => self.delete(&detail),
+                _ => Ok(()),
+            };
+        }
+
+        match key_event.code {
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                self.repository_store.set_repo_spec(detail.repo_spec());
+                self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => self.refresh_cache(&detail)?,
+            KeyCode::Char('n') | KeyCode::Char('N') => self.toggle_never_suggest(&detail)?,
+            KeyCode::Char('l') | KeyCode::Char('L') => {
+                self.event_bus
+                    .as_event_bus()
+                    .publish(NavigateTo::Push(ScreenType::Leaderboard));
+            }
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                *self.status_message.write().unwrap() = None;
+                *self.confirming_delete.write().unwrap() = true;
+            }
+            KeyCode::Esc => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Pop);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn render_ratatui(&self, frame: &mut Frame) -> Result<()> {
+        let colors = self.theme_service.get_colors();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Header
+                Constraint::Min(1),    // Overview and languages
+                Constraint::Length(1), // Status
+                Constraint::Length(1), // Controls
+            ])
+            .split(frame.area());
+
+        let Some(detail) = self.get_detail() else {
+            self.render_controls(frame, chunks[3], &colors);
+            return Ok(());
+        };
+
+        let body = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .split(chunks[1]);
+
+        self.render_header(frame, chunks[0], &detail, &colors);
+        self.render_overview(frame, body[0], &detail, &colors);
+        self.render_languages(frame, body[1], &detail, &colors);
+        self.render_status(frame, chunks[2], &colors);
+        self.render_controls(frame, chunks[3], &colors);
+        Ok(())
+    }
+
+    fn get_update_strategy(&self) -> UpdateStrategy {
+        UpdateStrategy::InputOnly
+    }
+
+    fn update(&self) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn is_exitable(&self) -> bool {
+        true
+    }
+}
+
+impl RepoDetailScreenInterface for RepoDetailScreen {}
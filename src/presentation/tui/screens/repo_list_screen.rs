@@ -3,7 +3,8 @@ use crate::domain::events::EventBusInterface;
 use crate::domain::models::storage::StoredRepositoryWithLanguages;
 use crate::domain::services::repository_service::RepositoryService;
 use crate::domain::services::theme_service::ThemeServiceInterface;
-use crate::infrastructure::database::database::{Database, DatabaseInterface};
+use crate::domain::stores::RepositoryStoreInterface;
+use crate::infrastructure::database::storage_backend::open_default_backend;
 use crate::infrastructure::git::RemoteGitRepositoryClient;
 use crate::presentation::tui::views::repo_list::{
     CacheInfoView, ControlsView, HeaderView, LegendView, RepositoryListView,
@@ -13,6 +14,7 @@ use crate::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
 use ratatui::{
     layout::{Constraint, Direction, Layout},
+    widgets::ListState,
     Frame,
 };
 use std::sync::{Arc, RwLock};
@@ -31,23 +33,54 @@ pub struct RepoListScreen {
     repositories: RwLock<Vec<(StoredRepositoryWithLanguages, bool)>>,
     #[shaku(default)]
     cache_dir: RwLock<String>,
+    #[shaku(default)]
+    list_state: RwLock<ListState>,
     #[shaku(inject)]
     event_bus: Arc<dyn EventBusInterface>,
     #[shaku(inject)]
     theme_service: Arc<dyn ThemeServiceInterface>,
+    #[shaku(inject)]
+    repository_store: Arc<dyn RepositoryStoreInterface>,
 }
 
 impl RepoListScreen {
     pub fn new(
         event_bus: Arc<dyn EventBusInterface>,
         theme_service: Arc<dyn ThemeServiceInterface>,
+        repository_store: Arc<dyn RepositoryStoreInterface>,
     ) -> Self {
         Self {
             repositories: RwLock::new(Vec::new()),
             cache_dir: RwLock::new(String::new()),
+            list_state: RwLock::new(ListState::default()),
             event_bus,
             theme_service,
+            repository_store,
+        }
+    }
+
+    pub fn get_selected_repository(&self) -> Option<(StoredRepositoryWithLanguages, bool)> {
+        let selected = self.list_state.read().unwrap().selected()?;
+        self.repositories.read().unwrap().get(selected).cloned()
+    }
+
+    /// Repository chosen with Play on the detail screen, if any
+    pub fn get_play_request(&self) -> Option<String> {
+        self.repository_store.get_repo_spec()
+    }
+
+    fn move_selection(&self, forward: bool) {
+        let len = self.repositories.read().unwrap().len();
+        if len == 0 {
+            return;
         }
+        let mut list_state = self.list_state.write().unwrap();
+        let selected = list_state.selected().unwrap_or(0);
+        list_state.select(Some(if forward {
+            (selected + 1).min(len - 1)
+        } else {
+            selected.saturating_sub(1)
+        }));
     }
 }
 
@@ -56,12 +89,9 @@ pub struct RepoListScreenDataProvider;
 impl ScreenDataProvider for RepoListScreenDataProvider {
     fn provide(&self) -> Result<Box<dyn std::any::Any>> {
         use crate::domain::services::repository_service::RepositoryServiceInterface;
-        use crate::infrastructure::database::daos::{RepositoryDao, RepositoryDaoInterface};
-        let db = Arc::new(Database::new()?) as Arc<dyn DatabaseInterface>;
-        let repository_dao =
-            Arc::new(RepositoryDao::new(Arc::clone(&db))) as Arc<dyn RepositoryDaoInterface>;
+        let repository_dao = open_default_backend()?.repository_dao();
         let remote_git_client = RemoteGitRepositoryClient::new();
-        let service = RepositoryService::new(repository_dao, remote_git_client);
+        let service = RepositoryService::new(repository_dao.into(), remote_git_client);
 
         let repositories_with_cache = service.get_all_repositories_with_cache_status()?;
         let cache_dir = RepositoryService::get_cache_directory();
@@ -87,6 +117,8 @@ impl Screen for RepoListScreen {
 
     fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
         if let Ok(screen_data) = data.downcast::<RepoListScreenData>() {
+            *self.list_state.write().unwrap() = ListState::default()
+                .with_selected((!screen_data.repositories.is_empty()).then_some(0));
             *self.repositories.write().unwrap() = screen_data.repositories;
             *self.cache_dir.write().unwrap() = screen_data.cache_dir;
         }
@@ -109,6 +141,13 @@ impl Screen for RepoListScreen {
             {
                 self.event_bus.as_event_bus().publish(NavigateTo::Exit);
             }
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(false),
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(true),
+            KeyCode::Enter if self.get_selected_repository().is_some() => {
+                self.event_bus
+                    .as_event_bus()
+                    .publish(NavigateTo::Push(ScreenType::RepoDetail));
+            }
             _ => {}
         }
 
@@ -134,7 +173,13 @@ impl Screen for RepoListScreen {
         let cache_dir = self.cache_dir.read().unwrap();
         CacheInfoView::render(frame, chunks[2], &cache_dir, &colors);
         let repositories = self.repositories.read().unwrap();
-        RepositoryListView::render(frame, chunks[4], &repositories, &colors);
+        RepositoryListView::render(
+            frame,
+            chunks[4],
+            &repositories,
+            &mut self.list_state.write().unwrap(),
+            &colors,
+        );
         LegendView::render(frame, chunks[5], &colors);
         ControlsView::render(frame, chunks[6], &colors);
 
@@ -1,20 +1,31 @@
-use crate::domain::events::presentation_events::NavigateTo;
+use crate::domain::events::presentation_events::{NavigateTo, StarredRepositoriesLoaded};
 use crate::domain::events::EventBusInterface;
 use crate::domain::models::storage::StoredRepositoryWithLanguages;
+use crate::domain::models::{Languages, Locale};
+use crate::domain::repositories::starred_repository::StarredRepositoryInterface;
 use crate::domain::services::repository_service::RepositoryService;
 use crate::domain::services::theme_service::ThemeServiceInterface;
-use crate::infrastructure::database::database::{Database, DatabaseInterface};
+use crate::infrastructure::database::storage_backend::open_default_backend;
 use crate::infrastructure::git::RemoteGitRepositoryClient;
-use crate::presentation::tui::views::repo_play::{ControlsView, HeaderView, RepositoryListView};
+use crate::infrastructure::http::GitHubStarredRepository;
+use crate::presentation::tui::views::loading::loading_progress_view::SPINNER_CHARS;
+use crate::presentation::tui::views::repo_play::repository_list_view::last_played_label;
+use crate::presentation::tui::views::repo_play::{
+    ControlsView, HeaderView, RepositoryListView, SearchBarView, StarredListView,
+};
 use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
+use crate::presentation::ui::{fuzzy_score, Colors};
 use crate::Result;
-use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     widgets::ListState,
     Frame,
 };
-use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
 
 pub struct RepoPlayScreenData {
     pub repositories: Vec<(StoredRepositoryWithLanguages, bool)>,
@@ -28,29 +39,64 @@ pub struct RepoPlayScreen {
     #[shaku(default)]
     repositories: RwLock<Vec<(StoredRepositoryWithLanguages, bool)>>,
     #[shaku(default)]
+    query: RwLock<String>,
+    #[shaku(default)]
     list_state: RwLock<ListState>,
     #[shaku(default)]
     selected_index: RwLock<Option<usize>>,
+    /// Listing the GitHub stars instead of the played repositories
+    #[shaku(default)]
+    showing_starred: AtomicBool,
+    /// `None` until the stars were fetched
+    #[shaku(default)]
+    starred: RwLock<Option<Vec<GitHubStarredRepository>>>,
+    #[shaku(default)]
+    starred_loading: AtomicBool,
+    #[shaku(default)]
+    starred_error: RwLock<Option<String>>,
+    /// `owner/name` of the starred repositories to warm and play, in order
+    #[shaku(default)]
+    queue: RwLock<Vec<String>>,
+    /// Fetch results received from the EventBus, applied on the next `update`
+    #[shaku(default)]
+    loaded: Arc<Mutex<Vec<StarredRepositoriesLoaded>>>,
+    #[shaku(default)]
+    subscribed: AtomicBool,
+    #[shaku(default)]
+    spinner_index: AtomicUsize,
     #[shaku(inject)]
     event_bus: Arc<dyn EventBusInterface>,
     #[shaku(inject)]
     theme_service: Arc<dyn ThemeServiceInterface>,
+    #[shaku(inject)]
+    starred_repository: Arc<dyn StarredRepositoryInterface>,
 }
 
 impl RepoPlayScreen {
     pub fn new(
         event_bus: Arc<dyn EventBusInterface>,
         theme_service: Arc<dyn ThemeServiceInterface>,
+        starred_repository: Arc<dyn StarredRepositoryInterface>,
     ) -> Self {
         let mut list_state = ListState::default();
         list_state.select(Some(0));
 
         Self {
             repositories: RwLock::new(Vec::new()),
+            query: RwLock::new(String::new()),
             list_state: RwLock::new(list_state),
             selected_index: RwLock::new(None),
+            showing_starred: AtomicBool::new(false),
+            starred: RwLock::new(None),
+            starred_loading: AtomicBool::new(false),
+            starred_error: RwLock::new(None),
+            queue: RwLock::new(Vec::new()),
+            loaded: Arc::new(Mutex::new(Vec::new())),
+            subscribed: AtomicBool::new(false),
+            spinner_index: AtomicUsize::new(0),
             event_bus,
             theme_service,
+            starred_repository,
         }
     }
 
@@ -63,6 +109,260 @@ impl RepoPlayScreen {
         let repositories = self.repositories.read().unwrap();
         selected_index.and_then(|index| repositories.get(index).cloned())
     }
+
+    pub fn get_query(&self) -> String {
+        self.query.read().unwrap().clone()
+    }
+
+    /// Repositories matching the typed query, best match first; all of them while it is empty
+    pub fn visible_repositories(&self) -> Vec<(StoredRepositoryWithLanguages, bool)> {
+        let repositories = self.repositories.read().unwrap();
+        self.visible_indices()
+            .into_iter()
+            .map(|index| repositories[index].clone())
+            .collect()
+    }
+
+    /// Starred repositories matching the typed query, best match first
+    pub fn visible_starred(&self) -> Vec<GitHubStarredRepository> {
+        let starred = self.starred.read().unwrap();
+        let starred = starred.as_deref().unwrap_or_default();
+        self.visible_indices()
+            .into_iter()
+            .map(|index| starred[index].clone())
+            .collect()
+    }
+
+    pub fn is_showing_starred(&self) -> bool {
+        self.showing_starred.load(Ordering::Relaxed)
+    }
+
+    pub fn is_loading_starred(&self) -> bool {
+        self.starred_loading.load(Ordering::Relaxed)
+    }
+
+    /// `owner/name` of the starred repositories queued to warm and play, in order
+    pub fn get_practice_queue(&self) -> Vec<String> {
+        self.queue.read().unwrap().clone()
+    }
+
+    /// Indices into the list shown, played or starred
+    fn visible_indices(&self) -> Vec<usize> {
+        let query = self.query.read().unwrap();
+        let texts = self.search_texts();
+        if query.trim().is_empty() {
+            return (0..texts.len()).collect();
+        }
+
+        let mut matches: Vec<(usize, u32)> = texts
+            .iter()
+            .enumerate()
+            .filter_map(|(index, text)| fuzzy_score(&query, text).map(|score| (index, score)))
+            .collect();
+        matches.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+        matches.into_iter().map(|(index, _)| index).collect()
+    }
+
+    fn search_texts(&self) -> Vec<String> {
+        if self.is_showing_starred() {
+            return self
+                .starred
+                .read()
+                .unwrap()
+                .iter()
+                .flatten()
+                .map(starred_search_text)
+                .collect();
+        }
+
+        let locale = Locale::current();
+        self.repositories
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(repo, _)| search_text(&locale, repo))
+            .collect()
+    }
+
+    /// Switch between the played and the starred repositories, fetching the
+    /// stars the first time they are shown
+    fn toggle_starred(&self) {
+        let showing_starred = !self.showing_starred.fetch_xor(true, Ordering::Relaxed);
+        self.update_query(String::clear);
+        if showing_starred
+            && self.starred.read().unwrap().is_none()
+            && !self.starred_loading.swap(true, Ordering::Relaxed)
+        {
+            *self.starred_error.write().unwrap() = None;
+            self.subscribe_to_loaded();
+            self.fetch_starred_in_background();
+        }
+    }
+
+    /// Stash fetch results published by the background thread; registered once
+    /// since every `init_with_data` reuses the same screen
+    fn subscribe_to_loaded(&self) {
+        if self.subscribed.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let loaded = Arc::clone(&self.loaded);
+        self.event_bus
+            .as_event_bus()
+            .subscribe(move |event: &StarredRepositoriesLoaded| {
+                loaded.lock().unwrap().push(event.clone());
+            });
+    }
+
+    /// Fetch the stars off the UI thread so the list stays responsive
+    fn fetch_starred_in_background(&self) {
+        let starred_repository = Arc::clone(&self.starred_repository);
+        let event_bus = Arc::clone(&self.event_bus);
+
+        thread::spawn(move || {
+            let repositories = starred_repository
+                .get_starred_repositories_sync()
+                .map_err(|e| e.to_string());
+            event_bus
+                .as_event_bus()
+                .publish(StarredRepositoriesLoaded { repositories });
+        });
+    }
+
+    fn apply_loaded(&self, loaded: StarredRepositoriesLoaded) {
+        self.starred_loading.store(false, Ordering::Relaxed);
+        match loaded.repositories {
+            Ok(repositories) => *self.starred.write().unwrap() = Some(repositories),
+            Err(message) => {
+                log::warn!("Failed to fetch starred repositories: {}", message);
+                *self.starred_error.write().unwrap() = Some(message);
+            }
+        }
+    }
+
+    /// The starred repository under the cursor, if any
+    fn highlighted_starred(&self) -> Option<String> {
+        let selected = self.list_state.read().unwrap().selected()?;
+        let index = *self.visible_indices().get(selected)?;
+        let starred = self.starred.read().unwrap();
+        Some(starred.as_ref()?.get(index)?.full_name.clone())
+    }
+
+    /// Add the highlighted repository to the queue, or take it out again
+    fn toggle_queued(&self) {
+        let Some(full_name) = self.highlighted_starred() else {
+            return;
+        };
+        let mut queue = self.queue.write().unwrap();
+        match queue.iter().position(|queued| *queued == full_name) {
+            Some(position) => {
+                queue.remove(position);
+            }
+            None => queue.push(full_name),
+        }
+    }
+
+    /// Leave with the queue, or with the highlighted repository when nothing is queued
+    fn confirm_queue(&self) {
+        if self.queue.read().unwrap().is_empty() {
+            self.toggle_queued();
+        }
+        if !self.queue.read().unwrap().is_empty() {
+            self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+        }
+    }
+
+    fn update_query(&self, update: impl FnOnce(&mut String)) {
+        update(&mut self.query.write().unwrap());
+        self.list_state.write().unwrap().select(Some(0));
+    }
+
+    fn move_selection(&self, forward: bool) {
+        let visible = self.visible_indices().len();
+        let mut list_state = self.list_state.write().unwrap();
+        let selected = list_state.selected().unwrap_or(0);
+        match forward {
+            true if selected + 1 < visible => list_state.select(Some(selected + 1)),
+            false if selected > 0 => list_state.select(Some(selected - 1)),
+            _ => {}
+        }
+    }
+
+    fn render_starred(
+        &self,
+        frame: &mut Frame,
+        search_area: Rect,
+        list_area: Rect,
+        colors: &Colors,
+    ) {
+        let repositories = self.visible_starred();
+        SearchBarView::render(
+            frame,
+            search_area,
+            &self.get_query(),
+            "Type to filter by owner, name, language or description",
+            repositories.len(),
+            self.starred.read().unwrap().as_ref().map_or(0, Vec::len),
+            colors,
+        );
+
+        if self.is_loading_starred() {
+            let spinner =
+                SPINNER_CHARS[self.spinner_index.load(Ordering::Relaxed) % SPINNER_CHARS.len()];
+            StarredListView::render_message(
+                frame,
+                list_area,
+                &format!("{} Fetching starred repositories...", spinner),
+                colors.text_secondary(),
+                colors,
+            );
+        } else if let Some(error) = self.starred_error.read().unwrap().as_deref() {
+            StarredListView::render_message(
+                frame,
+                list_area,
+                &format!("Failed to fetch starred repositories: {}", error),
+                colors.error(),
+                colors,
+            );
+        } else {
+            let mut list_state = self.list_state.write().unwrap();
+            StarredListView::render(
+                frame,
+                list_area,
+                &repositories,
+                &self.queue.read().unwrap(),
+                &mut list_state,
+                colors,
+            );
+        }
+    }
+}
+
+/// What the query is matched against: `owner/name`, languages and the last played date
+fn search_text(locale: &Locale, repo: &StoredRepositoryWithLanguages) -> String {
+    let languages = repo
+        .languages
+        .iter()
+        .map(|language| Languages::get_display_name(Some(language)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(
+        "{}/{} {} {}",
+        repo.user_name,
+        repo.repository_name,
+        languages,
+        last_played_label(locale, repo)
+    )
+}
+
+/// What the query is matched against for a star: `owner/name`, language and description
+fn starred_search_text(repo: &GitHubStarredRepository) -> String {
+    format!(
+        "{} {} {}",
+        repo.full_name,
+        repo.language.as_deref().unwrap_or(""),
+        repo.description.as_deref().unwrap_or("")
+    )
 }
 
 pub struct RepoPlayScreenDataProvider;
@@ -70,11 +370,9 @@ pub struct RepoPlayScreenDataProvider;
 impl ScreenDataProvider for RepoPlayScreenDataProvider {
     fn provide(&self) -> Result<Box<dyn std::any::Any>> {
         use crate::domain::services::repository_service::RepositoryServiceInterface;
-        use crate::infrastructure::database::daos::{RepositoryDao, RepositoryDaoInterface};
-        let db = Arc::new(Database::new()?) as Arc<dyn DatabaseInterface>;
-        let repository_dao =
-            Arc::new(RepositoryDao::new(Arc::clone(&db))) as Arc<dyn RepositoryDaoInterface>;
-        let service = RepositoryService::new(repository_dao, RemoteGitRepositoryClient::new());
+        let repository_dao = open_default_backend()?.repository_dao();
+        let service =
+            RepositoryService::new(repository_dao.into(), RemoteGitRepositoryClient::new());
 
         let repositories_with_cache = service.get_all_repositories_with_cache_status()?;
 
@@ -99,10 +397,13 @@ impl Screen for RepoPlayScreen {
     fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
         if let Ok(screen_data) = data.downcast::<RepoPlayScreenData>() {
             *self.repositories.write().unwrap() = screen_data.repositories;
+            self.query.write().unwrap().clear();
             let mut list_state = ListState::default();
             list_state.select(Some(0));
             *self.list_state.write().unwrap() = list_state;
             *self.selected_index.write().unwrap() = None;
+            self.showing_starred.store(false, Ordering::Relaxed);
+            self.queue.write().unwrap().clear();
         }
         Ok(())
     }
@@ -112,41 +413,33 @@ impl Screen for RepoPlayScreen {
             return Ok(());
         }
 
+        let control = key_event.modifiers.contains(KeyModifiers::CONTROL);
         match key_event.code {
-            KeyCode::Esc => {
+            KeyCode::Char('c') if control => {
                 self.event_bus.as_event_bus().publish(NavigateTo::Exit);
             }
-            KeyCode::Char('c')
-                if key_event
-                    .modifiers
-                    .contains(crossterm::event::KeyModifiers::CONTROL) =>
-            {
+            KeyCode::Esc if self.query.read().unwrap().is_empty() => {
                 self.event_bus.as_event_bus().publish(NavigateTo::Exit);
             }
-            KeyCode::Char('j') | KeyCode::Down => {
-                let mut list_state = self.list_state.write().unwrap();
-                if let Some(selected) = list_state.selected() {
-                    let repositories = self.repositories.read().unwrap();
-                    if !repositories.is_empty() && selected < repositories.len() - 1 {
-                        list_state.select(Some(selected + 1));
-                    }
-                }
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                let mut list_state = self.list_state.write().unwrap();
-                if let Some(selected) = list_state.selected() {
-                    if selected > 0 {
-                        list_state.select(Some(selected - 1));
-                    }
-                }
-            }
-            KeyCode::Char(' ') => {
-                let list_state = self.list_state.read().unwrap();
-                if let Some(selected) = list_state.selected() {
-                    *self.selected_index.write().unwrap() = Some(selected);
+            KeyCode::Esc => self.update_query(String::clear),
+            KeyCode::Down => self.move_selection(true),
+            KeyCode::Char('n') if control => self.move_selection(true),
+            KeyCode::Up => self.move_selection(false),
+            KeyCode::Char('p') if control => self.move_selection(false),
+            KeyCode::Tab => self.toggle_starred(),
+            KeyCode::Char(' ') if self.is_showing_starred() => self.toggle_queued(),
+            KeyCode::Enter if self.is_showing_starred() => self.confirm_queue(),
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                let selected = self.list_state.read().unwrap().selected();
+                if let Some(index) = selected.and_then(|s| self.visible_indices().get(s).copied()) {
+                    *self.selected_index.write().unwrap() = Some(index);
                     self.event_bus.as_event_bus().publish(NavigateTo::Exit);
                 }
             }
+            KeyCode::Backspace => self.update_query(|query| {
+                query.pop();
+            }),
+            KeyCode::Char(c) if !control => self.update_query(|query| query.push(c)),
             _ => {}
         }
 
@@ -169,25 +462,59 @@ impl Screen for RepoPlayScreen {
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(3), // Header
+                Constraint::Length(3), // Search bar
                 Constraint::Min(1),    // Repository list
                 Constraint::Length(1), // Controls at bottom
             ])
             .split(outer_chunks[1]);
 
-        HeaderView::render(frame, chunks[0], &colors);
-        let repositories = self.repositories.read().unwrap();
-        let mut list_state = self.list_state.write().unwrap();
-        RepositoryListView::render(frame, chunks[1], &repositories, &mut list_state, &colors);
-        ControlsView::render(frame, chunks[2], &colors);
+        let showing_starred = self.is_showing_starred();
+        HeaderView::render(frame, chunks[0], showing_starred, &colors);
+        if showing_starred {
+            self.render_starred(frame, chunks[1], chunks[2], &colors);
+        } else {
+            let repositories = self.visible_repositories();
+            SearchBarView::render(
+                frame,
+                chunks[1],
+                &self.get_query(),
+                "Type to filter by owner, name, language or last played",
+                repositories.len(),
+                self.repositories.read().unwrap().len(),
+                &colors,
+            );
+            let mut list_state = self.list_state.write().unwrap();
+            RepositoryListView::render(frame, chunks[2], &repositories, &mut list_state, &colors);
+        }
+        ControlsView::render(frame, chunks[3], showing_starred, &colors);
 
         Ok(())
     }
 
     fn get_update_strategy(&self) -> UpdateStrategy {
-        UpdateStrategy::InputOnly
+        if self.is_loading_starred() {
+            UpdateStrategy::Hybrid {
+                interval: Duration::from_millis(100),
+                input_priority: true,
+            }
+        } else {
+            UpdateStrategy::InputOnly
+        }
     }
 
     fn update(&self) -> Result<bool> {
+        let loaded: Vec<_> = self.loaded.lock().unwrap().drain(..).collect();
+        if !loaded.is_empty() {
+            loaded
+                .into_iter()
+                .for_each(|loaded| self.apply_loaded(loaded));
+            return Ok(true);
+        }
+
+        if self.is_loading_starred() {
+            self.spinner_index.fetch_add(1, Ordering::Relaxed);
+            return Ok(true);
+        }
         Ok(false)
     }
 
@@ -1,11 +1,18 @@
 use crate::domain::events::presentation_events::NavigateTo;
 use crate::domain::events::EventBusInterface;
 use crate::domain::models::storage::SessionStageResult;
+use crate::domain::models::{Challenge, RaceBot, StageScreenshot, WpmMode};
 use crate::domain::repositories::session_repository::SessionRepositoryTrait;
+use crate::domain::services::session_manager_service::SessionManagerInterface;
 use crate::domain::services::session_service::SessionDisplayData;
 use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::domain::services::SessionManager;
+use crate::infrastructure::browser;
+use crate::infrastructure::storage::file_storage::FileStorageInterface;
 use crate::presentation::tui::screens::RecordsScreen;
-use crate::presentation::tui::views::{PerformanceMetricsView, SessionInfoView, StageDetailsView};
+use crate::presentation::tui::views::{
+    MistakeBreakdownView, PerformanceMetricsView, SessionInfoView, StageDetailsView,
+};
 use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
 use crate::{GitTypeError, Result};
 use crossterm::event::{KeyCode, KeyModifiers};
@@ -15,6 +22,7 @@ use ratatui::{
     text::{Line, Span},
     widgets::Paragraph,
 };
+use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 
 pub enum SessionDetailAction {
@@ -38,6 +46,10 @@ pub struct SessionDetailScreen {
     theme_service: Arc<dyn ThemeServiceInterface>,
     #[shaku(inject)]
     session_repository: Arc<dyn SessionRepositoryTrait>,
+    #[shaku(inject)]
+    file_storage: Arc<dyn FileStorageInterface>,
+    #[shaku(inject)]
+    session_manager: Arc<dyn SessionManagerInterface>,
 }
 
 impl SessionDetailScreen {
@@ -45,6 +57,8 @@ impl SessionDetailScreen {
         event_bus: Arc<dyn EventBusInterface>,
         theme_service: Arc<dyn ThemeServiceInterface>,
         session_repository: Arc<dyn SessionRepositoryTrait>,
+        file_storage: Arc<dyn FileStorageInterface>,
+        session_manager: Arc<dyn SessionManagerInterface>,
     ) -> Self {
         Self {
             session_data: RwLock::new(SessionDisplayData::default()),
@@ -53,8 +67,58 @@ impl SessionDetailScreen {
             event_bus,
             theme_service,
             session_repository,
+            file_storage,
+            session_manager,
         }
     }
+
+    fn selected_stage(&self) -> Option<SessionStageResult> {
+        self.stage_results
+            .read()
+            .unwrap()
+            .get(*self.stage_scroll_offset.read().unwrap())
+            .cloned()
+    }
+
+    /// HTML screenshot of the stage at the top of the list, if one was saved
+    pub fn selected_screenshot(&self) -> Option<PathBuf> {
+        let session_id = self.session_data.read().unwrap().session.id;
+        let stage_number = self.selected_stage()?.stage_number;
+        let app_data_dir = self.file_storage.get_app_data_dir().ok()?;
+
+        Some(StageScreenshot::html_path(
+            &app_data_dir,
+            session_id,
+            stage_number as usize,
+        ))
+        .filter(|path| self.file_storage.file_exists(path))
+    }
+
+    /// The challenge of the stage at the top of the list with the ghost of
+    /// that attempt; only finished stages whose code was kept can be raced
+    pub fn selected_ghost_race(&self) -> Option<(Challenge, RaceBot)> {
+        let stage = self
+            .selected_stage()
+            .filter(|stage| !stage.was_skipped && !stage.was_failed)?;
+        Some((stage.challenge()?, stage.ghost(WpmMode::current())))
+    }
+
+    fn race_selected_ghost(&self) {
+        let Some((challenge, ghost)) = self.selected_ghost_race() else {
+            return;
+        };
+        let Some(session_manager) = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+        else {
+            return;
+        };
+        session_manager.queue_ghost_race(challenge, ghost);
+        self.event_bus
+            .as_event_bus()
+            .publish(NavigateTo::Replace(ScreenType::Typing));
+    }
 }
 
 pub struct SessionDetailScreenDataProvider;
@@ -158,6 +222,18 @@ impl Screen for SessionDetailScreen {
                 }
                 Ok(())
             }
+            KeyCode::Char('g') | KeyCode::Char('G') => {
+                self.race_selected_ghost();
+                Ok(())
+            }
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                if let Some(path) = self.selected_screenshot() {
+                    if let Err(e) = browser::open_url(&path.to_string_lossy()) {
+                        log::warn!("Failed to open {}: {}", path.display(), e);
+                    }
+                }
+                Ok(())
+            }
             _ => Ok(()),
         }
     }
@@ -193,7 +269,11 @@ impl Screen for SessionDetailScreen {
 
         let top_chunks = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .constraints([
+                Constraint::Percentage(34),
+                Constraint::Percentage(33),
+                Constraint::Percentage(33),
+            ])
             .split(content_chunks[0]);
 
         SessionInfoView::render(
@@ -207,6 +287,17 @@ impl Screen for SessionDetailScreen {
             frame,
             top_chunks[1],
             session_data.session_result.as_ref(),
+            &stage_results,
+            &colors,
+        );
+        MistakeBreakdownView::render(
+            frame,
+            top_chunks[2],
+            stage_results
+                .iter()
+                .filter_map(|stage| stage.mistake_breakdown)
+                .reduce(|total, stage| total.merge(&stage))
+                .as_ref(),
             &colors,
         );
         StageDetailsView::render(
@@ -217,12 +308,27 @@ impl Screen for SessionDetailScreen {
             &colors,
         );
 
-        let controls_line = Line::from(vec![
+        let mut controls = vec![
             Span::styled("[↑↓/JK]", Style::default().fg(colors.key_navigation())),
             Span::styled(" Scroll Stages  ", Style::default().fg(colors.text())),
-            Span::styled("[ESC]", Style::default().fg(colors.error())),
-            Span::styled(" Back", Style::default().fg(colors.text())),
-        ]);
+        ];
+        if self.selected_screenshot().is_some() {
+            controls.push(Span::styled("[O]", Style::default().fg(colors.info())));
+            controls.push(Span::styled(
+                " Open Screenshot  ",
+                Style::default().fg(colors.text()),
+            ));
+        }
+        if self.selected_ghost_race().is_some() {
+            controls.push(Span::styled("[G]", Style::default().fg(colors.success())));
+            controls.push(Span::styled(
+                " Race Ghost  ",
+                Style::default().fg(colors.text()),
+            ));
+        }
+        controls.push(Span::styled("[ESC]", Style::default().fg(colors.error())));
+        controls.push(Span::styled(" Back", Style::default().fg(colors.text())));
+        let controls_line = Line::from(controls);
 
         let controls = Paragraph::new(controls_line).alignment(Alignment::Center);
         frame.render_widget(controls, main_chunks[2]);
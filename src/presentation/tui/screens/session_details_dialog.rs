@@ -89,11 +89,7 @@ impl SessionDetailsDialog {
             3 // Just header and no records message
         };
 
-        let stage_results_lines = if stage_count > 0 {
-            2 + (stage_count * 2) // Header + (stage_name + metrics) * count
-        } else {
-            2 // Just header
-        };
+        let stage_results_lines = StageResultsView::height(stage_count);
 
         let total_content_height = 1 + 1 + best_records_lines + stage_results_lines + 1 + 1; // header + spacing + content + spacing + controls
         let dialog_height =
@@ -1,11 +1,13 @@
 use crate::domain::events::presentation_events::NavigateTo;
 use crate::domain::events::EventBusInterface;
-use crate::domain::models::{GitRepository, SessionResult};
+use crate::domain::models::{FailureAnalysis, GitRepository, SessionResult};
 use crate::domain::services::session_manager_service::SessionManagerInterface;
 use crate::domain::services::theme_service::ThemeServiceInterface;
 use crate::domain::services::SessionManager;
 use crate::domain::stores::RepositoryStoreInterface;
-use crate::presentation::tui::views::session_failure::{ContentView, FooterView, HeaderView};
+use crate::presentation::tui::views::session_failure::{
+    AnalysisView, ContentView, FooterView, HeaderView,
+};
 use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
 use crate::Result;
 use crossterm::event::{KeyCode, KeyModifiers};
@@ -19,6 +21,7 @@ pub struct SessionFailureScreenData {
     pub session_result: SessionResult,
     pub total_stages: usize,
     pub repo_info: Option<GitRepository>,
+    pub analysis: Option<FailureAnalysis>,
 }
 
 pub struct SessionFailureScreenDataProvider;
@@ -40,6 +43,8 @@ pub struct SessionFailureScreen {
     total_stages: RwLock<usize>,
     #[shaku(default)]
     repo_info: RwLock<Option<GitRepository>>,
+    #[shaku(default)]
+    analysis: RwLock<Option<FailureAnalysis>>,
     #[shaku(inject)]
     event_bus: Arc<dyn EventBusInterface>,
     #[shaku(inject)]
@@ -61,12 +66,33 @@ impl SessionFailureScreen {
             session_result: RwLock::new(SessionResult::default()),
             total_stages: RwLock::new(1),
             repo_info: RwLock::new(None),
+            analysis: RwLock::new(None),
             event_bus,
             theme_service,
             session_manager,
             repository_store,
         }
     }
+
+    pub fn get_analysis(&self) -> Option<FailureAnalysis> {
+        self.analysis.read().unwrap().clone()
+    }
+
+    fn retry_failed_challenge(&self) {
+        let Some(analysis) = self.get_analysis() else {
+            return;
+        };
+        if let Some(sm) = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+        {
+            sm.queue_challenge_retry(analysis.challenge);
+        }
+        self.event_bus
+            .as_event_bus()
+            .publish(NavigateTo::Replace(ScreenType::Typing));
+    }
 }
 
 impl Screen for SessionFailureScreen {
@@ -82,13 +108,14 @@ impl Screen for SessionFailureScreen {
     }
 
     fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
-        let (session_result, total_stages, repo_info) = if let Ok(screen_data) =
+        let (session_result, total_stages, repo_info, analysis) = if let Ok(screen_data) =
             data.downcast::<SessionFailureScreenData>()
         {
             (
                 screen_data.session_result,
                 screen_data.total_stages,
                 screen_data.repo_info,
+                screen_data.analysis,
             )
         } else {
             // If no data provided, get from injected dependencies
@@ -106,12 +133,18 @@ impl Screen for SessionFailureScreen {
             let total_stages = sm.get_stage_info().map(|(_, total)| total).unwrap_or(1);
             let repo_info = self.repository_store.get_repository();
 
-            (session_result, total_stages, repo_info)
+            (
+                session_result,
+                total_stages,
+                repo_info,
+                sm.get_failure_analysis(),
+            )
         };
 
         *self.session_result.write().unwrap() = session_result;
         *self.total_stages.write().unwrap() = total_stages;
         *self.repo_info.write().unwrap() = repo_info;
+        *self.analysis.write().unwrap() = analysis;
 
         Ok(())
     }
@@ -124,6 +157,12 @@ impl Screen for SessionFailureScreen {
                     .publish(NavigateTo::Replace(ScreenType::Typing));
                 Ok(())
             }
+            KeyCode::Char('c') | KeyCode::Char('C')
+                if !key_event.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                self.retry_failed_challenge();
+                Ok(())
+            }
             KeyCode::Char('t') | KeyCode::Char('T') => {
                 self.event_bus
                     .as_event_bus()
@@ -145,19 +184,24 @@ impl Screen for SessionFailureScreen {
     fn render_ratatui(&self, frame: &mut Frame) -> Result<()> {
         let colors = self.theme_service.get_colors();
         let area = frame.area();
+        let analysis = self.analysis.read().unwrap();
+        let analysis_height = analysis.as_ref().map_or(0, AnalysisView::height);
 
         // Calculate vertical centering
-        let content_height = 10; // header + 3 spacing + content (6 lines) + 1 spacing + nav
+        // header + 3 spacing + content (6 lines) + analysis + 1 spacing + nav
+        let content_height = 10 + analysis_height + u16::from(analysis.is_some());
         let top_spacing = (area.height.saturating_sub(content_height)) / 2;
 
         // Create vertical layout
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(top_spacing), // Top spacing
-                Constraint::Length(1),           // Header
-                Constraint::Length(3),           // Spacing after header
+                Constraint::Length(top_spacing),                   // Top spacing
+                Constraint::Length(1),                             // Header
+                Constraint::Length(3),                             // Spacing after header
                 Constraint::Length(6), // Content (stage + spacing + metrics*2 + spacing + message)
+                Constraint::Length(u16::from(analysis.is_some())), // Spacing
+                Constraint::Length(analysis_height), // Failure analysis
                 Constraint::Length(1), // Spacing
                 Constraint::Length(1), // Navigation
                 Constraint::Min(0),    // Bottom spacing
@@ -169,7 +213,10 @@ impl Screen for SessionFailureScreen {
 
         HeaderView::render(frame, chunks[1], &colors);
         ContentView::render(frame, chunks[3], &session_result, total_stages, &colors);
-        FooterView::render(frame, chunks[5], &colors);
+        if let Some(analysis) = analysis.as_ref() {
+            AnalysisView::render(frame, chunks[5], analysis, &colors);
+        }
+        FooterView::render(frame, chunks[7], analysis.is_some(), &colors);
 
         Ok(())
     }
@@ -1,21 +1,40 @@
 use crate::domain::events::presentation_events::NavigateTo;
 use crate::domain::events::EventBusInterface;
-use crate::domain::models::{GitRepository, Rank, SessionResult};
+use crate::domain::models::storage::RepositoryRating;
+use crate::domain::models::{DemoMode, GitRepository, Rank, SessionResult, StreamerMode};
+use crate::domain::services::repository_rating_service::RepositoryRatingServiceInterface;
 use crate::domain::services::session_manager_service::SessionManagerInterface;
 use crate::domain::services::theme_service::ThemeServiceInterface;
 use crate::domain::services::SessionManager;
 use crate::domain::stores::RepositoryStoreInterface;
+use crate::infrastructure::storage::file_storage::FileStorageInterface;
 use crate::presentation::tui::views::{
     OptionsView, RankView, ScoreView, SessionSummaryHeaderView, SummaryView,
 };
 use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
+use crate::presentation::ui::{Colors, ScreenCapture};
 use crate::{GitTypeError, Result};
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
-    Frame,
+    backend::TestBackend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::Line,
+    widgets::Paragraph,
+    Frame, Terminal,
 };
+use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// Directory under the app data directory holding exported summaries
+pub const EXPORTS_DIR: &str = "exports";
+
+/// Width of the exported summary; wide enough for every rank title
+const EXPORT_WIDTH: u16 = 100;
+
+/// Spacing and the line crediting the repository in an exported summary
+const ATTRIBUTION_HEIGHT: u16 = 2;
 
 pub struct SessionSummaryScreenData {
     pub session_result: Option<SessionResult>,
@@ -50,6 +69,10 @@ pub struct SessionSummaryScreen {
     session_result: RwLock<Option<SessionResult>>,
     #[shaku(default)]
     git_repository: RwLock<Option<GitRepository>>,
+    #[shaku(default)]
+    status_message: RwLock<Option<String>>,
+    #[shaku(default)]
+    shown_at: RwLock<Option<Instant>>,
     #[shaku(inject)]
     event_bus: Arc<dyn EventBusInterface>,
     #[shaku(inject)]
@@ -58,6 +81,10 @@ pub struct SessionSummaryScreen {
     repository_store: Arc<dyn RepositoryStoreInterface>,
     #[shaku(inject)]
     theme_service: Arc<dyn ThemeServiceInterface>,
+    #[shaku(inject)]
+    file_storage: Arc<dyn FileStorageInterface>,
+    #[shaku(inject)]
+    repository_rating_service: Arc<dyn RepositoryRatingServiceInterface>,
 }
 
 impl SessionSummaryScreen {
@@ -66,21 +93,256 @@ impl SessionSummaryScreen {
         theme_service: Arc<dyn ThemeServiceInterface>,
         session_manager: Arc<dyn SessionManagerInterface>,
         repository_store: Arc<dyn RepositoryStoreInterface>,
+        file_storage: Arc<dyn FileStorageInterface>,
+        repository_rating_service: Arc<dyn RepositoryRatingServiceInterface>,
     ) -> Self {
         Self {
             action_result: RwLock::new(None),
             session_result: RwLock::new(None),
             git_repository: RwLock::new(None),
+            status_message: RwLock::new(None),
+            shown_at: RwLock::new(None),
             event_bus,
             session_manager,
             repository_store,
             theme_service,
+            file_storage,
+            repository_rating_service,
         }
     }
 
     pub fn get_action_result(&self) -> Option<ResultAction> {
         self.action_result.read().unwrap().clone()
     }
+
+    pub fn get_status_message(&self) -> Option<String> {
+        self.status_message.read().unwrap().clone()
+    }
+
+    /// Id of the session to rate; `None` when it wasn't recorded or had no repository
+    pub fn rateable_session_id(&self) -> Option<i64> {
+        self.git_repository.read().unwrap().as_ref()?;
+        self.session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()?
+            .get_recorded_session_id()
+    }
+
+    fn rate(&self, rating: u8) -> Result<()> {
+        let Some(session_id) = self.rateable_session_id() else {
+            return Ok(());
+        };
+        let message = match self
+            .repository_rating_service
+            .rate_session(session_id, rating)
+        {
+            Ok(true) => format!(
+                "Rated {} {}/{}",
+                self.git_repository
+                    .read()
+                    .unwrap()
+                    .as_ref()
+                    .map(|repository| repository.repository_name.as_str())
+                    .unwrap_or("the repository"),
+                rating,
+                RepositoryRating::MAX
+            ),
+            Ok(false) => "This session has no repository to rate".to_string(),
+            Err(e) => format!("Rating failed: {}", e),
+        };
+        *self.status_message.write().unwrap() = Some(message);
+        Ok(())
+    }
+
+    /// The summary without the key options as a standalone HTML page in the theme's colors
+    pub fn summary_html(&self) -> Result<Option<String>> {
+        let attribution_height = self.attribution().map_or(0, |_| ATTRIBUTION_HEIGHT);
+        let Some(height) = self.session_result.read().unwrap().as_ref().map(|result| {
+            Self::content_height(Rank::for_score(result.session_score).name(), false)
+                + attribution_height
+        }) else {
+            return Ok(None);
+        };
+
+        let to_terminal_error =
+            |e: std::convert::Infallible| GitTypeError::TerminalError(e.to_string());
+        let mut terminal =
+            Terminal::new(TestBackend::new(EXPORT_WIDTH, height)).map_err(to_terminal_error)?;
+        let mut rendered = Ok(());
+        terminal
+            .draw(|frame| rendered = self.render_summary(frame, false))
+            .map_err(to_terminal_error)?;
+        rendered?;
+        Ok(Some(ScreenCapture::to_themed_html(
+            terminal.backend().buffer(),
+            "gittype · Session Summary",
+            &self.theme_service.get_colors(),
+        )))
+    }
+
+    /// Write [`Self::summary_html`] to the exports directory and return its path
+    pub fn export_html(&self) -> Result<Option<PathBuf>> {
+        let Some(html) = self.summary_html()? else {
+            return Ok(None);
+        };
+        let path = self
+            .file_storage
+            .get_app_data_dir()?
+            .join(EXPORTS_DIR)
+            .join(format!(
+                "session-summary-{}.html",
+                chrono::Local::now().format("%Y%m%d-%H%M%S")
+            ));
+        if let Some(parent) = path.parent() {
+            self.file_storage.create_dir_all(parent)?;
+        }
+        self.file_storage.write(&path, html.as_bytes())?;
+        Ok(Some(path))
+    }
+
+    fn rank_height(rank_name: &str) -> u16 {
+        let rank_patterns = crate::domain::models::ui::ascii_rank_titles::get_all_rank_patterns();
+        let rank_lines = rank_patterns.get(rank_name);
+        let rank_ascii_height = rank_lines.map(|l| l.len()).unwrap_or(0);
+
+        // Check if last line is empty to determine spacing needed
+        let last_line_is_empty = rank_lines
+            .and_then(|lines| lines.last())
+            .map(|line| line.trim().is_empty())
+            .unwrap_or(false);
+
+        if last_line_is_empty {
+            rank_ascii_height as u16 + 1 // ASCII + tier info
+        } else {
+            rank_ascii_height as u16 + 2 // ASCII + spacing + tier info
+        }
+    }
+
+    fn content_height(rank_name: &str, with_options: bool) -> u16 {
+        let header_height = 4; // Header (title + spacing + YOU'RE)
+        let score_height = 8; // Score label + best label + ASCII + diff
+        let summary_height = 2; // Two lines of metrics
+        let options_height = if with_options { 2 + 2 } else { 0 }; // Spacing + two lines of options
+        header_height
+            + Self::rank_height(rank_name)
+            + 2 // spacing before score
+            + score_height
+            + 1 // spacing after score
+            + summary_height
+            + options_height
+    }
+
+    fn render_summary(&self, frame: &mut Frame, with_options: bool) -> Result<()> {
+        let colors = self.theme_service.get_colors();
+        let session_result = self.session_result.read().unwrap();
+        if let Some(ref session_result) = *session_result {
+            let area = frame.area();
+
+            let best_rank = Rank::for_score(session_result.session_score);
+
+            // Get best status using session start records from SessionManager instance
+            let best_status = self
+                .session_manager
+                .as_any()
+                .downcast_ref::<SessionManager>()
+                .and_then(|manager| {
+                    manager
+                        .get_best_status_for_score(session_result.session_score)
+                        .ok()
+                        .flatten()
+                })
+                // Unverified results never count as a new best
+                .filter(|_| session_result.plausibility.is_verified());
+
+            // Exports credit the repository below the summary, where the options go on screen
+            let attribution = self.attribution().filter(|_| !with_options);
+            let rank_total_height = Self::rank_height(best_rank.name());
+            let total_content_height = Self::content_height(best_rank.name(), with_options)
+                + attribution.as_ref().map_or(0, |_| ATTRIBUTION_HEIGHT);
+            let top_spacing = (area.height.saturating_sub(total_content_height)) / 2;
+
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(top_spacing),
+                    Constraint::Length(4),                 // Header
+                    Constraint::Length(rank_total_height), // Rank + tier info (with spacing if needed)
+                    Constraint::Length(2),                 // Spacing before score
+                    Constraint::Length(8),                 // Score
+                    Constraint::Length(1),                 // Spacing after score
+                    Constraint::Length(2),                 // Summary
+                    Constraint::Length(2),                 // Spacing or attribution
+                    Constraint::Length(if with_options { 2 } else { 0 }), // Options
+                    Constraint::Min(0),
+                ])
+                .split(area);
+
+            SessionSummaryHeaderView::render(frame, chunks[1], &colors);
+            RankView::render(frame, chunks[2], &best_rank, session_result.session_score);
+            ScoreView::render(
+                frame,
+                chunks[4],
+                session_result,
+                &best_rank,
+                best_status.as_ref(),
+                &colors,
+            );
+            SummaryView::render(frame, chunks[6], session_result, &colors);
+            if with_options {
+                self.render_status(frame, chunks[7], session_result, &colors);
+                OptionsView::render(
+                    frame,
+                    chunks[8],
+                    self.rateable_session_id().is_some(),
+                    &colors,
+                );
+            } else if let Some(attribution) = attribution {
+                let area = Rect {
+                    y: chunks[7].y + 1,
+                    height: 1,
+                    ..chunks[7]
+                };
+                frame.render_widget(
+                    Paragraph::new(Line::styled(
+                        attribution,
+                        Style::default().fg(colors.text_secondary()),
+                    ))
+                    .alignment(Alignment::Center),
+                    area,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn attribution(&self) -> Option<String> {
+        self.git_repository
+            .read()
+            .unwrap()
+            .as_ref()
+            .filter(|_| !StreamerMode::is_enabled())
+            .map(GitRepository::attribution)
+    }
+
+    /// The latest status message, or else why the result is unverified
+    fn render_status(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        session_result: &SessionResult,
+        colors: &Colors,
+    ) {
+        let line = match (
+            self.get_status_message(),
+            session_result.plausibility.label(),
+        ) {
+            (Some(message), _) => Line::styled(message, Style::default().fg(colors.info())),
+            (None, Some(label)) => Line::styled(label, Style::default().fg(colors.warning())),
+            (None, None) => return,
+        };
+        let area = Rect { height: 1, ..area };
+        frame.render_widget(Paragraph::new(line).alignment(Alignment::Center), area);
+    }
 }
 
 pub struct SessionSummaryScreenProvider;
@@ -96,11 +358,15 @@ impl shaku::Provider<crate::presentation::di::AppModule> for SessionSummaryScree
         let theme_service: Arc<dyn ThemeServiceInterface> = module.resolve();
         let session_manager: Arc<dyn SessionManagerInterface> = module.resolve();
         let repository_store: Arc<dyn RepositoryStoreInterface> = module.resolve();
+        let file_storage: Arc<dyn FileStorageInterface> = module.resolve();
+        let repository_rating_service: Arc<dyn RepositoryRatingServiceInterface> = module.resolve();
         Ok(Box::new(SessionSummaryScreen::new(
             event_bus,
             theme_service,
             session_manager,
             repository_store,
+            file_storage,
+            repository_rating_service,
         )))
     }
 }
@@ -119,6 +385,8 @@ impl Screen for SessionSummaryScreen {
 
     fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
         *self.action_result.write().unwrap() = None;
+        *self.status_message.write().unwrap() = None;
+        *self.shown_at.write().unwrap() = Some(Instant::now());
 
         let (session_result, git_repository) =
             if let Ok(screen_data) = data.downcast::<SessionSummaryScreenData>() {
@@ -149,6 +417,14 @@ impl Screen for SessionSummaryScreen {
     }
 
     fn handle_key_event(&self, key_event: crossterm::event::KeyEvent) -> Result<()> {
+        if DemoMode::is_playing() {
+            DemoMode::set_playing(false);
+            self.event_bus
+                .as_event_bus()
+                .publish(NavigateTo::PopTo(ScreenType::Title));
+            return Ok(());
+        }
+
         match key_event.code {
             KeyCode::Char('d') | KeyCode::Char('D') => {
                 self.event_bus
@@ -170,6 +446,16 @@ impl Screen for SessionSummaryScreen {
                     .publish(NavigateTo::Push(ScreenType::SessionSharing));
                 Ok(())
             }
+            KeyCode::Char(digit @ '1'..='5') => self.rate(digit as u8 - b'0'),
+            KeyCode::Char('h') | KeyCode::Char('H') => {
+                let message = match self.export_html() {
+                    Ok(Some(path)) => format!("Exported to {}", path.display()),
+                    Ok(None) => return Ok(()),
+                    Err(e) => format!("Export failed: {}", e),
+                };
+                *self.status_message.write().unwrap() = Some(message);
+                Ok(())
+            }
             KeyCode::Char('t') | KeyCode::Char('T') => {
                 *self.action_result.write().unwrap() = Some(ResultAction::BackToTitle);
                 self.event_bus
@@ -192,96 +478,31 @@ impl Screen for SessionSummaryScreen {
     }
 
     fn render_ratatui(&self, frame: &mut Frame) -> Result<()> {
-        let colors = self.theme_service.get_colors();
-        let session_result = self.session_result.read().unwrap();
-        if let Some(ref session_result) = *session_result {
-            let area = frame.area();
-
-            let best_rank = Rank::for_score(session_result.session_score);
-
-            // Get best status using session start records from SessionManager instance
-            let best_status = self
-                .session_manager
-                .as_any()
-                .downcast_ref::<SessionManager>()
-                .and_then(|manager| {
-                    manager
-                        .get_best_status_for_score(session_result.session_score)
-                        .ok()
-                        .flatten()
-                });
-
-            // Get actual rank ASCII height
-            let rank_patterns =
-                crate::domain::models::ui::ascii_rank_titles::get_all_rank_patterns();
-            let rank_lines = rank_patterns.get(best_rank.name());
-            let rank_ascii_height = rank_lines.map(|l| l.len()).unwrap_or(0);
-
-            // Check if last line is empty to determine spacing needed
-            let last_line_is_empty = rank_lines
-                .and_then(|lines| lines.last())
-                .map(|line| line.trim().is_empty())
-                .unwrap_or(false);
-
-            let rank_total_height = if last_line_is_empty {
-                rank_ascii_height + 1 // ASCII + tier info
-            } else {
-                rank_ascii_height + 2 // ASCII + spacing + tier info
-            };
-
-            // Calculate content height
-            let header_height = 4; // Header (title + spacing + YOU'RE)
-            let score_height = 8; // Score label + best label + ASCII + diff
-            let summary_height = 2; // Two lines of metrics
-            let options_height = 2; // Two lines of options
-            let total_content_height = header_height
-                + rank_total_height
-                + 2 // spacing before score
-                + score_height
-                + 1 // spacing after score
-                + summary_height
-                + 2 // spacing
-                + options_height;
-
-            let top_spacing = (area.height.saturating_sub(total_content_height as u16)) / 2;
-
-            let chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([
-                    Constraint::Length(top_spacing),
-                    Constraint::Length(4),                        // Header
-                    Constraint::Length(rank_total_height as u16), // Rank + tier info (with spacing if needed)
-                    Constraint::Length(2),                        // Spacing before score
-                    Constraint::Length(score_height as u16),      // Score
-                    Constraint::Length(1),                        // Spacing after score
-                    Constraint::Length(2),                        // Summary
-                    Constraint::Length(2),                        // Spacing
-                    Constraint::Length(2),                        // Options
-                    Constraint::Min(0),
-                ])
-                .split(area);
-
-            SessionSummaryHeaderView::render(frame, chunks[1], &colors);
-            RankView::render(frame, chunks[2], &best_rank, session_result.session_score);
-            ScoreView::render(
-                frame,
-                chunks[4],
-                session_result,
-                &best_rank,
-                best_status.as_ref(),
-                &colors,
-            );
-            SummaryView::render(frame, chunks[6], session_result, &colors);
-            OptionsView::render(frame, chunks[8], &colors);
-        }
-        Ok(())
+        self.render_summary(frame, true)
     }
 
     fn get_update_strategy(&self) -> UpdateStrategy {
-        UpdateStrategy::InputOnly
+        if DemoMode::is_playing() {
+            UpdateStrategy::TimeBased(Duration::from_millis(250))
+        } else {
+            UpdateStrategy::InputOnly
+        }
     }
 
+    /// A finished demo goes back to the title screen, which starts the next one
     fn update(&self) -> Result<bool> {
+        let shown_long_enough = self
+            .shown_at
+            .read()
+            .unwrap()
+            .is_some_and(|at| at.elapsed() >= DemoMode::SUMMARY_DURATION);
+        if DemoMode::is_playing() && shown_long_enough {
+            DemoMode::set_playing(false);
+            *self.shown_at.write().unwrap() = None;
+            self.event_bus
+                .as_event_bus()
+                .publish(NavigateTo::PopTo(ScreenType::Title));
+        }
         Ok(false)
     }
 
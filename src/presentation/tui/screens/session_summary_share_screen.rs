@@ -1,6 +1,9 @@
 use crate::domain::events::presentation_events::NavigateTo;
 use crate::domain::events::EventBusInterface;
-use crate::domain::models::{GitRepository, SessionResult};
+use crate::domain::models::storage::RepositoryMetadata;
+use crate::domain::models::{GitRepository, PlayerProfile, SessionResult};
+use crate::domain::repositories::repository_metadata_repository::RepositoryMetadataRepositoryInterface;
+use crate::domain::services::profile_service::ProfileServiceInterface;
 use crate::domain::services::session_manager_service::SessionManagerInterface;
 use crate::domain::services::theme_service::ThemeServiceInterface;
 use crate::domain::services::SessionManager;
@@ -40,6 +43,10 @@ pub struct SessionSummaryShareScreen {
     session_result: RwLock<Option<SessionResult>>,
     #[shaku(default)]
     git_repository: RwLock<Option<GitRepository>>,
+    #[shaku(default)]
+    repository_metadata: RwLock<Option<RepositoryMetadata>>,
+    #[shaku(default)]
+    profile: RwLock<Option<PlayerProfile>>,
     #[shaku(inject)]
     event_bus: Arc<dyn EventBusInterface>,
     #[shaku(inject)]
@@ -48,6 +55,10 @@ pub struct SessionSummaryShareScreen {
     session_manager: Arc<dyn SessionManagerInterface>,
     #[shaku(inject)]
     repository_store: Arc<dyn RepositoryStoreInterface>,
+    #[shaku(inject)]
+    repository_metadata_repository: Arc<dyn RepositoryMetadataRepositoryInterface>,
+    #[shaku(inject)]
+    profile_service: Arc<dyn ProfileServiceInterface>,
 }
 
 impl SessionSummaryShareScreen {
@@ -56,16 +67,42 @@ impl SessionSummaryShareScreen {
         theme_service: Arc<dyn ThemeServiceInterface>,
         session_manager: Arc<dyn SessionManagerInterface>,
         repository_store: Arc<dyn RepositoryStoreInterface>,
+        repository_metadata_repository: Arc<dyn RepositoryMetadataRepositoryInterface>,
+        profile_service: Arc<dyn ProfileServiceInterface>,
     ) -> Self {
         Self {
             session_result: RwLock::new(None),
             git_repository: RwLock::new(None),
+            repository_metadata: RwLock::new(None),
+            profile: RwLock::new(None),
             event_bus,
             theme_service,
             session_manager,
             repository_store,
+            repository_metadata_repository,
+            profile_service,
         }
     }
+
+    fn load_repository_metadata(
+        &self,
+        git_repository: &Option<GitRepository>,
+    ) -> Option<RepositoryMetadata> {
+        let git_repository = git_repository.as_ref()?;
+        self.repository_metadata_repository
+            .refresh_metadata(git_repository)
+            .unwrap_or_else(|e| {
+                log::warn!("Failed to load repository metadata: {}", e);
+                None
+            })
+    }
+
+    fn load_profile(&self) -> Option<PlayerProfile> {
+        self.profile_service
+            .load_profile()
+            .map_err(|e| log::warn!("Failed to load player profile: {}", e))
+            .ok()
+    }
 }
 
 pub struct SessionSummaryShareScreenProvider;
@@ -81,11 +118,16 @@ impl shaku::Provider<crate::presentation::di::AppModule> for SessionSummaryShare
         let theme_service: Arc<dyn ThemeServiceInterface> = module.resolve();
         let session_manager: Arc<dyn SessionManagerInterface> = module.resolve();
         let repository_store: Arc<dyn RepositoryStoreInterface> = module.resolve();
+        let repository_metadata_repository: Arc<dyn RepositoryMetadataRepositoryInterface> =
+            module.resolve();
+        let profile_service: Arc<dyn ProfileServiceInterface> = module.resolve();
         Ok(Box::new(SessionSummaryShareScreen::new(
             event_bus,
             theme_service,
             session_manager,
             repository_store,
+            repository_metadata_repository,
+            profile_service,
         )))
     }
 }
@@ -122,6 +164,8 @@ impl Screen for SessionSummaryShareScreen {
                 (session_result, git_repository)
             };
 
+        *self.repository_metadata.write().unwrap() = self.load_repository_metadata(&git_repository);
+        *self.profile.write().unwrap() = self.load_profile();
         *self.session_result.write().unwrap() = session_result;
         *self.git_repository.write().unwrap() = git_repository;
 
@@ -133,11 +177,15 @@ impl Screen for SessionSummaryShareScreen {
             KeyCode::Char('1') => {
                 let session_result = self.session_result.read().unwrap();
                 let git_repository = self.git_repository.read().unwrap();
+                let repository_metadata = self.repository_metadata.read().unwrap();
+                let profile = self.profile.read().unwrap();
                 if let Some(ref session_result) = *session_result {
                     let _ = SharingService::share_result(
                         session_result,
                         SharingPlatform::X,
                         &git_repository,
+                        &repository_metadata,
+                        &profile,
                     );
                 }
                 self.event_bus.as_event_bus().publish(NavigateTo::Pop);
@@ -146,11 +194,15 @@ impl Screen for SessionSummaryShareScreen {
             KeyCode::Char('2') => {
                 let session_result = self.session_result.read().unwrap();
                 let git_repository = self.git_repository.read().unwrap();
+                let repository_metadata = self.repository_metadata.read().unwrap();
+                let profile = self.profile.read().unwrap();
                 if let Some(ref session_result) = *session_result {
                     let _ = SharingService::share_result(
                         session_result,
                         SharingPlatform::Reddit,
                         &git_repository,
+                        &repository_metadata,
+                        &profile,
                     );
                 }
                 self.event_bus.as_event_bus().publish(NavigateTo::Pop);
@@ -159,11 +211,15 @@ impl Screen for SessionSummaryShareScreen {
             KeyCode::Char('3') => {
                 let session_result = self.session_result.read().unwrap();
                 let git_repository = self.git_repository.read().unwrap();
+                let repository_metadata = self.repository_metadata.read().unwrap();
+                let profile = self.profile.read().unwrap();
                 if let Some(ref session_result) = *session_result {
                     let _ = SharingService::share_result(
                         session_result,
                         SharingPlatform::LinkedIn,
                         &git_repository,
+                        &repository_metadata,
+                        &profile,
                     );
                 }
                 self.event_bus.as_event_bus().publish(NavigateTo::Pop);
@@ -172,11 +228,15 @@ impl Screen for SessionSummaryShareScreen {
             KeyCode::Char('4') => {
                 let session_result = self.session_result.read().unwrap();
                 let git_repository = self.git_repository.read().unwrap();
+                let repository_metadata = self.repository_metadata.read().unwrap();
+                let profile = self.profile.read().unwrap();
                 if let Some(ref session_result) = *session_result {
                     let _ = SharingService::share_result(
                         session_result,
                         SharingPlatform::Facebook,
                         &git_repository,
+                        &repository_metadata,
+                        &profile,
                     );
                 }
                 self.event_bus.as_event_bus().publish(NavigateTo::Pop);
@@ -198,10 +258,12 @@ impl Screen for SessionSummaryShareScreen {
         let colors = self.theme_service.get_colors();
         let session_result = self.session_result.read().unwrap();
         let git_repository = self.git_repository.read().unwrap();
+        let repository_metadata = self.repository_metadata.read().unwrap();
+        let profile = self.profile.read().unwrap();
         if let Some(ref session_result) = *session_result {
             let area = frame.area();
 
-            let content_height = 12;
+            let content_height = 15;
             let top_spacing = (area.height.saturating_sub(content_height)) / 2;
 
             let chunks = Layout::default()
@@ -210,7 +272,7 @@ impl Screen for SessionSummaryShareScreen {
                     Constraint::Length(top_spacing),
                     Constraint::Length(1),
                     Constraint::Length(3),
-                    Constraint::Length(1),
+                    Constraint::Length(4),
                     Constraint::Length(1),
                     Constraint::Length(4),
                     Constraint::Length(1),
@@ -220,7 +282,15 @@ impl Screen for SessionSummaryShareScreen {
                 .split(area);
 
             ShareTitleView::render(frame, chunks[1], &colors);
-            SharePreviewView::render(frame, chunks[3], session_result, &git_repository, &colors);
+            SharePreviewView::render(
+                frame,
+                chunks[3],
+                session_result,
+                &git_repository,
+                &repository_metadata,
+                &profile,
+                &colors,
+            );
             SharePlatformOptionsView::render(frame, chunks[5], &colors);
             ShareBackOptionView::render(frame, chunks[7], &colors);
         }
@@ -1,38 +1,134 @@
 use crate::domain::events::presentation_events::NavigateTo;
 use crate::domain::events::EventBusInterface;
 use crate::domain::models::color_mode::ColorMode;
+use crate::domain::models::config::{ReleaseChannel, VersionCheckConfig, VersionCheckFrequency};
+use crate::domain::models::locale::LocalePreset;
 use crate::domain::models::theme::Theme;
 use crate::domain::services::config_service::{ConfigService, ConfigServiceInterface};
+use crate::domain::services::streamer_stats_service::DEFAULT_STATS_FILE_NAME;
 use crate::domain::services::theme_service::ThemeServiceInterface;
 use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType};
-use crate::presentation::ui::Colors;
+use crate::presentation::ui::{fuzzy_score, Colors};
 use crate::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::Style,
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph, Tabs, Wrap},
+    widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph, Wrap},
     Frame,
 };
 use std::sync::Arc;
 use std::sync::RwLock;
+const DISCORD_OPTIONS: &[&str] = &["Off", "On"];
+const DISCORD_ENABLED_INDEX: usize = 1;
+const STREAMER_OPTIONS: &[&str] = &["Off", "On"];
+const STREAMER_ENABLED_INDEX: usize = 1;
+const UPDATE_CHECK_OPTIONS: &[(&str, VersionCheckFrequency, ReleaseChannel)] = &[
+    ("Off", VersionCheckFrequency::Off, ReleaseChannel::Stable),
+    (
+        "Daily",
+        VersionCheckFrequency::Daily,
+        ReleaseChannel::Stable,
+    ),
+    (
+        "Weekly",
+        VersionCheckFrequency::Weekly,
+        ReleaseChannel::Stable,
+    ),
+    (
+        "Daily, including pre-releases",
+        VersionCheckFrequency::Daily,
+        ReleaseChannel::PreRelease,
+    ),
+    (
+        "Weekly, including pre-releases",
+        VersionCheckFrequency::Weekly,
+        ReleaseChannel::PreRelease,
+    ),
+];
+
+/// Groups shown in the sidebar; new settings join an existing category
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SettingsCategory {
+    Appearance,
+    Integrations,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum SettingsSection {
     #[default]
     ColorMode,
     Theme,
+    Formats,
+    Discord,
+    Streamer,
+    Updates,
+}
+
+impl SettingsCategory {
+    pub fn all() -> &'static [SettingsCategory] {
+        &[SettingsCategory::Appearance, SettingsCategory::Integrations]
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            SettingsCategory::Appearance => "Appearance",
+            SettingsCategory::Integrations => "Integrations",
+        }
+    }
 }
 
 impl SettingsSection {
-    fn all() -> &'static [SettingsSection] {
-        &[SettingsSection::ColorMode, SettingsSection::Theme]
+    pub fn all() -> &'static [SettingsSection] {
+        &[
+            SettingsSection::ColorMode,
+            SettingsSection::Theme,
+            SettingsSection::Formats,
+            SettingsSection::Discord,
+            SettingsSection::Streamer,
+            SettingsSection::Updates,
+        ]
     }
 
-    fn title(&self) -> &'static str {
+    pub fn title(&self) -> &'static str {
         match self {
             SettingsSection::ColorMode => "Color Mode",
             SettingsSection::Theme => "Theme",
+            SettingsSection::Formats => "Formats",
+            SettingsSection::Discord => "Discord",
+            SettingsSection::Streamer => "Streamer",
+            SettingsSection::Updates => "Updates",
+        }
+    }
+
+    pub fn category(&self) -> SettingsCategory {
+        match self {
+            SettingsSection::ColorMode | SettingsSection::Theme | SettingsSection::Formats => {
+                SettingsCategory::Appearance
+            }
+            SettingsSection::Discord | SettingsSection::Streamer | SettingsSection::Updates => {
+                SettingsCategory::Integrations
+            }
+        }
+    }
+
+    /// Best fuzzy match of `query` against the title, category and search keywords
+    pub fn search_score(&self, query: &str) -> Option<u32> {
+        [self.title(), self.category().title(), self.keywords()]
+            .iter()
+            .filter_map(|text| fuzzy_score(query, text))
+            .max()
+    }
+
+    fn keywords(&self) -> &'static str {
+        match self {
+            SettingsSection::ColorMode => "dark light background",
+            SettingsSection::Theme => "colors palette preview",
+            SettingsSection::Formats => "locale region number date 24h clock",
+            SettingsSection::Discord => "rich presence status",
+            SettingsSection::Streamer => "obs twitch privacy hide overlay",
+            SettingsSection::Updates => "version release check network beta",
         }
     }
 
@@ -40,6 +136,12 @@ impl SettingsSection {
         match self {
             SettingsSection::ColorMode => "Choose between dark and light modes",
             SettingsSection::Theme => "Select theme - preview changes instantly",
+            SettingsSection::Formats => "How numbers, dates and times are written on every screen",
+            SettingsSection::Discord => {
+                "Show what you are typing in your Discord status while a session is active"
+            }
+            SettingsSection::Streamer => "Keep private code details off screen while streaming",
+            SettingsSection::Updates => "How often to check GitHub for a newer release on startup",
         }
     }
 }
@@ -72,6 +174,18 @@ pub struct SettingsScreen {
     original_color_mode: RwLock<ColorMode>,
     #[shaku(default)]
     is_preview_mode: RwLock<bool>,
+    #[shaku(default)]
+    discord_state: RwLock<ListState>,
+    #[shaku(default)]
+    streamer_state: RwLock<ListState>,
+    #[shaku(default)]
+    update_check_state: RwLock<ListState>,
+    #[shaku(default)]
+    locale_state: RwLock<ListState>,
+    #[shaku(default)]
+    search_query: RwLock<String>,
+    #[shaku(default)]
+    is_searching: RwLock<bool>,
     #[shaku(inject)]
     event_bus: Arc<dyn EventBusInterface>,
     #[shaku(inject)]
@@ -100,12 +214,98 @@ impl SettingsScreen {
             original_theme: RwLock::new(Theme::default()),
             original_color_mode: RwLock::new(ColorMode::default()),
             is_preview_mode: RwLock::new(false),
+            discord_state: RwLock::new(ListState::default()),
+            streamer_state: RwLock::new(ListState::default()),
+            update_check_state: RwLock::new(ListState::default()),
+            locale_state: RwLock::new(ListState::default()),
+            search_query: RwLock::new(String::new()),
+            is_searching: RwLock::new(false),
             event_bus,
             config_service,
             theme_service,
         }
     }
 
+    pub fn current_section(&self) -> SettingsSection {
+        *self.current_section.read().unwrap()
+    }
+
+    pub fn search_query(&self) -> String {
+        self.search_query.read().unwrap().clone()
+    }
+
+    pub fn is_searching(&self) -> bool {
+        *self.is_searching.read().unwrap()
+    }
+
+    /// Sections matching the search query, best match first; all sections when empty
+    pub fn visible_sections(&self) -> Vec<SettingsSection> {
+        let query = self.search_query.read().unwrap();
+        if query.trim().is_empty() {
+            return SettingsSection::all().to_vec();
+        }
+
+        let mut matches: Vec<(SettingsSection, u32)> = SettingsSection::all()
+            .iter()
+            .filter_map(|section| section.search_score(&query).map(|score| (*section, score)))
+            .collect();
+        matches.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+        matches.into_iter().map(|(section, _)| section).collect()
+    }
+
+    fn move_section(&self, forward: bool) {
+        let sections = self.visible_sections();
+        if sections.is_empty() {
+            return;
+        }
+        let current_section = self.current_section();
+        let new_index = match sections.iter().position(|&s| s == current_section) {
+            Some(index) if forward => (index + 1) % sections.len(),
+            Some(index) => (index + sections.len() - 1) % sections.len(),
+            None => 0,
+        };
+        *self.current_section.write().unwrap() = sections[new_index];
+    }
+
+    fn next_category(&self) {
+        let sections = self.visible_sections();
+        let current_category = self.current_section().category();
+        let categories = SettingsCategory::all();
+        let start = categories
+            .iter()
+            .position(|&c| c == current_category)
+            .unwrap_or(0);
+
+        if let Some(section) = (1..=categories.len())
+            .map(|offset| categories[(start + offset) % categories.len()])
+            .find_map(|category| sections.iter().find(|s| s.category() == category))
+        {
+            *self.current_section.write().unwrap() = *section;
+        }
+    }
+
+    fn update_search(&self, update: impl FnOnce(&mut String)) {
+        update(&mut self.search_query.write().unwrap());
+        if let Some(best) = self.visible_sections().first() {
+            *self.current_section.write().unwrap() = *best;
+        }
+    }
+
+    fn handle_search_key(&self, key_event: KeyEvent) {
+        match key_event.code {
+            KeyCode::Enter => *self.is_searching.write().unwrap() = false,
+            KeyCode::Esc => {
+                *self.is_searching.write().unwrap() = false;
+                self.update_search(String::clear);
+            }
+            KeyCode::Backspace => self.update_search(|query| {
+                query.pop();
+            }),
+            KeyCode::Char(c) => self.update_search(|query| query.push(c)),
+            _ => {}
+        }
+    }
+
     fn apply_current_selection(&self) {
         *self.is_preview_mode.write().unwrap() = true;
 
@@ -139,6 +339,13 @@ impl SettingsScreen {
         // Save theme and color mode to config file
         let selected_color_mode = self.get_selected_color_mode();
         let selected_theme = self.get_selected_theme();
+        let discord_enabled = self.is_discord_enabled_selected();
+        let streamer_enabled = self.is_streamer_enabled_selected();
+        let version_check = self.get_selected_version_check();
+        let locale = self.get_selected_locale();
+        // Picking a different color mode stops following the terminal background
+        let color_mode_changed =
+            selected_color_mode.as_ref() != Some(&*self.original_color_mode.read().unwrap());
 
         if let (Some(color_mode), Some(theme)) = (selected_color_mode, selected_theme) {
             // Downcast to concrete type to access update_config method
@@ -147,8 +354,22 @@ impl SettingsScreen {
             {
                 let _ = config_service.update_config(|config| {
                     config.theme.current_color_mode = color_mode.clone();
+                    if color_mode_changed {
+                        config.theme.auto_color_mode = false;
+                    }
                     config.theme.current_theme_id = theme.id.clone();
+                    config.discord.enabled = discord_enabled;
+                    config.streamer.enabled = streamer_enabled;
+                    if let Some(version_check) = version_check {
+                        config.version_check = version_check;
+                    }
+                    if let Some(locale) = locale {
+                        config.display.locale = locale;
+                    }
                 });
+                if let Err(e) = ConfigService::apply_settings(&config_service.get_config()) {
+                    log::warn!("{}", e);
+                }
                 let _ = self.config_service.save();
             }
         }
@@ -168,6 +389,26 @@ impl SettingsScreen {
         theme_state.selected().and_then(|i| themes.get(i).cloned())
     }
 
+    fn is_discord_enabled_selected(&self) -> bool {
+        self.discord_state.read().unwrap().selected() == Some(DISCORD_ENABLED_INDEX)
+    }
+
+    fn is_streamer_enabled_selected(&self) -> bool {
+        self.streamer_state.read().unwrap().selected() == Some(STREAMER_ENABLED_INDEX)
+    }
+
+    fn get_selected_version_check(&self) -> Option<VersionCheckConfig> {
+        let selected = self.update_check_state.read().unwrap().selected()?;
+        UPDATE_CHECK_OPTIONS
+            .get(selected)
+            .map(|&(_, frequency, channel)| VersionCheckConfig { frequency, channel })
+    }
+
+    fn get_selected_locale(&self) -> Option<LocalePreset> {
+        let selected = self.locale_state.read().unwrap().selected()?;
+        LocalePreset::all().get(selected).copied()
+    }
+
     fn render_color_mode_section(&self, f: &mut Frame, area: Rect, colors: &Colors) {
         let color_modes = self.color_modes.read().unwrap();
         let items: Vec<ListItem> = color_modes
@@ -216,12 +457,128 @@ impl SettingsScreen {
         f.render_stateful_widget(list, area, &mut *theme_state);
     }
 
+    fn render_discord_section(&self, f: &mut Frame, area: Rect, colors: &Colors) {
+        let items: Vec<ListItem> = DISCORD_OPTIONS.iter().map(|&o| ListItem::new(o)).collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title("Discord Rich Presence")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(colors.border()))
+                    .padding(Padding::horizontal(2)),
+            )
+            .highlight_style(Style::default().bg(colors.text()).fg(colors.background()));
+
+        let mut discord_state = self.discord_state.write().unwrap();
+        f.render_stateful_widget(list, area, &mut *discord_state);
+    }
+
+    fn render_streamer_section(&self, f: &mut Frame, area: Rect, colors: &Colors) {
+        let items: Vec<ListItem> = STREAMER_OPTIONS.iter().map(|&o| ListItem::new(o)).collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title("Streamer Mode")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(colors.border()))
+                    .padding(Padding::horizontal(2)),
+            )
+            .highlight_style(Style::default().bg(colors.text()).fg(colors.background()));
+
+        let mut streamer_state = self.streamer_state.write().unwrap();
+        f.render_stateful_widget(list, area, &mut *streamer_state);
+    }
+
+    fn render_updates_section(&self, f: &mut Frame, area: Rect, colors: &Colors) {
+        let items: Vec<ListItem> = UPDATE_CHECK_OPTIONS
+            .iter()
+            .map(|&(label, _, _)| ListItem::new(label))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title("Update Check")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(colors.border()))
+                    .padding(Padding::horizontal(2)),
+            )
+            .highlight_style(Style::default().bg(colors.text()).fg(colors.background()));
+
+        let mut update_check_state = self.update_check_state.write().unwrap();
+        f.render_stateful_widget(list, area, &mut *update_check_state);
+    }
+
+    fn render_formats_section(&self, f: &mut Frame, area: Rect, colors: &Colors) {
+        let items: Vec<ListItem> = LocalePreset::all()
+            .iter()
+            .map(|preset| ListItem::new(preset.label()))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title("Locale")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(colors.border()))
+                    .padding(Padding::horizontal(2)),
+            )
+            .highlight_style(Style::default().bg(colors.text()).fg(colors.background()));
+
+        let mut locale_state = self.locale_state.write().unwrap();
+        f.render_stateful_widget(list, area, &mut *locale_state);
+    }
+
     fn render_description(&self, f: &mut Frame, area: Rect, colors: &Colors) {
         let current_section = *self.current_section.read().unwrap();
         let content = match current_section {
             SettingsSection::ColorMode => {
                 vec![Line::from(current_section.description())]
             }
+            SettingsSection::Discord => vec![
+                Line::from(current_section.description()),
+                Line::from(""),
+                Line::from("Example: Typing rust in tokio — Stage 3/5, 72 WPM"),
+                Line::from(""),
+                Line::from("Requires the Discord desktop app to be running."),
+            ],
+            SettingsSection::Streamer => vec![
+                Line::from(current_section.description()),
+                Line::from(""),
+                Line::from("• Hides repository names, file paths, branches and commits"),
+                Line::from("• Shows the big live WPM panel while typing"),
+                Line::from(format!(
+                    "• Writes live WPM and accuracy to {} in ~/.gittype for an OBS text source",
+                    DEFAULT_STATS_FILE_NAME
+                )),
+                Line::from(""),
+                Line::from("Set streamer.stats_file in config.json to write elsewhere."),
+            ],
+            SettingsSection::Formats => {
+                let locale = self.get_selected_locale().unwrap_or_default().resolve();
+                let sample = chrono::NaiveDate::from_ymd_opt(2024, 12, 31)
+                    .and_then(|date| date.and_hms_opt(13, 5, 0))
+                    .unwrap_or_default();
+                vec![
+                    Line::from(current_section.description()),
+                    Line::from(""),
+                    Line::from(format!("Numbers: {}", locale.format_decimal(12345.6, 1))),
+                    Line::from(format!(
+                        "Dates:   {}",
+                        locale.format_datetime(sample, false)
+                    )),
+                    Line::from(""),
+                    Line::from("System follows LC_ALL, LC_TIME or LANG."),
+                ]
+            }
+            SettingsSection::Updates => vec![
+                Line::from(current_section.description()),
+                Line::from(""),
+                Line::from("Turn it off on networks that block GitHub."),
+                Line::from("Failed checks wait for the next interval too."),
+            ],
             SettingsSection::Theme => {
                 let mut lines = vec![Line::from(current_section.description())];
 
@@ -280,63 +637,140 @@ impl SettingsScreen {
         f.render_widget(paragraph, area);
     }
 
-    fn render_tabs(&self, f: &mut Frame, area: Rect, colors: &Colors) {
-        let sections = SettingsSection::all();
-        let titles: Vec<Line> = sections
+    fn render_search_bar(&self, f: &mut Frame, area: Rect, colors: &Colors) {
+        let query = self.search_query();
+        let line = if self.is_searching() || !query.is_empty() {
+            Line::from(vec![
+                Span::styled("/ ", Style::default().fg(colors.key_action())),
+                Span::styled(query, Style::default().fg(colors.text())),
+                Span::styled(
+                    if self.is_searching() { "_" } else { "" },
+                    Style::default().fg(colors.key_action()),
+                ),
+            ])
+        } else {
+            Line::from(Span::styled(
+                "Press / to search settings",
+                Style::default().fg(colors.text_secondary()),
+            ))
+        };
+
+        let search_bar = Paragraph::new(line).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border()))
+                .title("Settings")
+                .padding(Padding::horizontal(1)),
+        );
+        f.render_widget(search_bar, area);
+    }
+
+    fn render_sidebar(&self, f: &mut Frame, area: Rect, colors: &Colors) {
+        let sections = self.visible_sections();
+        let current_section = self.current_section();
+
+        let lines: Vec<Line> = SettingsCategory::all()
             .iter()
-            .map(|section| Line::from(section.title()))
+            .filter(|category| sections.iter().any(|s| s.category() == **category))
+            .flat_map(|category| {
+                let header = Line::from(Span::styled(
+                    category.title(),
+                    Style::default()
+                        .fg(colors.title())
+                        .add_modifier(Modifier::BOLD),
+                ));
+                let entries = sections
+                    .iter()
+                    .filter(|section| section.category() == *category)
+                    .map(|section| {
+                        let style = if *section == current_section {
+                            Style::default().bg(colors.text()).fg(colors.background())
+                        } else {
+                            Style::default().fg(colors.text())
+                        };
+                        Line::from(Span::styled(format!("  {}", section.title()), style))
+                    });
+                std::iter::once(header).chain(entries)
+            })
             .collect();
 
-        let current_section = *self.current_section.read().unwrap();
-        let selected_index = sections
-            .iter()
-            .position(|&s| s == current_section)
-            .unwrap_or(0);
+        let sidebar = Paragraph::new(lines).block(
+            Block::default()
+                .title("Categories")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border()))
+                .padding(Padding::horizontal(1)),
+        );
+        f.render_widget(sidebar, area);
+    }
 
-        let tabs = Tabs::new(titles)
+    fn render_content(&self, f: &mut Frame, area: Rect, colors: &Colors) {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(24), Constraint::Min(0)])
+            .split(area);
+        self.render_sidebar(f, chunks[0], colors);
+
+        if self.visible_sections().is_empty() {
+            let empty = Paragraph::new(Span::styled(
+                format!("No settings match \"{}\"", self.search_query()),
+                Style::default().fg(colors.text_secondary()),
+            ))
+            .alignment(Alignment::Center)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(colors.border()))
-                    .title("Settings"),
-            )
-            .highlight_style(Style::default().fg(colors.text()).bold())
-            .select(selected_index);
-
-        f.render_widget(tabs, area);
-    }
+                    .border_style(Style::default().fg(colors.border())),
+            );
+            f.render_widget(empty, chunks[1]);
+            return;
+        }
 
-    fn render_content(&self, f: &mut Frame, area: Rect, colors: &Colors) {
         let content_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-            .split(area);
+            .split(chunks[1]);
 
-        let current_section = *self.current_section.read().unwrap();
-        match current_section {
+        match self.current_section() {
             SettingsSection::ColorMode => {
-                self.render_color_mode_section(f, content_chunks[0], colors);
-                self.render_description(f, content_chunks[1], colors);
-            }
-            SettingsSection::Theme => {
-                self.render_theme_section(f, content_chunks[0], colors);
-                self.render_description(f, content_chunks[1], colors);
+                self.render_color_mode_section(f, content_chunks[0], colors)
             }
+            SettingsSection::Theme => self.render_theme_section(f, content_chunks[0], colors),
+            SettingsSection::Formats => self.render_formats_section(f, content_chunks[0], colors),
+            SettingsSection::Discord => self.render_discord_section(f, content_chunks[0], colors),
+            SettingsSection::Streamer => self.render_streamer_section(f, content_chunks[0], colors),
+            SettingsSection::Updates => self.render_updates_section(f, content_chunks[0], colors),
         }
+        self.render_description(f, content_chunks[1], colors);
     }
 
     fn render_footer(&self, f: &mut Frame, area: Rect, colors: &Colors) {
         // Instructions (matching help screen format)
-        let instructions = vec![
-            Span::styled("[←→/HL]", Style::default().fg(colors.info())),
-            Span::styled(" Switch tabs ", Style::default().fg(colors.text())),
-            Span::styled("[↑↓/JK]", Style::default().fg(colors.info())),
-            Span::styled(" Navigate ", Style::default().fg(colors.text())),
-            Span::styled("[SPACE]", Style::default().fg(colors.key_action())),
-            Span::styled(" Save ", Style::default().fg(colors.text())),
-            Span::styled("[ESC]", Style::default().fg(colors.error())),
-            Span::styled(" Cancel", Style::default().fg(colors.text())),
-        ];
+        let instructions = if self.is_searching() {
+            vec![
+                Span::styled("[ENTER]", Style::default().fg(colors.key_action())),
+                Span::styled(" Done ", Style::default().fg(colors.text())),
+                Span::styled("[ESC]", Style::default().fg(colors.error())),
+                Span::styled(" Clear search", Style::default().fg(colors.text())),
+            ]
+        } else {
+            vec![
+                Span::styled("[/]", Style::default().fg(colors.key_action())),
+                Span::styled(" Search ", Style::default().fg(colors.text())),
+                Span::styled("[←→/HL]", Style::default().fg(colors.info())),
+                Span::styled(" Section ", Style::default().fg(colors.text())),
+                Span::styled("[TAB]", Style::default().fg(colors.info())),
+                Span::styled(" Category ", Style::default().fg(colors.text())),
+                Span::styled("[↑↓/JK]", Style::default().fg(colors.info())),
+                Span::styled(" Navigate ", Style::default().fg(colors.text())),
+                Span::styled("[SPACE]", Style::default().fg(colors.key_action())),
+                Span::styled(" Save ", Style::default().fg(colors.text())),
+                Span::styled("[D]", Style::default().fg(colors.key_action())),
+                Span::styled(" Keyboard test ", Style::default().fg(colors.text())),
+                Span::styled("[ESC]", Style::default().fg(colors.error())),
+                Span::styled(" Cancel", Style::default().fg(colors.text())),
+            ]
+        };
         let instructions_para =
             Paragraph::new(Line::from(instructions)).alignment(Alignment::Center);
         f.render_widget(instructions_para, area);
@@ -372,6 +806,8 @@ impl Screen for SettingsScreen {
 
     fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
         let _data = data.downcast::<SettingsScreenData>()?;
+        self.search_query.write().unwrap().clear();
+        *self.is_searching.write().unwrap() = false;
 
         // Fetch data from theme_service since provider returns empty data
         let color_modes = vec![ColorMode::Dark, ColorMode::Light];
@@ -393,38 +829,79 @@ impl Screen for SettingsScreen {
             self.theme_state.write().unwrap().select(Some(pos));
         }
 
+        let discord_enabled = self.config_service.get_config().discord.enabled;
+        self.discord_state
+            .write()
+            .unwrap()
+            .select(Some(if discord_enabled {
+                DISCORD_ENABLED_INDEX
+            } else {
+                0
+            }));
+
+        let streamer_enabled = self.config_service.get_config().streamer.enabled;
+        self.streamer_state
+            .write()
+            .unwrap()
+            .select(Some(if streamer_enabled {
+                STREAMER_ENABLED_INDEX
+            } else {
+                0
+            }));
+
+        let version_check = self.config_service.get_config().version_check;
+        let update_check_index = UPDATE_CHECK_OPTIONS
+            .iter()
+            .position(|&(_, frequency, channel)| {
+                frequency == version_check.frequency
+                    && (frequency == VersionCheckFrequency::Off || channel == version_check.channel)
+            })
+            .unwrap_or(0);
+        self.update_check_state
+            .write()
+            .unwrap()
+            .select(Some(update_check_index));
+
+        let locale = self.config_service.get_config().display.locale;
+        let locale_index = LocalePreset::all()
+            .iter()
+            .position(|preset| *preset == locale)
+            .unwrap_or(0);
+        self.locale_state
+            .write()
+            .unwrap()
+            .select(Some(locale_index));
+
         Ok(())
     }
 
     fn handle_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        if self.is_searching()
+            && !(key_event.code == KeyCode::Char('c')
+                && key_event.modifiers.contains(KeyModifiers::CONTROL))
+        {
+            self.handle_search_key(key_event);
+            return Ok(());
+        }
+
         match key_event.code {
             KeyCode::Left | KeyCode::Char('h') => {
-                let sections = SettingsSection::all();
-                let current_section = *self.current_section.read().unwrap();
-                let current_index = sections
-                    .iter()
-                    .position(|&s| s == current_section)
-                    .unwrap_or(0);
-                let new_index = if current_index == 0 {
-                    sections.len() - 1
-                } else {
-                    current_index - 1
-                };
-                *self.current_section.write().unwrap() = sections[new_index];
+                self.move_section(false);
                 Ok(())
             }
             KeyCode::Right | KeyCode::Char('l') => {
-                let sections = SettingsSection::all();
-                let current_section = *self.current_section.read().unwrap();
-                let current_index = sections
-                    .iter()
-                    .position(|&s| s == current_section)
-                    .unwrap_or(0);
-                let new_index = (current_index + 1) % sections.len();
-                *self.current_section.write().unwrap() = sections[new_index];
+                self.move_section(true);
                 Ok(())
             }
-            KeyCode::Up | KeyCode::Char('k') => {
+            KeyCode::Tab => {
+                self.next_category();
+                Ok(())
+            }
+            KeyCode::Char('/') => {
+                *self.is_searching.write().unwrap() = true;
+                Ok(())
+            }
+            KeyCode::Up | KeyCode::Char('k') if !self.visible_sections().is_empty() => {
                 let current_section = *self.current_section.read().unwrap();
                 match current_section {
                     SettingsSection::ColorMode => {
@@ -445,10 +922,30 @@ impl Screen for SettingsScreen {
                             self.apply_current_selection();
                         }
                     }
+                    SettingsSection::Discord => {
+                        let mut discord_state = self.discord_state.write().unwrap();
+                        let selected = discord_state.selected().unwrap_or(0);
+                        discord_state.select(Some(selected.saturating_sub(1)));
+                    }
+                    SettingsSection::Streamer => {
+                        let mut streamer_state = self.streamer_state.write().unwrap();
+                        let selected = streamer_state.selected().unwrap_or(0);
+                        streamer_state.select(Some(selected.saturating_sub(1)));
+                    }
+                    SettingsSection::Formats => {
+                        let mut locale_state = self.locale_state.write().unwrap();
+                        let selected = locale_state.selected().unwrap_or(0);
+                        locale_state.select(Some(selected.saturating_sub(1)));
+                    }
+                    SettingsSection::Updates => {
+                        let mut update_check_state = self.update_check_state.write().unwrap();
+                        let selected = update_check_state.selected().unwrap_or(0);
+                        update_check_state.select(Some(selected.saturating_sub(1)));
+                    }
                 }
                 Ok(())
             }
-            KeyCode::Down | KeyCode::Char('j') => {
+            KeyCode::Down | KeyCode::Char('j') if !self.visible_sections().is_empty() => {
                 let current_section = *self.current_section.read().unwrap();
                 match current_section {
                     SettingsSection::ColorMode => {
@@ -471,6 +968,28 @@ impl Screen for SettingsScreen {
                             self.apply_current_selection();
                         }
                     }
+                    SettingsSection::Discord => {
+                        let mut discord_state = self.discord_state.write().unwrap();
+                        let selected = discord_state.selected().unwrap_or(0);
+                        discord_state.select(Some((selected + 1).min(DISCORD_OPTIONS.len() - 1)));
+                    }
+                    SettingsSection::Streamer => {
+                        let mut streamer_state = self.streamer_state.write().unwrap();
+                        let selected = streamer_state.selected().unwrap_or(0);
+                        streamer_state.select(Some((selected + 1).min(STREAMER_OPTIONS.len() - 1)));
+                    }
+                    SettingsSection::Formats => {
+                        let mut locale_state = self.locale_state.write().unwrap();
+                        let selected = locale_state.selected().unwrap_or(0);
+                        locale_state
+                            .select(Some((selected + 1).min(LocalePreset::all().len() - 1)));
+                    }
+                    SettingsSection::Updates => {
+                        let mut update_check_state = self.update_check_state.write().unwrap();
+                        let selected = update_check_state.selected().unwrap_or(0);
+                        update_check_state
+                            .select(Some((selected + 1).min(UPDATE_CHECK_OPTIONS.len() - 1)));
+                    }
                 }
                 Ok(())
             }
@@ -479,6 +998,17 @@ impl Screen for SettingsScreen {
                 self.event_bus.as_event_bus().publish(NavigateTo::Pop);
                 Ok(())
             }
+            KeyCode::Char('d') => {
+                self.revert_to_original();
+                self.event_bus
+                    .as_event_bus()
+                    .publish(NavigateTo::Push(ScreenType::KeyboardTest));
+                Ok(())
+            }
+            KeyCode::Esc if !self.search_query().is_empty() => {
+                self.update_search(String::clear);
+                Ok(())
+            }
             KeyCode::Esc => {
                 self.revert_to_original();
                 self.event_bus.as_event_bus().publish(NavigateTo::Pop);
@@ -505,7 +1035,7 @@ impl Screen for SettingsScreen {
             ])
             .split(area);
 
-        self.render_tabs(f, chunks[0], &colors);
+        self.render_search_bar(f, chunks[0], &colors);
         self.render_content(f, chunks[1], &colors);
         self.render_footer(f, chunks[2], &colors);
 
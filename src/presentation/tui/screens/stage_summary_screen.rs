@@ -1,22 +1,33 @@
-use crate::domain::events::presentation_events::NavigateTo;
+use crate::domain::events::presentation_events::{NavigateTo, OpenInEditor};
 use crate::domain::events::EventBusInterface;
+use crate::domain::models::{ChallengePreview, DemoMode, MistakeDiff, SourceStudy};
+use crate::domain::repositories::SessionRepository;
+use crate::domain::services::challenge_flag_service::ChallengeFlagServiceInterface;
+use crate::domain::services::context_loader;
 use crate::domain::services::scoring::StageResult;
 use crate::domain::services::session_manager_service::SessionManagerInterface;
 use crate::domain::services::theme_service::ThemeServiceInterface;
 use crate::domain::services::SessionManager;
+use crate::infrastructure::storage::file_storage::FileStorage;
 use crate::presentation::tui::screens::ResultAction;
-use crate::presentation::tui::views::StageCompletionView;
+use crate::presentation::tui::views::{SourceStudyView, StageCompletionView};
 use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
 use crate::{GitTypeError, Result};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::Frame;
 use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+/// Lines shown above the challenge when the study view opens
+const STUDY_CONTEXT_LINES: usize = 5;
+const STUDY_PAGE_LINES: usize = 10;
 
 pub struct StageSummaryData {
     pub stage_result: StageResult,
     pub current_stage: usize,
     pub total_stages: usize,
     pub is_completed: bool,
+    pub mistake_diff: Option<MistakeDiff>,
 }
 
 pub struct StageSummaryDataProvider;
@@ -42,12 +53,32 @@ pub struct StageSummaryScreen {
     total_stages: RwLock<usize>,
     #[shaku(default)]
     is_completed: RwLock<bool>,
+    #[shaku(default)]
+    mistake_diff: RwLock<Option<MistakeDiff>>,
+    #[shaku(default)]
+    flagged: RwLock<bool>,
+    #[shaku(default)]
+    next_challenge: RwLock<Option<ChallengePreview>>,
+    #[shaku(default)]
+    can_reroll: RwLock<bool>,
+    #[shaku(default)]
+    is_studying: RwLock<bool>,
+    #[shaku(default)]
+    study: RwLock<Option<SourceStudy>>,
+    #[shaku(default)]
+    study_offset: RwLock<usize>,
+    #[shaku(default)]
+    source_missing: RwLock<bool>,
+    #[shaku(default)]
+    shown_at: RwLock<Option<Instant>>,
     #[shaku(inject)]
     event_bus: Arc<dyn EventBusInterface>,
     #[shaku(inject)]
     theme_service: Arc<dyn ThemeServiceInterface>,
     #[shaku(inject)]
     session_manager: Arc<dyn SessionManagerInterface>,
+    #[shaku(inject)]
+    challenge_flag_service: Arc<dyn ChallengeFlagServiceInterface>,
 }
 
 impl StageSummaryScreen {
@@ -55,6 +86,7 @@ impl StageSummaryScreen {
         event_bus: Arc<dyn EventBusInterface>,
         theme_service: Arc<dyn ThemeServiceInterface>,
         session_manager: Arc<dyn SessionManagerInterface>,
+        challenge_flag_service: Arc<dyn ChallengeFlagServiceInterface>,
     ) -> Self {
         Self {
             stage_result: RwLock::new(None),
@@ -62,9 +94,19 @@ impl StageSummaryScreen {
             session_current_stage: RwLock::new(1),
             total_stages: RwLock::new(3),
             is_completed: RwLock::new(false),
+            mistake_diff: RwLock::new(None),
+            flagged: RwLock::new(false),
+            next_challenge: RwLock::new(None),
+            can_reroll: RwLock::new(false),
+            is_studying: RwLock::new(false),
+            study: RwLock::new(None),
+            study_offset: RwLock::new(0),
+            source_missing: RwLock::new(false),
+            shown_at: RwLock::new(None),
             event_bus,
             theme_service,
             session_manager,
+            challenge_flag_service,
         }
     }
 
@@ -80,6 +122,170 @@ impl StageSummaryScreen {
     pub fn get_action_result(&self) -> Option<ResultAction> {
         self.action_result.read().unwrap().clone()
     }
+
+    pub fn get_mistake_diff(&self) -> Option<MistakeDiff> {
+        self.mistake_diff.read().unwrap().clone()
+    }
+
+    pub fn is_flagged(&self) -> bool {
+        *self.flagged.read().unwrap()
+    }
+
+    pub fn get_next_challenge_preview(&self) -> Option<ChallengePreview> {
+        self.next_challenge.read().unwrap().clone()
+    }
+
+    pub fn is_studying(&self) -> bool {
+        *self.is_studying.read().unwrap()
+    }
+
+    pub fn get_study(&self) -> Option<SourceStudy> {
+        self.study.read().unwrap().clone()
+    }
+
+    pub fn get_study_offset(&self) -> usize {
+        *self.study_offset.read().unwrap()
+    }
+
+    /// Whether the last attempt to open the editor found no source file on this machine
+    pub fn is_source_missing(&self) -> bool {
+        *self.source_missing.read().unwrap()
+    }
+
+    /// On to the next stage, or to the result animation after the last one
+    fn continue_session(&self) {
+        let is_session_completed = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+            .and_then(|sm| sm.is_session_completed().ok())
+            .unwrap_or(true);
+
+        if !is_session_completed {
+            self.event_bus
+                .as_event_bus()
+                .publish(NavigateTo::Replace(ScreenType::Typing));
+        } else {
+            self.event_bus
+                .as_event_bus()
+                .publish(NavigateTo::Replace(ScreenType::Animation));
+        }
+    }
+
+    /// Open the source file of the stage just typed, scrolled to the challenge
+    fn open_study(&self) {
+        let study = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+            .and_then(|sm| {
+                let challenge = sm.get_last_stage_challenge()?;
+                let git_root = sm.get_git_repository().and_then(|repo| repo.root_path);
+                context_loader::load_source_study(&challenge, git_root.as_deref())
+                    .map_err(|e| log::warn!("Failed to load the source of {}: {}", challenge.id, e))
+                    .ok()
+                    .flatten()
+            });
+
+        *self.study_offset.write().unwrap() = study
+            .as_ref()
+            .map_or(0, |study| study.initial_offset(STUDY_CONTEXT_LINES));
+        *self.study.write().unwrap() = study;
+        *self.is_studying.write().unwrap() = true;
+    }
+
+    fn handle_study_key_event(&self, key_event: KeyEvent) {
+        let max_offset = self
+            .study
+            .read()
+            .unwrap()
+            .as_ref()
+            .map_or(0, SourceStudy::max_offset);
+        let mut offset = self.study_offset.write().unwrap();
+        match key_event.code {
+            KeyCode::Esc | KeyCode::Char('s' | 'S') => *self.is_studying.write().unwrap() = false,
+            KeyCode::Up | KeyCode::Char('k') => *offset = offset.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => *offset = (*offset + 1).min(max_offset),
+            KeyCode::PageUp => *offset = offset.saturating_sub(STUDY_PAGE_LINES),
+            KeyCode::PageDown => *offset = (*offset + STUDY_PAGE_LINES).min(max_offset),
+            KeyCode::Home | KeyCode::Char('g') => *offset = 0,
+            KeyCode::End | KeyCode::Char('G') => *offset = max_offset,
+            _ => {}
+        }
+    }
+
+    /// Draw the next stage's challenge now so its preview can be shown
+    fn load_next_challenge(&self, reroll: bool) {
+        let Some(sm) = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+        else {
+            return;
+        };
+        if sm.is_session_completed().unwrap_or(true) {
+            *self.next_challenge.write().unwrap() = None;
+            return;
+        }
+
+        let challenge = if reroll {
+            sm.reroll_next_challenge()
+        } else {
+            sm.preview_next_challenge()
+        };
+        match challenge {
+            Ok(challenge) => {
+                let calibrations = SessionRepository::get_language_calibrations_global()
+                    .ok()
+                    .flatten()
+                    .unwrap_or_default();
+                *self.next_challenge.write().unwrap() = challenge.as_ref().map(|challenge| {
+                    ChallengePreview::new(challenge).with_calibrations(challenge, &calibrations)
+                });
+            }
+            Err(e) => log::warn!("Failed to preview the next challenge: {}", e),
+        }
+        *self.can_reroll.write().unwrap() = sm.can_reroll_next_challenge();
+    }
+
+    fn open_in_editor(&self) {
+        let location = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+            .and_then(|sm| {
+                let challenge = sm.get_last_stage_challenge()?;
+                let git_root = sm.get_git_repository().and_then(|repo| repo.root_path);
+                context_loader::locate_source_file(
+                    &FileStorage::new(),
+                    &challenge,
+                    git_root.as_deref(),
+                )
+            });
+
+        *self.source_missing.write().unwrap() = location.is_none();
+        if let Some((path, line)) = location {
+            self.event_bus
+                .as_event_bus()
+                .publish(OpenInEditor { path, line });
+        }
+    }
+
+    fn flag_challenge(&self) {
+        let challenge = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+            .and_then(|sm| sm.get_last_stage_challenge());
+        let Some(challenge) = challenge else {
+            return;
+        };
+
+        match self.challenge_flag_service.flag(&challenge) {
+            Ok(_) => *self.flagged.write().unwrap() = true,
+            Err(e) => log::warn!("Failed to flag challenge {}: {}", challenge.id, e),
+        }
+    }
 }
 
 pub struct StageSummaryScreenProvider;
@@ -94,10 +300,12 @@ impl shaku::Provider<crate::presentation::di::AppModule> for StageSummaryScreenP
         let event_bus: Arc<dyn EventBusInterface> = module.resolve();
         let theme_service: Arc<dyn ThemeServiceInterface> = module.resolve();
         let session_manager: Arc<dyn SessionManagerInterface> = module.resolve();
+        let challenge_flag_service: Arc<dyn ChallengeFlagServiceInterface> = module.resolve();
         Ok(Box::new(StageSummaryScreen::new(
             event_bus,
             theme_service,
             session_manager,
+            challenge_flag_service,
         )))
     }
 }
@@ -116,14 +324,20 @@ impl Screen for StageSummaryScreen {
 
     fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
         *self.action_result.write().unwrap() = None;
+        *self.flagged.write().unwrap() = false;
+        *self.is_studying.write().unwrap() = false;
+        *self.study.write().unwrap() = None;
+        *self.source_missing.write().unwrap() = false;
+        *self.shown_at.write().unwrap() = Some(Instant::now());
 
-        let (stage_result, current_stage, total_stages, is_completed) =
+        let (stage_result, current_stage, total_stages, is_completed, mistake_diff) =
             if let Ok(data) = data.downcast::<StageSummaryData>() {
                 (
                     Some(data.stage_result),
                     data.current_stage,
                     data.total_stages,
                     data.is_completed,
+                    data.mistake_diff,
                 )
             } else {
                 // If no data provided, get from injected dependencies
@@ -139,18 +353,41 @@ impl Screen for StageSummaryScreen {
                 let (current_stage, total_stages) = sm.get_stage_info().unwrap_or((1, 3));
                 let is_completed = sm.is_session_completed().unwrap_or(false);
 
-                (stage_result, current_stage, total_stages, is_completed)
+                (
+                    stage_result,
+                    current_stage,
+                    total_stages,
+                    is_completed,
+                    sm.get_last_stage_mistake_diff(),
+                )
             };
 
         *self.stage_result.write().unwrap() = stage_result;
         *self.session_current_stage.write().unwrap() = current_stage;
         *self.total_stages.write().unwrap() = total_stages;
         *self.is_completed.write().unwrap() = is_completed;
+        *self.mistake_diff.write().unwrap() = mistake_diff;
+        self.load_next_challenge(false);
 
         Ok(())
     }
 
     fn handle_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        if DemoMode::is_playing() {
+            DemoMode::set_playing(false);
+            self.event_bus
+                .as_event_bus()
+                .publish(NavigateTo::PopTo(ScreenType::Title));
+            return Ok(());
+        }
+
+        let is_ctrl_c = key_event.code == KeyCode::Char('c')
+            && key_event.modifiers.contains(KeyModifiers::CONTROL);
+        if self.is_studying() && !is_ctrl_c {
+            self.handle_study_key_event(key_event);
+            return Ok(());
+        }
+
         match key_event.code {
             KeyCode::Esc => {
                 *self.action_result.write().unwrap() = Some(ResultAction::BackToTitle);
@@ -166,23 +403,24 @@ impl Screen for StageSummaryScreen {
                     .publish(NavigateTo::Replace(ScreenType::SessionFailure));
                 Ok(())
             }
+            KeyCode::Char('f' | 'F') => {
+                self.flag_challenge();
+                Ok(())
+            }
+            KeyCode::Char('s' | 'S') => {
+                self.open_study();
+                Ok(())
+            }
+            KeyCode::Char('o' | 'O') => {
+                self.open_in_editor();
+                Ok(())
+            }
+            KeyCode::Char('r' | 'R') if *self.can_reroll.read().unwrap() => {
+                self.load_next_challenge(true);
+                Ok(())
+            }
             KeyCode::Char(' ') => {
-                let is_session_completed = self
-                    .session_manager
-                    .as_any()
-                    .downcast_ref::<SessionManager>()
-                    .and_then(|sm| sm.is_session_completed().ok())
-                    .unwrap_or(true);
-
-                if !is_session_completed {
-                    self.event_bus
-                        .as_event_bus()
-                        .publish(NavigateTo::Replace(ScreenType::Typing));
-                } else {
-                    self.event_bus
-                        .as_event_bus()
-                        .publish(NavigateTo::Replace(ScreenType::Animation));
-                }
+                self.continue_session();
                 Ok(())
             }
             _ => Ok(()),
@@ -191,6 +429,17 @@ impl Screen for StageSummaryScreen {
 
     fn render_ratatui(&self, frame: &mut Frame) -> Result<()> {
         let colors = self.theme_service.get_colors();
+        if self.is_studying() {
+            SourceStudyView::render(
+                frame,
+                frame.area(),
+                self.study.read().unwrap().as_ref(),
+                self.get_study_offset(),
+                &colors,
+            );
+            return Ok(());
+        }
+
         let stage_result = self.stage_result.read().unwrap();
         if let Some(ref stage_result) = *stage_result {
             let is_completed = *self.is_completed.read().unwrap();
@@ -216,6 +465,11 @@ impl Screen for StageSummaryScreen {
                 total_stages,
                 has_next,
                 stage_result.keystrokes,
+                self.mistake_diff.read().unwrap().as_ref(),
+                *self.flagged.read().unwrap(),
+                self.next_challenge.read().unwrap().as_ref(),
+                *self.can_reroll.read().unwrap(),
+                self.is_source_missing(),
                 &colors,
             );
         }
@@ -224,10 +478,23 @@ impl Screen for StageSummaryScreen {
     }
 
     fn get_update_strategy(&self) -> UpdateStrategy {
-        UpdateStrategy::InputOnly
+        if DemoMode::is_playing() {
+            UpdateStrategy::TimeBased(Duration::from_millis(250))
+        } else {
+            UpdateStrategy::InputOnly
+        }
     }
 
     fn update(&self) -> Result<bool> {
+        let shown_long_enough = self
+            .shown_at
+            .read()
+            .unwrap()
+            .is_some_and(|at| at.elapsed() >= DemoMode::SUMMARY_DURATION);
+        if DemoMode::is_playing() && shown_long_enough {
+            *self.shown_at.write().unwrap() = None;
+            self.continue_session();
+        }
         Ok(false)
     }
 
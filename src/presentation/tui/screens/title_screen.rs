@@ -1,21 +1,32 @@
 use crate::domain::events::presentation_events::NavigateTo;
 use crate::domain::events::EventBusInterface;
-use crate::domain::models::{DifficultyLevel, GitRepository};
+use crate::domain::models::version::ReleaseNotes;
+use crate::domain::models::{
+    DemoMode, DifficultyLevel, DifficultyLock, DrillGenerator, GitRepository,
+};
+use crate::domain::repositories::SessionRepository;
+use crate::domain::services::difficulty_gate_service::DifficultyGateServiceInterface;
 use crate::domain::services::session_manager_service::SessionManagerInterface;
 use crate::domain::services::stage_builder_service::StageRepositoryInterface;
 use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::domain::services::version_service::VersionServiceInterface;
 use crate::domain::services::{SessionManager, StageRepository};
 use crate::domain::stores::RepositoryStoreInterface;
-use crate::presentation::tui::views::title::{DifficultySelectionView, StaticElementsView};
+use crate::infrastructure::browser;
+use crate::presentation::tui::views::title::{
+    ChallengeSummaryView, DifficultySelectionView, GitRepositoryView, QuickPlayView,
+    StaticElementsView, UpdateBannerView,
+};
 use crate::presentation::tui::ScreenDataProvider;
 use crate::presentation::tui::{Screen, ScreenType, UpdateStrategy};
 use crate::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     Frame,
 };
 use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 
 const DIFFICULTIES: [(&str, DifficultyLevel); 5] = [
     ("Easy", DifficultyLevel::Easy),
@@ -27,7 +38,9 @@ const DIFFICULTIES: [(&str, DifficultyLevel); 5] = [
 
 pub struct TitleScreenData {
     pub challenge_counts: [usize; 5],
+    pub language_counts: Vec<(String, usize)>,
     pub git_repository: Option<GitRepository>,
+    pub update_notice: Option<ReleaseNotes>,
 }
 
 pub struct TitleScreenDataProvider;
@@ -41,9 +54,12 @@ impl ScreenDataProvider for TitleScreenDataProvider {
 #[derive(Clone, Debug)]
 pub enum TitleAction {
     Start(DifficultyLevel),
+    Drills,
     Records,
     Analytics,
     Settings,
+    Achievements,
+    Profile,
     Quit,
 }
 
@@ -57,6 +73,8 @@ pub struct TitleScreen {
     #[shaku(default)]
     challenge_counts: RwLock<[usize; 5]>,
     #[shaku(default)]
+    language_counts: RwLock<Vec<(String, usize)>>,
+    #[shaku(default)]
     git_repository: RwLock<Option<GitRepository>>,
     #[shaku(default)]
     action_result: RwLock<Option<TitleAction>>,
@@ -64,6 +82,15 @@ pub struct TitleScreen {
     needs_render: RwLock<bool>,
     #[shaku(default)]
     error_message: RwLock<Option<String>>,
+    #[shaku(default)]
+    quick_play: RwLock<bool>,
+    #[shaku(default)]
+    difficulty_locks: RwLock<Vec<DifficultyLock>>,
+    #[shaku(default)]
+    update_notice: RwLock<Option<ReleaseNotes>>,
+    /// Last input, for starting the demo once the screen sits idle
+    #[shaku(default)]
+    idle_since: RwLock<Option<Instant>>,
     #[shaku(inject)]
     event_bus: Arc<dyn EventBusInterface>,
     #[shaku(inject)]
@@ -74,6 +101,10 @@ pub struct TitleScreen {
     repository_store: Arc<dyn RepositoryStoreInterface>,
     #[shaku(inject)]
     session_manager: Arc<dyn SessionManagerInterface>,
+    #[shaku(inject)]
+    difficulty_gate_service: Arc<dyn DifficultyGateServiceInterface>,
+    #[shaku(inject)]
+    version_service: Arc<dyn VersionServiceInterface>,
 }
 
 impl TitleScreen {
@@ -83,19 +114,28 @@ impl TitleScreen {
         stage_repository: Arc<dyn StageRepositoryInterface>,
         repository_store: Arc<dyn RepositoryStoreInterface>,
         session_manager: Arc<dyn SessionManagerInterface>,
+        difficulty_gate_service: Arc<dyn DifficultyGateServiceInterface>,
+        version_service: Arc<dyn VersionServiceInterface>,
     ) -> Self {
         Self {
             selected_difficulty: RwLock::new(1),
             challenge_counts: RwLock::new([0, 0, 0, 0, 0]),
+            language_counts: RwLock::new(Vec::new()),
             git_repository: RwLock::new(None),
             action_result: RwLock::new(None),
             needs_render: RwLock::new(true),
             error_message: RwLock::new(None),
+            quick_play: RwLock::new(false),
+            difficulty_locks: RwLock::new(Vec::new()),
+            update_notice: RwLock::new(None),
+            idle_since: RwLock::new(None),
             event_bus,
             theme_service,
             stage_repository,
             repository_store,
             session_manager,
+            difficulty_gate_service,
+            version_service,
         }
     }
 
@@ -121,6 +161,10 @@ impl TitleScreen {
         *self.challenge_counts.write().unwrap() = counts;
     }
 
+    pub fn set_language_counts(&self, counts: Vec<(String, usize)>) {
+        *self.language_counts.write().unwrap() = counts;
+    }
+
     pub fn set_git_repository(&self, repo: Option<GitRepository>) {
         *self.git_repository.write().unwrap() = repo;
     }
@@ -128,6 +172,171 @@ impl TitleScreen {
     pub fn get_error_message(&self) -> Option<String> {
         self.error_message.read().unwrap().clone()
     }
+
+    /// Lock on the selected difficulty while `difficulty_gates` keeps it out of reach
+    pub fn get_selected_lock(&self) -> Option<DifficultyLock> {
+        let difficulty = self.get_selected_difficulty();
+        self.difficulty_locks
+            .read()
+            .unwrap()
+            .iter()
+            .find(|lock| lock.difficulty == difficulty)
+            .cloned()
+    }
+
+    /// Newer release announced by the banner, until it is dismissed
+    pub fn get_update_notice(&self) -> Option<ReleaseNotes> {
+        self.update_notice.read().unwrap().clone()
+    }
+
+    fn open_release_notes(&self) -> Result<()> {
+        if let Some(release) = self.get_update_notice() {
+            if let Err(e) = browser::open_url(&release.url) {
+                log::warn!("Failed to open release notes {}: {}", release.url, e);
+            }
+        }
+        Ok(())
+    }
+
+    fn dismiss_update_notice(&self) -> Result<()> {
+        if self.update_notice.write().unwrap().take().is_some() {
+            if let Err(e) = self.version_service.dismiss_update() {
+                log::warn!("Failed to save the dismissed update: {}", e);
+            }
+            *self.needs_render.write().unwrap() = true;
+        }
+        Ok(())
+    }
+
+    /// Whether the screen asks to play the current repository instead of showing the menu
+    pub fn is_quick_play(&self) -> bool {
+        *self.quick_play.read().unwrap()
+    }
+
+    fn start_selected_difficulty(&self) -> Result<()> {
+        // Check if challenges are available for the selected difficulty
+        let selected_difficulty = *self.selected_difficulty.read().unwrap();
+        if let Some(lock) = self.get_selected_lock() {
+            *self.error_message.write().unwrap() = Some(lock.message());
+            *self.needs_render.write().unwrap() = true;
+            return Ok(());
+        }
+        // An empty tier falls back to the nearest one with challenges when the
+        // session starts, so only a repository with none at all stops here
+        if self.challenge_counts.read().unwrap().iter().sum::<usize>() == 0 {
+            *self.error_message.write().unwrap() = Some(
+                "No challenges available in this repository. Please try a different repository."
+                    .to_string(),
+            );
+            *self.needs_render.write().unwrap() = true;
+            return Ok(());
+        }
+
+        *self.error_message.write().unwrap() = None;
+        *self.quick_play.write().unwrap() = false;
+        let difficulty = DIFFICULTIES[selected_difficulty].1;
+        *self.action_result.write().unwrap() = Some(TitleAction::Start(difficulty));
+
+        // Set difficulty in SessionManager before transitioning to Typing screen
+        if let Some(sm) = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+        {
+            sm.set_drills(None);
+            sm.set_difficulty(difficulty);
+        }
+
+        let event_bus = self.event_bus.as_event_bus();
+        log::info!(
+            "TitleScreen: EventBus subscribers address: {:p}",
+            event_bus.get_subscribers_ptr()
+        );
+        log::info!("TitleScreen: Publishing NavigateTo::Replace(ScreenType::Typing) event");
+        event_bus.publish(NavigateTo::Replace(ScreenType::Typing));
+        log::info!("TitleScreen: NavigateTo event published");
+        Ok(())
+    }
+
+    /// Play drills built from this repository's code around the keys mistyped
+    /// most so far, instead of its challenges
+    fn start_drills(&self) -> Result<()> {
+        let Some(sm) = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+        else {
+            return Ok(());
+        };
+        let key_load = SessionRepository::get_key_load_global()
+            .unwrap_or_else(|e| {
+                log::warn!("Failed to load key stats for drills: {}", e);
+                None
+            })
+            .unwrap_or_default();
+        let drills = self
+            .stage_repository
+            .as_any()
+            .downcast_ref::<StageRepository>()
+            .and_then(|repo| {
+                repo.with_challenges(|challenges| DrillGenerator::new(challenges, &key_load))
+            })
+            .filter(|generator| !generator.is_empty())
+            .map(|generator| generator.generate(sm.challenge_slots(), rand::random()));
+
+        let Some(drills) = drills else {
+            *self.error_message.write().unwrap() =
+                Some("No code in this repository to build drills from.".to_string());
+            *self.needs_render.write().unwrap() = true;
+            return Ok(());
+        };
+
+        *self.error_message.write().unwrap() = None;
+        *self.quick_play.write().unwrap() = false;
+        *self.action_result.write().unwrap() = Some(TitleAction::Drills);
+        sm.set_drills(Some(drills));
+        self.event_bus
+            .as_event_bus()
+            .publish(NavigateTo::Replace(ScreenType::Typing));
+        Ok(())
+    }
+
+    /// Let the demo typist play the selected difficulty; when it can't start,
+    /// the idle wait begins again
+    fn start_demo(&self) -> Result<()> {
+        *self.idle_since.write().unwrap() = Some(Instant::now());
+        DemoMode::set_playing(true);
+        self.start_selected_difficulty()?;
+        if self.get_action_result().is_none() {
+            DemoMode::set_playing(false);
+        }
+        Ok(())
+    }
+
+    fn handle_quick_play_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Char('u') | KeyCode::Char('U') => self.open_release_notes(),
+            KeyCode::Char('x') | KeyCode::Char('X') => self.dismiss_update_notice(),
+            KeyCode::Enter | KeyCode::Char(' ') => self.start_selected_difficulty(),
+            KeyCode::Char('m') | KeyCode::Char('M') => {
+                *self.quick_play.write().unwrap() = false;
+                *self.error_message.write().unwrap() = None;
+                *self.needs_render.write().unwrap() = true;
+                Ok(())
+            }
+            KeyCode::Esc => {
+                *self.action_result.write().unwrap() = Some(TitleAction::Quit);
+                self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+                Ok(())
+            }
+            KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                *self.action_result.write().unwrap() = Some(TitleAction::Quit);
+                self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 impl Screen for TitleScreen {
@@ -145,68 +354,67 @@ impl Screen for TitleScreen {
     fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
         *self.action_result.write().unwrap() = None;
         *self.needs_render.write().unwrap() = true;
+        *self.idle_since.write().unwrap() = Some(Instant::now());
 
-        let (challenge_counts, git_repository) =
+        let (challenge_counts, language_counts, git_repository, update_notice) =
             if let Ok(screen_data) = data.downcast::<TitleScreenData>() {
-                (screen_data.challenge_counts, screen_data.git_repository)
+                (
+                    screen_data.challenge_counts,
+                    screen_data.language_counts,
+                    screen_data.git_repository,
+                    screen_data.update_notice,
+                )
             } else {
                 // If no data provided, get from injected dependencies
-                let challenge_counts = self
+                let stage_repository = self
                     .stage_repository
                     .as_any()
-                    .downcast_ref::<StageRepository>()
+                    .downcast_ref::<StageRepository>();
+                let challenge_counts = stage_repository
                     .map(|repo| repo.count_challenges_by_difficulty())
                     .unwrap_or([0; 5]);
+                let language_counts = stage_repository
+                    .map(|repo| repo.count_challenges_by_language())
+                    .unwrap_or_default();
 
                 let git_repository = self.repository_store.get_repository();
 
-                (challenge_counts, git_repository)
+                (
+                    challenge_counts,
+                    language_counts,
+                    git_repository,
+                    self.version_service.pending_update(),
+                )
             };
 
+        if self.repository_store.take_quick_play() && git_repository.is_some() {
+            *self.quick_play.write().unwrap() = true;
+        }
         *self.challenge_counts.write().unwrap() = challenge_counts;
+        *self.language_counts.write().unwrap() = language_counts;
         *self.git_repository.write().unwrap() = git_repository;
+        *self.update_notice.write().unwrap() = update_notice;
+        *self.difficulty_locks.write().unwrap() = self
+            .difficulty_gate_service
+            .locked_difficulties()
+            .unwrap_or_else(|e| {
+                log::warn!("Failed to load difficulty locks: {}", e);
+                Vec::new()
+            });
 
         Ok(())
     }
 
     fn handle_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        *self.idle_since.write().unwrap() = Some(Instant::now());
+        DemoMode::set_playing(false);
+        if self.is_quick_play() {
+            return self.handle_quick_play_key_event(key_event);
+        }
+
         match key_event.code {
-            KeyCode::Char(' ') => {
-                // Check if challenges are available for the selected difficulty
-                let selected_difficulty = *self.selected_difficulty.read().unwrap();
-                if self.challenge_counts.read().unwrap()[selected_difficulty] == 0 {
-                    *self.error_message.write().unwrap() = Some(
-                        "No challenges available for this difficulty. Please try a different difficulty or repository.".to_string()
-                    );
-                    *self.needs_render.write().unwrap() = true;
-                    Ok(())
-                } else {
-                    *self.error_message.write().unwrap() = None;
-                    let difficulty = DIFFICULTIES[selected_difficulty].1;
-                    *self.action_result.write().unwrap() = Some(TitleAction::Start(difficulty));
-
-                    // Set difficulty in SessionManager before transitioning to Typing screen
-                    if let Some(sm) = self
-                        .session_manager
-                        .as_any()
-                        .downcast_ref::<SessionManager>()
-                    {
-                        sm.set_difficulty(difficulty);
-                    }
-
-                    let event_bus = self.event_bus.as_event_bus();
-                    log::info!(
-                        "TitleScreen: EventBus subscribers address: {:p}",
-                        event_bus.get_subscribers_ptr()
-                    );
-                    log::info!(
-                        "TitleScreen: Publishing NavigateTo::Replace(ScreenType::Typing) event"
-                    );
-                    event_bus.publish(NavigateTo::Replace(ScreenType::Typing));
-                    log::info!("TitleScreen: NavigateTo event published");
-                    Ok(())
-                }
-            }
+            KeyCode::Char(' ') => self.start_selected_difficulty(),
+            KeyCode::Char('d') | KeyCode::Char('D') => self.start_drills(),
             KeyCode::Left | KeyCode::Char('h') => {
                 let current = *self.selected_difficulty.read().unwrap();
                 *self.selected_difficulty.write().unwrap() = if current == 0 {
@@ -262,6 +470,22 @@ impl Screen for TitleScreen {
                     .publish(NavigateTo::Push(ScreenType::Settings));
                 Ok(())
             }
+            KeyCode::Char('e') | KeyCode::Char('E') => {
+                *self.action_result.write().unwrap() = Some(TitleAction::Achievements);
+                self.event_bus
+                    .as_event_bus()
+                    .publish(NavigateTo::Push(ScreenType::Achievements));
+                Ok(())
+            }
+            KeyCode::Char('u') | KeyCode::Char('U') => self.open_release_notes(),
+            KeyCode::Char('x') | KeyCode::Char('X') => self.dismiss_update_notice(),
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                *self.action_result.write().unwrap() = Some(TitleAction::Profile);
+                self.event_bus
+                    .as_event_bus()
+                    .publish(NavigateTo::Push(ScreenType::Profile));
+                Ok(())
+            }
             _ => Ok(()),
         }
     }
@@ -275,6 +499,7 @@ impl Screen for TitleScreen {
         let subtitle_height = 1;
         let instructions_height = 3;
         let difficulty_height = 4;
+        let summary_height = ChallengeSummaryView::HEIGHT as usize;
         let spacing = 1;
         let git_info_height = 1;
 
@@ -284,6 +509,8 @@ impl Screen for TitleScreen {
             + spacing
             + difficulty_height
             + spacing
+            + summary_height
+            + spacing
             + instructions_height
             + spacing
             + git_info_height;
@@ -300,18 +527,60 @@ impl Screen for TitleScreen {
                 Constraint::Length(spacing as u16),     // Spacing
                 Constraint::Length(difficulty_height as u16), // Difficulty selection
                 Constraint::Length(spacing as u16),     // Spacing
+                Constraint::Length(summary_height as u16), // Challenge summary
+                Constraint::Length(spacing as u16),     // Spacing
                 Constraint::Length(instructions_height as u16), // Instructions
                 Constraint::Min(0),                     // Bottom (includes git info)
             ])
             .split(area);
 
+        StaticElementsView::render_read_only_banner(frame, &colors);
+        if let Some(release) = self.update_notice.read().unwrap().as_ref() {
+            // Row 0 is kept for the read-only banner
+            UpdateBannerView::render(
+                frame,
+                Rect {
+                    y: chunks[0].y + 1,
+                    height: chunks[0].height.saturating_sub(1),
+                    ..chunks[0]
+                },
+                release,
+                &colors,
+            );
+        }
+        ChallengeSummaryView::render(
+            frame,
+            chunks[7],
+            &DIFFICULTIES,
+            &self.challenge_counts.read().unwrap(),
+            &self.language_counts.read().unwrap(),
+            &colors,
+        );
+
+        let git_repository = self.git_repository.read().unwrap();
+        if let Some(repository) = git_repository.as_ref().filter(|_| self.is_quick_play()) {
+            let selected_difficulty = *self.selected_difficulty.read().unwrap();
+            StaticElementsView::render_heading(frame, chunks[1], chunks[3], &colors);
+            QuickPlayView::render(
+                frame,
+                chunks[5],
+                repository,
+                DIFFICULTIES[selected_difficulty].0,
+                self.challenge_counts.read().unwrap()[selected_difficulty],
+                self.error_message.read().unwrap().as_ref(),
+                &colors,
+            );
+            GitRepositoryView::render(frame, Some(repository), &colors);
+            return Ok(());
+        }
+
         // Render static elements (logo, subtitle, instructions, git info)
         StaticElementsView::render(
             frame,
             chunks[1], // logo
             chunks[3], // subtitle
-            chunks[7], // instructions
-            self.git_repository.read().unwrap().as_ref(),
+            chunks[9], // instructions
+            git_repository.as_ref(),
             &colors,
         );
 
@@ -322,6 +591,7 @@ impl Screen for TitleScreen {
             &DIFFICULTIES,
             *self.selected_difficulty.read().unwrap(),
             &self.challenge_counts.read().unwrap(),
+            self.get_selected_lock().as_ref(),
             self.error_message.read().unwrap().as_ref(),
             &colors,
         );
@@ -330,10 +600,26 @@ impl Screen for TitleScreen {
     }
 
     fn get_update_strategy(&self) -> UpdateStrategy {
-        UpdateStrategy::InputOnly
+        if DemoMode::is_enabled() {
+            UpdateStrategy::Hybrid {
+                interval: Duration::from_millis(500),
+                input_priority: true,
+            }
+        } else {
+            UpdateStrategy::InputOnly
+        }
     }
 
     fn update(&self) -> Result<bool> {
+        let idle = self
+            .idle_since
+            .read()
+            .unwrap()
+            .is_some_and(|since| since.elapsed() >= DemoMode::idle_timeout());
+        if DemoMode::is_enabled() && idle {
+            self.start_demo()?;
+        }
+
         let should_render = *self.needs_render.read().unwrap();
         if should_render {
             *self.needs_render.write().unwrap() = false;
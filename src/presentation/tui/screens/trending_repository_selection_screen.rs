@@ -1,9 +1,12 @@
-use crate::domain::events::presentation_events::NavigateTo;
+use crate::domain::events::presentation_events::{NavigateTo, TrendingRepositoriesLoaded};
 use crate::domain::events::EventBusInterface;
 use crate::domain::repositories::trending_repository::{
     TrendingRepositoryInfo, TrendingRepositoryInterface,
 };
+use crate::domain::services::dismissed_repository_service::DismissedRepositoryServiceInterface;
+use crate::domain::services::repository_rating_service::RepositoryRatingServiceInterface;
 use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::presentation::tui::views::loading::loading_progress_view::SPINNER_CHARS;
 use crate::presentation::tui::views::trending_repository_selection::{
     ControlsView, HeaderView, RepositoryListView,
 };
@@ -15,7 +18,10 @@ use ratatui::{
     widgets::ListState,
     Frame,
 };
-use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Duration;
 
 pub trait TrendingRepositorySelectionScreenInterface: Screen {}
 
@@ -28,12 +34,28 @@ pub struct TrendingRepositorySelectionScreen {
     list_state: RwLock<ListState>,
     #[shaku(default)]
     selected_index: RwLock<Option<usize>>,
+    /// Cache key of the list being fetched in the background
+    #[shaku(default)]
+    loading: RwLock<Option<String>>,
+    #[shaku(default)]
+    load_error: RwLock<Option<String>>,
+    /// Fetch results received from the EventBus, applied on the next `update`
+    #[shaku(default)]
+    loaded: Arc<Mutex<Vec<TrendingRepositoriesLoaded>>>,
+    #[shaku(default)]
+    subscribed: AtomicBool,
+    #[shaku(default)]
+    spinner_index: AtomicUsize,
     #[shaku(inject)]
     event_bus: Arc<dyn EventBusInterface>,
     #[shaku(inject)]
     theme_service: Arc<dyn ThemeServiceInterface>,
     #[shaku(inject)]
     trending_repository: Arc<dyn TrendingRepositoryInterface>,
+    #[shaku(inject)]
+    repository_rating_service: Arc<dyn RepositoryRatingServiceInterface>,
+    #[shaku(inject)]
+    dismissed_repository_service: Arc<dyn DismissedRepositoryServiceInterface>,
 }
 
 impl TrendingRepositorySelectionScreen {
@@ -41,6 +63,8 @@ impl TrendingRepositorySelectionScreen {
         event_bus: Arc<dyn EventBusInterface>,
         theme_service: Arc<dyn ThemeServiceInterface>,
         trending_repository: Arc<dyn TrendingRepositoryInterface>,
+        repository_rating_service: Arc<dyn RepositoryRatingServiceInterface>,
+        dismissed_repository_service: Arc<dyn DismissedRepositoryServiceInterface>,
     ) -> Self {
         let mut list_state = ListState::default();
         list_state.select(Some(0));
@@ -49,9 +73,16 @@ impl TrendingRepositorySelectionScreen {
             repositories: RwLock::new(Vec::new()),
             list_state: RwLock::new(list_state),
             selected_index: RwLock::new(None),
+            loading: RwLock::new(None),
+            load_error: RwLock::new(None),
+            loaded: Arc::new(Mutex::new(Vec::new())),
+            subscribed: AtomicBool::new(false),
+            spinner_index: AtomicUsize::new(0),
             event_bus,
             theme_service,
             trending_repository,
+            repository_rating_service,
+            dismissed_repository_service,
         }
     }
 
@@ -63,6 +94,86 @@ impl TrendingRepositorySelectionScreen {
         self.repositories.read().unwrap().clone()
     }
 
+    pub fn is_loading(&self) -> bool {
+        self.loading.read().unwrap().is_some()
+    }
+
+    /// Stash fetch results published by the background thread; registered once
+    /// since every `init_with_data` reuses the same screen
+    fn subscribe_to_loaded(&self) {
+        if self.subscribed.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let loaded = Arc::clone(&self.loaded);
+        self.event_bus
+            .as_event_bus()
+            .subscribe(move |event: &TrendingRepositoriesLoaded| {
+                loaded.lock().unwrap().push(event.clone());
+            });
+    }
+
+    /// Fetch the trending list off the UI thread so the screen shows up right away
+    fn fetch_in_background(&self, cache_key: String, language: Option<String>, period: String) {
+        let trending_repository = Arc::clone(&self.trending_repository);
+        let event_bus = Arc::clone(&self.event_bus);
+
+        thread::spawn(move || {
+            let repositories = trending_repository
+                .get_trending_repositories_sync(&cache_key, language.as_deref(), &period)
+                .map_err(|e| e.to_string());
+            event_bus
+                .as_event_bus()
+                .publish(TrendingRepositoriesLoaded {
+                    cache_key,
+                    repositories,
+                });
+        });
+    }
+
+    /// Apply a fetch result for the list still being waited on; stale results from
+    /// an earlier language or period are dropped
+    fn apply_loaded(&self, loaded: TrendingRepositoriesLoaded) -> bool {
+        let mut loading = self.loading.write().unwrap();
+        if loading.as_deref() != Some(loaded.cache_key.as_str()) {
+            return false;
+        }
+        *loading = None;
+
+        match loaded.repositories {
+            Ok(repositories) => {
+                *self.repositories.write().unwrap() =
+                    self.repository_rating_service.sort_by_preference(
+                        self.dismissed_repository_service
+                            .exclude_dismissed(repositories),
+                    );
+            }
+            Err(message) => {
+                log::warn!("Failed to fetch trending repositories: {}", message);
+                *self.load_error.write().unwrap() = Some(message);
+            }
+        }
+        true
+    }
+
+    /// Hide the selected repository from this and every later trending list
+    fn dismiss_selected(&self) -> Result<()> {
+        let mut list_state = self.list_state.write().unwrap();
+        let mut repositories = self.repositories.write().unwrap();
+        let Some(selected) = list_state
+            .selected()
+            .filter(|selected| *selected < repositories.len())
+        else {
+            return Ok(());
+        };
+
+        self.dismissed_repository_service
+            .dismiss(&repositories[selected].repo_name)?;
+        repositories.remove(selected);
+        list_state.select((!repositories.is_empty()).then(|| selected.min(repositories.len() - 1)));
+        Ok(())
+    }
+
     fn render_ui(&self, frame: &mut Frame, colors: &crate::presentation::ui::Colors) {
         // Add horizontal padding
         let outer_chunks = Layout::default()
@@ -84,9 +195,29 @@ impl TrendingRepositorySelectionScreen {
             .split(outer_chunks[1]);
 
         HeaderView::render(frame, chunks[0], colors);
-        let repositories = self.repositories.read().unwrap();
-        let mut list_state = self.list_state.write().unwrap();
-        RepositoryListView::render(frame, chunks[1], &repositories, &mut list_state, colors);
+        if self.is_loading() {
+            let spinner =
+                SPINNER_CHARS[self.spinner_index.load(Ordering::Relaxed) % SPINNER_CHARS.len()];
+            RepositoryListView::render_message(
+                frame,
+                chunks[1],
+                &format!("{} Fetching trending repositories...", spinner),
+                colors.text_secondary(),
+                colors,
+            );
+        } else if let Some(error) = self.load_error.read().unwrap().as_deref() {
+            RepositoryListView::render_message(
+                frame,
+                chunks[1],
+                &format!("Failed to fetch trending repositories: {}", error),
+                colors.error(),
+                colors,
+            );
+        } else {
+            let repositories = self.repositories.read().unwrap();
+            let mut list_state = self.list_state.write().unwrap();
+            RepositoryListView::render(frame, chunks[1], &repositories, &mut list_state, colors);
+        }
         ControlsView::render(frame, chunks[2], colors);
     }
 }
@@ -118,14 +249,12 @@ impl Screen for TrendingRepositorySelectionScreen {
             // Build cache key
             let cache_key = format!("{}:{}", language.as_deref().unwrap_or("all"), period);
 
-            // Fetch repositories
-            let repositories = self.trending_repository.get_trending_repositories_sync(
-                &cache_key,
-                language.as_deref(),
-                &period,
-            )?;
+            self.subscribe_to_loaded();
+            *self.loading.write().unwrap() = Some(cache_key.clone());
+            *self.load_error.write().unwrap() = None;
+            self.repositories.write().unwrap().clear();
+            self.fetch_in_background(cache_key, language, period);
 
-            *self.repositories.write().unwrap() = repositories;
             let mut list_state = ListState::default();
             list_state.select(Some(0));
             *self.list_state.write().unwrap() = list_state;
@@ -167,6 +296,7 @@ impl Screen for TrendingRepositorySelectionScreen {
                     }
                 }
             }
+            KeyCode::Char('n') | KeyCode::Char('N') => self.dismiss_selected()?,
             KeyCode::Char(' ') => {
                 let list_state = self.list_state.read().unwrap();
                 if let Some(selected) = list_state.selected() {
@@ -187,10 +317,30 @@ impl Screen for TrendingRepositorySelectionScreen {
     }
 
     fn get_update_strategy(&self) -> UpdateStrategy {
-        UpdateStrategy::InputOnly
+        if self.is_loading() {
+            UpdateStrategy::Hybrid {
+                interval: Duration::from_millis(100),
+                input_priority: true,
+            }
+        } else {
+            UpdateStrategy::InputOnly
+        }
     }
 
     fn update(&self) -> Result<bool> {
+        let loaded: Vec<_> = self.loaded.lock().unwrap().drain(..).collect();
+        let mut applied = false;
+        for loaded in loaded {
+            applied |= self.apply_loaded(loaded);
+        }
+        if applied {
+            return Ok(true);
+        }
+
+        if self.is_loading() {
+            self.spinner_index.fetch_add(1, Ordering::Relaxed);
+            return Ok(true);
+        }
         Ok(false)
     }
 
@@ -0,0 +1,733 @@
+use crate::domain::events::presentation_events::NavigateTo;
+use crate::domain::events::EventBusInterface;
+use crate::domain::models::rank::{Rank, RankTier};
+use crate::domain::models::{Locale, StageResult};
+use crate::domain::repositories::session_repository::SessionRepositoryTrait;
+use crate::domain::services::config_service::{ConfigService, ConfigServiceInterface};
+use crate::domain::services::editor_session::{EditorProgress, EditorSession};
+use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
+use crate::presentation::ui::Colors;
+use crate::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Padding, Paragraph, Tabs, Wrap},
+    Frame,
+};
+use std::sync::{Arc, RwLock};
+
+/// Built-in challenge typed during the tutorial
+pub const PRACTICE_SNIPPET: &str = "fn greet(name: &str) {\n    println!(\"Hello, {}!\", name);\n}";
+
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum TutorialStep {
+    #[default]
+    Welcome,
+    Practice,
+    SkipAndFail,
+    Scoring,
+    Ranks,
+    Finish,
+}
+
+impl TutorialStep {
+    pub fn title(&self) -> &'static str {
+        match self {
+            TutorialStep::Welcome => "Welcome",
+            TutorialStep::Practice => "Practice",
+            TutorialStep::SkipAndFail => "Skip & Fail",
+            TutorialStep::Scoring => "Scoring",
+            TutorialStep::Ranks => "Ranks",
+            TutorialStep::Finish => "Ready",
+        }
+    }
+
+    pub fn all() -> Vec<TutorialStep> {
+        vec![
+            TutorialStep::Welcome,
+            TutorialStep::Practice,
+            TutorialStep::SkipAndFail,
+            TutorialStep::Scoring,
+            TutorialStep::Ranks,
+            TutorialStep::Finish,
+        ]
+    }
+
+    pub fn next(&self) -> Option<TutorialStep> {
+        let steps = Self::all();
+        let index = steps.iter().position(|step| step == self)?;
+        steps.get(index + 1).copied()
+    }
+
+    pub fn previous(&self) -> Option<TutorialStep> {
+        let steps = Self::all();
+        let index = steps.iter().position(|step| step == self)?;
+        index.checked_sub(1).and_then(|i| steps.get(i).copied())
+    }
+}
+
+pub trait TutorialScreenInterface: Screen {}
+
+/// Guided walkthrough for first-time players, also reachable from Help
+#[derive(shaku::Component)]
+#[shaku(interface = TutorialScreenInterface)]
+pub struct TutorialScreen {
+    #[shaku(default)]
+    current_step: RwLock<TutorialStep>,
+
+    #[shaku(default)]
+    practice: RwLock<EditorSession>,
+
+    #[shaku(default)]
+    practice_text: RwLock<String>,
+
+    #[shaku(default)]
+    practice_progress: RwLock<Option<EditorProgress>>,
+
+    #[shaku(default)]
+    practice_result: RwLock<Option<StageResult>>,
+
+    #[shaku(inject)]
+    event_bus: Arc<dyn EventBusInterface>,
+    #[shaku(inject)]
+    theme_service: Arc<dyn ThemeServiceInterface>,
+    #[shaku(inject)]
+    config_service: Arc<dyn ConfigServiceInterface>,
+    #[shaku(inject)]
+    session_repository: Arc<dyn SessionRepositoryTrait>,
+}
+
+impl TutorialScreen {
+    pub fn new(
+        event_bus: Arc<dyn EventBusInterface>,
+        theme_service: Arc<dyn ThemeServiceInterface>,
+        config_service: Arc<dyn ConfigServiceInterface>,
+        session_repository: Arc<dyn SessionRepositoryTrait>,
+    ) -> Self {
+        Self {
+            current_step: RwLock::new(TutorialStep::default()),
+            practice: RwLock::new(EditorSession::new()),
+            practice_text: RwLock::new(String::new()),
+            practice_progress: RwLock::new(None),
+            practice_result: RwLock::new(None),
+            event_bus,
+            theme_service,
+            config_service,
+            session_repository,
+        }
+    }
+
+    /// First launch: the tutorial has never been finished and no session has been played
+    pub fn should_show_on_launch(&self) -> bool {
+        !self.config_service.get_config().tutorial.completed
+            && self
+                .session_repository
+                .get_sessions_filtered(None, None, "date", true)
+                .map(|sessions| sessions.is_empty())
+                .unwrap_or(false)
+    }
+
+    pub fn current_step(&self) -> TutorialStep {
+        *self.current_step.read().unwrap()
+    }
+
+    pub fn practice_result(&self) -> Option<StageResult> {
+        self.practice_result.read().unwrap().clone()
+    }
+
+    fn reset_practice(&self) -> Result<()> {
+        let mut practice = EditorSession::new();
+        let challenge = practice.load(PRACTICE_SNIPPET, Some("rust"), None)?;
+        *self.practice.write().unwrap() = practice;
+        *self.practice_text.write().unwrap() = challenge.text_to_type;
+        *self.practice_progress.write().unwrap() = None;
+        *self.practice_result.write().unwrap() = None;
+        Ok(())
+    }
+
+    fn is_practicing(&self) -> bool {
+        self.current_step() == TutorialStep::Practice && self.practice_result().is_none()
+    }
+
+    fn type_practice(&self, text: &str) -> Result<()> {
+        let mut practice = self.practice.write().unwrap();
+        if self.practice_progress.read().unwrap().is_none() {
+            practice.start()?;
+        }
+
+        let progress = practice.input(text)?;
+        if progress.completed {
+            *self.practice_result.write().unwrap() = Some(practice.finish()?);
+        }
+        *self.practice_progress.write().unwrap() = Some(progress);
+        Ok(())
+    }
+
+    fn go_to(&self, step: Option<TutorialStep>) {
+        if let Some(step) = step {
+            *self.current_step.write().unwrap() = step;
+        }
+    }
+
+    /// Remember the tutorial was seen and return to the previous screen
+    fn finish(&self) {
+        if let Some(config_service) =
+            (self.config_service.as_ref() as &dyn std::any::Any).downcast_ref::<ConfigService>()
+        {
+            let _ = config_service.update_config(|config| config.tutorial.completed = true);
+            let _ = self.config_service.save();
+        }
+        self.event_bus.as_event_bus().publish(NavigateTo::Pop);
+    }
+
+    fn handle_practice_key(&self, key_event: KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Esc => {
+                self.go_to(self.current_step().next());
+                Ok(())
+            }
+            KeyCode::Enter => self.type_practice("\n"),
+            KeyCode::Tab => self.type_practice("\t"),
+            KeyCode::Char(c) => self.type_practice(&c.to_string()),
+            _ => Ok(()),
+        }
+    }
+
+    fn get_welcome_content(colors: &Colors) -> Text<'static> {
+        Text::from(vec![
+            Line::from(Span::styled(
+                "Welcome to GitType!",
+                Style::default().fg(colors.title()).bold(),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                "GitType turns real source code into typing practice. A session is a few stages, \
+                 and each stage is a function, class, or block taken from a repository.",
+                Style::default().fg(colors.text()),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                "This short tutorial covers:",
+                Style::default().fg(colors.text()),
+            )),
+            Line::from(Span::styled(
+                "  • A tiny practice challenge",
+                Style::default().fg(colors.text()),
+            )),
+            Line::from(Span::styled(
+                "  • Skipping and failing stages",
+                Style::default().fg(colors.text()),
+            )),
+            Line::from(Span::styled(
+                "  • How your score is calculated",
+                Style::default().fg(colors.text()),
+            )),
+            Line::from(Span::styled(
+                "  • Ranks and tiers",
+                Style::default().fg(colors.text()),
+            )),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(
+                    "You can reopen it any time from Help: ",
+                    Style::default().fg(colors.text_secondary()),
+                ),
+                Span::styled("[?]", Style::default().fg(colors.key_action())),
+                Span::styled(
+                    " on the title screen, then ",
+                    Style::default().fg(colors.text_secondary()),
+                ),
+                Span::styled("[T]", Style::default().fg(colors.key_action())),
+                Span::styled(".", Style::default().fg(colors.text_secondary())),
+            ]),
+        ])
+    }
+
+    fn get_practice_content(&self, colors: &Colors) -> Text<'static> {
+        let text = self.practice_text.read().unwrap().clone();
+        let progress = self.practice_progress.read().unwrap().clone();
+        let position = progress.as_ref().map(|p| p.position).unwrap_or(0);
+        let mistakes = progress.as_ref().map(|p| p.mistakes).unwrap_or(0);
+        let total = text.chars().count();
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                "Type the code below. Indentation is filled in for you; press Enter at the end of each line.",
+                Style::default().fg(colors.text()),
+            )),
+            Line::from(Span::styled(
+                "A wrong key does not move the cursor: type the correct character to continue.",
+                Style::default().fg(colors.text_secondary()),
+            )),
+            Line::from(""),
+        ];
+        lines.extend(Self::practice_code_lines(&text, position, colors));
+        lines.push(Line::from(""));
+
+        match self.practice_result() {
+            Some(result) => {
+                lines.push(Line::from(Span::styled(
+                    "Nice work!",
+                    Style::default().fg(colors.success()).bold(),
+                )));
+                lines.push(Line::from(vec![
+                    Span::styled("CPM ", Style::default().fg(colors.cpm_wpm())),
+                    Span::styled(
+                        Locale::current().format_decimal(result.cpm, 0),
+                        Style::default().fg(colors.text()),
+                    ),
+                    Span::raw("  │  "),
+                    Span::styled("WPM ", Style::default().fg(colors.cpm_wpm())),
+                    Span::styled(
+                        Locale::current().format_decimal(result.wpm, 0),
+                        Style::default().fg(colors.text()),
+                    ),
+                    Span::raw("  │  "),
+                    Span::styled("Accuracy ", Style::default().fg(colors.accuracy())),
+                    Span::styled(
+                        format!("{}%", Locale::current().format_decimal(result.accuracy, 1)),
+                        Style::default().fg(colors.text()),
+                    ),
+                    Span::raw("  │  "),
+                    Span::styled("Score ", Style::default().fg(colors.score())),
+                    Span::styled(
+                        Locale::current().format_decimal(result.challenge_score, 0),
+                        Style::default().fg(colors.text()),
+                    ),
+                    Span::raw("  │  "),
+                    Span::styled("Rank ", Style::default().fg(colors.score())),
+                    Span::styled(result.rank_name.clone(), Style::default().fg(colors.text())),
+                ]));
+                lines.push(Line::from(Span::styled(
+                    "Practice results are not saved to your records.",
+                    Style::default().fg(colors.text_secondary()),
+                )));
+            }
+            None => lines.push(Line::from(vec![
+                Span::styled("Progress: ", Style::default().fg(colors.stage_info())),
+                Span::styled(
+                    format!("{}/{}", position, total),
+                    Style::default().fg(colors.text()),
+                ),
+                Span::raw("  │  "),
+                Span::styled("Mistakes: ", Style::default().fg(colors.error())),
+                Span::styled(mistakes.to_string(), Style::default().fg(colors.text())),
+            ])),
+        }
+
+        Text::from(lines)
+    }
+
+    /// Snippet with typed, cursor, and untyped characters; indentation is shown but never typed
+    fn practice_code_lines(text: &str, position: usize, colors: &Colors) -> Vec<Line<'static>> {
+        let mut index = 0;
+        text.split('\n')
+            .zip(PRACTICE_SNIPPET.lines())
+            .map(|(line, source_line)| {
+                let indent = source_line.len() - source_line.trim_start().len();
+                let mut spans = vec![Span::raw(format!("    {}", " ".repeat(indent)))];
+                for c in line.chars().chain(std::iter::once('\n')) {
+                    let style = if index < position {
+                        Style::default().fg(colors.typed_text())
+                    } else if index == position {
+                        Style::default()
+                            .fg(colors.current_cursor())
+                            .bg(colors.cursor_bg())
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(colors.untyped_text())
+                    };
+                    if c != '\n' {
+                        spans.push(Span::styled(c.to_string(), style));
+                    } else if index == position {
+                        spans.push(Span::styled("↵", style));
+                    }
+                    index += 1;
+                }
+                Line::from(spans)
+            })
+            .collect()
+    }
+
+    fn get_skip_and_fail_content(colors: &Colors) -> Text<'static> {
+        let key_line = |key: &'static str, description: &'static str, key_color| {
+            Line::from(vec![
+                Span::styled(
+                    format!("  {:<8}", key),
+                    Style::default().fg(key_color).bold(),
+                ),
+                Span::styled(description, Style::default().fg(colors.text())),
+            ])
+        };
+
+        Text::from(vec![
+            Line::from(Span::styled(
+                "Stuck on a stage?",
+                Style::default().fg(colors.title()).bold(),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                "While typing, press [ESC] to open the Game Options menu:",
+                Style::default().fg(colors.text()),
+            )),
+            Line::from(""),
+            key_line(
+                "[S]",
+                "Skip: swap in a new challenge. You get 3 skips per session, and skipped stages are not scored.",
+                colors.info(),
+            ),
+            key_line(
+                "[Q]",
+                "Quit: give up the session. It is recorded as failed.",
+                colors.error(),
+            ),
+            key_line(
+                "[ESC]",
+                "Back to game: close the menu and keep typing.",
+                colors.key_action(),
+            ),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Mistakes never fail a stage, but every one lowers your accuracy and score.",
+                Style::default().fg(colors.text()),
+            )),
+            Line::from(vec![
+                Span::styled("Press ", Style::default().fg(colors.text_secondary())),
+                Span::styled("[Ctrl+C]", Style::default().fg(colors.error())),
+                Span::styled(
+                    " at any time to leave GitType.",
+                    Style::default().fg(colors.text_secondary()),
+                ),
+            ]),
+        ])
+    }
+
+    fn get_scoring_content(colors: &Colors) -> Text<'static> {
+        Text::from(vec![
+            Line::from(Span::styled(
+                "How scoring works",
+                Style::default().fg(colors.title()).bold(),
+            )),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("CPM", Style::default().fg(colors.cpm_wpm())),
+                Span::styled(
+                    " is characters typed per minute; ",
+                    Style::default().fg(colors.text()),
+                ),
+                Span::styled("WPM", Style::default().fg(colors.cpm_wpm())),
+                Span::styled(" is CPM / 5.", Style::default().fg(colors.text())),
+            ]),
+            Line::from(vec![
+                Span::styled("Accuracy", Style::default().fg(colors.accuracy())),
+                Span::styled(
+                    " is the share of keystrokes that were correct.",
+                    Style::default().fg(colors.text()),
+                ),
+            ]),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Base Score = CPM × (Accuracy / 100) × 10",
+                Style::default().fg(colors.score()),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                "• Consistency Bonus: up to 70% extra for high accuracy",
+                Style::default().fg(colors.text()),
+            )),
+            Line::from(Span::styled(
+                "• Time Bonus: finishing quickly adds extra points",
+                Style::default().fg(colors.text()),
+            )),
+            Line::from(Span::styled(
+                "• Mistake Penalty: -5 points per error",
+                Style::default().fg(colors.text()),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Accuracy multiplies everything, so slow and clean beats fast and sloppy.",
+                Style::default().fg(colors.text_secondary()),
+            )),
+        ])
+    }
+
+    fn get_ranks_content(&self, colors: &Colors) -> Text<'static> {
+        let ranks = Rank::all_ranks();
+        let mut lines = vec![
+            Line::from(Span::styled(
+                "Ranks and tiers",
+                Style::default().fg(colors.title()).bold(),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Every stage and session score maps to a rank. Ranks are grouped into five tiers:",
+                Style::default().fg(colors.text()),
+            )),
+            Line::from(""),
+        ];
+
+        for tier in [
+            RankTier::Beginner,
+            RankTier::Intermediate,
+            RankTier::Advanced,
+            RankTier::Expert,
+            RankTier::Legendary,
+        ] {
+            let Some(first) = ranks.iter().find(|rank| *rank.tier() == tier) else {
+                continue;
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<14}", format!("{:?}", tier)),
+                    Style::default()
+                        .fg(Colors::from_crossterm(tier.terminal_color()))
+                        .bold(),
+                ),
+                Span::styled(
+                    format!("from {:>5} points", first.min_score),
+                    Style::default().fg(colors.text_secondary()),
+                ),
+                Span::styled("  starting at ", Style::default().fg(colors.text())),
+                Span::styled(
+                    first.name().to_string(),
+                    Style::default().fg(Colors::from_crossterm(first.terminal_color())),
+                ),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        if let Some(result) = self.practice_result() {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    "Your practice run would rank as ",
+                    Style::default().fg(colors.text()),
+                ),
+                Span::styled(
+                    result.rank_name.clone(),
+                    Style::default().fg(colors.score()).bold(),
+                ),
+                Span::styled(
+                    format!(" ({} tier).", result.tier_name),
+                    Style::default().fg(colors.text()),
+                ),
+            ]));
+        }
+        lines.push(Line::from(Span::styled(
+            "See Help → Rank System for the full list.",
+            Style::default().fg(colors.text_secondary()),
+        )));
+
+        Text::from(lines)
+    }
+
+    fn get_finish_content(colors: &Colors) -> Text<'static> {
+        Text::from(vec![
+            Line::from(Span::styled(
+                "You're ready!",
+                Style::default().fg(colors.title()).bold(),
+            )),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Press ", Style::default().fg(colors.text())),
+                Span::styled("[SPACE]", Style::default().fg(colors.success())),
+                Span::styled(
+                    " on the title screen to start a session, ",
+                    Style::default().fg(colors.text()),
+                ),
+                Span::styled("[←→]", Style::default().fg(colors.info())),
+                Span::styled(
+                    " to pick a difficulty, and ",
+                    Style::default().fg(colors.text()),
+                ),
+                Span::styled("[R]", Style::default().fg(colors.key_action())),
+                Span::styled(
+                    " or [A] to review your records and analytics.",
+                    Style::default().fg(colors.text()),
+                ),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Press ", Style::default().fg(colors.text())),
+                Span::styled("[ENTER]", Style::default().fg(colors.success())),
+                Span::styled(
+                    " to finish the tutorial.",
+                    Style::default().fg(colors.text()),
+                ),
+            ]),
+        ])
+    }
+
+    fn render_steps(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let steps = TutorialStep::all();
+        let current_step = self.current_step();
+        let selected_index = steps.iter().position(|&s| s == current_step).unwrap_or(0);
+
+        let tabs = Tabs::new(
+            steps
+                .iter()
+                .enumerate()
+                .map(|(i, step)| format!("{}. {}", i + 1, step.title()))
+                .collect::<Vec<_>>(),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border()))
+                .title("Tutorial")
+                .title_style(Style::default().fg(colors.title()).bold()),
+        )
+        .style(Style::default().fg(colors.text_secondary()))
+        .highlight_style(Style::default().fg(colors.text()).bold())
+        .select(selected_index);
+
+        frame.render_widget(tabs, area);
+    }
+
+    fn render_content(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let content = match self.current_step() {
+            TutorialStep::Welcome => Self::get_welcome_content(colors),
+            TutorialStep::Practice => self.get_practice_content(colors),
+            TutorialStep::SkipAndFail => Self::get_skip_and_fail_content(colors),
+            TutorialStep::Scoring => Self::get_scoring_content(colors),
+            TutorialStep::Ranks => self.get_ranks_content(colors),
+            TutorialStep::Finish => Self::get_finish_content(colors),
+        };
+
+        let paragraph = Paragraph::new(content)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(colors.border()))
+                    .padding(Padding::new(2, 2, 1, 0)),
+            )
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(paragraph, area);
+    }
+
+    fn render_footer(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let instructions = if self.is_practicing() {
+            vec![
+                Span::styled("Type the code", Style::default().fg(colors.text())),
+                Span::styled("  [ESC]", Style::default().fg(colors.error())),
+                Span::styled(" Skip practice", Style::default().fg(colors.text())),
+            ]
+        } else {
+            let mut spans = vec![
+                Span::styled("[→/L/ENTER]", Style::default().fg(colors.info())),
+                Span::styled(" Next ", Style::default().fg(colors.text())),
+                Span::styled("[←/H]", Style::default().fg(colors.info())),
+                Span::styled(" Back ", Style::default().fg(colors.text())),
+            ];
+            if self.current_step() == TutorialStep::Practice {
+                spans.extend([
+                    Span::styled("[R]", Style::default().fg(colors.key_action())),
+                    Span::styled(" Retry ", Style::default().fg(colors.text())),
+                ]);
+            }
+            spans.extend([
+                Span::styled("[ESC]", Style::default().fg(colors.error())),
+                Span::styled(" Close", Style::default().fg(colors.text())),
+            ]);
+            spans
+        };
+
+        let footer = Paragraph::new(Line::from(instructions)).alignment(Alignment::Center);
+        frame.render_widget(footer, area);
+    }
+}
+
+pub struct TutorialScreenDataProvider;
+
+impl ScreenDataProvider for TutorialScreenDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(()))
+    }
+}
+
+impl Screen for TutorialScreen {
+    fn get_type(&self) -> ScreenType {
+        ScreenType::Tutorial
+    }
+
+    fn default_provider() -> Box<dyn ScreenDataProvider>
+    where
+        Self: Sized,
+    {
+        Box::new(TutorialScreenDataProvider)
+    }
+
+    fn init_with_data(&self, _data: Box<dyn std::any::Any>) -> Result<()> {
+        *self.current_step.write().unwrap() = TutorialStep::default();
+        self.reset_practice()
+    }
+
+    fn handle_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        if key_event.code == KeyCode::Char('c')
+            && key_event.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+            return Ok(());
+        }
+
+        if self.is_practicing() {
+            return self.handle_practice_key(key_event);
+        }
+
+        let current_step = self.current_step();
+        match key_event.code {
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') | KeyCode::Enter => {
+                match current_step.next() {
+                    Some(step) => self.go_to(Some(step)),
+                    None => self.finish(),
+                }
+                Ok(())
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.go_to(current_step.previous());
+                Ok(())
+            }
+            KeyCode::Char('r') if current_step == TutorialStep::Practice => self.reset_practice(),
+            KeyCode::Esc => {
+                self.finish();
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn render_ratatui(&self, frame: &mut Frame) -> Result<()> {
+        let colors = self.theme_service.get_colors();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(2),
+            ])
+            .split(frame.area());
+
+        self.render_steps(frame, chunks[0], &colors);
+        self.render_content(frame, chunks[1], &colors);
+        self.render_footer(frame, chunks[2], &colors);
+
+        Ok(())
+    }
+
+    fn get_update_strategy(&self) -> UpdateStrategy {
+        UpdateStrategy::InputOnly
+    }
+
+    fn update(&self) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl TutorialScreenInterface for TutorialScreen {}
@@ -2,19 +2,35 @@ use crate::domain::events::domain_events::DomainEvent;
 use crate::domain::events::presentation_events::NavigateTo;
 use crate::domain::events::EventBusInterface;
 use crate::domain::models::typing::{CodeContext, InputResult, ProcessingOptions};
-use crate::domain::models::{Challenge, Countdown, GitRepository};
+use crate::domain::models::{
+    Challenge, Countdown, DemoMode, DemoTypist, GitRepository, Presence, RaceBot, StreamerMode,
+    StreamerStats,
+};
+use crate::domain::services::challenge_flag_service::ChallengeFlagServiceInterface;
+use crate::domain::services::config_service::{ConfigService, ConfigServiceInterface};
 use crate::domain::services::context_loader;
+use crate::domain::services::discord_presence_service::DiscordPresenceServiceInterface;
+use crate::domain::services::scoring::{RealTimeCalculator, RealTimeResult};
 use crate::domain::services::session_manager_service::SessionManagerInterface;
+use crate::domain::services::streamer_stats_service::StreamerStatsServiceInterface;
 use crate::domain::services::theme_service::ThemeServiceInterface;
 use crate::domain::services::typing_core::TypingCore;
 use crate::domain::services::SessionManager;
 use crate::domain::stores::RepositoryStoreInterface;
-use crate::presentation::tui::views::TypingView;
+use crate::infrastructure::terminal::WindowTitle;
+use crate::presentation::spectator::SpectatorBroadcast;
+use crate::presentation::tui::views::typing::typing_content_view::PreparedContent;
+use crate::presentation::tui::views::{TypingPasteNoticeView, TypingView};
 use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
+use crate::presentation::ui::{Colors, ScreenCapture};
 use crate::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use std::sync::{Arc, RwLock};
-use std::time::Duration;
+use ratatui::{backend::TestBackend, Terminal};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+/// How long the notice about an ignored paste stays on screen
+const PASTE_NOTICE_DURATION: Duration = Duration::from_secs(3);
 
 pub trait TypingScreenInterface: Screen {}
 
@@ -35,8 +51,32 @@ pub struct TypingScreen {
     waiting_to_start: RwLock<bool>,
     #[shaku(default)]
     dialog_shown: RwLock<bool>,
+    /// Set when the dialog was opened because the terminal lost focus
+    #[shaku(default)]
+    focus_paused: RwLock<bool>,
+    #[shaku(default)]
+    big_wpm_shown: RwLock<bool>,
+    #[shaku(default)]
+    wide_layout: bool,
+    #[shaku(default)]
+    window_title: bool,
+    /// Ghost of an earlier attempt raced in this stage, besides the bots
+    #[shaku(default)]
+    ghost: RwLock<Option<RaceBot>>,
     #[shaku(default)]
     typing_view: RwLock<TypingView>,
+    /// When text was last pasted, which is ignored rather than typed
+    #[shaku(default)]
+    paste_rejected_at: RwLock<Option<Instant>>,
+    /// Whether laying out the next stage's challenge was started during this stage
+    #[shaku(default)]
+    next_stage_prepared: RwLock<bool>,
+    /// Layout of the next stage's challenge, once the background thread made it
+    #[shaku(default)]
+    next_stage_layout: Arc<Mutex<Option<PreparedContent>>>,
+    /// Scripted typist and when it began typing this stage, while a demo plays
+    #[shaku(default)]
+    demo_typist: RwLock<Option<(DemoTypist, Instant)>>,
     #[shaku(inject)]
     event_bus: Arc<dyn EventBusInterface>,
     #[shaku(inject)]
@@ -45,6 +85,14 @@ pub struct TypingScreen {
     repository_store: Arc<dyn RepositoryStoreInterface>,
     #[shaku(inject)]
     session_manager: Arc<dyn SessionManagerInterface>,
+    #[shaku(inject)]
+    discord_presence_service: Arc<dyn DiscordPresenceServiceInterface>,
+    #[shaku(inject)]
+    challenge_flag_service: Arc<dyn ChallengeFlagServiceInterface>,
+    #[shaku(inject)]
+    config_service: Arc<dyn ConfigServiceInterface>,
+    #[shaku(inject)]
+    streamer_stats_service: Arc<dyn StreamerStatsServiceInterface>,
 }
 
 pub enum SessionState {
@@ -59,13 +107,19 @@ pub enum SessionState {
 }
 
 impl TypingScreen {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         event_bus: Arc<dyn EventBusInterface>,
         theme_service: Arc<dyn ThemeServiceInterface>,
         repository_store: Arc<dyn RepositoryStoreInterface>,
         session_manager: Arc<dyn SessionManagerInterface>,
+        discord_presence_service: Arc<dyn DiscordPresenceServiceInterface>,
+        challenge_flag_service: Arc<dyn ChallengeFlagServiceInterface>,
+        config_service: Arc<dyn ConfigServiceInterface>,
+        streamer_stats_service: Arc<dyn StreamerStatsServiceInterface>,
     ) -> Self {
         let git_repository = repository_store.get_repository();
+        let display = config_service.get_config().display;
 
         Self {
             countdown: RwLock::new(Countdown::new()),
@@ -75,14 +129,53 @@ impl TypingScreen {
             code_context: RwLock::new(CodeContext::empty()),
             waiting_to_start: RwLock::new(true),
             dialog_shown: RwLock::new(false),
+            focus_paused: RwLock::new(false),
+            big_wpm_shown: RwLock::new(display.big_wpm),
+            wide_layout: display.wide_layout,
+            window_title: display.window_title,
+            ghost: RwLock::new(None),
             typing_view: RwLock::new(TypingView::new()),
+            paste_rejected_at: RwLock::new(None),
+            next_stage_prepared: RwLock::new(false),
+            next_stage_layout: Arc::new(Mutex::new(None)),
+            demo_typist: RwLock::new(None),
             event_bus,
             theme_service,
             repository_store,
             session_manager,
+            discord_presence_service,
+            challenge_flag_service,
+            config_service,
+            streamer_stats_service,
         }
     }
 
+    /// Streamer mode always shows the big WPM panel
+    pub fn is_big_wpm_shown(&self) -> bool {
+        *self.big_wpm_shown.read().unwrap() || StreamerMode::is_enabled()
+    }
+
+    pub fn is_dialog_shown(&self) -> bool {
+        *self.dialog_shown.read().unwrap()
+    }
+
+    pub fn has_prepared_next_stage(&self) -> bool {
+        self.typing_view.read().unwrap().has_prepared_content()
+    }
+
+    /// Whether the notice about an ignored paste is still up
+    pub fn is_paste_notice_shown(&self) -> bool {
+        self.paste_rejected_at
+            .read()
+            .unwrap()
+            .is_some_and(|at| at.elapsed() < PASTE_NOTICE_DURATION)
+    }
+
+    /// Position in the challenge the next keystroke is checked against
+    pub fn typed_position(&self) -> usize {
+        self.typing_core.read().unwrap().current_position_to_type()
+    }
+
     pub fn set_waiting_to_start(&self, waiting: bool) {
         *self.waiting_to_start.write().unwrap() = waiting;
     }
@@ -98,40 +191,34 @@ impl TypingScreen {
     }
 
     fn load_current_challenge(&self) -> Result<bool> {
-        let challenge = if let Some(session_manager) = self
+        let (challenge, ghost) = if let Some(session_manager) = self
             .session_manager
             .as_any()
             .downcast_ref::<SessionManager>()
         {
-            session_manager.get_current_challenge()?
+            (
+                session_manager.get_current_challenge()?,
+                session_manager.take_ghost(),
+            )
         } else {
-            None
+            (None, None)
         };
+        *self.ghost.write().unwrap() = ghost;
 
         if let Some(challenge) = challenge {
-            let comment_ranges = &challenge.comment_ranges;
-            let options = ProcessingOptions {
-                preserve_empty_lines: true,
-                ..Default::default()
-            };
-
-            *self.typing_core.write().unwrap() =
-                TypingCore::new(&challenge.code_content, comment_ranges, options);
-
-            // Get git root from repository store for context loading
-            let git_repository = self.repository_store.get_repository();
-            let git_root = git_repository
-                .as_ref()
-                .and_then(|repo| repo.root_path.as_deref());
-            *self.code_context.write().unwrap() =
-                context_loader::load_context_for_challenge(&challenge, 4, git_root)?;
+            *self.typing_core.write().unwrap() = Self::typing_core_for(&challenge);
+            *self.code_context.write().unwrap() = self.load_code_context(&challenge)?;
 
+            *self.next_stage_prepared.write().unwrap() = false;
+            *self.next_stage_layout.lock().unwrap() = None;
+            *self.demo_typist.write().unwrap() = None;
             *self.countdown.write().unwrap() = Countdown::new();
             *self.challenge.write().unwrap() = Some(challenge.clone());
             // Update git_repository from RepositoryStore
             *self.git_repository.write().unwrap() = self.repository_store.get_repository();
             *self.waiting_to_start.write().unwrap() = true;
             *self.dialog_shown.write().unwrap() = false;
+            *self.focus_paused.write().unwrap() = false;
 
             // Publish ChallengeLoaded event
             self.event_bus
@@ -140,6 +227,7 @@ impl TypingScreen {
                     text: self.typing_core.read().unwrap().text_to_type().to_string(),
                     source_path: challenge.source_file_path.clone().unwrap_or_default(),
                 });
+            self.update_window_title();
 
             Ok(true)
         } else {
@@ -147,6 +235,82 @@ impl TypingScreen {
         }
     }
 
+    fn typing_core_for(challenge: &Challenge) -> TypingCore {
+        let options = ProcessingOptions {
+            preserve_empty_lines: true,
+            ..Default::default()
+        };
+        TypingCore::new(&challenge.code_content, &challenge.comment_ranges, options)
+    }
+
+    fn load_code_context(&self, challenge: &Challenge) -> Result<CodeContext> {
+        Self::code_context_for(&self.repository_store, challenge)
+    }
+
+    fn code_context_for(
+        repository_store: &Arc<dyn RepositoryStoreInterface>,
+        challenge: &Challenge,
+    ) -> Result<CodeContext> {
+        // Get git root from repository store for context loading
+        let git_repository = repository_store.get_repository();
+        let git_root = git_repository
+            .as_ref()
+            .and_then(|repo| repo.root_path.as_deref());
+        context_loader::load_context_for_challenge(challenge, 4, git_root)
+    }
+
+    /// Lay out the next stage's challenge once per stage on a background
+    /// thread while this one is typed, so large chunks stall neither a frame
+    /// nor the stage transition; `update` hands the layout to the view
+    fn prepare_next_stage(&self, colors: Colors) {
+        *self.next_stage_prepared.write().unwrap() = true;
+        let area = self.typing_view.read().unwrap().content_area();
+        if area.is_empty() {
+            return;
+        }
+        let session_manager = Arc::clone(&self.session_manager);
+        let repository_store = Arc::clone(&self.repository_store);
+        let next_stage_layout = Arc::clone(&self.next_stage_layout);
+
+        std::thread::spawn(move || {
+            let Some(session_manager) = session_manager.as_any().downcast_ref::<SessionManager>()
+            else {
+                return;
+            };
+            let challenge = match session_manager.prefetch_next_challenge() {
+                Ok(Some(challenge)) => challenge,
+                Ok(None) => return,
+                Err(e) => {
+                    log::warn!("Failed to prefetch the next challenge: {}", e);
+                    return;
+                }
+            };
+            let code_context = match Self::code_context_for(&repository_store, &challenge) {
+                Ok(code_context) => code_context,
+                Err(e) => {
+                    log::warn!("Failed to load context of the next challenge: {}", e);
+                    return;
+                }
+            };
+            *next_stage_layout.lock().unwrap() = TypingView::layout_next(
+                area,
+                &challenge,
+                &Self::typing_core_for(&challenge),
+                &code_context,
+                &colors,
+            );
+        });
+    }
+
+    fn take_next_stage_layout(&self) {
+        if let Some(prepared) = self.next_stage_layout.lock().unwrap().take() {
+            self.typing_view
+                .write()
+                .unwrap()
+                .set_prepared_next(prepared);
+        }
+    }
+
     fn handle_key(&self, key_event: KeyEvent) -> Result<SessionState> {
         if !matches!(key_event.kind, KeyEventKind::Press) {
             return Ok(SessionState::Continue);
@@ -183,6 +347,14 @@ impl TypingScreen {
                         Ok(SessionState::WaitingToStart)
                     }
                 }
+                KeyCode::Char('f' | 'F') if dialog_shown => match self.handle_flag_action()? {
+                    SessionState::Skip => Ok(SessionState::Skip),
+                    _ => Ok(SessionState::WaitingToStart),
+                },
+                KeyCode::Char('w' | 'W') if dialog_shown => {
+                    self.toggle_big_wpm();
+                    Ok(SessionState::WaitingToStart)
+                }
                 KeyCode::Char('q' | 'Q') => {
                     if dialog_shown {
                         self.close_dialog();
@@ -222,6 +394,14 @@ impl TypingScreen {
                         Ok(SessionState::Countdown)
                     }
                 }
+                KeyCode::Char('f' | 'F') if dialog_shown => match self.handle_flag_action()? {
+                    SessionState::Skip => Ok(SessionState::Skip),
+                    _ => Ok(SessionState::Countdown),
+                },
+                KeyCode::Char('w' | 'W') if dialog_shown => {
+                    self.toggle_big_wpm();
+                    Ok(SessionState::Countdown)
+                }
                 KeyCode::Char('q' | 'Q') => {
                     if dialog_shown {
                         self.close_dialog();
@@ -262,6 +442,11 @@ impl TypingScreen {
                         self.handle_character_input(ch)
                     }
                 }
+                KeyCode::Char('f' | 'F') if dialog_shown => self.handle_flag_action(),
+                KeyCode::Char('w' | 'W') if dialog_shown => {
+                    self.toggle_big_wpm();
+                    Ok(SessionState::Continue)
+                }
                 KeyCode::Char('q' | 'Q') => {
                     if dialog_shown {
                         self.close_dialog();
@@ -330,6 +515,34 @@ impl TypingScreen {
         }
     }
 
+    /// Flag the current challenge as bad, then skip it if skips remain
+    fn handle_flag_action(&self) -> Result<SessionState> {
+        if let Some(challenge) = self.challenge.read().unwrap().as_ref() {
+            if let Err(e) = self.challenge_flag_service.flag(challenge) {
+                log::warn!("Failed to flag challenge {}: {}", challenge.id, e);
+            }
+        }
+        self.handle_skip_action()
+    }
+
+    fn toggle_big_wpm(&self) {
+        self.close_dialog();
+        if StreamerMode::is_enabled() {
+            return;
+        }
+        let big_wpm_shown = !self.is_big_wpm_shown();
+        *self.big_wpm_shown.write().unwrap() = big_wpm_shown;
+
+        if let Some(config_service) =
+            (self.config_service.as_ref() as &dyn std::any::Any).downcast_ref::<ConfigService>()
+        {
+            let _ = config_service.update_config(|config| config.display.big_wpm = big_wpm_shown);
+            if let Err(e) = self.config_service.save() {
+                log::warn!("Failed to save big WPM setting: {}", e);
+            }
+        }
+    }
+
     fn handle_tab_key(&self) -> Result<SessionState> {
         // Publish KeyPressed event
         self.event_bus
@@ -395,6 +608,7 @@ impl TypingScreen {
 
     fn close_dialog(&self) {
         *self.dialog_shown.write().unwrap() = false;
+        *self.focus_paused.write().unwrap() = false;
 
         // Publish StageResumed event
         self.event_bus
@@ -404,6 +618,256 @@ impl TypingScreen {
         self.countdown.write().unwrap().resume();
     }
 
+    /// Bots from the lineup, then the ghost if this stage races one
+    fn racers(&self) -> Vec<RaceBot> {
+        let mut racers = RaceBot::lineup();
+        racers.extend(self.ghost.read().unwrap().clone());
+        racers
+    }
+
+    /// Metrics of the stage being typed, or `None` before it starts
+    fn live_metrics(&self) -> Option<RealTimeResult> {
+        if *self.waiting_to_start.read().unwrap() || self.countdown.read().unwrap().is_active() {
+            return None;
+        }
+        let session_manager = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()?;
+
+        let (text, elapsed_time) = session_manager
+            .get_current_stage_tracker()
+            .map(|tracker| {
+                let data = tracker.get_data();
+                (data.target_text, data.elapsed_time)
+            })
+            .unwrap_or_default();
+        let typing_core = self.typing_core.read().unwrap();
+        Some(RealTimeCalculator::calculate(
+            &text,
+            typing_core.current_position_to_type(),
+            typing_core.mistakes(),
+            elapsed_time,
+        ))
+    }
+
+    fn update_presence(&self) {
+        let Some(metrics) = self.live_metrics() else {
+            return;
+        };
+        let Some(session_manager) = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+        else {
+            return;
+        };
+        let Ok((current_stage, total_stages)) = session_manager.get_stage_info() else {
+            return;
+        };
+
+        let presence = Presence {
+            language: self
+                .challenge
+                .read()
+                .unwrap()
+                .as_ref()
+                .and_then(|challenge| challenge.language.clone()),
+            repository: self
+                .git_repository
+                .read()
+                .unwrap()
+                .as_ref()
+                .filter(|_| !StreamerMode::is_enabled())
+                .map(|repo| repo.repository_name.clone()),
+            current_stage,
+            total_stages,
+            wpm: metrics.wpm,
+        };
+        self.discord_presence_service.update(&presence);
+    }
+
+    /// Streamer mode leaves the repository out, as everywhere else on screen
+    fn update_window_title(&self) {
+        if !self.window_title {
+            return;
+        }
+        let Some(session_manager) = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+        else {
+            return;
+        };
+        let Ok((current_stage, total_stages)) = session_manager.get_stage_info() else {
+            return;
+        };
+
+        let repository = self
+            .git_repository
+            .read()
+            .unwrap()
+            .as_ref()
+            .filter(|_| !StreamerMode::is_enabled())
+            .map(|repo| repo.repository_name.clone());
+        WindowTitle::set(&WindowTitle::for_stage(
+            repository.as_deref(),
+            current_stage,
+            total_stages,
+        ));
+    }
+
+    fn update_streamer_stats(&self) {
+        let stats = self
+            .live_metrics()
+            .map(|metrics| StreamerStats {
+                wpm: metrics.wpm,
+                accuracy: metrics.accuracy,
+            })
+            .unwrap_or_default();
+        self.streamer_stats_service.publish(&stats);
+    }
+
+    /// Render the finished stage off-screen at the terminal's size, so the
+    /// screenshot shows the last keystroke too
+    fn capture_screenshot(&self) {
+        if !self.config_service.get_config().screenshots.enabled {
+            return;
+        }
+        let Some(session_manager) = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+        else {
+            return;
+        };
+
+        let (width, height) = crossterm::terminal::size().unwrap_or((120, 40));
+        let mut terminal = match Terminal::new(TestBackend::new(width, height)) {
+            Ok(terminal) => terminal,
+            Err(e) => {
+                log::warn!("Failed to capture the stage screenshot: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = terminal.draw(|frame| {
+            let _ = self.render_ratatui(frame);
+        }) {
+            log::warn!("Failed to capture the stage screenshot: {}", e);
+            return;
+        }
+
+        let buffer = terminal.backend().buffer();
+        let (stage, _) = session_manager.get_stage_info().unwrap_or_default();
+        session_manager.capture_stage_screenshot(
+            ScreenCapture::to_ansi(buffer),
+            ScreenCapture::to_html(buffer, &format!("gittype · Stage {}", stage)),
+        );
+    }
+
+    /// Act on a key, whether pressed by the player or the demo typist
+    fn apply_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        self.handle_countdown_logic();
+
+        let session_state = self.handle_key(key_event)?;
+
+        match session_state {
+            SessionState::Complete => {
+                self.capture_screenshot();
+                // Publish StageFinalized event
+                self.event_bus
+                    .as_event_bus()
+                    .publish(DomainEvent::StageFinalized);
+                // Publish NavigateTo event
+                self.event_bus
+                    .as_event_bus()
+                    .publish(NavigateTo::Replace(ScreenType::StageSummary));
+                Ok(())
+            }
+            SessionState::Exit => {
+                // Publish NavigateTo event
+                self.event_bus
+                    .as_event_bus()
+                    .publish(NavigateTo::PopTo(ScreenType::Title));
+                Ok(())
+            }
+            SessionState::Skip => {
+                // Publish StageSkipped event
+                self.event_bus
+                    .as_event_bus()
+                    .publish(DomainEvent::StageSkipped);
+                // Publish NavigateTo event
+                self.event_bus
+                    .as_event_bus()
+                    .publish(NavigateTo::Replace(ScreenType::StageSummary));
+                Ok(())
+            }
+            SessionState::Failed => {
+                if let (Some(session_manager), Some(challenge)) = (
+                    self.session_manager
+                        .as_any()
+                        .downcast_ref::<SessionManager>(),
+                    self.challenge.read().unwrap().clone(),
+                ) {
+                    session_manager.record_failed_challenge(challenge);
+                }
+                // Publish NavigateTo event
+                self.event_bus
+                    .as_event_bus()
+                    .publish(NavigateTo::Replace(ScreenType::SessionFailure));
+                Ok(())
+            }
+            SessionState::ShowDialog => Ok(()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Press the keys the demo typist has due, starting the stage first
+    fn play_demo(&self) -> Result<()> {
+        self.handle_countdown_logic();
+        if *self.dialog_shown.read().unwrap() {
+            return Ok(());
+        }
+        if *self.waiting_to_start.read().unwrap() {
+            return self.apply_key_event(KeyEvent::from(KeyCode::Char(' ')));
+        }
+        if self.countdown.read().unwrap().is_active() {
+            return Ok(());
+        }
+
+        loop {
+            let Some(expected) = self.typing_core.read().unwrap().current_char_to_type() else {
+                return Ok(());
+            };
+            let key = {
+                let mut demo_typist = self.demo_typist.write().unwrap();
+                let (typist, started_at) = demo_typist
+                    .get_or_insert_with(|| (DemoTypist::new(DemoMode::WPM), Instant::now()));
+                typist.next_key(started_at.elapsed(), expected)
+            };
+            let Some(key) = key else {
+                return Ok(());
+            };
+            let code = match key {
+                '\n' => KeyCode::Enter,
+                '\t' => KeyCode::Tab,
+                ch => KeyCode::Char(ch),
+            };
+            self.apply_key_event(KeyEvent::from(code))?;
+        }
+    }
+
+    /// A key pressed while the demo plays hands the title screen back to a person
+    fn stop_demo(&self, key_event: KeyEvent) -> Result<()> {
+        if matches!(key_event.kind, KeyEventKind::Press) {
+            DemoMode::set_playing(false);
+            self.event_bus
+                .as_event_bus()
+                .publish(NavigateTo::PopTo(ScreenType::Title));
+        }
+        Ok(())
+    }
+
     fn handle_countdown_logic(&self) {
         if !self.countdown.read().unwrap().is_active() {
             return;
@@ -446,11 +910,19 @@ impl shaku::Provider<crate::presentation::di::AppModule> for TypingScreenProvide
         let theme_service: Arc<dyn ThemeServiceInterface> = module.resolve();
         let repository_store: Arc<dyn RepositoryStoreInterface> = module.resolve();
         let session_manager: Arc<dyn SessionManagerInterface> = module.resolve();
+        let discord_presence_service: Arc<dyn DiscordPresenceServiceInterface> = module.resolve();
+        let challenge_flag_service: Arc<dyn ChallengeFlagServiceInterface> = module.resolve();
+        let config_service: Arc<dyn ConfigServiceInterface> = module.resolve();
+        let streamer_stats_service: Arc<dyn StreamerStatsServiceInterface> = module.resolve();
         Ok(Box::new(TypingScreen::new(
             event_bus,
             theme_service,
             repository_store,
             session_manager,
+            discord_presence_service,
+            challenge_flag_service,
+            config_service,
+            streamer_stats_service,
         )))
     }
 }
@@ -474,50 +946,51 @@ impl Screen for TypingScreen {
     }
 
     fn handle_key_event(&self, key_event: KeyEvent) -> Result<()> {
-        self.handle_countdown_logic();
+        if DemoMode::is_playing() {
+            return self.stop_demo(key_event);
+        }
+        self.apply_key_event(key_event)
+    }
 
-        let session_state = self.handle_key(key_event)?;
+    /// Pasted code would score as hundreds of instant keystrokes, so it is
+    /// dropped and the player told to type it
+    fn handle_paste(&self, text: &str) -> Result<()> {
+        log::info!(
+            "Ignored {} pasted characters on the typing screen",
+            text.chars().count()
+        );
+        *self.paste_rejected_at.write().unwrap() = Some(Instant::now());
+        if let Some(session_manager) = self
+            .session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+        {
+            session_manager.record_paste_attempt();
+        }
+        Ok(())
+    }
 
-        match session_state {
-            SessionState::Complete => {
-                // Publish StageFinalized event
-                self.event_bus
-                    .as_event_bus()
-                    .publish(DomainEvent::StageFinalized);
-                // Publish NavigateTo event
-                self.event_bus
-                    .as_event_bus()
-                    .publish(NavigateTo::Replace(ScreenType::StageSummary));
-                Ok(())
+    /// Losing focus mid-stage pauses it behind the dialog; regaining focus
+    /// resumes unless the dialog was opened by hand
+    fn handle_focus_change(&self, focused: bool) -> Result<()> {
+        if focused {
+            if *self.focus_paused.read().unwrap() {
+                self.close_dialog();
             }
-            SessionState::Exit => {
-                // Publish NavigateTo event
-                self.event_bus
-                    .as_event_bus()
-                    .publish(NavigateTo::PopTo(ScreenType::Title));
-                Ok(())
-            }
-            SessionState::Skip => {
-                // Publish StageSkipped event
-                self.event_bus
-                    .as_event_bus()
-                    .publish(DomainEvent::StageSkipped);
-                // Publish NavigateTo event
-                self.event_bus
-                    .as_event_bus()
-                    .publish(NavigateTo::Replace(ScreenType::StageSummary));
-                Ok(())
-            }
-            SessionState::Failed => {
-                // Publish NavigateTo event
-                self.event_bus
-                    .as_event_bus()
-                    .publish(NavigateTo::Replace(ScreenType::SessionFailure));
-                Ok(())
-            }
-            SessionState::ShowDialog => Ok(()),
-            _ => Ok(()),
+            return Ok(());
         }
+
+        let stage_running =
+            self.challenge.read().unwrap().is_some() && !*self.waiting_to_start.read().unwrap();
+        if stage_running
+            && !DemoMode::is_playing()
+            && !*self.dialog_shown.read().unwrap()
+            && self.config_service.get_config().input.pause_on_focus_loss
+        {
+            self.open_dialog();
+            *self.focus_paused.write().unwrap() = true;
+        }
+        Ok(())
     }
 
     fn render_ratatui(&self, frame: &mut ratatui::Frame) -> Result<()> {
@@ -552,9 +1025,22 @@ impl Screen for TypingScreen {
             self.countdown.read().unwrap().get_current_count(),
             skips_remaining,
             *self.dialog_shown.read().unwrap(),
+            self.is_big_wpm_shown(),
+            self.wide_layout,
+            &self.racers(),
             &self.session_manager,
             &colors,
         );
+        if self.is_paste_notice_shown() {
+            TypingPasteNoticeView::render(frame, &colors);
+        }
+        SpectatorBroadcast::publish_buffer(frame.buffer_mut());
+
+        let typing =
+            !*self.waiting_to_start.read().unwrap() && !self.countdown.read().unwrap().is_active();
+        if typing && !*self.next_stage_prepared.read().unwrap() {
+            self.prepare_next_stage(colors);
+        }
 
         Ok(())
     }
@@ -565,7 +1051,7 @@ impl Screen for TypingScreen {
                 interval: Duration::from_millis(50),
                 input_priority: true,
             }
-        } else if *self.waiting_to_start.read().unwrap() {
+        } else if *self.waiting_to_start.read().unwrap() && !DemoMode::is_playing() {
             UpdateStrategy::InputOnly
         } else {
             UpdateStrategy::Hybrid {
@@ -576,10 +1062,19 @@ impl Screen for TypingScreen {
     }
 
     fn update(&self) -> Result<bool> {
+        if DemoMode::is_playing() {
+            self.play_demo()?;
+        }
+        self.take_next_stage_layout();
+        self.update_presence();
+        self.update_streamer_stats();
+        self.update_window_title();
         Ok(true)
     }
 
     fn cleanup(&self) -> Result<()> {
+        self.discord_presence_service.clear();
+        WindowTitle::restore();
         Ok(())
     }
 
@@ -4,20 +4,9 @@ use crate::domain::services::theme_service::ThemeServiceInterface;
 use crate::presentation::tui::views::VersionCheckView;
 use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
 use crate::Result;
-use crossterm::{
-    event::{self, Event, KeyCode, KeyModifiers},
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-    ExecutableCommand,
-};
-use ratatui::{backend::CrosstermBackend, Terminal};
-use std::io::{stdout, Stdout};
+use crossterm::event::{self, KeyCode, KeyModifiers};
 use std::sync::{Arc, RwLock};
 
-pub enum VersionCheckResult {
-    Continue,
-    Exit,
-}
-
 pub trait VersionCheckScreenInterface: Screen {}
 
 #[derive(shaku::Component)]
@@ -45,58 +34,6 @@ impl VersionCheckScreen {
             latest_version: RwLock::new(String::new()),
         }
     }
-
-    pub fn show_legacy(current_version: &str, latest_version: &str) -> Result<VersionCheckResult> {
-        enable_raw_mode()?;
-        let mut stdout = stdout();
-        stdout.execute(EnterAlternateScreen)?;
-        let backend = CrosstermBackend::new(stdout);
-        let mut terminal = Terminal::new(backend)?;
-
-        let result = Self::run_notification_loop(&mut terminal, current_version, latest_version);
-
-        disable_raw_mode()?;
-        terminal.backend_mut().execute(LeaveAlternateScreen)?;
-
-        result
-    }
-
-    fn run_notification_loop(
-        terminal: &mut Terminal<CrosstermBackend<Stdout>>,
-        current_version: &str,
-        latest_version: &str,
-    ) -> Result<VersionCheckResult> {
-        // Use default theme for legacy mode
-        let default_theme_json = include_str!("../../../../assets/themes/default.json");
-        let theme_file: crate::domain::models::color_scheme::ThemeFile =
-            serde_json::from_str(default_theme_json).expect("Default theme should be valid JSON");
-        let color_scheme = crate::domain::models::color_scheme::ColorScheme::from_theme_file(
-            &theme_file,
-            &crate::domain::models::color_mode::ColorMode::Dark,
-        );
-        let colors = crate::presentation::ui::Colors::new(color_scheme);
-
-        loop {
-            terminal
-                .draw(|f| VersionCheckView::draw_ui(f, current_version, latest_version, &colors))?;
-
-            if let Ok(true) = event::poll(std::time::Duration::from_millis(50)) {
-                if let Ok(Event::Key(key_event)) = event::read() {
-                    match key_event.code {
-                        KeyCode::Char(' ') => {
-                            return Ok(VersionCheckResult::Continue);
-                        }
-                        KeyCode::Esc => {
-                            return Ok(VersionCheckResult::Exit);
-                        }
-                        _ => {
-                            // Ignore other keys
-                        }
-                    }
-                }
-            }
-        }
-    }
 }
 
 pub struct VersionCheckScreenDataProvider;
@@ -0,0 +1,289 @@
+use crate::domain::events::presentation_events::NavigateTo;
+use crate::domain::events::EventBusInterface;
+use crate::domain::models::{Changelog, ChangelogRelease};
+use crate::domain::services::config_service::{ConfigService, ConfigServiceInterface};
+use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
+use crate::presentation::ui::Colors;
+use crate::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{
+        Block, Borders, Padding, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap,
+    },
+    Frame,
+};
+use std::sync::{Arc, RwLock};
+
+const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub trait WhatsNewScreenInterface: Screen {}
+
+#[derive(shaku::Component)]
+#[shaku(interface = WhatsNewScreenInterface)]
+pub struct WhatsNewScreen {
+    #[shaku(default)]
+    release: RwLock<Option<ChangelogRelease>>,
+    #[shaku(default)]
+    scroll_position: RwLock<u16>,
+    #[shaku(default)]
+    viewport_height: RwLock<u16>,
+    #[shaku(default)]
+    content_height: RwLock<u16>,
+    #[shaku(inject)]
+    event_bus: Arc<dyn EventBusInterface>,
+    #[shaku(inject)]
+    theme_service: Arc<dyn ThemeServiceInterface>,
+    #[shaku(inject)]
+    config_service: Arc<dyn ConfigServiceInterface>,
+}
+
+impl WhatsNewScreen {
+    pub fn new(
+        event_bus: Arc<dyn EventBusInterface>,
+        theme_service: Arc<dyn ThemeServiceInterface>,
+        config_service: Arc<dyn ConfigServiceInterface>,
+    ) -> Self {
+        Self {
+            release: RwLock::new(None),
+            scroll_position: RwLock::new(0),
+            viewport_height: RwLock::new(0),
+            content_height: RwLock::new(0),
+            event_bus,
+            theme_service,
+            config_service,
+        }
+    }
+
+    /// This build's notes have not been shown since the player last updated
+    pub fn should_show_on_launch(&self) -> bool {
+        let config = self.config_service.get_config();
+        Changelog::bundled().has_unseen_release(
+            config.whats_new.last_seen_version.as_deref(),
+            CURRENT_VERSION,
+        )
+    }
+
+    /// Record the running version so its notes are not shown again
+    pub fn mark_seen(&self) {
+        if let Some(config_service) =
+            (self.config_service.as_ref() as &dyn std::any::Any).downcast_ref::<ConfigService>()
+        {
+            let _ = config_service.update_config(|config| {
+                config.whats_new.last_seen_version = Some(CURRENT_VERSION.to_string())
+            });
+            let _ = self.config_service.save();
+        }
+    }
+
+    pub fn scroll_position(&self) -> u16 {
+        *self.scroll_position.read().unwrap()
+    }
+
+    fn scroll(&self, down: bool) {
+        let max_scroll = self
+            .content_height
+            .read()
+            .unwrap()
+            .saturating_sub(*self.viewport_height.read().unwrap());
+        let mut scroll_position = self.scroll_position.write().unwrap();
+        *scroll_position = if down {
+            scroll_position.saturating_add(1).min(max_scroll)
+        } else {
+            scroll_position.saturating_sub(1)
+        };
+    }
+
+    fn release_content(release: &ChangelogRelease, colors: &Colors) -> Text<'static> {
+        let mut lines: Vec<Line> = Vec::new();
+        for section in release.sections.iter().filter(|s| !s.entries.is_empty()) {
+            if !lines.is_empty() {
+                lines.push(Line::from(""));
+            }
+            lines.push(Line::from(Span::styled(
+                section.title.clone(),
+                Style::default()
+                    .fg(colors.title())
+                    .add_modifier(Modifier::BOLD),
+            )));
+            lines.extend(section.entries.iter().map(|entry| {
+                Line::from(vec![
+                    Span::styled("  • ", Style::default().fg(colors.text_secondary())),
+                    Span::styled(entry.clone(), Style::default().fg(colors.text())),
+                ])
+            }));
+        }
+        Text::from(lines)
+    }
+
+    fn render_header(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let release = self.release.read().unwrap();
+        let mut spans = vec![Span::styled(
+            release
+                .as_ref()
+                .map_or("What's new in gittype".to_string(), |release| {
+                    format!("What's new in gittype v{}", release.version)
+                }),
+            Style::default()
+                .fg(colors.title())
+                .add_modifier(Modifier::BOLD),
+        )];
+        if let Some(date) = release.as_ref().and_then(|release| release.date.as_ref()) {
+            spans.push(Span::styled(
+                format!("  {}", date),
+                Style::default().fg(colors.text_secondary()),
+            ));
+        }
+
+        let header = Paragraph::new(Line::from(spans))
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(colors.border())),
+            );
+        frame.render_widget(header, area);
+    }
+
+    fn render_content(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let content = self
+            .release
+            .read()
+            .unwrap()
+            .as_ref()
+            .map(|release| Self::release_content(release, colors))
+            .unwrap_or_else(|| {
+                Text::from(Span::styled(
+                    "No release notes are bundled with this build.",
+                    Style::default().fg(colors.text_secondary()),
+                ))
+            });
+
+        let viewport_height = area.height.saturating_sub(2);
+        let content_height = content.lines.len() as u16;
+        *self.viewport_height.write().unwrap() = viewport_height;
+        *self.content_height.write().unwrap() = content_height;
+
+        let scroll_position = self.scroll_position();
+        let paragraph = Paragraph::new(content)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(colors.border()))
+                    .padding(Padding::horizontal(2)),
+            )
+            .wrap(Wrap { trim: false })
+            .scroll((scroll_position, 0));
+        frame.render_widget(paragraph, area);
+
+        if content_height > viewport_height {
+            let mut scrollbar_state =
+                ScrollbarState::new(content_height.saturating_sub(viewport_height) as usize)
+                    .position(scroll_position as usize);
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight),
+                area.inner(Margin {
+                    vertical: 1,
+                    horizontal: 0,
+                }),
+                &mut scrollbar_state,
+            );
+        }
+    }
+
+    fn render_footer(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let controls = Paragraph::new(Line::from(vec![
+            Span::styled("[↑↓/JK]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Scroll  ", Style::default().fg(colors.text())),
+            Span::styled("[ENTER/ESC]", Style::default().fg(colors.key_action())),
+            Span::styled(" Continue", Style::default().fg(colors.text())),
+        ]))
+        .alignment(Alignment::Center);
+        frame.render_widget(controls, area);
+    }
+}
+
+pub struct WhatsNewScreenDataProvider;
+
+impl ScreenDataProvider for WhatsNewScreenDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(
+            Changelog::bundled().release(CURRENT_VERSION).cloned(),
+        ))
+    }
+}
+
+impl Screen for WhatsNewScreen {
+    fn get_type(&self) -> ScreenType {
+        ScreenType::WhatsNew
+    }
+
+    fn default_provider() -> Box<dyn ScreenDataProvider>
+    where
+        Self: Sized,
+    {
+        Box::new(WhatsNewScreenDataProvider)
+    }
+
+    fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
+        if let Ok(release) = data.downcast::<Option<ChangelogRelease>>() {
+            *self.release.write().unwrap() = *release;
+        }
+        *self.scroll_position.write().unwrap() = 0;
+        Ok(())
+    }
+
+    fn on_pushed_from(&self, _source_screen: &dyn Screen) -> Result<()> {
+        self.mark_seen();
+        Ok(())
+    }
+
+    fn handle_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        match key_event.code {
+            KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.scroll(false),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll(true),
+            KeyCode::Enter | KeyCode::Esc => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Pop);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn render_ratatui(&self, frame: &mut Frame) -> Result<()> {
+        let colors = self.theme_service.get_colors();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(frame.area());
+
+        self.render_header(frame, chunks[0], &colors);
+        self.render_content(frame, chunks[1], &colors);
+        self.render_footer(frame, chunks[2], &colors);
+        Ok(())
+    }
+
+    fn get_update_strategy(&self) -> UpdateStrategy {
+        UpdateStrategy::InputOnly
+    }
+
+    fn update(&self) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl WhatsNewScreenInterface for WhatsNewScreen {}
@@ -0,0 +1,285 @@
+use crate::domain::events::presentation_events::NavigateTo;
+use crate::domain::events::EventBusInterface;
+use crate::domain::models::WorkspacePackage;
+use crate::domain::services::theme_service::ThemeServiceInterface;
+use crate::domain::services::workspace_detector::WorkspaceDetector;
+use crate::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
+use crate::presentation::ui::Colors;
+use crate::Result;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph},
+    Frame,
+};
+use std::sync::{Arc, RwLock};
+
+pub struct WorkspacePackageScreenData {
+    pub repository: String,
+    pub packages: Vec<WorkspacePackage>,
+}
+
+/// What the package picker was closed with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackageChoice {
+    WholeRepository,
+    Package(WorkspacePackage),
+}
+
+pub trait WorkspacePackageScreenInterface: Screen {}
+
+/// Asks which package of a workspace to extract challenges from; the first
+/// row keeps the whole repository
+#[derive(shaku::Component)]
+#[shaku(interface = WorkspacePackageScreenInterface)]
+pub struct WorkspacePackageScreen {
+    #[shaku(default)]
+    repository: RwLock<String>,
+    #[shaku(default)]
+    packages: RwLock<Vec<WorkspacePackage>>,
+    #[shaku(default)]
+    list_state: RwLock<ListState>,
+    #[shaku(default)]
+    choice: RwLock<Option<PackageChoice>>,
+    #[shaku(inject)]
+    event_bus: Arc<dyn EventBusInterface>,
+    #[shaku(inject)]
+    theme_service: Arc<dyn ThemeServiceInterface>,
+}
+
+impl WorkspacePackageScreen {
+    pub fn new(
+        event_bus: Arc<dyn EventBusInterface>,
+        theme_service: Arc<dyn ThemeServiceInterface>,
+    ) -> Self {
+        Self {
+            repository: RwLock::new(String::new()),
+            packages: RwLock::new(Vec::new()),
+            list_state: RwLock::new(ListState::default()),
+            choice: RwLock::new(None),
+            event_bus,
+            theme_service,
+        }
+    }
+
+    pub fn packages(&self) -> Vec<WorkspacePackage> {
+        self.packages.read().unwrap().clone()
+    }
+
+    /// `None` until a row is confirmed, and after closing with Esc
+    pub fn get_choice(&self) -> Option<PackageChoice> {
+        self.choice.read().unwrap().clone()
+    }
+
+    /// The row under the cursor, as it would be chosen
+    pub fn highlighted(&self) -> Option<PackageChoice> {
+        let selected = self.list_state.read().unwrap().selected()?;
+        match selected {
+            0 => Some(PackageChoice::WholeRepository),
+            row => self
+                .packages
+                .read()
+                .unwrap()
+                .get(row - 1)
+                .cloned()
+                .map(PackageChoice::Package),
+        }
+    }
+
+    fn move_selection(&self, forward: bool) {
+        let rows = self.packages.read().unwrap().len() + 1;
+        let mut list_state = self.list_state.write().unwrap();
+        let selected = list_state.selected().unwrap_or(0);
+        list_state.select(Some(if forward {
+            (selected + 1).min(rows - 1)
+        } else {
+            selected.saturating_sub(1)
+        }));
+    }
+
+    fn confirm(&self) {
+        *self.choice.write().unwrap() = self.highlighted();
+        self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+    }
+
+    fn render_header(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let header = Paragraph::new(Line::from(vec![
+            Span::styled(
+                "Pick a Package  ",
+                Style::default()
+                    .fg(colors.title())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                self.repository.read().unwrap().clone(),
+                Style::default().fg(colors.text_secondary()),
+            ),
+        ]))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border())),
+        );
+        frame.render_widget(header, area);
+    }
+
+    fn render_content(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let packages = self.packages();
+        let name_width = packages
+            .iter()
+            .map(|package| package.name.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(16);
+
+        let whole_repository = ListItem::new(Line::from(vec![
+            Span::styled(
+                format!("{:<width$}", "All packages", width = name_width),
+                Style::default()
+                    .fg(colors.text())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "  the whole repository",
+                Style::default().fg(colors.text_secondary()),
+            ),
+        ]));
+        let items: Vec<ListItem> = std::iter::once(whole_repository)
+            .chain(packages.iter().map(|package| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<width$}", package.name, width = name_width),
+                        Style::default()
+                            .fg(colors.text())
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("  {:<6}", package.kind.name()),
+                        Style::default().fg(colors.info()),
+                    ),
+                    Span::styled(
+                        package.path.display().to_string(),
+                        Style::default().fg(colors.text_secondary()),
+                    ),
+                ]))
+            }))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(format!(" {} packages ", packages.len()))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(colors.border()))
+                    .padding(Padding::horizontal(1)),
+            )
+            .highlight_style(Style::default().bg(colors.background_secondary()))
+            .highlight_symbol("▶ ");
+        frame.render_stateful_widget(list, area, &mut self.list_state.write().unwrap());
+    }
+
+    fn render_footer(&self, frame: &mut Frame, area: Rect, colors: &Colors) {
+        let controls = Paragraph::new(Line::from(vec![
+            Span::styled("[↑↓/JK]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Select  ", Style::default().fg(colors.text())),
+            Span::styled("[ENTER]", Style::default().fg(colors.key_action())),
+            Span::styled(" Practice  ", Style::default().fg(colors.text())),
+            Span::styled("[ESC]", Style::default().fg(colors.key_back())),
+            Span::styled(" Quit", Style::default().fg(colors.text())),
+        ]))
+        .alignment(Alignment::Center);
+        frame.render_widget(controls, area);
+    }
+}
+
+/// Packages of the workspace in the current directory
+pub struct WorkspacePackageScreenDataProvider;
+
+impl ScreenDataProvider for WorkspacePackageScreenDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        let root = std::env::current_dir()?;
+        Ok(Box::new(WorkspacePackageScreenData {
+            repository: root
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            packages: WorkspaceDetector::detect(&root),
+        }))
+    }
+}
+
+impl Screen for WorkspacePackageScreen {
+    fn get_type(&self) -> ScreenType {
+        ScreenType::WorkspacePackages
+    }
+
+    fn default_provider() -> Box<dyn ScreenDataProvider>
+    where
+        Self: Sized,
+    {
+        Box::new(WorkspacePackageScreenDataProvider)
+    }
+
+    fn init_with_data(&self, data: Box<dyn std::any::Any>) -> Result<()> {
+        let data = data.downcast::<WorkspacePackageScreenData>()?;
+        *self.repository.write().unwrap() = data.repository;
+        *self.packages.write().unwrap() = data.packages;
+        *self.list_state.write().unwrap() = ListState::default().with_selected(Some(0));
+        *self.choice.write().unwrap() = None;
+        Ok(())
+    }
+
+    fn handle_key_event(&self, key_event: KeyEvent) -> Result<()> {
+        if key_event.kind != KeyEventKind::Press {
+            return Ok(());
+        }
+
+        match key_event.code {
+            KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(false),
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(true),
+            KeyCode::Enter | KeyCode::Char(' ') => self.confirm(),
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.event_bus.as_event_bus().publish(NavigateTo::Exit);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn render_ratatui(&self, frame: &mut Frame) -> Result<()> {
+        let colors = self.theme_service.get_colors();
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(frame.area());
+
+        self.render_header(frame, chunks[0], &colors);
+        self.render_content(frame, chunks[1], &colors);
+        self.render_footer(frame, chunks[2], &colors);
+        Ok(())
+    }
+
+    fn get_update_strategy(&self) -> UpdateStrategy {
+        UpdateStrategy::InputOnly
+    }
+
+    fn update(&self) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl WorkspacePackageScreenInterface for WorkspacePackageScreen {}
@@ -0,0 +1,119 @@
+use crate::domain::models::{Finger, Locale};
+use crate::domain::services::analytics_service::AnalyticsData;
+use crate::presentation::ui::Colors;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+pub struct FingersView;
+
+impl FingersView {
+    /// Width of the load bar of the busiest finger
+    const BAR_WIDTH: usize = 30;
+    /// Fingers with fewer keystrokes are not named as the weakest
+    const MIN_KEYSTROKES_FOR_WEAKEST: usize = 20;
+
+    pub fn render(f: &mut Frame, area: Rect, data: &AnalyticsData, colors: &Colors) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.border()))
+            .title("Finger Load (US QWERTY)");
+        let load = &data.finger_load;
+
+        if load.is_empty() {
+            let empty_msg = Paragraph::new(vec![
+                Line::from(""),
+                Line::from(vec![
+                    Span::raw("  "),
+                    Span::raw(
+                        "No finger data yet - play a session to see which fingers do the work!",
+                    ),
+                ]),
+            ])
+            .alignment(Alignment::Left)
+            .block(block);
+            f.render_widget(empty_msg, area);
+            return;
+        }
+
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!(
+                    "  {:<14}{:>10}  {:<width$}  {:>6}  {:>7}",
+                    "Finger",
+                    "Keys",
+                    "Load",
+                    "",
+                    "Errors",
+                    width = Self::BAR_WIDTH
+                ),
+                Style::default()
+                    .fg(colors.text_secondary())
+                    .add_modifier(Modifier::BOLD),
+            )),
+        ];
+
+        let busiest = Finger::all()
+            .iter()
+            .map(|finger| load.stats(*finger).keystrokes)
+            .max()
+            .unwrap_or(1);
+        lines.extend(Finger::all().iter().map(|finger| {
+            let stats = load.stats(*finger);
+            let percentage = load.load_percentage(*finger);
+            let bar_length = stats.keystrokes * Self::BAR_WIDTH / busiest;
+            Line::from(vec![
+                Span::styled(
+                    format!("  {:<14}", finger.label()),
+                    Style::default().fg(colors.stage_info()),
+                ),
+                Span::styled(
+                    format!("{:>10}  ", Locale::current().format_count(stats.keystrokes)),
+                    Style::default().fg(colors.text()),
+                ),
+                Span::styled(
+                    format!(
+                        "{:<width$}",
+                        "█".repeat(bar_length),
+                        width = Self::BAR_WIDTH
+                    ),
+                    Style::default().fg(colors.cpm_wpm()),
+                ),
+                Span::styled(
+                    format!("  {:>5}%", Locale::current().format_decimal(percentage, 1)),
+                    Style::default().fg(colors.text()),
+                ),
+                Span::styled(
+                    format!(
+                        "  {:>6}%",
+                        Locale::current().format_decimal(stats.error_rate(), 1)
+                    ),
+                    Style::default().fg(colors.error()),
+                ),
+            ])
+        }));
+
+        if let Some(&finger) = load.weakest(Self::MIN_KEYSTROKES_FOR_WEAKEST, 1).first() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled("Most error-prone: ", Style::default().fg(colors.warning())),
+                Span::styled(
+                    format!(
+                        "{} ({}% of its keys mistyped)",
+                        finger.label(),
+                        Locale::current().format_decimal(load.stats(finger).error_rate(), 1)
+                    ),
+                    Style::default().fg(colors.text()),
+                ),
+            ]));
+        }
+
+        f.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::domain::models::Languages;
+use crate::domain::models::{Languages, Locale, WpmMode};
 use crate::domain::services::analytics_service::AnalyticsData;
 use crate::presentation::ui::Colors;
 use ratatui::{
@@ -143,15 +143,18 @@ impl LanguagesView {
                         Span::raw("    "),
                         Span::styled("• Average CPM: ", Style::default().fg(colors.cpm_wpm())),
                         Span::styled(
-                            format!("{:.1}", stats.avg_cpm),
+                            Locale::current().format_decimal(stats.avg_cpm, 1),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
                     Line::from(vec![
                         Span::raw("    "),
-                        Span::styled("• Average WPM: ", Style::default().fg(colors.cpm_wpm())),
                         Span::styled(
-                            format!("{:.1}", stats.avg_wpm),
+                            format!("• Average {}: ", WpmMode::current().label()),
+                            Style::default().fg(colors.cpm_wpm()),
+                        ),
+                        Span::styled(
+                            Locale::current().format_decimal(stats.avg_wpm, 1),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
@@ -159,7 +162,7 @@ impl LanguagesView {
                         Span::raw("    "),
                         Span::styled("• Best CPM: ", Style::default().fg(colors.cpm_wpm())),
                         Span::styled(
-                            format!("{:.1}", stats.best_cpm),
+                            Locale::current().format_decimal(stats.best_cpm, 1),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
@@ -180,7 +183,10 @@ impl LanguagesView {
                             Style::default().fg(colors.accuracy()),
                         ),
                         Span::styled(
-                            format!("{:.1}%", stats.avg_accuracy),
+                            format!(
+                                "{}%",
+                                Locale::current().format_decimal(stats.avg_accuracy, 1)
+                            ),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
@@ -188,7 +194,10 @@ impl LanguagesView {
                         Span::raw("    "),
                         Span::styled("• Best Accuracy: ", Style::default().fg(colors.accuracy())),
                         Span::styled(
-                            format!("{:.1}%", stats.best_accuracy),
+                            format!(
+                                "{}%",
+                                Locale::current().format_decimal(stats.best_accuracy, 1)
+                            ),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
@@ -228,7 +237,7 @@ impl LanguagesView {
                             Style::default().fg(colors.stage_info()),
                         ),
                         Span::styled(
-                            format!("{}", stats.total_keystrokes),
+                            Locale::current().format_count(stats.total_keystrokes),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
@@ -258,7 +267,7 @@ impl LanguagesView {
                         Span::raw("    "),
                         Span::styled("• Average Score: ", Style::default().fg(colors.score())),
                         Span::styled(
-                            format!("{:.0}", stats.avg_score),
+                            Locale::current().format_decimal(stats.avg_score, 0),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
@@ -280,7 +289,7 @@ impl LanguagesView {
                         Span::raw("  "),
                         Span::styled("• Average CPM: ", Style::default().fg(colors.text())),
                         Span::styled(
-                            format!("{:.1}", lang_data.1),
+                            Locale::current().format_decimal(lang_data.1, 1),
                             Style::default().fg(colors.cpm_wpm()),
                         ),
                     ]),
@@ -288,7 +297,7 @@ impl LanguagesView {
                         Span::raw("  "),
                         Span::styled("• WPM Equivalent: ", Style::default().fg(colors.text())),
                         Span::styled(
-                            format!("{:.1}", lang_data.1 / 5.0),
+                            Locale::current().format_decimal(lang_data.1 / 5.0, 1),
                             Style::default().fg(colors.cpm_wpm()),
                         ),
                     ]),
@@ -305,6 +314,12 @@ impl LanguagesView {
                     ]),
                 ]);
             }
+            lines.extend(Self::calibration_lines(
+                data,
+                lang_name,
+                detailed_stats.map(|stats| stats.avg_wpm),
+                colors,
+            ));
 
             lines
         } else {
@@ -335,4 +350,59 @@ impl LanguagesView {
             );
         f.render_widget(details, area);
     }
+
+    /// Pace factor of the language against the player's overall pace, and
+    /// the average speed it normalizes to
+    fn calibration_lines(
+        data: &AnalyticsData,
+        lang_name: &str,
+        avg_wpm: Option<f64>,
+        colors: &Colors,
+    ) -> Vec<Line<'static>> {
+        let calibration = data.language_calibrations.for_language(lang_name);
+        let source = if calibration.is_measured() {
+            format!("({} stages)", calibration.stages)
+        } else {
+            "(default)".to_string()
+        };
+
+        let mut lines = vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled(
+                    "🧭 Calibration:",
+                    Style::default()
+                        .fg(colors.text())
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                Span::raw("    "),
+                Span::styled("• Pace Factor: ", Style::default().fg(colors.cpm_wpm())),
+                Span::styled(
+                    format!(
+                        "×{} {}",
+                        Locale::current().format_decimal(calibration.factor, 2),
+                        source
+                    ),
+                    Style::default().fg(colors.text()),
+                ),
+            ]),
+        ];
+        if let Some(avg_wpm) = avg_wpm {
+            lines.push(Line::from(vec![
+                Span::raw("    "),
+                Span::styled(
+                    format!("• Normalized {}: ", WpmMode::current().label()),
+                    Style::default().fg(colors.cpm_wpm()),
+                ),
+                Span::styled(
+                    Locale::current().format_decimal(calibration.normalize(avg_wpm), 1),
+                    Style::default().fg(colors.text()),
+                ),
+            ]));
+        }
+        lines
+    }
 }
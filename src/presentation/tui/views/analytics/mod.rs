@@ -1,8 +1,10 @@
+pub mod fingers_view;
 pub mod languages_view;
 pub mod overview_view;
 pub mod repositories_view;
 pub mod trends_view;
 
+pub use fingers_view::FingersView;
 pub use languages_view::LanguagesView;
 pub use overview_view::OverviewView;
 pub use repositories_view::RepositoriesView;
@@ -1,6 +1,6 @@
-use crate::domain::models::Languages;
+use crate::domain::models::{Languages, Locale};
 use crate::domain::services::analytics_service::AnalyticsData;
-use crate::presentation::ui::Colors;
+use crate::presentation::ui::{Colors, SparklineWidget};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
@@ -12,11 +12,14 @@ use ratatui::{
 pub struct OverviewView;
 
 impl OverviewView {
+    /// Columns of the CPM trend sparkline; longer trends are averaged down
+    const TREND_WIDTH: usize = 30;
+
     pub fn render(f: &mut Frame, area: Rect, data: &AnalyticsData, colors: &Colors) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(5), // Stats summary
+                Constraint::Length(6), // Stats summary
                 Constraint::Min(3),    // Chart area
                 Constraint::Length(8), // Top repositories and languages
             ])
@@ -28,8 +31,8 @@ impl OverviewView {
     }
 
     fn render_overview_stats(f: &mut Frame, area: Rect, data: &AnalyticsData, colors: &Colors) {
-        // Overview stats (two lines)
-        let overview_text = vec![
+        // Overview stats (two lines, plus the prose baseline once imported)
+        let mut overview_text = vec![
             Line::from(vec![
                 Span::raw("  "), // Left padding
                 Span::styled("Sessions: ", Style::default().fg(colors.stage_info())),
@@ -40,19 +43,22 @@ impl OverviewView {
                 Span::raw("  │  "),
                 Span::styled("Avg CPM: ", Style::default().fg(colors.cpm_wpm())),
                 Span::styled(
-                    format!("{:.1}", data.avg_cpm),
+                    Locale::current().format_decimal(data.avg_cpm, 1),
                     Style::default().fg(colors.text()),
                 ),
                 Span::raw("  │  "),
                 Span::styled("Best CPM: ", Style::default().fg(colors.cpm_wpm())),
                 Span::styled(
-                    format!("{:.1}", data.best_cpm),
+                    Locale::current().format_decimal(data.best_cpm, 1),
                     Style::default().fg(colors.text()),
                 ),
                 Span::raw("  │  "),
                 Span::styled("Avg Accuracy: ", Style::default().fg(colors.accuracy())),
                 Span::styled(
-                    format!("{:.1}%", data.avg_accuracy),
+                    format!(
+                        "{}%",
+                        Locale::current().format_decimal(data.avg_accuracy, 1)
+                    ),
                     Style::default().fg(colors.text()),
                 ),
             ]),
@@ -82,6 +88,8 @@ impl OverviewView {
                 ),
             ]),
         ];
+        overview_text.extend(Self::trend_line(data, colors));
+        overview_text.extend(Self::baseline_line(data, colors));
 
         let overview = Paragraph::new(overview_text)
             .alignment(Alignment::Center)
@@ -94,6 +102,64 @@ impl OverviewView {
         f.render_widget(overview, area);
     }
 
+    /// Daily CPM as a sparkline, once there are two days to compare
+    fn trend_line(data: &AnalyticsData, colors: &Colors) -> Option<Line<'static>> {
+        if data.cpm_trend.len() < 2 {
+            return None;
+        }
+        let cpm: Vec<f64> = data.cpm_trend.iter().map(|(_, cpm)| *cpm).collect();
+        Some(Line::from(vec![
+            Span::styled("CPM Trend: ", Style::default().fg(colors.cpm_wpm())),
+            SparklineWidget::new(&cpm)
+                .style(Style::default().fg(colors.cpm_wpm()))
+                .span(Self::TREND_WIDTH),
+            Span::styled(
+                format!(" ({} days)", cpm.len()),
+                Style::default().fg(colors.text_secondary()),
+            ),
+        ]))
+    }
+
+    /// Code vs prose speed, shown once monkeytype/keybr results have been imported
+    fn baseline_line(data: &AnalyticsData, colors: &Colors) -> Option<Line<'static>> {
+        let baseline = data.prose_baseline.as_ref()?;
+        let code_wpm = data.avg_cpm / 5.0;
+        let sources = baseline
+            .sources
+            .iter()
+            .map(|source| source.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut spans = vec![
+            Span::styled("Prose Baseline: ", Style::default().fg(colors.cpm_wpm())),
+            Span::styled(
+                format!(
+                    "{:.1} WPM ({}, {} tests)",
+                    baseline.avg_wpm, sources, baseline.tests
+                ),
+                Style::default().fg(colors.text()),
+            ),
+            Span::raw("  │  "),
+            Span::styled("Code WPM: ", Style::default().fg(colors.cpm_wpm())),
+            Span::styled(
+                Locale::current().format_decimal(code_wpm, 1),
+                Style::default().fg(colors.text()),
+            ),
+        ];
+        if let Some(ratio) = baseline.code_to_prose_ratio(code_wpm) {
+            spans.extend([
+                Span::raw("  │  "),
+                Span::styled("Code vs Prose: ", Style::default().fg(colors.accuracy())),
+                Span::styled(
+                    format!("{}%", Locale::current().format_decimal(ratio, 0)),
+                    Style::default().fg(colors.text()),
+                ),
+            ]);
+        }
+        Some(Line::from(spans))
+    }
+
     fn render_simple_chart(f: &mut Frame, area: Rect, data: &AnalyticsData, colors: &Colors) {
         if data.daily_sessions.is_empty() {
             let empty_msg = Paragraph::new(vec![
@@ -1,3 +1,4 @@
+use crate::domain::models::{Locale, WpmMode};
 use crate::domain::services::analytics_service::AnalyticsData;
 use crate::presentation::ui::Colors;
 use ratatui::{
@@ -139,15 +140,18 @@ impl RepositoriesView {
                         Span::raw("    "),
                         Span::styled("• Average CPM: ", Style::default().fg(colors.cpm_wpm())),
                         Span::styled(
-                            format!("{:.1}", stats.avg_cpm),
+                            Locale::current().format_decimal(stats.avg_cpm, 1),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
                     Line::from(vec![
                         Span::raw("    "),
-                        Span::styled("• Average WPM: ", Style::default().fg(colors.cpm_wpm())),
                         Span::styled(
-                            format!("{:.1}", stats.avg_wpm),
+                            format!("• Average {}: ", WpmMode::current().label()),
+                            Style::default().fg(colors.cpm_wpm()),
+                        ),
+                        Span::styled(
+                            Locale::current().format_decimal(stats.avg_wpm, 1),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
@@ -155,7 +159,7 @@ impl RepositoriesView {
                         Span::raw("    "),
                         Span::styled("• Best CPM: ", Style::default().fg(colors.cpm_wpm())),
                         Span::styled(
-                            format!("{:.1}", stats.best_cpm),
+                            Locale::current().format_decimal(stats.best_cpm, 1),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
@@ -176,7 +180,10 @@ impl RepositoriesView {
                             Style::default().fg(colors.accuracy()),
                         ),
                         Span::styled(
-                            format!("{:.1}%", stats.avg_accuracy),
+                            format!(
+                                "{}%",
+                                Locale::current().format_decimal(stats.avg_accuracy, 1)
+                            ),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
@@ -184,7 +191,10 @@ impl RepositoriesView {
                         Span::raw("    "),
                         Span::styled("• Best Accuracy: ", Style::default().fg(colors.accuracy())),
                         Span::styled(
-                            format!("{:.1}%", stats.best_accuracy),
+                            format!(
+                                "{}%",
+                                Locale::current().format_decimal(stats.best_accuracy, 1)
+                            ),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
@@ -224,7 +234,7 @@ impl RepositoriesView {
                             Style::default().fg(colors.stage_info()),
                         ),
                         Span::styled(
-                            format!("{}", stats.total_keystrokes),
+                            Locale::current().format_count(stats.total_keystrokes),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
@@ -254,7 +264,7 @@ impl RepositoriesView {
                         Span::raw("    "),
                         Span::styled("• Average Score: ", Style::default().fg(colors.score())),
                         Span::styled(
-                            format!("{:.0}", stats.avg_score),
+                            Locale::current().format_decimal(stats.avg_score, 0),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
@@ -276,7 +286,7 @@ impl RepositoriesView {
                         Span::raw("  "),
                         Span::styled("• Average CPM: ", Style::default().fg(colors.cpm_wpm())),
                         Span::styled(
-                            format!("{:.1}", repo_data.1),
+                            Locale::current().format_decimal(repo_data.1, 1),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
@@ -284,7 +294,7 @@ impl RepositoriesView {
                         Span::raw("  "),
                         Span::styled("• WPM Equivalent: ", Style::default().fg(colors.cpm_wpm())),
                         Span::styled(
-                            format!("{:.1}", repo_data.1 / 5.0),
+                            Locale::current().format_decimal(repo_data.1 / 5.0, 1),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
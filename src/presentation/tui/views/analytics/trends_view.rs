@@ -1,3 +1,4 @@
+use crate::domain::models::Locale;
 use crate::domain::services::analytics_service::AnalyticsData;
 use crate::presentation::ui::Colors;
 use ratatui::{
@@ -104,15 +105,15 @@ impl TrendsView {
                     .bounds([min_cpm - cpm_range * 0.1, max_cpm + cpm_range * 0.1])
                     .labels(vec![
                         Span::styled(
-                            format!("{:.0}", min_cpm),
+                            Locale::current().format_decimal(min_cpm, 0),
                             Style::default().fg(colors.text()),
                         ),
                         Span::styled(
-                            format!("{:.0}", (min_cpm + max_cpm) / 2.0),
+                            Locale::current().format_decimal((min_cpm + max_cpm) / 2.0, 0),
                             Style::default().fg(colors.text()),
                         ),
                         Span::styled(
-                            format!("{:.0}", max_cpm),
+                            Locale::current().format_decimal(max_cpm, 0),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
@@ -202,15 +203,19 @@ impl TrendsView {
                     ])
                     .labels(vec![
                         Span::styled(
-                            format!("{:.1}%", min_accuracy),
+                            format!("{}%", Locale::current().format_decimal(min_accuracy, 1)),
                             Style::default().fg(colors.text()),
                         ),
                         Span::styled(
-                            format!("{:.1}%", (min_accuracy + max_accuracy) / 2.0),
+                            format!(
+                                "{}%",
+                                Locale::current()
+                                    .format_decimal((min_accuracy + max_accuracy) / 2.0, 1)
+                            ),
                             Style::default().fg(colors.text()),
                         ),
                         Span::styled(
-                            format!("{:.1}%", max_accuracy),
+                            format!("{}%", Locale::current().format_decimal(max_accuracy, 1)),
                             Style::default().fg(colors.text()),
                         ),
                     ]),
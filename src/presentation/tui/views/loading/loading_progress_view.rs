@@ -10,7 +10,7 @@ use ratatui::{
 };
 use std::sync::atomic::Ordering;
 
-const SPINNER_CHARS: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+pub const SPINNER_CHARS: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
 
 pub struct LoadingProgressView;
 
@@ -62,6 +62,10 @@ impl LoadingProgressView {
         // Get spinner character
         let spinner_index = state.spinner_index.load(Ordering::Relaxed);
         let spinner = SPINNER_CHARS[spinner_index % SPINNER_CHARS.len()];
+        let spinner = match state.sub_step.read().ok().and_then(|label| label.clone()) {
+            Some(label) => format!("{} {}", spinner, label),
+            None => spinner.to_string(),
+        };
 
         let progress_text = if total_files > 0 {
             let unit = match current_step_type {
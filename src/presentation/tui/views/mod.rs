@@ -17,7 +17,9 @@ pub mod typing;
 pub mod version_check;
 
 pub use loading::LoadingMainView;
-pub use session_detail::{PerformanceMetricsView, SessionInfoView, StageDetailsView};
+pub use session_detail::{
+    MistakeBreakdownView, PerformanceMetricsView, SessionInfoView, StageDetailsView,
+};
 pub use session_detail_dialog::{BestRecordsView, ControlsView, HeaderView, StageResultsView};
 pub use session_summary::{
     HeaderView as SessionSummaryHeaderView, OptionsView, RankView, ScoreView, SummaryView,
@@ -26,7 +28,7 @@ pub use session_summary_share_screen::{
     BackOptionView as ShareBackOptionView, PlatformOptionsView as SharePlatformOptionsView,
     PreviewView as SharePreviewView, TitleView as ShareTitleView,
 };
-pub use stage_summary::StageCompletionView;
+pub use stage_summary::{SourceStudyView, StageCompletionView};
 pub use total_summary::{AsciiScoreView, StatisticsView};
 pub use total_summary_share::SharingView;
 pub use typing::typing_animation_view::TypingAnimationView;
@@ -35,5 +37,6 @@ pub use typing::typing_countdown_view::TypingCountdownView;
 pub use typing::typing_dialog_view::TypingDialogView;
 pub use typing::typing_footer_view::TypingFooterView;
 pub use typing::typing_header_view::TypingHeaderView;
+pub use typing::typing_paste_notice_view::TypingPasteNoticeView;
 pub use typing::typing_view::TypingView;
 pub use version_check::VersionCheckView;
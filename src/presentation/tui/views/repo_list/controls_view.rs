@@ -12,6 +12,10 @@ pub struct ControlsView;
 impl ControlsView {
     pub fn render(frame: &mut Frame, area: Rect, colors: &Colors) {
         let controls_line = Line::from(vec![
+            Span::styled("[↑↓/JK]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Navigate  ", Style::default().fg(colors.text())),
+            Span::styled("[ENTER]", Style::default().fg(colors.key_action())),
+            Span::styled(" Details  ", Style::default().fg(colors.text())),
             Span::styled("[ESC]", Style::default().fg(colors.key_back())),
             Span::styled(" Return", Style::default().fg(colors.text())),
         ]);
@@ -5,7 +5,7 @@ use ratatui::{
     layout::Rect,
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Padding},
+    widgets::{Block, Borders, List, ListItem, ListState, Padding},
     Frame,
 };
 
@@ -16,6 +16,7 @@ impl RepositoryListView {
         frame: &mut Frame,
         area: Rect,
         repositories: &[(StoredRepositoryWithLanguages, bool)],
+        list_state: &mut ListState,
         colors: &Colors,
     ) {
         let repo_width = 35;
@@ -89,6 +90,18 @@ impl RepositoryListView {
                 }
 
                 line_spans.push(Span::styled(" ", Style::default()));
+                if let Some(metadata) = &repo.metadata {
+                    line_spans.push(Span::styled(
+                        format!("{} ", metadata.stars_label()),
+                        Style::default().fg(colors.warning()),
+                    ));
+                }
+                if let Some(rating) = &repo.rating {
+                    line_spans.push(Span::styled(
+                        format!("{} ", rating.label()),
+                        Style::default().fg(colors.accuracy()),
+                    ));
+                }
                 line_spans.push(Span::styled(
                     repo.http_url(),
                     Style::default().fg(colors.text_secondary()),
@@ -111,7 +124,12 @@ impl RepositoryListView {
                     )
                     .padding(Padding::horizontal(1)),
             )
-            .style(Style::default().fg(colors.text()));
-        frame.render_widget(list, area);
+            .style(Style::default().fg(colors.text()))
+            .highlight_style(
+                Style::default()
+                    .bg(colors.background_secondary())
+                    .add_modifier(Modifier::BOLD),
+            );
+        frame.render_stateful_widget(list, area, list_state);
     }
 }
@@ -10,12 +10,20 @@ use ratatui::{
 pub struct ControlsView;
 
 impl ControlsView {
-    pub fn render(frame: &mut Frame, area: Rect, colors: &Colors) {
+    pub fn render(frame: &mut Frame, area: Rect, showing_starred: bool, colors: &Colors) {
+        if showing_starred {
+            return Self::render_starred(frame, area, colors);
+        }
+
         let controls_line = Line::from(vec![
-            Span::styled("[↑↓/JK]", Style::default().fg(colors.key_navigation())),
+            Span::styled("[↑↓]", Style::default().fg(colors.key_navigation())),
             Span::styled(" Navigate  ", Style::default().fg(colors.text())),
-            Span::styled("[SPACE]", Style::default().fg(colors.key_action())),
+            Span::styled("[TYPE]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Filter  ", Style::default().fg(colors.text())),
+            Span::styled("[ENTER]", Style::default().fg(colors.key_action())),
             Span::styled(" Play  ", Style::default().fg(colors.text())),
+            Span::styled("[TAB]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Starred  ", Style::default().fg(colors.text())),
             Span::styled("[ESC]", Style::default().fg(colors.key_back())),
             Span::styled(" Return  ", Style::default().fg(colors.text())),
             Span::styled("●", Style::default().fg(colors.success())),
@@ -26,4 +34,23 @@ impl ControlsView {
         let controls = Paragraph::new(controls_line).alignment(Alignment::Center);
         frame.render_widget(controls, area);
     }
+
+    fn render_starred(frame: &mut Frame, area: Rect, colors: &Colors) {
+        let controls_line = Line::from(vec![
+            Span::styled("[↑↓]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Navigate  ", Style::default().fg(colors.text())),
+            Span::styled("[TYPE]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Filter  ", Style::default().fg(colors.text())),
+            Span::styled("[SPACE]", Style::default().fg(colors.key_action())),
+            Span::styled(" Queue  ", Style::default().fg(colors.text())),
+            Span::styled("[ENTER]", Style::default().fg(colors.key_action())),
+            Span::styled(" Warm & Play  ", Style::default().fg(colors.text())),
+            Span::styled("[TAB]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Played  ", Style::default().fg(colors.text())),
+            Span::styled("[ESC]", Style::default().fg(colors.key_back())),
+            Span::styled(" Return", Style::default().fg(colors.text())),
+        ]);
+        let controls = Paragraph::new(controls_line).alignment(Alignment::Center);
+        frame.render_widget(controls, area);
+    }
 }
@@ -10,7 +10,17 @@ use ratatui::{
 pub struct HeaderView;
 
 impl HeaderView {
-    pub fn render(frame: &mut Frame, area: Rect, colors: &Colors) {
+    /// The title with the two lists, the one shown highlighted
+    pub fn render(frame: &mut Frame, area: Rect, showing_starred: bool, colors: &Colors) {
+        let tab_style = |active: bool| {
+            if active {
+                Style::default()
+                    .fg(colors.key_action())
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(colors.text_secondary())
+            }
+        };
         let header_lines = vec![Line::from(vec![
             Span::raw("  "),
             Span::styled(
@@ -19,6 +29,10 @@ impl HeaderView {
                     .fg(colors.info())
                     .add_modifier(Modifier::BOLD),
             ),
+            Span::raw("    "),
+            Span::styled("Played", tab_style(!showing_starred)),
+            Span::styled(" │ ", Style::default().fg(colors.border())),
+            Span::styled("Starred", tab_style(showing_starred)),
         ])];
 
         let header = Paragraph::new(header_lines).block(
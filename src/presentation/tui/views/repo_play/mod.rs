@@ -1,7 +1,11 @@
 pub mod controls_view;
 pub mod header_view;
 pub mod repository_list_view;
+pub mod search_bar_view;
+pub mod starred_list_view;
 
 pub use controls_view::ControlsView;
 pub use header_view::HeaderView;
 pub use repository_list_view::RepositoryListView;
+pub use search_bar_view::SearchBarView;
+pub use starred_list_view::StarredListView;
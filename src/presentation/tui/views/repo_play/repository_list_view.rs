@@ -1,5 +1,5 @@
 use crate::domain::models::storage::StoredRepositoryWithLanguages;
-use crate::domain::models::Languages;
+use crate::domain::models::{Languages, Locale};
 use crate::presentation::ui::Colors;
 use ratatui::{
     layout::Rect,
@@ -19,6 +19,7 @@ impl RepositoryListView {
         list_state: &mut ListState,
         colors: &Colors,
     ) {
+        let locale = Locale::current();
         let items: Vec<ListItem> = repositories
             .iter()
             .map(|(repo, is_cached)| {
@@ -63,6 +64,10 @@ impl RepositoryListView {
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::raw(" "),
+                    Span::styled(
+                        format!("{:<12}", last_played_label(&locale, repo)),
+                        Style::default().fg(colors.text_secondary()),
+                    ),
                 ];
                 line_spans.extend(language_spans);
 
@@ -92,3 +97,10 @@ impl RepositoryListView {
         frame.render_stateful_widget(list, area, list_state);
     }
 }
+
+/// Local date of the last session, `-` if the repository was never played
+pub fn last_played_label(locale: &Locale, repo: &StoredRepositoryWithLanguages) -> String {
+    repo.last_played_at
+        .map(|played_at| locale.format_date(played_at.with_timezone(&chrono::Local).date_naive()))
+        .unwrap_or_else(|| "-".to_string())
+}
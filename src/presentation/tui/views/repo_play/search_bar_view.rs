@@ -0,0 +1,48 @@
+use crate::presentation::ui::Colors;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph},
+    Frame,
+};
+
+pub struct SearchBarView;
+
+impl SearchBarView {
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        query: &str,
+        placeholder: &str,
+        matches: usize,
+        total: usize,
+        colors: &Colors,
+    ) {
+        let line = if query.is_empty() {
+            Line::from(Span::styled(
+                placeholder.to_string(),
+                Style::default().fg(colors.text_secondary()),
+            ))
+        } else {
+            Line::from(vec![
+                Span::styled("/ ", Style::default().fg(colors.key_action())),
+                Span::styled(query.to_string(), Style::default().fg(colors.text())),
+                Span::styled("_", Style::default().fg(colors.key_action())),
+                Span::styled(
+                    format!("  {}/{}", matches, total),
+                    Style::default().fg(colors.text_secondary()),
+                ),
+            ])
+        };
+
+        let search_bar = Paragraph::new(line).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border()))
+                .title("Search")
+                .padding(Padding::horizontal(1)),
+        );
+        frame.render_widget(search_bar, area);
+    }
+}
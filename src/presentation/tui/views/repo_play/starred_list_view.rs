@@ -0,0 +1,115 @@
+use crate::domain::models::Locale;
+use crate::infrastructure::http::GitHubStarredRepository;
+use crate::presentation::ui::Colors;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+pub struct StarredListView;
+
+impl StarredListView {
+    /// Queued repositories show their place in the queue
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        repositories: &[GitHubStarredRepository],
+        queue: &[String],
+        list_state: &mut ListState,
+        colors: &Colors,
+    ) {
+        let locale = Locale::current();
+        let items: Vec<ListItem> = repositories
+            .iter()
+            .map(|repo| {
+                let position = queue.iter().position(|queued| *queued == repo.full_name);
+                let marker = position.map_or_else(
+                    || "[ ]".to_string(),
+                    |position| format!("[{}]", position + 1),
+                );
+                let marker_color = if position.is_some() {
+                    colors.success()
+                } else {
+                    colors.text_secondary()
+                };
+                let language = repo.language.as_deref().unwrap_or("-");
+                let description = repo.description.as_deref().unwrap_or("");
+                let truncated_desc = if description.chars().count() > 50 {
+                    let truncated: String = description.chars().take(47).collect();
+                    format!("{}...", truncated)
+                } else {
+                    description.to_string()
+                };
+
+                ListItem::new(Line::from(vec![
+                    Span::raw("  "),
+                    Span::styled(format!("{:<4}", marker), Style::default().fg(marker_color)),
+                    Span::styled(
+                        format!("{:<32}", repo.full_name),
+                        Style::default()
+                            .fg(colors.text())
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(
+                        format!("{:<12}", language),
+                        Style::default().fg(colors.info()),
+                    ),
+                    Span::styled(
+                        format!(
+                            "★ {:<8}",
+                            locale.format_decimal(repo.stargazers_count as f64, 0)
+                        ),
+                        Style::default().fg(colors.warning()),
+                    ),
+                    Span::styled(truncated_desc, Style::default().fg(colors.text_secondary())),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(Self::block(queue.len(), colors))
+            .style(Style::default().fg(colors.text()))
+            .highlight_style(
+                Style::default()
+                    .bg(colors.background_secondary())
+                    .add_modifier(Modifier::BOLD),
+            );
+        frame.render_stateful_widget(list, area, list_state);
+    }
+
+    /// A status line in place of the list, while it is fetched or after it failed
+    pub fn render_message(
+        frame: &mut Frame,
+        area: Rect,
+        message: &str,
+        color: Color,
+        colors: &Colors,
+    ) {
+        let paragraph = Paragraph::new(message.to_string())
+            .block(Self::block(0, colors))
+            .style(Style::default().fg(color))
+            .wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, area);
+    }
+
+    fn block(queued: usize, colors: &Colors) -> Block<'static> {
+        let title = match queued {
+            0 => "Starred Repositories".to_string(),
+            queued => format!("Starred Repositories ({} queued)", queued),
+        };
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.border()))
+            .title(title)
+            .title_style(
+                Style::default()
+                    .fg(colors.text())
+                    .add_modifier(Modifier::BOLD),
+            )
+            .padding(Padding::uniform(1))
+    }
+}
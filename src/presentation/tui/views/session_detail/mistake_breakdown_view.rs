@@ -0,0 +1,72 @@
+use crate::domain::models::{Locale, MistakeBreakdown, MistakeKind};
+use crate::presentation::ui::Colors;
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+pub struct MistakeBreakdownView;
+
+impl MistakeBreakdownView {
+    pub fn render(
+        f: &mut Frame,
+        area: ratatui::prelude::Rect,
+        breakdown: Option<&MistakeBreakdown>,
+        colors: &Colors,
+    ) {
+        let mut lines = vec![Line::from("")];
+
+        match breakdown {
+            None => lines.push(Line::from(Span::styled(
+                "  Not recorded for this session",
+                Style::default().fg(colors.text_secondary()),
+            ))),
+            Some(breakdown) if breakdown.is_empty() => lines.push(Line::from(Span::styled(
+                "  No mistakes",
+                Style::default().fg(colors.success()),
+            ))),
+            Some(breakdown) => {
+                lines.extend(MistakeKind::all().iter().map(|kind| {
+                    Line::from(vec![
+                        Span::raw("  "),
+                        Span::styled(
+                            format!("{:<16}", format!("{}:", kind.label())),
+                            Style::default().fg(colors.error()),
+                        ),
+                        Span::styled(
+                            Locale::current().format_count(breakdown.count(*kind)),
+                            Style::default().fg(colors.text()),
+                        ),
+                    ])
+                }));
+
+                if let Some(kind) = breakdown.most_common() {
+                    lines.push(Line::from(""));
+                    lines.push(Line::from(vec![
+                        Span::raw("  "),
+                        Span::styled(
+                            "Tip: ",
+                            Style::default()
+                                .fg(colors.info())
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(kind.advice(), Style::default().fg(colors.text())),
+                    ]));
+                }
+            }
+        }
+
+        let paragraph = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(colors.border()))
+                    .title("Mistake Types"),
+            )
+            .wrap(Wrap { trim: false });
+
+        f.render_widget(paragraph, area);
+    }
+}
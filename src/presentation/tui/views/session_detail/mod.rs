@@ -1,7 +1,9 @@
+pub mod mistake_breakdown_view;
 pub mod performance_metrics_view;
 pub mod session_info_view;
 pub mod stage_details_view;
 
+pub use mistake_breakdown_view::MistakeBreakdownView;
 pub use performance_metrics_view::PerformanceMetricsView;
 pub use session_info_view::SessionInfoView;
 pub use stage_details_view::StageDetailsView;
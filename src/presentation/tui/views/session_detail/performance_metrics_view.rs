@@ -1,4 +1,5 @@
-use crate::domain::models::storage::SessionResultData;
+use crate::domain::models::storage::{SessionResultData, SessionStageResult};
+use crate::domain::models::{Locale, WpmMode};
 use crate::presentation::ui::Colors;
 use ratatui::{
     style::Style,
@@ -14,6 +15,7 @@ impl PerformanceMetricsView {
         f: &mut Frame,
         area: ratatui::prelude::Rect,
         session_result: Option<&SessionResultData>,
+        stage_results: &[SessionStageResult],
         colors: &Colors,
     ) {
         let mut metrics_lines = Vec::new();
@@ -21,6 +23,25 @@ impl PerformanceMetricsView {
         metrics_lines.push(Line::from(""));
 
         if let Some(result) = session_result {
+            let wpm_mode = WpmMode::current();
+            let wpm = if stage_results.is_empty() {
+                result.wpm
+            } else {
+                wpm_mode.wpm_across(
+                    result.cpm,
+                    stage_results
+                        .iter()
+                        .filter(|stage| !stage.was_skipped && !stage.was_failed)
+                        .map(|stage| {
+                            (
+                                stage.wpm_in(wpm_mode),
+                                stage.cpm,
+                                stage.duration_ms as f64 / 1000.0,
+                            )
+                        }),
+                )
+            };
+
             metrics_lines.push(Line::from(vec![
                 Span::raw("  "),
                 Span::styled("Tier/Rank: ", Style::default().fg(colors.stage_info())),
@@ -38,7 +59,7 @@ impl PerformanceMetricsView {
                 Span::raw("  "),
                 Span::styled("Score: ", Style::default().fg(colors.score())),
                 Span::styled(
-                    format!("{:.1}", result.score),
+                    Locale::current().format_decimal(result.score, 1),
                     Style::default().fg(colors.text()),
                 ),
             ]));
@@ -47,16 +68,19 @@ impl PerformanceMetricsView {
                 Span::raw("  "),
                 Span::styled("CPM: ", Style::default().fg(colors.cpm_wpm())),
                 Span::styled(
-                    format!("{:.1}", result.cpm),
+                    Locale::current().format_decimal(result.cpm, 1),
                     Style::default().fg(colors.text()),
                 ),
             ]));
 
             metrics_lines.push(Line::from(vec![
                 Span::raw("  "),
-                Span::styled("WPM: ", Style::default().fg(colors.cpm_wpm())),
                 Span::styled(
-                    format!("{:.1}", result.wpm),
+                    format!("{}: ", wpm_mode.label()),
+                    Style::default().fg(colors.cpm_wpm()),
+                ),
+                Span::styled(
+                    Locale::current().format_decimal(wpm, 1),
                     Style::default().fg(colors.text()),
                 ),
             ]));
@@ -65,7 +89,7 @@ impl PerformanceMetricsView {
                 Span::raw("  "),
                 Span::styled("Accuracy: ", Style::default().fg(colors.accuracy())),
                 Span::styled(
-                    format!("{:.1}%", result.accuracy),
+                    format!("{}%", Locale::current().format_decimal(result.accuracy, 1)),
                     Style::default().fg(colors.text()),
                 ),
             ]));
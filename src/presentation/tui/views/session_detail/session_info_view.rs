@@ -1,5 +1,7 @@
+use crate::domain::models::git_repository::DETACHED_HEAD;
 use crate::domain::models::storage::StoredRepository;
 use crate::domain::models::storage::StoredSession;
+use crate::domain::models::Locale;
 use crate::presentation::ui::Colors;
 use chrono::{DateTime, Local};
 use ratatui::{
@@ -35,14 +37,25 @@ impl SessionInfoView {
         info_lines.push(Line::from(vec![
             Span::raw("  "),
             Span::styled("Started: ", Style::default().fg(colors.accuracy())),
-            Span::raw(local_time.format("%Y-%m-%d %H:%M:%S").to_string()),
+            Span::raw(Locale::current().format_datetime(local_time.naive_local(), true)),
         ]));
 
-        if let Some(ref branch) = session.branch {
+        // Sessions played on a detached HEAD have a commit but no branch
+        let branch = session
+            .branch
+            .clone()
+            .filter(|branch| branch != DETACHED_HEAD)
+            .or_else(|| {
+                session
+                    .commit_hash
+                    .as_ref()
+                    .map(|_| "(detached HEAD)".to_string())
+            });
+        if let Some(branch) = branch {
             info_lines.push(Line::from(vec![
                 Span::raw("  "),
                 Span::styled("Branch: ", Style::default().fg(colors.accuracy())),
-                Span::raw(branch.clone()),
+                Span::raw(branch),
             ]));
         }
 
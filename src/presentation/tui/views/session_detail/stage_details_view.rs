@@ -1,4 +1,5 @@
 use crate::domain::models::storage::SessionStageResult;
+use crate::domain::models::{Locale, MistakeKind, WpmMode};
 use crate::presentation::ui::Colors;
 use ratatui::{
     style::{Modifier, Style},
@@ -83,19 +84,22 @@ impl StageDetailsView {
                 Span::raw("    "),
                 Span::styled("Score: ", Style::default().fg(colors.score())),
                 Span::styled(
-                    format!("{:.1}", stage.score),
+                    Locale::current().format_decimal(stage.score, 1),
                     Style::default().fg(colors.text()),
                 ),
                 Span::raw("  "),
                 Span::styled("CPM: ", Style::default().fg(colors.cpm_wpm())),
                 Span::styled(
-                    format!("{:.1}", stage.cpm),
+                    Locale::current().format_decimal(stage.cpm, 1),
                     Style::default().fg(colors.text()),
                 ),
                 Span::raw("    "),
-                Span::styled("WPM: ", Style::default().fg(colors.cpm_wpm())),
                 Span::styled(
-                    format!("{:.1}", stage.wpm),
+                    format!("{}: ", WpmMode::current().label()),
+                    Style::default().fg(colors.cpm_wpm()),
+                ),
+                Span::styled(
+                    Locale::current().format_decimal(stage.wpm_in(WpmMode::current()), 1),
                     Style::default().fg(colors.text()),
                 ),
             ]));
@@ -104,7 +108,7 @@ impl StageDetailsView {
                 Span::raw("    "),
                 Span::styled("Keystrokes: ", Style::default().fg(colors.stage_info())),
                 Span::styled(
-                    format!("{}", stage.keystrokes),
+                    Locale::current().format_count(stage.keystrokes),
                     Style::default().fg(colors.text()),
                 ),
                 Span::raw("  "),
@@ -116,7 +120,7 @@ impl StageDetailsView {
                 Span::raw("  "),
                 Span::styled("Accuracy: ", Style::default().fg(colors.accuracy())),
                 Span::styled(
-                    format!("{:.1}%", stage.accuracy),
+                    format!("{}%", Locale::current().format_decimal(stage.accuracy, 1)),
                     Style::default().fg(colors.text()),
                 ),
                 Span::raw("  "),
@@ -127,6 +131,20 @@ impl StageDetailsView {
                 ),
             ]));
 
+            if let Some(breakdown) = stage.mistake_breakdown.filter(|b| !b.is_empty()) {
+                let kinds = MistakeKind::all()
+                    .iter()
+                    .filter(|kind| breakdown.count(**kind) > 0)
+                    .map(|kind| format!("{} {}", kind.label(), breakdown.count(*kind)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                stage_text_lines.push(Line::from(vec![
+                    Span::raw("    "),
+                    Span::styled("Mistake Types: ", Style::default().fg(colors.error())),
+                    Span::styled(kinds, Style::default().fg(colors.text())),
+                ]));
+            }
+
             if actual_idx < stage_results.len() - 1 && i < end_idx - start_idx - 1 {
                 stage_text_lines.push(Line::raw(""));
             }
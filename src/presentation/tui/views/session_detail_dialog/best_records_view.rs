@@ -1,4 +1,4 @@
-use crate::domain::models::SessionResult;
+use crate::domain::models::{Locale, SessionResult};
 use crate::domain::repositories::session_repository::{BestRecords, BestStatus};
 use crate::presentation::ui::Colors;
 use ratatui::{
@@ -75,21 +75,21 @@ impl BestRecordsView {
 
                     spans.push(Span::styled("Score ", Style::default().fg(colors.score())));
                     spans.push(Span::styled(
-                        format!("{:.0}", record.score),
+                        Locale::current().format_decimal(record.score, 0),
                         Style::default().fg(colors.text()),
                     ));
                     spans.push(Span::styled(" | ", Style::default().fg(colors.text())));
 
                     spans.push(Span::styled("CPM ", Style::default().fg(colors.cpm_wpm())));
                     spans.push(Span::styled(
-                        format!("{:.0}", record.cpm),
+                        Locale::current().format_decimal(record.cpm, 0),
                         Style::default().fg(colors.text()),
                     ));
                     spans.push(Span::styled(" | ", Style::default().fg(colors.text())));
 
                     spans.push(Span::styled("Acc ", Style::default().fg(colors.accuracy())));
                     spans.push(Span::styled(
-                        format!("{:.1}%", record.accuracy),
+                        format!("{}%", Locale::current().format_decimal(record.accuracy, 1)),
                         Style::default().fg(colors.text()),
                     ));
 
@@ -1,5 +1,5 @@
-use crate::domain::models::{GitRepository, SessionResult};
-use crate::presentation::ui::Colors;
+use crate::domain::models::{GitRepository, Locale, SessionResult};
+use crate::presentation::ui::{Colors, SparklineWidget};
 use ratatui::{
     layout::{Alignment, Rect},
     style::{Modifier, Style},
@@ -11,6 +11,16 @@ use ratatui::{
 pub struct StageResultsView;
 
 impl StageResultsView {
+    /// Header, the CPM sparkline once there are stages to compare, and a
+    /// name and metrics line per stage
+    pub fn height(stage_count: usize) -> usize {
+        match stage_count {
+            0 => 2,
+            1 => 2 + 2,
+            count => 3 + count * 2,
+        }
+    }
+
     pub fn render(
         f: &mut Frame,
         area: Rect,
@@ -36,6 +46,19 @@ impl StageResultsView {
                     .fg(colors.info())
                     .add_modifier(Modifier::BOLD),
             )));
+            if session_result.stage_results.len() > 1 {
+                let cpm: Vec<f64> = session_result
+                    .stage_results
+                    .iter()
+                    .map(|stage_result| stage_result.cpm)
+                    .collect();
+                lines.push(Line::from(vec![
+                    Span::styled("CPM by stage: ", Style::default().fg(colors.cpm_wpm())),
+                    SparklineWidget::new(&cpm)
+                        .style(Style::default().fg(colors.cpm_wpm()))
+                        .span(area.width.saturating_sub(14) as usize),
+                ]));
+            }
             lines.push(Line::from(""));
 
             for (i, stage_result) in session_result.stage_results.iter().enumerate() {
@@ -55,14 +78,14 @@ impl StageResultsView {
 
                 metrics_spans.push(Span::styled("Score: ", Style::default().fg(colors.score())));
                 metrics_spans.push(Span::styled(
-                    format!("{:.0}", stage_result.challenge_score),
+                    Locale::current().format_decimal(stage_result.challenge_score, 0),
                     Style::default().fg(colors.text()),
                 ));
                 metrics_spans.push(Span::styled(" | ", Style::default().fg(colors.text())));
 
                 metrics_spans.push(Span::styled("CPM: ", Style::default().fg(colors.cpm_wpm())));
                 metrics_spans.push(Span::styled(
-                    format!("{:.0}", stage_result.cpm),
+                    Locale::current().format_decimal(stage_result.cpm, 0),
                     Style::default().fg(colors.text()),
                 ));
                 metrics_spans.push(Span::styled(" | ", Style::default().fg(colors.text())));
@@ -72,7 +95,10 @@ impl StageResultsView {
                     Style::default().fg(colors.accuracy()),
                 ));
                 metrics_spans.push(Span::styled(
-                    format!("{:.1}%", stage_result.accuracy),
+                    format!(
+                        "{}%",
+                        Locale::current().format_decimal(stage_result.accuracy, 1)
+                    ),
                     Style::default().fg(colors.text()),
                 ));
 
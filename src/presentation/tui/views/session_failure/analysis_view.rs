@@ -0,0 +1,112 @@
+use crate::domain::models::{FailureAnalysis, Languages};
+use crate::presentation::ui::Colors;
+use ratatui::{
+    layout::Alignment,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+pub struct AnalysisView;
+
+impl AnalysisView {
+    /// Lines needed to render `analysis`
+    pub fn height(analysis: &FailureAnalysis) -> u16 {
+        4 + analysis.clusters.len().max(1) as u16
+    }
+
+    pub fn render(
+        frame: &mut Frame,
+        area: ratatui::layout::Rect,
+        analysis: &FailureAnalysis,
+        colors: &Colors,
+    ) {
+        let mut challenge_spans = vec![
+            Span::styled("Failed on: ", Style::default().fg(colors.stage_info())),
+            Span::styled(
+                analysis.challenge_label(),
+                Style::default().fg(colors.text()),
+            ),
+        ];
+        if let Some(language) = &analysis.challenge.language {
+            challenge_spans.push(Span::styled(
+                format!(" ({})", Languages::get_display_name(Some(language))),
+                Style::default().fg(colors.text_secondary()),
+            ));
+        }
+
+        let mut stopped_spans = vec![
+            Span::styled("Stopped at: ", Style::default().fg(colors.stage_info())),
+            Span::styled(
+                format!(
+                    "line {}, col {}",
+                    analysis.failure_location.line, analysis.failure_location.column
+                ),
+                Style::default().fg(colors.text()),
+            ),
+        ];
+        if let Some(expected) = analysis.expected_char_display() {
+            stopped_spans.push(Span::styled(
+                " | Expected: ",
+                Style::default().fg(colors.stage_info()),
+            ));
+            stopped_spans.push(Span::styled(
+                format!("'{}'", expected),
+                Style::default()
+                    .fg(colors.warning())
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        stopped_spans.push(Span::styled(
+            format!(
+                " | {}/{} chars ({:.0}%)",
+                analysis.typed_chars,
+                analysis.total_chars,
+                analysis.progress_percentage()
+            ),
+            Style::default().fg(colors.text_secondary()),
+        ));
+
+        let mut lines = vec![
+            Line::from(challenge_spans),
+            Line::from(stopped_spans),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Error clusters",
+                Style::default()
+                    .fg(colors.error())
+                    .add_modifier(Modifier::BOLD),
+            )),
+        ];
+
+        if analysis.clusters.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No mistakes before giving up",
+                Style::default().fg(colors.text_secondary()),
+            )));
+        } else {
+            lines.extend(analysis.clusters.iter().map(|cluster| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("L{}:{}  ", cluster.location.line, cluster.location.column),
+                        Style::default().fg(colors.text_secondary()),
+                    ),
+                    Span::styled(
+                        match cluster.mistakes {
+                            1 => "1 mistake".to_string(),
+                            mistakes => format!("{} mistakes", mistakes),
+                        },
+                        Style::default().fg(colors.error()),
+                    ),
+                    Span::styled(
+                        format!("  {}", cluster.snippet),
+                        Style::default().fg(colors.text()),
+                    ),
+                ])
+            }));
+        }
+
+        frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), area);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::domain::models::SessionResult;
+use crate::domain::models::{Locale, SessionResult, WpmMode};
 use crate::presentation::ui::Colors;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
@@ -50,17 +50,28 @@ impl ContentView {
         let metrics_line1 = Line::from(vec![
             Span::styled("CPM: ", Style::default().fg(colors.cpm_wpm())),
             Span::styled(
-                format!("{:.0}", session_result.overall_cpm),
+                Locale::current().format_decimal(session_result.overall_cpm, 0),
                 Style::default().fg(colors.text()),
             ),
-            Span::styled(" | WPM: ", Style::default().fg(colors.cpm_wpm())),
             Span::styled(
-                format!("{:.0}", session_result.overall_wpm),
+                format!(" | {}: ", WpmMode::current().label()),
+                Style::default().fg(colors.cpm_wpm()),
+            ),
+            Span::styled(
+                Locale::current().format_decimal(
+                    WpmMode::current()
+                        .from_recorded(session_result.overall_wpm, session_result.token_density()),
+                    0,
+                ),
                 Style::default().fg(colors.text()),
             ),
             Span::styled(" | Time: ", Style::default().fg(colors.duration())),
             Span::styled(
-                format!("{:.1}s", session_result.session_duration.as_secs_f64()),
+                format!(
+                    "{}s",
+                    Locale::current()
+                        .format_decimal(session_result.session_duration.as_secs_f64(), 1)
+                ),
                 Style::default().fg(colors.text()),
             ),
         ]);
@@ -71,7 +82,7 @@ impl ContentView {
         let metrics_line2 = Line::from(vec![
             Span::styled("Keystrokes: ", Style::default().fg(colors.stage_info())),
             Span::styled(
-                format!("{}", total_keystrokes),
+                Locale::current().format_count(total_keystrokes),
                 Style::default().fg(colors.text()),
             ),
             Span::styled(" | Mistakes: ", Style::default().fg(colors.error())),
@@ -81,7 +92,10 @@ impl ContentView {
             ),
             Span::styled(" | Accuracy: ", Style::default().fg(colors.accuracy())),
             Span::styled(
-                format!("{:.1}%", session_result.overall_accuracy),
+                format!(
+                    "{}%",
+                    Locale::current().format_decimal(session_result.overall_accuracy, 1)
+                ),
                 Style::default().fg(colors.text()),
             ),
         ]);
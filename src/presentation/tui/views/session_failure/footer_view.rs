@@ -10,10 +10,24 @@ use ratatui::{
 pub struct FooterView;
 
 impl FooterView {
-    pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, colors: &Colors) {
-        let nav_line = Line::from(vec![
+    pub fn render(
+        frame: &mut Frame,
+        area: ratatui::layout::Rect,
+        can_retry_challenge: bool,
+        colors: &Colors,
+    ) {
+        let mut spans = vec![
             Span::styled("[R]", Style::default().fg(colors.success())),
             Span::styled(" Retry | ", Style::default().fg(colors.text())),
+        ];
+        if can_retry_challenge {
+            spans.push(Span::styled("[C]", Style::default().fg(colors.success())));
+            spans.push(Span::styled(
+                " Retry Challenge | ",
+                Style::default().fg(colors.text()),
+            ));
+        }
+        spans.extend([
             Span::styled("[T]", Style::default().fg(colors.success())),
             Span::styled(" Back to Title | ", Style::default().fg(colors.text())),
             Span::styled("[ESC]", Style::default().fg(colors.error())),
@@ -22,7 +36,7 @@ impl FooterView {
                 Style::default().fg(colors.text()),
             ),
         ]);
-        let navigation = Paragraph::new(nav_line).alignment(Alignment::Center);
+        let navigation = Paragraph::new(Line::from(spans)).alignment(Alignment::Center);
         frame.render_widget(navigation, area);
     }
 }
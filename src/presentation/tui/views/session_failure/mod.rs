@@ -1,7 +1,9 @@
+pub mod analysis_view;
 pub mod content_view;
 pub mod footer_view;
 pub mod header_view;
 
+pub use analysis_view::AnalysisView;
 pub use content_view::ContentView;
 pub use footer_view::FooterView;
 pub use header_view::HeaderView;
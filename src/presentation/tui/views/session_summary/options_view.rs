@@ -10,7 +10,7 @@ use ratatui::{
 pub struct OptionsView;
 
 impl OptionsView {
-    pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, colors: &Colors) {
+    pub fn render(frame: &mut Frame, area: ratatui::layout::Rect, can_rate: bool, colors: &Colors) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -19,14 +19,24 @@ impl OptionsView {
             ])
             .split(area);
 
-        // Row 1: [D] Show Detail  [S] Share Result
-        let row1 = Line::from(vec![
+        // Row 1: [D] Show Detail  [S] Share Result  [H] Export HTML  [1-5] Rate Repo
+        let mut row1 = Line::from(vec![
             Span::styled("[D]", Style::default().fg(colors.info())),
             Span::styled(" Show Detail", Style::default().fg(colors.text())),
             Span::styled("  ", Style::default().fg(colors.text())),
             Span::styled("[S]", Style::default().fg(colors.info())),
             Span::styled(" Share Result", Style::default().fg(colors.text())),
+            Span::styled("  ", Style::default().fg(colors.text())),
+            Span::styled("[H]", Style::default().fg(colors.info())),
+            Span::styled(" Export HTML", Style::default().fg(colors.text())),
         ]);
+        if can_rate {
+            row1.spans.extend([
+                Span::styled("  ", Style::default().fg(colors.text())),
+                Span::styled("[1-5]", Style::default().fg(colors.info())),
+                Span::styled(" Rate Repo", Style::default().fg(colors.text())),
+            ]);
+        }
         let row1_widget = Paragraph::new(row1).alignment(Alignment::Center);
         frame.render_widget(row1_widget, chunks[0]);
 
@@ -1,4 +1,7 @@
-use crate::domain::models::{GitRepository, Rank, SessionResult};
+use crate::domain::models::storage::RepositoryMetadata;
+use crate::domain::models::{
+    GitRepository, Locale, PlayerProfile, Rank, SessionResult, StreamerMode,
+};
 use crate::presentation::ui::Colors;
 use ratatui::{
     layout::Alignment,
@@ -16,6 +19,8 @@ impl PreviewView {
         area: ratatui::layout::Rect,
         metrics: &SessionResult,
         repo_info: &Option<GitRepository>,
+        repo_metadata: &Option<RepositoryMetadata>,
+        profile: &Option<PlayerProfile>,
         colors: &Colors,
     ) {
         let best_rank = Rank::for_score(metrics.session_score);
@@ -31,12 +36,24 @@ impl PreviewView {
             ),
         ];
 
-        if let Some(repo) = repo_info {
+        if let Some(repo) = repo_info.as_ref().filter(|_| !StreamerMode::is_enabled()) {
             spans.push(Span::styled(" on [", Style::default().fg(colors.text())));
             spans.push(Span::styled(
                 format!("{}/{}", repo.user_name, repo.repository_name),
                 Style::default().fg(colors.info()),
             ));
+            if let Some(version) = repo.version_label() {
+                spans.push(Span::styled(
+                    format!(" ({})", version),
+                    Style::default().fg(colors.text_secondary()),
+                ));
+            }
+            if let Some(metadata) = repo_metadata {
+                spans.push(Span::styled(
+                    format!(" {}", metadata.summary()),
+                    Style::default().fg(colors.text_secondary()),
+                ));
+            }
             spans.push(Span::styled("]", Style::default().fg(colors.text())));
         }
 
@@ -44,7 +61,7 @@ impl PreviewView {
             Span::styled(" - ", Style::default().fg(colors.text())),
             Span::styled("CPM: ", Style::default().fg(colors.cpm_wpm())),
             Span::styled(
-                format!("{:.0}", metrics.overall_cpm),
+                Locale::current().format_decimal(metrics.overall_cpm, 0),
                 Style::default().fg(colors.text()),
             ),
             Span::styled(", ", Style::default().fg(colors.text())),
@@ -55,8 +72,31 @@ impl PreviewView {
             ),
         ]);
 
-        let preview_line = Line::from(spans);
-        let preview_widget = Paragraph::new(preview_line).alignment(Alignment::Center);
+        let mut lines = vec![Line::from(spans)];
+        if let Some(repo) = repo_info.as_ref().filter(|_| !StreamerMode::is_enabled()) {
+            lines.push(Line::from(Span::styled(
+                repo.attribution(),
+                Style::default().fg(colors.text_secondary()),
+            )));
+        }
+        if let Some(profile) = profile {
+            lines.push(Line::from(Span::styled(
+                profile.share_signature(),
+                Style::default().fg(colors.text_secondary()),
+            )));
+        }
+        if let Some(code) = &metrics.verification_code {
+            lines.push(Line::from(Span::styled(
+                format!("Verify: {}", code),
+                Style::default().fg(colors.text_secondary()),
+            )));
+        } else if let Some(label) = metrics.plausibility.label() {
+            lines.push(Line::from(Span::styled(
+                label,
+                Style::default().fg(colors.warning()),
+            )));
+        }
+        let preview_widget = Paragraph::new(lines).alignment(Alignment::Center);
         frame.render_widget(preview_widget, area);
     }
 }
@@ -0,0 +1,165 @@
+use crate::domain::models::{DiffLine, MistakeDiff};
+use crate::presentation::ui::Colors;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+const MAX_LINES: usize = 3;
+
+pub struct MistakeDiffView;
+
+impl MistakeDiffView {
+    /// Lines needed to render `diff`: a title, each code line with its
+    /// annotation line, and the costly tokens summary
+    pub fn height(diff: &MistakeDiff) -> u16 {
+        let code_lines = diff.lines.len().min(MAX_LINES) * 2;
+        let tokens = usize::from(!diff.costly_tokens.is_empty());
+        (1 + code_lines + tokens) as u16
+    }
+
+    pub fn render(frame: &mut Frame, area: Rect, diff: &MistakeDiff, colors: &Colors) {
+        let lines = &diff.lines[..diff.lines.len().min(MAX_LINES)];
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length((lines.len() * 2) as u16),
+                Constraint::Min(0),
+            ])
+            .split(area);
+
+        let hidden = diff.lines.len() - lines.len();
+        let mut title = vec![Span::styled(
+            "Where accuracy slipped",
+            Style::default()
+                .fg(colors.error())
+                .add_modifier(Modifier::BOLD),
+        )];
+        if hidden > 0 {
+            title.push(Span::styled(
+                format!(" (+{} more lines)", hidden),
+                Style::default().fg(colors.text_secondary()),
+            ));
+        }
+        frame.render_widget(
+            Paragraph::new(Line::from(title)).alignment(Alignment::Center),
+            chunks[0],
+        );
+
+        let indent = lines
+            .iter()
+            .map(|line| {
+                line.chars
+                    .iter()
+                    .take_while(|c| c.expected == ' ' && c.mistyped.is_none())
+                    .count()
+            })
+            .min()
+            .unwrap_or(0);
+        let gutter_width = lines
+            .iter()
+            .map(|line| line.line_number.to_string().len())
+            .max()
+            .unwrap_or(1);
+        let code: Vec<Line> = lines
+            .iter()
+            .flat_map(|line| Self::diff_lines(line, indent, gutter_width, colors))
+            .collect();
+        let code_width = code.iter().map(Line::width).max().unwrap_or(0) as u16;
+        let code_area = Rect {
+            x: chunks[1].x + chunks[1].width.saturating_sub(code_width) / 2,
+            width: code_width.min(chunks[1].width),
+            ..chunks[1]
+        };
+        frame.render_widget(Paragraph::new(code), code_area);
+
+        if !diff.costly_tokens.is_empty() {
+            let mut spans = vec![Span::styled(
+                "Costly tokens: ",
+                Style::default().fg(colors.stage_info()),
+            )];
+            for (index, (token, mistakes)) in diff.costly_tokens.iter().enumerate() {
+                if index > 0 {
+                    spans.push(Span::raw("  "));
+                }
+                spans.push(Span::styled(
+                    token.clone(),
+                    Style::default()
+                        .fg(colors.text())
+                        .add_modifier(Modifier::BOLD),
+                ));
+                spans.push(Span::styled(
+                    format!(" ×{}", mistakes),
+                    Style::default().fg(colors.error()),
+                ));
+            }
+            frame.render_widget(
+                Paragraph::new(Line::from(spans)).alignment(Alignment::Center),
+                chunks[2],
+            );
+        }
+    }
+
+    /// The code line with mistyped characters highlighted, followed by a line
+    /// showing what was typed under each of them
+    fn diff_lines(
+        line: &DiffLine,
+        indent: usize,
+        gutter_width: usize,
+        colors: &Colors,
+    ) -> [Line<'static>; 2] {
+        let gutter_style = Style::default().fg(colors.text_secondary());
+        let mut code = vec![Span::styled(
+            format!("{:>width$} │ ", line.line_number, width = gutter_width),
+            gutter_style,
+        )];
+        let mut annotation = vec![Span::styled(
+            format!("{:>width$} │ ", "", width = gutter_width),
+            gutter_style,
+        )];
+
+        for diff_char in line
+            .chars
+            .iter()
+            .skip(indent)
+            .filter(|c| c.expected != '\n' || c.mistyped.is_some())
+        {
+            let expected = Self::visible(diff_char.expected);
+            match diff_char.mistyped {
+                Some(typed) => {
+                    code.push(Span::styled(
+                        expected,
+                        Style::default()
+                            .fg(colors.error())
+                            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                    ));
+                    annotation.push(Span::styled(
+                        match typed {
+                            ' ' => "␣".to_string(),
+                            typed => Self::visible(typed),
+                        },
+                        Style::default().fg(colors.warning()),
+                    ));
+                }
+                None => {
+                    code.push(Span::styled(expected, Style::default().fg(colors.text())));
+                    annotation.push(Span::raw(" "));
+                }
+            }
+        }
+
+        [Line::from(code), Line::from(annotation)]
+    }
+
+    fn visible(c: char) -> String {
+        match c {
+            '\n' => "↵".to_string(),
+            '\t' => "→".to_string(),
+            c => c.to_string(),
+        }
+    }
+}
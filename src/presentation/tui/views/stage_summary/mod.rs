@@ -1,3 +1,7 @@
+pub mod mistake_diff_view;
+pub mod source_study_view;
 pub mod stage_completion_view;
 
+pub use mistake_diff_view::MistakeDiffView;
+pub use source_study_view::SourceStudyView;
 pub use stage_completion_view::StageCompletionView;
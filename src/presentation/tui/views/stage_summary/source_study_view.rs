@@ -0,0 +1,112 @@
+use crate::domain::models::SourceStudy;
+use crate::presentation::ui::Colors;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph},
+    Frame,
+};
+
+pub struct SourceStudyView;
+
+impl SourceStudyView {
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        study: Option<&SourceStudy>,
+        offset: usize,
+        colors: &Colors,
+    ) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
+            .split(area);
+
+        match study {
+            Some(study) => Self::render_source(frame, chunks[0], study, offset, colors),
+            None => {
+                let message = Paragraph::new(Line::from(Span::styled(
+                    "The source file of this challenge is not available on this machine",
+                    Style::default().fg(colors.text_secondary()),
+                )))
+                .alignment(Alignment::Center)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(colors.border()))
+                        .title("Study"),
+                );
+                frame.render_widget(message, chunks[0]);
+            }
+        }
+
+        let controls = Line::from(vec![
+            Span::styled("[↑↓/JK]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Scroll  ", Style::default().fg(colors.text())),
+            Span::styled("[PGUP/PGDN]", Style::default().fg(colors.key_navigation())),
+            Span::styled(" Page  ", Style::default().fg(colors.text())),
+            Span::styled("[S/ESC]", Style::default().fg(colors.key_back())),
+            Span::styled(" Back to summary", Style::default().fg(colors.text())),
+        ]);
+        frame.render_widget(
+            Paragraph::new(controls).alignment(Alignment::Center),
+            chunks[1],
+        );
+    }
+
+    fn render_source(
+        frame: &mut Frame,
+        area: Rect,
+        study: &SourceStudy,
+        offset: usize,
+        colors: &Colors,
+    ) {
+        let gutter_width = study.lines.len().to_string().len();
+        let lines: Vec<Line> = study
+            .lines
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(area.height.saturating_sub(2) as usize)
+            .map(|(index, line)| {
+                let line_number = index + 1;
+                let (marker, number_style, code_style) = if study.is_challenge_line(line_number) {
+                    (
+                        "▌",
+                        Style::default().fg(colors.info()),
+                        Style::default().fg(colors.text()),
+                    )
+                } else {
+                    (
+                        " ",
+                        Style::default().fg(colors.text_secondary()),
+                        Style::default()
+                            .fg(colors.text_secondary())
+                            .add_modifier(Modifier::DIM),
+                    )
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:>width$} ", line_number, width = gutter_width),
+                        number_style,
+                    ),
+                    Span::styled(marker, Style::default().fg(colors.info())),
+                    Span::styled(format!(" {}", line.replace('\t', "    ")), code_style),
+                ])
+            })
+            .collect();
+
+        let source = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors.border()))
+                .title(format!(
+                    "Study: {} (challenge lines {}-{})",
+                    study.path, study.start_line, study.end_line
+                ))
+                .padding(Padding::horizontal(1)),
+        );
+        frame.render_widget(source, area);
+    }
+}
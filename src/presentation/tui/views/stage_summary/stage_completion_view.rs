@@ -1,6 +1,7 @@
 use crate::domain::models::ui::{ascii_digits::get_digit_patterns, rank_colors};
-use crate::domain::models::Rank;
+use crate::domain::models::{ChallengePreview, Locale, MistakeDiff, Rank, WpmMode};
 use crate::domain::services::scoring::StageResult;
+use crate::presentation::tui::views::stage_summary::MistakeDiffView;
 use crate::presentation::ui::{Colors, GradationText};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
@@ -13,6 +14,7 @@ use ratatui::{
 pub struct StageCompletionView;
 
 impl StageCompletionView {
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         frame: &mut Frame,
         metrics: &StageResult,
@@ -20,9 +22,17 @@ impl StageCompletionView {
         total_stages: usize,
         has_next_stage: bool,
         keystrokes: usize,
+        mistake_diff: Option<&MistakeDiff>,
+        flagged: bool,
+        next_challenge: Option<&ChallengePreview>,
+        can_reroll: bool,
+        source_missing: bool,
         colors: &Colors,
     ) {
         let area = frame.area();
+        let next_challenge = next_challenge.filter(|_| has_next_stage);
+        let mistake_diff = mistake_diff
+            .filter(|diff| !diff.is_empty() && !metrics.was_failed && !metrics.was_skipped);
 
         // Calculate total content height
         let title_height = 1;
@@ -44,6 +54,8 @@ impl StageCompletionView {
         } else {
             0
         };
+        let diff_height = mistake_diff.map_or(0, |diff| MistakeDiffView::height(diff) as usize);
+        let diff_spacing = usize::from(mistake_diff.is_some());
         let progress_height = if has_next_stage { 3 } else { 1 };
         let progress_spacing = 1;
         let options_height = 1;
@@ -55,6 +67,8 @@ impl StageCompletionView {
             + ascii_spacing
             + metrics_height
             + metrics_spacing
+            + diff_height
+            + diff_spacing
             + progress_height
             + progress_spacing
             + options_height;
@@ -75,6 +89,11 @@ impl StageCompletionView {
             constraints.push(Constraint::Length(metrics_spacing as u16));
         }
 
+        if mistake_diff.is_some() {
+            constraints.push(Constraint::Length(diff_height as u16));
+            constraints.push(Constraint::Length(diff_spacing as u16));
+        }
+
         constraints.push(Constraint::Length(progress_height as u16));
         constraints.push(Constraint::Length(progress_spacing as u16));
         constraints.push(Constraint::Length(options_height as u16));
@@ -103,6 +122,11 @@ impl StageCompletionView {
             chunk_idx += 2; // metrics + spacing
         }
 
+        if let Some(diff) = mistake_diff {
+            MistakeDiffView::render(frame, chunks[chunk_idx], diff, colors);
+            chunk_idx += 2; // diff + spacing
+        }
+
         // Render progress indicator
         Self::render_progress_indicator(
             colors,
@@ -111,11 +135,19 @@ impl StageCompletionView {
             current_stage,
             total_stages,
             has_next_stage,
+            next_challenge,
         );
         chunk_idx += 2; // progress + spacing
 
         // Render options
-        Self::render_options(colors, frame, chunks[chunk_idx]);
+        Self::render_options(
+            colors,
+            frame,
+            chunks[chunk_idx],
+            flagged,
+            next_challenge.is_some() && can_reroll,
+            source_missing,
+        );
     }
 
     fn create_ascii_numbers(score: &str) -> Vec<String> {
@@ -265,19 +297,25 @@ impl StageCompletionView {
         let line1 = Line::from(vec![
             Span::styled("CPM: ", Style::default().fg(colors.cpm_wpm())),
             Span::styled(
-                format!("{:.0}", metrics.cpm),
+                Locale::current().format_decimal(metrics.cpm, 0),
                 Style::default().fg(colors.text()),
             ),
             Span::styled(" | ", Style::default().fg(colors.text())),
-            Span::styled("WPM: ", Style::default().fg(colors.cpm_wpm())),
             Span::styled(
-                format!("{:.0}", metrics.wpm),
+                format!("{}: ", WpmMode::current().label()),
+                Style::default().fg(colors.cpm_wpm()),
+            ),
+            Span::styled(
+                Locale::current().format_decimal(
+                    WpmMode::current().from_recorded(metrics.wpm, metrics.token_density),
+                    0,
+                ),
                 Style::default().fg(colors.text()),
             ),
             Span::styled(" | ", Style::default().fg(colors.text())),
             Span::styled("Time: ", Style::default().fg(colors.duration())),
             Span::styled(
-                format!("{:.1}s", time_secs),
+                format!("{}s", Locale::current().format_decimal(time_secs, 1)),
                 Style::default().fg(colors.text()),
             ),
         ]);
@@ -290,7 +328,7 @@ impl StageCompletionView {
         let line2 = Line::from(vec![
             Span::styled("Keystrokes: ", Style::default().fg(colors.stage_info())),
             Span::styled(
-                format!("{}", keystrokes),
+                Locale::current().format_count(keystrokes),
                 Style::default().fg(colors.text()),
             ),
             Span::styled(" | ", Style::default().fg(colors.text())),
@@ -302,7 +340,7 @@ impl StageCompletionView {
             Span::styled(" | ", Style::default().fg(colors.text())),
             Span::styled("Accuracy: ", Style::default().fg(colors.accuracy())),
             Span::styled(
-                format!("{:.1}%", metrics.accuracy),
+                format!("{}%", Locale::current().format_decimal(metrics.accuracy, 1)),
                 Style::default().fg(colors.text()),
             ),
         ]);
@@ -319,6 +357,7 @@ impl StageCompletionView {
         current_stage: usize,
         total_stages: usize,
         has_next_stage: bool,
+        next_challenge: Option<&ChallengePreview>,
     ) {
         if has_next_stage {
             let chunks = Layout::default()
@@ -338,12 +377,17 @@ impl StageCompletionView {
             .alignment(Alignment::Center);
             frame.render_widget(progress, chunks[0]);
 
-            let next_text = "Next stage starting...";
-            let next = Paragraph::new(Line::from(vec![Span::styled(
-                next_text,
-                Style::default().fg(colors.warning()),
-            )]))
-            .alignment(Alignment::Center);
+            let next_line = match next_challenge {
+                Some(preview) => Line::from(vec![
+                    Span::styled("Next: ", Style::default().fg(colors.warning())),
+                    Span::styled(preview.summary(), Style::default().fg(colors.text())),
+                ]),
+                None => Line::from(Span::styled(
+                    "Next stage starting...",
+                    Style::default().fg(colors.warning()),
+                )),
+            };
+            let next = Paragraph::new(next_line).alignment(Alignment::Center);
             frame.render_widget(next, chunks[2]);
         } else {
             let progress_text = format!("Stage {} of {}", current_stage, total_stages);
@@ -356,13 +400,64 @@ impl StageCompletionView {
         }
     }
 
-    fn render_options(colors: &Colors, frame: &mut Frame, area: ratatui::layout::Rect) {
-        let options = Line::from(vec![
-            Span::styled("[SPACE]", Style::default().fg(colors.success())),
-            Span::styled(" Continue  ", Style::default().fg(colors.text())),
-            Span::styled("[ESC]", Style::default().fg(colors.error())),
-            Span::styled(" Quit", Style::default().fg(colors.text())),
-        ]);
+    fn render_options(
+        colors: &Colors,
+        frame: &mut Frame,
+        area: ratatui::layout::Rect,
+        flagged: bool,
+        can_reroll: bool,
+        source_missing: bool,
+    ) {
+        let reroll_option = if can_reroll {
+            vec![
+                Span::styled("[R]", Style::default().fg(colors.info())),
+                Span::styled(" Re-roll next  ", Style::default().fg(colors.text())),
+            ]
+        } else {
+            vec![]
+        };
+        let flag_option = if flagged {
+            vec![Span::styled(
+                "Flagged as bad  ",
+                Style::default().fg(colors.text_secondary()),
+            )]
+        } else {
+            vec![
+                Span::styled("[F]", Style::default().fg(colors.warning())),
+                Span::styled(" Flag as bad  ", Style::default().fg(colors.text())),
+            ]
+        };
+        let editor_option = if source_missing {
+            vec![Span::styled(
+                "No source file  ",
+                Style::default().fg(colors.text_secondary()),
+            )]
+        } else {
+            vec![
+                Span::styled("[O]", Style::default().fg(colors.info())),
+                Span::styled(" Editor  ", Style::default().fg(colors.text())),
+            ]
+        };
+        let options = Line::from(
+            [
+                vec![
+                    Span::styled("[SPACE]", Style::default().fg(colors.success())),
+                    Span::styled(" Continue  ", Style::default().fg(colors.text())),
+                ],
+                reroll_option,
+                vec![
+                    Span::styled("[S]", Style::default().fg(colors.info())),
+                    Span::styled(" Study  ", Style::default().fg(colors.text())),
+                ],
+                editor_option,
+                flag_option,
+                vec![
+                    Span::styled("[ESC]", Style::default().fg(colors.error())),
+                    Span::styled(" Quit", Style::default().fg(colors.text())),
+                ],
+            ]
+            .concat(),
+        );
 
         let options_widget = Paragraph::new(options).alignment(Alignment::Center);
         frame.render_widget(options_widget, area);
@@ -0,0 +1,77 @@
+use crate::domain::models::DifficultyLevel;
+use crate::presentation::ui::Colors;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// What the loaded repository offers: the total with its most common
+/// languages, then how the challenges spread over the difficulties
+pub struct ChallengeSummaryView;
+
+impl ChallengeSummaryView {
+    pub const HEIGHT: u16 = 2;
+
+    /// Languages named before the rest are folded into "+N more"
+    const MAX_LANGUAGES: usize = 4;
+
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        difficulties: &[(&str, DifficultyLevel); 5],
+        challenge_counts: &[usize; 5],
+        language_counts: &[(String, usize)],
+        colors: &Colors,
+    ) {
+        let total: usize = challenge_counts.iter().sum();
+        if total == 0 {
+            return;
+        }
+
+        let separator = || Span::styled(" · ", Style::default().fg(colors.text_secondary()));
+        let count = |label: &str, count: usize| {
+            [
+                Span::styled(
+                    format!("{} ", label),
+                    Style::default().fg(colors.text_secondary()),
+                ),
+                Span::styled(count.to_string(), Style::default().fg(colors.text())),
+            ]
+        };
+
+        let mut languages = vec![Span::styled(
+            format!("{} challenges", total),
+            Style::default()
+                .fg(colors.info())
+                .add_modifier(Modifier::BOLD),
+        )];
+        for (language, language_count) in language_counts.iter().take(Self::MAX_LANGUAGES) {
+            languages.push(separator());
+            languages.extend(count(language, *language_count));
+        }
+        if language_counts.len() > Self::MAX_LANGUAGES {
+            languages.push(separator());
+            languages.push(Span::styled(
+                format!("+{} more", language_counts.len() - Self::MAX_LANGUAGES),
+                Style::default().fg(colors.text_secondary()),
+            ));
+        }
+
+        let mut tiers = Vec::new();
+        for (i, ((name, _), tier_count)) in difficulties.iter().zip(challenge_counts).enumerate() {
+            if i > 0 {
+                tiers.push(separator());
+            }
+            tiers.extend(count(name, *tier_count));
+        }
+
+        frame.render_widget(
+            Paragraph::new(vec![Line::from(languages), Line::from(tiers)])
+                .alignment(Alignment::Center),
+            area,
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::domain::models::DifficultyLevel;
+use crate::domain::models::{DifficultyLevel, DifficultyLock};
 use crate::presentation::ui::Colors;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
@@ -11,12 +11,14 @@ use ratatui::{
 pub struct DifficultySelectionView;
 
 impl DifficultySelectionView {
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         frame: &mut Frame,
         area: ratatui::layout::Rect,
         difficulties: &[(&str, DifficultyLevel); 5],
         selected_difficulty: usize,
         challenge_counts: &[usize; 5],
+        lock: Option<&DifficultyLock>,
         error_message: Option<&String>,
         colors: &Colors,
     ) {
@@ -38,7 +40,10 @@ impl DifficultySelectionView {
             Span::styled("Difficulty: ", Style::default().fg(colors.text())),
             Span::styled("← ", Style::default().fg(colors.accuracy())),
             Span::styled(
-                name.to_string(),
+                match lock {
+                    Some(_) => format!("🔒 {}", name),
+                    None => name.to_string(),
+                },
                 Style::default()
                     .fg(colors.text())
                     .add_modifier(Modifier::BOLD),
@@ -76,6 +81,19 @@ impl DifficultySelectionView {
             )]))
             .alignment(Alignment::Center);
             frame.render_widget(error_line, chunks[2]);
+        } else if let Some(lock) = lock {
+            let lock_lines = [
+                (lock.message(), colors.warning()),
+                (difficulty_level.description().to_string(), colors.text()),
+            ];
+            for (i, (text, color)) in lock_lines.into_iter().enumerate() {
+                let line = Paragraph::new(Line::from(vec![Span::styled(
+                    text,
+                    Style::default().fg(color).add_modifier(Modifier::DIM),
+                )]))
+                .alignment(Alignment::Center);
+                frame.render_widget(line, chunks[2 + i]);
+            }
         } else {
             let descriptions = [difficulty_level.description(), difficulty_level.subtitle()];
             for (i, description) in descriptions.iter().enumerate() {
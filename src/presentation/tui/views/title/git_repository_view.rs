@@ -1,4 +1,4 @@
-use crate::domain::models::GitRepository;
+use crate::domain::models::{GitRepository, StreamerMode};
 use crate::presentation::ui::Colors;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
@@ -16,15 +16,18 @@ impl GitRepositoryView {
             let area = frame.area();
 
             // Build git info string
-            let mut parts = vec![format!("📁 {}/{}", info.user_name, info.repository_name)];
-
-            if let Some(ref branch) = info.branch {
-                parts.push(format!("🌿 {}", branch));
-            }
-
-            if let Some(ref commit) = info.commit_hash {
-                parts.push(format!("📝 {}", &commit[..8]));
-            }
+            let mut parts = if StreamerMode::is_enabled() {
+                vec![format!("📁 {}", StreamerMode::HIDDEN_REPOSITORY)]
+            } else {
+                let mut parts = vec![format!("📁 {}/{}", info.user_name, info.repository_name)];
+                if let Some(ref branch) = info.branch {
+                    parts.push(format!("🌿 {}", branch));
+                }
+                if let Some(ref commit) = info.commit_hash {
+                    parts.push(format!("📝 {}", &commit[..8]));
+                }
+                parts
+            };
 
             let status_symbol = if info.is_dirty { "⚠️" } else { "✓" };
             parts.push(status_symbol.to_string());
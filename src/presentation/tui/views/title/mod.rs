@@ -1,8 +1,14 @@
+pub mod challenge_summary_view;
 pub mod difficulty_selection_view;
 pub mod git_repository_view;
 pub mod logo;
+pub mod quick_play_view;
 pub mod static_elements_view;
+pub mod update_banner_view;
 
+pub use challenge_summary_view::ChallengeSummaryView;
 pub use difficulty_selection_view::DifficultySelectionView;
 pub use git_repository_view::GitRepositoryView;
+pub use quick_play_view::QuickPlayView;
 pub use static_elements_view::StaticElementsView;
+pub use update_banner_view::UpdateBannerView;
@@ -0,0 +1,68 @@
+use crate::domain::models::GitRepository;
+use crate::presentation::ui::Colors;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+pub struct QuickPlayView;
+
+impl QuickPlayView {
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        git_repository: &GitRepository,
+        difficulty_name: &str,
+        challenge_count: usize,
+        error_message: Option<&String>,
+        colors: &Colors,
+    ) {
+        let status_line = match error_message {
+            Some(error) => Line::from(Span::styled(
+                error.as_str(),
+                Style::default()
+                    .fg(colors.error())
+                    .add_modifier(Modifier::BOLD),
+            )),
+            None => Line::from(Span::styled(
+                format!(
+                    "{} · {} challenges available",
+                    difficulty_name, challenge_count
+                ),
+                Style::default()
+                    .fg(colors.info())
+                    .add_modifier(Modifier::DIM),
+            )),
+        };
+
+        let lines = vec![
+            Line::from(vec![
+                Span::styled("Play current repo ", Style::default().fg(colors.text())),
+                Span::styled(
+                    format!(
+                        "{}/{}",
+                        git_repository.user_name, git_repository.repository_name
+                    ),
+                    Style::default()
+                        .fg(colors.text())
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled("?", Style::default().fg(colors.text())),
+            ]),
+            status_line,
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("[ENTER]", Style::default().fg(colors.success())),
+                Span::styled(" Play  ", Style::default().fg(colors.text())),
+                Span::styled("[M]", Style::default().fg(colors.info())),
+                Span::styled(" Open menu  ", Style::default().fg(colors.text())),
+                Span::styled("[ESC]", Style::default().fg(colors.error())),
+                Span::styled(" Quit", Style::default().fg(colors.text())),
+            ]),
+        ];
+        frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), area);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::domain::models::GitRepository;
+use crate::domain::models::{GitRepository, ReadOnlyMode};
 use crate::presentation::tui::views::title::{logo, GitRepositoryView};
 use crate::presentation::ui::{Colors, GradationText};
 use ratatui::{
@@ -20,26 +20,7 @@ impl StaticElementsView {
         git_repository: Option<&GitRepository>,
         colors: &Colors,
     ) {
-        // Render logo
-        let logo_lines = logo::get_logo_lines();
-        let logo_colors = logo::get_logo_colors();
-        let logo_chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(vec![Constraint::Length(1); 6])
-            .split(logo_area);
-
-        for (i, line) in logo_lines.iter().enumerate() {
-            let widget = GradationText::new(line, logo_colors).alignment(Alignment::Center);
-            frame.render_widget(widget, logo_chunks[i]);
-        }
-
-        // Render subtitle
-        let subtitle = Paragraph::new(Line::from(vec![Span::styled(
-            "Code Typing Challenge",
-            Style::default().fg(colors.text_secondary()),
-        )]))
-        .alignment(Alignment::Center);
-        frame.render_widget(subtitle, subtitle_area);
+        Self::render_heading(frame, logo_area, subtitle_area, colors);
 
         // Render instructions
         let instructions_chunks = Layout::default()
@@ -67,6 +48,10 @@ impl StaticElementsView {
             Span::styled(" Records  ", Style::default().fg(colors.text())),
             Span::styled("[A]", Style::default().fg(colors.info())),
             Span::styled(" Analytics  ", Style::default().fg(colors.text())),
+            Span::styled("[E]", Style::default().fg(colors.info())),
+            Span::styled(" Achievements  ", Style::default().fg(colors.text())),
+            Span::styled("[P]", Style::default().fg(colors.info())),
+            Span::styled(" Profile  ", Style::default().fg(colors.text())),
             Span::styled("[S]", Style::default().fg(colors.info())),
             Span::styled(" Settings  ", Style::default().fg(colors.text())),
             Span::styled("[I/?]", Style::default().fg(colors.info())),
@@ -81,6 +66,8 @@ impl StaticElementsView {
         let tier3 = Line::from(vec![
             Span::styled("[SPACE]", Style::default().fg(colors.success())),
             Span::styled(" Start  ", Style::default().fg(colors.text())),
+            Span::styled("[D]", Style::default().fg(colors.success())),
+            Span::styled(" Drills  ", Style::default().fg(colors.text())),
             Span::styled("[ESC]", Style::default().fg(colors.error())),
             Span::styled(" Quit", Style::default().fg(colors.text())),
         ]);
@@ -92,4 +79,47 @@ impl StaticElementsView {
         // Render git repository info
         GitRepositoryView::render(frame, git_repository, colors);
     }
+
+    /// Top line warning that nothing is saved while another gittype owns the database
+    pub fn render_read_only_banner(frame: &mut Frame, colors: &Colors) {
+        if !ReadOnlyMode::is_enabled() {
+            return;
+        }
+        let area = frame.area();
+        let banner = Paragraph::new(Line::from(Span::styled(
+            ReadOnlyMode::BANNER,
+            Style::default().fg(colors.warning()),
+        )))
+        .alignment(Alignment::Center);
+        frame.render_widget(banner, ratatui::layout::Rect { height: 1, ..area });
+    }
+
+    /// Logo and subtitle, shared by the menu and the quick play prompt
+    pub fn render_heading(
+        frame: &mut Frame,
+        logo_area: ratatui::layout::Rect,
+        subtitle_area: ratatui::layout::Rect,
+        colors: &Colors,
+    ) {
+        // Render logo
+        let logo_lines = logo::get_logo_lines();
+        let logo_colors = logo::get_logo_colors();
+        let logo_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(1); 6])
+            .split(logo_area);
+
+        for (i, line) in logo_lines.iter().enumerate() {
+            let widget = GradationText::new(line, logo_colors).alignment(Alignment::Center);
+            frame.render_widget(widget, logo_chunks[i]);
+        }
+
+        // Render subtitle
+        let subtitle = Paragraph::new(Line::from(vec![Span::styled(
+            "Code Typing Challenge",
+            Style::default().fg(colors.text_secondary()),
+        )]))
+        .alignment(Alignment::Center);
+        frame.render_widget(subtitle, subtitle_area);
+    }
 }
@@ -0,0 +1,48 @@
+use crate::domain::models::version::ReleaseNotes;
+use crate::presentation::ui::Colors;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Announces a newer release above the logo, previewing the highlights of
+/// its notes; rows that don't fit drop the last highlights first
+pub struct UpdateBannerView;
+
+impl UpdateBannerView {
+    pub fn height(release: &ReleaseNotes) -> u16 {
+        1 + release.highlights.len() as u16
+    }
+
+    pub fn render(frame: &mut Frame, area: Rect, release: &ReleaseNotes, colors: &Colors) {
+        let mut lines = vec![Line::from(vec![
+            Span::styled(
+                format!("⬆ gittype {} is available  ", release.version),
+                Style::default()
+                    .fg(colors.info())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("[U]", Style::default().fg(colors.key_action())),
+            Span::styled(" Release notes  ", Style::default().fg(colors.text())),
+            Span::styled("[X]", Style::default().fg(colors.key_back())),
+            Span::styled(" Dismiss", Style::default().fg(colors.text())),
+        ])];
+        lines.extend(release.highlights.iter().map(|highlight| {
+            Line::from(Span::styled(
+                format!("• {}", highlight),
+                Style::default().fg(colors.text_secondary()),
+            ))
+        }));
+
+        frame.render_widget(
+            Paragraph::new(lines).alignment(Alignment::Center),
+            Rect {
+                height: area.height.min(Self::height(release)),
+                ..area
+            },
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::domain::models::TotalResult;
+use crate::domain::models::{Locale, SessionResult, TotalResult, WpmMode};
 use crate::presentation::ui::Colors;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
@@ -27,24 +27,41 @@ impl StatisticsView {
             ])
             .split(area);
 
+        let wpm_mode = WpmMode::current();
+        let (best_density, worst_density) =
+            SessionResult::best_and_worst(&total_summary.session_results)
+                .map_or((0.0, 0.0), |(best, worst)| {
+                    (best.token_density(), worst.token_density())
+                });
+
         // Line 1: Overall CPM, WPM, Accuracy
         let line1 = Line::from(vec![
             Span::styled("Overall ", Style::default().fg(colors.text())),
             Span::styled("CPM: ", Style::default().fg(colors.cpm_wpm())),
             Span::styled(
-                format!("{:.1}", total_summary.overall_cpm),
+                Locale::current().format_decimal(total_summary.overall_cpm, 1),
                 Style::default().fg(colors.text()),
             ),
             Span::styled(" | ", Style::default().fg(colors.text())),
-            Span::styled("WPM: ", Style::default().fg(colors.cpm_wpm())),
             Span::styled(
-                format!("{:.1}", total_summary.overall_wpm),
+                format!("{}: ", wpm_mode.label()),
+                Style::default().fg(colors.cpm_wpm()),
+            ),
+            Span::styled(
+                Locale::current().format_decimal(
+                    wpm_mode
+                        .from_recorded(total_summary.overall_wpm, total_summary.token_density()),
+                    1,
+                ),
                 Style::default().fg(colors.text()),
             ),
             Span::styled(" | ", Style::default().fg(colors.text())),
             Span::styled("Accuracy: ", Style::default().fg(colors.accuracy())),
             Span::styled(
-                format!("{:.1}%", total_summary.overall_accuracy),
+                format!(
+                    "{}%",
+                    Locale::current().format_decimal(total_summary.overall_accuracy, 1)
+                ),
                 Style::default().fg(colors.text()),
             ),
         ]);
@@ -87,7 +104,7 @@ impl StatisticsView {
             Span::styled("Total ", Style::default().fg(colors.text())),
             Span::styled("Keystrokes: ", Style::default().fg(colors.stage_info())),
             Span::styled(
-                format!("{}", total_summary.total_keystrokes),
+                Locale::current().format_count(total_summary.total_keystrokes),
                 Style::default().fg(colors.text()),
             ),
             Span::styled(" | ", Style::default().fg(colors.text())),
@@ -112,22 +129,36 @@ impl StatisticsView {
         let line4 = Line::from(vec![
             Span::styled("Best Session: ", Style::default().fg(colors.text())),
             Span::styled(
-                format!("{:.0} CPM", total_summary.best_session_wpm * 5.0),
+                format!(
+                    "{:.0} {}",
+                    wpm_mode.from_recorded(total_summary.best_session_wpm, best_density),
+                    wpm_mode.label()
+                ),
                 Style::default().fg(colors.cpm_wpm()),
             ),
             Span::styled(", ", Style::default().fg(colors.text())),
             Span::styled(
-                format!("{:.1}%", total_summary.best_session_accuracy),
+                format!(
+                    "{}%",
+                    Locale::current().format_decimal(total_summary.best_session_accuracy, 1)
+                ),
                 Style::default().fg(colors.accuracy()),
             ),
             Span::styled(" | Worst: ", Style::default().fg(colors.text())),
             Span::styled(
-                format!("{:.0} CPM", total_summary.worst_session_wpm * 5.0),
+                format!(
+                    "{:.0} {}",
+                    wpm_mode.from_recorded(total_summary.worst_session_wpm, worst_density),
+                    wpm_mode.label()
+                ),
                 Style::default().fg(colors.cpm_wpm()),
             ),
             Span::styled(", ", Style::default().fg(colors.text())),
             Span::styled(
-                format!("{:.1}%", total_summary.worst_session_accuracy),
+                format!(
+                    "{}%",
+                    Locale::current().format_decimal(total_summary.worst_session_accuracy, 1)
+                ),
                 Style::default().fg(colors.accuracy()),
             ),
         ]);
@@ -1,4 +1,4 @@
-use crate::domain::models::TotalResult;
+use crate::domain::models::{Locale, TotalResult};
 use crate::presentation::sharing::SharingPlatform;
 use crate::presentation::ui::Colors;
 use ratatui::{
@@ -64,19 +64,19 @@ impl SharingView {
         let preview = Line::from(vec![
             Span::styled("Score: ", Style::default().fg(colors.score())),
             Span::styled(
-                format!("{:.0}", total_summary.total_score),
+                Locale::current().format_decimal(total_summary.total_score, 0),
                 Style::default().fg(colors.text()),
             ),
             Span::styled(", ", Style::default().fg(colors.text())),
             Span::styled("CPM: ", Style::default().fg(colors.cpm_wpm())),
             Span::styled(
-                format!("{:.0}", total_summary.overall_cpm),
+                Locale::current().format_decimal(total_summary.overall_cpm, 0),
                 Style::default().fg(colors.text()),
             ),
             Span::styled(", ", Style::default().fg(colors.text())),
             Span::styled("Keystrokes: ", Style::default().fg(colors.stage_info())),
             Span::styled(
-                format!("{}", total_summary.total_keystrokes),
+                Locale::current().format_count(total_summary.total_keystrokes),
                 Style::default().fg(colors.text()),
             ),
             Span::styled(", ", Style::default().fg(colors.text())),
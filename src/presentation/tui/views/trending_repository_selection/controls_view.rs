@@ -16,6 +16,8 @@ impl ControlsView {
             Span::styled(" Navigate  ", Style::default().fg(colors.text())),
             Span::styled("[SPACE]", Style::default().fg(colors.key_action())),
             Span::styled(" Play  ", Style::default().fg(colors.text())),
+            Span::styled("[N]", Style::default().fg(colors.key_action())),
+            Span::styled(" Never suggest  ", Style::default().fg(colors.text())),
             Span::styled("[ESC]", Style::default().fg(colors.key_back())),
             Span::styled(" Return", Style::default().fg(colors.text())),
         ]);
@@ -2,9 +2,9 @@ use crate::domain::repositories::trending_repository::TrendingRepositoryInfo;
 use crate::presentation::ui::Colors;
 use ratatui::{
     layout::Rect,
-    style::{Modifier, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState, Padding},
+    widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph, Wrap},
     Frame,
 };
 
@@ -64,18 +64,7 @@ impl RepositoryListView {
             .collect();
 
         let list = List::new(items)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(Style::default().fg(colors.border()))
-                    .title("Trending Repositories")
-                    .title_style(
-                        Style::default()
-                            .fg(colors.text())
-                            .add_modifier(Modifier::BOLD),
-                    )
-                    .padding(Padding::uniform(1)),
-            )
+            .block(Self::block(colors))
             .style(Style::default().fg(colors.text()))
             .highlight_style(
                 Style::default()
@@ -84,4 +73,32 @@ impl RepositoryListView {
             );
         frame.render_stateful_widget(list, area, list_state);
     }
+
+    /// A status line in place of the list, while it is fetched or after it failed
+    pub fn render_message(
+        frame: &mut Frame,
+        area: Rect,
+        message: &str,
+        color: Color,
+        colors: &Colors,
+    ) {
+        let paragraph = Paragraph::new(message.to_string())
+            .block(Self::block(colors))
+            .style(Style::default().fg(color))
+            .wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, area);
+    }
+
+    fn block(colors: &Colors) -> Block<'static> {
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.border()))
+            .title("Trending Repositories")
+            .title_style(
+                Style::default()
+                    .fg(colors.text())
+                    .add_modifier(Modifier::BOLD),
+            )
+            .padding(Padding::uniform(1))
+    }
 }
@@ -1,15 +1,25 @@
 pub mod typing_animation_view;
+pub mod typing_big_wpm_view;
 pub mod typing_content_view;
 pub mod typing_countdown_view;
 pub mod typing_dialog_view;
 pub mod typing_footer_view;
 pub mod typing_header_view;
+pub mod typing_minimap_view;
+pub mod typing_paste_notice_view;
+pub mod typing_race_view;
+pub mod typing_stats_view;
 pub mod typing_view;
 
 pub use typing_animation_view::TypingAnimationView;
+pub use typing_big_wpm_view::TypingBigWpmView;
 pub use typing_content_view::TypingContentView;
 pub use typing_countdown_view::TypingCountdownView;
 pub use typing_dialog_view::TypingDialogView;
 pub use typing_footer_view::TypingFooterView;
 pub use typing_header_view::TypingHeaderView;
+pub use typing_minimap_view::TypingMinimapView;
+pub use typing_paste_notice_view::TypingPasteNoticeView;
+pub use typing_race_view::TypingRaceView;
+pub use typing_stats_view::TypingStatsView;
 pub use typing_view::TypingView;
@@ -0,0 +1,78 @@
+use crate::domain::models::WpmMode;
+use crate::presentation::ui::{AsciiNumbersWidget, BrailleChartWidget, Colors};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Borders},
+    Frame,
+};
+
+/// Side panel showing the live WPM in large digits, for streaming, with
+/// its recent history charted below when the panel is tall enough
+pub struct TypingBigWpmView;
+
+impl TypingBigWpmView {
+    /// Wide enough for three digits plus the panel border
+    pub const WIDTH: u16 = 28;
+
+    /// Rows of the WPM history chart, drawn one row below the digits
+    pub const CHART_HEIGHT: u16 = 2;
+
+    /// Seconds of history charted, one braille dot column each
+    const CHART_SECONDS: usize = 60;
+
+    pub fn render(frame: &mut Frame, area: Rect, wpm: f64, history: &[f64], colors: &Colors) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.border()))
+            .title(WpmMode::current().label())
+            .title_alignment(Alignment::Center)
+            .title_style(
+                Style::default()
+                    .fg(colors.text_secondary())
+                    .add_modifier(Modifier::BOLD),
+            );
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chart_height = if history.len() > 1
+            && inner.height > AsciiNumbersWidget::HEIGHT + Self::CHART_HEIGHT
+        {
+            Self::CHART_HEIGHT + 1
+        } else {
+            0
+        };
+        let number_area = Rect {
+            y: inner.y
+                + inner
+                    .height
+                    .saturating_sub(AsciiNumbersWidget::HEIGHT + chart_height)
+                    / 2,
+            height: AsciiNumbersWidget::HEIGHT.min(inner.height),
+            ..inner
+        };
+        let wpm_value = format!("{:.0}", wpm.max(0.0));
+        frame.render_widget(
+            AsciiNumbersWidget::new(&wpm_value).style(
+                Style::default()
+                    .fg(colors.score())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            number_area,
+        );
+
+        if chart_height > 0 {
+            let recent = &history[history.len().saturating_sub(Self::CHART_SECONDS)..];
+            let chart_area = Rect {
+                x: inner.x + 1,
+                y: number_area.y + number_area.height + 1,
+                width: inner.width.saturating_sub(2),
+                height: Self::CHART_HEIGHT,
+            };
+            frame.render_widget(
+                BrailleChartWidget::new(recent).style(Style::default().fg(colors.cpm_wpm())),
+                chart_area,
+            );
+        }
+    }
+}
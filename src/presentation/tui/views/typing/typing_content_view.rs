@@ -9,11 +9,26 @@ use ratatui::{
     Frame,
 };
 
+/// Width of the `"  12 │ "` line number gutter
+const GUTTER_WIDTH: u16 = 7;
+/// Borders and padding around the code
+const FRAME_WIDTH: u16 = 4;
+
 pub struct TypingContentView {
     // Individual caches with different update frequencies
     pre_context_cache: Option<(u64, Vec<Line<'static>>)>,
     post_context_cache: Option<(u64, Vec<Line<'static>>)>,
-    main_content_cache: Option<(u64, Vec<Line<'static>>)>,
+    main_content_cache: Option<(u64, Vec<Line<'static>>, Option<usize>)>,
+    // Next challenge laid out ahead of time, moved into the caches above
+    // when it is shown
+    prepared_content: Option<PreparedContent>,
+}
+
+/// Layout of a challenge made before it is shown
+pub struct PreparedContent {
+    pre_context: (u64, Vec<Line<'static>>),
+    post_context: (u64, Vec<Line<'static>>),
+    main_content: (u64, Vec<Line<'static>>, Option<usize>),
 }
 
 impl Default for TypingContentView {
@@ -27,9 +42,97 @@ impl TypingContentView {
             pre_context_cache: None,
             post_context_cache: None,
             main_content_cache: None,
+            prepared_content: None,
         }
     }
 
+    pub fn has_prepared_content(&self) -> bool {
+        self.prepared_content.is_some()
+    }
+
+    pub fn take_prepared_content(&mut self) -> Option<PreparedContent> {
+        self.prepared_content.take()
+    }
+
+    pub fn set_prepared_content(&mut self, prepared: PreparedContent) {
+        self.prepared_content = Some(prepared);
+    }
+
+    /// Lay out a challenge that isn't shown yet at its starting position, so
+    /// its first frame in `area` comes from the cache
+    pub fn prepare(
+        &mut self,
+        area: ratatui::layout::Rect,
+        challenge: &Challenge,
+        typing_core: &TypingCore,
+        code_context: &CodeContext,
+        colors: &Colors,
+    ) {
+        let terminal_width = area.width;
+        let view_height = area.height.saturating_sub(2);
+        let chars: Vec<char> = typing_core.text_to_display().chars().collect();
+        let start_line_number = challenge.start_line.unwrap_or(1);
+
+        let mut pre_context = Vec::new();
+        self.add_pre_context_lines(
+            &mut pre_context,
+            terminal_width,
+            code_context,
+            start_line_number,
+            colors,
+        );
+        let mut post_context = Vec::new();
+        self.add_post_context_lines(
+            &mut post_context,
+            terminal_width,
+            code_context,
+            Some(challenge),
+            start_line_number,
+            colors,
+        );
+        let mut main_content = Vec::new();
+        let cursor_line = self.process_main_content(
+            &mut main_content,
+            terminal_width,
+            typing_core,
+            &chars,
+            start_line_number,
+            colors,
+        );
+
+        self.prepared_content = Some(PreparedContent {
+            pre_context: (
+                self.calculate_pre_context_cache_key(
+                    terminal_width,
+                    code_context,
+                    start_line_number,
+                ),
+                pre_context,
+            ),
+            post_context: (
+                self.calculate_post_context_cache_key(
+                    terminal_width,
+                    code_context,
+                    Some(challenge),
+                    start_line_number,
+                ),
+                post_context,
+            ),
+            main_content: (
+                self.calculate_main_content_cache_key(
+                    terminal_width,
+                    Some(challenge),
+                    typing_core,
+                    &chars,
+                    start_line_number,
+                    view_height,
+                ),
+                main_content,
+                cursor_line,
+            ),
+        });
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn render(
         &mut self,
@@ -44,7 +147,7 @@ impl TypingContentView {
     ) {
         if show_code {
             let view_height = area.height.saturating_sub(2);
-            let content_spans = self.create_content_spans(
+            let (content_spans, cursor_line_index) = self.create_content_spans(
                 area.width,
                 challenge,
                 typing_core,
@@ -55,15 +158,17 @@ impl TypingContentView {
             );
             let total_lines = content_spans.len() as u16;
 
-            let current_display_line_index =
-                self.find_current_display_line_index(&content_spans, typing_core);
-            let effective_line_index = if current_display_line_index == 0 {
-                let pre_context_lines = code_context.pre_context.len() as u16;
-                let current_line = typing_core.current_line_to_display() as u16;
-                pre_context_lines + current_line
-            } else {
-                current_display_line_index
-            };
+            let effective_line_index = cursor_line_index.unwrap_or_else(|| {
+                let current_display_line_index =
+                    self.find_current_display_line_index(&content_spans, typing_core);
+                if current_display_line_index == 0 {
+                    let pre_context_lines = code_context.pre_context.len() as u16;
+                    let current_line = typing_core.current_line_to_display() as u16;
+                    pre_context_lines + current_line
+                } else {
+                    current_display_line_index
+                }
+            });
 
             let scroll_offset =
                 Self::calculate_scroll_offset(view_height, total_lines, effective_line_index);
@@ -92,6 +197,7 @@ impl TypingContentView {
         }
     }
 
+    /// Display lines and the index of the one holding the cursor
     #[allow(clippy::too_many_arguments)]
     fn create_content_spans(
         &mut self,
@@ -102,15 +208,27 @@ impl TypingContentView {
         code_context: &CodeContext,
         view_height: u16,
         colors: &Colors,
-    ) -> Vec<Line<'static>> {
+    ) -> (Vec<Line<'static>>, Option<u16>) {
         let mut lines = Vec::new();
         let start_line_number = challenge.and_then(|c| c.start_line).unwrap_or(1);
+        self.promote_prepared_content(
+            terminal_width,
+            challenge,
+            typing_core,
+            chars,
+            start_line_number,
+            view_height,
+        );
 
-        let pre_context_lines =
-            self.get_cached_pre_context_lines(code_context, start_line_number, colors);
+        let pre_context_lines = self.get_cached_pre_context_lines(
+            terminal_width,
+            code_context,
+            start_line_number,
+            colors,
+        );
         lines.extend(pre_context_lines);
 
-        let main_content_lines = self.get_cached_main_content_lines(
+        let (main_content_lines, cursor_line) = self.get_cached_main_content_lines(
             terminal_width,
             challenge,
             typing_core,
@@ -119,10 +237,16 @@ impl TypingContentView {
             view_height,
             colors,
         );
+        let cursor_line_index = cursor_line.map(|line| (lines.len() + line) as u16);
         lines.extend(main_content_lines);
 
-        let post_context_lines =
-            self.get_cached_post_context_lines(code_context, challenge, start_line_number, colors);
+        let post_context_lines = self.get_cached_post_context_lines(
+            terminal_width,
+            code_context,
+            challenge,
+            start_line_number,
+            colors,
+        );
         lines.extend(post_context_lines);
 
         if lines.is_empty() {
@@ -135,12 +259,45 @@ impl TypingContentView {
             lines.push(Line::from(vec![Span::raw("")]));
         }
 
-        lines
+        (lines, cursor_line_index)
+    }
+
+    /// Use the prepared layout once the challenge it was made for is shown
+    fn promote_prepared_content(
+        &mut self,
+        terminal_width: u16,
+        challenge: Option<&Challenge>,
+        typing_core: &TypingCore,
+        chars: &[char],
+        start_line_number: usize,
+        view_height: u16,
+    ) {
+        let Some(prepared) = self.prepared_content.as_ref() else {
+            return;
+        };
+        let main_content_key = self.calculate_main_content_cache_key(
+            terminal_width,
+            challenge,
+            typing_core,
+            chars,
+            start_line_number,
+            view_height,
+        );
+        if prepared.main_content.0 != main_content_key {
+            return;
+        }
+
+        if let Some(prepared) = self.prepared_content.take() {
+            self.pre_context_cache = Some(prepared.pre_context);
+            self.post_context_cache = Some(prepared.post_context);
+            self.main_content_cache = Some(prepared.main_content);
+        }
     }
 
     fn add_pre_context_lines(
         &self,
         lines: &mut Vec<Line<'static>>,
+        terminal_width: u16,
         code_context: &CodeContext,
         start_line_number: usize,
         colors: &Colors,
@@ -148,21 +305,19 @@ impl TypingContentView {
         for (ctx_idx, pre_line) in code_context.pre_context.iter().enumerate() {
             let ctx_line_number =
                 start_line_number.saturating_sub(code_context.pre_context.len() - ctx_idx);
-            let line_num_span = Span::styled(
-                format!("{:>4} │ ", ctx_line_number),
-                Style::default().fg(colors.text_secondary()),
-            );
-            let content_span = Span::styled(
-                pre_line.clone(),
-                Style::default().fg(colors.text_secondary()),
-            );
-            lines.push(Line::from(vec![line_num_span, content_span]));
+            lines.extend(self.create_context_lines(
+                ctx_line_number,
+                pre_line,
+                terminal_width,
+                colors,
+            ));
         }
     }
 
     fn add_post_context_lines(
         &self,
         lines: &mut Vec<Line<'static>>,
+        terminal_width: u16,
         code_context: &CodeContext,
         challenge: Option<&Challenge>,
         start_line_number: usize,
@@ -174,25 +329,40 @@ impl TypingContentView {
 
         for (ctx_idx, post_line) in code_context.post_context.iter().enumerate() {
             let ctx_line_number = end_line_number + ctx_idx + 1;
-            let line_num_span = Span::styled(
-                format!("{:>4} │ ", ctx_line_number),
-                Style::default().fg(colors.text_secondary()),
-            );
-            let content_span = Span::styled(
-                post_line.clone(),
-                Style::default().fg(colors.text_secondary()),
-            );
-            lines.push(Line::from(vec![line_num_span, content_span]));
+            lines.extend(self.create_context_lines(
+                ctx_line_number,
+                post_line,
+                terminal_width,
+                colors,
+            ));
         }
     }
 
+    fn create_context_lines(
+        &self,
+        line_number: usize,
+        text: &str,
+        terminal_width: u16,
+        colors: &Colors,
+    ) -> Vec<Line<'static>> {
+        let style = Style::default().fg(colors.text_secondary());
+        let gutter = Span::styled(format!("{:>4} │ ", line_number), style);
+        let cells = text
+            .chars()
+            .map(|ch| Span::styled(self.format_character(ch), style))
+            .collect();
+        Self::wrap_cells(gutter, cells, terminal_width, None, colors).0
+    }
+
     fn get_cached_pre_context_lines(
         &mut self,
+        terminal_width: u16,
         code_context: &CodeContext,
         start_line_number: usize,
         colors: &Colors,
     ) -> Vec<Line<'static>> {
-        let cache_key = self.calculate_pre_context_cache_key(code_context, start_line_number);
+        let cache_key =
+            self.calculate_pre_context_cache_key(terminal_width, code_context, start_line_number);
 
         // Check if we can use cached result
         if let Some((cached_key, ref cached_lines)) = self.pre_context_cache {
@@ -203,7 +373,13 @@ impl TypingContentView {
 
         // Generate new lines
         let mut lines = Vec::new();
-        self.add_pre_context_lines(&mut lines, code_context, start_line_number, colors);
+        self.add_pre_context_lines(
+            &mut lines,
+            terminal_width,
+            code_context,
+            start_line_number,
+            colors,
+        );
 
         // Cache the result
         self.pre_context_cache = Some((cache_key, lines.clone()));
@@ -212,13 +388,18 @@ impl TypingContentView {
 
     fn get_cached_post_context_lines(
         &mut self,
+        terminal_width: u16,
         code_context: &CodeContext,
         challenge: Option<&Challenge>,
         start_line_number: usize,
         colors: &Colors,
     ) -> Vec<Line<'static>> {
-        let cache_key =
-            self.calculate_post_context_cache_key(code_context, challenge, start_line_number);
+        let cache_key = self.calculate_post_context_cache_key(
+            terminal_width,
+            code_context,
+            challenge,
+            start_line_number,
+        );
 
         // Check if we can use cached result
         if let Some((cached_key, ref cached_lines)) = self.post_context_cache {
@@ -231,6 +412,7 @@ impl TypingContentView {
         let mut lines = Vec::new();
         self.add_post_context_lines(
             &mut lines,
+            terminal_width,
             code_context,
             challenge,
             start_line_number,
@@ -252,7 +434,7 @@ impl TypingContentView {
         start_line_number: usize,
         view_height: u16,
         colors: &Colors,
-    ) -> Vec<Line<'static>> {
+    ) -> (Vec<Line<'static>>, Option<usize>) {
         let cache_key = self.calculate_main_content_cache_key(
             terminal_width,
             challenge,
@@ -263,15 +445,15 @@ impl TypingContentView {
         );
 
         // Check if we can use cached result
-        if let Some((cached_key, ref cached_lines)) = self.main_content_cache {
+        if let Some((cached_key, ref cached_lines, cursor_line)) = self.main_content_cache {
             if cached_key == cache_key {
-                return cached_lines.clone();
+                return (cached_lines.clone(), cursor_line);
             }
         }
 
         // Generate new lines
         let mut lines = Vec::new();
-        self.process_main_content(
+        let cursor_line = self.process_main_content(
             &mut lines,
             terminal_width,
             typing_core,
@@ -281,10 +463,12 @@ impl TypingContentView {
         );
 
         // Cache the result
-        self.main_content_cache = Some((cache_key, lines.clone()));
-        lines
+        self.main_content_cache = Some((cache_key, lines.clone(), cursor_line));
+        (lines, cursor_line)
     }
 
+    /// Soft-wraps each source line to the view width; returns the display
+    /// line holding the cursor
     fn process_main_content(
         &self,
         lines: &mut Vec<Line<'static>>,
@@ -293,71 +477,94 @@ impl TypingContentView {
         chars: &[char],
         start_line_number: usize,
         colors: &Colors,
-    ) {
-        let line_number_width = 6u16;
-        let max_width = terminal_width.saturating_sub(line_number_width + 1);
-
-        let mut current_line_spans = Vec::new();
-        let mut current_line_width = 0u16;
-        let mut line_number = 0;
-        let mut line_start = true;
-        let mut byte_position = 0;
-
+    ) -> Option<usize> {
         let current_display_position = typing_core.current_position_to_display();
         let current_mistake_position = typing_core.current_mistake_position();
         let current_line_number = typing_core.current_line_to_display();
         let display_comment_ranges = typing_core.display_comment_ranges();
 
-        for (i, &ch) in chars.iter().enumerate() {
-            // Add line number at the start of each line
-            if line_start {
-                let line_num_span = self.create_line_number_span(
-                    start_line_number + line_number,
-                    line_number == current_line_number,
-                    colors,
-                );
-                current_line_spans.push(line_num_span);
-                current_line_width += line_number_width;
-                line_start = false;
-            }
-
-            // Handle newlines
-            if ch == '\n' {
-                lines.push(Line::from(current_line_spans));
-                current_line_spans = Vec::new();
-                current_line_width = 0;
-                line_number += 1;
-                line_start = true;
-                byte_position += ch.len_utf8();
-                continue;
-            }
+        let mut cursor_line = None;
+        let mut char_index = 0;
+        let mut byte_position = 0;
 
-            let is_in_comment = self.is_in_comment_range(byte_position, &display_comment_ranges);
-            let style = self.determine_character_style(
-                i,
-                is_in_comment,
-                current_display_position,
-                current_mistake_position,
+        for (line_number, source_line) in chars.split_inclusive(|&c| c == '\n').enumerate() {
+            let gutter = self.create_line_number_span(
+                start_line_number + line_number,
+                line_number == current_line_number,
                 colors,
             );
 
-            let (display_char, char_width) = self.format_character(ch);
-
-            // Check if we need to wrap
-            if current_line_width + char_width > max_width {
-                lines.push(Line::from(current_line_spans));
-                current_line_spans = Vec::new();
-                current_line_width = 0;
+            let mut cells = Vec::with_capacity(source_line.len());
+            let mut cursor_cell = None;
+            for &ch in source_line {
+                if char_index == current_display_position {
+                    cursor_cell = Some(cells.len());
+                }
+                if ch != '\n' {
+                    let is_in_comment =
+                        self.is_in_comment_range(byte_position, &display_comment_ranges);
+                    let style = self.determine_character_style(
+                        char_index,
+                        is_in_comment,
+                        current_display_position,
+                        current_mistake_position,
+                        colors,
+                    );
+                    cells.push(Span::styled(self.format_character(ch), style));
+                }
+                char_index += 1;
+                byte_position += ch.len_utf8();
             }
 
-            current_line_spans.push(Span::styled(display_char, style));
-            current_line_width += char_width;
-            byte_position += ch.len_utf8();
+            let (wrapped, cursor_row) =
+                Self::wrap_cells(gutter, cells, terminal_width, cursor_cell, colors);
+            if let Some(row) = cursor_row {
+                cursor_line = Some(lines.len() + row);
+            }
+            lines.extend(wrapped);
         }
 
-        if !current_line_spans.is_empty() {
-            lines.push(Line::from(current_line_spans));
+        cursor_line
+    }
+
+    /// Splits one source line into display lines that fit the view, marking
+    /// continuations in the gutter. Also returns the display line holding
+    /// `marked_cell`; an index past the end marks the last one.
+    fn wrap_cells(
+        gutter: Span<'static>,
+        cells: Vec<Span<'static>>,
+        terminal_width: u16,
+        marked_cell: Option<usize>,
+        colors: &Colors,
+    ) -> (Vec<Line<'static>>, Option<usize>) {
+        let content_width = terminal_width
+            .saturating_sub(FRAME_WIDTH + GUTTER_WIDTH)
+            .max(1) as usize;
+        let continuation = Span::styled(
+            format!("{:>4} │ ", "↪"),
+            Style::default().fg(colors.text_secondary()),
+        );
+
+        let mut rows = vec![vec![gutter]];
+        let mut row_width = 0;
+        let mut marked_row = None;
+        for (index, cell) in cells.into_iter().enumerate() {
+            let width = cell.width();
+            if row_width > 0 && row_width + width > content_width {
+                rows.push(vec![continuation.clone()]);
+                row_width = 0;
+            }
+            if marked_cell == Some(index) {
+                marked_row = Some(rows.len() - 1);
+            }
+            row_width += width;
+            if let Some(row) = rows.last_mut() {
+                row.push(cell);
+            }
         }
+
+        let marked_row = marked_row.or(marked_cell.map(|_| rows.len() - 1));
+        (rows.into_iter().map(Line::from).collect(), marked_row)
     }
 
     fn create_line_number_span(
@@ -416,16 +623,17 @@ impl TypingContentView {
         }
     }
 
-    fn format_character(&self, ch: char) -> (String, u16) {
+    fn format_character(&self, ch: char) -> String {
         match ch {
-            '\t' => ("    ".to_string(), 4),
-            c if c.is_control() => ("?".to_string(), 1),
-            c => (c.to_string(), 1),
+            '\t' => "    ".to_string(),
+            c if c.is_control() => "?".to_string(),
+            c => c.to_string(),
         }
     }
 
     fn calculate_pre_context_cache_key(
         &self,
+        terminal_width: u16,
         code_context: &CodeContext,
         start_line_number: usize,
     ) -> u64 {
@@ -435,6 +643,7 @@ impl TypingContentView {
         let mut hasher = DefaultHasher::new();
 
         // Pre-context rarely changes
+        terminal_width.hash(&mut hasher);
         start_line_number.hash(&mut hasher);
         code_context.pre_context.hash(&mut hasher);
 
@@ -443,6 +652,7 @@ impl TypingContentView {
 
     fn calculate_post_context_cache_key(
         &self,
+        terminal_width: u16,
         code_context: &CodeContext,
         challenge: Option<&Challenge>,
         start_line_number: usize,
@@ -452,6 +662,7 @@ impl TypingContentView {
 
         let mut hasher = DefaultHasher::new();
 
+        terminal_width.hash(&mut hasher);
         // Post-context rarely changes
         if let Some(challenge) = challenge {
             challenge.end_line.hash(&mut hasher);
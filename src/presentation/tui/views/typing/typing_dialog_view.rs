@@ -1,3 +1,4 @@
+use crate::domain::models::StreamerMode;
 use crate::presentation::ui::Colors;
 use ratatui::{
     layout::Rect,
@@ -10,11 +11,11 @@ use ratatui::{
 pub struct TypingDialogView;
 
 impl TypingDialogView {
-    pub fn render(frame: &mut Frame, skips_remaining: usize, colors: &Colors) {
+    pub fn render(frame: &mut Frame, skips_remaining: usize, big_wpm_shown: bool, colors: &Colors) {
         // Calculate dialog size and position
         let area = frame.area();
-        let dialog_width = 50.min(area.width - 4);
-        let dialog_height = 9;
+        let dialog_width = 50.min(area.width.saturating_sub(4));
+        let dialog_height = 11.min(area.height);
 
         let dialog_area = Rect {
             x: (area.width - dialog_width) / 2,
@@ -59,6 +60,45 @@ impl TypingDialogView {
                     )
                 },
             ]),
+            Line::from(vec![
+                Span::styled(
+                    "[F] ",
+                    Style::default()
+                        .fg(colors.warning())
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    if skips_remaining > 0 {
+                        "Flag as bad & skip"
+                    } else {
+                        "Flag as bad"
+                    },
+                    Style::default().fg(colors.text()),
+                ),
+            ]),
+            if StreamerMode::is_enabled() {
+                Line::from(Span::styled(
+                    "Big WPM on (streamer mode)",
+                    Style::default().fg(colors.text_secondary()),
+                ))
+            } else {
+                Line::from(vec![
+                    Span::styled(
+                        "[W] ",
+                        Style::default()
+                            .fg(colors.info())
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        if big_wpm_shown {
+                            "Hide big WPM"
+                        } else {
+                            "Show big WPM"
+                        },
+                        Style::default().fg(colors.text()),
+                    ),
+                ])
+            },
             Line::from(vec![
                 Span::styled(
                     "[Q] ",
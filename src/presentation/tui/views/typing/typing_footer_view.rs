@@ -1,10 +1,11 @@
+use crate::domain::models::WpmMode;
 use crate::domain::services::scoring::RealTimeCalculator;
 use crate::{
     domain::services::scoring::tracker::stage::StageTracker,
     domain::services::typing_core::TypingCore, presentation::ui::Colors,
 };
 use ratatui::{
-    style::Style,
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Gauge, Paragraph},
     Frame,
@@ -13,6 +14,9 @@ use ratatui::{
 pub struct TypingFooterView;
 
 impl TypingFooterView {
+    /// Mistakes listed in the recent errors strip
+    pub const RECENT_MISTAKES: usize = 5;
+
     #[allow(clippy::too_many_arguments)]
     pub fn render_metrics(
         frame: &mut Frame,
@@ -24,44 +28,127 @@ impl TypingFooterView {
         typing_core: &TypingCore,
         colors: &Colors,
     ) {
-        let metrics_line = if waiting_to_start || countdown_active {
-            // Show zeros during waiting and countdown
-            format!(
-                "WPM: 0 | CPM: 0 | Accuracy: 0% | Mistakes: 0 | Streak: 0 | Time: 0s | Skips: {}",
-                skips_remaining
-            )
-        } else {
-            let elapsed_time = stage_tracker.get_data().elapsed_time;
+        let metrics_line = Self::metrics(
+            waiting_to_start,
+            countdown_active,
+            skips_remaining,
+            stage_tracker,
+            typing_core,
+        )
+        .iter()
+        .map(|(label, value)| format!("{}: {}", label, value))
+        .collect::<Vec<_>>()
+        .join(" | ");
 
-            // Use typing_core position (correctly typed characters) and mistakes for RealtimeCalculator
-            let current_position = typing_core.current_position_to_type();
-            let mistakes = typing_core.mistakes();
-
-            let metrics = RealTimeCalculator::calculate(current_position, mistakes, elapsed_time);
-            let elapsed_secs = elapsed_time.as_secs();
-
-            let streak = stage_tracker.get_data().current_streak;
-            format!(
-                "WPM: {:.0} | CPM: {:.0} | Accuracy: {:.0}% | Mistakes: {} | Streak: {} | Time: {}s | Skips: {}",
-                metrics.wpm, metrics.cpm, metrics.accuracy, metrics.mistakes, streak, elapsed_secs, skips_remaining
-            )
-        };
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.border()))
+            .title("Metrics")
+            .title_style(Style::default().fg(colors.text_secondary()))
+            .padding(ratatui::widgets::Padding::horizontal(1));
+        if let Some(recent_mistakes) = Self::recent_mistakes_line(
+            &stage_tracker.recent_mistakes(Self::RECENT_MISTAKES),
+            colors,
+        ) {
+            block = block.title_bottom(recent_mistakes.right_aligned());
+        }
 
         let metrics_widget = Paragraph::new(vec![Line::from(vec![Span::styled(
             metrics_line,
             Style::default().fg(colors.text_secondary()),
         )])])
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(colors.border()))
-                .title("Metrics")
-                .title_style(Style::default().fg(colors.text_secondary()))
-                .padding(ratatui::widgets::Padding::horizontal(1)),
-        );
+        .block(block);
         frame.render_widget(metrics_widget, area);
     }
 
+    /// `expected→typed` pairs of the latest mistakes, or `None` before the first one
+    pub fn recent_mistakes_line(
+        mistakes: &[(char, char)],
+        colors: &Colors,
+    ) -> Option<Line<'static>> {
+        if mistakes.is_empty() {
+            return None;
+        }
+        let spans = mistakes.iter().flat_map(|&(expected, typed)| {
+            [
+                Span::raw(" "),
+                Span::styled(
+                    Self::visible_char(expected).to_string(),
+                    Style::default().fg(colors.text()),
+                ),
+                Span::styled("→", Style::default().fg(colors.text_secondary())),
+                Span::styled(
+                    Self::visible_char(typed).to_string(),
+                    Style::default()
+                        .fg(colors.error())
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]
+        });
+        Some(Line::from(
+            std::iter::once(Span::styled(
+                " Recent errors:",
+                Style::default().fg(colors.text_secondary()),
+            ))
+            .chain(spans)
+            .chain(std::iter::once(Span::raw(" ")))
+            .collect::<Vec<_>>(),
+        ))
+    }
+
+    /// Whitespace shown as a visible symbol
+    fn visible_char(ch: char) -> char {
+        match ch {
+            ' ' => '␣',
+            '\n' => '⏎',
+            '\t' => '⇥',
+            ch => ch,
+        }
+    }
+
+    /// Live stats as label and value pairs, zeroed during waiting and countdown
+    pub fn metrics(
+        waiting_to_start: bool,
+        countdown_active: bool,
+        skips_remaining: usize,
+        stage_tracker: &StageTracker,
+        typing_core: &TypingCore,
+    ) -> Vec<(&'static str, String)> {
+        let (wpm, cpm, accuracy, mistakes, streak, elapsed_secs) =
+            if waiting_to_start || countdown_active {
+                (0.0, 0.0, 0.0, 0, 0, 0)
+            } else {
+                let data = stage_tracker.get_data();
+                let elapsed_time = data.elapsed_time;
+
+                // Use typing_core position (correctly typed characters) and mistakes for RealtimeCalculator
+                let metrics = RealTimeCalculator::calculate(
+                    &data.target_text,
+                    typing_core.current_position_to_type(),
+                    typing_core.mistakes(),
+                    elapsed_time,
+                );
+                (
+                    metrics.wpm,
+                    metrics.cpm,
+                    metrics.accuracy,
+                    metrics.mistakes,
+                    data.current_streak,
+                    elapsed_time.as_secs(),
+                )
+            };
+
+        vec![
+            (WpmMode::current().label(), format!("{:.0}", wpm)),
+            ("CPM", format!("{:.0}", cpm)),
+            ("Accuracy", format!("{:.0}%", accuracy)),
+            ("Mistakes", mistakes.to_string()),
+            ("Streak", streak.to_string()),
+            ("Time", format!("{}s", elapsed_secs)),
+            ("Skips", skips_remaining.to_string()),
+        ]
+    }
+
     pub fn render_progress(
         frame: &mut Frame,
         area: ratatui::layout::Rect,
@@ -1,4 +1,4 @@
-use crate::domain::models::Languages;
+use crate::domain::models::{Languages, StreamerMode};
 use crate::{
     domain::models::{Challenge, GitRepository},
     presentation::ui::Colors,
@@ -26,7 +26,11 @@ impl TypingHeaderView {
                 None => "Unknown".to_string(),
             };
 
-            let base_title = challenge.get_display_title_with_repo(&git_repository.cloned());
+            let base_title = if StreamerMode::is_enabled() {
+                StreamerMode::HIDDEN_REPOSITORY.to_string()
+            } else {
+                challenge.get_display_title_with_repo(&git_repository.cloned())
+            };
 
             // Create spans for colored language display before difficulty
             let mut spans = vec![Span::styled(
@@ -0,0 +1,94 @@
+use crate::presentation::ui::Colors;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::ops::Range;
+
+/// Scaled-down outline of the whole challenge, marking the typed lines and
+/// the current one
+pub struct TypingMinimapView;
+
+impl TypingMinimapView {
+    const MARK: char = '▬';
+
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        text: &str,
+        current_line: Option<usize>,
+        colors: &Colors,
+    ) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.border()))
+            .title("Minimap")
+            .title_style(Style::default().fg(colors.text_secondary()));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let lines: Vec<Line> = Self::rows(text, inner.height as usize, inner.width as usize)
+            .into_iter()
+            .map(|(source_lines, outline)| {
+                let color = match current_line {
+                    Some(current) if source_lines.contains(&current) => colors.current_cursor(),
+                    Some(current) if source_lines.end <= current => colors.typed_text(),
+                    _ => colors.untyped_text(),
+                };
+                Line::from(Span::styled(outline, Style::default().fg(color)))
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+
+    /// One outline row per group of source lines, so that the whole text fits
+    /// in `height` rows; indentation and length are scaled to fit `width`
+    pub fn rows(text: &str, height: usize, width: usize) -> Vec<(Range<usize>, String)> {
+        let lines: Vec<&str> = text.lines().collect();
+        let longest = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        if height == 0 || width == 0 || longest == 0 {
+            return Vec::new();
+        }
+
+        let scale = (width as f64 / longest as f64).min(1.0);
+        let scaled = |len: usize| (len as f64 * scale).round() as usize;
+        let group_size = lines.len().div_ceil(height);
+
+        lines
+            .chunks(group_size)
+            .enumerate()
+            .map(|(index, group)| {
+                let start = index * group_size;
+                let outline = group
+                    .iter()
+                    .filter(|line| !line.trim().is_empty())
+                    .map(|line| {
+                        let indent = line.chars().take_while(|ch| ch.is_whitespace()).count();
+                        let length = line.trim_end().chars().count();
+                        (indent, length)
+                    })
+                    .reduce(|(indent, length), (next_indent, next_length)| {
+                        (indent.min(next_indent), length.max(next_length))
+                    })
+                    .map(|(indent, length)| {
+                        let indent_width = scaled(indent).min(width - 1);
+                        let marks = scaled(length - indent).clamp(1, width - indent_width);
+                        format!(
+                            "{}{}",
+                            " ".repeat(indent_width),
+                            Self::MARK.to_string().repeat(marks)
+                        )
+                    })
+                    .unwrap_or_default();
+                (start..start + group.len(), outline)
+            })
+            .collect()
+    }
+}
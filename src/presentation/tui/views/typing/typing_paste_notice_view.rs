@@ -0,0 +1,36 @@
+use crate::presentation::ui::Colors;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Bottom-line notice that a paste was ignored instead of typed
+pub struct TypingPasteNoticeView;
+
+impl TypingPasteNoticeView {
+    pub const MESSAGE: &'static str = "Pasting is disabled — type the code yourself";
+
+    pub fn render(frame: &mut Frame, colors: &Colors) {
+        let area = frame.area();
+        let width = (Self::MESSAGE.chars().count() as u16).min(area.width);
+        let notice_area = Rect {
+            x: area.width.saturating_sub(width) / 2,
+            y: area.height.saturating_sub(1),
+            width,
+            height: 1,
+        };
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                Self::MESSAGE,
+                Style::default()
+                    .fg(colors.warning())
+                    .add_modifier(Modifier::BOLD),
+            )))
+            .alignment(Alignment::Center),
+            notice_area,
+        );
+    }
+}
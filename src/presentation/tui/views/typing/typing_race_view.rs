@@ -0,0 +1,97 @@
+use crate::presentation::ui::Colors;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph},
+    Frame,
+};
+
+/// Progress of the player and every race bot, one bar per racer, replacing
+/// the single progress bar when bots are racing
+pub struct TypingRaceView;
+
+impl TypingRaceView {
+    const PLAYER_LABEL: &'static str = "You";
+
+    /// Height fitting the player, `bot_count` bots and the panel border
+    pub fn height(bot_count: usize) -> u16 {
+        bot_count as u16 + 3
+    }
+
+    /// The player's place among the racers; ties go to the player
+    pub fn place(player_percent: u16, bots: &[(String, u16)]) -> usize {
+        1 + bots
+            .iter()
+            .filter(|(_, percent)| *percent > player_percent)
+            .count()
+    }
+
+    /// `bots` holds each bot's label with its percent done
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        player_percent: u16,
+        bots: &[(String, u16)],
+        colors: &Colors,
+    ) {
+        let label_width = bots
+            .iter()
+            .map(|(label, _)| label.chars().count())
+            .chain([Self::PLAYER_LABEL.len()])
+            .max()
+            .unwrap_or(0);
+        let bar_width = (area.width as usize)
+            .saturating_sub(4) // border and padding
+            .saturating_sub(label_width + 6); // spaces and the percent
+        let row = |label: &str, percent: u16, color| {
+            let filled = bar_width * percent.min(100) as usize / 100;
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<width$} ", label, width = label_width),
+                    Style::default().fg(colors.text_secondary()),
+                ),
+                Span::styled("█".repeat(filled), Style::default().fg(color)),
+                Span::styled(
+                    "░".repeat(bar_width - filled),
+                    Style::default().fg(colors.border()),
+                ),
+                Span::styled(
+                    format!(" {:>3}%", percent),
+                    Style::default().fg(colors.text()),
+                ),
+            ])
+        };
+
+        let lines: Vec<Line> =
+            std::iter::once(row(Self::PLAYER_LABEL, player_percent, colors.success()))
+                .chain(
+                    bots.iter()
+                        .map(|(label, percent)| row(label, *percent, colors.text_secondary())),
+                )
+                .collect();
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.border()))
+            .title(format!(
+                "Race · {} of {}",
+                Self::ordinal(Self::place(player_percent, bots)),
+                bots.len() + 1
+            ))
+            .title_style(Style::default().fg(colors.text_secondary()))
+            .padding(Padding::horizontal(1));
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    fn ordinal(place: usize) -> String {
+        let suffix = match (place % 10, place % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        };
+        format!("{}{}", place, suffix)
+    }
+}
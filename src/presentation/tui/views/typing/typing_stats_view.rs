@@ -0,0 +1,53 @@
+use crate::presentation::ui::Colors;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph},
+    Frame,
+};
+
+/// Live stats listed one per line, for the side column of the two-column layout
+pub struct TypingStatsView;
+
+impl TypingStatsView {
+    const LABEL_WIDTH: usize = 10;
+
+    /// Height fitting `metric_count` stats plus the panel border
+    pub fn height(metric_count: usize) -> u16 {
+        metric_count as u16 + 2
+    }
+
+    pub fn render(
+        frame: &mut Frame,
+        area: Rect,
+        metrics: &[(&'static str, String)],
+        recent_mistakes: Option<Line<'static>>,
+        colors: &Colors,
+    ) {
+        let lines: Vec<Line> = metrics
+            .iter()
+            .map(|(label, value)| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<width$}", label, width = Self::LABEL_WIDTH),
+                        Style::default().fg(colors.text_secondary()),
+                    ),
+                    Span::styled(value.clone(), Style::default().fg(colors.text())),
+                ])
+            })
+            .collect();
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors.border()))
+            .title("Metrics")
+            .title_style(Style::default().fg(colors.text_secondary()))
+            .padding(Padding::horizontal(1));
+        let block = match recent_mistakes {
+            Some(recent_mistakes) => block.title_bottom(recent_mistakes),
+            None => block,
+        };
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
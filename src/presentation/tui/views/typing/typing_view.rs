@@ -1,21 +1,33 @@
+use super::typing_content_view::PreparedContent;
 use super::{
-    TypingContentView, TypingCountdownView, TypingDialogView, TypingFooterView, TypingHeaderView,
+    TypingBigWpmView, TypingContentView, TypingCountdownView, TypingDialogView, TypingFooterView,
+    TypingHeaderView, TypingMinimapView, TypingRaceView, TypingStatsView,
 };
 use crate::domain::models::typing::CodeContext;
-use crate::domain::models::{Challenge, GitRepository};
+use crate::domain::models::{Challenge, GitRepository, RaceBot, WpmMode};
+use crate::domain::services::scoring::RealTimeCalculator;
 use crate::domain::services::typing_core::TypingCore;
 use crate::domain::services::SessionManager;
-use crate::presentation::ui::Colors;
+use crate::presentation::ui::{AsciiNumbersWidget, Colors, LayoutHelpers, TypingLayout};
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
     widgets::Paragraph,
     Frame,
 };
+use std::time::Duration;
+
+/// Narrower content areas keep the code at full width instead of showing the big WPM panel
+const MIN_WIDTH_FOR_BIG_WPM: u16 = 80;
+
+/// Keystrokes counted for each point of the big WPM panel's history chart
+const WPM_HISTORY_WINDOW: Duration = Duration::from_secs(5);
 
 pub struct TypingView {
     content_view: TypingContentView,
+    /// Where the code was last drawn, for laying out the next challenge
+    content_area: Rect,
 }
 
 impl Default for TypingView {
@@ -27,7 +39,39 @@ impl TypingView {
     pub fn new() -> Self {
         Self {
             content_view: TypingContentView::new(),
+            content_area: Rect::default(),
+        }
+    }
+
+    pub fn has_prepared_content(&self) -> bool {
+        self.content_view.has_prepared_content()
+    }
+
+    /// Where the code was last drawn
+    pub fn content_area(&self) -> Rect {
+        self.content_area
+    }
+
+    /// Lay out the next challenge in `area` without touching the shown view,
+    /// so it can be done off the UI thread and handed over afterwards
+    pub fn layout_next(
+        area: Rect,
+        challenge: &Challenge,
+        typing_core: &TypingCore,
+        code_context: &CodeContext,
+        colors: &Colors,
+    ) -> Option<PreparedContent> {
+        if area.is_empty() {
+            return None;
         }
+        let mut content_view = TypingContentView::new();
+        content_view.prepare(area, challenge, typing_core, code_context, colors);
+        content_view.take_prepared_content()
+    }
+
+    /// Keep a layout from `layout_next`, so the stage change renders from the cache
+    pub fn set_prepared_next(&mut self, prepared: PreparedContent) {
+        self.content_view.set_prepared_content(prepared);
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -43,35 +87,147 @@ impl TypingView {
         countdown_number: Option<u8>,
         skips_remaining: usize,
         dialog_shown: bool,
+        big_wpm_shown: bool,
+        wide_layout: bool,
+        race_bots: &[RaceBot],
         session_manager: &std::sync::Arc<
             dyn crate::domain::services::session_manager_service::SessionManagerInterface,
         >,
         colors: &Colors,
     ) {
         let countdown_active = countdown_number.is_some();
+        let layout = LayoutHelpers::typing_layout(frame.area(), wide_layout);
+        let progress_height = if race_bots.is_empty() {
+            3
+        } else {
+            TypingRaceView::height(race_bots.len())
+        };
 
+        // The two-column layout moves the metrics bar into the side column
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .margin(1)
-            .constraints(
-                [
+            .constraints(match layout {
+                TypingLayout::Single => vec![
                     Constraint::Length(3),
                     Constraint::Min(3),
                     Constraint::Length(3),
+                    Constraint::Length(progress_height),
+                ],
+                TypingLayout::TwoColumn => vec![
                     Constraint::Length(3),
-                ]
-                .as_ref(),
-            )
+                    Constraint::Min(3),
+                    Constraint::Length(progress_height),
+                ],
+            })
             .split(frame.area());
 
         // Header
         TypingHeaderView::render(frame, chunks[0], challenge, git_repository, colors);
 
-        // Content
+        let stage_tracker = session_manager
+            .as_any()
+            .downcast_ref::<SessionManager>()
+            .and_then(|instance| instance.get_current_stage_tracker());
+
         let show_code = !(waiting_to_start || countdown_active);
+        let wpm = match &stage_tracker {
+            Some(stage_tracker) if show_code => {
+                let data = stage_tracker.get_data();
+                RealTimeCalculator::calculate(
+                    &data.target_text,
+                    typing_core.current_position_to_type(),
+                    typing_core.mistakes(),
+                    data.elapsed_time,
+                )
+                .wpm
+            }
+            _ => 0.0,
+        };
+        let wpm_history = match &stage_tracker {
+            Some(stage_tracker) if show_code && big_wpm_shown => {
+                let data = stage_tracker.get_data();
+                let keystroke_times: Vec<Duration> = data
+                    .keystrokes
+                    .iter()
+                    .filter(|keystroke| keystroke.is_correct)
+                    .filter_map(|keystroke| {
+                        data.start_time
+                            .map(|start| keystroke.timestamp.saturating_duration_since(start))
+                    })
+                    .collect();
+                RealTimeCalculator::wpm_history(
+                    &data.target_text,
+                    &keystroke_times,
+                    data.elapsed_time,
+                    WPM_HISTORY_WINDOW,
+                )
+            }
+            _ => Vec::new(),
+        };
+        let metrics = stage_tracker.as_ref().map(|stage_tracker| {
+            TypingFooterView::metrics(
+                waiting_to_start,
+                countdown_active,
+                skips_remaining,
+                stage_tracker,
+                typing_core,
+            )
+        });
+
+        let content_area = match layout {
+            // Code on the left; big WPM, stats and minimap stacked on the right
+            TypingLayout::TwoColumn => {
+                let (content_area, side_area) =
+                    LayoutHelpers::split_side_column(chunks[1], LayoutHelpers::SIDE_COLUMN_WIDTH);
+                let big_wpm_height = if big_wpm_shown {
+                    AsciiNumbersWidget::HEIGHT + 2
+                } else {
+                    0
+                };
+                let stats_height = metrics
+                    .as_ref()
+                    .map_or(0, |metrics| TypingStatsView::height(metrics.len()));
+                let side = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Length(big_wpm_height),
+                        Constraint::Length(stats_height),
+                        Constraint::Min(0),
+                    ])
+                    .split(side_area);
+                if big_wpm_shown {
+                    TypingBigWpmView::render(frame, side[0], wpm, &wpm_history, colors);
+                }
+                if let (Some(metrics), Some(stage_tracker)) = (&metrics, &stage_tracker) {
+                    let recent_mistakes = TypingFooterView::recent_mistakes_line(
+                        &stage_tracker.recent_mistakes(TypingFooterView::RECENT_MISTAKES),
+                        colors,
+                    );
+                    TypingStatsView::render(frame, side[1], metrics, recent_mistakes, colors);
+                }
+                TypingMinimapView::render(
+                    frame,
+                    side[2],
+                    typing_core.text_to_display(),
+                    show_code.then(|| typing_core.current_line_to_display()),
+                    colors,
+                );
+                content_area
+            }
+            // Content, with the big WPM panel beside it when enabled and there is room
+            TypingLayout::Single if big_wpm_shown && chunks[1].width >= MIN_WIDTH_FOR_BIG_WPM => {
+                let (content_area, wpm_area) =
+                    LayoutHelpers::split_side_column(chunks[1], TypingBigWpmView::WIDTH);
+                TypingBigWpmView::render(frame, wpm_area, wpm, &wpm_history, colors);
+                content_area
+            }
+            TypingLayout::Single => chunks[1],
+        };
+        self.content_area = content_area;
         self.content_view.render(
             frame,
-            chunks[1],
+            content_area,
             show_code,
             challenge,
             typing_core,
@@ -81,31 +237,59 @@ impl TypingView {
         );
 
         // Metrics
-        if let Some(instance) = session_manager.as_any().downcast_ref::<SessionManager>() {
-            if let Some(stage_tracker) = instance.get_current_stage_tracker() {
-                TypingFooterView::render_metrics(
-                    frame,
-                    chunks[2],
-                    waiting_to_start,
-                    countdown_active,
-                    skips_remaining,
-                    &stage_tracker,
-                    typing_core,
-                    colors,
-                );
-            }
+        if let (TypingLayout::Single, Some(stage_tracker)) = (layout, &stage_tracker) {
+            TypingFooterView::render_metrics(
+                frame,
+                chunks[2],
+                waiting_to_start,
+                countdown_active,
+                skips_remaining,
+                stage_tracker,
+                typing_core,
+                colors,
+            );
         }
 
-        // Progress bar
-        TypingFooterView::render_progress(
-            frame,
-            chunks[3],
-            waiting_to_start,
-            countdown_active,
-            typing_core,
-            typing_core.text_to_display().chars().count(),
-            colors,
-        );
+        // Progress bar, or one per racer when racing bots
+        let progress_area = chunks[chunks.len() - 1];
+        if race_bots.is_empty() {
+            TypingFooterView::render_progress(
+                frame,
+                progress_area,
+                waiting_to_start,
+                countdown_active,
+                typing_core,
+                typing_core.text_to_display().chars().count(),
+                colors,
+            );
+        } else {
+            let percent =
+                |done: usize, total: usize| (done * 100).checked_div(total).unwrap_or(0) as u16;
+            let player_percent = if show_code {
+                percent(
+                    typing_core.current_position_to_display(),
+                    typing_core.text_to_display().chars().count(),
+                )
+            } else {
+                0
+            };
+            let (text, elapsed_time) = match &stage_tracker {
+                Some(stage_tracker) if show_code => {
+                    let data = stage_tracker.get_data();
+                    (data.target_text, data.elapsed_time)
+                }
+                _ => Default::default(),
+            };
+            let mode = WpmMode::current();
+            let bots: Vec<(String, u16)> = race_bots
+                .iter()
+                .map(|bot| {
+                    let done = bot.position(&text, elapsed_time, mode);
+                    (bot.label(mode), percent(done, text.chars().count()))
+                })
+                .collect();
+            TypingRaceView::render(frame, progress_area, player_percent, &bots, colors);
+        }
 
         // ESC Options
         let esc_area = ratatui::layout::Rect {
@@ -145,13 +329,39 @@ impl TypingView {
             };
             let start_text = Paragraph::new(vec![Line::from(start_line)]);
             frame.render_widget(start_text, start_area);
+
+            // The requested tier had no challenges; say which one is being played
+            if let Some((requested, played)) = session_manager
+                .as_any()
+                .downcast_ref::<SessionManager>()
+                .and_then(|instance| instance.get_difficulty_fallback())
+            {
+                let notice = format!(
+                    "No {:?} challenges in this repository · playing {:?} instead",
+                    requested, played
+                );
+                let notice_width = (notice.chars().count() as u16).min(frame.area().width);
+                let notice_area = ratatui::layout::Rect {
+                    x: center_x.saturating_sub(notice_width / 2),
+                    y: center_y.saturating_sub(2),
+                    width: notice_width,
+                    height: 1,
+                };
+                frame.render_widget(
+                    Paragraph::new(Line::from(Span::styled(
+                        notice,
+                        Style::default().fg(colors.warning()),
+                    ))),
+                    notice_area,
+                );
+            }
         } else if let Some(count) = countdown_number {
             TypingCountdownView::render(frame, count, colors);
         }
 
         // Dialog
         if dialog_shown {
-            TypingDialogView::render(frame, skips_remaining, colors);
+            TypingDialogView::render(frame, skips_remaining, big_wpm_shown, colors);
         }
     }
 }
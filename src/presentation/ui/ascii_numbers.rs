@@ -0,0 +1,71 @@
+use crate::domain::models::ui::ascii_digits::get_digit_patterns;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+
+/// Renders the digits of a number as four-row ASCII art. Characters other
+/// than digits, such as signs and separators, are skipped.
+pub struct AsciiNumbersWidget<'a> {
+    value: &'a str,
+    style: Style,
+    alignment: Alignment,
+}
+
+impl<'a> AsciiNumbersWidget<'a> {
+    pub const HEIGHT: u16 = 4;
+
+    pub fn new(value: &'a str) -> Self {
+        Self {
+            value,
+            style: Style::default(),
+            alignment: Alignment::Center,
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        let digit_patterns = get_digit_patterns();
+        self.value.chars().filter_map(|ch| ch.to_digit(10)).fold(
+            vec![String::new(); Self::HEIGHT as usize],
+            |mut lines, digit| {
+                for (line, row) in lines.iter_mut().zip(&digit_patterns[digit as usize]) {
+                    line.push_str(row);
+                    line.push(' ');
+                }
+                lines
+            },
+        )
+    }
+
+    pub fn width(&self) -> u16 {
+        self.lines()
+            .first()
+            .map_or(0, |line| line.chars().count() as u16)
+    }
+}
+
+impl<'a> Widget for AsciiNumbersWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let lines: Vec<Line> = self
+            .lines()
+            .into_iter()
+            .map(|line| Line::from(Span::styled(line, self.style)))
+            .collect();
+        Paragraph::new(lines)
+            .alignment(self.alignment)
+            .render(area, buf);
+    }
+}
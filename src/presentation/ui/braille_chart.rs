@@ -0,0 +1,88 @@
+use super::sparkline::{bounds, resample};
+use ratatui::{buffer::Buffer, layout::Rect, style::Style, widgets::Widget};
+
+/// First braille pattern; each raised dot adds its bit
+const BRAILLE_BLANK: u32 = 0x2800;
+
+/// Dot bits of a braille cell by row, top to bottom, for the left and right column
+const DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// A line chart drawn with braille dots: two points per column and four per
+/// row, scaled between the lowest and highest value
+pub struct BrailleChartWidget<'a> {
+    values: &'a [f64],
+    style: Style,
+}
+
+impl<'a> BrailleChartWidget<'a> {
+    pub fn new(values: &'a [f64]) -> Self {
+        Self {
+            values,
+            style: Style::default(),
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// `height` rows of `width` braille characters, consecutive points joined
+    /// vertically so the line has no gaps
+    pub fn rows(&self, width: usize, height: usize) -> Vec<String> {
+        let mut cells = vec![vec![0u8; width]; height];
+        let values = resample(self.values, width * 2);
+        let (min, max) = bounds(&values);
+        let dot_rows = height * 4;
+        // Dot row of each point, counted from the top
+        let dot_row = |value: f64| {
+            let fraction = if max > min {
+                (value - min) / (max - min)
+            } else {
+                0.5
+            };
+            dot_rows - 1 - (fraction * (dot_rows - 1) as f64).round() as usize
+        };
+
+        let mut previous = None;
+        for (x, value) in values.iter().enumerate() {
+            let y = dot_row(*value);
+            let (from, to) = match previous {
+                Some(previous) if previous < y => (previous + 1, y),
+                Some(previous) if previous > y => (y, previous - 1),
+                _ => (y, y),
+            };
+            for y in from..=to {
+                cells[y / 4][x / 2] |= DOTS[y % 4][x % 2];
+            }
+            previous = Some(y);
+        }
+
+        cells
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|bits| match bits {
+                        0 => ' ',
+                        bits => char::from_u32(BRAILLE_BLANK + bits as u32).unwrap_or(' '),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<'a> Widget for BrailleChartWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() || self.values.is_empty() {
+            return;
+        }
+        for (i, row) in self
+            .rows(area.width as usize, area.height as usize)
+            .into_iter()
+            .enumerate()
+        {
+            buf.set_string(area.x, area.y + i as u16, row, self.style);
+        }
+    }
+}
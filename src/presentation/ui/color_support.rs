@@ -0,0 +1,128 @@
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+};
+
+use crate::presentation::ui::gradation_text::{ansi256_to_rgb, Rgb};
+
+pub(crate) const ANSI16: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+/// How many colors the terminal can display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorSupport {
+    #[default]
+    TrueColor,
+    Ansi256,
+    Ansi16,
+    /// `NO_COLOR` is set or the terminal is dumb
+    Monochrome,
+}
+
+impl ColorSupport {
+    pub fn detect() -> Self {
+        Self::from_env(
+            std::env::var("NO_COLOR").ok().as_deref(),
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    /// A non-empty `NO_COLOR` wins (https://no-color.org). Without `TERM`,
+    /// as on Windows consoles, full color is assumed.
+    pub fn from_env(no_color: Option<&str>, colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if no_color.is_some_and(|value| !value.is_empty()) {
+            return ColorSupport::Monochrome;
+        }
+        if matches!(colorterm, Some("truecolor" | "24bit")) {
+            return ColorSupport::TrueColor;
+        }
+
+        match term.unwrap_or_default() {
+            "" => ColorSupport::TrueColor,
+            "dumb" => ColorSupport::Monochrome,
+            term if term.contains("direct") => ColorSupport::TrueColor,
+            term if term.contains("256color") => ColorSupport::Ansi256,
+            _ => ColorSupport::Ansi16,
+        }
+    }
+
+    /// The closest color this terminal can show
+    pub fn downgrade(self, color: Color) -> Color {
+        match (self, color) {
+            (ColorSupport::TrueColor, color) => color,
+            (ColorSupport::Monochrome, _) => Color::Reset,
+            (ColorSupport::Ansi256, Color::Rgb(r, g, b)) => {
+                Color::Indexed(Self::nearest_ansi256(Rgb::new(r, g, b)))
+            }
+            (ColorSupport::Ansi16, Color::Rgb(r, g, b)) => Self::nearest_ansi16(Rgb::new(r, g, b)),
+            (ColorSupport::Ansi16, Color::Indexed(code)) => {
+                Self::nearest_ansi16(ansi256_to_rgb(code))
+            }
+            (_, color) => color,
+        }
+    }
+
+    /// Downgrade every cell of a rendered frame. Without color, highlighted
+    /// cells such as the typing cursor are shown reversed instead.
+    pub fn apply(self, buffer: &mut Buffer) {
+        if self == ColorSupport::TrueColor {
+            return;
+        }
+
+        buffer.content.iter_mut().for_each(|cell| {
+            if self == ColorSupport::Monochrome && cell.bg != Color::Reset {
+                cell.modifier.insert(Modifier::REVERSED);
+            }
+            cell.fg = self.downgrade(cell.fg);
+            cell.bg = self.downgrade(cell.bg);
+        });
+    }
+
+    /// Best of the closest 6x6x6 cube entry and the closest gray ramp entry
+    fn nearest_ansi256(rgb: Rgb) -> u8 {
+        let level = |value: u8| match value {
+            0..=47 => 0,
+            48..=114 => 1,
+            value => (value - 35) / 40,
+        };
+        let cube = 16 + 36 * level(rgb.r) + 6 * level(rgb.g) + level(rgb.b);
+
+        let average = ((rgb.r as u16 + rgb.g as u16 + rgb.b as u16) / 3) as u8;
+        let gray = 232 + (average.saturating_sub(3) / 10).min(23);
+
+        [cube, gray]
+            .into_iter()
+            .min_by_key(|&code| Self::distance(rgb, ansi256_to_rgb(code)))
+            .unwrap_or(cube)
+    }
+
+    fn nearest_ansi16(rgb: Rgb) -> Color {
+        (0..ANSI16.len() as u8)
+            .min_by_key(|&code| Self::distance(rgb, ansi256_to_rgb(code)))
+            .map_or(Color::Reset, |code| ANSI16[code as usize])
+    }
+
+    fn distance(a: Rgb, b: Rgb) -> u32 {
+        [(a.r, b.r), (a.g, b.g), (a.b, b.b)]
+            .into_iter()
+            .map(|(x, y)| (x as i32 - y as i32).pow(2) as u32)
+            .sum()
+    }
+}
@@ -0,0 +1,22 @@
+/// Case-insensitive subsequence match; higher scores favour consecutive
+/// characters and matches at word starts. An empty query matches everything.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<u32> {
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for query_char in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let index = position
+            + candidate[position..]
+                .iter()
+                .position(|&c| c == query_char)?;
+        let consecutive = previous_match.is_some_and(|previous| previous + 1 == index);
+        let at_word_start = index == 0 || !candidate[index - 1].is_alphanumeric();
+        score += 1 + u32::from(consecutive) * 3 + u32::from(at_word_start) * 2;
+        previous_match = Some(index);
+        position = index + 1;
+    }
+
+    Some(score)
+}
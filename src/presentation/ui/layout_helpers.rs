@@ -0,0 +1,38 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+
+/// How the typing screen arranges the code and its live stats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypingLayout {
+    /// Code above a one-line metrics bar
+    Single,
+    /// Code on the left, live stats and a minimap in a column on the right
+    TwoColumn,
+}
+
+pub struct LayoutHelpers;
+
+impl LayoutHelpers {
+    /// Narrowest terminal that gets the two-column typing layout
+    pub const ULTRA_WIDE_MIN_WIDTH: u16 = 160;
+    /// Width of the stats and minimap column in the two-column layout
+    pub const SIDE_COLUMN_WIDTH: u16 = 40;
+
+    /// Two columns only when enabled and the terminal is ultra-wide
+    pub fn typing_layout(area: Rect, two_column_enabled: bool) -> TypingLayout {
+        if two_column_enabled && area.width >= Self::ULTRA_WIDE_MIN_WIDTH {
+            TypingLayout::TwoColumn
+        } else {
+            TypingLayout::Single
+        }
+    }
+
+    /// Splits a fixed-width column off the right of `area`, returning the
+    /// remaining area and the column
+    pub fn split_side_column(area: Rect, width: u16) -> (Rect, Rect) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(width)])
+            .split(area);
+        (columns[0], columns[1])
+    }
+}
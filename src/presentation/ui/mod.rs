@@ -1,5 +1,19 @@
+pub mod ascii_numbers;
+pub mod braille_chart;
+pub mod color_support;
 pub mod colors;
+pub mod fuzzy;
 pub mod gradation_text;
+pub mod layout_helpers;
+pub mod screen_capture;
+pub mod sparkline;
 
+pub use ascii_numbers::AsciiNumbersWidget;
+pub use braille_chart::BrailleChartWidget;
+pub use color_support::ColorSupport;
 pub use colors::Colors;
+pub use fuzzy::fuzzy_score;
 pub use gradation_text::{ansi256_to_rgb, GradationText, Rgb};
+pub use layout_helpers::{LayoutHelpers, TypingLayout};
+pub use screen_capture::ScreenCapture;
+pub use sparkline::SparklineWidget;
@@ -0,0 +1,227 @@
+use ratatui::{
+    buffer::{Buffer, Cell},
+    style::{Color, Modifier},
+    text::Span,
+};
+
+use crate::presentation::ui::color_support::ANSI16;
+use crate::presentation::ui::gradation_text::{ansi256_to_rgb, Rgb};
+use crate::presentation::ui::Colors;
+
+const HTML_FOREGROUND: &str = "#d4d4d4";
+const HTML_BACKGROUND: &str = "#1e1e1e";
+
+const SGR_MODIFIERS: [(Modifier, &str); 6] = [
+    (Modifier::BOLD, "1"),
+    (Modifier::DIM, "2"),
+    (Modifier::ITALIC, "3"),
+    (Modifier::UNDERLINED, "4"),
+    (Modifier::REVERSED, "7"),
+    (Modifier::CROSSED_OUT, "9"),
+];
+
+pub(crate) type CellStyle = (Color, Color, Modifier);
+
+/// CSS colors for cells drawn on the terminal's default colors
+struct PageColors {
+    foreground: String,
+    background: String,
+}
+
+impl Default for PageColors {
+    fn default() -> Self {
+        Self {
+            foreground: HTML_FOREGROUND.to_string(),
+            background: HTML_BACKGROUND.to_string(),
+        }
+    }
+}
+
+/// A rendered frame as text that keeps its colors, for sharing an exact run
+pub struct ScreenCapture;
+
+impl ScreenCapture {
+    /// Lines with SGR escape codes; `cat` in a terminal shows the frame as it was drawn
+    pub fn to_ansi(buffer: &Buffer) -> String {
+        Self::styled_rows(buffer)
+            .iter()
+            .map(|runs| {
+                runs.iter()
+                    .map(|((fg, bg, modifier), text)| {
+                        format!("{}{}", Self::sgr(*fg, *bg, *modifier), text)
+                    })
+                    .collect::<String>()
+                    + "\x1b[0m\n"
+            })
+            .collect()
+    }
+
+    /// A standalone page showing the frame in a `<pre>`, one `<span>` per run of equal style
+    pub fn to_html(buffer: &Buffer, title: &str) -> String {
+        Self::html_page(buffer, title, &PageColors::default())
+    }
+
+    /// Like [`Self::to_html`], with the page drawn in the theme's text and background colors
+    pub fn to_themed_html(buffer: &Buffer, title: &str, colors: &Colors) -> String {
+        let defaults = PageColors::default();
+        let page = PageColors {
+            foreground: css_color(colors.text()).unwrap_or(defaults.foreground),
+            background: css_color(colors.background()).unwrap_or(defaults.background),
+        };
+        Self::html_page(buffer, title, &page)
+    }
+
+    fn html_page(buffer: &Buffer, title: &str, page: &PageColors) -> String {
+        let body: String = Self::styled_rows(buffer)
+            .iter()
+            .map(|runs| {
+                runs.iter()
+                    .map(|(style, text)| Self::html_span(*style, text, page))
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect();
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n\
+             <body style=\"margin:0;background:{};color:{}\">\n\
+             <pre style=\"margin:0;padding:1em;font-family:monospace;line-height:1.2\">{}</pre>\n\
+             </body>\n</html>\n",
+            escape_html(title),
+            page.background,
+            page.foreground,
+            body
+        )
+    }
+
+    /// Each row as runs of equally styled text, without the cells covered by
+    /// wide characters and without trailing blank cells
+    pub(crate) fn styled_rows(buffer: &Buffer) -> Vec<Vec<(CellStyle, String)>> {
+        let width = buffer.area.width as usize;
+        buffer
+            .content
+            .chunks(width.max(1))
+            .map(|row| {
+                let mut cells: Vec<&Cell> = Vec::with_capacity(row.len());
+                let mut covered = 0;
+                for cell in row {
+                    if covered > 0 {
+                        covered -= 1;
+                        continue;
+                    }
+                    covered = Span::raw(cell.symbol()).width().saturating_sub(1);
+                    cells.push(cell);
+                }
+                while cells.last().is_some_and(|cell| Self::is_blank(cell)) {
+                    cells.pop();
+                }
+
+                cells
+                    .into_iter()
+                    .fold(Vec::<(CellStyle, String)>::new(), |mut runs, cell| {
+                        let style = (cell.fg, cell.bg, cell.modifier);
+                        match runs.last_mut() {
+                            Some((last, text)) if *last == style => text.push_str(cell.symbol()),
+                            _ => runs.push((style, cell.symbol().to_string())),
+                        }
+                        runs
+                    })
+            })
+            .collect()
+    }
+
+    fn is_blank(cell: &Cell) -> bool {
+        cell.symbol() == " " && cell.bg == Color::Reset && cell.modifier.is_empty()
+    }
+
+    fn sgr(fg: Color, bg: Color, modifier: Modifier) -> String {
+        let codes: Vec<String> = std::iter::once("0".to_string())
+            .chain(
+                SGR_MODIFIERS
+                    .iter()
+                    .filter(|(flag, _)| modifier.contains(*flag))
+                    .map(|(_, code)| code.to_string()),
+            )
+            .chain(Self::sgr_color(fg, 30))
+            .chain(Self::sgr_color(bg, 40))
+            .collect();
+        format!("\x1b[{}m", codes.join(";"))
+    }
+
+    /// `base` is 30 for the foreground and 40 for the background
+    fn sgr_color(color: Color, base: u8) -> Option<String> {
+        match color {
+            Color::Reset => None,
+            Color::Indexed(code) => Some(format!("{};5;{}", base + 8, code)),
+            Color::Rgb(r, g, b) => Some(format!("{};2;{};{};{}", base + 8, r, g, b)),
+            named => named_index(named).map(|index| match index {
+                0..=7 => (base + index).to_string(),
+                _ => (base + 60 + index - 8).to_string(),
+            }),
+        }
+    }
+
+    fn html_span((fg, bg, modifier): CellStyle, text: &str, page: &PageColors) -> String {
+        let (fg, bg) = if modifier.contains(Modifier::REVERSED) {
+            (
+                css_color(bg).unwrap_or_else(|| page.background.clone()),
+                css_color(fg).or_else(|| Some(page.foreground.clone())),
+            )
+        } else {
+            (
+                css_color(fg).unwrap_or_else(|| page.foreground.clone()),
+                css_color(bg),
+            )
+        };
+
+        let style = [
+            Some(format!("color:{}", fg)),
+            bg.map(|bg| format!("background:{}", bg)),
+            modifier
+                .contains(Modifier::BOLD)
+                .then(|| "font-weight:bold".to_string()),
+            modifier
+                .contains(Modifier::DIM)
+                .then(|| "opacity:0.6".to_string()),
+            modifier
+                .contains(Modifier::ITALIC)
+                .then(|| "font-style:italic".to_string()),
+            modifier
+                .contains(Modifier::UNDERLINED)
+                .then(|| "text-decoration:underline".to_string()),
+            modifier
+                .contains(Modifier::CROSSED_OUT)
+                .then(|| "text-decoration:line-through".to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(";");
+
+        format!("<span style=\"{}\">{}</span>", style, escape_html(text))
+    }
+}
+
+/// Position of a named color in the 16-color palette
+fn named_index(color: Color) -> Option<u8> {
+    ANSI16
+        .iter()
+        .position(|named| *named == color)
+        .map(|index| index as u8)
+}
+
+fn css_color(color: Color) -> Option<String> {
+    let Rgb { r, g, b } = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => Rgb::new(r, g, b),
+        Color::Indexed(code) => ansi256_to_rgb(code),
+        named => ansi256_to_rgb(named_index(named)?),
+    };
+    Some(format!("#{:02x}{:02x}{:02x}", r, g, b))
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,79 @@
+use ratatui::{buffer::Buffer, layout::Rect, style::Style, text::Span, widgets::Widget};
+
+/// Block heights from lowest to highest
+const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// One row of block characters, one column per value, scaled between the
+/// lowest and highest value; more values than columns are averaged together
+pub struct SparklineWidget<'a> {
+    values: &'a [f64],
+    style: Style,
+}
+
+impl<'a> SparklineWidget<'a> {
+    pub fn new(values: &'a [f64]) -> Self {
+        Self {
+            values,
+            style: Style::default(),
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Up to `width` characters; a flat series sits at mid height
+    pub fn text(&self, width: usize) -> String {
+        let values = resample(self.values, width);
+        let (min, max) = bounds(&values);
+        values
+            .iter()
+            .map(|value| {
+                let level = if max > min {
+                    ((value - min) / (max - min) * (LEVELS.len() - 1) as f64).round() as usize
+                } else {
+                    LEVELS.len() / 2 - 1
+                };
+                LEVELS[level.min(LEVELS.len() - 1)]
+            })
+            .collect()
+    }
+
+    /// For placing the sparkline inside a line of text
+    pub fn span(&self, width: usize) -> Span<'static> {
+        Span::styled(self.text(width), self.style)
+    }
+}
+
+impl<'a> Widget for SparklineWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        buf.set_string(area.x, area.y, self.text(area.width as usize), self.style);
+    }
+}
+
+/// `values` squeezed into at most `width` points by averaging neighbours
+pub(crate) fn resample(values: &[f64], width: usize) -> Vec<f64> {
+    if values.len() <= width {
+        return values.to_vec();
+    }
+    (0..width)
+        .map(|column| {
+            let start = column * values.len() / width;
+            let end = ((column + 1) * values.len() / width).max(start + 1);
+            let bucket = &values[start..end];
+            bucket.iter().sum::<f64>() / bucket.len() as f64
+        })
+        .collect()
+}
+
+pub(crate) fn bounds(values: &[f64]) -> (f64, f64) {
+    values
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(min, max), value| {
+            (min.min(*value), max.max(*value))
+        })
+}
@@ -13,6 +13,7 @@ pub fn build() -> GitRepository {
         commit_hash: Some("abc123def456".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     }
 }
 
@@ -26,6 +27,7 @@ pub fn build_with_names(user_name: &str, repository_name: &str) -> GitRepository
         commit_hash: Some("abc123def456".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     }
 }
 
@@ -39,6 +41,7 @@ pub fn build_with_root_path(root_path: PathBuf) -> GitRepository {
         commit_hash: Some("abc123def456".to_string()),
         is_dirty: false,
         root_path: Some(root_path),
+        license: None,
     }
 }
 
@@ -52,5 +55,6 @@ pub fn build_dirty() -> GitRepository {
         commit_hash: Some("abc123def456".to_string()),
         is_dirty: true,
         root_path: None,
+        license: None,
     }
 }
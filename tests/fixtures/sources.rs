@@ -0,0 +1,34 @@
+//! Fixture source files, embedded so tests don't depend on the working directory
+
+use std::path::PathBuf;
+
+pub const COMPLEX_COMMENTED_RUST: &str = include_str!("complex_commented_rust.rs");
+pub const COMPLEX_RUST_SERVICE: &str = include_str!("complex_rust_service.rs");
+
+/// Path fixtures are reported under in extracted chunks and snapshots
+pub fn logical_path(file_name: &str) -> PathBuf {
+    PathBuf::from("tests/fixtures").join(file_name)
+}
+
+/// Embedded content of a fixture file
+pub fn content(file_name: &str) -> &'static str {
+    match file_name {
+        "complex_commented_rust.rs" => COMPLEX_COMMENTED_RUST,
+        "complex_rust_service.rs" => COMPLEX_RUST_SERVICE,
+        _ => panic!("Unknown fixture: {}", file_name),
+    }
+}
+
+/// Directory holding the fixture files on disk, for tests that need real
+/// files. `GITTYPE_FIXTURE_ROOT` overrides it for sandboxed builds (e.g. Nix)
+/// where the source tree lives somewhere else at test time.
+pub fn root() -> PathBuf {
+    std::env::var_os("GITTYPE_FIXTURE_ROOT")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures"))
+}
+
+/// On-disk path of a fixture file
+pub fn path(file_name: &str) -> PathBuf {
+    root().join(file_name)
+}
@@ -83,7 +83,10 @@ mod byte_char_position_bugs {
     #[test]
     fn test_real_models_options_file_reproduces_bug() {
         // Test the actual models/options.rs file that demonstrates the reported bug
-        let options_path = Path::new("src/domain/models/extraction_options.rs");
+        let options_path = Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/domain/models/extraction_options.rs"
+        ));
 
         // Extract chunks from the real file
         let chunks = extract_from_file_for_test(options_path, "rust").unwrap();
@@ -525,3 +525,43 @@ func receiver(ch1, ch2 <-chan Message, done chan<- bool) {
         Loop: 2,
     }
 }
+
+test_language_extractor! {
+    name: test_go_generic_types_aliases_and_receivers,
+    language: "go",
+    extension: "go",
+    source: r#"
+package main
+
+type Celsius = float64
+
+type Stack[T any] struct {
+    items []T
+}
+
+func (s *Stack[T]) Push(item T) {
+    s.items = append(s.items, item)
+}
+
+type (
+    Shape interface {
+        Area() float64
+    }
+    Square struct {
+        side float64
+    }
+)
+
+func (sq Square) Area() float64 {
+    return sq.side * sq.side
+}
+"#,
+    total_chunks: 7,
+    chunk_counts: {
+        File: 1,
+        TypeAlias: 1,
+        Struct: 2,
+        Interface: 1,
+        Method: 2,
+    }
+}
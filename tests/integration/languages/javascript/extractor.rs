@@ -627,3 +627,40 @@ const iterator = {
         Function: 6,
     }
 }
+
+test_language_extractor! {
+    name: test_javascript_react_components_and_hooks,
+    language: "javascript",
+    extension: "jsx",
+    source: r#"
+function useFetch(url) {
+    const [data, setData] = useState(null);
+    useEffect(() => {
+        fetch(url).then((res) => res.json()).then(setData);
+    }, [url]);
+    return data;
+}
+
+const TodoList = ({ url }) => {
+    const todos = useFetch(url) || [];
+    return (
+        <ul>
+            {todos.map((todo) => <li key={todo.id}>{todo.title}</li>)}
+        </ul>
+    );
+};
+
+function formatTitle(title) {
+    return title.trim();
+}
+"#,
+    total_chunks: 8,
+    chunk_counts: {
+        Hook: 1,
+        Component: 3,
+        Function: 1,
+        FunctionCall: 1,
+        CodeBlock: 1,
+        File: 1,
+    }
+}
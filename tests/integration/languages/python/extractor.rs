@@ -381,3 +381,42 @@ class ConfigManager:
         CodeBlock: 4,
     }
 }
+
+test_language_extractor! {
+    name: test_python_async_decorated_and_nested_functions,
+    language: "python",
+    extension: "py",
+    source: r#"
+import functools
+
+
+def retry(times):
+    def decorator(func):
+        @functools.wraps(func)
+        def wrapper(*args, **kwargs):
+            return func(*args, **kwargs)
+        return wrapper
+    return decorator
+
+
+@retry(times=3)
+async def fetch_user(session, user_id):
+    response = await session.get(f"/users/{user_id}")
+    return await response.json()
+
+
+@dataclass
+class User:
+    id: int
+    name: str
+"#,
+    total_chunks: 10,
+    chunk_counts: {
+        File: 1,
+        Function: 3,
+        AsyncFunction: 1,
+        DecoratedFunction: 2,
+        Class: 2,
+        CodeBlock: 1,
+    }
+}
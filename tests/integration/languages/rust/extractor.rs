@@ -226,11 +226,11 @@ test_language_extractor! {
     }
 }
 "#,
-    total_chunks: 17,
+    total_chunks: 14,
     chunk_counts: {
         File: 1,
         Function: 4,
-        CodeBlock: 6,
+        CodeBlock: 3,
         Module: 2,
         Struct: 1,
         Class: 3
@@ -407,10 +407,11 @@ impl<T: Clone> DataCache<T> {
     }
 }
 "#,
-    total_chunks: 21,
+    total_chunks: 22,
     chunk_counts: {
         File: 1,
         Struct: 1,
+        Class: 1,
         FunctionCall: 7,
         Function: 4,
         Loop: 1,
@@ -418,3 +419,40 @@ impl<T: Clone> DataCache<T> {
         CodeBlock: 4,
     }
 }
+
+test_language_extractor! {
+    name: test_rust_macro_rules_and_impl_blocks,
+    language: "rust",
+    extension: "rs",
+    source: r#"
+macro_rules! hashmap {
+    ($($key:expr => $value:expr),* $(,)?) => {{
+        let mut map = ::std::collections::HashMap::new();
+        $(map.insert($key, $value);)*
+        map
+    }};
+}
+
+pub struct Wrapper<T>(Vec<T>);
+
+impl<T: Clone> Wrapper<T> {
+    pub fn first(&self) -> Option<T> {
+        self.0.first().cloned()
+    }
+}
+
+impl<T> std::fmt::Display for Wrapper<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} items", self.0.len())
+    }
+}
+"#,
+    total_chunks: 7,
+    chunk_counts: {
+        File: 1,
+        Macro: 1,
+        Struct: 1,
+        Class: 2,
+        Function: 2,
+    }
+}
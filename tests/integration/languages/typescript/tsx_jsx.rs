@@ -94,8 +94,8 @@ class Dialog extends React.Component<Props> {
     );
     assert_eq!(
         function_chunks.len(),
-        4,
-        "Should find 4 function chunks as shown in output"
+        0,
+        "Functions returning JSX should be detected as components"
     );
     assert_eq!(
         class_chunks.len(),
@@ -109,20 +109,16 @@ class Dialog extends React.Component<Props> {
         "Should find 'Props' interface in chunk names"
     );
 
-    let function_names: Vec<&String> = function_chunks.iter().map(|c| &c.name).collect();
-    println!("Function names: {:?}", function_names);
-    // Based on output, function names are actual names like UserCard, WelcomeComponent, App, Button
-    assert!(
-        function_names.contains(&&"UserCard".to_string())
-            || function_names.contains(&&"WelcomeComponent".to_string())
-            || function_names.contains(&&"App".to_string())
-            || function_names.contains(&&"Button".to_string()),
-        "Should find actual function names in function chunks"
-    );
-
-    // Should also find JSX components as Component chunks
+    // Function components and the JSX elements they render are Component chunks
     let component_names: Vec<&String> = component_chunks.iter().map(|c| &c.name).collect();
     println!("Component names: {:?}", component_names);
+    for name in ["UserCard", "WelcomeComponent", "App", "Button"] {
+        assert!(
+            component_names.contains(&&name.to_string()),
+            "Should find function component {}",
+            name
+        );
+    }
 }
 
 #[test]
@@ -196,9 +192,9 @@ function FormComponent() {
     println!("Lambda names: {:?}", lambda_names);
     println!("Component names: {:?}", component_names);
 
-    // Based on actual output, both ProfileCard and FormComponent are detected as functions with their actual names
-    assert!(function_names.contains(&&"ProfileCard".to_string()));
-    assert!(function_names.contains(&&"FormComponent".to_string()));
+    // Both return JSX, so they are detected as components with their actual names
+    assert!(component_names.contains(&&"ProfileCard".to_string()));
+    assert!(component_names.contains(&&"FormComponent".to_string()));
 
     // Should find JSX components (div, img, input, br, CustomComponent)
     // Note: These are HTML elements and custom components used in JSX
@@ -331,9 +327,12 @@ export default UserList;
     );
     assert_eq!(enum_count, 1, "Enum chunks should be detected as Enum type");
     assert_eq!(
-        function_count, 2,
-        "Function chunks should be detected as Function type"
+        function_count, 1,
+        "Only handleClick should stay a Function; UserList is a component"
     );
+    assert!(chunks
+        .iter()
+        .any(|c| c.name == "UserList" && c.chunk_type == ChunkType::Component));
     assert_eq!(
         class_count, 1,
         "Should find 1 class as shown in actual output"
@@ -353,3 +352,47 @@ export default UserList;
         "Should find 'Status' enum in chunk names"
     );
 }
+
+#[test]
+fn test_hooks_and_plain_functions_are_told_apart() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("hooks.tsx");
+
+    let tsx_code = r#"
+function useCounter(initial: number) {
+    const [count, setCount] = useState(initial);
+    return { count, increment: () => setCount(count + 1) };
+}
+
+const useToggle = (initial = false) => {
+    const [on, setOn] = useState(initial);
+    return [on, () => setOn(!on)] as const;
+};
+
+function user_name(first: string, last: string) {
+    return `${first} ${last}`;
+}
+
+function renderRow(label: string) {
+    return <li className="row">{label}</li>;
+}
+"#;
+    fs::write(&file_path, tsx_code).unwrap();
+
+    let mut extractor = SourceCodeParser::new().unwrap();
+    let chunks =
+        extract_chunks_for_test(&mut extractor, temp_dir.path(), test_extraction_options())
+            .unwrap();
+    let chunk_type = |name: &str| {
+        chunks
+            .iter()
+            .find(|c| c.name == name)
+            .map(|c| c.chunk_type.clone())
+    };
+
+    assert_eq!(chunk_type("useCounter"), Some(ChunkType::Hook));
+    assert_eq!(chunk_type("useToggle"), Some(ChunkType::Hook));
+    assert_eq!(chunk_type("user_name"), Some(ChunkType::Function));
+    // Lowercase functions are not components even when they return JSX
+    assert_eq!(chunk_type("renderRow"), Some(ChunkType::Function));
+}
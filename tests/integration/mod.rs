@@ -6,13 +6,13 @@ pub mod missing_ascii_art_test;
 pub mod screens;
 
 use gittype::domain::models::languages::*;
+use gittype::domain::models::loading::NoOpProgressReporter;
 use gittype::domain::models::{Challenge, CodeChunk, ExtractionOptions, Language, Languages};
 use gittype::domain::services::challenge_generator::ChallengeGenerator;
 use gittype::domain::services::source_code_parser::parsers::parse_with_thread_local;
 use gittype::domain::services::source_code_parser::ChunkExtractor;
 use gittype::domain::services::source_code_parser::SourceCodeParser;
 use gittype::domain::services::source_file_extractor::SourceFileExtractor;
-use gittype::presentation::tui::screens::loading_screen::NoOpProgressReporter;
 use gittype::GitTypeError;
 use gittype::Result;
 use ignore::WalkBuilder;
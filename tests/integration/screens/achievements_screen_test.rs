@@ -0,0 +1,102 @@
+use crate::integration::screens::mocks::achievements_screen_mock::{
+    MockAchievementsDataProvider, MockEmptyAchievementsDataProvider,
+};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use gittype::domain::events::presentation_events::NavigateTo;
+use gittype::domain::events::EventBus;
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::theme::Theme;
+use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::presentation::tui::screens::achievements_screen::AchievementsScreen;
+use gittype::presentation::tui::{Screen, ScreenDataProvider};
+use std::sync::Arc;
+
+fn make_achievements_screen() -> AchievementsScreen {
+    AchievementsScreen::new(
+        Arc::new(EventBus::new()),
+        Arc::new(ThemeService::new_for_test(
+            Theme::default(),
+            ColorMode::Dark,
+        )) as Arc<dyn ThemeServiceInterface>,
+    )
+}
+
+screen_snapshot_test!(
+    test_achievements_screen_snapshot,
+    AchievementsScreen,
+    make_achievements_screen(),
+    provider = MockAchievementsDataProvider
+);
+
+screen_snapshot_test!(
+    test_achievements_screen_snapshot_after_navigation,
+    AchievementsScreen,
+    make_achievements_screen(),
+    provider = MockAchievementsDataProvider,
+    keys = [
+        KeyEvent::new(KeyCode::Down, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Char('j'), KeyModifiers::empty())
+    ]
+);
+
+screen_basic_methods_test!(
+    test_achievements_screen_basic_methods,
+    AchievementsScreen,
+    make_achievements_screen(),
+    gittype::presentation::tui::ScreenType::Achievements,
+    false
+);
+
+screen_key_event_test!(
+    test_achievements_screen_esc_navigates_back,
+    AchievementsScreen,
+    NavigateTo,
+    KeyCode::Esc,
+    KeyModifiers::empty(),
+    MockAchievementsDataProvider
+);
+
+screen_key_event_test!(
+    test_achievements_screen_ctrl_c_exits,
+    AchievementsScreen,
+    NavigateTo,
+    KeyCode::Char('c'),
+    KeyModifiers::CONTROL,
+    MockAchievementsDataProvider
+);
+
+#[test]
+fn test_achievements_screen_selection_stays_in_bounds() {
+    let screen = make_achievements_screen();
+    screen
+        .init_with_data(MockAchievementsDataProvider.provide().unwrap())
+        .unwrap();
+    assert_eq!(screen.selected_index(), Some(0));
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Up, KeyModifiers::empty()))
+        .unwrap();
+    assert_eq!(screen.selected_index(), Some(0));
+
+    for _ in 0..20 {
+        screen
+            .handle_key_event(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::empty()))
+            .unwrap();
+    }
+    assert_eq!(
+        screen.selected_index(),
+        Some(gittype::domain::models::Achievement::all().len() - 1)
+    );
+}
+
+#[test]
+fn test_achievements_screen_empty_has_no_selection() {
+    let screen = make_achievements_screen();
+    screen
+        .init_with_data(MockEmptyAchievementsDataProvider.provide().unwrap())
+        .unwrap();
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::empty()))
+        .unwrap();
+    assert_eq!(screen.selected_index(), None);
+}
@@ -19,12 +19,14 @@ fn test_analytics_view_mode_cycles_forward_and_backward() {
     assert_eq!(ViewMode::Overview.next(), ViewMode::Trends);
     assert_eq!(ViewMode::Trends.next(), ViewMode::Repositories);
     assert_eq!(ViewMode::Repositories.next(), ViewMode::Languages);
-    assert_eq!(ViewMode::Languages.next(), ViewMode::Overview);
+    assert_eq!(ViewMode::Languages.next(), ViewMode::Fingers);
+    assert_eq!(ViewMode::Fingers.next(), ViewMode::Overview);
 
-    assert_eq!(ViewMode::Overview.previous(), ViewMode::Languages);
+    assert_eq!(ViewMode::Overview.previous(), ViewMode::Fingers);
     assert_eq!(ViewMode::Trends.previous(), ViewMode::Overview);
     assert_eq!(ViewMode::Repositories.previous(), ViewMode::Trends);
     assert_eq!(ViewMode::Languages.previous(), ViewMode::Repositories);
+    assert_eq!(ViewMode::Fingers.previous(), ViewMode::Languages);
 }
 
 screen_snapshot_test!(
@@ -455,6 +457,34 @@ fn test_analytics_screen_unhandled_key_returns_ok() {
     assert!(result.is_ok());
 }
 
+screen_snapshot_test!(
+    test_analytics_screen_snapshot_fingers,
+    AnalyticsScreen,
+    AnalyticsScreen::new(
+        Arc::new(EventBus::new()),
+        Arc::new(ThemeService::new_for_test(
+            Theme::default(),
+            ColorMode::Dark
+        )) as Arc<dyn ThemeServiceInterface>
+    ),
+    provider = MockAnalyticsDataProvider,
+    keys = [KeyEvent::new(KeyCode::Left, KeyModifiers::empty())]
+);
+
+screen_snapshot_test!(
+    test_analytics_screen_snapshot_fingers_empty,
+    AnalyticsScreen,
+    AnalyticsScreen::new(
+        Arc::new(EventBus::new()),
+        Arc::new(ThemeService::new_for_test(
+            Theme::default(),
+            ColorMode::Dark
+        )) as Arc<dyn ThemeServiceInterface>
+    ),
+    provider = MockAnalyticsDataProviderEmpty,
+    keys = [KeyEvent::new(KeyCode::Left, KeyModifiers::empty())]
+);
+
 #[test]
 fn test_analytics_view_mode_default_is_overview() {
     assert_eq!(ViewMode::default(), ViewMode::Overview);
@@ -466,4 +496,5 @@ fn test_analytics_view_mode_display_names() {
     assert_eq!(ViewMode::Trends.display_name(), "Trends");
     assert_eq!(ViewMode::Repositories.display_name(), "Repositories");
     assert_eq!(ViewMode::Languages.display_name(), "Languages");
+    assert_eq!(ViewMode::Fingers.display_name(), "Fingers");
 }
@@ -0,0 +1,107 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use gittype::domain::events::presentation_events::NavigateTo;
+use gittype::domain::events::{EventBus, EventBusInterface};
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::theme::Theme;
+use gittype::domain::models::ErrorReport;
+use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::domain::stores::{SessionStore, SessionStoreInterface};
+use gittype::presentation::tui::screens::error_screen::ErrorScreen;
+use gittype::presentation::tui::{Screen, ScreenType};
+use gittype::GitTypeError;
+use std::sync::{Arc, Mutex};
+
+fn create_error_screen(event_bus: Arc<dyn EventBusInterface>, error: GitTypeError) -> ErrorScreen {
+    let theme_service = Arc::new(ThemeService::new_for_test(
+        Theme::default(),
+        ColorMode::Dark,
+    )) as Arc<dyn ThemeServiceInterface>;
+    let session_store = Arc::new(SessionStore::new_for_test()) as Arc<dyn SessionStoreInterface>;
+    session_store.set_error_report(ErrorReport::from_error(&error));
+
+    ErrorScreen::new(event_bus, theme_service, session_store)
+}
+
+fn clone_auth_error() -> GitTypeError {
+    GitTypeError::RepositoryCloneError(git2::Error::new(
+        git2::ErrorCode::Auth,
+        git2::ErrorClass::Http,
+        "remote authentication required but no callback set",
+    ))
+}
+
+fn navigation_after(screen: &ErrorScreen, event_bus: &EventBus, key: KeyEvent) -> Vec<NavigateTo> {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let captured = Arc::clone(&events);
+    event_bus.subscribe(move |event: &NavigateTo| {
+        captured.lock().unwrap().push(event.clone());
+    });
+
+    screen.init_with_data(Box::new(())).unwrap();
+    screen.handle_key_event(key).unwrap();
+
+    let events = events.lock().unwrap();
+    events.clone()
+}
+
+screen_snapshot_test!(
+    test_error_screen_snapshot_clone_auth,
+    ErrorScreen,
+    create_error_screen(Arc::new(EventBus::new()), clone_auth_error())
+);
+
+#[test]
+fn test_error_screen_shows_the_stored_report() {
+    let screen = create_error_screen(Arc::new(EventBus::new()), GitTypeError::NoSupportedFiles);
+    screen.init_with_data(Box::new(())).unwrap();
+
+    let report = screen.get_report().unwrap();
+    assert_eq!(report.title, "No challenges found");
+    assert_eq!(screen.get_type(), ScreenType::Error);
+    assert!(screen.is_exitable());
+}
+
+#[test]
+fn test_error_screen_r_retries_loading() {
+    let event_bus = Arc::new(EventBus::new());
+    let screen = create_error_screen(event_bus.clone(), clone_auth_error());
+
+    let events = navigation_after(
+        &screen,
+        &event_bus,
+        KeyEvent::new(KeyCode::Char('r'), KeyModifiers::empty()),
+    );
+
+    assert!(matches!(
+        events.as_slice(),
+        [NavigateTo::Replace(ScreenType::Loading)]
+    ));
+}
+
+#[test]
+fn test_error_screen_r_is_ignored_when_retrying_cannot_help() {
+    let event_bus = Arc::new(EventBus::new());
+    let screen = create_error_screen(event_bus.clone(), GitTypeError::NoSupportedFiles);
+
+    let events = navigation_after(
+        &screen,
+        &event_bus,
+        KeyEvent::new(KeyCode::Char('r'), KeyModifiers::empty()),
+    );
+
+    assert!(events.is_empty());
+}
+
+#[test]
+fn test_error_screen_esc_exits() {
+    let event_bus = Arc::new(EventBus::new());
+    let screen = create_error_screen(event_bus.clone(), clone_auth_error());
+
+    let events = navigation_after(
+        &screen,
+        &event_bus,
+        KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()),
+    );
+
+    assert!(matches!(events.as_slice(), [NavigateTo::Exit]));
+}
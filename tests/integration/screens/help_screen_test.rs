@@ -1,47 +1,41 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use gittype::domain::events::presentation_events::NavigateTo;
-use gittype::domain::events::EventBus;
+use gittype::domain::events::{EventBus, EventBusInterface};
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::theme::Theme;
+use gittype::domain::services::keymap_service::{KeymapService, KeymapServiceInterface};
 use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
 use gittype::presentation::tui::screens::help_screen::HelpScreen;
 use std::sync::Arc;
 
-screen_snapshot_test!(
-    test_help_screen_snapshot_cli,
-    HelpScreen,
+fn create_help_screen(event_bus: Arc<dyn EventBusInterface>) -> HelpScreen {
     HelpScreen::new(
-        Arc::new(EventBus::new()),
+        event_bus,
         Arc::new(ThemeService::new_for_test(
             Theme::default(),
-            ColorMode::Dark
-        )) as Arc<dyn ThemeServiceInterface>
+            ColorMode::Dark,
+        )) as Arc<dyn ThemeServiceInterface>,
+        Arc::new(KeymapService::new()) as Arc<dyn KeymapServiceInterface>,
     )
+}
+
+screen_snapshot_test!(
+    test_help_screen_snapshot_cli,
+    HelpScreen,
+    create_help_screen(Arc::new(EventBus::new()))
 );
 
 screen_snapshot_test!(
     test_help_screen_snapshot_scoring,
     HelpScreen,
-    HelpScreen::new(
-        Arc::new(EventBus::new()),
-        Arc::new(ThemeService::new_for_test(
-            Theme::default(),
-            ColorMode::Dark
-        )) as Arc<dyn ThemeServiceInterface>
-    ),
+    create_help_screen(Arc::new(EventBus::new())),
     keys = [KeyEvent::new(KeyCode::Right, KeyModifiers::empty())]
 );
 
 screen_snapshot_test!(
     test_help_screen_snapshot_ranks,
     HelpScreen,
-    HelpScreen::new(
-        Arc::new(EventBus::new()),
-        Arc::new(ThemeService::new_for_test(
-            Theme::default(),
-            ColorMode::Dark
-        )) as Arc<dyn ThemeServiceInterface>
-    ),
+    create_help_screen(Arc::new(EventBus::new())),
     keys = [
         KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
         KeyEvent::new(KeyCode::Right, KeyModifiers::empty())
@@ -51,13 +45,7 @@ screen_snapshot_test!(
 screen_snapshot_test!(
     test_help_screen_snapshot_game_help,
     HelpScreen,
-    HelpScreen::new(
-        Arc::new(EventBus::new()),
-        Arc::new(ThemeService::new_for_test(
-            Theme::default(),
-            ColorMode::Dark
-        )) as Arc<dyn ThemeServiceInterface>
-    ),
+    create_help_screen(Arc::new(EventBus::new())),
     keys = [
         KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
         KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
@@ -65,20 +53,27 @@ screen_snapshot_test!(
     ]
 );
 
+screen_snapshot_test!(
+    test_help_screen_snapshot_keybindings,
+    HelpScreen,
+    create_help_screen(Arc::new(EventBus::new())),
+    keys = [
+        KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Right, KeyModifiers::empty())
+    ]
+);
+
 screen_snapshot_test!(
     test_help_screen_snapshot_community,
     HelpScreen,
-    HelpScreen::new(
-        Arc::new(EventBus::new()),
-        Arc::new(ThemeService::new_for_test(
-            Theme::default(),
-            ColorMode::Dark
-        )) as Arc<dyn ThemeServiceInterface>
-    ),
+    create_help_screen(Arc::new(EventBus::new())),
     keys = [
         KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
         KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
         KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
         KeyEvent::new(KeyCode::Right, KeyModifiers::empty())
     ]
 );
@@ -89,15 +84,27 @@ use crate::integration::screens::helpers::EmptyMockProvider;
 screen_key_event_test!(
     test_help_screen_esc_navigates_back,
     HelpScreen,
+    create_help_screen,
     NavigateTo,
     KeyCode::Esc,
     KeyModifiers::empty(),
     EmptyMockProvider
 );
 
+screen_key_event_test!(
+    test_help_screen_t_opens_tutorial,
+    HelpScreen,
+    create_help_screen,
+    NavigateTo,
+    KeyCode::Char('t'),
+    KeyModifiers::empty(),
+    EmptyMockProvider
+);
+
 screen_key_event_test!(
     test_help_screen_ctrl_c_exits,
     HelpScreen,
+    create_help_screen,
     NavigateTo,
     KeyCode::Char('c'),
     KeyModifiers::CONTROL,
@@ -105,8 +112,9 @@ screen_key_event_test!(
 );
 
 // Non-event key tests
-screen_key_tests!(
+screen_key_tests_custom!(
     HelpScreen,
+    create_help_screen,
     EmptyMockProvider,
     [
         (
@@ -161,13 +169,7 @@ screen_key_tests!(
 screen_basic_methods_test!(
     test_help_screen_basic_methods,
     HelpScreen,
-    HelpScreen::new(
-        Arc::new(EventBus::new()),
-        Arc::new(ThemeService::new_for_test(
-            Theme::default(),
-            ColorMode::Dark
-        )) as Arc<dyn ThemeServiceInterface>
-    ),
+    create_help_screen(Arc::new(EventBus::new())),
     gittype::presentation::tui::ScreenType::Help,
     false
 );
@@ -178,13 +180,7 @@ use ratatui::backend::TestBackend;
 use ratatui::Terminal;
 
 fn make_help_screen() -> HelpScreen {
-    HelpScreen::new(
-        Arc::new(EventBus::new()),
-        Arc::new(ThemeService::new_for_test(
-            Theme::default(),
-            ColorMode::Dark,
-        )) as Arc<dyn ThemeServiceInterface>,
-    )
+    create_help_screen(Arc::new(EventBus::new()))
 }
 
 fn render_buffer_text(screen: &HelpScreen) -> String {
@@ -218,6 +214,7 @@ fn help_section_title_covers_all_variants() {
     assert_eq!(HelpSection::Scoring.title(), "Scoring System");
     assert_eq!(HelpSection::Ranks.title(), "Rank System");
     assert_eq!(HelpSection::GameHelp.title(), "Game Help");
+    assert_eq!(HelpSection::Keybindings.title(), "Keys");
     assert_eq!(HelpSection::CLI.title(), "CLI Usage");
     assert_eq!(HelpSection::About.title(), "About & Credits");
     assert_eq!(
@@ -237,6 +234,7 @@ fn help_section_all_lists_every_variant_in_order() {
             HelpSection::Scoring,
             HelpSection::Ranks,
             HelpSection::GameHelp,
+            HelpSection::Keybindings,
             HelpSection::Community,
             HelpSection::About,
             HelpSection::ThirdPartyLicenses,
@@ -244,6 +242,19 @@ fn help_section_all_lists_every_variant_in_order() {
     );
 }
 
+#[test]
+fn rendering_keybindings_section_lists_bindings_from_keymap() {
+    let screen = make_help_screen();
+    for _ in 0..4 {
+        press(&screen, KeyCode::Right);
+    }
+    let output = render_buffer_text(&screen);
+
+    assert!(output.contains("Title:"));
+    assert!(output.contains("Typing:"));
+    assert!(output.contains("Skip stage (in menu)"));
+}
+
 #[test]
 fn help_section_default_is_cli() {
     assert_eq!(HelpSection::default(), HelpSection::CLI);
@@ -252,8 +263,8 @@ fn help_section_default_is_cli() {
 #[test]
 fn rendering_about_section_includes_credits_content() {
     let screen = make_help_screen();
-    // CLI -> Scoring -> Ranks -> GameHelp -> Community -> About
-    for _ in 0..5 {
+    // CLI -> Scoring -> Ranks -> GameHelp -> Keybindings -> Community -> About
+    for _ in 0..6 {
         press(&screen, KeyCode::Right);
     }
     let output = render_buffer_text(&screen);
@@ -266,7 +277,7 @@ fn rendering_about_section_includes_credits_content() {
 #[test]
 fn rendering_third_party_licenses_section_includes_license_content() {
     let screen = make_help_screen();
-    for _ in 0..6 {
+    for _ in 0..7 {
         press(&screen, KeyCode::Right);
     }
     let output = render_buffer_text(&screen);
@@ -301,7 +312,7 @@ fn pressing_h_wraps_to_last_section_like_left() {
 fn pressing_down_then_up_keeps_scroll_position_within_bounds() {
     let screen = make_help_screen();
     // Move to ThirdPartyLicenses which has long content so max_scroll > 0.
-    for _ in 0..6 {
+    for _ in 0..7 {
         press(&screen, KeyCode::Right);
     }
     // Render once so render_content publishes content_height/viewport_height.
@@ -0,0 +1,114 @@
+use crate::integration::screens::helpers::EmptyMockProvider;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use gittype::domain::events::presentation_events::NavigateTo;
+use gittype::domain::events::{EventBus, EventBusInterface};
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::theme::Theme;
+use gittype::domain::services::config_service::ConfigService;
+use gittype::domain::services::scoring::{
+    SessionTracker, SessionTrackerInterface, TotalTracker, TotalTrackerInterface,
+};
+use gittype::domain::services::session_manager_service::SessionManagerInterface;
+use gittype::domain::services::stage_builder_service::{StageRepository, StageRepositoryInterface};
+use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::domain::services::SessionManager;
+use gittype::domain::stores::{ChallengeStore, RepositoryStore, SessionStore};
+use gittype::presentation::tui::screens::keyboard_test_screen::KeyboardTestScreen;
+use gittype::presentation::tui::{Screen, ScreenDataProvider};
+use std::sync::{Arc, Mutex};
+
+fn make_keyboard_test_screen(event_bus: Arc<dyn EventBusInterface>) -> KeyboardTestScreen {
+    let stage_repository = Arc::new(StageRepository::new(
+        None,
+        Arc::new(ChallengeStore::new_for_test()),
+        Arc::new(RepositoryStore::new_for_test()),
+        Arc::new(SessionStore::new_for_test()),
+    )) as Arc<dyn StageRepositoryInterface>;
+    let session_tracker: Arc<dyn SessionTrackerInterface> = Arc::new(SessionTracker::default());
+    let total_tracker: Arc<dyn TotalTrackerInterface> = Arc::new(TotalTracker::default());
+    let session_manager = Arc::new(SessionManager::new_with_dependencies(
+        event_bus.clone(),
+        stage_repository,
+        session_tracker,
+        total_tracker,
+    )) as Arc<dyn SessionManagerInterface>;
+
+    KeyboardTestScreen::new(
+        event_bus,
+        Arc::new(ThemeService::new_for_test(
+            Theme::default(),
+            ColorMode::Dark,
+        )) as Arc<dyn ThemeServiceInterface>,
+        Arc::new(ConfigService::new_for_test().unwrap()),
+        session_manager,
+    )
+}
+
+screen_snapshot_test!(
+    test_keyboard_test_screen_snapshot_empty,
+    KeyboardTestScreen,
+    make_keyboard_test_screen(Arc::new(EventBus::new()))
+);
+
+screen_snapshot_test!(
+    test_keyboard_test_screen_snapshot_with_keys,
+    KeyboardTestScreen,
+    make_keyboard_test_screen(Arc::new(EventBus::new())),
+    provider = EmptyMockProvider,
+    keys = [
+        KeyEvent::new(KeyCode::Tab, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT),
+        KeyEvent::new(KeyCode::Backspace, KeyModifiers::ALT),
+        KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL)
+    ]
+);
+
+screen_basic_methods_test!(
+    test_keyboard_test_screen_basic_methods,
+    KeyboardTestScreen,
+    make_keyboard_test_screen(Arc::new(EventBus::new())),
+    gittype::presentation::tui::ScreenType::KeyboardTest,
+    false
+);
+
+fn captured_events(keys: &[KeyEvent]) -> Vec<NavigateTo> {
+    let event_bus = Arc::new(EventBus::new());
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = Arc::clone(&events);
+    event_bus.subscribe(move |event: &NavigateTo| {
+        events_clone.lock().unwrap().push(event.clone());
+    });
+
+    let screen = make_keyboard_test_screen(event_bus);
+    screen
+        .init_with_data(EmptyMockProvider.provide().unwrap())
+        .unwrap();
+    for key in keys {
+        screen.handle_key_event(*key).unwrap();
+    }
+
+    let captured = events.lock().unwrap().clone();
+    captured
+}
+
+#[test]
+fn test_keyboard_test_screen_esc_closes() {
+    let events = captured_events(&[KeyEvent::new(KeyCode::Esc, KeyModifiers::empty())]);
+    assert!(matches!(events.as_slice(), [NavigateTo::Pop]));
+}
+
+#[test]
+fn test_keyboard_test_screen_ctrl_c_exits() {
+    let events = captured_events(&[KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)]);
+    assert!(matches!(events.as_slice(), [NavigateTo::Exit]));
+}
+
+#[test]
+fn test_keyboard_test_screen_other_keys_stay_open() {
+    let events = captured_events(&[
+        KeyEvent::new(KeyCode::Char('q'), KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::F(5), KeyModifiers::empty()),
+    ]);
+    assert!(events.is_empty());
+}
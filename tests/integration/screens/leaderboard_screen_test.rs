@@ -0,0 +1,130 @@
+use crate::integration::screens::mocks::leaderboard_screen_mock::{
+    MockEmptyLeaderboardDataProvider, MockLeaderboardDataProvider,
+};
+use crate::integration::screens::mocks::repo_detail_screen_mock::{
+    dismissed_repository_service, MockRepoDetailDataProvider, MockRepositoryDetailService,
+};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use gittype::domain::events::presentation_events::NavigateTo;
+use gittype::domain::events::EventBus;
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::theme::Theme;
+use gittype::domain::services::repository_detail_service::RepositoryDetailServiceInterface;
+use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::domain::stores::RepositoryStore;
+use gittype::presentation::tui::screens::{LeaderboardScreen, RepoDetailScreen};
+use gittype::presentation::tui::{Screen, ScreenDataProvider, ScreenType};
+use std::sync::{Arc, Mutex};
+
+fn theme_service() -> Arc<dyn ThemeServiceInterface> {
+    Arc::new(ThemeService::new_for_test(
+        Theme::default(),
+        ColorMode::Dark,
+    ))
+}
+
+fn make_leaderboard_screen(event_bus: Arc<EventBus>) -> LeaderboardScreen {
+    LeaderboardScreen::new(
+        event_bus,
+        theme_service(),
+        Arc::new(MockRepositoryDetailService::default())
+            as Arc<dyn RepositoryDetailServiceInterface>,
+    )
+}
+
+screen_snapshot_test!(
+    test_leaderboard_screen_snapshot,
+    LeaderboardScreen,
+    make_leaderboard_screen(Arc::new(EventBus::new())),
+    provider = MockLeaderboardDataProvider
+);
+
+screen_snapshot_test!(
+    test_leaderboard_screen_snapshot_empty,
+    LeaderboardScreen,
+    make_leaderboard_screen(Arc::new(EventBus::new())),
+    provider = MockEmptyLeaderboardDataProvider
+);
+
+screen_key_event_test!(
+    test_leaderboard_screen_esc_navigates_back,
+    LeaderboardScreen,
+    make_leaderboard_screen,
+    NavigateTo,
+    KeyCode::Esc,
+    KeyModifiers::empty(),
+    MockLeaderboardDataProvider
+);
+
+screen_basic_methods_test!(
+    test_leaderboard_screen_basic_methods,
+    LeaderboardScreen,
+    make_leaderboard_screen(Arc::new(EventBus::new())),
+    ScreenType::Leaderboard,
+    true,
+    MockLeaderboardDataProvider
+);
+
+#[test]
+fn test_leaderboard_screen_loads_repository_shown_in_detail() {
+    let repo_detail = RepoDetailScreen::new(
+        Arc::new(EventBus::new()),
+        theme_service(),
+        Arc::new(MockRepositoryDetailService::default()),
+        Arc::new(RepositoryStore::new_for_test()),
+        dismissed_repository_service(),
+    );
+    repo_detail
+        .init_with_data(MockRepoDetailDataProvider.provide().unwrap())
+        .unwrap();
+    let service = Arc::new(MockRepositoryDetailService::default());
+    let screen = LeaderboardScreen::new(
+        Arc::new(EventBus::new()),
+        theme_service(),
+        Arc::clone(&service) as Arc<dyn RepositoryDetailServiceInterface>,
+    );
+
+    screen.on_pushed_from(&repo_detail).unwrap();
+
+    let data = screen.get_data();
+    assert_eq!(data.repo_spec, "unhappychoice/gittype");
+    assert_eq!(data.entries.len(), 2);
+    assert_eq!(*service.calls.lock().unwrap(), vec!["leaderboard:1"]);
+}
+
+#[test]
+fn test_leaderboard_screen_requires_repo_detail_source() {
+    let screen = make_leaderboard_screen(Arc::new(EventBus::new()));
+    let other = make_leaderboard_screen(Arc::new(EventBus::new()));
+
+    assert!(screen.on_pushed_from(&other).is_err());
+}
+
+#[test]
+fn test_repo_detail_screen_l_opens_leaderboard() {
+    let event_bus = Arc::new(EventBus::new());
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = Arc::clone(&events);
+    event_bus.subscribe(move |event: &NavigateTo| {
+        events_clone.lock().unwrap().push(event.clone());
+    });
+    let screen = RepoDetailScreen::new(
+        event_bus,
+        theme_service(),
+        Arc::new(MockRepositoryDetailService::default()),
+        Arc::new(RepositoryStore::new_for_test()),
+        dismissed_repository_service(),
+    );
+    screen
+        .init_with_data(MockRepoDetailDataProvider.provide().unwrap())
+        .unwrap();
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('l'), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(matches!(
+        events.lock().unwrap().as_slice(),
+        [NavigateTo::Push(ScreenType::Leaderboard)]
+    ));
+}
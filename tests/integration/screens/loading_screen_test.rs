@@ -3,13 +3,11 @@ use crossterm::event::{KeyCode, KeyModifiers};
 use gittype::domain::events::presentation_events::ExitRequested;
 use gittype::domain::events::EventBus;
 use gittype::domain::models::color_mode::ColorMode;
-use gittype::domain::models::loading::StepType;
+use gittype::domain::models::loading::{NoOpProgressReporter, ProgressReporter, StepType};
 use gittype::domain::models::theme::Theme;
 use gittype::domain::models::{ExtractionOptions, GitRepository};
 use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
-use gittype::presentation::tui::screens::loading_screen::{
-    LoadingScreen, LoadingScreenData, NoOpProgressReporter, ProgressReporter,
-};
+use gittype::presentation::tui::screens::loading_screen::{LoadingScreen, LoadingScreenData};
 use gittype::presentation::tui::{Screen, ScreenType, UpdateStrategy};
 use gittype::GitTypeError;
 use std::path::PathBuf;
@@ -43,6 +41,7 @@ fn test_loading_screen_ctrl_c_requests_exit() {
 
     let captured_events = events.lock().unwrap();
     assert_eq!(captured_events.len(), 1);
+    assert!(screen.is_cancelled());
 }
 
 #[test]
@@ -211,6 +210,7 @@ fn test_set_git_repository_full() {
         commit_hash: Some("abcdef1234567890".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     screen.set_git_repository(&repo).unwrap();
 }
@@ -226,6 +226,7 @@ fn test_set_git_repository_dirty() {
         commit_hash: None,
         is_dirty: true,
         root_path: None,
+        license: None,
     };
     screen.set_git_repository(&repo).unwrap();
 }
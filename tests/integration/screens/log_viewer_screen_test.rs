@@ -0,0 +1,139 @@
+use crate::integration::screens::mocks::log_viewer_screen_mock::{
+    MockEmptyLogViewerDataProvider, MockLogViewerDataProvider, MockLongLogViewerDataProvider,
+};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use gittype::domain::events::presentation_events::NavigateTo;
+use gittype::domain::events::EventBus;
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::theme::Theme;
+use gittype::domain::models::LogLevel;
+use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::presentation::tui::screens::LogViewerScreen;
+use gittype::presentation::tui::{Screen, ScreenDataProvider};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use std::sync::Arc;
+
+fn make_log_viewer_screen(event_bus: Arc<EventBus>) -> LogViewerScreen {
+    LogViewerScreen::new(
+        event_bus,
+        Arc::new(ThemeService::new_for_test(
+            Theme::default(),
+            ColorMode::Dark,
+        )) as Arc<dyn ThemeServiceInterface>,
+    )
+}
+
+screen_snapshot_test!(
+    test_log_viewer_screen_snapshot,
+    LogViewerScreen,
+    make_log_viewer_screen(Arc::new(EventBus::new())),
+    provider = MockLogViewerDataProvider
+);
+
+screen_snapshot_test!(
+    test_log_viewer_screen_snapshot_warnings_only,
+    LogViewerScreen,
+    make_log_viewer_screen(Arc::new(EventBus::new())),
+    provider = MockLogViewerDataProvider,
+    keys = [KeyEvent::new(KeyCode::Char('f'), KeyModifiers::empty())]
+);
+
+screen_snapshot_test!(
+    test_log_viewer_screen_snapshot_without_log_file,
+    LogViewerScreen,
+    make_log_viewer_screen(Arc::new(EventBus::new())),
+    provider = MockEmptyLogViewerDataProvider
+);
+
+screen_key_event_test!(
+    test_log_viewer_screen_esc_exits,
+    LogViewerScreen,
+    make_log_viewer_screen,
+    NavigateTo,
+    KeyCode::Esc,
+    KeyModifiers::empty(),
+    MockLogViewerDataProvider
+);
+
+screen_key_event_test!(
+    test_log_viewer_screen_ctrl_c_exits,
+    LogViewerScreen,
+    make_log_viewer_screen,
+    NavigateTo,
+    KeyCode::Char('c'),
+    KeyModifiers::CONTROL,
+    MockLogViewerDataProvider
+);
+
+screen_basic_methods_test!(
+    test_log_viewer_screen_basic_methods,
+    LogViewerScreen,
+    make_log_viewer_screen(Arc::new(EventBus::new())),
+    gittype::presentation::tui::ScreenType::LogViewer,
+    false,
+    MockLogViewerDataProvider
+);
+
+fn render(screen: &LogViewerScreen) {
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| screen.render_ratatui(frame).unwrap())
+        .unwrap();
+}
+
+fn press(screen: &LogViewerScreen, code: KeyCode) {
+    screen
+        .handle_key_event(KeyEvent::new(code, KeyModifiers::empty()))
+        .unwrap()
+}
+
+#[test]
+fn test_log_viewer_screen_opens_at_newest_lines_and_scrolls() {
+    let screen = make_log_viewer_screen(Arc::new(EventBus::new()));
+    screen
+        .init_with_data(MockLongLogViewerDataProvider.provide().unwrap())
+        .unwrap();
+    render(&screen);
+
+    // 100 lines in an 18 line viewport
+    assert_eq!(screen.scroll_position(), 82);
+
+    press(&screen, KeyCode::Down);
+    assert_eq!(screen.scroll_position(), 82);
+
+    press(&screen, KeyCode::Char('k'));
+    assert_eq!(screen.scroll_position(), 81);
+
+    press(&screen, KeyCode::PageUp);
+    assert_eq!(screen.scroll_position(), 63);
+
+    press(&screen, KeyCode::Char('g'));
+    assert_eq!(screen.scroll_position(), 0);
+
+    press(&screen, KeyCode::Char('G'));
+    assert_eq!(screen.scroll_position(), 82);
+}
+
+#[test]
+fn test_log_viewer_screen_filter_cycles_through_levels() {
+    let screen = make_log_viewer_screen(Arc::new(EventBus::new()));
+    screen
+        .init_with_data(MockLogViewerDataProvider.provide().unwrap())
+        .unwrap();
+
+    assert_eq!(screen.visible_entries().len(), 7);
+
+    press(&screen, KeyCode::Char('f'));
+    assert_eq!(screen.min_level(), Some(LogLevel::Warn));
+    // The warning, the error and the error's continuation line
+    assert_eq!(screen.visible_entries().len(), 3);
+
+    press(&screen, KeyCode::Char('f'));
+    assert_eq!(screen.min_level(), Some(LogLevel::Error));
+    assert_eq!(screen.visible_entries().len(), 2);
+
+    press(&screen, KeyCode::Char('f'));
+    assert_eq!(screen.min_level(), None);
+    assert_eq!(screen.visible_entries().len(), 7);
+}
@@ -0,0 +1,32 @@
+use chrono::{TimeZone, Utc};
+use gittype::domain::models::{Achievement, AchievementStatus};
+use gittype::presentation::tui::ScreenDataProvider;
+use gittype::Result;
+
+pub struct MockAchievementsDataProvider;
+
+impl ScreenDataProvider for MockAchievementsDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        let unlocked_at = Utc.with_ymd_and_hms(2024, 5, 10, 12, 0, 0).unwrap();
+        let achievements: Vec<AchievementStatus> = Achievement::all()
+            .iter()
+            .map(|achievement| AchievementStatus {
+                achievement: *achievement,
+                unlocked_at: matches!(
+                    achievement,
+                    Achievement::FirstStage | Achievement::TenStages | Achievement::Wpm40
+                )
+                .then_some(unlocked_at),
+            })
+            .collect();
+        Ok(Box::new(achievements))
+    }
+}
+
+pub struct MockEmptyAchievementsDataProvider;
+
+impl ScreenDataProvider for MockEmptyAchievementsDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(Vec::<AchievementStatus>::new()))
+    }
+}
@@ -1,3 +1,7 @@
+use gittype::domain::models::storage::LanguageActivity;
+use gittype::domain::models::{
+    BaselineSource, BaselineSummary, Finger, FingerLoad, LanguageCalibrations,
+};
 use gittype::domain::services::analytics_service::{AnalyticsData, LangStats, RepoStats};
 use gittype::presentation::tui::ScreenDataProvider;
 use gittype::Result;
@@ -78,6 +82,9 @@ impl ScreenDataProvider for MockAnalyticsDataProvider {
             repository_stats,
             language_stats,
             reference_date: None,
+            prose_baseline: None,
+            finger_load: sample_finger_load(),
+            language_calibrations: sample_language_calibrations(),
         };
 
         Ok(Box::new(data))
@@ -180,6 +187,15 @@ impl ScreenDataProvider for MockAnalyticsDataProviderWithActivity {
             repository_stats,
             language_stats,
             reference_date,
+            prose_baseline: Some(BaselineSummary {
+                tests: 120,
+                avg_wpm: 95.0,
+                best_wpm: 118.0,
+                avg_accuracy: 97.2,
+                sources: vec![BaselineSource::Monkeytype],
+            }),
+            finger_load: FingerLoad::default(),
+            language_calibrations: LanguageCalibrations::default(),
         };
 
         Ok(Box::new(data))
@@ -208,8 +224,44 @@ impl ScreenDataProvider for MockAnalyticsDataProviderEmpty {
             repository_stats: HashMap::new(),
             language_stats: HashMap::new(),
             reference_date: None,
+            prose_baseline: None,
+            finger_load: FingerLoad::default(),
+            language_calibrations: LanguageCalibrations::default(),
         };
 
         Ok(Box::new(data))
     }
 }
+
+fn sample_language_calibrations() -> LanguageCalibrations {
+    let activity = |language: &str, stages: usize, avg_wpm: f64| LanguageActivity {
+        language: language.to_string(),
+        stages,
+        avg_wpm,
+        best_wpm: avg_wpm,
+        avg_accuracy: 95.0,
+        keystrokes: stages * 200,
+    };
+    LanguageCalibrations::from_activity(&[activity("rust", 15, 64.0), activity("python", 3, 80.0)])
+}
+
+fn sample_finger_load() -> FingerLoad {
+    let keystrokes = [
+        (Finger::LeftPinky, 40, 6),
+        (Finger::LeftRing, 60, 3),
+        (Finger::LeftMiddle, 90, 2),
+        (Finger::LeftIndex, 120, 4),
+        (Finger::Thumb, 150, 0),
+        (Finger::RightIndex, 110, 3),
+        (Finger::RightMiddle, 80, 1),
+        (Finger::RightRing, 50, 2),
+        (Finger::RightPinky, 70, 9),
+    ];
+    keystrokes.iter().fold(
+        FingerLoad::default(),
+        |mut load, &(finger, total, mistakes)| {
+            (0..total).for_each(|i| load.record(finger, i >= mistakes));
+            load
+        },
+    )
+}
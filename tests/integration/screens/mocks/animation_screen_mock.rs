@@ -30,6 +30,8 @@ impl ScreenDataProvider for MockAnimationDataProvider {
             worst_stage_accuracy: 94.0,
             session_score: 1200.0,
             session_successful: true,
+            verification_code: None,
+            plausibility: Default::default(),
         };
 
         Ok(Box::new(AnimationData { session_result }))
@@ -1,6 +1,6 @@
+use gittype::domain::models::loading::ProgressReporter;
 use gittype::domain::models::{Challenge, GitRepository};
 use gittype::domain::repositories::challenge_repository::ChallengeRepositoryInterface;
-use gittype::presentation::tui::screens::loading_screen::ProgressReporter;
 use gittype::Result;
 
 pub struct MockChallengeRepository;
@@ -44,4 +44,16 @@ impl ChallengeRepositoryInterface for MockChallengeRepository {
     fn list_cache_keys(&self) -> Result<Vec<String>> {
         Ok(vec![])
     }
+
+    fn count_cached_challenges(&self, _repo_key: &str) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn invalidate_repository_key(&self, _repo_key: &str) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn remove_stale_caches(&self) -> Result<(usize, u64)> {
+        Ok((0, 0))
+    }
 }
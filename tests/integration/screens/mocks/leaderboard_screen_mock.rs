@@ -0,0 +1,26 @@
+use super::repo_detail_screen_mock::mock_leaderboard_entries;
+use gittype::presentation::tui::screens::LeaderboardScreenData;
+use gittype::presentation::tui::ScreenDataProvider;
+use gittype::Result;
+
+pub struct MockLeaderboardDataProvider;
+
+impl ScreenDataProvider for MockLeaderboardDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(LeaderboardScreenData {
+            repo_spec: "unhappychoice/gittype".to_string(),
+            entries: mock_leaderboard_entries(),
+        }))
+    }
+}
+
+pub struct MockEmptyLeaderboardDataProvider;
+
+impl ScreenDataProvider for MockEmptyLeaderboardDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(LeaderboardScreenData {
+            repo_spec: "unhappychoice/gittype".to_string(),
+            entries: Vec::new(),
+        }))
+    }
+}
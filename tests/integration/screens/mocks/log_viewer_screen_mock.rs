@@ -0,0 +1,57 @@
+use gittype::domain::models::LogEntry;
+use gittype::presentation::tui::screens::LogViewerScreenData;
+use gittype::presentation::tui::ScreenDataProvider;
+use gittype::Result;
+use std::path::PathBuf;
+
+pub const MOCK_LOG: &str = "\
+2026-05-01 10:00:00 [INFO] gittype::infrastructure::logging - GitType logging initialized
+2026-05-01 10:00:01 [DEBUG] gittype::domain::services::source_file_extractor - Scanning 42 files
+2026-05-01 10:00:02 [WARN] gittype::domain::services::source_code_parser - Failed to parse src/broken.rs: unexpected token
+2026-05-01 10:00:03 [INFO] gittype::domain::services::challenge_generator - Generated 120 challenges
+2026-05-01 10:00:04 [ERROR] gittype::infrastructure::database - Failed to save session: database is locked
+Caused by: SQLITE_BUSY
+2026-05-01 10:00:05 [INFO] gittype::presentation::cli::commands::game - Session finished";
+
+pub struct MockLogViewerDataProvider;
+
+impl ScreenDataProvider for MockLogViewerDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(LogViewerScreenData {
+            log_path: Some(PathBuf::from(
+                "/home/user/.gittype/logs/gittype_20260501_100000.log",
+            )),
+            entries: MOCK_LOG.lines().map(LogEntry::parse).collect(),
+        }))
+    }
+}
+
+pub struct MockLongLogViewerDataProvider;
+
+impl ScreenDataProvider for MockLongLogViewerDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(LogViewerScreenData {
+            log_path: Some(PathBuf::from("/tmp/gittype_20260501_100000.log")),
+            entries: (0..100)
+                .map(|i| {
+                    LogEntry::parse(&format!(
+                        "2026-05-01 10:00:00 [{}] gittype - line {}",
+                        if i % 10 == 0 { "WARN" } else { "INFO" },
+                        i
+                    ))
+                })
+                .collect(),
+        }))
+    }
+}
+
+pub struct MockEmptyLogViewerDataProvider;
+
+impl ScreenDataProvider for MockEmptyLogViewerDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(LogViewerScreenData {
+            log_path: None,
+            entries: Vec::new(),
+        }))
+    }
+}
@@ -1,7 +1,13 @@
+pub mod achievements_screen_mock;
 pub mod analytics_screen_mock;
 pub mod animation_screen_mock;
 pub mod challenge_repository_mock;
+pub mod leaderboard_screen_mock;
+pub mod log_viewer_screen_mock;
+pub mod profile_screen_mock;
 pub mod records_screen_mock;
+pub mod reminder_screen_mock;
+pub mod repo_detail_screen_mock;
 pub mod repo_list_screen_mock;
 pub mod repo_play_screen_mock;
 pub mod session_details_dialog_mock;
@@ -18,3 +24,5 @@ pub mod total_summary_share_screen_mock;
 pub mod trending_repository_mock;
 pub mod trending_repository_selection_screen_mock;
 pub mod typing_screen_mock;
+pub mod whats_new_screen_mock;
+pub mod workspace_package_screen_mock;
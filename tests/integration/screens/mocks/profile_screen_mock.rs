@@ -0,0 +1,49 @@
+use gittype::domain::models::PlayerProfile;
+use gittype::domain::services::profile_service::ProfileServiceInterface;
+use gittype::presentation::tui::ScreenDataProvider;
+use gittype::Result;
+use std::sync::Mutex;
+
+pub fn mock_player_profile() -> PlayerProfile {
+    PlayerProfile {
+        display_name: "Ada Lovelace".to_string(),
+        rank_title: Some("Senior Engineer".to_string()),
+        favorite_language: Some("rust".to_string()),
+        current_streak_days: 4,
+        stages_completed: 42,
+        best_score: 5120.0,
+    }
+}
+
+pub struct MockProfileDataProvider;
+
+impl ScreenDataProvider for MockProfileDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(mock_player_profile()))
+    }
+}
+
+/// Keeps the display name in memory instead of writing config.json
+#[derive(Default)]
+pub struct MockProfileService {
+    pub display_name: Mutex<Option<String>>,
+}
+
+impl ProfileServiceInterface for MockProfileService {
+    fn load_profile(&self) -> Result<PlayerProfile> {
+        Ok(PlayerProfile {
+            display_name: self
+                .display_name
+                .lock()
+                .unwrap()
+                .clone()
+                .unwrap_or_else(|| mock_player_profile().display_name),
+            ..mock_player_profile()
+        })
+    }
+
+    fn set_display_name(&self, display_name: &str) -> Result<()> {
+        *self.display_name.lock().unwrap() = PlayerProfile::normalize_name(Some(display_name));
+        Ok(())
+    }
+}
@@ -15,12 +15,14 @@ impl ScreenDataProvider for MockRecordsDataProvider {
                 user_name: "unhappychoice".to_string(),
                 repository_name: "gittype".to_string(),
                 remote_url: "https://github.com/unhappychoice/gittype".to_string(),
+                license: None,
             },
             StoredRepository {
                 id: 2,
                 user_name: "rails".to_string(),
                 repository_name: "rails".to_string(),
                 remote_url: "https://github.com/rails/rails".to_string(),
+                license: None,
             },
         ];
 
@@ -38,6 +40,8 @@ impl ScreenDataProvider for MockRecordsDataProvider {
                     difficulty_level: Some("Normal".to_string()),
                     max_stages: Some(3),
                     time_limit_seconds: None,
+                    player_name: None,
+                    unverified: false,
                 },
                 repository: Some(repositories[0].clone()),
                 session_result: Some(SessionResultData {
@@ -72,6 +76,8 @@ impl ScreenDataProvider for MockRecordsDataProvider {
                     difficulty_level: Some("Hard".to_string()),
                     max_stages: Some(3),
                     time_limit_seconds: None,
+                    player_name: None,
+                    unverified: false,
                 },
                 repository: Some(repositories[1].clone()),
                 session_result: Some(SessionResultData {
@@ -106,6 +112,8 @@ impl ScreenDataProvider for MockRecordsDataProvider {
                     difficulty_level: Some("Easy".to_string()),
                     max_stages: Some(3),
                     time_limit_seconds: None,
+                    player_name: None,
+                    unverified: false,
                 },
                 repository: Some(repositories[0].clone()),
                 session_result: Some(SessionResultData {
@@ -0,0 +1,28 @@
+use gittype::domain::models::Reminder;
+use gittype::presentation::tui::screens::ReminderScreenData;
+use gittype::presentation::tui::ScreenDataProvider;
+use gittype::Result;
+
+pub struct MockReminderScreenDataProvider;
+
+impl ScreenDataProvider for MockReminderScreenDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(ReminderScreenData {
+            reminders: vec![
+                Reminder::parse("09:00").unwrap(),
+                Reminder::parse("13:30").unwrap(),
+                Reminder::parse("21:15").unwrap(),
+            ],
+        }))
+    }
+}
+
+pub struct MockEmptyReminderScreenDataProvider;
+
+impl ScreenDataProvider for MockEmptyReminderScreenDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(ReminderScreenData {
+            reminders: Vec::new(),
+        }))
+    }
+}
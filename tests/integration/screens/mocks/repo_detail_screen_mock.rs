@@ -0,0 +1,139 @@
+use chrono::{TimeZone, Utc};
+use gittype::domain::models::storage::{
+    LanguageStats, LeaderboardEntry, StoredRepositoryWithLanguages,
+};
+use gittype::domain::models::RepositoryDetail;
+use gittype::domain::services::dismissed_repository_service::{
+    DismissedRepositoryService, DismissedRepositoryServiceInterface,
+};
+use gittype::domain::services::repository_detail_service::RepositoryDetailServiceInterface;
+use gittype::infrastructure::database::daos::DismissedRepositoryDao;
+use gittype::infrastructure::database::database::Database;
+use gittype::presentation::tui::ScreenDataProvider;
+use gittype::Result;
+use std::sync::{Arc, Mutex};
+
+pub fn mock_repository_detail() -> RepositoryDetail {
+    RepositoryDetail {
+        repository: StoredRepositoryWithLanguages {
+            id: 1,
+            user_name: "unhappychoice".to_string(),
+            repository_name: "gittype".to_string(),
+            remote_url: "https://github.com/unhappychoice/gittype".to_string(),
+            languages: vec!["rust".to_string(), "go".to_string()],
+            metadata: None,
+            last_played_at: None,
+            rating: None,
+        },
+        is_cached: true,
+        stages_completed: 14,
+        best_wpm: 82.4,
+        best_accuracy: 99.1,
+        best_score: 2350.0,
+        languages: vec![
+            LanguageStats {
+                language: "rust".to_string(),
+                stage_count: 11,
+                avg_wpm: 64.2,
+                avg_accuracy: 95.8,
+                avg_score: 1800.0,
+            },
+            LanguageStats {
+                language: "go".to_string(),
+                stage_count: 3,
+                avg_wpm: 55.0,
+                avg_accuracy: 92.3,
+                avg_score: 1400.0,
+            },
+        ],
+        cached_challenges: 128,
+        last_played_at: Some(Utc.with_ymd_and_hms(2024, 5, 10, 12, 30, 0).unwrap()),
+    }
+}
+
+pub fn mock_leaderboard_entries() -> Vec<LeaderboardEntry> {
+    vec![
+        LeaderboardEntry {
+            player_name: "Ada".to_string(),
+            best_score: 2350.0,
+            best_wpm: 82.4,
+            best_accuracy: 99.1,
+            sessions: 9,
+            last_played_at: Some(Utc.with_ymd_and_hms(2024, 5, 10, 12, 30, 0).unwrap()),
+        },
+        LeaderboardEntry {
+            player_name: "Player".to_string(),
+            best_score: 1820.0,
+            best_wpm: 64.0,
+            best_accuracy: 95.5,
+            sessions: 5,
+            last_played_at: None,
+        },
+    ]
+}
+
+pub struct MockRepoDetailDataProvider;
+
+impl ScreenDataProvider for MockRepoDetailDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(mock_repository_detail()))
+    }
+}
+
+/// Records which actions ran and reports the repository as uncached afterwards
+#[derive(Default)]
+pub struct MockRepositoryDetailService {
+    pub calls: Mutex<Vec<String>>,
+}
+
+impl RepositoryDetailServiceInterface for MockRepositoryDetailService {
+    fn get_repository_detail(
+        &self,
+        repository: &StoredRepositoryWithLanguages,
+    ) -> Result<RepositoryDetail> {
+        let has_run_action = !self.calls.lock().unwrap().is_empty();
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("detail:{}", repository.id));
+        Ok(RepositoryDetail {
+            repository: repository.clone(),
+            is_cached: !has_run_action,
+            cached_challenges: if has_run_action { 0 } else { 128 },
+            ..mock_repository_detail()
+        })
+    }
+
+    fn refresh_cache(&self, repository: &StoredRepositoryWithLanguages) -> Result<usize> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("refresh:{}", repository.id));
+        Ok(2)
+    }
+
+    fn delete_repository(&self, repository: &StoredRepositoryWithLanguages) -> Result<()> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("delete:{}", repository.id));
+        Ok(())
+    }
+
+    fn get_leaderboard(
+        &self,
+        repository: &StoredRepositoryWithLanguages,
+    ) -> Result<Vec<LeaderboardEntry>> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("leaderboard:{}", repository.id));
+        Ok(mock_leaderboard_entries())
+    }
+}
+
+pub fn dismissed_repository_service() -> Arc<dyn DismissedRepositoryServiceInterface> {
+    Arc::new(DismissedRepositoryService::new(Arc::new(
+        DismissedRepositoryDao::new(Arc::new(Database::new().unwrap())),
+    )))
+}
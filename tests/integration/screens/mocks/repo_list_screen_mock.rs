@@ -14,6 +14,9 @@ impl ScreenDataProvider for MockRepoListDataProvider {
                     user_name: "unhappychoice".to_string(),
                     repository_name: "gittype".to_string(),
                     remote_url: "https://github.com/unhappychoice/gittype".to_string(),
+                    metadata: None,
+                    last_played_at: None,
+                    rating: None,
                     languages: vec!["Rust".to_string(), "Shell".to_string()],
                 },
                 false, // not cached
@@ -24,6 +27,9 @@ impl ScreenDataProvider for MockRepoListDataProvider {
                     user_name: "rails".to_string(),
                     repository_name: "rails".to_string(),
                     remote_url: "https://github.com/rails/rails".to_string(),
+                    metadata: None,
+                    last_played_at: None,
+                    rating: None,
                     languages: vec![
                         "Ruby".to_string(),
                         "HTML".to_string(),
@@ -38,6 +44,9 @@ impl ScreenDataProvider for MockRepoListDataProvider {
                     user_name: "golang".to_string(),
                     repository_name: "go".to_string(),
                     remote_url: "https://github.com/golang/go".to_string(),
+                    metadata: None,
+                    last_played_at: None,
+                    rating: None,
                     languages: vec!["Go".to_string(), "Assembly".to_string()],
                 },
                 false, // not cached
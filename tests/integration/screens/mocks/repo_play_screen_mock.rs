@@ -1,3 +1,4 @@
+use chrono::{TimeZone, Utc};
 use gittype::domain::models::storage::StoredRepositoryWithLanguages;
 use gittype::presentation::tui::screens::repo_play_screen::RepoPlayScreenData;
 use gittype::presentation::tui::ScreenDataProvider;
@@ -14,6 +15,9 @@ impl ScreenDataProvider for MockRepoPlayDataProvider {
                     user_name: "unhappychoice".to_string(),
                     repository_name: "gittype".to_string(),
                     remote_url: "https://github.com/unhappychoice/gittype".to_string(),
+                    metadata: None,
+                    last_played_at: Some(Utc.with_ymd_and_hms(2026, 10, 10, 12, 0, 0).unwrap()),
+                    rating: None,
                     languages: vec!["Rust".to_string(), "Shell".to_string()],
                 },
                 false, // not cached
@@ -24,6 +28,9 @@ impl ScreenDataProvider for MockRepoPlayDataProvider {
                     user_name: "rails".to_string(),
                     repository_name: "rails".to_string(),
                     remote_url: "https://github.com/rails/rails".to_string(),
+                    metadata: None,
+                    last_played_at: None,
+                    rating: None,
                     languages: vec![
                         "Ruby".to_string(),
                         "HTML".to_string(),
@@ -38,6 +45,9 @@ impl ScreenDataProvider for MockRepoPlayDataProvider {
                     user_name: "golang".to_string(),
                     repository_name: "go".to_string(),
                     remote_url: "https://github.com/golang/go".to_string(),
+                    metadata: None,
+                    last_played_at: Some(Utc.with_ymd_and_hms(2026, 9, 1, 12, 0, 0).unwrap()),
+                    rating: None,
                     languages: vec!["Go".to_string(), "Assembly".to_string()],
                 },
                 false, // not cached
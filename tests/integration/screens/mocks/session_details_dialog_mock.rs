@@ -1,5 +1,7 @@
 use gittype::domain::models::storage::SessionResultData;
-use gittype::domain::models::{GitRepository, SessionResult, StageResult};
+use gittype::domain::models::{
+    GitRepository, KeyLoad, MistakeBreakdown, SessionResult, StageResult,
+};
 use gittype::domain::repositories::session_repository::{BestRecords, BestStatus};
 use gittype::presentation::tui::screens::session_details_dialog::SessionDetailsDialogData;
 use gittype::presentation::tui::ScreenDataProvider;
@@ -18,12 +20,14 @@ impl ScreenDataProvider for MockSessionDetailsDialogDataProvider {
             commit_hash: Some("abc123def456".to_string()),
             is_dirty: false,
             root_path: None,
+            license: None,
         });
 
         let stage_results = vec![
             StageResult {
                 cpm: 350.0,
                 wpm: 70.0,
+                token_density: 0.2,
                 accuracy: 96.7,
                 keystrokes: 150,
                 mistakes: 5,
@@ -39,10 +43,13 @@ impl ScreenDataProvider for MockSessionDetailsDialogDataProvider {
                 was_skipped: false,
                 was_failed: false,
                 challenge_path: "src/main.rs".to_string(),
+                mistake_breakdown: MistakeBreakdown::default(),
+                key_load: KeyLoad::default(),
             },
             StageResult {
                 cpm: 375.0,
                 wpm: 75.0,
+                token_density: 0.2,
                 accuracy: 96.0,
                 keystrokes: 200,
                 mistakes: 8,
@@ -58,10 +65,13 @@ impl ScreenDataProvider for MockSessionDetailsDialogDataProvider {
                 was_skipped: false,
                 was_failed: false,
                 challenge_path: "src/lib.rs".to_string(),
+                mistake_breakdown: MistakeBreakdown::default(),
+                key_load: KeyLoad::default(),
             },
             StageResult {
                 cpm: 400.0,
                 wpm: 80.0,
+                token_density: 0.2,
                 accuracy: 95.3,
                 keystrokes: 150,
                 mistakes: 7,
@@ -77,6 +87,8 @@ impl ScreenDataProvider for MockSessionDetailsDialogDataProvider {
                 was_skipped: false,
                 was_failed: false,
                 challenge_path: "src/utils.rs".to_string(),
+                mistake_breakdown: MistakeBreakdown::default(),
+                key_load: KeyLoad::default(),
             },
         ];
 
@@ -102,6 +114,8 @@ impl ScreenDataProvider for MockSessionDetailsDialogDataProvider {
             worst_stage_accuracy: 95.3,
             session_score: 1200.0,
             session_successful: true,
+            verification_code: None,
+            plausibility: Default::default(),
         });
 
         let best_status = Some(BestStatus {
@@ -1,5 +1,7 @@
-use gittype::domain::models::storage::{SessionStageResult, StoredRepository, StoredSession};
-use gittype::domain::models::{Challenge, GitRepository, SessionResult};
+use gittype::domain::models::storage::{
+    LanguageActivity, SessionStageResult, StoredRepository, StoredSession,
+};
+use gittype::domain::models::{Challenge, GitRepository, MistakeBreakdown, SessionResult};
 use gittype::domain::repositories::session_repository::SessionRepositoryTrait;
 use gittype::domain::services::scoring::StageTracker;
 use gittype::Result;
@@ -38,6 +40,16 @@ impl SessionRepositoryTrait for MockSessionRepository {
                 start_line: Some(1),
                 end_line: Some(20),
                 code_content: Some("fn main() { ... }".to_string()),
+                challenge_id: None,
+                comment_ranges: Vec::new(),
+                mistake_breakdown: Some(MistakeBreakdown {
+                    adjacent_key: 2,
+                    case: 1,
+                    shifted_symbol: 1,
+                    transposition: 1,
+                    other: 0,
+                }),
+                key_load: None,
             },
             SessionStageResult {
                 stage_number: 2,
@@ -62,6 +74,16 @@ impl SessionRepositoryTrait for MockSessionRepository {
                 start_line: Some(10),
                 end_line: Some(30),
                 code_content: Some("pub fn test() { ... }".to_string()),
+                challenge_id: None,
+                comment_ranges: Vec::new(),
+                mistake_breakdown: Some(MistakeBreakdown {
+                    adjacent_key: 3,
+                    case: 0,
+                    shifted_symbol: 2,
+                    transposition: 1,
+                    other: 2,
+                }),
+                key_load: None,
             },
             SessionStageResult {
                 stage_number: 3,
@@ -86,6 +108,10 @@ impl SessionRepositoryTrait for MockSessionRepository {
                 start_line: Some(5),
                 end_line: Some(25),
                 code_content: Some("pub mod models;".to_string()),
+                challenge_id: None,
+                comment_ranges: Vec::new(),
+                mistake_breakdown: None,
+                key_load: None,
             },
         ])
     }
@@ -94,6 +120,14 @@ impl SessionRepositoryTrait for MockSessionRepository {
         Ok(vec![])
     }
 
+    fn get_repository(&self, _repository_id: i64) -> Result<Option<StoredRepository>> {
+        Ok(None)
+    }
+
+    fn get_session(&self, _session_id: i64) -> Result<Option<StoredSession>> {
+        Ok(None)
+    }
+
     fn get_sessions_filtered(
         &self,
         _repository_filter: Option<i64>,
@@ -122,6 +156,10 @@ impl SessionRepositoryTrait for MockSessionRepository {
         Ok(None)
     }
 
+    fn get_language_baselines(&self) -> Result<Vec<LanguageActivity>> {
+        Ok(vec![])
+    }
+
     fn record_session(
         &self,
         _session_result: &SessionResult,
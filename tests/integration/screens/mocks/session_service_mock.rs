@@ -21,6 +21,13 @@ impl SessionServiceInterface for MockSessionService {
         Ok(vec![])
     }
 
+    fn get_session_with_display_data(
+        &self,
+        _session_id: i64,
+    ) -> Result<Option<SessionDisplayData>> {
+        Ok(None)
+    }
+
     fn get_all_repositories(&self) -> Result<Vec<StoredRepository>> {
         Ok(vec![])
     }
@@ -30,6 +30,8 @@ impl ScreenDataProvider for MockSessionSummaryDataProvider {
             worst_stage_accuracy: 90.0,
             session_score: 9500.0,
             session_successful: true,
+            verification_code: None,
+            plausibility: Default::default(),
         });
 
         let git_repository = Some(GitRepository {
@@ -40,6 +42,7 @@ impl ScreenDataProvider for MockSessionSummaryDataProvider {
             commit_hash: Some("def456".to_string()),
             is_dirty: false,
             root_path: None,
+            license: None,
         });
 
         let data = SessionSummaryScreenData {
@@ -76,6 +79,8 @@ impl ScreenDataProvider for MockLoadBalancerPrimarchDataProvider {
             worst_stage_accuracy: 98.0,
             session_score: 13000.0, // Load Balancer Primarch range: 12801-13400
             session_successful: true,
+            verification_code: None,
+            plausibility: Default::default(),
         });
 
         let git_repository = Some(GitRepository {
@@ -86,6 +91,7 @@ impl ScreenDataProvider for MockLoadBalancerPrimarchDataProvider {
             commit_hash: Some("def456".to_string()),
             is_dirty: false,
             root_path: None,
+            license: None,
         });
 
         let data = SessionSummaryScreenData {
@@ -122,6 +128,8 @@ impl ScreenDataProvider for MockCompilerDataProvider {
             worst_stage_accuracy: 94.0,
             session_score: 9600.0, // Compiler range: 9501-9800
             session_successful: true,
+            verification_code: None,
+            plausibility: Default::default(),
         });
 
         let git_repository = Some(GitRepository {
@@ -132,6 +140,7 @@ impl ScreenDataProvider for MockCompilerDataProvider {
             commit_hash: Some("def456".to_string()),
             is_dirty: false,
             root_path: None,
+            license: None,
         });
 
         let data = SessionSummaryScreenData {
@@ -30,6 +30,8 @@ impl ScreenDataProvider for MockSessionSummaryShareDataProvider {
             worst_stage_accuracy: 90.0,
             session_score: 8500.0,
             session_successful: true,
+            verification_code: None,
+            plausibility: Default::default(),
         };
 
         let git_repository = Some(GitRepository {
@@ -40,6 +42,7 @@ impl ScreenDataProvider for MockSessionSummaryShareDataProvider {
             commit_hash: Some("abc123".to_string()),
             is_dirty: false,
             root_path: None,
+            license: None,
         });
 
         let data = SessionSummaryShareData {
@@ -1,4 +1,4 @@
-use gittype::domain::models::StageResult;
+use gittype::domain::models::{KeyLoad, MistakeBreakdown, MistakeDiff, StageResult};
 use gittype::presentation::tui::screens::stage_summary_screen::StageSummaryData;
 use gittype::presentation::tui::ScreenDataProvider;
 use gittype::Result;
@@ -12,6 +12,7 @@ impl ScreenDataProvider for MockStageSummaryDataProvider {
             challenge_score: 850.0,
             cpm: 280.0,
             wpm: 56.0,
+            token_density: 0.2,
             accuracy: 95.5,
             completion_time: Duration::from_secs_f64(12.5),
             mistakes: 3,
@@ -26,6 +27,8 @@ impl ScreenDataProvider for MockStageSummaryDataProvider {
             was_failed: false,
             was_skipped: false,
             challenge_path: "test/path".to_string(),
+            mistake_breakdown: MistakeBreakdown::default(),
+            key_load: KeyLoad::default(),
         };
 
         Ok(Box::new(StageSummaryData {
@@ -33,6 +36,10 @@ impl ScreenDataProvider for MockStageSummaryDataProvider {
             current_stage: 2,
             total_stages: 3,
             is_completed: false,
+            mistake_diff: Some(MistakeDiff::new(
+                "fn add(a: i32, b: i32) -> i32 {\n    a + b\n}",
+                &[(4, 's'), (4, 'f'), (38, '-')],
+            )),
         }))
     }
 }
@@ -1,3 +1,4 @@
+use gittype::domain::models::version::ReleaseNotes;
 use gittype::domain::models::GitRepository;
 use gittype::presentation::tui::screens::title_screen::TitleScreenData;
 use gittype::presentation::tui::ScreenDataProvider;
@@ -7,22 +8,53 @@ pub struct MockTitleScreenDataProvider;
 
 impl ScreenDataProvider for MockTitleScreenDataProvider {
     fn provide(&self) -> Result<Box<dyn std::any::Any>> {
-        let challenge_counts = [10, 25, 40, 30, 15]; // Easy, Normal, Hard, Wild, Zen
-
-        let git_repository = Some(GitRepository {
-            user_name: "unhappychoice".to_string(),
-            repository_name: "gittype".to_string(),
-            remote_url: "https://github.com/unhappychoice/gittype.git".to_string(),
-            branch: Some("main".to_string()),
-            commit_hash: Some("abc1234567890def".to_string()),
-            is_dirty: false,
-            root_path: None,
-        });
-
-        let data = TitleScreenData {
-            challenge_counts,
-            git_repository,
-        };
-        Ok(Box::new(data))
+        Ok(Box::new(title_screen_data(None)))
+    }
+}
+
+/// Same as `MockTitleScreenDataProvider`, with a newer release to announce
+pub struct MockTitleScreenUpdateDataProvider;
+
+impl ScreenDataProvider for MockTitleScreenUpdateDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(title_screen_data(Some(ReleaseNotes {
+            version: "1.2.0".to_string(),
+            url: "https://github.com/unhappychoice/gittype/releases/tag/v1.2.0".to_string(),
+            highlights: vec![
+                "Race the ghost of any past attempt".to_string(),
+                "Daily practice reminders".to_string(),
+            ],
+        }))))
+    }
+}
+
+fn title_screen_data(update_notice: Option<ReleaseNotes>) -> TitleScreenData {
+    let challenge_counts = [10, 25, 40, 30, 15]; // Easy, Normal, Hard, Wild, Zen
+
+    let git_repository = Some(GitRepository {
+        user_name: "unhappychoice".to_string(),
+        repository_name: "gittype".to_string(),
+        remote_url: "https://github.com/unhappychoice/gittype.git".to_string(),
+        branch: Some("main".to_string()),
+        commit_hash: Some("abc1234567890def".to_string()),
+        is_dirty: false,
+        root_path: None,
+        license: None,
+    });
+
+    let language_counts = vec![
+        ("Rust".to_string(), 70),
+        ("TypeScript".to_string(), 30),
+        ("Python".to_string(), 12),
+        ("Go".to_string(), 5),
+        ("Shell".to_string(), 2),
+        ("Ruby".to_string(), 1),
+    ];
+
+    TitleScreenData {
+        challenge_counts,
+        language_counts,
+        git_repository,
+        update_notice,
     }
 }
@@ -2,6 +2,13 @@ use gittype::domain::events::EventBusInterface;
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::theme::Theme;
 use gittype::domain::models::Challenge;
+use gittype::domain::services::challenge_flag_service::{
+    ChallengeFlagService, ChallengeFlagServiceInterface,
+};
+use gittype::domain::services::config_service::ConfigService;
+use gittype::domain::services::discord_presence_service::{
+    DiscordPresenceService, DiscordPresenceServiceInterface,
+};
 use gittype::domain::services::scoring::tracker::StageTracker;
 use gittype::domain::services::scoring::{
     SessionTracker, SessionTrackerInterface, TotalTracker, TotalTrackerInterface,
@@ -9,9 +16,15 @@ use gittype::domain::services::scoring::{
 use gittype::domain::services::session_manager_service::SessionManagerInterface;
 use gittype::domain::services::stage_builder_service::StageRepository;
 use gittype::domain::services::stage_builder_service::StageRepositoryInterface;
+use gittype::domain::services::streamer_stats_service::{
+    StreamerStatsService, StreamerStatsServiceInterface,
+};
 use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
 use gittype::domain::services::SessionManager;
 use gittype::domain::stores::{ChallengeStore, RepositoryStore, SessionStore};
+use gittype::infrastructure::database::daos::ChallengeFlagDao;
+use gittype::infrastructure::database::database::Database;
+use gittype::infrastructure::storage::file_storage::FileStorage;
 use gittype::presentation::tui::screens::typing_screen::TypingScreen;
 use gittype::presentation::tui::{Screen, ScreenDataProvider};
 use gittype::Result;
@@ -31,6 +44,18 @@ impl ScreenDataProvider for MockTypingScreenDataProvider {
 pub fn create_typing_screen_with_challenge(
     event_bus: Arc<dyn EventBusInterface>,
     code: Option<&str>,
+) -> TypingScreen {
+    create_typing_screen_with_config(
+        event_bus,
+        code,
+        Arc::new(ConfigService::new_for_test().unwrap()),
+    )
+}
+
+pub fn create_typing_screen_with_config(
+    event_bus: Arc<dyn EventBusInterface>,
+    code: Option<&str>,
+    config_service: Arc<ConfigService>,
 ) -> TypingScreen {
     let (_challenge_store, repository_store, _session_store, stage_repository) =
         if let Some(code_content) = code {
@@ -147,6 +172,10 @@ pub fn create_typing_screen_with_challenge(
         theme_service,
         repository_store,
         session_manager_arc as Arc<dyn SessionManagerInterface>,
+        discord_presence_service(),
+        challenge_flag_service(),
+        config_service,
+        streamer_stats_service(),
     );
 
     // Load challenge if provided
@@ -157,3 +186,23 @@ pub fn create_typing_screen_with_challenge(
 
     screen
 }
+
+fn challenge_flag_service() -> Arc<dyn ChallengeFlagServiceInterface> {
+    Arc::new(ChallengeFlagService::new(Arc::new(ChallengeFlagDao::new(
+        Arc::new(Database::new().unwrap()),
+    ))))
+}
+
+fn discord_presence_service() -> Arc<dyn DiscordPresenceServiceInterface> {
+    Arc::new(DiscordPresenceService::new(Arc::new(
+        ConfigService::new_for_test().unwrap(),
+    )))
+}
+
+fn streamer_stats_service() -> Arc<dyn StreamerStatsServiceInterface> {
+    let config_service = Arc::new(ConfigService::new_for_test().unwrap());
+    Arc::new(StreamerStatsService::new(
+        Arc::new(FileStorage::new()),
+        config_service,
+    ))
+}
@@ -0,0 +1,54 @@
+use gittype::domain::models::{ChangelogRelease, ChangelogSection};
+use gittype::presentation::tui::ScreenDataProvider;
+use gittype::Result;
+
+pub struct MockWhatsNewDataProvider;
+
+impl ScreenDataProvider for MockWhatsNewDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(Some(ChangelogRelease {
+            version: "1.2.0".to_string(),
+            date: Some("2026-05-01".to_string()),
+            sections: vec![
+                ChangelogSection {
+                    title: "✨ Features".to_string(),
+                    entries: vec![
+                        "feat: add achievements screen".to_string(),
+                        "feat: add repository detail screen".to_string(),
+                    ],
+                },
+                ChangelogSection {
+                    title: "🐛 Bug Fixes".to_string(),
+                    entries: vec!["fix: keep stage timestamps when loading history".to_string()],
+                },
+                ChangelogSection {
+                    title: "📝 Other Changes".to_string(),
+                    entries: Vec::new(),
+                },
+            ],
+        })))
+    }
+}
+
+pub struct MockLongWhatsNewDataProvider;
+
+impl ScreenDataProvider for MockLongWhatsNewDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(Some(ChangelogRelease {
+            version: "1.2.0".to_string(),
+            date: None,
+            sections: vec![ChangelogSection {
+                title: "✨ Features".to_string(),
+                entries: (1..=60).map(|i| format!("feat: change {}", i)).collect(),
+            }],
+        })))
+    }
+}
+
+pub struct MockMissingWhatsNewDataProvider;
+
+impl ScreenDataProvider for MockMissingWhatsNewDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(None::<ChangelogRelease>))
+    }
+}
@@ -0,0 +1,31 @@
+use gittype::domain::models::{WorkspaceKind, WorkspacePackage};
+use gittype::presentation::tui::screens::WorkspacePackageScreenData;
+use gittype::presentation::tui::ScreenDataProvider;
+use gittype::Result;
+
+pub struct MockWorkspacePackageScreenDataProvider;
+
+impl ScreenDataProvider for MockWorkspacePackageScreenDataProvider {
+    fn provide(&self) -> Result<Box<dyn std::any::Any>> {
+        Ok(Box::new(WorkspacePackageScreenData {
+            repository: "acme".to_string(),
+            packages: vec![
+                WorkspacePackage {
+                    name: "acme-core".to_string(),
+                    path: "crates/core".into(),
+                    kind: WorkspaceKind::Cargo,
+                },
+                WorkspacePackage {
+                    name: "example.com/acme/api".to_string(),
+                    path: "services/api".into(),
+                    kind: WorkspaceKind::Go,
+                },
+                WorkspacePackage {
+                    name: "@acme/web".to_string(),
+                    path: "web".into(),
+                    kind: WorkspaceKind::Npm,
+                },
+            ],
+        }))
+    }
+}
@@ -3,13 +3,21 @@ mod helpers;
 
 mod mocks;
 
+mod achievements_screen_test;
 mod analytics_screen_test;
 mod animation_screen_test;
+mod error_screen_test;
 mod help_screen_test;
 mod info_dialog_test;
+mod keyboard_test_screen_test;
+mod leaderboard_screen_test;
 mod loading_screen_test;
+mod log_viewer_screen_test;
 mod panic_screen_test;
+mod profile_screen_test;
 mod records_screen_test;
+mod reminder_screen_test;
+mod repo_detail_screen_test;
 mod repo_list_screen_test;
 mod repo_play_screen_test;
 mod session_detail_screen_test;
@@ -24,5 +32,8 @@ mod total_summary_screen_test;
 mod total_summary_share_screen_test;
 mod trending_language_selection_screen_test;
 mod trending_repository_selection_screen_test;
+mod tutorial_screen_test;
 mod typing_screen_test;
 mod version_check_screen_test;
+mod whats_new_screen_test;
+mod workspace_package_screen_test;
@@ -0,0 +1,131 @@
+use crate::integration::screens::mocks::profile_screen_mock::{
+    MockProfileDataProvider, MockProfileService,
+};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use gittype::domain::events::presentation_events::NavigateTo;
+use gittype::domain::events::EventBus;
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::theme::Theme;
+use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::presentation::tui::screens::ProfileScreen;
+use gittype::presentation::tui::{Screen, ScreenDataProvider, ScreenType};
+use std::sync::{Arc, Mutex};
+
+fn make_profile_screen(event_bus: Arc<EventBus>) -> ProfileScreen {
+    ProfileScreen::new(
+        event_bus,
+        Arc::new(ThemeService::new_for_test(
+            Theme::default(),
+            ColorMode::Dark,
+        )) as Arc<dyn ThemeServiceInterface>,
+        Arc::new(MockProfileService::default()),
+    )
+}
+
+fn press(screen: &ProfileScreen, code: KeyCode) {
+    screen
+        .handle_key_event(KeyEvent::new(code, KeyModifiers::empty()))
+        .unwrap();
+}
+
+screen_snapshot_test!(
+    test_profile_screen_snapshot,
+    ProfileScreen,
+    make_profile_screen(Arc::new(EventBus::new())),
+    provider = MockProfileDataProvider
+);
+
+screen_snapshot_test!(
+    test_profile_screen_snapshot_editing_name,
+    ProfileScreen,
+    make_profile_screen(Arc::new(EventBus::new())),
+    provider = MockProfileDataProvider,
+    keys = [
+        KeyEvent::new(KeyCode::Char('e'), KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Backspace, KeyModifiers::empty())
+    ]
+);
+
+screen_key_event_test!(
+    test_profile_screen_esc_navigates_back,
+    ProfileScreen,
+    make_profile_screen,
+    NavigateTo,
+    KeyCode::Esc,
+    KeyModifiers::empty(),
+    MockProfileDataProvider
+);
+
+screen_key_event_test!(
+    test_profile_screen_ctrl_c_exits,
+    ProfileScreen,
+    make_profile_screen,
+    NavigateTo,
+    KeyCode::Char('c'),
+    KeyModifiers::CONTROL,
+    MockProfileDataProvider
+);
+
+screen_basic_methods_test!(
+    test_profile_screen_basic_methods,
+    ProfileScreen,
+    make_profile_screen(Arc::new(EventBus::new())),
+    ScreenType::Profile,
+    false,
+    MockProfileDataProvider
+);
+
+#[test]
+fn test_profile_screen_saves_edited_display_name() {
+    let screen = make_profile_screen(Arc::new(EventBus::new()));
+    screen
+        .init_with_data(MockProfileDataProvider.provide().unwrap())
+        .unwrap();
+
+    press(&screen, KeyCode::Char('e'));
+    assert!(screen.is_editing_name());
+    "Ada Lovelace"
+        .chars()
+        .for_each(|_| press(&screen, KeyCode::Backspace));
+    "  Grace Hopper "
+        .chars()
+        .for_each(|c| press(&screen, KeyCode::Char(c)));
+    press(&screen, KeyCode::Enter);
+
+    assert!(!screen.is_editing_name());
+    assert_eq!(screen.get_profile().unwrap().display_name, "Grace Hopper");
+    assert_eq!(
+        screen.get_status_message().as_deref(),
+        Some("Display name saved")
+    );
+}
+
+#[test]
+fn test_profile_screen_esc_cancels_editing_without_leaving() {
+    let event_bus = Arc::new(EventBus::new());
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = Arc::clone(&events);
+    event_bus.subscribe(move |event: &NavigateTo| {
+        events_clone.lock().unwrap().push(event.clone());
+    });
+    let screen = make_profile_screen(event_bus);
+    screen
+        .init_with_data(MockProfileDataProvider.provide().unwrap())
+        .unwrap();
+
+    press(&screen, KeyCode::Char('e'));
+    press(&screen, KeyCode::Char('x'));
+    press(&screen, KeyCode::Esc);
+
+    assert!(!screen.is_editing_name());
+    assert_eq!(screen.get_profile().unwrap().display_name, "Ada Lovelace");
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_profile_screen_loads_from_service_without_provider_data() {
+    let screen = make_profile_screen(Arc::new(EventBus::new()));
+    screen.init_with_data(Box::new(())).unwrap();
+
+    assert_eq!(screen.get_profile().unwrap().stages_completed, 42);
+}
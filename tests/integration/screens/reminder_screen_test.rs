@@ -0,0 +1,141 @@
+use crate::integration::screens::mocks::reminder_screen_mock::{
+    MockEmptyReminderScreenDataProvider, MockReminderScreenDataProvider,
+};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use gittype::domain::events::presentation_events::NavigateTo;
+use gittype::domain::events::EventBus;
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::theme::Theme;
+use gittype::domain::models::Reminder;
+use gittype::domain::services::config_service::{ConfigService, ConfigServiceInterface};
+use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::presentation::tui::screens::ReminderScreen;
+use gittype::presentation::tui::{Screen, ScreenDataProvider};
+use std::sync::Arc;
+
+fn make_reminder_screen(event_bus: Arc<EventBus>) -> ReminderScreen {
+    ReminderScreen::new(
+        event_bus,
+        Arc::new(ThemeService::new_for_test(
+            Theme::default(),
+            ColorMode::Dark,
+        )) as Arc<dyn ThemeServiceInterface>,
+        Arc::new(ConfigService::new_for_test().unwrap()) as Arc<dyn ConfigServiceInterface>,
+    )
+}
+
+screen_snapshot_test!(
+    test_reminder_screen_snapshot,
+    ReminderScreen,
+    make_reminder_screen(Arc::new(EventBus::new())),
+    provider = MockReminderScreenDataProvider
+);
+
+screen_snapshot_test!(
+    test_reminder_screen_snapshot_after_removing,
+    ReminderScreen,
+    make_reminder_screen(Arc::new(EventBus::new())),
+    provider = MockReminderScreenDataProvider,
+    keys = [
+        KeyEvent::new(KeyCode::Down, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Char('d'), KeyModifiers::empty())
+    ]
+);
+
+screen_snapshot_test!(
+    test_reminder_screen_snapshot_without_reminders,
+    ReminderScreen,
+    make_reminder_screen(Arc::new(EventBus::new())),
+    provider = MockEmptyReminderScreenDataProvider
+);
+
+screen_key_event_test!(
+    test_reminder_screen_esc_exits,
+    ReminderScreen,
+    make_reminder_screen,
+    NavigateTo,
+    KeyCode::Esc,
+    KeyModifiers::empty(),
+    MockReminderScreenDataProvider
+);
+
+screen_key_event_test!(
+    test_reminder_screen_ctrl_c_exits,
+    ReminderScreen,
+    make_reminder_screen,
+    NavigateTo,
+    KeyCode::Char('c'),
+    KeyModifiers::CONTROL,
+    MockReminderScreenDataProvider
+);
+
+screen_basic_methods_test!(
+    test_reminder_screen_basic_methods,
+    ReminderScreen,
+    make_reminder_screen(Arc::new(EventBus::new())),
+    gittype::presentation::tui::ScreenType::Reminders,
+    false,
+    MockReminderScreenDataProvider
+);
+
+fn press(screen: &ReminderScreen, code: KeyCode) {
+    screen
+        .handle_key_event(KeyEvent::new(code, KeyModifiers::empty()))
+        .unwrap()
+}
+
+#[test]
+fn test_reminder_screen_selection_stays_within_list() {
+    let screen = make_reminder_screen(Arc::new(EventBus::new()));
+    screen
+        .init_with_data(MockReminderScreenDataProvider.provide().unwrap())
+        .unwrap();
+
+    assert_eq!(screen.selected_reminder(), Reminder::parse("09:00").ok());
+    press(&screen, KeyCode::Up);
+    assert_eq!(screen.selected_reminder(), Reminder::parse("09:00").ok());
+
+    press(&screen, KeyCode::Char('j'));
+    press(&screen, KeyCode::Down);
+    press(&screen, KeyCode::Down);
+    assert_eq!(screen.selected_reminder(), Reminder::parse("21:15").ok());
+}
+
+#[test]
+fn test_reminder_screen_removing_last_selects_previous() {
+    let screen = make_reminder_screen(Arc::new(EventBus::new()));
+    screen
+        .init_with_data(MockReminderScreenDataProvider.provide().unwrap())
+        .unwrap();
+
+    press(&screen, KeyCode::Down);
+    press(&screen, KeyCode::Down);
+    press(&screen, KeyCode::Delete);
+
+    assert_eq!(
+        screen.reminders(),
+        vec![
+            Reminder::parse("09:00").unwrap(),
+            Reminder::parse("13:30").unwrap()
+        ]
+    );
+    assert_eq!(screen.selected_reminder(), Reminder::parse("13:30").ok());
+    assert_eq!(
+        screen.message().as_deref(),
+        Some("Removed the 21:15 reminder")
+    );
+}
+
+#[test]
+fn test_reminder_screen_remove_without_reminders_does_nothing() {
+    let screen = make_reminder_screen(Arc::new(EventBus::new()));
+    screen
+        .init_with_data(MockEmptyReminderScreenDataProvider.provide().unwrap())
+        .unwrap();
+
+    press(&screen, KeyCode::Char('d'));
+
+    assert!(screen.reminders().is_empty());
+    assert_eq!(screen.selected_reminder(), None);
+    assert_eq!(screen.message(), None);
+}
@@ -0,0 +1,247 @@
+use crate::integration::screens::mocks::repo_detail_screen_mock::{
+    dismissed_repository_service, MockRepoDetailDataProvider, MockRepositoryDetailService,
+};
+use crate::integration::screens::mocks::repo_list_screen_mock::MockRepoListDataProvider;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use gittype::domain::events::presentation_events::NavigateTo;
+use gittype::domain::events::EventBus;
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::theme::Theme;
+use gittype::domain::services::repository_detail_service::RepositoryDetailServiceInterface;
+use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::domain::stores::{RepositoryStore, RepositoryStoreInterface};
+use gittype::presentation::tui::screens::{RepoDetailScreen, RepoListScreen};
+use gittype::presentation::tui::{Screen, ScreenDataProvider, ScreenType};
+use std::sync::{Arc, Mutex};
+
+fn theme_service() -> Arc<dyn ThemeServiceInterface> {
+    Arc::new(ThemeService::new_for_test(
+        Theme::default(),
+        ColorMode::Dark,
+    ))
+}
+
+fn make_repo_detail_screen(event_bus: Arc<EventBus>) -> RepoDetailScreen {
+    RepoDetailScreen::new(
+        event_bus,
+        theme_service(),
+        Arc::new(MockRepositoryDetailService::default())
+            as Arc<dyn RepositoryDetailServiceInterface>,
+        Arc::new(RepositoryStore::new_for_test()) as Arc<dyn RepositoryStoreInterface>,
+        dismissed_repository_service(),
+    )
+}
+
+fn press(screen: &RepoDetailScreen, code: KeyCode) {
+    screen
+        .handle_key_event(KeyEvent::new(code, KeyModifiers::empty()))
+        .unwrap();
+}
+
+screen_snapshot_test!(
+    test_repo_detail_screen_snapshot,
+    RepoDetailScreen,
+    make_repo_detail_screen(Arc::new(EventBus::new())),
+    provider = MockRepoDetailDataProvider
+);
+
+screen_snapshot_test!(
+    test_repo_detail_screen_snapshot_confirming_delete,
+    RepoDetailScreen,
+    make_repo_detail_screen(Arc::new(EventBus::new())),
+    provider = MockRepoDetailDataProvider,
+    keys = [KeyEvent::new(KeyCode::Char('d'), KeyModifiers::empty())]
+);
+
+screen_key_event_test!(
+    test_repo_detail_screen_esc_navigates_back,
+    RepoDetailScreen,
+    make_repo_detail_screen,
+    NavigateTo,
+    KeyCode::Esc,
+    KeyModifiers::empty(),
+    MockRepoDetailDataProvider
+);
+
+screen_key_event_test!(
+    test_repo_detail_screen_ctrl_c_exits,
+    RepoDetailScreen,
+    make_repo_detail_screen,
+    NavigateTo,
+    KeyCode::Char('c'),
+    KeyModifiers::CONTROL,
+    MockRepoDetailDataProvider
+);
+
+screen_basic_methods_test!(
+    test_repo_detail_screen_basic_methods,
+    RepoDetailScreen,
+    make_repo_detail_screen(Arc::new(EventBus::new())),
+    ScreenType::RepoDetail,
+    true,
+    MockRepoDetailDataProvider
+);
+
+#[test]
+fn test_repo_detail_screen_loads_repository_selected_in_list() {
+    let repository_store =
+        Arc::new(RepositoryStore::new_for_test()) as Arc<dyn RepositoryStoreInterface>;
+    let repo_list = RepoListScreen::new(
+        Arc::new(EventBus::new()),
+        theme_service(),
+        Arc::clone(&repository_store),
+    );
+    repo_list
+        .init_with_data(MockRepoListDataProvider.provide().unwrap())
+        .unwrap();
+    repo_list
+        .handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::empty()))
+        .unwrap();
+
+    let screen = RepoDetailScreen::new(
+        Arc::new(EventBus::new()),
+        theme_service(),
+        Arc::new(MockRepositoryDetailService::default()),
+        repository_store,
+        dismissed_repository_service(),
+    );
+    screen.on_pushed_from(&repo_list).unwrap();
+
+    let detail = screen.get_detail().unwrap();
+    assert_eq!(detail.repository.id, 2);
+    assert_eq!(detail.repo_spec(), "rails/rails");
+}
+
+#[test]
+fn test_repo_detail_screen_requires_repo_list_source() {
+    let screen = make_repo_detail_screen(Arc::new(EventBus::new()));
+    let other = make_repo_detail_screen(Arc::new(EventBus::new()));
+
+    assert!(screen.on_pushed_from(&other).is_err());
+}
+
+#[test]
+fn test_repo_detail_screen_play_stores_spec_and_exits() {
+    let event_bus = Arc::new(EventBus::new());
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = Arc::clone(&events);
+    event_bus.subscribe(move |event: &NavigateTo| {
+        events_clone.lock().unwrap().push(event.clone());
+    });
+    let repository_store =
+        Arc::new(RepositoryStore::new_for_test()) as Arc<dyn RepositoryStoreInterface>;
+    let repo_list = RepoListScreen::new(
+        Arc::new(EventBus::new()),
+        theme_service(),
+        Arc::clone(&repository_store),
+    );
+    let screen = RepoDetailScreen::new(
+        event_bus,
+        theme_service(),
+        Arc::new(MockRepositoryDetailService::default()),
+        repository_store,
+        dismissed_repository_service(),
+    );
+    screen
+        .init_with_data(MockRepoDetailDataProvider.provide().unwrap())
+        .unwrap();
+
+    press(&screen, KeyCode::Char('p'));
+
+    assert_eq!(
+        repo_list.get_play_request().as_deref(),
+        Some("unhappychoice/gittype")
+    );
+    assert!(matches!(
+        events.lock().unwrap().as_slice(),
+        [NavigateTo::Exit]
+    ));
+}
+
+#[test]
+fn test_repo_detail_screen_refresh_clears_cache_and_reloads() {
+    let service = Arc::new(MockRepositoryDetailService::default());
+    let screen = RepoDetailScreen::new(
+        Arc::new(EventBus::new()),
+        theme_service(),
+        Arc::clone(&service) as Arc<dyn RepositoryDetailServiceInterface>,
+        Arc::new(RepositoryStore::new_for_test()),
+        dismissed_repository_service(),
+    );
+    screen
+        .init_with_data(MockRepoDetailDataProvider.provide().unwrap())
+        .unwrap();
+
+    press(&screen, KeyCode::Char('r'));
+
+    assert_eq!(
+        *service.calls.lock().unwrap(),
+        vec!["refresh:1", "detail:1"]
+    );
+    assert_eq!(screen.get_detail().unwrap().cached_challenges, 0);
+    assert!(screen
+        .get_status_message()
+        .unwrap()
+        .starts_with("Cleared 2 cached challenge sets"));
+}
+
+#[test]
+fn test_repo_detail_screen_delete_requires_confirmation() {
+    let service = Arc::new(MockRepositoryDetailService::default());
+    let screen = RepoDetailScreen::new(
+        Arc::new(EventBus::new()),
+        theme_service(),
+        Arc::clone(&service) as Arc<dyn RepositoryDetailServiceInterface>,
+        Arc::new(RepositoryStore::new_for_test()),
+        dismissed_repository_service(),
+    );
+    screen
+        .init_with_data(MockRepoDetailDataProvider.provide().unwrap())
+        .unwrap();
+
+    press(&screen, KeyCode::Char('d'));
+    assert!(screen.is_confirming_delete());
+    press(&screen, KeyCode::Char('n'));
+    assert!(!screen.is_confirming_delete());
+    assert!(service.calls.lock().unwrap().is_empty());
+
+    press(&screen, KeyCode::Char('d'));
+    press(&screen, KeyCode::Char('y'));
+
+    assert_eq!(*service.calls.lock().unwrap(), vec!["delete:1", "detail:1"]);
+    assert!(!screen.get_detail().unwrap().is_cached);
+    assert!(!screen.is_confirming_delete());
+}
+
+#[test]
+fn test_repo_detail_screen_n_toggles_never_suggest() {
+    let dismissed_repository_service = dismissed_repository_service();
+    let screen = RepoDetailScreen::new(
+        Arc::new(EventBus::new()),
+        theme_service(),
+        Arc::new(MockRepositoryDetailService::default()),
+        Arc::new(RepositoryStore::new_for_test()),
+        Arc::clone(&dismissed_repository_service),
+    );
+    screen
+        .init_with_data(MockRepoDetailDataProvider.provide().unwrap())
+        .unwrap();
+
+    press(&screen, KeyCode::Char('n'));
+    assert!(dismissed_repository_service
+        .is_dismissed("unhappychoice/gittype")
+        .unwrap());
+    assert_eq!(
+        screen.get_status_message().as_deref(),
+        Some("unhappychoice/gittype will no longer be suggested in trending")
+    );
+
+    press(&screen, KeyCode::Char('N'));
+    assert!(!dismissed_repository_service
+        .is_dismissed("unhappychoice/gittype")
+        .unwrap());
+    assert_eq!(
+        screen.get_status_message().as_deref(),
+        Some("unhappychoice/gittype can be suggested in trending again")
+    );
+}
@@ -1,30 +1,46 @@
 use crate::integration::screens::mocks::repo_list_screen_mock::MockRepoListDataProvider;
-use crossterm::event::{KeyCode, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use gittype::domain::events::presentation_events::NavigateTo;
 use gittype::domain::events::EventBus;
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::theme::Theme;
 use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::domain::stores::{RepositoryStore, RepositoryStoreInterface};
 use gittype::presentation::tui::screens::RepoListScreen;
-use std::sync::Arc;
+use gittype::presentation::tui::{Screen, ScreenDataProvider, ScreenType};
+use std::sync::{Arc, Mutex};
 
-screen_snapshot_test!(
-    test_repo_list_screen_snapshot,
-    RepoListScreen,
+fn make_repo_list_screen(event_bus: Arc<EventBus>) -> RepoListScreen {
     RepoListScreen::new(
-        Arc::new(EventBus::new()),
+        event_bus,
         Arc::new(ThemeService::new_for_test(
             Theme::default(),
-            ColorMode::Dark
-        )) as Arc<dyn ThemeServiceInterface>
-    ),
+            ColorMode::Dark,
+        )) as Arc<dyn ThemeServiceInterface>,
+        Arc::new(RepositoryStore::new_for_test()) as Arc<dyn RepositoryStoreInterface>,
+    )
+}
+
+screen_snapshot_test!(
+    test_repo_list_screen_snapshot,
+    RepoListScreen,
+    make_repo_list_screen(Arc::new(EventBus::new())),
     provider = MockRepoListDataProvider
 );
 
+screen_snapshot_test!(
+    test_repo_list_screen_snapshot_after_navigation,
+    RepoListScreen,
+    make_repo_list_screen(Arc::new(EventBus::new())),
+    provider = MockRepoListDataProvider,
+    keys = [KeyEvent::new(KeyCode::Char('j'), KeyModifiers::empty())]
+);
+
 // Event-producing key tests
 screen_key_event_test!(
     test_repo_list_screen_esc_exits,
     RepoListScreen,
+    make_repo_list_screen,
     NavigateTo,
     KeyCode::Esc,
     KeyModifiers::empty(),
@@ -34,23 +50,92 @@ screen_key_event_test!(
 screen_key_event_test!(
     test_repo_list_screen_ctrl_c_exits,
     RepoListScreen,
+    make_repo_list_screen,
     NavigateTo,
     KeyCode::Char('c'),
     KeyModifiers::CONTROL,
     MockRepoListDataProvider
 );
 
+#[test]
+fn test_repo_list_screen_enter_opens_selected_repository() {
+    let event_bus = Arc::new(EventBus::new());
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = Arc::clone(&events);
+    event_bus.subscribe(move |event: &NavigateTo| {
+        events_clone.lock().unwrap().push(event.clone());
+    });
+
+    let screen = make_repo_list_screen(event_bus);
+    screen
+        .init_with_data(MockRepoListDataProvider.provide().unwrap())
+        .unwrap();
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::empty()))
+        .unwrap();
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()))
+        .unwrap();
+
+    let (repository, is_cached) = screen.get_selected_repository().unwrap();
+    assert_eq!(repository.repository_name, "rails");
+    assert!(is_cached);
+    assert!(matches!(
+        events.lock().unwrap().as_slice(),
+        [NavigateTo::Push(ScreenType::RepoDetail)]
+    ));
+}
+
+#[test]
+fn test_repo_list_screen_selection_stays_in_bounds() {
+    let screen = make_repo_list_screen(Arc::new(EventBus::new()));
+    screen
+        .init_with_data(MockRepoListDataProvider.provide().unwrap())
+        .unwrap();
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Up, KeyModifiers::empty()))
+        .unwrap();
+    assert_eq!(
+        screen.get_selected_repository().unwrap().0.repository_name,
+        "gittype"
+    );
+
+    for _ in 0..5 {
+        screen
+            .handle_key_event(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::empty()))
+            .unwrap();
+    }
+    assert_eq!(
+        screen.get_selected_repository().unwrap().0.repository_name,
+        "go"
+    );
+    assert_eq!(screen.get_play_request(), None);
+}
+
+#[test]
+fn test_repo_list_screen_enter_without_repositories_does_nothing() {
+    let event_bus = Arc::new(EventBus::new());
+    let events = Arc::new(Mutex::new(Vec::<NavigateTo>::new()));
+    let events_clone = Arc::clone(&events);
+    event_bus.subscribe(move |event: &NavigateTo| {
+        events_clone.lock().unwrap().push(event.clone());
+    });
+
+    let screen = make_repo_list_screen(event_bus);
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(screen.get_selected_repository().is_none());
+    assert!(events.lock().unwrap().is_empty());
+}
+
 // Basic methods test
 screen_basic_methods_test!(
     test_repo_list_screen_basic_methods,
     RepoListScreen,
-    RepoListScreen::new(
-        Arc::new(EventBus::new()),
-        Arc::new(ThemeService::new_for_test(
-            Theme::default(),
-            ColorMode::Dark
-        )) as Arc<dyn ThemeServiceInterface>
-    ),
+    make_repo_list_screen(Arc::new(EventBus::new())),
     gittype::presentation::tui::ScreenType::RepoList,
     true,
     MockRepoListDataProvider
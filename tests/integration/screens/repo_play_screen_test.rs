@@ -4,7 +4,11 @@ use gittype::domain::events::presentation_events::NavigateTo;
 use gittype::domain::events::EventBus;
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::theme::Theme;
+use gittype::domain::repositories::starred_repository::{
+    StarredRepository, StarredRepositoryInterface,
+};
 use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::infrastructure::http::github_api_client::GitHubApiClientFactoryImpl;
 use gittype::presentation::tui::screens::RepoPlayScreen;
 use gittype::presentation::tui::{Screen, ScreenDataProvider};
 use std::sync::{Arc, Mutex};
@@ -17,7 +21,8 @@ screen_snapshot_test!(
         Arc::new(ThemeService::new_for_test(
             Theme::default(),
             ColorMode::Dark
-        )) as Arc<dyn ThemeServiceInterface>
+        )) as Arc<dyn ThemeServiceInterface>,
+        starred_repository()
     ),
     provider = MockRepoPlayDataProvider
 );
@@ -26,6 +31,7 @@ screen_snapshot_test!(
 screen_key_event_test!(
     test_repo_play_screen_esc_exits,
     RepoPlayScreen,
+    screen_with,
     NavigateTo,
     KeyCode::Esc,
     KeyModifiers::empty(),
@@ -35,6 +41,7 @@ screen_key_event_test!(
 screen_key_event_test!(
     test_repo_play_screen_ctrl_c_exits,
     RepoPlayScreen,
+    screen_with,
     NavigateTo,
     KeyCode::Char('c'),
     KeyModifiers::CONTROL,
@@ -44,6 +51,7 @@ screen_key_event_test!(
 screen_key_event_test!(
     test_repo_play_screen_space_selects,
     RepoPlayScreen,
+    screen_with,
     NavigateTo,
     KeyCode::Char(' '),
     KeyModifiers::empty(),
@@ -51,8 +59,9 @@ screen_key_event_test!(
 );
 
 // Non-event key tests
-screen_key_tests!(
+screen_key_tests_custom!(
     RepoPlayScreen,
+    screen_with,
     MockRepoPlayDataProvider,
     [
         (
@@ -61,9 +70,9 @@ screen_key_tests!(
             KeyModifiers::empty()
         ),
         (
-            test_repo_play_screen_k_navigates,
-            KeyCode::Char('k'),
-            KeyModifiers::empty()
+            test_repo_play_screen_ctrl_p_navigates,
+            KeyCode::Char('p'),
+            KeyModifiers::CONTROL
         ),
         (
             test_repo_play_screen_down_navigates,
@@ -71,9 +80,9 @@ screen_key_tests!(
             KeyModifiers::empty()
         ),
         (
-            test_repo_play_screen_j_navigates,
-            KeyCode::Char('j'),
-            KeyModifiers::empty()
+            test_repo_play_screen_ctrl_n_navigates,
+            KeyCode::Char('n'),
+            KeyModifiers::CONTROL
         ),
     ]
 );
@@ -87,7 +96,8 @@ screen_basic_methods_test!(
         Arc::new(ThemeService::new_for_test(
             Theme::default(),
             ColorMode::Dark
-        )) as Arc<dyn ThemeServiceInterface>
+        )) as Arc<dyn ThemeServiceInterface>,
+        starred_repository()
     ),
     gittype::presentation::tui::ScreenType::RepoPlay,
     true,
@@ -95,15 +105,26 @@ screen_basic_methods_test!(
 );
 
 fn make_screen() -> RepoPlayScreen {
+    screen_with(Arc::new(EventBus::new()))
+}
+
+fn screen_with(event_bus: Arc<EventBus>) -> RepoPlayScreen {
     RepoPlayScreen::new(
-        Arc::new(EventBus::new()),
+        event_bus,
         Arc::new(ThemeService::new_for_test(
             Theme::default(),
             ColorMode::Dark,
         )) as Arc<dyn ThemeServiceInterface>,
+        starred_repository(),
     )
 }
 
+fn starred_repository() -> Arc<dyn StarredRepositoryInterface> {
+    Arc::new(StarredRepository::new(Arc::new(
+        GitHubApiClientFactoryImpl::default(),
+    )))
+}
+
 #[test]
 fn test_repo_play_screen_selected_accessors_default_to_none() {
     let screen = make_screen();
@@ -163,7 +184,7 @@ fn test_repo_play_screen_up_at_first_index_stays_at_zero() {
         .unwrap();
 
     screen
-        .handle_key_event(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::empty()))
+        .handle_key_event(KeyEvent::new(KeyCode::Up, KeyModifiers::empty()))
         .unwrap();
     screen
         .handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty()))
@@ -181,7 +202,7 @@ fn test_repo_play_screen_down_past_end_clamps_to_last_index() {
 
     for _ in 0..10 {
         screen
-            .handle_key_event(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::empty()))
+            .handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::empty()))
             .unwrap();
     }
     screen
@@ -223,13 +244,13 @@ fn test_repo_play_screen_unhandled_key_does_not_publish_or_select() {
         captured_clone.lock().unwrap().push(event.clone());
     });
 
-    let screen = RepoPlayScreen::new(event_bus, theme_service);
+    let screen = RepoPlayScreen::new(event_bus, theme_service, starred_repository());
     screen
         .init_with_data(MockRepoPlayDataProvider.provide().unwrap())
         .unwrap();
 
     screen
-        .handle_key_event(KeyEvent::new(KeyCode::Tab, KeyModifiers::empty()))
+        .handle_key_event(KeyEvent::new(KeyCode::F(5), KeyModifiers::empty()))
         .unwrap();
 
     assert!(captured.lock().unwrap().is_empty());
@@ -249,3 +270,217 @@ fn test_repo_play_screen_as_any_downcasts_to_concrete_type() {
 
     assert!(screen.as_any().downcast_ref::<RepoPlayScreen>().is_some());
 }
+
+fn type_query(screen: &RepoPlayScreen, query: &str) {
+    for c in query.chars() {
+        screen
+            .handle_key_event(KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty()))
+            .unwrap();
+    }
+}
+
+fn visible_names(screen: &RepoPlayScreen) -> Vec<String> {
+    screen
+        .visible_repositories()
+        .into_iter()
+        .map(|(repo, _)| repo.repository_name)
+        .collect()
+}
+
+#[test]
+fn test_repo_play_screen_typing_filters_by_owner_and_name() {
+    let screen = make_screen();
+    screen
+        .init_with_data(MockRepoPlayDataProvider.provide().unwrap())
+        .unwrap();
+
+    type_query(&screen, "rails");
+
+    assert_eq!(screen.get_query(), "rails");
+    assert_eq!(visible_names(&screen), vec!["rails"]);
+}
+
+#[test]
+fn test_repo_play_screen_typing_filters_by_language_and_last_played() {
+    let screen = make_screen();
+    screen
+        .init_with_data(MockRepoPlayDataProvider.provide().unwrap())
+        .unwrap();
+
+    type_query(&screen, "assembly");
+    assert_eq!(visible_names(&screen), vec!["go"]);
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()))
+        .unwrap();
+    type_query(&screen, "2026-10");
+    assert_eq!(visible_names(&screen), vec!["gittype"]);
+}
+
+#[test]
+fn test_repo_play_screen_enter_plays_the_filtered_selection() {
+    let screen = make_screen();
+    screen
+        .init_with_data(MockRepoPlayDataProvider.provide().unwrap())
+        .unwrap();
+
+    type_query(&screen, "golang");
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()))
+        .unwrap();
+
+    assert_eq!(screen.get_selected_index(), Some(2));
+    let (repo, _) = screen.get_selected_repository().unwrap();
+    assert_eq!(repo.repository_name, "go");
+}
+
+#[test]
+fn test_repo_play_screen_enter_without_matches_selects_nothing() {
+    let screen = make_screen();
+    screen
+        .init_with_data(MockRepoPlayDataProvider.provide().unwrap())
+        .unwrap();
+
+    type_query(&screen, "zzz");
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(screen.visible_repositories().is_empty());
+    assert!(screen.get_selected_index().is_none());
+}
+
+#[test]
+fn test_repo_play_screen_esc_clears_query_before_exiting() {
+    let event_bus = Arc::new(EventBus::new());
+    let theme_service = Arc::new(ThemeService::new_for_test(
+        Theme::default(),
+        ColorMode::Dark,
+    )) as Arc<dyn ThemeServiceInterface>;
+    let captured = Arc::new(Mutex::new(Vec::<NavigateTo>::new()));
+    let captured_clone = Arc::clone(&captured);
+    event_bus.subscribe(move |event: &NavigateTo| {
+        captured_clone.lock().unwrap().push(event.clone());
+    });
+
+    let screen = RepoPlayScreen::new(event_bus, theme_service, starred_repository());
+    screen
+        .init_with_data(MockRepoPlayDataProvider.provide().unwrap())
+        .unwrap();
+
+    type_query(&screen, "go");
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::empty()))
+        .unwrap();
+    assert_eq!(screen.get_query(), "g");
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()))
+        .unwrap();
+    assert_eq!(screen.get_query(), "");
+    assert!(captured.lock().unwrap().is_empty());
+    assert_eq!(screen.visible_repositories().len(), 3);
+}
+
+/// Switch to the starred list and wait for the background fetch
+fn show_starred(screen: &RepoPlayScreen) {
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Tab, KeyModifiers::empty()))
+        .unwrap();
+    assert!(screen.is_showing_starred());
+    for _ in 0..200 {
+        screen.update().unwrap();
+        if !screen.is_loading_starred() {
+            return;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    panic!("starred repositories never loaded");
+}
+
+#[test]
+fn test_repo_play_screen_tab_lists_starred_repositories() {
+    let screen = make_screen();
+    screen
+        .init_with_data(MockRepoPlayDataProvider.provide().unwrap())
+        .unwrap();
+
+    show_starred(&screen);
+
+    let names: Vec<String> = screen
+        .visible_starred()
+        .into_iter()
+        .map(|repo| repo.full_name)
+        .collect();
+    assert_eq!(names, vec!["tokio-rs/tokio", "sharkdp/bat"]);
+
+    type_query(&screen, "sharkdp");
+    assert_eq!(screen.visible_starred().len(), 1);
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Tab, KeyModifiers::empty()))
+        .unwrap();
+    assert!(!screen.is_showing_starred());
+    assert_eq!(screen.visible_repositories().len(), 3);
+}
+
+#[test]
+fn test_repo_play_screen_space_queues_starred_repositories_in_order() {
+    let screen = make_screen();
+    screen
+        .init_with_data(MockRepoPlayDataProvider.provide().unwrap())
+        .unwrap();
+    show_starred(&screen);
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::empty()))
+        .unwrap();
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty()))
+        .unwrap();
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Up, KeyModifiers::empty()))
+        .unwrap();
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty()))
+        .unwrap();
+    assert_eq!(
+        screen.get_practice_queue(),
+        vec!["sharkdp/bat", "tokio-rs/tokio"]
+    );
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty()))
+        .unwrap();
+    assert_eq!(screen.get_practice_queue(), vec!["sharkdp/bat"]);
+    assert!(screen.get_selected_repository().is_none());
+}
+
+#[test]
+fn test_repo_play_screen_enter_on_starred_queues_the_highlighted_one_and_exits() {
+    let event_bus = Arc::new(EventBus::new());
+    let captured = Arc::new(Mutex::new(Vec::<NavigateTo>::new()));
+    let captured_clone = Arc::clone(&captured);
+    event_bus.subscribe(move |event: &NavigateTo| {
+        captured_clone.lock().unwrap().push(event.clone());
+    });
+    let screen = RepoPlayScreen::new(
+        event_bus,
+        Arc::new(ThemeService::new_for_test(
+            Theme::default(),
+            ColorMode::Dark,
+        )) as Arc<dyn ThemeServiceInterface>,
+        starred_repository(),
+    );
+    screen
+        .init_with_data(MockRepoPlayDataProvider.provide().unwrap())
+        .unwrap();
+    show_starred(&screen);
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()))
+        .unwrap();
+
+    assert_eq!(screen.get_practice_queue(), vec!["tokio-rs/tokio"]);
+    assert_eq!(captured.lock().unwrap().len(), 1);
+}
@@ -8,8 +8,20 @@ use gittype::domain::events::{EventBus, EventBusInterface};
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::storage::{SessionResultData, StoredSession};
 use gittype::domain::models::theme::Theme;
+use gittype::domain::models::{StageScreenshot, WpmMode};
+use gittype::domain::services::scoring::{
+    SessionTracker, SessionTrackerInterface, TotalTracker, TotalTrackerInterface,
+};
+use gittype::domain::services::session_manager_service::SessionManagerInterface;
 use gittype::domain::services::session_service::SessionDisplayData;
+use gittype::domain::services::stage_builder_service::{StageRepository, StageRepositoryInterface};
 use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::domain::services::SessionManager;
+use gittype::domain::stores::{
+    ChallengeStore, ChallengeStoreInterface, RepositoryStore, RepositoryStoreInterface,
+    SessionStore, SessionStoreInterface,
+};
+use gittype::infrastructure::storage::file_storage::FileStorage;
 use gittype::presentation::tui::screens::records_screen::RecordsScreenData;
 use gittype::presentation::tui::screens::{RecordsScreen, SessionDetailScreen};
 use gittype::presentation::tui::ScreenDataProvider;
@@ -17,11 +29,36 @@ use gittype::presentation::tui::{Screen, ScreenType, UpdateStrategy};
 use gittype::GitTypeError;
 use ratatui::backend::TestBackend;
 use ratatui::Terminal;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
+fn session_manager() -> Arc<dyn SessionManagerInterface> {
+    let repository_store =
+        Arc::new(RepositoryStore::new_for_test()) as Arc<dyn RepositoryStoreInterface>;
+    let stage_repository = Arc::new(StageRepository::new(
+        None,
+        Arc::new(ChallengeStore::new_for_test()) as Arc<dyn ChallengeStoreInterface>,
+        repository_store,
+        Arc::new(SessionStore::new_for_test()) as Arc<dyn SessionStoreInterface>,
+    )) as Arc<dyn StageRepositoryInterface>;
+    Arc::new(SessionManager::new_with_dependencies(
+        Arc::new(EventBus::new()),
+        stage_repository,
+        Arc::new(SessionTracker::default()) as Arc<dyn SessionTrackerInterface>,
+        Arc::new(TotalTracker::default()) as Arc<dyn TotalTrackerInterface>,
+    ))
+}
+
 // Helper function to create and initialize SessionDetailScreen from RecordsScreen
 fn create_initialized_session_detail_screen(
     event_bus: Arc<dyn EventBusInterface>,
+) -> SessionDetailScreen {
+    create_initialized_session_detail_screen_with(event_bus, session_manager())
+}
+
+fn create_initialized_session_detail_screen_with(
+    event_bus: Arc<dyn EventBusInterface>,
+    session_manager: Arc<dyn SessionManagerInterface>,
 ) -> SessionDetailScreen {
     let theme_service = Arc::new(ThemeService::new_for_test(
         Theme::default(),
@@ -31,6 +68,8 @@ fn create_initialized_session_detail_screen(
         event_bus.clone(),
         theme_service.clone(),
         Arc::new(MockSessionRepository::new()),
+        Arc::new(FileStorage::new()),
+        session_manager,
     );
 
     let records = RecordsScreen::new(
@@ -55,7 +94,9 @@ screen_snapshot_test!(
             Theme::default(),
             ColorMode::Dark
         )) as Arc<dyn ThemeServiceInterface>,
-        Arc::new(MockSessionRepository::new())
+        Arc::new(MockSessionRepository::new()),
+        Arc::new(FileStorage::new()),
+        session_manager()
     ),
     pushed_from = {
         let theme_service = Arc::new(ThemeService::new_for_test(
@@ -193,6 +234,8 @@ fn session_with_id(id: i64) -> SessionDisplayData {
             difficulty_level: None,
             max_stages: Some(1),
             time_limit_seconds: None,
+            player_name: None,
+            unverified: false,
         },
         repository: None,
         session_result: Some(SessionResultData {
@@ -216,6 +259,61 @@ fn session_with_id(id: i64) -> SessionDisplayData {
     }
 }
 
+fn create_screen_with_storage(file_storage: FileStorage) -> SessionDetailScreen {
+    let theme_service = Arc::new(ThemeService::new_for_test(
+        Theme::default(),
+        ColorMode::Dark,
+    )) as Arc<dyn ThemeServiceInterface>;
+    let screen = SessionDetailScreen::new(
+        Arc::new(EventBus::new()),
+        theme_service.clone(),
+        Arc::new(MockSessionRepository::new()),
+        Arc::new(file_storage),
+        session_manager(),
+    );
+    let records = RecordsScreen::new(
+        Arc::new(EventBus::new()),
+        theme_service,
+        Arc::new(MockSessionService::new()),
+    );
+    records
+        .init_with_data(MockRecordsDataProvider.provide().unwrap())
+        .unwrap();
+    records.set_selected_session_from_index(0);
+    screen.on_pushed_from(&records).unwrap();
+    screen
+}
+
+#[test]
+fn test_session_detail_screen_has_no_screenshot_when_none_was_saved() {
+    let screen = create_screen_with_storage(FileStorage::new());
+
+    assert_eq!(screen.selected_screenshot(), None);
+}
+
+#[test]
+fn test_session_detail_screen_finds_the_screenshot_of_the_selected_stage() {
+    let app_data_dir = PathBuf::from("/tmp/test");
+    let mut file_storage = FileStorage::new();
+    file_storage.set_file_content(
+        StageScreenshot::html_path(&app_data_dir, 1, 2),
+        "<pre></pre>".to_string(),
+    );
+    let screen = create_screen_with_storage(file_storage);
+
+    assert_eq!(screen.selected_screenshot(), None);
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::empty()))
+        .unwrap();
+    assert_eq!(
+        screen.selected_screenshot(),
+        Some(app_data_dir.join("screenshots/session-1/stage-2.html"))
+    );
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::empty()))
+        .unwrap();
+}
+
 fn make_screen() -> SessionDetailScreen {
     SessionDetailScreen::new(
         Arc::new(EventBus::new()),
@@ -224,6 +322,8 @@ fn make_screen() -> SessionDetailScreen {
             ColorMode::Dark,
         )) as Arc<dyn ThemeServiceInterface>,
         Arc::new(MockSessionRepository::new()),
+        Arc::new(FileStorage::new()),
+        session_manager(),
     )
 }
 
@@ -367,3 +467,57 @@ fn test_session_detail_screen_render_after_scroll_keys() {
     }
     assert!(rendered.contains("Session Details"));
 }
+
+#[test]
+fn test_session_detail_screen_g_races_the_ghost_of_the_selected_stage() {
+    let event_bus = Arc::new(EventBus::new());
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = Arc::clone(&events);
+    event_bus.subscribe(move |event: &NavigateTo| {
+        events_clone.lock().unwrap().push(event.clone());
+    });
+    let session_manager = session_manager();
+    let screen = create_initialized_session_detail_screen_with(event_bus, session_manager.clone());
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(matches!(
+        events.lock().unwrap().as_slice(),
+        [NavigateTo::Replace(ScreenType::Typing)]
+    ));
+    let ghost = session_manager
+        .as_any()
+        .downcast_ref::<SessionManager>()
+        .unwrap()
+        .take_ghost()
+        .unwrap();
+    assert!(ghost.is_ghost());
+    assert_eq!(ghost.label(WpmMode::Characters), "Ghost 75 WPM · 97%");
+}
+
+#[test]
+fn test_session_detail_screen_ghost_race_replays_the_stored_challenge() {
+    let screen = create_initialized_session_detail_screen(Arc::new(EventBus::new()));
+
+    let (challenge, ghost) = screen.selected_ghost_race().unwrap();
+
+    assert_eq!(challenge.code_content, "fn main() { ... }");
+    assert_eq!(challenge.source_file_path.as_deref(), Some("src/main.rs"));
+    assert_eq!(
+        (challenge.start_line, challenge.end_line),
+        (Some(1), Some(20))
+    );
+    assert_eq!(challenge.language.as_deref(), Some("Rust"));
+    // The attempt took 20 seconds, so the ghost is halfway after 10
+    let half = challenge.code_content.chars().count() / 2;
+    assert_eq!(
+        ghost.position(
+            &challenge.code_content,
+            std::time::Duration::from_secs(10),
+            WpmMode::Characters
+        ),
+        half
+    );
+}
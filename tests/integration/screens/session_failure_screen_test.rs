@@ -1,9 +1,12 @@
-use crate::integration::screens::mocks::session_failure_screen_mock::MockSessionFailureDataProvider;
+use crate::integration::screens::mocks::session_failure_screen_mock::{
+    failed_challenge, MockSessionFailureDataProvider, MockSessionFailureWithoutAnalysisDataProvider,
+};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use gittype::domain::events::presentation_events::NavigateTo;
 use gittype::domain::events::{EventBus, EventBusInterface};
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::theme::Theme;
+use gittype::domain::models::SessionAction;
 use gittype::domain::services::scoring::{
     SessionTracker, SessionTrackerInterface, TotalTracker, TotalTrackerInterface,
 };
@@ -16,7 +19,7 @@ use gittype::domain::stores::{
     ChallengeStoreInterface, RepositoryStoreInterface, SessionStoreInterface,
 };
 use gittype::presentation::tui::screens::session_failure_screen::SessionFailureScreen;
-use gittype::presentation::tui::Screen;
+use gittype::presentation::tui::{Screen, ScreenDataProvider, ScreenType};
 use gittype::GitTypeError;
 use std::sync::{Arc, Mutex};
 
@@ -56,6 +59,13 @@ screen_snapshot_test!(
     provider = MockSessionFailureDataProvider
 );
 
+screen_snapshot_test!(
+    test_session_failure_screen_snapshot_without_analysis,
+    SessionFailureScreen,
+    create_session_failure_screen(Arc::new(EventBus::new())),
+    provider = MockSessionFailureWithoutAnalysisDataProvider
+);
+
 // Event-producing key tests
 screen_key_event_test!(
     test_session_failure_screen_r_retries,
@@ -212,3 +222,73 @@ fn test_session_failure_screen_as_any_downcasts_to_concrete_type() {
         .downcast_ref::<SessionFailureScreen>()
         .is_some());
 }
+
+#[test]
+fn test_session_failure_screen_c_queues_failed_challenge_and_retries() {
+    let event_bus = Arc::new(EventBus::new());
+    let published_events = Arc::new(Mutex::new(Vec::<NavigateTo>::new()));
+    let observed_events = Arc::clone(&published_events);
+    let session_manager = Arc::new(SessionManager::new_with_dependencies(
+        event_bus.clone(),
+        Arc::new(StageRepository::new(
+            None,
+            Arc::new(ChallengeStore::new_for_test()),
+            Arc::new(RepositoryStore::new_for_test()),
+            Arc::new(SessionStore::new_for_test()),
+        )),
+        Arc::new(SessionTracker::default()),
+        Arc::new(TotalTracker::default()),
+    ));
+    let screen = create_session_failure_screen_with_session_manager(
+        event_bus.clone(),
+        session_manager.clone(),
+    );
+    screen
+        .init_with_data(MockSessionFailureDataProvider.provide().unwrap())
+        .unwrap();
+
+    event_bus.subscribe(move |event: &NavigateTo| {
+        observed_events.lock().unwrap().push(event.clone());
+    });
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(matches!(
+        published_events.lock().unwrap().as_slice(),
+        [NavigateTo::Replace(ScreenType::Typing)]
+    ));
+
+    session_manager.reduce(SessionAction::Start).unwrap();
+    assert_eq!(
+        session_manager.get_current_challenge().unwrap(),
+        Some(failed_challenge())
+    );
+}
+
+#[test]
+fn test_session_failure_screen_c_without_analysis_does_nothing() {
+    let event_bus = Arc::new(EventBus::new());
+    let published_events = Arc::new(Mutex::new(Vec::<NavigateTo>::new()));
+    let observed_events = Arc::clone(&published_events);
+    let screen = create_session_failure_screen(event_bus.clone());
+    screen
+        .init_with_data(
+            MockSessionFailureWithoutAnalysisDataProvider
+                .provide()
+                .unwrap(),
+        )
+        .unwrap();
+
+    event_bus.subscribe(move |event: &NavigateTo| {
+        observed_events.lock().unwrap().push(event.clone());
+    });
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(screen.get_analysis().is_none());
+    assert!(published_events.lock().unwrap().is_empty());
+}
@@ -6,6 +6,7 @@ use gittype::domain::events::presentation_events::NavigateTo;
 use gittype::domain::events::{EventBus, EventBusInterface};
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::theme::Theme;
+use gittype::domain::services::repository_rating_service::RepositoryRatingService;
 use gittype::domain::services::scoring::{
     SessionTracker, SessionTrackerInterface, TotalTracker, TotalTrackerInterface,
 };
@@ -17,16 +18,28 @@ use gittype::domain::stores::{ChallengeStore, RepositoryStore, SessionStore};
 use gittype::domain::stores::{
     ChallengeStoreInterface, RepositoryStoreInterface, SessionStoreInterface,
 };
+use gittype::infrastructure::database::daos::{RepositoryRatingDao, RepositoryRatingDaoInterface};
+use gittype::infrastructure::database::database::{Database, DatabaseInterface};
+use gittype::infrastructure::storage::file_storage::FileStorage;
 use gittype::presentation::di::AppModule;
 use gittype::presentation::tui::screens::session_summary_screen::{
     ResultAction, SessionSummaryScreen, SessionSummaryScreenProvider,
 };
+use gittype::presentation::tui::ScreenDataProvider;
 use gittype::presentation::tui::{Screen, ScreenType};
 use shaku::Provider;
 use std::sync::Arc;
 
 // Helper function to create SessionSummaryScreen with all required dependencies
 fn create_session_summary_screen(event_bus: Arc<dyn EventBusInterface>) -> SessionSummaryScreen {
+    create_session_summary_screen_with_database(event_bus, Arc::new(Database::new().unwrap()), None)
+}
+
+fn create_session_summary_screen_with_database(
+    event_bus: Arc<dyn EventBusInterface>,
+    database: Arc<dyn DatabaseInterface>,
+    recorded_session_id: Option<i64>,
+) -> SessionSummaryScreen {
     let theme_service = Arc::new(ThemeService::new_for_test(
         Theme::default(),
         ColorMode::Dark,
@@ -44,14 +57,25 @@ fn create_session_summary_screen(event_bus: Arc<dyn EventBusInterface>) -> Sessi
     )) as Arc<dyn StageRepositoryInterface>;
     let session_tracker: Arc<dyn SessionTrackerInterface> = Arc::new(SessionTracker::default());
     let total_tracker: Arc<dyn TotalTrackerInterface> = Arc::new(TotalTracker::default());
-    let session_manager = Arc::new(SessionManager::new_with_dependencies(
+    let session_manager = SessionManager::new_with_dependencies(
         event_bus.clone(),
         stage_repository,
         session_tracker,
         total_tracker,
-    )) as Arc<dyn SessionManagerInterface>;
-
-    SessionSummaryScreen::new(event_bus, theme_service, session_manager, repository_store)
+    );
+    session_manager.set_recorded_session_id_for_test(recorded_session_id);
+    let session_manager = Arc::new(session_manager) as Arc<dyn SessionManagerInterface>;
+
+    SessionSummaryScreen::new(
+        event_bus,
+        theme_service,
+        session_manager,
+        repository_store,
+        Arc::new(FileStorage::new()),
+        Arc::new(RepositoryRatingService::new(Arc::new(
+            RepositoryRatingDao::new(database),
+        ))),
+    )
 }
 
 screen_snapshot_test!(
@@ -186,6 +210,102 @@ screen_basic_methods_test!(
     MockSessionSummaryDataProvider
 );
 
+#[test]
+fn test_session_summary_screen_summary_html_renders_the_summary_without_options() {
+    let screen = create_session_summary_screen(Arc::new(EventBus::new()));
+    assert_eq!(screen.summary_html().unwrap(), None);
+
+    screen
+        .init_with_data(MockSessionSummaryDataProvider.provide().unwrap())
+        .unwrap();
+    let html = screen.summary_html().unwrap().unwrap();
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>gittype · Session Summary</title>"));
+    assert!(!html.contains("Export HTML"));
+}
+
+#[test]
+fn test_session_summary_screen_h_key_exports_html_and_reports_the_path() {
+    let screen = create_session_summary_screen(Arc::new(EventBus::new()));
+    screen
+        .init_with_data(MockSessionSummaryDataProvider.provide().unwrap())
+        .unwrap();
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('h'), KeyModifiers::empty()))
+        .unwrap();
+
+    let message = screen.get_status_message().unwrap();
+    assert!(message.starts_with("Exported to /tmp/test/exports/session-summary-"));
+    assert!(message.ends_with(".html"));
+    assert!(screen.get_action_result().is_none());
+}
+
+/// A database holding session 1, played on a repository
+fn database_with_recorded_session() -> Arc<dyn DatabaseInterface> {
+    let database = Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>;
+    {
+        let conn = database.get_connection().unwrap();
+        conn.execute(
+            "INSERT INTO repositories (id, user_name, repository_name, remote_url)
+             VALUES (1, 'unhappychoice', 'gittype', 'https://github.com/unhappychoice/gittype')",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO sessions (id, repository_id, started_at, game_mode)
+             VALUES (1, 1, '2026-01-01 00:00:00', 'Normal')",
+            [],
+        )
+        .unwrap();
+    }
+    database
+}
+
+#[test]
+fn test_session_summary_screen_number_keys_rate_the_recorded_session() {
+    let database = database_with_recorded_session();
+    let screen = create_session_summary_screen_with_database(
+        Arc::new(EventBus::new()),
+        database.clone(),
+        Some(1),
+    );
+    screen
+        .init_with_data(MockSessionSummaryDataProvider.provide().unwrap())
+        .unwrap();
+    assert_eq!(screen.rateable_session_id(), Some(1));
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('2'), KeyModifiers::empty()))
+        .unwrap();
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('5'), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(screen.get_status_message().unwrap().ends_with(" 5/5"));
+    let rated = RepositoryRatingDao::new(database)
+        .get_rated_repositories()
+        .unwrap();
+    assert_eq!(rated.len(), 1);
+    assert_eq!(rated[0].rating.average, 5.0);
+    assert_eq!(rated[0].rating.count, 1);
+}
+
+#[test]
+fn test_session_summary_screen_number_keys_do_nothing_before_the_session_is_recorded() {
+    let screen = create_session_summary_screen(Arc::new(EventBus::new()));
+    screen
+        .init_with_data(MockSessionSummaryDataProvider.provide().unwrap())
+        .unwrap();
+
+    assert_eq!(screen.rateable_session_id(), None);
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('4'), KeyModifiers::empty()))
+        .unwrap();
+    assert_eq!(screen.get_status_message(), None);
+}
+
 #[test]
 fn test_session_summary_screen_get_action_result_initially_none() {
     let screen = create_session_summary_screen(Arc::new(EventBus::new()));
@@ -1,9 +1,13 @@
+use crate::integration::screens::mocks::profile_screen_mock::MockProfileService;
 use crate::integration::screens::mocks::session_summary_share_screen_mock::MockSessionSummaryShareDataProvider;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use gittype::domain::events::presentation_events::NavigateTo;
 use gittype::domain::events::{EventBus, EventBusInterface};
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::theme::Theme;
+use gittype::domain::repositories::repository_metadata_repository::{
+    RepositoryMetadataRepository, RepositoryMetadataRepositoryInterface,
+};
 use gittype::domain::services::scoring::{
     SessionTracker, SessionTrackerInterface, TotalTracker, TotalTrackerInterface,
 };
@@ -15,6 +19,9 @@ use gittype::domain::stores::{ChallengeStore, RepositoryStore, SessionStore};
 use gittype::domain::stores::{
     ChallengeStoreInterface, RepositoryStoreInterface, SessionStoreInterface,
 };
+use gittype::infrastructure::database::daos::RepositoryDao;
+use gittype::infrastructure::database::database::{Database, DatabaseInterface};
+use gittype::infrastructure::http::github_api_client::GitHubApiClientFactoryImpl;
 use gittype::presentation::tui::screens::session_summary_share_screen::SessionSummaryShareScreen;
 use gittype::presentation::tui::Screen;
 use gittype::GitTypeError;
@@ -23,6 +30,14 @@ use ratatui::Terminal;
 use std::sync::{Arc, Mutex};
 
 // Helper function to create SessionSummaryShareScreen with all required dependencies
+fn repository_metadata_repository() -> Arc<dyn RepositoryMetadataRepositoryInterface> {
+    let database = Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>;
+    Arc::new(RepositoryMetadataRepository::new(
+        Arc::new(GitHubApiClientFactoryImpl::default()),
+        Arc::new(RepositoryDao::new(database)),
+    ))
+}
+
 fn create_session_summary_share_screen(
     event_bus: Arc<dyn EventBusInterface>,
 ) -> SessionSummaryShareScreen {
@@ -50,7 +65,14 @@ fn create_session_summary_share_screen(
         total_tracker,
     )) as Arc<dyn SessionManagerInterface>;
 
-    SessionSummaryShareScreen::new(event_bus, theme_service, session_manager, repository_store)
+    SessionSummaryShareScreen::new(
+        event_bus,
+        theme_service,
+        session_manager,
+        repository_store,
+        repository_metadata_repository(),
+        Arc::new(MockProfileService::default()),
+    )
 }
 
 screen_snapshot_test!(
@@ -151,7 +173,14 @@ fn create_session_summary_share_screen_with_session_manager(
     let repository_store =
         Arc::new(RepositoryStore::new_for_test()) as Arc<dyn RepositoryStoreInterface>;
 
-    SessionSummaryShareScreen::new(event_bus, theme_service, session_manager, repository_store)
+    SessionSummaryShareScreen::new(
+        event_bus,
+        theme_service,
+        session_manager,
+        repository_store,
+        repository_metadata_repository(),
+        Arc::new(MockProfileService::default()),
+    )
 }
 
 #[test]
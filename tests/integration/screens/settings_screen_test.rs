@@ -2,9 +2,10 @@ use crate::integration::screens::mocks::settings_screen_mock::MockSettingsScreen
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use gittype::domain::events::presentation_events::NavigateTo;
 use gittype::domain::events::EventBus;
-use gittype::presentation::tui::screens::settings_screen::SettingsScreen;
+use gittype::presentation::tui::screens::settings_screen::{SettingsScreen, SettingsSection};
 use gittype::presentation::tui::Screen;
 use gittype::presentation::tui::ScreenDataProvider;
+use gittype::presentation::tui::ScreenType;
 use std::sync::{Arc, Mutex};
 
 screen_snapshot_test!(
@@ -22,6 +23,43 @@ screen_snapshot_test!(
     keys = [KeyEvent::new(KeyCode::Right, KeyModifiers::empty())]
 );
 
+screen_snapshot_test!(
+    test_settings_screen_snapshot_formats,
+    SettingsScreen,
+    SettingsScreen::new(Arc::new(EventBus::new())),
+    provider = MockSettingsScreenDataProvider,
+    keys = [
+        KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Down, KeyModifiers::empty())
+    ]
+);
+
+screen_snapshot_test!(
+    test_settings_screen_snapshot_discord,
+    SettingsScreen,
+    SettingsScreen::new(Arc::new(EventBus::new())),
+    provider = MockSettingsScreenDataProvider,
+    keys = [
+        KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Right, KeyModifiers::empty())
+    ]
+);
+
+screen_snapshot_test!(
+    test_settings_screen_snapshot_streamer,
+    SettingsScreen,
+    SettingsScreen::new(Arc::new(EventBus::new())),
+    provider = MockSettingsScreenDataProvider,
+    keys = [
+        KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Right, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Right, KeyModifiers::empty())
+    ]
+);
+
 // Event-producing key tests (manual implementation because SettingsScreen takes only 1 arg)
 #[test]
 fn test_settings_screen_space_saves_and_navigates_back() {
@@ -89,6 +127,31 @@ fn test_settings_screen_ctrl_c_exits() {
     assert_eq!(captured_events.len(), 1);
 }
 
+#[test]
+fn test_settings_screen_d_opens_keyboard_test() {
+    let event_bus = Arc::new(EventBus::new());
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = Arc::clone(&events);
+
+    event_bus.subscribe(move |event: &NavigateTo| {
+        events_clone.lock().unwrap().push(event.clone());
+    });
+
+    let screen = SettingsScreen::new(event_bus);
+    let data = MockSettingsScreenDataProvider.provide().unwrap();
+    let _ = screen.init_with_data(data);
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::empty()))
+        .unwrap();
+
+    let captured_events = events.lock().unwrap();
+    assert!(matches!(
+        captured_events.as_slice(),
+        [NavigateTo::Push(ScreenType::KeyboardTest)]
+    ));
+}
+
 // Non-event key tests (manual implementation)
 #[test]
 fn test_settings_screen_key_left_switches_tab() {
@@ -195,3 +258,180 @@ screen_basic_methods_test!(
     false,
     MockSettingsScreenDataProvider
 );
+
+fn type_keys(screen: &SettingsScreen, codes: impl IntoIterator<Item = KeyCode>) {
+    codes.into_iter().for_each(|code| {
+        screen
+            .handle_key_event(KeyEvent::new(code, KeyModifiers::empty()))
+            .unwrap()
+    });
+}
+
+fn init_settings_screen(event_bus: Arc<EventBus>) -> SettingsScreen {
+    let screen = SettingsScreen::new(event_bus);
+    screen
+        .init_with_data(MockSettingsScreenDataProvider.provide().unwrap())
+        .unwrap();
+    screen
+}
+
+screen_snapshot_test!(
+    test_settings_screen_snapshot_search,
+    SettingsScreen,
+    SettingsScreen::new(Arc::new(EventBus::new())),
+    provider = MockSettingsScreenDataProvider,
+    keys = [
+        KeyEvent::new(KeyCode::Char('/'), KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Char('p'), KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Char('r'), KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Char('e'), KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Char('s'), KeyModifiers::empty())
+    ]
+);
+
+#[test]
+fn test_settings_screen_search_jumps_to_best_match() {
+    let screen = init_settings_screen(Arc::new(EventBus::new()));
+
+    type_keys(
+        &screen,
+        [
+            KeyCode::Char('/'),
+            KeyCode::Char('d'),
+            KeyCode::Char('i'),
+            KeyCode::Char('s'),
+        ],
+    );
+
+    assert!(screen.is_searching());
+    assert_eq!(screen.current_section(), SettingsSection::Discord);
+    assert_eq!(screen.visible_sections(), vec![SettingsSection::Discord]);
+}
+
+#[test]
+fn test_settings_screen_search_finds_update_check() {
+    let screen = init_settings_screen(Arc::new(EventBus::new()));
+
+    type_keys(
+        &screen,
+        [KeyCode::Char('/')]
+            .into_iter()
+            .chain("beta".chars().map(KeyCode::Char)),
+    );
+
+    assert_eq!(screen.current_section(), SettingsSection::Updates);
+}
+
+#[test]
+fn test_settings_screen_search_finds_formats() {
+    let screen = init_settings_screen(Arc::new(EventBus::new()));
+
+    type_keys(
+        &screen,
+        [KeyCode::Char('/')]
+            .into_iter()
+            .chain("locale".chars().map(KeyCode::Char)),
+    );
+
+    assert_eq!(screen.current_section(), SettingsSection::Formats);
+}
+
+#[test]
+fn test_settings_screen_search_finds_streamer() {
+    let screen = init_settings_screen(Arc::new(EventBus::new()));
+
+    type_keys(
+        &screen,
+        [KeyCode::Char('/')]
+            .into_iter()
+            .chain("obs".chars().map(KeyCode::Char)),
+    );
+
+    assert_eq!(screen.current_section(), SettingsSection::Streamer);
+}
+
+#[test]
+fn test_settings_screen_search_matches_category_names() {
+    let screen = init_settings_screen(Arc::new(EventBus::new()));
+
+    type_keys(
+        &screen,
+        [KeyCode::Char('/')]
+            .into_iter()
+            .chain("appear".chars().map(KeyCode::Char)),
+    );
+
+    assert_eq!(
+        screen.visible_sections(),
+        vec![
+            SettingsSection::ColorMode,
+            SettingsSection::Theme,
+            SettingsSection::Formats
+        ]
+    );
+}
+
+#[test]
+fn test_settings_screen_search_keys_do_not_trigger_shortcuts() {
+    let event_bus = Arc::new(EventBus::new());
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = Arc::clone(&events);
+    event_bus.subscribe(move |event: &NavigateTo| {
+        events_clone.lock().unwrap().push(event.clone());
+    });
+    let screen = init_settings_screen(event_bus);
+
+    type_keys(
+        &screen,
+        [KeyCode::Char('/'), KeyCode::Char('d'), KeyCode::Char(' ')],
+    );
+
+    assert_eq!(screen.search_query(), "d ");
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_settings_screen_enter_keeps_filter_and_esc_clears_it() {
+    let event_bus = Arc::new(EventBus::new());
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = Arc::clone(&events);
+    event_bus.subscribe(move |event: &NavigateTo| {
+        events_clone.lock().unwrap().push(event.clone());
+    });
+    let screen = init_settings_screen(event_bus);
+
+    type_keys(
+        &screen,
+        [KeyCode::Char('/'), KeyCode::Char('t'), KeyCode::Enter],
+    );
+    assert!(!screen.is_searching());
+    assert_eq!(screen.search_query(), "t");
+
+    type_keys(&screen, [KeyCode::Esc]);
+    assert_eq!(screen.search_query(), "");
+    assert_eq!(screen.visible_sections().len(), 6);
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_settings_screen_unmatched_search_shows_no_sections() {
+    let screen = init_settings_screen(Arc::new(EventBus::new()));
+
+    type_keys(
+        &screen,
+        [KeyCode::Char('/'), KeyCode::Char('z'), KeyCode::Char('z')],
+    );
+
+    assert!(screen.visible_sections().is_empty());
+}
+
+#[test]
+fn test_settings_screen_tab_jumps_to_next_category() {
+    let screen = init_settings_screen(Arc::new(EventBus::new()));
+
+    type_keys(&screen, [KeyCode::Tab]);
+    assert_eq!(screen.current_section(), SettingsSection::Discord);
+
+    type_keys(&screen, [KeyCode::Tab]);
+    assert_eq!(screen.current_section(), SettingsSection::ColorMode);
+}
@@ -1,9 +1,14 @@
+use crate::fixtures::models::challenge;
 use crate::integration::screens::mocks::stage_summary_screen_mock::MockStageSummaryDataProvider;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use gittype::domain::events::presentation_events::NavigateTo;
 use gittype::domain::events::{EventBus, EventBusInterface};
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::theme::Theme;
+use gittype::domain::services::challenge_flag_service::{
+    ChallengeFlagService, ChallengeFlagServiceInterface,
+};
+use gittype::domain::services::scoring::tracker::StageTracker;
 use gittype::domain::services::scoring::{
     SessionTracker, SessionTrackerInterface, TotalTracker, TotalTrackerInterface,
 };
@@ -15,6 +20,8 @@ use gittype::domain::stores::{ChallengeStore, RepositoryStore, SessionStore};
 use gittype::domain::stores::{
     ChallengeStoreInterface, RepositoryStoreInterface, SessionStoreInterface,
 };
+use gittype::infrastructure::database::daos::ChallengeFlagDao;
+use gittype::infrastructure::database::database::Database;
 use gittype::presentation::tui::screens::stage_summary_screen::{
     StageSummaryData, StageSummaryScreen,
 };
@@ -34,12 +41,13 @@ impl SessionManagerInterface for NonConcreteSessionManager {
     }
 }
 
-// Helper function to create StageSummaryScreen with all required dependencies
-fn create_stage_summary_screen(event_bus: Arc<dyn EventBusInterface>) -> StageSummaryScreen {
-    let theme_service = Arc::new(ThemeService::new_for_test(
-        Theme::default(),
-        ColorMode::Dark,
-    )) as Arc<dyn ThemeServiceInterface>;
+fn challenge_flag_service() -> Arc<dyn ChallengeFlagServiceInterface> {
+    Arc::new(ChallengeFlagService::new(Arc::new(ChallengeFlagDao::new(
+        Arc::new(Database::new().unwrap()),
+    ))))
+}
+
+fn create_session_manager(event_bus: Arc<dyn EventBusInterface>) -> Arc<SessionManager> {
     let challenge_store =
         Arc::new(ChallengeStore::new_for_test()) as Arc<dyn ChallengeStoreInterface>;
     let repository_store =
@@ -53,14 +61,18 @@ fn create_stage_summary_screen(event_bus: Arc<dyn EventBusInterface>) -> StageSu
     )) as Arc<dyn StageRepositoryInterface>;
     let session_tracker: Arc<dyn SessionTrackerInterface> = Arc::new(SessionTracker::default());
     let total_tracker: Arc<dyn TotalTrackerInterface> = Arc::new(TotalTracker::default());
-    let session_manager = Arc::new(SessionManager::new_with_dependencies(
-        event_bus.clone(),
+    Arc::new(SessionManager::new_with_dependencies(
+        event_bus,
         stage_repository,
         session_tracker,
         total_tracker,
-    )) as Arc<dyn SessionManagerInterface>;
+    ))
+}
 
-    StageSummaryScreen::new(event_bus, theme_service, session_manager)
+// Helper function to create StageSummaryScreen with all required dependencies
+fn create_stage_summary_screen(event_bus: Arc<dyn EventBusInterface>) -> StageSummaryScreen {
+    let session_manager = create_session_manager(event_bus.clone());
+    create_stage_summary_screen_with_session_manager(event_bus, session_manager)
 }
 
 fn create_stage_summary_screen_with_session_manager(
@@ -72,7 +84,12 @@ fn create_stage_summary_screen_with_session_manager(
         ColorMode::Dark,
     )) as Arc<dyn ThemeServiceInterface>;
 
-    StageSummaryScreen::new(event_bus, theme_service, session_manager)
+    StageSummaryScreen::new(
+        event_bus,
+        theme_service,
+        session_manager,
+        challenge_flag_service(),
+    )
 }
 
 fn stage_result() -> gittype::domain::models::StageResult {
@@ -80,6 +97,7 @@ fn stage_result() -> gittype::domain::models::StageResult {
         challenge_score: 123.0,
         cpm: 240.0,
         wpm: 48.0,
+        token_density: 0.2,
         accuracy: 97.5,
         completion_time: Duration::from_secs_f64(10.5),
         mistakes: 1,
@@ -94,6 +112,8 @@ fn stage_result() -> gittype::domain::models::StageResult {
         was_failed: false,
         was_skipped: false,
         challenge_path: "src/lib.rs".to_string(),
+        mistake_breakdown: gittype::domain::models::MistakeBreakdown::default(),
+        key_load: gittype::domain::models::KeyLoad::default(),
     }
 }
 
@@ -179,6 +199,7 @@ fn test_stage_summary_screen_renders_completed_stage_progress() {
             current_stage: 3,
             total_stages: 3,
             is_completed: true,
+            mistake_diff: None,
         }))
         .unwrap();
 
@@ -294,6 +315,7 @@ fn test_stage_summary_screen_renders_next_stage_when_not_completed() {
             current_stage: 2,
             total_stages: 3,
             is_completed: false,
+            mistake_diff: None,
         }))
         .unwrap();
 
@@ -352,3 +374,140 @@ fn test_stage_summary_screen_as_any_downcasts_to_concrete_type() {
         .downcast_ref::<StageSummaryScreen>()
         .is_some());
 }
+
+#[test]
+fn test_stage_summary_screen_f_flags_last_stage_challenge() {
+    let event_bus: Arc<dyn EventBusInterface> = Arc::new(EventBus::new());
+    let session_manager = create_session_manager(event_bus.clone());
+    let flagged_challenge = challenge::build_with_id_and_code("flagged", "fn bad() {}");
+    session_manager.add_stage_data(
+        "Stage 1".to_string(),
+        StageTracker::new(flagged_challenge.code_content.clone()),
+        flagged_challenge.clone(),
+    );
+    let flag_service = challenge_flag_service();
+    let theme_service = Arc::new(ThemeService::new_for_test(
+        Theme::default(),
+        ColorMode::Dark,
+    )) as Arc<dyn ThemeServiceInterface>;
+    let screen = StageSummaryScreen::new(
+        event_bus,
+        theme_service,
+        session_manager,
+        flag_service.clone(),
+    );
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(screen.is_flagged());
+    let kept = flag_service.exclude_flagged(vec![flagged_challenge, challenge::build()]);
+    assert_eq!(kept.len(), 1);
+    assert_ne!(kept[0].id, "flagged");
+}
+
+#[test]
+fn test_stage_summary_screen_f_without_finished_stage_does_not_flag() {
+    let screen = create_stage_summary_screen(Arc::new(EventBus::new()));
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(!screen.is_flagged());
+}
+
+#[test]
+fn test_stage_summary_screen_s_opens_study_view() {
+    let event_bus: Arc<dyn EventBusInterface> = Arc::new(EventBus::new());
+    let session_manager = create_session_manager(event_bus.clone());
+    session_manager.add_stage_data(
+        "Stage 1".to_string(),
+        StageTracker::new("fn gone() {}".to_string()),
+        challenge::build_with_id_and_code("gone", "fn gone() {}"),
+    );
+    let screen = create_stage_summary_screen_with_session_manager(event_bus, session_manager);
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(screen.is_studying());
+    // The fixture's source file does not exist, so the view explains that instead
+    assert!(screen.get_study().is_none());
+
+    let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+    terminal
+        .draw(|frame| screen.render_ratatui(frame).unwrap())
+        .unwrap();
+    assert!(buffer_text(terminal.backend().buffer()).contains("not available on this machine"));
+}
+
+#[test]
+fn test_stage_summary_screen_o_without_source_file_does_not_open_editor() {
+    use gittype::domain::events::presentation_events::OpenInEditor;
+
+    let event_bus: Arc<dyn EventBusInterface> = Arc::new(EventBus::new());
+    let requests = Arc::new(Mutex::new(Vec::<OpenInEditor>::new()));
+    let captured = requests.clone();
+    event_bus
+        .as_event_bus()
+        .subscribe(move |event: &OpenInEditor| captured.lock().unwrap().push(event.clone()));
+    let session_manager = create_session_manager(event_bus.clone());
+    session_manager.add_stage_data(
+        "Stage 1".to_string(),
+        StageTracker::new("fn gone() {}".to_string()),
+        challenge::build_with_id_and_code("gone", "fn gone() {}"),
+    );
+    let screen = create_stage_summary_screen_with_session_manager(event_bus, session_manager);
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(screen.is_source_missing());
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_stage_summary_screen_esc_in_study_view_returns_to_summary() {
+    let event_bus = Arc::new(EventBus::new());
+    let events = Arc::new(Mutex::new(Vec::<NavigateTo>::new()));
+    let captured = events.clone();
+    event_bus
+        .as_event_bus()
+        .subscribe(move |event: &NavigateTo| captured.lock().unwrap().push(event.clone()));
+    let screen = create_stage_summary_screen(event_bus);
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::empty()))
+        .unwrap();
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty()))
+        .unwrap();
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(!screen.is_studying());
+    assert!(screen.get_action_result().is_none());
+    assert!(events.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_stage_summary_screen_ctrl_c_in_study_view_quits() {
+    let screen = create_stage_summary_screen(Arc::new(EventBus::new()));
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::empty()))
+        .unwrap();
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL))
+        .unwrap();
+
+    assert!(matches!(
+        screen.get_action_result(),
+        Some(ResultAction::Quit)
+    ));
+}
@@ -1,35 +1,79 @@
-use crate::integration::screens::mocks::title_screen_mock::MockTitleScreenDataProvider;
+use crate::integration::screens::mocks::title_screen_mock::{
+    MockTitleScreenDataProvider, MockTitleScreenUpdateDataProvider,
+};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use gittype::domain::events::presentation_events::NavigateTo;
 use gittype::domain::events::{EventBus, EventBusInterface};
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::theme::Theme;
-use gittype::domain::models::DifficultyLevel;
+use gittype::domain::models::{Challenge, DifficultyLevel};
+use gittype::domain::services::config_service::ConfigService;
+use gittype::domain::services::difficulty_gate_service::DifficultyGateService;
 use gittype::domain::services::scoring::{
     SessionTracker, SessionTrackerInterface, TotalTracker, TotalTrackerInterface,
 };
 use gittype::domain::services::session_manager_service::SessionManagerInterface;
 use gittype::domain::services::stage_builder_service::{StageRepository, StageRepositoryInterface};
 use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::domain::services::version_service::VersionService;
 use gittype::domain::services::SessionManager;
 use gittype::domain::stores::{ChallengeStore, RepositoryStore, SessionStore};
 use gittype::domain::stores::{
     ChallengeStoreInterface, RepositoryStoreInterface, SessionStoreInterface,
 };
+use gittype::infrastructure::database::daos::DifficultyUnlockDao;
+use gittype::infrastructure::database::database::{Database, DatabaseInterface};
 use gittype::presentation::tui::screens::title_screen::TitleScreen;
-use gittype::presentation::tui::Screen;
+use gittype::presentation::tui::{Screen, ScreenDataProvider};
 use std::sync::Arc;
 
 // Helper function to create TitleScreen with all required dependencies
 fn create_title_screen(event_bus: Arc<dyn EventBusInterface>) -> TitleScreen {
+    create_title_screen_with_store(
+        event_bus,
+        Arc::new(RepositoryStore::new_for_test()) as Arc<dyn RepositoryStoreInterface>,
+    )
+}
+
+fn create_title_screen_with_store(
+    event_bus: Arc<dyn EventBusInterface>,
+    repository_store: Arc<dyn RepositoryStoreInterface>,
+) -> TitleScreen {
+    create_title_screen_with_gates(
+        event_bus,
+        repository_store,
+        Arc::new(ChallengeStore::new_for_test()),
+        Arc::new(ConfigService::new_for_test().unwrap()),
+        Arc::new(Database::new().unwrap()),
+    )
+}
+
+fn create_title_screen_with_challenges(
+    event_bus: Arc<dyn EventBusInterface>,
+    challenges: Vec<Challenge>,
+) -> TitleScreen {
+    let challenge_store = ChallengeStore::new_for_test();
+    challenge_store.set_challenges(challenges);
+    create_title_screen_with_gates(
+        event_bus,
+        Arc::new(RepositoryStore::new_for_test()),
+        Arc::new(challenge_store),
+        Arc::new(ConfigService::new_for_test().unwrap()),
+        Arc::new(Database::new().unwrap()),
+    )
+}
+
+fn create_title_screen_with_gates(
+    event_bus: Arc<dyn EventBusInterface>,
+    repository_store: Arc<dyn RepositoryStoreInterface>,
+    challenge_store: Arc<dyn ChallengeStoreInterface>,
+    config_service: Arc<ConfigService>,
+    database: Arc<dyn DatabaseInterface>,
+) -> TitleScreen {
     let theme_service = Arc::new(ThemeService::new_for_test(
         Theme::default(),
         ColorMode::Dark,
     )) as Arc<dyn ThemeServiceInterface>;
-    let challenge_store =
-        Arc::new(ChallengeStore::new_for_test()) as Arc<dyn ChallengeStoreInterface>;
-    let repository_store =
-        Arc::new(RepositoryStore::new_for_test()) as Arc<dyn RepositoryStoreInterface>;
     let session_store = Arc::new(SessionStore::new_for_test()) as Arc<dyn SessionStoreInterface>;
     let stage_repository = Arc::new(StageRepository::new(
         None,
@@ -54,6 +98,11 @@ fn create_title_screen(event_bus: Arc<dyn EventBusInterface>) -> TitleScreen {
         stage_repository,
         repository_store,
         session_manager,
+        Arc::new(DifficultyGateService::new(
+            Arc::new(DifficultyUnlockDao::new(database)),
+            config_service,
+        )),
+        Arc::new(VersionService::new_for_test().unwrap()),
     )
 }
 
@@ -64,6 +113,13 @@ screen_snapshot_test!(
     provider = MockTitleScreenDataProvider
 );
 
+screen_snapshot_test!(
+    test_title_screen_snapshot_with_update_notice,
+    TitleScreen,
+    create_title_screen(Arc::new(EventBus::new())),
+    provider = MockTitleScreenUpdateDataProvider
+);
+
 // Event-producing key tests
 screen_key_event_test!(
     test_title_screen_space_starts_game,
@@ -175,6 +231,26 @@ screen_key_event_test!(
     MockTitleScreenDataProvider
 );
 
+screen_key_event_test!(
+    test_title_screen_e_opens_achievements,
+    TitleScreen,
+    create_title_screen,
+    NavigateTo,
+    KeyCode::Char('e'),
+    KeyModifiers::empty(),
+    MockTitleScreenDataProvider
+);
+
+screen_key_event_test!(
+    test_title_screen_p_opens_profile,
+    TitleScreen,
+    create_title_screen,
+    NavigateTo,
+    KeyCode::Char('p'),
+    KeyModifiers::empty(),
+    MockTitleScreenDataProvider
+);
+
 // Non-event key tests
 screen_key_tests_custom!(
     TitleScreen,
@@ -226,12 +302,75 @@ fn test_title_screen_space_without_challenges_sets_error_message() {
     assert!(screen.get_action_result().is_none());
     assert_eq!(
         screen.get_error_message().as_deref(),
-        Some("No challenges available for this difficulty. Please try a different difficulty or repository.")
+        Some("No challenges available in this repository. Please try a different repository.")
     );
     assert!(screen.update().unwrap());
     assert!(!screen.update().unwrap());
 }
 
+#[test]
+fn test_title_screen_d_without_code_sets_error_message() {
+    let screen = create_title_screen(Arc::new(EventBus::new()));
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(screen.get_action_result().is_none());
+    assert_eq!(
+        screen.get_error_message().as_deref(),
+        Some("No code in this repository to build drills from.")
+    );
+}
+
+#[test]
+fn test_title_screen_d_starts_drills() {
+    let event_bus = Arc::new(EventBus::new());
+    let captured: Arc<std::sync::Mutex<Vec<NavigateTo>>> = Arc::new(std::sync::Mutex::new(vec![]));
+    let cap = captured.clone();
+    event_bus
+        .as_event_bus()
+        .subscribe(move |ev: &NavigateTo| cap.lock().unwrap().push(ev.clone()));
+    let screen = create_title_screen_with_challenges(
+        event_bus,
+        vec![Challenge::new(
+            "code".to_string(),
+            "let config = Config::load()?;".to_string(),
+        )],
+    );
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(matches!(
+        screen.get_action_result(),
+        Some(gittype::presentation::tui::screens::title_screen::TitleAction::Drills)
+    ));
+    assert!(matches!(
+        captured.lock().unwrap().as_slice(),
+        [NavigateTo::Replace(
+            gittype::presentation::tui::ScreenType::Typing
+        )]
+    ));
+}
+
+#[test]
+fn test_title_screen_space_on_empty_difficulty_still_starts() {
+    let screen = create_title_screen(Arc::new(EventBus::new()));
+    screen.set_challenge_counts([0, 0, 3, 0, 0]);
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(matches!(
+        screen.get_action_result(),
+        Some(gittype::presentation::tui::screens::title_screen::TitleAction::Start(_))
+    ));
+    assert!(screen.get_error_message().is_none());
+}
+
 #[test]
 fn test_title_screen_left_from_first_difficulty_wraps_to_last() {
     let screen = create_title_screen(Arc::new(EventBus::new()));
@@ -272,6 +411,7 @@ fn test_title_screen_with_git_repository_sets_repo() {
         commit_hash: None,
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     let _screen =
         create_title_screen(Arc::new(EventBus::new())).with_git_repository(Some(repo.clone()));
@@ -288,6 +428,7 @@ fn test_title_screen_set_git_repository_overrides_value() {
         commit_hash: Some("deadbeef".to_string()),
         is_dirty: true,
         root_path: None,
+        license: None,
     };
 
     screen.set_git_repository(Some(repo));
@@ -333,3 +474,263 @@ fn test_title_screen_as_any_downcasts_to_self() {
     let any = screen.as_any();
     assert!(any.downcast_ref::<TitleScreen>().is_some());
 }
+
+fn quick_play_screen(event_bus: Arc<dyn EventBusInterface>) -> TitleScreen {
+    let repository_store =
+        Arc::new(RepositoryStore::new_for_test()) as Arc<dyn RepositoryStoreInterface>;
+    repository_store.set_repository(gittype::domain::models::GitRepository {
+        user_name: "alice".to_string(),
+        repository_name: "demo".to_string(),
+        remote_url: "https://github.com/alice/demo".to_string(),
+        branch: Some("main".to_string()),
+        commit_hash: None,
+        is_dirty: false,
+        root_path: None,
+        license: None,
+    });
+    repository_store.set_quick_play(true);
+
+    let screen = create_title_screen_with_store(event_bus, repository_store);
+    screen.init_with_data(Box::new(())).unwrap();
+    screen
+}
+
+#[test]
+fn test_title_screen_offers_quick_play_for_current_repository() {
+    let screen = quick_play_screen(Arc::new(EventBus::new()));
+
+    assert!(screen.is_quick_play());
+
+    let backend = ratatui::backend::TestBackend::new(100, 30);
+    let mut terminal = ratatui::Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| screen.render_ratatui(frame).unwrap())
+        .unwrap();
+    let buffer = terminal.backend().buffer();
+    let text: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+    assert!(text.contains("Play current repo alice/demo?"));
+    assert!(text.contains("[M] Open menu"));
+    assert!(!text.contains("Records"));
+}
+
+#[test]
+fn test_title_screen_quick_play_needs_a_repository() {
+    let repository_store =
+        Arc::new(RepositoryStore::new_for_test()) as Arc<dyn RepositoryStoreInterface>;
+    repository_store.set_quick_play(true);
+    let screen = create_title_screen_with_store(Arc::new(EventBus::new()), repository_store);
+
+    screen.init_with_data(Box::new(())).unwrap();
+
+    assert!(!screen.is_quick_play());
+}
+
+#[test]
+fn test_title_screen_quick_play_m_opens_menu() {
+    let screen = quick_play_screen(Arc::new(EventBus::new()));
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('m'), KeyModifiers::empty()))
+        .unwrap();
+    assert!(!screen.is_quick_play());
+
+    // The offer is made once per launch
+    screen.init_with_data(Box::new(())).unwrap();
+    assert!(!screen.is_quick_play());
+}
+
+#[test]
+fn test_title_screen_quick_play_ignores_menu_keys() {
+    let event_bus = Arc::new(EventBus::new());
+    let captured: Arc<std::sync::Mutex<Vec<NavigateTo>>> = Arc::new(std::sync::Mutex::new(vec![]));
+    let cap = captured.clone();
+    event_bus
+        .as_event_bus()
+        .subscribe(move |ev: &NavigateTo| cap.lock().unwrap().push(ev.clone()));
+    let screen = quick_play_screen(event_bus);
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(captured.lock().unwrap().is_empty());
+    assert!(screen.is_quick_play());
+}
+
+#[test]
+fn test_title_screen_quick_play_enter_starts_game() {
+    let event_bus = Arc::new(EventBus::new());
+    let captured: Arc<std::sync::Mutex<Vec<NavigateTo>>> = Arc::new(std::sync::Mutex::new(vec![]));
+    let cap = captured.clone();
+    event_bus
+        .as_event_bus()
+        .subscribe(move |ev: &NavigateTo| cap.lock().unwrap().push(ev.clone()));
+    let screen = quick_play_screen(event_bus);
+    screen.set_challenge_counts([1, 2, 3, 4, 5]);
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(matches!(
+        screen.get_action_result(),
+        Some(
+            gittype::presentation::tui::screens::title_screen::TitleAction::Start(
+                DifficultyLevel::Normal
+            )
+        )
+    ));
+    assert!(!screen.is_quick_play());
+    assert!(matches!(
+        captured.lock().unwrap().as_slice(),
+        [NavigateTo::Replace(
+            gittype::presentation::tui::ScreenType::Typing
+        )]
+    ));
+}
+
+#[test]
+fn test_title_screen_quick_play_enter_without_challenges_sets_error_message() {
+    let screen = quick_play_screen(Arc::new(EventBus::new()));
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(screen.get_action_result().is_none());
+    assert!(screen.get_error_message().is_some());
+    assert!(screen.is_quick_play());
+}
+
+fn gated_title_screen(normal_accuracy: Option<f64>) -> TitleScreen {
+    let config_service = Arc::new(ConfigService::new_for_test().unwrap());
+    config_service
+        .update_config(|config| config.difficulty_gates.enabled = true)
+        .unwrap();
+    let database = Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>;
+    if let Some(accuracy) = normal_accuracy {
+        let conn = database.get_connection().unwrap();
+        conn.execute_batch(
+            "INSERT INTO repositories (id, user_name, repository_name, remote_url)
+             VALUES (1, 'owner', 'repo', 'https://github.com/owner/repo');
+             INSERT INTO sessions (id, repository_id, started_at, game_mode)
+             VALUES (1, 1, '2026-01-01 00:00:00', 'Normal');",
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO session_results (session_id, repository_id, keystrokes, mistakes,
+                 duration_ms, accuracy, stages_completed, stages_attempted, stages_skipped,
+                 difficulty_level)
+             VALUES (1, 1, 100, 0, 60000, ?, 3, 3, 0, 'Normal')",
+            [accuracy],
+        )
+        .unwrap();
+    }
+
+    let screen = create_title_screen_with_gates(
+        Arc::new(EventBus::new()),
+        Arc::new(RepositoryStore::new_for_test()),
+        Arc::new(ChallengeStore::new_for_test()),
+        config_service,
+        database,
+    );
+    screen
+        .init_with_data(MockTitleScreenDataProvider.provide().unwrap())
+        .unwrap();
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Right, KeyModifiers::empty()))
+        .unwrap();
+    screen
+}
+
+#[test]
+fn test_title_screen_locked_difficulty_cannot_start() {
+    let screen = gated_title_screen(Some(90.0));
+
+    assert_eq!(screen.get_selected_difficulty(), DifficultyLevel::Hard);
+    assert!(screen.get_selected_lock().is_some());
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(screen.get_action_result().is_none());
+    assert_eq!(
+        screen.get_error_message().as_deref(),
+        Some("Reach 95% accuracy on Normal to unlock Hard (best so far 90.0%)")
+    );
+}
+
+#[test]
+fn test_title_screen_unlocked_difficulty_starts() {
+    let screen = gated_title_screen(Some(96.5));
+
+    assert!(screen.get_selected_lock().is_none());
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty()))
+        .unwrap();
+
+    assert!(matches!(
+        screen.get_action_result(),
+        Some(
+            gittype::presentation::tui::screens::title_screen::TitleAction::Start(
+                DifficultyLevel::Hard
+            )
+        )
+    ));
+}
+
+#[test]
+fn test_title_screen_snapshot_locked_difficulty() {
+    let screen = gated_title_screen(None);
+
+    let backend = ratatui::backend::TestBackend::new(100, 30);
+    let mut terminal = ratatui::Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| screen.render_ratatui(frame).unwrap())
+        .unwrap();
+    let buffer = terminal.backend().buffer();
+    let output = (0..buffer.area.height)
+        .map(|y| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn test_title_screen_x_dismisses_update_notice() {
+    let screen = create_title_screen(Arc::new(EventBus::new()));
+    screen
+        .init_with_data(MockTitleScreenUpdateDataProvider.provide().unwrap())
+        .unwrap();
+    assert_eq!(
+        screen.get_update_notice().map(|release| release.version),
+        Some("1.2.0".to_string())
+    );
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::empty()))
+        .unwrap();
+    assert!(screen.get_update_notice().is_some());
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::empty()))
+        .unwrap();
+    assert!(screen.get_update_notice().is_none());
+    assert!(screen.get_action_result().is_none());
+}
+
+#[test]
+fn test_title_screen_without_update_has_no_notice() {
+    let screen = create_title_screen(Arc::new(EventBus::new()));
+    screen
+        .init_with_data(MockTitleScreenDataProvider.provide().unwrap())
+        .unwrap();
+
+    assert!(screen.get_update_notice().is_none());
+}
@@ -1,14 +1,35 @@
 use crate::integration::screens::mocks::trending_repository_mock::MockTrendingRepository;
 use crate::integration::screens::mocks::trending_repository_selection_screen_mock::MockTrendingRepositorySelectionDataProvider;
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
-use gittype::domain::events::presentation_events::NavigateTo;
+use gittype::domain::events::presentation_events::{NavigateTo, TrendingRepositoriesLoaded};
 use gittype::domain::events::EventBus;
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::theme::Theme;
+use gittype::domain::services::dismissed_repository_service::{
+    DismissedRepositoryService, DismissedRepositoryServiceInterface,
+};
+use gittype::domain::services::repository_rating_service::{
+    RepositoryRatingService, RepositoryRatingServiceInterface,
+};
 use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::infrastructure::database::daos::{DismissedRepositoryDao, RepositoryRatingDao};
+use gittype::infrastructure::database::database::Database;
 use gittype::presentation::tui::screens::TrendingRepositorySelectionScreen;
 use gittype::presentation::tui::{Screen, ScreenDataProvider};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+fn dismissed_repository_service() -> Arc<dyn DismissedRepositoryServiceInterface> {
+    Arc::new(DismissedRepositoryService::new(Arc::new(
+        DismissedRepositoryDao::new(Arc::new(Database::new().unwrap())),
+    )))
+}
+
+fn repository_rating_service() -> Arc<dyn RepositoryRatingServiceInterface> {
+    Arc::new(RepositoryRatingService::new(Arc::new(
+        RepositoryRatingDao::new(Arc::new(Database::new().unwrap())),
+    )))
+}
 
 screen_snapshot_test!(
     test_trending_repository_selection_screen_snapshot,
@@ -19,7 +40,9 @@ screen_snapshot_test!(
             Theme::default(),
             ColorMode::Dark
         )) as Arc<dyn ThemeServiceInterface>,
-        Arc::new(MockTrendingRepository::new())
+        Arc::new(MockTrendingRepository::new()),
+        repository_rating_service(),
+        dismissed_repository_service(),
     ),
     provider = MockTrendingRepositorySelectionDataProvider
 );
@@ -37,6 +60,8 @@ screen_key_event_test!(
             event_bus,
             theme_service,
             Arc::new(MockTrendingRepository::new()),
+            repository_rating_service(),
+            dismissed_repository_service(),
         )
     },
     NavigateTo,
@@ -57,6 +82,8 @@ screen_key_event_test!(
             event_bus,
             theme_service,
             Arc::new(MockTrendingRepository::new()),
+            repository_rating_service(),
+            dismissed_repository_service(),
         )
     },
     NavigateTo,
@@ -77,6 +104,8 @@ screen_key_event_test!(
             event_bus,
             theme_service,
             Arc::new(MockTrendingRepository::new()),
+            repository_rating_service(),
+            dismissed_repository_service(),
         )
     },
     NavigateTo,
@@ -97,6 +126,8 @@ screen_key_tests_custom!(
             event_bus,
             theme_service,
             Arc::new(MockTrendingRepository::new()),
+            repository_rating_service(),
+            dismissed_repository_service(),
         )
     },
     MockTrendingRepositorySelectionDataProvider,
@@ -134,7 +165,9 @@ screen_basic_methods_test!(
             Theme::default(),
             ColorMode::Dark
         )) as Arc<dyn ThemeServiceInterface>,
-        Arc::new(MockTrendingRepository::new())
+        Arc::new(MockTrendingRepository::new()),
+        repository_rating_service(),
+        dismissed_repository_service(),
     ),
     gittype::presentation::tui::ScreenType::TrendingRepositorySelection,
     true,
@@ -150,6 +183,8 @@ fn make_screen() -> TrendingRepositorySelectionScreen {
         Arc::new(EventBus::new()),
         theme_service,
         Arc::new(MockTrendingRepository::new()),
+        repository_rating_service(),
+        dismissed_repository_service(),
     )
 }
 
@@ -158,6 +193,105 @@ fn init_with_mock_data(screen: &TrendingRepositorySelectionScreen) {
         .provide()
         .unwrap();
     screen.init_with_data(data).unwrap();
+    wait_until_loaded(screen);
+}
+
+/// Drive `update` like the screen manager does until the background fetch lands
+fn wait_until_loaded(screen: &TrendingRepositorySelectionScreen) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while screen.is_loading() {
+        assert!(Instant::now() < deadline, "trending fetch did not finish");
+        screen.update().unwrap();
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn test_trending_repository_selection_screen_loaded_snapshot() {
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    let screen = make_screen();
+    init_with_mock_data(&screen);
+
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+    terminal
+        .draw(|frame| screen.render_ratatui(frame).unwrap())
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let mut output = String::new();
+    for y in 0..buffer.area.height {
+        for x in 0..buffer.area.width {
+            output.push_str(buffer[(x, y)].symbol());
+        }
+        output.push('\n');
+    }
+    insta::assert_snapshot!(output);
+}
+
+#[test]
+fn test_init_with_data_fetches_in_background() {
+    let event_bus = Arc::new(EventBus::new());
+    let published = Arc::new(Mutex::new(Vec::<TrendingRepositoriesLoaded>::new()));
+    let published_clone = Arc::clone(&published);
+    event_bus.subscribe(move |event: &TrendingRepositoriesLoaded| {
+        published_clone.lock().unwrap().push(event.clone());
+    });
+    let screen = TrendingRepositorySelectionScreen::new(
+        event_bus,
+        Arc::new(ThemeService::new_for_test(
+            Theme::default(),
+            ColorMode::Dark,
+        )) as Arc<dyn ThemeServiceInterface>,
+        Arc::new(MockTrendingRepository::new()),
+        repository_rating_service(),
+        dismissed_repository_service(),
+    );
+
+    let data = MockTrendingRepositorySelectionDataProvider
+        .provide()
+        .unwrap();
+    screen.init_with_data(data).unwrap();
+    assert!(screen.is_loading());
+    assert!(screen.get_repositories().is_empty());
+
+    wait_until_loaded(&screen);
+
+    let published = published.lock().unwrap();
+    assert_eq!(published.len(), 1);
+    assert_eq!(published[0].cache_key, "Rust:daily");
+    assert_eq!(published[0].repositories.as_ref().unwrap().len(), 2);
+    assert_eq!(screen.get_repositories().len(), 2);
+}
+
+#[test]
+fn test_update_drops_results_for_a_superseded_request() {
+    let event_bus = Arc::new(EventBus::new());
+    let screen = TrendingRepositorySelectionScreen::new(
+        Arc::clone(&event_bus) as Arc<dyn gittype::domain::events::EventBusInterface>,
+        Arc::new(ThemeService::new_for_test(
+            Theme::default(),
+            ColorMode::Dark,
+        )) as Arc<dyn ThemeServiceInterface>,
+        Arc::new(MockTrendingRepository::new()),
+        repository_rating_service(),
+        dismissed_repository_service(),
+    );
+    init_with_mock_data(&screen);
+
+    screen
+        .init_with_data(Box::new((Some("Go".to_string()), "weekly".to_string())))
+        .unwrap();
+    event_bus.publish(TrendingRepositoriesLoaded {
+        cache_key: "Rust:daily".to_string(),
+        repositories: Ok(Vec::new()),
+    });
+    screen.update().unwrap();
+
+    assert!(screen.is_loading());
+    wait_until_loaded(&screen);
+    assert_eq!(screen.get_repositories().len(), 2);
 }
 
 #[test]
@@ -223,6 +357,8 @@ fn test_space_selects_current_index() {
         event_bus,
         theme_service,
         Arc::new(MockTrendingRepository::new()),
+        repository_rating_service(),
+        dismissed_repository_service(),
     );
     init_with_mock_data(&screen);
 
@@ -321,6 +457,8 @@ fn test_unhandled_key_does_not_change_state() {
         event_bus,
         theme_service,
         Arc::new(MockTrendingRepository::new()),
+        repository_rating_service(),
+        dismissed_repository_service(),
     );
     init_with_mock_data(&screen);
 
@@ -354,3 +492,33 @@ fn test_default_provider_returns_unit_payload() {
         .unwrap();
     assert!(data.downcast::<()>().is_ok());
 }
+
+#[test]
+fn test_n_dismisses_selected_repository_for_later_lists() {
+    let dismissed_repository_service = dismissed_repository_service();
+    let screen = TrendingRepositorySelectionScreen::new(
+        Arc::new(EventBus::new()),
+        Arc::new(ThemeService::new_for_test(
+            Theme::default(),
+            ColorMode::Dark,
+        )) as Arc<dyn ThemeServiceInterface>,
+        Arc::new(MockTrendingRepository::new()),
+        repository_rating_service(),
+        Arc::clone(&dismissed_repository_service),
+    );
+    init_with_mock_data(&screen);
+
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::empty()))
+        .unwrap();
+
+    let repositories = screen.get_repositories();
+    assert_eq!(repositories.len(), 1);
+    assert_ne!(repositories[0].repo_name, "rust-lang/rust");
+    assert!(dismissed_repository_service
+        .is_dismissed("rust-lang/rust")
+        .unwrap());
+
+    init_with_mock_data(&screen);
+    assert_eq!(screen.get_repositories().len(), 1);
+}
@@ -0,0 +1,152 @@
+use crate::integration::screens::helpers::EmptyMockProvider;
+use crate::integration::screens::mocks::session_repository_mock::MockSessionRepository;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use gittype::domain::events::presentation_events::NavigateTo;
+use gittype::domain::events::{EventBus, EventBusInterface};
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::theme::Theme;
+use gittype::domain::services::config_service::ConfigService;
+use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::presentation::tui::screens::tutorial_screen::TutorialScreen;
+use gittype::presentation::tui::{Screen, ScreenDataProvider};
+use std::sync::{Arc, Mutex};
+
+fn make_tutorial_screen(event_bus: Arc<dyn EventBusInterface>) -> TutorialScreen {
+    TutorialScreen::new(
+        event_bus,
+        Arc::new(ThemeService::new_for_test(
+            Theme::default(),
+            ColorMode::Dark,
+        )) as Arc<dyn ThemeServiceInterface>,
+        Arc::new(ConfigService::new_for_test().unwrap()),
+        Arc::new(MockSessionRepository::new()),
+    )
+}
+
+fn right() -> KeyEvent {
+    KeyEvent::new(KeyCode::Right, KeyModifiers::empty())
+}
+
+fn char_key(c: char) -> KeyEvent {
+    KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty())
+}
+
+screen_snapshot_test!(
+    test_tutorial_screen_snapshot_welcome,
+    TutorialScreen,
+    make_tutorial_screen(Arc::new(EventBus::new()))
+);
+
+screen_snapshot_test!(
+    test_tutorial_screen_snapshot_practice,
+    TutorialScreen,
+    make_tutorial_screen(Arc::new(EventBus::new())),
+    provider = EmptyMockProvider,
+    keys = [right(), char_key('f'), char_key('n'), char_key('x')]
+);
+
+screen_snapshot_test!(
+    test_tutorial_screen_snapshot_skip_and_fail,
+    TutorialScreen,
+    make_tutorial_screen(Arc::new(EventBus::new())),
+    provider = EmptyMockProvider,
+    keys = [right(), KeyEvent::new(KeyCode::Esc, KeyModifiers::empty())]
+);
+
+screen_snapshot_test!(
+    test_tutorial_screen_snapshot_scoring,
+    TutorialScreen,
+    make_tutorial_screen(Arc::new(EventBus::new())),
+    provider = EmptyMockProvider,
+    keys = [
+        right(),
+        KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()),
+        right()
+    ]
+);
+
+screen_snapshot_test!(
+    test_tutorial_screen_snapshot_ranks,
+    TutorialScreen,
+    make_tutorial_screen(Arc::new(EventBus::new())),
+    provider = EmptyMockProvider,
+    keys = [
+        right(),
+        KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()),
+        right(),
+        right()
+    ]
+);
+
+screen_snapshot_test!(
+    test_tutorial_screen_snapshot_finish,
+    TutorialScreen,
+    make_tutorial_screen(Arc::new(EventBus::new())),
+    provider = EmptyMockProvider,
+    keys = [
+        right(),
+        KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()),
+        right(),
+        right(),
+        right()
+    ]
+);
+
+screen_basic_methods_test!(
+    test_tutorial_screen_basic_methods,
+    TutorialScreen,
+    make_tutorial_screen(Arc::new(EventBus::new())),
+    gittype::presentation::tui::ScreenType::Tutorial,
+    false
+);
+
+fn captured_events(keys: &[KeyEvent]) -> Vec<NavigateTo> {
+    let event_bus = Arc::new(EventBus::new());
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = Arc::clone(&events);
+    event_bus.subscribe(move |event: &NavigateTo| {
+        events_clone.lock().unwrap().push(event.clone());
+    });
+
+    let screen = make_tutorial_screen(event_bus);
+    screen
+        .init_with_data(EmptyMockProvider.provide().unwrap())
+        .unwrap();
+    for key in keys {
+        screen.handle_key_event(*key).unwrap();
+    }
+
+    let captured = events.lock().unwrap().clone();
+    captured
+}
+
+#[test]
+fn test_tutorial_screen_esc_closes() {
+    let events = captured_events(&[KeyEvent::new(KeyCode::Esc, KeyModifiers::empty())]);
+    assert!(matches!(events.as_slice(), [NavigateTo::Pop]));
+}
+
+#[test]
+fn test_tutorial_screen_ctrl_c_exits() {
+    let events = captured_events(&[KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)]);
+    assert!(matches!(events.as_slice(), [NavigateTo::Exit]));
+}
+
+#[test]
+fn test_tutorial_screen_enter_on_last_step_closes() {
+    let events = captured_events(&[
+        right(),
+        KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()),
+        right(),
+        right(),
+        right(),
+        KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()),
+    ]);
+    assert!(matches!(events.as_slice(), [NavigateTo::Pop]));
+}
+
+#[test]
+fn test_tutorial_screen_esc_during_practice_skips_practice_only() {
+    let events = captured_events(&[right(), KeyEvent::new(KeyCode::Esc, KeyModifiers::empty())]);
+    assert!(events.is_empty());
+}
@@ -1,9 +1,11 @@
 use crate::integration::screens::mocks::typing_screen_mock::{
-    create_typing_screen_with_challenge, MockTypingScreenDataProvider,
+    create_typing_screen_with_challenge, create_typing_screen_with_config,
+    MockTypingScreenDataProvider,
 };
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
 use gittype::domain::events::presentation_events::NavigateTo;
 use gittype::domain::events::EventBus;
+use gittype::domain::services::config_service::ConfigService;
 use gittype::presentation::tui::screens::typing_screen::TypingScreen;
 use gittype::presentation::tui::Screen;
 use std::sync::{Arc, Mutex};
@@ -92,6 +94,91 @@ fn test_typing_screen_snapshot_typing_progress() {
     insta::assert_snapshot!(output);
 }
 
+#[test]
+fn test_typing_screen_prepares_the_next_stage_once_typing_starts() {
+    use gittype::presentation::tui::ScreenDataProvider;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    let screen = create_typing_screen_with_challenge(
+        Arc::new(EventBus::new()),
+        Some("fn main() {\n    println!(\"Hello\");\n}"),
+    );
+    let data = MockTypingScreenDataProvider.provide().unwrap();
+    let _ = screen.init_with_data(data);
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+
+    terminal
+        .draw(|frame| screen.render_ratatui(frame).unwrap())
+        .unwrap();
+    assert!(!screen.has_prepared_next_stage());
+
+    let _ = screen.handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty()));
+    screen.skip_countdown_for_test();
+    screen.set_waiting_to_start(false);
+    terminal
+        .draw(|frame| screen.render_ratatui(frame).unwrap())
+        .unwrap();
+    // The layout is made on a background thread and handed over by update
+    for _ in 0..200 {
+        let _ = screen.update();
+        if screen.has_prepared_next_stage() {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert!(screen.has_prepared_next_stage());
+}
+
+#[test]
+fn test_typing_screen_ignores_pasted_code() {
+    use gittype::presentation::tui::views::TypingPasteNoticeView;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    let screen =
+        create_typing_screen_with_challenge(Arc::new(EventBus::new()), Some("fn main() {}"));
+    let _ = screen.handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty()));
+    screen.skip_countdown_for_test();
+    screen.set_waiting_to_start(false);
+
+    screen.handle_paste("fn main() {}").unwrap();
+
+    assert!(screen.is_paste_notice_shown());
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+    terminal
+        .draw(|frame| screen.render_ratatui(frame).unwrap())
+        .unwrap();
+    let buffer = terminal.backend().buffer();
+    let bottom_line: String = (0..buffer.area.width)
+        .map(|x| buffer[(x, buffer.area.height - 1)].symbol())
+        .collect();
+    assert!(bottom_line.contains(TypingPasteNoticeView::MESSAGE));
+    assert!(!screen.is_dialog_shown());
+}
+
+#[test]
+fn test_typing_screen_scores_keys_read_back_to_back_after_a_stall() {
+    use gittype::presentation::tui::key_input::KeyInputFilter;
+
+    let code = "fn all() {}";
+    let screen = create_typing_screen_with_challenge(Arc::new(EventBus::new()), Some(code));
+    let _ = screen.handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty()));
+    screen.skip_countdown_for_test();
+    screen.set_waiting_to_start(false);
+
+    // Everything typed while the loop was stalled is read back to back
+    for c in code.chars() {
+        let key_event = KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty());
+        if let Some(key_event) = KeyInputFilter::accept(key_event) {
+            screen.handle_key_event(key_event).unwrap();
+        }
+    }
+
+    assert_eq!(screen.typed_position(), code.chars().count());
+    assert!(!screen.is_paste_notice_shown());
+}
+
 // Key event test: Ctrl+C exits
 screen_key_event_test!(
     test_typing_screen_ctrl_c_exits,
@@ -788,3 +875,101 @@ fn test_typing_uppercase_q_types_character() {
         .handle_key_event(KeyEvent::new(KeyCode::Char('Q'), KeyModifiers::empty()))
         .unwrap();
 }
+
+// Snapshot test: big WPM panel toggled on from the dialog
+screen_snapshot_test!(
+    test_typing_screen_snapshot_big_wpm_shown,
+    TypingScreen,
+    create_typing_screen_with_challenge(Arc::new(EventBus::new()), Some("fn test() { }")),
+    provider = MockTypingScreenDataProvider,
+    keys = [
+        KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()),
+        KeyEvent::new(KeyCode::Char('w'), KeyModifiers::empty())
+    ]
+);
+
+#[test]
+fn test_typing_screen_toggles_big_wpm_from_dialog() {
+    let screen =
+        create_typing_screen_with_challenge(Arc::new(EventBus::new()), Some("fn test() { }"));
+    let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::empty());
+    let w = KeyEvent::new(KeyCode::Char('w'), KeyModifiers::empty());
+    assert!(!screen.is_big_wpm_shown());
+
+    screen.handle_key_event(w).unwrap();
+    assert!(!screen.is_big_wpm_shown());
+
+    screen.handle_key_event(esc).unwrap();
+    screen.handle_key_event(w).unwrap();
+    assert!(screen.is_big_wpm_shown());
+
+    screen.handle_key_event(esc).unwrap();
+    screen.handle_key_event(w).unwrap();
+    assert!(!screen.is_big_wpm_shown());
+}
+
+// ---------------------------------------------------------------------------
+// Terminal focus changes
+// ---------------------------------------------------------------------------
+
+fn typing_screen_in_stage() -> TypingScreen {
+    let screen = create_typing_screen_with_challenge(Arc::new(EventBus::new()), Some("fn t() {}"));
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty()))
+        .unwrap();
+    screen.skip_countdown_for_test();
+    screen
+}
+
+#[test]
+fn test_focus_loss_pauses_stage_until_focus_returns() {
+    let screen = typing_screen_in_stage();
+
+    screen.handle_focus_change(false).unwrap();
+    assert!(screen.is_dialog_shown());
+
+    screen.handle_focus_change(true).unwrap();
+    assert!(!screen.is_dialog_shown());
+}
+
+#[test]
+fn test_focus_gain_keeps_dialog_opened_by_hand() {
+    let screen = typing_screen_in_stage();
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()))
+        .unwrap();
+
+    screen.handle_focus_change(false).unwrap();
+    screen.handle_focus_change(true).unwrap();
+
+    assert!(screen.is_dialog_shown());
+}
+
+#[test]
+fn test_focus_loss_before_start_does_not_pause() {
+    let screen = create_typing_screen_with_challenge(Arc::new(EventBus::new()), Some("fn t() {}"));
+
+    screen.handle_focus_change(false).unwrap();
+
+    assert!(!screen.is_dialog_shown());
+}
+
+#[test]
+fn test_focus_loss_does_not_pause_when_disabled() {
+    let config_service = Arc::new(ConfigService::new_for_test().unwrap());
+    config_service
+        .update_config(|config| config.input.pause_on_focus_loss = false)
+        .unwrap();
+    let screen = create_typing_screen_with_config(
+        Arc::new(EventBus::new()),
+        Some("fn t() {}"),
+        config_service,
+    );
+    screen
+        .handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty()))
+        .unwrap();
+
+    screen.handle_focus_change(false).unwrap();
+
+    assert!(!screen.is_dialog_shown());
+}
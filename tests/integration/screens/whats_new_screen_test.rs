@@ -0,0 +1,112 @@
+use crate::integration::screens::mocks::whats_new_screen_mock::{
+    MockLongWhatsNewDataProvider, MockMissingWhatsNewDataProvider, MockWhatsNewDataProvider,
+};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use gittype::domain::events::presentation_events::NavigateTo;
+use gittype::domain::events::EventBus;
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::theme::Theme;
+use gittype::domain::services::config_service::ConfigService;
+use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::presentation::tui::screens::WhatsNewScreen;
+use gittype::presentation::tui::{Screen, ScreenDataProvider};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use std::sync::Arc;
+
+fn make_whats_new_screen(event_bus: Arc<EventBus>) -> WhatsNewScreen {
+    WhatsNewScreen::new(
+        event_bus,
+        Arc::new(ThemeService::new_for_test(
+            Theme::default(),
+            ColorMode::Dark,
+        )) as Arc<dyn ThemeServiceInterface>,
+        Arc::new(ConfigService::new_for_test().unwrap()),
+    )
+}
+
+screen_snapshot_test!(
+    test_whats_new_screen_snapshot,
+    WhatsNewScreen,
+    make_whats_new_screen(Arc::new(EventBus::new())),
+    provider = MockWhatsNewDataProvider
+);
+
+screen_snapshot_test!(
+    test_whats_new_screen_snapshot_without_notes,
+    WhatsNewScreen,
+    make_whats_new_screen(Arc::new(EventBus::new())),
+    provider = MockMissingWhatsNewDataProvider
+);
+
+screen_key_event_test!(
+    test_whats_new_screen_enter_continues,
+    WhatsNewScreen,
+    make_whats_new_screen,
+    NavigateTo,
+    KeyCode::Enter,
+    KeyModifiers::empty(),
+    MockWhatsNewDataProvider
+);
+
+screen_key_event_test!(
+    test_whats_new_screen_esc_continues,
+    WhatsNewScreen,
+    make_whats_new_screen,
+    NavigateTo,
+    KeyCode::Esc,
+    KeyModifiers::empty(),
+    MockWhatsNewDataProvider
+);
+
+screen_key_event_test!(
+    test_whats_new_screen_ctrl_c_exits,
+    WhatsNewScreen,
+    make_whats_new_screen,
+    NavigateTo,
+    KeyCode::Char('c'),
+    KeyModifiers::CONTROL,
+    MockWhatsNewDataProvider
+);
+
+screen_basic_methods_test!(
+    test_whats_new_screen_basic_methods,
+    WhatsNewScreen,
+    make_whats_new_screen(Arc::new(EventBus::new())),
+    gittype::presentation::tui::ScreenType::WhatsNew,
+    false,
+    MockWhatsNewDataProvider
+);
+
+#[test]
+fn test_whats_new_screen_scrolls_within_long_notes() {
+    let screen = make_whats_new_screen(Arc::new(EventBus::new()));
+    screen
+        .init_with_data(MockLongWhatsNewDataProvider.provide().unwrap())
+        .unwrap();
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| screen.render_ratatui(frame).unwrap())
+        .unwrap();
+
+    let press = |code| {
+        screen
+            .handle_key_event(KeyEvent::new(code, KeyModifiers::empty()))
+            .unwrap()
+    };
+    press(KeyCode::Up);
+    assert_eq!(screen.scroll_position(), 0);
+
+    press(KeyCode::Down);
+    press(KeyCode::Char('j'));
+    assert_eq!(screen.scroll_position(), 2);
+
+    for _ in 0..100 {
+        press(KeyCode::Down);
+    }
+    // 61 lines of notes in an 18 line viewport
+    assert_eq!(screen.scroll_position(), 43);
+
+    press(KeyCode::Char('k'));
+    assert_eq!(screen.scroll_position(), 42);
+}
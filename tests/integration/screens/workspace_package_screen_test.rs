@@ -0,0 +1,127 @@
+use crate::integration::screens::mocks::workspace_package_screen_mock::MockWorkspacePackageScreenDataProvider;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use gittype::domain::events::presentation_events::NavigateTo;
+use gittype::domain::events::EventBus;
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::theme::Theme;
+use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
+use gittype::presentation::tui::screens::{PackageChoice, WorkspacePackageScreen};
+use gittype::presentation::tui::{Screen, ScreenDataProvider};
+use std::sync::Arc;
+
+fn make_workspace_package_screen(event_bus: Arc<EventBus>) -> WorkspacePackageScreen {
+    WorkspacePackageScreen::new(
+        event_bus,
+        Arc::new(ThemeService::new_for_test(
+            Theme::default(),
+            ColorMode::Dark,
+        )) as Arc<dyn ThemeServiceInterface>,
+    )
+}
+
+screen_snapshot_test!(
+    test_workspace_package_screen_snapshot,
+    WorkspacePackageScreen,
+    make_workspace_package_screen(Arc::new(EventBus::new())),
+    provider = MockWorkspacePackageScreenDataProvider
+);
+
+screen_key_event_test!(
+    test_workspace_package_screen_enter_exits,
+    WorkspacePackageScreen,
+    make_workspace_package_screen,
+    NavigateTo,
+    KeyCode::Enter,
+    KeyModifiers::empty(),
+    MockWorkspacePackageScreenDataProvider
+);
+
+screen_key_event_test!(
+    test_workspace_package_screen_esc_exits,
+    WorkspacePackageScreen,
+    make_workspace_package_screen,
+    NavigateTo,
+    KeyCode::Esc,
+    KeyModifiers::empty(),
+    MockWorkspacePackageScreenDataProvider
+);
+
+screen_key_event_test!(
+    test_workspace_package_screen_ctrl_c_exits,
+    WorkspacePackageScreen,
+    make_workspace_package_screen,
+    NavigateTo,
+    KeyCode::Char('c'),
+    KeyModifiers::CONTROL,
+    MockWorkspacePackageScreenDataProvider
+);
+
+screen_basic_methods_test!(
+    test_workspace_package_screen_basic_methods,
+    WorkspacePackageScreen,
+    make_workspace_package_screen(Arc::new(EventBus::new())),
+    gittype::presentation::tui::ScreenType::WorkspacePackages,
+    false,
+    MockWorkspacePackageScreenDataProvider
+);
+
+fn initialized_screen() -> WorkspacePackageScreen {
+    let screen = make_workspace_package_screen(Arc::new(EventBus::new()));
+    screen
+        .init_with_data(MockWorkspacePackageScreenDataProvider.provide().unwrap())
+        .unwrap();
+    screen
+}
+
+fn press(screen: &WorkspacePackageScreen, code: KeyCode) {
+    screen
+        .handle_key_event(KeyEvent::new(code, KeyModifiers::empty()))
+        .unwrap()
+}
+
+#[test]
+fn test_workspace_package_screen_starts_on_whole_repository() {
+    let screen = initialized_screen();
+
+    assert_eq!(screen.highlighted(), Some(PackageChoice::WholeRepository));
+    press(&screen, KeyCode::Enter);
+    assert_eq!(screen.get_choice(), Some(PackageChoice::WholeRepository));
+}
+
+#[test]
+fn test_workspace_package_screen_enter_chooses_highlighted_package() {
+    let screen = initialized_screen();
+
+    press(&screen, KeyCode::Down);
+    press(&screen, KeyCode::Char('j'));
+    press(&screen, KeyCode::Enter);
+
+    assert_eq!(
+        screen.get_choice(),
+        Some(PackageChoice::Package(screen.packages()[1].clone()))
+    );
+}
+
+#[test]
+fn test_workspace_package_screen_selection_stays_within_list() {
+    let screen = initialized_screen();
+
+    press(&screen, KeyCode::Up);
+    assert_eq!(screen.highlighted(), Some(PackageChoice::WholeRepository));
+
+    (0..5).for_each(|_| press(&screen, KeyCode::Down));
+    assert_eq!(
+        screen.highlighted(),
+        Some(PackageChoice::Package(screen.packages()[2].clone()))
+    );
+}
+
+#[test]
+fn test_workspace_package_screen_esc_leaves_no_choice() {
+    let screen = initialized_screen();
+
+    press(&screen, KeyCode::Down);
+    press(&screen, KeyCode::Esc);
+
+    assert_eq!(screen.get_choice(), None);
+}
@@ -0,0 +1,119 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use gittype::domain::models::storage::StoredStageResult;
+use gittype::domain::models::Achievement;
+
+fn day(offset: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 5, 10, 12, 0, 0).unwrap() + Duration::days(offset)
+}
+
+fn stage(completed_at: DateTime<Utc>, wpm: f64) -> StoredStageResult {
+    StoredStageResult {
+        id: 1,
+        repository_id: Some(1),
+        repository_name: Some("repo".to_string()),
+        user_name: Some("user".to_string()),
+        wpm,
+        cpm: wpm * 5.0,
+        accuracy: 95.0,
+        keystrokes: 100,
+        mistakes: 5,
+        duration_ms: 60_000,
+        score: 100.0,
+        language: Some("rust".to_string()),
+        difficulty_level: Some("Normal".to_string()),
+        completed_at,
+        rank_name: None,
+        tier_name: None,
+    }
+}
+
+fn unlocked(stages: &[StoredStageResult]) -> Vec<Achievement> {
+    Achievement::evaluate(stages)
+        .into_iter()
+        .map(|(achievement, _)| achievement)
+        .collect()
+}
+
+#[test]
+fn ids_round_trip_and_are_unique() {
+    for achievement in Achievement::all() {
+        assert_eq!(Achievement::from_id(achievement.id()), Some(*achievement));
+        assert!(!achievement.name().is_empty());
+        assert!(!achievement.hint().is_empty());
+    }
+    assert_eq!(Achievement::from_id("unknown"), None);
+}
+
+#[test]
+fn no_stages_unlock_nothing() {
+    assert!(Achievement::evaluate(&[]).is_empty());
+}
+
+#[test]
+fn unlock_time_is_the_stage_that_earned_it() {
+    let stages = [
+        stage(day(2), 65.0),
+        stage(day(0), 30.0),
+        stage(day(1), 45.0),
+    ];
+    let evaluated = Achievement::evaluate(&stages);
+
+    let unlocked_at = |target: Achievement| {
+        evaluated
+            .iter()
+            .find(|(achievement, _)| *achievement == target)
+            .map(|(_, at)| *at)
+    };
+    assert_eq!(unlocked_at(Achievement::FirstStage), Some(day(0)));
+    assert_eq!(unlocked_at(Achievement::Wpm40), Some(day(1)));
+    assert_eq!(unlocked_at(Achievement::Wpm60), Some(day(2)));
+    assert_eq!(unlocked_at(Achievement::ThreeDayStreak), Some(day(2)));
+    assert_eq!(unlocked_at(Achievement::Wpm80), None);
+}
+
+#[test]
+fn streak_resets_after_a_missed_day() {
+    let stages = [
+        stage(day(0), 20.0),
+        stage(day(1), 20.0),
+        stage(day(3), 20.0),
+        stage(day(3) + Duration::hours(1), 20.0),
+    ];
+    assert!(!unlocked(&stages).contains(&Achievement::ThreeDayStreak));
+}
+
+#[test]
+fn variety_achievements_count_distinct_languages_and_repositories() {
+    let stages: Vec<StoredStageResult> = ["rust", "Rust", "go", "python"]
+        .iter()
+        .enumerate()
+        .map(|(i, language)| StoredStageResult {
+            language: Some(language.to_string()),
+            repository_id: Some(i as i64),
+            ..stage(day(0), 20.0)
+        })
+        .collect();
+
+    let earned = unlocked(&stages);
+    assert!(earned.contains(&Achievement::Polyglot));
+    assert!(!earned.contains(&Achievement::Explorer));
+}
+
+#[test]
+fn flawless_and_wild_stages() {
+    let stages = [
+        StoredStageResult {
+            accuracy: 100.0,
+            mistakes: 0,
+            ..stage(day(0), 20.0)
+        },
+        StoredStageResult {
+            difficulty_level: Some("Wild".to_string()),
+            ..stage(day(0), 20.0)
+        },
+    ];
+
+    let earned = unlocked(&stages);
+    assert!(earned.contains(&Achievement::Flawless));
+    assert!(earned.contains(&Achievement::IntoTheWild));
+}
@@ -0,0 +1,105 @@
+use gittype::domain::models::{Challenge, ChallengePack, DifficultyLevel};
+use gittype::GitTypeError;
+
+use flate2::{write::GzEncoder, Compression};
+use std::io::Write;
+
+fn challenge(id: &str, difficulty: DifficultyLevel) -> Challenge {
+    Challenge::new(id.to_string(), format!("fn {}() {{}}", id)).with_difficulty_level(difficulty)
+}
+
+fn challenges() -> Vec<Challenge> {
+    (0..10)
+        .map(|i| challenge(&format!("normal-{}", i), DifficultyLevel::Normal))
+        .chain([challenge("hard", DifficultyLevel::Hard)])
+        .collect()
+}
+
+fn ids(pack: &ChallengePack) -> Vec<&str> {
+    pack.challenges.iter().map(|c| c.id.as_str()).collect()
+}
+
+fn gzip(json: &str) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(json.as_bytes()).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn new_picks_stages_of_the_requested_difficulty() {
+    let pack = ChallengePack::new("owner/repo", challenges(), DifficultyLevel::Normal, 3, 7);
+
+    assert_eq!(pack.challenges.len(), 3);
+    assert!(pack
+        .challenges
+        .iter()
+        .all(|c| c.difficulty_level == Some(DifficultyLevel::Normal)));
+    assert_eq!(pack.source, "owner/repo");
+    assert_eq!(pack.seed, 7);
+}
+
+#[test]
+fn new_is_deterministic_for_a_seed_regardless_of_input_order_and_ids() {
+    let mut reversed = challenges();
+    reversed.reverse();
+    reversed
+        .iter_mut()
+        .for_each(|challenge| challenge.id = format!("re-extracted-{}", challenge.id));
+
+    let pack = ChallengePack::new("owner/repo", challenges(), DifficultyLevel::Normal, 4, 42);
+    let same = ChallengePack::new("owner/repo", reversed, DifficultyLevel::Normal, 4, 42);
+    let other = ChallengePack::new("owner/repo", challenges(), DifficultyLevel::Normal, 4, 43);
+
+    assert_eq!(
+        ids(&pack)
+            .iter()
+            .map(|id| format!("re-extracted-{}", id))
+            .collect::<Vec<_>>(),
+        ids(&same)
+    );
+    assert_ne!(ids(&pack), ids(&other));
+}
+
+#[test]
+fn new_keeps_fewer_challenges_when_not_enough_match() {
+    let pack = ChallengePack::new("owner/repo", challenges(), DifficultyLevel::Hard, 3, 1);
+
+    assert_eq!(ids(&pack), vec!["hard"]);
+}
+
+#[test]
+fn bytes_round_trip() {
+    let pack = ChallengePack::new("owner/repo", challenges(), DifficultyLevel::Normal, 3, 9);
+
+    assert_eq!(
+        ChallengePack::from_bytes(&pack.to_bytes().unwrap()).unwrap(),
+        pack
+    );
+}
+
+#[test]
+fn from_bytes_rejects_files_that_are_not_packs() {
+    assert!(matches!(
+        ChallengePack::from_bytes(b"not a pack"),
+        Err(GitTypeError::ValidationError(_))
+    ));
+}
+
+#[test]
+fn from_bytes_rejects_newer_formats_and_empty_packs() {
+    let newer = gzip(
+        r#"{"format_version":99,"source":"o/r","seed":1,"difficulty":"Normal","challenges":[]}"#,
+    );
+    let empty = gzip(
+        r#"{"format_version":1,"source":"o/r","seed":1,"difficulty":"Normal","challenges":[]}"#,
+    );
+
+    assert!(matches!(
+        ChallengePack::from_bytes(&newer),
+        Err(GitTypeError::ValidationError(message)) if message.contains("newer gittype")
+    ));
+    assert!(matches!(
+        ChallengePack::from_bytes(&empty),
+        Err(GitTypeError::ValidationError(message)) if message.contains("no challenges")
+    ));
+}
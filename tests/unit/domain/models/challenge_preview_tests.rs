@@ -0,0 +1,49 @@
+use gittype::domain::models::storage::LanguageActivity;
+use gittype::domain::models::{Challenge, ChallengePreview, DifficultyLevel, LanguageCalibrations};
+
+#[test]
+fn preview_summarizes_file_name_language_length_and_difficulty() {
+    let challenge = Challenge::new("id".to_string(), "fn main() {\n    run();\n}".to_string())
+        .with_source_info("src/bin/main.rs".to_string(), 1, 3)
+        .with_language("rust".to_string())
+        .with_difficulty_level(DifficultyLevel::Normal);
+
+    let preview = ChallengePreview::new(&challenge);
+
+    assert_eq!(preview.file_name.as_deref(), Some("main.rs"));
+    assert_eq!(preview.line_count, 3);
+    assert_eq!(preview.char_count, 24);
+    assert_eq!(
+        preview.summary(),
+        "main.rs · Rust · 3 lines · 24 chars · Normal"
+    );
+}
+
+#[test]
+fn preview_summary_skips_missing_file_and_difficulty() {
+    let challenge = Challenge::new("id".to_string(), "x = 1".to_string());
+
+    assert_eq!(
+        ChallengePreview::new(&challenge).summary(),
+        "Unknown · 1 line · 5 chars"
+    );
+}
+
+#[test]
+fn preview_summary_shows_the_time_expected_at_the_player_pace() {
+    let challenge =
+        Challenge::new("id".to_string(), "x".repeat(500)).with_language("rust".to_string());
+    let calibrations = LanguageCalibrations::from_activity(&[LanguageActivity {
+        language: "rust".to_string(),
+        stages: 10,
+        avg_wpm: 40.0,
+        best_wpm: 50.0,
+        avg_accuracy: 95.0,
+        keystrokes: 2000,
+    }]);
+
+    let preview = ChallengePreview::new(&challenge).with_calibrations(&challenge, &calibrations);
+
+    assert_eq!(preview.expected_seconds, Some(150));
+    assert_eq!(preview.summary(), "Rust · 1 line · 500 chars · ~2m 30s");
+}
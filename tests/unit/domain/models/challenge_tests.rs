@@ -75,6 +75,21 @@ fn test_builder_pattern_chaining() {
     assert_eq!(challenge.difficulty_level, Some(DifficultyLevel::Normal));
 }
 
+#[test]
+fn test_fingerprint_depends_only_on_code_content() {
+    let challenge = Challenge::new("a".to_string(), "fn main() {}".to_string());
+    let same_code = Challenge::new("b".to_string(), "fn main() {}".to_string()).with_source_info(
+        "main.rs".to_string(),
+        1,
+        1,
+    );
+    let other_code = Challenge::new("a".to_string(), "fn other() {}".to_string());
+
+    assert_eq!(challenge.fingerprint(), same_code.fingerprint());
+    assert_ne!(challenge.fingerprint(), other_code.fingerprint());
+    assert_eq!(challenge.fingerprint().len(), 64);
+}
+
 #[test]
 fn test_get_display_title_without_source_info() {
     let challenge = Challenge::new("test-123".to_string(), "code".to_string());
@@ -0,0 +1,74 @@
+use gittype::domain::models::Changelog;
+
+const SAMPLE: &str = "# Changelog
+
+All notable changes to this project will be documented in this file.
+
+## [0.3.0] - 2025-02-01
+
+### ✨ Features
+
+- feat: add a thing ([abc1234](https://github.com/owner/repo/commit/abc1234))
+- feat: another thing (#12) ([def5678](https://github.com/owner/repo/commit/def5678))
+
+### 🐛 Bug Fixes
+
+- fix: plain entry without link
+
+## [0.2.0] - 2025-01-01
+
+### 📝 Other Changes
+
+- chore: bump version ([0123456](https://github.com/owner/repo/commit/0123456))
+";
+
+#[test]
+fn parse_reads_releases_sections_and_entries() {
+    let changelog = Changelog::parse(SAMPLE);
+
+    assert_eq!(changelog.releases.len(), 2);
+    let latest = &changelog.releases[0];
+    assert_eq!(latest.version, "0.3.0");
+    assert_eq!(latest.date.as_deref(), Some("2025-02-01"));
+    assert_eq!(latest.sections.len(), 2);
+    assert_eq!(latest.sections[0].title, "✨ Features");
+    assert_eq!(
+        latest.sections[0].entries,
+        vec!["feat: add a thing", "feat: another thing (#12)"]
+    );
+    assert_eq!(
+        latest.sections[1].entries,
+        vec!["fix: plain entry without link"]
+    );
+}
+
+#[test]
+fn release_lookup_ignores_leading_v() {
+    let changelog = Changelog::parse(SAMPLE);
+
+    assert_eq!(changelog.release("v0.2.0").unwrap().version, "0.2.0");
+    assert_eq!(changelog.release("0.3.0").unwrap().version, "0.3.0");
+    assert!(changelog.release("0.4.0").is_none());
+}
+
+#[test]
+fn has_unseen_release_only_for_new_versions_with_notes() {
+    let changelog = Changelog::parse(SAMPLE);
+
+    assert!(changelog.has_unseen_release(None, "0.3.0"));
+    assert!(changelog.has_unseen_release(Some("0.2.0"), "0.3.0"));
+    assert!(!changelog.has_unseen_release(Some("0.3.0"), "0.3.0"));
+    assert!(!changelog.has_unseen_release(Some("v0.3.0"), "0.3.0"));
+    assert!(!changelog.has_unseen_release(Some("0.3.0"), "0.4.0"));
+}
+
+#[test]
+fn bundled_changelog_parses() {
+    let changelog = Changelog::bundled();
+
+    assert!(!changelog.releases.is_empty());
+    assert!(changelog
+        .releases
+        .iter()
+        .all(|release| !release.version.is_empty()));
+}
@@ -1,6 +1,11 @@
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::color_scheme::{ColorScheme, SerializableColor, ThemeFile};
-use gittype::domain::models::config::ThemeConfig;
+use gittype::domain::models::config::{
+    Config, DisplayConfig, ExtractionConfig, LanguageSelection, NetworkConfig, ReleaseChannel,
+    SecretHandling, StreamerConfig, ThemeConfig, VersionCheckConfig, VersionCheckFrequency,
+};
+use gittype::domain::models::languages::{JavaScript, Python, Rust, Scala};
+use gittype::domain::models::{CompletionFeedback, Languages, LocalePreset, WpmMode};
 use ratatui::style::Color;
 
 #[test]
@@ -179,6 +184,12 @@ fn test_theme_config_deserialize_defaults_missing_theme_id() {
 
     assert_eq!(deserialized.current_theme_id, "default");
     assert_eq!(deserialized.current_color_mode, ColorMode::Dark);
+    assert!(!deserialized.auto_color_mode);
+}
+
+#[test]
+fn test_theme_config_default_follows_terminal_background() {
+    assert!(ThemeConfig::default().auto_color_mode);
 }
 
 #[test]
@@ -189,3 +200,375 @@ fn test_theme_config_clone() {
     assert_eq!(config.current_theme_id, cloned.current_theme_id);
     assert_eq!(config.current_color_mode, cloned.current_color_mode);
 }
+
+#[test]
+fn test_config_without_discord_section_defaults_to_disabled() {
+    let json = r#"{"theme":{"current_theme_id":"ascii","current_color_mode":"Dark"}}"#;
+    let config: gittype::domain::models::config::Config = serde_json::from_str(json).unwrap();
+
+    assert!(!config.discord.enabled);
+    assert_eq!(
+        config.discord.client_id,
+        gittype::domain::models::config::DEFAULT_DISCORD_CLIENT_ID
+    );
+}
+
+#[test]
+fn version_check_config_parses_snake_case_values() {
+    let config: Config = serde_json::from_str(
+        r#"{
+            "theme": { "current_color_mode": "Dark" },
+            "version_check": { "frequency": "weekly", "channel": "pre_release" }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.version_check.frequency,
+        VersionCheckFrequency::Weekly
+    );
+    assert_eq!(config.version_check.channel, ReleaseChannel::PreRelease);
+    assert_eq!(config.version_check.frequency.interval_hours(), Some(168));
+}
+
+#[test]
+fn version_check_defaults_to_daily_stable_checks() {
+    let config: Config =
+        serde_json::from_str(r#"{ "theme": { "current_color_mode": "Dark" } }"#).unwrap();
+
+    assert_eq!(config.version_check, VersionCheckConfig::default());
+    assert_eq!(config.version_check.frequency.interval_hours(), Some(24));
+    assert_eq!(VersionCheckFrequency::Off.interval_hours(), None);
+}
+
+#[test]
+fn display_locale_defaults_to_system_and_parses_presets() {
+    let config: Config =
+        serde_json::from_str(r#"{ "theme": { "current_color_mode": "Dark" } }"#).unwrap();
+    assert_eq!(config.display, DisplayConfig::default());
+    assert_eq!(config.display.locale, LocalePreset::System);
+
+    let config: Config = serde_json::from_str(
+        r#"{
+            "theme": { "current_color_mode": "Dark" },
+            "display": { "locale": "en_gb" }
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(config.display.locale, LocalePreset::EnGb);
+}
+
+#[test]
+fn display_big_wpm_defaults_to_off() {
+    let config: Config = serde_json::from_str(
+        r#"{
+            "theme": { "current_color_mode": "Dark" },
+            "display": { "locale": "iso" }
+        }"#,
+    )
+    .unwrap();
+    assert!(!config.display.big_wpm);
+
+    let config: Config = serde_json::from_str(
+        r#"{
+            "theme": { "current_color_mode": "Dark" },
+            "display": { "big_wpm": true }
+        }"#,
+    )
+    .unwrap();
+    assert!(config.display.big_wpm);
+    assert_eq!(config.display.locale, LocalePreset::System);
+}
+
+#[test]
+fn display_wpm_mode_defaults_to_characters() {
+    let config: Config =
+        serde_json::from_str(r#"{ "theme": { "current_color_mode": "Dark" } }"#).unwrap();
+    assert_eq!(config.display.wpm_mode, WpmMode::Characters);
+
+    let config: Config = serde_json::from_str(
+        r#"{
+            "theme": { "current_color_mode": "Dark" },
+            "display": { "wpm_mode": "tokens" }
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(config.display.wpm_mode, WpmMode::Tokens);
+}
+
+#[test]
+fn display_completion_feedback_defaults_to_none() {
+    assert_eq!(
+        Config::default().display.completion_feedback,
+        CompletionFeedback::None
+    );
+
+    let config: Config = serde_json::from_str(
+        r#"{
+            "theme": { "current_color_mode": "Dark" },
+            "display": { "completion_feedback": "flash" }
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        config.display.completion_feedback,
+        CompletionFeedback::Flash
+    );
+}
+
+#[test]
+fn pause_on_focus_loss_defaults_to_on() {
+    assert!(Config::default().input.pause_on_focus_loss);
+
+    let config: Config = serde_json::from_str(
+        r#"{
+            "theme": { "current_color_mode": "Dark" },
+            "input": { "latency_ms": 12 }
+        }"#,
+    )
+    .unwrap();
+    assert!(config.input.pause_on_focus_loss);
+    assert_eq!(config.input.latency_ms, 12);
+
+    let config: Config = serde_json::from_str(
+        r#"{
+            "theme": { "current_color_mode": "Dark" },
+            "input": { "pause_on_focus_loss": false }
+        }"#,
+    )
+    .unwrap();
+    assert!(!config.input.pause_on_focus_loss);
+}
+
+#[test]
+fn difficulty_gates_default_to_off_at_95_percent() {
+    let config = Config::default();
+    assert!(!config.difficulty_gates.enabled);
+    assert_eq!(config.difficulty_gates.required_accuracy, 95.0);
+
+    let config: Config = serde_json::from_str(
+        r#"{
+            "theme": { "current_color_mode": "Dark" },
+            "difficulty_gates": { "enabled": true }
+        }"#,
+    )
+    .unwrap();
+    assert!(config.difficulty_gates.enabled);
+    assert_eq!(config.difficulty_gates.required_accuracy, 95.0);
+}
+
+#[test]
+fn streamer_defaults_to_off_without_stats_file() {
+    let config: Config =
+        serde_json::from_str(r#"{ "theme": { "current_color_mode": "Dark" } }"#).unwrap();
+    assert_eq!(config.streamer, StreamerConfig::default());
+    assert!(!config.streamer.enabled);
+
+    let config: Config = serde_json::from_str(
+        r#"{
+            "theme": { "current_color_mode": "Dark" },
+            "streamer": { "enabled": true, "stats_file": "/tmp/wpm.txt" }
+        }"#,
+    )
+    .unwrap();
+    assert!(config.streamer.enabled);
+    assert_eq!(
+        config.streamer.stats_file,
+        Some(std::path::PathBuf::from("/tmp/wpm.txt"))
+    );
+}
+
+#[test]
+fn extraction_languages_default_to_allowing_everything() {
+    let config: Config =
+        serde_json::from_str(r#"{ "theme": { "current_color_mode": "Dark" } }"#).unwrap();
+    assert_eq!(config.extraction.languages, LanguageSelection::ALL);
+    assert!(config.extraction.languages.allows(&Rust));
+
+    let config: Config = serde_json::from_str(
+        r#"{
+            "theme": { "current_color_mode": "Dark" },
+            "extraction": { "languages": { "disabled": ["JS"] } }
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(config.extraction.languages.disabled, vec!["JS".to_string()]);
+}
+
+#[test]
+fn extraction_secrets_default_to_skipping_chunks() {
+    let config: Config =
+        serde_json::from_str(r#"{ "theme": { "current_color_mode": "Dark" } }"#).unwrap();
+    assert_eq!(config.extraction.secrets, SecretHandling::Skip);
+
+    let config: Config = serde_json::from_str(
+        r#"{
+            "theme": { "current_color_mode": "Dark" },
+            "extraction": { "secrets": "mask" }
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(config.extraction.secrets, SecretHandling::Mask);
+}
+
+#[test]
+fn language_selection_matches_names_and_aliases_with_disabled_winning() {
+    let selection = LanguageSelection {
+        enabled: vec!["rust".to_string(), "py".to_string(), "js".to_string()],
+        disabled: vec!["javascript".to_string()],
+    };
+    assert!(selection.allows(&Rust));
+    assert!(selection.allows(&Python));
+    assert!(!selection.allows(&JavaScript));
+
+    let disabled_only = LanguageSelection {
+        enabled: vec![],
+        disabled: vec!["python".to_string()],
+    };
+    assert!(disabled_only.allows(&Rust));
+    assert!(!disabled_only.allows(&Python));
+}
+
+#[test]
+fn language_selection_reports_unknown_names() {
+    let selection = LanguageSelection {
+        enabled: vec!["rust".to_string(), "markdown".to_string()],
+        disabled: vec!["json".to_string()],
+    };
+    assert_eq!(
+        selection.unknown_names(),
+        vec!["markdown".to_string(), "json".to_string()]
+    );
+    assert!(LanguageSelection::ALL.unknown_names().is_empty());
+}
+
+#[test]
+fn from_extension_in_skips_languages_left_out_of_the_selection() {
+    let extraction = ExtractionConfig {
+        languages: LanguageSelection {
+            enabled: vec![],
+            disabled: vec!["python".to_string()],
+        },
+        ..ExtractionConfig::DEFAULT
+    };
+    assert!(Languages::from_extension_in("py", &extraction).is_none());
+    assert_eq!(
+        Languages::from_extension_in("rs", &extraction).map(|l| l.name()),
+        Some("rust")
+    );
+    assert!(Languages::from_extension_in("py", &ExtractionConfig::DEFAULT).is_some());
+}
+
+#[test]
+fn extension_mapping_overrides_the_built_in_table() {
+    let config: Config = serde_json::from_str(
+        r#"{
+            "theme": { "current_color_mode": "Dark" },
+            "extraction": { "extensions": { ".sc": "scala", "cjs": "js", "h": "cpp" } }
+        }"#,
+    )
+    .unwrap();
+    let extraction = config.extraction;
+
+    let language_of = |ext: &str| Languages::from_extension_in(ext, &extraction).map(|l| l.name());
+    assert_eq!(language_of("sc"), Some("scala"));
+    assert_eq!(language_of("cjs"), Some("javascript"));
+    assert_eq!(language_of("h"), Some("cpp"));
+    assert_eq!(language_of("rs"), Some("rust"));
+    assert_eq!(language_of("unknown"), None);
+    assert_eq!(extraction.extensions_for(&Scala), vec!["sc"]);
+}
+
+#[test]
+fn extension_mapping_respects_the_language_selection_and_reports_unknown_names() {
+    let extraction = ExtractionConfig {
+        languages: LanguageSelection {
+            enabled: vec![],
+            disabled: vec!["scala".to_string()],
+        },
+        extensions: [
+            ("sc".to_string(), "scala".to_string()),
+            ("tpl".to_string(), "handlebars".to_string()),
+        ]
+        .into_iter()
+        .collect(),
+        ..ExtractionConfig::DEFAULT
+    };
+    assert!(Languages::from_extension_in("sc", &extraction).is_none());
+    assert!(Languages::from_extension_in("tpl", &extraction).is_none());
+    assert_eq!(extraction.unknown_names(), vec!["handlebars".to_string()]);
+}
+
+#[test]
+fn network_config_defaults_when_missing_and_round_trips() {
+    let mut json = serde_json::to_value(Config::default()).unwrap();
+    json.as_object_mut().unwrap().remove("network");
+    let config: Config = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(config.network, NetworkConfig::default());
+
+    json["network"] = serde_json::json!({
+        "proxy": "http://proxy.corp:8080",
+        "ca_bundle": "/etc/corp-ca.pem"
+    });
+    let config: Config = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        config.network.proxy.as_deref(),
+        Some("http://proxy.corp:8080")
+    );
+    assert_eq!(
+        config.network.ca_bundle,
+        Some(std::path::PathBuf::from("/etc/corp-ca.pem"))
+    );
+
+    let json = serde_json::to_string(&config.network).unwrap();
+    assert!(!json.contains("no_proxy"));
+}
+
+#[test]
+fn network_proxy_prefers_config_then_https_then_http_variables() {
+    let env = |name: &str| match name {
+        "HTTPS_PROXY" => Some("  ".to_string()),
+        "http_proxy" => Some("http://env-http:3128".to_string()),
+        "NO_PROXY" => Some("localhost,.corp".to_string()),
+        _ => None,
+    };
+
+    let from_env = NetworkConfig::default();
+    assert_eq!(
+        from_env.effective_proxy_with(env).as_deref(),
+        Some("http://env-http:3128")
+    );
+    assert_eq!(
+        from_env.effective_no_proxy_with(env).as_deref(),
+        Some("localhost,.corp")
+    );
+
+    let configured = NetworkConfig {
+        proxy: Some("http://proxy.corp:8080".to_string()),
+        no_proxy: Some("github.com".to_string()),
+        ca_bundle: None,
+    };
+    assert_eq!(
+        configured.effective_proxy_with(env).as_deref(),
+        Some("http://proxy.corp:8080")
+    );
+    assert_eq!(
+        configured.effective_no_proxy_with(env).as_deref(),
+        Some("github.com")
+    );
+    assert_eq!(
+        NetworkConfig::default().effective_proxy_with(|_| None),
+        None
+    );
+}
+
+#[test]
+fn window_title_is_on_unless_turned_off() {
+    assert!(DisplayConfig::default().window_title);
+
+    let display: DisplayConfig = serde_json::from_str(r#"{"big_wpm":true}"#).unwrap();
+    assert!(display.window_title);
+
+    let display: DisplayConfig = serde_json::from_str(r#"{"window_title":false}"#).unwrap();
+    assert!(!display.window_title);
+}
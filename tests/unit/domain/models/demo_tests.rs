@@ -0,0 +1,66 @@
+use gittype::domain::models::DemoTypist;
+use std::time::Duration;
+
+/// Keys the typist presses for `text` within `within`, polled every 10ms
+fn type_text(typist: &mut DemoTypist, text: &str, within: Duration) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut typed = String::new();
+    let mut position = 0;
+    let mut elapsed = Duration::ZERO;
+    while position < chars.len() && elapsed <= within {
+        while let Some(key) = typist.next_key(elapsed, chars[position]) {
+            typed.push(key);
+            if key == chars[position] {
+                position += 1;
+            }
+            if position == chars.len() {
+                break;
+            }
+        }
+        elapsed += Duration::from_millis(10);
+    }
+    typed
+}
+
+#[test]
+fn first_key_is_due_at_once_and_the_next_waits_for_the_pace() {
+    let mut typist = DemoTypist::new(60.0);
+
+    assert_eq!(typist.next_key(Duration::ZERO, 'f'), Some('f'));
+    assert_eq!(typist.next_key(Duration::from_millis(50), 'n'), None);
+    assert_eq!(typist.next_key(Duration::from_secs(1), 'n'), Some('n'));
+}
+
+#[test]
+fn mistypes_now_and_then_and_corrects_the_typo() {
+    let mut typist = DemoTypist::new(600.0);
+    let text = "abcdefghij".repeat(5);
+
+    let typed = type_text(&mut typist, &text, Duration::from_secs(60));
+
+    assert_eq!(typed.len(), text.len() + 1);
+    assert_eq!(typed.matches('x').count(), 1);
+    assert_eq!(typed.replace('x', ""), text);
+}
+
+#[test]
+fn keeps_roughly_the_requested_pace() {
+    let mut typist = DemoTypist::new(60.0);
+    let text = "fn main() {}\n".repeat(20);
+
+    type_text(&mut typist, &text, Duration::from_secs(30));
+
+    // 60 WPM is 5 keystrokes a second
+    let keystrokes = typist.keystrokes();
+    assert!((140..=160).contains(&keystrokes), "{}", keystrokes);
+}
+
+#[test]
+fn never_mistypes_whitespace() {
+    let mut typist = DemoTypist::new(6000.0);
+    let text = " ".repeat(100);
+
+    let typed = type_text(&mut typist, &text, Duration::from_secs(10));
+
+    assert_eq!(typed, text);
+}
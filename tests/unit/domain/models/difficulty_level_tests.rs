@@ -219,3 +219,16 @@ fn test_difficulty_serialize_deserialize() {
     let deserialized: DifficultyLevel = serde_json::from_str(&serialized).unwrap();
     assert_eq!(difficulty, deserialized);
 }
+
+#[test]
+fn test_difficulty_from_name_ignores_case() {
+    assert_eq!(
+        DifficultyLevel::from_name("normal"),
+        Some(DifficultyLevel::Normal)
+    );
+    assert_eq!(
+        DifficultyLevel::from_name("ZEN"),
+        Some(DifficultyLevel::Zen)
+    );
+    assert_eq!(DifficultyLevel::from_name("extreme"), None);
+}
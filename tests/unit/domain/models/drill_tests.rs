@@ -0,0 +1,112 @@
+use gittype::domain::models::{Challenge, DrillGenerator, KeyLoad};
+
+fn challenges() -> Vec<Challenge> {
+    vec![
+        Challenge::new(
+            "1".to_string(),
+            "fn main() -> Result<()> {\n    let config = Config::load()?;\n    run(config).unwrap();\n}"
+                .to_string(),
+        ),
+        Challenge::new(
+            "2".to_string(),
+            "// a comment nobody types\nlet value = Vec::new();".to_string(),
+        )
+        .with_comment_ranges(vec![(0, 25)]),
+    ]
+}
+
+fn key_load(entries: &[(char, usize, usize)]) -> KeyLoad {
+    entries
+        .iter()
+        .fold(KeyLoad::default(), |mut load, &(key, total, mistakes)| {
+            (0..total).for_each(|i| load.record(key, i >= mistakes));
+            load
+        })
+}
+
+#[test]
+fn drills_are_lines_of_tokens_from_the_code() {
+    let generator = DrillGenerator::new(&challenges(), &KeyLoad::default());
+    let drills = generator.generate(3, 7);
+
+    assert_eq!(drills.len(), 3);
+    let code: String = challenges()
+        .iter()
+        .map(|c| c.code_content.clone())
+        .collect::<Vec<_>>()
+        .join(" ");
+    for drill in &drills {
+        assert!(drill.source_file_path.is_none());
+        let lines: Vec<&str> = drill.code_content.lines().collect();
+        assert_eq!(lines.len(), DrillGenerator::LINES_PER_DRILL);
+        for token in lines.iter().flat_map(|line| line.split(' ')) {
+            assert!(code.split_whitespace().any(|t| t == token), "{}", token);
+            assert!(!["comment", "nobody", "types"].contains(&token));
+        }
+        assert!(lines
+            .iter()
+            .all(|line| line.len() <= DrillGenerator::LINE_WIDTH));
+    }
+}
+
+#[test]
+fn same_seed_gives_the_same_drills() {
+    let generator = DrillGenerator::new(&challenges(), &KeyLoad::default());
+
+    let contents = |seed| {
+        generator
+            .generate(2, seed)
+            .into_iter()
+            .map(|drill| drill.code_content)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(contents(42), contents(42));
+}
+
+#[test]
+fn weak_keys_come_from_the_key_load_once_typed_often_enough() {
+    let typed = key_load(&[(':', 40, 20), ('(', 40, 4), ('e', 40, 0), ('!', 3, 3)]);
+    let generator = DrillGenerator::new(&challenges(), &typed);
+
+    assert_eq!(generator.weak_keys(), &[':', '(']);
+}
+
+#[test]
+fn without_enough_history_symbols_count_as_weak_keys() {
+    let generator = DrillGenerator::new(&challenges(), &key_load(&[(':', 3, 3)]));
+
+    assert!(generator.weak_keys().contains(&':'));
+    assert!(generator.weak_keys().contains(&'-'));
+    assert!(generator
+        .weak_keys()
+        .iter()
+        .all(|c| c.is_ascii_punctuation()));
+}
+
+#[test]
+fn tokens_with_weak_keys_are_drawn_most_often() {
+    let generator = DrillGenerator::new(&challenges(), &key_load(&[(':', 40, 20)]));
+    let text: String = generator
+        .generate(10, 1)
+        .into_iter()
+        .map(|drill| drill.code_content)
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let with_colons = tokens.iter().filter(|token| token.contains(':')).count();
+    assert!(
+        with_colons * 3 > tokens.len(),
+        "{} of {}",
+        with_colons,
+        tokens.len()
+    );
+}
+
+#[test]
+fn no_code_gives_no_drills() {
+    let generator = DrillGenerator::new(&[], &KeyLoad::default());
+
+    assert!(generator.is_empty());
+    assert!(generator.generate(3, 1).is_empty());
+}
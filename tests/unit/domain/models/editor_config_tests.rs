@@ -0,0 +1,152 @@
+use gittype::domain::models::{EditorConfig, IndentStyle, Indentation};
+use std::borrow::Cow;
+use std::path::Path;
+
+const CONFIG: &str = "\
+# top-most EditorConfig file
+root = true
+
+[*]
+indent_style = space
+indent_size = 4
+
+[*.{go,mk}]
+indent_style = tab
+tab_width = 8
+
+[Makefile]
+indent_style = tab
+
+[lib/**.js]
+indent_size = 2
+";
+
+fn spaces(width: usize) -> Indentation {
+    Indentation {
+        style: Some(IndentStyle::Space),
+        indent_size: None,
+        tab_width: Some(width),
+    }
+}
+
+fn tabs(width: usize) -> Indentation {
+    Indentation {
+        style: Some(IndentStyle::Tab),
+        indent_size: None,
+        tab_width: Some(width),
+    }
+}
+
+#[test]
+fn parse_reads_root_flag() {
+    assert!(EditorConfig::parse(CONFIG).root);
+    assert!(!EditorConfig::parse("[*]\nindent_style = tab\n").root);
+}
+
+#[test]
+fn star_section_applies_to_files_in_any_directory() {
+    let config = EditorConfig::parse(CONFIG);
+
+    assert_eq!(
+        config.indentation_for(Path::new("src/deep/main.rs")),
+        Indentation {
+            style: Some(IndentStyle::Space),
+            indent_size: Some(4),
+            tab_width: None,
+        }
+    );
+}
+
+#[test]
+fn later_sections_override_earlier_ones() {
+    let config = EditorConfig::parse(CONFIG);
+
+    assert_eq!(
+        config.indentation_for(Path::new("cmd/main.go")),
+        Indentation {
+            style: Some(IndentStyle::Tab),
+            indent_size: Some(4),
+            tab_width: Some(8),
+        }
+    );
+    assert_eq!(
+        config.indentation_for(Path::new("Makefile")).style,
+        Some(IndentStyle::Tab)
+    );
+}
+
+#[test]
+fn sections_with_a_slash_are_relative_to_the_config_directory() {
+    let config = EditorConfig::parse(CONFIG);
+
+    assert_eq!(
+        config
+            .indentation_for(Path::new("lib/util/index.js"))
+            .indent_size,
+        Some(2)
+    );
+    assert_eq!(
+        config
+            .indentation_for(Path::new("src/lib/index.js"))
+            .indent_size,
+        Some(4)
+    );
+}
+
+#[test]
+fn unknown_values_are_left_unset() {
+    let config = EditorConfig::parse("[*]\nindent_style = unset\nindent_size = tab\n");
+
+    assert_eq!(
+        config.indentation_for(Path::new("main.rs")),
+        Indentation::default()
+    );
+}
+
+#[test]
+fn tab_width_falls_back_to_indent_size_then_four() {
+    let indent_size = Indentation {
+        indent_size: Some(2),
+        ..Indentation::default()
+    };
+
+    assert_eq!(indent_size.tab_width(), 2);
+    assert_eq!(Indentation::default().tab_width(), 4);
+}
+
+#[test]
+fn apply_without_style_keeps_source() {
+    let source = "fn main() {\n\tlet a = 1;\n}\n";
+
+    assert!(matches!(
+        Indentation::default().apply(source),
+        Cow::Borrowed(_)
+    ));
+}
+
+#[test]
+fn apply_space_style_expands_leading_tabs() {
+    let source = "fn main() {\n\tif x {\n\t\ty(\"\\t\");\n\t}\n}\n";
+
+    assert_eq!(
+        spaces(2).apply(source),
+        "fn main() {\n  if x {\n    y(\"\\t\");\n  }\n}\n"
+    );
+}
+
+#[test]
+fn apply_tab_style_collapses_leading_spaces_and_keeps_alignment() {
+    let source = "func main() {\n    x := 1\n        y()\n      /* aligned */\n}";
+
+    assert_eq!(
+        tabs(4).apply(source),
+        "func main() {\n\tx := 1\n\t\ty()\n\t  /* aligned */\n}"
+    );
+}
+
+#[test]
+fn apply_keeps_source_already_in_style() {
+    let source = "func main() {\n\tx := 1\n}\n";
+
+    assert!(matches!(tabs(4).apply(source), Cow::Borrowed(_)));
+}
@@ -0,0 +1,129 @@
+use gittype::domain::models::ErrorReport;
+use gittype::GitTypeError;
+
+fn clone_error(code: git2::ErrorCode, class: git2::ErrorClass, message: &str) -> GitTypeError {
+    GitTypeError::RepositoryCloneError(git2::Error::new(code, class, message))
+}
+
+fn sqlite_error(code: i32) -> GitTypeError {
+    GitTypeError::DatabaseError(rusqlite::Error::SqliteFailure(
+        rusqlite::ffi::Error::new(code),
+        None,
+    ))
+}
+
+#[test]
+fn clone_auth_failures_suggest_access_fixes() {
+    let report = ErrorReport::from_error(&clone_error(
+        git2::ErrorCode::Auth,
+        git2::ErrorClass::Ssh,
+        "failed to authenticate SSH session",
+    ));
+
+    assert_eq!(report.title, "Authentication failed while cloning");
+    assert_eq!(
+        report.detail.as_deref(),
+        Some("failed to authenticate SSH session")
+    );
+    assert!(report.suggestions.iter().any(|s| s.contains("ssh-agent")));
+    assert!(report.retryable);
+}
+
+#[test]
+fn http_auth_messages_count_as_auth_failures() {
+    let report = ErrorReport::from_error(&clone_error(
+        git2::ErrorCode::GenericError,
+        git2::ErrorClass::Http,
+        "remote authentication required but no callback set",
+    ));
+
+    assert_eq!(report.title, "Authentication failed while cloning");
+}
+
+#[test]
+fn certificate_failures_point_to_the_ca_bundle() {
+    let report = ErrorReport::from_error(&clone_error(
+        git2::ErrorCode::Certificate,
+        git2::ErrorClass::Ssl,
+        "the SSL certificate is invalid",
+    ));
+
+    assert!(report.suggestions.iter().any(|s| s.contains("ca_bundle")));
+}
+
+#[test]
+fn other_clone_failures_suggest_checking_the_url() {
+    let report = ErrorReport::from_error(&clone_error(
+        git2::ErrorCode::NotFound,
+        git2::ErrorClass::Net,
+        "unexpected http status code: 404",
+    ));
+
+    assert_eq!(report.title, "Failed to clone repository");
+    assert!(report.retryable);
+}
+
+#[test]
+fn no_supported_files_means_no_challenges() {
+    let report = ErrorReport::from_error(&GitTypeError::NoSupportedFiles);
+
+    assert_eq!(report.title, "No challenges found");
+    assert!(report.suggestions.iter().any(|s| s.contains("--langs")));
+    assert!(!report.retryable);
+}
+
+#[test]
+fn empty_finalization_means_no_challenges() {
+    let report = ErrorReport::from_error(&GitTypeError::ExtractionFailed(
+        "No challenges available for finalization".to_string(),
+    ));
+
+    assert_eq!(report.title, "No challenges found");
+}
+
+#[test]
+fn unknown_languages_suggest_the_languages_command() {
+    let report = ErrorReport::from_error(&GitTypeError::ExtractionFailed(
+        "Unknown language: cobol".to_string(),
+    ));
+
+    assert_eq!(report.title, "Unsupported language");
+    assert!(report
+        .suggestions
+        .iter()
+        .any(|s| s.contains("gittype languages")));
+}
+
+#[test]
+fn busy_and_locked_databases_are_retryable() {
+    for code in [rusqlite::ffi::SQLITE_BUSY, rusqlite::ffi::SQLITE_LOCKED] {
+        let report = ErrorReport::from_error(&sqlite_error(code));
+
+        assert_eq!(report.title, "The database is locked");
+        assert!(report.retryable);
+    }
+}
+
+#[test]
+fn other_database_errors_have_no_suggestions() {
+    let report = ErrorReport::from_error(&sqlite_error(rusqlite::ffi::SQLITE_CORRUPT));
+
+    assert_eq!(report.title, "Database error");
+    assert!(report.suggestions.is_empty());
+    assert!(!report.retryable);
+}
+
+#[test]
+fn validation_errors_use_their_message_as_the_title() {
+    let report = ErrorReport::from_error(&GitTypeError::ValidationError("Bad --bots".to_string()));
+
+    assert_eq!(report.title, "Bad --bots");
+    assert_eq!(report.detail, None);
+}
+
+#[test]
+fn internal_errors_ask_for_a_report() {
+    let report = ErrorReport::from_error(&GitTypeError::PanicError("boom".to_string()));
+
+    assert!(report.suggestions[0].contains("github.com/unhappychoice/gittype/issues"));
+}
@@ -25,7 +25,43 @@ fn test_default_extraction_options() {
     assert!(options.languages.is_none());
 
     // Default max file size should be 1MB
-    assert_eq!(options.max_file_size_bytes, 1024 * 1024);
+    assert_eq!(options.max_file_size_kb, 1024);
+
+    // Symlinks are skipped unless explicitly followed
+    assert!(!options.follow_symlinks);
+    assert!(!options.include_prose);
+    assert!(!options.bypasses_cache());
+}
+
+#[test]
+fn prose_commit_and_package_scoped_extraction_bypass_the_cache() {
+    let prose = ExtractionOptions {
+        include_prose: true,
+        ..ExtractionOptions::default()
+    };
+    assert!(prose.bypasses_cache());
+
+    let commit_scoped = ExtractionOptions {
+        commit_files: Some(vec![]),
+        ..ExtractionOptions::default()
+    };
+    assert!(commit_scoped.bypasses_cache());
+
+    let package_scoped = ExtractionOptions {
+        package: Some("core".to_string()),
+        ..ExtractionOptions::default()
+    };
+    assert!(package_scoped.bypasses_cache());
+}
+
+#[test]
+fn enable_snippets_collects_every_file_and_bypasses_the_cache() {
+    let mut options = ExtractionOptions::default();
+    options.enable_snippets();
+
+    assert!(options.snippets);
+    assert_eq!(options.include_patterns, vec!["**/*".to_string()]);
+    assert!(options.bypasses_cache());
 }
 
 #[test]
@@ -34,13 +70,20 @@ fn test_custom_extraction_options() {
         include_patterns: vec!["**/*.rs".to_string()],
         exclude_patterns: vec!["**/tests/**".to_string()],
         languages: Some(vec!["rust".to_string()]),
-        max_file_size_bytes: 2 * 1024 * 1024, // 2MB
+        max_file_size_kb: 2 * 1024, // 2MB
+        max_average_line_length: 500,
+        commit_files: None,
+        package: None,
+        follow_symlinks: false,
+        include_prose: false,
+        snippets: false,
+        pack: None,
     };
 
     assert_eq!(options.include_patterns.len(), 1);
     assert_eq!(options.exclude_patterns.len(), 1);
     assert_eq!(options.languages, Some(vec!["rust".to_string()]));
-    assert_eq!(options.max_file_size_bytes, 2 * 1024 * 1024);
+    assert_eq!(options.max_file_size_kb, 2 * 1024);
 }
 
 #[test]
@@ -106,7 +149,14 @@ fn test_clone_extraction_options() {
         include_patterns: vec!["**/*.rs".to_string()],
         exclude_patterns: vec!["**/tests/**".to_string()],
         languages: Some(vec!["rust".to_string()]),
-        max_file_size_bytes: 2 * 1024 * 1024,
+        max_file_size_kb: 2 * 1024,
+        max_average_line_length: 500,
+        commit_files: None,
+        package: None,
+        follow_symlinks: false,
+        include_prose: false,
+        snippets: false,
+        pack: None,
     };
 
     let cloned = options.clone();
@@ -114,7 +164,7 @@ fn test_clone_extraction_options() {
     assert_eq!(options.include_patterns, cloned.include_patterns);
     assert_eq!(options.exclude_patterns, cloned.exclude_patterns);
     assert_eq!(options.languages, cloned.languages);
-    assert_eq!(options.max_file_size_bytes, cloned.max_file_size_bytes);
+    assert_eq!(options.max_file_size_kb, cloned.max_file_size_kb);
 }
 
 #[test]
@@ -143,3 +193,23 @@ fn test_default_excludes_generated_code() {
         .contains(&"**/generated/**".to_string()));
     assert!(options.exclude_patterns.contains(&"**/gen/**".to_string()));
 }
+
+#[test]
+fn test_commit_scoped_only_with_commit_files() {
+    let mut options = ExtractionOptions::default();
+    assert!(!options.is_commit_scoped());
+
+    options.commit_files = Some(vec!["src/main.rs".into()]);
+    assert!(options.is_commit_scoped());
+}
+
+#[test]
+fn test_exceeds_file_size_limit() {
+    let options = ExtractionOptions {
+        max_file_size_kb: 1,
+        ..ExtractionOptions::default()
+    };
+
+    assert!(!options.exceeds_file_size_limit(1024));
+    assert!(options.exceeds_file_size_limit(1025));
+}
@@ -0,0 +1,117 @@
+use gittype::domain::models::{Challenge, FailureAnalysis, SourceLocation};
+
+const CODE: &str = "fn main() {\n    let x = 1;\n    println!(\"{}\", x);\n}";
+
+fn challenge() -> Challenge {
+    Challenge::new("challenge".to_string(), CODE.to_string())
+        .with_source_info("src/main.rs".to_string(), 10, 13)
+        .with_language("rust".to_string())
+}
+
+#[test]
+fn locates_failure_relative_to_challenge_start_line() {
+    let analysis = FailureAnalysis::new(challenge(), CODE, 16, &[]);
+
+    assert_eq!(
+        analysis.failure_location,
+        SourceLocation {
+            line: 11,
+            column: 5
+        }
+    );
+    assert_eq!(analysis.expected_char, Some('l'));
+}
+
+#[test]
+fn progress_is_clamped_to_text_length() {
+    let analysis = FailureAnalysis::new(challenge(), CODE, 999, &[]);
+
+    assert_eq!(analysis.typed_chars, analysis.total_chars);
+    assert_eq!(analysis.expected_char, None);
+    assert_eq!(analysis.progress_percentage(), 100.0);
+}
+
+#[test]
+fn empty_text_has_zero_progress() {
+    let analysis = FailureAnalysis::new(
+        Challenge::new("empty".to_string(), String::new()),
+        "",
+        0,
+        &[],
+    );
+
+    assert_eq!(analysis.progress_percentage(), 0.0);
+    assert!(analysis.clusters.is_empty());
+}
+
+#[test]
+fn expected_whitespace_is_made_visible() {
+    assert_eq!(
+        FailureAnalysis::new(challenge(), CODE, 11, &[]).expected_char_display(),
+        Some("↵".to_string())
+    );
+    assert_eq!(
+        FailureAnalysis::new(challenge(), CODE, 2, &[]).expected_char_display(),
+        Some("␣".to_string())
+    );
+}
+
+#[test]
+fn nearby_mistakes_form_one_cluster() {
+    let analysis = FailureAnalysis::new(challenge(), CODE, 30, &[16, 17, 20, 24]);
+
+    assert_eq!(analysis.clusters.len(), 1);
+    assert_eq!(analysis.clusters[0].mistakes, 4);
+    assert_eq!(
+        analysis.clusters[0].location,
+        SourceLocation {
+            line: 11,
+            column: 5
+        }
+    );
+    assert_eq!(analysis.clusters[0].snippet, "let x = 1;");
+}
+
+#[test]
+fn clusters_are_ordered_by_mistake_count() {
+    let analysis = FailureAnalysis::new(challenge(), CODE, 40, &[0, 31, 31, 32]);
+
+    assert_eq!(analysis.clusters.len(), 2);
+    assert_eq!(analysis.clusters[0].mistakes, 3);
+    assert_eq!(analysis.clusters[0].location.line, 12);
+    assert_eq!(analysis.clusters[1].mistakes, 1);
+    assert_eq!(analysis.clusters[1].snippet, "fn main() {");
+}
+
+#[test]
+fn at_most_three_clusters_are_reported() {
+    let analysis = FailureAnalysis::new(challenge(), CODE, 40, &[0, 12, 24, 36]);
+
+    assert_eq!(analysis.clusters.len(), 3);
+}
+
+#[test]
+fn mistakes_past_the_text_are_ignored() {
+    let analysis = FailureAnalysis::new(challenge(), CODE, 0, &[500]);
+
+    assert!(analysis.clusters.is_empty());
+}
+
+#[test]
+fn challenge_label_includes_line_range() {
+    let analysis = FailureAnalysis::new(challenge(), CODE, 0, &[]);
+
+    assert_eq!(analysis.challenge_label(), "src/main.rs:10-13");
+}
+
+#[test]
+fn challenge_label_falls_back_to_id() {
+    let analysis = FailureAnalysis::new(
+        Challenge::new("id-1".to_string(), CODE.to_string()),
+        CODE,
+        0,
+        &[],
+    );
+
+    assert_eq!(analysis.challenge_label(), "id-1");
+}
@@ -10,6 +10,7 @@ fn git_repository_equality_depends_on_all_fields() {
         commit_hash: Some("abc".into()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let mut same = repo.clone();
@@ -29,6 +30,7 @@ fn test_cache_key_https_url() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     assert_eq!(repo.cache_key(), "github_com_owner_repo");
 }
@@ -43,6 +45,7 @@ fn test_cache_key_ssh_url() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     assert_eq!(repo.cache_key(), "github_com_owner_repo");
 }
@@ -57,6 +60,7 @@ fn test_cache_key_ssh_protocol_url() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     assert_eq!(repo.cache_key(), "github_com_owner_repo");
 }
@@ -71,6 +75,7 @@ fn test_cache_key_with_git_suffix() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     assert_eq!(repo.cache_key(), "github_com_owner_repo");
 }
@@ -85,6 +90,7 @@ fn test_cache_key_different_hosts() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let gitlab_repo = GitRepository {
@@ -95,6 +101,7 @@ fn test_cache_key_different_hosts() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     assert_eq!(github_repo.cache_key(), "github_com_owner_repo");
@@ -112,6 +119,7 @@ fn test_git_repository_clone() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let cloned = repo.clone();
@@ -128,6 +136,7 @@ fn test_git_repository_serialize_deserialize() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let serialized = serde_json::to_string(&repo).unwrap();
@@ -145,6 +154,7 @@ fn test_cache_key_malformed_url() {
         commit_hash: None,
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     // Should return fallback format
@@ -161,6 +171,7 @@ fn test_cache_key_malformed_ssh_urls_fall_back_to_sanitized_url() {
         commit_hash: None,
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     let invalid_ssh_protocol_repo = GitRepository {
         user_name: "user".to_string(),
@@ -170,6 +181,7 @@ fn test_cache_key_malformed_ssh_urls_fall_back_to_sanitized_url() {
         commit_hash: None,
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     assert_eq!(invalid_git_at_repo.cache_key(), "git@github_com_owner");
@@ -196,6 +208,7 @@ fn test_cache_key_incomplete_prefixed_urls_fall_back_to_sanitized_url() {
             commit_hash: None,
             is_dirty: false,
             root_path: None,
+            license: None,
         };
 
         assert_eq!(repo.cache_key(), expected);
@@ -212,6 +225,7 @@ fn test_git_repository_with_dirty_state() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: true,
         root_path: None,
+        license: None,
     };
 
     assert!(dirty_repo.is_dirty);
@@ -229,8 +243,72 @@ fn test_git_repository_with_root_path() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: Some(PathBuf::from("/path/to/repo")),
+        license: None,
     };
 
     assert!(repo.root_path.is_some());
     assert_eq!(repo.root_path.unwrap(), PathBuf::from("/path/to/repo"));
 }
+
+#[test]
+fn attribution_names_the_license_when_detected() {
+    let repo = GitRepository {
+        user_name: "owner".into(),
+        repository_name: "repo".into(),
+        remote_url: "https://github.com/owner/repo".into(),
+        branch: None,
+        commit_hash: None,
+        is_dirty: false,
+        root_path: None,
+        license: Some("Apache-2.0".into()),
+    };
+
+    assert_eq!(
+        repo.attribution(),
+        "Code by owner/repo contributors, Apache-2.0 license"
+    );
+    assert_eq!(
+        GitRepository {
+            license: None,
+            ..repo
+        }
+        .attribution(),
+        "Code by owner/repo contributors"
+    );
+}
+
+#[test]
+fn license_defaults_to_none_when_missing_from_json() {
+    let repo: GitRepository = serde_json::from_str(
+        r#"{"user_name":"o","repository_name":"r","remote_url":"u","branch":null,"commit_hash":null,"is_dirty":false,"root_path":null}"#,
+    )
+    .unwrap();
+
+    assert_eq!(repo.license, None);
+}
+
+#[test]
+fn version_label_joins_branch_and_short_commit() {
+    assert_eq!(
+        GitRepository::version_label_for(Some("main"), Some("1a2b3c4d5e6f")).as_deref(),
+        Some("main@1a2b3c4")
+    );
+    assert_eq!(
+        GitRepository::version_label_for(Some("main"), None).as_deref(),
+        Some("main")
+    );
+    assert_eq!(GitRepository::version_label_for(None, None), None);
+}
+
+#[test]
+fn version_label_marks_detached_heads() {
+    assert_eq!(
+        GitRepository::version_label_for(None, Some("1a2b3c4d5e6f")).as_deref(),
+        Some("1a2b3c4 (detached)")
+    );
+    // Sessions recorded before detached heads were told apart stored "HEAD" as the branch
+    assert_eq!(
+        GitRepository::version_label_for(Some("HEAD"), Some("1a2b3c4d5e6f")).as_deref(),
+        Some("1a2b3c4 (detached)")
+    );
+}
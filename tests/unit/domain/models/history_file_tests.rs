@@ -0,0 +1,108 @@
+use chrono::{TimeZone, Utc};
+use gittype::domain::models::{HistoryFile, HistoryRepository, HistoryResult, HistorySession};
+
+fn session() -> HistorySession {
+    HistorySession {
+        started_at: Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap(),
+        completed_at: Utc.with_ymd_and_hms(2026, 3, 1, 9, 5, 0).unwrap(),
+        player: Some("alice".to_string()),
+        repository: HistoryRepository {
+            owner: "rust-lang".to_string(),
+            name: "rust".to_string(),
+            url: "https://github.com/rust-lang/rust".to_string(),
+        },
+        branch: Some("master".to_string()),
+        commit: None,
+        dirty: false,
+        game_mode: "normal".to_string(),
+        difficulty: Some("Normal".to_string()),
+        unverified: false,
+        result: HistoryResult {
+            duration_ms: 300_000,
+            keystrokes: 1500,
+            mistakes: 30,
+            wpm: 60.0,
+            cpm: 300.0,
+            accuracy: 98.0,
+            score: 4200.0,
+            stages_completed: 3,
+            stages_attempted: 3,
+            stages_skipped: 0,
+        },
+    }
+}
+
+fn file_with(session: HistorySession) -> String {
+    serde_json::to_string(&HistoryFile::new(Utc::now(), vec![session])).unwrap()
+}
+
+#[test]
+fn history_file_round_trips_through_json() {
+    let file = HistoryFile::new(
+        Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap(),
+        vec![session()],
+    );
+    let json = serde_json::to_string(&file).unwrap();
+
+    assert!(HistoryFile::is_history_file(&json));
+    assert_eq!(HistoryFile::parse(&json).unwrap(), file);
+}
+
+#[test]
+fn parse_ignores_unknown_fields_and_defaults_optional_ones() {
+    let json = r#"{
+        "format": "gittype-history", "version": 1, "exported_at": "2026-03-02T00:00:00Z",
+        "generator": "some-tool 1.0",
+        "sessions": [{
+            "started_at": "2026-03-01T09:00:00Z", "completed_at": "2026-03-01T09:05:00Z",
+            "repository": {"owner": "a", "name": "b", "url": "https://example.com/a/b"},
+            "game_mode": "normal",
+            "result": {"duration_ms": 1000, "keystrokes": 10, "mistakes": 0, "wpm": 20.0,
+                       "cpm": 100.0, "accuracy": 100.0, "score": 10.0,
+                       "stages_completed": 1, "stages_attempted": 1, "stages_skipped": 0}
+        }]
+    }"#;
+
+    let file = HistoryFile::parse(json).unwrap();
+
+    assert_eq!(file.sessions[0].player, None);
+    assert!(!file.sessions[0].unverified);
+}
+
+#[test]
+fn parse_rejects_other_formats_and_newer_versions() {
+    assert!(!HistoryFile::is_history_file("[]"));
+    assert!(HistoryFile::parse(r#"{"format": "other", "version": 1}"#).is_err());
+
+    let newer = r#"{"format": "gittype-history", "version": 2, "exported_at": "2026-03-02T00:00:00Z", "sessions": []}"#;
+    let error = HistoryFile::parse(newer).unwrap_err().to_string();
+    assert!(error.contains("newer"), "{}", error);
+}
+
+#[test]
+fn parse_names_the_invalid_field() {
+    let mut invalid = session();
+    invalid.result.accuracy = 120.0;
+
+    let error = HistoryFile::parse(&file_with(invalid))
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("sessions[0].result.accuracy"), "{}", error);
+}
+
+#[test]
+fn parse_rejects_sessions_that_end_before_they_start() {
+    let mut invalid = session();
+    invalid.completed_at = Utc.with_ymd_and_hms(2026, 2, 28, 0, 0, 0).unwrap();
+
+    assert!(HistoryFile::parse(&file_with(invalid)).is_err());
+}
+
+#[test]
+fn parse_rejects_missing_required_fields() {
+    let json = r#"{"format": "gittype-history", "version": 1, "exported_at": "2026-03-02T00:00:00Z",
+                   "sessions": [{"started_at": "2026-03-01T09:00:00Z"}]}"#;
+
+    assert!(HistoryFile::parse(json).is_err());
+}
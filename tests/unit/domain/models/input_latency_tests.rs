@@ -0,0 +1,42 @@
+use gittype::domain::models::input_latency::MAX_LATENCY_COMPENSATION;
+use gittype::domain::models::LatencyEstimate;
+use std::time::Duration;
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+#[test]
+fn test_from_samples_empty_returns_none() {
+    assert_eq!(LatencyEstimate::from_samples(&[]), None);
+}
+
+#[test]
+fn test_from_samples_odd_count_uses_middle_value() {
+    let estimate = LatencyEstimate::from_samples(&[ms(9), ms(3), ms(5)]).unwrap();
+    assert_eq!(estimate.samples, 3);
+    assert_eq!(estimate.median, ms(5));
+    assert_eq!(estimate.min, ms(3));
+    assert_eq!(estimate.max, ms(9));
+}
+
+#[test]
+fn test_from_samples_even_count_averages_middle_values() {
+    let estimate = LatencyEstimate::from_samples(&[ms(2), ms(8), ms(4), ms(40)]).unwrap();
+    assert_eq!(estimate.median, ms(6));
+}
+
+#[test]
+fn test_compensation_rounds_down_to_milliseconds() {
+    let estimate = LatencyEstimate::from_samples(&[Duration::from_micros(7_900)]).unwrap();
+    assert_eq!(estimate.compensation_ms(), 7);
+}
+
+#[test]
+fn test_compensation_is_capped() {
+    let estimate = LatencyEstimate::from_samples(&[ms(500)]).unwrap();
+    assert_eq!(
+        estimate.compensation_ms(),
+        MAX_LATENCY_COMPENSATION.as_millis() as u64
+    );
+}
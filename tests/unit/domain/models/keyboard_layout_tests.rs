@@ -0,0 +1,68 @@
+use gittype::domain::models::{Finger, KeyboardLayout};
+
+#[test]
+fn home_row_keys_map_to_their_touch_typing_fingers() {
+    let layout = KeyboardLayout::Qwerty;
+    let home_row: Vec<Option<Finger>> = "asdfghjkl;".chars().map(|ch| layout.finger(ch)).collect();
+
+    assert_eq!(
+        home_row,
+        [
+            Finger::LeftPinky,
+            Finger::LeftRing,
+            Finger::LeftMiddle,
+            Finger::LeftIndex,
+            Finger::LeftIndex,
+            Finger::RightIndex,
+            Finger::RightIndex,
+            Finger::RightMiddle,
+            Finger::RightRing,
+            Finger::RightPinky,
+        ]
+        .map(Some)
+    );
+}
+
+#[test]
+fn number_row_is_shifted_one_key_to_the_left() {
+    let layout = KeyboardLayout::Qwerty;
+
+    assert_eq!(layout.finger('1'), Some(Finger::LeftPinky));
+    assert_eq!(layout.finger('5'), Some(Finger::LeftIndex));
+    assert_eq!(layout.finger('6'), Some(Finger::RightIndex));
+    assert_eq!(layout.finger('0'), Some(Finger::RightPinky));
+    assert_eq!(layout.finger('='), Some(Finger::RightPinky));
+}
+
+#[test]
+fn shifted_characters_use_the_finger_of_their_key() {
+    let layout = KeyboardLayout::Qwerty;
+
+    assert_eq!(layout.finger('A'), Some(Finger::LeftPinky));
+    assert_eq!(layout.finger('('), Some(Finger::RightRing));
+    assert_eq!(layout.finger('{'), Some(Finger::RightPinky));
+    assert_eq!(layout.finger('<'), Some(Finger::RightMiddle));
+}
+
+#[test]
+fn whitespace_keys_and_unknown_characters() {
+    let layout = KeyboardLayout::Qwerty;
+
+    assert_eq!(layout.finger(' '), Some(Finger::Thumb));
+    assert_eq!(layout.finger('\t'), Some(Finger::LeftPinky));
+    assert_eq!(layout.finger('\n'), Some(Finger::RightPinky));
+    assert_eq!(layout.finger('é'), None);
+}
+
+#[test]
+fn adjacency_follows_the_row_stagger() {
+    let layout = KeyboardLayout::Qwerty;
+    let key = |ch| layout.key_position(ch).unwrap();
+
+    assert!(layout.are_adjacent(key('s'), key('d')));
+    assert!(layout.are_adjacent(key('q'), key('2')));
+    assert!(layout.are_adjacent(key('a'), key('z')));
+    assert!(!layout.are_adjacent(key('q'), key('`')));
+    assert!(!layout.are_adjacent(key('a'), key('c')));
+    assert!(!layout.are_adjacent(key('1'), key('a')));
+}
@@ -0,0 +1,51 @@
+use gittype::domain::models::{KeyBinding, Keymap, KeymapContext};
+
+#[test]
+fn bindings_for_returns_bindings_of_context() {
+    let keymap = Keymap::new(vec![(
+        KeymapContext::Title,
+        vec![KeyBinding::new("SPACE", "Start game")],
+    )]);
+
+    assert_eq!(
+        keymap.bindings_for(KeymapContext::Title),
+        &[KeyBinding::new("SPACE", "Start game")]
+    );
+}
+
+#[test]
+fn bindings_for_unknown_context_is_empty() {
+    let keymap = Keymap::new(vec![]);
+    assert!(keymap.bindings_for(KeymapContext::Typing).is_empty());
+}
+
+#[test]
+fn contexts_skip_empty_and_follow_display_order() {
+    let keymap = Keymap::new(vec![
+        (KeymapContext::Help, vec![KeyBinding::new("ESC", "Close")]),
+        (KeymapContext::Records, vec![]),
+        (
+            KeymapContext::Global,
+            vec![KeyBinding::new("Ctrl+C", "Quit")],
+        ),
+    ]);
+
+    let contexts: Vec<KeymapContext> = keymap.contexts().map(|(context, _)| context).collect();
+    assert_eq!(contexts, vec![KeymapContext::Global, KeymapContext::Help]);
+}
+
+#[test]
+fn default_keymap_covers_every_context() {
+    let keymap = Keymap::default();
+    assert!(KeymapContext::all()
+        .iter()
+        .all(|&context| !keymap.bindings_for(context).is_empty()));
+}
+
+#[test]
+fn context_titles_are_unique() {
+    let mut titles: Vec<&str> = KeymapContext::all().iter().map(|c| c.title()).collect();
+    titles.sort();
+    titles.dedup();
+    assert_eq!(titles.len(), KeymapContext::all().len());
+}
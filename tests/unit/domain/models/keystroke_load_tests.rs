@@ -0,0 +1,111 @@
+use gittype::domain::models::{Finger, FingerLoad, KeyLoad, KeyboardLayout, LoadStats};
+
+fn key_load(entries: &[(char, usize, usize)]) -> KeyLoad {
+    entries
+        .iter()
+        .fold(KeyLoad::default(), |mut load, &(key, total, mistakes)| {
+            (0..total).for_each(|i| load.record(key, i >= mistakes));
+            load
+        })
+}
+
+fn finger_load(entries: &[(Finger, usize, usize)]) -> FingerLoad {
+    entries.iter().fold(
+        FingerLoad::default(),
+        |mut load, &(finger, total, mistakes)| {
+            (0..total).for_each(|i| load.record(finger, i >= mistakes));
+            load
+        },
+    )
+}
+
+#[test]
+fn record_counts_keystrokes_and_mistakes_per_key() {
+    let load = key_load(&[(':', 4, 1), ('a', 2, 0)]);
+
+    assert_eq!(
+        load.stats(':'),
+        LoadStats {
+            keystrokes: 4,
+            mistakes: 1
+        }
+    );
+    assert_eq!(load.stats('z'), LoadStats::default());
+    assert_eq!(load.total_keystrokes(), 6);
+    assert!(!load.is_empty());
+    assert!(KeyLoad::default().is_empty());
+}
+
+#[test]
+fn load_and_error_rate_are_percentages() {
+    let load = finger_load(&[(Finger::LeftIndex, 4, 1), (Finger::Thumb, 4, 0)]);
+
+    assert_eq!(load.load_percentage(Finger::LeftIndex), 50.0);
+    assert_eq!(load.stats(Finger::LeftIndex).error_rate(), 25.0);
+    assert_eq!(FingerLoad::default().load_percentage(Finger::Thumb), 0.0);
+    assert_eq!(LoadStats::default().error_rate(), 0.0);
+}
+
+#[test]
+fn merge_adds_counts() {
+    let merged = key_load(&[('>', 3, 1)]).merge(&key_load(&[('>', 2, 2), ('-', 1, 0)]));
+
+    assert_eq!(
+        merged.stats('>'),
+        LoadStats {
+            keystrokes: 5,
+            mistakes: 3
+        }
+    );
+    assert_eq!(merged.stats('-').keystrokes, 1);
+}
+
+#[test]
+fn weakest_are_ordered_by_error_rate_and_skip_rare_keys() {
+    let load = key_load(&[
+        ('~', 2, 2),
+        (':', 30, 9),
+        ('(', 30, 3),
+        ('e', 30, 0),
+        ('>', 20, 4),
+    ]);
+
+    assert_eq!(load.weakest(20, 5), vec![':', '>', '(']);
+    assert_eq!(load.weakest(20, 1), vec![':']);
+    assert_eq!(load.weakest(1, 1), vec!['~']);
+    assert!(KeyLoad::default().weakest(1, 1).is_empty());
+}
+
+#[test]
+fn by_finger_adds_up_the_keys_of_each_finger() {
+    let load = key_load(&[('f', 3, 1), ('g', 2, 1), ('j', 1, 0), ('é', 5, 5)]);
+    let fingers = load.by_finger(&KeyboardLayout::default());
+
+    assert_eq!(
+        fingers.stats(Finger::LeftIndex),
+        LoadStats {
+            keystrokes: 5,
+            mistakes: 2
+        }
+    );
+    assert_eq!(fingers.stats(Finger::RightIndex).keystrokes, 1);
+    assert_eq!(fingers.total_keystrokes(), 6);
+}
+
+#[test]
+fn key_load_serializes_as_a_map_by_key() {
+    let load = key_load(&[(':', 2, 1)]);
+    let json = serde_json::to_string(&load).unwrap();
+
+    assert_eq!(json, r#"{":":{"keystrokes":2,"mistakes":1}}"#);
+    assert_eq!(serde_json::from_str::<KeyLoad>(&json).unwrap(), load);
+}
+
+#[test]
+fn finger_load_serializes_as_a_map_by_finger() {
+    let load = finger_load(&[(Finger::RightMiddle, 2, 1)]);
+    let json = serde_json::to_string(&load).unwrap();
+
+    assert_eq!(json, r#"{"right_middle":{"keystrokes":2,"mistakes":1}}"#);
+    assert_eq!(serde_json::from_str::<FingerLoad>(&json).unwrap(), load);
+}
@@ -0,0 +1,69 @@
+use gittype::domain::models::storage::LanguageActivity;
+use gittype::domain::models::{LanguageCalibration, LanguageCalibrations};
+
+fn activity(language: &str, stages: usize, avg_wpm: f64) -> LanguageActivity {
+    LanguageActivity {
+        language: language.to_string(),
+        stages,
+        avg_wpm,
+        best_wpm: avg_wpm,
+        avg_accuracy: 95.0,
+        keystrokes: stages * 100,
+    }
+}
+
+#[test]
+fn measured_languages_are_calibrated_against_the_overall_pace() {
+    let calibrations = LanguageCalibrations::from_activity(&[
+        activity("go", 10, 60.0),
+        activity("haskell", 10, 40.0),
+    ]);
+
+    assert_eq!(calibrations.overall_wpm, Some(50.0));
+    let haskell = calibrations.for_language("haskell");
+    assert!(haskell.is_measured());
+    assert_eq!(haskell.factor, 0.8);
+    assert_eq!(haskell.normalize(40.0), 50.0);
+    assert_eq!(calibrations.for_language("go").factor, 1.2);
+}
+
+#[test]
+fn languages_with_few_stages_use_the_default_factor() {
+    let calibrations = LanguageCalibrations::from_activity(&[
+        activity("go", 10, 60.0),
+        activity("haskell", 2, 20.0),
+    ]);
+
+    let haskell = calibrations.for_language("haskell");
+    assert!(!haskell.is_measured());
+    assert_eq!(
+        haskell.factor,
+        LanguageCalibration::default_factor("haskell")
+    );
+    assert_eq!(haskell.baseline_wpm, Some(20.0));
+}
+
+#[test]
+fn unplayed_languages_fall_back_to_the_default_factor() {
+    let calibrations = LanguageCalibrations::from_activity(&[activity("go", 10, 60.0)]);
+
+    let clojure = calibrations.for_language("clojure");
+    assert_eq!(clojure.stages, 0);
+    assert_eq!(clojure.baseline_wpm, None);
+    assert_eq!(clojure.factor, 0.85);
+    assert_eq!(calibrations.for_language("Go").factor, 1.0);
+}
+
+#[test]
+fn expected_wpm_prefers_the_measured_baseline() {
+    let calibrations =
+        LanguageCalibrations::from_activity(&[activity("go", 10, 60.0), activity("rust", 5, 45.0)]);
+
+    assert_eq!(calibrations.expected_wpm("rust"), Some(45.0));
+    let overall = calibrations.overall_wpm.unwrap();
+    assert_eq!(
+        calibrations.expected_wpm("haskell"),
+        Some(overall * LanguageCalibration::default_factor("haskell"))
+    );
+    assert_eq!(LanguageCalibrations::default().expected_wpm("rust"), None);
+}
@@ -0,0 +1,28 @@
+use gittype::domain::models::{ChunkType, LanguageCapability};
+
+fn capability(name: &str, aliases: &[&str]) -> LanguageCapability {
+    LanguageCapability {
+        name: name.to_string(),
+        display_name: name.to_uppercase(),
+        aliases: aliases.iter().map(|a| a.to_string()).collect(),
+        extensions: vec!["x".to_string(), "xx".to_string()],
+        chunk_types: vec![ChunkType::Function, ChunkType::Macro],
+        has_middle_implementation: aliases.is_empty(),
+    }
+}
+
+#[test]
+fn render_lists_each_language_in_its_own_block() {
+    let rendered = LanguageCapability::render(&[capability("foo", &["f"]), capability("bar", &[])]);
+
+    assert_eq!(
+        rendered,
+        "FOO (foo)\n  Extensions:   .x, .xx\n  Aliases:      f\n  Chunk types:  Function, Macro\n  Inner blocks: no\n\n\
+         BAR (bar)\n  Extensions:   .x, .xx\n  Chunk types:  Function, Macro\n  Inner blocks: yes"
+    );
+}
+
+#[test]
+fn render_of_no_languages_is_empty() {
+    assert_eq!(LanguageCapability::render(&[]), "");
+}
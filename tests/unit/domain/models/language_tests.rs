@@ -1,6 +1,7 @@
 use gittype::domain::models::{Language, Languages};
 use ratatui::style::Color;
 use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
 struct MinimalLanguage;
@@ -251,3 +252,66 @@ fn all_languages_have_extensions() {
         );
     }
 }
+
+fn language_names(files: &[&str]) -> Vec<(String, &'static str)> {
+    let files: Vec<PathBuf> = files.iter().map(PathBuf::from).collect();
+    Languages::for_project_files(&files)
+        .into_iter()
+        .map(|(path, language)| (path.display().to_string(), language.name()))
+        .collect()
+}
+
+#[test]
+fn for_project_files_parses_headers_as_cpp_in_cpp_projects() {
+    assert_eq!(
+        language_names(&["src/widget.h", "src/widget.cpp", "README.md"]),
+        vec![
+            ("src/widget.h".to_string(), "cpp"),
+            ("src/widget.cpp".to_string(), "cpp"),
+        ]
+    );
+}
+
+#[test]
+fn for_project_files_keeps_headers_as_c_in_c_and_mixed_projects() {
+    assert_eq!(
+        language_names(&["lib.h", "lib.c"]),
+        vec![("lib.h".to_string(), "c"), ("lib.c".to_string(), "c")]
+    );
+    assert_eq!(
+        language_names(&["lib.h", "lib.c", "app.cc"])[0],
+        ("lib.h".to_string(), "c")
+    );
+    assert_eq!(
+        language_names(&["only.h"]),
+        vec![("only.h".to_string(), "c")]
+    );
+}
+
+#[test]
+fn refine_header_detects_cpp_syntax() {
+    let content =
+        "#pragma once\n\nnamespace ui {\nclass Widget {\npublic:\n    void draw();\n};\n}\n";
+    let c: Box<dyn Language> = Languages::from_extension("h").unwrap();
+    let language = Languages::refine_header(Path::new("widget.h"), content, c);
+    assert_eq!(language.name(), "cpp");
+}
+
+#[test]
+fn refine_header_treats_extern_c_headers_as_c() {
+    let content = "#ifdef __cplusplus\nextern \"C\" {\n#endif\nint add(int a, int b);\n#ifdef __cplusplus\n}\n#endif\n";
+    let cpp = Languages::get_by_name("cpp").unwrap();
+    let language = Languages::refine_header(Path::new("api.h"), content, cpp);
+    assert_eq!(language.name(), "c");
+}
+
+#[test]
+fn refine_header_leaves_other_files_alone() {
+    let cpp = Languages::get_by_name("cpp").unwrap();
+    let language = Languages::refine_header(Path::new("api.hpp"), "extern \"C\" {}", cpp);
+    assert_eq!(language.name(), "cpp");
+
+    let c = Languages::get_by_name("c").unwrap();
+    let language = Languages::refine_header(Path::new("plain.h"), "int add(int a, int b);", c);
+    assert_eq!(language.name(), "c");
+}
@@ -1,12 +1,14 @@
 use crate::fixtures::models::{challenge, git_repository};
+use gittype::domain::models::loading::ProgressReporter;
 use gittype::domain::models::loading::{CacheCheckStep, ExecutionContext, Step, StepResult};
-use gittype::domain::models::{Challenge, GitRepository};
+use gittype::domain::models::{
+    Challenge, ChallengePack, DifficultyLevel, ExtractionOptions, GitRepository,
+};
 use gittype::domain::repositories::challenge_repository::ChallengeRepositoryInterface;
 use gittype::domain::stores::{
     ChallengeStore, ChallengeStoreInterface, RepositoryStore, RepositoryStoreInterface,
     SessionStore, SessionStoreInterface,
 };
-use gittype::presentation::tui::screens::loading_screen::ProgressReporter;
 use gittype::{GitTypeError, Result};
 use std::sync::{Arc, Mutex};
 
@@ -88,6 +90,18 @@ impl ChallengeRepositoryInterface for MockChallengeRepository {
     fn list_cache_keys(&self) -> Result<Vec<String>> {
         Ok(vec![])
     }
+
+    fn count_cached_challenges(&self, _repo_key: &str) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn invalidate_repository_key(&self, _repo_key: &str) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn remove_stale_caches(&self) -> Result<(usize, u64)> {
+        Ok((0, 0))
+    }
 }
 
 fn create_context<'a>(
@@ -102,7 +116,9 @@ fn create_context<'a>(
         repo_path: None,
         extraction_options: None,
         loading_screen: None,
+        progress_reporter: None,
         challenge_repository,
+        challenge_flag_service: None,
         current_repo_path: None,
         git_repository,
         scanned_files: None,
@@ -222,3 +238,37 @@ fn execute_populates_stores_when_cache_hits() {
     assert_eq!(repository_store.get_repository(), Some(git_repository));
     assert!(session_store.is_loading_completed());
 }
+
+#[test]
+fn execute_loads_challenge_pack_without_cache_lookup() {
+    let challenges = vec![challenge::build(), challenge::build_with_id("packed-2")];
+    let options = ExtractionOptions {
+        pack: Some(ChallengePack {
+            format_version: 1,
+            source: "owner/repo".to_string(),
+            seed: 7,
+            difficulty: DifficultyLevel::Normal,
+            challenges: challenges.clone(),
+        }),
+        ..ExtractionOptions::default()
+    };
+    let repository = Arc::new(MockChallengeRepository::miss());
+    let challenge_store = Arc::new(ChallengeStore::new_for_test());
+    let session_store = Arc::new(SessionStore::new_for_test());
+    let mut context = create_context(
+        None,
+        Some(repository.clone() as Arc<dyn ChallengeRepositoryInterface>),
+        Some(challenge_store.clone() as Arc<dyn ChallengeStoreInterface>),
+        None,
+        Some(session_store.clone() as Arc<dyn SessionStoreInterface>),
+    );
+    context.extraction_options = Some(&options);
+
+    let result = CacheCheckStep.execute(&mut context).unwrap();
+
+    assert!(matches!(result, StepResult::Skipped));
+    assert_eq!(repository.load_calls(), 0);
+    assert!(context.cache_used);
+    assert_eq!(challenge_store.get_challenges(), Some(challenges));
+    assert!(session_store.is_loading_completed());
+}
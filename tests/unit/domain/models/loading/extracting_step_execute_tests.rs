@@ -1,11 +1,12 @@
 use gittype::domain::events::EventBus;
 use gittype::domain::models::color_mode::ColorMode;
-use gittype::domain::models::loading::{ExecutionContext, ExtractingStep, Step};
+use gittype::domain::models::loading::ProgressReporter;
+use gittype::domain::models::loading::{ExecutionContext, ExtractingStep, Step, StepResult};
 use gittype::domain::models::theme::Theme;
 use gittype::domain::models::{Challenge, ExtractionOptions, GitRepository};
 use gittype::domain::repositories::challenge_repository::ChallengeRepositoryInterface;
 use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
-use gittype::presentation::tui::screens::loading_screen::{LoadingScreen, ProgressReporter};
+use gittype::presentation::tui::screens::loading_screen::LoadingScreen;
 use gittype::{GitTypeError, Result};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -45,6 +46,18 @@ impl ChallengeRepositoryInterface for StubChallengeRepository {
     fn list_cache_keys(&self) -> Result<Vec<String>> {
         Ok(vec![])
     }
+
+    fn count_cached_challenges(&self, _repo_key: &str) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn invalidate_repository_key(&self, _repo_key: &str) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn remove_stale_caches(&self) -> Result<(usize, u64)> {
+        Ok((0, 0))
+    }
 }
 
 fn create_loading_screen() -> LoadingScreen {
@@ -70,7 +83,9 @@ fn create_context<'a>(
         repo_path: None,
         extraction_options,
         loading_screen,
+        progress_reporter: None,
         challenge_repository: None,
+        challenge_flag_service: None,
         current_repo_path: None,
         git_repository: None,
         scanned_files,
@@ -85,11 +100,7 @@ fn create_context<'a>(
 }
 
 fn fixture_path(file_name: &str) -> PathBuf {
-    std::env::current_dir()
-        .unwrap()
-        .join("tests")
-        .join("fixtures")
-        .join(file_name)
+    crate::fixtures::sources::path(file_name)
 }
 
 #[test]
@@ -137,7 +148,7 @@ fn execute_returns_no_supported_files_when_all_files_are_filtered_out() {
     let file_path = fixture_path("complex_commented_rust.rs");
     let screen = create_loading_screen();
     let options = ExtractionOptions {
-        max_file_size_bytes: 0,
+        max_file_size_kb: 0,
         ..ExtractionOptions::default()
     };
     let mut context = create_context(Some(&options), Some(&screen), Some(vec![file_path]));
@@ -149,7 +160,7 @@ fn execute_returns_no_supported_files_when_all_files_are_filtered_out() {
 
 #[test]
 fn execute_errors_when_scanned_files_have_no_supported_language() {
-    let file_path = std::env::current_dir().unwrap().join("Cargo.toml");
+    let file_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
     let screen = create_loading_screen();
     let options = ExtractionOptions::default();
     let mut context = create_context(Some(&options), Some(&screen), Some(vec![file_path]));
@@ -161,7 +172,7 @@ fn execute_errors_when_scanned_files_have_no_supported_language() {
 
 #[test]
 fn execute_errors_when_scanned_file_has_no_extension() {
-    let file_path = std::env::current_dir().unwrap().join("README");
+    let file_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("README");
     let screen = create_loading_screen();
     let options = ExtractionOptions::default();
     let mut context = create_context(Some(&options), Some(&screen), Some(vec![file_path]));
@@ -170,3 +181,27 @@ fn execute_errors_when_scanned_file_has_no_extension() {
 
     assert!(matches!(error, GitTypeError::ExtractionFailed(_)));
 }
+
+#[test]
+fn execute_builds_whole_file_chunks_for_snippets() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let snippet = dir.path().join("exercise.txt");
+    std::fs::write(&snippet, "The quick brown fox jumps over the lazy dog.\n").unwrap();
+    let screen = create_loading_screen();
+    let mut options = ExtractionOptions::default();
+    options.enable_snippets();
+    let mut context = create_context(Some(&options), Some(&screen), Some(vec![snippet]));
+    context.current_repo_path = Some(dir.path().to_path_buf());
+
+    let result = ExtractingStep.execute(&mut context).unwrap();
+
+    let StepResult::Chunks(chunks) = result else {
+        panic!("expected chunks, got {:?}", result);
+    };
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(chunks[0].file_path, PathBuf::from("exercise.txt"));
+    assert_eq!(
+        chunks[0].content,
+        "The quick brown fox jumps over the lazy dog."
+    );
+}
@@ -31,7 +31,9 @@ fn create_context<'a>(
         repo_path: None,
         extraction_options: None,
         loading_screen: None,
+        progress_reporter: None,
         challenge_repository: None,
+        challenge_flag_service: None,
         current_repo_path: None,
         git_repository: None,
         scanned_files: None,
@@ -1,6 +1,7 @@
 use crate::fixtures::models::git_repository;
 use gittype::domain::events::EventBus;
 use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::loading::ProgressReporter;
 use gittype::domain::models::loading::{ExecutionContext, GeneratingStep, Step, StepResult};
 use gittype::domain::models::theme::Theme;
 use gittype::domain::models::{Challenge, ChunkType, CodeChunk, GitRepository};
@@ -10,7 +11,7 @@ use gittype::domain::stores::{
     ChallengeStore, ChallengeStoreInterface, RepositoryStore, RepositoryStoreInterface,
     SessionStore, SessionStoreInterface,
 };
-use gittype::presentation::tui::screens::loading_screen::{LoadingScreen, ProgressReporter};
+use gittype::presentation::tui::screens::loading_screen::LoadingScreen;
 use gittype::{GitTypeError, Result};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
@@ -88,6 +89,18 @@ impl ChallengeRepositoryInterface for MockChallengeRepository {
     fn list_cache_keys(&self) -> Result<Vec<String>> {
         Ok(vec![])
     }
+
+    fn count_cached_challenges(&self, _repo_key: &str) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn invalidate_repository_key(&self, _repo_key: &str) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn remove_stale_caches(&self) -> Result<(usize, u64)> {
+        Ok((0, 0))
+    }
 }
 
 fn create_loading_screen() -> LoadingScreen {
@@ -131,7 +144,9 @@ fn create_context<'a>(
         repo_path: None,
         extraction_options: None,
         loading_screen,
+        progress_reporter: None,
         challenge_repository,
+        challenge_flag_service: None,
         current_repo_path: None,
         git_repository,
         scanned_files: None,
@@ -34,15 +34,15 @@ fn database_init_step_name() {
 }
 
 #[test]
-fn database_init_no_progress() {
-    assert!(!DatabaseInitStep.supports_progress());
-    assert_eq!(DatabaseInitStep.progress_unit(), "");
+fn database_init_supports_progress() {
+    assert!(DatabaseInitStep.supports_progress());
+    assert_eq!(DatabaseInitStep.progress_unit(), "migrations");
 }
 
 #[test]
 fn database_init_format_progress() {
-    let result = DatabaseInitStep.format_progress(0, 0, 0.0, '⠋');
-    assert!(result.contains("Initializing"));
+    let result = DatabaseInitStep.format_progress(2, 6, 2.0 / 6.0, '⠋');
+    assert_eq!(result, "⠋ 33.3% 2/6 migrations");
 }
 
 #[test]
@@ -5,5 +5,6 @@ pub mod extracting_step_execute_tests;
 pub mod finalizing_step_execute_tests;
 pub mod generating_step_execute_tests;
 pub mod loading_steps_tests;
+pub mod progress_reporter_tests;
 pub mod scanning_step_execute_tests;
 pub mod step_manager_tests;
@@ -0,0 +1,104 @@
+use gittype::domain::models::loading::{
+    CancellationToken, NoOpProgressReporter, ProgressReporter, StepType, SubStepReporter,
+};
+use gittype::GitTypeError;
+use std::sync::Mutex;
+
+#[derive(Default)]
+struct RecordingReporter {
+    counts: Mutex<Vec<(usize, usize)>>,
+    sub_steps: Mutex<Vec<Option<String>>>,
+    cancellation: CancellationToken,
+}
+
+impl ProgressReporter for RecordingReporter {
+    fn set_step(&self, _step_type: StepType) {}
+    fn set_current_file(&self, _file: Option<String>) {}
+    fn set_file_counts(
+        &self,
+        _step_type: StepType,
+        processed: usize,
+        total: usize,
+        _current_file: Option<String>,
+    ) {
+        self.counts.lock().unwrap().push((processed, total));
+    }
+    fn set_sub_step(&self, label: Option<String>) {
+        self.sub_steps.lock().unwrap().push(label);
+    }
+    fn is_cancelled(&self) -> bool {
+        self.cancellation.is_cancelled()
+    }
+}
+
+#[test]
+fn cancellation_token_is_shared_between_clones() {
+    let token = CancellationToken::new();
+    let observer = token.clone();
+
+    assert!(!observer.is_cancelled());
+    token.cancel();
+    assert!(observer.is_cancelled());
+}
+
+#[test]
+fn check_cancelled_fails_only_after_cancelling() {
+    let reporter = RecordingReporter::default();
+    assert!(reporter.check_cancelled().is_ok());
+
+    reporter.cancellation.cancel();
+    assert!(matches!(
+        reporter.check_cancelled(),
+        Err(GitTypeError::Cancelled)
+    ));
+}
+
+#[test]
+fn no_op_reporter_is_never_cancelled() {
+    assert!(!NoOpProgressReporter.is_cancelled());
+    assert!(NoOpProgressReporter.check_cancelled().is_ok());
+}
+
+#[test]
+fn sub_step_maps_its_progress_into_its_slice_of_the_step() {
+    let reporter = RecordingReporter::default();
+
+    SubStepReporter::new(&reporter, 0, 2).set_file_counts(StepType::Cloning, 5, 10, None);
+    SubStepReporter::new(&reporter, 1, 2).set_file_counts(StepType::Cloning, 5, 10, None);
+
+    assert_eq!(*reporter.counts.lock().unwrap(), vec![(5, 20), (15, 20)]);
+}
+
+#[test]
+fn nested_sub_steps_narrow_the_slice_further() {
+    let reporter = RecordingReporter::default();
+    let outer = SubStepReporter::new(&reporter, 1, 2);
+
+    SubStepReporter::new(&outer, 1, 2).set_file_counts(StepType::Extracting, 1, 4, None);
+
+    assert_eq!(*reporter.counts.lock().unwrap(), vec![(13, 16)]);
+}
+
+#[test]
+fn sub_step_forwards_labels_and_cancellation_to_its_parent() {
+    let reporter = RecordingReporter::default();
+    let sub_step = SubStepReporter::new(&reporter, 0, 2);
+
+    sub_step.set_sub_step(Some("Fetching".to_string()));
+    reporter.cancellation.cancel();
+
+    assert_eq!(
+        *reporter.sub_steps.lock().unwrap(),
+        vec![Some("Fetching".to_string())]
+    );
+    assert!(sub_step.is_cancelled());
+}
+
+#[test]
+fn sub_step_without_a_total_passes_counts_through() {
+    let reporter = RecordingReporter::default();
+
+    SubStepReporter::new(&reporter, 1, 2).set_file_counts(StepType::Scanning, 3, 0, None);
+
+    assert_eq!(*reporter.counts.lock().unwrap(), vec![(3, 0)]);
+}
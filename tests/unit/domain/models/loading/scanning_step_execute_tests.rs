@@ -1,12 +1,13 @@
 use git2::Repository;
 use gittype::domain::events::EventBus;
 use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::loading::ProgressReporter;
 use gittype::domain::models::loading::{ExecutionContext, ScanningStep, Step, StepResult};
 use gittype::domain::models::theme::Theme;
 use gittype::domain::models::{Challenge, GitRepository};
 use gittype::domain::repositories::challenge_repository::ChallengeRepositoryInterface;
 use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
-use gittype::presentation::tui::screens::loading_screen::{LoadingScreen, ProgressReporter};
+use gittype::presentation::tui::screens::loading_screen::LoadingScreen;
 use gittype::{GitTypeError, Result};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -46,6 +47,18 @@ impl ChallengeRepositoryInterface for StubChallengeRepository {
     fn list_cache_keys(&self) -> Result<Vec<String>> {
         Ok(vec![])
     }
+
+    fn count_cached_challenges(&self, _repo_key: &str) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn invalidate_repository_key(&self, _repo_key: &str) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn remove_stale_caches(&self) -> Result<(usize, u64)> {
+        Ok((0, 0))
+    }
 }
 
 fn create_loading_screen() -> LoadingScreen {
@@ -71,7 +84,9 @@ fn create_context<'a>(
         repo_path,
         extraction_options: None,
         loading_screen,
+        progress_reporter: None,
         challenge_repository: None,
+        challenge_flag_service: None,
         current_repo_path,
         git_repository: None,
         scanned_files: None,
@@ -137,6 +152,7 @@ fn execute_keeps_existing_git_repository_without_repo_path() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: Some(repo_path),
+        license: None,
     };
     context.git_repository = Some(existing_repository.clone());
 
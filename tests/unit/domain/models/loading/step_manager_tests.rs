@@ -1,7 +1,9 @@
 use crate::fixtures::models::{challenge, git_repository};
 use gittype::domain::events::{EventBus, EventBusInterface};
 use gittype::domain::models::color_mode::ColorMode;
-use gittype::domain::models::loading::{ExecutionContext, StepManager, StepType};
+use gittype::domain::models::loading::{
+    CancellationToken, ExecutionContext, ProgressReporter, StepManager, StepType,
+};
 use gittype::domain::models::theme::Theme;
 use gittype::domain::models::{Challenge, GitRepository};
 use gittype::domain::repositories::challenge_repository::ChallengeRepositoryInterface;
@@ -15,8 +17,9 @@ use gittype::domain::stores::{
     ChallengeStore, ChallengeStoreInterface, RepositoryStore, RepositoryStoreInterface,
     SessionStore, SessionStoreInterface,
 };
-use gittype::presentation::tui::screens::loading_screen::{LoadingScreen, ProgressReporter};
+use gittype::presentation::tui::screens::loading_screen::LoadingScreen;
 use gittype::{GitTypeError, Result};
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
 #[test]
@@ -31,6 +34,26 @@ fn default_creates_same_as_new() {
     assert_eq!(manager.get_all_steps().len(), 7);
 }
 
+#[test]
+fn headless_clones_and_extracts_without_database_or_finalizing() {
+    let manager = StepManager::headless();
+    let types: Vec<StepType> = manager
+        .get_all_steps()
+        .iter()
+        .map(|s| s.step_type())
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            StepType::Cloning,
+            StepType::CacheCheck,
+            StepType::Scanning,
+            StepType::Extracting,
+            StepType::Generating,
+        ]
+    );
+}
+
 #[test]
 fn steps_are_in_correct_order() {
     let manager = StepManager::new();
@@ -193,6 +216,18 @@ impl ChallengeRepositoryInterface for MockChallengeRepository {
     fn list_cache_keys(&self) -> Result<Vec<String>> {
         Ok(vec![])
     }
+
+    fn count_cached_challenges(&self, _repo_key: &str) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn invalidate_repository_key(&self, _repo_key: &str) -> Result<usize> {
+        Ok(0)
+    }
+
+    fn remove_stale_caches(&self) -> Result<(usize, u64)> {
+        Ok((0, 0))
+    }
 }
 
 struct TestServices {
@@ -240,13 +275,15 @@ fn create_services() -> TestServices {
 
 #[test]
 fn execute_pipeline_propagates_scanning_error_without_loading_screen() {
-    let repo_path = std::env::current_dir().unwrap();
+    let repo_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     let mut context = ExecutionContext {
         repo_spec: None,
         repo_path: Some(&repo_path),
         extraction_options: None,
         loading_screen: None,
+        progress_reporter: None,
         challenge_repository: None,
+        challenge_flag_service: None,
         current_repo_path: None,
         git_repository: None,
         scanned_files: None,
@@ -289,9 +326,11 @@ fn execute_pipeline_skips_remaining_steps_after_cache_hit() {
         repo_path: None,
         extraction_options: None,
         loading_screen: Some(&screen),
+        progress_reporter: None,
         challenge_repository: Some(
             challenge_repository.clone() as Arc<dyn ChallengeRepositoryInterface>
         ),
+        challenge_flag_service: None,
         current_repo_path: None,
         git_repository: Some(git_repository::build()),
         scanned_files: None,
@@ -329,3 +368,97 @@ fn execute_pipeline_skips_remaining_steps_after_cache_hit() {
             > 0
     );
 }
+
+#[derive(Default)]
+struct RecordingReporter {
+    steps: Mutex<Vec<StepType>>,
+    cancellation: CancellationToken,
+}
+
+impl ProgressReporter for RecordingReporter {
+    fn set_step(&self, step_type: StepType) {
+        self.steps.lock().unwrap().push(step_type);
+    }
+    fn set_current_file(&self, _file: Option<String>) {}
+    fn set_file_counts(
+        &self,
+        _step_type: StepType,
+        _processed: usize,
+        _total: usize,
+        _current_file: Option<String>,
+    ) {
+    }
+    fn is_cancelled(&self) -> bool {
+        self.cancellation.is_cancelled()
+    }
+}
+
+#[test]
+fn execute_pipeline_reports_to_progress_reporter_without_loading_screen() {
+    let cached_challenges = vec![challenge::build()];
+    let challenge_repository = Arc::new(MockChallengeRepository::hit(cached_challenges.clone()));
+    let challenge_store = Arc::new(ChallengeStore::new_for_test());
+    let reporter = RecordingReporter::default();
+    let mut context = ExecutionContext {
+        repo_spec: None,
+        repo_path: None,
+        extraction_options: None,
+        loading_screen: None,
+        progress_reporter: Some(&reporter),
+        challenge_repository: Some(challenge_repository as Arc<dyn ChallengeRepositoryInterface>),
+        challenge_flag_service: None,
+        current_repo_path: None,
+        git_repository: Some(git_repository::build()),
+        scanned_files: None,
+        chunks: None,
+        cache_used: false,
+        challenge_store: Some(challenge_store.clone() as Arc<dyn ChallengeStoreInterface>),
+        repository_store: None,
+        session_store: None,
+        stage_repository: None,
+        session_manager: None,
+    };
+
+    StepManager::headless()
+        .execute_pipeline(&mut context)
+        .unwrap();
+
+    assert!(context.cache_used);
+    assert_eq!(
+        *reporter.steps.lock().unwrap(),
+        vec![StepType::Cloning, StepType::CacheCheck]
+    );
+    assert_eq!(challenge_store.get_challenges(), Some(cached_challenges));
+}
+
+#[test]
+fn execute_pipeline_stops_before_the_next_step_once_cancelled() {
+    let challenge_repository = Arc::new(MockChallengeRepository::hit(vec![challenge::build()]));
+    let reporter = RecordingReporter::default();
+    reporter.cancellation.clone().cancel();
+    let mut context = ExecutionContext {
+        repo_spec: None,
+        repo_path: None,
+        extraction_options: None,
+        loading_screen: None,
+        progress_reporter: Some(&reporter),
+        challenge_repository: Some(challenge_repository as Arc<dyn ChallengeRepositoryInterface>),
+        challenge_flag_service: None,
+        current_repo_path: None,
+        git_repository: Some(git_repository::build()),
+        scanned_files: None,
+        chunks: None,
+        cache_used: false,
+        challenge_store: None,
+        repository_store: None,
+        session_store: None,
+        stage_repository: None,
+        session_manager: None,
+    };
+
+    let result = StepManager::headless().execute_pipeline(&mut context);
+
+    assert!(matches!(result, Err(GitTypeError::Cancelled)));
+    assert!(reporter.steps.lock().unwrap().is_empty());
+    assert!(!context.cache_used);
+}
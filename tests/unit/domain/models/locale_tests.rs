@@ -0,0 +1,66 @@
+use chrono::NaiveDate;
+use gittype::domain::models::{Locale, LocalePreset};
+
+fn sample() -> chrono::NaiveDateTime {
+    NaiveDate::from_ymd_opt(2024, 3, 7)
+        .unwrap()
+        .and_hms_opt(13, 5, 9)
+        .unwrap()
+}
+
+#[test]
+fn format_count_groups_thousands() {
+    assert_eq!(Locale::EN_US.format_count(1234567), "1,234,567");
+    assert_eq!(Locale::DE.format_count(1234), "1.234");
+    assert_eq!(Locale::FR.format_count(999), "999");
+    assert_eq!(Locale::ISO.format_count(1234567), "1234567");
+}
+
+#[test]
+fn format_decimal_uses_locale_separators() {
+    assert_eq!(Locale::EN_US.format_decimal(12345.67, 1), "12,345.7");
+    assert_eq!(Locale::DE.format_decimal(12345.67, 1), "12.345,7");
+    assert_eq!(Locale::FR.format_decimal(-1234.5, 0), "-1 234");
+    assert_eq!(Locale::ISO.format_decimal(98.25, 2), "98.25");
+}
+
+#[test]
+fn format_date_follows_date_order() {
+    let date = sample().date();
+    assert_eq!(Locale::EN_US.format_date(date), "03/07/2024");
+    assert_eq!(Locale::EN_GB.format_date(date), "07/03/2024");
+    assert_eq!(Locale::DE.format_date(date), "07.03.2024");
+    assert_eq!(Locale::JA.format_date(date), "2024/03/07");
+    assert_eq!(Locale::ISO.format_date(date), "2024-03-07");
+}
+
+#[test]
+fn format_datetime_uses_twelve_or_twenty_four_hours() {
+    assert_eq!(
+        Locale::EN_US.format_datetime(sample(), false),
+        "03/07/2024 1:05 PM"
+    );
+    assert_eq!(
+        Locale::ISO.format_datetime(sample(), true),
+        "2024-03-07 13:05:09"
+    );
+}
+
+#[test]
+fn from_tag_maps_posix_locale_names() {
+    assert_eq!(Locale::from_tag("en_US.UTF-8"), Locale::EN_US);
+    assert_eq!(Locale::from_tag("en_GB.UTF-8"), Locale::EN_GB);
+    assert_eq!(Locale::from_tag("de_AT"), Locale::DE);
+    assert_eq!(Locale::from_tag("fr-CA"), Locale::FR);
+    assert_eq!(Locale::from_tag("ja_JP.eucJP"), Locale::JA);
+    assert_eq!(Locale::from_tag("C.UTF-8"), Locale::ISO);
+    assert_eq!(Locale::from_tag("POSIX"), Locale::ISO);
+    assert_eq!(Locale::from_tag("ko_KR.UTF-8").language, "en");
+}
+
+#[test]
+fn presets_resolve_to_their_conventions() {
+    assert_eq!(LocalePreset::EnUs.resolve(), Locale::EN_US);
+    assert_eq!(LocalePreset::Iso.resolve(), Locale::ISO);
+    assert_eq!(LocalePreset::default(), LocalePreset::System);
+}
@@ -0,0 +1,53 @@
+use gittype::domain::models::{LogEntry, LogLevel};
+
+#[test]
+fn parse_splits_formatted_line() {
+    let entry = LogEntry::parse(
+        "2026-05-01 10:00:02 [WARN] gittype::domain::services::source_code_parser - Failed to parse src/a.rs - skipping",
+    );
+
+    assert_eq!(entry.timestamp.as_deref(), Some("2026-05-01 10:00:02"));
+    assert_eq!(entry.level, Some(LogLevel::Warn));
+    assert_eq!(
+        entry.target.as_deref(),
+        Some("gittype::domain::services::source_code_parser")
+    );
+    assert_eq!(entry.message, "Failed to parse src/a.rs - skipping");
+}
+
+#[test]
+fn parse_keeps_unformatted_line_as_message() {
+    let entry = LogEntry::parse("Caused by: SQLITE_BUSY [retry] later");
+
+    assert_eq!(entry.level, None);
+    assert_eq!(entry.timestamp, None);
+    assert_eq!(entry.message, "Caused by: SQLITE_BUSY [retry] later");
+}
+
+#[test]
+fn filter_by_level_keeps_severe_entries_and_their_continuations() {
+    let entries: Vec<LogEntry> = [
+        "2026-05-01 10:00:00 [INFO] gittype - started",
+        "2026-05-01 10:00:01 [ERROR] gittype - failed",
+        "Caused by: disk full",
+        "2026-05-01 10:00:02 [WARN] gittype - retrying",
+        "2026-05-01 10:00:03 [DEBUG] gittype - details",
+        "more details",
+    ]
+    .iter()
+    .map(|line| LogEntry::parse(line))
+    .collect();
+
+    let warnings = LogEntry::filter_by_level(&entries, LogLevel::Warn);
+    let messages: Vec<&str> = warnings.iter().map(|e| e.message.as_str()).collect();
+    assert_eq!(messages, vec!["failed", "Caused by: disk full", "retrying"]);
+
+    assert_eq!(
+        LogEntry::filter_by_level(&entries, LogLevel::Error).len(),
+        2
+    );
+    assert_eq!(
+        LogEntry::filter_by_level(&entries, LogLevel::Trace).len(),
+        6
+    );
+}
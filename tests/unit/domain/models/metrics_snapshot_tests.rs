@@ -0,0 +1,81 @@
+use chrono::{Duration, NaiveDate};
+use gittype::domain::models::storage::{DailyActivity, LanguageActivity};
+use gittype::domain::models::MetricsSnapshot;
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 5, 10).unwrap()
+}
+
+fn day(days_ago: i64, sessions: usize, keystrokes: usize) -> DailyActivity {
+    DailyActivity {
+        date: today() - Duration::days(days_ago),
+        sessions,
+        avg_wpm: 60.0,
+        best_wpm: 70.0,
+        avg_accuracy: 95.0,
+        keystrokes,
+    }
+}
+
+fn rust() -> LanguageActivity {
+    LanguageActivity {
+        language: "rust".to_string(),
+        stages: 12,
+        avg_wpm: 72.456,
+        best_wpm: 91.0,
+        avg_accuracy: 96.5,
+        keystrokes: 4200,
+    }
+}
+
+#[test]
+fn new_sums_sessions_and_keystrokes_across_days() {
+    let activity = vec![day(0, 2, 300), day(1, 1, 150), day(5, 3, 400)];
+
+    let snapshot = MetricsSnapshot::new(&activity, vec![rust()], today());
+
+    assert_eq!(snapshot.sessions, 6);
+    assert_eq!(snapshot.keystrokes, 850);
+    assert_eq!(snapshot.practice_days, 3);
+    assert_eq!(snapshot.streak, 2);
+    assert_eq!(snapshot.languages, vec![rust()]);
+}
+
+#[test]
+fn to_prometheus_renders_counters_and_language_gauges() {
+    let snapshot = MetricsSnapshot::new(&[day(0, 2, 300)], vec![rust()], today());
+
+    let output = snapshot.to_prometheus();
+
+    assert!(output.contains("# TYPE gittype_sessions_total counter\ngittype_sessions_total 2\n"));
+    assert!(output.contains("gittype_keystrokes_total 300\n"));
+    assert!(output.contains("# TYPE gittype_streak_days gauge\ngittype_streak_days 1\n"));
+    assert!(output.contains("gittype_language_wpm{language=\"rust\"} 72.46\n"));
+    assert!(output.contains("gittype_language_best_wpm{language=\"rust\"} 91.00\n"));
+    assert!(output.contains("gittype_language_stages_total{language=\"rust\"} 12\n"));
+    assert!(output.contains("gittype_language_keystrokes_total{language=\"rust\"} 4200\n"));
+}
+
+#[test]
+fn to_prometheus_escapes_label_values() {
+    let snapshot = MetricsSnapshot {
+        languages: vec![LanguageActivity {
+            language: "c\"sharp\\".to_string(),
+            ..rust()
+        }],
+        ..MetricsSnapshot::default()
+    };
+
+    assert!(snapshot
+        .to_prometheus()
+        .contains("gittype_language_wpm{language=\"c\\\"sharp\\\\\"} 72.46\n"));
+}
+
+#[test]
+fn to_prometheus_keeps_metric_families_without_languages() {
+    let output = MetricsSnapshot::default().to_prometheus();
+
+    assert!(output.contains("gittype_sessions_total 0\n"));
+    assert!(output.contains("# TYPE gittype_language_wpm gauge\n"));
+    assert!(!output.contains("{language="));
+}
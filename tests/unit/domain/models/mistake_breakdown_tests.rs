@@ -0,0 +1,128 @@
+use gittype::domain::models::{MistakeBreakdown, MistakeKind};
+
+#[test]
+fn wrong_case_letter_is_a_case_mistake() {
+    assert_eq!(MistakeKind::classify('a', 'A', None), MistakeKind::Case);
+    assert_eq!(MistakeKind::classify('Q', 'q', None), MistakeKind::Case);
+}
+
+#[test]
+fn same_key_with_wrong_shift_is_a_shifted_symbol() {
+    assert_eq!(
+        MistakeKind::classify(':', ';', None),
+        MistakeKind::ShiftedSymbol
+    );
+    assert_eq!(
+        MistakeKind::classify('1', '!', None),
+        MistakeKind::ShiftedSymbol
+    );
+}
+
+#[test]
+fn typing_the_next_character_early_is_a_transposition() {
+    assert_eq!(
+        MistakeKind::classify('e', 't', Some('t')),
+        MistakeKind::Transposition
+    );
+}
+
+#[test]
+fn neighbouring_key_is_an_adjacent_key_slip() {
+    assert_eq!(
+        MistakeKind::classify('s', 'd', None),
+        MistakeKind::AdjacentKey
+    );
+    assert_eq!(
+        MistakeKind::classify('g', 't', None),
+        MistakeKind::AdjacentKey
+    );
+    assert_eq!(
+        MistakeKind::classify('a', 'Z', None),
+        MistakeKind::AdjacentKey
+    );
+}
+
+#[test]
+fn distant_or_unknown_keys_are_other_mistakes() {
+    assert_eq!(MistakeKind::classify('a', 'p', None), MistakeKind::Other);
+    assert_eq!(MistakeKind::classify('a', 'c', None), MistakeKind::Other);
+    assert_eq!(MistakeKind::classify(' ', 'x', None), MistakeKind::Other);
+    assert_eq!(MistakeKind::classify('é', 'e', None), MistakeKind::Other);
+}
+
+#[test]
+fn breakdown_counts_recorded_kinds() {
+    let mut breakdown = MistakeBreakdown::default();
+    breakdown.record(MistakeKind::AdjacentKey);
+    breakdown.record(MistakeKind::AdjacentKey);
+    breakdown.record(MistakeKind::Other);
+
+    assert_eq!(breakdown.adjacent_key, 2);
+    assert_eq!(breakdown.other, 1);
+    assert_eq!(breakdown.total(), 3);
+    assert!(!breakdown.is_empty());
+    assert!(MistakeBreakdown::default().is_empty());
+}
+
+#[test]
+fn merge_adds_counts() {
+    let a = MistakeBreakdown {
+        adjacent_key: 1,
+        case: 2,
+        ..Default::default()
+    };
+    let b = MistakeBreakdown {
+        case: 1,
+        transposition: 4,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        a.merge(&b),
+        MistakeBreakdown {
+            adjacent_key: 1,
+            case: 3,
+            shifted_symbol: 0,
+            transposition: 4,
+            other: 0,
+        }
+    );
+}
+
+#[test]
+fn most_common_ignores_other_and_prefers_the_first_kind_on_ties() {
+    let breakdown = MistakeBreakdown {
+        case: 2,
+        shifted_symbol: 2,
+        other: 9,
+        ..Default::default()
+    };
+    assert_eq!(breakdown.most_common(), Some(MistakeKind::Case));
+
+    let only_other = MistakeBreakdown {
+        other: 3,
+        ..Default::default()
+    };
+    assert_eq!(only_other.most_common(), None);
+}
+
+#[test]
+fn breakdown_round_trips_through_json_and_tolerates_missing_kinds() {
+    let breakdown = MistakeBreakdown {
+        adjacent_key: 1,
+        transposition: 2,
+        ..Default::default()
+    };
+    let json = serde_json::to_string(&breakdown).unwrap();
+    assert_eq!(
+        serde_json::from_str::<MistakeBreakdown>(&json).unwrap(),
+        breakdown
+    );
+    assert_eq!(
+        serde_json::from_str::<MistakeBreakdown>(r#"{"case":3}"#).unwrap(),
+        MistakeBreakdown {
+            case: 3,
+            ..Default::default()
+        }
+    );
+}
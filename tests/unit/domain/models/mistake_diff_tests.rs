@@ -0,0 +1,65 @@
+use gittype::domain::models::{DiffChar, MistakeDiff};
+
+const CODE: &str = "fn add(a: i32, b: i32) -> i32 {\n    a + b\n}";
+
+#[test]
+fn no_mistakes_produce_an_empty_diff() {
+    let diff = MistakeDiff::new(CODE, &[]);
+
+    assert!(diff.is_empty());
+    assert!(diff.lines.is_empty());
+    assert!(diff.costly_tokens.is_empty());
+}
+
+#[test]
+fn only_lines_with_mistakes_are_kept() {
+    let diff = MistakeDiff::new(CODE, &[(38, '-')]);
+
+    assert_eq!(diff.lines.len(), 1);
+    assert_eq!(diff.lines[0].line_number, 2);
+    assert_eq!(
+        diff.lines[0].chars[6],
+        DiffChar {
+            expected: '+',
+            mistyped: Some('-')
+        }
+    );
+    assert_eq!(diff.lines[0].chars[0].mistyped, None);
+}
+
+#[test]
+fn first_wrong_character_is_annotated() {
+    let diff = MistakeDiff::new(CODE, &[(4, 's'), (4, 'f')]);
+
+    assert_eq!(diff.total_mistakes, 2);
+    assert_eq!(diff.lines[0].chars[4].mistyped, Some('s'));
+}
+
+#[test]
+fn costly_tokens_count_mistakes_per_identifier() {
+    let diff = MistakeDiff::new(CODE, &[(4, 's'), (5, 'f'), (38, '-'), (10, 'u')]);
+
+    assert_eq!(
+        diff.costly_tokens,
+        vec![
+            ("add".to_string(), 2),
+            ("+".to_string(), 1),
+            ("i32".to_string(), 1),
+        ]
+    );
+}
+
+#[test]
+fn whitespace_mistakes_are_shown_but_not_counted_as_tokens() {
+    let diff = MistakeDiff::new(CODE, &[(2, 'x')]);
+
+    assert_eq!(diff.lines.len(), 1);
+    assert!(diff.costly_tokens.is_empty());
+}
+
+#[test]
+fn mistakes_past_the_text_are_ignored() {
+    let diff = MistakeDiff::new(CODE, &[(999, 'x')]);
+
+    assert!(diff.is_empty());
+}
@@ -1,19 +1,58 @@
+pub mod achievement_tests;
 pub mod ascii_rank_titles_tests;
+pub mod challenge_pack_tests;
+pub mod challenge_preview_tests;
 pub mod challenge_tests;
+pub mod changelog_tests;
 pub mod color_scheme_tests;
 pub mod config_tests;
 pub mod countdown_tests;
+pub mod demo_tests;
 pub mod difficulty_level_tests;
+pub mod drill_tests;
+pub mod editor_config_tests;
+pub mod error_report_tests;
 pub mod extraction_options_tests;
+pub mod failure_analysis_tests;
 pub mod git_repository_ref_tests;
 pub mod git_repository_tests;
+pub mod history_file_tests;
+pub mod input_latency_tests;
+pub mod keyboard_layout_tests;
+pub mod keymap_tests;
+pub mod keystroke_load_tests;
+pub mod language_calibration_tests;
+pub mod language_capability_tests;
 pub mod language_tests;
 pub mod languages;
 pub mod loading;
+pub mod locale_tests;
+pub mod log_entry_tests;
+pub mod metrics_snapshot_tests;
+pub mod mistake_breakdown_tests;
+pub mod mistake_diff_tests;
+pub mod player_profile_tests;
+pub mod practice_calendar_tests;
+pub mod presence_tests;
+pub mod race_bot_tests;
 pub mod rank_colors_tests;
 pub mod rank_tests;
+pub mod reminder_tests;
+pub mod repository_detail_tests;
+pub mod repository_report_tests;
+pub mod result_plausibility_tests;
+pub mod result_verification_tests;
+pub mod scan_report_tests;
 pub mod session_tests;
+pub mod source_study_tests;
 pub mod stage_tests;
+pub mod status_summary_tests;
 pub mod storage;
+pub mod streamer_tests;
+pub mod telemetry_tests;
 pub mod theme_tests;
 pub mod total_tests;
+pub mod typing_baseline_tests;
+pub mod version_tests;
+pub mod warm_progress_tests;
+pub mod wpm_mode_tests;
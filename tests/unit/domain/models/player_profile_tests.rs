@@ -0,0 +1,135 @@
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use gittype::domain::models::player_profile::DEFAULT_DISPLAY_NAME;
+use gittype::domain::models::storage::StoredStageResult;
+use gittype::domain::models::PlayerProfile;
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 5, 10).unwrap()
+}
+
+fn day(offset: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 5, 10, 12, 0, 0).unwrap() + Duration::days(offset)
+}
+
+fn stage(completed_at: DateTime<Utc>, language: &str, score: f64) -> StoredStageResult {
+    StoredStageResult {
+        id: 1,
+        repository_id: Some(1),
+        repository_name: Some("repo".to_string()),
+        user_name: Some("user".to_string()),
+        wpm: 50.0,
+        cpm: 250.0,
+        accuracy: 95.0,
+        keystrokes: 100,
+        mistakes: 5,
+        duration_ms: 60_000,
+        score,
+        language: Some(language.to_string()),
+        difficulty_level: Some("Normal".to_string()),
+        completed_at,
+        rank_name: None,
+        tier_name: None,
+    }
+}
+
+#[test]
+fn empty_history_uses_defaults() {
+    let profile = PlayerProfile::from_history(None, &[], today());
+
+    assert_eq!(profile.display_name, DEFAULT_DISPLAY_NAME);
+    assert_eq!(profile.rank_title, None);
+    assert_eq!(profile.favorite_language, None);
+    assert_eq!(profile.current_streak_days, 0);
+    assert_eq!(profile.stages_completed, 0);
+}
+
+#[test]
+fn blank_display_name_falls_back_to_default() {
+    let profile = PlayerProfile::from_history(Some("   "), &[], today());
+
+    assert_eq!(profile.display_name, DEFAULT_DISPLAY_NAME);
+}
+
+#[test]
+fn favorite_language_is_the_most_played() {
+    let stages = vec![
+        stage(day(0), "Rust", 100.0),
+        stage(day(0), "go", 100.0),
+        stage(day(0), "rust", 100.0),
+    ];
+    let profile = PlayerProfile::from_history(Some("Ada"), &stages, today());
+
+    assert_eq!(profile.favorite_language.as_deref(), Some("rust"));
+    assert_eq!(profile.favorite_language_display().as_deref(), Some("Rust"));
+}
+
+#[test]
+fn favorite_language_ties_break_alphabetically() {
+    let stages = vec![stage(day(0), "rust", 100.0), stage(day(0), "go", 100.0)];
+    let profile = PlayerProfile::from_history(None, &stages, today());
+
+    assert_eq!(profile.favorite_language.as_deref(), Some("go"));
+}
+
+#[test]
+fn rank_title_follows_best_score() {
+    let stages = vec![stage(day(0), "rust", 10.0), stage(day(0), "rust", 9000.0)];
+    let profile = PlayerProfile::from_history(None, &stages, today());
+
+    assert_eq!(profile.best_score, 9000.0);
+    assert_eq!(
+        profile.rank_title.as_deref(),
+        Some(gittype::domain::models::Rank::for_score(9000.0).name())
+    );
+}
+
+#[test]
+fn streak_counts_consecutive_days_ending_today() {
+    let stages = vec![
+        stage(day(0), "rust", 100.0),
+        stage(day(-1), "rust", 100.0),
+        stage(day(-2), "rust", 100.0),
+        stage(day(-4), "rust", 100.0),
+    ];
+    let profile = PlayerProfile::from_history(None, &stages, today());
+
+    assert_eq!(profile.current_streak_days, 3);
+}
+
+#[test]
+fn streak_survives_until_a_full_day_is_missed() {
+    let yesterday = vec![stage(day(-1), "rust", 100.0), stage(day(-2), "rust", 100.0)];
+    let lapsed = vec![stage(day(-2), "rust", 100.0)];
+
+    assert_eq!(
+        PlayerProfile::from_history(None, &yesterday, today()).current_streak_days,
+        2
+    );
+    assert_eq!(
+        PlayerProfile::from_history(None, &lapsed, today()).current_streak_days,
+        0
+    );
+}
+
+#[test]
+fn initials_use_first_two_words() {
+    let initials = |name: &str| PlayerProfile::from_history(Some(name), &[], today()).initials();
+
+    assert_eq!(initials("ada lovelace byron"), "AL");
+    assert_eq!(initials("octocat"), "O");
+}
+
+#[test]
+fn share_signature_skips_missing_parts() {
+    let empty = PlayerProfile::from_history(Some("Ada"), &[], today());
+    let played = PlayerProfile::from_history(Some("Ada"), &[stage(day(0), "rust", 0.0)], today());
+
+    assert_eq!(empty.share_signature(), "— Ada");
+    assert_eq!(
+        played.share_signature(),
+        format!(
+            "— Ada · {} · Rust · 🔥 1-day streak",
+            played.rank_title.as_deref().unwrap()
+        )
+    );
+}
@@ -0,0 +1,136 @@
+use chrono::{DateTime, TimeZone, Utc};
+use gittype::domain::models::storage::{SessionResultData, StoredRepository, StoredSession};
+use gittype::domain::models::{PracticeCalendar, PracticeEvent};
+
+fn at(hour: u32, minute: u32, second: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 5, 10, hour, minute, second)
+        .unwrap()
+}
+
+fn event() -> PracticeEvent {
+    PracticeEvent {
+        session_id: 42,
+        started_at: at(9, 30, 0),
+        duration_ms: 192_500,
+        repository: Some("rust-lang/rust".to_string()),
+        score: 1234.4,
+        wpm: 72.46,
+        accuracy: 96.5,
+        stages_completed: 3,
+        rank_name: Some("Hacker".to_string()),
+    }
+}
+
+#[test]
+fn new_builds_event_from_stored_session() {
+    let session = StoredSession {
+        id: 7,
+        repository_id: Some(1),
+        started_at: at(9, 30, 0),
+        completed_at: None,
+        branch: None,
+        commit_hash: None,
+        is_dirty: false,
+        game_mode: "normal".to_string(),
+        difficulty_level: None,
+        max_stages: None,
+        time_limit_seconds: None,
+        player_name: None,
+        unverified: false,
+    };
+    let repository = StoredRepository {
+        id: 1,
+        user_name: "rust-lang".to_string(),
+        repository_name: "rust".to_string(),
+        remote_url: "https://github.com/rust-lang/rust".to_string(),
+        license: None,
+    };
+    let result = SessionResultData {
+        keystrokes: 300,
+        mistakes: 5,
+        duration_ms: 60_000,
+        wpm: 60.0,
+        cpm: 300.0,
+        accuracy: 98.3,
+        stages_completed: 2,
+        stages_attempted: 2,
+        stages_skipped: 0,
+        score: 500.0,
+        rank_name: None,
+        tier_name: None,
+        rank_position: None,
+        rank_total: None,
+        position: None,
+        total: None,
+    };
+
+    let event = PracticeEvent::new(&session, Some(&repository), &result);
+
+    assert_eq!(event.session_id, 7);
+    assert_eq!(event.repository.as_deref(), Some("rust-lang/rust"));
+    assert_eq!(event.ended_at(), at(9, 31, 0));
+}
+
+#[test]
+fn summary_falls_back_without_repository() {
+    let mut event = event();
+    assert_eq!(event.summary(), "gittype: rust-lang/rust");
+
+    event.repository = None;
+    assert_eq!(event.summary(), "gittype practice");
+}
+
+#[test]
+fn description_lists_duration_and_score() {
+    assert_eq!(
+        event().description(),
+        "Duration: 3m 12s\nScore: 1234\nWPM: 72.5\nAccuracy: 96.5%\nStages: 3\nRank: Hacker"
+    );
+}
+
+fn calendar(events: &[PracticeEvent], generated_at: DateTime<Utc>) -> String {
+    let mut ics = PracticeCalendar::ics_header();
+    for event in events {
+        ics.push_str(&PracticeCalendar::ics_event(event, generated_at));
+    }
+    ics.push_str(&PracticeCalendar::ics_footer());
+    ics
+}
+
+#[test]
+fn ics_has_one_event_per_session() {
+    let mut second = event();
+    second.session_id = 43;
+
+    let ics = calendar(&[event(), second], at(12, 0, 0));
+
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+    assert!(ics.contains("UID:session-42@gittype\r\n"));
+    assert!(ics.contains("DTSTAMP:20240510T120000Z\r\n"));
+    assert!(ics.contains("DTSTART:20240510T093000Z\r\n"));
+    assert!(ics.contains("DTEND:20240510T093312Z\r\n"));
+    assert!(ics.contains("SUMMARY:gittype: rust-lang/rust\r\n"));
+    assert!(ics.contains("DESCRIPTION:Duration: 3m 12s\\nScore: 1234\\nWPM: 72.5"));
+}
+
+#[test]
+fn ics_event_escapes_text_and_folds_long_lines() {
+    let mut event = event();
+    event.repository = Some(format!("a,b;c/{}", "x".repeat(80)));
+
+    let ics = calendar(&[event], at(12, 0, 0));
+
+    assert!(ics.contains("SUMMARY:gittype: a\\,b\\;c/"));
+    assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+    assert!(ics.contains("\r\n x"));
+}
+
+#[test]
+fn ics_without_sessions_is_an_empty_calendar() {
+    let ics = calendar(&[], at(12, 0, 0));
+
+    assert!(!ics.contains("VEVENT"));
+    assert!(ics.contains("PRODID:-//gittype//Practice History//EN\r\n"));
+}
@@ -0,0 +1,49 @@
+use gittype::domain::models::Presence;
+
+fn presence() -> Presence {
+    Presence {
+        language: Some("rust".to_string()),
+        repository: Some("tokio".to_string()),
+        current_stage: 3,
+        total_stages: 5,
+        wpm: 72.4,
+    }
+}
+
+#[test]
+fn summary_shows_language_repository_stage_and_wpm() {
+    assert_eq!(
+        presence().summary(),
+        "Typing rust in tokio — Stage 3/5, 72 WPM"
+    );
+}
+
+#[test]
+fn details_fall_back_when_language_or_repository_is_unknown() {
+    let without_repo = Presence {
+        repository: None,
+        ..presence()
+    };
+    let without_language = Presence {
+        language: None,
+        ..presence()
+    };
+
+    assert_eq!(without_repo.details(), "Typing rust");
+    assert_eq!(without_language.details(), "Typing code in tokio");
+}
+
+#[test]
+fn wpm_change_alone_is_not_a_new_activity() {
+    let faster = Presence {
+        wpm: 90.0,
+        ..presence()
+    };
+    let next_stage = Presence {
+        current_stage: 4,
+        ..presence()
+    };
+
+    assert!(!faster.is_new_activity(&presence()));
+    assert!(next_stage.is_new_activity(&presence()));
+}
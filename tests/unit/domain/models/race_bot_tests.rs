@@ -0,0 +1,107 @@
+use gittype::domain::models::config::Config;
+use gittype::domain::models::{RaceBot, WpmMode};
+use std::time::Duration;
+
+#[test]
+fn parse_accepts_presets_and_speed_specs() {
+    let pro = RaceBot::parse("Pro").unwrap();
+    assert_eq!(pro.name, "Pro");
+    assert_eq!((pro.wpm, pro.accuracy), (100.0, 98.0));
+
+    let custom = RaceBot::parse(" 70@96% ").unwrap();
+    assert_eq!(custom.name, "Bot");
+    assert_eq!((custom.wpm, custom.accuracy), (70.0, 96.0));
+
+    assert_eq!(RaceBot::parse("55").unwrap().accuracy, 100.0);
+}
+
+#[test]
+fn parse_rejects_unknown_names_and_out_of_range_profiles() {
+    let error = RaceBot::parse("grandmaster").unwrap_err().to_string();
+    assert!(error.contains("novice, casual, fast, pro, legend"));
+
+    assert!(RaceBot::parse("0@90").is_err());
+    assert!(RaceBot::parse("60@0").is_err());
+    assert!(RaceBot::parse("60@101").is_err());
+}
+
+#[test]
+fn position_follows_the_speed_and_stops_at_the_end() {
+    let bot = RaceBot::parse("60").unwrap();
+    let text = "x".repeat(100);
+
+    assert_eq!(bot.position(&text, Duration::ZERO, WpmMode::Characters), 0);
+    assert_eq!(
+        bot.position(&text, Duration::from_secs(10), WpmMode::Characters),
+        50
+    );
+    assert_eq!(
+        bot.position(&text, Duration::from_secs(60), WpmMode::Characters),
+        100
+    );
+}
+
+#[test]
+fn position_slows_down_for_mistakes() {
+    let bot = RaceBot::parse("60@90").unwrap();
+    let text = "x".repeat(100);
+
+    // Each character costs 1.1 keystrokes of 0.2s plus 0.1 of a 0.3s pause
+    assert_eq!(
+        bot.position(&text, Duration::from_secs(11), WpmMode::Characters),
+        44
+    );
+}
+
+#[test]
+fn position_in_token_mode_scales_by_token_density() {
+    let bot = RaceBot::parse("60").unwrap();
+
+    // 4 tokens in 5 characters: 75 characters a minute
+    assert_eq!(
+        bot.position("ab(c)", Duration::from_secs(2), WpmMode::Tokens),
+        2
+    );
+}
+
+#[test]
+fn label_shows_the_profile_in_the_current_unit() {
+    let bot = RaceBot::parse("casual").unwrap();
+
+    assert_eq!(bot.label(WpmMode::Characters), "Casual 45 WPM · 95%");
+    assert_eq!(bot.label(WpmMode::Tokens), "Casual 45 TPM · 95%");
+}
+
+#[test]
+fn config_bots_default_to_none_and_round_trip() {
+    let mut json = serde_json::to_value(Config::default()).unwrap();
+    assert!(json["race"].as_object().unwrap().is_empty());
+
+    json["race"] = serde_json::json!({
+        "bots": [{ "name": "Rival", "wpm": 65, "accuracy": 97 }]
+    });
+    let config: Config = serde_json::from_value(json).unwrap();
+
+    assert_eq!(
+        config.race.bots,
+        vec![RaceBot::new("Rival", 65.0, 97.0).unwrap()]
+    );
+}
+
+#[test]
+fn ghost_finishes_when_the_attempt_did() {
+    let ghost = RaceBot::ghost(48.0, 90.0, Duration::from_secs(40));
+    let text = "x".repeat(160);
+
+    assert!(ghost.is_ghost());
+    assert!(!RaceBot::parse("pro").unwrap().is_ghost());
+    assert_eq!(
+        ghost.position(&text, Duration::from_secs(10), WpmMode::Characters),
+        40
+    );
+    assert_eq!(
+        ghost.position(&text, Duration::from_secs(40), WpmMode::Tokens),
+        160
+    );
+    assert_eq!(ghost.label(WpmMode::Characters), "Ghost 48 WPM · 90%");
+}
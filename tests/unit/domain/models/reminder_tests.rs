@@ -0,0 +1,77 @@
+use gittype::domain::models::config::{Config, ReminderConfig};
+use gittype::domain::models::Reminder;
+
+fn at(time: &str) -> Reminder {
+    Reminder::parse(time).unwrap()
+}
+
+#[test]
+fn parse_accepts_24_hour_times() {
+    let reminder = at("21:05");
+    assert_eq!((reminder.hour(), reminder.minute()), (21, 5));
+    assert_eq!(at(" 9:00 ").label(), "09:00");
+}
+
+#[test]
+fn parse_rejects_invalid_times() {
+    for invalid in ["", "9", "24:00", "09:60", "9am", "09:00:00"] {
+        let error = Reminder::parse(invalid).unwrap_err().to_string();
+        assert!(
+            error.contains("use 24-hour HH:MM"),
+            "{}: {}",
+            invalid,
+            error
+        );
+    }
+}
+
+#[test]
+fn task_name_is_unique_per_time() {
+    assert_eq!(at("09:00").task_name(), "gittype-reminder-0900");
+    assert_eq!(at("21:30").task_name(), "gittype-reminder-2130");
+}
+
+#[test]
+fn notification_body_mentions_the_streak() {
+    assert!(!Reminder::notification_body(0).contains("streak"));
+    assert!(Reminder::notification_body(1).contains("1-day streak"));
+    assert!(Reminder::notification_body(12).contains("12-day streak"));
+}
+
+#[test]
+fn reminders_are_stored_as_hh_mm() {
+    let mut config = Config::default();
+    config.reminders.add(at("9:00"));
+
+    let json = serde_json::to_value(&config).unwrap();
+    assert_eq!(json["reminders"]["at"], serde_json::json!(["09:00"]));
+
+    let restored: Config = serde_json::from_value(json).unwrap();
+    assert_eq!(restored.reminders.at, vec![at("09:00")]);
+}
+
+#[test]
+fn invalid_stored_reminder_fails_to_load() {
+    let result = serde_json::from_str::<ReminderConfig>(r#"{"at": ["25:00"]}"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn add_keeps_reminders_sorted_and_unique() {
+    let mut reminders = ReminderConfig::default();
+    assert!(reminders.add(at("18:00")));
+    assert!(reminders.add(at("09:00")));
+    assert!(!reminders.add(at("18:00")));
+
+    assert_eq!(reminders.at, vec![at("09:00"), at("18:00")]);
+}
+
+#[test]
+fn remove_reports_whether_the_reminder_was_set() {
+    let mut reminders = ReminderConfig::default();
+    reminders.add(at("09:00"));
+
+    assert!(!reminders.remove(at("10:00")));
+    assert!(reminders.remove(at("09:00")));
+    assert!(reminders.at.is_empty());
+}
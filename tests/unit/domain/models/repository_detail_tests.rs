@@ -0,0 +1,68 @@
+use chrono::{DateTime, TimeZone, Utc};
+use gittype::domain::models::storage::{StoredRepositoryWithLanguages, StoredStageResult};
+use gittype::domain::models::RepositoryDetail;
+
+fn repository() -> StoredRepositoryWithLanguages {
+    StoredRepositoryWithLanguages {
+        id: 7,
+        user_name: "owner".to_string(),
+        repository_name: "repo".to_string(),
+        remote_url: "https://github.com/owner/repo".to_string(),
+        languages: vec!["rust".to_string()],
+        metadata: None,
+        last_played_at: None,
+        rating: None,
+    }
+}
+
+fn stage(completed_at: DateTime<Utc>, wpm: f64, accuracy: f64, score: f64) -> StoredStageResult {
+    StoredStageResult {
+        id: 1,
+        repository_id: Some(7),
+        repository_name: Some("repo".to_string()),
+        user_name: Some("owner".to_string()),
+        wpm,
+        cpm: wpm * 5.0,
+        accuracy,
+        keystrokes: 100,
+        mistakes: 2,
+        duration_ms: 60_000,
+        score,
+        language: Some("rust".to_string()),
+        difficulty_level: Some("Normal".to_string()),
+        completed_at,
+        rank_name: None,
+        tier_name: None,
+    }
+}
+
+#[test]
+fn from_history_takes_best_of_each_metric_and_latest_play() {
+    let earlier = Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap();
+    let later = Utc.with_ymd_and_hms(2024, 5, 3, 18, 0, 0).unwrap();
+    let stages = vec![
+        stage(later, 55.0, 99.0, 900.0),
+        stage(earlier, 72.5, 91.0, 1200.0),
+    ];
+
+    let detail = RepositoryDetail::from_history(repository(), true, &stages, Vec::new(), 42);
+
+    assert_eq!(detail.stages_completed, 2);
+    assert_eq!(detail.best_wpm, 72.5);
+    assert_eq!(detail.best_accuracy, 99.0);
+    assert_eq!(detail.best_score, 1200.0);
+    assert_eq!(detail.last_played_at, Some(later));
+    assert_eq!(detail.cached_challenges, 42);
+    assert!(detail.is_cached);
+    assert_eq!(detail.repo_spec(), "owner/repo");
+}
+
+#[test]
+fn from_history_without_stages_is_never_played() {
+    let detail = RepositoryDetail::from_history(repository(), false, &[], Vec::new(), 0);
+
+    assert_eq!(detail.stages_completed, 0);
+    assert_eq!(detail.best_wpm, 0.0);
+    assert_eq!(detail.best_score, 0.0);
+    assert_eq!(detail.last_played_at, None);
+}
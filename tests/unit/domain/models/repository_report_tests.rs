@@ -0,0 +1,83 @@
+use chrono::{DateTime, TimeZone, Utc};
+use gittype::domain::models::{PracticeEvent, RepositoryReport};
+
+fn at(day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 5, day, 9, 30, 0).unwrap()
+}
+
+fn session(day: u32, score: f64, wpm: f64, accuracy: f64) -> PracticeEvent {
+    PracticeEvent {
+        session_id: day as i64,
+        started_at: at(day),
+        duration_ms: 192_500,
+        repository: Some("rust-lang/rust".to_string()),
+        score,
+        wpm,
+        accuracy,
+        stages_completed: 3,
+        rank_name: Some("Hacker".to_string()),
+    }
+}
+
+#[test]
+fn new_orders_sessions_oldest_first() {
+    let report = RepositoryReport::new(
+        "rust-lang/rust",
+        vec![session(3, 1.0, 1.0, 1.0), session(1, 1.0, 1.0, 1.0)],
+    );
+
+    assert_eq!(report.sessions[0].session_id, 1);
+    assert_eq!(report.sessions[1].session_id, 3);
+}
+
+#[test]
+fn sparkline_scales_between_lowest_and_highest_value() {
+    assert_eq!(RepositoryReport::sparkline(&[40.0, 50.0, 60.0]), "▁▅█");
+    assert_eq!(RepositoryReport::sparkline(&[50.0, 50.0]), "▄▄");
+    assert_eq!(RepositoryReport::sparkline(&[]), "");
+}
+
+#[test]
+fn markdown_lists_bests_trend_and_history_newest_first() {
+    let report = RepositoryReport::new(
+        "rust-lang/rust",
+        vec![
+            session(1, 900.0, 60.0, 99.0),
+            session(2, 1200.0, 55.0, 95.0),
+            session(3, 1000.0, 72.5, 97.0),
+        ],
+    );
+
+    let markdown = report.to_markdown(at(10));
+
+    assert!(markdown.starts_with("# gittype report: rust-lang/rust\n"));
+    assert!(markdown.contains("_Generated 2024-05-10 09:30 UTC · 3 sessions_"));
+    assert!(markdown.contains("| Score | 1200 | 2024-05-02 |"));
+    assert!(markdown.contains("| WPM | 72.5 | 2024-05-03 |"));
+    assert!(markdown.contains("| Accuracy | 99.0% | 2024-05-01 |"));
+    assert!(markdown.contains("(55.0–72.5)"));
+    assert!(markdown.contains("```\n▃▁█\n```"));
+    assert!(markdown.contains("| 2024-05-03 09:30 | 3m 12s | 1000 | 72.5 | 97.0% | 3 | Hacker |"));
+    let newest = markdown.find("| 2024-05-03 09:30").unwrap();
+    let oldest = markdown.find("| 2024-05-01 09:30").unwrap();
+    assert!(newest < oldest);
+}
+
+#[test]
+fn markdown_without_sessions_says_so() {
+    let markdown = RepositoryReport::new("rust-lang/rust", Vec::new()).to_markdown(at(10));
+
+    assert!(markdown.contains("No sessions recorded for this repository yet."));
+    assert!(!markdown.contains("## History"));
+}
+
+#[test]
+fn markdown_credits_the_repository_and_its_license() {
+    let unlicensed = RepositoryReport::new("rust-lang/rust", Vec::new()).to_markdown(at(10));
+    let licensed = RepositoryReport::new("rust-lang/rust", Vec::new())
+        .with_license(Some("MIT OR Apache-2.0".to_string()))
+        .to_markdown(at(10));
+
+    assert!(unlicensed.contains("_Code by rust-lang/rust contributors_"));
+    assert!(licensed.contains("_Code by rust-lang/rust contributors, MIT OR Apache-2.0 license_"));
+}
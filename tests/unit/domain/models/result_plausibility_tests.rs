@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use gittype::domain::models::{ImplausibilityReason, ResultPlausibility};
+
+/// `count` keystrokes `gap_ms` apart, alternating `jitter_ms` early and late
+fn keystrokes(count: usize, gap_ms: u64, jitter_ms: u64) -> Vec<Duration> {
+    (0..count as u64)
+        .map(|i| {
+            let at = i * gap_ms;
+            Duration::from_millis(if i % 2 == 0 { at + jitter_ms } else { at })
+        })
+        .collect()
+}
+
+#[test]
+fn human_typing_is_verified() {
+    let plausibility = ResultPlausibility::assess(&[keystrokes(200, 150, 40)], 0);
+
+    assert!(plausibility.is_verified());
+    assert_eq!(plausibility.label(), None);
+}
+
+#[test]
+fn sustained_speed_above_300_wpm_is_unverified() {
+    // 30ms a keystroke is 400 WPM
+    let plausibility = ResultPlausibility::assess(&[keystrokes(120, 30, 10)], 0);
+
+    assert_eq!(
+        plausibility.reasons(),
+        &[ImplausibilityReason::SustainedSpeed]
+    );
+}
+
+#[test]
+fn short_bursts_of_speed_do_not_count_as_sustained() {
+    let plausibility = ResultPlausibility::assess(&[keystrokes(30, 30, 10)], 0);
+
+    assert!(plausibility.is_verified());
+}
+
+#[test]
+fn evenly_spaced_keystrokes_are_unverified() {
+    let plausibility = ResultPlausibility::assess(&[keystrokes(40, 150, 0)], 0);
+
+    assert_eq!(
+        plausibility.reasons(),
+        &[ImplausibilityReason::UniformRhythm]
+    );
+}
+
+#[test]
+fn pasting_marks_the_result_unverified() {
+    let plausibility = ResultPlausibility::assess(&[keystrokes(200, 150, 40)], 1);
+
+    assert_eq!(plausibility.reasons(), &[ImplausibilityReason::Pasted]);
+    assert_eq!(
+        plausibility.label().as_deref(),
+        Some("Unverified (pasted text)")
+    );
+}
+
+#[test]
+fn label_lists_every_reason() {
+    let plausibility = ResultPlausibility::assess(&[keystrokes(100, 20, 0)], 1);
+
+    assert_eq!(
+        plausibility.label().as_deref(),
+        Some("Unverified (sustained >300 WPM, machine-even keystrokes, pasted text)")
+    );
+}
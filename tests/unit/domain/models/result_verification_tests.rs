@@ -0,0 +1,74 @@
+use gittype::domain::models::{ResultVerification, SessionResult};
+use gittype::GitTypeError;
+
+fn result(score: f64, cpm: f64, mistakes: usize) -> SessionResult {
+    let mut result = SessionResult::new();
+    result.session_score = score;
+    result.overall_cpm = cpm;
+    result.valid_mistakes = mistakes;
+    result.invalid_mistakes = 1;
+    result
+}
+
+#[test]
+fn code_round_trips_the_shared_figures() {
+    let verification =
+        ResultVerification::new(&result(4321.4, 384.6, 11), "Stage 1\na:0:1:120;", 7);
+    let code = verification.code();
+
+    assert!(code.starts_with("GT1-4321-385-12-00000007"));
+    assert_eq!(ResultVerification::parse(&code).unwrap(), verification);
+}
+
+#[test]
+fn code_is_an_hmac_sha256_of_the_payload() {
+    assert_eq!(
+        ResultVerification::new(&SessionResult::new(), "log", 3).code(),
+        "GT1-0-0-0-00000003836ff184-02adeec9fb0b"
+    );
+}
+
+#[test]
+fn code_depends_on_keystroke_log_and_seed() {
+    let metrics = result(100.0, 200.0, 0);
+    let code = ResultVerification::new(&metrics, "a:0:1:120;", 1).code();
+
+    assert_eq!(
+        ResultVerification::new(&metrics, "a:0:1:120;", 1).code(),
+        code
+    );
+    assert_ne!(
+        ResultVerification::new(&metrics, "a:0:1:121;", 1).code(),
+        code
+    );
+    assert_ne!(
+        ResultVerification::new(&metrics, "a:0:1:120;", 2).code(),
+        code
+    );
+}
+
+#[test]
+fn parse_rejects_edited_figures() {
+    let code = ResultVerification::new(&result(100.0, 200.0, 3), "log", 1).code();
+    let edited = code.replacen("GT1-100-", "GT1-900-", 1);
+
+    assert!(matches!(
+        ResultVerification::parse(&edited),
+        Err(GitTypeError::ValidationError(message)) if message.contains("does not match")
+    ));
+}
+
+#[test]
+fn parse_rejects_malformed_codes() {
+    for code in [
+        "",
+        "GT1-1-2-3",
+        "XX1-1-2-3-0000000000000000-000000000000",
+        "GT1-a-2-3-0000000000000000-000000000000",
+    ] {
+        assert!(matches!(
+            ResultVerification::parse(code),
+            Err(GitTypeError::ValidationError(message)) if message.starts_with("Not a gittype verification code")
+        ));
+    }
+}
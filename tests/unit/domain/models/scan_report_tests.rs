@@ -0,0 +1,116 @@
+use gittype::domain::models::{Challenge, ChunkType, CodeChunk, DifficultyLevel, ScanReport};
+use std::path::{Path, PathBuf};
+
+fn chunk(file: &str, chunk_type: ChunkType) -> CodeChunk {
+    CodeChunk {
+        content: "fn main() {}".to_string(),
+        file_path: PathBuf::from(file),
+        start_line: 1,
+        end_line: 1,
+        language: "rust".to_string(),
+        chunk_type,
+        name: "main".to_string(),
+        comment_ranges: vec![],
+        original_indentation: 0,
+    }
+}
+
+fn challenge(difficulty: DifficultyLevel) -> Challenge {
+    Challenge::new("id".to_string(), "fn main() {}".to_string()).with_difficulty_level(difficulty)
+}
+
+fn files() -> Vec<PathBuf> {
+    ["/repo/src/main.rs", "/repo/src/lib.rs", "/repo/app.py"]
+        .iter()
+        .map(PathBuf::from)
+        .collect()
+}
+
+#[test]
+fn new_counts_files_chunks_and_challenges() {
+    let chunks = vec![
+        chunk("src/main.rs", ChunkType::Function),
+        chunk("src/main.rs", ChunkType::File),
+        chunk("app.py", ChunkType::Function),
+    ];
+    let challenges = vec![
+        challenge(DifficultyLevel::Easy),
+        challenge(DifficultyLevel::Wild),
+        challenge(DifficultyLevel::Wild),
+    ];
+
+    let report = ScanReport::new(Path::new("/repo"), &files(), &chunks, &challenges);
+
+    assert_eq!(
+        report.files_per_language,
+        vec![("rust".to_string(), 2), ("python".to_string(), 1)]
+    );
+    assert_eq!(
+        report.chunks_per_type,
+        vec![("Function".to_string(), 2), ("File".to_string(), 1)]
+    );
+    assert_eq!(
+        report.challenges_per_difficulty,
+        vec![
+            (DifficultyLevel::Easy, 1),
+            (DifficultyLevel::Normal, 0),
+            (DifficultyLevel::Hard, 0),
+            (DifficultyLevel::Wild, 2),
+            (DifficultyLevel::Zen, 0),
+        ]
+    );
+    assert_eq!(report.files_without_chunks, 1);
+    assert_eq!(report.total_files(), 3);
+    assert_eq!(report.total_chunks(), 3);
+    assert_eq!(report.total_challenges(), 3);
+}
+
+#[test]
+fn render_lists_sections_without_hint_when_playable() {
+    let chunks = vec![chunk("src/main.rs", ChunkType::Function)];
+    let challenges = vec![challenge(DifficultyLevel::Easy)];
+
+    let text = ScanReport::new(Path::new("/repo"), &files(), &chunks, &challenges).render();
+
+    assert!(text.starts_with("Scan report for /repo"));
+    assert!(text.contains("Files found: 3"));
+    assert!(text.contains("  rust                 2"));
+    assert!(text.contains("Code chunks: 1 (2 files produced none)"));
+    assert!(text.contains("Estimated challenges: 1"));
+    assert!(text.contains("  Easy                 1"));
+    assert!(!text.contains("Flagged as bad"));
+    assert!(!text.contains("No supported source files"));
+}
+
+#[test]
+fn render_includes_flagged_count() {
+    let chunks = vec![chunk("src/main.rs", ChunkType::Function)];
+    let challenges = vec![challenge(DifficultyLevel::Easy)];
+
+    let text = ScanReport::new(Path::new("/repo"), &files(), &chunks, &challenges)
+        .with_flagged_challenges(1)
+        .render();
+
+    assert!(text.contains("Flagged as bad: 1 (excluded from games)"));
+}
+
+#[test]
+fn render_hints_when_no_files_found() {
+    let text = ScanReport::new(Path::new("/repo"), &[], &[], &[]).render();
+    assert!(text.ends_with(
+        "No supported source files found. Check the path, --langs, and any .gittypeignore or exclude patterns."
+    ));
+}
+
+#[test]
+fn render_hints_when_no_chunks_extracted() {
+    let text = ScanReport::new(Path::new("/repo"), &files(), &[], &[]).render();
+    assert!(text.contains("Files were found but none could be parsed."));
+}
+
+#[test]
+fn render_hints_when_no_challenges_generated() {
+    let chunks = vec![chunk("src/main.rs", ChunkType::Function)];
+    let text = ScanReport::new(Path::new("/repo"), &files(), &chunks, &[]).render();
+    assert!(text.contains("every chunk was too short or too long"));
+}
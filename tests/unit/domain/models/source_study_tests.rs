@@ -0,0 +1,40 @@
+use gittype::domain::models::SourceStudy;
+
+fn study(line_count: usize, start_line: usize, end_line: usize) -> SourceStudy {
+    SourceStudy {
+        path: "src/lib.rs".to_string(),
+        lines: (1..=line_count).map(|n| format!("line {}", n)).collect(),
+        start_line,
+        end_line,
+    }
+}
+
+#[test]
+fn is_challenge_line_covers_start_to_end_inclusive() {
+    let study = study(20, 5, 8);
+
+    assert!(!study.is_challenge_line(4));
+    assert!(study.is_challenge_line(5));
+    assert!(study.is_challenge_line(8));
+    assert!(!study.is_challenge_line(9));
+}
+
+#[test]
+fn initial_offset_keeps_context_above_the_challenge() {
+    assert_eq!(study(40, 20, 25).initial_offset(5), 14);
+}
+
+#[test]
+fn initial_offset_stops_at_the_top_of_the_file() {
+    assert_eq!(study(40, 3, 5).initial_offset(5), 0);
+}
+
+#[test]
+fn initial_offset_never_passes_the_last_line() {
+    assert_eq!(study(4, 30, 32).initial_offset(0), 3);
+}
+
+#[test]
+fn max_offset_is_zero_for_an_empty_file() {
+    assert_eq!(study(0, 1, 1).max_offset(), 0);
+}
@@ -0,0 +1,81 @@
+use chrono::{Duration, NaiveDate};
+use gittype::domain::models::storage::DailyActivity;
+use gittype::domain::models::StatusSummary;
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 5, 10).unwrap()
+}
+
+fn day(days_ago: i64, avg_wpm: f64) -> DailyActivity {
+    DailyActivity {
+        date: today() - Duration::days(days_ago),
+        sessions: 2,
+        avg_wpm,
+        best_wpm: avg_wpm + 10.0,
+        avg_accuracy: 96.4,
+        keystrokes: 500,
+    }
+}
+
+#[test]
+fn streak_counts_consecutive_days_ending_today() {
+    let activity = vec![day(0, 60.0), day(1, 55.0), day(2, 50.0), day(4, 40.0)];
+
+    let summary = StatusSummary::from_daily_activity(&activity, today());
+
+    assert_eq!(summary.streak, 3);
+    assert_eq!(summary.today_sessions, 2);
+    assert_eq!(summary.today_wpm, 60.0);
+}
+
+#[test]
+fn streak_survives_until_today_is_played() {
+    let activity = vec![day(1, 55.0), day(2, 50.0)];
+
+    let summary = StatusSummary::from_daily_activity(&activity, today());
+
+    assert_eq!(summary.streak, 2);
+    assert_eq!(summary.today_sessions, 0);
+    assert_eq!(summary.today_wpm, 0.0);
+}
+
+#[test]
+fn streak_is_broken_after_a_missed_day() {
+    let activity = vec![day(2, 50.0), day(3, 45.0)];
+
+    let summary = StatusSummary::from_daily_activity(&activity, today());
+
+    assert_eq!(summary.streak, 0);
+}
+
+#[test]
+fn empty_activity_yields_default_summary() {
+    let summary = StatusSummary::from_daily_activity(&[], today());
+
+    assert_eq!(summary, StatusSummary::default());
+}
+
+#[test]
+fn render_replaces_known_placeholders() {
+    let summary = StatusSummary::from_daily_activity(&[day(0, 72.4)], today());
+
+    let line = summary.render(
+        "{streak}d {today_wpm}/{today_best_wpm}wpm {today_accuracy}% {today_sessions}x {today_keystrokes}",
+    );
+
+    assert_eq!(line, "1d 72/82wpm 96% 2x 500");
+}
+
+#[test]
+fn render_leaves_unknown_placeholders_untouched() {
+    let summary = StatusSummary::default();
+
+    assert_eq!(summary.render("{streak} {unknown}"), "0 {unknown}");
+}
+
+#[test]
+fn default_format_renders_streak_and_wpm() {
+    let summary = StatusSummary::from_daily_activity(&[day(0, 72.0)], today());
+
+    assert_eq!(summary.render(StatusSummary::DEFAULT_FORMAT), "🔥1 ⌨ 72wpm");
+}
@@ -1,5 +1,6 @@
+use chrono::{Duration, Utc};
 use gittype::domain::models::storage::repository::{
-    StoredRepository, StoredRepositoryWithLanguages,
+    RepositoryMetadata, RepositoryRating, StoredRepository, StoredRepositoryWithLanguages,
 };
 
 #[test]
@@ -9,6 +10,7 @@ fn stored_repository_clone() {
         user_name: "testuser".to_string(),
         repository_name: "testrepo".to_string(),
         remote_url: "https://github.com/testuser/testrepo.git".to_string(),
+        license: None,
     };
 
     let cloned = repo.clone();
@@ -25,6 +27,9 @@ fn stored_repository_with_languages_clone() {
         user_name: "testuser".to_string(),
         repository_name: "testrepo".to_string(),
         remote_url: "https://github.com/testuser/testrepo.git".to_string(),
+        metadata: None,
+        last_played_at: None,
+        rating: None,
         languages: vec!["Rust".to_string(), "Python".to_string()],
     };
 
@@ -40,6 +45,9 @@ fn http_url_with_valid_github_url() {
         user_name: "testuser".to_string(),
         repository_name: "testrepo".to_string(),
         remote_url: "github.com/testuser/testrepo".to_string(),
+        metadata: None,
+        last_played_at: None,
+        rating: None,
         languages: vec![],
     };
 
@@ -58,6 +66,9 @@ fn http_url_with_already_formatted_url() {
         user_name: "testuser".to_string(),
         repository_name: "testrepo".to_string(),
         remote_url: "https://github.com/testuser/testrepo.git".to_string(),
+        metadata: None,
+        last_played_at: None,
+        rating: None,
         languages: vec![],
     };
 
@@ -74,6 +85,9 @@ fn http_url_with_invalid_format_returns_original() {
         user_name: "testuser".to_string(),
         repository_name: "testrepo".to_string(),
         remote_url: "invalid-url".to_string(),
+        metadata: None,
+        last_played_at: None,
+        rating: None,
         languages: vec![],
     };
 
@@ -88,6 +102,9 @@ fn stored_repository_with_languages_has_empty_languages() {
         user_name: "testuser".to_string(),
         repository_name: "testrepo".to_string(),
         remote_url: "https://github.com/testuser/testrepo.git".to_string(),
+        metadata: None,
+        last_played_at: None,
+        rating: None,
         languages: vec![],
     };
 
@@ -101,6 +118,9 @@ fn stored_repository_with_languages_has_multiple_languages() {
         user_name: "testuser".to_string(),
         repository_name: "testrepo".to_string(),
         remote_url: "https://github.com/testuser/testrepo.git".to_string(),
+        metadata: None,
+        last_played_at: None,
+        rating: None,
         languages: vec![
             "Rust".to_string(),
             "Python".to_string(),
@@ -113,3 +133,41 @@ fn stored_repository_with_languages_has_multiple_languages() {
     assert!(repo.languages.contains(&"Python".to_string()));
     assert!(repo.languages.contains(&"JavaScript".to_string()));
 }
+
+fn metadata(stars: u64, primary_language: Option<&str>) -> RepositoryMetadata {
+    RepositoryMetadata {
+        description: None,
+        stars,
+        default_branch: Some("main".to_string()),
+        primary_language: primary_language.map(str::to_string),
+        fetched_at: Utc::now(),
+    }
+}
+
+#[test]
+fn repository_metadata_is_fresh_within_ttl() {
+    let metadata = metadata(1, None);
+    assert!(metadata.is_fresh(Utc::now()));
+    assert!(!metadata.is_fresh(Utc::now() + Duration::hours(RepositoryMetadata::TTL_HOURS)));
+}
+
+#[test]
+fn repository_metadata_stars_label_is_compact() {
+    assert_eq!(metadata(987, None).stars_label(), "★ 987");
+    assert_eq!(metadata(12_345, None).stars_label(), "★ 12.3k");
+    assert_eq!(metadata(2_500_000, None).stars_label(), "★ 2.5M");
+}
+
+#[test]
+fn repository_metadata_summary_includes_language_when_known() {
+    assert_eq!(metadata(42, Some("Rust")).summary(), "★ 42 · Rust");
+    assert_eq!(metadata(42, None).summary(), "★ 42");
+}
+
+#[test]
+fn repository_rating_label_shows_average_and_count() {
+    let rating = |average, count| RepositoryRating { average, count };
+
+    assert_eq!(rating(4.0, 1).label(), "4.0/5 (1 rating)");
+    assert_eq!(rating(3.666, 3).label(), "3.7/5 (3 ratings)");
+}
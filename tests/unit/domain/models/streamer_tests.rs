@@ -0,0 +1,26 @@
+use gittype::domain::models::StreamerStats;
+
+#[test]
+fn render_writes_one_metric_per_line() {
+    let stats = StreamerStats {
+        wpm: 72.6,
+        accuracy: 97.4,
+    };
+
+    assert_eq!(stats.render(), "WPM: 73\nAccuracy: 97%\n");
+}
+
+#[test]
+fn render_defaults_to_zero() {
+    assert_eq!(StreamerStats::default().render(), "WPM: 0\nAccuracy: 0%\n");
+}
+
+#[test]
+fn render_clamps_out_of_range_values() {
+    let stats = StreamerStats {
+        wpm: -3.0,
+        accuracy: 120.0,
+    };
+
+    assert_eq!(stats.render(), "WPM: 0\nAccuracy: 100%\n");
+}
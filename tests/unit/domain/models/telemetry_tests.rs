@@ -0,0 +1,118 @@
+use chrono::NaiveDate;
+use gittype::domain::models::config::TelemetryConfig;
+use gittype::domain::models::storage::{DailyActivity, LanguageActivity};
+use gittype::domain::models::TelemetryReport;
+use std::collections::BTreeMap;
+
+fn day(sessions: usize) -> DailyActivity {
+    DailyActivity {
+        date: NaiveDate::from_ymd_opt(2024, 5, 10).unwrap(),
+        sessions,
+        avg_wpm: 60.0,
+        best_wpm: 70.0,
+        avg_accuracy: 95.0,
+        keystrokes: 500,
+    }
+}
+
+fn language(name: &str, stages: usize) -> LanguageActivity {
+    LanguageActivity {
+        language: name.to_string(),
+        stages,
+        avg_wpm: 60.0,
+        best_wpm: 70.0,
+        avg_accuracy: 95.0,
+        keystrokes: 500,
+    }
+}
+
+#[test]
+fn report_counts_sessions_and_stages_per_language() {
+    let crashes = BTreeMap::from([("1.0.0 screens/typing_screen.rs:42".to_string(), 2)]);
+
+    let report = TelemetryReport::new(
+        &[day(2), day(3)],
+        &[language("rust", 5), language("go", 1)],
+        crashes.clone(),
+    );
+
+    assert_eq!(report.version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(report.sessions, 5);
+    assert_eq!(report.languages.get("rust"), Some(&5));
+    assert_eq!(report.languages.get("go"), Some(&1));
+    assert_eq!(report.crash_signatures, crashes);
+}
+
+#[test]
+fn crash_signature_keeps_only_the_last_two_path_components() {
+    let signature = TelemetryReport::crash_signature(
+        "/home/alice/.cargo/registry/src/gittype/src/presentation/tui/screens/typing_screen.rs",
+        42,
+    );
+
+    assert_eq!(
+        signature,
+        format!("{} screens/typing_screen.rs:42", env!("CARGO_PKG_VERSION"))
+    );
+    assert!(!signature.contains("alice"));
+}
+
+#[test]
+fn report_json_has_only_counts() {
+    let json = TelemetryReport::new(&[day(1)], &[language("rust", 1)], BTreeMap::new())
+        .to_json()
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let mut keys: Vec<&str> = value
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    keys.sort();
+
+    assert_eq!(
+        keys,
+        vec!["crash_signatures", "languages", "os", "sessions", "version"]
+    );
+}
+
+#[test]
+fn telemetry_is_due_only_when_enabled_with_an_endpoint_and_not_sent_today() {
+    let today = NaiveDate::from_ymd_opt(2024, 5, 10).unwrap();
+    let enabled = TelemetryConfig {
+        enabled: true,
+        endpoint: Some("https://example.com/telemetry".to_string()),
+        last_sent_on: None,
+    };
+
+    assert_eq!(
+        enabled.due_endpoint(today),
+        Some("https://example.com/telemetry")
+    );
+    assert_eq!(TelemetryConfig::default().due_endpoint(today), None);
+    assert_eq!(
+        TelemetryConfig {
+            enabled: false,
+            ..enabled.clone()
+        }
+        .due_endpoint(today),
+        None
+    );
+    assert_eq!(
+        TelemetryConfig {
+            endpoint: None,
+            ..enabled.clone()
+        }
+        .due_endpoint(today),
+        None
+    );
+    assert_eq!(
+        TelemetryConfig {
+            last_sent_on: Some(today),
+            ..enabled
+        }
+        .due_endpoint(today),
+        None
+    );
+}
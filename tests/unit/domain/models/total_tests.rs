@@ -1,5 +1,6 @@
 use gittype::domain::models::session::Session;
 use gittype::domain::models::total::{Total, TotalResult};
+use std::time::Duration;
 
 #[test]
 fn total_new_creates_empty() {
@@ -122,6 +123,45 @@ fn create_share_text_format() {
     assert!(text.contains("#coding"));
 }
 
+#[test]
+fn create_summary_text_lists_totals() {
+    let mut result = TotalResult::new();
+    result.total_sessions_completed = 2;
+    result.total_sessions_attempted = 3;
+    result.total_stages_completed = 8;
+    result.total_stages_attempted = 9;
+    result.total_stages_skipped = 1;
+    result.total_score = 1234.4;
+    result.overall_wpm = 54.25;
+    result.overall_cpm = 271.2;
+    result.best_session_wpm = 60.0;
+    result.overall_accuracy = 96.54;
+    result.total_keystrokes = 1500;
+    result.total_mistakes = 52;
+    result.total_duration = Duration::from_secs(192);
+
+    assert_eq!(
+        result.create_summary_text(),
+        "gittype session summary\n\
+         Sessions:   2/3 completed\n\
+         Stages:     8/9 completed, 1 skipped\n\
+         Score:      1234\n\
+         Speed:      54.2 WPM / 271 CPM (best session 60.0 WPM)\n\
+         Accuracy:   96.5%\n\
+         Keystrokes: 1500 (52 mistakes)\n\
+         Time:       3m 12s"
+    );
+}
+
+#[test]
+fn create_summary_text_without_sessions() {
+    let result = TotalResult::new();
+    assert_eq!(
+        result.create_summary_text(),
+        "gittype: no sessions completed"
+    );
+}
+
 #[test]
 fn total_result_clone() {
     let mut result = TotalResult::new();
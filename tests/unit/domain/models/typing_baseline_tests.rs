@@ -0,0 +1,104 @@
+use chrono::{TimeZone, Utc};
+use gittype::domain::models::{BaselineSource, BaselineSummary, TypingBaseline};
+
+const MONKEYTYPE_CSV: &str = "\
+_id,isPb,wpm,acc,rawWpm,consistency,charStats,mode,mode2,quoteLength,restartCount,testDuration,afkDuration,incompleteTestSeconds,lazyMode,blindMode,bailedOut,tags,timestamp
+65a1,true,98.4,96.5,101.2,78.1,246;4;0;0,time,30,-1,0,30,0,0,false,false,false,\"a,b\",1704067200000
+65a2,false,91,94.2,95,75,230;9;0;0,time,30,-1,1,30.02,0,0,false,false,false,,1704153600000
+";
+
+const KEYBR_CSV: &str = "\
+timeStamp,length,time,errors,speed
+2024-01-01T10:00:00.000Z,200,40000,4,300
+2024-01-02T10:00:00Z,100,20000,0,360
+";
+
+#[test]
+fn parse_csv_detects_monkeytype_exports() {
+    let tests = TypingBaseline::parse_csv(MONKEYTYPE_CSV, None).unwrap();
+
+    assert_eq!(tests.len(), 2);
+    assert_eq!(
+        tests[0],
+        TypingBaseline {
+            source: BaselineSource::Monkeytype,
+            taken_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            wpm: 98.4,
+            accuracy: 96.5,
+            duration_seconds: Some(30.0),
+        }
+    );
+    assert_eq!(tests[1].duration_seconds, Some(30.02));
+}
+
+#[test]
+fn parse_csv_converts_keybr_speed_and_errors() {
+    let tests = TypingBaseline::parse_csv(KEYBR_CSV, None).unwrap();
+
+    assert_eq!(tests.len(), 2);
+    assert_eq!(tests[0].source, BaselineSource::Keybr);
+    assert_eq!(
+        tests[0].taken_at,
+        Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap()
+    );
+    assert_eq!(tests[0].wpm, 60.0);
+    assert_eq!(tests[0].accuracy, 98.0);
+    assert_eq!(tests[0].duration_seconds, Some(40.0));
+    assert_eq!(tests[1].accuracy, 100.0);
+}
+
+#[test]
+fn parse_csv_rejects_unknown_headers_unless_source_given() {
+    let csv = "date,score\n2024-01-01,10\n";
+
+    assert!(TypingBaseline::parse_csv(csv, None).is_err());
+    assert!(TypingBaseline::parse_csv(csv, Some(BaselineSource::Monkeytype)).is_err());
+}
+
+#[test]
+fn parse_csv_reports_invalid_rows() {
+    let csv = "wpm,acc,timestamp\n90,95,1704067200000\nfast,95,1704067200000\n";
+
+    let error = TypingBaseline::parse_csv(csv, None).unwrap_err();
+
+    assert!(error.to_string().contains("line 3"));
+}
+
+#[test]
+fn parse_csv_rejects_empty_files() {
+    assert!(TypingBaseline::parse_csv("\n", None).is_err());
+}
+
+#[test]
+fn baseline_source_parses_case_insensitively() {
+    assert_eq!(
+        "Monkeytype".parse::<BaselineSource>().unwrap(),
+        BaselineSource::Monkeytype
+    );
+    assert_eq!(
+        "keybr".parse::<BaselineSource>().unwrap(),
+        BaselineSource::Keybr
+    );
+    assert!("typeracer".parse::<BaselineSource>().is_err());
+}
+
+#[test]
+fn code_to_prose_ratio_compares_against_average() {
+    let summary = BaselineSummary {
+        tests: 3,
+        avg_wpm: 80.0,
+        best_wpm: 95.0,
+        avg_accuracy: 96.0,
+        sources: vec![BaselineSource::Monkeytype],
+    };
+
+    assert_eq!(summary.code_to_prose_ratio(60.0), Some(75.0));
+    assert_eq!(
+        BaselineSummary {
+            avg_wpm: 0.0,
+            ..summary
+        }
+        .code_to_prose_ratio(60.0),
+        None
+    );
+}
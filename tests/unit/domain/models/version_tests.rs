@@ -0,0 +1,48 @@
+use gittype::domain::models::version::ReleaseNotes;
+
+#[test]
+fn from_markdown_takes_the_first_bullets_without_attributions() {
+    let body = "## What's Changed\n\
+                * Add a **ghost** race by @alice in https://github.com/unhappychoice/gittype/pull/10\n\
+                - Faster loading\n\
+                \n\
+                * Stream exports by @bob in https://github.com/unhappychoice/gittype/pull/12\n\
+                * Fourth change\n\
+                \n\
+                **Full Changelog**: https://github.com/unhappychoice/gittype/compare/v1.0.0...v1.1.0";
+
+    let release = ReleaseNotes::from_markdown(
+        "1.1.0",
+        Some("https://github.com/unhappychoice/gittype/releases/tag/v1.1.0"),
+        body,
+    );
+
+    assert_eq!(
+        release.highlights,
+        vec!["Add a ghost race", "Faster loading", "Stream exports"]
+    );
+    assert_eq!(
+        release.url,
+        "https://github.com/unhappychoice/gittype/releases/tag/v1.1.0"
+    );
+}
+
+#[test]
+fn from_markdown_links_the_release_tag_without_a_url() {
+    let release = ReleaseNotes::from_markdown("1.1.0", None, "No bullets here");
+
+    assert!(release.highlights.is_empty());
+    assert_eq!(
+        release.url,
+        "https://github.com/unhappychoice/gittype/releases/tag/v1.1.0"
+    );
+}
+
+#[test]
+fn without_highlights_keeps_the_version_and_url() {
+    let release = ReleaseNotes::without_highlights("2.0.0");
+
+    assert_eq!(release.version, "2.0.0");
+    assert!(release.highlights.is_empty());
+    assert!(release.url.ends_with("/releases/tag/v2.0.0"));
+}
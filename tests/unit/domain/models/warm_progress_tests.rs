@@ -0,0 +1,57 @@
+use gittype::domain::models::loading::StepType;
+use gittype::domain::models::{WarmProgress, WarmStatus};
+
+fn progress() -> WarmProgress {
+    WarmProgress::new(&[
+        "unhappychoice/gittype".to_string(),
+        "rust-lang/rust".to_string(),
+        "tokio-rs/tokio".to_string(),
+    ])
+}
+
+#[test]
+fn rows_start_queued_and_follow_the_loading_steps() {
+    let mut progress = progress();
+    assert_eq!(progress.status(0), Some(&WarmStatus::Queued));
+
+    progress.set_step(0, StepType::Cloning);
+    progress.set_progress(0, StepType::Extracting, 30, 120);
+
+    assert_eq!(
+        progress.status(0),
+        Some(&WarmStatus::Running {
+            step: StepType::Extracting,
+            progress: 0.25
+        })
+    );
+}
+
+#[test]
+fn render_aligns_one_line_per_repository() {
+    let mut progress = progress();
+    progress.set_progress(0, StepType::Cloning, 1, 2);
+    progress.finish(1, 123, false);
+    progress.fail(2, "Repository not found\nmore details");
+
+    assert_eq!(
+        progress.render(usize::MAX),
+        [
+            "» unhappychoice/gittype  cloning         50%",
+            "✓ rust-lang/rust         123 challenges",
+            "✗ tokio-rs/tokio         Repository not found",
+        ]
+    );
+    assert_eq!(progress.failed_count(), 1);
+}
+
+#[test]
+fn render_marks_cache_hits_and_cuts_lines_to_the_width() {
+    let mut progress = progress();
+    progress.finish(0, 12, true);
+
+    assert_eq!(
+        progress.render(80)[0],
+        "✓ unhappychoice/gittype  12 challenges (already cached)"
+    );
+    assert_eq!(progress.render(10)[2], "◦ tokio-rs");
+}
@@ -0,0 +1,56 @@
+use gittype::domain::models::WpmMode;
+
+#[test]
+fn tokens_are_identifiers_numbers_and_single_symbols() {
+    assert_eq!(WpmMode::count_tokens("let total_count = 42;"), 5);
+    assert_eq!(WpmMode::count_tokens("foo.bar()"), 5);
+    assert_eq!(WpmMode::count_tokens("a->b"), 4);
+    assert_eq!(WpmMode::count_tokens("  \n\t "), 0);
+}
+
+#[test]
+fn characters_mode_counts_five_characters_per_word() {
+    assert_eq!(WpmMode::Characters.wpm(300.0, "fn main() {}"), 60.0);
+    assert_eq!(WpmMode::Characters.wpm(300.0, ""), 60.0);
+}
+
+#[test]
+fn tokens_mode_scales_speed_by_token_density() {
+    // 4 tokens in 8 characters: one token every two characters
+    assert_eq!(WpmMode::Tokens.wpm(300.0, "x = y + "), 150.0);
+    assert_eq!(WpmMode::Tokens.wpm(300.0, ""), 0.0);
+}
+
+#[test]
+fn wpm_across_weights_each_part_by_its_typing_time() {
+    let parts = [(150.0, 300.0, 60.0), (50.0, 300.0, 20.0)];
+
+    assert_eq!(WpmMode::Characters.wpm_across(300.0, parts), 60.0);
+    assert_eq!(WpmMode::Tokens.wpm_across(300.0, parts), 125.0);
+    assert_eq!(WpmMode::Tokens.wpm_across(300.0, []), 0.0);
+}
+
+#[test]
+fn labels_name_the_unit() {
+    assert_eq!(WpmMode::Characters.label(), "WPM");
+    assert_eq!(WpmMode::Tokens.label(), "TPM");
+}
+
+#[test]
+fn recorded_wpm_is_shown_in_the_mode() {
+    let density = WpmMode::token_density("x = y + ");
+
+    assert_eq!(density, 0.5);
+    assert_eq!(WpmMode::Characters.from_recorded(60.0, density), 60.0);
+    assert_eq!(WpmMode::Tokens.from_recorded(60.0, density), 150.0);
+    assert_eq!(WpmMode::token_density(""), 0.0);
+}
+
+#[test]
+fn mean_token_density_weights_parts_by_characters() {
+    assert_eq!(
+        WpmMode::mean_token_density([(300.0, 0.5), (100.0, 0.1)]),
+        0.4
+    );
+    assert_eq!(WpmMode::mean_token_density([]), 0.0);
+}
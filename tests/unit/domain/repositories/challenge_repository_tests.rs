@@ -1,3 +1,4 @@
+use gittype::domain::models::loading::ProgressReporter;
 use gittype::domain::models::loading::StepType;
 use gittype::domain::models::{Challenge, DifficultyLevel, GitRepository};
 use gittype::domain::repositories::challenge_repository::{
@@ -6,7 +7,6 @@ use gittype::domain::repositories::challenge_repository::{
 use gittype::infrastructure::storage::file_storage::FileStorage;
 use gittype::infrastructure::storage::file_storage::FileStorageInterface;
 use gittype::presentation::di::AppModule;
-use gittype::presentation::tui::screens::loading_screen::ProgressReporter;
 use shaku::HasComponent;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
@@ -25,6 +25,7 @@ fn create_test_repo(commit: Option<String>, dirty: bool) -> GitRepository {
         commit_hash: commit,
         is_dirty: dirty,
         root_path: Some(PathBuf::from("/tmp/mock-repo")),
+        license: None,
     }
 }
 
@@ -239,6 +240,7 @@ fn load_challenges_reconstructs_saved_source_slice() {
         commit_hash: Some(format!("load-success-{}", std::process::id())),
         is_dirty: false,
         root_path: Some(temp_dir.path().join("repo")),
+        license: None,
     };
     let challenge = Challenge::new("t1".to_string(), "fn beta() {}".to_string())
         .with_source_info("src/lib.rs".to_string(), 2, 2)
@@ -285,6 +287,7 @@ fn load_challenges_uses_whole_file_when_lines_are_absent() {
         commit_hash: Some(format!("load-whole-file-{}", std::process::id())),
         is_dirty: false,
         root_path: Some(temp_dir.path().join("repo")),
+        license: None,
     };
     let challenge = Challenge {
         id: "no-lines".to_string(),
@@ -328,6 +331,7 @@ fn load_challenges_returns_none_when_pointer_has_no_source_path() {
         commit_hash: Some(format!("load-no-path-{}", std::process::id())),
         is_dirty: false,
         root_path: Some(temp_dir.path().join("repo")),
+        license: None,
     };
     let challenge = Challenge {
         id: "no-source-path".to_string(),
@@ -368,6 +372,7 @@ fn load_challenges_returns_none_when_start_line_exceeds_file_length() {
         commit_hash: Some(format!("load-bad-range-{}", std::process::id())),
         is_dirty: false,
         root_path: Some(temp_dir.path().join("repo")),
+        license: None,
     };
     let challenge = Challenge {
         id: "out-of-range".to_string(),
@@ -408,6 +413,7 @@ fn load_challenges_returns_none_when_start_line_exceeds_end_line() {
         commit_hash: Some(format!("load-inverted-{}", std::process::id())),
         is_dirty: false,
         root_path: Some(temp_dir.path().join("repo")),
+        license: None,
     };
     let challenge = Challenge {
         id: "inverted-range".to_string(),
@@ -449,6 +455,7 @@ fn load_challenges_returns_none_when_source_path_escapes_repo_root() {
         commit_hash: Some(format!("load-escape-{}", std::process::id())),
         is_dirty: false,
         root_path: Some(repo_path),
+        license: None,
     };
     let challenge = Challenge {
         id: "escape-attempt".to_string(),
@@ -515,6 +522,7 @@ fn load_challenges_with_progress_reports_progress_to_reporter() {
         commit_hash: Some(format!("load-progress-{}", std::process::id())),
         is_dirty: false,
         root_path: Some(temp_dir.path().join("repo")),
+        license: None,
     };
     let challenges = vec![
         Challenge::new("c1".to_string(), "fn one() {}".to_string()).with_source_info(
@@ -572,6 +580,7 @@ fn load_challenges_returns_none_when_root_path_is_missing() {
         commit_hash: Some(format!("load-no-root-{}", std::process::id())),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     let challenge = Challenge::new("c".to_string(), "fn x() {}".to_string()).with_source_info(
         "src/lib.rs".to_string(),
@@ -586,3 +595,121 @@ fn load_challenges_returns_none_when_root_path_is_missing() {
     let loaded = repository.load_challenges_with_progress(&git_repository, None);
     assert!(loaded.is_none());
 }
+
+#[test]
+fn count_cached_challenges_sums_every_commit_of_a_repository() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let repository = ChallengeRepository::new_for_test(
+        temp_dir.path().join("cache"),
+        Arc::new(FileStorage::new()),
+    );
+    let first = create_test_repo(Some("count-a".to_string()), false);
+    let second = create_test_repo(Some("count-b".to_string()), false);
+    let other = GitRepository {
+        remote_url: "https://github.com/test/other".to_string(),
+        ..create_test_repo(Some("count-a".to_string()), false)
+    };
+
+    repository
+        .save_challenges(&first, &[create_test_challenge("a1", "fn a() {}")])
+        .unwrap();
+    repository
+        .save_challenges(
+            &second,
+            &[
+                create_test_challenge("b1", "fn b() {}"),
+                create_test_challenge("b2", "fn c() {}"),
+            ],
+        )
+        .unwrap();
+    repository
+        .save_challenges(&other, &[create_test_challenge("o1", "fn o() {}")])
+        .unwrap();
+
+    assert_eq!(
+        repository
+            .count_cached_challenges(&first.cache_key())
+            .unwrap(),
+        3
+    );
+    assert_eq!(
+        repository
+            .count_cached_challenges("github_com_test_missing")
+            .unwrap(),
+        0
+    );
+}
+
+#[test]
+fn invalidate_repository_key_only_removes_that_repository() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let repository = ChallengeRepository::new_for_test(
+        temp_dir.path().join("cache"),
+        Arc::new(FileStorage::new()),
+    );
+    let target = create_test_repo(Some("invalidate-a".to_string()), false);
+    let older = create_test_repo(Some("invalidate-b".to_string()), false);
+    let other = GitRepository {
+        remote_url: "https://github.com/test/other".to_string(),
+        ..create_test_repo(Some("invalidate-a".to_string()), false)
+    };
+    for repo in [&target, &older, &other] {
+        repository
+            .save_challenges(repo, &[create_test_challenge("t1", "fn main() {}")])
+            .unwrap();
+    }
+
+    let removed = repository
+        .invalidate_repository_key(&target.cache_key())
+        .unwrap();
+
+    assert_eq!(removed, 2);
+    assert_eq!(
+        repository
+            .count_cached_challenges(&target.cache_key())
+            .unwrap(),
+        0
+    );
+    assert_eq!(
+        repository
+            .count_cached_challenges(&other.cache_key())
+            .unwrap(),
+        1
+    );
+}
+
+#[test]
+fn remove_stale_caches_deletes_only_caches_of_missing_checkouts() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(temp_dir.path().join("kept")).unwrap();
+    let repository = ChallengeRepository::new_for_test(
+        temp_dir.path().join("cache"),
+        Arc::new(FileStorage::new()),
+    );
+    let repo_at = |name: &str| GitRepository {
+        user_name: "test".to_string(),
+        repository_name: name.to_string(),
+        remote_url: format!("https://github.com/test/{}", name),
+        branch: Some("main".to_string()),
+        commit_hash: Some("abc123".to_string()),
+        is_dirty: false,
+        root_path: Some(temp_dir.path().join(name)),
+        license: None,
+    };
+    let challenges = vec![create_test_challenge("t1", "fn main() {}")];
+    repository
+        .save_challenges(&repo_at("kept"), &challenges)
+        .unwrap();
+    repository
+        .save_challenges(&repo_at("deleted"), &challenges)
+        .unwrap();
+
+    let (removed, freed) = repository.remove_stale_caches().unwrap();
+
+    assert_eq!(removed, 1);
+    assert!(freed > 0);
+    assert_eq!(
+        repository.list_cache_keys().unwrap(),
+        vec!["github_com_test_kept:abc123".to_string()]
+    );
+}
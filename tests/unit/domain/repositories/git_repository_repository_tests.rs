@@ -13,6 +13,7 @@ fn create_test_repository() -> GitRepository {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     }
 }
 
@@ -1,6 +1,8 @@
 pub mod challenge_repository_tests;
 pub mod git_repository_repository_tests;
+pub mod repository_metadata_repository_tests;
 pub mod session_repository_tests;
 pub mod stage_repository_tests;
+pub mod starred_repository_tests;
 pub mod trending_repository_tests;
 pub mod version_repository_tests;
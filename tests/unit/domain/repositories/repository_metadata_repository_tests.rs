@@ -0,0 +1,63 @@
+use gittype::domain::models::GitRepository;
+use gittype::domain::repositories::repository_metadata_repository::{
+    RepositoryMetadataRepository, RepositoryMetadataRepositoryInterface,
+};
+use gittype::infrastructure::database::daos::RepositoryDao;
+use gittype::infrastructure::database::database::{Database, DatabaseInterface};
+use gittype::infrastructure::http::github_api_client::GitHubApiClientFactoryImpl;
+use std::sync::Arc;
+
+fn create_repository() -> RepositoryMetadataRepository {
+    let database = Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>;
+    RepositoryMetadataRepository::new(
+        Arc::new(GitHubApiClientFactoryImpl::default()),
+        Arc::new(RepositoryDao::new(database)),
+    )
+}
+
+fn git_repository(remote_url: &str) -> GitRepository {
+    GitRepository {
+        user_name: "owner".to_string(),
+        repository_name: "repo".to_string(),
+        remote_url: remote_url.to_string(),
+        branch: None,
+        commit_hash: None,
+        is_dirty: false,
+        root_path: None,
+        license: None,
+    }
+}
+
+#[test]
+fn refresh_metadata_fetches_and_caches_github_metadata() {
+    let repository = create_repository();
+    let git_repo = git_repository("https://github.com/owner/repo");
+
+    assert!(repository.get_cached_metadata(&git_repo).unwrap().is_none());
+
+    let metadata = repository.refresh_metadata(&git_repo).unwrap().unwrap();
+    assert_eq!(metadata.stars, 1234);
+    assert_eq!(metadata.primary_language.as_deref(), Some("Rust"));
+    assert_eq!(
+        repository
+            .get_cached_metadata(&git_repo)
+            .unwrap()
+            .unwrap()
+            .stars,
+        1234
+    );
+}
+
+#[test]
+fn refresh_metadata_skips_non_github_remotes() {
+    let repository = create_repository();
+    let git_repo = git_repository("https://gitlab.com/owner/repo");
+
+    assert!(repository.refresh_metadata(&git_repo).unwrap().is_none());
+    assert!(repository.get_cached_metadata(&git_repo).unwrap().is_none());
+}
+
+#[test]
+fn refresh_all_metadata_succeeds_without_repositories() {
+    assert!(create_repository().refresh_all_metadata().is_ok());
+}
@@ -1,5 +1,5 @@
 use gittype::domain::models::storage::SessionResultData;
-use gittype::domain::models::{Challenge, GitRepository, SessionResult};
+use gittype::domain::models::{Challenge, GitRepository, MistakeBreakdown, SessionResult};
 use gittype::domain::repositories::session_repository::{
     BestRecords, BestStatus, SessionRepository, SessionRepositoryTrait,
 };
@@ -229,6 +229,7 @@ fn test_get_all_repositories_with_data() {
         commit_hash: Some("all123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let challenge = Challenge::new("allrepo-id".to_string(), "test".to_string());
@@ -273,6 +274,7 @@ fn test_get_language_stats_with_data() {
         commit_hash: Some("lang123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let challenge = Challenge::new("lang-id".to_string(), "test".to_string())
@@ -313,6 +315,7 @@ fn test_get_sessions_filtered_sort_verification() {
         commit_hash: Some("sort123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     // Create two sessions with different scores
@@ -361,6 +364,7 @@ fn test_get_session_result_for_analytics_with_data() {
         commit_hash: Some("analytics123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let challenge = Challenge::new("analytics-id".to_string(), "test".to_string());
@@ -452,6 +456,7 @@ fn test_trait_get_all_repositories_returns_recorded_repository() {
         commit_hash: Some("trait1".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     let challenge = Challenge::new("trait-id".to_string(), "trait".to_string());
     let mut tracker = StageTracker::new("trait".to_string());
@@ -489,6 +494,7 @@ fn test_trait_get_sessions_filtered_returns_recorded_sessions() {
         commit_hash: Some("tfilter1".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     let challenge = Challenge::new("tfilter-id".to_string(), "tfilter".to_string());
     let mut tracker = StageTracker::new("tfilter".to_string());
@@ -527,6 +533,7 @@ fn test_trait_get_session_result_returns_recorded_session() {
         commit_hash: Some("tresult1".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     let challenge = Challenge::new("tresult-id".to_string(), "tresult".to_string());
     let mut tracker = StageTracker::new("tresult".to_string());
@@ -571,6 +578,7 @@ fn test_trait_get_language_stats_includes_recorded_language() {
         commit_hash: Some("tlang1".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     let challenge = Challenge::new("tlang-id".to_string(), "tlang".to_string())
         .with_language("rust".to_string());
@@ -621,6 +629,7 @@ fn test_trait_get_session_result_for_analytics_returns_recorded_session() {
         commit_hash: Some("tanalytics1".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     let challenge = Challenge::new("tanalytics-id".to_string(), "tanalytics".to_string());
     let mut tracker = StageTracker::new("tanalytics".to_string());
@@ -673,6 +682,7 @@ fn test_record_session_and_retrieve() {
         commit_hash: Some("xyz789".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     // Create challenges
@@ -728,6 +738,54 @@ fn test_record_session_and_retrieve() {
     assert_eq!(stage_results[1].language, Some("rust".to_string()));
 }
 
+#[test]
+fn test_record_session_keeps_mistake_breakdown_and_key_load() {
+    let repo = SessionRepository::new().unwrap();
+
+    let git_repo = GitRepository {
+        user_name: "mistakeuser".to_string(),
+        repository_name: "mistakerepo".to_string(),
+        remote_url: "https://github.com/mistakeuser/mistakerepo".to_string(),
+        branch: Some("main".to_string()),
+        commit_hash: Some("mis123".to_string()),
+        is_dirty: false,
+        root_path: None,
+        license: None,
+    };
+
+    let challenge = Challenge::new("mistake-id".to_string(), "fn".to_string());
+    let mut tracker = StageTracker::new("fn".to_string());
+    tracker.record(StageInput::Start);
+    for (ch, position) in [('g', 0), ('F', 0), ('f', 0), ('n', 1)] {
+        tracker.record(StageInput::Keystroke { ch, position });
+    }
+    tracker.record(StageInput::Finish);
+
+    let session_id = repo
+        .record_session(
+            &SessionResult::new(),
+            Some(&git_repo),
+            "normal",
+            None,
+            &[("stage1".to_string(), tracker)],
+            &[challenge],
+        )
+        .unwrap();
+
+    let stage_results = repo.get_session_stage_results(session_id).unwrap();
+    let key_load = stage_results[0].key_load.as_ref().unwrap();
+    assert_eq!(key_load.stats('f').keystrokes, 3);
+    assert_eq!(key_load.stats('f').mistakes, 2);
+    assert_eq!(
+        stage_results[0].mistake_breakdown,
+        Some(MistakeBreakdown {
+            adjacent_key: 1,
+            case: 1,
+            ..Default::default()
+        })
+    );
+}
+
 #[test]
 fn test_record_session_with_repository() {
     let repo = SessionRepository::new().unwrap();
@@ -743,6 +801,7 @@ fn test_record_session_with_repository() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let challenge = Challenge::new("test-id".to_string(), "test".to_string());
@@ -813,6 +872,7 @@ fn test_get_repository_history_with_data() {
         commit_hash: Some("def456".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let challenge = Challenge::new("hist-id".to_string(), "hist".to_string());
@@ -863,6 +923,7 @@ fn test_get_best_records_with_data() {
         commit_hash: Some("best123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let challenge = Challenge::new("best-id".to_string(), "best".to_string());
@@ -908,6 +969,7 @@ fn test_get_sessions_filtered_with_data() {
         commit_hash: Some("filter123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let challenge = Challenge::new("filter-id".to_string(), "filter".to_string());
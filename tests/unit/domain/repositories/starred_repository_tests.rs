@@ -0,0 +1,22 @@
+use gittype::domain::repositories::starred_repository::{
+    StarredRepository, StarredRepositoryInterface,
+};
+use gittype::infrastructure::http::github_api_client::GitHubApiClientFactoryImpl;
+use gittype::presentation::di::AppModule;
+use shaku::HasComponent;
+use std::sync::Arc;
+
+#[test]
+fn test_creates_repository_via_di() {
+    let module = AppModule::builder().build();
+    let _repo: Arc<dyn StarredRepositoryInterface> = module.resolve();
+}
+
+#[test]
+fn test_get_starred_repositories_sync_collects_all_pages() {
+    let repo = StarredRepository::new(Arc::new(GitHubApiClientFactoryImpl::default()));
+    let starred = repo.get_starred_repositories_sync().unwrap();
+
+    let names: Vec<&str> = starred.iter().map(|r| r.full_name.as_str()).collect();
+    assert_eq!(names, vec!["tokio-rs/tokio", "sharkdp/bat"]);
+}
@@ -1,4 +1,5 @@
 use chrono::{Duration, Utc};
+use gittype::domain::models::config::ReleaseChannel;
 use gittype::domain::models::version::VersionCacheEntry;
 use gittype::domain::repositories::version_repository::VersionRepository;
 use gittype::infrastructure::http::github_api_client::{GitHubApiClient, GitHubApiClientFactory};
@@ -15,11 +16,43 @@ fn new_creates_repository() {
 async fn fetch_latest_version_returns_normalized_api_release() {
     let repository = VersionRepository::new_for_test().unwrap();
 
-    let version = repository.fetch_latest_version().await.unwrap();
+    let version = repository
+        .fetch_latest_version(ReleaseChannel::Stable, 24)
+        .await
+        .unwrap();
 
     assert_eq!(version, "1.0.0");
 }
 
+#[tokio::test]
+async fn fetch_latest_version_on_pre_release_channel_includes_pre_releases() {
+    let repository = VersionRepository::new_for_test().unwrap();
+
+    let version = repository
+        .fetch_latest_version(ReleaseChannel::PreRelease, 24)
+        .await
+        .unwrap();
+
+    assert_eq!(version, "1.1.0-beta.1");
+}
+
+#[tokio::test]
+async fn fetch_latest_release_includes_highlights_and_url() {
+    let repository = VersionRepository::new_for_test().unwrap();
+
+    let release = repository
+        .fetch_latest_release(ReleaseChannel::Stable, 24)
+        .await
+        .unwrap();
+
+    assert_eq!(release.version, "1.0.0");
+    assert_eq!(
+        release.url,
+        "https://github.com/unhappychoice/gittype/releases/tag/v1.0.0"
+    );
+    assert_eq!(release.highlights, vec!["Add release notes preview"]);
+}
+
 struct FailingGitHubApiClientFactory;
 
 impl GitHubApiClientFactory for FailingGitHubApiClientFactory {
@@ -36,6 +69,8 @@ fn cache_entry(current_version: &str, hours_ago: i64) -> VersionCacheEntry {
         current_version: current_version.to_string(),
         update_available: false,
         last_checked: Utc::now() - Duration::hours(hours_ago),
+        channel: ReleaseChannel::Stable,
+        release_notes: None,
     }
 }
 
@@ -44,7 +79,9 @@ async fn fetch_latest_version_returns_api_error_without_cache_fallback() {
     let repository =
         VersionRepository::new_for_test_with_factory(Arc::new(FailingGitHubApiClientFactory));
 
-    let result = repository.fetch_latest_version().await;
+    let result = repository
+        .fetch_latest_version(ReleaseChannel::Stable, 24)
+        .await;
 
     assert!(matches!(
         result,
@@ -57,7 +94,7 @@ fn is_cache_valid_accepts_fresh_current_version_entry() {
     let entry = cache_entry(env!("CARGO_PKG_VERSION"), 1);
     let repository = VersionRepository::new_for_test().unwrap();
 
-    assert!(repository.is_cache_valid_for_test(&entry, 24));
+    assert!(repository.is_cache_valid_for_test(&entry, ReleaseChannel::Stable, 24));
 }
 
 #[test]
@@ -65,7 +102,23 @@ fn is_cache_valid_rejects_stale_entry() {
     let entry = cache_entry(env!("CARGO_PKG_VERSION"), 25);
     let repository = VersionRepository::new_for_test().unwrap();
 
-    assert!(!repository.is_cache_valid_for_test(&entry, 24));
+    assert!(!repository.is_cache_valid_for_test(&entry, ReleaseChannel::Stable, 24));
+}
+
+#[test]
+fn is_cache_valid_honors_weekly_frequency() {
+    let entry = cache_entry(env!("CARGO_PKG_VERSION"), 48);
+    let repository = VersionRepository::new_for_test().unwrap();
+
+    assert!(repository.is_cache_valid_for_test(&entry, ReleaseChannel::Stable, 168));
+}
+
+#[test]
+fn is_cache_valid_rejects_entry_from_other_channel() {
+    let entry = cache_entry(env!("CARGO_PKG_VERSION"), 1);
+    let repository = VersionRepository::new_for_test().unwrap();
+
+    assert!(!repository.is_cache_valid_for_test(&entry, ReleaseChannel::PreRelease, 24));
 }
 
 #[test]
@@ -73,7 +126,7 @@ fn is_cache_valid_rejects_different_current_version() {
     let entry = cache_entry("0.0.0", 1);
     let repository = VersionRepository::new_for_test().unwrap();
 
-    assert!(!repository.is_cache_valid_for_test(&entry, 24));
+    assert!(!repository.is_cache_valid_for_test(&entry, ReleaseChannel::Stable, 24));
 }
 
 #[test]
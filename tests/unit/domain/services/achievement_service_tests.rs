@@ -0,0 +1,67 @@
+use gittype::domain::models::{Achievement, Challenge, GitRepository, SessionResult};
+use gittype::domain::repositories::session_repository::SessionRepositoryTrait;
+use gittype::domain::services::achievement_service::AchievementServiceInterface;
+use gittype::domain::services::scoring::{StageInput, StageTracker};
+use gittype::presentation::di::AppModule;
+use shaku::HasComponent;
+use std::sync::Arc;
+
+fn record_stage(container: &AppModule) {
+    let session_repository: Arc<dyn SessionRepositoryTrait> = container.resolve();
+    let mut tracker = StageTracker::new("fn main() {}".to_string());
+    tracker.record(StageInput::Start);
+    tracker.record(StageInput::Finish);
+    session_repository
+        .record_session(
+            &SessionResult::new(),
+            Some(&GitRepository {
+                user_name: "user".to_string(),
+                repository_name: "repo".to_string(),
+                remote_url: "https://github.com/user/repo".to_string(),
+                branch: Some("main".to_string()),
+                commit_hash: Some("abc123".to_string()),
+                is_dirty: false,
+                root_path: None,
+                license: None,
+            }),
+            "normal",
+            None,
+            &[("Stage 1".to_string(), tracker)],
+            &[Challenge::new(
+                "achievement-test".to_string(),
+                "fn main() {}".to_string(),
+            )],
+        )
+        .unwrap();
+}
+
+#[test]
+fn lists_every_achievement_locked_without_history() {
+    let container = AppModule::builder().build();
+    let service: Arc<dyn AchievementServiceInterface> = container.resolve();
+
+    let achievements = service.load_achievements().unwrap();
+    assert_eq!(achievements.len(), Achievement::all().len());
+    assert!(achievements.iter().all(|status| !status.is_unlocked()));
+}
+
+#[test]
+fn completed_stage_unlocks_first_stage_once() {
+    let container = AppModule::builder().build();
+    record_stage(&container);
+    let service: Arc<dyn AchievementServiceInterface> = container.resolve();
+
+    let unlocked_at = |service: &Arc<dyn AchievementServiceInterface>| {
+        service
+            .load_achievements()
+            .unwrap()
+            .into_iter()
+            .find(|status| status.achievement == Achievement::FirstStage)
+            .and_then(|status| status.unlocked_at)
+    };
+    let first = unlocked_at(&service);
+    assert!(first.is_some());
+
+    record_stage(&container);
+    assert_eq!(unlocked_at(&service), first);
+}
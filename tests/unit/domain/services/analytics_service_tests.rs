@@ -1,14 +1,18 @@
 use chrono::{DateTime, Utc};
 use gittype::domain::models::storage::{
-    SessionResultData, SessionStageResult, StoredRepository, StoredSession,
+    LanguageActivity, SessionResultData, SessionStageResult, StoredRepository, StoredSession,
+};
+use gittype::domain::models::{
+    BaselineSource, Challenge, Finger, GitRepository, KeyLoad, SessionResult, TypingBaseline,
 };
-use gittype::domain::models::{Challenge, GitRepository, SessionResult};
 use gittype::domain::repositories::session_repository::{
     SessionRepository, SessionRepositoryTrait,
 };
 use gittype::domain::services::analytics_service::{AnalyticsService, AnalyticsServiceInterface};
 use gittype::domain::services::scoring::StageTracker;
-use gittype::infrastructure::database::daos::{RepositoryDao, RepositoryDaoInterface};
+use gittype::infrastructure::database::daos::{
+    BaselineDao, BaselineDaoInterface, RepositoryDao, RepositoryDaoInterface,
+};
 use gittype::infrastructure::database::database::{Database, DatabaseInterface};
 use gittype::Result;
 use rusqlite::Transaction;
@@ -22,6 +26,7 @@ struct MockSessionRepo {
     results: Vec<(i64, SessionResultData)>,
     stage_results: Vec<(i64, Vec<SessionStageResult>)>,
     language_stats: Vec<(String, f64, usize)>,
+    language_baselines: Vec<LanguageActivity>,
     repositories: Vec<StoredRepository>,
 }
 
@@ -32,6 +37,7 @@ impl MockSessionRepo {
             results: Vec::new(),
             stage_results: Vec::new(),
             language_stats: Vec::new(),
+            language_baselines: Vec::new(),
             repositories: Vec::new(),
         }
     }
@@ -60,6 +66,20 @@ impl SessionRepositoryTrait for MockSessionRepo {
     fn get_all_repositories(&self) -> Result<Vec<StoredRepository>> {
         Ok(self.repositories.clone())
     }
+    fn get_repository(&self, repository_id: i64) -> Result<Option<StoredRepository>> {
+        Ok(self
+            .repositories
+            .iter()
+            .find(|repository| repository.id == repository_id)
+            .cloned())
+    }
+    fn get_session(&self, session_id: i64) -> Result<Option<StoredSession>> {
+        Ok(self
+            .sessions
+            .iter()
+            .find(|session| session.id == session_id)
+            .cloned())
+    }
     fn get_sessions_filtered(
         &self,
         _repository_filter: Option<i64>,
@@ -85,6 +105,9 @@ impl SessionRepositoryTrait for MockSessionRepo {
     ) -> Result<Option<SessionResultData>> {
         self.get_session_result(session_id)
     }
+    fn get_language_baselines(&self) -> Result<Vec<LanguageActivity>> {
+        Ok(self.language_baselines.clone())
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -139,11 +162,28 @@ impl RepositoryDaoInterface for MockRepoDao {
     ) -> Result<Vec<gittype::domain::models::storage::StoredRepositoryWithLanguages>> {
         Ok(vec![])
     }
+    fn get_repository_metadata(
+        &self,
+        _repository_id: i64,
+    ) -> Result<Option<gittype::domain::models::storage::RepositoryMetadata>> {
+        Ok(None)
+    }
+    fn update_repository_metadata(
+        &self,
+        _repository_id: i64,
+        _metadata: &gittype::domain::models::storage::RepositoryMetadata,
+    ) -> Result<()> {
+        Ok(())
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
+fn baseline_dao() -> Arc<dyn BaselineDaoInterface> {
+    Arc::new(BaselineDao::new(Arc::new(Database::new().unwrap())))
+}
+
 fn make_session(id: i64, repo_id: Option<i64>) -> StoredSession {
     StoredSession {
         id,
@@ -157,6 +197,8 @@ fn make_session(id: i64, repo_id: Option<i64>) -> StoredSession {
         difficulty_level: None,
         max_stages: None,
         time_limit_seconds: None,
+        player_name: None,
+        unverified: false,
     }
 }
 
@@ -205,6 +247,10 @@ fn make_stage_result(language: Option<&str>) -> SessionStageResult {
         start_line: None,
         end_line: None,
         code_content: None,
+        challenge_id: None,
+        comment_ranges: Vec::new(),
+        mistake_breakdown: None,
+        key_load: None,
     }
 }
 
@@ -214,6 +260,7 @@ fn make_repo(id: i64, user: &str, name: &str) -> StoredRepository {
         user_name: user.to_string(),
         repository_name: name.to_string(),
         remote_url: format!("https://github.com/{}/{}", user, name),
+        license: None,
     }
 }
 
@@ -226,7 +273,7 @@ fn test_analytics_service_new() {
     let db = Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>;
     let repository_dao =
         Arc::new(RepositoryDao::new(Arc::clone(&db))) as Arc<dyn RepositoryDaoInterface>;
-    let _service = AnalyticsService::new(session_repository, repository_dao);
+    let _service = AnalyticsService::new(session_repository, repository_dao, baseline_dao());
 }
 
 #[test]
@@ -235,7 +282,7 @@ fn test_load_analytics_data_empty() {
     let db = Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>;
     let repository_dao =
         Arc::new(RepositoryDao::new(Arc::clone(&db))) as Arc<dyn RepositoryDaoInterface>;
-    let service = AnalyticsService::new(session_repository, repository_dao);
+    let service = AnalyticsService::new(session_repository, repository_dao, baseline_dao());
 
     let data = service.load_analytics_data().unwrap();
     assert_eq!(data.total_sessions, 0);
@@ -248,6 +295,7 @@ fn test_load_analytics_data_empty() {
     assert!(data.repository_stats.is_empty());
     assert!(data.language_stats.is_empty());
     assert!(data.reference_date.is_none());
+    assert!(data.prose_baseline.is_none());
 }
 
 // ---------------------------------------------------------------------------
@@ -257,7 +305,7 @@ fn test_load_analytics_data_empty() {
 fn test_analytics_empty_sessions_returns_zeroed_data() {
     let mock_repo = MockSessionRepo::new();
     let mock_dao = MockRepoDao::new(vec![]);
-    let service = AnalyticsService::new(Arc::new(mock_repo), Arc::new(mock_dao));
+    let service = AnalyticsService::new(Arc::new(mock_repo), Arc::new(mock_dao), baseline_dao());
 
     let data = service.load_analytics_data().unwrap();
     assert_eq!(data.total_sessions, 0);
@@ -271,7 +319,7 @@ fn test_analytics_session_without_result_uses_zero_averages() {
     let mut mock_repo = MockSessionRepo::new();
     mock_repo.sessions = vec![make_session(1, None)];
     let mock_dao = MockRepoDao::new(vec![]);
-    let service = AnalyticsService::new(Arc::new(mock_repo), Arc::new(mock_dao));
+    let service = AnalyticsService::new(Arc::new(mock_repo), Arc::new(mock_dao), baseline_dao());
 
     let data = service.load_analytics_data().unwrap();
 
@@ -293,7 +341,7 @@ fn test_analytics_single_session_basic_aggregation() {
     mock.language_stats = vec![("rust".to_string(), 300.0, 1)];
 
     let mock_dao = MockRepoDao::new(vec![repo]);
-    let service = AnalyticsService::new(Arc::new(mock), Arc::new(mock_dao));
+    let service = AnalyticsService::new(Arc::new(mock), Arc::new(mock_dao), baseline_dao());
 
     let data = service.load_analytics_data().unwrap();
     assert_eq!(data.total_sessions, 1);
@@ -322,7 +370,7 @@ fn test_analytics_repository_stats_fully_computed() {
     mock.repositories = vec![repo.clone()];
 
     let mock_dao = MockRepoDao::new(vec![repo]);
-    let service = AnalyticsService::new(Arc::new(mock), Arc::new(mock_dao));
+    let service = AnalyticsService::new(Arc::new(mock), Arc::new(mock_dao), baseline_dao());
 
     let data = service.load_analytics_data().unwrap();
 
@@ -375,7 +423,7 @@ fn test_analytics_language_stats_fully_computed() {
     ];
 
     let mock_dao = MockRepoDao::new(vec![]);
-    let service = AnalyticsService::new(Arc::new(mock), Arc::new(mock_dao));
+    let service = AnalyticsService::new(Arc::new(mock), Arc::new(mock_dao), baseline_dao());
 
     let data = service.load_analytics_data().unwrap();
 
@@ -398,6 +446,37 @@ fn test_analytics_language_stats_fully_computed() {
     assert_eq!(go_stats.total_sessions, 1);
 }
 
+#[test]
+fn test_analytics_finger_load_merges_stages_that_recorded_it() {
+    let mut load = KeyLoad::default();
+    load.record('f', true);
+    load.record('g', false);
+
+    let mut recorded = make_stage_result(Some("rust"));
+    recorded.key_load = Some(load);
+    let mut mock = MockSessionRepo::new();
+    mock.sessions = vec![make_session(1, None), make_session(2, None)];
+    mock.results = vec![
+        (1, make_result(200.0, 85.0, 20000)),
+        (2, make_result(400.0, 99.0, 50000)),
+    ];
+    mock.stage_results = vec![
+        (1, vec![recorded.clone(), make_stage_result(Some("go"))]),
+        (2, vec![recorded]),
+    ];
+
+    let service = AnalyticsService::new(
+        Arc::new(mock),
+        Arc::new(MockRepoDao::new(vec![])),
+        baseline_dao(),
+    );
+    let data = service.load_analytics_data().unwrap();
+
+    assert_eq!(data.finger_load.stats(Finger::LeftIndex).keystrokes, 4);
+    assert_eq!(data.finger_load.stats(Finger::LeftIndex).mistakes, 2);
+    assert_eq!(data.finger_load.total_keystrokes(), 4);
+}
+
 #[test]
 fn test_analytics_multiple_days_trend_sorted() {
     let mut mock = MockSessionRepo::new();
@@ -420,7 +499,11 @@ fn test_analytics_multiple_days_trend_sorted() {
         (2, vec![make_stage_result(None)]),
     ];
 
-    let service = AnalyticsService::new(Arc::new(mock), Arc::new(MockRepoDao::new(vec![])));
+    let service = AnalyticsService::new(
+        Arc::new(mock),
+        Arc::new(MockRepoDao::new(vec![])),
+        baseline_dao(),
+    );
     let data = service.load_analytics_data().unwrap();
 
     // Trends should be sorted by date (ascending)
@@ -451,7 +534,11 @@ fn test_analytics_best_cpm_tracked_across_sessions() {
     ];
     mock.stage_results = vec![(1, vec![]), (2, vec![]), (3, vec![])];
 
-    let service = AnalyticsService::new(Arc::new(mock), Arc::new(MockRepoDao::new(vec![])));
+    let service = AnalyticsService::new(
+        Arc::new(mock),
+        Arc::new(MockRepoDao::new(vec![])),
+        baseline_dao(),
+    );
     let data = service.load_analytics_data().unwrap();
 
     assert!((data.best_cpm - 500.0).abs() < 0.01);
@@ -469,7 +556,11 @@ fn test_analytics_mistakes_estimation() {
     mock.results = vec![(1, make_result(200.0, 90.0, 10000))];
     mock.stage_results = vec![(1, vec![])];
 
-    let service = AnalyticsService::new(Arc::new(mock), Arc::new(MockRepoDao::new(vec![])));
+    let service = AnalyticsService::new(
+        Arc::new(mock),
+        Arc::new(MockRepoDao::new(vec![])),
+        baseline_dao(),
+    );
     let data = service.load_analytics_data().unwrap();
 
     // (100.0 - 90.0) / 100.0 * 3 = 0.3 → 0 as usize
@@ -484,7 +575,11 @@ fn test_analytics_session_duration_calculated() {
     mock.results = vec![(1, make_result(300.0, 95.0, 120000))];
     mock.stage_results = vec![(1, vec![])];
 
-    let service = AnalyticsService::new(Arc::new(mock), Arc::new(MockRepoDao::new(vec![])));
+    let service = AnalyticsService::new(
+        Arc::new(mock),
+        Arc::new(MockRepoDao::new(vec![])),
+        baseline_dao(),
+    );
     let data = service.load_analytics_data().unwrap();
 
     // avg_session_duration = 120000 / 1 / 60000 = 2.0 minutes
@@ -500,7 +595,11 @@ fn test_analytics_session_without_repo_skips_repo_stats() {
     mock.results = vec![(1, make_result(300.0, 95.0, 30000))];
     mock.stage_results = vec![(1, vec![])];
 
-    let service = AnalyticsService::new(Arc::new(mock), Arc::new(MockRepoDao::new(vec![])));
+    let service = AnalyticsService::new(
+        Arc::new(mock),
+        Arc::new(MockRepoDao::new(vec![])),
+        baseline_dao(),
+    );
     let data = service.load_analytics_data().unwrap();
 
     assert!(data.top_repositories.is_empty());
@@ -520,7 +619,7 @@ fn test_analytics_top_repositories_sorted_by_avg_cpm_descending() {
     mock.stage_results = vec![(1, vec![]), (2, vec![])];
 
     let mock_dao = MockRepoDao::new(vec![repo_a, repo_b]);
-    let service = AnalyticsService::new(Arc::new(mock), Arc::new(mock_dao));
+    let service = AnalyticsService::new(Arc::new(mock), Arc::new(mock_dao), baseline_dao());
     let data = service.load_analytics_data().unwrap();
 
     assert_eq!(data.top_repositories.len(), 2);
@@ -536,7 +635,11 @@ fn test_analytics_stage_results_without_language_ignored() {
     mock.results = vec![(1, make_result(300.0, 95.0, 30000))];
     mock.stage_results = vec![(1, vec![make_stage_result(None)])];
 
-    let service = AnalyticsService::new(Arc::new(mock), Arc::new(MockRepoDao::new(vec![])));
+    let service = AnalyticsService::new(
+        Arc::new(mock),
+        Arc::new(MockRepoDao::new(vec![])),
+        baseline_dao(),
+    );
     let data = service.load_analytics_data().unwrap();
 
     assert!(
@@ -567,7 +670,7 @@ fn test_analytics_multiple_repos_independent_stats() {
     ];
 
     let mock_dao = MockRepoDao::new(vec![repo_a, repo_b]);
-    let service = AnalyticsService::new(Arc::new(mock), Arc::new(mock_dao));
+    let service = AnalyticsService::new(Arc::new(mock), Arc::new(mock_dao), baseline_dao());
     let data = service.load_analytics_data().unwrap();
 
     assert!(data.repository_stats.contains_key("owner/alpha"));
@@ -575,3 +678,29 @@ fn test_analytics_multiple_repos_independent_stats() {
     assert_eq!(data.repository_stats["owner/alpha"].total_sessions, 2);
     assert_eq!(data.repository_stats["owner/beta"].total_sessions, 1);
 }
+
+#[test]
+fn test_analytics_includes_imported_prose_baseline() {
+    let baseline_dao = baseline_dao();
+    baseline_dao
+        .insert_baselines(&[TypingBaseline {
+            source: BaselineSource::Keybr,
+            taken_at: Utc::now(),
+            wpm: 80.0,
+            accuracy: 96.0,
+            duration_seconds: None,
+        }])
+        .unwrap();
+    let service = AnalyticsService::new(
+        Arc::new(MockSessionRepo::new()),
+        Arc::new(MockRepoDao::new(vec![])),
+        baseline_dao,
+    );
+
+    let data = service.load_analytics_data().unwrap();
+
+    let baseline = data.prose_baseline.unwrap();
+    assert_eq!(baseline.tests, 1);
+    assert_eq!(baseline.avg_wpm, 80.0);
+    assert_eq!(baseline.sources, vec![BaselineSource::Keybr]);
+}
@@ -0,0 +1,50 @@
+use gittype::domain::models::Challenge;
+use gittype::domain::services::challenge_flag_service::{
+    ChallengeFlagService, ChallengeFlagServiceInterface,
+};
+use gittype::infrastructure::database::daos::ChallengeFlagDao;
+use gittype::infrastructure::database::database::{Database, DatabaseInterface};
+use std::sync::Arc;
+
+fn service() -> ChallengeFlagService {
+    let db = Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>;
+    ChallengeFlagService::new(Arc::new(ChallengeFlagDao::new(db)))
+}
+
+fn challenge(id: &str, code: &str) -> Challenge {
+    Challenge::new(id.to_string(), code.to_string())
+}
+
+#[test]
+fn exclude_flagged_keeps_everything_without_flags() {
+    let challenges = vec![challenge("a", "fn a() {}"), challenge("b", "fn b() {}")];
+
+    assert_eq!(service().exclude_flagged(challenges.clone()), challenges);
+}
+
+#[test]
+fn exclude_flagged_matches_by_content_not_id() {
+    let service = service();
+    assert!(service.flag(&challenge("old-id", "fn bad() {}")).unwrap());
+
+    let kept = service.exclude_flagged(vec![
+        challenge("new-id", "fn bad() {}"),
+        challenge("good", "fn good() {}"),
+    ]);
+
+    assert_eq!(kept.len(), 1);
+    assert_eq!(kept[0].id, "good");
+}
+
+#[test]
+fn count_flagged_counts_only_given_challenges() {
+    let service = service();
+    service.flag(&challenge("a", "fn a() {}")).unwrap();
+    service.flag(&challenge("b", "fn b() {}")).unwrap();
+
+    let count = service
+        .count_flagged(&[challenge("a", "fn a() {}"), challenge("c", "fn c() {}")])
+        .unwrap();
+
+    assert_eq!(count, 1);
+}
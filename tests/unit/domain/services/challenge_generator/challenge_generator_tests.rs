@@ -1,12 +1,11 @@
+use gittype::domain::models::loading::ProgressReporter;
 use gittype::domain::models::loading::StepType;
 use gittype::domain::models::{
     Challenge, ChunkType, CodeChunk, DifficultyLevel, ExtractionOptions, Languages,
 };
 use gittype::domain::services::challenge_generator::ChallengeGenerator;
 use gittype::domain::services::source_code_parser::SourceCodeParser;
-use gittype::presentation::tui::screens::loading_screen::ProgressReporter;
 use std::collections::HashMap;
-use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
 // Mock ProgressReporter for testing
@@ -63,12 +62,9 @@ impl ProgressReporter for MockProgressReporter {
 
 fn create_real_code_chunks_from_fixture(fixture_filename: &str) -> Vec<CodeChunk> {
     use gittype::infrastructure::storage::file_storage::FileStorage;
-    use std::fs;
 
-    let fixture_path = PathBuf::from("tests/fixtures").join(fixture_filename);
-
-    // Read the fixture file content
-    let content = fs::read_to_string(&fixture_path).expect("Failed to read fixture file");
+    let fixture_path = crate::fixtures::sources::logical_path(fixture_filename);
+    let content = crate::fixtures::sources::content(fixture_filename).to_string();
 
     // Create a parser with mock FileStorage that has the fixture content
     let mut file_storage = FileStorage::new();
@@ -319,3 +315,31 @@ fn test_progress_reporting() {
         "Should report generating step"
     );
 }
+
+#[test]
+fn convert_snippets_plays_each_snippet_whole_at_every_difficulty() {
+    let content = "fn long() {\n".to_string() + &"    step();\n".repeat(200) + "}";
+    let chunk = CodeChunk {
+        content: content.clone(),
+        file_path: "long.rs".into(),
+        start_line: 1,
+        end_line: 202,
+        language: "rust".to_string(),
+        chunk_type: ChunkType::File,
+        name: "long".to_string(),
+        comment_ranges: vec![],
+        original_indentation: 0,
+    };
+
+    let challenges = ChallengeGenerator::new().convert_snippets(vec![chunk]);
+
+    assert_eq!(challenges.len(), DifficultyLevel::ALL.len());
+    assert!(challenges
+        .iter()
+        .all(|challenge| challenge.code_content == content));
+    let difficulties: Vec<_> = challenges
+        .iter()
+        .filter_map(|challenge| challenge.difficulty_level)
+        .collect();
+    assert_eq!(difficulties, DifficultyLevel::ALL.to_vec());
+}
@@ -311,3 +311,52 @@ fn split_returns_none_when_truncation_selects_only_blank_lines() {
 
     assert!(result.is_none());
 }
+
+#[test]
+fn split_prose_cuts_after_the_last_sentence_within_the_limit() {
+    let splitter = ChunkSplitter::new();
+    let mut chunk = create_test_chunk(
+        "The first sentence is short. The second one is a little longer than that.\nA third sentence pushes the paragraph past the easy limit entirely.",
+        vec![],
+    );
+    chunk.chunk_type = ChunkType::Prose;
+
+    let (content, comment_ranges, end_line) = splitter
+        .split_prose(&chunk, &DifficultyLevel::Easy)
+        .unwrap();
+
+    assert_eq!(
+        content,
+        "The first sentence is short. The second one is a little longer than that."
+    );
+    assert!(comment_ranges.is_empty());
+    assert_eq!(end_line, 1);
+}
+
+#[test]
+fn split_prose_returns_none_without_a_sentence_end_in_range() {
+    let splitter = ChunkSplitter::new();
+    let mut chunk = create_test_chunk(&"word ".repeat(60), vec![]);
+    chunk.chunk_type = ChunkType::Prose;
+
+    assert!(splitter
+        .split_prose(&chunk, &DifficultyLevel::Easy)
+        .is_none());
+}
+
+#[test]
+fn split_weighted_cuts_complex_code_shorter() {
+    let splitter = ChunkSplitter::new();
+    let content = (0..20)
+        .map(|i| format!("let value_{} = {};", i, i))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let chunk = create_test_chunk(&content, vec![]);
+
+    let (plain, _, _) = splitter.split(&chunk, &DifficultyLevel::Normal).unwrap();
+    let (weighted, _, _) = splitter
+        .split_weighted(&chunk, &DifficultyLevel::Normal, 1.5)
+        .unwrap();
+
+    assert!(weighted.len() < plain.len());
+}
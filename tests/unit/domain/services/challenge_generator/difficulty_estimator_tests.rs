@@ -0,0 +1,72 @@
+use gittype::domain::models::{ChunkType, CodeChunk};
+use gittype::domain::services::challenge_generator::DifficultyEstimator;
+use std::path::PathBuf;
+
+fn chunk(content: &str) -> CodeChunk {
+    CodeChunk {
+        content: content.to_string(),
+        file_path: PathBuf::from("test.rs"),
+        start_line: 1,
+        end_line: content.lines().count(),
+        language: "rust".to_string(),
+        chunk_type: ChunkType::Function,
+        name: "test".to_string(),
+        comment_ranges: vec![],
+        original_indentation: 0,
+    }
+}
+
+#[test]
+fn regex_heavy_code_is_harder_than_struct_fields_of_the_same_length() {
+    let fields = chunk(
+        "struct User {\n    name: String,\n    email: String,\n    address: String,\n    country: String,\n    created: u64,\n}",
+    );
+    let regexes = chunk(
+        "fn patterns() {\n    let a = r\"^(\\d{3})-(\\w+)$\";\n    let b = r\"[a-z]+@[a-z]+\\.[a-z]{2,}\";\n    let c = r\"(?i)^\\s*#.*$\";\n}",
+    );
+    let estimator = DifficultyEstimator::new();
+
+    assert!(estimator.complexity(&regexes) > estimator.complexity(&fields));
+    assert!(estimator.complexity(&fields) < 1.0);
+    assert!(estimator.complexity(&regexes) > 1.5);
+}
+
+#[test]
+fn complexity_ignores_symbols_inside_comments() {
+    let plain = chunk("let total = price;\nlet count = items;");
+    let mut commented = chunk("let total = price; // {[(<>)]}!?\nlet count = items;");
+    commented.comment_ranges = vec![(19, 33)];
+    let estimator = DifficultyEstimator::new();
+
+    assert!(
+        (estimator.complexity(&plain) - estimator.complexity(&commented)).abs() < 0.15,
+        "comment symbols should not count as code symbols"
+    );
+}
+
+#[test]
+fn comment_ranges_are_byte_offsets_after_multibyte_characters() {
+    let with_comment = |content: &str| {
+        let mut chunk = chunk(content);
+        let start = content.find("//").unwrap();
+        chunk.comment_ranges = vec![(start, content.find('\n').unwrap())];
+        chunk
+    };
+    let ascii = with_comment("let s = \"eeeeee\"; // {[(<>)]}!? x\nlet count = items;");
+    let multibyte = with_comment("let s = \"éééééé\"; // {[(<>)]}!? x\nlet count = items;");
+    let estimator = DifficultyEstimator::new();
+
+    assert_eq!(
+        estimator.complexity(&ascii),
+        estimator.complexity(&multibyte)
+    );
+}
+
+#[test]
+fn complexity_is_clamped_and_neutral_for_empty_chunks() {
+    let estimator = DifficultyEstimator::new();
+
+    assert_eq!(estimator.complexity(&chunk("")), 1.0);
+    assert!(estimator.complexity(&chunk("{}[]();{}[]();{}[]();")) <= 2.5);
+    assert!(estimator.complexity(&chunk("word word word word word")) >= 0.75);
+}
@@ -1,4 +1,5 @@
 mod challenge_generator_tests;
 mod chunk_splitter_tests;
 mod code_character_counter_tests;
+mod difficulty_estimator_tests;
 mod progress_tracker_tests;
@@ -1,4 +1,5 @@
 use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::config::Config;
 use gittype::domain::services::config_service::{ConfigService, ConfigServiceInterface};
 use gittype::infrastructure::storage::file_storage::{FileEntry, FileStorageInterface};
 use gittype::GitTypeError;
@@ -17,7 +18,11 @@ impl FileStorageInterface for NonFileStorage {
         false
     }
 
-    fn walk_directory(&self, _path: &Path) -> gittype::Result<Vec<FileEntry>> {
+    fn walk_directory(
+        &self,
+        _path: &Path,
+        _follow_symlinks: bool,
+    ) -> gittype::Result<Vec<FileEntry>> {
         Ok(Vec::new())
     }
 
@@ -25,6 +30,10 @@ impl FileStorageInterface for NonFileStorage {
         Ok(String::new())
     }
 
+    fn read(&self, _file_path: &Path) -> gittype::Result<Vec<u8>> {
+        Ok(Vec::new())
+    }
+
     fn create_dir_all(&self, _path: &Path) -> gittype::Result<()> {
         Ok(())
     }
@@ -180,3 +189,15 @@ fn test_new_rejects_non_file_storage() {
         if message == "Failed to downcast storage"
     ));
 }
+
+#[test]
+fn test_apply_settings_reports_an_unreadable_rank_messages_file() {
+    let mut config = Config::default();
+    config.commentary.messages_file = Some(PathBuf::from("/missing/rank-messages.json"));
+
+    let error = ConfigService::apply_settings(&config).unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("Failed to load rank messages from /missing/rank-messages.json"));
+}
@@ -0,0 +1,120 @@
+use gittype::domain::models::DifficultyLevel;
+use gittype::domain::services::config_service::ConfigService;
+use gittype::domain::services::difficulty_gate_service::{
+    DifficultyGateService, DifficultyGateServiceInterface,
+};
+use gittype::infrastructure::database::daos::{DifficultyUnlockDao, DifficultyUnlockDaoInterface};
+use gittype::infrastructure::database::database::{Database, DatabaseInterface};
+use std::sync::Arc;
+
+fn database() -> Arc<dyn DatabaseInterface> {
+    let db = Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>;
+    db.get_connection()
+        .unwrap()
+        .execute(
+            "INSERT INTO repositories (id, user_name, repository_name, remote_url)
+             VALUES (1, 'owner', 'repo', 'https://github.com/owner/repo')",
+            [],
+        )
+        .unwrap();
+    db
+}
+
+/// Record a session by the default player
+fn play(db: &Arc<dyn DatabaseInterface>, difficulty: &str, accuracy: f64) {
+    let conn = db.get_connection().unwrap();
+    conn.execute(
+        "INSERT INTO sessions (repository_id, started_at, game_mode)
+         VALUES (1, '2026-01-01 00:00:00', ?)",
+        [difficulty],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO session_results (session_id, repository_id, keystrokes, mistakes,
+             duration_ms, accuracy, stages_completed, stages_attempted, stages_skipped,
+             difficulty_level)
+         VALUES (last_insert_rowid(), 1, 100, 0, 60000, ?, 3, 3, 0, ?)",
+        rusqlite::params![accuracy, difficulty],
+    )
+    .unwrap();
+}
+
+fn service(db: &Arc<dyn DatabaseInterface>, required_accuracy: f64) -> DifficultyGateService {
+    let config_service = ConfigService::new_for_test().unwrap();
+    config_service
+        .update_config(|config| {
+            config.difficulty_gates.enabled = true;
+            config.difficulty_gates.required_accuracy = required_accuracy;
+        })
+        .unwrap();
+    DifficultyGateService::new(
+        Arc::new(DifficultyUnlockDao::new(Arc::clone(db))),
+        Arc::new(config_service),
+    )
+}
+
+fn locked(service: &DifficultyGateService) -> Vec<DifficultyLevel> {
+    service
+        .locked_difficulties()
+        .unwrap()
+        .iter()
+        .map(|lock| lock.difficulty)
+        .collect()
+}
+
+#[test]
+fn nothing_is_locked_while_gates_are_disabled() {
+    let db = database();
+    let service = DifficultyGateService::new(
+        Arc::new(DifficultyUnlockDao::new(Arc::clone(&db))),
+        Arc::new(ConfigService::new_for_test().unwrap()),
+    );
+
+    assert!(service.locked_difficulties().unwrap().is_empty());
+}
+
+#[test]
+fn hard_and_wild_start_locked() {
+    let db = database();
+    let locks = service(&db, 95.0).locked_difficulties().unwrap();
+
+    assert_eq!(locks.len(), 2);
+    assert_eq!(locks[0].difficulty, DifficultyLevel::Hard);
+    assert_eq!(locks[0].requires, DifficultyLevel::Normal);
+    assert_eq!(locks[0].best_accuracy, None);
+    assert_eq!(locks[1].difficulty, DifficultyLevel::Wild);
+    assert_eq!(locks[1].requires, DifficultyLevel::Hard);
+}
+
+#[test]
+fn reaching_the_accuracy_unlocks_the_next_tier_only() {
+    let db = database();
+    play(&db, "Normal", 94.0);
+    let service = service(&db, 95.0);
+
+    let locks = service.locked_difficulties().unwrap();
+    assert_eq!(locks[0].best_accuracy, Some(94.0));
+    assert_eq!(
+        locked(&service),
+        vec![DifficultyLevel::Hard, DifficultyLevel::Wild]
+    );
+
+    play(&db, "Normal", 96.0);
+    assert_eq!(locked(&service), vec![DifficultyLevel::Wild]);
+
+    play(&db, "Hard", 95.0);
+    assert!(locked(&service).is_empty());
+}
+
+#[test]
+fn unlocks_stay_when_the_required_accuracy_is_raised() {
+    let db = database();
+    play(&db, "Normal", 96.0);
+    assert_eq!(locked(&service(&db, 95.0)), vec![DifficultyLevel::Wild]);
+
+    assert_eq!(locked(&service(&db, 99.0)), vec![DifficultyLevel::Wild]);
+    assert_eq!(
+        DifficultyUnlockDao::new(db).get_unlocked("Player").unwrap(),
+        vec![DifficultyLevel::Hard]
+    );
+}
@@ -0,0 +1,83 @@
+use gittype::domain::models::Presence;
+use gittype::domain::services::config_service::{ConfigService, ConfigServiceInterface};
+use gittype::domain::services::discord_presence_service::{
+    DiscordPresenceService, DiscordPresenceServiceInterface,
+};
+use std::sync::Arc;
+
+fn service(enabled: bool) -> DiscordPresenceService {
+    let config_service = ConfigService::new_for_test().unwrap();
+    config_service
+        .update_config(|config| config.discord.enabled = enabled)
+        .unwrap();
+    DiscordPresenceService::new(Arc::new(config_service) as Arc<dyn ConfigServiceInterface>)
+}
+
+fn presence(current_stage: usize, wpm: f64) -> Presence {
+    Presence {
+        language: Some("rust".to_string()),
+        repository: Some("tokio".to_string()),
+        current_stage,
+        total_stages: 5,
+        wpm,
+    }
+}
+
+#[test]
+fn update_does_nothing_when_disabled() {
+    let service = service(false);
+
+    service.update(&presence(1, 40.0));
+
+    assert!(service.sent_activities_for_test().is_empty());
+}
+
+#[test]
+fn update_sends_details_and_state_when_enabled() {
+    let service = service(true);
+
+    service.update(&presence(3, 72.0));
+
+    let activities = service.sent_activities_for_test();
+    assert_eq!(activities.len(), 1);
+    let activity = activities[0].as_ref().unwrap();
+    assert_eq!(activity["details"], "Typing rust in tokio");
+    assert_eq!(activity["state"], "Stage 3/5, 72 WPM");
+    assert!(activity["timestamps"]["start"].is_i64());
+}
+
+#[test]
+fn update_throttles_wpm_only_changes() {
+    let service = service(true);
+
+    service.update(&presence(1, 40.0));
+    service.update(&presence(1, 55.0));
+
+    assert_eq!(service.sent_activities_for_test().len(), 1);
+}
+
+#[test]
+fn update_sends_immediately_on_stage_change() {
+    let service = service(true);
+
+    service.update(&presence(1, 40.0));
+    service.update(&presence(2, 40.0));
+
+    assert_eq!(service.sent_activities_for_test().len(), 2);
+}
+
+#[test]
+fn clear_resets_activity_after_update() {
+    let service = service(true);
+
+    service.update(&presence(1, 40.0));
+    service.clear();
+    service.clear();
+
+    assert_eq!(
+        service.sent_activities_for_test().last(),
+        Some(&None),
+        "clear should send an empty activity once"
+    );
+    assert_eq!(service.sent_activities_for_test().len(), 2);
+}
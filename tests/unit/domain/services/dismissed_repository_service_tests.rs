@@ -0,0 +1,71 @@
+use gittype::domain::repositories::trending_repository::TrendingRepositoryInfo;
+use gittype::domain::services::dismissed_repository_service::{
+    DismissedRepositoryService, DismissedRepositoryServiceInterface,
+};
+use gittype::infrastructure::database::daos::DismissedRepositoryDao;
+use gittype::infrastructure::database::database::Database;
+use std::sync::Arc;
+
+fn service() -> DismissedRepositoryService {
+    DismissedRepositoryService::new(Arc::new(DismissedRepositoryDao::new(Arc::new(
+        Database::new().unwrap(),
+    ))))
+}
+
+fn trending(repo_name: &str) -> TrendingRepositoryInfo {
+    TrendingRepositoryInfo {
+        repo_name: repo_name.to_string(),
+        primary_language: None,
+        description: None,
+        stars: "0".to_string(),
+        forks: "0".to_string(),
+        total_score: "0".to_string(),
+    }
+}
+
+#[test]
+fn dismiss_rejects_names_not_in_owner_repo_format() {
+    let service = service();
+
+    assert!(service.dismiss("rust").is_err());
+    assert!(service.dismiss("/rust").is_err());
+    assert!(service.dismiss("rust-lang/").is_err());
+    assert!(service.dismiss("a/b/c").is_err());
+    assert!(service.list().unwrap().is_empty());
+}
+
+#[test]
+fn dismissed_repositories_are_recognized_regardless_of_case() {
+    let service = service();
+
+    assert!(service.dismiss(" rust-lang/rust ").unwrap());
+
+    assert!(service.is_dismissed("Rust-Lang/Rust").unwrap());
+    assert!(!service.is_dismissed("golang/go").unwrap());
+    assert_eq!(service.list().unwrap(), vec!["rust-lang/rust".to_string()]);
+}
+
+#[test]
+fn restore_allows_a_repository_again() {
+    let service = service();
+    service.dismiss("rust-lang/rust").unwrap();
+
+    assert!(service.restore("rust-lang/rust").unwrap());
+    assert!(!service.restore("rust-lang/rust").unwrap());
+    assert!(!service.is_dismissed("rust-lang/rust").unwrap());
+}
+
+#[test]
+fn exclude_dismissed_keeps_the_order_of_the_rest() {
+    let service = service();
+    service.dismiss("RUST-LANG/rust").unwrap();
+
+    let remaining = service.exclude_dismissed(vec![
+        trending("golang/go"),
+        trending("rust-lang/rust"),
+        trending("denoland/deno"),
+    ]);
+
+    let names: Vec<&str> = remaining.iter().map(|r| r.repo_name.as_str()).collect();
+    assert_eq!(names, vec!["golang/go", "denoland/deno"]);
+}
@@ -0,0 +1,82 @@
+use gittype::domain::services::editor_session::EditorSession;
+use gittype::GitTypeError;
+
+#[test]
+fn load_skips_comments_for_known_language() {
+    let mut session = EditorSession::new();
+
+    let challenge = session
+        .load(
+            "fn main() {\n    // say hi\n    hi();\n}",
+            None,
+            Some("src/main.rs".to_string()),
+        )
+        .unwrap();
+
+    assert_eq!(challenge.language.as_deref(), Some("rust"));
+    assert!(!challenge.text_to_type.contains("say hi"));
+    assert!(challenge.text_to_type.contains("hi();"));
+}
+
+#[test]
+fn load_rejects_empty_text() {
+    let mut session = EditorSession::new();
+
+    let result = session.load("   \n", Some("rust"), None);
+
+    assert!(matches!(result, Err(GitTypeError::ValidationError(_))));
+    assert!(!session.is_loaded());
+}
+
+#[test]
+fn input_requires_started_challenge() {
+    let mut session = EditorSession::new();
+    session.load("let x = 1;", Some("rust"), None).unwrap();
+
+    assert!(matches!(
+        session.input("l"),
+        Err(GitTypeError::ValidationError(msg)) if msg.contains("not been started")
+    ));
+}
+
+#[test]
+fn typing_full_text_completes_and_scores() {
+    let mut session = EditorSession::new();
+    session.load("let x = 1;", Some("rust"), None).unwrap();
+    session.start().unwrap();
+
+    let progress = session.input("let x = 2").unwrap();
+    assert_eq!(progress.mistakes, 1);
+    assert!(!progress.completed);
+
+    let progress = session.input("1;").unwrap();
+    assert!(progress.completed);
+    assert_eq!(progress.position, progress.total);
+
+    let result = session.finish().unwrap();
+    assert_eq!(result.mistakes, 1);
+    assert!(!result.was_skipped);
+    assert!(!session.is_loaded());
+}
+
+#[test]
+fn finishing_early_marks_challenge_skipped() {
+    let mut session = EditorSession::new();
+    session.load("let x = 1;", Some("rust"), None).unwrap();
+    session.start().unwrap();
+    session.input("let").unwrap();
+
+    let result = session.finish().unwrap();
+
+    assert!(result.was_skipped);
+}
+
+#[test]
+fn finish_without_challenge_returns_error() {
+    let mut session = EditorSession::new();
+
+    assert!(matches!(
+        session.finish(),
+        Err(GitTypeError::ValidationError(_))
+    ));
+}
@@ -0,0 +1,121 @@
+use gittype::domain::services::license_detector::LicenseDetector;
+use tempfile::TempDir;
+
+const MIT_TEXT: &str = "MIT License\n\nCopyright (c) 2024 Someone\n\nPermission is hereby granted, free of charge,\nto any person obtaining a copy";
+const APACHE_TEXT: &str = "                                 Apache License\n                           Version 2.0, January 2004";
+
+#[test]
+fn identify_recognizes_well_known_texts() {
+    assert_eq!(LicenseDetector::identify(MIT_TEXT).as_deref(), Some("MIT"));
+    assert_eq!(
+        LicenseDetector::identify(APACHE_TEXT).as_deref(),
+        Some("Apache-2.0")
+    );
+    assert_eq!(
+        LicenseDetector::identify("GNU GENERAL PUBLIC LICENSE\n Version 3, 29 June 2007")
+            .as_deref(),
+        Some("GPL-3.0")
+    );
+    assert_eq!(
+        LicenseDetector::identify("GNU LESSER GENERAL PUBLIC LICENSE\nVersion 3").as_deref(),
+        Some("LGPL-3.0")
+    );
+}
+
+#[test]
+fn identify_tells_bsd_variants_apart() {
+    let two_clause = "Redistribution and use in source and binary forms ... Redistributions in binary form must reproduce the above";
+    let three_clause = format!(
+        "{} ... Neither the name of the copyright holder",
+        two_clause
+    );
+
+    assert_eq!(
+        LicenseDetector::identify(two_clause).as_deref(),
+        Some("BSD-2-Clause")
+    );
+    assert_eq!(
+        LicenseDetector::identify(&three_clause).as_deref(),
+        Some("BSD-3-Clause")
+    );
+}
+
+#[test]
+fn identify_prefers_the_spdx_tag() {
+    let text =
+        "// SPDX-License-Identifier: MPL-2.0 */\nPermission is hereby granted, free of charge";
+
+    assert_eq!(LicenseDetector::identify(text).as_deref(), Some("MPL-2.0"));
+}
+
+#[test]
+fn identify_returns_none_for_unknown_text() {
+    assert_eq!(LicenseDetector::identify("All rights reserved."), None);
+}
+
+#[test]
+fn detect_reads_the_license_file() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("LICENSE.md"), MIT_TEXT).unwrap();
+
+    assert_eq!(LicenseDetector::detect(dir.path()).as_deref(), Some("MIT"));
+}
+
+#[test]
+fn detect_offers_several_license_files_as_alternatives() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("LICENSE-MIT"), MIT_TEXT).unwrap();
+    std::fs::write(dir.path().join("LICENSE-APACHE"), APACHE_TEXT).unwrap();
+
+    assert_eq!(
+        LicenseDetector::detect(dir.path()).as_deref(),
+        Some("Apache-2.0 OR MIT")
+    );
+}
+
+#[test]
+fn detect_ignores_files_that_only_start_like_a_license() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(dir.path().join("licenses_test.rs"), MIT_TEXT).unwrap();
+
+    assert_eq!(LicenseDetector::detect(dir.path()), None);
+}
+
+#[test]
+fn detect_falls_back_to_the_cargo_manifest() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("Cargo.toml"),
+        "[package]\nname = \"demo\"\nlicense = \"MIT OR Apache-2.0\"\n\n[dependencies]\nlicense = \"1\"\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        LicenseDetector::detect(dir.path()).as_deref(),
+        Some("MIT OR Apache-2.0")
+    );
+}
+
+#[test]
+fn detect_falls_back_to_package_json() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("package.json"),
+        r#"{"name": "demo", "license": "ISC"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(LicenseDetector::detect(dir.path()).as_deref(), Some("ISC"));
+}
+
+#[test]
+fn detect_returns_none_without_license_information() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("package.json"),
+        r#"{"name": "demo", "license": "UNLICENSED"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(LicenseDetector::detect(dir.path()), None);
+}
@@ -1,6 +1,14 @@
+mod achievement_service_tests;
 mod analytics_service_tests;
+mod challenge_flag_service_tests;
 mod challenge_generator;
 mod config_service_tests;
+mod difficulty_gate_service_tests;
+mod discord_presence_service_tests;
+mod dismissed_repository_service_tests;
+mod editor_session_tests;
+mod license_detector_tests;
+mod repository_rating_service_tests;
 mod repository_service_tests;
 pub mod scoring;
 mod session_manager_service_tests;
@@ -8,6 +16,8 @@ mod session_service_tests;
 mod source_code_parser;
 mod source_file_extractor;
 mod stage_builder_service_tests;
+mod streamer_stats_service_tests;
 mod theme_manager_tests;
 mod typing_core_tests;
 mod version_service_tests;
+mod workspace_detector_tests;
@@ -0,0 +1,89 @@
+use gittype::domain::repositories::trending_repository::TrendingRepositoryInfo;
+use gittype::domain::services::repository_rating_service::{
+    RepositoryRatingService, RepositoryRatingServiceInterface,
+};
+use gittype::infrastructure::database::daos::RepositoryRatingDao;
+use gittype::infrastructure::database::database::{Database, DatabaseInterface};
+use std::sync::Arc;
+
+/// `owner/loved` (Rust) and `owner/disliked` (Go), each with one session
+fn service() -> RepositoryRatingService {
+    let db = Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>;
+    db.get_connection()
+        .unwrap()
+        .execute_batch(
+            "INSERT INTO repositories (id, user_name, repository_name, remote_url, primary_language)
+             VALUES (1, 'owner', 'loved', 'https://github.com/owner/loved', 'Rust'),
+                    (2, 'owner', 'disliked', 'https://github.com/owner/disliked', 'Go');
+             INSERT INTO sessions (id, repository_id, started_at, game_mode)
+             VALUES (1, 1, '2026-01-01 00:00:00', 'Normal'),
+                    (2, 2, '2026-01-02 00:00:00', 'Normal');",
+        )
+        .unwrap();
+    RepositoryRatingService::new(Arc::new(RepositoryRatingDao::new(db)))
+}
+
+fn trending(repo_name: &str, primary_language: Option<&str>) -> TrendingRepositoryInfo {
+    TrendingRepositoryInfo {
+        repo_name: repo_name.to_string(),
+        primary_language: primary_language.map(str::to_string),
+        description: None,
+        stars: "0".to_string(),
+        forks: "0".to_string(),
+        total_score: "0".to_string(),
+    }
+}
+
+fn names(repositories: &[TrendingRepositoryInfo]) -> Vec<&str> {
+    repositories
+        .iter()
+        .map(|repository| repository.repo_name.as_str())
+        .collect()
+}
+
+#[test]
+fn rate_session_rejects_ratings_outside_one_to_five() {
+    let service = service();
+
+    assert!(service.rate_session(1, 0).is_err());
+    assert!(service.rate_session(1, 6).is_err());
+    assert!(service.rate_session(1, 5).unwrap());
+}
+
+#[test]
+fn sort_by_preference_keeps_the_order_without_ratings() {
+    let repositories = vec![
+        trending("a/one", Some("Go")),
+        trending("a/two", Some("Rust")),
+    ];
+
+    let sorted = service().sort_by_preference(repositories);
+
+    assert_eq!(names(&sorted), ["a/one", "a/two"]);
+}
+
+#[test]
+fn sort_by_preference_ranks_by_own_then_language_ratings() {
+    let service = service();
+    service.rate_session(1, 5).unwrap();
+    service.rate_session(2, 1).unwrap();
+
+    let sorted = service.sort_by_preference(vec![
+        trending("other/go-project", Some("Go")),
+        trending("other/unknown", None),
+        trending("OWNER/DISLIKED", Some("Rust")),
+        trending("other/rust-project", Some("rust")),
+        trending("owner/loved", None),
+    ]);
+
+    assert_eq!(
+        names(&sorted),
+        [
+            "other/rust-project",
+            "owner/loved",
+            "other/unknown",
+            "other/go-project",
+            "OWNER/DISLIKED",
+        ]
+    );
+}
@@ -57,6 +57,7 @@ fn test_get_all_repositories_with_data() {
         commit_hash: Some("reposervice123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let challenge = Challenge::new("reposervice-test".to_string(), "test code".to_string());
@@ -153,6 +154,7 @@ fn get_all_repositories_with_cache_status_marks_uncached_repository() {
         commit_hash: None,
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     repository_dao.ensure_repository(&git_repo).unwrap();
@@ -8,7 +8,7 @@ fn test_calculate_basic() {
     let current_position = 100;
     let mistakes = 0;
     let elapsed_time = Duration::from_secs(60);
-    let result = RealTimeCalculator::calculate(current_position, mistakes, elapsed_time);
+    let result = RealTimeCalculator::calculate("", current_position, mistakes, elapsed_time);
 
     assert!((result.wpm - 20.0).abs() < EPSILON);
     assert!((result.cpm - 100.0).abs() < EPSILON);
@@ -21,7 +21,7 @@ fn test_calculate_with_mistakes() {
     let current_position = 100;
     let mistakes = 10;
     let elapsed_time = Duration::from_secs(60);
-    let result = RealTimeCalculator::calculate(current_position, mistakes, elapsed_time);
+    let result = RealTimeCalculator::calculate("", current_position, mistakes, elapsed_time);
 
     assert!((result.wpm - 20.0).abs() < EPSILON); // CPM is based on total characters, not correct ones
     assert!((result.cpm - 100.0).abs() < EPSILON);
@@ -34,7 +34,7 @@ fn test_calculate_zero_position() {
     let current_position = 0;
     let mistakes = 0;
     let elapsed_time = Duration::from_secs(10);
-    let result = RealTimeCalculator::calculate(current_position, mistakes, elapsed_time);
+    let result = RealTimeCalculator::calculate("", current_position, mistakes, elapsed_time);
 
     assert!((result.wpm - 0.0).abs() < EPSILON);
     assert!((result.cpm - 0.0).abs() < EPSILON);
@@ -47,7 +47,7 @@ fn test_calculate_zero_elapsed_time() {
     let current_position = 50;
     let mistakes = 0;
     let elapsed_time = Duration::from_millis(0);
-    let result = RealTimeCalculator::calculate(current_position, mistakes, elapsed_time);
+    let result = RealTimeCalculator::calculate("", current_position, mistakes, elapsed_time);
 
     // elapsed_secs is max(0.1), so 50 / 0.1 * 60 = 30000
     assert!((result.cpm - 30000.0).abs() < EPSILON);
@@ -61,10 +61,46 @@ fn test_calculate_high_mistakes() {
     let current_position = 10;
     let mistakes = 10;
     let elapsed_time = Duration::from_secs(1);
-    let result = RealTimeCalculator::calculate(current_position, mistakes, elapsed_time);
+    let result = RealTimeCalculator::calculate("", current_position, mistakes, elapsed_time);
 
     assert!((result.wpm - 120.0).abs() < EPSILON); // CPM is based on total characters, not correct ones
     assert!((result.cpm - 600.0).abs() < EPSILON);
     assert!((result.accuracy - 0.0).abs() < EPSILON);
     assert_eq!(result.mistakes, 10);
 }
+
+#[test]
+fn test_wpm_history_has_a_point_per_whole_second() {
+    // Two keystrokes every second for three and a half seconds
+    let keystroke_times: Vec<Duration> = (1..=7).map(|i| Duration::from_millis(i * 500)).collect();
+    let history = RealTimeCalculator::wpm_history(
+        "",
+        &keystroke_times,
+        Duration::from_millis(3500),
+        Duration::from_secs(2),
+    );
+
+    // The first second only looks back one second: 2 keystrokes, 120 CPM
+    assert_eq!(history.len(), 3);
+    assert!(history.iter().all(|wpm| (wpm - 24.0).abs() < EPSILON));
+}
+
+#[test]
+fn test_wpm_history_follows_changes_in_speed() {
+    let keystroke_times = [
+        Duration::from_millis(200),
+        Duration::from_millis(1100),
+        Duration::from_millis(1300),
+        Duration::from_millis(1500),
+        Duration::from_millis(1700),
+    ];
+    let history = RealTimeCalculator::wpm_history(
+        "",
+        &keystroke_times,
+        Duration::from_secs(2),
+        Duration::from_secs(1),
+    );
+
+    assert!((history[0] - 12.0).abs() < EPSILON);
+    assert!((history[1] - 48.0).abs() < EPSILON);
+}
@@ -47,6 +47,9 @@ fn test_calculate_basic_correct() {
 
     assert!(result.cpm > 0.0);
     assert!(result.wpm > 0.0);
+    // Recorded WPM counts five-character words whatever the display mode
+    assert!((result.wpm - result.cpm / 5.0).abs() < EPSILON);
+    assert!((result.token_density - 0.2).abs() < EPSILON);
     assert!((result.accuracy - 100.0).abs() < EPSILON);
     assert_eq!(result.keystrokes, 5);
     assert_eq!(result.mistakes, 0);
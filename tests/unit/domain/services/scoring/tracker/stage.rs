@@ -1,3 +1,4 @@
+use gittype::domain::models::{Finger, KeyboardLayout, MistakeBreakdown, MistakeKind};
 use gittype::domain::services::scoring::tracker::{StageInput, StageTracker};
 use std::time::Duration;
 
@@ -47,6 +48,71 @@ fn test_record_keystroke_incorrect() {
     assert!(data.streaks.is_empty());
 }
 
+#[test]
+fn test_mistakes_are_classified_by_kind() {
+    let mut tracker = StageTracker::new("Let x: u8".to_string());
+    tracker.record(StageInput::Start);
+    for (ch, position) in [
+        ('l', 0),
+        ('L', 0),
+        ('t', 1),
+        ('w', 1),
+        ('e', 1),
+        ('t', 2),
+        (';', 5),
+        ('p', 7),
+    ] {
+        tracker.record(StageInput::Keystroke { ch, position });
+    }
+
+    let data = tracker.get_data();
+    assert_eq!(data.keystrokes[0].mistake, Some(MistakeKind::Case));
+    assert_eq!(data.keystrokes[1].mistake, None);
+    assert_eq!(
+        tracker.mistake_breakdown(),
+        MistakeBreakdown {
+            adjacent_key: 1,
+            case: 1,
+            shifted_symbol: 1,
+            transposition: 1,
+            other: 1,
+        }
+    );
+}
+
+#[test]
+fn test_finger_load_follows_expected_characters() {
+    let mut tracker = StageTracker::new("fj k".to_string());
+    tracker.record(StageInput::Start);
+    for (ch, position) in [('f', 0), ('k', 1), ('j', 1), (' ', 2), ('k', 3), ('x', 9)] {
+        tracker.record(StageInput::Keystroke { ch, position });
+    }
+
+    let load = tracker.key_load().by_finger(&KeyboardLayout::default());
+    assert_eq!(load.stats(Finger::LeftIndex).keystrokes, 1);
+    assert_eq!(load.stats(Finger::RightIndex).keystrokes, 2);
+    assert_eq!(load.stats(Finger::RightIndex).mistakes, 1);
+    assert_eq!(load.stats(Finger::Thumb).keystrokes, 1);
+    assert_eq!(load.stats(Finger::RightMiddle).keystrokes, 1);
+    assert_eq!(load.total_keystrokes(), 5);
+}
+
+#[test]
+fn test_key_load_follows_expected_characters() {
+    let mut tracker = StageTracker::new("a::b".to_string());
+    tracker.record(StageInput::Start);
+    for (ch, position) in [('a', 0), (';', 1), (':', 1), (':', 2), ('b', 3), ('x', 9)] {
+        tracker.record(StageInput::Keystroke { ch, position });
+    }
+
+    let load = tracker.key_load();
+    assert_eq!(load.stats(':').keystrokes, 3);
+    assert_eq!(load.stats(':').mistakes, 1);
+    assert_eq!(load.stats('a').keystrokes, 1);
+    assert_eq!(load.stats('b').mistakes, 0);
+    assert_eq!(load.stats(';').keystrokes, 0);
+}
+
 #[test]
 fn test_streaks() {
     let mut tracker = StageTracker::new("abc".to_string());
@@ -290,3 +356,53 @@ fn test_set_start_time_overrides_default() {
         data.elapsed_time
     );
 }
+
+#[test]
+fn test_latency_compensation_is_removed_on_finish() {
+    let mut tracker = StageTracker::new("test".to_string());
+    tracker.set_start_time(std::time::Instant::now() - Duration::from_millis(200));
+    tracker.set_latency_compensation(Duration::from_millis(50));
+    tracker.record(StageInput::Start);
+    tracker.record(StageInput::Finish);
+
+    let data = tracker.get_data();
+    assert!(
+        data.elapsed_time >= Duration::from_millis(150)
+            && data.elapsed_time < Duration::from_millis(190),
+        "expected 200ms minus 50ms compensation, got {:?}",
+        data.elapsed_time
+    );
+}
+
+#[test]
+fn test_latency_compensation_never_goes_negative() {
+    let mut tracker = StageTracker::new("test".to_string());
+    tracker.set_latency_compensation(Duration::from_secs(10));
+    tracker.record(StageInput::Start);
+    tracker.record(StageInput::Finish);
+
+    assert_eq!(tracker.get_data().elapsed_time, Duration::ZERO);
+}
+
+#[test]
+fn test_recent_mistakes_keeps_latest_expected_and_typed_pairs() {
+    let mut tracker = StageTracker::new("a b\nc".to_string());
+    tracker.record(StageInput::Start);
+    [
+        ('x', 0),
+        ('a', 0),
+        ('_', 1),
+        (' ', 1),
+        ('q', 2),
+        ('b', 2),
+        ('x', 3),
+    ]
+    .into_iter()
+    .for_each(|(ch, position)| tracker.record(StageInput::Keystroke { ch, position }));
+
+    assert_eq!(tracker.recent_mistakes(2), vec![('b', 'q'), ('\n', 'x')]);
+    assert_eq!(tracker.recent_mistakes(10).len(), 4);
+    assert!(StageTracker::new("a".to_string())
+        .recent_mistakes(5)
+        .is_empty());
+}
@@ -1,6 +1,9 @@
+use gittype::domain::events::domain_events::StageCompleted;
 use gittype::domain::events::EventBus;
 use gittype::domain::events::EventBusInterface;
-use gittype::domain::models::{DifficultyLevel, SessionAction, SessionConfig, SessionState};
+use gittype::domain::models::{
+    Challenge, DifficultyLevel, SessionAction, SessionConfig, SessionState,
+};
 use gittype::domain::services::scoring::{
     SessionTracker, SessionTrackerInterface, StageCalculator, StageInput, StageResult,
     StageTracker, TotalTracker, TotalTrackerInterface,
@@ -8,8 +11,10 @@ use gittype::domain::services::scoring::{
 use gittype::domain::services::session_manager_service::SessionManager;
 use gittype::domain::services::session_manager_service::SessionManagerInterface;
 use gittype::domain::services::stage_builder_service::{StageRepository, StageRepositoryInterface};
-use gittype::domain::stores::{ChallengeStore, RepositoryStore, SessionStore};
-use std::sync::Arc;
+use gittype::domain::stores::{
+    ChallengeStore, ChallengeStoreInterface, RepositoryStore, SessionStore,
+};
+use std::sync::{Arc, Mutex};
 
 #[allow(clippy::type_complexity)]
 fn create_test_dependencies() -> (
@@ -78,6 +83,42 @@ fn test_set_difficulty() {
     assert_eq!(manager.get_difficulty(), DifficultyLevel::Hard);
 }
 
+#[test]
+fn test_set_difficulty_falls_back_to_nearest_tier_with_challenges() {
+    let (event_bus, _, session_tracker, total_tracker) = create_test_dependencies();
+    let challenge_store = Arc::new(ChallengeStore::new_for_test());
+    challenge_store.set_challenges(vec![Challenge::new("wild".to_string(), "code".to_string())
+        .with_difficulty_level(DifficultyLevel::Wild)]);
+    let stage_repository = Arc::new(StageRepository::new(
+        None,
+        challenge_store,
+        Arc::new(RepositoryStore::new_for_test()),
+        Arc::new(SessionStore::new_for_test()),
+    ));
+    let manager = SessionManager::new_with_dependencies(
+        event_bus,
+        stage_repository,
+        session_tracker,
+        total_tracker,
+    );
+
+    manager.set_difficulty(DifficultyLevel::Easy);
+
+    assert_eq!(manager.get_difficulty(), DifficultyLevel::Wild);
+    assert_eq!(
+        manager.get_difficulty_fallback(),
+        Some((DifficultyLevel::Easy, DifficultyLevel::Wild))
+    );
+}
+
+#[test]
+fn test_set_difficulty_without_challenges_keeps_requested_tier() {
+    let manager = create_session_manager();
+    manager.set_difficulty(DifficultyLevel::Zen);
+    assert_eq!(manager.get_difficulty(), DifficultyLevel::Zen);
+    assert_eq!(manager.get_difficulty_fallback(), None);
+}
+
 #[test]
 fn test_is_completed_initially_false() {
     let manager = create_session_manager();
@@ -180,7 +221,7 @@ fn test_reduce_complete_stage_advances_stage() {
 
     let stage_result = create_dummy_stage_result();
     manager
-        .reduce(SessionAction::CompleteStage(stage_result))
+        .reduce(SessionAction::CompleteStage(Box::new(stage_result)))
         .unwrap();
 
     let (current, _total) = manager.get_stage_info().unwrap();
@@ -196,7 +237,7 @@ fn test_reduce_all_stages_completed_transitions_to_completed() {
     for _ in 0..3 {
         let stage_result = create_dummy_stage_result();
         manager
-            .reduce(SessionAction::CompleteStage(stage_result))
+            .reduce(SessionAction::CompleteStage(Box::new(stage_result)))
             .unwrap();
     }
 
@@ -228,7 +269,7 @@ fn test_reduce_reset_clears_state() {
     manager.reduce(SessionAction::Start).unwrap();
     let stage_result = create_dummy_stage_result();
     manager
-        .reduce(SessionAction::CompleteStage(stage_result))
+        .reduce(SessionAction::CompleteStage(Box::new(stage_result)))
         .unwrap();
 
     manager.reduce(SessionAction::Reset).unwrap();
@@ -242,7 +283,7 @@ fn test_reduce_invalid_transition_returns_error() {
     let manager = create_session_manager();
     // Start from NotStarted with CompleteStage should fail
     let stage_result = create_dummy_stage_result();
-    let result = manager.reduce(SessionAction::CompleteStage(stage_result));
+    let result = manager.reduce(SessionAction::CompleteStage(Box::new(stage_result)));
     assert!(result.is_err());
 }
 
@@ -263,7 +304,7 @@ fn test_reduce_skipped_stages_dont_count_for_completion() {
     let mut skipped_result = create_dummy_stage_result();
     skipped_result.was_skipped = true;
     manager
-        .reduce(SessionAction::CompleteStage(skipped_result))
+        .reduce(SessionAction::CompleteStage(Box::new(skipped_result)))
         .unwrap();
 
     // Not completed yet - skipped stages don't count
@@ -310,7 +351,7 @@ fn test_reset_clears_everything() {
         crate::fixtures::models::challenge::build(),
     );
     manager
-        .reduce(SessionAction::CompleteStage(stage_result))
+        .reduce(SessionAction::CompleteStage(Box::new(stage_result)))
         .unwrap();
 
     manager.reset();
@@ -375,7 +416,7 @@ fn test_skips_tracking() {
     let mut skipped = create_dummy_stage_result();
     skipped.was_skipped = true;
     manager
-        .reduce(SessionAction::CompleteStage(skipped))
+        .reduce(SessionAction::CompleteStage(Box::new(skipped)))
         .unwrap();
 
     assert_eq!(manager.get_skips_used(), 1);
@@ -410,7 +451,7 @@ fn test_get_stage_info_completed() {
     for _ in 0..3 {
         let stage_result = create_dummy_stage_result();
         manager
-            .reduce(SessionAction::CompleteStage(stage_result))
+            .reduce(SessionAction::CompleteStage(Box::new(stage_result)))
             .unwrap();
     }
     let (current, total) = manager.get_stage_info().unwrap();
@@ -434,7 +475,9 @@ fn test_stage_number_progression_after_reset() {
 
     // Stage 1 complete -> next stage is 2, displayed stage is 1.
     manager
-        .reduce(SessionAction::CompleteStage(create_dummy_stage_result()))
+        .reduce(SessionAction::CompleteStage(Box::new(
+            create_dummy_stage_result(),
+        )))
         .unwrap();
     let (current, _) = manager.get_stage_info().unwrap();
     assert_eq!(current, 2);
@@ -442,7 +485,9 @@ fn test_stage_number_progression_after_reset() {
 
     // Stage 2 complete -> next stage is 3, displayed stage is 2.
     manager
-        .reduce(SessionAction::CompleteStage(create_dummy_stage_result()))
+        .reduce(SessionAction::CompleteStage(Box::new(
+            create_dummy_stage_result(),
+        )))
         .unwrap();
     let (current, _) = manager.get_stage_info().unwrap();
     assert_eq!(current, 3);
@@ -450,7 +495,9 @@ fn test_stage_number_progression_after_reset() {
 
     // Stage 3 complete -> session completed, displayed stage is 3.
     manager
-        .reduce(SessionAction::CompleteStage(create_dummy_stage_result()))
+        .reduce(SessionAction::CompleteStage(Box::new(
+            create_dummy_stage_result(),
+        )))
         .unwrap();
     let (current, _) = manager.get_stage_info().unwrap();
     assert_eq!(current, 3);
@@ -553,6 +600,70 @@ fn test_finalize_current_stage_with_tracker() {
     assert_eq!(manager.get_stage_results().len(), 1);
 }
 
+#[test]
+fn test_finalize_current_stage_publishes_stage_completed() {
+    let (event_bus, stage_repository, session_tracker, total_tracker) = create_test_dependencies();
+    let completed = Arc::new(Mutex::new(Vec::new()));
+    {
+        let completed = Arc::clone(&completed);
+        event_bus
+            .as_event_bus()
+            .subscribe(move |event: &StageCompleted| {
+                completed.lock().unwrap().push(event.personal_best);
+            });
+    }
+    let manager = SessionManager::new_with_dependencies(
+        event_bus,
+        stage_repository,
+        session_tracker,
+        total_tracker,
+    );
+    manager.reduce(SessionAction::Start).unwrap();
+
+    finalize_typed_stage(&manager, None);
+
+    assert_eq!(*completed.lock().unwrap(), vec![false]);
+}
+
+fn finalize_typed_stage(manager: &SessionManager, screenshot: Option<&str>) {
+    let mut tracker = StageTracker::new("hi".to_string());
+    tracker.record(StageInput::Start);
+    for (i, ch) in "hi".chars().enumerate() {
+        tracker.record(StageInput::Keystroke { ch, position: i });
+    }
+    manager.set_current_stage_tracker(tracker);
+    if let Some(text) = screenshot {
+        manager.capture_stage_screenshot(text.to_string(), format!("<pre>{}</pre>", text));
+    }
+    manager.finalize_current_stage().unwrap();
+}
+
+#[test]
+fn test_stage_screenshots_are_numbered_like_their_stages() {
+    let manager = create_session_manager();
+    manager.reduce(SessionAction::Start).unwrap();
+
+    finalize_typed_stage(&manager, None);
+    finalize_typed_stage(&manager, Some("second"));
+
+    let screenshots = manager.get_stage_screenshots_for_test();
+    assert_eq!(screenshots.len(), 1);
+    assert_eq!(screenshots[0].stage_number, 2);
+    assert_eq!(screenshots[0].ansi, "second");
+    assert_eq!(screenshots[0].html, "<pre>second</pre>");
+}
+
+#[test]
+fn test_reset_discards_stage_screenshots() {
+    let manager = create_session_manager();
+    manager.reduce(SessionAction::Start).unwrap();
+    finalize_typed_stage(&manager, Some("first"));
+
+    manager.reset();
+
+    assert!(manager.get_stage_screenshots_for_test().is_empty());
+}
+
 // ============================================
 // Skip current stage
 // ============================================
@@ -636,6 +747,15 @@ fn test_get_best_status_for_score() {
     assert!(result.unwrap().is_some());
 }
 
+#[test]
+fn test_is_personal_best_session_only_once_completed() {
+    let manager = create_session_manager();
+    assert!(!manager.is_personal_best_session());
+
+    manager.reduce(SessionAction::Start).unwrap();
+    assert!(!manager.is_personal_best_session());
+}
+
 // ============================================
 // Record and update trackers
 // ============================================
@@ -914,7 +1034,7 @@ fn test_reduce_failed_stages_dont_count_for_completion() {
     let mut failed_result = create_dummy_stage_result();
     failed_result.was_failed = true;
     manager
-        .reduce(SessionAction::CompleteStage(failed_result))
+        .reduce(SessionAction::CompleteStage(Box::new(failed_result)))
         .unwrap();
 
     assert!(!manager.is_completed());
@@ -1070,3 +1190,414 @@ fn test_finalize_current_stage_records_session_challenge_when_available() {
     assert_eq!(manager.get_stage_results().len(), 1);
     assert_eq!(manager.get_session_challenges_for_test().len(), 1);
 }
+
+// ============================================
+// Failure analysis and challenge retry
+// ============================================
+
+#[test]
+fn test_get_failure_analysis_requires_failed_challenge() {
+    let manager = create_session_manager();
+    manager.set_current_stage_tracker(StageTracker::new("hello".to_string()));
+
+    assert!(manager.get_failure_analysis().is_none());
+}
+
+#[test]
+fn test_get_failure_analysis_uses_current_stage_tracker() {
+    use gittype::domain::models::Challenge;
+
+    let manager = create_session_manager();
+    let mut tracker = StageTracker::new("let x = 1;".to_string());
+    tracker.record(StageInput::Start);
+    tracker.record(StageInput::Keystroke {
+        ch: 'l',
+        position: 0,
+    });
+    tracker.record(StageInput::Keystroke {
+        ch: 'x',
+        position: 1,
+    });
+    tracker.record(StageInput::Keystroke {
+        ch: 'e',
+        position: 1,
+    });
+    manager.set_current_stage_tracker(tracker);
+    manager.record_failed_challenge(Challenge::new(
+        "failed".to_string(),
+        "let x = 1;".to_string(),
+    ));
+
+    let analysis = manager.get_failure_analysis().unwrap();
+
+    assert_eq!(analysis.challenge.id, "failed");
+    assert_eq!(analysis.typed_chars, 2);
+    assert_eq!(analysis.expected_char, Some('t'));
+    assert_eq!(analysis.clusters.len(), 1);
+    assert_eq!(analysis.clusters[0].mistakes, 1);
+}
+
+#[test]
+fn test_reset_clears_failed_challenge() {
+    use gittype::domain::models::Challenge;
+
+    let manager = create_session_manager();
+    manager.set_current_stage_tracker(StageTracker::new("hello".to_string()));
+    manager.record_failed_challenge(Challenge::new("failed".to_string(), "hello".to_string()));
+
+    manager.reduce(SessionAction::Reset).unwrap();
+    manager.set_current_stage_tracker(StageTracker::new("hello".to_string()));
+
+    assert!(manager.get_failure_analysis().is_none());
+}
+
+#[test]
+fn test_queued_challenge_retry_is_served_once_after_restart() {
+    use gittype::domain::models::Challenge;
+
+    let manager = create_manager_with_seeded_challenges();
+    let retry = Challenge::new("retry".to_string(), "fn retry() {}".to_string());
+    manager.queue_challenge_retry(retry.clone());
+
+    manager.reduce(SessionAction::Reset).unwrap();
+    manager.reduce(SessionAction::Start).unwrap();
+
+    assert_eq!(manager.get_current_challenge().unwrap(), Some(retry));
+    assert_ne!(
+        manager.get_current_challenge().unwrap().map(|c| c.id),
+        Some("retry".to_string())
+    );
+}
+
+#[test]
+fn test_get_last_stage_mistake_diff_without_stages_is_none() {
+    let manager = create_session_manager();
+
+    assert!(manager.get_last_stage_mistake_diff().is_none());
+}
+
+#[test]
+fn test_get_last_stage_mistake_diff_uses_finalized_stage() {
+    let manager = create_manager_with_seeded_challenges();
+    manager.reduce(SessionAction::Start).unwrap();
+
+    let mut tracker = StageTracker::new("let".to_string());
+    tracker.record(StageInput::Start);
+    tracker.record(StageInput::Keystroke {
+        ch: 'l',
+        position: 0,
+    });
+    tracker.record(StageInput::Keystroke {
+        ch: 'a',
+        position: 1,
+    });
+    tracker.record(StageInput::Keystroke {
+        ch: 'e',
+        position: 1,
+    });
+    tracker.record(StageInput::Keystroke {
+        ch: 't',
+        position: 2,
+    });
+    manager.set_current_stage_tracker(tracker);
+    manager.finalize_current_stage().unwrap();
+
+    let diff = manager.get_last_stage_mistake_diff().unwrap();
+
+    assert_eq!(diff.total_mistakes, 1);
+    assert_eq!(diff.lines[0].chars[1].mistyped, Some('a'));
+    assert_eq!(diff.costly_tokens, vec![("let".to_string(), 1)]);
+}
+
+// ============================================
+// Interrupted sessions
+// ============================================
+
+#[test]
+fn test_persist_interrupted_session_records_in_flight_stage_as_failed() {
+    let manager = create_manager_with_seeded_challenges();
+    manager.set_git_repository(Some(crate::fixtures::models::git_repository::build()));
+    manager.reduce(SessionAction::Start).unwrap();
+    let mut tracker = StageTracker::new("fn foo() {}".to_string());
+    tracker.record(StageInput::Start);
+    tracker.record(StageInput::Keystroke {
+        ch: 'f',
+        position: 0,
+    });
+    manager.set_current_stage_tracker(tracker);
+
+    manager.persist_interrupted_session().unwrap();
+
+    assert!(matches!(manager.get_state(), SessionState::Aborted { .. }));
+    assert!(manager.get_current_stage_tracker().is_none());
+    let stage_results = manager.get_stage_results();
+    assert_eq!(stage_results.len(), 1);
+    assert!(stage_results[0].was_failed);
+    assert_eq!(manager.get_stage_trackers_for_test().len(), 1);
+    assert_eq!(manager.get_session_challenges_for_test().len(), 1);
+}
+
+#[test]
+fn test_persist_interrupted_session_ignores_unstarted_stage() {
+    let (event_bus, stage_repository, session_tracker, total_tracker) = create_test_dependencies();
+    let manager = SessionManager::new_with_dependencies(
+        event_bus,
+        stage_repository,
+        session_tracker,
+        total_tracker.clone(),
+    );
+    manager.reduce(SessionAction::Start).unwrap();
+    manager.set_current_stage_tracker(StageTracker::new("hello".to_string()));
+
+    manager.persist_interrupted_session().unwrap();
+
+    assert!(matches!(manager.get_state(), SessionState::Aborted { .. }));
+    assert!(manager.get_stage_results().is_empty());
+    assert!(total_tracker.get_data().session_results.is_empty());
+}
+
+#[test]
+fn test_persist_interrupted_session_does_nothing_when_not_in_progress() {
+    let (event_bus, stage_repository, session_tracker, total_tracker) = create_test_dependencies();
+    let manager = SessionManager::new_with_dependencies(
+        event_bus,
+        stage_repository,
+        session_tracker,
+        total_tracker.clone(),
+    );
+
+    manager.persist_interrupted_session().unwrap();
+
+    assert!(matches!(manager.get_state(), SessionState::NotStarted));
+    assert!(total_tracker.get_data().session_results.is_empty());
+}
+
+fn create_manager_with_normal_challenges(ids: &[&str]) -> SessionManager {
+    use gittype::domain::models::Challenge;
+    use gittype::domain::stores::ChallengeStoreInterface;
+
+    let (event_bus, _, session_tracker, total_tracker) = create_test_dependencies();
+    let challenge_store = Arc::new(ChallengeStore::new_for_test());
+    challenge_store.set_challenges(
+        ids.iter()
+            .map(|id| {
+                Challenge::new(id.to_string(), format!("fn {}() {{}}", id))
+                    .with_difficulty_level(DifficultyLevel::Normal)
+            })
+            .collect(),
+    );
+    let stage_repository = Arc::new(StageRepository::new(
+        None,
+        challenge_store,
+        Arc::new(RepositoryStore::new_for_test()),
+        Arc::new(SessionStore::new_for_test()),
+    )) as Arc<dyn StageRepositoryInterface>;
+
+    SessionManager::new_with_dependencies(
+        event_bus,
+        stage_repository,
+        session_tracker,
+        total_tracker,
+    )
+}
+
+#[test]
+fn test_previewed_challenge_is_served_for_the_next_stage() {
+    let manager = create_manager_with_normal_challenges(&["a", "b", "c"]);
+    manager.reduce(SessionAction::Start).unwrap();
+
+    let preview = manager.preview_next_challenge().unwrap().unwrap();
+
+    assert_eq!(
+        manager.preview_next_challenge().unwrap(),
+        Some(preview.clone())
+    );
+    assert_eq!(manager.get_current_challenge().unwrap(), Some(preview));
+}
+
+#[test]
+fn test_next_challenge_can_be_rerolled_once_per_session() {
+    let manager = create_manager_with_normal_challenges(&["a", "b", "c"]);
+    manager.reduce(SessionAction::Start).unwrap();
+    let preview = manager.preview_next_challenge().unwrap().unwrap();
+    assert!(manager.can_reroll_next_challenge());
+
+    let rerolled = manager.reroll_next_challenge().unwrap().unwrap();
+
+    assert!(!manager.can_reroll_next_challenge());
+    assert_ne!(rerolled.id, preview.id);
+    assert_eq!(
+        manager.reroll_next_challenge().unwrap(),
+        Some(rerolled.clone())
+    );
+    assert_eq!(manager.get_current_challenge().unwrap(), Some(rerolled));
+
+    manager.reduce(SessionAction::Reset).unwrap();
+    assert!(manager.can_reroll_next_challenge());
+}
+
+#[test]
+fn test_preview_next_challenge_not_in_progress_returns_none() {
+    let manager = create_manager_with_normal_challenges(&["a"]);
+
+    assert!(manager.preview_next_challenge().unwrap().is_none());
+}
+
+#[test]
+fn test_fixed_challenges_are_served_in_order_without_rerolls() {
+    use gittype::domain::models::Challenge;
+
+    let manager = create_manager_with_normal_challenges(&["a", "b", "c"]);
+    let first = Challenge::new("first".to_string(), "let".to_string());
+    let second = Challenge::new("second".to_string(), "fn".to_string());
+    manager.set_fixed_challenges(Some(vec![first.clone(), second.clone()]));
+    manager.reduce(SessionAction::Start).unwrap();
+
+    assert_eq!(
+        manager.get_current_challenge().unwrap(),
+        Some(first.clone())
+    );
+    assert_eq!(manager.get_current_challenge().unwrap(), Some(first));
+
+    let mut tracker = StageTracker::new("let".to_string());
+    tracker.record(StageInput::Start);
+    manager.set_current_stage_tracker(tracker);
+    manager.finalize_current_stage().unwrap();
+
+    assert!(!manager.can_reroll_next_challenge());
+    assert_eq!(
+        manager.preview_next_challenge().unwrap(),
+        Some(second.clone())
+    );
+    assert_eq!(manager.reroll_next_challenge().unwrap(), Some(second));
+}
+
+#[test]
+fn test_prefetched_challenge_becomes_the_next_preview() {
+    let manager = create_manager_with_normal_challenges(&["a", "b", "c"]);
+    manager.reduce(SessionAction::Start).unwrap();
+    manager.get_current_challenge().unwrap();
+
+    let prefetched = manager.prefetch_next_challenge().unwrap().unwrap();
+    assert_eq!(
+        manager.prefetch_next_challenge().unwrap(),
+        Some(prefetched.clone())
+    );
+
+    let mut tracker = StageTracker::new("a".to_string());
+    tracker.record(StageInput::Start);
+    manager.set_current_stage_tracker(tracker);
+    manager.finalize_current_stage().unwrap();
+
+    assert_eq!(
+        manager.preview_next_challenge().unwrap(),
+        Some(prefetched.clone())
+    );
+    assert_eq!(manager.get_current_challenge().unwrap(), Some(prefetched));
+}
+
+#[test]
+fn test_prefetch_serves_the_fixed_challenge_of_the_next_stage() {
+    use gittype::domain::models::Challenge;
+
+    let manager = create_manager_with_normal_challenges(&["a", "b", "c"]);
+    let first = Challenge::new("first".to_string(), "let".to_string());
+    let second = Challenge::new("second".to_string(), "fn".to_string());
+    manager.set_fixed_challenges(Some(vec![first.clone(), second.clone()]));
+    manager.reduce(SessionAction::Start).unwrap();
+
+    assert_eq!(manager.get_current_challenge().unwrap(), Some(first));
+    assert_eq!(manager.prefetch_next_challenge().unwrap(), Some(second));
+}
+
+#[test]
+fn test_drills_take_the_place_of_fixed_challenges_until_cleared() {
+    use gittype::domain::models::Challenge;
+
+    let manager = create_manager_with_normal_challenges(&["a", "b", "c"]);
+    let fixed = Challenge::new("fixed".to_string(), "let".to_string());
+    let drill = Challenge::new("drill".to_string(), "a::b ->".to_string());
+    let next_drill = Challenge::new("next-drill".to_string(), "c::d".to_string());
+    manager.set_fixed_challenges(Some(vec![fixed.clone()]));
+    manager.set_drills(Some(vec![drill.clone(), next_drill.clone()]));
+    manager.reduce(SessionAction::Start).unwrap();
+
+    assert!(manager.is_drilling());
+    assert!(!manager.can_reroll_next_challenge());
+    assert_eq!(manager.get_current_challenge().unwrap(), Some(drill));
+    assert_eq!(manager.prefetch_next_challenge().unwrap(), Some(next_drill));
+
+    manager.reduce(SessionAction::Reset).unwrap();
+    manager.set_drills(None);
+    manager.reduce(SessionAction::Start).unwrap();
+
+    assert!(!manager.is_drilling());
+    assert_eq!(manager.get_current_challenge().unwrap(), Some(fixed));
+}
+
+#[test]
+fn test_challenge_slots_cover_every_stage_and_skip() {
+    let manager = create_session_manager();
+    manager.set_config(SessionConfig {
+        max_stages: 3,
+        max_skips: 2,
+        ..Default::default()
+    });
+
+    assert_eq!(manager.challenge_slots(), 5);
+}
+
+#[test]
+fn test_session_with_a_paste_attempt_is_unverified_without_a_code() {
+    use gittype::domain::models::ImplausibilityReason;
+
+    let manager = create_session_manager();
+    manager.reduce(SessionAction::Start).unwrap();
+    manager.record_paste_attempt();
+
+    let result = manager.get_session_result().unwrap();
+
+    assert_eq!(
+        result.plausibility.reasons(),
+        &[ImplausibilityReason::Pasted]
+    );
+    assert_eq!(result.verification_code, None);
+}
+
+#[test]
+fn test_starting_a_session_forgets_earlier_paste_attempts() {
+    let manager = create_session_manager();
+    manager.record_paste_attempt();
+    manager.reduce(SessionAction::Start).unwrap();
+
+    assert!(manager
+        .get_session_result()
+        .unwrap()
+        .plausibility
+        .is_verified());
+}
+
+#[test]
+fn test_prefetch_next_challenge_not_in_progress_returns_none() {
+    let manager = create_manager_with_normal_challenges(&["a"]);
+
+    assert!(manager.prefetch_next_challenge().unwrap().is_none());
+}
+
+#[test]
+fn test_session_result_carries_a_valid_verification_code() {
+    use gittype::domain::models::ResultVerification;
+
+    let manager = create_session_manager();
+    manager.reduce(SessionAction::Start).unwrap();
+
+    let result = manager.get_session_result().unwrap();
+    let verification =
+        ResultVerification::parse(result.verification_code.as_deref().unwrap()).unwrap();
+
+    assert_eq!(verification.score, result.session_score.round() as u64);
+    assert_eq!(
+        verification.mistakes,
+        result.valid_mistakes + result.invalid_mistakes
+    );
+}
@@ -45,6 +45,7 @@ fn test_get_sessions_with_display_data_with_session() {
         commit_hash: Some("session123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let challenge = Challenge::new("session-test".to_string(), "test code".to_string())
@@ -98,6 +99,7 @@ fn test_get_sessions_with_display_data_with_repository_filter() {
         commit_hash: Some("filter123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let challenge = Challenge::new("filter-test".to_string(), "filter code".to_string());
@@ -155,6 +157,7 @@ fn test_get_sessions_with_display_data_with_date_filter() {
         commit_hash: Some("date123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let challenge = Challenge::new("date-test".to_string(), "date code".to_string());
@@ -199,6 +202,7 @@ fn test_get_sessions_with_display_data_sort_by_score() {
             commit_hash: Some(format!("sort{}", i)),
             is_dirty: false,
             root_path: None,
+            license: None,
         };
 
         let challenge = Challenge::new(format!("sort-{}", i), "sort code".to_string());
@@ -243,6 +247,7 @@ fn test_get_all_repositories() {
         commit_hash: Some("all123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let challenge = Challenge::new("all-test".to_string(), "all code".to_string());
@@ -289,6 +294,7 @@ fn test_session_display_data_has_session_result() {
         commit_hash: Some("result123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let challenge = Challenge::new("result-test".to_string(), "result code".to_string());
@@ -358,6 +364,7 @@ fn test_get_sessions_with_display_data_sort_ascending() {
         commit_hash: Some("asc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let challenge = Challenge::new("asc-test".to_string(), "asc code".to_string());
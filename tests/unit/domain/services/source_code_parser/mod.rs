@@ -1,4 +1,7 @@
 pub mod cache_builder_tests;
 pub mod comment_processor_tests;
 pub mod parsers;
+pub mod prose_extractor_tests;
+pub mod secret_scanner_tests;
+pub mod snippet_extractor_tests;
 pub mod source_code_parser_tests;
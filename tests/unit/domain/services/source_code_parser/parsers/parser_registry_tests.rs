@@ -1,5 +1,5 @@
 use gittype::domain::services::source_code_parser::parsers::{
-    get_parser_registry, parse_with_thread_local,
+    get_parser_registry, parse_with_thread_local, QueryOverride,
 };
 
 #[test]
@@ -147,3 +147,84 @@ fn parse_with_thread_local_empty_content() {
     let tree = parse_with_thread_local("rust", "");
     assert!(tree.is_some()); // tree-sitter can parse empty input
 }
+
+#[test]
+fn capabilities_cover_every_language_in_registry_order() {
+    use gittype::domain::models::Languages;
+
+    let capabilities = get_parser_registry().capabilities();
+    let names: Vec<&str> = capabilities.iter().map(|c| c.name.as_str()).collect();
+    let expected: Vec<&str> = Languages::all_languages()
+        .iter()
+        .map(|language| language.name())
+        .collect();
+    assert_eq!(names, expected);
+    assert!(capabilities.iter().all(|c| !c.chunk_types.is_empty()));
+}
+
+#[test]
+fn capability_lists_query_and_refined_chunk_types() {
+    use gittype::domain::models::languages::{Rust, TypeScript};
+    use gittype::domain::models::ChunkType;
+
+    let registry = get_parser_registry();
+    let rust = registry.capability(&Rust).unwrap();
+    assert_eq!(rust.extensions, vec!["rs".to_string()]);
+    assert!(rust.chunk_types.contains(&ChunkType::Macro));
+    assert!(!rust.chunk_types.contains(&ChunkType::CodeBlock));
+    assert!(rust.has_middle_implementation);
+
+    let typescript = registry.capability(&TypeScript).unwrap();
+    assert!(typescript.chunk_types.contains(&ChunkType::Hook));
+    assert_eq!(
+        typescript
+            .chunk_types
+            .iter()
+            .filter(|t| **t == ChunkType::Function)
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn query_override_extends_builtin_patterns_unless_marked_replace() {
+    let extending = QueryOverride::parse("(const_item) @const");
+    assert!(!extending.replace);
+    assert_eq!(
+        extending.merge("(function_item) @function"),
+        "(function_item) @function\n(const_item) @const"
+    );
+
+    let replacing = QueryOverride::parse("; replace\n(const_item) @const");
+    assert!(replacing.replace);
+    assert_eq!(
+        replacing.merge("(function_item) @function"),
+        "; replace\n(const_item) @const"
+    );
+}
+
+#[test]
+fn create_query_with_merges_override_captures() {
+    let registry = get_parser_registry();
+    let query = registry
+        .create_query_with("rust", &QueryOverride::parse("(const_item) @const"))
+        .unwrap();
+    assert!(query.capture_names().contains(&"const"));
+    assert!(query.capture_names().contains(&"function"));
+
+    let query = registry
+        .create_query_with(
+            "rust",
+            &QueryOverride::parse("; replace\n(const_item) @const"),
+        )
+        .unwrap();
+    assert_eq!(query.capture_names(), &["const"]);
+}
+
+#[test]
+fn set_query_override_rejects_invalid_patterns() {
+    let result = get_parser_registry()
+        .set_query_override("rust", QueryOverride::parse("(not_a_rust_node) @function"));
+    assert!(result.is_err());
+    assert!(get_parser_registry().create_query("rust").is_ok());
+}
@@ -0,0 +1,59 @@
+use gittype::domain::models::languages::Rust;
+use gittype::domain::models::ChunkType;
+use gittype::domain::services::source_code_parser::parsers::parse_with_thread_local;
+use gittype::domain::services::source_code_parser::{ProseExtractor, PROSE_LANGUAGE};
+use std::path::Path;
+
+#[test]
+fn extract_from_comments_merges_consecutive_comment_lines_into_one_chunk() {
+    let source = r#"/// Parses the configuration file and returns the merged settings,
+/// falling back to defaults when the file is missing.
+fn load() {}
+
+// let value = compute(input);
+// TODO
+fn other() {}
+"#;
+    let tree = parse_with_thread_local("rust", source).unwrap();
+
+    let chunks =
+        ProseExtractor::extract_from_comments(&tree, source, Path::new("src/lib.rs"), &Rust)
+            .unwrap();
+
+    assert_eq!(chunks.len(), 1);
+    let chunk = &chunks[0];
+    assert_eq!(chunk.chunk_type, ChunkType::Prose);
+    assert_eq!(chunk.language, PROSE_LANGUAGE);
+    assert_eq!((chunk.start_line, chunk.end_line), (1, 2));
+    assert_eq!(
+        chunk.content,
+        "Parses the configuration file and returns the merged settings, falling\nback to defaults when the file is missing."
+    );
+    assert_eq!(chunk.name, "Parses the configuration file");
+}
+
+#[test]
+fn extract_from_markdown_keeps_paragraphs_and_skips_markup() {
+    let readme = r#"# gittype
+
+[![CI](https://example.com/badge.svg)](https://example.com)
+
+GitType turns your **own source code** into typing challenges, so you
+practice with the [code you write](https://example.com/docs) every day.
+
+```bash
+cargo install gittype --locked --force --verbose
+```
+
+Too short to type.
+"#;
+
+    let chunks = ProseExtractor::extract_from_markdown(readme, Path::new("README.md"));
+
+    assert_eq!(chunks.len(), 1);
+    assert_eq!((chunks[0].start_line, chunks[0].end_line), (5, 6));
+    assert_eq!(
+        chunks[0].content,
+        "GitType turns your own source code into typing challenges, so you\npractice with the code you write every day."
+    );
+}
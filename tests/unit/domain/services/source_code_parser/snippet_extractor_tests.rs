@@ -0,0 +1,53 @@
+use gittype::domain::models::ChunkType;
+use gittype::domain::services::source_code_parser::{SnippetExtractor, PROSE_LANGUAGE};
+use std::path::Path;
+use tempfile::TempDir;
+
+#[test]
+fn extract_turns_each_file_into_one_whole_chunk() {
+    let dir = TempDir::new().unwrap();
+    let code = dir.path().join("loops/sum.rs");
+    let notes = dir.path().join("notes.txt");
+    let blank = dir.path().join("blank.txt");
+    std::fs::create_dir_all(code.parent().unwrap()).unwrap();
+    std::fs::write(
+        &code,
+        "fn sum(v: &[i32]) -> i32 {\r\n    v.iter().sum()\r\n}\r\n\r\n",
+    )
+    .unwrap();
+    std::fs::write(&notes, "Type this sentence exactly.\n").unwrap();
+    std::fs::write(&blank, "  \n\n").unwrap();
+
+    let chunks = SnippetExtractor::extract(dir.path(), &[code, notes, blank]);
+
+    assert_eq!(chunks.len(), 2);
+    let chunk = &chunks[0];
+    assert_eq!(chunk.chunk_type, ChunkType::File);
+    assert_eq!(chunk.language, "rust");
+    assert_eq!(chunk.file_path, Path::new("loops/sum.rs"));
+    assert_eq!(chunk.name, "sum");
+    assert_eq!(
+        chunk.content,
+        "fn sum(v: &[i32]) -> i32 {\n    v.iter().sum()\n}"
+    );
+    assert_eq!((chunk.start_line, chunk.end_line), (1, 3));
+    assert!(chunk.comment_ranges.is_empty());
+    assert_eq!(chunks[1].language, PROSE_LANGUAGE);
+    assert_eq!(chunks[1].content, "Type this sentence exactly.");
+}
+
+#[test]
+fn detect_language_falls_back_to_prose() {
+    assert_eq!(
+        SnippetExtractor::detect_language(Path::new("a.py")),
+        "python"
+    );
+    assert_eq!(
+        SnippetExtractor::detect_language(Path::new("README")),
+        PROSE_LANGUAGE
+    );
+    assert_eq!(
+        SnippetExtractor::detect_language(Path::new("lesson.md")),
+        PROSE_LANGUAGE
+    );
+}
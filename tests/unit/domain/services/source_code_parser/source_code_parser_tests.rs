@@ -1,10 +1,10 @@
+use gittype::domain::models::loading::ProgressReporter;
 use gittype::domain::models::loading::StepType;
 use gittype::domain::models::Languages;
 use gittype::domain::models::{Challenge, ChunkType, CodeChunk, ExtractionOptions};
 use gittype::domain::services::challenge_generator::ChallengeGenerator;
 use gittype::domain::services::source_code_parser::SourceCodeParser;
 use gittype::domain::services::source_file_extractor::SourceFileExtractor;
-use gittype::presentation::tui::screens::loading_screen::ProgressReporter;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::RwLock;
@@ -532,3 +532,155 @@ enum Color {
         class_count
     );
 }
+
+#[test]
+fn test_crlf_and_mixed_line_endings_are_normalized() {
+    use gittype::infrastructure::storage::file_storage::FileStorage;
+
+    let temp_dir = TempDir::new().unwrap();
+    std::process::Command::new("git")
+        .arg("init")
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to initialize git repository");
+    let src_file = temp_dir.path().join("main.rs");
+    let mut file_storage = FileStorage::new();
+    file_storage.set_file_content(
+        src_file.clone(),
+        "fn crlf() {\r\n    let a = 1;\r\n}\r\n\rfn mixed() {\r\n    let b = 2;\n}\r\n".to_string(),
+    );
+
+    let files = vec![(
+        src_file,
+        Languages::from_extension("rs").expect("rust language should be supported"),
+    )];
+    let mut parser = SourceCodeParser::with_file_storage(file_storage).unwrap();
+    let chunks = parser
+        .extract_chunks_with_progress(
+            files,
+            &ExtractionOptions::default(),
+            &RecordingProgress::new(),
+        )
+        .unwrap();
+
+    assert!(chunks.iter().any(|c| c.name == "crlf"));
+    assert!(chunks.iter().any(|c| c.name == "mixed"));
+    assert!(chunks.iter().all(|c| !c.content.contains('\r')));
+}
+
+#[test]
+fn test_editorconfig_indent_style_is_applied_to_chunks() {
+    use gittype::infrastructure::storage::file_storage::FileStorage;
+
+    let temp_dir = TempDir::new().unwrap();
+    std::process::Command::new("git")
+        .arg("init")
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to initialize git repository");
+    let src_file = temp_dir.path().join("src").join("main.rs");
+    let mut file_storage = FileStorage::new();
+    file_storage.set_file_content(
+        temp_dir.path().join(".editorconfig"),
+        "root = true\n\n[*.rs]\nindent_style = space\nindent_size = 2\n".to_string(),
+    );
+    file_storage.set_file_content(
+        src_file.clone(),
+        "fn tabbed() {\n\tif true {\n\t\tlet a = 1;\n\t}\n}\n".to_string(),
+    );
+
+    let mut parser = SourceCodeParser::with_file_storage(file_storage).unwrap();
+    let chunks = parser
+        .extract_chunks_with_progress(
+            vec![(
+                src_file,
+                Languages::from_extension("rs").expect("rust language should be supported"),
+            )],
+            &ExtractionOptions::default(),
+            &RecordingProgress::new(),
+        )
+        .unwrap();
+
+    let tabbed = chunks.iter().find(|c| c.name == "tabbed").unwrap();
+    assert_eq!(
+        tabbed.content,
+        "fn tabbed() {\n  if true {\n    let a = 1;\n  }\n}"
+    );
+}
+
+#[test]
+fn test_bom_and_invalid_utf8_do_not_abort_extraction() {
+    use gittype::infrastructure::storage::file_storage::FileStorage;
+
+    let temp_dir = TempDir::new().unwrap();
+    std::process::Command::new("git")
+        .arg("init")
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to initialize git repository");
+    let bom_file = temp_dir.path().join("bom.rs");
+    let latin1_file = temp_dir.path().join("latin1.rs");
+    let mut file_storage = FileStorage::new();
+    file_storage.set_file_bytes(
+        bom_file.clone(),
+        b"\xEF\xBB\xBFfn with_bom() {\n    let a = 1;\n}\n".to_vec(),
+    );
+    file_storage.set_file_bytes(
+        latin1_file.clone(),
+        b"fn broken() {\n    let s = \"caf\xE9\";\n}\n\nfn clean() {\n    let b = 2;\n}\n".to_vec(),
+    );
+
+    let rust = || Languages::from_extension("rs").expect("rust language should be supported");
+    let mut parser = SourceCodeParser::with_file_storage(file_storage).unwrap();
+    let chunks = parser
+        .extract_chunks_with_progress(
+            vec![(bom_file, rust()), (latin1_file, rust())],
+            &ExtractionOptions::default(),
+            &RecordingProgress::new(),
+        )
+        .unwrap();
+
+    let with_bom = chunks.iter().find(|c| c.name == "with_bom").unwrap();
+    assert!(with_bom.content.starts_with("fn with_bom"));
+    assert!(chunks.iter().any(|c| c.name == "clean"));
+    assert!(chunks.iter().all(|c| c.name != "broken"));
+}
+
+#[test]
+fn test_cpp_header_in_c_project_is_parsed_as_cpp() {
+    use gittype::infrastructure::storage::file_storage::FileStorage;
+
+    let temp_dir = TempDir::new().unwrap();
+    std::process::Command::new("git")
+        .arg("init")
+        .current_dir(temp_dir.path())
+        .output()
+        .expect("Failed to initialize git repository");
+    let header = temp_dir.path().join("counter.h");
+    let source = temp_dir.path().join("main.c");
+    let mut file_storage = FileStorage::new();
+    file_storage.set_file_content(
+        header.clone(),
+        "class Counter {\npublic:\n    int increment(int step) {\n        value += step;\n        return value;\n    }\nprivate:\n    int value = 0;\n};\n".to_string(),
+    );
+    file_storage.set_file_content(
+        source.clone(),
+        "int main(void) {\n    return 0;\n}\n".to_string(),
+    );
+
+    let files = Languages::for_project_files(&[header, source]);
+    assert_eq!(files[0].1.name(), "c");
+    let mut parser = SourceCodeParser::with_file_storage(file_storage).unwrap();
+    let chunks = parser
+        .extract_chunks_with_progress(
+            files,
+            &ExtractionOptions::default(),
+            &RecordingProgress::new(),
+        )
+        .unwrap();
+
+    let class = chunks.iter().find(|c| c.name == "Counter").unwrap();
+    assert_eq!(class.chunk_type, ChunkType::Struct);
+    let method = chunks.iter().find(|c| c.name == "increment").unwrap();
+    assert_eq!(method.language, "cpp");
+}
@@ -1,8 +1,8 @@
+use gittype::domain::models::loading::ProgressReporter;
 use gittype::domain::models::loading::StepType;
 use gittype::domain::models::ExtractionOptions;
 use gittype::domain::services::source_file_extractor::SourceFileExtractor;
 use gittype::infrastructure::storage::file_storage::FileStorage;
-use gittype::presentation::tui::screens::loading_screen::ProgressReporter;
 use std::path::Path;
 use std::sync::RwLock;
 
@@ -228,6 +228,100 @@ mod tests {
         assert_eq!(files, vec![Path::new("/mock/src/main.rs")]);
     }
 
+    #[test]
+    fn test_collect_with_progress_restricts_to_commit_files() {
+        let mut mock_storage = FileStorage::new();
+        mock_storage.add_file("/mock/src/main.rs");
+        mock_storage.add_file("/mock/src/lib.rs");
+        mock_storage.add_file("/mock/README.md");
+
+        let extractor = SourceFileExtractor::with_storage(mock_storage);
+        let progress = MockProgressReporter::new();
+        let options = ExtractionOptions {
+            commit_files: Some(vec!["src/lib.rs".into(), "README.md".into()]),
+            ..ExtractionOptions::default()
+        };
+
+        let files = extractor
+            .collect_with_progress_with_options(Path::new("/mock"), &options, &progress)
+            .unwrap();
+
+        assert_eq!(files, vec![Path::new("/mock/src/lib.rs")]);
+    }
+
+    #[test]
+    fn test_collect_with_progress_restricts_to_workspace_package() {
+        // The workspace manifests are read from disk, the files from storage
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .unwrap();
+        for name in ["core", "cli"] {
+            std::fs::create_dir_all(root.join("crates").join(name)).unwrap();
+            std::fs::write(
+                root.join("crates").join(name).join("Cargo.toml"),
+                format!("[package]\nname = \"{}\"\n", name),
+            )
+            .unwrap();
+        }
+
+        let mut mock_storage = FileStorage::new();
+        mock_storage.add_file(root.join("crates/core/src/lib.rs"));
+        mock_storage.add_file(root.join("crates/cli/src/main.rs"));
+        mock_storage.add_file(root.join("build.rs"));
+
+        let extractor = SourceFileExtractor::with_storage(mock_storage);
+        let progress = MockProgressReporter::new();
+        // The default exclusions would drop everything under /tmp
+        let options = ExtractionOptions {
+            package: Some("core".to_string()),
+            exclude_patterns: Vec::new(),
+            ..ExtractionOptions::default()
+        };
+
+        let files = extractor
+            .collect_with_progress_with_options(root, &options, &progress)
+            .unwrap();
+        assert_eq!(files, vec![root.join("crates/core/src/lib.rs")]);
+
+        let unknown = ExtractionOptions {
+            package: Some("web".to_string()),
+            ..ExtractionOptions::default()
+        };
+        assert!(extractor
+            .collect_with_progress_with_options(root, &unknown, &progress)
+            .is_err());
+    }
+
+    #[test]
+    fn test_collect_with_progress_keeps_any_extension_for_snippets() {
+        let mut mock_storage = FileStorage::new();
+        mock_storage.add_file("/mock/lesson1.txt");
+        mock_storage.add_file("/mock/lesson2.rs");
+        mock_storage.add_file("/mock/lesson3");
+
+        let extractor = SourceFileExtractor::with_storage(mock_storage);
+        let progress = MockProgressReporter::new();
+        let mut options = ExtractionOptions::default();
+        options.enable_snippets();
+
+        let files = extractor
+            .collect_with_progress_with_options(Path::new("/mock"), &options, &progress)
+            .unwrap();
+
+        assert_eq!(
+            files,
+            vec![
+                Path::new("/mock/lesson1.txt"),
+                Path::new("/mock/lesson2.rs"),
+                Path::new("/mock/lesson3"),
+            ]
+        );
+    }
+
     #[test]
     fn test_collect_with_progress_applies_multiple_gittypeignore_lines() {
         let mut mock_storage = FileStorage::new();
@@ -269,4 +363,78 @@ mod tests {
         let files = result.unwrap();
         assert_eq!(files, vec![Path::new("/mock/src/main.rs")]);
     }
+
+    #[test]
+    fn test_collect_with_progress_skips_binary_files() {
+        let mut mock_storage = FileStorage::new();
+        mock_storage.add_file("/mock/src/main.rs");
+        mock_storage.add_file("/mock/src/blob.rs");
+        mock_storage.set_file_content("/mock/src/main.rs", "fn main() {}\n".to_string());
+        mock_storage.set_file_bytes("/mock/src/blob.rs", vec![0x7F, b'E', b'L', b'F', 0, 0]);
+
+        let extractor = SourceFileExtractor::with_storage(mock_storage);
+        let progress = MockProgressReporter::new();
+
+        let files = extractor
+            .collect_with_progress_with_options(
+                Path::new("/mock"),
+                &ExtractionOptions::default(),
+                &progress,
+            )
+            .unwrap();
+
+        assert_eq!(files, vec![Path::new("/mock/src/main.rs")]);
+    }
+
+    #[test]
+    fn test_collect_with_progress_keeps_utf16_files_marked_by_bom() {
+        let mut mock_storage = FileStorage::new();
+        mock_storage.add_file("/mock/src/main.rs");
+        let utf16le: Vec<u8> = [0xFF, 0xFE]
+            .into_iter()
+            .chain("fn main() {}\r\n".encode_utf16().flat_map(u16::to_le_bytes))
+            .collect();
+        mock_storage.set_file_bytes("/mock/src/main.rs", utf16le);
+
+        let extractor = SourceFileExtractor::with_storage(mock_storage);
+        let progress = MockProgressReporter::new();
+
+        let files = extractor
+            .collect_with_progress_with_options(
+                Path::new("/mock"),
+                &ExtractionOptions::default(),
+                &progress,
+            )
+            .unwrap();
+
+        assert_eq!(files, vec![Path::new("/mock/src/main.rs")]);
+    }
+
+    #[test]
+    fn test_collect_with_progress_skips_minified_files() {
+        let mut mock_storage = FileStorage::new();
+        mock_storage.add_file("/mock/src/app.js");
+        mock_storage.add_file("/mock/src/app.min.js");
+        mock_storage.set_file_content(
+            "/mock/src/app.js",
+            "function add(a, b) {\n  return a + b;\n}\n".to_string(),
+        );
+        mock_storage.set_file_content(
+            "/mock/src/app.min.js",
+            "function add(a,b){return a+b}".repeat(40),
+        );
+
+        let extractor = SourceFileExtractor::with_storage(mock_storage);
+        let progress = MockProgressReporter::new();
+        let options = ExtractionOptions {
+            max_average_line_length: 200,
+            ..ExtractionOptions::default()
+        };
+
+        let files = extractor
+            .collect_with_progress_with_options(Path::new("/mock"), &options, &progress)
+            .unwrap();
+
+        assert_eq!(files, vec![Path::new("/mock/src/app.js")]);
+    }
 }
@@ -501,6 +501,62 @@ fn test_count_challenges_by_difficulty_from_store() {
     assert_eq!(counts, [2, 1, 1, 2, 1]);
 }
 
+#[test]
+fn test_resolve_difficulty_keeps_tier_with_challenges() {
+    let cs = create_challenge_store();
+    cs.set_challenges(make_challenges_with_difficulties(&[
+        DifficultyLevel::Easy,
+        DifficultyLevel::Hard,
+    ]));
+    let repo = create_repository(cs);
+
+    assert_eq!(
+        repo.resolve_difficulty(DifficultyLevel::Hard),
+        Some(DifficultyLevel::Hard)
+    );
+}
+
+#[test]
+fn test_resolve_difficulty_falls_back_to_nearest_tier() {
+    let cs = create_challenge_store();
+    cs.set_challenges(make_challenges_with_difficulties(&[
+        DifficultyLevel::Easy,
+        DifficultyLevel::Zen,
+    ]));
+    let repo = create_repository(cs);
+
+    assert_eq!(
+        repo.resolve_difficulty(DifficultyLevel::Normal),
+        Some(DifficultyLevel::Easy)
+    );
+    assert_eq!(
+        repo.resolve_difficulty(DifficultyLevel::Wild),
+        Some(DifficultyLevel::Zen)
+    );
+}
+
+#[test]
+fn test_resolve_difficulty_prefers_easier_tier_on_tie() {
+    let cs = create_challenge_store();
+    cs.set_challenges(make_challenges_with_difficulties(&[
+        DifficultyLevel::Normal,
+        DifficultyLevel::Wild,
+    ]));
+    let repo = create_repository(cs);
+
+    assert_eq!(
+        repo.resolve_difficulty(DifficultyLevel::Hard),
+        Some(DifficultyLevel::Normal)
+    );
+}
+
+#[test]
+fn test_resolve_difficulty_without_challenges_is_none() {
+    let repo = create_repository(create_challenge_store());
+
+    assert_eq!(repo.resolve_difficulty(DifficultyLevel::Normal), None);
+}
+
 #[test]
 fn test_count_challenges_by_difficulty_defaults_missing_difficulty_to_easy() {
     let cs = create_challenge_store();
@@ -534,6 +590,48 @@ fn test_count_challenges_by_difficulty_uses_cached_indices() {
     assert_eq!(counts, [1, 0, 2, 0, 0]);
 }
 
+// === count_challenges_by_language ===
+
+#[test]
+fn test_count_challenges_by_language_most_common_first() {
+    let cs = create_challenge_store();
+    let challenge = |id: &str, language: Option<&str>| {
+        let challenge = Challenge::new(id.to_string(), "code".to_string());
+        match language {
+            Some(language) => challenge.with_language(language.to_string()),
+            None => challenge,
+        }
+    };
+    cs.set_challenges(vec![
+        challenge("1", Some("python")),
+        challenge("2", Some("rust")),
+        challenge("3", Some("rust")),
+        challenge("4", Some("go")),
+        challenge("5", None),
+    ]);
+    let repo = create_repository(cs);
+
+    let counts = repo.count_challenges_by_language();
+
+    assert_eq!(
+        counts,
+        vec![
+            ("Rust".to_string(), 2),
+            ("Go".to_string(), 1),
+            ("Python".to_string(), 1),
+            ("Unknown".to_string(), 1),
+        ]
+    );
+}
+
+#[test]
+fn test_count_challenges_by_language_with_no_challenges() {
+    let (cs, rs, ss) = create_stores();
+    let repo = StageRepository::new(None, cs, rs, ss);
+
+    assert!(repo.count_challenges_by_language().is_empty());
+}
+
 // === build_difficulty_indices ===
 
 #[test]
@@ -0,0 +1,79 @@
+use gittype::domain::models::StreamerStats;
+use gittype::domain::services::config_service::{ConfigService, ConfigServiceInterface};
+use gittype::domain::services::streamer_stats_service::{
+    StreamerStatsService, StreamerStatsServiceInterface, DEFAULT_STATS_FILE_NAME,
+};
+use gittype::infrastructure::storage::file_storage::{FileStorage, FileStorageInterface};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+fn service(enabled: bool, stats_file: Option<PathBuf>) -> StreamerStatsService {
+    let config_service = ConfigService::new_for_test().unwrap();
+    config_service
+        .update_config(|config| {
+            config.streamer.enabled = enabled;
+            config.streamer.stats_file = stats_file;
+        })
+        .unwrap();
+    StreamerStatsService::new(
+        Arc::new(FileStorage::new()) as Arc<dyn FileStorageInterface>,
+        Arc::new(config_service) as Arc<dyn ConfigServiceInterface>,
+    )
+}
+
+fn stats(wpm: f64) -> StreamerStats {
+    StreamerStats {
+        wpm,
+        accuracy: 95.0,
+    }
+}
+
+#[test]
+fn publish_does_nothing_when_disabled() {
+    let service = service(false, None);
+
+    service.publish(&stats(60.0));
+
+    assert_eq!(service.last_written_for_test(), None);
+}
+
+#[test]
+fn publish_writes_rendered_stats_when_enabled() {
+    let service = service(true, None);
+
+    service.publish(&stats(60.0));
+
+    assert_eq!(
+        service.last_written_for_test(),
+        Some("WPM: 60\nAccuracy: 95%\n".to_string())
+    );
+}
+
+#[test]
+fn publish_throttles_rapid_changes() {
+    let service = service(true, None);
+
+    service.publish(&stats(60.0));
+    service.publish(&stats(61.0));
+
+    assert_eq!(
+        service.last_written_for_test(),
+        Some("WPM: 60\nAccuracy: 95%\n".to_string())
+    );
+}
+
+#[test]
+fn stats_path_defaults_to_app_data_dir() {
+    let path = service(true, None).stats_path().unwrap();
+
+    assert!(path.ends_with(DEFAULT_STATS_FILE_NAME));
+}
+
+#[test]
+fn stats_path_uses_configured_file() {
+    let path = service(true, Some(PathBuf::from("/tmp/obs/wpm.txt")))
+        .stats_path()
+        .unwrap();
+
+    assert_eq!(path, PathBuf::from("/tmp/obs/wpm.txt"));
+}
@@ -40,3 +40,33 @@ fn process_tab_input_accepts_internal_tabs() {
     assert_eq!(core.process_character_input('b'), InputResult::Completed);
     assert_eq!(core.process_tab_input(), InputResult::NoAction);
 }
+
+#[test]
+fn new_normalizes_crlf_line_endings() {
+    let core = TypingCore::new("a\r\nb\rc", &[], ProcessingOptions::default());
+
+    assert_eq!(core.text_to_type(), "a\nb\nc");
+}
+
+#[test]
+fn new_shifts_comment_ranges_past_dropped_carriage_returns() {
+    let core = TypingCore::new(
+        "a\r\nb // note\r\nc",
+        &[(5, 12)],
+        ProcessingOptions::default(),
+    );
+
+    assert_eq!(core.text_to_type(), "a\nb\nc");
+}
+
+#[test]
+fn carriage_return_and_line_feed_input_satisfy_line_end() {
+    let mut core = TypingCore::new("a\r\nb\nc", &[], ProcessingOptions::default());
+
+    assert_eq!(core.process_character_input('a'), InputResult::Correct);
+    assert_eq!(core.process_character_input('\r'), InputResult::Correct);
+    assert_eq!(core.process_character_input('b'), InputResult::Correct);
+    assert_eq!(core.process_character_input('\n'), InputResult::Correct);
+    assert_eq!(core.process_character_input('c'), InputResult::Completed);
+    assert_eq!(core.mistakes(), 0);
+}
@@ -1,5 +1,8 @@
 #[allow(clippy::module_inception)]
 mod version_service_tests {
+    use gittype::domain::models::config::{
+        ReleaseChannel, VersionCheckConfig, VersionCheckFrequency,
+    };
     use gittype::domain::services::version_service::{VersionService, VersionServiceInterface};
 
     #[tokio::test]
@@ -145,4 +148,93 @@ mod version_service_tests {
         ));
         assert!(!VersionService::is_version_newer_for_test("1.0.1", "1.x.0"));
     }
+
+    #[tokio::test]
+    async fn check_is_skipped_when_frequency_is_off() {
+        let service = VersionService::new_for_test_with_config(VersionCheckConfig {
+            frequency: VersionCheckFrequency::Off,
+            channel: ReleaseChannel::Stable,
+        })
+        .expect("Service creation should succeed");
+
+        let (has_update, current, latest) = service
+            .check_with_version("0.8.0")
+            .await
+            .expect("Should succeed");
+
+        assert!(!has_update);
+        assert_eq!(current, "0.8.0");
+        assert_eq!(latest, "0.8.0");
+    }
+
+    #[tokio::test]
+    async fn pre_release_channel_offers_pre_releases() {
+        let service = VersionService::new_for_test_with_config(VersionCheckConfig {
+            frequency: VersionCheckFrequency::Weekly,
+            channel: ReleaseChannel::PreRelease,
+        })
+        .expect("Service creation should succeed");
+
+        let (has_update, _, latest) = service
+            .check_with_version("1.0.0")
+            .await
+            .expect("Should succeed");
+
+        assert!(has_update);
+        assert_eq!(latest, "1.1.0-beta.1");
+    }
+
+    #[test]
+    fn release_is_newer_than_its_pre_releases() {
+        assert!(VersionService::is_version_newer_for_test(
+            "1.1.0",
+            "1.1.0-beta.2"
+        ));
+        assert!(!VersionService::is_version_newer_for_test(
+            "1.1.0-beta.2",
+            "1.1.0"
+        ));
+        assert!(VersionService::is_version_newer_for_test(
+            "1.1.0-beta.1",
+            "1.0.0"
+        ));
+    }
+
+    #[test]
+    fn pre_release_identifiers_compare_numerically() {
+        assert!(VersionService::is_version_newer_for_test(
+            "1.1.0-beta.10",
+            "1.1.0-beta.2"
+        ));
+        assert!(VersionService::is_version_newer_for_test(
+            "1.1.0-rc.1",
+            "1.1.0-beta.3"
+        ));
+        assert!(!VersionService::is_version_newer_for_test(
+            "1.1.0-beta",
+            "1.1.0-beta.1"
+        ));
+    }
+
+    #[tokio::test]
+    async fn newer_release_is_pending_until_dismissed() {
+        let service = VersionService::new_for_test().expect("Service creation should succeed");
+
+        service.check_with_version("0.8.0").await.unwrap();
+        let release = service.pending_update().expect("1.0.0 should be pending");
+        assert_eq!(release.version, "1.0.0");
+        assert_eq!(release.highlights, vec!["Add release notes preview"]);
+
+        service.dismiss_update().unwrap();
+        assert!(service.pending_update().is_none());
+    }
+
+    #[tokio::test]
+    async fn nothing_is_pending_when_up_to_date() {
+        let service = VersionService::new_for_test().expect("Service creation should succeed");
+
+        service.check_with_version("1.0.0").await.unwrap();
+
+        assert!(service.pending_update().is_none());
+    }
 }
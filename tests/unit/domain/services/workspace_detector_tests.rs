@@ -0,0 +1,165 @@
+use gittype::domain::models::WorkspaceKind;
+use gittype::domain::services::workspace_detector::WorkspaceDetector;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn write(root: &Path, path: &str, content: &str) {
+    let path = root.join(path);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+#[test]
+fn cargo_members_reads_multiline_arrays_and_exclusions() {
+    let manifest = r#"
+[package]
+name = "root"
+members = ["not-a-workspace"]
+
+[workspace]
+resolver = "2"
+members = [
+    "crates/*", # every crate
+    'tools/gen',
+]
+exclude = ["crates/scratch"]
+
+[workspace.dependencies]
+serde = "1"
+"#;
+
+    let (members, excluded) = WorkspaceDetector::cargo_members(manifest);
+
+    assert_eq!(members, vec!["crates/*", "tools/gen"]);
+    assert_eq!(excluded, vec!["crates/scratch"]);
+}
+
+#[test]
+fn npm_workspaces_accepts_lists_objects_and_negations() {
+    let (members, excluded) =
+        WorkspaceDetector::npm_workspaces(r#"{"workspaces": ["packages/*", "!packages/legacy"]}"#);
+    assert_eq!(members, vec!["packages/*"]);
+    assert_eq!(excluded, vec!["packages/legacy"]);
+
+    let (members, _) =
+        WorkspaceDetector::npm_workspaces(r#"{"workspaces": {"packages": ["apps/*"]}}"#);
+    assert_eq!(members, vec!["apps/*"]);
+
+    let (members, _) = WorkspaceDetector::npm_workspaces(r#"{"name": "single"}"#);
+    assert!(members.is_empty());
+}
+
+#[test]
+fn go_work_uses_reads_single_and_block_directives() {
+    let go_work =
+        "go 1.22\n\nuse ./cmd // the binary\n\nuse (\n\t./pkg/api\n\t\"./pkg/store\"\n)\n";
+
+    assert_eq!(
+        WorkspaceDetector::go_work_uses(go_work),
+        vec!["./cmd", "./pkg/api", "./pkg/store"]
+    );
+}
+
+#[test]
+fn detect_lists_packages_with_their_manifest_names() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    write(
+        root,
+        "Cargo.toml",
+        "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"crates/scratch\"]\n",
+    );
+    write(
+        root,
+        "crates/core/Cargo.toml",
+        "[package]\nname = \"acme-core\"\n",
+    );
+    write(
+        root,
+        "crates/cli/Cargo.toml",
+        "[package]\nname = \"acme-cli\"\n",
+    );
+    write(
+        root,
+        "crates/scratch/Cargo.toml",
+        "[package]\nname = \"scratch\"\n",
+    );
+    std::fs::create_dir_all(root.join("crates/docs")).unwrap();
+    write(root, "package.json", r#"{"workspaces": ["web/*"]}"#);
+    write(root, "web/app/package.json", r#"{"name": "@acme/app"}"#);
+    write(root, "go.work", "use ./services/api\n");
+    write(root, "services/api/go.mod", "module example.com/acme/api\n");
+
+    let packages = WorkspaceDetector::detect(root);
+
+    let summary: Vec<(&str, PathBuf, WorkspaceKind)> = packages
+        .iter()
+        .map(|package| (package.name.as_str(), package.path.clone(), package.kind))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                "acme-cli",
+                PathBuf::from("crates/cli"),
+                WorkspaceKind::Cargo
+            ),
+            (
+                "acme-core",
+                PathBuf::from("crates/core"),
+                WorkspaceKind::Cargo
+            ),
+            (
+                "example.com/acme/api",
+                PathBuf::from("services/api"),
+                WorkspaceKind::Go
+            ),
+            ("@acme/app", PathBuf::from("web/app"), WorkspaceKind::Npm),
+        ]
+    );
+}
+
+#[test]
+fn detect_is_empty_without_a_workspace() {
+    let dir = TempDir::new().unwrap();
+    write(dir.path(), "Cargo.toml", "[package]\nname = \"single\"\n");
+
+    assert!(WorkspaceDetector::detect(dir.path()).is_empty());
+}
+
+#[test]
+fn find_matches_names_and_directories() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    write(
+        root,
+        "Cargo.toml",
+        "[workspace]\nmembers = [\"crates/*\"]\n",
+    );
+    write(
+        root,
+        "crates/core/Cargo.toml",
+        "[package]\nname = \"acme-core\"\n",
+    );
+
+    assert_eq!(
+        WorkspaceDetector::find(root, "acme-core").unwrap().path,
+        PathBuf::from("crates/core")
+    );
+    assert_eq!(
+        WorkspaceDetector::find(root, "./crates/core/")
+            .unwrap()
+            .name,
+        "acme-core"
+    );
+
+    let error = WorkspaceDetector::find(root, "missing").unwrap_err();
+    assert!(error.to_string().contains("acme-core"));
+}
+
+#[test]
+fn find_fails_without_a_workspace() {
+    let dir = TempDir::new().unwrap();
+
+    assert!(WorkspaceDetector::find(dir.path(), "core").is_err());
+}
@@ -106,6 +106,23 @@ fn test_set_and_get_extraction_options() {
     assert!(!result.include_patterns.is_empty());
 }
 
+// --- quick_play field ---
+
+#[test]
+fn test_take_quick_play_is_false_by_default() {
+    let store = create_store();
+    assert!(!store.take_quick_play());
+}
+
+#[test]
+fn test_take_quick_play_withdraws_the_offer() {
+    let store = create_store();
+    store.set_quick_play(true);
+
+    assert!(store.take_quick_play());
+    assert!(!store.take_quick_play());
+}
+
 // --- clear all ---
 
 #[test]
@@ -115,6 +132,7 @@ fn test_clear_resets_all_fields() {
     store.set_repo_spec("owner/repo".to_string());
     store.set_repo_path(PathBuf::from("/tmp/test"));
     store.set_extraction_options(gittype::domain::models::ExtractionOptions::default());
+    store.set_quick_play(true);
 
     store.clear();
 
@@ -122,6 +140,7 @@ fn test_clear_resets_all_fields() {
     assert!(store.get_repo_spec().is_none());
     assert!(store.get_repo_path().is_none());
     assert!(store.get_extraction_options().is_none());
+    assert!(!store.take_quick_play());
 }
 
 #[test]
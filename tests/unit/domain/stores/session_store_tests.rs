@@ -1,4 +1,6 @@
+use gittype::domain::models::ErrorReport;
 use gittype::domain::stores::{SessionStore, SessionStoreInterface};
+use gittype::GitTypeError;
 
 fn create_store() -> SessionStore {
     SessionStore::new_for_test()
@@ -91,6 +93,21 @@ fn test_clear_error_message_on_none_is_noop() {
     assert!(store.get_error_message().is_none());
 }
 
+// --- error_report ---
+
+#[test]
+fn test_set_and_get_error_report() {
+    let store = create_store();
+    assert!(store.get_error_report().is_none());
+
+    store.set_error_report(ErrorReport::from_error(&GitTypeError::NoSupportedFiles));
+
+    assert_eq!(
+        store.get_error_report().unwrap().title,
+        "No challenges found"
+    );
+}
+
 // --- clear all ---
 
 #[test]
@@ -99,12 +116,14 @@ fn test_clear_resets_all_fields() {
     store.set_loading_completed(true);
     store.set_loading_failed(true);
     store.set_error_message("Error".to_string());
+    store.set_error_report(ErrorReport::from_error(&GitTypeError::NoSupportedFiles));
 
     store.clear();
 
     assert!(!store.is_loading_completed());
     assert!(!store.is_loading_failed());
     assert!(store.get_error_message().is_none());
+    assert!(store.get_error_report().is_none());
 }
 
 #[test]
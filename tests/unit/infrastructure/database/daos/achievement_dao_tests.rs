@@ -0,0 +1,42 @@
+use chrono::{TimeZone, Utc};
+use gittype::domain::models::Achievement;
+use gittype::infrastructure::database::daos::{AchievementDao, AchievementDaoInterface};
+use gittype::infrastructure::database::database::{Database, DatabaseInterface};
+use std::sync::Arc;
+
+fn dao() -> AchievementDao {
+    AchievementDao::new(Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>)
+}
+
+#[test]
+fn no_achievements_are_unlocked_initially() {
+    assert!(dao().get_unlocked_achievements().unwrap().is_empty());
+}
+
+#[test]
+fn insert_keeps_the_first_unlock_time() {
+    let dao = dao();
+    let first = Utc.with_ymd_and_hms(2024, 5, 10, 9, 30, 0).unwrap();
+    let later = Utc.with_ymd_and_hms(2024, 6, 1, 9, 30, 0).unwrap();
+
+    let inserted = dao
+        .insert_unlocked_achievements(&[
+            (Achievement::FirstStage, first),
+            (Achievement::Wpm40, later),
+        ])
+        .unwrap();
+    assert_eq!(inserted, 2);
+
+    let inserted = dao
+        .insert_unlocked_achievements(&[(Achievement::FirstStage, later)])
+        .unwrap();
+    assert_eq!(inserted, 0);
+
+    assert_eq!(
+        dao.get_unlocked_achievements().unwrap(),
+        vec![
+            (Achievement::FirstStage, first),
+            (Achievement::Wpm40, later)
+        ]
+    );
+}
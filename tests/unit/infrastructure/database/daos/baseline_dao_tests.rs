@@ -0,0 +1,58 @@
+use chrono::{TimeZone, Utc};
+use gittype::domain::models::{BaselineSource, TypingBaseline};
+use gittype::infrastructure::database::daos::{BaselineDao, BaselineDaoInterface};
+use gittype::infrastructure::database::database::{Database, DatabaseInterface};
+use std::sync::Arc;
+
+fn dao() -> BaselineDao {
+    BaselineDao::new(Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>)
+}
+
+fn baseline(source: BaselineSource, minute: u32, wpm: f64) -> TypingBaseline {
+    TypingBaseline {
+        source,
+        taken_at: Utc.with_ymd_and_hms(2024, 5, 10, 9, minute, 0).unwrap(),
+        wpm,
+        accuracy: 95.0,
+        duration_seconds: Some(30.0),
+    }
+}
+
+#[test]
+fn summary_is_none_without_baselines() {
+    assert_eq!(dao().get_baseline_summary().unwrap(), None);
+}
+
+#[test]
+fn insert_baselines_skips_already_imported_tests() {
+    let dao = dao();
+    let tests = [
+        baseline(BaselineSource::Monkeytype, 0, 90.0),
+        baseline(BaselineSource::Monkeytype, 1, 110.0),
+    ];
+
+    assert_eq!(dao.insert_baselines(&tests).unwrap(), 2);
+    assert_eq!(dao.insert_baselines(&tests).unwrap(), 0);
+    assert_eq!(dao.get_baseline_summary().unwrap().unwrap().tests, 2);
+}
+
+#[test]
+fn summary_aggregates_across_sources() {
+    let dao = dao();
+    dao.insert_baselines(&[
+        baseline(BaselineSource::Monkeytype, 0, 90.0),
+        baseline(BaselineSource::Keybr, 0, 70.0),
+    ])
+    .unwrap();
+
+    let summary = dao.get_baseline_summary().unwrap().unwrap();
+
+    assert_eq!(summary.tests, 2);
+    assert_eq!(summary.avg_wpm, 80.0);
+    assert_eq!(summary.best_wpm, 90.0);
+    assert_eq!(summary.avg_accuracy, 95.0);
+    assert_eq!(
+        summary.sources,
+        vec![BaselineSource::Keybr, BaselineSource::Monkeytype]
+    );
+}
@@ -0,0 +1,26 @@
+use gittype::domain::models::Challenge;
+use gittype::infrastructure::database::daos::{ChallengeFlagDao, ChallengeFlagDaoInterface};
+use gittype::infrastructure::database::database::{Database, DatabaseInterface};
+use std::sync::Arc;
+
+fn dao() -> ChallengeFlagDao {
+    ChallengeFlagDao::new(Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>)
+}
+
+#[test]
+fn no_challenges_are_flagged_initially() {
+    assert!(dao().get_flagged_fingerprints().unwrap().is_empty());
+}
+
+#[test]
+fn insert_flag_records_the_fingerprint_once() {
+    let dao = dao();
+    let challenge = Challenge::new("garbled".to_string(), "x=1;y=2;z=3".to_string());
+
+    assert!(dao.insert_flag(&challenge).unwrap());
+    assert!(!dao.insert_flag(&challenge).unwrap());
+
+    let flagged = dao.get_flagged_fingerprints().unwrap();
+    assert_eq!(flagged.len(), 1);
+    assert!(flagged.contains(&challenge.fingerprint()));
+}
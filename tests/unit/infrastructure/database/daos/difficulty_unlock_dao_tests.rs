@@ -0,0 +1,72 @@
+use gittype::domain::models::DifficultyLevel;
+use gittype::infrastructure::database::daos::{DifficultyUnlockDao, DifficultyUnlockDaoInterface};
+use gittype::infrastructure::database::database::{Database, DatabaseInterface};
+use std::sync::Arc;
+
+/// Normal sessions at 92% and 97% for "Ada", and 99% recorded before profiles were tracked
+fn database() -> Arc<dyn DatabaseInterface> {
+    let db = Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>;
+    db.get_connection()
+        .unwrap()
+        .execute_batch(
+            "INSERT INTO repositories (id, user_name, repository_name, remote_url)
+             VALUES (1, 'owner', 'repo', 'https://github.com/owner/repo');
+             INSERT INTO sessions (id, repository_id, started_at, game_mode, player_name)
+             VALUES (1, 1, '2026-01-01 00:00:00', 'Normal', 'Ada'),
+                    (2, 1, '2026-01-02 00:00:00', 'Normal', 'Ada'),
+                    (3, 1, '2026-01-03 00:00:00', 'Normal', NULL);
+             INSERT INTO session_results (session_id, repository_id, keystrokes, mistakes,
+                 duration_ms, accuracy, stages_completed, stages_attempted, stages_skipped,
+                 difficulty_level)
+             VALUES (1, 1, 100, 8, 60000, 92.0, 3, 3, 0, 'Normal'),
+                    (2, 1, 100, 3, 60000, 97.0, 3, 3, 0, 'Normal'),
+                    (3, 1, 100, 1, 60000, 99.0, 3, 3, 0, 'Normal');",
+        )
+        .unwrap();
+    db
+}
+
+#[test]
+fn best_accuracy_is_per_player_and_difficulty() {
+    let dao = DifficultyUnlockDao::new(database());
+
+    assert_eq!(
+        dao.get_best_accuracy("Ada", DifficultyLevel::Normal)
+            .unwrap(),
+        Some(97.0)
+    );
+    assert_eq!(
+        dao.get_best_accuracy("Ada", DifficultyLevel::Hard).unwrap(),
+        None
+    );
+    assert_eq!(
+        dao.get_best_accuracy("Grace", DifficultyLevel::Normal)
+            .unwrap(),
+        None
+    );
+}
+
+#[test]
+fn sessions_without_player_count_for_the_default_player() {
+    let dao = DifficultyUnlockDao::new(database());
+
+    assert_eq!(
+        dao.get_best_accuracy("Player", DifficultyLevel::Normal)
+            .unwrap(),
+        Some(99.0)
+    );
+}
+
+#[test]
+fn unlocks_are_kept_per_player() {
+    let dao = DifficultyUnlockDao::new(database());
+
+    assert!(dao.insert_unlock("Ada", DifficultyLevel::Hard).unwrap());
+    assert!(!dao.insert_unlock("Ada", DifficultyLevel::Hard).unwrap());
+
+    assert_eq!(
+        dao.get_unlocked("Ada").unwrap(),
+        vec![DifficultyLevel::Hard]
+    );
+    assert!(dao.get_unlocked("Grace").unwrap().is_empty());
+}
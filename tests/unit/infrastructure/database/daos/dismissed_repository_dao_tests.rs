@@ -0,0 +1,47 @@
+use gittype::infrastructure::database::daos::{
+    DismissedRepositoryDao, DismissedRepositoryDaoInterface,
+};
+use gittype::infrastructure::database::database::Database;
+use std::sync::Arc;
+
+fn dao() -> DismissedRepositoryDao {
+    DismissedRepositoryDao::new(Arc::new(Database::new().unwrap()))
+}
+
+#[test]
+fn no_repositories_are_dismissed_initially() {
+    assert!(dao().get_dismissed().unwrap().is_empty());
+}
+
+#[test]
+fn dismissed_repositories_are_listed_in_name_order() {
+    let dao = dao();
+
+    assert!(dao.insert_dismissed("rust-lang/rust").unwrap());
+    assert!(dao.insert_dismissed("golang/go").unwrap());
+
+    assert_eq!(
+        dao.get_dismissed().unwrap(),
+        vec!["golang/go".to_string(), "rust-lang/rust".to_string()]
+    );
+}
+
+#[test]
+fn dismissing_again_ignores_case() {
+    let dao = dao();
+
+    assert!(dao.insert_dismissed("rust-lang/rust").unwrap());
+    assert!(!dao.insert_dismissed("Rust-Lang/Rust").unwrap());
+
+    assert_eq!(dao.get_dismissed().unwrap().len(), 1);
+}
+
+#[test]
+fn delete_dismissed_reports_whether_the_repository_was_dismissed() {
+    let dao = dao();
+    dao.insert_dismissed("rust-lang/rust").unwrap();
+
+    assert!(dao.delete_dismissed("RUST-LANG/rust").unwrap());
+    assert!(!dao.delete_dismissed("rust-lang/rust").unwrap());
+    assert!(dao.get_dismissed().unwrap().is_empty());
+}
@@ -1,4 +1,10 @@
+pub mod achievement_dao_tests;
+pub mod baseline_dao_tests;
 pub mod challenge_dao_tests;
+pub mod challenge_flag_dao_tests;
+pub mod difficulty_unlock_dao_tests;
+pub mod dismissed_repository_dao_tests;
 pub mod repository_dao_tests;
+pub mod repository_rating_dao_tests;
 pub mod session_dao_tests;
 pub mod stage_dao_tests;
@@ -1,8 +1,8 @@
-use gittype::domain::models::storage::SaveSessionResultParams;
+use gittype::domain::models::storage::{RepositoryMetadata, SaveSessionResultParams};
 use gittype::domain::models::{Challenge, GitRepository, SessionResult};
 use gittype::infrastructure::database::daos::{
-    ChallengeDao, ChallengeDaoInterface, RepositoryDao, RepositoryDaoInterface, SessionDao,
-    SessionDaoInterface,
+    ChallengeDao, ChallengeDaoInterface, RepositoryDao, RepositoryDaoInterface,
+    RepositoryRatingDao, RepositoryRatingDaoInterface, SessionDao, SessionDaoInterface,
 };
 use gittype::infrastructure::database::database::{Database, DatabaseInterface};
 use std::sync::Arc;
@@ -29,6 +29,7 @@ fn test_ensure_repository_creates_new_repository() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let id = dao.ensure_repository(&git_repo).unwrap();
@@ -50,6 +51,7 @@ fn test_ensure_repository_returns_existing_repository() {
         commit_hash: Some("xyz789".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     // Insert first time
@@ -79,6 +81,7 @@ fn test_ensure_repository_in_transaction() {
         commit_hash: Some("def456".to_string()),
         is_dirty: true,
         root_path: None,
+        license: None,
     };
 
     let conn = db.get_connection().unwrap();
@@ -116,6 +119,7 @@ fn test_get_all_repositories() {
             commit_hash: None,
             is_dirty: false,
             root_path: None,
+            license: None,
         },
         GitRepository {
             user_name: "user2".to_string(),
@@ -125,6 +129,7 @@ fn test_get_all_repositories() {
             commit_hash: None,
             is_dirty: false,
             root_path: None,
+            license: None,
         },
         GitRepository {
             user_name: "user1".to_string(),
@@ -134,6 +139,7 @@ fn test_get_all_repositories() {
             commit_hash: None,
             is_dirty: false,
             root_path: None,
+            license: None,
         },
     ];
 
@@ -172,6 +178,7 @@ fn test_get_repository_by_id() {
         commit_hash: None,
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let id = dao.ensure_repository(&git_repo).unwrap();
@@ -216,6 +223,7 @@ fn test_find_repository() {
         commit_hash: None,
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let id = dao.ensure_repository(&git_repo).unwrap();
@@ -262,6 +270,7 @@ fn test_get_all_repositories_with_languages() {
         commit_hash: None,
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     dao.ensure_repository(&git_repo).unwrap();
@@ -300,6 +309,7 @@ fn insert_stage_result_with_language(
         commit_hash: Some("abc".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     let session_result = SessionResult::new();
 
@@ -319,6 +329,7 @@ fn insert_stage_result_with_language(
             Some(&git_repo),
             "normal",
             Some("easy"),
+            None,
         )
         .unwrap();
     session_dao
@@ -396,6 +407,7 @@ fn test_get_all_repositories_with_languages_aggregates_multiple_languages() {
         commit_hash: None,
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     let repository_id = dao.ensure_repository(&git_repo).unwrap();
 
@@ -418,6 +430,99 @@ fn test_get_all_repositories_with_languages_aggregates_multiple_languages() {
     assert!(repo.languages.iter().any(|l| l == "javascript"));
 }
 
+#[test]
+fn test_get_all_repositories_with_languages_aggregates_ratings() {
+    let db_impl = Database::new().unwrap();
+    db_impl.init().unwrap();
+    let db = Arc::new(db_impl) as Arc<dyn DatabaseInterface>;
+    let dao = RepositoryDao::new(Arc::clone(&db));
+
+    let repository_id = dao
+        .ensure_repository(&GitRepository {
+            user_name: "languser".to_string(),
+            repository_name: "langrepo".to_string(),
+            remote_url: "https://github.com/languser/langrepo".to_string(),
+            branch: None,
+            commit_hash: None,
+            is_dirty: false,
+            root_path: None,
+            license: None,
+        })
+        .unwrap();
+    let repo = |dao: &RepositoryDao| {
+        dao.get_all_repositories_with_languages()
+            .unwrap()
+            .into_iter()
+            .find(|r| r.id == repository_id)
+            .unwrap()
+    };
+    assert_eq!(repo(&dao).rating, None);
+
+    insert_stage_result_with_language(&db, repository_id, "rust", "challenge-rust-1");
+    insert_stage_result_with_language(&db, repository_id, "javascript", "challenge-js-1");
+    let session_ids: Vec<i64> = db
+        .get_connection()
+        .unwrap()
+        .prepare("SELECT id FROM sessions ORDER BY id")
+        .unwrap()
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let rating_dao = RepositoryRatingDao::new(Arc::clone(&db));
+    rating_dao.rate_session(session_ids[0], 5).unwrap();
+    rating_dao.rate_session(session_ids[1], 2).unwrap();
+
+    let rating = repo(&dao).rating.expect("Repository should be rated");
+    assert_eq!(rating.average, 3.5);
+    assert_eq!(rating.count, 2);
+}
+
+#[test]
+fn test_get_all_repositories_with_languages_reports_last_played() {
+    let db_impl = Database::new().unwrap();
+    db_impl.init().unwrap();
+    let db = Arc::new(db_impl) as Arc<dyn DatabaseInterface>;
+    let dao = RepositoryDao::new(Arc::clone(&db));
+
+    let played_id = dao
+        .ensure_repository(&GitRepository {
+            user_name: "languser".to_string(),
+            repository_name: "langrepo".to_string(),
+            remote_url: "https://github.com/languser/langrepo".to_string(),
+            branch: None,
+            commit_hash: None,
+            is_dirty: false,
+            root_path: None,
+            license: None,
+        })
+        .unwrap();
+    dao.ensure_repository(&GitRepository {
+        user_name: "idle".to_string(),
+        repository_name: "never-played".to_string(),
+        remote_url: "https://github.com/idle/never-played".to_string(),
+        branch: None,
+        commit_hash: None,
+        is_dirty: false,
+        root_path: None,
+        license: None,
+    })
+    .unwrap();
+    insert_stage_result_with_language(&db, played_id, "rust", "challenge-played-1");
+
+    let repos = dao.get_all_repositories_with_languages().unwrap();
+    let last_played = |name: &str| {
+        repos
+            .iter()
+            .find(|r| r.repository_name == name)
+            .unwrap()
+            .last_played_at
+    };
+
+    assert!(last_played("langrepo").is_some());
+    assert!(last_played("never-played").is_none());
+}
+
 #[test]
 fn test_get_all_repositories_with_languages_handles_empty_database() {
     let db_impl = Database::new().unwrap();
@@ -444,6 +549,7 @@ fn test_find_repository_distinguishes_by_user_and_name() {
         commit_hash: None,
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     let repo_b = GitRepository {
         user_name: "bob".to_string(),
@@ -453,6 +559,7 @@ fn test_find_repository_distinguishes_by_user_and_name() {
         commit_hash: None,
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let id_a = dao.ensure_repository(&repo_a).unwrap();
@@ -488,6 +595,7 @@ fn test_get_repository_by_id_returns_correct_record() {
             commit_hash: None,
             is_dirty: false,
             root_path: None,
+            license: None,
         };
         ids.push(dao.ensure_repository(&git_repo).unwrap());
     }
@@ -515,6 +623,7 @@ fn test_repository_uniqueness_constraint() {
         commit_hash: None,
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let git_repo2 = GitRepository {
@@ -525,6 +634,7 @@ fn test_repository_uniqueness_constraint() {
         commit_hash: None,
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let id1 = dao.ensure_repository(&git_repo1).unwrap();
@@ -553,6 +663,7 @@ fn test_multiple_repositories_in_transaction() {
             commit_hash: None,
             is_dirty: false,
             root_path: None,
+            license: None,
         },
         GitRepository {
             user_name: "txuser2".to_string(),
@@ -562,6 +673,7 @@ fn test_multiple_repositories_in_transaction() {
             commit_hash: None,
             is_dirty: false,
             root_path: None,
+            license: None,
         },
         GitRepository {
             user_name: "txuser3".to_string(),
@@ -571,6 +683,7 @@ fn test_multiple_repositories_in_transaction() {
             commit_hash: None,
             is_dirty: false,
             root_path: None,
+            license: None,
         },
     ];
 
@@ -631,6 +744,7 @@ fn ensure_repository_in_transaction_returns_database_error_when_existing_id_has_
         commit_hash: None,
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     let error = dao
         .ensure_repository_in_transaction(&tx, &git_repo)
@@ -688,3 +802,77 @@ fn find_repository_returns_database_error_when_row_has_wrong_type() {
 
     assert!(error.to_string().contains("Database error"));
 }
+
+#[test]
+fn repository_metadata_round_trips_through_update_and_get() {
+    let db = Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>;
+    let dao = RepositoryDao::new(Arc::clone(&db));
+    let git_repo = GitRepository {
+        user_name: "metauser".to_string(),
+        repository_name: "metarepo".to_string(),
+        remote_url: "https://github.com/metauser/metarepo".to_string(),
+        branch: None,
+        commit_hash: None,
+        is_dirty: false,
+        root_path: None,
+        license: None,
+    };
+    let id = dao.ensure_repository(&git_repo).unwrap();
+    assert!(dao.get_repository_metadata(id).unwrap().is_none());
+
+    let fetched_at = chrono::DateTime::parse_from_rfc3339("2025-01-02T03:04:05Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    let metadata = RepositoryMetadata {
+        description: Some("Metadata test".to_string()),
+        stars: 42,
+        default_branch: Some("trunk".to_string()),
+        primary_language: Some("Rust".to_string()),
+        fetched_at,
+    };
+    dao.update_repository_metadata(id, &metadata).unwrap();
+
+    assert_eq!(
+        dao.get_repository_metadata(id).unwrap(),
+        Some(metadata.clone())
+    );
+    let listed = dao.get_all_repositories_with_languages().unwrap();
+    assert_eq!(listed[0].metadata, Some(metadata));
+}
+
+#[test]
+fn test_ensure_repository_keeps_the_last_detected_license() {
+    let db_impl = Database::new().unwrap();
+    db_impl.init().unwrap();
+    let db = Arc::new(db_impl) as Arc<dyn DatabaseInterface>;
+    let dao = RepositoryDao::new(Arc::clone(&db));
+    let git_repo = GitRepository {
+        user_name: "licensed".to_string(),
+        repository_name: "repo".to_string(),
+        remote_url: "https://github.com/licensed/repo".to_string(),
+        branch: None,
+        commit_hash: None,
+        is_dirty: false,
+        root_path: None,
+        license: None,
+    };
+    let license = |dao: &RepositoryDao| {
+        dao.find_repository("licensed", "repo")
+            .unwrap()
+            .unwrap()
+            .license
+    };
+
+    dao.ensure_repository(&git_repo).unwrap();
+    assert_eq!(license(&dao), None);
+
+    dao.ensure_repository(&GitRepository {
+        license: Some("MIT".to_string()),
+        ..git_repo.clone()
+    })
+    .unwrap();
+    assert_eq!(license(&dao).as_deref(), Some("MIT"));
+
+    dao.ensure_repository(&git_repo).unwrap();
+    assert_eq!(license(&dao).as_deref(), Some("MIT"));
+}
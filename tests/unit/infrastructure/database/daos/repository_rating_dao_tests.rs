@@ -0,0 +1,70 @@
+use gittype::infrastructure::database::daos::{RepositoryRatingDao, RepositoryRatingDaoInterface};
+use gittype::infrastructure::database::database::{Database, DatabaseInterface};
+use std::sync::Arc;
+
+/// Sessions 1 and 2 on `owner/rust-repo` (Rust), 3 on `owner/go-repo` and 4 without a repository
+fn database() -> Arc<dyn DatabaseInterface> {
+    let db = Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>;
+    db.get_connection()
+        .unwrap()
+        .execute_batch(
+            "INSERT INTO repositories (id, user_name, repository_name, remote_url, primary_language)
+             VALUES (1, 'owner', 'rust-repo', 'https://github.com/owner/rust-repo', 'Rust'),
+                    (2, 'owner', 'go-repo', 'https://github.com/owner/go-repo', NULL);
+             INSERT INTO sessions (id, repository_id, started_at, game_mode)
+             VALUES (1, 1, '2026-01-01 00:00:00', 'Normal'),
+                    (2, 1, '2026-01-02 00:00:00', 'Normal'),
+                    (3, 2, '2026-01-03 00:00:00', 'Normal'),
+                    (4, NULL, '2026-01-04 00:00:00', 'Normal');",
+        )
+        .unwrap();
+    db
+}
+
+#[test]
+fn no_repositories_are_rated_initially() {
+    let dao = RepositoryRatingDao::new(database());
+
+    assert!(dao.get_rated_repositories().unwrap().is_empty());
+}
+
+#[test]
+fn ratings_are_averaged_per_repository() {
+    let dao = RepositoryRatingDao::new(database());
+
+    assert!(dao.rate_session(1, 5).unwrap());
+    assert!(dao.rate_session(2, 2).unwrap());
+    assert!(dao.rate_session(3, 4).unwrap());
+
+    let mut rated = dao.get_rated_repositories().unwrap();
+    rated.sort_by(|a, b| a.repository_name.cmp(&b.repository_name));
+    assert_eq!(rated.len(), 2);
+    assert_eq!(rated[0].repository_name, "go-repo");
+    assert_eq!(rated[0].primary_language, None);
+    assert_eq!(rated[0].rating.count, 1);
+    assert_eq!(rated[1].repository_name, "rust-repo");
+    assert_eq!(rated[1].primary_language.as_deref(), Some("Rust"));
+    assert_eq!(rated[1].rating.average, 3.5);
+    assert_eq!(rated[1].rating.count, 2);
+}
+
+#[test]
+fn rating_a_session_again_replaces_its_rating() {
+    let dao = RepositoryRatingDao::new(database());
+
+    assert!(dao.rate_session(1, 1).unwrap());
+    assert!(dao.rate_session(1, 4).unwrap());
+
+    let rated = dao.get_rated_repositories().unwrap();
+    assert_eq!(rated[0].rating.average, 4.0);
+    assert_eq!(rated[0].rating.count, 1);
+}
+
+#[test]
+fn sessions_without_a_repository_are_not_rated() {
+    let dao = RepositoryRatingDao::new(database());
+
+    assert!(!dao.rate_session(4, 3).unwrap());
+    assert!(!dao.rate_session(99, 3).unwrap());
+    assert!(dao.get_rated_repositories().unwrap().is_empty());
+}
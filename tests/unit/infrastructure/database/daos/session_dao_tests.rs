@@ -1,4 +1,7 @@
-use gittype::domain::models::{Challenge, DifficultyLevel, GitRepository, SessionResult};
+use gittype::domain::models::storage::SessionHistoryFilter;
+use gittype::domain::models::{
+    Challenge, DifficultyLevel, GitRepository, ResultPlausibility, SessionResult,
+};
 use gittype::infrastructure::database::daos::{
     ChallengeDao, ChallengeDaoInterface, RepositoryDao, RepositoryDaoInterface, SessionDao,
     SessionDaoInterface,
@@ -30,6 +33,7 @@ fn test_create_session_in_transaction() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -48,6 +52,7 @@ fn test_create_session_in_transaction() {
             Some(&git_repo),
             "normal",
             Some("easy"),
+            None,
         )
         .unwrap();
     tx.commit().unwrap();
@@ -72,6 +77,7 @@ fn test_save_session_result_in_transaction() {
         commit_hash: Some("xyz789".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -106,6 +112,7 @@ fn test_save_session_result_in_transaction() {
             Some(&git_repo),
             "normal",
             Some("medium"),
+            None,
         )
         .unwrap();
 
@@ -156,6 +163,7 @@ fn test_save_stage_result_in_transaction() {
         commit_hash: Some("stage123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -187,6 +195,7 @@ fn test_save_stage_result_in_transaction() {
             Some(&git_repo),
             "normal",
             Some("easy"),
+            None,
         )
         .unwrap();
     tx.commit().unwrap();
@@ -274,6 +283,7 @@ fn test_get_repository_sessions() {
         commit_hash: Some("hist123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -293,6 +303,7 @@ fn test_get_repository_sessions() {
                 Some(&git_repo),
                 "normal",
                 Some("easy"),
+                None,
             )
             .unwrap();
         tx.commit().unwrap();
@@ -308,6 +319,36 @@ fn test_get_repository_sessions() {
     }
 }
 
+#[test]
+fn test_get_session_by_id() {
+    let db_impl = Database::new().unwrap();
+    db_impl.init().unwrap();
+    let db = Arc::new(db_impl) as Arc<dyn DatabaseInterface>;
+    let session_dao = SessionDao::new(Arc::clone(&db));
+
+    let conn = db.get_connection().unwrap();
+    let tx = conn.unchecked_transaction().unwrap();
+    let session_id = session_dao
+        .create_session_in_transaction(
+            &tx,
+            None,
+            &SessionResult::new(),
+            None,
+            "normal",
+            Some("easy"),
+            None,
+        )
+        .unwrap();
+    tx.commit().unwrap();
+    drop(conn);
+
+    let session = session_dao.get_session(session_id).unwrap().unwrap();
+    assert_eq!(session.id, session_id);
+    assert_eq!(session.game_mode, "normal");
+    assert_eq!(session.difficulty_level.as_deref(), Some("easy"));
+    assert!(session_dao.get_session(session_id + 1).unwrap().is_none());
+}
+
 #[test]
 fn test_get_todays_best_session() {
     let db_impl = Database::new().unwrap();
@@ -324,6 +365,7 @@ fn test_get_todays_best_session() {
         commit_hash: Some("today123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -344,6 +386,7 @@ fn test_get_todays_best_session() {
                 Some(&git_repo),
                 "normal",
                 Some("easy"),
+                None,
             )
             .unwrap();
 
@@ -395,6 +438,7 @@ fn test_get_weekly_best_session() {
         commit_hash: Some("week123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -412,6 +456,7 @@ fn test_get_weekly_best_session() {
             Some(&git_repo),
             "normal",
             Some("easy"),
+            None,
         )
         .unwrap();
 
@@ -452,6 +497,7 @@ fn test_get_all_time_best_session() {
         commit_hash: Some("alltime123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -472,6 +518,7 @@ fn test_get_all_time_best_session() {
                 Some(&git_repo),
                 "normal",
                 Some("easy"),
+                None,
             )
             .unwrap();
 
@@ -523,6 +570,7 @@ fn test_get_session_result() {
         commit_hash: Some("get123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -542,6 +590,7 @@ fn test_get_session_result() {
             Some(&git_repo),
             "normal",
             Some("easy"),
+            None,
         )
         .unwrap();
 
@@ -602,6 +651,7 @@ fn test_get_sessions_filtered_by_repository() {
         commit_hash: Some("filter1".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let git_repo2 = GitRepository {
@@ -612,6 +662,7 @@ fn test_get_sessions_filtered_by_repository() {
         commit_hash: Some("filter2".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let repo_id1 = repo_dao.ensure_repository(&git_repo1).unwrap();
@@ -638,6 +689,7 @@ fn test_get_sessions_filtered_by_repository() {
                 Some(git_repo),
                 "normal",
                 Some("easy"),
+                None,
             )
             .unwrap();
 
@@ -689,6 +741,7 @@ fn test_get_sessions_filtered_by_date() {
         commit_hash: Some("date123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -706,6 +759,7 @@ fn test_get_sessions_filtered_by_date() {
             Some(&git_repo),
             "normal",
             Some("easy"),
+            None,
         )
         .unwrap();
 
@@ -753,6 +807,7 @@ fn test_get_sessions_filtered_sorted_by_score() {
         commit_hash: Some("sort123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -773,6 +828,7 @@ fn test_get_sessions_filtered_sorted_by_score() {
                 Some(&git_repo),
                 "normal",
                 Some("easy"),
+                None,
             )
             .unwrap();
 
@@ -830,6 +886,7 @@ fn test_get_session_stage_results() {
         commit_hash: Some("stageresult123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -868,6 +925,7 @@ fn test_get_session_stage_results() {
             Some(&git_repo),
             "normal",
             Some("easy"),
+            None,
         )
         .unwrap();
     tx.commit().unwrap();
@@ -953,6 +1011,7 @@ fn make_git_repo(user: &str, repo: &str, commit: &str) -> GitRepository {
         commit_hash: Some(commit.to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     }
 }
 
@@ -978,6 +1037,7 @@ fn seed_session_with_score(
             Some(git_repo),
             "normal",
             Some("easy"),
+            None,
         )
         .unwrap();
 
@@ -1024,6 +1084,7 @@ fn test_save_session_result_in_transaction_requires_repository_id() {
             Some(&git_repo),
             "normal",
             Some("easy"),
+            None,
         )
         .unwrap();
 
@@ -1289,3 +1350,446 @@ fn test_get_sessions_filtered_combines_repository_and_date_filters() {
         );
     }
 }
+
+#[test]
+fn test_get_daily_activity_aggregates_sessions_per_day() {
+    let db_impl = Database::new().unwrap();
+    db_impl.init().unwrap();
+    let db = Arc::new(db_impl) as Arc<dyn DatabaseInterface>;
+    let session_dao = SessionDao::new(Arc::clone(&db));
+    let repo_dao = RepositoryDao::new(Arc::clone(&db));
+
+    let git_repo = make_git_repo("dailyuser", "dailyrepo", "daily123");
+    let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
+    let first = seed_session_with_score(&db, &session_dao, repository_id, &git_repo, 100.0, 1000);
+    let second = seed_session_with_score(&db, &session_dao, repository_id, &git_repo, 200.0, 1000);
+    let older = seed_session_with_score(&db, &session_dao, repository_id, &git_repo, 300.0, 1000);
+
+    {
+        let conn = db.get_connection().unwrap();
+        for (session_id, wpm) in [(first, 40.0), (second, 60.0), (older, 90.0)] {
+            conn.execute(
+                "UPDATE session_results SET wpm = ?, accuracy = 95.0, keystrokes = 100 WHERE session_id = ?",
+                rusqlite::params![wpm, session_id],
+            )
+            .unwrap();
+        }
+        conn.execute(
+            "UPDATE sessions SET started_at = DATETIME(started_at, '-2 days') WHERE id = ?",
+            rusqlite::params![older],
+        )
+        .unwrap();
+    }
+
+    let activity = session_dao.get_daily_activity().unwrap();
+
+    assert_eq!(activity.len(), 2);
+    assert!(activity[0].date > activity[1].date);
+    assert_eq!(activity[0].sessions, 2);
+    assert_eq!(activity[0].avg_wpm, 50.0);
+    assert_eq!(activity[0].best_wpm, 60.0);
+    assert_eq!(activity[0].avg_accuracy, 95.0);
+    assert_eq!(activity[0].keystrokes, 200);
+    assert_eq!(activity[1].sessions, 1);
+    assert_eq!(activity[1].best_wpm, 90.0);
+}
+
+#[test]
+fn test_get_daily_activity_empty_database() {
+    let db_impl = Database::new().unwrap();
+    db_impl.init().unwrap();
+    let db = Arc::new(db_impl) as Arc<dyn DatabaseInterface>;
+    let session_dao = SessionDao::new(db);
+
+    assert!(session_dao.get_daily_activity().unwrap().is_empty());
+}
+
+#[test]
+fn test_get_language_activity_aggregates_completed_stages() {
+    let db_impl = Database::new().unwrap();
+    db_impl.init().unwrap();
+    let db = Arc::new(db_impl) as Arc<dyn DatabaseInterface>;
+    let session_dao = SessionDao::new(Arc::clone(&db));
+    let repo_dao = RepositoryDao::new(Arc::clone(&db));
+    let challenge_dao = ChallengeDao::new(Arc::clone(&db));
+
+    let git_repo = make_git_repo("languser", "langrepo", "lang123");
+    let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
+    let session_id =
+        seed_session_with_score(&db, &session_dao, repository_id, &git_repo, 100.0, 1000);
+    let challenge = Challenge::new("lang-activity".to_string(), "fn a() {}".to_string())
+        .with_language("rust".to_string());
+
+    let conn = db.get_connection().unwrap();
+    let tx = conn.unchecked_transaction().unwrap();
+    challenge_dao
+        .ensure_challenge_in_transaction(&tx, &challenge)
+        .unwrap();
+    let stages = [
+        ("rust", 60.0, 100i64, false),
+        ("rust", 80.0, 200, false),
+        ("rust", 5.0, 10, true),
+        ("go", 50.0, 150, false),
+    ];
+    for (i, (language, wpm, keystrokes, skipped)) in stages.into_iter().enumerate() {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        tx.execute(
+            "INSERT INTO stages (session_id, challenge_id, stage_number, started_at, completed_at)
+             VALUES (?, ?, ?, ?, ?)",
+            rusqlite::params![
+                session_id,
+                challenge.id.as_str(),
+                (i + 1) as i64,
+                &now,
+                &now
+            ],
+        )
+        .unwrap();
+        tx.execute(
+            "INSERT INTO stage_results (
+                stage_id, session_id, repository_id, keystrokes, mistakes, duration_ms,
+                wpm, cpm, accuracy, was_skipped, was_failed, completed_at, language
+            ) VALUES (?, ?, ?, ?, 0, 1000, ?, ?, 95.0, ?, 0, ?, ?)",
+            rusqlite::params![
+                tx.last_insert_rowid(),
+                session_id,
+                repository_id,
+                keystrokes,
+                wpm,
+                wpm * 5.0,
+                skipped,
+                &now,
+                language
+            ],
+        )
+        .unwrap();
+    }
+    tx.commit().unwrap();
+    drop(conn);
+
+    let activity = session_dao.get_language_activity().unwrap();
+
+    assert_eq!(activity.len(), 2);
+    assert_eq!(activity[0].language, "go");
+    assert_eq!(activity[1].language, "rust");
+    assert_eq!(activity[1].stages, 2);
+    assert_eq!(activity[1].avg_wpm, 70.0);
+    assert_eq!(activity[1].best_wpm, 80.0);
+    assert_eq!(activity[1].avg_accuracy, 95.0);
+    assert_eq!(activity[1].keystrokes, 300);
+}
+
+#[test]
+fn test_get_repository_leaderboard_ranks_players_by_best_score() {
+    let db_impl = Database::new().unwrap();
+    db_impl.init().unwrap();
+    let db = Arc::new(db_impl) as Arc<dyn DatabaseInterface>;
+    let session_dao = SessionDao::new(Arc::clone(&db));
+    let repo_dao = RepositoryDao::new(Arc::clone(&db));
+
+    let git_repo = make_git_repo("boarduser", "boardrepo", "board123");
+    let other_repo = make_git_repo("boarduser", "otherrepo", "other123");
+    let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
+    let other_id = repo_dao.ensure_repository(&other_repo).unwrap();
+    let sessions = [
+        (Some("Ada"), 300.0, repository_id, &git_repo),
+        (Some("Ada"), 500.0, repository_id, &git_repo),
+        (Some("Linus"), 400.0, repository_id, &git_repo),
+        (None, 100.0, repository_id, &git_repo),
+        (Some("Grace"), 900.0, other_id, &other_repo),
+    ];
+    for (player_name, score, repo_id, repo) in sessions {
+        let session_id = seed_session_with_score(&db, &session_dao, repo_id, repo, score, 1000);
+        db.get_connection()
+            .unwrap()
+            .execute(
+                "UPDATE sessions SET player_name = ? WHERE id = ?",
+                rusqlite::params![player_name, session_id],
+            )
+            .unwrap();
+    }
+
+    let leaderboard = session_dao
+        .get_repository_leaderboard(repository_id)
+        .unwrap();
+
+    let ranking: Vec<(&str, f64, usize)> = leaderboard
+        .iter()
+        .map(|entry| (entry.player_name.as_str(), entry.best_score, entry.sessions))
+        .collect();
+    assert_eq!(
+        ranking,
+        vec![("Ada", 500.0, 2), ("Linus", 400.0, 1), ("Player", 100.0, 1)]
+    );
+    assert!(leaderboard[0].last_played_at.is_some());
+}
+
+#[test]
+fn test_create_session_in_transaction_marks_implausible_results_unverified() {
+    let db_impl = Database::new().unwrap();
+    db_impl.init().unwrap();
+    let db = Arc::new(db_impl) as Arc<dyn DatabaseInterface>;
+    let session_dao = SessionDao::new(Arc::clone(&db));
+
+    let mut session_result = SessionResult::new();
+    session_result.plausibility = ResultPlausibility::assess(&[], 1);
+
+    let conn = db.get_connection().unwrap();
+    let tx = conn.unchecked_transaction().unwrap();
+    let session_id = session_dao
+        .create_session_in_transaction(&tx, None, &session_result, None, "normal", None, None)
+        .unwrap();
+    tx.commit().unwrap();
+
+    let unverified: bool = conn
+        .query_row(
+            "SELECT unverified FROM sessions WHERE id = ?",
+            rusqlite::params![session_id],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert!(unverified);
+}
+
+#[test]
+fn test_unverified_sessions_stay_out_of_best_records_and_leaderboards() {
+    let db_impl = Database::new().unwrap();
+    db_impl.init().unwrap();
+    let db = Arc::new(db_impl) as Arc<dyn DatabaseInterface>;
+    let session_dao = SessionDao::new(Arc::clone(&db));
+    let repo_dao = RepositoryDao::new(Arc::clone(&db));
+
+    let git_repo = make_git_repo("cheatuser", "cheatrepo", "cheat123");
+    let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
+    let verified =
+        seed_session_with_score(&db, &session_dao, repository_id, &git_repo, 200.0, 1000);
+    let unverified =
+        seed_session_with_score(&db, &session_dao, repository_id, &git_repo, 9000.0, 1000);
+    db.get_connection()
+        .unwrap()
+        .execute(
+            "UPDATE sessions SET unverified = 1 WHERE id = ?",
+            rusqlite::params![unverified],
+        )
+        .unwrap();
+
+    for best in [
+        session_dao.get_todays_best_session().unwrap(),
+        session_dao.get_weekly_best_session().unwrap(),
+        session_dao.get_all_time_best_session().unwrap(),
+    ] {
+        assert_eq!(best.map(|session| session.id), Some(verified));
+    }
+    let leaderboard = session_dao
+        .get_repository_leaderboard(repository_id)
+        .unwrap();
+    assert_eq!(leaderboard.len(), 1);
+    assert_eq!(leaderboard[0].best_score, 200.0);
+    assert_eq!(leaderboard[0].sessions, 1);
+}
+
+#[test]
+fn test_get_language_baselines_leaves_out_unverified_sessions() {
+    let db_impl = Database::new().unwrap();
+    db_impl.init().unwrap();
+    let db = Arc::new(db_impl) as Arc<dyn DatabaseInterface>;
+    let session_dao = SessionDao::new(Arc::clone(&db));
+    let repo_dao = RepositoryDao::new(Arc::clone(&db));
+    let challenge_dao = ChallengeDao::new(Arc::clone(&db));
+
+    let git_repo = make_git_repo("baseuser", "baserepo", "base123");
+    let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
+    let verified =
+        seed_session_with_score(&db, &session_dao, repository_id, &git_repo, 100.0, 1000);
+    let unverified =
+        seed_session_with_score(&db, &session_dao, repository_id, &git_repo, 900.0, 1000);
+    let challenge = Challenge::new("lang-baseline".to_string(), "main = pure ()".to_string())
+        .with_language("haskell".to_string());
+
+    let conn = db.get_connection().unwrap();
+    let tx = conn.unchecked_transaction().unwrap();
+    challenge_dao
+        .ensure_challenge_in_transaction(&tx, &challenge)
+        .unwrap();
+    for (session_id, wpm) in [(verified, 40.0), (unverified, 400.0)] {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        tx.execute(
+            "INSERT INTO stages (session_id, challenge_id, stage_number, started_at, completed_at)
+             VALUES (?, ?, 1, ?, ?)",
+            rusqlite::params![session_id, challenge.id.as_str(), &now, &now],
+        )
+        .unwrap();
+        tx.execute(
+            "INSERT INTO stage_results (
+                stage_id, session_id, repository_id, keystrokes, mistakes, duration_ms,
+                wpm, cpm, accuracy, was_skipped, was_failed, completed_at, language
+            ) VALUES (?, ?, ?, 100, 0, 1000, ?, ?, 95.0, 0, 0, ?, 'haskell')",
+            rusqlite::params![
+                tx.last_insert_rowid(),
+                session_id,
+                repository_id,
+                wpm,
+                wpm * 5.0,
+                &now
+            ],
+        )
+        .unwrap();
+    }
+    tx.execute(
+        "UPDATE sessions SET unverified = 1 WHERE id = ?",
+        rusqlite::params![unverified],
+    )
+    .unwrap();
+    tx.commit().unwrap();
+    drop(conn);
+
+    let baselines = session_dao.get_language_baselines().unwrap();
+
+    assert_eq!(baselines.len(), 1);
+    assert_eq!(baselines[0].language, "haskell");
+    assert_eq!(baselines[0].stages, 1);
+    assert_eq!(baselines[0].avg_wpm, 40.0);
+}
+
+#[test]
+fn test_get_key_load_adds_up_every_recorded_stage() {
+    let db_impl = Database::new().unwrap();
+    db_impl.init().unwrap();
+    let db = Arc::new(db_impl) as Arc<dyn DatabaseInterface>;
+    let session_dao = SessionDao::new(Arc::clone(&db));
+    let repo_dao = RepositoryDao::new(Arc::clone(&db));
+    let challenge_dao = ChallengeDao::new(Arc::clone(&db));
+
+    let git_repo = make_git_repo("keyuser", "keyrepo", "key123");
+    let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
+    let session_id =
+        seed_session_with_score(&db, &session_dao, repository_id, &git_repo, 100.0, 1000);
+    let challenge = Challenge::new("key-load".to_string(), "a::b".to_string());
+
+    let conn = db.get_connection().unwrap();
+    let tx = conn.unchecked_transaction().unwrap();
+    challenge_dao
+        .ensure_challenge_in_transaction(&tx, &challenge)
+        .unwrap();
+    for key_load in [
+        Some(r#"{":":{"keystrokes":2,"mistakes":1}}"#),
+        Some(r#"{":":{"keystrokes":4,"mistakes":2},"a":{"keystrokes":1,"mistakes":0}}"#),
+        None,
+    ] {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        tx.execute(
+            "INSERT INTO stages (session_id, challenge_id, stage_number, started_at, completed_at)
+             VALUES (?, ?, 1, ?, ?)",
+            rusqlite::params![session_id, challenge.id.as_str(), &now, &now],
+        )
+        .unwrap();
+        tx.execute(
+            "INSERT INTO stage_results (
+                stage_id, session_id, repository_id, keystrokes, mistakes, duration_ms,
+                wpm, cpm, accuracy, was_skipped, was_failed, completed_at, key_load
+            ) VALUES (?, ?, ?, 4, 1, 1000, 40.0, 200.0, 75.0, 0, 0, ?, ?)",
+            rusqlite::params![
+                tx.last_insert_rowid(),
+                session_id,
+                repository_id,
+                &now,
+                key_load
+            ],
+        )
+        .unwrap();
+    }
+    tx.commit().unwrap();
+    drop(conn);
+
+    let key_load = session_dao.get_key_load().unwrap();
+
+    assert_eq!(key_load.stats(':').keystrokes, 6);
+    assert_eq!(key_load.stats(':').mistakes, 3);
+    assert_eq!(key_load.stats('a').keystrokes, 1);
+    assert_eq!(key_load.stats('b').keystrokes, 0);
+}
+
+#[test]
+fn test_get_session_history_page_walks_sessions_in_start_order() {
+    let db_impl = Database::new().unwrap();
+    db_impl.init().unwrap();
+    let db = Arc::new(db_impl) as Arc<dyn DatabaseInterface>;
+    let session_dao = SessionDao::new(Arc::clone(&db));
+    let repo_dao = RepositoryDao::new(Arc::clone(&db));
+
+    let git_repo = make_git_repo("pageuser", "pagerepo", "page1");
+    let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
+    let first = seed_session_with_score(&db, &session_dao, repository_id, &git_repo, 100.0, 1000);
+    let second = seed_session_with_score(&db, &session_dao, repository_id, &git_repo, 200.0, 1000);
+    let older = seed_session_with_score(&db, &session_dao, repository_id, &git_repo, 300.0, 1000);
+    {
+        let conn = db.get_connection().unwrap();
+        conn.execute(
+            "UPDATE sessions SET started_at = DATETIME(started_at, '-10 days') WHERE id = ?",
+            rusqlite::params![older],
+        )
+        .unwrap();
+    }
+    let filter = SessionHistoryFilter::default();
+
+    let page = session_dao
+        .get_session_history_page(&filter, None, 2)
+        .unwrap();
+    let ids: Vec<i64> = page.iter().map(|(session, _)| session.id).collect();
+    assert_eq!(ids, vec![older, first]);
+    assert_eq!(page[0].1.score, 300.0);
+
+    let next = session_dao
+        .get_session_history_page(&filter, Some(&page[1].0), 2)
+        .unwrap();
+    let ids: Vec<i64> = next.iter().map(|(session, _)| session.id).collect();
+    assert_eq!(ids, vec![second]);
+}
+
+#[test]
+fn test_get_session_history_page_filters_by_date_range_and_repository() {
+    let db_impl = Database::new().unwrap();
+    db_impl.init().unwrap();
+    let db = Arc::new(db_impl) as Arc<dyn DatabaseInterface>;
+    let session_dao = SessionDao::new(Arc::clone(&db));
+    let repo_dao = RepositoryDao::new(Arc::clone(&db));
+
+    let target = make_git_repo("rangeuser", "rangerepo", "range1");
+    let other = make_git_repo("rangeother", "otherrepo", "range2");
+    let target_id = repo_dao.ensure_repository(&target).unwrap();
+    let other_id = repo_dao.ensure_repository(&other).unwrap();
+    let recent = seed_session_with_score(&db, &session_dao, target_id, &target, 100.0, 1000);
+    let older = seed_session_with_score(&db, &session_dao, target_id, &target, 200.0, 1000);
+    seed_session_with_score(&db, &session_dao, other_id, &other, 300.0, 1000);
+    {
+        let conn = db.get_connection().unwrap();
+        conn.execute(
+            "UPDATE sessions SET started_at = DATETIME(started_at, '-10 days') WHERE id = ?",
+            rusqlite::params![older],
+        )
+        .unwrap();
+    }
+    let today = chrono::Utc::now().date_naive();
+
+    let since_yesterday = SessionHistoryFilter {
+        repository_id: Some(target_id),
+        since: Some(today - chrono::Duration::days(1)),
+        until: None,
+    };
+    let page = session_dao
+        .get_session_history_page(&since_yesterday, None, 10)
+        .unwrap();
+    let ids: Vec<i64> = page.iter().map(|(session, _)| session.id).collect();
+    assert_eq!(ids, vec![recent]);
+
+    let until_last_week = SessionHistoryFilter {
+        repository_id: None,
+        since: None,
+        until: Some(today - chrono::Duration::days(7)),
+    };
+    let page = session_dao
+        .get_session_history_page(&until_last_week, None, 10)
+        .unwrap();
+    let ids: Vec<i64> = page.iter().map(|(session, _)| session.id).collect();
+    assert_eq!(ids, vec![older]);
+}
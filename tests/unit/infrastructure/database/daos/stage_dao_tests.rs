@@ -32,6 +32,7 @@ fn setup_test_data(
         commit_hash: Some("test123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let repository_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -78,6 +79,7 @@ fn setup_test_data(
                 Some(&git_repo),
                 "normal",
                 Some("easy"),
+                None,
             )
             .unwrap();
 
@@ -502,6 +504,7 @@ fn test_empty_stage_statistics() {
         commit_hash: Some("empty123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let empty_repo_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -532,6 +535,7 @@ fn test_empty_language_breakdown() {
         commit_hash: Some("emptylang123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let empty_repo_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -564,6 +568,7 @@ fn test_empty_difficulty_breakdown() {
         commit_hash: Some("emptydiff123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let empty_repo_id = repo_dao.ensure_repository(&git_repo).unwrap();
@@ -621,6 +626,7 @@ fn test_multiple_repositories_isolation() {
         commit_hash: Some("test456".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
     let repo_id2 = repo_dao.ensure_repository(&git_repo2).unwrap();
 
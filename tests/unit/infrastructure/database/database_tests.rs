@@ -1,5 +1,28 @@
+use gittype::domain::models::ReadOnlyMode;
 use gittype::infrastructure::database::database::{Database, DatabaseInterface};
 use gittype::infrastructure::database::migrations::get_latest_version;
+use std::path::Path;
+
+fn count_repositories(db: &Database) -> i32 {
+    db.get_connection()
+        .unwrap()
+        .query_row("SELECT COUNT(*) FROM repositories", [], |row| row.get(0))
+        .unwrap()
+}
+
+fn write_database_file(path: &Path, repositories: &[&str]) {
+    let db = Database::new().unwrap();
+    let conn = db.get_connection().unwrap();
+    for name in repositories {
+        conn.execute(
+            "INSERT INTO repositories (user_name, repository_name, remote_url) VALUES ('owner', ?1, ?1)",
+            [name],
+        )
+        .unwrap();
+    }
+    conn.execute("VACUUM INTO ?1", [path.to_string_lossy().as_ref()])
+        .unwrap();
+}
 
 #[test]
 fn test_database_creation() {
@@ -87,6 +110,148 @@ fn test_migration_idempotency() {
     assert_eq!(count, get_latest_version());
 }
 
+#[test]
+fn test_init_with_progress_reports_each_pending_migration() {
+    let db = Database::new().unwrap();
+    {
+        let conn = db.get_connection().unwrap();
+        conn.execute("PRAGMA foreign_keys = OFF", []).unwrap();
+        let tables: Vec<String> = conn
+            .prepare(
+                "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'",
+            )
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        for table in tables {
+            conn.execute(&format!("DROP TABLE {}", table), []).unwrap();
+        }
+        conn.execute("PRAGMA foreign_keys = ON", []).unwrap();
+    }
+
+    let mut reported = Vec::new();
+    db.init_with_progress(|applied, pending| reported.push((applied, pending)))
+        .unwrap();
+
+    let latest = get_latest_version() as usize;
+    assert_eq!(
+        reported,
+        (0..=latest)
+            .map(|applied| (applied, latest))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        db.get_current_schema_version().unwrap(),
+        get_latest_version()
+    );
+}
+
+#[test]
+fn test_init_with_progress_reports_nothing_when_up_to_date() {
+    let db = Database::new().unwrap();
+
+    let mut reported = Vec::new();
+    db.init_with_progress(|applied, pending| reported.push((applied, pending)))
+        .unwrap();
+
+    assert!(reported.is_empty());
+}
+
+#[test]
+fn test_quick_check_reports_no_problems_for_intact_database() {
+    let db = Database::new().unwrap();
+
+    assert!(db.quick_check().unwrap().is_empty());
+    assert!(Database::integrity_problems().unwrap().is_empty());
+}
+
+#[test]
+fn test_vacuum_reclaims_pages_freed_by_deletes() {
+    let db = Database::new().unwrap();
+    db.with_connection(|conn| {
+        conn.execute_batch(
+            "CREATE TABLE filler (data BLOB);
+             WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 200)
+             INSERT INTO filler SELECT zeroblob(4096) FROM n;
+             DELETE FROM filler;",
+        )?;
+        Ok(())
+    })
+    .unwrap();
+
+    assert!(db.vacuum().unwrap() > 0);
+    assert_eq!(db.vacuum().unwrap(), 0);
+}
+
+#[test]
+fn test_backup_damaged_is_unavailable_for_in_memory_database() {
+    assert!(Database::backup_damaged().is_err());
+}
+
+#[test]
+fn test_copy_of_reads_the_file_but_never_writes_it() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("gittype.db");
+    write_database_file(&path, &["first", "second"]);
+
+    let copy = Database::copy_of(&path).unwrap();
+    assert_eq!(count_repositories(&copy), 2);
+
+    copy.get_connection()
+        .unwrap()
+        .execute(
+            "INSERT INTO repositories (user_name, repository_name, remote_url) VALUES ('owner', 'third', 'third')",
+            [],
+        )
+        .unwrap();
+    assert_eq!(count_repositories(&copy), 3);
+    assert_eq!(count_repositories(&Database::copy_of(&path).unwrap()), 2);
+}
+
+#[test]
+fn test_copy_of_a_file_at_another_schema_version_is_empty() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("gittype.db");
+    write_database_file(&path, &["first"]);
+    rusqlite::Connection::open(&path)
+        .unwrap()
+        .execute(
+            "DELETE FROM schema_version WHERE version = (SELECT MAX(version) FROM schema_version)",
+            [],
+        )
+        .unwrap();
+
+    let copy = Database::copy_of(&path).unwrap();
+
+    assert_eq!(count_repositories(&copy), 0);
+    assert_eq!(
+        copy.get_current_schema_version().unwrap(),
+        get_latest_version()
+    );
+}
+
+#[test]
+fn test_copy_of_a_missing_file_is_empty_and_creates_nothing() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("gittype.db");
+
+    assert_eq!(count_repositories(&Database::copy_of(&path).unwrap()), 0);
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_instance_lock_is_always_available_with_test_mocks() {
+    assert!(Database::acquire_instance_lock().unwrap());
+}
+
+#[test]
+fn test_acquire_or_read_only_keeps_read_only_off_when_the_lock_is_taken() {
+    assert!(Database::acquire_or_read_only().unwrap());
+    assert!(!ReadOnlyMode::is_enabled());
+}
+
 #[test]
 fn test_normalized_tables_structure() {
     let db = Database::new().unwrap();
@@ -1,4 +1,15 @@
 use gittype::infrastructure::database::migrations::v001_initial_schema::InitialSchema;
+use gittype::infrastructure::database::migrations::v002_repository_metadata::RepositoryMetadata;
+use gittype::infrastructure::database::migrations::v003_typing_baselines::TypingBaselines;
+use gittype::infrastructure::database::migrations::v004_achievements::Achievements;
+use gittype::infrastructure::database::migrations::v005_challenge_flags::ChallengeFlags;
+use gittype::infrastructure::database::migrations::v006_session_players::SessionPlayers;
+use gittype::infrastructure::database::migrations::v007_repository_ratings::RepositoryRatings;
+use gittype::infrastructure::database::migrations::v008_dismissed_repositories::DismissedRepositories;
+use gittype::infrastructure::database::migrations::v009_difficulty_unlocks::DifficultyUnlocks;
+use gittype::infrastructure::database::migrations::v010_stage_mistake_breakdown::StageMistakeBreakdown;
+use gittype::infrastructure::database::migrations::v011_stage_key_load::StageKeyLoad;
+use gittype::infrastructure::database::migrations::v013_session_unverified::SessionUnverified;
 use gittype::infrastructure::database::migrations::{
     get_all_migrations, get_latest_version, Migration,
 };
@@ -87,3 +98,180 @@ fn get_all_migrations_returns_ordered_versions_up_to_latest() {
     let latest = get_latest_version();
     assert!(migrations.iter().any(|m| m.version() == latest));
 }
+
+fn column_exists(conn: &Connection, table_name: &str, column_name: &str) -> bool {
+    conn.prepare(&format!("PRAGMA table_info({})", table_name))
+        .unwrap()
+        .query_map([], |row| row.get::<_, String>(1))
+        .unwrap()
+        .any(|name| name.unwrap() == column_name)
+}
+
+#[test]
+fn repository_metadata_reports_version_two() {
+    assert_eq!(RepositoryMetadata.version(), 2);
+}
+
+#[test]
+fn repository_metadata_up_adds_metadata_columns_idempotently() {
+    let conn = Connection::open_in_memory().unwrap();
+    InitialSchema.up(&conn).unwrap();
+    RepositoryMetadata.up(&conn).unwrap();
+    RepositoryMetadata.up(&conn).unwrap();
+
+    for column in [
+        "description",
+        "stars",
+        "default_branch",
+        "primary_language",
+        "metadata_fetched_at",
+    ] {
+        assert!(column_exists(&conn, "repositories", column));
+    }
+}
+
+#[test]
+fn typing_baselines_reports_version_three() {
+    assert_eq!(TypingBaselines.version(), 3);
+}
+
+#[test]
+fn typing_baselines_up_creates_table_idempotently() {
+    let conn = Connection::open_in_memory().unwrap();
+    TypingBaselines.up(&conn).unwrap();
+    TypingBaselines.up(&conn).unwrap();
+
+    assert!(table_exists(&conn, "typing_baselines"));
+    assert!(column_exists(&conn, "typing_baselines", "duration_seconds"));
+}
+
+#[test]
+fn achievements_reports_version_four() {
+    assert_eq!(Achievements.version(), 4);
+}
+
+#[test]
+fn achievements_up_creates_table_idempotently() {
+    let conn = Connection::open_in_memory().unwrap();
+    Achievements.up(&conn).unwrap();
+    Achievements.up(&conn).unwrap();
+
+    assert!(table_exists(&conn, "achievements"));
+}
+
+#[test]
+fn challenge_flags_reports_version_five() {
+    assert_eq!(ChallengeFlags.version(), 5);
+}
+
+#[test]
+fn challenge_flags_up_creates_table_idempotently() {
+    let conn = Connection::open_in_memory().unwrap();
+    ChallengeFlags.up(&conn).unwrap();
+    ChallengeFlags.up(&conn).unwrap();
+
+    assert!(table_exists(&conn, "challenge_flags"));
+}
+
+#[test]
+fn session_players_reports_version_six() {
+    assert_eq!(SessionPlayers.version(), 6);
+}
+
+#[test]
+fn session_players_up_adds_player_name_idempotently() {
+    let conn = Connection::open_in_memory().unwrap();
+    InitialSchema.up(&conn).unwrap();
+    SessionPlayers.up(&conn).unwrap();
+    SessionPlayers.up(&conn).unwrap();
+
+    assert!(column_exists(&conn, "sessions", "player_name"));
+}
+
+#[test]
+fn repository_ratings_reports_version_seven() {
+    assert_eq!(RepositoryRatings.version(), 7);
+}
+
+#[test]
+fn repository_ratings_up_creates_table_idempotently() {
+    let conn = Connection::open_in_memory().unwrap();
+    RepositoryRatings.up(&conn).unwrap();
+    RepositoryRatings.up(&conn).unwrap();
+
+    assert!(table_exists(&conn, "repository_ratings"));
+    assert!(index_exists(&conn, "idx_repository_ratings_repository_id"));
+}
+
+#[test]
+fn dismissed_repositories_reports_version_eight() {
+    assert_eq!(DismissedRepositories.version(), 8);
+}
+
+#[test]
+fn dismissed_repositories_up_creates_table_idempotently() {
+    let conn = Connection::open_in_memory().unwrap();
+    DismissedRepositories.up(&conn).unwrap();
+    DismissedRepositories.up(&conn).unwrap();
+
+    assert!(table_exists(&conn, "dismissed_repositories"));
+}
+
+#[test]
+fn difficulty_unlocks_reports_version_nine() {
+    assert_eq!(DifficultyUnlocks.version(), 9);
+}
+
+#[test]
+fn difficulty_unlocks_up_creates_table_idempotently() {
+    let conn = Connection::open_in_memory().unwrap();
+    DifficultyUnlocks.up(&conn).unwrap();
+    DifficultyUnlocks.up(&conn).unwrap();
+
+    assert!(table_exists(&conn, "difficulty_unlocks"));
+}
+
+#[test]
+fn stage_mistake_breakdown_reports_version_ten() {
+    assert_eq!(StageMistakeBreakdown.version(), 10);
+}
+
+#[test]
+fn stage_mistake_breakdown_up_adds_column_idempotently() {
+    let conn = Connection::open_in_memory().unwrap();
+    InitialSchema.up(&conn).unwrap();
+    StageMistakeBreakdown.up(&conn).unwrap();
+    StageMistakeBreakdown.up(&conn).unwrap();
+
+    assert!(column_exists(&conn, "stage_results", "mistake_breakdown"));
+}
+
+#[test]
+fn stage_key_load_reports_version_eleven() {
+    assert_eq!(StageKeyLoad.version(), 11);
+}
+
+#[test]
+fn stage_key_load_up_adds_column_idempotently() {
+    let conn = Connection::open_in_memory().unwrap();
+    InitialSchema.up(&conn).unwrap();
+    StageKeyLoad.up(&conn).unwrap();
+    StageKeyLoad.up(&conn).unwrap();
+
+    assert!(column_exists(&conn, "stage_results", "key_load"));
+}
+
+#[test]
+fn session_unverified_reports_version_thirteen() {
+    assert_eq!(SessionUnverified.version(), 13);
+}
+
+#[test]
+fn session_unverified_up_adds_column_idempotently() {
+    let conn = Connection::open_in_memory().unwrap();
+    InitialSchema.up(&conn).unwrap();
+    SessionUnverified.up(&conn).unwrap();
+    SessionUnverified.up(&conn).unwrap();
+
+    assert!(column_exists(&conn, "sessions", "unverified"));
+}
@@ -1,3 +1,4 @@
 pub mod daos;
 pub mod database_tests;
 pub mod migrations_tests;
+pub mod storage_backend_tests;
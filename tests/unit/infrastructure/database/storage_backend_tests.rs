@@ -0,0 +1,62 @@
+use chrono::{TimeZone, Utc};
+use gittype::domain::models::{BaselineSource, TypingBaseline};
+use gittype::infrastructure::database::storage_backend::{
+    open_default_backend, SqliteBackend, StorageBackend,
+};
+
+fn baseline() -> TypingBaseline {
+    TypingBaseline {
+        source: BaselineSource::Monkeytype,
+        taken_at: Utc.with_ymd_and_hms(2024, 5, 10, 9, 0, 0).unwrap(),
+        wpm: 90.0,
+        accuracy: 95.0,
+        duration_seconds: Some(30.0),
+    }
+}
+
+#[test]
+fn default_backend_is_sqlite() {
+    assert_eq!(open_default_backend().unwrap().name(), "sqlite");
+}
+
+#[test]
+fn daos_of_a_backend_share_its_storage() {
+    let backend = SqliteBackend::in_memory().unwrap();
+
+    backend
+        .baseline_dao()
+        .insert_baselines(&[baseline()])
+        .unwrap();
+
+    let summary = backend.baseline_dao().get_baseline_summary().unwrap();
+    assert_eq!(summary.unwrap().tests, 1);
+}
+
+#[test]
+fn in_memory_backends_are_isolated() {
+    let first = SqliteBackend::in_memory().unwrap();
+    let second = SqliteBackend::in_memory().unwrap();
+
+    first
+        .baseline_dao()
+        .insert_baselines(&[baseline()])
+        .unwrap();
+
+    assert_eq!(second.baseline_dao().get_baseline_summary().unwrap(), None);
+}
+
+#[test]
+fn in_memory_backend_has_the_full_schema() {
+    let backend = SqliteBackend::in_memory().unwrap();
+
+    assert!(backend
+        .session_dao()
+        .get_daily_activity()
+        .unwrap()
+        .is_empty());
+    assert!(backend
+        .repository_dao()
+        .get_all_repositories()
+        .unwrap()
+        .is_empty());
+}
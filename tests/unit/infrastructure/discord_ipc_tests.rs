@@ -0,0 +1,49 @@
+use gittype::infrastructure::discord_ipc::{
+    decode_header, encode_frame, set_activity_payload, DiscordIpcClient, OP_FRAME, OP_HANDSHAKE,
+};
+use serde_json::json;
+
+#[test]
+fn encode_frame_prefixes_opcode_and_length() {
+    let payload = json!({ "v": 1 });
+    let frame = encode_frame(OP_HANDSHAKE, &payload);
+    let body = payload.to_string();
+
+    let header: [u8; 8] = frame[..8].try_into().unwrap();
+    assert_eq!(decode_header(header), (OP_HANDSHAKE, body.len() as u32));
+    assert_eq!(&frame[8..], body.as_bytes());
+}
+
+#[test]
+fn decode_header_reads_little_endian_values() {
+    assert_eq!(decode_header([1, 0, 0, 0, 0, 1, 0, 0]), (OP_FRAME, 256));
+}
+
+#[test]
+fn set_activity_payload_wraps_activity_in_command() {
+    let activity = json!({ "details": "Typing rust" });
+    let payload = set_activity_payload(42, Some(&activity), 7);
+
+    assert_eq!(payload["cmd"], "SET_ACTIVITY");
+    assert_eq!(payload["args"]["pid"], 42);
+    assert_eq!(payload["args"]["activity"], activity);
+    assert_eq!(payload["nonce"], "7");
+}
+
+#[test]
+fn set_activity_payload_uses_null_to_clear() {
+    let payload = set_activity_payload(42, None, 1);
+
+    assert!(payload["args"]["activity"].is_null());
+}
+
+#[test]
+fn mock_client_records_activities() {
+    let mut client = DiscordIpcClient::connect("123").unwrap();
+    let activity = json!({ "state": "Stage 1/3" });
+
+    client.set_activity(Some(&activity)).unwrap();
+    client.set_activity(None).unwrap();
+
+    assert_eq!(client.activities(), &[Some(activity), None]);
+}
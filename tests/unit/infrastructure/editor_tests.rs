@@ -0,0 +1,65 @@
+use gittype::infrastructure::editor::{editor_command, open_in_editor};
+use std::path::Path;
+
+fn command(editor: &str) -> Option<(String, Vec<String>)> {
+    editor_command(editor, Path::new("/repo/src/lib.rs"), 42)
+}
+
+#[test]
+fn terminal_editors_get_plus_line_before_the_file() {
+    assert_eq!(
+        command("nvim"),
+        Some((
+            "nvim".to_string(),
+            vec!["+42".to_string(), "/repo/src/lib.rs".to_string()]
+        ))
+    );
+}
+
+#[test]
+fn editor_arguments_are_kept_before_the_location() {
+    assert_eq!(
+        command("emacs -nw"),
+        Some((
+            "emacs".to_string(),
+            vec![
+                "-nw".to_string(),
+                "+42".to_string(),
+                "/repo/src/lib.rs".to_string()
+            ]
+        ))
+    );
+}
+
+#[test]
+fn vscode_family_uses_goto() {
+    assert_eq!(
+        command("/usr/local/bin/code --wait"),
+        Some((
+            "/usr/local/bin/code".to_string(),
+            vec![
+                "--wait".to_string(),
+                "--goto".to_string(),
+                "/repo/src/lib.rs:42".to_string()
+            ]
+        ))
+    );
+}
+
+#[test]
+fn path_colon_line_editors_get_a_single_argument() {
+    assert_eq!(
+        command("hx"),
+        Some(("hx".to_string(), vec!["/repo/src/lib.rs:42".to_string()]))
+    );
+}
+
+#[test]
+fn blank_editor_has_no_command() {
+    assert_eq!(command("   "), None);
+}
+
+#[test]
+fn mock_open_in_editor_succeeds_without_spawning() {
+    assert!(open_in_editor(Path::new("/repo/src/lib.rs"), 42).is_ok());
+}
@@ -221,6 +221,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_detached_head_has_commit_but_no_branch() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = Repository::init(temp_dir.path()).unwrap();
+        repo.remote("origin", "https://github.com/octocat/hello-world.git")
+            .unwrap();
+        let commit_hash = commit_file(&repo, "README.md", "hello");
+        repo.set_head_detached(git2::Oid::from_str(&commit_hash).unwrap())
+            .unwrap();
+
+        let client = LocalGitRepositoryClient::new();
+        let extracted = client.extract_git_repository(temp_dir.path()).unwrap();
+        let created = client.create_from_local_path(temp_dir.path()).unwrap();
+
+        for git_repository in [extracted, created] {
+            assert_eq!(git_repository.branch, None);
+            assert_eq!(git_repository.commit_hash, Some(commit_hash.clone()));
+        }
+    }
+
     #[test]
     fn test_create_from_local_path_uses_origin_url_when_available() {
         let temp_dir = tempfile::TempDir::new().unwrap();
@@ -295,4 +315,215 @@ mod tests {
         assert_eq!(git_repository.user_name, "octocat");
         assert_eq!(git_repository.repository_name, "hello-world");
     }
+
+    /// A bare repository with `origin` and one commit on `main`, and the path of
+    /// a linked worktree checking out `main`
+    fn bare_repository_with_worktree(dir: &std::path::Path) -> std::path::PathBuf {
+        let source = Repository::init(dir.join("source")).unwrap();
+        commit_file(&source, "README.md", "hello");
+        let source_branch = source.head().unwrap().shorthand().unwrap().to_string();
+
+        let bare_path = dir.join("project.git");
+        let bare = Repository::init_bare(&bare_path).unwrap();
+        bare.remote("origin", "https://github.com/octocat/hello-world.git")
+            .unwrap();
+        let mut remote = bare
+            .remote_anonymous(source.path().to_str().unwrap())
+            .unwrap();
+        remote
+            .fetch(
+                &[format!("refs/heads/{0}:refs/heads/main", source_branch)],
+                None,
+                None,
+            )
+            .unwrap();
+        bare.set_head("refs/heads/main").unwrap();
+
+        let branch = bare.find_reference("refs/heads/main").unwrap();
+        let work_tree = dir.join("main");
+        bare.worktree(
+            "main",
+            &work_tree,
+            Some(git2::WorktreeAddOptions::new().reference(Some(&branch))),
+        )
+        .unwrap();
+        work_tree
+    }
+
+    #[test]
+    fn test_extract_git_repository_reads_linked_worktree() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let temp_path = temp_dir.path().canonicalize().unwrap();
+        let work_tree = bare_repository_with_worktree(&temp_path);
+
+        let client = LocalGitRepositoryClient::new();
+        let nested = work_tree.join("src");
+        std::fs::create_dir_all(&nested).unwrap();
+        assert_eq!(client.get_repository_root(&nested), Some(work_tree.clone()));
+
+        let git_repository = client.extract_git_repository(&work_tree).unwrap();
+        assert_eq!(git_repository.user_name, "octocat");
+        assert_eq!(git_repository.branch.as_deref(), Some("main"));
+        assert!(git_repository.commit_hash.is_some());
+        assert_eq!(git_repository.root_path, Some(work_tree));
+    }
+
+    #[test]
+    fn test_bare_repository_resolves_to_its_worktree() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let temp_path = temp_dir.path().canonicalize().unwrap();
+        let work_tree = bare_repository_with_worktree(&temp_path);
+        let bare_path = temp_path.join("project.git");
+
+        let client = LocalGitRepositoryClient::new();
+        assert_eq!(client.resolve_work_tree(&bare_path).unwrap(), work_tree);
+        assert_eq!(
+            client.get_repository_root(&bare_path.join("refs")),
+            Some(work_tree.clone())
+        );
+
+        let git_repository = client.create_from_local_path(&bare_path).unwrap();
+        assert_eq!(git_repository.repository_name, "hello-world");
+        assert_eq!(git_repository.branch.as_deref(), Some("main"));
+        assert_eq!(git_repository.root_path, Some(work_tree.clone()));
+        assert_eq!(
+            client.extract_git_repository(&bare_path).unwrap().root_path,
+            Some(work_tree)
+        );
+    }
+
+    #[test]
+    fn test_bare_repository_without_worktrees_is_an_error() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        Repository::init_bare(temp_dir.path()).unwrap();
+
+        let client = LocalGitRepositoryClient::new();
+
+        assert!(client.get_repository_root(temp_dir.path()).is_none());
+        assert!(matches!(
+            client.resolve_work_tree(temp_dir.path()),
+            Err(GitTypeError::ExtractionFailed(msg)) if msg.contains("bare repository without worktrees")
+        ));
+    }
+
+    #[test]
+    fn test_resolve_work_tree_keeps_non_bare_paths() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        Repository::init(temp_dir.path()).unwrap();
+
+        let client = LocalGitRepositoryClient::new();
+
+        assert_eq!(
+            client.resolve_work_tree(temp_dir.path()).unwrap(),
+            temp_dir.path().to_path_buf()
+        );
+    }
+
+    #[test]
+    fn test_get_commit_changed_files_lists_files_touched_by_head() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = Repository::init(temp_dir.path()).unwrap();
+        commit_file(&repo, "README.md", "hello");
+
+        std::fs::write(temp_dir.path().join("main.rs"), "fn main() {}").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(std::path::Path::new("main.rs")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let parent = repo.head().unwrap().peel_to_commit().unwrap();
+        let signature = Signature::now("Test User", "test@example.com").unwrap();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            "Add main",
+            &tree,
+            &[&parent],
+        )
+        .unwrap();
+
+        let client = LocalGitRepositoryClient::new();
+
+        assert_eq!(
+            client
+                .get_commit_changed_files(temp_dir.path(), "HEAD")
+                .unwrap(),
+            vec![std::path::PathBuf::from("main.rs")]
+        );
+        assert_eq!(
+            client
+                .get_commit_changed_files(temp_dir.path(), "HEAD~1")
+                .unwrap(),
+            vec![std::path::PathBuf::from("README.md")]
+        );
+    }
+
+    #[test]
+    fn test_get_commit_changed_files_returns_error_for_unknown_revision() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = Repository::init(temp_dir.path()).unwrap();
+        commit_file(&repo, "README.md", "hello");
+
+        let client = LocalGitRepositoryClient::new();
+        let result = client.get_commit_changed_files(temp_dir.path(), "does-not-exist");
+
+        assert!(matches!(
+            result,
+            Err(GitTypeError::ExtractionFailed(msg))
+            if msg.starts_with("Failed to resolve commit 'does-not-exist'")
+        ));
+    }
+
+    #[test]
+    fn test_hooks_dir_defaults_to_the_git_hooks_directory() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = Repository::init(temp_dir.path()).unwrap();
+        let nested = temp_dir.path().join("src");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        let hooks_dir = LocalGitRepositoryClient::new().hooks_dir(&nested).unwrap();
+
+        assert_eq!(hooks_dir, repo.path().join("hooks"));
+    }
+
+    #[test]
+    fn test_hooks_dir_resolves_relative_hooks_path_from_the_work_tree() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repo = Repository::init(temp_dir.path()).unwrap();
+        repo.config()
+            .unwrap()
+            .set_str("core.hooksPath", ".githooks")
+            .unwrap();
+
+        let hooks_dir = LocalGitRepositoryClient::new()
+            .hooks_dir(temp_dir.path())
+            .unwrap();
+
+        assert_eq!(hooks_dir, repo.workdir().unwrap().join(".githooks"));
+    }
+
+    #[test]
+    fn test_hooks_dir_resolves_relative_hooks_path_from_a_bare_git_directory() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let bare = Repository::init_bare(temp_dir.path().join("project.git")).unwrap();
+        bare.config()
+            .unwrap()
+            .set_str("core.hooksPath", "custom-hooks")
+            .unwrap();
+
+        let hooks_dir = LocalGitRepositoryClient::new()
+            .hooks_dir(bare.path())
+            .unwrap();
+
+        assert_eq!(hooks_dir, bare.commondir().join("custom-hooks"));
+    }
+
+    #[test]
+    fn test_hooks_dir_outside_repository_returns_repository_not_found() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+
+        let result = LocalGitRepositoryClient::new().hooks_dir(temp_dir.path());
+
+        assert!(matches!(result, Err(GitTypeError::RepositoryNotFound(_))));
+    }
 }
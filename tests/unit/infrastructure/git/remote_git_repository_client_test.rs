@@ -79,7 +79,7 @@ mod tests {
     #[test]
     fn test_clone_repository_returns_error_for_invalid_spec() {
         let client = RemoteGitRepositoryClient::new();
-        let result = client.clone_repository("invalid repository spec", |_, _| {});
+        let result = client.clone_repository("invalid repository spec", |_, _, _| true);
 
         assert!(result.is_err());
     }
@@ -103,7 +103,7 @@ mod tests {
         let result = client
             .clone_repository(
                 &format!("https://coverage.invalid/gittype/{}", repo_info.name),
-                |_, _| panic!("cached clone should not report progress"),
+                |_, _, _| panic!("cached clone should not report progress"),
             )
             .unwrap();
 
@@ -128,7 +128,7 @@ mod tests {
 
         let result = client.clone_repository(
             &format!("https://127.0.0.1:1/gittype/{}", repo_info.name),
-            |_, _| {},
+            |_, _, _| true,
         );
 
         assert!(result.is_err());
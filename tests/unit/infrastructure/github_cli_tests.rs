@@ -0,0 +1,6 @@
+use gittype::infrastructure::github_cli::auth_token;
+
+#[test]
+fn auth_token_is_none_under_test_mocks() {
+    assert_eq!(auth_token(), None);
+}
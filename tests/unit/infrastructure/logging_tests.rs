@@ -1,6 +1,6 @@
 use gittype::infrastructure::logging::{
-    get_current_log_file_path, get_environment_context, get_log_directory, log_error_to_file,
-    log_panic_to_file, setup_console_logging,
+    find_latest_log_file, get_current_log_file_path, get_environment_context, get_log_directory,
+    log_error_to_file, log_panic_to_file, read_log_tail, setup_console_logging, trim_old_logs,
 };
 use gittype::GitTypeError;
 use std::path::{Path, PathBuf};
@@ -256,3 +256,67 @@ fn log_panic_to_file_handles_string_payload() {
 fn log_panic_to_file_handles_non_string_payload() {
     run_with_panic_hook(|| std::panic::panic_any(42_u32));
 }
+
+#[test]
+fn test_find_latest_log_file_picks_newest_gittype_log() {
+    let temp_dir = TempDir::new().unwrap();
+    for name in [
+        "gittype_20260501_090000.log",
+        "gittype_20260502_080000.log",
+        "panic_20260503_000000.log",
+        "notes.txt",
+    ] {
+        std::fs::write(temp_dir.path().join(name), "").unwrap();
+    }
+
+    let latest = find_latest_log_file(temp_dir.path()).unwrap();
+
+    assert!(latest.ends_with("gittype_20260502_080000.log"));
+}
+
+#[test]
+fn test_find_latest_log_file_without_logs() {
+    let temp_dir = TempDir::new().unwrap();
+
+    assert_eq!(find_latest_log_file(temp_dir.path()), None);
+    assert_eq!(find_latest_log_file(&temp_dir.path().join("missing")), None);
+}
+
+#[test]
+fn test_trim_old_logs_removes_logs_before_cutoff_but_keeps_newest() {
+    let temp_dir = TempDir::new().unwrap();
+    for name in [
+        "gittype_20260101_090000.log",
+        "gittype_20260102_090000.log",
+        "panic_20260101_000000.log",
+        "gittype_20260601_090000.log",
+        "notes.txt",
+    ] {
+        std::fs::write(temp_dir.path().join(name), "0123456789").unwrap();
+    }
+    let cutoff = chrono::NaiveDate::from_ymd_opt(2026, 3, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+
+    assert_eq!(trim_old_logs(temp_dir.path(), cutoff).unwrap(), (3, 30));
+    assert!(temp_dir.path().join("gittype_20260601_090000.log").exists());
+    assert!(temp_dir.path().join("notes.txt").exists());
+
+    // The newest log stays even when it is past the cutoff
+    let later = chrono::NaiveDate::from_ymd_opt(2027, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    assert_eq!(trim_old_logs(temp_dir.path(), later).unwrap(), (0, 0));
+}
+
+#[test]
+fn test_read_log_tail_returns_last_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("gittype_20260501_090000.log");
+    std::fs::write(&path, "one\ntwo\nthree\n").unwrap();
+
+    assert_eq!(read_log_tail(&path, 2).unwrap(), vec!["two", "three"]);
+    assert_eq!(read_log_tail(&path, 10).unwrap().len(), 3);
+}
@@ -1,7 +1,12 @@
 pub mod console_tests;
 pub mod database;
+pub mod discord_ipc_tests;
+pub mod editor_tests;
 pub mod git;
+pub mod github_cli_tests;
 pub mod logging_tests;
+pub mod network_tests;
 pub mod oss_insight_client_tests;
+pub mod reminder_scheduler_tests;
 pub mod storage;
 pub mod terminal_tests;
@@ -0,0 +1,79 @@
+use gittype::domain::models::config::NetworkConfig;
+use gittype::infrastructure::http::Network;
+use std::fs;
+use tempfile::TempDir;
+
+fn with_ca_bundle(path: std::path::PathBuf) -> NetworkConfig {
+    NetworkConfig {
+        ca_bundle: Some(path),
+        ..NetworkConfig::DEFAULT
+    }
+}
+
+#[test]
+fn ca_certificates_are_empty_without_a_bundle() {
+    assert!(Network::ca_certificates(&NetworkConfig::DEFAULT)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn ca_certificates_report_a_missing_bundle() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("missing.pem");
+
+    let error = Network::ca_certificates(&with_ca_bundle(path.clone()))
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("Failed to read CA bundle"));
+    assert!(error.contains(&path.display().to_string()));
+}
+
+#[test]
+fn ca_certificates_reject_a_bundle_without_certificates() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("empty.pem");
+    fs::write(&path, "not a certificate\n").unwrap();
+
+    let error = Network::ca_certificates(&with_ca_bundle(path))
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("contains no certificates"));
+}
+
+#[test]
+fn client_builder_builds_with_default_settings() {
+    assert!(Network::client_builder().unwrap().build().is_ok());
+}
+
+#[test]
+fn bypasses_proxy_matches_listed_hosts_and_their_subdomains() {
+    let no_proxy = "localhost, .corp.example,GitHub.com";
+
+    assert!(Network::bypasses_proxy(
+        no_proxy,
+        "https://github.com/rust-lang/rust.git"
+    ));
+    assert!(Network::bypasses_proxy(
+        no_proxy,
+        "https://git.corp.example/team/app"
+    ));
+    assert!(Network::bypasses_proxy(
+        no_proxy,
+        "http://localhost:8080/repo"
+    ));
+    assert!(!Network::bypasses_proxy(
+        no_proxy,
+        "https://notgithub.com/a/b"
+    ));
+    assert!(!Network::bypasses_proxy(no_proxy, "https://gitlab.com/a/b"));
+    assert!(!Network::bypasses_proxy(no_proxy, "not a url"));
+}
+
+#[test]
+fn bypasses_proxy_wildcard_matches_every_host() {
+    assert!(Network::bypasses_proxy("*", "https://gitlab.com/a/b"));
+    assert!(!Network::bypasses_proxy("", "https://gitlab.com/a/b"));
+}
@@ -0,0 +1,130 @@
+use gittype::domain::models::Reminder;
+use gittype::infrastructure::reminder_scheduler::{
+    crontab_line, crontab_with, crontab_without, install_reminder, launch_agent_path,
+    launchd_label, launchd_plist, schtasks_create_args, schtasks_delete_args, uninstall_reminder,
+    Scheduler,
+};
+use std::path::Path;
+
+const EXE: &str = "/home/me/.cargo/bin/gittype";
+
+fn at(time: &str) -> Reminder {
+    Reminder::parse(time).unwrap()
+}
+
+#[test]
+fn crontab_line_runs_notify_daily_at_the_time() {
+    let line = crontab_line(&at("09:05"), Path::new(EXE));
+
+    assert!(line.starts_with("5 9 * * * "), "{}", line);
+    assert!(line.contains("'/home/me/.cargo/bin/gittype' remind --notify"));
+    assert!(line.ends_with("# gittype reminder 09:05"));
+}
+
+#[test]
+fn crontab_line_quotes_paths_with_quotes() {
+    let line = crontab_line(&at("09:00"), Path::new("/opt/it's/gittype"));
+    assert!(line.contains(r"'/opt/it'\''s/gittype'"), "{}", line);
+}
+
+#[test]
+fn crontab_with_keeps_other_entries_and_replaces_the_same_time() {
+    let existing =
+        "0 3 * * * backup.sh\n0 9 * * * old-gittype remind --notify # gittype reminder 09:00\n";
+
+    let crontab = crontab_with(existing, &at("09:00"), Path::new(EXE));
+
+    let lines: Vec<&str> = crontab.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], "0 3 * * * backup.sh");
+    assert!(lines[1].contains(EXE));
+    assert!(crontab.ends_with('\n'));
+}
+
+#[test]
+fn crontab_without_only_removes_the_given_time() {
+    let existing = format!(
+        "0 3 * * * backup.sh\n{}\n{}\n",
+        crontab_line(&at("09:00"), Path::new(EXE)),
+        crontab_line(&at("18:30"), Path::new(EXE))
+    );
+
+    let crontab = crontab_without(&existing, &at("09:00"));
+
+    assert_eq!(
+        crontab,
+        format!(
+            "0 3 * * * backup.sh\n{}\n",
+            crontab_line(&at("18:30"), Path::new(EXE))
+        )
+    );
+    assert_eq!(crontab_without(&crontab, &at("09:00")), crontab);
+}
+
+#[test]
+fn launchd_plist_schedules_notify_at_the_time() {
+    let plist = launchd_plist(&at("21:30"), Path::new("/Apps/R&D/gittype"));
+
+    assert!(plist.contains("<string>com.gittype.reminder.2130</string>"));
+    assert!(plist.contains("<string>/Apps/R&amp;D/gittype</string>"));
+    assert!(plist.contains("<string>--notify</string>"));
+    assert!(plist.contains("<key>Hour</key>\n        <integer>21</integer>"));
+    assert!(plist.contains("<key>Minute</key>\n        <integer>30</integer>"));
+}
+
+#[test]
+fn launch_agent_is_written_to_the_user_launch_agents() {
+    assert_eq!(launchd_label(&at("07:00")), "com.gittype.reminder.0700");
+    assert_eq!(
+        launch_agent_path(Path::new("/Users/me"), &at("07:00")),
+        Path::new("/Users/me/Library/LaunchAgents/com.gittype.reminder.0700.plist")
+    );
+}
+
+#[test]
+fn schtasks_arguments_create_and_delete_a_daily_task() {
+    let create = schtasks_create_args(&at("09:00"), Path::new(r"C:\tools\gittype.exe"));
+    assert_eq!(
+        create,
+        vec![
+            "/Create",
+            "/F",
+            "/SC",
+            "DAILY",
+            "/TN",
+            "gittype-reminder-0900",
+            "/TR",
+            r#""C:\tools\gittype.exe" remind --notify"#,
+            "/ST",
+            "09:00"
+        ]
+    );
+    assert_eq!(
+        schtasks_delete_args(&at("09:00")),
+        vec!["/Delete", "/F", "/TN", "gittype-reminder-0900"]
+    );
+}
+
+#[test]
+fn notification_commands_escape_the_text() {
+    let (program, args) = Scheduler::Cron.notification_command("gittype", "Practice");
+    assert_eq!(program, "notify-send");
+    assert_eq!(args, vec!["gittype", "Practice"]);
+
+    let (program, args) = Scheduler::Launchd.notification_command("gittype", r#"Say "hi""#);
+    assert_eq!(program, "osascript");
+    assert_eq!(
+        args[1],
+        r#"display notification "Say \"hi\"" with title "gittype""#
+    );
+
+    let (program, args) = Scheduler::TaskScheduler.notification_command("gittype", "It's time");
+    assert_eq!(program, "powershell");
+    assert!(args[2].contains("ShowBalloonTip(10000, 'gittype', 'It''s time', 'Info')"));
+}
+
+#[test]
+fn install_and_uninstall_are_mocked_in_tests() {
+    assert!(install_reminder(&at("09:00")).is_ok());
+    assert!(uninstall_reminder(&at("09:00")).is_ok());
+}
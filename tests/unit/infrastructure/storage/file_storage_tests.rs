@@ -138,7 +138,7 @@ mod mock_tests {
         storage.add_file("/test/file1.txt");
         storage.add_file("/test/file2.txt");
 
-        let result = storage.walk_directory(Path::new("/test"));
+        let result = storage.walk_directory(Path::new("/test"), false);
 
         assert!(result.is_ok());
         let files = result.unwrap();
@@ -150,7 +150,7 @@ mod mock_tests {
         use std::path::Path;
 
         let storage = FileStorage::new();
-        let result = storage.walk_directory(Path::new("/nonexistent/path"));
+        let result = storage.walk_directory(Path::new("/nonexistent/path"), false);
 
         assert!(result.is_err());
     }
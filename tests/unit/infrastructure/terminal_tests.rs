@@ -1,4 +1,9 @@
-use gittype::infrastructure::terminal::{TerminalComponent, TerminalInterface};
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::CompletionFeedback;
+use gittype::infrastructure::terminal::{
+    TerminalBackground, TerminalBell, TerminalComponent, TerminalInterface, WindowTitle,
+};
+use std::time::Instant;
 
 #[test]
 fn terminal_component_get_creates_terminal_backend() {
@@ -23,3 +28,128 @@ fn terminal_component_get_returns_terminal() {
 
     assert!(terminal.size().is_ok());
 }
+
+#[test]
+fn window_title_names_repository_and_stage() {
+    assert_eq!(
+        WindowTitle::for_stage(Some("tokio"), 2, 5),
+        "gittype — tokio — stage 2/5"
+    );
+    assert_eq!(WindowTitle::for_stage(None, 1, 3), "gittype — stage 1/3");
+}
+
+#[test]
+fn window_title_saves_once_skips_unchanged_titles_and_restores() {
+    let mut out = Vec::new();
+
+    WindowTitle::set_on(&mut out, "gittype — tokio — stage 1/5").unwrap();
+    WindowTitle::set_on(&mut out, "gittype — tokio — stage 1/5").unwrap();
+    WindowTitle::set_on(&mut out, "gittype — tokio — stage 2/5").unwrap();
+    WindowTitle::restore_on(&mut out).unwrap();
+    WindowTitle::restore_on(&mut out).unwrap();
+
+    let written = String::from_utf8(out).unwrap();
+    assert_eq!(written.matches("\x1b[22;0t").count(), 1);
+    assert_eq!(written.matches("stage 1/5").count(), 1);
+    assert_eq!(written.matches("stage 2/5").count(), 1);
+    assert_eq!(written.matches("\x1b[23;0t").count(), 1);
+    assert!(written.starts_with("\x1b[22;0t"));
+    assert!(written.ends_with("\x1b[23;0t"));
+}
+
+fn ring_all(feedback: CompletionFeedback, repeats: usize) -> Vec<u8> {
+    let mut bell = TerminalBell::new();
+    let mut out = Vec::new();
+    bell.ring(feedback, repeats, Instant::now());
+    bell.finish(&mut out).unwrap();
+    out
+}
+
+#[test]
+fn terminal_bell_rings_once_per_stage_and_twice_for_a_personal_best() {
+    assert_eq!(
+        ring_all(CompletionFeedback::Bell, CompletionFeedback::repeats(false)),
+        b"\x07"
+    );
+    assert_eq!(
+        ring_all(CompletionFeedback::Bell, CompletionFeedback::repeats(true)),
+        b"\x07\x07"
+    );
+}
+
+#[test]
+fn terminal_bell_flashes_in_reverse_video_and_back() {
+    assert_eq!(
+        String::from_utf8(ring_all(CompletionFeedback::Flash, 1)).unwrap(),
+        "\x1b[?5h\x1b[?5l"
+    );
+}
+
+#[test]
+fn terminal_bell_writes_nothing_when_off() {
+    assert!(ring_all(CompletionFeedback::None, 2).is_empty());
+}
+
+#[test]
+fn terminal_bell_turns_a_flash_off_on_a_later_step() {
+    let mut bell = TerminalBell::new();
+    let start = Instant::now();
+    bell.ring(CompletionFeedback::Flash, 1, start);
+
+    let mut out = Vec::new();
+    bell.write_due(&mut out, start).unwrap();
+    assert_eq!(out, b"\x1b[?5h");
+    assert_eq!(bell.next_due(), Some(start + TerminalBell::FLASH_DURATION));
+
+    bell.write_due(&mut out, start + TerminalBell::FLASH_DURATION)
+        .unwrap();
+    assert_eq!(out, b"\x1b[?5h\x1b[?5l");
+    assert_eq!(bell.next_due(), None);
+}
+
+#[test]
+fn terminal_background_reads_the_osc_11_reply() {
+    assert_eq!(
+        TerminalBackground::from_osc_reply(b"\x1b]11;rgb:ffff/ffff/dddd\x07\x1b[12;1R"),
+        Some(ColorMode::Light)
+    );
+    assert_eq!(
+        TerminalBackground::from_osc_reply(b"\x1b]11;rgb:1e1e/1e1e/2e2e\x1b\\"),
+        Some(ColorMode::Dark)
+    );
+    assert_eq!(
+        TerminalBackground::from_osc_reply(b"\x1b]11;rgb:f/f/f\x07"),
+        Some(ColorMode::Light)
+    );
+}
+
+#[test]
+fn terminal_background_is_unknown_without_an_osc_11_reply() {
+    assert_eq!(TerminalBackground::from_osc_reply(b"\x1b[12;1R"), None);
+    assert_eq!(
+        TerminalBackground::from_osc_reply(b"\x1b]11;rgb:zz/00/00\x07"),
+        None
+    );
+}
+
+#[test]
+fn terminal_background_falls_back_to_colorfgbg() {
+    assert_eq!(
+        TerminalBackground::from_colorfgbg("15;0"),
+        Some(ColorMode::Dark)
+    );
+    assert_eq!(
+        TerminalBackground::from_colorfgbg("0;default;15"),
+        Some(ColorMode::Light)
+    );
+    assert_eq!(
+        TerminalBackground::from_colorfgbg("0;7"),
+        Some(ColorMode::Light)
+    );
+    assert_eq!(TerminalBackground::from_colorfgbg("default"), None);
+}
+
+#[test]
+fn terminal_background_is_not_queried_in_tests() {
+    assert_eq!(TerminalBackground::detect(), None);
+}
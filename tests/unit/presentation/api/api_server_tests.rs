@@ -0,0 +1,236 @@
+use gittype::domain::models::{Challenge, GitRepository, SessionResult};
+use gittype::domain::repositories::session_repository::SessionRepositoryTrait;
+use gittype::domain::services::analytics_service::AnalyticsServiceInterface;
+use gittype::domain::services::scoring::{StageInput, StageTracker};
+use gittype::domain::services::session_service::SessionServiceInterface;
+use gittype::presentation::api::{ApiServer, HttpRequest};
+use gittype::presentation::cli::commands::serve::{bind_api_listener, create_api_server};
+use gittype::presentation::di::AppModule;
+use shaku::HasComponent;
+use std::io::{Cursor, Read, Write};
+use std::sync::Arc;
+
+fn server_with_session() -> (ApiServer, i64) {
+    let container = AppModule::builder().build();
+    let session_repository: Arc<dyn SessionRepositoryTrait> = container.resolve();
+    let session_service: Arc<dyn SessionServiceInterface> = container.resolve();
+    let analytics_service: Arc<dyn AnalyticsServiceInterface> = container.resolve();
+
+    let mut session_result = SessionResult::new();
+    session_result.session_score = 150.0;
+    session_result.overall_wpm = 60.0;
+    let git_repo = GitRepository {
+        user_name: "apiuser".to_string(),
+        repository_name: "apirepo".to_string(),
+        remote_url: "https://github.com/apiuser/apirepo".to_string(),
+        branch: Some("main".to_string()),
+        commit_hash: Some("api123".to_string()),
+        is_dirty: false,
+        root_path: None,
+        license: None,
+    };
+    let mut tracker = StageTracker::new("fn main() {}".to_string());
+    tracker.record(StageInput::Start);
+    tracker.record(StageInput::Finish);
+    let challenge = Challenge::new("api-test".to_string(), "fn main() {}".to_string())
+        .with_language("rust".to_string());
+
+    let session_id = session_repository
+        .record_session(
+            &session_result,
+            Some(&git_repo),
+            "normal",
+            None,
+            &[("Stage 1".to_string(), tracker)],
+            &[challenge],
+        )
+        .unwrap();
+
+    (
+        ApiServer::new(session_service, session_repository, analytics_service),
+        session_id,
+    )
+}
+
+fn get(server: &ApiServer, target: &str) -> (u16, serde_json::Value) {
+    let request = HttpRequest::parse(&format!("GET {} HTTP/1.1", target)).unwrap();
+    let response = server.handle(&request);
+    (response.status, response.body)
+}
+
+#[test]
+fn index_lists_endpoints() {
+    let (server, _) = server_with_session();
+
+    let (status, body) = get(&server, "/");
+
+    assert_eq!(status, 200);
+    assert_eq!(body["endpoints"][0], "/sessions");
+}
+
+#[test]
+fn sessions_returns_session_with_repository_and_result() {
+    let (server, session_id) = server_with_session();
+
+    let (status, body) = get(&server, "/sessions?days=7&limit=10");
+
+    assert_eq!(status, 200);
+    let sessions = body.as_array().unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0]["session"]["id"], session_id);
+    assert_eq!(sessions[0]["repository"]["repository_name"], "apirepo");
+    assert_eq!(sessions[0]["session_result"]["score"], 150.0);
+}
+
+#[test]
+fn sessions_respects_limit() {
+    let (server, _) = server_with_session();
+
+    let (_, body) = get(&server, "/sessions?limit=0");
+
+    assert!(body.as_array().unwrap().is_empty());
+}
+
+#[test]
+fn session_detail_includes_stages() {
+    let (server, session_id) = server_with_session();
+
+    let (status, body) = get(&server, &format!("/sessions/{}", session_id));
+
+    assert_eq!(status, 200);
+    assert_eq!(body["session"]["id"], session_id);
+    assert_eq!(body["stages"].as_array().unwrap().len(), 1);
+    assert_eq!(body["stages"][0]["language"], "rust");
+}
+
+#[test]
+fn unknown_session_is_not_found() {
+    let (server, _) = server_with_session();
+
+    assert_eq!(get(&server, "/sessions/999999").0, 404);
+    assert_eq!(get(&server, "/sessions/abc").0, 404);
+}
+
+#[test]
+fn stats_summarizes_sessions() {
+    let (server, _) = server_with_session();
+
+    let (status, body) = get(&server, "/stats");
+
+    assert_eq!(status, 200);
+    assert_eq!(body["total_sessions"], 1);
+    assert_eq!(body["best_score"], 150.0);
+    assert!(body["last_session_at"].is_string());
+}
+
+#[test]
+fn analytics_returns_analytics_data() {
+    let (server, _) = server_with_session();
+
+    let (status, body) = get(&server, "/analytics");
+
+    assert_eq!(status, 200);
+    assert_eq!(body["total_sessions"], 1);
+    assert!(body["language_stats"].is_object());
+}
+
+#[test]
+fn invalid_query_is_bad_request() {
+    let (server, _) = server_with_session();
+
+    assert_eq!(get(&server, "/sessions?sort=name").0, 400);
+    assert_eq!(get(&server, "/sessions?order=up").0, 400);
+    assert_eq!(get(&server, "/stats?days=week").0, 400);
+}
+
+#[test]
+fn unknown_path_is_not_found() {
+    let (server, _) = server_with_session();
+
+    assert_eq!(get(&server, "/admin").0, 404);
+}
+
+#[test]
+fn non_get_methods_are_rejected() {
+    let (server, _) = server_with_session();
+    let request = HttpRequest::parse("DELETE /sessions HTTP/1.1").unwrap();
+
+    assert_eq!(server.handle(&request).status, 405);
+}
+
+struct Duplex {
+    input: Cursor<Vec<u8>>,
+    output: Vec<u8>,
+}
+
+impl Read for Duplex {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.input.read(buf)
+    }
+}
+
+impl Write for Duplex {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.output.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn handle_connection_writes_http_response() {
+    let (server, _) = server_with_session();
+    let mut stream = Duplex {
+        input: Cursor::new(b"GET /stats HTTP/1.1\r\nHost: localhost\r\n\r\n".to_vec()),
+        output: Vec::new(),
+    };
+
+    server.handle_connection(&mut stream).unwrap();
+
+    let text = String::from_utf8(stream.output).unwrap();
+    assert!(text.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(text.contains("\"total_sessions\":1"));
+}
+
+#[test]
+fn handle_connection_answers_garbage_with_bad_request() {
+    let (server, _) = server_with_session();
+    let mut stream = Duplex {
+        input: Cursor::new(b"hello\r\n\r\n".to_vec()),
+        output: Vec::new(),
+    };
+
+    server.handle_connection(&mut stream).unwrap();
+
+    assert!(String::from_utf8(stream.output)
+        .unwrap()
+        .starts_with("HTTP/1.1 400 Bad Request\r\n"));
+}
+
+#[test]
+fn api_server_serves_over_tcp() {
+    let listener = bind_api_listener("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let server = create_api_server().unwrap();
+    std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        server.handle_connection(stream).unwrap();
+    });
+
+    let mut client = std::net::TcpStream::connect(address).unwrap();
+    client
+        .write_all(b"GET /sessions HTTP/1.1\r\nHost: localhost\r\n\r\n")
+        .unwrap();
+    let mut response = String::new();
+    client.read_to_string(&mut response).unwrap();
+
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.ends_with("[]"));
+}
+
+#[test]
+fn bind_api_listener_reports_invalid_address() {
+    assert!(bind_api_listener("not-an-address").is_err());
+}
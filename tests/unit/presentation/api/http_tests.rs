@@ -0,0 +1,74 @@
+use gittype::presentation::api::{HttpRequest, HttpResponse};
+use serde_json::json;
+use std::io::Cursor;
+
+#[test]
+fn parse_splits_path_and_decodes_query() {
+    let request =
+        HttpRequest::parse("GET /sessions/?days=7&sort=score&name=a%20b+c HTTP/1.1").unwrap();
+
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.path, "/sessions");
+    assert_eq!(request.query.get("days").map(String::as_str), Some("7"));
+    assert_eq!(request.query.get("name").map(String::as_str), Some("a b c"));
+}
+
+#[test]
+fn parse_rejects_malformed_request_lines() {
+    assert!(HttpRequest::parse("GET /sessions").is_err());
+    assert!(HttpRequest::parse("GET /sessions SPDY/3").is_err());
+}
+
+#[test]
+fn query_param_parses_or_reports_invalid_values() {
+    let request = HttpRequest::parse("GET /sessions?days=7&limit=abc HTTP/1.1").unwrap();
+
+    assert_eq!(request.query_param::<i64>("days").unwrap(), Some(7));
+    assert_eq!(request.query_param::<i64>("missing").unwrap(), None);
+    assert!(request.query_param::<usize>("limit").is_err());
+}
+
+#[test]
+fn read_consumes_headers_until_blank_line() {
+    let mut reader = Cursor::new("GET /stats HTTP/1.1\r\nHost: localhost\r\nAccept: */*\r\n\r\n");
+
+    let request = HttpRequest::read(&mut reader).unwrap();
+
+    assert_eq!(request.path, "/stats");
+}
+
+#[test]
+fn read_rejects_oversized_headers() {
+    let raw = format!(
+        "GET /stats HTTP/1.1\r\nX-Big: {}\r\n\r\n",
+        "a".repeat(10_000)
+    );
+
+    assert!(HttpRequest::read(&mut Cursor::new(raw)).is_err());
+}
+
+#[test]
+fn write_to_emits_json_with_content_length() {
+    let mut out = Vec::new();
+
+    HttpResponse::ok(json!({ "ok": true }))
+        .write_to(&mut out)
+        .unwrap();
+
+    let text = String::from_utf8(out).unwrap();
+    assert!(text.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(text.contains("Content-Type: application/json\r\n"));
+    assert!(text.contains("Content-Length: 11\r\n"));
+    assert!(text.ends_with("\r\n\r\n{\"ok\":true}"));
+}
+
+#[test]
+fn write_to_advertises_allowed_methods_for_405() {
+    let mut out = Vec::new();
+
+    HttpResponse::error(405, "nope").write_to(&mut out).unwrap();
+
+    let text = String::from_utf8(out).unwrap();
+    assert!(text.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+    assert!(text.contains("Allow: GET\r\n"));
+}
@@ -0,0 +1,2 @@
+pub mod api_server_tests;
+pub mod http_tests;
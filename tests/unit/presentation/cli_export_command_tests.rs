@@ -0,0 +1,333 @@
+use chrono::{NaiveDate, Utc};
+use gittype::domain::models::storage::{SaveSessionResultParams, SessionHistoryFilter};
+use gittype::domain::models::{GitRepository, HistoryFile, MetricsSnapshot, SessionResult};
+use gittype::infrastructure::database::daos::SessionDaoInterface;
+use gittype::infrastructure::database::storage_backend::{SqliteBackend, StorageBackend};
+use gittype::presentation::cli::commands::export::{
+    for_each_session, load_metrics_snapshot, load_repository_report, resolve_history_filter,
+    run_export, run_ics_export, run_prometheus_export, write_history_export, write_ics_export,
+    write_json_export, ExportScope,
+};
+use gittype::presentation::di::AppModule;
+use shaku::HasComponent;
+use std::path::Path;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+#[test]
+fn load_metrics_snapshot_is_empty_for_fresh_database() {
+    let container = AppModule::builder().build();
+    let session_dao: Arc<dyn SessionDaoInterface> = container.resolve();
+    let snapshot = load_metrics_snapshot(session_dao.as_ref()).unwrap();
+
+    assert_eq!(snapshot, MetricsSnapshot::default());
+}
+
+#[test]
+fn run_prometheus_export_writes_metrics_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("gittype.prom");
+
+    run_prometheus_export(&path).unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(contents.contains("gittype_sessions_total 0"));
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn run_prometheus_export_supports_stdout() {
+    assert!(run_prometheus_export(Path::new("-")).is_ok());
+}
+
+#[test]
+fn run_ics_export_writes_calendar_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("practice.ics");
+
+    run_ics_export(Some(&path), &ExportScope::default()).unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(contents.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(contents.ends_with("END:VCALENDAR\r\n"));
+}
+
+fn seed_session(backend: &SqliteBackend, user: &str, repo: &str, score: f64) -> i64 {
+    let git_repo = GitRepository {
+        user_name: user.to_string(),
+        repository_name: repo.to_string(),
+        remote_url: format!("https://github.com/{}/{}", user, repo),
+        branch: Some("main".to_string()),
+        commit_hash: None,
+        is_dirty: false,
+        root_path: None,
+        license: None,
+    };
+    let repository_id = backend
+        .repository_dao()
+        .ensure_repository(&git_repo)
+        .unwrap();
+    let mut session_result = SessionResult::new();
+    session_result.session_score = score;
+
+    let session_dao = backend.session_dao();
+    let database = backend.database();
+    let conn = database.get_connection().unwrap();
+    let tx = conn.unchecked_transaction().unwrap();
+    let session_id = session_dao
+        .create_session_in_transaction(
+            &tx,
+            Some(repository_id),
+            &session_result,
+            Some(&git_repo),
+            "normal",
+            None,
+            None,
+        )
+        .unwrap();
+    session_dao
+        .save_session_result_in_transaction(
+            &tx,
+            SaveSessionResultParams {
+                session_id,
+                repository_id: Some(repository_id),
+                session_result: &session_result,
+                stage_engines: &[],
+                game_mode: "normal",
+                difficulty_level: None,
+            },
+        )
+        .unwrap();
+    tx.commit().unwrap();
+    session_id
+}
+
+#[test]
+fn for_each_session_reads_every_page() {
+    let backend = SqliteBackend::in_memory().unwrap();
+    let ids: Vec<i64> = (0..5)
+        .map(|i| seed_session(&backend, "pageuser", "pagerepo", i as f64))
+        .collect();
+
+    let mut visited = Vec::new();
+    let count = for_each_session(
+        backend.session_dao().as_ref(),
+        &SessionHistoryFilter::default(),
+        2,
+        |session, _| {
+            visited.push(session.id);
+            Ok(())
+        },
+    )
+    .unwrap();
+
+    assert_eq!(count, 5);
+    assert_eq!(visited, ids);
+}
+
+#[test]
+fn write_json_export_streams_a_json_array_of_sessions() {
+    let backend = SqliteBackend::in_memory().unwrap();
+    seed_session(&backend, "jsonuser", "jsonrepo", 111.0);
+    seed_session(&backend, "jsonuser", "jsonrepo", 222.0);
+    seed_session(&backend, "jsonuser", "jsonrepo", 333.0);
+
+    let mut out = Vec::new();
+    let count = write_json_export(&backend, &SessionHistoryFilter::default(), 2, &mut out).unwrap();
+
+    assert_eq!(count, 3);
+    let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    let sessions = json.as_array().unwrap();
+    assert_eq!(sessions.len(), 3);
+    assert_eq!(sessions[0]["session_result"]["score"], 111.0);
+    assert_eq!(sessions[2]["session_result"]["score"], 333.0);
+    assert_eq!(sessions[0]["repository"]["repository_name"], "jsonrepo");
+}
+
+#[test]
+fn write_json_export_writes_an_empty_array_without_sessions() {
+    let backend = SqliteBackend::in_memory().unwrap();
+
+    let mut out = Vec::new();
+    let count = write_json_export(&backend, &SessionHistoryFilter::default(), 2, &mut out).unwrap();
+
+    assert_eq!(count, 0);
+    assert_eq!(String::from_utf8(out).unwrap(), "[]\n");
+}
+
+#[test]
+fn write_history_export_writes_a_valid_history_file() {
+    let backend = SqliteBackend::in_memory().unwrap();
+    seed_session(&backend, "histuser", "histrepo", 111.0);
+    seed_session(&backend, "histuser", "histrepo", 222.0);
+
+    let mut out = Vec::new();
+    let count = write_history_export(
+        &backend,
+        &SessionHistoryFilter::default(),
+        1,
+        Utc::now(),
+        &mut out,
+    )
+    .unwrap();
+
+    assert_eq!(count, 2);
+    let history = HistoryFile::parse(std::str::from_utf8(&out).unwrap()).unwrap();
+    assert_eq!(history.version, HistoryFile::VERSION);
+    assert_eq!(history.sessions.len(), 2);
+    assert_eq!(history.sessions[0].result.score, 111.0);
+    assert_eq!(history.sessions[1].repository.name, "histrepo");
+    assert_eq!(
+        history.sessions[1].repository.url,
+        "https://github.com/histuser/histrepo"
+    );
+}
+
+#[test]
+fn write_history_export_writes_an_empty_history_without_sessions() {
+    let backend = SqliteBackend::in_memory().unwrap();
+
+    let mut out = Vec::new();
+    write_history_export(
+        &backend,
+        &SessionHistoryFilter::default(),
+        2,
+        Utc::now(),
+        &mut out,
+    )
+    .unwrap();
+
+    let history = HistoryFile::parse(std::str::from_utf8(&out).unwrap()).unwrap();
+    assert!(history.sessions.is_empty());
+}
+
+#[test]
+fn write_ics_export_only_includes_the_filtered_repository() {
+    let backend = SqliteBackend::in_memory().unwrap();
+    let kept = seed_session(&backend, "icsuser", "kept", 1.0);
+    seed_session(&backend, "icsuser", "skipped", 2.0);
+    let filter = resolve_history_filter(
+        &backend,
+        &ExportScope {
+            repo: Some("icsuser/kept".to_string()),
+            ..ExportScope::default()
+        },
+    )
+    .unwrap();
+
+    let mut out = Vec::new();
+    let count = write_ics_export(&backend, &filter, 1, Utc::now(), &mut out).unwrap();
+
+    let ics = String::from_utf8(out).unwrap();
+    assert_eq!(count, 1);
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.contains(&format!("UID:session-{}@gittype", kept)));
+    assert!(!ics.contains("icsuser/skipped"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+}
+
+#[test]
+fn write_ics_export_includes_finished_sessions() {
+    let backend = SqliteBackend::in_memory().unwrap();
+    let session_id = seed_session(&backend, "icsuser", "icsrepo", 321.0);
+
+    let mut out = Vec::new();
+    let count = write_ics_export(
+        &backend,
+        &SessionHistoryFilter::default(),
+        2,
+        Utc::now(),
+        &mut out,
+    )
+    .unwrap();
+
+    let ics = String::from_utf8(out).unwrap();
+    assert_eq!(count, 1);
+    assert!(ics.contains(&format!("UID:session-{}@gittype", session_id)));
+    assert!(ics.contains("SUMMARY:gittype: icsuser/icsrepo"));
+    assert!(ics.contains("Score: 321"));
+}
+
+#[test]
+fn load_repository_report_includes_sessions_of_that_repository() {
+    let backend = SqliteBackend::in_memory().unwrap();
+    seed_session(&backend, "mduser", "mdrepo", 654.0);
+    seed_session(&backend, "mduser", "other", 1.0);
+    let scope = ExportScope {
+        repo: Some("mduser/mdrepo".to_string()),
+        ..ExportScope::default()
+    };
+
+    let report = load_repository_report(&backend, &scope).unwrap();
+
+    assert_eq!(report.repo_spec, "mduser/mdrepo");
+    assert_eq!(report.sessions.len(), 1);
+    assert_eq!(report.sessions[0].score, 654.0);
+}
+
+#[test]
+fn load_repository_report_keeps_sessions_within_the_local_days() {
+    let backend = SqliteBackend::in_memory().unwrap();
+    seed_session(&backend, "mduser", "mdrepo", 654.0);
+    let today = chrono::Local::now().date_naive();
+    let scope = |since: NaiveDate| ExportScope {
+        repo: Some("mduser/mdrepo".to_string()),
+        since: Some(since),
+        until: None,
+    };
+
+    assert_eq!(
+        load_repository_report(&backend, &scope(today))
+            .unwrap()
+            .sessions
+            .len(),
+        1
+    );
+    assert!(
+        load_repository_report(&backend, &scope(today.succ_opt().unwrap()))
+            .unwrap()
+            .sessions
+            .is_empty()
+    );
+}
+
+#[test]
+fn load_repository_report_rejects_missing_unknown_or_malformed_repositories() {
+    let backend = SqliteBackend::in_memory().unwrap();
+
+    for repo in [None, Some("nobody/nothing"), Some("not-a-spec")] {
+        let scope = ExportScope {
+            repo: repo.map(str::to_string),
+            ..ExportScope::default()
+        };
+        assert!(load_repository_report(&backend, &scope).is_err());
+    }
+}
+
+#[test]
+fn resolve_history_filter_rejects_unknown_repositories() {
+    let backend = SqliteBackend::in_memory().unwrap();
+
+    for repo in ["nobody/nothing", "not-a-spec"] {
+        let scope = ExportScope {
+            repo: Some(repo.to_string()),
+            ..ExportScope::default()
+        };
+        assert!(resolve_history_filter(&backend, &scope).is_err());
+    }
+}
+
+#[test]
+fn run_export_rejects_since_after_until() {
+    let scope = ExportScope {
+        repo: None,
+        since: NaiveDate::from_ymd_opt(2025, 6, 1),
+        until: NaiveDate::from_ymd_opt(2025, 1, 1),
+    };
+
+    let error = run_export("json".to_string(), None, None, scope).unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("--since 2025-06-01 is after --until 2025-01-01"));
+}
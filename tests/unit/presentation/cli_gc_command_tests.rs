@@ -0,0 +1,55 @@
+use gittype::domain::models::Challenge;
+use gittype::domain::repositories::challenge_repository::ChallengeRepository;
+use gittype::infrastructure::database::daos::{ChallengeDao, ChallengeDaoInterface};
+use gittype::infrastructure::database::database::{Database, DatabaseInterface};
+use gittype::infrastructure::storage::file_storage::FileStorage;
+use gittype::presentation::cli::commands::gc::{collect_garbage, GcReport};
+use std::sync::Arc;
+
+#[test]
+fn collect_garbage_removes_orphaned_challenges_and_old_logs() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let log_dir = temp_dir.path().join("logs");
+    std::fs::create_dir_all(&log_dir).unwrap();
+    std::fs::write(log_dir.join("gittype_20200101_000000.log"), "old").unwrap();
+    std::fs::write(log_dir.join("gittype_20200102_000000.log"), "newest").unwrap();
+
+    let database = Arc::new(Database::in_memory().unwrap());
+    {
+        let conn = database.get_connection().unwrap();
+        let tx = conn.unchecked_transaction().unwrap();
+        ChallengeDao::new(Arc::clone(&database) as Arc<dyn DatabaseInterface>)
+            .ensure_challenge_in_transaction(
+                &tx,
+                &Challenge::new("orphan".to_string(), "fn main() {}".to_string()),
+            )
+            .unwrap();
+        tx.commit().unwrap();
+    }
+    let challenge_repository = ChallengeRepository::new_for_test(
+        temp_dir.path().join("cache"),
+        Arc::new(FileStorage::new()),
+    );
+
+    let report = collect_garbage(&challenge_repository, database, &log_dir).unwrap();
+
+    assert_eq!(report.orphaned_challenges, 1);
+    assert_eq!(report.stale_caches, 0);
+    assert_eq!((report.old_logs, report.log_bytes), (1, 3));
+    assert!(log_dir.join("gittype_20200102_000000.log").exists());
+}
+
+#[test]
+fn gc_report_sums_reclaimed_space() {
+    let report = GcReport {
+        stale_caches: 2,
+        cache_bytes: 1024,
+        orphaned_challenges: 0,
+        database_bytes: 2048,
+        old_logs: 1,
+        log_bytes: 1024,
+    };
+
+    assert_eq!(report.reclaimed(), 4096);
+    assert_eq!(report.lines().last().unwrap(), "✅ Reclaimed 4.0 KB");
+}
@@ -0,0 +1,126 @@
+use git2::Repository;
+use gittype::presentation::cli::commands::hooks::{
+    install_post_commit_hook, post_commit_hook_script, uninstall_post_commit_hook,
+};
+use gittype::GitTypeError;
+
+fn init_repo() -> tempfile::TempDir {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    Repository::init(temp_dir.path()).unwrap();
+    temp_dir
+}
+
+#[test]
+fn install_writes_post_commit_hook_running_commit_practice() {
+    let temp_dir = init_repo();
+
+    let hook_path = install_post_commit_hook(temp_dir.path(), false).unwrap();
+
+    assert!(hook_path.ends_with(".git/hooks/post-commit"));
+    let content = std::fs::read_to_string(&hook_path).unwrap();
+    assert!(content.starts_with("#!/bin/sh"));
+    assert!(content.contains("gittype --commit HEAD"));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&hook_path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111);
+    }
+}
+
+#[test]
+fn install_from_subdirectory_uses_repository_hooks_dir() {
+    let temp_dir = init_repo();
+    let nested = temp_dir.path().join("src");
+    std::fs::create_dir_all(&nested).unwrap();
+
+    let hook_path = install_post_commit_hook(&nested, false).unwrap();
+
+    assert!(hook_path.ends_with(".git/hooks/post-commit"));
+    assert!(hook_path.exists());
+}
+
+#[test]
+fn install_is_idempotent_for_gittype_hook() {
+    let temp_dir = init_repo();
+
+    install_post_commit_hook(temp_dir.path(), false).unwrap();
+    let hook_path = install_post_commit_hook(temp_dir.path(), false).unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(hook_path).unwrap(),
+        post_commit_hook_script()
+    );
+}
+
+#[test]
+fn install_refuses_to_overwrite_foreign_hook_without_force() {
+    let temp_dir = init_repo();
+    let hook_path = temp_dir.path().join(".git/hooks/post-commit");
+    std::fs::create_dir_all(hook_path.parent().unwrap()).unwrap();
+    std::fs::write(&hook_path, "#!/bin/sh\necho custom\n").unwrap();
+
+    let result = install_post_commit_hook(temp_dir.path(), false);
+
+    assert!(matches!(result, Err(GitTypeError::ValidationError(msg)) if msg.contains("--force")));
+    assert_eq!(
+        std::fs::read_to_string(&hook_path).unwrap(),
+        "#!/bin/sh\necho custom\n"
+    );
+}
+
+#[test]
+fn install_with_force_overwrites_foreign_hook() {
+    let temp_dir = init_repo();
+    let hook_path = temp_dir.path().join(".git/hooks/post-commit");
+    std::fs::create_dir_all(hook_path.parent().unwrap()).unwrap();
+    std::fs::write(&hook_path, "#!/bin/sh\necho custom\n").unwrap();
+
+    install_post_commit_hook(temp_dir.path(), true).unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(&hook_path).unwrap(),
+        post_commit_hook_script()
+    );
+}
+
+#[test]
+fn uninstall_removes_gittype_hook() {
+    let temp_dir = init_repo();
+    let hook_path = install_post_commit_hook(temp_dir.path(), false).unwrap();
+
+    let removed = uninstall_post_commit_hook(temp_dir.path()).unwrap();
+
+    assert_eq!(removed, Some(hook_path.clone()));
+    assert!(!hook_path.exists());
+}
+
+#[test]
+fn uninstall_without_hook_returns_none() {
+    let temp_dir = init_repo();
+
+    assert_eq!(uninstall_post_commit_hook(temp_dir.path()).unwrap(), None);
+}
+
+#[test]
+fn uninstall_keeps_foreign_hook() {
+    let temp_dir = init_repo();
+    let hook_path = temp_dir.path().join(".git/hooks/post-commit");
+    std::fs::create_dir_all(hook_path.parent().unwrap()).unwrap();
+    std::fs::write(&hook_path, "#!/bin/sh\necho custom\n").unwrap();
+
+    let result = uninstall_post_commit_hook(temp_dir.path());
+
+    assert!(matches!(result, Err(GitTypeError::ValidationError(_))));
+    assert!(hook_path.exists());
+}
+
+#[test]
+fn install_outside_repository_returns_repository_not_found() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+
+    let result = install_post_commit_hook(temp_dir.path(), false);
+
+    assert!(matches!(result, Err(GitTypeError::RepositoryNotFound(_))));
+}
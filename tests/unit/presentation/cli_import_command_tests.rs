@@ -0,0 +1,126 @@
+use gittype::domain::models::storage::SessionHistoryFilter;
+use gittype::infrastructure::database::daos::{BaselineDao, BaselineDaoInterface};
+use gittype::infrastructure::database::database::{Database, DatabaseInterface};
+use gittype::infrastructure::database::storage_backend::{SqliteBackend, StorageBackend};
+use gittype::presentation::cli::commands::import::{import_baselines, import_history, run_import};
+use std::sync::Arc;
+use tempfile::TempDir;
+
+const KEYBR_CSV: &str = "timeStamp,length,time,errors,speed\n\
+                         2024-01-01T10:00:00Z,200,40000,4,300\n";
+
+fn write_csv(dir: &TempDir, contents: &str) -> std::path::PathBuf {
+    let path = dir.path().join("results.csv");
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn import_baselines_counts_new_and_duplicate_tests() {
+    let dir = TempDir::new().unwrap();
+    let path = write_csv(&dir, KEYBR_CSV);
+    let dao = BaselineDao::new(Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>);
+
+    assert_eq!(import_baselines(&path, None, &dao).unwrap(), (1, 1));
+    assert_eq!(
+        import_baselines(&path, Some("keybr"), &dao).unwrap(),
+        (1, 0)
+    );
+    assert_eq!(dao.get_baseline_summary().unwrap().unwrap().avg_wpm, 60.0);
+}
+
+#[test]
+fn import_baselines_rejects_mismatched_source() {
+    let dir = TempDir::new().unwrap();
+    let path = write_csv(&dir, KEYBR_CSV);
+    let dao = BaselineDao::new(Arc::new(Database::new().unwrap()) as Arc<dyn DatabaseInterface>);
+
+    assert!(import_baselines(&path, Some("monkeytype"), &dao).is_err());
+}
+
+#[test]
+fn run_import_reports_missing_file() {
+    let dir = TempDir::new().unwrap();
+
+    assert!(run_import(&dir.path().join("missing.csv"), None).is_err());
+}
+
+#[test]
+fn run_import_imports_csv() {
+    let dir = TempDir::new().unwrap();
+    let path = write_csv(&dir, KEYBR_CSV);
+
+    assert!(run_import(&path, None).is_ok());
+}
+
+const HISTORY_JSON: &str = r#"{
+    "format": "gittype-history", "version": 1, "exported_at": "2026-03-02T00:00:00Z",
+    "sessions": [
+        {"started_at": "2026-03-01T09:00:00Z", "completed_at": "2026-03-01T09:05:00Z",
+         "player": "alice",
+         "repository": {"owner": "team", "name": "app", "url": "https://github.com/team/app"},
+         "game_mode": "normal", "difficulty": "Normal",
+         "result": {"duration_ms": 300000, "keystrokes": 1500, "mistakes": 30, "wpm": 60.0,
+                    "cpm": 300.0, "accuracy": 98.0, "score": 4200.0,
+                    "stages_completed": 3, "stages_attempted": 3, "stages_skipped": 0}},
+        {"started_at": "2026-03-01T10:00:00Z", "completed_at": "2026-03-01T10:02:00Z",
+         "repository": {"owner": "team", "name": "app", "url": "https://github.com/team/app"},
+         "game_mode": "normal", "unverified": true,
+         "result": {"duration_ms": 120000, "keystrokes": 900, "mistakes": 0, "wpm": 400.0,
+                    "cpm": 2000.0, "accuracy": 100.0, "score": 9000.0,
+                    "stages_completed": 1, "stages_attempted": 1, "stages_skipped": 0}}
+    ]
+}"#;
+
+#[test]
+fn import_history_stores_sessions_once() {
+    let backend = SqliteBackend::in_memory().unwrap();
+
+    assert_eq!(import_history(HISTORY_JSON, &backend).unwrap(), (2, 2));
+    assert_eq!(import_history(HISTORY_JSON, &backend).unwrap(), (2, 0));
+
+    let repository = backend
+        .repository_dao()
+        .find_repository("team", "app")
+        .unwrap()
+        .unwrap();
+    let page = backend
+        .session_dao()
+        .get_session_history_page(&SessionHistoryFilter::default(), None, 10)
+        .unwrap();
+    assert_eq!(page.len(), 2);
+    assert_eq!(page[0].0.repository_id, Some(repository.id));
+    assert_eq!(page[0].0.player_name.as_deref(), Some("alice"));
+    assert_eq!(page[0].1.score, 4200.0);
+}
+
+#[test]
+fn import_history_keeps_imported_sessions_out_of_best_records() {
+    let backend = SqliteBackend::in_memory().unwrap();
+
+    import_history(HISTORY_JSON, &backend).unwrap();
+
+    let page = backend
+        .session_dao()
+        .get_session_history_page(&SessionHistoryFilter::default(), None, 10)
+        .unwrap();
+    assert!(page.iter().all(|(session, _)| session.unverified));
+    assert!(backend
+        .session_dao()
+        .get_all_time_best_session()
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn import_history_stores_nothing_from_an_invalid_file() {
+    let backend = SqliteBackend::in_memory().unwrap();
+    let invalid = HISTORY_JSON.replace("\"accuracy\": 100.0", "\"accuracy\": 140.0");
+
+    assert!(import_history(&invalid, &backend).is_err());
+    assert!(backend
+        .repository_dao()
+        .get_all_repositories()
+        .unwrap()
+        .is_empty());
+}
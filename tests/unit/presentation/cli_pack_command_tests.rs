@@ -0,0 +1,67 @@
+use gittype::presentation::cli::args::{Cli, Commands, PackCommands};
+use gittype::presentation::cli::commands::run_pack_create;
+use gittype::presentation::cli::run_cli;
+use gittype::GitTypeError;
+
+use std::path::PathBuf;
+
+fn play_cli(file: PathBuf) -> Cli {
+    Cli {
+        repo_path: None,
+        repo: None,
+        langs: None,
+        commit: None,
+        package: None,
+        follow_symlinks: false,
+        prose: false,
+        summary_only: false,
+        broadcast: None,
+        bots: None,
+        command: Some(Commands::Pack {
+            pack_command: PackCommands::Play { file },
+        }),
+    }
+}
+
+#[test]
+fn pack_create_fails_without_matching_challenges() {
+    let repo = tempfile::tempdir().unwrap();
+    let out = repo.path().join("class.gtpack");
+
+    assert!(matches!(
+        run_pack_create(repo.path(), &out, 3, "hard", Some(5)),
+        Err(GitTypeError::ValidationError(message)) if message.starts_with("No Hard challenges")
+    ));
+    assert!(!out.exists());
+}
+
+#[test]
+fn pack_create_rejects_zero_stages() {
+    let repo = tempfile::tempdir().unwrap();
+    let out = repo.path().join("class.gtpack");
+
+    assert!(matches!(
+        run_pack_create(repo.path(), &out, 0, "normal", None),
+        Err(GitTypeError::ValidationError(_))
+    ));
+    assert!(!out.exists());
+}
+
+#[test]
+fn pack_play_rejects_missing_file() {
+    assert!(matches!(
+        run_cli(play_cli("does/not/exist.gtpack".into())),
+        Err(GitTypeError::ValidationError(message)) if message.contains("does/not/exist.gtpack")
+    ));
+}
+
+#[test]
+fn pack_play_rejects_repository_options() {
+    let mut cli = play_cli("class.gtpack".into());
+    cli.repo = Some("owner/repo".to_string());
+
+    assert!(matches!(
+        run_cli(cli),
+        Err(GitTypeError::ValidationError(_))
+    ));
+}
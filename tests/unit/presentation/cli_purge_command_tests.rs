@@ -0,0 +1,78 @@
+use gittype::presentation::cli::commands::purge::PurgeTarget;
+use std::fs;
+
+fn data_dir() -> tempfile::TempDir {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::write(root.join("gittype.db"), vec![0u8; 2048]).unwrap();
+    fs::create_dir_all(root.join("repos/github.com/owner/name")).unwrap();
+    fs::write(
+        root.join("repos/github.com/owner/name/main.rs"),
+        "fn main() {}",
+    )
+    .unwrap();
+    fs::create_dir_all(root.join("cache")).unwrap();
+    fs::write(root.join("cache/challenges.bin"), vec![0u8; 100]).unwrap();
+    fs::write(root.join("config.json"), "{}").unwrap();
+    temp_dir
+}
+
+#[test]
+fn size_sums_files_in_nested_directories() {
+    let temp_dir = data_dir();
+
+    assert_eq!(PurgeTarget::Repos.size(temp_dir.path()), Some(12));
+    assert_eq!(PurgeTarget::Database.size(temp_dir.path()), Some(2048));
+}
+
+#[test]
+fn size_is_none_when_target_has_no_data() {
+    let temp_dir = data_dir();
+
+    assert_eq!(PurgeTarget::Logs.size(temp_dir.path()), None);
+}
+
+#[test]
+fn purge_removes_only_the_selected_target() {
+    let temp_dir = data_dir();
+    let root = temp_dir.path();
+
+    let freed = PurgeTarget::Cache.purge(root).unwrap();
+
+    assert_eq!(freed, 100);
+    assert!(!root.join("cache").exists());
+    assert!(root.join("gittype.db").exists());
+    assert!(root.join("repos").exists());
+    assert!(root.join("config.json").exists());
+}
+
+#[test]
+fn purge_removes_files_and_directories() {
+    let temp_dir = data_dir();
+    let root = temp_dir.path();
+
+    PurgeTarget::Database.purge(root).unwrap();
+    PurgeTarget::Repos.purge(root).unwrap();
+    PurgeTarget::Config.purge(root).unwrap();
+
+    assert!(!root.join("gittype.db").exists());
+    assert!(!root.join("repos").exists());
+    assert!(!root.join("config.json").exists());
+}
+
+#[test]
+fn purge_of_missing_target_is_a_no_op() {
+    let temp_dir = data_dir();
+
+    assert_eq!(PurgeTarget::Logs.purge(temp_dir.path()).unwrap(), 0);
+}
+
+#[test]
+fn paths_stay_inside_data_dir() {
+    let temp_dir = data_dir();
+
+    assert!(PurgeTarget::all()
+        .iter()
+        .flat_map(|target| target.paths(temp_dir.path()))
+        .all(|path| path.starts_with(temp_dir.path())));
+}
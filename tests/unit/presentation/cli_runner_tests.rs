@@ -8,6 +8,13 @@ fn make_cli(command: Commands) -> Cli {
         repo_path: None,
         repo: None,
         langs: None,
+        commit: None,
+        package: None,
+        follow_symlinks: false,
+        prose: false,
+        summary_only: false,
+        broadcast: None,
+        bots: None,
         command: Some(command),
     }
 }
@@ -48,6 +55,26 @@ fn run_cli_executes_repo_clear_force_command() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn run_cli_executes_repo_dismissed_command() {
+    let result = run_cli(make_cli(Commands::Repo {
+        repo_command: RepoCommands::Dismissed,
+    }));
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn run_cli_repo_restore_rejects_names_without_owner() {
+    let result = run_cli(make_cli(Commands::Repo {
+        repo_command: RepoCommands::Restore {
+            name: "gittype".to_string(),
+        },
+    }));
+
+    assert!(matches!(result, Err(GitTypeError::ValidationError(_))));
+}
+
 #[test]
 fn run_cli_executes_repo_list_command_without_tty() {
     if atty::is(atty::Stream::Stdout) {
@@ -92,6 +119,13 @@ fn run_cli_without_command_returns_error_without_tty() {
         repo_path: None,
         repo: None,
         langs: None,
+        commit: None,
+        package: None,
+        follow_symlinks: false,
+        prose: false,
+        summary_only: false,
+        broadcast: None,
+        bots: None,
         command: None,
     });
 
@@ -135,23 +169,35 @@ fn run_cli_returns_validation_error_for_invalid_trending_language() {
 }
 
 #[test]
-fn export_command_exits_with_failure_status() {
+fn export_command_rejects_unknown_formats() {
     let output = Command::new(env!("CARGO_BIN_EXE_gittype"))
         .args(["export", "--format", "csv", "--output", "sessions.csv"])
         .output()
         .unwrap();
 
-    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(output.status.code(), Some(2));
 }
 
 #[test]
-fn export_command_without_output_exits_with_failure_status() {
+fn export_command_without_output_prints_json_to_stdout() {
     let output = Command::new(env!("CARGO_BIN_EXE_gittype"))
         .args(["export", "--format", "json"])
         .output()
         .unwrap();
 
-    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(json.is_array());
+}
+
+#[test]
+fn export_command_rejects_malformed_dates() {
+    let output = Command::new(env!("CARGO_BIN_EXE_gittype"))
+        .args(["export", "--since", "last-week"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
 }
 
 #[test]
@@ -174,6 +220,16 @@ fn history_command_exits_with_not_implemented_status() {
     assert_eq!(output.status.code(), Some(1));
 }
 
+#[test]
+fn languages_command_exits_successfully() {
+    let output = Command::new(env!("CARGO_BIN_EXE_gittype"))
+        .arg("languages")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+}
+
 #[derive(Debug)]
 struct FailingChallengeRepository;
 
@@ -190,9 +246,7 @@ impl gittype::domain::repositories::challenge_repository::ChallengeRepositoryInt
         &self,
         _repo: &gittype::domain::models::GitRepository,
         _challenges: &[gittype::domain::models::Challenge],
-        _reporter: Option<
-            &dyn gittype::presentation::tui::screens::loading_screen::ProgressReporter,
-        >,
+        _reporter: Option<&dyn gittype::domain::models::loading::ProgressReporter>,
     ) -> gittype::Result<()> {
         Ok(())
     }
@@ -200,9 +254,7 @@ impl gittype::domain::repositories::challenge_repository::ChallengeRepositoryInt
     fn load_challenges_with_progress(
         &self,
         _repo: &gittype::domain::models::GitRepository,
-        _reporter: Option<
-            &dyn gittype::presentation::tui::screens::loading_screen::ProgressReporter,
-        >,
+        _reporter: Option<&dyn gittype::domain::models::loading::ProgressReporter>,
     ) -> gittype::Result<Option<Vec<gittype::domain::models::Challenge>>> {
         Ok(None)
     }
@@ -225,6 +277,18 @@ impl gittype::domain::repositories::challenge_repository::ChallengeRepositoryInt
     fn list_cache_keys(&self) -> gittype::Result<Vec<String>> {
         Err(GitTypeError::ExtractionFailed("list failed".to_string()))
     }
+
+    fn count_cached_challenges(&self, _repo_key: &str) -> gittype::Result<usize> {
+        Ok(0)
+    }
+
+    fn invalidate_repository_key(&self, _repo_key: &str) -> gittype::Result<usize> {
+        Ok(0)
+    }
+
+    fn remove_stale_caches(&self) -> gittype::Result<(usize, u64)> {
+        Ok((0, 0))
+    }
 }
 
 impl gittype::domain::repositories::challenge_repository::ChallengeRepositoryInterface
@@ -234,9 +298,7 @@ impl gittype::domain::repositories::challenge_repository::ChallengeRepositoryInt
         &self,
         _repo: &gittype::domain::models::GitRepository,
         _challenges: &[gittype::domain::models::Challenge],
-        _reporter: Option<
-            &dyn gittype::presentation::tui::screens::loading_screen::ProgressReporter,
-        >,
+        _reporter: Option<&dyn gittype::domain::models::loading::ProgressReporter>,
     ) -> gittype::Result<()> {
         Ok(())
     }
@@ -244,9 +306,7 @@ impl gittype::domain::repositories::challenge_repository::ChallengeRepositoryInt
     fn load_challenges_with_progress(
         &self,
         _repo: &gittype::domain::models::GitRepository,
-        _reporter: Option<
-            &dyn gittype::presentation::tui::screens::loading_screen::ProgressReporter,
-        >,
+        _reporter: Option<&dyn gittype::domain::models::loading::ProgressReporter>,
     ) -> gittype::Result<Option<Vec<gittype::domain::models::Challenge>>> {
         Ok(None)
     }
@@ -269,6 +329,18 @@ impl gittype::domain::repositories::challenge_repository::ChallengeRepositoryInt
     fn list_cache_keys(&self) -> gittype::Result<Vec<String>> {
         Ok(self.cache_keys.clone())
     }
+
+    fn count_cached_challenges(&self, _repo_key: &str) -> gittype::Result<usize> {
+        Ok(0)
+    }
+
+    fn invalidate_repository_key(&self, _repo_key: &str) -> gittype::Result<usize> {
+        Ok(0)
+    }
+
+    fn remove_stale_caches(&self) -> gittype::Result<(usize, u64)> {
+        Ok((0, 0))
+    }
 }
 
 #[test]
@@ -332,3 +404,76 @@ fn run_cache_command_handles_successful_stats_and_list_outputs() {
         .is_ok()
     );
 }
+
+#[test]
+fn run_cli_snippets_rejects_missing_directory() {
+    let result = run_cli(make_cli(Commands::Snippets {
+        dir: "does/not/exist".into(),
+    }));
+
+    assert!(matches!(
+        result,
+        Err(GitTypeError::ValidationError(message)) if message.contains("does/not/exist")
+    ));
+}
+
+#[test]
+fn run_cli_snippets_rejects_repository_options() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut cli = make_cli(Commands::Snippets {
+        dir: temp_dir.path().to_path_buf(),
+    });
+    cli.repo = Some("owner/repo".to_string());
+
+    assert!(matches!(
+        run_cli(cli),
+        Err(GitTypeError::ValidationError(_))
+    ));
+}
+
+#[test]
+fn run_cli_verify_accepts_valid_code_and_rejects_edited_one() {
+    use gittype::domain::models::{ResultVerification, SessionResult};
+
+    let code = ResultVerification::new(&SessionResult::new(), "log", 3).code();
+
+    assert!(run_cli(make_cli(Commands::Verify { code: code.clone() })).is_ok());
+    assert!(matches!(
+        run_cli(make_cli(Commands::Verify {
+            code: code.replacen("GT1-0-", "GT1-9999-", 1)
+        })),
+        Err(GitTypeError::ValidationError(_))
+    ));
+}
+
+#[test]
+fn broadcast_takes_an_address_only_with_equals() {
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from(["gittype", "--broadcast", "/path/to/repo"]).unwrap();
+    assert_eq!(cli.broadcast.as_deref(), Some("127.0.0.1:7879"));
+    assert_eq!(
+        cli.repo_path,
+        Some(std::path::PathBuf::from("/path/to/repo"))
+    );
+
+    let cli = Cli::try_parse_from(["gittype", "--broadcast=0.0.0.0:9000"]).unwrap();
+    assert_eq!(cli.broadcast.as_deref(), Some("0.0.0.0:9000"));
+
+    let cli = Cli::try_parse_from(["gittype", "spectate"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Spectate { ref address }) if address == "127.0.0.1:7879"
+    ));
+}
+
+#[test]
+fn bots_take_a_comma_separated_list() {
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from(["gittype", "--bots", "novice,70@96"]).unwrap();
+    assert_eq!(
+        cli.bots,
+        Some(vec!["novice".to_string(), "70@96".to_string()])
+    );
+}
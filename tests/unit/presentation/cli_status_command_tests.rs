@@ -0,0 +1,15 @@
+use gittype::domain::models::StatusSummary;
+use gittype::presentation::cli::commands::run_status;
+use gittype::presentation::cli::commands::status::load_status_summary;
+
+#[test]
+fn load_status_summary_is_empty_for_fresh_database() {
+    let summary = load_status_summary().unwrap();
+
+    assert_eq!(summary, StatusSummary::default());
+}
+
+#[test]
+fn run_status_prints_with_custom_format() {
+    assert!(run_status("{streak} {today_wpm}").is_ok());
+}
@@ -0,0 +1,19 @@
+use gittype::presentation::cli::commands::telemetry::load_telemetry_report;
+use gittype::presentation::cli::commands::{
+    run_telemetry_disable, run_telemetry_enable, run_telemetry_show,
+};
+
+#[test]
+fn load_telemetry_report_is_empty_for_fresh_database() {
+    let report = load_telemetry_report().unwrap();
+
+    assert_eq!(report.sessions, 0);
+    assert!(report.languages.is_empty());
+}
+
+#[test]
+fn telemetry_commands_succeed() {
+    assert!(run_telemetry_show().is_ok());
+    assert!(run_telemetry_enable(Some("https://example.com/telemetry")).is_ok());
+    assert!(run_telemetry_disable().is_ok());
+}
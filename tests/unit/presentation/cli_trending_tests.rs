@@ -40,18 +40,14 @@ fn run_trending_accepts_supported_language_before_repo_name_validation() {
 }
 
 #[test]
-fn run_trending_with_full_repo_name_returns_io_error_without_tty() {
+fn run_trending_with_full_repo_name_returns_terminal_error_without_tty() {
     let result = run_trending(
         Some("Rust".to_string()),
         Some("owner/repo".to_string()),
         "daily".to_string(),
     );
 
-    if atty::is(atty::Stream::Stdout) {
-        return;
-    }
-
-    assert!(matches!(result, Err(GitTypeError::IoError(_))));
+    assert_non_tty_terminal_error(result);
 }
 
 #[test]
@@ -0,0 +1,46 @@
+use gittype::presentation::cli::commands::warm::{parse_repository_list, run_warm};
+use gittype::GitTypeError;
+use tempfile::TempDir;
+
+#[test]
+fn parse_repository_list_skips_blank_and_comment_lines() {
+    let repositories = parse_repository_list(
+        "# workshop\nunhappychoice/gittype\n\n  https://github.com/rust-lang/rust.git  \n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        repositories,
+        [
+            "unhappychoice/gittype",
+            "https://github.com/rust-lang/rust.git"
+        ]
+    );
+}
+
+#[test]
+fn parse_repository_list_reports_every_invalid_line() {
+    let result = parse_repository_list("gittype\nunhappychoice/gittype\nnot a repo\n");
+
+    assert!(matches!(
+        result,
+        Err(GitTypeError::ValidationError(message))
+        if message == "Unsupported repository format (line 1: gittype, line 3: not a repo)"
+    ));
+}
+
+#[test]
+fn run_warm_without_repositories_does_nothing() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("repos.txt");
+    std::fs::write(&path, "# nothing yet\n").unwrap();
+
+    assert!(run_warm(&path, 4).is_ok());
+}
+
+#[test]
+fn run_warm_fails_for_a_missing_file() {
+    let dir = TempDir::new().unwrap();
+
+    assert!(run_warm(&dir.path().join("missing.txt"), 4).is_err());
+}
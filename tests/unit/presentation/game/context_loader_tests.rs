@@ -1,5 +1,8 @@
 use gittype::domain::models::Challenge;
-use gittype::domain::services::context_loader::{load_context_for_challenge, load_context_lines};
+use gittype::domain::services::context_loader::{
+    load_context_for_challenge, load_context_lines, load_source_lines, load_source_study,
+    locate_source_file,
+};
 use gittype::infrastructure::storage::file_storage::FileStorage;
 use std::fs;
 use std::path::Path;
@@ -108,3 +111,85 @@ fn load_context_for_challenge_resolves_relative_source_paths_from_git_root() {
     assert!(context.pre_context.is_empty());
     assert!(context.post_context.is_empty());
 }
+
+#[test]
+fn load_source_lines_reads_the_whole_file() {
+    let content = "first\nsecond\nthird";
+    let temp_file = NamedTempFile::new().unwrap();
+    fs::write(&temp_file, content).unwrap();
+
+    let mut file_storage = FileStorage::new();
+    file_storage.set_file_content(temp_file.path().to_path_buf(), content.to_string());
+
+    let lines = load_source_lines(&file_storage, temp_file.path()).unwrap();
+
+    assert_eq!(
+        lines,
+        Some(vec![
+            "first".to_string(),
+            "second".to_string(),
+            "third".to_string()
+        ])
+    );
+}
+
+#[test]
+fn load_source_lines_returns_none_for_missing_file() {
+    let file_storage = FileStorage::new();
+
+    let lines = load_source_lines(&file_storage, Path::new("/nonexistent/file.rs")).unwrap();
+
+    assert!(lines.is_none());
+}
+
+#[test]
+fn load_source_study_returns_none_without_source_info() {
+    let challenge = Challenge::new("no-source".to_string(), "target".to_string());
+
+    assert!(load_source_study(&challenge, None).unwrap().is_none());
+}
+
+#[test]
+fn load_source_study_returns_none_when_file_is_gone() {
+    let challenge = Challenge::new("gone".to_string(), "target".to_string()).with_source_info(
+        "missing/file.rs".to_string(),
+        1,
+        1,
+    );
+
+    assert!(
+        load_source_study(&challenge, Some(Path::new("/nonexistent")))
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn locate_source_file_resolves_from_git_root_at_start_line() {
+    let challenge = Challenge::new("found".to_string(), "target".to_string()).with_source_info(
+        "src/lib.rs".to_string(),
+        12,
+        20,
+    );
+    let mut file_storage = FileStorage::new();
+    file_storage.set_file_content(Path::new("/repo/src/lib.rs").to_path_buf(), String::new());
+
+    assert_eq!(
+        locate_source_file(&file_storage, &challenge, Some(Path::new("/repo"))),
+        Some((Path::new("/repo/src/lib.rs").to_path_buf(), 12))
+    );
+}
+
+#[test]
+fn locate_source_file_returns_none_when_file_is_gone() {
+    let challenge = Challenge::new("gone".to_string(), "target".to_string()).with_source_info(
+        "missing/file.rs".to_string(),
+        1,
+        1,
+    );
+
+    assert_eq!(
+        locate_source_file(&FileStorage::new(), &challenge, Some(Path::new("/repo"))),
+        None
+    );
+}
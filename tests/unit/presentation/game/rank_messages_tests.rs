@@ -1,6 +1,7 @@
 use gittype::domain::models::ui::rank_messages::{
-    get_colored_messages_for_rank, get_hacking_messages_for_rank, ColoredMessage,
+    get_colored_messages_for_rank, get_hacking_messages_for_rank, ColoredMessage, RankMessages,
 };
+use gittype::domain::models::{CommentaryStyle, Rank};
 
 #[test]
 fn get_hacking_messages_for_known_rank() {
@@ -178,3 +179,75 @@ fn colored_messages_match_regular_messages() {
         assert_eq!(reg, &col.text, "Message at index {} should match", i);
     }
 }
+
+#[test]
+fn bundled_sets_cover_every_rank() {
+    for style in [CommentaryStyle::Playful, CommentaryStyle::Serious] {
+        let messages = RankMessages::bundled("en", style);
+        for rank in Rank::all_ranks() {
+            let lines = messages.for_rank(rank.name());
+            assert_eq!(
+                lines.map(|lines| lines.len()),
+                Some(4),
+                "{:?} commentary for '{}'",
+                style,
+                rank.name()
+            );
+        }
+    }
+}
+
+#[test]
+fn serious_commentary_names_the_rank_and_tier() {
+    let lines = RankMessages::bundled("en", CommentaryStyle::Serious)
+        .for_rank("Git Ninja")
+        .unwrap();
+
+    assert!(lines
+        .iter()
+        .any(|line| line.text == "> rank: Git Ninja, intermediate tier."));
+    assert!(lines.iter().all(|line| line.text.starts_with('>')));
+}
+
+#[test]
+fn custom_messages_cover_only_the_ranks_and_tiers_they_name() {
+    let custom = RankMessages::from_json(
+        r#"{
+            "ranks": {
+                "Hello World": [{ "text": "> Hallo Welt, {rank}!", "color": "success" }]
+            },
+            "tiers": {
+                "legendary": [{ "text": "> legendär.", "color": "score" }]
+            }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        custom.for_rank("Hello World").unwrap()[0].text,
+        "> Hallo Welt, Hello World!"
+    );
+    assert_eq!(
+        custom.for_rank("Kernel Panic").unwrap()[0].text,
+        "> legendär."
+    );
+    assert!(custom.for_rank("Git Ninja").is_none());
+}
+
+#[test]
+fn custom_messages_reject_unknown_colors() {
+    let result =
+        RankMessages::from_json(r#"{ "fallback": [{ "text": "> done.", "color": "plaid" }] }"#);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn bundled_messages_fall_back_to_english_for_untranslated_languages() {
+    for style in [CommentaryStyle::Playful, CommentaryStyle::Serious] {
+        assert_eq!(
+            RankMessages::bundled("de", style),
+            RankMessages::bundled("en", style)
+        );
+    }
+}
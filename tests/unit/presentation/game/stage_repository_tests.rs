@@ -54,6 +54,7 @@ fn test_stage_repository_new() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let repo = StageRepository::new(
@@ -101,6 +102,7 @@ fn test_stage_repository_with_config() {
         commit_hash: Some("abc123".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     };
 
     let config = StageConfig {
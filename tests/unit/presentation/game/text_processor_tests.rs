@@ -425,3 +425,44 @@ fn should_skip_final_newline_non_newline_char() {
     let result = TextProcessor::should_skip_final_newline(text, 4);
     assert!(!result); // 'o' is not a newline
 }
+
+#[test]
+fn normalize_line_endings_converts_crlf_and_lone_cr() {
+    let result = TextProcessor::normalize_line_endings("a\r\nb\rc\nd");
+    assert_eq!(result, "a\nb\nc\nd");
+}
+
+#[test]
+fn decode_source_strips_utf8_bom() {
+    let (text, lossy) = TextProcessor::decode_source(b"\xEF\xBB\xBFfn main() {}\r\n");
+    assert_eq!(text, "fn main() {}\n");
+    assert!(!lossy);
+}
+
+#[test]
+fn decode_source_decodes_utf16_with_bom() {
+    let le: Vec<u8> = [0xFF, 0xFE]
+        .into_iter()
+        .chain("let x;".encode_utf16().flat_map(u16::to_le_bytes))
+        .collect();
+    let be: Vec<u8> = [0xFE, 0xFF]
+        .into_iter()
+        .chain("let y;".encode_utf16().flat_map(u16::to_be_bytes))
+        .collect();
+
+    assert_eq!(
+        TextProcessor::decode_source(&le),
+        ("let x;".to_string(), false)
+    );
+    assert_eq!(
+        TextProcessor::decode_source(&be),
+        ("let y;".to_string(), false)
+    );
+}
+
+#[test]
+fn decode_source_replaces_invalid_utf8() {
+    let (text, lossy) = TextProcessor::decode_source(b"// caf\xE9\nlet x;");
+    assert_eq!(text, "// caf\u{FFFD}\nlet x;");
+    assert!(lossy);
+}
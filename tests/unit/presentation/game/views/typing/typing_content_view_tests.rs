@@ -108,3 +108,106 @@ fn render_includes_context_lines_and_reuses_cached_content() {
     assert!(output.contains("    let value = 1;"));
     assert!(output.contains("value?"));
 }
+
+fn render_to_text(
+    view: &mut TypingContentView,
+    challenge: &Challenge,
+    typing_core: &TypingCore,
+    context: &CodeContext,
+    width: u16,
+    height: u16,
+) -> Vec<String> {
+    let chars = typing_core.text_to_display().chars().collect::<Vec<_>>();
+    let colors = test_colors();
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|frame| {
+            view.render(
+                frame,
+                Rect::new(0, 0, width, height),
+                true,
+                Some(challenge),
+                typing_core,
+                &chars,
+                context,
+                &colors,
+            );
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| (0..width).map(|x| buffer[(x, y)].symbol()).collect())
+        .collect()
+}
+
+#[test]
+fn long_lines_soft_wrap_with_continuation_marker() {
+    let code = "let numbers = [one, two, three, four, five, six];";
+    let challenge = Challenge::new("wrap".to_string(), code.to_string());
+    let typing_core = TypingCore::from_challenge(&challenge, Some(ProcessingOptions::default()));
+    let mut view = TypingContentView::new();
+
+    let rows = render_to_text(
+        &mut view,
+        &challenge,
+        &typing_core,
+        &CodeContext::default(),
+        31,
+        12,
+    );
+
+    let code_rows: Vec<&String> = rows.iter().filter(|row| row.contains(" │ ")).collect();
+    assert!(code_rows[0].contains("   1 │ let numbers = [one,"));
+    assert!(code_rows[1].contains("   ↪ │ two, three, four, fi"));
+    assert!(code_rows[2].contains("   ↪ │ ve, six];"));
+}
+
+#[test]
+fn context_lines_soft_wrap_too() {
+    let code = "x";
+    let challenge = Challenge::new("wrap-context".to_string(), code.to_string()).with_source_info(
+        "src/lib.rs".to_string(),
+        2,
+        2,
+    );
+    let typing_core = TypingCore::from_challenge(&challenge, Some(ProcessingOptions::default()));
+    let context = CodeContext {
+        pre_context: vec!["// a comment that is far too long".to_string()],
+        post_context: Vec::new(),
+    };
+    let mut view = TypingContentView::new();
+
+    let rows = render_to_text(&mut view, &challenge, &typing_core, &context, 31, 12);
+
+    let output = rows.join("\n");
+    assert!(output.contains("   1 │ // a comment that is"));
+    assert!(output.contains("   ↪ │  far too long"));
+}
+
+#[test]
+fn scroll_follows_cursor_onto_wrapped_rows() {
+    let code = (0..12)
+        .map(|segment| format!("seg{:02}_abcdefghijkl", segment))
+        .collect::<String>();
+    let challenge = Challenge::new("wrap-scroll".to_string(), code.clone());
+    let mut typing_core =
+        TypingCore::from_challenge(&challenge, Some(ProcessingOptions::default()));
+    for ch in code.chars().take(20 * 10) {
+        typing_core.process_character_input(ch);
+    }
+    let mut view = TypingContentView::new();
+
+    let rows = render_to_text(
+        &mut view,
+        &challenge,
+        &typing_core,
+        &CodeContext::default(),
+        31,
+        12,
+    );
+
+    let output = rows.join("\n");
+    assert!(output.contains("   ↪ │ seg10_abcdefghijkl"));
+    assert!(!output.contains("seg00"));
+}
@@ -1,9 +1,21 @@
+pub mod api;
+pub mod cli_export_command_tests;
+pub mod cli_gc_command_tests;
+pub mod cli_hooks_command_tests;
+pub mod cli_import_command_tests;
+pub mod cli_pack_command_tests;
+pub mod cli_purge_command_tests;
 pub mod cli_repo_command_tests;
 pub mod cli_runner_tests;
 pub mod cli_screen_runner_tests;
+pub mod cli_status_command_tests;
+pub mod cli_telemetry_command_tests;
 pub mod cli_trending_tests;
+pub mod cli_warm_command_tests;
 pub mod game;
+pub mod rpc;
 pub mod sharing_tests;
 pub mod signal_handler_tests;
+pub mod spectator;
 pub mod tui;
 pub mod ui;
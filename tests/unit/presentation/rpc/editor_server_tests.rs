@@ -0,0 +1,149 @@
+use gittype::presentation::rpc::protocol::{
+    INVALID_PARAMS, METHOD_NOT_FOUND, PARSE_ERROR, SERVER_ERROR,
+};
+use gittype::presentation::rpc::{EditorServer, RpcResponse};
+use serde_json::{json, Value};
+
+fn call(server: &mut EditorServer, id: u64, method: &str, params: Value) -> RpcResponse {
+    let request = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+    let line = server.handle_line(&request.to_string()).unwrap();
+    serde_json::from_str(&line).unwrap()
+}
+
+#[test]
+fn initialize_reports_name_and_methods() {
+    let mut server = EditorServer::new();
+
+    let response = call(&mut server, 1, "initialize", Value::Null);
+
+    assert_eq!(response.id, json!(1));
+    let result = response.result.unwrap();
+    assert_eq!(result["name"], "gittype");
+    assert!(result["methods"]
+        .as_array()
+        .unwrap()
+        .contains(&json!("challenge/load")));
+}
+
+#[test]
+fn full_challenge_round_trip() {
+    let mut server = EditorServer::new();
+
+    let loaded = call(
+        &mut server,
+        1,
+        "challenge/load",
+        json!({ "text": "x = 1", "language": "python" }),
+    )
+    .result
+    .unwrap();
+    assert_eq!(loaded["textToType"], "x = 1");
+    assert_eq!(loaded["language"], "python");
+    assert_eq!(loaded["characters"], 5);
+
+    call(&mut server, 2, "challenge/start", Value::Null);
+    let progress = call(
+        &mut server,
+        3,
+        "challenge/input",
+        json!({ "text": "x = 1" }),
+    )
+    .result
+    .unwrap();
+    assert_eq!(progress["completed"], true);
+
+    let result = call(&mut server, 4, "challenge/finish", Value::Null)
+        .result
+        .unwrap();
+    assert_eq!(result["mistakes"], 0);
+    assert_eq!(result["skipped"], false);
+    assert!(result["rank"].is_string());
+}
+
+#[test]
+fn load_reads_line_range_from_file() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let path = temp_dir.path().join("sample.py");
+    std::fs::write(&path, "a = 1\nb = 2\nc = 3\n").unwrap();
+    let mut server = EditorServer::new();
+
+    let loaded = call(
+        &mut server,
+        1,
+        "challenge/load",
+        json!({ "path": path, "startLine": 2, "endLine": 2 }),
+    )
+    .result
+    .unwrap();
+
+    assert_eq!(loaded["textToType"], "b = 2");
+    assert_eq!(loaded["language"], "python");
+}
+
+#[test]
+fn load_without_text_or_path_is_server_error() {
+    let mut server = EditorServer::new();
+
+    let response = call(&mut server, 1, "challenge/load", json!({}));
+
+    assert_eq!(response.error.unwrap().code, SERVER_ERROR);
+}
+
+#[test]
+fn input_with_missing_params_is_invalid_params() {
+    let mut server = EditorServer::new();
+
+    let response = call(&mut server, 1, "challenge/input", json!({}));
+
+    assert_eq!(response.error.unwrap().code, INVALID_PARAMS);
+}
+
+#[test]
+fn unknown_method_is_method_not_found() {
+    let mut server = EditorServer::new();
+
+    let response = call(&mut server, 7, "challenge/unknown", Value::Null);
+
+    assert_eq!(response.id, json!(7));
+    assert_eq!(response.error.unwrap().code, METHOD_NOT_FOUND);
+}
+
+#[test]
+fn malformed_json_is_parse_error() {
+    let mut server = EditorServer::new();
+
+    let line = server.handle_line("{not json").unwrap();
+    let response: RpcResponse = serde_json::from_str(&line).unwrap();
+
+    assert_eq!(response.id, Value::Null);
+    assert_eq!(response.error.unwrap().code, PARSE_ERROR);
+}
+
+#[test]
+fn notifications_get_no_response() {
+    let mut server = EditorServer::new();
+
+    let line = server.handle_line(r#"{"jsonrpc":"2.0","method":"shutdown"}"#);
+
+    assert!(line.is_none());
+}
+
+#[test]
+fn run_stops_after_exit() {
+    let input = concat!(
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize"}"#,
+        "\n",
+        r#"{"jsonrpc":"2.0","method":"exit"}"#,
+        "\n",
+        r#"{"jsonrpc":"2.0","id":2,"method":"initialize"}"#,
+        "\n",
+    );
+    let mut output = Vec::new();
+    let mut server = EditorServer::new();
+
+    server.run(input.as_bytes(), &mut output).unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(output.lines().count(), 1);
+    assert!(server.has_exited());
+}
@@ -0,0 +1 @@
+pub mod editor_server_tests;
@@ -1,6 +1,8 @@
 use std::collections::HashSet;
 
-use gittype::domain::models::{GitRepository, SessionResult};
+use chrono::Utc;
+use gittype::domain::models::storage::RepositoryMetadata;
+use gittype::domain::models::{GitRepository, PlayerProfile, ResultPlausibility, SessionResult};
 use gittype::presentation::sharing::{SharingPlatform, SharingService};
 
 #[test]
@@ -50,6 +52,7 @@ fn make_repo() -> GitRepository {
         commit_hash: Some("abc".to_string()),
         is_dirty: false,
         root_path: None,
+        license: None,
     }
 }
 
@@ -59,7 +62,7 @@ fn make_repo() -> GitRepository {
 #[test]
 fn create_share_text_without_repo() {
     let metrics = make_metrics(150.0, 300.0, 3, 2);
-    let text = SharingService::create_share_text(&metrics, &None);
+    let text = SharingService::create_share_text(&metrics, &None, &None, &None);
 
     assert!(text.contains("150"), "should contain score");
     assert!(text.contains("300"), "should contain cpm");
@@ -77,7 +80,7 @@ fn create_share_text_without_repo() {
 fn create_share_text_with_repo() {
     let metrics = make_metrics(200.0, 400.0, 1, 0);
     let repo = make_repo();
-    let text = SharingService::create_share_text(&metrics, &Some(repo));
+    let text = SharingService::create_share_text(&metrics, &Some(repo), &None, &None);
 
     assert!(text.contains("200"), "should contain score");
     assert!(text.contains("400"), "should contain cpm");
@@ -88,13 +91,50 @@ fn create_share_text_with_repo() {
     assert!(text.contains("#gittype"));
 }
 
+#[test]
+fn create_share_text_with_repo_metadata_includes_stars_and_language() {
+    let metrics = make_metrics(200.0, 400.0, 1, 0);
+    let metadata = RepositoryMetadata {
+        description: Some("A test repository".to_string()),
+        stars: 1234,
+        default_branch: Some("main".to_string()),
+        primary_language: Some("Rust".to_string()),
+        fetched_at: Utc::now(),
+    };
+    let text =
+        SharingService::create_share_text(&metrics, &Some(make_repo()), &Some(metadata), &None);
+
+    assert!(text.contains("[testuser/testrepo (main@abc) ★ 1.2k · Rust]"));
+}
+
+#[test]
+fn create_share_text_with_repo_credits_its_license() {
+    let metrics = make_metrics(200.0, 400.0, 1, 0);
+    let repo = GitRepository {
+        license: Some("MIT".to_string()),
+        ..make_repo()
+    };
+    let text = SharingService::create_share_text(&metrics, &Some(repo), &None, &None);
+
+    assert!(text.contains("\nCode by testuser/testrepo contributors, MIT license"));
+}
+
+#[test]
+fn create_share_text_without_repo_has_no_attribution() {
+    let metrics = make_metrics(200.0, 400.0, 1, 0);
+    let text = SharingService::create_share_text(&metrics, &None, &None, &None);
+
+    assert!(!text.contains("Code by"));
+}
+
 // ---------------------------------------------------------------------------
 // generate_share_url tests — one per platform
 // ---------------------------------------------------------------------------
 #[test]
 fn generate_share_url_x() {
     let metrics = make_metrics(100.0, 250.0, 2, 1);
-    let url = SharingService::generate_share_url(&metrics, &SharingPlatform::X, &None);
+    let url =
+        SharingService::generate_share_url(&metrics, &SharingPlatform::X, &None, &None, &None);
     assert!(url.starts_with("https://x.com/intent/tweet?text="));
     assert!(url.contains("gittype"));
 }
@@ -102,7 +142,8 @@ fn generate_share_url_x() {
 #[test]
 fn generate_share_url_reddit() {
     let metrics = make_metrics(100.0, 250.0, 2, 1);
-    let url = SharingService::generate_share_url(&metrics, &SharingPlatform::Reddit, &None);
+    let url =
+        SharingService::generate_share_url(&metrics, &SharingPlatform::Reddit, &None, &None, &None);
     assert!(url.starts_with("https://www.reddit.com/submit?"));
     assert!(url.contains("title="));
     assert!(url.contains("selftext=true"));
@@ -112,7 +153,13 @@ fn generate_share_url_reddit() {
 #[test]
 fn generate_share_url_linkedin() {
     let metrics = make_metrics(100.0, 250.0, 2, 1);
-    let url = SharingService::generate_share_url(&metrics, &SharingPlatform::LinkedIn, &None);
+    let url = SharingService::generate_share_url(
+        &metrics,
+        &SharingPlatform::LinkedIn,
+        &None,
+        &None,
+        &None,
+    );
     assert!(url.starts_with("https://www.linkedin.com/feed/"));
     assert!(url.contains("shareActive=true"));
 }
@@ -120,7 +167,13 @@ fn generate_share_url_linkedin() {
 #[test]
 fn generate_share_url_facebook() {
     let metrics = make_metrics(100.0, 250.0, 2, 1);
-    let url = SharingService::generate_share_url(&metrics, &SharingPlatform::Facebook, &None);
+    let url = SharingService::generate_share_url(
+        &metrics,
+        &SharingPlatform::Facebook,
+        &None,
+        &None,
+        &None,
+    );
     assert!(url.starts_with("https://www.facebook.com/sharer/"));
     assert!(url.contains("quote="));
 }
@@ -129,7 +182,13 @@ fn generate_share_url_facebook() {
 fn generate_share_url_x_with_repo() {
     let metrics = make_metrics(300.0, 600.0, 0, 0);
     let repo = make_repo();
-    let url = SharingService::generate_share_url(&metrics, &SharingPlatform::X, &Some(repo));
+    let url = SharingService::generate_share_url(
+        &metrics,
+        &SharingPlatform::X,
+        &Some(repo),
+        &None,
+        &None,
+    );
     assert!(url.starts_with("https://x.com/intent/tweet?text="));
     // URL-encoded repo name should be present
     assert!(url.contains("testuser"));
@@ -139,7 +198,13 @@ fn generate_share_url_x_with_repo() {
 fn generate_share_url_reddit_with_repo() {
     let metrics = make_metrics(300.0, 600.0, 0, 0);
     let repo = make_repo();
-    let url = SharingService::generate_share_url(&metrics, &SharingPlatform::Reddit, &Some(repo));
+    let url = SharingService::generate_share_url(
+        &metrics,
+        &SharingPlatform::Reddit,
+        &Some(repo),
+        &None,
+        &None,
+    );
     assert!(url.contains("reddit.com"));
     assert!(url.contains("title="));
 }
@@ -148,7 +213,13 @@ fn generate_share_url_reddit_with_repo() {
 fn generate_share_url_linkedin_with_repo() {
     let metrics = make_metrics(300.0, 600.0, 0, 0);
     let repo = make_repo();
-    let url = SharingService::generate_share_url(&metrics, &SharingPlatform::LinkedIn, &Some(repo));
+    let url = SharingService::generate_share_url(
+        &metrics,
+        &SharingPlatform::LinkedIn,
+        &Some(repo),
+        &None,
+        &None,
+    );
     assert!(url.contains("linkedin.com"));
 }
 
@@ -156,7 +227,13 @@ fn generate_share_url_linkedin_with_repo() {
 fn generate_share_url_facebook_with_repo() {
     let metrics = make_metrics(300.0, 600.0, 0, 0);
     let repo = make_repo();
-    let url = SharingService::generate_share_url(&metrics, &SharingPlatform::Facebook, &Some(repo));
+    let url = SharingService::generate_share_url(
+        &metrics,
+        &SharingPlatform::Facebook,
+        &Some(repo),
+        &None,
+        &None,
+    );
     assert!(url.contains("facebook.com"));
 }
 
@@ -191,7 +268,7 @@ fn share_result_opens_browser_for_every_platform_without_repo() {
     let metrics = make_metrics(125.0, 270.0, 1, 4);
 
     for platform in SharingPlatform::all() {
-        let result = SharingService::share_result(&metrics, platform, &None);
+        let result = SharingService::share_result(&metrics, platform, &None, &None, &None);
         assert!(result.is_ok(), "share_result should succeed under mocks");
     }
 }
@@ -202,7 +279,8 @@ fn share_result_opens_browser_for_every_platform_with_repo() {
     let repo = make_repo();
 
     for platform in SharingPlatform::all() {
-        let result = SharingService::share_result(&metrics, platform, &Some(repo.clone()));
+        let result =
+            SharingService::share_result(&metrics, platform, &Some(repo.clone()), &None, &None);
         assert!(
             result.is_ok(),
             "share_result with repo should succeed under mocks"
@@ -216,7 +294,8 @@ fn share_result_opens_browser_for_every_platform_with_repo() {
 #[test]
 fn generate_share_url_x_encodes_text_payload() {
     let metrics = make_metrics(100.0, 250.0, 2, 1);
-    let url = SharingService::generate_share_url(&metrics, &SharingPlatform::X, &None);
+    let url =
+        SharingService::generate_share_url(&metrics, &SharingPlatform::X, &None, &None, &None);
 
     assert!(url.contains("text="));
     assert!(
@@ -229,7 +308,8 @@ fn generate_share_url_x_encodes_text_payload() {
 #[test]
 fn generate_share_url_reddit_includes_rank_name_in_title() {
     let metrics = make_metrics(0.0, 0.0, 0, 0);
-    let url = SharingService::generate_share_url(&metrics, &SharingPlatform::Reddit, &None);
+    let url =
+        SharingService::generate_share_url(&metrics, &SharingPlatform::Reddit, &None, &None, &None);
 
     let title_segment = url
         .split("title=")
@@ -245,7 +325,13 @@ fn generate_share_url_reddit_includes_rank_name_in_title() {
 #[test]
 fn generate_share_url_facebook_encodes_repo_link_separately_from_quote() {
     let metrics = make_metrics(10.0, 20.0, 0, 0);
-    let url = SharingService::generate_share_url(&metrics, &SharingPlatform::Facebook, &None);
+    let url = SharingService::generate_share_url(
+        &metrics,
+        &SharingPlatform::Facebook,
+        &None,
+        &None,
+        &None,
+    );
 
     let u_segment = url
         .split("u=")
@@ -264,3 +350,38 @@ fn generate_share_url_facebook_encodes_repo_link_separately_from_quote() {
     assert_eq!(decoded_u, "https://github.com/unhappychoice/gittype");
     assert!(decoded_quote.contains("gittype"));
 }
+
+#[test]
+fn create_share_text_with_profile_appends_signature() {
+    let metrics = make_metrics(200.0, 400.0, 1, 0);
+    let profile = PlayerProfile {
+        display_name: "Ada Lovelace".to_string(),
+        rank_title: Some("Hello World".to_string()),
+        favorite_language: Some("rust".to_string()),
+        current_streak_days: 3,
+        stages_completed: 12,
+        best_score: 200.0,
+    };
+    let text = SharingService::create_share_text(&metrics, &None, &None, &Some(profile));
+
+    assert!(text.contains("🚀\n— Ada Lovelace · Hello World · Rust · 🔥 3-day streak\n\n"));
+}
+
+#[test]
+fn create_share_text_with_verification_code_appends_it() {
+    let mut metrics = make_metrics(200.0, 400.0, 1, 0);
+    metrics.verification_code = Some("GT1-200-400-1-0000000100000002-abcdefabcdef".to_string());
+    let text = SharingService::create_share_text(&metrics, &None, &None, &None);
+
+    assert!(text.contains("🚀\nVerify: GT1-200-400-1-0000000100000002-abcdefabcdef\n\n"));
+}
+
+#[test]
+fn create_share_text_marks_unverified_results() {
+    let mut metrics = make_metrics(200.0, 400.0, 1, 0);
+    metrics.plausibility = ResultPlausibility::assess(&[], 2);
+    let text = SharingService::create_share_text(&metrics, &None, &None, &None);
+
+    assert!(text.contains("🚀\nUnverified (pasted text)\n\n"));
+    assert!(!text.contains("Verify:"));
+}
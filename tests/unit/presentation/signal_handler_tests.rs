@@ -1,4 +1,8 @@
-use gittype::presentation::signal_handler::cleanup_panic_terminal_for_test;
+use gittype::domain::services::session_manager_service::SessionManagerInterface;
+use gittype::presentation::signal_handler::{
+    cleanup_panic_terminal_for_test, persist_interrupted_session,
+};
+use std::sync::Arc;
 
 #[test]
 fn cleanup_panic_terminal_ignores_missing_terminal_state() {
@@ -11,3 +15,17 @@ fn cleanup_panic_terminal_handles_initialized_terminal_state() {
     cleanup_panic_terminal_for_test(true, false);
     cleanup_panic_terminal_for_test(true, true);
 }
+
+struct FakeSessionManager;
+
+impl SessionManagerInterface for FakeSessionManager {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[test]
+fn persist_interrupted_session_ignores_non_concrete_session_manager() {
+    let session_manager: Arc<dyn SessionManagerInterface> = Arc::new(FakeSessionManager);
+    persist_interrupted_session(&session_manager);
+}
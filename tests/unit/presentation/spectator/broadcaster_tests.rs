@@ -0,0 +1,80 @@
+use gittype::presentation::spectator::{SpectatorBroadcaster, SpectatorFrame};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use std::io::{BufRead, BufReader};
+use std::net::TcpStream;
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn frame(text: &str) -> SpectatorFrame {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+    buffer.set_string(0, 0, text, Style::default());
+    SpectatorFrame::capture(&buffer)
+}
+
+fn connect(broadcaster: &SpectatorBroadcaster) -> BufReader<TcpStream> {
+    let stream = TcpStream::connect(broadcaster.local_addr()).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    BufReader::new(stream)
+}
+
+fn wait_for_spectators(broadcaster: &SpectatorBroadcaster, count: usize) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while broadcaster.spectator_count() < count {
+        assert!(Instant::now() < deadline, "spectator was not accepted");
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+fn read_frame(reader: &mut BufReader<TcpStream>) -> SpectatorFrame {
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    SpectatorFrame::from_json_line(&line).unwrap()
+}
+
+#[test]
+fn bind_reports_addresses_in_use() {
+    let broadcaster = SpectatorBroadcaster::bind("127.0.0.1:0").unwrap();
+
+    let error = SpectatorBroadcaster::bind(&broadcaster.local_addr().to_string())
+        .err()
+        .unwrap();
+
+    assert!(error.to_string().contains("Failed to start the broadcast"));
+}
+
+#[test]
+fn publish_sends_frames_to_connected_spectators() {
+    let broadcaster = SpectatorBroadcaster::bind("127.0.0.1:0").unwrap();
+    let mut spectator = connect(&broadcaster);
+    wait_for_spectators(&broadcaster, 1);
+
+    broadcaster.publish(&frame("let x")).unwrap();
+
+    assert_eq!(read_frame(&mut spectator), frame("let x"));
+}
+
+#[test]
+fn late_spectators_receive_the_latest_frame_first() {
+    let broadcaster = SpectatorBroadcaster::bind("127.0.0.1:0").unwrap();
+    broadcaster.publish(&frame("fn main")).unwrap();
+
+    let mut spectator = connect(&broadcaster);
+
+    assert_eq!(read_frame(&mut spectator), frame("fn main"));
+}
+
+#[test]
+fn publish_skips_frames_sent_too_soon() {
+    let broadcaster = SpectatorBroadcaster::bind("127.0.0.1:0").unwrap();
+    broadcaster.publish(&frame("first")).unwrap();
+
+    assert!(!broadcaster.is_due());
+    broadcaster.publish(&frame("second")).unwrap();
+
+    let mut spectator = connect(&broadcaster);
+    assert_eq!(read_frame(&mut spectator), frame("first"));
+}
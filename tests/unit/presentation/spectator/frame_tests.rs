@@ -0,0 +1,60 @@
+use gittype::presentation::spectator::SpectatorFrame;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+
+fn typed_buffer() -> Buffer {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 3));
+    buffer.set_string(
+        0,
+        0,
+        "fn main",
+        Style::default()
+            .fg(Color::Green)
+            .add_modifier(Modifier::BOLD),
+    );
+    buffer.set_string(7, 0, "() {}", Style::default().fg(Color::Rgb(30, 30, 30)));
+    buffer.set_string(2, 2, "WPM 72", Style::default().bg(Color::Indexed(42)));
+    buffer
+}
+
+#[test]
+fn capture_keeps_text_and_styles_as_runs() {
+    let frame = SpectatorFrame::capture(&typed_buffer());
+
+    assert_eq!((frame.width, frame.height), (20, 3));
+    assert_eq!(frame.rows.len(), 3);
+    assert_eq!(frame.rows[0].len(), 2);
+    assert_eq!(frame.rows[0][0].text, "fn main");
+    assert_eq!(frame.rows[0][0].fg, "Green");
+    assert_eq!(frame.rows[0][0].modifier, Modifier::BOLD.bits());
+    assert_eq!(frame.rows[0][1].fg, "#1E1E1E");
+    assert!(frame.rows[1].is_empty());
+}
+
+#[test]
+fn frames_round_trip_through_json_and_redraw_identically() {
+    let original = typed_buffer();
+    let line = SpectatorFrame::capture(&original).to_json_line().unwrap();
+    assert!(line.ends_with('\n'));
+    assert_eq!(line.matches('\n').count(), 1);
+
+    let frame = SpectatorFrame::from_json_line(&line).unwrap();
+    let mut redrawn = Buffer::empty(original.area);
+    frame.render(&mut redrawn, original.area);
+
+    assert_eq!(redrawn, original);
+}
+
+#[test]
+fn render_clips_frames_larger_than_the_area() {
+    let frame = SpectatorFrame::capture(&typed_buffer());
+    let area = Rect::new(0, 0, 4, 1);
+    let mut small = Buffer::empty(area);
+
+    frame.render(&mut small, area);
+
+    let text: String = (0..4).map(|x| small[(x, 0)].symbol()).collect();
+    assert_eq!(text, "fn m");
+    assert_eq!(small[(0, 0)].fg, Color::Green);
+}
@@ -0,0 +1,3 @@
+pub mod broadcaster_tests;
+pub mod frame_tests;
+pub mod viewer_tests;
@@ -0,0 +1,67 @@
+use gittype::presentation::spectator::{SpectatorFrame, SpectatorViewer};
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::Terminal;
+
+fn draw(viewer: &SpectatorViewer) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(60, 4)).unwrap();
+    terminal.draw(|frame| viewer.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..buffer.area.height)
+        .map(|y| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn frame_line(text: &str) -> String {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 2));
+    buffer.set_string(0, 0, text, Style::default());
+    SpectatorFrame::capture(&buffer).to_json_line().unwrap()
+}
+
+#[test]
+fn shows_a_waiting_message_before_the_first_frame() {
+    let viewer = SpectatorViewer::new();
+
+    assert!(draw(&viewer).contains("Waiting for the player to start typing"));
+}
+
+#[test]
+fn mirrors_the_latest_frame() {
+    let mut viewer = SpectatorViewer::new();
+
+    viewer.handle_line(&frame_line("fn main() {}"));
+    viewer.handle_line(&frame_line("fn main() { run }"));
+
+    let screen = draw(&viewer);
+    assert!(screen.starts_with("fn main() { run }"));
+    assert!(!screen.contains("Waiting"));
+}
+
+#[test]
+fn ignores_malformed_lines() {
+    let mut viewer = SpectatorViewer::new();
+    viewer.handle_line(&frame_line("let x = 1;"));
+
+    viewer.handle_line("{not json");
+
+    assert!(draw(&viewer).starts_with("let x = 1;"));
+}
+
+#[test]
+fn keeps_the_last_frame_when_the_broadcast_ends() {
+    let mut viewer = SpectatorViewer::new();
+    viewer.handle_line(&frame_line("let x = 1;"));
+
+    viewer.end();
+
+    let screen = draw(&viewer);
+    assert!(screen.starts_with("let x = 1;"));
+    assert!(screen.contains("The broadcast has ended"));
+}
@@ -46,6 +46,9 @@ fn analytics_data_with_repository_summary() -> AnalyticsData {
         repository_stats: HashMap::new(),
         language_stats: HashMap::new(),
         reference_date: None,
+        prose_baseline: None,
+        finger_load: Default::default(),
+        language_calibrations: Default::default(),
     }
 }
 
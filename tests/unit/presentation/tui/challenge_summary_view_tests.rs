@@ -0,0 +1,85 @@
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::color_scheme::{ColorScheme, ThemeFile};
+use gittype::domain::models::DifficultyLevel;
+use gittype::presentation::tui::views::title::ChallengeSummaryView;
+use gittype::presentation::ui::colors::Colors;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::Terminal;
+
+const DIFFICULTIES: [(&str, DifficultyLevel); 5] = [
+    ("Easy", DifficultyLevel::Easy),
+    ("Normal", DifficultyLevel::Normal),
+    ("Hard", DifficultyLevel::Hard),
+    ("Wild", DifficultyLevel::Wild),
+    ("Zen", DifficultyLevel::Zen),
+];
+
+fn default_colors() -> Colors {
+    let json = include_str!("../../../../assets/themes/default.json");
+    let theme: ThemeFile = serde_json::from_str(json).unwrap();
+    Colors::new(ColorScheme::from_theme_file(&theme, &ColorMode::Dark))
+}
+
+fn buffer_text(buffer: &Buffer) -> String {
+    (0..buffer.area.height)
+        .map(|row| {
+            (0..buffer.area.width)
+                .map(|column| buffer[(column, row)].symbol().to_string())
+                .collect::<Vec<_>>()
+                .join("")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render(challenge_counts: [usize; 5], language_counts: &[(String, usize)]) -> String {
+    let colors = default_colors();
+    let mut terminal = Terminal::new(TestBackend::new(100, 2)).unwrap();
+    terminal
+        .draw(|frame| {
+            ChallengeSummaryView::render(
+                frame,
+                frame.area(),
+                &DIFFICULTIES,
+                &challenge_counts,
+                language_counts,
+                &colors,
+            );
+        })
+        .unwrap();
+    buffer_text(terminal.backend().buffer())
+}
+
+fn languages(counts: &[(&str, usize)]) -> Vec<(String, usize)> {
+    counts
+        .iter()
+        .map(|(language, count)| (language.to_string(), *count))
+        .collect()
+}
+
+#[test]
+fn shows_the_total_languages_and_difficulties() {
+    let output = render([3, 5, 2, 0, 1], &languages(&[("Rust", 8), ("Go", 3)]));
+
+    assert!(output.contains("11 challenges · Rust 8 · Go 3"));
+    assert!(output.contains("Easy 3 · Normal 5 · Hard 2 · Wild 0 · Zen 1"));
+}
+
+#[test]
+fn folds_the_least_common_languages() {
+    let output = render(
+        [10, 0, 0, 0, 0],
+        &languages(&[("Rust", 4), ("Go", 2), ("Ruby", 2), ("C", 1), ("Lua", 1)]),
+    );
+
+    assert!(output.contains("Rust 4 · Go 2 · Ruby 2 · C 1 · +1 more"));
+    assert!(!output.contains("Lua"));
+}
+
+#[test]
+fn renders_nothing_before_loading() {
+    let output = render([0; 5], &[]);
+
+    assert!(output.trim().is_empty());
+}
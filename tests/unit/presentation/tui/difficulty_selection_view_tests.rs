@@ -1,6 +1,6 @@
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::color_scheme::{ColorScheme, ThemeFile};
-use gittype::domain::models::DifficultyLevel;
+use gittype::domain::models::{DifficultyLevel, DifficultyLock};
 use gittype::presentation::tui::views::title::DifficultySelectionView;
 use gittype::presentation::ui::colors::Colors;
 use ratatui::backend::TestBackend;
@@ -48,6 +48,7 @@ fn render_zero_count_with_error_message() {
                 &difficulties,
                 0,
                 &challenge_counts,
+                None,
                 Some(&error),
                 &colors,
             );
@@ -62,3 +63,45 @@ fn render_zero_count_with_error_message() {
     assert!(output.contains("No challenges available for Easy"));
     assert!(!output.contains("Short code snippets"));
 }
+
+#[test]
+fn render_locked_difficulty_shows_unlock_requirement() {
+    let colors = default_colors();
+    let difficulties = [
+        ("Easy", DifficultyLevel::Easy),
+        ("Normal", DifficultyLevel::Normal),
+        ("Hard", DifficultyLevel::Hard),
+        ("Wild", DifficultyLevel::Wild),
+        ("Zen", DifficultyLevel::Zen),
+    ];
+    let lock = DifficultyLock {
+        difficulty: DifficultyLevel::Hard,
+        requires: DifficultyLevel::Normal,
+        required_accuracy: 95.0,
+        best_accuracy: Some(91.25),
+    };
+    let backend = TestBackend::new(100, 8);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    terminal
+        .draw(|frame| {
+            DifficultySelectionView::render(
+                frame,
+                frame.area(),
+                &difficulties,
+                2,
+                &[10, 25, 40, 30, 15],
+                Some(&lock),
+                None,
+                &colors,
+            );
+        })
+        .unwrap();
+
+    let output = buffer_text(terminal.backend().buffer());
+
+    assert!(output.contains("🔒"));
+    assert!(output.contains("Reach 95% accuracy on Normal to unlock Hard (best so far 91.2%)"));
+    assert!(output.contains("~500 characters"));
+    assert!(!output.contains("Long functions or classes"));
+}
@@ -0,0 +1,68 @@
+use gittype::presentation::tui::FrameLimiter;
+use std::time::{Duration, Instant};
+
+#[test]
+fn max_fps_sets_the_frame_interval() {
+    assert_eq!(
+        FrameLimiter::new(50).frame_interval(),
+        Duration::from_millis(20)
+    );
+    assert_eq!(FrameLimiter::new(0).frame_interval(), Duration::ZERO);
+}
+
+#[test]
+fn nothing_is_due_without_a_request() {
+    let limiter = FrameLimiter::new(60);
+
+    assert!(!limiter.is_pending());
+    assert!(!limiter.is_due(Instant::now()));
+    assert_eq!(limiter.time_until_due(Instant::now()), None);
+}
+
+#[test]
+fn first_requested_frame_is_due_at_once() {
+    let mut limiter = FrameLimiter::new(60);
+
+    limiter.request();
+
+    assert!(limiter.is_due(Instant::now()));
+}
+
+#[test]
+fn frame_requested_within_the_interval_waits_for_it() {
+    let mut limiter = FrameLimiter::new(50);
+    let start = Instant::now();
+    limiter.frame_drawn(start);
+
+    limiter.request();
+    limiter.request();
+
+    let soon = start + Duration::from_millis(5);
+    assert!(!limiter.is_due(soon));
+    assert_eq!(
+        limiter.time_until_due(soon),
+        Some(Duration::from_millis(15))
+    );
+    assert!(limiter.is_due(start + Duration::from_millis(20)));
+}
+
+#[test]
+fn drawing_a_frame_satisfies_the_request() {
+    let mut limiter = FrameLimiter::new(60);
+    limiter.request();
+
+    limiter.frame_drawn(Instant::now());
+
+    assert!(!limiter.is_pending());
+}
+
+#[test]
+fn unlimited_frames_are_always_due() {
+    let mut limiter = FrameLimiter::new(0);
+    let now = Instant::now();
+    limiter.frame_drawn(now);
+
+    limiter.request();
+
+    assert!(limiter.is_due(now));
+}
@@ -0,0 +1,162 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use gittype::presentation::tui::KeyInputFilter;
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+    KeyEvent::new(code, modifiers)
+}
+
+fn key_with_kind(code: KeyCode, kind: KeyEventKind) -> KeyEvent {
+    KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+        kind,
+        state: KeyEventState::NONE,
+    }
+}
+
+#[test]
+fn press_passes_through_unchanged() {
+    let event = key(KeyCode::Char('a'), KeyModifiers::NONE);
+
+    assert_eq!(KeyInputFilter::accept(event), Some(event));
+}
+
+#[test]
+fn release_and_repeat_events_are_dropped() {
+    assert_eq!(
+        KeyInputFilter::accept(key_with_kind(KeyCode::Char('a'), KeyEventKind::Release)),
+        None
+    );
+    assert_eq!(
+        KeyInputFilter::accept(key_with_kind(KeyCode::Char('a'), KeyEventKind::Repeat)),
+        None
+    );
+}
+
+#[test]
+fn press_followed_by_release_yields_one_character() {
+    let accepted: Vec<KeyEvent> = [
+        key_with_kind(KeyCode::Char('x'), KeyEventKind::Press),
+        key_with_kind(KeyCode::Char('x'), KeyEventKind::Release),
+        key_with_kind(KeyCode::Char('x'), KeyEventKind::Release),
+    ]
+    .into_iter()
+    .filter_map(KeyInputFilter::accept)
+    .collect();
+
+    assert_eq!(accepted.len(), 1);
+}
+
+#[test]
+fn repeated_keystrokes_read_back_to_back_are_kept() {
+    let event = key(KeyCode::Char('l'), KeyModifiers::NONE);
+
+    assert_eq!(KeyInputFilter::accept(event), Some(event));
+    assert_eq!(KeyInputFilter::accept(event), Some(event));
+}
+
+#[cfg(windows)]
+#[test]
+fn altgr_character_is_plain_text() {
+    let accepted = KeyInputFilter::accept(key(
+        KeyCode::Char('@'),
+        KeyModifiers::CONTROL | KeyModifiers::ALT,
+    ))
+    .unwrap();
+
+    assert_eq!(accepted.code, KeyCode::Char('@'));
+    assert_eq!(accepted.modifiers, KeyModifiers::NONE);
+}
+
+#[cfg(windows)]
+#[test]
+fn altgr_keeps_shift() {
+    let accepted = KeyInputFilter::accept(key(
+        KeyCode::Char('Ł'),
+        KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT,
+    ))
+    .unwrap();
+
+    assert_eq!(accepted.modifiers, KeyModifiers::SHIFT);
+}
+
+#[cfg(not(windows))]
+#[test]
+fn ctrl_alt_bindings_are_kept_outside_windows() {
+    let event = key(
+        KeyCode::Char('t'),
+        KeyModifiers::CONTROL | KeyModifiers::ALT,
+    );
+
+    assert_eq!(KeyInputFilter::accept(event), Some(event));
+}
+
+#[test]
+fn ctrl_c_is_left_alone() {
+    let event = key(KeyCode::Char('c'), KeyModifiers::CONTROL);
+
+    assert_eq!(KeyInputFilter::accept(event), Some(event));
+}
+
+#[test]
+fn legacy_console_control_characters_become_named_keys() {
+    let cases = [
+        ('\r', KeyCode::Enter),
+        ('\n', KeyCode::Enter),
+        ('\t', KeyCode::Tab),
+        ('\u{8}', KeyCode::Backspace),
+        ('\u{7f}', KeyCode::Backspace),
+        ('\u{1b}', KeyCode::Esc),
+    ];
+
+    for (c, expected) in cases {
+        let accepted = KeyInputFilter::accept(key(KeyCode::Char(c), KeyModifiers::NONE)).unwrap();
+        assert_eq!(accepted.code, expected, "{:?}", c);
+    }
+}
+
+#[test]
+fn legacy_console_ctrl_letter_becomes_ctrl_modifier() {
+    let accepted = KeyInputFilter::accept(key(KeyCode::Char('\u{3}'), KeyModifiers::NONE)).unwrap();
+
+    assert_eq!(accepted.code, KeyCode::Char('c'));
+    assert!(accepted.modifiers.contains(KeyModifiers::CONTROL));
+}
+
+fn burst(text: &str) -> Vec<KeyEvent> {
+    text.chars()
+        .map(|c| match c {
+            '\n' => key(KeyCode::Enter, KeyModifiers::NONE),
+            c if c.is_ascii_uppercase() => key(KeyCode::Char(c), KeyModifiers::SHIFT),
+            c => key(KeyCode::Char(c), KeyModifiers::NONE),
+        })
+        .collect()
+}
+
+#[test]
+fn long_text_burst_is_a_paste() {
+    let code = "fn Main() {\n    println!(\"hello\");\n}\n";
+
+    assert_eq!(
+        KeyInputFilter::paste_text(&burst(code)),
+        Some(code.to_string())
+    );
+}
+
+#[test]
+fn keys_bunched_up_by_a_stall_are_typed_keys() {
+    assert_eq!(KeyInputFilter::paste_text(&burst("fn main() {}")), None);
+}
+
+#[test]
+fn burst_with_control_keys_is_not_a_paste() {
+    let mut keys = burst("let value = compute_the_answer(41) + 1;");
+    keys.push(key(KeyCode::Char('c'), KeyModifiers::CONTROL));
+
+    assert_eq!(KeyInputFilter::paste_text(&keys), None);
+
+    let mut keys = burst("let value = compute_the_answer(41) + 1;");
+    keys.insert(3, key(KeyCode::Backspace, KeyModifiers::NONE));
+
+    assert_eq!(KeyInputFilter::paste_text(&keys), None);
+}
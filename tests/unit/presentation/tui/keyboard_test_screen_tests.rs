@@ -0,0 +1,104 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use gittype::domain::services::config_service::ConfigServiceInterface;
+use gittype::presentation::di::AppModule;
+use gittype::presentation::tui::screens::keyboard_test_screen::KeyboardTestScreen;
+use gittype::presentation::tui::screens::KeyboardTestScreenInterface;
+use gittype::presentation::tui::Screen;
+use shaku::HasComponent;
+use std::sync::Arc;
+use std::time::Duration;
+
+fn describe(code: KeyCode, modifiers: KeyModifiers) -> String {
+    KeyboardTestScreen::describe_key(&KeyEvent::new(code, modifiers))
+}
+
+fn with_keyboard_test(test: impl FnOnce(&KeyboardTestScreen, &AppModule)) {
+    let container = AppModule::builder().build();
+    let screen: Arc<dyn KeyboardTestScreenInterface> = container.resolve();
+    let keyboard_test = screen
+        .as_any()
+        .downcast_ref::<KeyboardTestScreen>()
+        .unwrap();
+    keyboard_test.init_with_data(Box::new(())).unwrap();
+    test(keyboard_test, &container);
+}
+
+#[test]
+fn describes_plain_and_modified_keys() {
+    assert_eq!(describe(KeyCode::Char('a'), KeyModifiers::empty()), "a");
+    assert_eq!(describe(KeyCode::Char('A'), KeyModifiers::SHIFT), "A");
+    assert_eq!(describe(KeyCode::Char(' '), KeyModifiers::empty()), "Space");
+    assert_eq!(
+        describe(KeyCode::Char('w'), KeyModifiers::CONTROL),
+        "Ctrl+W"
+    );
+    assert_eq!(
+        describe(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT
+        ),
+        "Ctrl+Alt+C"
+    );
+    assert_eq!(describe(KeyCode::BackTab, KeyModifiers::SHIFT), "Shift+Tab");
+    assert_eq!(describe(KeyCode::Left, KeyModifiers::CONTROL), "Ctrl+←");
+    assert_eq!(
+        describe(KeyCode::Backspace, KeyModifiers::ALT),
+        "Alt+Backspace"
+    );
+    assert_eq!(describe(KeyCode::F(5), KeyModifiers::empty()), "F5");
+}
+
+#[test]
+fn logs_newest_key_first() {
+    with_keyboard_test(|keyboard_test, _| {
+        keyboard_test
+            .handle_key_event(KeyEvent::new(KeyCode::Tab, KeyModifiers::empty()))
+            .unwrap();
+        keyboard_test
+            .handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::CONTROL))
+            .unwrap();
+
+        assert_eq!(
+            keyboard_test.logged_keys(),
+            vec!["Ctrl+Backspace".to_string(), "Tab".to_string()]
+        );
+    });
+}
+
+#[test]
+fn latency_is_unavailable_outside_raw_mode() {
+    with_keyboard_test(|keyboard_test, _| {
+        assert!(keyboard_test.latency().is_none());
+    });
+}
+
+#[test]
+fn enter_without_measurement_is_logged_not_saved() {
+    with_keyboard_test(|keyboard_test, container| {
+        let config_service: Arc<dyn ConfigServiceInterface> = container.resolve();
+        keyboard_test
+            .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()))
+            .unwrap();
+
+        assert_eq!(keyboard_test.logged_keys(), vec!["Enter".to_string()]);
+        assert_eq!(config_service.get_config().input.latency_ms, 0);
+    });
+}
+
+#[test]
+fn enter_saves_the_measured_calibration() {
+    with_keyboard_test(|keyboard_test, container| {
+        let config_service: Arc<dyn ConfigServiceInterface> = container.resolve();
+        keyboard_test.set_latency_samples_for_test(&[
+            Duration::from_millis(12),
+            Duration::from_millis(8),
+            Duration::from_millis(30),
+        ]);
+
+        keyboard_test
+            .handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()))
+            .unwrap();
+
+        assert_eq!(config_service.get_config().input.latency_ms, 12);
+    });
+}
@@ -0,0 +1,74 @@
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::color_scheme::{ColorScheme, ThemeFile};
+use gittype::domain::models::MistakeBreakdown;
+use gittype::presentation::tui::views::MistakeBreakdownView;
+use gittype::presentation::ui::Colors;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::Terminal;
+
+fn default_colors() -> Colors {
+    let json = include_str!("../../../../assets/themes/default.json");
+    let theme: ThemeFile = serde_json::from_str(json).unwrap();
+    Colors::new(ColorScheme::from_theme_file(&theme, &ColorMode::Dark))
+}
+
+fn buffer_text(buffer: &Buffer) -> String {
+    (0..buffer.area.height)
+        .map(|row| {
+            (0..buffer.area.width)
+                .map(|column| buffer[(column, row)].symbol().to_string())
+                .collect::<Vec<_>>()
+                .join("")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_breakdown(breakdown: Option<&MistakeBreakdown>) -> String {
+    let colors = default_colors();
+    let backend = TestBackend::new(80, 12);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    terminal
+        .draw(|frame| {
+            MistakeBreakdownView::render(frame, frame.area(), breakdown, &colors);
+        })
+        .unwrap();
+
+    buffer_text(terminal.backend().buffer())
+}
+
+#[test]
+fn render_breakdown_lists_counts_and_tip_for_most_common_kind() {
+    let output = render_breakdown(Some(&MistakeBreakdown {
+        adjacent_key: 1,
+        case: 4,
+        shifted_symbol: 0,
+        transposition: 2,
+        other: 3,
+    }));
+
+    assert!(output.contains("Mistake Types"));
+    assert!(output.contains("Adjacent key:   1"));
+    assert!(output.contains("Wrong case:     4"));
+    assert!(output.contains("Shifted symbol: 0"));
+    assert!(output.contains("Transposition:  2"));
+    assert!(output.contains("Other:          3"));
+    assert!(output.contains("Tip: Press Shift before the letter"));
+}
+
+#[test]
+fn render_breakdown_without_mistakes_says_so() {
+    let output = render_breakdown(Some(&MistakeBreakdown::default()));
+
+    assert!(output.contains("No mistakes"));
+    assert!(!output.contains("Tip:"));
+}
+
+#[test]
+fn render_breakdown_missing_for_older_sessions() {
+    let output = render_breakdown(None);
+
+    assert!(output.contains("Not recorded for this session"));
+}
@@ -1,9 +1,14 @@
 pub mod analytics_repositories_view_tests;
 pub mod best_records_view_tests;
+pub mod challenge_summary_view_tests;
 pub mod difficulty_selection_view_tests;
+pub mod frame_limiter_tests;
 pub mod git_repository_view_tests;
+pub mod key_input_tests;
+pub mod keyboard_test_screen_tests;
 pub mod loading_description_view_tests;
 pub mod loading_progress_view_tests;
+pub mod mistake_breakdown_view_tests;
 pub mod performance_metrics_view_tests;
 pub mod rank_view_tests;
 pub mod records_screen_tests;
@@ -13,6 +18,7 @@ pub mod score_view_tests;
 pub mod screen_manager_tests;
 pub mod screen_trait_tests;
 pub mod screen_transition_manager_tests;
+pub mod source_study_view_tests;
 pub mod stage_completion_view_tests;
 pub mod stage_details_view_tests;
 pub mod stage_results_view_tests;
@@ -20,9 +26,14 @@ pub mod total_summary_ascii_score_view_tests;
 pub mod total_summary_share_screen_tests;
 pub mod total_summary_share_sharing_view_tests;
 pub mod trending_repository_list_view_tests;
+pub mod tutorial_screen_tests;
 pub mod typing_animation_view_tests;
 pub mod typing_countdown_view_tests;
 pub mod typing_dialog_view_tests;
+pub mod typing_footer_view_tests;
 pub mod typing_header_view_tests;
+pub mod typing_minimap_view_tests;
+pub mod typing_race_view_tests;
 pub mod typing_screen_tests;
 pub mod typing_view_tests;
+pub mod whats_new_screen_tests;
@@ -32,7 +32,7 @@ fn render_metrics(session_result: Option<&SessionResultData>) -> String {
 
     terminal
         .draw(|frame| {
-            PerformanceMetricsView::render(frame, frame.area(), session_result, &colors);
+            PerformanceMetricsView::render(frame, frame.area(), session_result, &[], &colors);
         })
         .unwrap();
 
@@ -29,6 +29,13 @@ impl SessionServiceInterface for StubSessionService {
         Ok(vec![])
     }
 
+    fn get_session_with_display_data(
+        &self,
+        _session_id: i64,
+    ) -> Result<Option<SessionDisplayData>> {
+        Ok(None)
+    }
+
     fn get_all_repositories(&self) -> Result<Vec<StoredRepository>> {
         Ok(vec![])
     }
@@ -49,6 +56,15 @@ impl SessionServiceInterface for FailingSessionService {
         ))
     }
 
+    fn get_session_with_display_data(
+        &self,
+        _session_id: i64,
+    ) -> Result<Option<SessionDisplayData>> {
+        Err(GitTypeError::TerminalError(
+            "stub session failure".to_string(),
+        ))
+    }
+
     fn get_all_repositories(&self) -> Result<Vec<StoredRepository>> {
         Err(GitTypeError::TerminalError(
             "stub repos failure".to_string(),
@@ -125,6 +141,8 @@ fn make_session(id: i64, repo_id: Option<i64>) -> SessionDisplayData {
             difficulty_level: None,
             max_stages: None,
             time_limit_seconds: None,
+            player_name: None,
+            unverified: false,
         },
         repository: None,
         session_result: Some(SessionResultData {
@@ -237,6 +255,7 @@ fn records_screen_data_holds_sessions_and_repositories() {
         user_name: "alice".to_string(),
         repository_name: "tools".to_string(),
         remote_url: "https://example.com/alice/tools".to_string(),
+        license: None,
     }];
     let data = RecordsScreenData {
         sessions,
@@ -555,6 +574,7 @@ fn render_with_long_repository_name_truncates() {
         user_name: "very-very-very-long-user".to_string(),
         repository_name: "and-an-incredibly-long-repository-name".to_string(),
         remote_url: "https://example.com/x/y".to_string(),
+        license: None,
     });
 
     let data = RecordsScreenData {
@@ -1,11 +1,12 @@
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::color_scheme::{ColorScheme, ThemeFile};
-use gittype::domain::models::storage::StoredRepositoryWithLanguages;
+use gittype::domain::models::storage::{RepositoryRating, StoredRepositoryWithLanguages};
 use gittype::presentation::tui::views::repo_list::RepositoryListView;
 use gittype::presentation::ui::colors::Colors;
 use ratatui::backend::TestBackend;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
+use ratatui::widgets::ListState;
 use ratatui::Terminal;
 
 fn default_colors() -> Colors {
@@ -20,6 +21,9 @@ fn repository(name: &str, languages: Vec<&str>) -> StoredRepositoryWithLanguages
         user_name: "owner".to_string(),
         repository_name: name.to_string(),
         remote_url: format!("https://example.com/owner/{name}"),
+        metadata: None,
+        last_played_at: None,
+        rating: None,
         languages: languages.into_iter().map(str::to_string).collect(),
     }
 }
@@ -62,7 +66,13 @@ fn render_handles_empty_and_overflowing_language_lists() {
 
     terminal
         .draw(|frame| {
-            RepositoryListView::render(frame, Rect::new(0, 0, 120, 8), &repositories, &colors);
+            RepositoryListView::render(
+                frame,
+                Rect::new(0, 0, 120, 8),
+                &repositories,
+                &mut ListState::default(),
+                &colors,
+            );
         })
         .unwrap();
 
@@ -75,3 +85,31 @@ fn render_handles_empty_and_overflowing_language_lists() {
     assert!(!text.contains("abcdefghijklmnopQRSTUVW, Rust"));
     assert!(!text.contains("abcdefghijklmnopQRSTUVW, ..."));
 }
+
+#[test]
+fn render_shows_the_rating_of_rated_repositories() {
+    let colors = default_colors();
+    let mut terminal = Terminal::new(TestBackend::new(120, 4)).unwrap();
+    let rated = StoredRepositoryWithLanguages {
+        rating: Some(RepositoryRating {
+            average: 4.5,
+            count: 2,
+        }),
+        ..repository("rated", vec!["rust"])
+    };
+    let repositories = vec![(rated, true)];
+
+    terminal
+        .draw(|frame| {
+            RepositoryListView::render(
+                frame,
+                Rect::new(0, 0, 120, 4),
+                &repositories,
+                &mut ListState::default(),
+                &colors,
+            );
+        })
+        .unwrap();
+
+    assert!(buffer_text(terminal.backend().buffer()).contains("4.5/5 (2 ratings)"));
+}
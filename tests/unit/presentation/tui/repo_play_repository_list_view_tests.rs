@@ -33,6 +33,9 @@ fn repository(languages: Vec<String>) -> StoredRepositoryWithLanguages {
         user_name: "owner".to_string(),
         repository_name: "project".to_string(),
         remote_url: "https://github.com/owner/project".to_string(),
+        metadata: None,
+        last_played_at: None,
+        rating: None,
         languages,
     }
 }
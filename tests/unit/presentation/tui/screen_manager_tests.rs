@@ -470,6 +470,44 @@ fn test_render_current_screen_with_test_backend() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_render_if_due_skips_when_no_frame_was_requested() {
+    let mut manager = create_test_screen_manager();
+    manager.register_screen(MockScreen::new(ScreenType::Title));
+
+    manager.render_current_screen().unwrap();
+
+    assert!(!manager.has_pending_frame());
+    assert!(!manager.render_if_due().unwrap());
+}
+
+#[test]
+fn test_rendering_an_unchanged_frame_twice_succeeds() {
+    let mut manager = create_test_screen_manager();
+    manager.register_screen(MockScreen::new(ScreenType::Title));
+    manager.set_max_fps(0);
+
+    assert!(manager.render_current_screen().is_ok());
+    assert!(manager.render_current_screen().is_ok());
+}
+
+#[test]
+fn test_handle_resize_redraws_without_changing_screen() {
+    let mut manager = create_test_screen_manager();
+    let pushed_from = Arc::new(Mutex::new(None));
+
+    manager.register_screen(MockScreen::new(ScreenType::Title));
+    manager.register_screen_arc(Arc::new(PushAwareScreen::new(
+        ScreenType::Help,
+        Arc::clone(&pushed_from),
+    )));
+    manager.push_screen(ScreenType::Help).unwrap();
+
+    assert!(manager.handle_resize().is_ok());
+    assert_eq!(*manager.get_current_screen_type(), ScreenType::Help);
+    assert_eq!(manager.get_screen_stack(), &vec![ScreenType::Title]);
+}
+
 #[test]
 fn test_is_terminal_initialized() {
     let manager = create_test_screen_manager();
@@ -515,11 +553,14 @@ fn test_set_current_screen_uses_default_providers_for_registered_screen_types()
         ScreenType::VersionCheck,
         ScreenType::InfoDialog,
         ScreenType::DetailsDialog,
+        ScreenType::Error,
         ScreenType::Panic,
         ScreenType::RepoPlay,
+        ScreenType::Reminders,
         ScreenType::RepoList,
         ScreenType::TrendingLanguageSelection,
         ScreenType::TrendingRepositorySelection,
+        ScreenType::WorkspacePackages,
     ];
 
     screen_types.into_iter().for_each(|screen_type| {
@@ -753,6 +794,7 @@ fn test_factory_create_registers_di_screens() {
         ScreenType::Animation,
         ScreenType::Help,
         ScreenType::Loading,
+        ScreenType::Error,
         ScreenType::Panic,
         ScreenType::SessionFailure,
         ScreenType::InfoDialog,
@@ -769,8 +811,10 @@ fn test_factory_create_registers_di_screens() {
         ScreenType::VersionCheck,
         ScreenType::RepoList,
         ScreenType::RepoPlay,
+        ScreenType::Reminders,
         ScreenType::TrendingLanguageSelection,
         ScreenType::TrendingRepositorySelection,
+        ScreenType::WorkspacePackages,
     ]
     .into_iter()
     .for_each(|screen_type| {
@@ -803,3 +847,28 @@ fn setup_event_subscriptions_stores_pending_navigation_transition() {
         Some(ScreenTransition::Replace(ScreenType::Help))
     ));
 }
+
+#[test]
+fn setup_event_subscriptions_stores_pending_editor_request() {
+    use gittype::domain::events::presentation_events::OpenInEditor;
+    use gittype::domain::events::EventBusInterface;
+    use std::path::PathBuf;
+
+    let manager = Arc::new(Mutex::new(create_test_screen_manager()));
+
+    ScreenManagerImpl::setup_event_subscriptions(&manager);
+    let event_bus: Arc<dyn EventBusInterface> = manager.lock().unwrap().get_event_bus();
+
+    event_bus.as_event_bus().publish(OpenInEditor {
+        path: PathBuf::from("/repo/src/lib.rs"),
+        line: 42,
+    });
+
+    assert_eq!(
+        manager.lock().unwrap().pending_editor_for_test(),
+        Some(OpenInEditor {
+            path: PathBuf::from("/repo/src/lib.rs"),
+            line: 42,
+        })
+    );
+}
@@ -1,7 +1,8 @@
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyCode, KeyEvent};
 use gittype::presentation::tui::{Screen, ScreenDataProvider, ScreenType, UpdateStrategy};
 use ratatui::Frame;
 use std::any::Any;
+use std::sync::Mutex;
 
 struct UnitProvider;
 
@@ -56,3 +57,55 @@ fn default_screen_methods_are_noop_and_input_only() {
         UpdateStrategy::InputOnly
     ));
 }
+
+#[derive(Default)]
+struct RecordingScreen {
+    keys: Mutex<Vec<KeyCode>>,
+}
+
+impl Screen for RecordingScreen {
+    fn get_type(&self) -> ScreenType {
+        ScreenType::Help
+    }
+
+    fn default_provider() -> Box<dyn ScreenDataProvider>
+    where
+        Self: Sized,
+    {
+        Box::new(UnitProvider)
+    }
+
+    fn init_with_data(&self, _data: Box<dyn Any>) -> gittype::Result<()> {
+        Ok(())
+    }
+
+    fn handle_key_event(&self, key_event: KeyEvent) -> gittype::Result<()> {
+        self.keys.lock().unwrap().push(key_event.code);
+        Ok(())
+    }
+
+    fn render_ratatui(&self, _frame: &mut Frame) -> gittype::Result<()> {
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[test]
+fn default_paste_arrives_as_key_presses() {
+    let screen = RecordingScreen::default();
+
+    screen.handle_paste("a\tb\r\n").unwrap();
+
+    assert_eq!(
+        *screen.keys.lock().unwrap(),
+        vec![
+            KeyCode::Char('a'),
+            KeyCode::Tab,
+            KeyCode::Char('b'),
+            KeyCode::Enter
+        ]
+    );
+}
@@ -517,6 +517,7 @@ mod tests {
             commit_hash: Some("abc123def456".to_string()),
             is_dirty: false,
             root_path: None,
+            license: None,
         }));
     }
 
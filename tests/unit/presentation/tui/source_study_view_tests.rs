@@ -0,0 +1,64 @@
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::color_scheme::{ColorScheme, ThemeFile};
+use gittype::domain::models::SourceStudy;
+use gittype::presentation::tui::views::SourceStudyView;
+use gittype::presentation::ui::colors::Colors;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::Terminal;
+
+fn default_colors() -> Colors {
+    let json = include_str!("../../../../assets/themes/default.json");
+    let theme: ThemeFile = serde_json::from_str(json).unwrap();
+    Colors::new(ColorScheme::from_theme_file(&theme, &ColorMode::Dark))
+}
+
+fn buffer_text(buffer: &Buffer) -> String {
+    (0..buffer.area.height)
+        .map(|row| {
+            (0..buffer.area.width)
+                .map(|column| buffer[(column, row)].symbol().to_string())
+                .collect::<Vec<_>>()
+                .join("")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render(study: Option<&SourceStudy>, offset: usize) -> String {
+    let colors = default_colors();
+    let mut terminal = Terminal::new(TestBackend::new(80, 12)).unwrap();
+    terminal
+        .draw(|frame| SourceStudyView::render(frame, frame.area(), study, offset, &colors))
+        .unwrap();
+    buffer_text(terminal.backend().buffer())
+}
+
+fn study() -> SourceStudy {
+    SourceStudy {
+        path: "src/lib.rs".to_string(),
+        lines: (1..=30)
+            .map(|n| format!("let value_{} = {};", n, n))
+            .collect(),
+        start_line: 12,
+        end_line: 14,
+    }
+}
+
+#[test]
+fn render_shows_file_from_offset_with_line_numbers() {
+    let text = render(Some(&study()), 10);
+
+    assert!(text.contains("Study: src/lib.rs (challenge lines 12-14)"));
+    assert!(text.contains("11   let value_11 = 11;"));
+    assert!(text.contains("12 ▌ let value_12 = 12;"));
+    assert!(!text.contains("value_10 "));
+    assert!(text.contains("[S/ESC] Back to summary"));
+}
+
+#[test]
+fn render_explains_missing_source_file() {
+    let text = render(None, 0);
+
+    assert!(text.contains("not available on this machine"));
+}
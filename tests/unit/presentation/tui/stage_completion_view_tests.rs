@@ -34,7 +34,20 @@ fn render_stage_completion(metrics: &StageResult, has_next_stage: bool) -> Strin
 
     terminal
         .draw(|frame| {
-            StageCompletionView::render(frame, metrics, 2, 3, has_next_stage, 42, &colors);
+            StageCompletionView::render(
+                frame,
+                metrics,
+                2,
+                3,
+                has_next_stage,
+                42,
+                None,
+                false,
+                None,
+                false,
+                false,
+                &colors,
+            );
         })
         .unwrap();
 
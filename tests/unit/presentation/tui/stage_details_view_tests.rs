@@ -1,6 +1,7 @@
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::color_scheme::{ColorScheme, ThemeFile};
 use gittype::domain::models::storage::SessionStageResult;
+use gittype::domain::models::MistakeBreakdown;
 use gittype::presentation::tui::views::StageDetailsView;
 use gittype::presentation::ui::Colors;
 use ratatui::backend::TestBackend;
@@ -63,6 +64,10 @@ fn stage(number: i64, was_failed: bool, was_skipped: bool) -> SessionStageResult
         start_line: Some(10),
         end_line: Some(20),
         code_content: None,
+        challenge_id: None,
+        comment_ranges: Vec::new(),
+        mistake_breakdown: None,
+        key_load: None,
     }
 }
 
@@ -96,3 +101,18 @@ fn render_many_stages_shows_scroll_hint() {
     assert!(output.contains("stages shown"));
     assert!(output.contains("to scroll"));
 }
+
+#[test]
+fn render_stage_with_mistake_breakdown_lists_kinds_that_occurred() {
+    let mut classified = stage(1, false, false);
+    classified.mistake_breakdown = Some(MistakeBreakdown {
+        adjacent_key: 3,
+        transposition: 2,
+        ..Default::default()
+    });
+
+    let output = render_stage_details(&[classified], 10);
+
+    assert!(output.contains("Mistake Types: Adjacent key 3, Transposition 2"));
+    assert!(!output.contains("Wrong case"));
+}
@@ -0,0 +1,142 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use gittype::domain::models::{Challenge, GitRepository, SessionResult};
+use gittype::domain::repositories::session_repository::SessionRepositoryTrait;
+use gittype::domain::services::config_service::ConfigServiceInterface;
+use gittype::domain::services::scoring::{StageInput, StageTracker};
+use gittype::presentation::di::AppModule;
+use gittype::presentation::tui::screens::tutorial_screen::{TutorialScreen, TutorialStep};
+use gittype::presentation::tui::screens::TutorialScreenInterface;
+use gittype::presentation::tui::Screen;
+use shaku::HasComponent;
+use std::sync::Arc;
+
+fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::empty())
+}
+
+fn type_text(tutorial: &TutorialScreen, text: &str) {
+    for c in text.chars() {
+        let code = match c {
+            '\n' => KeyCode::Enter,
+            c => KeyCode::Char(c),
+        };
+        tutorial.handle_key_event(key(code)).unwrap();
+    }
+}
+
+fn with_tutorial(test: impl FnOnce(&TutorialScreen, &AppModule)) {
+    let container = AppModule::builder().build();
+    let screen: Arc<dyn TutorialScreenInterface> = container.resolve();
+    let tutorial = screen.as_any().downcast_ref::<TutorialScreen>().unwrap();
+    tutorial.init_with_data(Box::new(())).unwrap();
+    test(tutorial, &container);
+}
+
+#[test]
+fn steps_walk_forward_and_back() {
+    assert_eq!(TutorialStep::Welcome.next(), Some(TutorialStep::Practice));
+    assert_eq!(TutorialStep::Finish.next(), None);
+    assert_eq!(TutorialStep::Welcome.previous(), None);
+    assert_eq!(TutorialStep::Ranks.previous(), Some(TutorialStep::Scoring));
+}
+
+#[test]
+fn typing_the_practice_snippet_produces_a_result() {
+    with_tutorial(|tutorial, _| {
+        tutorial.handle_key_event(key(KeyCode::Right)).unwrap();
+        assert_eq!(tutorial.current_step(), TutorialStep::Practice);
+
+        type_text(
+            tutorial,
+            "fn greet(name: &str) {\nprintln!(\"Hello, {}!\", name);\n}",
+        );
+
+        let result = tutorial.practice_result().expect("practice completed");
+        assert_eq!(result.mistakes, 0);
+        assert!(!result.rank_name.is_empty());
+        assert_eq!(tutorial.current_step(), TutorialStep::Practice);
+
+        tutorial.handle_key_event(key(KeyCode::Enter)).unwrap();
+        assert_eq!(tutorial.current_step(), TutorialStep::SkipAndFail);
+    });
+}
+
+#[test]
+fn retry_resets_the_practice() {
+    with_tutorial(|tutorial, _| {
+        tutorial.handle_key_event(key(KeyCode::Right)).unwrap();
+        type_text(
+            tutorial,
+            "fn greet(name: &str) {\nprintln!(\"Hello, {}!\", name);\n}",
+        );
+        assert!(tutorial.practice_result().is_some());
+
+        tutorial.handle_key_event(key(KeyCode::Char('r'))).unwrap();
+        assert!(tutorial.practice_result().is_none());
+        assert_eq!(tutorial.current_step(), TutorialStep::Practice);
+    });
+}
+
+#[test]
+fn letter_keys_are_typed_during_practice() {
+    with_tutorial(|tutorial, _| {
+        tutorial.handle_key_event(key(KeyCode::Right)).unwrap();
+        type_text(tutorial, "fn gr");
+
+        // `h` and `l` are typed, not treated as navigation
+        tutorial.handle_key_event(key(KeyCode::Char('h'))).unwrap();
+        tutorial.handle_key_event(key(KeyCode::Char('l'))).unwrap();
+        assert_eq!(tutorial.current_step(), TutorialStep::Practice);
+    });
+}
+
+#[test]
+fn shows_on_launch_for_new_players_only() {
+    with_tutorial(|tutorial, _| {
+        assert!(tutorial.should_show_on_launch());
+    });
+
+    with_tutorial(|tutorial, container| {
+        let session_repository: Arc<dyn SessionRepositoryTrait> = container.resolve();
+        let mut tracker = StageTracker::new("fn main() {}".to_string());
+        tracker.record(StageInput::Start);
+        tracker.record(StageInput::Finish);
+        session_repository
+            .record_session(
+                &SessionResult::new(),
+                Some(&GitRepository {
+                    user_name: "user".to_string(),
+                    repository_name: "repo".to_string(),
+                    remote_url: "https://github.com/user/repo".to_string(),
+                    branch: Some("main".to_string()),
+                    commit_hash: Some("abc123".to_string()),
+                    is_dirty: false,
+                    root_path: None,
+                    license: None,
+                }),
+                "normal",
+                None,
+                &[("Stage 1".to_string(), tracker)],
+                &[Challenge::new(
+                    "tutorial-test".to_string(),
+                    "fn main() {}".to_string(),
+                )],
+            )
+            .unwrap();
+
+        assert!(!tutorial.should_show_on_launch());
+    });
+}
+
+#[test]
+fn finishing_marks_the_tutorial_completed() {
+    with_tutorial(|tutorial, container| {
+        let config_service: Arc<dyn ConfigServiceInterface> = container.resolve();
+        assert!(!config_service.get_config().tutorial.completed);
+
+        tutorial.handle_key_event(key(KeyCode::Esc)).unwrap();
+
+        assert!(config_service.get_config().tutorial.completed);
+        assert!(!tutorial.should_show_on_launch());
+    });
+}
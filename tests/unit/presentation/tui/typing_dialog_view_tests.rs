@@ -24,13 +24,13 @@ fn buffer_text(buffer: &Buffer) -> String {
         .join("\n")
 }
 
-fn render_dialog(skips_remaining: usize) -> String {
+fn render_dialog(skips_remaining: usize, big_wpm_shown: bool) -> String {
     let colors = default_colors();
     let backend = TestBackend::new(64, 16);
     let mut terminal = Terminal::new(backend).unwrap();
 
     terminal
-        .draw(|frame| TypingDialogView::render(frame, skips_remaining, &colors))
+        .draw(|frame| TypingDialogView::render(frame, skips_remaining, big_wpm_shown, &colors))
         .unwrap();
 
     buffer_text(terminal.backend().buffer())
@@ -38,8 +38,14 @@ fn render_dialog(skips_remaining: usize) -> String {
 
 #[test]
 fn render_with_no_skips_shows_disabled_skip_option() {
-    let output = render_dialog(0);
+    let output = render_dialog(0, false);
 
     assert!(output.contains("No skips remaining"));
     assert!(!output.contains("Skip challenge"));
 }
+
+#[test]
+fn render_offers_to_toggle_big_wpm() {
+    assert!(render_dialog(1, false).contains("[W] Show big WPM"));
+    assert!(render_dialog(1, true).contains("[W] Hide big WPM"));
+}
@@ -0,0 +1,26 @@
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::color_scheme::{ColorScheme, ThemeFile};
+use gittype::presentation::tui::views::typing::TypingFooterView;
+use gittype::presentation::ui::Colors;
+
+fn default_colors() -> Colors {
+    let json = include_str!("../../../../assets/themes/default.json");
+    let theme: ThemeFile = serde_json::from_str(json).unwrap();
+    Colors::new(ColorScheme::from_theme_file(&theme, &ColorMode::Dark))
+}
+
+#[test]
+fn recent_mistakes_line_shows_expected_and_typed_with_visible_whitespace() {
+    let line = TypingFooterView::recent_mistakes_line(
+        &[('e', 'r'), (' ', 'x'), ('\n', '\t')],
+        &default_colors(),
+    )
+    .unwrap();
+
+    assert_eq!(line.to_string(), " Recent errors: e→r ␣→x ⏎→⇥ ");
+}
+
+#[test]
+fn recent_mistakes_line_is_none_without_mistakes() {
+    assert!(TypingFooterView::recent_mistakes_line(&[], &default_colors()).is_none());
+}
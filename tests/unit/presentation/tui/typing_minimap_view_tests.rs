@@ -0,0 +1,36 @@
+use gittype::presentation::tui::views::typing::TypingMinimapView;
+
+#[test]
+fn rows_outline_each_line_with_its_indentation() {
+    let rows = TypingMinimapView::rows("fn a() {\n    b();\n\n}", 10, 40);
+
+    assert_eq!(
+        rows,
+        vec![
+            (0..1, "▬▬▬▬▬▬▬▬".to_string()),
+            (1..2, "    ▬▬▬▬".to_string()),
+            (2..3, String::new()),
+            (3..4, "▬".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn rows_group_lines_and_scale_to_fit_the_area() {
+    let text = (0..10)
+        .map(|_| "x".repeat(20))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let rows = TypingMinimapView::rows(&text, 4, 10);
+
+    assert_eq!(rows.len(), 4);
+    assert_eq!(rows[0], (0..3, "▬".repeat(10)));
+    assert_eq!(rows[3].0, 9..10);
+}
+
+#[test]
+fn rows_are_empty_without_text_or_room() {
+    assert!(TypingMinimapView::rows("", 10, 10).is_empty());
+    assert!(TypingMinimapView::rows("fn a() {}", 0, 10).is_empty());
+}
@@ -0,0 +1,52 @@
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::color_scheme::{ColorScheme, ThemeFile};
+use gittype::presentation::tui::views::typing::TypingRaceView;
+use gittype::presentation::ui::Colors;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn default_colors() -> Colors {
+    let json = include_str!("../../../../assets/themes/default.json");
+    let theme: ThemeFile = serde_json::from_str(json).unwrap();
+    Colors::new(ColorScheme::from_theme_file(&theme, &ColorMode::Dark))
+}
+
+fn render_rows(player_percent: u16, bots: &[(String, u16)]) -> Vec<String> {
+    let colors = default_colors();
+    let height = TypingRaceView::height(bots.len());
+    let mut terminal = Terminal::new(TestBackend::new(30, height)).unwrap();
+    terminal
+        .draw(|frame| {
+            TypingRaceView::render(frame, frame.area(), player_percent, bots, &colors);
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| (0..30).map(|x| buffer[(x, y)].symbol()).collect::<String>())
+        .collect()
+}
+
+#[test]
+fn render_draws_a_bar_per_racer_with_the_player_first() {
+    let rows = render_rows(50, &[("Pro".to_string(), 100), ("Bot".to_string(), 0)]);
+
+    assert_eq!(rows.len(), 5);
+    assert!(rows[0].contains("Race · 2nd of 3"));
+    assert_eq!(rows[1], "│ You ████████░░░░░░░░░  50% │");
+    assert_eq!(rows[2], "│ Pro █████████████████ 100% │");
+    assert_eq!(rows[3], "│ Bot ░░░░░░░░░░░░░░░░░   0% │");
+}
+
+#[test]
+fn place_counts_only_bots_strictly_ahead() {
+    let bots = [
+        ("A".to_string(), 40),
+        ("B".to_string(), 60),
+        ("C".to_string(), 70),
+    ];
+
+    assert_eq!(TypingRaceView::place(60, &bots), 2);
+    assert_eq!(TypingRaceView::place(80, &bots), 1);
+    assert_eq!(TypingRaceView::place(0, &bots), 4);
+}
@@ -3,9 +3,22 @@ use std::sync::Arc;
 use gittype::domain::events::EventBus;
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::theme::Theme;
+use gittype::domain::services::challenge_flag_service::{
+    ChallengeFlagService, ChallengeFlagServiceInterface,
+};
+use gittype::domain::services::config_service::ConfigService;
+use gittype::domain::services::discord_presence_service::{
+    DiscordPresenceService, DiscordPresenceServiceInterface,
+};
 use gittype::domain::services::session_manager_service::SessionManagerInterface;
+use gittype::domain::services::streamer_stats_service::{
+    StreamerStatsService, StreamerStatsServiceInterface,
+};
 use gittype::domain::services::theme_service::{ThemeService, ThemeServiceInterface};
 use gittype::domain::stores::{RepositoryStore, RepositoryStoreInterface};
+use gittype::infrastructure::database::daos::ChallengeFlagDao;
+use gittype::infrastructure::database::database::Database;
+use gittype::infrastructure::storage::file_storage::FileStorage;
 use gittype::presentation::di::AppModule;
 use gittype::presentation::tui::screens::typing_screen::{TypingScreen, TypingScreenProvider};
 use gittype::presentation::tui::{Screen, UpdateStrategy};
@@ -35,6 +48,10 @@ fn create_screen() -> TypingScreen {
         theme_service,
         repository_store,
         session_manager,
+        discord_presence_service(),
+        challenge_flag_service(),
+        Arc::new(ConfigService::new_for_test().unwrap()),
+        streamer_stats_service(),
     )
 }
 
@@ -86,3 +103,23 @@ fn provider_resolves_typing_screen_from_app_module() {
 
     assert!(provided.is_ok());
 }
+
+fn challenge_flag_service() -> Arc<dyn ChallengeFlagServiceInterface> {
+    Arc::new(ChallengeFlagService::new(Arc::new(ChallengeFlagDao::new(
+        Arc::new(Database::new().unwrap()),
+    ))))
+}
+
+fn discord_presence_service() -> Arc<dyn DiscordPresenceServiceInterface> {
+    Arc::new(DiscordPresenceService::new(Arc::new(
+        ConfigService::new_for_test().unwrap(),
+    )))
+}
+
+fn streamer_stats_service() -> Arc<dyn StreamerStatsServiceInterface> {
+    let config_service = Arc::new(ConfigService::new_for_test().unwrap());
+    Arc::new(StreamerStatsService::new(
+        Arc::new(FileStorage::new()),
+        config_service,
+    ))
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use gittype::domain::models::color_mode::ColorMode;
 use gittype::domain::models::color_scheme::{ColorScheme, ThemeFile};
 use gittype::domain::models::typing::CodeContext;
-use gittype::domain::models::ProcessingOptions;
+use gittype::domain::models::{ProcessingOptions, RaceBot};
 use gittype::domain::services::session_manager_service::SessionManagerInterface;
 use gittype::domain::services::typing_core::TypingCore;
 use gittype::presentation::tui::views::typing::TypingView;
@@ -61,6 +61,9 @@ fn render_empty_typing_view_skips_metrics_for_non_concrete_session_manager() {
                 None,
                 0,
                 false,
+                false,
+                false,
+                &[],
                 &session_manager,
                 &colors,
             );
@@ -73,3 +76,98 @@ fn render_empty_typing_view_skips_metrics_for_non_concrete_session_manager() {
     assert!(output.contains("0%"));
     assert!(!output.contains("Metrics"));
 }
+
+fn render_with_big_wpm(width: u16) -> String {
+    render_with_layout(width, true, false)
+}
+
+fn render_with_layout(width: u16, big_wpm_shown: bool, wide_layout: bool) -> String {
+    render_with_bots(width, big_wpm_shown, wide_layout, &[])
+}
+
+fn render_with_bots(
+    width: u16,
+    big_wpm_shown: bool,
+    wide_layout: bool,
+    race_bots: &[RaceBot],
+) -> String {
+    let colors = default_colors();
+    let typing_core = TypingCore::new("fn main() {}", &[], ProcessingOptions::default());
+    let code_context = CodeContext {
+        pre_context: Vec::new(),
+        post_context: Vec::new(),
+    };
+    let session_manager: Arc<dyn SessionManagerInterface> = Arc::new(FakeSessionManager);
+    let mut view = TypingView::new();
+    let backend = TestBackend::new(width, 20);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    terminal
+        .draw(|frame| {
+            view.render(
+                frame,
+                None,
+                None,
+                &typing_core,
+                &[],
+                &code_context,
+                true,
+                None,
+                0,
+                false,
+                big_wpm_shown,
+                wide_layout,
+                race_bots,
+                &session_manager,
+                &colors,
+            );
+        })
+        .unwrap();
+
+    buffer_text(terminal.backend().buffer())
+}
+
+#[test]
+fn render_big_wpm_panel_shows_zero_before_typing_starts() {
+    let output = render_with_big_wpm(120);
+
+    assert!(output.contains("WPM"));
+    assert!(output.contains(" | () |"));
+}
+
+#[test]
+fn render_big_wpm_panel_is_hidden_on_narrow_terminals() {
+    let output = render_with_big_wpm(60);
+
+    assert!(!output.contains(" | () |"));
+}
+
+#[test]
+fn render_wide_layout_shows_minimap_beside_code_on_ultra_wide_terminals() {
+    let output = render_with_layout(170, true, true);
+
+    assert!(output.contains("Minimap"));
+    assert!(output.contains(" | () |"));
+}
+
+#[test]
+fn render_wide_layout_falls_back_to_single_column_below_the_threshold() {
+    assert!(!render_with_layout(150, false, true).contains("Minimap"));
+    assert!(!render_with_layout(170, false, false).contains("Minimap"));
+}
+
+#[test]
+fn render_race_bots_replaces_the_progress_bar_with_one_bar_per_racer() {
+    let bots = [
+        RaceBot::parse("novice").unwrap(),
+        RaceBot::parse("70@96").unwrap(),
+    ];
+
+    let output = render_with_bots(100, false, false, &bots);
+
+    assert!(output.contains("Race · 1st of 3"));
+    assert!(output.contains("You "));
+    assert!(output.contains("Novice 30 WPM · 92%"));
+    assert!(output.contains("Bot 70 WPM · 96%"));
+    assert!(!output.contains("Progress"));
+}
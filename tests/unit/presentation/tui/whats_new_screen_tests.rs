@@ -0,0 +1,46 @@
+use gittype::domain::services::config_service::ConfigServiceInterface;
+use gittype::presentation::di::AppModule;
+use gittype::presentation::tui::screens::{WhatsNewScreen, WhatsNewScreenInterface};
+use gittype::presentation::tui::Screen;
+use shaku::HasComponent;
+use std::sync::Arc;
+
+fn with_whats_new(test: impl FnOnce(&WhatsNewScreen, &AppModule)) {
+    let container = AppModule::builder().build();
+    let screen: Arc<dyn WhatsNewScreenInterface> = container.resolve();
+    let whats_new = screen.as_any().downcast_ref::<WhatsNewScreen>().unwrap();
+    test(whats_new, &container);
+}
+
+#[test]
+fn shows_until_the_running_version_is_marked_seen() {
+    with_whats_new(|whats_new, container| {
+        let config_service: Arc<dyn ConfigServiceInterface> = container.resolve();
+        assert_eq!(
+            config_service.get_config().whats_new.last_seen_version,
+            None
+        );
+        assert!(whats_new.should_show_on_launch());
+
+        whats_new.mark_seen();
+
+        assert_eq!(
+            config_service
+                .get_config()
+                .whats_new
+                .last_seen_version
+                .as_deref(),
+            Some(env!("CARGO_PKG_VERSION"))
+        );
+        assert!(!whats_new.should_show_on_launch());
+    });
+}
+
+#[test]
+fn being_pushed_records_the_version_as_seen() {
+    with_whats_new(|whats_new, _| {
+        whats_new.on_pushed_from(whats_new).unwrap();
+
+        assert!(!whats_new.should_show_on_launch());
+    });
+}
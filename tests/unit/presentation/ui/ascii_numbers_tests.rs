@@ -0,0 +1,37 @@
+use gittype::presentation::ui::AsciiNumbersWidget;
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+#[test]
+fn lines_join_digit_patterns_row_by_row() {
+    let lines = AsciiNumbersWidget::new("10").lines();
+
+    assert_eq!(lines.len(), AsciiNumbersWidget::HEIGHT as usize);
+    assert_eq!(lines[0], "  _     __   ");
+    assert_eq!(lines[2], " | |  | () | ");
+}
+
+#[test]
+fn lines_skip_non_digit_characters() {
+    let widget = AsciiNumbersWidget::new("-1,0.");
+
+    assert_eq!(widget.lines(), AsciiNumbersWidget::new("10").lines());
+}
+
+#[test]
+fn width_is_zero_without_digits() {
+    assert_eq!(AsciiNumbersWidget::new("").width(), 0);
+    assert_eq!(AsciiNumbersWidget::new("1").width(), 5);
+}
+
+#[test]
+fn render_centers_digits_in_area() {
+    let area = Rect::new(0, 0, 11, 4);
+    let mut buf = Buffer::empty(area);
+
+    AsciiNumbersWidget::new("1").render(area, &mut buf);
+
+    let first_row: String = (0..area.width)
+        .map(|x| buf[(x, 0)].symbol().to_string())
+        .collect();
+    assert_eq!(first_row, "     _     ");
+}
@@ -0,0 +1,34 @@
+use gittype::presentation::ui::BrailleChartWidget;
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+#[test]
+fn rising_line_climbs_from_the_bottom_to_the_top_dot() {
+    let values = [0.0, 1.0, 2.0, 3.0];
+
+    assert_eq!(BrailleChartWidget::new(&values).rows(2, 1), vec!["⡠⠊"]);
+}
+
+#[test]
+fn steep_jumps_are_joined_vertically() {
+    let values = [0.0, 7.0];
+
+    assert_eq!(BrailleChartWidget::new(&values).rows(1, 2), vec!["⢸", "⡸"]);
+}
+
+#[test]
+fn flat_series_is_drawn_through_the_middle() {
+    let values = [5.0, 5.0, 5.0, 5.0];
+
+    assert_eq!(BrailleChartWidget::new(&values).rows(2, 1), vec!["⠒⠒"]);
+}
+
+#[test]
+fn render_leaves_cells_without_dots_blank() {
+    let values = [0.0, 0.0, 8.0, 8.0];
+    let area = Rect::new(0, 0, 3, 2);
+    let mut buffer = Buffer::empty(area);
+
+    BrailleChartWidget::new(&values).render(area, &mut buffer);
+
+    assert_eq!(buffer, Buffer::with_lines([" ⡏ ", "⣀⠇ "]));
+}
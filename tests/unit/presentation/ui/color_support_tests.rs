@@ -0,0 +1,129 @@
+use gittype::presentation::ui::ColorSupport;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+
+#[test]
+fn no_color_disables_colors_regardless_of_terminal() {
+    assert_eq!(
+        ColorSupport::from_env(Some("1"), Some("truecolor"), Some("xterm-256color")),
+        ColorSupport::Monochrome
+    );
+}
+
+#[test]
+fn empty_no_color_is_ignored() {
+    assert_eq!(
+        ColorSupport::from_env(Some(""), Some("truecolor"), Some("xterm")),
+        ColorSupport::TrueColor
+    );
+}
+
+#[test]
+fn detects_support_from_term() {
+    let from_term = |term| ColorSupport::from_env(None, None, term);
+
+    assert_eq!(from_term(None), ColorSupport::TrueColor);
+    assert_eq!(from_term(Some("xterm-direct")), ColorSupport::TrueColor);
+    assert_eq!(from_term(Some("xterm-256color")), ColorSupport::Ansi256);
+    assert_eq!(from_term(Some("screen-256color")), ColorSupport::Ansi256);
+    assert_eq!(from_term(Some("xterm")), ColorSupport::Ansi16);
+    assert_eq!(from_term(Some("linux")), ColorSupport::Ansi16);
+    assert_eq!(from_term(Some("dumb")), ColorSupport::Monochrome);
+}
+
+#[test]
+fn colorterm_truecolor_overrides_term() {
+    assert_eq!(
+        ColorSupport::from_env(None, Some("24bit"), Some("xterm-256color")),
+        ColorSupport::TrueColor
+    );
+}
+
+#[test]
+fn true_color_keeps_colors() {
+    assert_eq!(
+        ColorSupport::TrueColor.downgrade(Color::Rgb(1, 2, 3)),
+        Color::Rgb(1, 2, 3)
+    );
+}
+
+#[test]
+fn ansi256_maps_rgb_to_palette() {
+    assert_eq!(
+        ColorSupport::Ansi256.downgrade(Color::Rgb(255, 0, 0)),
+        Color::Indexed(196)
+    );
+    assert_eq!(
+        ColorSupport::Ansi256.downgrade(Color::Rgb(128, 128, 128)),
+        Color::Indexed(244)
+    );
+    assert_eq!(ColorSupport::Ansi256.downgrade(Color::Cyan), Color::Cyan);
+}
+
+#[test]
+fn ansi16_maps_rgb_and_indexed_to_named_colors() {
+    assert_eq!(
+        ColorSupport::Ansi16.downgrade(Color::Rgb(250, 10, 10)),
+        Color::LightRed
+    );
+    assert_eq!(
+        ColorSupport::Ansi16.downgrade(Color::Rgb(0, 120, 0)),
+        Color::Green
+    );
+    assert_eq!(
+        ColorSupport::Ansi16.downgrade(Color::Indexed(21)),
+        Color::LightBlue
+    );
+    assert_eq!(ColorSupport::Ansi16.downgrade(Color::Reset), Color::Reset);
+}
+
+#[test]
+fn monochrome_resets_colors() {
+    assert_eq!(
+        ColorSupport::Monochrome.downgrade(Color::Yellow),
+        Color::Reset
+    );
+}
+
+#[test]
+fn apply_reverses_highlighted_cells_without_color() {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+    buffer.set_string(0, 0, "a", Style::default().fg(Color::Red));
+    buffer.set_string(
+        1,
+        0,
+        "b",
+        Style::default().fg(Color::Black).bg(Color::White),
+    );
+
+    ColorSupport::Monochrome.apply(&mut buffer);
+
+    let plain = &buffer[(0, 0)];
+    assert_eq!((plain.fg, plain.bg), (Color::Reset, Color::Reset));
+    assert!(!plain.modifier.contains(Modifier::REVERSED));
+    let highlighted = &buffer[(1, 0)];
+    assert_eq!(
+        (highlighted.fg, highlighted.bg),
+        (Color::Reset, Color::Reset)
+    );
+    assert!(highlighted.modifier.contains(Modifier::REVERSED));
+}
+
+#[test]
+fn apply_downgrades_every_cell() {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+    buffer.set_string(
+        0,
+        0,
+        "a",
+        Style::default()
+            .fg(Color::Rgb(0, 0, 250))
+            .bg(Color::Rgb(0, 0, 0)),
+    );
+
+    ColorSupport::Ansi16.apply(&mut buffer);
+
+    assert_eq!(buffer[(0, 0)].fg, Color::LightBlue);
+    assert_eq!(buffer[(0, 0)].bg, Color::Black);
+}
@@ -0,0 +1,36 @@
+use gittype::presentation::ui::fuzzy_score;
+
+#[test]
+fn empty_query_matches_everything() {
+    assert_eq!(fuzzy_score("", "Theme"), Some(0));
+}
+
+#[test]
+fn matches_subsequence_case_insensitively() {
+    assert!(fuzzy_score("CLRMD", "color mode").is_some());
+    assert!(fuzzy_score("thm", "Theme").is_some());
+}
+
+#[test]
+fn rejects_out_of_order_characters() {
+    assert_eq!(fuzzy_score("emht", "Theme"), None);
+    assert_eq!(fuzzy_score("discordx", "Discord"), None);
+}
+
+#[test]
+fn consecutive_and_word_start_matches_score_higher() {
+    let prefix = fuzzy_score("the", "Theme").unwrap();
+    let scattered = fuzzy_score("the", "the rich presence").unwrap();
+    let inside = fuzzy_score("the", "aesthetic").unwrap();
+
+    assert_eq!(prefix, scattered);
+    assert!(prefix > inside);
+}
+
+#[test]
+fn ignores_whitespace_in_query() {
+    assert_eq!(
+        fuzzy_score("color mode", "color mode"),
+        fuzzy_score("colormode", "color mode")
+    );
+}
@@ -0,0 +1,29 @@
+use gittype::presentation::ui::{LayoutHelpers, TypingLayout};
+use ratatui::layout::Rect;
+
+#[test]
+fn typing_layout_uses_two_columns_only_when_enabled_and_ultra_wide() {
+    let wide = Rect::new(0, 0, LayoutHelpers::ULTRA_WIDE_MIN_WIDTH, 40);
+    let narrow = Rect::new(0, 0, LayoutHelpers::ULTRA_WIDE_MIN_WIDTH - 1, 40);
+
+    assert_eq!(
+        LayoutHelpers::typing_layout(wide, true),
+        TypingLayout::TwoColumn
+    );
+    assert_eq!(
+        LayoutHelpers::typing_layout(wide, false),
+        TypingLayout::Single
+    );
+    assert_eq!(
+        LayoutHelpers::typing_layout(narrow, true),
+        TypingLayout::Single
+    );
+}
+
+#[test]
+fn split_side_column_keeps_the_column_width_on_the_right() {
+    let (main, side) = LayoutHelpers::split_side_column(Rect::new(2, 1, 100, 20), 30);
+
+    assert_eq!(main, Rect::new(2, 1, 70, 20));
+    assert_eq!(side, Rect::new(72, 1, 30, 20));
+}
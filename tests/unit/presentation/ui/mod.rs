@@ -1,2 +1,9 @@
+mod ascii_numbers_tests;
+mod braille_chart_tests;
+mod color_support_tests;
 mod colors_tests;
+mod fuzzy_tests;
 pub mod gradation_text_tests;
+pub mod layout_helpers_tests;
+mod screen_capture_tests;
+mod sparkline_tests;
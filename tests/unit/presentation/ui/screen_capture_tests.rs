@@ -0,0 +1,98 @@
+use gittype::domain::models::color_mode::ColorMode;
+use gittype::domain::models::color_scheme::{ColorScheme, ThemeFile};
+use gittype::presentation::ui::{Colors, ScreenCapture};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+
+fn buffer(lines: &[&str]) -> Buffer {
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0) as u16;
+    let mut buffer = Buffer::empty(Rect::new(0, 0, width + 2, lines.len() as u16));
+    for (y, line) in lines.iter().enumerate() {
+        buffer.set_string(0, y as u16, line, Style::default());
+    }
+    buffer
+}
+
+#[test]
+fn ansi_keeps_colors_and_trims_trailing_blanks() {
+    let mut buffer = buffer(&["ab"]);
+    buffer.set_style(
+        Rect::new(1, 0, 1, 1),
+        Style::default()
+            .fg(Color::Rgb(255, 0, 0))
+            .add_modifier(Modifier::BOLD),
+    );
+
+    assert_eq!(
+        ScreenCapture::to_ansi(&buffer),
+        "\x1b[0ma\x1b[0;1;38;2;255;0;0mb\x1b[0m\n"
+    );
+}
+
+#[test]
+fn ansi_uses_the_16_color_codes_for_named_colors() {
+    let mut buffer = buffer(&["ab"]);
+    buffer.set_style(Rect::new(0, 0, 1, 1), Style::default().fg(Color::Red));
+    buffer.set_style(Rect::new(1, 0, 1, 1), Style::default().bg(Color::LightBlue));
+
+    assert_eq!(
+        ScreenCapture::to_ansi(&buffer),
+        "\x1b[0;31ma\x1b[0;104mb\x1b[0m\n"
+    );
+}
+
+#[test]
+fn ansi_writes_wide_characters_once() {
+    let buffer = buffer(&["日本"]);
+
+    assert_eq!(ScreenCapture::to_ansi(&buffer), "\x1b[0m日本\x1b[0m\n");
+}
+
+#[test]
+fn html_escapes_text_and_styles_runs() {
+    let mut buffer = buffer(&["<a>"]);
+    buffer.set_style(
+        Rect::new(0, 0, 3, 1),
+        Style::default().fg(Color::Indexed(196)),
+    );
+
+    let html = ScreenCapture::to_html(&buffer, "Stage <1>");
+
+    assert!(html.contains("<title>Stage &lt;1&gt;</title>"));
+    assert!(html.contains("<span style=\"color:#ff0000\">&lt;a&gt;</span>\n"));
+}
+
+#[test]
+fn html_swaps_colors_of_reversed_cells() {
+    let mut buffer = buffer(&["x"]);
+    buffer.set_style(
+        Rect::new(0, 0, 1, 1),
+        Style::default()
+            .fg(Color::Rgb(1, 2, 3))
+            .bg(Color::Rgb(4, 5, 6))
+            .add_modifier(Modifier::REVERSED),
+    );
+
+    assert!(ScreenCapture::to_html(&buffer, "")
+        .contains("<span style=\"color:#040506;background:#010203\">x</span>"));
+}
+
+#[test]
+fn themed_html_draws_default_colored_cells_in_the_theme_colors() {
+    let json = include_str!("../../../../assets/themes/default.json");
+    let theme: ThemeFile = serde_json::from_str(json).unwrap();
+    let colors = Colors::new(ColorScheme::from_theme_file(&theme, &ColorMode::Dark));
+    let css = |color: Color| match color {
+        Color::Rgb(r, g, b) => format!("#{:02x}{:02x}{:02x}", r, g, b),
+        other => panic!("theme color is not RGB: {:?}", other),
+    };
+
+    let html = ScreenCapture::to_themed_html(&buffer(&["a"]), "", &colors);
+
+    assert!(html.contains(&format!("background:{}", css(colors.background()))));
+    assert!(html.contains(&format!(
+        "<span style=\"color:{}\">a</span>",
+        css(colors.text())
+    )));
+}
@@ -0,0 +1,37 @@
+use gittype::presentation::ui::SparklineWidget;
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+#[test]
+fn text_scales_values_between_lowest_and_highest() {
+    let values = [10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0];
+
+    assert_eq!(SparklineWidget::new(&values).text(20), "▁▂▃▄▅▆▇█");
+}
+
+#[test]
+fn text_averages_values_down_to_the_width() {
+    let values = [0.0, 0.0, 10.0, 10.0, 20.0, 20.0];
+
+    assert_eq!(SparklineWidget::new(&values).text(3), "▁▅█");
+}
+
+#[test]
+fn flat_series_sits_at_mid_height() {
+    assert_eq!(SparklineWidget::new(&[42.0, 42.0, 42.0]).text(10), "▄▄▄");
+}
+
+#[test]
+fn empty_series_draws_nothing() {
+    assert_eq!(SparklineWidget::new(&[]).text(10), "");
+}
+
+#[test]
+fn render_writes_the_first_row_of_the_area() {
+    let values = [1.0, 2.0];
+    let area = Rect::new(0, 0, 4, 2);
+    let mut buffer = Buffer::empty(area);
+
+    SparklineWidget::new(&values).render(area, &mut buffer);
+
+    assert_eq!(buffer, Buffer::with_lines(["▁█  ", "    "]));
+}